@@ -2,6 +2,36 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`AccountBrands::brand_resources_put`].
+#[derive(Default)]
+pub struct BrandResourcesPutForm {
+    file_xml: Option<crate::utils::FilePart>,
+}
+
+impl BrandResourcesPutForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Brand resource XML file.
+    pub fn file_xml(mut self, value: crate::utils::FilePart) -> Self {
+        self.file_xml = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.file_xml {
+            form = form.part("file.xml", value.into_part().await?);
+        } else {
+            anyhow::bail!("missing required multipart field `file.xml`");
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct AccountBrands {
     pub client: Client,
 }
@@ -313,7 +343,7 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
         logo_type: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/logos/{}",
@@ -324,13 +354,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "image/png")
             .await
     }
     /**
@@ -550,6 +574,7 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
         resource_content_type: &str,
+        body: BrandResourcesPutForm,
     ) -> Result<crate::types::BrandResources> {
         let url = self.client.url(
             &format!(
@@ -561,13 +586,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::PUT, &url, body.into_form().await?)
             .await
     }
 }
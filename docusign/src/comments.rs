@@ -32,7 +32,7 @@ impl Comments {
         account_id: &str,
         envelope_id: &str,
         encoding: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -48,13 +48,7 @@ impl Comments {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "application/pdf")
             .await
     }
 }
@@ -230,7 +230,7 @@ impl EnvelopeDocuments {
         shared_user_id: &str,
         show_changes: &str,
         watermark: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !certificate.is_empty() {
             query_args.push(("certificate".to_string(), certificate.to_string()));
@@ -274,13 +274,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "application/pdf")
             .await
     }
     /**
@@ -1204,7 +1204,7 @@ impl Envelopes {
         max_height: &str,
         max_width: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dpi.is_empty() {
             query_args.push(("dpi".to_string(), dpi.to_string()));
@@ -1231,13 +1231,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "image/png")
             .await
     }
     /**
@@ -1383,7 +1377,7 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1400,13 +1394,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "image/gif")
             .await
     }
     /**
@@ -1515,7 +1503,7 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1532,13 +1520,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "image/gif")
             .await
     }
     /**
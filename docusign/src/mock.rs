@@ -0,0 +1,7379 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`AccountBrands`](crate::account_brands::AccountBrands).
+    pub fn account_brands(&self) -> AccountBrands<'_> {
+        AccountBrands { mock: self }
+    }
+
+    /// Mock the functions of [`AccountConsumerDisclosures`](crate::account_consumer_disclosures::AccountConsumerDisclosures).
+    pub fn account_consumer_disclosures(&self) -> AccountConsumerDisclosures<'_> {
+        AccountConsumerDisclosures { mock: self }
+    }
+
+    /// Mock the functions of [`AccountCustomFields`](crate::account_custom_fields::AccountCustomFields).
+    pub fn account_custom_fields(&self) -> AccountCustomFields<'_> {
+        AccountCustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`AccountPasswordRules`](crate::account_password_rules::AccountPasswordRules).
+    pub fn account_password_rules(&self) -> AccountPasswordRules<'_> {
+        AccountPasswordRules { mock: self }
+    }
+
+    /// Mock the functions of [`AccountPermissionProfiles`](crate::account_permission_profiles::AccountPermissionProfiles).
+    pub fn account_permission_profiles(&self) -> AccountPermissionProfiles<'_> {
+        AccountPermissionProfiles { mock: self }
+    }
+
+    /// Mock the functions of [`AccountSealProviders`](crate::account_seal_providers::AccountSealProviders).
+    pub fn account_seal_providers(&self) -> AccountSealProviders<'_> {
+        AccountSealProviders { mock: self }
+    }
+
+    /// Mock the functions of [`AccountSignatureProviders`](crate::account_signature_providers::AccountSignatureProviders).
+    pub fn account_signature_providers(&self) -> AccountSignatureProviders<'_> {
+        AccountSignatureProviders { mock: self }
+    }
+
+    /// Mock the functions of [`AccountSignatures`](crate::account_signatures::AccountSignatures).
+    pub fn account_signatures(&self) -> AccountSignatures<'_> {
+        AccountSignatures { mock: self }
+    }
+
+    /// Mock the functions of [`AccountTabSettings`](crate::account_tab_settings::AccountTabSettings).
+    pub fn account_tab_settings(&self) -> AccountTabSettings<'_> {
+        AccountTabSettings { mock: self }
+    }
+
+    /// Mock the functions of [`AccountWatermarks`](crate::account_watermarks::AccountWatermarks).
+    pub fn account_watermarks(&self) -> AccountWatermarks<'_> {
+        AccountWatermarks { mock: self }
+    }
+
+    /// Mock the functions of [`Accounts`](crate::accounts::Accounts).
+    pub fn accounts(&self) -> Accounts<'_> {
+        Accounts { mock: self }
+    }
+
+    /// Mock the functions of [`ApplianceInfo`](crate::appliance_info::ApplianceInfo).
+    pub fn appliance_info(&self) -> ApplianceInfo<'_> {
+        ApplianceInfo { mock: self }
+    }
+
+    /// Mock the functions of [`BccEmailArchive`](crate::bcc_email_archive::BccEmailArchive).
+    pub fn bcc_email_archive(&self) -> BccEmailArchive<'_> {
+        BccEmailArchive { mock: self }
+    }
+
+    /// Mock the functions of [`BillingPlans`](crate::billing_plans::BillingPlans).
+    pub fn billing_plans(&self) -> BillingPlans<'_> {
+        BillingPlans { mock: self }
+    }
+
+    /// Mock the functions of [`BulkSend`](crate::bulk_send::BulkSend).
+    pub fn bulk_send(&self) -> BulkSend<'_> {
+        BulkSend { mock: self }
+    }
+
+    /// Mock the functions of [`ChunkedUploads`](crate::chunked_uploads::ChunkedUploads).
+    pub fn chunked_uploads(&self) -> ChunkedUploads<'_> {
+        ChunkedUploads { mock: self }
+    }
+
+    /// Mock the functions of [`CloudStorage`](crate::cloud_storage::CloudStorage).
+    pub fn cloud_storage(&self) -> CloudStorage<'_> {
+        CloudStorage { mock: self }
+    }
+
+    /// Mock the functions of [`CloudStorageProviders`](crate::cloud_storage_providers::CloudStorageProviders).
+    pub fn cloud_storage_providers(&self) -> CloudStorageProviders<'_> {
+        CloudStorageProviders { mock: self }
+    }
+
+    /// Mock the functions of [`Comments`](crate::comments::Comments).
+    pub fn comments(&self) -> Comments<'_> {
+        Comments { mock: self }
+    }
+
+    /// Mock the functions of [`ConnectConfigurations`](crate::connect_configurations::ConnectConfigurations).
+    pub fn connect_configurations(&self) -> ConnectConfigurations<'_> {
+        ConnectConfigurations { mock: self }
+    }
+
+    /// Mock the functions of [`ConnectEvents`](crate::connect_events::ConnectEvents).
+    pub fn connect_events(&self) -> ConnectEvents<'_> {
+        ConnectEvents { mock: self }
+    }
+
+    /// Mock the functions of [`ConnectSecret`](crate::connect_secret::ConnectSecret).
+    pub fn connect_secret(&self) -> ConnectSecret<'_> {
+        ConnectSecret { mock: self }
+    }
+
+    /// Mock the functions of [`Contacts`](crate::contacts::Contacts).
+    pub fn contacts(&self) -> Contacts<'_> {
+        Contacts { mock: self }
+    }
+
+    /// Mock the functions of [`CustomTabs`](crate::custom_tabs::CustomTabs).
+    pub fn custom_tabs(&self) -> CustomTabs<'_> {
+        CustomTabs { mock: self }
+    }
+
+    /// Mock the functions of [`DocumentResponsiveHtmlPreview`](crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview).
+    pub fn document_responsive_html_preview(&self) -> DocumentResponsiveHtmlPreview<'_> {
+        DocumentResponsiveHtmlPreview { mock: self }
+    }
+
+    /// Mock the functions of [`ENoteConfigurations`](crate::e_note_configurations::ENoteConfigurations).
+    pub fn e_note_configurations(&self) -> ENoteConfigurations<'_> {
+        ENoteConfigurations { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeAttachments`](crate::envelope_attachments::EnvelopeAttachments).
+    pub fn envelope_attachments(&self) -> EnvelopeAttachments<'_> {
+        EnvelopeAttachments { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeConsumerDisclosures`](crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures).
+    pub fn envelope_consumer_disclosures(&self) -> EnvelopeConsumerDisclosures<'_> {
+        EnvelopeConsumerDisclosures { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeCustomFields`](crate::envelope_custom_fields::EnvelopeCustomFields).
+    pub fn envelope_custom_fields(&self) -> EnvelopeCustomFields<'_> {
+        EnvelopeCustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeDocumentFields`](crate::envelope_document_fields::EnvelopeDocumentFields).
+    pub fn envelope_document_fields(&self) -> EnvelopeDocumentFields<'_> {
+        EnvelopeDocumentFields { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeDocumentHtmlDefinitions`](crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions).
+    pub fn envelope_document_html_definitions(&self) -> EnvelopeDocumentHtmlDefinitions<'_> {
+        EnvelopeDocumentHtmlDefinitions { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeDocumentTabs`](crate::envelope_document_tabs::EnvelopeDocumentTabs).
+    pub fn envelope_document_tabs(&self) -> EnvelopeDocumentTabs<'_> {
+        EnvelopeDocumentTabs { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeDocumentVisibility`](crate::envelope_document_visibility::EnvelopeDocumentVisibility).
+    pub fn envelope_document_visibility(&self) -> EnvelopeDocumentVisibility<'_> {
+        EnvelopeDocumentVisibility { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeDocuments`](crate::envelope_documents::EnvelopeDocuments).
+    pub fn envelope_documents(&self) -> EnvelopeDocuments<'_> {
+        EnvelopeDocuments { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeEmailSettings`](crate::envelope_email_settings::EnvelopeEmailSettings).
+    pub fn envelope_email_settings(&self) -> EnvelopeEmailSettings<'_> {
+        EnvelopeEmailSettings { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeFormData`](crate::envelope_form_data::EnvelopeFormData).
+    pub fn envelope_form_data(&self) -> EnvelopeFormData<'_> {
+        EnvelopeFormData { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeHtmlDefinitions`](crate::envelope_html_definitions::EnvelopeHtmlDefinitions).
+    pub fn envelope_html_definitions(&self) -> EnvelopeHtmlDefinitions<'_> {
+        EnvelopeHtmlDefinitions { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeLocks`](crate::envelope_locks::EnvelopeLocks).
+    pub fn envelope_locks(&self) -> EnvelopeLocks<'_> {
+        EnvelopeLocks { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeRecipientTabs`](crate::envelope_recipient_tabs::EnvelopeRecipientTabs).
+    pub fn envelope_recipient_tabs(&self) -> EnvelopeRecipientTabs<'_> {
+        EnvelopeRecipientTabs { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeRecipients`](crate::envelope_recipients::EnvelopeRecipients).
+    pub fn envelope_recipients(&self) -> EnvelopeRecipients<'_> {
+        EnvelopeRecipients { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeTemplates`](crate::envelope_templates::EnvelopeTemplates).
+    pub fn envelope_templates(&self) -> EnvelopeTemplates<'_> {
+        EnvelopeTemplates { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeTransferRules`](crate::envelope_transfer_rules::EnvelopeTransferRules).
+    pub fn envelope_transfer_rules(&self) -> EnvelopeTransferRules<'_> {
+        EnvelopeTransferRules { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeViews`](crate::envelope_views::EnvelopeViews).
+    pub fn envelope_views(&self) -> EnvelopeViews<'_> {
+        EnvelopeViews { mock: self }
+    }
+
+    /// Mock the functions of [`EnvelopeWorkflowDefinition`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition).
+    pub fn envelope_workflow_definition(&self) -> EnvelopeWorkflowDefinition<'_> {
+        EnvelopeWorkflowDefinition { mock: self }
+    }
+
+    /// Mock the functions of [`Envelopes`](crate::envelopes::Envelopes).
+    pub fn envelopes(&self) -> Envelopes<'_> {
+        Envelopes { mock: self }
+    }
+
+    /// Mock the functions of [`FavoriteTemplates`](crate::favorite_templates::FavoriteTemplates).
+    pub fn favorite_templates(&self) -> FavoriteTemplates<'_> {
+        FavoriteTemplates { mock: self }
+    }
+
+    /// Mock the functions of [`Folders`](crate::folders::Folders).
+    pub fn folders(&self) -> Folders<'_> {
+        Folders { mock: self }
+    }
+
+    /// Mock the functions of [`GroupBrands`](crate::group_brands::GroupBrands).
+    pub fn group_brands(&self) -> GroupBrands<'_> {
+        GroupBrands { mock: self }
+    }
+
+    /// Mock the functions of [`GroupUsers`](crate::group_users::GroupUsers).
+    pub fn group_users(&self) -> GroupUsers<'_> {
+        GroupUsers { mock: self }
+    }
+
+    /// Mock the functions of [`Groups`](crate::groups::Groups).
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { mock: self }
+    }
+
+    /// Mock the functions of [`IdentityVerifications`](crate::identity_verifications::IdentityVerifications).
+    pub fn identity_verifications(&self) -> IdentityVerifications<'_> {
+        IdentityVerifications { mock: self }
+    }
+
+    /// Mock the functions of [`Invoices`](crate::invoices::Invoices).
+    pub fn invoices(&self) -> Invoices<'_> {
+        Invoices { mock: self }
+    }
+
+    /// Mock the functions of [`Notary`](crate::notary::Notary).
+    pub fn notary(&self) -> Notary<'_> {
+        Notary { mock: self }
+    }
+
+    /// Mock the functions of [`NotaryJournals`](crate::notary_journals::NotaryJournals).
+    pub fn notary_journals(&self) -> NotaryJournals<'_> {
+        NotaryJournals { mock: self }
+    }
+
+    /// Mock the functions of [`NotaryJurisdiction`](crate::notary_jurisdiction::NotaryJurisdiction).
+    pub fn notary_jurisdiction(&self) -> NotaryJurisdiction<'_> {
+        NotaryJurisdiction { mock: self }
+    }
+
+    /// Mock the functions of [`PaymentGatewayAccounts`](crate::payment_gateway_accounts::PaymentGatewayAccounts).
+    pub fn payment_gateway_accounts(&self) -> PaymentGatewayAccounts<'_> {
+        PaymentGatewayAccounts { mock: self }
+    }
+
+    /// Mock the functions of [`Payments`](crate::payments::Payments).
+    pub fn payments(&self) -> Payments<'_> {
+        Payments { mock: self }
+    }
+
+    /// Mock the functions of [`PowerFormData`](crate::power_form_data::PowerFormData).
+    pub fn power_form_data(&self) -> PowerFormData<'_> {
+        PowerFormData { mock: self }
+    }
+
+    /// Mock the functions of [`PowerForms`](crate::power_forms::PowerForms).
+    pub fn power_forms(&self) -> PowerForms<'_> {
+        PowerForms { mock: self }
+    }
+
+    /// Mock the functions of [`Reports`](crate::reports::Reports).
+    pub fn reports(&self) -> Reports<'_> {
+        Reports { mock: self }
+    }
+
+    /// Mock the functions of [`RequestLogs`](crate::request_logs::RequestLogs).
+    pub fn request_logs(&self) -> RequestLogs<'_> {
+        RequestLogs { mock: self }
+    }
+
+    /// Mock the functions of [`Resources`](crate::resources::Resources).
+    pub fn resources(&self) -> Resources<'_> {
+        Resources { mock: self }
+    }
+
+    /// Mock the functions of [`ResponsiveHtmlPreview`](crate::responsive_html_preview::ResponsiveHtmlPreview).
+    pub fn responsive_html_preview(&self) -> ResponsiveHtmlPreview<'_> {
+        ResponsiveHtmlPreview { mock: self }
+    }
+
+    /// Mock the functions of [`Services`](crate::services::Services).
+    pub fn services(&self) -> Services<'_> {
+        Services { mock: self }
+    }
+
+    /// Mock the functions of [`SigningGroupUsers`](crate::signing_group_users::SigningGroupUsers).
+    pub fn signing_group_users(&self) -> SigningGroupUsers<'_> {
+        SigningGroupUsers { mock: self }
+    }
+
+    /// Mock the functions of [`SigningGroups`](crate::signing_groups::SigningGroups).
+    pub fn signing_groups(&self) -> SigningGroups<'_> {
+        SigningGroups { mock: self }
+    }
+
+    /// Mock the functions of [`TabsBlob`](crate::tabs_blob::TabsBlob).
+    pub fn tabs_blob(&self) -> TabsBlob<'_> {
+        TabsBlob { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateBulkRecipients`](crate::template_bulk_recipients::TemplateBulkRecipients).
+    pub fn template_bulk_recipients(&self) -> TemplateBulkRecipients<'_> {
+        TemplateBulkRecipients { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateCustomFields`](crate::template_custom_fields::TemplateCustomFields).
+    pub fn template_custom_fields(&self) -> TemplateCustomFields<'_> {
+        TemplateCustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocumentFields`](crate::template_document_fields::TemplateDocumentFields).
+    pub fn template_document_fields(&self) -> TemplateDocumentFields<'_> {
+        TemplateDocumentFields { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocumentHtmlDefinitions`](crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions).
+    pub fn template_document_html_definitions(&self) -> TemplateDocumentHtmlDefinitions<'_> {
+        TemplateDocumentHtmlDefinitions { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocumentResponsiveHtmlPreview`](crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview).
+    pub fn template_document_responsive_html_preview(
+        &self,
+    ) -> TemplateDocumentResponsiveHtmlPreview<'_> {
+        TemplateDocumentResponsiveHtmlPreview { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocumentTabs`](crate::template_document_tabs::TemplateDocumentTabs).
+    pub fn template_document_tabs(&self) -> TemplateDocumentTabs<'_> {
+        TemplateDocumentTabs { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocumentVisibility`](crate::template_document_visibility::TemplateDocumentVisibility).
+    pub fn template_document_visibility(&self) -> TemplateDocumentVisibility<'_> {
+        TemplateDocumentVisibility { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateDocuments`](crate::template_documents::TemplateDocuments).
+    pub fn template_documents(&self) -> TemplateDocuments<'_> {
+        TemplateDocuments { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateHtmlDefinitions`](crate::template_html_definitions::TemplateHtmlDefinitions).
+    pub fn template_html_definitions(&self) -> TemplateHtmlDefinitions<'_> {
+        TemplateHtmlDefinitions { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateLocks`](crate::template_locks::TemplateLocks).
+    pub fn template_locks(&self) -> TemplateLocks<'_> {
+        TemplateLocks { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateRecipientTabs`](crate::template_recipient_tabs::TemplateRecipientTabs).
+    pub fn template_recipient_tabs(&self) -> TemplateRecipientTabs<'_> {
+        TemplateRecipientTabs { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateRecipients`](crate::template_recipients::TemplateRecipients).
+    pub fn template_recipients(&self) -> TemplateRecipients<'_> {
+        TemplateRecipients { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateResponsiveHtmlPreview`](crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview).
+    pub fn template_responsive_html_preview(&self) -> TemplateResponsiveHtmlPreview<'_> {
+        TemplateResponsiveHtmlPreview { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateViews`](crate::template_views::TemplateViews).
+    pub fn template_views(&self) -> TemplateViews<'_> {
+        TemplateViews { mock: self }
+    }
+
+    /// Mock the functions of [`Templates`](crate::templates::Templates).
+    pub fn templates(&self) -> Templates<'_> {
+        Templates { mock: self }
+    }
+
+    /// Mock the functions of [`UserCustomSettings`](crate::user_custom_settings::UserCustomSettings).
+    pub fn user_custom_settings(&self) -> UserCustomSettings<'_> {
+        UserCustomSettings { mock: self }
+    }
+
+    /// Mock the functions of [`UserProfiles`](crate::user_profiles::UserProfiles).
+    pub fn user_profiles(&self) -> UserProfiles<'_> {
+        UserProfiles { mock: self }
+    }
+
+    /// Mock the functions of [`UserSignatures`](crate::user_signatures::UserSignatures).
+    pub fn user_signatures(&self) -> UserSignatures<'_> {
+        UserSignatures { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+
+    /// Mock the functions of [`WorkspaceItems`](crate::workspace_items::WorkspaceItems).
+    pub fn workspace_items(&self) -> WorkspaceItems<'_> {
+        WorkspaceItems { mock: self }
+    }
+
+    /// Mock the functions of [`Workspaces`](crate::workspaces::Workspaces).
+    pub fn workspaces(&self) -> Workspaces<'_> {
+        Workspaces { mock: self }
+    }
+}
+
+pub struct AccountBrands<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountBrands<'a> {
+    /// Mock [`AccountBrands::brands_get`](crate::account_brands::AccountBrands::brands_get), a `GET` to `/v2.1/accounts/{accountId}/brands`.
+    pub fn brands_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountBrands> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brands_post`](crate::account_brands::AccountBrands::brands_post), a `POST` to `/v2.1/accounts/{accountId}/brands`.
+    pub fn brands_post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountBrands> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/brands",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brands_delete`](crate::account_brands::AccountBrands::brands_delete), a `DELETE` to `/v2.1/accounts/{accountId}/brands`.
+    pub fn brands_delete(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountBrands> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/brands",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_get`](crate::account_brands::AccountBrands::brand_get), a `GET` to `/v2.1/accounts/{accountId}/brands/{brandId}`.
+    pub fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Brand> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_put`](crate::account_brands::AccountBrands::brand_put), a `PUT` to `/v2.1/accounts/{accountId}/brands/{brandId}`.
+    pub fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Brand> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/brands/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_delete`](crate::account_brands::AccountBrands::brand_delete), a `DELETE` to `/v2.1/accounts/{accountId}/brands/{brandId}`.
+    pub fn brand_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/brands/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_export_get_file`](crate::account_brands::AccountBrands::brand_export_get_file), a `GET` to `/v2.1/accounts/{accountId}/brands/{brandId}/file`.
+    pub fn brand_export_get_file(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/file",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_logo_get`](crate::account_brands::AccountBrands::brand_logo_get), a `GET` to `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}`.
+    pub fn brand_logo_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/logos/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(logo_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_logo_put`](crate::account_brands::AccountBrands::brand_logo_put), a `PUT` to `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}`.
+    pub fn brand_logo_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/logos/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(logo_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_logo_delete`](crate::account_brands::AccountBrands::brand_logo_delete), a `DELETE` to `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}`.
+    pub fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/logos/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(logo_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_resources_get_list`](crate::account_brands::AccountBrands::brand_resources_get_list), a `GET` to `/v2.1/accounts/{accountId}/brands/{brandId}/resources`.
+    pub fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BrandResourcesList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/resources",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_resources_get`](crate::account_brands::AccountBrands::brand_resources_get), a `GET` to `/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}`.
+    pub fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(resource_content_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountBrands::brand_resources_put`](crate::account_brands::AccountBrands::brand_resources_put), a `PUT` to `/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}`.
+    pub fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BrandResources> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(resource_content_type)
+            ),
+        )
+    }
+}
+
+pub struct AccountConsumerDisclosures<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountConsumerDisclosures<'a> {
+    /// Mock [`AccountConsumerDisclosures::consumer_disclosure_get`](crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get), a `GET` to `/v2.1/accounts/{accountId}/consumer_disclosure`.
+    pub fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountConsumerDisclosures> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/consumer_disclosure",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountConsumerDisclosures::consumer_disclosure_get_lang_code`](crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get_lang_code), a `GET` to `/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}`.
+    pub fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountConsumerDisclosures> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(lang_code)
+            ),
+        )
+    }
+
+    /// Mock [`AccountConsumerDisclosures::consumer_disclosure_put`](crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_put), a `PUT` to `/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}`.
+    pub fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConsumerDisclosure> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(lang_code)
+            ),
+        )
+    }
+}
+
+pub struct AccountCustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountCustomFields<'a> {
+    /// Mock [`AccountCustomFields::get`](crate::account_custom_fields::AccountCustomFields::get), a `GET` to `/v2.1/accounts/{accountId}/custom_fields`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountCustomFields> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountCustomFields::post`](crate::account_custom_fields::AccountCustomFields::post), a `POST` to `/v2.1/accounts/{accountId}/custom_fields`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountCustomFields> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountCustomFields::put`](crate::account_custom_fields::AccountCustomFields::put), a `PUT` to `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountCustomFields> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountCustomFields::delete`](crate::account_custom_fields::AccountCustomFields::delete), a `DELETE` to `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id)
+            ),
+        )
+    }
+}
+
+pub struct AccountPasswordRules<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountPasswordRules<'a> {
+    /// Mock [`AccountPasswordRules::get`](crate::account_password_rules::AccountPasswordRules::get), a `GET` to `/v2.1/accounts/{accountId}/settings/password_rules`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountPasswordRulesData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/password_rules",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPasswordRules::put`](crate::account_password_rules::AccountPasswordRules::put), a `PUT` to `/v2.1/accounts/{accountId}/settings/password_rules`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountPasswordRulesData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings/password_rules",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPasswordRules::password_rules_get`](crate::account_password_rules::AccountPasswordRules::password_rules_get), a `GET` to `/v2.1/current_user/password_rules`.
+    pub fn password_rules_get(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPasswordRules> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/current_user/password_rules".to_string(),
+        )
+    }
+}
+
+pub struct AccountPermissionProfiles<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountPermissionProfiles<'a> {
+    /// Mock [`AccountPermissionProfiles::permission_profiles_get`](crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get), a `GET` to `/v2.1/accounts/{accountId}/permission_profiles`.
+    pub fn permission_profiles_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PermissionProfileInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPermissionProfiles::permission_profiles_post`](crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_post), a `POST` to `/v2.1/accounts/{accountId}/permission_profiles`.
+    pub fn permission_profiles_post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PermissionProfile> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPermissionProfiles::permission_profiles_get_profile`](crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get_profile), a `GET` to `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}`.
+    pub fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PermissionProfile> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPermissionProfiles::permission_profiles_put`](crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_put), a `PUT` to `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}`.
+    pub fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PermissionProfile> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountPermissionProfiles::permission_profiles_delete`](crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_delete), a `DELETE` to `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}`.
+    pub fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id)
+            ),
+        )
+    }
+}
+
+pub struct AccountSealProviders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountSealProviders<'a> {
+    /// Mock [`AccountSealProviders::account_signature_providers_get_seal`](crate::account_seal_providers::AccountSealProviders::account_signature_providers_get_seal), a `GET` to `/v2.1/accounts/{accountId}/seals`.
+    pub fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSealProviders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/seals",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct AccountSignatureProviders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountSignatureProviders<'a> {
+    /// Mock [`AccountSignatureProviders::get`](crate::account_signature_providers::AccountSignatureProviders::get), a `GET` to `/v2.1/accounts/{accountId}/signatureProviders`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignatureProvidersData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signatureProviders",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct AccountSignatures<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountSignatures<'a> {
+    /// Mock [`AccountSignatures::get`](crate::account_signatures::AccountSignatures::get), a `GET` to `/v2.1/accounts/{accountId}/signatures`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signatures",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::put_signature`](crate::account_signatures::AccountSignatures::put_signature), a `PUT` to `/v2.1/accounts/{accountId}/signatures`.
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signatures",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::post`](crate::account_signatures::AccountSignatures::post), a `POST` to `/v2.1/accounts/{accountId}/signatures`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/signatures",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::get_signature`](crate::account_signatures::AccountSignatures::get_signature), a `GET` to `/v2.1/accounts/{accountId}/signatures/{signatureId}`.
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignature> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::put_signature_account_signatures`](crate::account_signatures::AccountSignatures::put_signature_account_signatures), a `PUT` to `/v2.1/accounts/{accountId}/signatures/{signatureId}`.
+    pub fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignature> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::delete_signature`](crate::account_signatures::AccountSignatures::delete_signature), a `DELETE` to `/v2.1/accounts/{accountId}/signatures/{signatureId}`.
+    pub fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::get_signature_image`](crate::account_signatures::AccountSignatures::get_signature_image), a `GET` to `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}`.
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::put_signature_image`](crate::account_signatures::AccountSignatures::put_signature_image), a `PUT` to `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}`.
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignature> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+
+    /// Mock [`AccountSignatures::delete_signature_image`](crate::account_signatures::AccountSignatures::delete_signature_image), a `DELETE` to `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}`.
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSignature> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+}
+
+pub struct AccountTabSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountTabSettings<'a> {
+    /// Mock [`AccountTabSettings::tab_settings_get`](crate::account_tab_settings::AccountTabSettings::tab_settings_get), a `GET` to `/v2.1/accounts/{accountId}/settings/tabs`.
+    pub fn tab_settings_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabAccountSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/tabs",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountTabSettings::tab_settings_put`](crate::account_tab_settings::AccountTabSettings::tab_settings_put), a `PUT` to `/v2.1/accounts/{accountId}/settings/tabs`.
+    pub fn tab_settings_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabAccountSettings> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings/tabs",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct AccountWatermarks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountWatermarks<'a> {
+    /// Mock [`AccountWatermarks::watermark_get`](crate::account_watermarks::AccountWatermarks::watermark_get), a `GET` to `/v2.1/accounts/{accountId}/watermark`.
+    pub fn watermark_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Watermark> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/watermark",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountWatermarks::watermark_put`](crate::account_watermarks::AccountWatermarks::watermark_put), a `PUT` to `/v2.1/accounts/{accountId}/watermark`.
+    pub fn watermark_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Watermark> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/watermark",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`AccountWatermarks::watermark_preview_put`](crate::account_watermarks::AccountWatermarks::watermark_preview_put), a `PUT` to `/v2.1/accounts/{accountId}/watermark/preview`.
+    pub fn watermark_preview_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Watermark> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/watermark/preview",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Accounts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Accounts<'a> {
+    /// Mock [`Accounts::post`](crate::accounts::Accounts::post), a `POST` to `/v2.1/accounts`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::NewAccountSummary> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v2.1/accounts".to_string())
+    }
+
+    /// Mock [`Accounts::get_provisioning`](crate::accounts::Accounts::get_provisioning), a `GET` to `/v2.1/accounts/provisioning`.
+    pub fn get_provisioning(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProvisioningInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/accounts/provisioning".to_string(),
+        )
+    }
+
+    /// Mock [`Accounts::get`](crate::accounts::Accounts::get), a `GET` to `/v2.1/accounts/{accountId}`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete`](crate::accounts::Accounts::delete), a `DELETE` to `/v2.1/accounts/{accountId}`.
+    pub fn delete(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::billing_charges_get`](crate::accounts::Accounts::billing_charges_get), a `GET` to `/v2.1/accounts/{accountId}/billing_charges`.
+    pub fn billing_charges_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingChargeResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_charges",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::captive_recipients_delete_part`](crate::accounts::Accounts::captive_recipients_delete_part), a `DELETE` to `/v2.1/accounts/{accountId}/captive_recipients/{recipientPart}`.
+    pub fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CaptiveRecipientInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/captive_recipients/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(recipient_part)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::recipient_names_get`](crate::accounts::Accounts::recipient_names_get), a `GET` to `/v2.1/accounts/{accountId}/recipient_names`.
+    pub fn recipient_names_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecipientNamesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/recipient_names",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_get`](crate::accounts::Accounts::settings_get), a `GET` to `/v2.1/accounts/{accountId}/settings`.
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_put`](crate::accounts::Accounts::settings_put), a `PUT` to `/v2.1/accounts/{accountId}/settings`.
+    pub fn settings_put(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::envelope_purge_configuration_get`](crate::accounts::Accounts::envelope_purge_configuration_get), a `GET` to `/v2.1/accounts/{accountId}/settings/envelope_purge_configuration`.
+    pub fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopePurgeConfiguration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/envelope_purge_configuration",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::envelope_purge_configuration_put`](crate::accounts::Accounts::envelope_purge_configuration_put), a `PUT` to `/v2.1/accounts/{accountId}/settings/envelope_purge_configuration`.
+    pub fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopePurgeConfiguration> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings/envelope_purge_configuration",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::notification_defaults_get`](crate::accounts::Accounts::notification_defaults_get), a `GET` to `/v2.1/accounts/{accountId}/settings/notification_defaults`.
+    pub fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NotificationDefaultsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/notification_defaults",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::notification_defaults_put`](crate::accounts::Accounts::notification_defaults_put), a `PUT` to `/v2.1/accounts/{accountId}/settings/notification_defaults`.
+    pub fn notification_defaults_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NotificationDefaultsData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings/notification_defaults",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::shared_access_get`](crate::accounts::Accounts::shared_access_get), a `GET` to `/v2.1/accounts/{accountId}/shared_access`.
+    pub fn shared_access_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSharedAccess> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/shared_access",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::shared_access_put`](crate::accounts::Accounts::shared_access_put), a `PUT` to `/v2.1/accounts/{accountId}/shared_access`.
+    pub fn shared_access_put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSharedAccess> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/shared_access",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::supported_languages_get`](crate::accounts::Accounts::supported_languages_get), a `GET` to `/v2.1/accounts/{accountId}/supported_languages`.
+    pub fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SupportedLanguages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/supported_languages",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::unsupported_file_types_get`](crate::accounts::Accounts::unsupported_file_types_get), a `GET` to `/v2.1/accounts/{accountId}/unsupported_file_types`.
+    pub fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileTypeList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/unsupported_file_types",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::organization_exports_get_settings_export`](crate::accounts::Accounts::organization_exports_get_settings_export), a `GET` to `/v2.1/organization_exports/{organizationId}/account_settings/{resultId}`.
+    pub fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/organization_exports/{}/account_settings/{}",
+                crate::progenitor_support::encode_path(organization_id),
+                crate::progenitor_support::encode_path(result_id)
+            ),
+        )
+    }
+}
+
+pub struct ApplianceInfo<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ApplianceInfo<'a> {
+    /// Mock [`ApplianceInfo::envelope_get_dynamic_system_setting`](crate::appliance_info::ApplianceInfo::envelope_get_dynamic_system_setting), a `GET` to `/v2.1/accounts/{accountId}/display_appliance_info/dynamicsystemsettings`.
+    pub fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_template`](crate::appliance_info::ApplianceInfo::envelope_get_template), a `POST` to `/v2.1/accounts/{accountId}/display_appliance_info/templateInfo`.
+    pub fn envelope_get_template(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/display_appliance_info/templateInfo",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get`](crate::appliance_info::ApplianceInfo::envelope_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info`.
+    pub fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_account`](crate::appliance_info::ApplianceInfo::envelope_get_account), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/account_info`.
+    pub fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DisplayApplianceAccount> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/account_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_custom_field`](crate::appliance_info::ApplianceInfo::envelope_get_custom_field), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields`.
+    pub fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_delete_custom_fields`](crate::appliance_info::ApplianceInfo::envelope_delete_custom_fields), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields/delete`.
+    pub fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields/delete",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_date_signed`](crate::appliance_info::ApplianceInfo::envelope_get_date_signed), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/date_signed`.
+    pub fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_put_document`](crate::appliance_info::ApplianceInfo::envelope_put_document), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}`.
+    pub fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_delete_document`](crate::appliance_info::ApplianceInfo::envelope_delete_document), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}`.
+    pub fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_document_page`](crate::appliance_info::ApplianceInfo::envelope_get_document_page), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document_page_list`.
+    pub fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_image`](crate::appliance_info::ApplianceInfo::envelope_get_image), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/image`.
+    pub fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_locale_policy`](crate::appliance_info::ApplianceInfo::envelope_get_locale_policy), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/localepolicy/{userId}`.
+    pub fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_put_page`](crate::appliance_info::ApplianceInfo::envelope_put_page), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info`.
+    pub fn envelope_put_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_post_page`](crate::appliance_info::ApplianceInfo::envelope_post_page), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info`.
+    pub fn envelope_post_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_delete_page`](crate::appliance_info::ApplianceInfo::envelope_delete_page), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info/delete`.
+    pub fn envelope_delete_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info/delete",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_put_pdf`](crate::appliance_info::ApplianceInfo::envelope_put_pdf), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf`.
+    pub fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_pdf`](crate::appliance_info::ApplianceInfo::envelope_get_pdf), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf/{pdfId}`.
+    pub fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(pdf_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_pdf_blob`](crate::appliance_info::ApplianceInfo::envelope_get_pdf_blob), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs`.
+    pub fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_put_pdf_blob`](crate::appliance_info::ApplianceInfo::envelope_put_pdf_blob), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs`.
+    pub fn envelope_put_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_post_pdf_blob`](crate::appliance_info::ApplianceInfo::envelope_post_pdf_blob), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs`.
+    pub fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_put_recipient_denied_document_copy`](crate::appliance_info::ApplianceInfo::envelope_put_recipient_denied_document_copy), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy`.
+    pub fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_delete_recipient_denied_document_copy`](crate::appliance_info::ApplianceInfo::envelope_delete_recipient_denied_document_copy), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy`.
+    pub fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_get_signer_attachment`](crate::appliance_info::ApplianceInfo::envelope_get_signer_attachment), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info`.
+    pub fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_delete_signer_attachment`](crate::appliance_info::ApplianceInfo::envelope_delete_signer_attachment), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info`.
+    pub fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_post_error`](crate::appliance_info::ApplianceInfo::envelope_post_error), a `POST` to `/v2.1/display_appliance_info/error`.
+    pub fn envelope_post_error(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v2.1/display_appliance_info/error".to_string(),
+        )
+    }
+
+    /// Mock [`ApplianceInfo::envelope_post_redeem`](crate::appliance_info::ApplianceInfo::envelope_post_redeem), a `POST` to `/v2.1/display_appliance_info/redeem`.
+    pub fn envelope_post_redeem(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplianceInfo> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v2.1/display_appliance_info/redeem".to_string(),
+        )
+    }
+}
+
+pub struct BccEmailArchive<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BccEmailArchive<'a> {
+    /// Mock [`BccEmailArchive::get_list`](crate::bcc_email_archive::BccEmailArchive::get_list), a `GET` to `/v2.1/accounts/{accountId}/settings/bcc_email_archives`.
+    pub fn get_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BccEmailArchiveList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BccEmailArchive::post`](crate::bcc_email_archive::BccEmailArchive::post), a `POST` to `/v2.1/accounts/{accountId}/settings/bcc_email_archives`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BccEmailArchiveData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BccEmailArchive::get_history_list`](crate::bcc_email_archive::BccEmailArchive::get_history_list), a `GET` to `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}`.
+    pub fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BccEmailArchiveHistoryList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bcc_email_archive_id)
+            ),
+        )
+    }
+
+    /// Mock [`BccEmailArchive::delete`](crate::bcc_email_archive::BccEmailArchive::delete), a `DELETE` to `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bcc_email_archive_id)
+            ),
+        )
+    }
+}
+
+pub struct BillingPlans<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BillingPlans<'a> {
+    /// Mock [`BillingPlans::get`](crate::billing_plans::BillingPlans::get), a `GET` to `/v2.1/accounts/{accountId}/billing_plan`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountBillingPlanResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_plan",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::put`](crate::billing_plans::BillingPlans::put), a `PUT` to `/v2.1/accounts/{accountId}/billing_plan`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPlanUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/billing_plan",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::get_credit_card_info`](crate::billing_plans::BillingPlans::get_credit_card_info), a `GET` to `/v2.1/accounts/{accountId}/billing_plan/credit_card`.
+    pub fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CreditCardInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_plan/credit_card",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::get_downgrade_request_info`](crate::billing_plans::BillingPlans::get_downgrade_request_info), a `GET` to `/v2.1/accounts/{accountId}/billing_plan/downgrade`.
+    pub fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DowngradRequestBillingInfoResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_plan/downgrade",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::put_downgrade_account`](crate::billing_plans::BillingPlans::put_downgrade_account), a `PUT` to `/v2.1/accounts/{accountId}/billing_plan/downgrade`.
+    pub fn put_downgrade_account(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DowngradePlanUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/billing_plan/downgrade",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::purchased_envelopes_put`](crate::billing_plans::BillingPlans::purchased_envelopes_put), a `PUT` to `/v2.1/accounts/{accountId}/billing_plan/purchased_envelopes`.
+    pub fn purchased_envelopes_put(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/billing_plan/purchased_envelopes",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPlans::get_billing_plans`](crate::billing_plans::BillingPlans::get_billing_plans), a `GET` to `/v2.1/billing_plans`.
+    pub fn get_billing_plans(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPlansResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v2.1/billing_plans".to_string())
+    }
+
+    /// Mock [`BillingPlans::get_plan`](crate::billing_plans::BillingPlans::get_plan), a `GET` to `/v2.1/billing_plans/{billingPlanId}`.
+    pub fn get_plan(
+        &self,
+        billing_plan_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPlanResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/billing_plans/{}",
+                crate::progenitor_support::encode_path(billing_plan_id)
+            ),
+        )
+    }
+}
+
+pub struct BulkSend<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BulkSend<'a> {
+    /// Mock [`BulkSend::batch_get_batche`](crate::bulk_send::BulkSend::batch_get_batche), a `GET` to `/v2.1/accounts/{accountId}/bulk_send_batch`.
+    pub fn batch_get_batche(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendBatchSummaries> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_batch",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::batch_get_statu`](crate::bulk_send::BulkSend::batch_get_statu), a `GET` to `/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}`.
+    pub fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendBatchStatus> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_batch/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::batch_put_status`](crate::bulk_send::BulkSend::batch_put_status), a `PUT` to `/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}`.
+    pub fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendBatchStatus> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_batch/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::v_2crud_get_list`](crate::bulk_send::BulkSend::v_2crud_get_list), a `GET` to `/v2.1/accounts/{accountId}/bulk_send_lists`.
+    pub fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendingListSummaries> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::v_2crud_post_list`](crate::bulk_send::BulkSend::v_2crud_post_list), a `POST` to `/v2.1/accounts/{accountId}/bulk_send_lists`.
+    pub fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendingList> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::v_2crud_get_list_bulk_send`](crate::bulk_send::BulkSend::v_2crud_get_list_bulk_send), a `GET` to `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}`.
+    pub fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendingList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::v_2crud_put_list`](crate::bulk_send::BulkSend::v_2crud_put_list), a `PUT` to `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}`.
+    pub fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendingList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::v_2crud_delete_list`](crate::bulk_send::BulkSend::v_2crud_delete_list), a `DELETE` to `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}`.
+    pub fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendingListSummaries> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::post_request`](crate::bulk_send::BulkSend::post_request), a `POST` to `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/send`.
+    pub fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists/{}/send",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`BulkSend::test_post_request`](crate::bulk_send::BulkSend::test_post_request), a `POST` to `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/test`.
+    pub fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkSendTestResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/bulk_send_lists/{}/test",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bulk_send_list_id)
+            ),
+        )
+    }
+}
+
+pub struct ChunkedUploads<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChunkedUploads<'a> {
+    /// Mock [`ChunkedUploads::post`](crate::chunked_uploads::ChunkedUploads::post), a `POST` to `/v2.1/accounts/{accountId}/chunked_uploads`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChunkedUploadResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/chunked_uploads",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChunkedUploads::get_upload`](crate::chunked_uploads::ChunkedUploads::get_upload), a `GET` to `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}`.
+    pub fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChunkedUploadResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChunkedUploads::put`](crate::chunked_uploads::ChunkedUploads::put), a `PUT` to `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChunkedUploadResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChunkedUploads::delete_upload`](crate::chunked_uploads::ChunkedUploads::delete_upload), a `DELETE` to `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}`.
+    pub fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChunkedUploadResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChunkedUploads::put_upload_part`](crate::chunked_uploads::ChunkedUploads::put_upload_part), a `PUT` to `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}/{chunkedUploadPartSeq}`.
+    pub fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChunkedUploadResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id),
+                crate::progenitor_support::encode_path(chunked_upload_part_seq)
+            ),
+        )
+    }
+}
+
+pub struct CloudStorage<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CloudStorage<'a> {
+    /// Mock [`CloudStorage::folder_get_all`](crate::cloud_storage::CloudStorage::folder_get_all), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders`.
+    pub fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ExternalFolder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudStorage::folder_get`](crate::cloud_storage::CloudStorage::folder_get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}`.
+    pub fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ExternalFolder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+}
+
+pub struct CloudStorageProviders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CloudStorageProviders<'a> {
+    /// Mock [`CloudStorageProviders::cloud_storage_get_provider`](crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get_provider), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage`.
+    pub fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudStorageProvidersData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudStorageProviders::cloud_storage_post`](crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_post), a `POST` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage`.
+    pub fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudStorageProvidersData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudStorageProviders::cloud_storage_delete_providers`](crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete_providers), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage`.
+    pub fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudStorageProvidersData> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudStorageProviders::cloud_storage_get`](crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}`.
+    pub fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudStorageProvidersData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudStorageProviders::cloud_storage_delete`](crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}`.
+    pub fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudStorageProvidersData> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id)
+            ),
+        )
+    }
+}
+
+pub struct Comments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Comments<'a> {
+    /// Mock [`Comments::get_transcript`](crate::comments::Comments::get_transcript), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript`.
+    pub fn get_transcript(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct ConnectConfigurations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ConnectConfigurations<'a> {
+    /// Mock [`ConnectConfigurations::connect_get_config`](crate::connect_configurations::ConnectConfigurations::connect_get_config), a `GET` to `/v2.1/accounts/{accountId}/connect`.
+    pub fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectConfigResults> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectConfigurations::connect_put_configuration`](crate::connect_configurations::ConnectConfigurations::connect_put_configuration), a `PUT` to `/v2.1/accounts/{accountId}/connect`.
+    pub fn connect_put_configuration(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectCustomConfiguration> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/connect",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectConfigurations::connect_post_configuration`](crate::connect_configurations::ConnectConfigurations::connect_post_configuration), a `POST` to `/v2.1/accounts/{accountId}/connect`.
+    pub fn connect_post_configuration(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectCustomConfiguration> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/connect",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectConfigurations::connect_get_config_connect_configurations`](crate::connect_configurations::ConnectConfigurations::connect_get_config_connect_configurations), a `GET` to `/v2.1/accounts/{accountId}/connect/{connectId}`.
+    pub fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectConfigResults> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(connect_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectConfigurations::connect_delete_config`](crate::connect_configurations::ConnectConfigurations::connect_delete_config), a `DELETE` to `/v2.1/accounts/{accountId}/connect/{connectId}`.
+    pub fn connect_delete_config(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/connect/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(connect_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectConfigurations::connect_get_user`](crate::connect_configurations::ConnectConfigurations::connect_get_user), a `GET` to `/v2.1/accounts/{accountId}/connect/{connectId}/users`.
+    pub fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IntegratedUserInfoList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(connect_id)
+            ),
+        )
+    }
+}
+
+pub struct ConnectEvents<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ConnectEvents<'a> {
+    /// Mock [`ConnectEvents::connect_publish_put_retry`](crate::connect_events::ConnectEvents::connect_publish_put_retry), a `PUT` to `/v2.1/accounts/{accountId}/connect/envelopes/retry_queue`.
+    pub fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectFailureResults> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/connect/envelopes/retry_queue",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_publish_put_retry_envelope`](crate::connect_events::ConnectEvents::connect_publish_put_retry_envelope), a `PUT` to `/v2.1/accounts/{accountId}/connect/envelopes/{envelopeId}/retry_queue`.
+    pub fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectFailureResults> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/connect/envelopes/{}/retry_queue",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_failures_get_log`](crate::connect_events::ConnectEvents::connect_failures_get_log), a `GET` to `/v2.1/accounts/{accountId}/connect/failures`.
+    pub fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectLogs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect/failures",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_failures_delete_failure_log`](crate::connect_events::ConnectEvents::connect_failures_delete_failure_log), a `DELETE` to `/v2.1/accounts/{accountId}/connect/failures/{failureId}`.
+    pub fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/connect/failures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(failure_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_log_get_log`](crate::connect_events::ConnectEvents::connect_log_get_log), a `GET` to `/v2.1/accounts/{accountId}/connect/logs`.
+    pub fn connect_log_get_log(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectLogs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect/logs",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_log_delete_logs`](crate::connect_events::ConnectEvents::connect_log_delete_logs), a `DELETE` to `/v2.1/accounts/{accountId}/connect/logs`.
+    pub fn connect_log_delete_logs(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/connect/logs",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_log_get`](crate::connect_events::ConnectEvents::connect_log_get), a `GET` to `/v2.1/accounts/{accountId}/connect/logs/{logId}`.
+    pub fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConnectLog> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/connect/logs/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(log_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectEvents::connect_log_delete`](crate::connect_events::ConnectEvents::connect_log_delete), a `DELETE` to `/v2.1/accounts/{accountId}/connect/logs/{logId}`.
+    pub fn connect_log_delete(
+        &self,
+        account_id: &str,
+        log_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/connect/logs/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(log_id)
+            ),
+        )
+    }
+}
+
+pub struct ConnectSecret<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ConnectSecret<'a> {
+    /// Mock [`ConnectSecret::connect_hmac_delete_secret`](crate::connect_secret::ConnectSecret::connect_hmac_delete_secret), a `DELETE` to `/v2.1/accounts/{accountId}/connect/secret/{keyId}`.
+    pub fn connect_hmac_delete_secret(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/connect/secret/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+}
+
+pub struct Contacts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Contacts<'a> {
+    /// Mock [`Contacts::put`](crate::contacts::Contacts::put), a `PUT` to `/v2.1/accounts/{accountId}/contacts`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/contacts",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::post`](crate::contacts::Contacts::post), a `POST` to `/v2.1/accounts/{accountId}/contacts`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/contacts",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::delete`](crate::contacts::Contacts::delete), a `DELETE` to `/v2.1/accounts/{accountId}/contacts`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/contacts",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::get`](crate::contacts::Contacts::get), a `GET` to `/v2.1/accounts/{accountId}/contacts/{contactId}`.
+    pub fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactGetResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/contacts/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(contact_id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::delete_contacts`](crate::contacts::Contacts::delete_contacts), a `DELETE` to `/v2.1/accounts/{accountId}/contacts/{contactId}`.
+    pub fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/contacts/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(contact_id)
+            ),
+        )
+    }
+}
+
+pub struct CustomTabs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CustomTabs<'a> {
+    /// Mock [`CustomTabs::tabs_get_tab_definition`](crate::custom_tabs::CustomTabs::tabs_get_tab_definition), a `GET` to `/v2.1/accounts/{accountId}/tab_definitions`.
+    pub fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabMetadataList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/tab_definitions",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomTabs::tabs_post_tab_definitions`](crate::custom_tabs::CustomTabs::tabs_post_tab_definitions), a `POST` to `/v2.1/accounts/{accountId}/tab_definitions`.
+    pub fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabMetadata> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/tab_definitions",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomTabs::tab_get_custom`](crate::custom_tabs::CustomTabs::tab_get_custom), a `GET` to `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}`.
+    pub fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabMetadata> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/tab_definitions/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_tab_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomTabs::tab_put_custom`](crate::custom_tabs::CustomTabs::tab_put_custom), a `PUT` to `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}`.
+    pub fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TabMetadata> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/tab_definitions/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_tab_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomTabs::tab_delete_custom`](crate::custom_tabs::CustomTabs::tab_delete_custom), a `DELETE` to `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}`.
+    pub fn tab_delete_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/tab_definitions/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_tab_id)
+            ),
+        )
+    }
+}
+
+pub struct DocumentResponsiveHtmlPreview<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DocumentResponsiveHtmlPreview<'a> {
+    /// Mock [`DocumentResponsiveHtmlPreview::responsive_html_post_document_preview`](crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview::responsive_html_post_document_preview), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/responsive_html_preview`.
+    pub fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitions> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/responsive_html_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct ENoteConfigurations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ENoteConfigurations<'a> {
+    /// Mock [`ENoteConfigurations::get`](crate::e_note_configurations::ENoteConfigurations::get), a `GET` to `/v2.1/accounts/{accountId}/settings/enote_configuration`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ENoteConfiguration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/settings/enote_configuration",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ENoteConfigurations::put`](crate::e_note_configurations::ENoteConfigurations::put), a `PUT` to `/v2.1/accounts/{accountId}/settings/enote_configuration`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ENoteConfiguration> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/settings/enote_configuration",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`ENoteConfigurations::delete`](crate::e_note_configurations::ENoteConfigurations::delete), a `DELETE` to `/v2.1/accounts/{accountId}/settings/enote_configuration`.
+    pub fn delete(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/settings/enote_configuration",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeAttachments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeAttachments<'a> {
+    /// Mock [`EnvelopeAttachments::attachments_get`](crate::envelope_attachments::EnvelopeAttachments::attachments_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments`.
+    pub fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeAttachmentsResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/attachments",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeAttachments::attachments_put`](crate::envelope_attachments::EnvelopeAttachments::attachments_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments`.
+    pub fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeAttachmentsResult> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/attachments",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeAttachments::attachments_delete`](crate::envelope_attachments::EnvelopeAttachments::attachments_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments`.
+    pub fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeAttachmentsResult> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/attachments",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeAttachments::attachments_get_attachment`](crate::envelope_attachments::EnvelopeAttachments::attachments_get_attachment), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}`.
+    pub fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(attachment_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeAttachments::attachments_put_attachment`](crate::envelope_attachments::EnvelopeAttachments::attachments_put_attachment), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}`.
+    pub fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeAttachmentsResult> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(attachment_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeConsumerDisclosures<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeConsumerDisclosures<'a> {
+    /// Mock [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient`](crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure`.
+    pub fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConsumerDisclosure> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code`](crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure/{langCode}`.
+    pub fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConsumerDisclosure> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                crate::progenitor_support::encode_path(lang_code)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeCustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeCustomFields<'a> {
+    /// Mock [`EnvelopeCustomFields::custom_fields_get`](crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields`.
+    pub fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomFieldsEnvelope> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeCustomFields::custom_fields_put`](crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields`.
+    pub fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeCustomFields> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeCustomFields::custom_fields_post`](crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_post), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields`.
+    pub fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeCustomFields> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeCustomFields::custom_fields_delete`](crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields`.
+    pub fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeCustomFields> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeDocumentFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeDocumentFields<'a> {
+    /// Mock [`EnvelopeDocumentFields::document_fields_get`](crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields`.
+    pub fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentFields> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentFields::document_fields_put`](crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields`.
+    pub fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentFields> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentFields::document_fields_post`](crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_post), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields`.
+    pub fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentFields> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentFields::document_fields_delete`](crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields`.
+    pub fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentFields> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeDocumentHtmlDefinitions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeDocumentHtmlDefinitions<'a> {
+    /// Mock [`EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition`](crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/html_definitions`.
+    pub fn responsive_html_get_envelope_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitionOriginals> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/html_definitions",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeDocumentTabs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeDocumentTabs<'a> {
+    /// Mock [`EnvelopeDocumentTabs::tabs_get_page`](crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_page), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/tabs`.
+    pub fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentTabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentTabs::tabs_get_document`](crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_document), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs`.
+    pub fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentTabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentTabs::tabs_put_document`](crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_put_document), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs`.
+    pub fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentTabs::tabs_post_document`](crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_post_document), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs`.
+    pub fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentTabs::tabs_delete_document`](crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_delete_document), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs`.
+    pub fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeDocumentVisibility<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeDocumentVisibility<'a> {
+    /// Mock [`EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility`](crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility`.
+    pub fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility`](crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility`.
+    pub fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeDocuments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeDocuments<'a> {
+    /// Mock [`EnvelopeDocuments::documents_get`](crate::envelope_documents::EnvelopeDocuments::documents_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents`.
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocuments::documents_put`](crate::envelope_documents::EnvelopeDocuments::documents_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents`.
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocuments::documents_delete`](crate::envelope_documents::EnvelopeDocuments::documents_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents`.
+    pub fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocuments::documents_get_document`](crate::envelope_documents::EnvelopeDocuments::documents_get_document), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}`.
+    pub fn documents_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeDocuments::documents_put_document`](crate::envelope_documents::EnvelopeDocuments::documents_put_document), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}`.
+    pub fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocument> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeEmailSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeEmailSettings<'a> {
+    /// Mock [`EnvelopeEmailSettings::email_settings_get`](crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings`.
+    pub fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/email_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeEmailSettings::email_settings_put`](crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings`.
+    pub fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailSettings> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/email_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeEmailSettings::email_settings_post`](crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_post), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings`.
+    pub fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailSettings> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/email_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeEmailSettings::email_settings_delete`](crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings`.
+    pub fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailSettings> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/email_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeFormData<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeFormData<'a> {
+    /// Mock [`EnvelopeFormData::form_data_get`](crate::envelope_form_data::EnvelopeFormData::form_data_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/form_data`.
+    pub fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeFormDataType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/form_data",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeHtmlDefinitions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeHtmlDefinitions<'a> {
+    /// Mock [`EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition`](crate::envelope_html_definitions::EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/html_definitions`.
+    pub fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitionOriginals> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/html_definitions",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeLocks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeLocks<'a> {
+    /// Mock [`EnvelopeLocks::lock_get_envelope`](crate::envelope_locks::EnvelopeLocks::lock_get_envelope), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock`.
+    pub fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeLocks> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeLocks::lock_put_envelope`](crate::envelope_locks::EnvelopeLocks::lock_put_envelope), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock`.
+    pub fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeLocks> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeLocks::lock_post_envelope`](crate::envelope_locks::EnvelopeLocks::lock_post_envelope), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock`.
+    pub fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeLocks> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeLocks::lock_delete_envelope`](crate::envelope_locks::EnvelopeLocks::lock_delete_envelope), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock`.
+    pub fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeLocks> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeRecipientTabs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeRecipientTabs<'a> {
+    /// Mock [`EnvelopeRecipientTabs::recipients_get_recipient_tab`](crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_get_recipient_tab), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipientTabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipientTabs::recipients_put_recipient_tabs`](crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_put_recipient_tabs), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipientTabs> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipientTabs::recipients_post_recipient_tabs`](crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_post_recipient_tabs), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipientTabs> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipientTabs::recipients_delete_recipient_tabs`](crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_delete_recipient_tabs), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipientTabs> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeRecipients<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeRecipients<'a> {
+    /// Mock [`EnvelopeRecipients::recipients_get`](crate::envelope_recipients::EnvelopeRecipients::recipients_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients`.
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipients> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_put`](crate::envelope_recipients::EnvelopeRecipients::recipients_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients`.
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecipientsUpdateSummary> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_post`](crate::envelope_recipients::EnvelopeRecipients::recipients_post), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients`.
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipients> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_delete`](crate::envelope_recipients::EnvelopeRecipients::recipients_delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients`.
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipients> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_put_document_visibility`](crate::envelope_recipients::EnvelopeRecipients::recipients_put_document_visibility), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/document_visibility`.
+    pub fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_delete_recipient`](crate::envelope_recipients::EnvelopeRecipients::recipients_delete_recipient), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}`.
+    pub fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeRecipients> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token`](crate::envelope_recipients::EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/identity_proof_token`.
+    pub fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProofServiceResourceToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/identity_proof_token",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::views_post_recipient_manual_review_view`](crate::envelope_recipients::EnvelopeRecipients::views_post_recipient_manual_review_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/views/identity_manual_review`.
+    pub fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/views/identity_manual_review",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeRecipients::views_post_preview`](crate::envelope_recipients::EnvelopeRecipients::views_post_preview), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient_preview`.
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/recipient_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeTemplates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeTemplates<'a> {
+    /// Mock [`EnvelopeTemplates::templates_get_document`](crate::envelope_templates::EnvelopeTemplates::templates_get_document), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates`.
+    pub fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTemplates::templates_post_document`](crate::envelope_templates::EnvelopeTemplates::templates_post_document), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates`.
+    pub fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentTemplateList> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTemplates::templates_delete_document`](crate::envelope_templates::EnvelopeTemplates::templates_delete_document), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}`.
+    pub fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTemplates::templates_get_envelope`](crate::envelope_templates::EnvelopeTemplates::templates_get_envelope), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates`.
+    pub fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTemplates::templates_post_envelope`](crate::envelope_templates::EnvelopeTemplates::templates_post_envelope), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates`.
+    pub fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentTemplateList> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeTransferRules<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeTransferRules<'a> {
+    /// Mock [`EnvelopeTransferRules::get`](crate::envelope_transfer_rules::EnvelopeTransferRules::get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/transfer_rules`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTransferRuleInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTransferRules::put`](crate::envelope_transfer_rules::EnvelopeTransferRules::put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/transfer_rules`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTransferRuleInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTransferRules::post`](crate::envelope_transfer_rules::EnvelopeTransferRules::post), a `POST` to `/v2.1/accounts/{accountId}/envelopes/transfer_rules`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTransferRuleInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTransferRules::put_rule`](crate::envelope_transfer_rules::EnvelopeTransferRules::put_rule), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}`.
+    pub fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTransferRule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_transfer_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeTransferRules::delete`](crate::envelope_transfer_rules::EnvelopeTransferRules::delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_transfer_rule_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeViews<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeViews<'a> {
+    /// Mock [`EnvelopeViews::views_post_envelope_correct_view`](crate::envelope_views::EnvelopeViews::views_post_envelope_correct_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct`.
+    pub fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeViews> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/correct",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_delete_envelope_correct_view`](crate::envelope_views::EnvelopeViews::views_delete_envelope_correct_view), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct`.
+    pub fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/correct",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_post_envelope_edit_view`](crate::envelope_views::EnvelopeViews::views_post_envelope_edit_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/edit`.
+    pub fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeViews> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/edit",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_post_envelope_recipient_view`](crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient`.
+    pub fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeViews> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/recipient",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_post_envelope_sender_view`](crate::envelope_views::EnvelopeViews::views_post_envelope_sender_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/sender`.
+    pub fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeViews> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/sender",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_post_envelope_recipient_shared_view`](crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_shared_view), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/shared`.
+    pub fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/views/shared",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeViews::views_post_account_console_view`](crate::envelope_views::EnvelopeViews::views_post_account_console_view), a `POST` to `/v2.1/accounts/{accountId}/views/console`.
+    pub fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeViews> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/views/console",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct EnvelopeWorkflowDefinition<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnvelopeWorkflowDefinition<'a> {
+    /// Mock [`EnvelopeWorkflowDefinition::get`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow`.
+    pub fn get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workflow> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeWorkflowDefinition::put`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workflow> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeWorkflowDefinition::delete`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeWorkflowDefinition::get_template`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/workflow`.
+    pub fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workflow> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeWorkflowDefinition::put_template`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/workflow`.
+    pub fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workflow> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnvelopeWorkflowDefinition::delete_template`](crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/workflow`.
+    pub fn delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/workflow",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct Envelopes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Envelopes<'a> {
+    /// Mock [`Envelopes::get`](crate::envelopes::Envelopes::get), a `GET` to `/v2.1/accounts/{accountId}/envelopes`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopesInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::post`](crate::envelopes::Envelopes::post), a `POST` to `/v2.1/accounts/{accountId}/envelopes`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeSummary> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::put_status`](crate::envelopes::Envelopes::put_status), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/status`.
+    pub fn put_status(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopesInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/status",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::get_envelopes`](crate::envelopes::Envelopes::get_envelopes), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}`.
+    pub fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Envelope> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::put`](crate::envelopes::Envelopes::put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeUpdateSummary> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::audit_events_get`](crate::envelopes::Envelopes::audit_events_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/audit_events`.
+    pub fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeAuditEventResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/audit_events",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::pages_get_page_image`](crate::envelopes::Envelopes::pages_get_page_image), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages`.
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageImages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::pages_delete_page`](crate::envelopes::Envelopes::pages_delete_page), a `DELETE` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}`.
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::pages_get_page_image_envelopes`](crate::envelopes::Envelopes::pages_get_page_image_envelopes), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image`.
+    pub fn pages_get_page_image_envelopes(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::pages_put_page_image`](crate::envelopes::Envelopes::pages_put_page_image), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image`.
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::notification_get`](crate::envelopes::Envelopes::notification_get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification`.
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Notification> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/notification",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::notification_put`](crate::envelopes::Envelopes::notification_put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification`.
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Notification> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/notification",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::recipients_get_recipient_initials_image`](crate::envelopes::Envelopes::recipients_get_recipient_initials_image), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image`.
+    pub fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::recipients_put_recipient_initials_image`](crate::envelopes::Envelopes::recipients_put_recipient_initials_image), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image`.
+    pub fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::recipients_get_recipient_signature`](crate::envelopes::Envelopes::recipients_get_recipient_signature), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature`.
+    pub fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignature> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::recipients_get_recipient_signature_image`](crate::envelopes::Envelopes::recipients_get_recipient_signature_image), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image`.
+    pub fn recipients_get_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`Envelopes::recipients_put_recipient_signature_image`](crate::envelopes::Envelopes::recipients_put_recipient_signature_image), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image`.
+    pub fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct FavoriteTemplates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FavoriteTemplates<'a> {
+    /// Mock [`FavoriteTemplates::get`](crate::favorite_templates::FavoriteTemplates::get), a `GET` to `/v2.1/accounts/{accountId}/favorite_templates`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FavoriteTemplatesInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/favorite_templates",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`FavoriteTemplates::put_template`](crate::favorite_templates::FavoriteTemplates::put_template), a `PUT` to `/v2.1/accounts/{accountId}/favorite_templates`.
+    pub fn put_template(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FavoriteTemplatesInfo> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/favorite_templates",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`FavoriteTemplates::un_template`](crate::favorite_templates::FavoriteTemplates::un_template), a `DELETE` to `/v2.1/accounts/{accountId}/favorite_templates`.
+    pub fn un_template(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FavoriteTemplatesInfo> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/favorite_templates",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Folders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Folders<'a> {
+    /// Mock [`Folders::get`](crate::folders::Folders::get), a `GET` to `/v2.1/accounts/{accountId}/folders`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FoldersResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/folders",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::get_items`](crate::folders::Folders::get_items), a `GET` to `/v2.1/accounts/{accountId}/folders/{folderId}`.
+    pub fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FolderItemsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::put`](crate::folders::Folders::put), a `PUT` to `/v2.1/accounts/{accountId}/folders/{folderId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FoldersResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::search_get_contents`](crate::folders::Folders::search_get_contents), a `GET` to `/v2.1/accounts/{accountId}/search_folders/{searchFolderId}`.
+    pub fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FolderItemResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/search_folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(search_folder_id)
+            ),
+        )
+    }
+}
+
+pub struct GroupBrands<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> GroupBrands<'a> {
+    /// Mock [`GroupBrands::brands_get_group`](crate::group_brands::GroupBrands::brands_get_group), a `GET` to `/v2.1/accounts/{accountId}/groups/{groupId}/brands`.
+    pub fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupBrands> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/brands",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`GroupBrands::brands_put_group`](crate::group_brands::GroupBrands::brands_put_group), a `PUT` to `/v2.1/accounts/{accountId}/groups/{groupId}/brands`.
+    pub fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupBrands> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/brands",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`GroupBrands::brands_delete_group`](crate::group_brands::GroupBrands::brands_delete_group), a `DELETE` to `/v2.1/accounts/{accountId}/groups/{groupId}/brands`.
+    pub fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupBrands> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/brands",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+}
+
+pub struct GroupUsers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> GroupUsers<'a> {
+    /// Mock [`GroupUsers::groups_get`](crate::group_users::GroupUsers::groups_get), a `GET` to `/v2.1/accounts/{accountId}/groups/{groupId}/users`.
+    pub fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`GroupUsers::groups_put`](crate::group_users::GroupUsers::groups_put), a `PUT` to `/v2.1/accounts/{accountId}/groups/{groupId}/users`.
+    pub fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`GroupUsers::groups_delete`](crate::group_users::GroupUsers::groups_delete), a `DELETE` to `/v2.1/accounts/{accountId}/groups/{groupId}/users`.
+    pub fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+}
+
+pub struct Groups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Groups<'a> {
+    /// Mock [`Groups::get`](crate::groups::Groups::get), a `GET` to `/v2.1/accounts/{accountId}/groups`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::put`](crate::groups::Groups::put), a `PUT` to `/v2.1/accounts/{accountId}/groups`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::post`](crate::groups::Groups::post), a `POST` to `/v2.1/accounts/{accountId}/groups`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::delete`](crate::groups::Groups::delete), a `DELETE` to `/v2.1/accounts/{accountId}/groups`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct IdentityVerifications<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IdentityVerifications<'a> {
+    /// Mock [`IdentityVerifications::account_get`](crate::identity_verifications::IdentityVerifications::account_get), a `GET` to `/v2.1/accounts/{accountId}/identity_verification`.
+    pub fn account_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountIdentityVerificationResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/identity_verification",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Invoices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Invoices<'a> {
+    /// Mock [`Invoices::billing_get`](crate::invoices::Invoices::billing_get), a `GET` to `/v2.1/accounts/{accountId}/billing_invoices`.
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingInvoicesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_invoices",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::billing_get_invoices`](crate::invoices::Invoices::billing_get_invoices), a `GET` to `/v2.1/accounts/{accountId}/billing_invoices/{invoiceId}`.
+    pub fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingInvoice> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_invoices/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(invoice_id)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::billing_get_past_due`](crate::invoices::Invoices::billing_get_past_due), a `GET` to `/v2.1/accounts/{accountId}/billing_invoices_past_due`.
+    pub fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingInvoicesSummary> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_invoices_past_due",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Notary<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Notary<'a> {
+    /// Mock [`Notary::get`](crate::notary::Notary::get), a `GET` to `/v2.1/current_user/notary`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/current_user/notary".to_string(),
+        )
+    }
+
+    /// Mock [`Notary::put`](crate::notary::Notary::put), a `PUT` to `/v2.1/current_user/notary`.
+    pub fn put(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            "/v2.1/current_user/notary".to_string(),
+        )
+    }
+
+    /// Mock [`Notary::post`](crate::notary::Notary::post), a `POST` to `/v2.1/current_user/notary`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v2.1/current_user/notary".to_string(),
+        )
+    }
+}
+
+pub struct NotaryJournals<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> NotaryJournals<'a> {
+    /// Mock [`NotaryJournals::get`](crate::notary_journals::NotaryJournals::get), a `GET` to `/v2.1/current_user/notary/journals`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryJournalList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/current_user/notary/journals".to_string(),
+        )
+    }
+}
+
+pub struct NotaryJurisdiction<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> NotaryJurisdiction<'a> {
+    /// Mock [`NotaryJurisdiction::s_get`](crate::notary_jurisdiction::NotaryJurisdiction::s_get), a `GET` to `/v2.1/current_user/notary/jurisdictions`.
+    pub fn s_get(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryJurisdictionList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/current_user/notary/jurisdictions".to_string(),
+        )
+    }
+
+    /// Mock [`NotaryJurisdiction::s_post`](crate::notary_jurisdiction::NotaryJurisdiction::s_post), a `POST` to `/v2.1/current_user/notary/jurisdictions`.
+    pub fn s_post(&self) -> crate::mock::MockOperation<'a, crate::types::NotaryJurisdictionData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v2.1/current_user/notary/jurisdictions".to_string(),
+        )
+    }
+
+    /// Mock [`NotaryJurisdiction::s_get_jurisdiction`](crate::notary_jurisdiction::NotaryJurisdiction::s_get_jurisdiction), a `GET` to `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}`.
+    pub fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NotaryJurisdictionData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/current_user/notary/jurisdictions/{}",
+                crate::progenitor_support::encode_path(jurisdiction_id)
+            ),
+        )
+    }
+
+    /// Mock [`NotaryJurisdiction::s_put_jurisdiction`](crate::notary_jurisdiction::NotaryJurisdiction::s_put_jurisdiction), a `PUT` to `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}`.
+    pub fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NotaryJurisdictionData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/current_user/notary/jurisdictions/{}",
+                crate::progenitor_support::encode_path(jurisdiction_id)
+            ),
+        )
+    }
+
+    /// Mock [`NotaryJurisdiction::s_delete_jurisdiction`](crate::notary_jurisdiction::NotaryJurisdiction::s_delete_jurisdiction), a `DELETE` to `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}`.
+    pub fn s_delete_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/current_user/notary/jurisdictions/{}",
+                crate::progenitor_support::encode_path(jurisdiction_id)
+            ),
+        )
+    }
+}
+
+pub struct PaymentGatewayAccounts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PaymentGatewayAccounts<'a> {
+    /// Mock [`PaymentGatewayAccounts::get_all`](crate::payment_gateway_accounts::PaymentGatewayAccounts::get_all), a `GET` to `/v2.1/accounts/{accountId}/payment_gateway_accounts`.
+    pub fn get_all(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentGatewayAccountsInfo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/payment_gateway_accounts",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Payments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Payments<'a> {
+    /// Mock [`Payments::billing_get_list`](crate::payments::Payments::billing_get_list), a `GET` to `/v2.1/accounts/{accountId}/billing_payments`.
+    pub fn billing_get_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPaymentsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_payments",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Payments::billing_post`](crate::payments::Payments::billing_post), a `POST` to `/v2.1/accounts/{accountId}/billing_payments`.
+    pub fn billing_post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPaymentResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/billing_payments",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Payments::billing_get`](crate::payments::Payments::billing_get), a `GET` to `/v2.1/accounts/{accountId}/billing_payments/{paymentId}`.
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BillingPaymentItem> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/billing_payments/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(payment_id)
+            ),
+        )
+    }
+}
+
+pub struct PowerFormData<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PowerFormData<'a> {
+    /// Mock [`PowerFormData::power_forms_get_form_data`](crate::power_form_data::PowerFormData::power_forms_get_form_data), a `GET` to `/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data`.
+    pub fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerFormsFormDataResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/powerforms/{}/form_data",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id)
+            ),
+        )
+    }
+}
+
+pub struct PowerForms<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PowerForms<'a> {
+    /// Mock [`PowerForms::get_list`](crate::power_forms::PowerForms::get_list), a `GET` to `/v2.1/accounts/{accountId}/powerforms`.
+    pub fn get_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerFormsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/powerforms",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::post_form`](crate::power_forms::PowerForms::post_form), a `POST` to `/v2.1/accounts/{accountId}/powerforms`.
+    pub fn post_form(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerForm> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/powerforms",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::delete_list`](crate::power_forms::PowerForms::delete_list), a `DELETE` to `/v2.1/accounts/{accountId}/powerforms`.
+    pub fn delete_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerFormsResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/powerforms",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::get_senders`](crate::power_forms::PowerForms::get_senders), a `GET` to `/v2.1/accounts/{accountId}/powerforms/senders`.
+    pub fn get_senders(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerFormSendersResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/powerforms/senders",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::get_form`](crate::power_forms::PowerForms::get_form), a `GET` to `/v2.1/accounts/{accountId}/powerforms/{powerFormId}`.
+    pub fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerForm> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/powerforms/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::put_form`](crate::power_forms::PowerForms::put_form), a `PUT` to `/v2.1/accounts/{accountId}/powerforms/{powerFormId}`.
+    pub fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PowerForm> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/powerforms/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id)
+            ),
+        )
+    }
+
+    /// Mock [`PowerForms::delete_form`](crate::power_forms::PowerForms::delete_form), a `DELETE` to `/v2.1/accounts/{accountId}/powerforms/{powerFormId}`.
+    pub fn delete_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/powerforms/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id)
+            ),
+        )
+    }
+}
+
+pub struct Reports<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reports<'a> {
+    /// Mock [`Reports::product_get_list`](crate::reports::Reports::product_get_list), a `GET` to `/v2.1/accounts/{accountId}/reports`.
+    pub fn product_get_list(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/reports",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_post_create`](crate::reports::Reports::product_post_create), a `POST` to `/v2.1/accounts/{accountId}/reports`.
+    pub fn product_post_create(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductSaveResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/reports",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_put_run_results`](crate::reports::Reports::product_put_run_results), a `PUT` to `/v2.1/accounts/{accountId}/reports/report_results`.
+    pub fn product_put_run_results(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductRunResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/reports/report_results",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_put_results_csv`](crate::reports::Reports::product_put_results_csv), a `PUT` to `/v2.1/accounts/{accountId}/reports/report_results_csv`.
+    pub fn product_put_results_csv(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/reports/report_results_csv",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_get`](crate::reports::Reports::product_get), a `GET` to `/v2.1/accounts/{accountId}/reports/{id}`.
+    pub fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductGet> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/reports/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_put_save`](crate::reports::Reports::product_put_save), a `PUT` to `/v2.1/accounts/{accountId}/reports/{id}`.
+    pub fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductSaveResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/reports/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::product_delete`](crate::reports::Reports::product_delete), a `DELETE` to `/v2.1/accounts/{accountId}/reports/{id}`.
+    pub fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportInProductSaveResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/reports/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct RequestLogs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RequestLogs<'a> {
+    /// Mock [`RequestLogs::api_get_log`](crate::request_logs::RequestLogs::api_get_log), a `GET` to `/v2.1/diagnostics/request_logs`.
+    pub fn api_get_log(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApiRequestLogsResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/diagnostics/request_logs".to_string(),
+        )
+    }
+
+    /// Mock [`RequestLogs::api_delete_logs`](crate::request_logs::RequestLogs::api_delete_logs), a `DELETE` to `/v2.1/diagnostics/request_logs`.
+    pub fn api_delete_logs(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/v2.1/diagnostics/request_logs".to_string(),
+        )
+    }
+
+    /// Mock [`RequestLogs::api_get`](crate::request_logs::RequestLogs::api_get), a `GET` to `/v2.1/diagnostics/request_logs/{requestLogId}`.
+    pub fn api_get(&self, request_log_id: &str) -> crate::mock::MockOperation<'a, bytes::Bytes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/diagnostics/request_logs/{}",
+                crate::progenitor_support::encode_path(request_log_id)
+            ),
+        )
+    }
+
+    /// Mock [`RequestLogs::api_get_setting`](crate::request_logs::RequestLogs::api_get_setting), a `GET` to `/v2.1/diagnostics/settings`.
+    pub fn api_get_setting(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DiagnosticsSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v2.1/diagnostics/settings".to_string(),
+        )
+    }
+
+    /// Mock [`RequestLogs::api_put_settings`](crate::request_logs::RequestLogs::api_put_settings), a `PUT` to `/v2.1/diagnostics/settings`.
+    pub fn api_put_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DiagnosticsSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            "/v2.1/diagnostics/settings".to_string(),
+        )
+    }
+}
+
+pub struct Resources<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Resources<'a> {
+    /// Mock [`Resources::service_information_get`](crate::resources::Resources::service_information_get), a `GET` to `/v2.1`.
+    pub fn service_information_get(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ResourceInformation> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v2.1".to_string())
+    }
+}
+
+pub struct ResponsiveHtmlPreview<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ResponsiveHtmlPreview<'a> {
+    /// Mock [`ResponsiveHtmlPreview::responsive_html_post_preview`](crate::responsive_html_preview::ResponsiveHtmlPreview::responsive_html_post_preview), a `POST` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/responsive_html_preview`.
+    pub fn responsive_html_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitions> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/responsive_html_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct Services<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Services<'a> {
+    /// Mock [`Services::information_get`](crate::services::Services::information_get), a `GET` to `/service_information`.
+    pub fn information_get(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ServiceInformation> {
+        self.mock
+            .operation(reqwest::Method::GET, "/service_information".to_string())
+    }
+}
+
+pub struct SigningGroupUsers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SigningGroupUsers<'a> {
+    /// Mock [`SigningGroupUsers::signing_groups_get_group_user`](crate::signing_group_users::SigningGroupUsers::signing_groups_get_group_user), a `GET` to `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users`.
+    pub fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupUsersData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signing_groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signing_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroupUsers::signing_groups_put_group_users`](crate::signing_group_users::SigningGroupUsers::signing_groups_put_group_users), a `PUT` to `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users`.
+    pub fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupUsersData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signing_groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signing_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroupUsers::signing_groups_delete_group_users`](crate::signing_group_users::SigningGroupUsers::signing_groups_delete_group_users), a `DELETE` to `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users`.
+    pub fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupUsersData> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/signing_groups/{}/users",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signing_group_id)
+            ),
+        )
+    }
+}
+
+pub struct SigningGroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SigningGroups<'a> {
+    /// Mock [`SigningGroups::get`](crate::signing_groups::SigningGroups::get), a `GET` to `/v2.1/accounts/{accountId}/signing_groups`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signing_groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroups::put`](crate::signing_groups::SigningGroups::put), a `PUT` to `/v2.1/accounts/{accountId}/signing_groups`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signing_groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroups::post`](crate::signing_groups::SigningGroups::post), a `POST` to `/v2.1/accounts/{accountId}/signing_groups`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/signing_groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroups::delete`](crate::signing_groups::SigningGroups::delete), a `DELETE` to `/v2.1/accounts/{accountId}/signing_groups`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroupInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/signing_groups",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroups::get_group`](crate::signing_groups::SigningGroups::get_group), a `GET` to `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}`.
+    pub fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroup> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/signing_groups/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signing_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`SigningGroups::put_group`](crate::signing_groups::SigningGroups::put_group), a `PUT` to `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}`.
+    pub fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SigningGroup> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/signing_groups/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signing_group_id)
+            ),
+        )
+    }
+}
+
+pub struct TabsBlob<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TabsBlob<'a> {
+    /// Mock [`TabsBlob::get`](crate::tabs_blob::TabsBlob::get), a `GET` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob`.
+    pub fn get(&self, account_id: &str, envelope_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+
+    /// Mock [`TabsBlob::put`](crate::tabs_blob::TabsBlob::put), a `PUT` to `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob`.
+    pub fn put(&self, account_id: &str, envelope_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateBulkRecipients<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateBulkRecipients<'a> {
+    /// Mock [`TemplateBulkRecipients::recipients_get_template_bulk`](crate::template_bulk_recipients::TemplateBulkRecipients::recipients_get_template_bulk), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients`.
+    pub fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkRecipientsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateBulkRecipients::recipients_put_template_bulk`](crate::template_bulk_recipients::TemplateBulkRecipients::recipients_put_template_bulk), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients`.
+    pub fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkRecipientsSummaryResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateBulkRecipients::recipients_delete_template_bulk_file`](crate::template_bulk_recipients::TemplateBulkRecipients::recipients_delete_template_bulk_file), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients`.
+    pub fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BulkRecipientsUpdateResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateCustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateCustomFields<'a> {
+    /// Mock [`TemplateCustomFields::custom_fields_get_template`](crate::template_custom_fields::TemplateCustomFields::custom_fields_get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields`.
+    pub fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomFields> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateCustomFields::custom_fields_put_template`](crate::template_custom_fields::TemplateCustomFields::custom_fields_put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields`.
+    pub fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomFields> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateCustomFields::custom_fields_post_template`](crate::template_custom_fields::TemplateCustomFields::custom_fields_post_template), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields`.
+    pub fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomFields> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateCustomFields::custom_fields_delete_template`](crate::template_custom_fields::TemplateCustomFields::custom_fields_delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields`.
+    pub fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomFields> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/custom_fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocumentFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocumentFields<'a> {
+    /// Mock [`TemplateDocumentFields::document_fields_get_template`](crate::template_document_fields::TemplateDocumentFields::document_fields_get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields`.
+    pub fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentFieldsInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentFields::document_fields_put_template`](crate::template_document_fields::TemplateDocumentFields::document_fields_put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields`.
+    pub fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentFieldsInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentFields::document_fields_post_template`](crate::template_document_fields::TemplateDocumentFields::document_fields_post_template), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields`.
+    pub fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentFieldsInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentFields::document_fields_delete_template`](crate::template_document_fields::TemplateDocumentFields::document_fields_delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields`.
+    pub fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentFieldsInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocumentHtmlDefinitions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocumentHtmlDefinitions<'a> {
+    /// Mock [`TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition`](crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/html_definitions`.
+    pub fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitionOriginals> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/html_definitions",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocumentResponsiveHtmlPreview<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocumentResponsiveHtmlPreview<'a> {
+    /// Mock [`TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview`](crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/responsive_html_preview`.
+    pub fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitions> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/responsive_html_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocumentTabs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocumentTabs<'a> {
+    /// Mock [`TemplateDocumentTabs::tabs_get_template_page`](crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_page), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/tabs`.
+    pub fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentTabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentTabs::tabs_get_template_document`](crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_document), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs`.
+    pub fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentTabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentTabs::tabs_put_template_document`](crate::template_document_tabs::TemplateDocumentTabs::tabs_put_template_document), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs`.
+    pub fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentTabs::tabs_post_template_document`](crate::template_document_tabs::TemplateDocumentTabs::tabs_post_template_document), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs`.
+    pub fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentTabs::tabs_delete_template_document`](crate::template_document_tabs::TemplateDocumentTabs::tabs_delete_template_document), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs`.
+    pub fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocumentVisibility<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocumentVisibility<'a> {
+    /// Mock [`TemplateDocumentVisibility::recipients_put`](crate::template_document_visibility::TemplateDocumentVisibility::recipients_put), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/document_visibility`.
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility`](crate::template_document_visibility::TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility`.
+    pub fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility`](crate::template_document_visibility::TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility`.
+    pub fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentVisibilityList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateDocuments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateDocuments<'a> {
+    /// Mock [`TemplateDocuments::documents_get_template`](crate::template_documents::TemplateDocuments::documents_get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents`.
+    pub fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocuments::documents_put_template`](crate::template_documents::TemplateDocuments::documents_put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents`.
+    pub fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocuments::documents_delete_template`](crate::template_documents::TemplateDocuments::documents_delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents`.
+    pub fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDocumentsResult> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocuments::documents_get`](crate::template_documents::TemplateDocuments::documents_get), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}`.
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateDocuments::documents_put`](crate::template_documents::TemplateDocuments::documents_put), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}`.
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeDocument> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateHtmlDefinitions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateHtmlDefinitions<'a> {
+    /// Mock [`TemplateHtmlDefinitions::responsive_html_get_template_definition`](crate::template_html_definitions::TemplateHtmlDefinitions::responsive_html_get_template_definition), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/html_definitions`.
+    pub fn responsive_html_get_template_definition(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitionOriginals> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/html_definitions",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateLocks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateLocks<'a> {
+    /// Mock [`TemplateLocks::lock_get_template`](crate::template_locks::TemplateLocks::lock_get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/lock`.
+    pub fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LockInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateLocks::lock_put_template`](crate::template_locks::TemplateLocks::lock_put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/lock`.
+    pub fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LockInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateLocks::lock_post_template`](crate::template_locks::TemplateLocks::lock_post_template), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/lock`.
+    pub fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LockInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateLocks::lock_delete_template`](crate::template_locks::TemplateLocks::lock_delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/lock`.
+    pub fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LockInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/lock",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateRecipientTabs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateRecipientTabs<'a> {
+    /// Mock [`TemplateRecipientTabs::recipients_get`](crate::template_recipient_tabs::TemplateRecipientTabs::recipients_get), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipientTabs::recipients_put`](crate::template_recipient_tabs::TemplateRecipientTabs::recipients_put), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipientTabs::recipients_post`](crate::template_recipient_tabs::TemplateRecipientTabs::recipients_post), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipientTabs::recipients_delete`](crate::template_recipient_tabs::TemplateRecipientTabs::recipients_delete), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs`.
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tabs> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateRecipients<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateRecipients<'a> {
+    /// Mock [`TemplateRecipients::recipients_get_template`](crate::template_recipients::TemplateRecipients::recipients_get_template), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients`.
+    pub fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Recipients> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipients::recipients_put_template`](crate::template_recipients::TemplateRecipients::recipients_put_template), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients`.
+    pub fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecipientsUpdateSummary> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipients::recipients_post_template`](crate::template_recipients::TemplateRecipients::recipients_post_template), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients`.
+    pub fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Recipients> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipients::recipients_delete_template`](crate::template_recipients::TemplateRecipients::recipients_delete_template), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients`.
+    pub fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Recipients> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipients::recipients_delete`](crate::template_recipients::TemplateRecipients::recipients_delete), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}`.
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Recipients> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateRecipients::views_post_preview`](crate::template_recipients::TemplateRecipients::views_post_preview), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/views/recipient_preview`.
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/views/recipient_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateResponsiveHtmlPreview<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateResponsiveHtmlPreview<'a> {
+    /// Mock [`TemplateResponsiveHtmlPreview::responsive_html_post_template_preview`](crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview::responsive_html_post_template_preview), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/responsive_html_preview`.
+    pub fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DocumentHtmlDefinitions> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/responsive_html_preview",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TemplateViews<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateViews<'a> {
+    /// Mock [`TemplateViews::views_post_template_edit_view`](crate::template_views::TemplateViews::views_post_template_edit_view), a `POST` to `/v2.1/accounts/{accountId}/templates/{templateId}/views/edit`.
+    pub fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/views/edit",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct Templates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Templates<'a> {
+    /// Mock [`Templates::get`](crate::templates::Templates::get), a `GET` to `/v2.1/accounts/{accountId}/templates`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTemplateResults> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::post`](crate::templates::Templates::post), a `POST` to `/v2.1/accounts/{accountId}/templates`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateSummary> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/templates",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::get_templates`](crate::templates::Templates::get_templates), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}`.
+    pub fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvelopeTemplate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::put`](crate::templates::Templates::put), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateUpdateSummary> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::pages_get_page_image`](crate::templates::Templates::pages_get_page_image), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages`.
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageImages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::pages_delete_page`](crate::templates::Templates::pages_delete_page), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}`.
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::pages_get_page_image_templates`](crate::templates::Templates::pages_get_page_image_templates), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image`.
+    pub fn pages_get_page_image_templates(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::pages_put_page_image`](crate::templates::Templates::pages_put_page_image), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image`.
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::notification_get`](crate::templates::Templates::notification_get), a `GET` to `/v2.1/accounts/{accountId}/templates/{templateId}/notification`.
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Notification> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/notification",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::notification_put`](crate::templates::Templates::notification_put), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/notification`.
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Notification> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/notification",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::put_part`](crate::templates::Templates::put_part), a `PUT` to `/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}`.
+    pub fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(template_part)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::delete_part`](crate::templates::Templates::delete_part), a `DELETE` to `/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}`.
+    pub fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/templates/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(template_part)
+            ),
+        )
+    }
+}
+
+pub struct UserCustomSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserCustomSettings<'a> {
+    /// Mock [`UserCustomSettings::get`](crate::user_custom_settings::UserCustomSettings::get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/custom_settings`.
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/custom_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserCustomSettings::put`](crate::user_custom_settings::UserCustomSettings::put), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/custom_settings`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/custom_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserCustomSettings::delete`](crate::user_custom_settings::UserCustomSettings::delete), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/custom_settings`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/custom_settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct UserProfiles<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserProfiles<'a> {
+    /// Mock [`UserProfiles::get`](crate::user_profiles::UserProfiles::get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/profile`.
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserProfile> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/profile",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserProfiles::put`](crate::user_profiles::UserProfiles::put), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/profile`.
+    pub fn put(&self, account_id: &str, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/profile",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct UserSignatures<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserSignatures<'a> {
+    /// Mock [`UserSignatures::get`](crate::user_signatures::UserSignatures::get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/signatures`.
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::put_signature`](crate::user_signatures::UserSignatures::put_signature), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/signatures`.
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::post`](crate::user_signatures::UserSignatures::post), a `POST` to `/v2.1/accounts/{accountId}/users/{userId}/signatures`.
+    pub fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignaturesInformation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::get_signature`](crate::user_signatures::UserSignatures::get_signature), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}`.
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignature> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::put_signature_user_signatures`](crate::user_signatures::UserSignatures::put_signature_user_signatures), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}`.
+    pub fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignature> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::delete_signature`](crate::user_signatures::UserSignatures::delete_signature), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}`.
+    pub fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::get_signature_image`](crate::user_signatures::UserSignatures::get_signature_image), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}`.
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::put_signature_image`](crate::user_signatures::UserSignatures::put_signature_image), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}`.
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignature> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+
+    /// Mock [`UserSignatures::delete_signature_image`](crate::user_signatures::UserSignatures::delete_signature_image), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}`.
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSignature> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type)
+            ),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::get`](crate::users::Users::get), a `GET` to `/v2.1/accounts/{accountId}/users`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserInformationList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::put`](crate::users::Users::put), a `PUT` to `/v2.1/accounts/{accountId}/users`.
+    pub fn put(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserInformationList> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::post`](crate::users::Users::post), a `POST` to `/v2.1/accounts/{accountId}/users`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NewUsersSummary> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::delete`](crate::users::Users::delete), a `DELETE` to `/v2.1/accounts/{accountId}/users`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get_users`](crate::users::Users::get_users), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}`.
+    pub fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::put_users`](crate::users::Users::put_users), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}`.
+    pub fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserInformation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::profile_image_get`](crate::users::Users::profile_image_get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/profile/image`.
+    pub fn profile_image_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::profile_image_put`](crate::users::Users::profile_image_put), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/profile/image`.
+    pub fn profile_image_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::profile_image_delete`](crate::users::Users::profile_image_delete), a `DELETE` to `/v2.1/accounts/{accountId}/users/{userId}/profile/image`.
+    pub fn profile_image_delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_get`](crate::users::Users::settings_get), a `GET` to `/v2.1/accounts/{accountId}/users/{userId}/settings`.
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSettingsInformation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/users/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_put`](crate::users::Users::settings_put), a `PUT` to `/v2.1/accounts/{accountId}/users/{userId}/settings`.
+    pub fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/users/{}/settings",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct WorkspaceItems<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> WorkspaceItems<'a> {
+    /// Mock [`WorkspaceItems::workspace_folder_get`](crate::workspace_items::WorkspaceItems::workspace_folder_get), a `GET` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}`.
+    pub fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkspaceFolderContents> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`WorkspaceItems::workspace_folder_delete_items`](crate::workspace_items::WorkspaceItems::workspace_folder_delete_items), a `DELETE` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}`.
+    pub fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`WorkspaceItems::workspace_file_post_files`](crate::workspace_items::WorkspaceItems::workspace_file_post_files), a `POST` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files`.
+    pub fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkspaceItem> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`WorkspaceItems::workspace_file_get`](crate::workspace_items::WorkspaceItems::workspace_file_get), a `GET` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}`.
+    pub fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`WorkspaceItems::workspace_file_put`](crate::workspace_items::WorkspaceItems::workspace_file_put), a `PUT` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}`.
+    pub fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkspaceItem> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`WorkspaceItems::workspace_file_pages_get`](crate::workspace_items::WorkspaceItems::workspace_file_pages_get), a `GET` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages`.
+    pub fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageImages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+}
+
+pub struct Workspaces<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Workspaces<'a> {
+    /// Mock [`Workspaces::get`](crate::workspaces::Workspaces::get), a `GET` to `/v2.1/accounts/{accountId}/workspaces`.
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkspaceList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/workspaces",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Workspaces::post`](crate::workspaces::Workspaces::post), a `POST` to `/v2.1/accounts/{accountId}/workspaces`.
+    pub fn post(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workspace> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2.1/accounts/{}/workspaces",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Workspaces::get_workspaces`](crate::workspaces::Workspaces::get_workspaces), a `GET` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}`.
+    pub fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workspace> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id)
+            ),
+        )
+    }
+
+    /// Mock [`Workspaces::put`](crate::workspaces::Workspaces::put), a `PUT` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}`.
+    pub fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workspace> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id)
+            ),
+        )
+    }
+
+    /// Mock [`Workspaces::delete`](crate::workspaces::Workspaces::delete), a `DELETE` to `/v2.1/accounts/{accountId}/workspaces/{workspaceId}`.
+    pub fn delete(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workspace> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v2.1/accounts/{}/workspaces/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id)
+            ),
+        )
+    }
+}
@@ -165,7 +165,7 @@ impl TemplateDocuments {
         template_id: &str,
         encrypt: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encrypt.is_empty() {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
@@ -185,13 +185,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "application/pdf")
             .await
     }
     /**
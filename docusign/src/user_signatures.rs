@@ -341,7 +341,7 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -359,13 +359,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "image/gif")
             .await
     }
     /**
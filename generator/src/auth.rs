@@ -0,0 +1,527 @@
+/*!
+ * Work out how a client authenticates from the spec's `securitySchemes`.
+ *
+ * The `[auth]` section of the crate's `generator.toml` wins over the spec,
+ * for APIs that don't document their auth, or document it wrong.
+ */
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{bail, Result};
+use inflector::cases::snakecase::to_snake_case;
+use serde::Deserialize;
+
+use crate::{
+    config::{AuthStyle, Config},
+    load, struct_name,
+};
+
+/// A credential the API takes as is, with every request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Credential {
+    /// A header, its value optionally preceded by a scheme, like `Bearer`.
+    Header {
+        name: String,
+        scheme: Option<String>,
+    },
+    Query {
+        name: String,
+    },
+    Cookie {
+        name: String,
+    },
+    /// HTTP basic auth.
+    Basic,
+}
+
+/// A variant of the generated `Credentials` enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaticScheme {
+    pub variant: String,
+    pub credential: Credential,
+}
+
+impl StaticScheme {
+    /// Whether the credential is a single secret, like an API key.
+    pub fn is_token(&self) -> bool {
+        self.credential != Credential::Basic
+    }
+
+    fn doc(&self) -> String {
+        match &self.credential {
+            Credential::Header {
+                name,
+                scheme: Some(scheme),
+            } => format!("Sent in the `{}` header, as `{} <token>`.", name, scheme),
+            Credential::Header { name, scheme: None } => {
+                format!("Sent as the `{}` header.", name)
+            }
+            Credential::Query { name } => format!("Sent as the `{}` query parameter.", name),
+            Credential::Cookie { name } => format!("Sent as the `{}` cookie.", name),
+            Credential::Basic => {
+                "Sent in the `Authorization` header, with HTTP basic auth.".to_string()
+            }
+        }
+    }
+
+    fn pattern(&self) -> String {
+        match &self.credential {
+            Credential::Basic => format!("Credentials::{} {{ username, password }}", self.variant),
+            _ => format!("Credentials::{}(token)", self.variant),
+        }
+    }
+
+    fn apply(&self) -> String {
+        match &self.credential {
+            Credential::Header { name, scheme } => {
+                let value = match scheme {
+                    Some(scheme) => format!(r#"format!("{} {{}}", token)"#, scheme),
+                    None => "token.to_string()".to_string(),
+                };
+                format!("Some(({}, {}))", header_name(name), value)
+            }
+            Credential::Query { name } => format!(
+                r#"{{
+                url.query_pairs_mut().append_pair("{}", token);
+                None
+            }}"#,
+                name
+            ),
+            Credential::Cookie { name } => format!(
+                r#"Some((http::header::COOKIE, format!("{}={{}}", token)))"#,
+                name
+            ),
+            Credential::Basic => r#"Some((
+                http::header::AUTHORIZATION,
+                format!("Basic {}", base64::encode(format!("{}:{}", username, password))),
+            ))"#
+            .to_string(),
+        }
+    }
+}
+
+fn header_name(name: &str) -> String {
+    match name.to_lowercase().as_str() {
+        "authorization" => "http::header::AUTHORIZATION".to_string(),
+        "cookie" => "http::header::COOKIE".to_string(),
+        n => format!(r#"http::header::HeaderName::from_static("{}")"#, n),
+    }
+}
+
+/// How the generated client authenticates.
+#[derive(Clone, Debug)]
+pub struct ClientAuth {
+    pub style: AuthStyle,
+    /// The credentials an API key client can be created with.
+    pub schemes: Vec<StaticScheme>,
+    pub token_endpoint: String,
+    pub user_consent_endpoint: String,
+    /// An OAuth client can also get tokens with the client credentials grant.
+    pub client_credentials: bool,
+}
+
+impl ClientAuth {
+    /// Whether the client refreshes its access tokens.
+    pub fn refresh(&self) -> bool {
+        self.style == AuthStyle::Token || self.style == AuthStyle::Google
+    }
+
+    /// The credentials `Client::new` takes: the first single secret, if any.
+    pub fn default_scheme(&self) -> &StaticScheme {
+        self.schemes
+            .iter()
+            .find(|s| s.is_token())
+            .unwrap_or(&self.schemes[0])
+    }
+
+    /// Dependencies the generated code needs for these credentials.
+    pub fn dependencies(&self, config: &Config) -> String {
+        let mut deps = String::new();
+        // The token store and the middleware passing on the rate limited
+        // responses are async traits.
+        if (self.refresh() || config.retry_after)
+            && !config.dependencies.contains_key("async-trait")
+        {
+            deps.push_str("\nasync-trait = \"^0.1.51\"");
+        }
+        // The middleware takes the extensions of the request.
+        if config.retry_after {
+            deps.push_str("\ntask-local-extensions = \"0.1.1\"");
+        }
+        if self.schemes.iter().any(|s| !s.is_token()) && !config.dependencies.contains_key("base64")
+        {
+            deps.push_str("\nbase64 = \"^0.13\"");
+        }
+        deps
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Spec {
+    #[serde(default)]
+    components: Components,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Components {
+    #[serde(default)]
+    security_schemes: BTreeMap<String, SecurityScheme>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum SecurityScheme {
+    ApiKey {
+        #[serde(rename = "in")]
+        location: String,
+        name: String,
+    },
+    Http {
+        scheme: String,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        flows: Flows,
+    },
+    OpenIdConnect {},
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Flows {
+    authorization_code: Option<Flow>,
+    client_credentials: Option<Flow>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Flow {
+    #[serde(default)]
+    authorization_url: String,
+    #[serde(default)]
+    token_url: String,
+}
+
+/*
+ * Read the security schemes of the spec, the `openapiv3` types lose the
+ * flows of schemes with more than one.
+ */
+pub fn client_auth<P: AsRef<Path>>(spec: P, config: &Config) -> Result<ClientAuth> {
+    let spec: Spec = load(spec)?;
+    resolve(&spec.components.security_schemes, config)
+}
+
+fn resolve(schemes: &BTreeMap<String, SecurityScheme>, config: &Config) -> Result<ClientAuth> {
+    let mut statics = Vec::new();
+    let mut authorization_code = None;
+    let mut client_credentials = None;
+    for (name, scheme) in schemes {
+        let credential = match scheme {
+            SecurityScheme::ApiKey { location, name } => match location.as_str() {
+                "header" if name.eq_ignore_ascii_case("authorization") => Credential::Header {
+                    name: name.to_string(),
+                    scheme: Some(config.auth.scheme.to_string()),
+                },
+                "header" => Credential::Header {
+                    name: name.to_string(),
+                    scheme: None,
+                },
+                "query" => Credential::Query {
+                    name: name.to_string(),
+                },
+                "cookie" => Credential::Cookie {
+                    name: name.to_string(),
+                },
+                l => bail!("unsupported api key location {} of {}", l, name),
+            },
+            SecurityScheme::Http { scheme } if scheme.eq_ignore_ascii_case("basic") => {
+                Credential::Basic
+            }
+            SecurityScheme::Http { scheme } => Credential::Header {
+                name: "Authorization".to_string(),
+                scheme: Some(to_scheme_case(scheme)),
+            },
+            SecurityScheme::OAuth2 { flows } => {
+                if let Some(f) = &flows.authorization_code {
+                    authorization_code = Some(f);
+                }
+                if let Some(f) = &flows.client_credentials {
+                    client_credentials = Some(f);
+                }
+                continue;
+            }
+            SecurityScheme::OpenIdConnect {} => continue,
+        };
+        statics.push(StaticScheme {
+            variant: struct_name(&to_snake_case(name)),
+            credential,
+        });
+    }
+
+    let style = match config.auth.style {
+        Some(style) => style,
+        None if authorization_code.is_some() => AuthStyle::Token,
+        None if client_credentials.is_some() => AuthStyle::ClientCredentials,
+        None if !statics.is_empty() => AuthStyle::ApiKey,
+        None => bail!(
+            "{} has no security schemes, set the auth style in its generator.toml",
+            config.proper_name
+        ),
+    };
+
+    if style == AuthStyle::ApiKey && statics.is_empty() {
+        // The spec documents OAuth, but the API takes a personal token too.
+        statics.push(StaticScheme {
+            variant: "Bearer".to_string(),
+            credential: Credential::Header {
+                name: "Authorization".to_string(),
+                scheme: Some(config.auth.scheme.to_string()),
+            },
+        });
+    }
+
+    // An empty token URL means the one of the authorization code flow.
+    let token_url = authorization_code
+        .map(|f| f.token_url.as_str())
+        .into_iter()
+        .chain(client_credentials.map(|f| f.token_url.as_str()))
+        .find(|u| !u.is_empty())
+        .unwrap_or_default();
+    let or_spec = |configured: &str, spec: &str| {
+        if configured.is_empty() {
+            spec.trim_start_matches("https://").to_string()
+        } else {
+            configured.to_string()
+        }
+    };
+
+    Ok(ClientAuth {
+        style,
+        schemes: statics,
+        token_endpoint: or_spec(&config.auth.token_endpoint, token_url),
+        user_consent_endpoint: or_spec(
+            &config.auth.user_consent_endpoint,
+            authorization_code
+                .map(|f| f.authorization_url.as_str())
+                .unwrap_or_default(),
+        ),
+        client_credentials: style == AuthStyle::Token && client_credentials.is_some(),
+    })
+}
+
+/// `bearer` is written `Bearer` in the header.
+fn to_scheme_case(scheme: &str) -> String {
+    let mut c = scheme.chars();
+    match c.next() {
+        Some(f) => f.to_uppercase().chain(c).collect(),
+        None => String::new(),
+    }
+}
+
+/// Generate the `Credentials` enum of an API key client.
+pub fn generate_credentials(auth: &ClientAuth) -> String {
+    let mut variants = String::new();
+    for s in &auth.schemes {
+        let fields = if s.is_token() {
+            "(String)"
+        } else {
+            " { username: String, password: String }"
+        };
+        variants.push_str(&format!(
+            "    /// {}\n    {}{},\n",
+            s.doc(),
+            s.variant,
+            fields
+        ));
+    }
+
+    format!(
+        r#"/// The credentials sent with each request.
+#[derive(Clone)]
+pub enum Credentials {{
+{variants}}}"#
+    )
+}
+
+/// Generate the function adding the credentials of an API key client to a
+/// request.
+pub fn generate_credentials_url_and_auth(auth: &ClientAuth) -> String {
+    let arms: String = auth
+        .schemes
+        .iter()
+        .map(|s| format!("        {} => {},\n", s.pattern(), s.apply()))
+        .collect();
+
+    let mutable = if auth
+        .schemes
+        .iter()
+        .any(|s| matches!(s.credential, Credential::Query { .. }))
+    {
+        "mut "
+    } else {
+        ""
+    };
+
+    format!(
+        r#"
+async fn url_and_auth(
+    &self,
+    uri: &str,
+) -> Result<(reqwest::Url, Option<(http::header::HeaderName, String)>)> {{
+    let {mutable}url = uri.parse::<reqwest::Url>().map_err(ClientError::from)?;
+    let auth = match &self.credentials {{
+{arms}    }};
+    Ok((url, auth))
+}}"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(auth: &str) -> Config {
+        toml::from_str(&format!(
+            r#"
+spec = "spec.json"
+output = "out"
+name = "out"
+version = "0.1.0"
+description = "Out."
+proper_name = "Out"
+spec_link = "https://example.com"
+host = "example.com"
+
+[auth]
+{}
+"#,
+            auth
+        ))
+        .unwrap()
+    }
+
+    fn schemes(json: &str) -> BTreeMap<String, SecurityScheme> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn api_keys_and_http_schemes() {
+        let auth = resolve(
+            &schemes(
+                r#"{
+                    "api_key": {"type": "apiKey", "in": "query", "name": "api_key"},
+                    "basicAuth": {"type": "http", "scheme": "basic"},
+                    "bearerAuth": {"type": "http", "scheme": "bearer"},
+                    "session": {"type": "apiKey", "in": "cookie", "name": "session"},
+                    "token": {"type": "apiKey", "in": "header", "name": "Authorization"},
+                    "x_key": {"type": "apiKey", "in": "header", "name": "X-Api-Key"}
+                }"#,
+            ),
+            &config(r#"scheme = "SSWS""#),
+        )
+        .unwrap();
+
+        assert_eq!(auth.style, AuthStyle::ApiKey);
+        assert_eq!(
+            auth.schemes
+                .iter()
+                .map(|s| (s.variant.as_str(), s.credential.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "ApiKey",
+                    Credential::Query {
+                        name: "api_key".to_string()
+                    }
+                ),
+                ("BasicAuth", Credential::Basic),
+                (
+                    "BearerAuth",
+                    Credential::Header {
+                        name: "Authorization".to_string(),
+                        scheme: Some("Bearer".to_string())
+                    }
+                ),
+                (
+                    "Session",
+                    Credential::Cookie {
+                        name: "session".to_string()
+                    }
+                ),
+                (
+                    "Token",
+                    Credential::Header {
+                        name: "Authorization".to_string(),
+                        scheme: Some("SSWS".to_string())
+                    }
+                ),
+                (
+                    "XKey",
+                    Credential::Header {
+                        name: "X-Api-Key".to_string(),
+                        scheme: None
+                    }
+                ),
+            ]
+        );
+        assert_eq!(auth.dependencies(&config("")), "\nbase64 = \"^0.13\"");
+
+        let generated = generate_credentials_url_and_auth(&auth);
+        assert!(generated.contains(r#"url.query_pairs_mut().append_pair("api_key", token);"#));
+        assert!(generated.contains(r#"HeaderName::from_static("x-api-key")"#));
+        assert!(generated.contains(r#"format!("SSWS {}", token)"#));
+    }
+
+    #[test]
+    fn oauth_flows() {
+        let oauth = schemes(
+            r#"{
+                "oauth": {"type": "oauth2", "flows": {
+                    "authorizationCode": {
+                        "authorizationUrl": "https://example.com/authorize",
+                        "tokenUrl": "https://example.com/token",
+                        "scopes": {}
+                    },
+                    "clientCredentials": {"tokenUrl": "", "scopes": {}}
+                }}
+            }"#,
+        );
+
+        let auth = resolve(&oauth, &config("")).unwrap();
+        assert_eq!(auth.style, AuthStyle::Token);
+        assert!(auth.refresh());
+        assert!(auth.client_credentials);
+        assert_eq!(auth.token_endpoint, "example.com/token");
+        assert_eq!(auth.user_consent_endpoint, "example.com/authorize");
+
+        let auth = resolve(
+            &oauth,
+            &config(r#"token_endpoint = "example.com/v2/token""#),
+        )
+        .unwrap();
+        assert_eq!(auth.token_endpoint, "example.com/v2/token");
+
+        let auth = resolve(
+            &schemes(
+                r#"{
+                    "oauth": {"type": "oauth2", "flows": {
+                        "clientCredentials": {"tokenUrl": "https://example.com/token"}
+                    }}
+                }"#,
+            ),
+            &config(""),
+        )
+        .unwrap();
+        assert_eq!(auth.style, AuthStyle::ClientCredentials);
+        assert!(!auth.client_credentials);
+    }
+
+    #[test]
+    fn configured_style_wins() {
+        let auth = resolve(&BTreeMap::new(), &config(r#"style = "api-key""#)).unwrap();
+        assert_eq!(auth.style, AuthStyle::ApiKey);
+        assert_eq!(auth.schemes[0].variant, "Bearer");
+
+        assert!(resolve(&BTreeMap::new(), &config("")).is_err());
+    }
+}
@@ -1,6 +1,10 @@
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::struct_name;
+use crate::{
+    auth::{generate_credentials, generate_credentials_url_and_auth, ClientAuth, Credential},
+    config::{AuthStyle, Config},
+    struct_name,
+};
 
 /*
  * Declare the client object:
@@ -13,6 +17,8 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+    rate_limit_policy: Option<crate::rate_limiter::RateLimitPolicy>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -23,19 +29,7 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().build()?;
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest_middleware::ClientBuilder::new(http)
-            // Trace HTTP requests. See the tracing crate to make use of these traces.
-            .with(reqwest_tracing::TracingMiddleware::default())
-            // Retry failed requests.
-            .with(
-                reqwest_conditional_middleware::ConditionalMiddleware::new(
-                    reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                    |req: &reqwest::Request| req.try_clone().is_some()
-                )
-            )
-            .build();
+        let client = ClientBuilder::new().build()?;
 
         #[cfg(feature = "httpcache")]
         {
@@ -69,6 +63,8 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            rate_limiter: Default::default(),
+            rate_limit_policy: None,
             http_cache,
         }
     }
@@ -85,6 +81,8 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            rate_limiter: Default::default(),
+            rate_limit_policy: None,
         }
     }
 
@@ -108,6 +106,27 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Send the requests with this HTTP client, like one built with a
+    /// `ClientBuilder`.
+    pub fn with_http_client(&mut self, client: reqwest_middleware::ClientWithMiddleware) -> &mut Self {
+        self.client = client;
+        self
+    }
+
+    /// Wait for, or fail on, exceeded rate limits instead of sending requests
+    /// that would be refused. This also retries requests refused because of
+    /// a rate limit, after a `Retry-After` or the reset of the limit.
+    pub fn with_rate_limit_policy(&mut self, policy: crate::rate_limiter::RateLimitPolicy) -> &mut Self {
+        self.rate_limit_policy = Some(policy);
+        self
+    }
+
+    /// The latest rate limits reported by GitHub, shared with the clones of
+    /// the client.
+    pub fn rate_limits(&self) -> &crate::rate_limiter::RateLimiter {
+        &self.rate_limiter
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!("{}{}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }
@@ -151,14 +170,14 @@ impl Client {
                         .append_pair("client_secret", secret);
                     (u, None)
                 })
-                .map_err(Error::from),
+                .map_err(|e| ClientError::from(e).into()),
             Some(&crate::auth::Credentials::Token(ref token)) => {
                 let auth = format!("token {}", token);
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url.map(|u| (u, Some(auth))).map_err(|e| ClientError::from(e).into())
             }
             Some(&crate::auth::Credentials::JWT(ref jwt)) => {
                 let auth = format!("Bearer {}", jwt.token());
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url.map(|u| (u, Some(auth))).map_err(|e| ClientError::from(e).into())
             }
             Some(&crate::auth::Credentials::InstallationToken(ref apptoken)) => {
                 let token = if let Some(token) = apptoken.token().await {
@@ -174,14 +193,15 @@ impl Client {
                         let token = self
                             .apps()
                             .create_installation_access_token(
-                                apptoken.installation_id as i64,
+                                apptoken.installation_id,
                                 &types::AppsCreateInstallationAccessTokenRequest {
                                     permissions: Default::default(),
                                     repositories: Default::default(),
                                     repository_ids: Default::default(),
                                 },
                             )
-                            .await?;
+                            .await
+                            .map_err(ClientError::AuthRefresh)?;
                         *token_guard = Some(crate::auth::ExpiringInstallationToken::new(
                             token.token.clone(),
                             created_at,
@@ -190,9 +210,9 @@ impl Client {
                     }
                 };
                 let auth = format!("token {}", token);
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url.map(|u| (u, Some(auth))).map_err(|e| ClientError::from(e).into())
             }
-            None => parsed_url.map(|u| (u, None)).map_err(Error::from),
+            None => parsed_url.map(|u| (u, None)).map_err(|e| ClientError::from(e).into()),
         }
     }
 
@@ -207,27 +227,22 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        #[cfg(feature = "httpcache")]
-        let uri2 = uri.to_string();
-
         let (url, auth) = self.url_and_auth(uri, authentication).await?;
+        let resource = crate::rate_limiter::Resource::for_path(url.path());
 
         let instance = <&Client>::clone(&self);
 
-        #[cfg(not(feature = "httpcache"))]
-        let mut req = instance.client.request(method, url);
-
         #[cfg(feature = "httpcache")]
-        let mut req = {
-            let mut req = instance.client.request(method.clone(), url);
-            if method == http::Method::GET {
-                if let Ok(etag) = instance.http_cache.lookup_etag(&uri2) {
-                    req = req.header(http::header::IF_NONE_MATCH, etag);
-                }
-            }
-            req
+        let cacheable = method == http::Method::GET;
+        #[cfg(feature = "httpcache")]
+        let cached = if cacheable {
+            crate::http_cache::lookup(&instance.http_cache, uri).await
+        } else {
+            None
         };
 
+        let mut req = instance.client.request(method, url);
+
         if let Some(content_type) = &message.content_type {
             req = req.header(http::header::CONTENT_TYPE, content_type.clone());
         }
@@ -245,21 +260,36 @@ impl Client {
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        let response = req.send().await?;
 
+        #[allow(unused_mut)]
+        let mut req = req.build().map_err(ClientError::from)?;
         #[cfg(feature = "httpcache")]
-        let instance2 = <&Client>::clone(&self);
-
+        let unconditional = req.try_clone();
         #[cfg(feature = "httpcache")]
-        let uri3 = uri.to_string();
+        if let Some(cached) = &cached {
+            req.headers_mut().extend(cached.conditional_headers());
+        }
 
-        #[cfg(not(feature = "httpcache"))]
-        let (remaining, reset) = crate::utils::get_header_values(response.headers());
+        #[allow(unused_mut)]
+        let mut response = instance
+            .rate_limiter
+            .send(&instance.client, req, &resource, instance.rate_limit_policy)
+            .await?;
 
+        // Nothing is cached to answer a 304 with, so ask for the whole response.
         #[cfg(feature = "httpcache")]
-        let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
+        if response.status() == http::StatusCode::NOT_MODIFIED && cached.is_none() {
+            if let Some(req) = unconditional {
+                log::debug!("received a 304 without a cached response, requesting it again");
+                response = instance
+                    .rate_limiter
+                    .send(&instance.client, req, &resource, instance.rate_limit_policy)
+                    .await?;
+            }
+        }
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -267,23 +297,13 @@ impl Client {
             .and_then(|l| parse_link_header::parse(l).ok());
         let next_link = link.as_ref().and_then(crate::utils::next_link);
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
             #[cfg(feature = "httpcache")]
-            {
-                if let Some(etag) = etag {
-                    if let Err(e) = instance2.http_cache.cache_response(
-                        &uri3,
-                        &response_body,
-                        &etag,
-                        &next_link.as_ref().map(|n| n.0.clone()),
-                    ) {
-                        // failing to cache isn't fatal, so just log & swallow the error
-                        log::info!("failed to cache body & etag: {}", e);
-                    }
-                }
+            if cacheable {
+                crate::http_cache::store(&instance.http_cache, uri, &headers, &response_body).await;
             }
 
             let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
@@ -291,43 +311,27 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map(|out| (next_link, out)).map_err(Error::from)
-        } else if status == http::StatusCode::NOT_MODIFIED {
-                // only supported case is when client provides if-none-match
-                // header when cargo builds with --cfg feature="httpcache"
-                #[cfg(feature = "httpcache")]
-                {
-                    let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                    let out = serde_json::from_str::<Out>(&body).unwrap();
-                    let link = match next_link {
-                        Some(next_link) => Ok(Some(next_link)),
-                        None => instance2.http_cache.lookup_next_link(&uri3)
-                            .map(|next_link| next_link.map(crate::utils::NextLink))
-                    };
-                    link.map(|link| (link, out))
-                }
-                #[cfg(not(feature = "httpcache"))]
-                {
-                    unreachable!("this should not be reachable without the httpcache feature enabled")
-                }
+            parsed_response.map(|out| (next_link, out)).map_err(|e| ClientError::from(e).into())
         } else {
-            let error = match (remaining, reset) {
-                (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs();
-                    anyhow!("rate limit exceeded, will reset in {} seconds", u64::from(reset) - now)
-                },
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!("code: {}, error: {:?}", status, String::from_utf8_lossy(&response_body),)
-                    }
+            // A 304 is only expected when the request was made conditional on a
+            // cached response, with the `httpcache` feature.
+            #[cfg(feature = "httpcache")]
+            if status == http::StatusCode::NOT_MODIFIED {
+                if let Some(cached) = cached {
+                    let out = serde_json::from_slice::<Out>(&cached.body).map_err(ClientError::from)?;
+                    let next_link = next_link.or_else(|| {
+                        cached
+                            .link
+                            .as_deref()
+                            .and_then(|l| parse_link_header::parse(l).ok())
+                            .as_ref()
+                            .and_then(crate::utils::next_link)
+                    });
+                    return Ok((next_link, out));
                 }
-            };
-            Err(error)
+            }
+
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -346,6 +350,49 @@ impl Client {
         Ok(r)
     }
 
+    #[allow(dead_code)]
+    async fn download(
+        &self,
+        method: http::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri, crate::auth::AuthenticationConstraint::Unconstrained).await?;
+        let resource = crate::rate_limiter::Resource::for_path(url.path());
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        req = req.header(http::header::USER_AGENT, &*instance.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            http::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        // Redirects to the download URL are followed by reqwest, which drops the
+        // authorization header if the redirect points to another host.
+        let req = req.build().map_err(ClientError::from)?;
+        let response = instance
+            .rate_limiter
+            .send(&instance.client, req, &resource, instance.rate_limit_policy)
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(crate::utils::Download::new(response))
+        } else {
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await.map_err(ClientError::from)?;
+            Err(ClientError::from_response(status, headers, &response_body).into())
+        }
+    }
+
     async fn get<D>(&self, uri: &str, message: Message) -> Result<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -359,7 +406,7 @@ impl Client {
     {
         self.request_entity(
             http::Method::GET,
-            &uri,
+            uri,
             message,
             media,
             crate::auth::AuthenticationConstraint::Unconstrained,
@@ -379,7 +426,7 @@ impl Client {
     {
         self.request(
             http::Method::GET,
-            &uri,
+            uri,
             Message::default(),
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::Unconstrained,
@@ -423,7 +470,7 @@ impl Client {
     {
         self.request_entity(
             http::Method::POST,
-            &uri,
+            uri,
             message,
             media,
             authentication,
@@ -436,7 +483,7 @@ impl Client {
     {
         self.request_entity(
             http::Method::PATCH,
-            &uri,
+            uri,
             message,
             media,
             crate::auth::AuthenticationConstraint::Unconstrained,
@@ -463,7 +510,7 @@ impl Client {
     {
         self.request_entity(
             http::Method::PUT,
-            &uri,
+            uri,
             message,
             media,
             crate::auth::AuthenticationConstraint::Unconstrained,
@@ -476,7 +523,7 @@ impl Client {
     {
         self.request_entity(
             http::Method::DELETE,
-            &uri,
+            uri,
             message,
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::Unconstrained,
@@ -508,17 +555,247 @@ impl Client {
         Ok(global_items)
     }"#;
 
+/*
+ * Declare the builder of the HTTP client the API client sends its requests with:
+ */
+const CLIENT_BUILDER_TEMPLATE: &str = r#"/// Builds the HTTP client the API client sends its requests with, to hand to
+/// `Client::with_http_client`.
+///
+/// By default, requests are traced and the transient failures are retried up
+/// to 3 times with increasing intervals between attempts. Only the requests
+/// that can safely be sent again are retried: the ones with an idempotent
+/// method, like `GET` or `PUT`, and the ones carrying an `Idempotency-Key`.
+pub struct ClientBuilder {
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<reqwest_retry::policies::ExponentialBackoff>,
+    retry_any_method: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    middleware: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            // Retry up to 3 times with increasing intervals between attempts.
+            retry_policy: Some(reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3)),
+            retry_any_method: false,
+            user_agent: None,
+            default_headers: Default::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Time out the requests, from connecting until the end of the response
+    /// body, after `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time out the connections to the server after `timeout`.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Retry the transient failures with this policy instead of the default one.
+    pub fn retry_policy(mut self, policy: reqwest_retry::policies::ExponentialBackoff) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Don't retry failed requests.
+    pub fn no_retries(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// Also retry the requests that aren't idempotent, like a `POST` without an
+    /// `Idempotency-Key`. A request that timed out may then be carried out twice.
+    pub fn retry_any_method(mut self) -> Self {
+        self.retry_any_method = true;
+        self
+    }
+
+    /// Send this `User-Agent` header with every request.
+    pub fn user_agent<A>(mut self, user_agent: A) -> Self
+    where
+        A: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send these headers with every request, besides the ones of the request.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Add a middleware layer. The layers run in the order they are added, after
+    /// the tracing and each time a request is retried.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the HTTP client. This fails when the user agent isn't a valid header
+    /// value or when the TLS backend can't be initialized.
+    pub fn build(self) -> Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(reqwest::header::HeaderValue::from_str(&user_agent)?);
+        }
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default());
+        if let Some(retry_policy) = self.retry_policy {
+            let retry_any_method = self.retry_any_method;
+            // Retry failed requests.
+            client = client.with(
+                reqwest_conditional_middleware::ConditionalMiddleware::new(
+                    reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                    move |req: &reqwest::Request| {
+                        req.try_clone().is_some() && (retry_any_method || is_idempotent(req))
+                    }
+                )
+            );
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+/// Whether sending the request twice has the same effect as sending it once.
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    req.method().is_idempotent() || req.headers().contains_key("idempotency-key")
+}
+"#;
+
+/// The builder of the HTTP client. With the `Retry-After` handling, the
+/// `429 Too Many Requests` responses skip the retry middleware, so that the
+/// client is the only one sending them again.
+pub fn generate_client_builder(config: &Config) -> String {
+    if !config.retry_after {
+        return CLIENT_BUILDER_TEMPLATE.to_string();
+    }
+
+    let mut builder = CLIENT_BUILDER_TEMPLATE.replace(
+        r#"                    }
+                )
+            );
+        }
+        for middleware in self.middleware {"#,
+        r#"                    }
+                )
+            );
+            // Send the rate limited requests again in the client, once their
+            // `Retry-After` has passed, instead of in the retry middleware.
+            client = client.with(PassRateLimited);
+        }
+        for middleware in self.middleware {"#,
+    );
+    builder.push_str(PASS_RATE_LIMITED_TEMPLATE);
+    builder
+}
+
+/// Hands the `429 Too Many Requests` responses back past the retry middleware.
+const PASS_RATE_LIMITED_TEMPLATE: &str = r#"
+/// Hands the `429 Too Many Requests` responses back past the retry middleware,
+/// which would send the request again without waiting out its `Retry-After`.
+struct PassRateLimited;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for PassRateLimited {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let resp = next.run(req, extensions).await?;
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(reqwest_middleware::Error::Middleware(RateLimitedResponse(resp).into()));
+        }
+        Ok(resp)
+    }
+}
+
+/// A `429 Too Many Requests` response, on its way back past the retry
+/// middleware.
+#[derive(Debug, thiserror::Error)]
+#[error("rate limited")]
+struct RateLimitedResponse(reqwest::Response);
+"#;
+
 pub fn generate_client_generic_token(
-    proper_name: &str,
-    token_endpoint: &str,
-    user_consent_endpoint: &str,
-    add_post_header: &str,
+    config: &Config,
+    auth: &ClientAuth,
     servers: &GeneratedServers,
 ) -> String {
-    let mut new_from_env = basic_new_from_env(proper_name, add_post_header, servers);
-    if proper_name.starts_with("Google") {
-        new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
-    }
+    let proper_name = config.proper_name.as_str();
+    let add_post_header = config.auth.add_post_header.as_str();
+    let google = auth.style == AuthStyle::Google;
+    let (idempotency_key_field, idempotency_key_init) = idempotency_key_field(config);
+    let (api_version_field, api_version_init) = api_version_field(config);
+    let (call_limit_field, call_limit_init) = call_limit_field(config);
+    let call_limit_type = call_limit_type(config);
+    let url_fn = url_function(config);
+
+    let host_variable = config.host_variable.as_str();
+    let mut new_from_env = basic_new_from_env(proper_name, add_post_header, host_variable, servers);
+    let (google_fields, google_init, google_types, refresh_hook) = if google {
+        new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.replace(
+            "service_account: None,",
+            &format!(
+                "service_account: None,{}{}{}",
+                idempotency_key_init, api_version_init, call_limit_init
+            ),
+        );
+        new_from_env.push_str(GOOGLE_SERVICE_ACCOUNT_TEMPLATE);
+        (
+            "service_account: Option<ServiceAccount>,",
+            "service_account: None,",
+            GOOGLE_SERVICE_ACCOUNT_TYPES,
+            GOOGLE_SERVICE_ACCOUNT_REFRESH,
+        )
+    } else {
+        ("", "", "", "")
+    };
 
     let add_post_header_struct = if add_post_header.is_empty() {
         "".to_string()
@@ -554,17 +831,67 @@ pub fn generate_client_generic_token(
         "".to_string()
     };
 
-    let consent_pattern = if proper_name.starts_with("Google") {
+    let consent_pattern = if google {
         "{}?client_id={}&access_type=offline&response_type=code&redirect_uri={}&state={}"
     } else {
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    let mut token_auth_template = if host_variable.is_empty() {
+        user_consent_url_template(consent_pattern)
+    } else {
+        String::new()
+    };
+    token_auth_template.push_str(&get_token_auth_template(refresh_hook));
+    if auth.client_credentials {
+        token_auth_template.push_str(CLIENT_CREDENTIALS_TOKEN_TEMPLATE);
+    }
 
     let server_block = servers.server_block();
-    let server_arg = servers.server_arg();
-    let server_to_host = servers.host_from_server();
+    let mut server_arg = servers.server_arg();
+    let mut server_to_host = servers.host_from_server();
+
+    // A host made of a variable, like the domain of a shop, is only known
+    // once the client is created for one.
+    let (endpoints, token_endpoint_init, new_doc, new_return, new_bind, new_end) = if host_variable
+        .is_empty()
+    {
+        (
+                format!(
+                    "const TOKEN_ENDPOINT: &str = \"https://{}\";\nconst USER_CONSENT_ENDPOINT: &str = \"https://{}\";",
+                    auth.token_endpoint, auth.user_consent_endpoint
+                ),
+                "TOKEN_ENDPOINT.to_string()".to_string(),
+                r#"/// Create a new Client struct. It takes a type that can convert into
+    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
+    /// given a valid API key your requests will work."#
+                    .to_string(),
+                "Self",
+                "",
+                String::new(),
+            )
+    } else {
+        server_arg.push_str(&format!("{}: impl ToString,", host_variable));
+        server_to_host = format!(
+            "// Set by `with_{}`.\nlet host = String::new();",
+            host_variable
+        );
+        (
+                format!(
+                    "/// The path of the token endpoint on the host.\nconst TOKEN_PATH: &str = \"{}\";\n/// The path of the user consent endpoint on the host.\nconst USER_CONSENT_PATH: &str = \"{}\";",
+                    auth.token_endpoint, auth.user_consent_endpoint
+                ),
+                "String::new()".to_string(),
+                format!(
+                    r#"/// Create a new Client struct for a {0}, which fails if the {0} isn't one
+    /// `with_{0}` accepts."#,
+                    host_variable
+                ),
+                "Result<Self>",
+                "let mut client = ",
+                format!(";\nclient.with_{0}({0})?;\nOk(client)", host_variable),
+            )
+    };
 
     format!(
         r#"use std::sync::Arc;
@@ -574,8 +901,7 @@ use std::ops::Add;
 use std::time::{{Duration, Instant}};
 use tokio::sync::RwLock;
 
-const TOKEN_ENDPOINT: &str = "https://{}";
-const USER_CONSENT_ENDPOINT: &str = "https://{}";
+{endpoints}
 
 {server_block}
 
@@ -585,15 +911,34 @@ pub struct Client {{
     host: String,
     host_override: Option<String>,
     token: Arc<RwLock<InnerToken>>,
+    token_endpoint: String,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
     {}
     auto_refresh: bool,
+    token_store: Option<Arc<dyn TokenStore>>,
+    refreshing: Arc<tokio::sync::Mutex<()>>,
+    {google_fields}
+    {idempotency_key_field}
+    {api_version_field}
+    {call_limit_field}
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
 {}
+{google_types}
+{call_limit_type}
+
+/// Persists the tokens of a client. The client hands over its tokens whenever
+/// it gets new ones, so a refresh token rotated by a refresh isn't lost.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {{
+    /// Save the tokens the client got.
+    async fn store(&self, token: &AccessToken) -> anyhow::Result<()>;
+}}
 
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
@@ -608,9 +953,7 @@ struct InnerToken {{
 }}
 
 impl Client {{
-    /// Create a new Client struct. It takes a type that can convert into
-    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
-    /// given a valid API key your requests will work.
+    {new_doc}
     pub fn new<I, K, R, T, Q{}>(
         client_id: I,
         client_secret: K,
@@ -619,7 +962,7 @@ impl Client {{
         refresh_token: Q,
         {}
         {server_arg}
-    ) -> Self
+    ) -> {new_return}
     where
         I: ToString,
         K: ToString,
@@ -628,43 +971,37 @@ impl Client {{
         Q: ToString,
         {}
     {{
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                {server_to_host}
-
-                Client {{
-                    host,
-                    host_override: None,
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {{
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None
-                    }})),
-                    {}
-                    auto_refresh: false,
-                    client,
-                }}
-            }}
+        let client = match ClientBuilder::new().build() {{
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
-        }}
+        }};
+
+        {server_to_host}
+
+        {new_bind}Client {{
+            host,
+            host_override: None,
+            token_endpoint: {token_endpoint_init},
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {{
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None
+            }})),
+            {}
+            auto_refresh: false,
+            token_store: None,
+            refreshing: Default::default(),
+            {google_init}
+            {idempotency_key_init}
+            {api_version_init}
+            {call_limit_init}
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
+        }}{new_end}
     }}
 
     /// Enables or disables the automatic refreshing of access tokens upon expiration
@@ -673,6 +1010,31 @@ impl Client {{
         self
     }}
 
+    /// Sets the store the client saves its tokens to whenever it gets new ones,
+    /// including on automatic refreshes.
+    pub fn set_token_store<S>(&mut self, store: S) -> &mut Self
+    where
+        S: TokenStore + 'static,
+    {{
+        self.token_store = Some(Arc::new(store));
+        self
+    }}
+
+    /// Keep the tokens in the client and hand them to the token store.
+    async fn save_token(&self, t: &AccessToken) -> Result<()> {{
+        *self.token.write().await = InnerToken {{
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        }};
+
+        if let Some(store) = &self.token_store {{
+            store.store(t).await.map_err(|e| e.context("storing the tokens failed"))?;
+        }}
+
+        Ok(())
+    }}
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -743,8 +1105,16 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
-    pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
-        format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
+{url_fn}
+
+    /// Override the endpoint access tokens are requested from, like the one of
+    /// a sandbox.
+    pub fn with_token_endpoint<E>(&mut self, endpoint: E) -> &mut Self
+    where
+        E: ToString,
+    {{
+        self.token_endpoint = endpoint.to_string();
+        self
     }}
 
     {}
@@ -754,8 +1124,6 @@ impl Client {{
     {}
 
     {}"#,
-        token_endpoint.trim_start_matches("https://"),
-        user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         add_post_header_type,
@@ -764,13 +1132,14 @@ impl Client {{
         add_post_header_fn,
         new_from_env,
         token_auth_template,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(config, auth)
     )
 }
 
 fn basic_new_from_env(
     proper_name: &str,
     add_post_header: &str,
+    host_variable: &str,
     servers: &GeneratedServers,
 ) -> String {
     let add_post_header_type = if !add_post_header.is_empty() {
@@ -795,7 +1164,24 @@ fn basic_new_from_env(
     };
 
     let server_arg = servers.server_arg();
-    let server_param = servers.server_param();
+    let mut server_param = servers.server_param();
+    let (host_variable_env, new_return) = if host_variable.is_empty() {
+        (String::new(), "Self")
+    } else {
+        let env_var = format!(
+            "{}_{}",
+            proper_name.to_uppercase().replace('.', ""),
+            host_variable.to_uppercase()
+        );
+        server_param.push_str(&format!("{},", host_variable));
+        (
+            format!(
+                r#"let {} = env::var("{}").expect("must set {}");"#,
+                host_variable, env_var, env_var
+            ),
+            "Result<Self>",
+        )
+    };
 
     format!(
         r#"
@@ -805,7 +1191,7 @@ fn basic_new_from_env(
 /// given a valid API key and your requests will work.
 /// We pass in the token and refresh token to the client so if you are storing
 /// it in a database, you can get it first.
-pub fn new_from_env<T, R{}>(token: T, refresh_token: R{}, {server_arg}) -> Self
+pub fn new_from_env<T, R{}>(token: T, refresh_token: R{}, {server_arg}) -> {new_return}
 where
     T: ToString,
     R: ToString,
@@ -814,6 +1200,7 @@ where
     let client_id = env::var("{}_CLIENT_ID").expect("must set {}_CLIENT_ID");
     let client_secret = env::var("{}_CLIENT_SECRET").expect("must set {}_CLIENT_SECRET");
     let redirect_uri = env::var("{}_REDIRECT_URI").expect("must set {}_REDIRECT_URI");
+    {host_variable_env}
 
     Client::new(
         client_id,
@@ -855,50 +1242,194 @@ where
     let secret = yup_oauth2::parse_application_secret(decoded_google_key)
         .expect("failed to read from google credential env var");
 
-    let client = reqwest::Client::builder().build();
-    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-
-    match client {
-        Ok(c) => {
-            let client = reqwest_middleware::ClientBuilder::new(c)
-                // Trace HTTP requests. See the tracing crate to make use of these traces.
-                .with(reqwest_tracing::TracingMiddleware::default())
-                // Retry failed requests.
-                .with(
-                    reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some()
-                    )
-                )
-                .build();
-
-            let host = RootDefaultServer::default().default_url().to_string();
-
-            Client {
-                host,
-                host_override: None,
-                client_id: secret.client_id.to_string(),
-                client_secret: secret.client_secret.to_string(),
-                redirect_uri: secret.redirect_uris[0].to_string(),
-                token: Arc::new(RwLock::new(InnerToken {
-                    access_token: token.to_string(),
-                    refresh_token: refresh_token.to_string(),
-                    expires_at: None
-                })),
-                auto_refresh: false,
-                client,
-            }
-        },
+    let client = match ClientBuilder::new().build() {
+        Ok(client) => client,
         Err(e) => panic!("creating reqwest client failed: {:?}", e),
+    };
+
+    let host = RootDefaultServer::default().default_url().to_string();
+
+    Client {
+        host,
+        host_override: None,
+        token_endpoint: TOKEN_ENDPOINT.to_string(),
+        client_id: secret.client_id.to_string(),
+        client_secret: secret.client_secret.to_string(),
+        redirect_uri: secret.redirect_uris[0].to_string(),
+        token: Arc::new(RwLock::new(InnerToken {
+            access_token: token.to_string(),
+            refresh_token: refresh_token.to_string(),
+            expires_at: None
+        })),
+        auto_refresh: false,
+        token_store: None,
+        refreshing: Default::default(),
+        service_account: None,
+        #[cfg(feature = "httpcache")]
+        http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+        client,
+    }
+}
+"#;
+
+const GOOGLE_SERVICE_ACCOUNT_TYPES: &str = r#"
+/// A service account the client authenticates as.
+#[derive(Debug, Clone)]
+struct ServiceAccount {
+    key: yup_oauth2::ServiceAccountKey,
+    scopes: Vec<String>,
+    subject: Option<String>,
+}
+
+/// The claims of the JWT a service account exchanges for an access token.
+#[derive(serde::Serialize)]
+struct ServiceAccountClaims<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+}
+"#;
+
+const GOOGLE_SERVICE_ACCOUNT_TEMPLATE: &str = r#"
+/// Create a new Client struct authenticated as a service account, from its
+/// JSON key. Access tokens for the scopes are minted with the JWT bearer grant,
+/// kept until they expire and minted again. With a `subject`, the service
+/// account impersonates that user through domain-wide delegation.
+pub fn new_from_service_account<S>(
+    key: yup_oauth2::ServiceAccountKey,
+    scopes: &[S],
+    subject: Option<&str>,
+) -> Self
+where
+    S: ToString,
+{
+    let mut client = Client::new(key.client_id.clone().unwrap_or_default(), "", "", "", "");
+    client.token_endpoint = key.token_uri.clone();
+    // There is no token yet, so one is minted before the first request.
+    client.token = Arc::new(RwLock::new(InnerToken {
+        access_token: String::new(),
+        refresh_token: String::new(),
+        expires_at: Some(Instant::now()),
+    }));
+    client.auto_refresh = true;
+    client.service_account = Some(ServiceAccount {
+        key,
+        scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        subject: subject.map(|s| s.to_string()),
+    });
+    client
+}
+
+/// Mint an access token for the service account, exchanging a JWT signed with
+/// its key.
+async fn get_service_account_access_token(&self, service_account: &ServiceAccount) -> Result<AccessToken> {
+    let iat = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+    let claims = ServiceAccountClaims {
+        iss: &service_account.key.client_email,
+        scope: service_account.scopes.join(" "),
+        aud: &service_account.key.token_uri,
+        iat,
+        exp: iat + 3600,
+        sub: service_account.subject.as_deref(),
+    };
+
+    let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+    header.kid = service_account.key.private_key_id.clone();
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(service_account.key.private_key.as_bytes())?;
+    let assertion = jsonwebtoken::encode(&header, &claims, &key)?;
+
+    let params = [
+        ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+        ("assertion", &assertion),
+    ];
+    let client = &self.client;
+    let resp = client
+        .post(&self.token_endpoint)
+        .header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+        .form(&params)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+        return Err(ClientError::from_response(status, headers, &body).into());
     }
+
+    // Unwrap the response.
+    let t: AccessToken = resp.json().await?;
+
+    self.save_token(&t).await?;
+
+    Ok(t)
 }
 "#;
 
+/// Service accounts have no refresh token, they mint a new access token.
+const GOOGLE_SERVICE_ACCOUNT_REFRESH: &str = r#"
+    if let Some(service_account) = &self.service_account {
+        return self.get_service_account_access_token(service_account).await;
+    }
+"#;
+
 pub fn generate_client_generic_api_key(
-    proper_name: &str,
-    add_post_header: &str,
+    config: &Config,
+    auth: &ClientAuth,
     servers: &GeneratedServers,
 ) -> String {
+    let proper_name = config.proper_name.to_uppercase().replace('.', "");
+    let credentials = generate_credentials(auth);
+
+    // `new` takes a single secret when the API accepts one.
+    let default_scheme = auth.default_scheme();
+    let (new_generics, new_args, new_where, new_credentials, from_env) = if default_scheme
+        .credential
+        == Credential::Basic
+    {
+        (
+                "U, P",
+                "username: U,\n        password: P,",
+                "U: ToString,\n        P: ToString,",
+                format!(
+                    "Credentials::{} {{ username: username.to_string(), password: password.to_string() }}",
+                    default_scheme.variant
+                ),
+                format!(
+                    r#"let username = env::var("{proper_name}_USERNAME").expect("must set {proper_name}_USERNAME");
+        let password = env::var("{proper_name}_PASSWORD").expect("must set {proper_name}_PASSWORD");
+
+        Client::new(
+            username,
+            password,
+        )"#
+                ),
+            )
+    } else {
+        (
+            "T",
+            "token: T,",
+            "T: ToString,",
+            format!("Credentials::{}(token.to_string())", default_scheme.variant),
+            format!(
+                r#"let token = env::var("{proper_name}_API_KEY").expect("must set {proper_name}_API_KEY");
+
+        Client::new(
+            token,
+        )"#
+            ),
+        )
+    };
+
     let server_block = if servers.count > 0 {
         servers.output.as_deref().unwrap()
     } else {
@@ -907,60 +1438,65 @@ pub fn generate_client_generic_api_key(
 
     let server_arg = servers.server_arg();
     let server_to_host = servers.host_from_server();
+    let (idempotency_key_field, idempotency_key_init) = idempotency_key_field(config);
+    let (api_version_field, api_version_init) = api_version_field(config);
+    let (call_limit_field, call_limit_init) = call_limit_field(config);
+    let call_limit_type = call_limit_type(config);
+    let url_fn = url_function(config);
 
     format!(
         r#"use std::env;
 
 {server_block}
 
+{credentials}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {{
     host: String,
     host_override: Option<String>,
-    token: String,
+    credentials: Credentials,
+    {idempotency_key_field}
+    {api_version_field}
+    {call_limit_field}
 
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
+{call_limit_type}
+
 impl Client {{
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
     /// given a valid API key your requests will work.
-    pub fn new<T>(
-        token: T,
+    pub fn new<{new_generics}>(
+        {new_args}
         {server_arg}
     ) -> Self
     where
-        T: ToString,
+        {new_where}
     {{
-        let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                {server_to_host}
-
-                Client {{
-                    host,
-                    host_override: None,
-                    token: token.to_string(),
-
-                    client,
-                }}
-            }}
+        let client = match ClientBuilder::new().build() {{
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
+        }};
+
+        {server_to_host}
+
+        Client {{
+            host,
+            host_override: None,
+            credentials: {new_credentials},
+            {idempotency_key_init}
+            {api_version_init}
+            {call_limit_init}
+
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
         }}
     }}
 
@@ -984,10 +1520,14 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
-    pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
-        format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
+    /// Replace the credentials sent with each request.
+    pub fn with_credentials(&mut self, credentials: Credentials) -> &mut Self {{
+        self.credentials = credentials;
+        self
     }}
 
+{url_fn}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -996,22 +1536,174 @@ impl Client {{
     /// it in a database, you can get it first.
     pub fn new_from_env() -> Self
     {{
-        let token = env::var("{}_API_KEY").expect("must set {}_API_KEY");
-
-        Client::new(
-            token,
-        )
+        {from_env}
     }}
 
     {}"#,
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(config, auth)
     )
 }
 
-fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
-    let post_header_args = if !add_post_header.is_empty() {
+/// The client field holding the idempotency key set by the caller, and its
+/// initialization, for the APIs taking one.
+fn idempotency_key_field(config: &Config) -> (&'static str, &'static str) {
+    if config.idempotency_key_header.is_empty() {
+        ("", "")
+    } else {
+        ("idempotency_key: Option<String>,", "idempotency_key: None,")
+    }
+}
+
+/// The client field holding the API version the requests are sent to, and its
+/// initialization to the configured version, for the APIs with a version in
+/// their paths.
+fn api_version_field(config: &Config) -> (&'static str, String) {
+    if config.api_version_prefix.is_empty() {
+        ("", String::new())
+    } else {
+        (
+            "api_version: String,",
+            format!("api_version: {:?}.to_string(),", config.api_version),
+        )
+    }
+}
+
+/// The client field tracking the fill of the leaky bucket of the API, shared
+/// by its clones, and its initialization, for the APIs reporting it.
+fn call_limit_field(config: &Config) -> (&'static str, &'static str) {
+    if config.call_limit_header.is_empty() {
+        ("", "")
+    } else {
+        (
+            "call_limit: CallLimit,",
+            "call_limit: CallLimit::default(),",
+        )
+    }
+}
+
+/// The type tracking the fill of the leaky bucket of the API, for the APIs
+/// reporting it.
+fn call_limit_type(config: &Config) -> String {
+    if config.call_limit_header.is_empty() {
+        String::new()
+    } else {
+        CALL_LIMIT_TEMPLATE.replace("{header}", &config.call_limit_header.to_lowercase())
+    }
+}
+
+/// Tracks the fill of a leaky bucket from the header the API reports it in.
+const CALL_LIMIT_TEMPLATE: &str = r#"
+/// The header the API reports the fill of its leaky bucket in, like `32/40`.
+const CALL_LIMIT_HEADER: &str = "{header}";
+
+/// The fill of the leaky bucket the API counts the requests in, shared by the
+/// clones of a client. The bucket is unknown until the first response reports
+/// it, and leaks its whole size in 20 seconds.
+#[derive(Clone, Debug, Default)]
+struct CallLimit(std::sync::Arc<std::sync::Mutex<Option<Bucket>>>);
+
+#[derive(Debug)]
+struct Bucket {
+    used: f64,
+    size: f64,
+    at: std::time::Instant,
+}
+
+impl Bucket {
+    /// Let the requests leak out of the bucket until `now`.
+    fn leak(&mut self, now: std::time::Instant) {
+        let leaked = now.saturating_duration_since(self.at).as_secs_f64() * self.size / 20.0;
+        self.used = (self.used - leaked).max(0.0);
+        self.at = now;
+    }
+}
+
+impl CallLimit {
+    /// Take a place in the bucket for a request, and tell how long to wait
+    /// before sending it so the bucket doesn't overflow. The waiting requests
+    /// hold their place too, so concurrent requests queue up.
+    fn reserve(&self) -> std::time::Duration {
+        let mut bucket = self.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let bucket = match bucket.as_mut() {
+            Some(bucket) => bucket,
+            None => return std::time::Duration::ZERO,
+        };
+
+        bucket.leak(std::time::Instant::now());
+        bucket.used += 1.0;
+        let over = bucket.used - bucket.size;
+        if over > 0.0 {
+            std::time::Duration::from_secs_f64(over * 20.0 / bucket.size)
+        } else {
+            std::time::Duration::ZERO
+        }
+    }
+
+    /// Take in the fill of the bucket a response reports. It only ever raises
+    /// the count, which holds the places of the requests still waiting. A
+    /// `429 Too Many Requests` means the bucket is full.
+    fn update(&self, status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap) {
+        let reported = headers
+            .get(CALL_LIMIT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| {
+                let (used, size) = v.split_once('/')?;
+                Some((used.trim().parse::<f64>().ok()?, size.trim().parse::<f64>().ok()?))
+            })
+            .filter(|(_, size)| *size > 0.0);
+
+        let now = std::time::Instant::now();
+        let mut bucket = self.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        match (bucket.as_mut(), reported) {
+            (Some(bucket), Some((used, size))) => {
+                bucket.leak(now);
+                bucket.used = bucket.used.max(used);
+                bucket.size = size;
+            }
+            (None, Some((used, size))) => *bucket = Some(Bucket { used, size, at: now }),
+            _ => {}
+        }
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            if let Some(bucket) = bucket.as_mut() {
+                bucket.leak(now);
+                bucket.used = bucket.used.max(bucket.size);
+            }
+        }
+    }
+}
+"#;
+
+/// The function joining the host and the path of a request. With an API
+/// version prefix, it swaps the version of the spec for the one of the client.
+fn url_function(config: &Config) -> String {
+    let prefix = config.api_version_prefix.as_str();
+    if prefix.is_empty() {
+        return r#"    pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
+        format!("{}{}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
+    }
+"#
+        .to_string();
+    }
+
+    format!(
+        r#"    pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
+        let host = self.get_host_override().or(host).unwrap_or(self.host.as_str());
+        match path.strip_prefix("{prefix}") {{
+            Some(rest) => {{
+                let rest = rest.split_once('/').map(|(_, rest)| rest).unwrap_or_default();
+                format!("{{}}{prefix}{{}}/{{}}", host, self.api_version, rest)
+            }}
+            None => format!("{{}}{{}}", host, path),
+        }}
+    }}
+"#
+    )
+}
+
+fn get_shared_functions(config: &Config, auth: &ClientAuth) -> String {
+    let add_post_header = config.auth.add_post_header.as_str();
+    let mut post_header_args = if !add_post_header.is_empty() {
         format!(
             r#"if method == reqwest::Method::POST {{
             req = req.header(
@@ -1026,30 +1718,213 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         String::new()
     };
 
-    let bearer = if proper_name == "Okta" {
-        "SSWS".to_string()
-    } else {
-        "Bearer".to_string()
-    };
+    let idempotency_key_header = config.idempotency_key_header.to_lowercase();
+    let idempotency_key_setter = if idempotency_key_header.is_empty() {
+        String::new()
+    } else {
+        // A new key per request, kept when the retry middleware sends it again.
+        post_header_args.push_str(&format!(
+            r#"
+if method == reqwest::Method::POST {{
+    let key = match &self.idempotency_key {{
+        Some(key) => key.to_string(),
+        None => uuid::Uuid::new_v4().to_string(),
+    }};
+    req = req.header(
+        reqwest::header::HeaderName::from_static("{idempotency_key_header}"),
+        reqwest::header::HeaderValue::from_str(&key)?,
+    );
+}}"#
+        ));
+
+        format!(
+            r#"
+/// A client sending `key` as the `{}` of its `POST` requests,
+/// instead of a new random key for each request. A request sent again with the
+/// same key isn't carried out twice, so use a key for a single request.
+pub fn with_idempotency_key<K>(&self, key: K) -> Self
+where
+    K: ToString,
+{{
+    let mut client = self.clone();
+    client.idempotency_key = Some(key.to_string());
+    client
+}}
+"#,
+            config.idempotency_key_header
+        )
+    };
+
+    // Some APIs answer failures with `200 OK` and `"ok": false`. The JSON
+    // responses are checked while parsing their body, the others when their
+    // body happens to be JSON.
+    let is_success = if config.ok_envelope {
+        "status.is_success() && ClientError::is_ok(&response_body)"
+    } else {
+        "status.is_success()"
+    };
+    let (parse_response, parse_error) = if config.ok_envelope {
+        (
+            "ClientError::parse_ok::<Out>(status, &headers, &response_body)",
+            "e.into()",
+        )
+    } else {
+        (
+            r#"if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
+            serde_json::from_str("null")
+        } else {
+            serde_json::from_slice::<Out>(&response_body)
+        }"#,
+            "ClientError::from(e).into()",
+        )
+    };
+
+    let url_and_auth = match auth.style {
+        AuthStyle::ApiKey => generate_credentials_url_and_auth(auth),
+        AuthStyle::ClientCredentials => get_token_url_and_auth(&config.auth, "self.token"),
+        _ => get_token_url_and_auth(&config.auth, "self.token.read().await.access_token"),
+    };
+
+    // How `request_raw` sends the request it built. The call limit holds the
+    // request until the bucket has room, under the `Retry-After` handling. With
+    // the `Retry-After` handling, the HTTP client hands the `429 Too Many
+    // Requests` responses back as errors, for `execute_once` to take back.
+    let execute_once = if config.retry_after {
+        "self.execute_once(req).await?"
+    } else {
+        "self.client.execute(req).await.map_err(ClientError::from)?"
+    };
+    let (client_execute, call_limit) = if config.call_limit_header.is_empty() {
+        (execute_once, String::new())
+    } else {
+        (
+            "self.execute_with_call_limit(req).await?",
+            CALL_LIMIT_FUNCTION.replace(
+                "self.client.execute(req).await.map_err(ClientError::from)?",
+                execute_once,
+            ),
+        )
+    };
+    let (execute, send, send_response, retry_after) = if config.retry_after {
+        (
+            "self.execute_with_retry_after(req).await?",
+            "let req = req.build().map_err(ClientError::from)?;\n    Ok(self.execute_with_retry_after(req).await?)",
+            "self.execute_with_retry_after(req.build().map_err(ClientError::from)?).await?",
+            RETRY_AFTER_TEMPLATE.replace(
+                "self.client.execute(req).await.map_err(ClientError::from)?",
+                client_execute,
+            ),
+        )
+    } else if !call_limit.is_empty() {
+        (
+            client_execute,
+            "let req = req.build().map_err(ClientError::from)?;\n    Ok(self.execute_with_call_limit(req).await?)",
+            "self.execute_with_call_limit(req.build().map_err(ClientError::from)?).await?",
+            String::new(),
+        )
+    } else {
+        (
+            client_execute,
+            "Ok(req.send().await.map_err(ClientError::from)?)",
+            "req.send().await.map_err(ClientError::from)?",
+            String::new(),
+        )
+    };
+
+    // Add auto refresh functionality to clients that support it
+    let raw_request = if auth.refresh() {
+        get_shared_raw_functions_with_refresh(&url_and_auth, &post_header_args, execute)
+    } else {
+        get_shared_raw_functions_without_refresh(&url_and_auth, &post_header_args, send)
+    };
+
+    format!(
+        r#"
+{}
+
+/// Send the requests with this HTTP client, like one built with a
+/// `ClientBuilder`.
+pub fn with_http_client(&mut self, client: reqwest_middleware::ClientWithMiddleware) -> &mut Self {{
+    self.client = client;
+    self
+}}
+{idempotency_key_setter}{retry_after}{call_limit}
+/// Cache the responses to `GET` requests, and revalidate them with their `ETag`
+/// and `Last-Modified` headers.
+#[cfg(feature = "httpcache")]
+pub fn with_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) -> &mut Self {{
+    self.http_cache = http_cache;
+    self
+}}
+
+/// Send the request and read the whole response. With the `httpcache` feature,
+/// a `GET` is answered from the cache when the server says it didn't change.
+async fn request_body(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    message: Message,
+) -> Result<(reqwest::StatusCode, reqwest::header::HeaderMap, bytes::Bytes)> {{
+    #[cfg(feature = "httpcache")]
+    if method == reqwest::Method::GET && message.body.is_none() {{
+        return self.request_cached(uri, message).await;
+    }}
+
+    let response = self.request_raw(method, uri, message, reqwest::header::HeaderMap::new()).await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
+
+    let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+    Ok((status, headers, response_body))
+}}
+
+#[cfg(feature = "httpcache")]
+async fn request_cached(
+    &self,
+    uri: &str,
+    message: Message,
+) -> Result<(reqwest::StatusCode, reqwest::header::HeaderMap, bytes::Bytes)> {{
+    let cached = crate::http_cache::lookup(&self.http_cache, uri).await;
+    let conditional_headers = cached
+        .as_ref()
+        .map(crate::http_cache::CachedResponse::conditional_headers)
+        .unwrap_or_default();
+    let content_type = message.content_type.clone();
+
+    let mut response = self.request_raw(reqwest::Method::GET, uri, message, conditional_headers).await?;
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {{
+        match cached {{
+            Some(cached) => {{
+                let mut headers = response.headers().clone();
+                // The other pages are the ones of the cached response.
+                if let Some(link) = cached.link.as_deref().and_then(|l| l.parse().ok()) {{
+                    headers.entry(reqwest::header::LINK).or_insert(link);
+                }}
+                return Ok((reqwest::StatusCode::OK, headers, cached.body));
+            }}
+            // Nothing is cached to answer a 304 with, so ask for the whole response.
+            None => {{
+                log::debug!("received a 304 without a cached response, requesting it again");
+                let message = Message {{ body: None, content_type }};
+                response = self.request_raw(reqwest::Method::GET, uri, message, reqwest::header::HeaderMap::new()).await?;
+            }}
+        }}
+    }}
+
+    let status = response.status();
+    let headers = response.headers().clone();
 
-    // Add auto refresh functionality to clients that support it
-    let raw_request = if proper_name.starts_with("Google")
-        || proper_name == "DocuSign"
-        || proper_name == "Gusto"
-        || proper_name == "MailChimp"
-        || proper_name == "Ramp"
-        || proper_name == "Shopify"
-        || proper_name == "Slack"
-        || proper_name == "Zoom"
-    {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
-    } else {
-        get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
-    };
+    let response_body = response.bytes().await.map_err(ClientError::from)?;
 
-    format!(
-        r#"
-{}
+    if status.is_success() {{
+        crate::http_cache::store(&self.http_cache, uri, &headers, &response_body).await;
+    }}
+
+    Ok((status, headers, response_body))
+}}
 
 async fn request<Out>(
     &self,
@@ -1060,32 +1935,14 @@ async fn request<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let response = self.request_raw(method, uri, message).await?;
-
-    let status = response.status();
-
-    let response_body = response.bytes().await?;
+    let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
-        }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+        let parsed_response = {parse_response};
+        parsed_response.map_err(|e| {parse_error})
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body).into())
     }}
 }}
 
@@ -1098,46 +1955,30 @@ async fn request_with_links<Out>(
 where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let response = self.request_raw(method, uri, message).await?;
+    let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
-    let status = response.status();
-    let link = response
-        .headers()
+    let link = headers
         .get(http::header::LINK)
         .and_then(|l| l.to_str().ok())
         .and_then(|l| parse_link_header::parse(l).ok())
         .as_ref()
         .and_then(crate::utils::next_link);
 
-    let response_body = response.bytes().await?;
-
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
 
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
-        }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map(|out| (link, out)).map_err(Error::from)
+        let parsed_response = {parse_response};
+        parsed_response.map(|out| (link, out)).map_err(|e| {parse_error})
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body).into())
     }}
 }}
 
 /* TODO: make this more DRY */
 #[allow(dead_code)]
-async fn post_form<Out>(
+async fn request_form<Out>(
     &self,
+    method: reqwest::Method,
     uri: &str,
     form: reqwest::multipart::Form,
 ) -> Result<Out>
@@ -1148,7 +1989,7 @@ async fn post_form<Out>(
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(http::Method::POST, url);
+    let mut req = instance.client.request(method, url);
 
     // Set the default headers.
     req = req.header(
@@ -1156,19 +1997,20 @@ async fn post_form<Out>(
         reqwest::header::HeaderValue::from_static("application/json"),
     );
 
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
     }}
 
     req = req.multipart(form);
 
-    let response = req.send().await?;
+    let response = {send_response};
 
     let status = response.status();
+    let headers = response.headers().clone();
 
-    let response_body = response.bytes().await?;
+    let response_body = response.bytes().await.map_err(ClientError::from)?;
 
-    if status.is_success() {{
+    if {is_success} {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")
@@ -1178,19 +2020,9 @@ async fn post_form<Out>(
         }} else {{
             serde_json::from_slice::<Out>(&response_body)
         }};
-        parsed_response.map_err(Error::from)
+        parsed_response.map_err(|e| ClientError::from(e).into())
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body).into())
     }}
 }}
 
@@ -1217,17 +2049,18 @@ async fn request_with_accept_mime<Out>(
         reqwest::header::HeaderValue::from_str(accept_mime_type)?,
     );
 
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
     }}
 
-    let response = req.send().await?;
+    let response = {send_response};
 
     let status = response.status();
+    let headers = response.headers().clone();
 
-    let response_body = response.bytes().await?;
+    let response_body = response.bytes().await.map_err(ClientError::from)?;
 
-    if status.is_success() {{
+    if {is_success} {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")
@@ -1237,19 +2070,46 @@ async fn request_with_accept_mime<Out>(
         }} else {{
             serde_json::from_slice::<Out>(&response_body)
         }};
-        parsed_response.map_err(Error::from)
+        parsed_response.map_err(|e| ClientError::from(e).into())
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        Err(ClientError::from_response(status, headers, &response_body).into())
+    }}
+}}
+
+/* TODO: make this more DRY */
+#[allow(dead_code)]
+async fn download(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    accept_mime_type: &str,
+) -> Result<crate::utils::Download> {{
+    let (url, auth) = self.url_and_auth(uri).await?;
+
+    let instance = <&Client>::clone(&self);
 
-        Err(error)
+    let mut req = instance.client.request(method, url);
+
+    // Set the default headers.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+    );
+
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
+    }}
+
+    let response = {send_response};
+
+    let status = response.status();
+
+    if status.is_success() {{
+        Ok(crate::utils::Download::new(response))
+    }} else {{
+        let headers = response.headers().clone();
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+        Err(ClientError::from_response(status, headers, &response_body).into())
     }}
 }}
 
@@ -1290,8 +2150,8 @@ async fn request_with_mime<Out>(
         reqwest::header::HeaderValue::from_bytes(format!("{{}}", content.len()).as_bytes()).unwrap(),
     );
 
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
     }}
 
     if content.len() > 1 {{
@@ -1300,32 +2160,19 @@ async fn request_with_mime<Out>(
         req = req.body(b);
     }}
 
-    let response = req.send().await?;
+    let response = {send_response};
 
     let status = response.status();
+    let headers = response.headers().clone();
 
-    let response_body = response.bytes().await?;
+    let response_body = response.bytes().await.map_err(ClientError::from)?;
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
-        }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+        let parsed_response = {parse_response};
+        parsed_response.map_err(|e| {parse_error})
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body).into())
     }}
 }}
 
@@ -1466,23 +2313,110 @@ where
     )
 }
 
-fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_token_url_and_auth(auth: &crate::config::Auth, token: &str) -> String {
+    // The token goes as is in its own header, or after the scheme in `Authorization`.
+    let (header, value) = if auth.header.is_empty() {
+        (
+            "http::header::AUTHORIZATION".to_string(),
+            format!(r#"format!("{} {{}}", {})"#, auth.scheme, token),
+        )
+    } else {
+        (
+            format!(
+                r#"http::header::HeaderName::from_static("{}")"#,
+                auth.header.to_lowercase()
+            ),
+            format!("{}.clone()", token),
+        )
+    };
+
     format!(
         r#"
 async fn url_and_auth(
     &self,
     uri: &str,
-) -> Result<(reqwest::Url, Option<String>)> {{
+) -> Result<(reqwest::Url, Option<(http::header::HeaderName, String)>)> {{
     let parsed_url = uri.parse::<reqwest::Url>();
-    let auth = format!("{} {{}}", self.token);
-    parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
-}}
+
+    let auth = {value};
+    parsed_url.map(|u| (u, Some(({header}, auth)))).map_err(|e| ClientError::from(e).into())
+}}"#
+    )
+}
+
+/// Sends a request again after the `Retry-After` of a `429 Too Many Requests`.
+const RETRY_AFTER_TEMPLATE: &str = r#"
+/// Send a request, and send it again once the `Retry-After` of a `429 Too Many
+/// Requests` response has passed, waiting up to a minute each time. This is
+/// done up to 3 times, and not for the requests whose body can't be sent again.
+async fn execute_with_retry_after(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+    let mut retry = req.try_clone();
+    let mut resp = self.client.execute(req).await.map_err(ClientError::from)?;
+
+    for _ in 0..3 {
+        let wait = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<f64>().ok());
+        match (retry.take(), wait) {
+            (Some(req), Some(secs)) if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS && (0.0..=60.0).contains(&secs) => {
+                log::debug!("rate limited, sending the request again in {} seconds", secs);
+                tokio::time::sleep(std::time::Duration::from_secs_f64(secs)).await;
+                retry = req.try_clone();
+                resp = self.client.execute(req).await.map_err(ClientError::from)?;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(resp)
+}
+
+/// Send a request once, taking back the `429 Too Many Requests` response the
+/// HTTP client handed back as an error, past its retry middleware.
+async fn execute_once(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+    match self.client.execute(req).await {
+        Err(reqwest_middleware::Error::Middleware(e)) => match e.downcast::<RateLimitedResponse>() {
+            Ok(RateLimitedResponse(resp)) => Ok(resp),
+            Err(e) => Err(ClientError::from(reqwest_middleware::Error::Middleware(e)).into()),
+        },
+        resp => Ok(resp.map_err(ClientError::from)?),
+    }
+}
+"#;
+
+/// Holds a request until the leaky bucket of the API has room for it.
+const CALL_LIMIT_FUNCTION: &str = r#"
+/// Send a request once the leaky bucket of the API has room for it, and take in
+/// the fill the response reports.
+async fn execute_with_call_limit(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+    let wait = self.call_limit.reserve();
+    if !wait.is_zero() {
+        log::debug!("the call limit is near, sending the request in {:?}", wait);
+        tokio::time::sleep(wait).await;
+    }
+
+    let resp = self.client.execute(req).await.map_err(ClientError::from)?;
+    self.call_limit.update(resp.status(), resp.headers());
+    Ok(resp)
+}
+"#;
+
+fn get_shared_raw_functions_without_refresh(
+    url_and_auth: &str,
+    post_header_args: &str,
+    send: &str,
+) -> String {
+    format!(
+        r#"{}
 
 async fn request_raw(
     &self,
     method: reqwest::Method,
     uri: &str,
     message: Message,
+    headers: reqwest::header::HeaderMap,
 ) -> Result<reqwest::Response>
 {{
     let (url, auth) = self.url_and_auth(uri).await?;
@@ -1493,6 +2427,7 @@ async fn request_raw(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_static("application/json"),
     );
+    req = req.headers(headers);
 
     if let Some(content_type) = &message.content_type {{
         req = req.header(
@@ -1507,37 +2442,33 @@ async fn request_raw(
     }}
 
     {}
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
     }}
     if let Some(body) = message.body {{
         req = req.body(body);
     }}
-    Ok(req.send().await?)
+    {send}
 }}
 "#,
-        bearer, post_header_args
+        url_and_auth, post_header_args
     )
 }
 
-fn get_shared_raw_functions_with_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_shared_raw_functions_with_refresh(
+    url_and_auth: &str,
+    post_header_args: &str,
+    execute: &str,
+) -> String {
     format!(
-        r#"
-async fn url_and_auth(
-    &self,
-    uri: &str,
-) -> Result<(reqwest::Url, Option<String>)> {{
-    let parsed_url = uri.parse::<reqwest::Url>();
-
-    let auth = format!("{} {{}}", self.token.read().await.access_token);
-    parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
-}}
+        r#"{}
 
 async fn make_request(
     &self,
     method: &reqwest::Method,
     uri: &str,
     message: Message,
+    headers: reqwest::header::HeaderMap,
 ) -> Result<reqwest::Request> {{
     let (url, auth) = self.url_and_auth(uri).await?;
 
@@ -1550,6 +2481,7 @@ async fn make_request(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_static("application/json"),
     );
+    req = req.headers(headers);
 
     if let Some(content_type) = &message.content_type {{
         req = req.header(
@@ -1565,15 +2497,15 @@ async fn make_request(
 
     {}
 
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    if let Some((name, value)) = auth {{
+        req = req.header(name, &*value);
     }}
 
     if let Some(body) = message.body {{
         req = req.body(body);
     }}
 
-    Ok(req.build()?)
+    Ok(req.build().map_err(ClientError::from)?)
 }}
 
 async fn request_raw(
@@ -1581,7 +2513,9 @@ async fn request_raw(
     method: reqwest::Method,
     uri: &str,
     message: Message,
+    headers: reqwest::header::HeaderMap,
 ) -> Result<reqwest::Response> {{
+    let mut refreshed = false;
     if self.auto_refresh {{
         let expired = self.is_expired().await;
 
@@ -1589,7 +2523,11 @@ async fn request_raw(
             // We have a known expired token, we know we need to perform a refresh prior to
             // attempting to make a request
             Some(true) => {{
-                self.refresh_access_token().await?;
+                let stale = self.token.read().await.access_token.clone();
+                self.refresh_stale_access_token(&stale)
+                    .await
+                    .map_err(ClientError::AuthRefresh)?;
+                refreshed = true;
             }}
 
             // We have a (theoretically) known good token available. We make an optimistic
@@ -1609,16 +2547,34 @@ async fn request_raw(
         }}
     }}
 
-    let req = self.make_request(&method, uri, message).await?;
-    let resp = self.client.execute(req).await?;
-
-    Ok(resp)
+    let sent_token = self.token.read().await.access_token.clone();
+    let req = self.make_request(&method, uri, message, headers).await?;
+    let retry = req.try_clone();
+    let resp = {execute};
+
+    // The token was revoked or expired early. Refresh it and try once more,
+    // unless it was just refreshed or the body can't be sent again.
+    match retry {{
+        Some(mut req) if resp.status() == reqwest::StatusCode::UNAUTHORIZED && self.auto_refresh && !refreshed => {{
+            log::debug!("request was unauthorized, refreshing the access token");
+            self.refresh_stale_access_token(&sent_token)
+                .await
+                .map_err(ClientError::AuthRefresh)?;
+
+            let (_, auth) = self.url_and_auth(uri).await?;
+            if let Some((name, value)) = auth {{
+                req.headers_mut().insert(name, reqwest::header::HeaderValue::from_str(&value)?);
+            }}
+            Ok({execute})
+        }}
+        _ => Ok(resp),
+    }}
 }}"#,
-        bearer, post_header_args
+        url_and_auth, post_header_args
     )
 }
 
-fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
+fn user_consent_url_template(consent_pattern: &str) -> String {
     format!(
         r#"
 /// Return a user consent url with an optional set of scopes.
@@ -1637,16 +2593,39 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 
     // Add the scopes.
     format!("{{}}&scope={{}}", url, scopes.join(" "))
-}}
+}}"#,
+        consent_pattern
+    )
+}
 
+fn get_token_auth_template(refresh_hook: &str) -> String {
+    format!(
+        r#"
 /// Refresh an access token from a refresh token. Client must have a refresh token
 /// for this to work.
 pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
+    let _refreshing = self.refreshing.lock().await;
+    self.fetch_refreshed_access_token().await
+}}
+
+/// Refresh the access token, unless another request replaced `stale` while
+/// this one waited for its turn. Refreshes run one at a time, so a refresh
+/// token rotated by the provider is only spent once.
+async fn refresh_stale_access_token(&self, stale: &str) -> Result<()> {{
+    let _refreshing = self.refreshing.lock().await;
+    if self.token.read().await.access_token != stale {{
+        return Ok(());
+    }}
+    self.fetch_refreshed_access_token().await?;
+    Ok(())
+}}
+
+async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {{{}
     let response = {{
         let refresh_token = &self.token.read().await.refresh_token;
 
         if refresh_token.is_empty() {{
-            anyhow!("refresh token cannot be empty");
+            anyhow::bail!("refresh token cannot be empty");
         }}
 
         let mut headers = reqwest::header::HeaderMap::new();
@@ -1662,9 +2641,9 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
             ("client_secret", &self.client_secret),
             ("redirect_uri", &self.redirect_uri),
         ];
-        let client = reqwest::Client::new();
+        let client = &self.client;
         client
-            .post(TOKEN_ENDPOINT)
+            .post(&self.token_endpoint)
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -1672,16 +2651,23 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
             .await?
     }};
 
+    let status = response.status();
+    if !status.is_success() {{
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        return Err(ClientError::from_response(status, headers, &body).into());
+    }}
+
     // Unwrap the response.
-    let t: AccessToken = response.json().await?;
+    let mut t: AccessToken = response.json().await?;
 
-    let refresh_token = self.token.read().await.refresh_token.clone();
+    // Providers that rotate refresh tokens send a new one, which replaces the
+    // one just used. The others keep accepting the current one.
+    if t.refresh_token.is_empty() {{
+        t.refresh_token = self.token.read().await.refresh_token.clone();
+    }}
 
-    *self.token.write().await = InnerToken {{
-        access_token: t.access_token.clone(),
-        refresh_token,
-        expires_at: Self::compute_expires_at(t.expires_in),
-    }};
+    self.save_token(&t).await?;
 
     Ok(t)
 }}
@@ -1703,30 +2689,73 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         ("redirect_uri", &self.redirect_uri),
         ("state", state),
     ];
-    let client = reqwest::Client::new();
+    let client = &self.client;
     let resp = client
-        .post(TOKEN_ENDPOINT)
+        .post(&self.token_endpoint)
         .headers(headers)
         .form(&params)
         .basic_auth(&self.client_id, Some(&self.client_secret))
         .send()
         .await?;
 
+    let status = resp.status();
+    if !status.is_success() {{
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+        return Err(ClientError::from_response(status, headers, &body).into());
+    }}
+
     // Unwrap the response.
     let t: AccessToken = resp.json().await?;
 
-    *self.token.write().await = InnerToken {{
-        access_token: t.access_token.clone(),
-        refresh_token: t.refresh_token.clone(),
-        expires_at: Self::compute_expires_at(t.expires_in),
-    }};
+    self.save_token(&t).await?;
 
     Ok(t)
 }}"#,
-        consent_pattern.as_ref()
+        refresh_hook
     )
 }
 
+const CLIENT_CREDENTIALS_TOKEN_TEMPLATE: &str = r#"
+
+/// Get an access token for the client itself, rather than a user, with the
+/// client credentials grant.
+pub async fn get_access_token_with_client_credentials(&mut self) -> Result<AccessToken> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.append(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+
+    let params = [
+        ("grant_type", "client_credentials"),
+        ("client_id", &self.client_id),
+        ("client_secret", &self.client_secret),
+    ];
+    let client = &self.client;
+    let resp = client
+        .post(&self.token_endpoint)
+        .headers(headers)
+        .form(&params)
+        .basic_auth(&self.client_id, Some(&self.client_secret))
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+        return Err(ClientError::from_response(status, headers, &body).into());
+    }
+
+    // Unwrap the response.
+    let t: AccessToken = resp.json().await?;
+
+    self.save_token(&t).await?;
+
+    Ok(t)
+}"#;
+
 const CLIENT_AUTH_TEMPLATE: &str = r#"
 /// Get an access token from the code returned by the URL paramter sent to the
 /// redirect URL.
@@ -1742,7 +2771,7 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         ("client_id", &self.client_id),
         ("client_secret", &self.client_secret),
     ];
-    let client = reqwest::Client::new();
+    let client = &self.client;
     let resp = client
         .post(TOKEN_ENDPOINT)
         .headers(headers)
@@ -1751,6 +2780,13 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         .send()
         .await?;
 
+    let status = resp.status();
+    if !status.is_success() {
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?;
+        return Err(ClientError::from_response(status, headers, &body).into());
+    }
+
     // Unwrap the response.
     let t: AccessToken = resp.json().await?;
 
@@ -1760,11 +2796,12 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
 }"#;
 
 pub fn generate_client_generic_client_credentials(
-    proper_name: &str,
-    token_endpoint: &str,
-    add_post_header: &str,
+    config: &Config,
+    auth: &ClientAuth,
     servers: &GeneratedServers,
 ) -> String {
+    let proper_name = config.proper_name.as_str();
+
     let server_block = if servers.count > 0 {
         servers.output.as_deref().unwrap()
     } else {
@@ -1773,6 +2810,11 @@ pub fn generate_client_generic_client_credentials(
 
     let server_arg = servers.server_arg();
     let server_to_host = servers.host_from_server();
+    let (idempotency_key_field, idempotency_key_init) = idempotency_key_field(config);
+    let (api_version_field, api_version_init) = api_version_field(config);
+    let (call_limit_field, call_limit_init) = call_limit_field(config);
+    let call_limit_type = call_limit_type(config);
+    let url_fn = url_function(config);
 
     format!(
         r#"use std::env;
@@ -1789,11 +2831,18 @@ pub struct Client {{
     token: String,
     client_id: String,
     client_secret: String,
+    {idempotency_key_field}
+    {api_version_field}
+    {call_limit_field}
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
 {}
 
+{call_limit_type}
+
 impl Client {{
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -1809,35 +2858,26 @@ impl Client {{
         K: ToString,
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                {server_to_host}
-
-                Client {{
-                    host,
-                    host_override: None,
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }}
-            }}
+        let client = match ClientBuilder::new().build() {{
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
+        }};
+
+        {server_to_host}
+
+        Client {{
+            host,
+            host_override: None,
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            token: token.to_string(),
+            {idempotency_key_init}
+            {api_version_init}
+            {call_limit_init}
+
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
         }}
     }}
     
@@ -1861,9 +2901,7 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
-    pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
-        format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
-    }}
+{url_fn}
 
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
@@ -1888,14 +2926,14 @@ impl Client {{
     {}
 
     {}"#,
-        token_endpoint.trim_start_matches("https://"),
+        auth.token_endpoint,
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         CLIENT_AUTH_TEMPLATE,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(config, auth)
     )
 }
 
@@ -2083,3 +3121,256 @@ impl {server_name} {{
 
     server_struct
 }
+
+/// Generate the `ClientError` type returned, inside an `anyhow::Error`, by every
+/// request the client makes. `error_schemas` maps a status code, or `None` for
+/// the `default` response, to the type its response body is decoded into.
+/// With `ok_envelope`, the `2xx` responses saying `"ok": false` are errors too.
+pub fn generate_client_error(
+    error_schemas: &std::collections::BTreeMap<Option<u16>, String>,
+    ok_envelope: bool,
+) -> String {
+    // Group the status codes by the type they decode into, one variant per type.
+    let mut by_type: std::collections::BTreeMap<&str, Vec<Option<u16>>> = Default::default();
+    for (code, rt) in error_schemas {
+        by_type.entry(rt.as_str()).or_default().push(*code);
+    }
+
+    let mut variants = String::new();
+    let mut status_arms = String::new();
+    let mut decode_arms = String::new();
+    let mut decode_default = String::new();
+    for (rt, codes) in by_type {
+        let variant = rt.rsplit("::").next().unwrap_or(rt);
+        variants.push_str(&format!(
+            r#"    /// The API responded with a non-success status code and a `{variant}` body.
+    #[error("code: {{status}}, error: {{error:?}}")]
+    {variant} {{
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: {rt},
+    }},
+"#
+        ));
+        status_arms.push_str(&format!("| ClientError::{} {{ status, .. }}\n", variant));
+
+        let codes = codes
+            .iter()
+            .flatten()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        if !codes.is_empty() {
+            decode_arms.push_str(&format!(
+                r#"            {codes} => {{
+                if let Ok(error) = serde_json::from_slice::<{rt}>(body) {{
+                    return ClientError::{variant} {{ status, headers, error }};
+                }}
+            }}
+"#,
+                codes = codes.join(" | "),
+            ));
+        }
+    }
+
+    // Bodies of the `default` response are tried for any other status code.
+    if let Some(rt) = error_schemas.get(&None) {
+        let variant = rt.rsplit("::").next().unwrap_or(rt);
+        decode_default = format!(
+            r#"
+        if let Ok(error) = serde_json::from_slice::<{rt}>(body) {{
+            return ClientError::{variant} {{ status, headers, error }};
+        }}
+"#
+        );
+    }
+
+    let mut decode_ok = String::new();
+    let mut is_ok = String::new();
+    if ok_envelope {
+        variants.push_str(
+            r#"    /// The API responded with `"ok": false`.
+    #[error("code: {status}, error: {error}")]
+    NotOk {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// What went wrong, like `channel_not_found`.
+        error: String,
+        /// What the request should change, like `missing_charset`.
+        warning: Option<String>,
+        /// The details of the error, from `response_metadata.messages`.
+        messages: Vec<String>,
+    },
+"#,
+        );
+        status_arms.push_str("| ClientError::NotOk { status, .. }\n");
+        decode_ok = r#"
+        if let Ok(envelope) = serde_json::from_slice::<serde_json::Value>(body) {
+            if envelope["ok"] == false {
+                let string = |v: &serde_json::Value| v.as_str().map(str::to_string);
+                return ClientError::NotOk {
+                    status,
+                    headers,
+                    error: string(&envelope["error"]).unwrap_or_default(),
+                    warning: string(&envelope["warning"]),
+                    messages: envelope["response_metadata"]["messages"]
+                        .as_array()
+                        .map(|m| m.iter().filter_map(string).collect())
+                        .unwrap_or_default(),
+                };
+            }
+        }
+"#
+        .to_string();
+        is_ok = r#"
+
+    /// Whether the body of a response doesn't say `"ok": false`, which the API
+    /// answers some failures with despite a `2xx` status code.
+    pub(crate) fn is_ok(body: &[u8]) -> bool {
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(envelope) => envelope["ok"] != false,
+            Err(_) => true,
+        }
+    }
+
+    /// Parse the body of a `2xx` response, unless it says `"ok": false`, which
+    /// the API answers some failures with. The body is parsed once into JSON,
+    /// which is then converted into `Out`.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn parse_ok<Out>(
+        status: reqwest::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Result<Out, ClientError>
+    where
+        Out: serde::de::DeserializeOwned + 'static,
+    {
+        let unit = status == reqwest::StatusCode::NO_CONTENT
+            || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>();
+        let envelope = match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(envelope) => envelope,
+            // Nothing is read from the body of a unit response.
+            Err(_) if unit => serde_json::Value::Null,
+            Err(e) => return Err(e.into()),
+        };
+        if envelope["ok"] == false {
+            return Err(ClientError::from_response(status, headers.clone(), body));
+        }
+
+        if unit {
+            Ok(serde_json::from_value(serde_json::Value::Null)?)
+        } else {
+            Ok(serde_json::from_value(envelope)?)
+        }
+    }"#
+        .to_string();
+    }
+
+    let decode = if decode_arms.is_empty() {
+        decode_default
+    } else {
+        format!(
+            r#"
+        match status.as_u16() {{
+{}            _ => (),
+        }}
+{}"#,
+            decode_arms, decode_default
+        )
+    };
+
+    format!(
+        r#"/// Errors returned by the client.
+///
+/// Every method returns an `anyhow::Result`. When a request fails because of the
+/// API or the transport, the error holds a `ClientError`, which can be recovered
+/// with `err.downcast_ref::<ClientError>()` and matched on.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {{
+    /// The API responded with a non-success status code.
+    #[error("code: {{status}}, error: {{body:?}}")]
+    HttpError {{
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: String,
+    }},
+{variants}    /// The API refused the request because a rate limit was exceeded.
+    #[error("code: {{status}}, rate limit exceeded")]
+    RateLimited {{
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// When the rate limit resets, if the response said so.
+        reset: Option<std::time::SystemTime>,
+    }},
+    /// Sending the request or reading the response failed.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    /// A middleware failed while sending the request.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// The response body could not be deserialized.
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    /// The request URL could not be parsed.
+    #[error(transparent)]
+    UrlParse(#[from] url::ParseError),
+    /// Refreshing the credentials used to authenticate the request failed.
+    #[error("refreshing credentials failed: {{0}}")]
+    AuthRefresh(anyhow::Error),
+}}
+
+impl ClientError {{
+    /// The status code the API responded with, if it responded.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {{
+        match self {{
+            ClientError::HttpError {{ status, .. }}
+            {status_arms}| ClientError::RateLimited {{ status, .. }} => Some(*status),
+            ClientError::Reqwest(e) => e.status(),
+            _ => None,
+        }}
+    }}
+
+    /// Build the error for a response with a non-success status code.
+    pub(crate) fn from_response(
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {{
+        let header = |name: &str| {{
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        }};
+
+        let retry_after = header("retry-after");
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || header("x-ratelimit-remaining") == Some(0)
+            || (status == reqwest::StatusCode::FORBIDDEN && retry_after.is_some())
+        {{
+            let now = std::time::SystemTime::now();
+            let reset = match (retry_after, header("x-ratelimit-reset")) {{
+                (Some(secs), _) => Some(now + std::time::Duration::from_secs(secs)),
+                // Some APIs send a unix timestamp, others the number of seconds left.
+                (None, Some(reset)) if reset > 1_000_000_000 => {{
+                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+                }}
+                (None, Some(secs)) => Some(now + std::time::Duration::from_secs(secs)),
+                (None, None) => None,
+            }};
+
+            return ClientError::RateLimited {{
+                status,
+                headers,
+                reset,
+            }};
+        }}
+{decode}{decode_ok}
+        ClientError::HttpError {{
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        }}
+    }}{is_ok}
+}}"#
+    )
+}
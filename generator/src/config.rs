@@ -0,0 +1,394 @@
+/*!
+ * The per-crate generator configuration, read from the `generator.toml` next
+ * to each generated crate.
+ *
+ * Everything an API does differently from the others lives here, so the
+ * generator itself doesn't need to know which API it is generating.
+ */
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The OpenAPI definition document (JSON | YAML).
+    pub spec: String,
+    /// The generated Rust crate directory.
+    pub output: String,
+    /// The target Rust crate name.
+    pub name: String,
+    /// The target Rust crate version.
+    pub version: String,
+    /// The target Rust crate description.
+    pub description: String,
+    /// The name of the API, as it is written in the docs.
+    pub proper_name: String,
+    /// A link to the spec.
+    pub spec_link: String,
+    /// The default host.
+    pub host: String,
+    /// A variable the host is made of instead, like Shopify's `shop` in
+    /// `{shop}.myshopify.com`. The client is then created with it, and the
+    /// module of the crate named after it points the client at it with a
+    /// `with_<variable>` function, which checks it. The `token_endpoint` is a
+    /// path on that host, and there is no `user_consent_url`.
+    #[serde(default)]
+    pub host_variable: String,
+    /// Set optional query parameters through a per-operation params builder.
+    #[serde(default)]
+    pub optional_params: bool,
+    /// Skip the tags of the spec that have no operations.
+    #[serde(default)]
+    pub skip_empty_tags: bool,
+    /// The header carrying the key that makes a `POST` request safe to send
+    /// again, like Stripe's `Idempotency-Key`.
+    #[serde(default)]
+    pub idempotency_key_header: String,
+    /// The API answers failures with `200 OK` and `"ok": false`, like Slack.
+    #[serde(default)]
+    pub ok_envelope: bool,
+    /// Wait out the `Retry-After` of `429 Too Many Requests` responses, and send
+    /// the request again.
+    #[serde(default)]
+    pub retry_after: bool,
+    /// The header the API reports the fill of its leaky bucket in, like
+    /// Shopify's `X-Shopify-Shop-Api-Call-Limit: 32/40`. The client then waits
+    /// before sending the requests that would overflow the bucket.
+    #[serde(default)]
+    pub call_limit_header: String,
+    /// The path prefix the API version follows, like Shopify's `/admin/api/`. The
+    /// client can then send the requests to another version than the one of the
+    /// spec.
+    #[serde(default)]
+    pub api_version_prefix: String,
+    /// The version of the API the client sends its requests to, unless another
+    /// is picked, instead of the version of each operation in the spec. Set
+    /// with an `api_version_prefix`.
+    #[serde(default)]
+    pub api_version: String,
+    /// Hand written modules added to the crate.
+    #[serde(default)]
+    pub modules: Vec<Module>,
+    /// Dependencies added to the generated `Cargo.toml`.
+    #[serde(default)]
+    pub dependencies: toml::value::Table,
+    #[serde(default)]
+    pub auth: Auth,
+    #[serde(default)]
+    pub pagination: Pagination,
+    #[serde(default)]
+    pub params: Params,
+    #[serde(default)]
+    pub types: Types,
+    #[serde(default)]
+    pub functions: Functions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Module {
+    pub name: String,
+    /// Only build the module with this feature enabled.
+    #[serde(default)]
+    pub feature: Option<String>,
+    /// A section about the module, in markdown, added to the docs of the crate.
+    #[serde(default)]
+    pub docs: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthStyle {
+    /// GitHub's own client, with app and installation tokens.
+    #[serde(rename = "github")]
+    GitHub,
+    /// A static API key.
+    ApiKey,
+    /// The OAuth client credentials flow.
+    ClientCredentials,
+    /// The OAuth authorization code flow.
+    Token,
+    /// The OAuth authorization code flow, with offline access and the
+    /// credentials read from Google's environment.
+    Google,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Auth {
+    /// How the client authenticates, when the spec's security schemes
+    /// don't say, or say it wrong.
+    #[serde(default)]
+    pub style: Option<AuthStyle>,
+    /// The scheme of the `Authorization` header.
+    #[serde(default = "default_scheme")]
+    pub scheme: String,
+    /// A header carrying the token as is, instead of the `Authorization`
+    /// header, like Shopify's `X-Shopify-Access-Token`.
+    #[serde(default)]
+    pub header: String,
+    /// Overrides the token URL of the spec's OAuth flows.
+    #[serde(default)]
+    pub token_endpoint: String,
+    /// Overrides the authorization URL of the spec's OAuth flows.
+    #[serde(default)]
+    pub user_consent_endpoint: String,
+    /// A header to add to post requests.
+    #[serde(default)]
+    pub add_post_header: String,
+}
+
+impl Default for Auth {
+    fn default() -> Self {
+        Auth {
+            style: Default::default(),
+            scheme: default_scheme(),
+            header: Default::default(),
+            token_endpoint: Default::default(),
+            user_consent_endpoint: Default::default(),
+            add_post_header: Default::default(),
+        }
+    }
+}
+
+fn default_scheme() -> String {
+    "Bearer".to_string()
+}
+
+/// How a response that is a page of items points at the next page.
+/// APIs without a style use the `Link` header.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaginationStyle {
+    /// `has_more`, and the id of the last item passed as `starting_after`.
+    Stripe,
+    /// `nextPageToken` passed back as `pageToken`.
+    Google,
+    /// The full URL of the next page in `page.next`.
+    Ramp,
+    /// Page numbers, from `page.currentPage` and `page.totalPages`.
+    #[serde(rename = "tripactions")]
+    TripActions,
+    /// `next_page_token` passed back as is.
+    Zoom,
+    /// `response_metadata.next_cursor` passed back as `cursor`.
+    Slack,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Pagination {
+    #[serde(default)]
+    pub style: Option<PaginationStyle>,
+    /// Parameters selecting a page, on top of the common ones.
+    #[serde(default)]
+    pub page_params: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Params {
+    /// Parameters left out of the functions, by their snake case name.
+    #[serde(default = "default_ignored_params")]
+    pub ignored: Vec<String>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            ignored: default_ignored_params(),
+        }
+    }
+}
+
+fn default_ignored_params() -> Vec<String> {
+    vec!["authorization".to_string()]
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Types {
+    /// Structs deriving `Default`, on top of the pages.
+    #[serde(default)]
+    pub default_structs: Vec<String>,
+    /// Derives added to a struct, by struct name.
+    #[serde(default)]
+    pub extra_derives: BTreeMap<String, Vec<String>>,
+    /// Render `anyOf` schemas like `oneOf` ones, boxing them since they are
+    /// often recursive.
+    #[serde(default)]
+    pub any_of_as_one_of: bool,
+    /// Types boxed when used as an optional field.
+    #[serde(default)]
+    pub boxed: Vec<String>,
+    /// Optional fields serialized as `null` when unset.
+    #[serde(default)]
+    pub nullable_fields: Vec<String>,
+    /// Make every boolean field optional, not just the ones of requests.
+    #[serde(default)]
+    pub optional_bools: bool,
+    /// The module in `utils` serializing date times, if the API doesn't
+    /// take RFC 3339.
+    #[serde(default)]
+    pub date_time_serializer: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Functions {
+    /// Use the snake case operation id as the function name, as is.
+    #[serde(default)]
+    pub raw_names: bool,
+    /// Trimmed from the start of function names.
+    #[serde(default)]
+    pub name_prefix: Option<String>,
+    /// Don't trim the trailing `s` of GETs that return a single item.
+    #[serde(default)]
+    pub keep_plural_gets: bool,
+    /// Fix up the plurals of tags and functions returning lists.
+    #[serde(default)]
+    pub make_plural: bool,
+    /// The extension holding the tags of operations without any.
+    #[serde(default)]
+    pub tags_extension: Option<String>,
+    /// Response types that are really empty.
+    #[serde(default)]
+    pub unit_responses: Vec<String>,
+    /// GETs without a documented response are file downloads.
+    #[serde(default)]
+    pub untyped_gets_are_downloads: bool,
+    /// Paths returning text in the format picked by their `accept` parameter.
+    #[serde(default)]
+    pub accept_overrides: Vec<String>,
+    /// The prefix of the operation ids of the copies of an operation for other
+    /// versions of the API, followed by the version, like Shopify's
+    /// `deprecated_202001_`. Only one copy of each operation is generated.
+    #[serde(default)]
+    pub version_prefix: Option<String>,
+    /// The types of the JSON bodies the spec leaves untyped, by operation id.
+    #[serde(default)]
+    pub request_types: BTreeMap<String, String>,
+    /// The types of the responses the spec leaves out, by operation id.
+    #[serde(default)]
+    pub response_types: BTreeMap<String, String>,
+}
+
+impl Config {
+    pub fn load<P: AsRef<Path>>(p: P) -> Result<Self> {
+        let p = p.as_ref();
+        let s = std::fs::read_to_string(p).with_context(|| format!("reading {}", p.display()))?;
+        toml::from_str(&s).with_context(|| format!("parsing {}", p.display()))
+    }
+
+    pub fn is_page_param(&self, s: &str) -> bool {
+        self.pagination.page_params.iter().any(|p| p == s)
+    }
+
+    pub fn is_ignored_param(&self, s: &str) -> bool {
+        self.params.ignored.iter().any(|p| p == s)
+    }
+
+    pub fn derives_default(&self, sn: &str) -> bool {
+        self.types.default_structs.iter().any(|s| s == sn)
+    }
+
+    /// The extra derives of a struct, each preceded by a comma.
+    pub fn extra_derives(&self, sn: &str) -> String {
+        self.types
+            .extra_derives
+            .get(sn)
+            .map(|d| d.iter().map(|d| format!(", {}", d)).collect())
+            .unwrap_or_default()
+    }
+
+    /// The `Cargo.toml` lines of the extra dependencies.
+    pub fn dependencies(&self) -> String {
+        self.dependencies
+            .iter()
+            .map(|(name, v)| format!("\n{} = {}", name, inline_value(v)))
+            .collect()
+    }
+}
+
+fn inline_value(v: &toml::Value) -> String {
+    match v {
+        toml::Value::Array(a) => format!(
+            "[{}]",
+            a.iter().map(inline_value).collect::<Vec<_>>().join(", ")
+        ),
+        toml::Value::Table(t) => format!(
+            "{{ {} }}",
+            t.iter()
+                .map(|(k, v)| format!("{} = {}", k, inline_value(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        v => v.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_configs_load() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut n = 0;
+        for entry in glob_configs(&root) {
+            let config = Config::load(&entry).unwrap();
+            assert!(
+                root.join(&config.output).join("generator.toml") == entry,
+                "{} has the wrong output",
+                entry.display()
+            );
+            n += 1;
+        }
+        assert!(n > 0);
+    }
+
+    fn glob_configs(root: &Path) -> Vec<std::path::PathBuf> {
+        let mut configs = Vec::new();
+        for dir in [root.to_path_buf(), root.join("google")] {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let p = entry.unwrap().path().join("generator.toml");
+                if p.exists() {
+                    configs.push(p);
+                }
+            }
+        }
+        configs
+    }
+
+    #[test]
+    fn dependencies_are_inline() {
+        let config: Config = toml::from_str(
+            r#"
+spec = "spec.json"
+output = "out"
+name = "out"
+version = "0.1.0"
+description = "Out."
+proper_name = "Out"
+spec_link = "https://example.com"
+host = "example.com"
+
+[dependencies]
+async-trait = "^0.1.51"
+uuid = { version = "1.1", features = ["serde", "v4"] }
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.dependencies(),
+            r#"
+async-trait = "^0.1.51"
+uuid = { version = "1.1", features = ["serde", "v4"] }"#
+        );
+        assert!(config.is_ignored_param("authorization"));
+        assert_eq!(config.auth.scheme, "Bearer");
+    }
+}
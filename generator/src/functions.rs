@@ -4,15 +4,20 @@ use anyhow::{bail, Result};
 use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
 
 use crate::{
-    clean_fn_name, clean_name, client::generate_servers, get_parameter_data, make_plural,
-    oid_to_object_name, path_to_operation_id, struct_name, template::parse, ExtractJsonMediaType,
-    ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace,
+    clean_fn_name, clean_name,
+    client::generate_servers,
+    config::{Config, PaginationStyle},
+    get_parameter_data, make_plural, oid_to_object_name, path_to_operation_id, struct_name,
+    template::{parse, Template},
+    ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace,
 };
 
 #[derive(Debug, Default)]
 pub struct FileOutput {
     pub head: String,
     pub impl_content: String,
+    /// The mocks of the functions, see `print_mock_fn`.
+    pub mock_content: String,
 }
 
 impl FileOutput {
@@ -23,6 +28,78 @@ impl FileOutput {
     fn add_content(&mut self, content: &str) {
         self.impl_content.push_str(content);
     }
+
+    fn add_mock(&mut self, mock: &str) {
+        self.mock_content.push_str(mock);
+    }
+}
+
+/// Keep one copy of each operation of the APIs with a copy per version, see
+/// `Functions::version_prefix`: the one without a version, else the one of the
+/// newest dated version, else the unstable one. The copy kept takes the
+/// operation id without the version.
+pub fn collapse_versions(api: &mut openapiv3::OpenAPI, prefix: &str) {
+    // The operation id without the version, and how new the version is.
+    let version_of = |op_id: &str| -> (String, u64) {
+        if let Some((version, base)) = op_id.strip_prefix(prefix).and_then(|s| s.split_once('_')) {
+            if version == "unstable" {
+                return (base.to_string(), 0);
+            }
+            if let Ok(date) = version.parse::<u64>() {
+                return (base.to_string(), date);
+            }
+        }
+        (op_id.to_string(), u64::MAX)
+    };
+
+    let mut newest: BTreeMap<String, u64> = BTreeMap::new();
+    for p in api.paths.paths.values_mut() {
+        if let openapiv3::ReferenceOr::Item(item) = p {
+            for o in operation_slots(item).into_iter().flatten() {
+                if let Some(op_id) = &o.operation_id {
+                    let (base, version) = version_of(op_id);
+                    let n = newest.entry(base).or_default();
+                    *n = (*n).max(version);
+                }
+            }
+        }
+    }
+
+    for p in api.paths.paths.values_mut() {
+        if let openapiv3::ReferenceOr::Item(item) = p {
+            for slot in operation_slots(item) {
+                match slot
+                    .as_ref()
+                    .and_then(|o| o.operation_id.as_deref())
+                    .map(version_of)
+                {
+                    Some((base, version)) if newest.get(&base) == Some(&version) => {
+                        slot.as_mut().unwrap().operation_id = Some(base);
+                    }
+                    Some(_) => *slot = None,
+                    None => {}
+                }
+            }
+        }
+    }
+
+    api.paths.paths.retain(|_, p| match p {
+        openapiv3::ReferenceOr::Item(item) => operation_slots(item).iter().any(|o| o.is_some()),
+        openapiv3::ReferenceOr::Reference { .. } => true,
+    });
+}
+
+fn operation_slots(item: &mut openapiv3::PathItem) -> [&mut Option<openapiv3::Operation>; 8] {
+    [
+        &mut item.get,
+        &mut item.put,
+        &mut item.post,
+        &mut item.delete,
+        &mut item.options,
+        &mut item.head,
+        &mut item.patch,
+        &mut item.trace,
+    ]
 }
 
 /*
@@ -30,7 +107,7 @@ impl FileOutput {
  */
 pub fn generate_files(
     api: &openapiv3::OpenAPI,
-    proper_name: &str,
+    config: &Config,
     ts: &mut TypeSpace,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
 ) -> Result<BTreeMap<String, FileOutput>> {
@@ -59,8 +136,12 @@ pub fn generate_files(
             // future but for now it seems fairly consistent.
             let mut tags = o.tags.clone();
             if tags.is_empty() {
-                // This "x-tags" bullshit is for Gusto.
-                if let Some(x) = o.extensions.get("x-tags") {
+                if let Some(x) = config
+                    .functions
+                    .tags_extension
+                    .as_ref()
+                    .and_then(|e| o.extensions.get(e))
+                {
                     let xtags: Vec<String> = serde_json::from_value(x.clone()).unwrap();
                     tags = xtags;
                 }
@@ -83,12 +164,9 @@ pub fn generate_files(
 
                 tags.push(t);
             }
-            let tag = to_snake_case(&clean_name(&make_plural(
-                proper_name,
-                tags.first().unwrap(),
-            )));
+            let tag = to_snake_case(&clean_name(&make_plural(config, tags.first().unwrap())));
 
-            let oid = clean_fn_name(proper_name, &od, &tag);
+            let oid = clean_fn_name(config, &od, &tag);
 
             let out = tag_files.entry(tag.clone()).or_default();
 
@@ -138,7 +216,18 @@ pub fn generate_files(
                 content
             };
 
-            let docs = get_fn_docs(o, m, p, parameters, ts)?;
+            // Optional query parameters are set through a builder, see
+            // `generate_params_struct`.
+            let params_name = struct_name(&format!(
+                "{} {} params",
+                tag,
+                oid.trim_start_matches(&tag).trim_start_matches('_')
+            ));
+            let params_arg = if config.optional_params {
+                Some(params_name.as_str())
+            } else {
+                None
+            };
 
             let mut bounds: Vec<String> = Vec::new();
 
@@ -146,7 +235,23 @@ pub fn generate_files(
 
             // println!("{:?} {:?}", o.summary, o.request_body);
 
-            let (body_param, body_func) = if let Some(b) = &o.request_body {
+            // Multipart bodies get a builder for the form, see `generate_multipart_form`.
+            let form_name = struct_name(&format!("{} form", oid_to_object_name(&od)));
+            let mut multipart = None;
+            let mut multipart_only = false;
+            if let Some(openapiv3::ReferenceOr::Item(b)) = &o.request_body {
+                multipart = get_multipart_schema(api, b);
+                if let Some((ct, _)) = b.content.first() {
+                    multipart_only = multipart.is_some()
+                        && (ct == "multipart/form-data"
+                            || !b.content.contains_key("multipart/form-data"));
+                }
+            }
+
+            let (body_param, body_func) = if multipart_only {
+                body_content_type_header = Some("multipart/form-data".to_string());
+                (Some(form_name.to_string()), Some("multipart".to_string()))
+            } else if let Some(b) = &o.request_body {
                 if let Ok(b) = b.item() {
                     if b.is_binary()? {
                         let (ct, _) = b.content.first().unwrap();
@@ -161,7 +266,15 @@ pub fn generate_files(
                         if ct == "application/json"
                             || ct == "application/octet-stream"
                             || ct.contains("application/json")
+                            || ct == "application/x-www-form-urlencoded"
                         {
+                            // Form encoded bodies are typed the same way as JSON
+                            // bodies, they only differ in how they are serialized.
+                            let body_kind = if ct == "application/x-www-form-urlencoded" {
+                                "form"
+                            } else {
+                                "json"
+                            };
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
@@ -180,10 +293,10 @@ pub fn generate_files(
                                                     rt.trim_start_matches("Vec<")
                                                         .trim_end_matches('>')
                                                 )),
-                                                Some("json".to_string()),
+                                                Some(body_kind.to_string()),
                                             )
                                         } else {
-                                            (Some(format!("&{}", rt)), Some("json".to_string()))
+                                            (Some(format!("&{}", rt)), Some(body_kind.to_string()))
                                         }
                                     }
                                 } else {
@@ -194,25 +307,15 @@ pub fn generate_files(
                                                 "&[{}]",
                                                 rt.trim_start_matches("Vec<").trim_end_matches('>')
                                             )),
-                                            Some("json".to_string()),
+                                            Some(body_kind.to_string()),
                                         )
                                     } else {
-                                        (Some(format!("&{}", rt)), Some("json".to_string()))
+                                        (Some(format!("&{}", rt)), Some(body_kind.to_string()))
                                     }
                                 }
                             } else {
                                 (None, None)
                             }
-                        } else if ct == "multipart/form-data" {
-                            println!("got multipart/formdata for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
-                        } else if ct == "application/x-www-form-urlencoded" {
-                            println!("got application/x-www-form-urlencoded for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
@@ -239,13 +342,34 @@ pub fn generate_files(
                 (None, None)
             };
 
+            let body_param = match config.functions.request_types.get(&od) {
+                Some(t) if body_func.as_deref() == Some("json") => Some(format!("&{}", t)),
+                _ => body_param,
+            };
+
             // println!("{body_content_type_header:?}");
 
             /*
              * Get the function parameters.
              */
-            let (fn_params_str, query_params) =
-                get_fn_params(ts, o, parameters, false, op.parameters.clone(), proper_name)?;
+            let (fn_params_str, query_params, optional_query_params) = get_fn_params(
+                ts,
+                o,
+                parameters,
+                false,
+                op.parameters.clone(),
+                config,
+                params_arg,
+            )?;
+
+            let docs = get_fn_docs(
+                o,
+                m,
+                p,
+                parameters,
+                ts,
+                params_arg.filter(|_| !optional_query_params.is_empty()),
+            )?;
 
             // Generate the server to send the request to
             let server_arg = if o.servers.len() == 1 {
@@ -273,9 +397,12 @@ pub fn generate_files(
             let (mut response_type, tid, inner_response_type, pagination_property) =
                 get_response_type(&od, ts, o)?;
 
-            if proper_name == "GitHub" && response_type == "crate::types::Data" {
+            if config.functions.unit_responses.contains(&response_type) {
                 response_type = "()".to_string();
             }
+            if let Some(t) = config.functions.response_types.get(&od) {
+                response_type = t.to_string();
+            }
             // We shouldn't ever have an optional response type, thats just annoying.
             if response_type.starts_with("Option<") {
                 response_type = response_type
@@ -285,7 +412,7 @@ pub fn generate_files(
             }
 
             let mut fn_inner = get_fn_inner(
-                proper_name,
+                config,
                 &oid,
                 m,
                 &body_func,
@@ -296,12 +423,27 @@ pub fn generate_files(
                 body_content_type_header.as_deref(),
             )?;
 
-            // TODO: don't special case this.
-            if p == "/jobs/{id}/transcript" || p == "/jobs/{id}/captions" {
+            // The body of the response, as it is sent. Paginated responses
+            // are unwrapped by the functions.
+            let mut mock_response_type = response_type.to_string();
+
+            // Files and other bodies that aren't JSON are handed back unread, so
+            // they can be streamed instead of being buffered.
+            if let Some(accept) = get_download_accept(config, m, o) {
+                fn_inner = format!(
+                    r#"self.client.download(reqwest::Method::{}, &url, "{}").await"#,
+                    m, accept
+                );
+                response_type = "crate::utils::Download".to_string();
+                mock_response_type = "crate::mock::Raw".to_string();
+            }
+
+            if config.functions.accept_overrides.iter().any(|a| a == p) {
                 fn_inner =
                     r#"self.client.request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string()).await"#
                         .to_string();
                 response_type = "String".to_string();
+                mock_response_type = "crate::mock::Raw".to_string();
             }
 
             if let Some(te) = ts.id_to_entry.get(&tid) {
@@ -340,7 +482,7 @@ pub fn generate_files(
                 .trim_start_matches(&tag)
                 .trim_start_matches('_')
                 .to_string();
-            if proper_name != "GitHub"
+            if !config.functions.keep_plural_gets
                 && !frt.starts_with("Vec<")
                 && !frt.ends_with("Response")
                 && !frt.ends_with("Summary")
@@ -353,7 +495,7 @@ pub fn generate_files(
                 // Don't make a function plural where it is not needed.
                 fn_name = fn_name.trim_end_matches('s').to_string();
             } else if frt.starts_with("Vec<") && fn_name != "get" && fn_name != "list" {
-                fn_name = make_plural(proper_name, &fn_name);
+                fn_name = make_plural(config, &fn_name);
             } else if frt.starts_with("Vec<") && fn_name == "get" {
                 fn_name = "get_page".to_string()
             }
@@ -382,6 +524,64 @@ pub fn generate_files(
                 &fn_name,
             ));
 
+            out.add_mock(&print_mock_fn(
+                &tag,
+                &fn_name,
+                m,
+                p,
+                &tmp,
+                &fn_params_str,
+                &mock_response_type,
+            ));
+
+            if !optional_query_params.is_empty() {
+                out.add_head(&generate_params_struct(
+                    &params_name,
+                    &format!("{}::{}", struct_name(&tag), fn_name),
+                    &optional_query_params,
+                ));
+            }
+
+            // If the body can also be sent as a multipart form, add a function for that
+            // next to the standard one.
+            if let Some(schema) = &multipart {
+                let mut form_fn_name = fn_name.to_string();
+                if !multipart_only {
+                    form_fn_name = format!("{}_multipart", fn_name);
+
+                    let fn_inner = get_fn_inner(
+                        config,
+                        &oid,
+                        m,
+                        &Some("multipart".to_string()),
+                        &response_type,
+                        &inner_response_type,
+                        &pagination_property,
+                        false,
+                        Some("multipart/form-data"),
+                    )?;
+
+                    out.add_content(&print_fn(
+                        &docs,
+                        &Default::default(),
+                        &fn_params_str,
+                        &Some(form_name.to_string()),
+                        &frt,
+                        &template,
+                        &fn_inner,
+                        &form_fn_name,
+                    ));
+                }
+
+                out.add_head(&generate_multipart_form(
+                    ts,
+                    &od,
+                    &form_name,
+                    &format!("{}::{}", struct_name(&tag), form_fn_name),
+                    schema,
+                )?);
+            }
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
             if frt.starts_with("Vec<") && http::Method::GET == m {
@@ -392,14 +592,21 @@ pub fn generate_files(
                     oid.trim_start_matches(&tag).trim_start_matches('_'),
                 )?;
 
-                let (fn_params_str, query_params) =
-                    get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+                let (fn_params_str, query_params, _) = get_fn_params(
+                    ts,
+                    o,
+                    parameters,
+                    true,
+                    op.parameters.clone(),
+                    config,
+                    params_arg,
+                )?;
 
                 let tmp = parse(p)?;
                 let template = tmp.compile(query_params, &server_arg);
 
                 let fn_inner = get_fn_inner(
-                    proper_name,
+                    config,
                     &oid,
                     m,
                     &body_func,
@@ -410,36 +617,7 @@ pub fn generate_files(
                     body_content_type_header.as_deref(),
                 )?;
 
-                let mut fn_name = oid
-                    .replace("_get_", "_get_all_")
-                    .replace("_list_", "_list_all_")
-                    .trim_start_matches(&tag)
-                    .trim_start_matches('_')
-                    .to_string();
-
-                if fn_name == "list" {
-                    fn_name = "list_all".to_string();
-                } else if fn_name == "get" {
-                    fn_name = "get_all".to_string();
-                } else if fn_name.starts_with("get_") && !fn_name.starts_with("get_all") {
-                    fn_name = fn_name.replace("get_", "get_all_");
-                } else if fn_name.starts_with("list_") && !fn_name.starts_with("list_all") {
-                    fn_name = fn_name.replace("list_", "list_all_");
-                } else if !fn_name.contains("get")
-                    && !fn_name.contains("get_all")
-                    && !fn_name.contains("list")
-                    && !fn_name.contains("list_all")
-                {
-                    fn_name = format!("get_all_{}", fn_name);
-                }
-
-                if fn_name != "get_all"
-                    && fn_name != "list_all"
-                    && fn_name != "get"
-                    && fn_name != "list"
-                {
-                    fn_name = make_plural(proper_name, &fn_name);
-                }
+                let mut fn_name = get_all_pages_fn_name(config, &oid, &tag);
 
                 // Do this right before printing. Check if we already have this function name.
                 // This will ensure we don't have any duplicates.
@@ -459,6 +637,60 @@ pub fn generate_files(
                     &fn_inner,
                     &fn_name,
                 ));
+
+                // And a variant that streams the items instead of collecting them.
+                let stream_fn_name = format!("{}_stream", fn_name);
+                fn_names.push(stream_fn_name.clone() + &tag);
+                out.add_content(&print_stream_fn(
+                    &get_fn_docs_stream(o, m, p, &fn_name)?,
+                    &fn_params_str,
+                    vec_item_type(&frt),
+                    &template,
+                    &get_stream_inner(ts, &tid, config, &response_type, &pagination_property)?,
+                    &stream_fn_name,
+                ));
+            } else if config.pagination.style == Some(PaginationStyle::Slack)
+                && http::Method::GET == m
+                && has_param(o, "cursor")
+            {
+                // Slack doesn't paginate the response type itself, the items are in
+                // a vector next to the `response_metadata` holding the next cursor.
+                if let Some((items_type, items_property, optional)) = get_slack_paging(ts, &tid)? {
+                    let (fn_params_str, query_params, _) = get_fn_params(
+                        ts,
+                        o,
+                        parameters,
+                        true,
+                        op.parameters.clone(),
+                        config,
+                        params_arg,
+                    )?;
+
+                    let tmp = parse(p)?;
+                    let template = tmp.compile(query_params, &server_arg);
+
+                    let all_fn_name = get_all_pages_fn_name(config, &oid, &tag);
+                    let mut stream_fn_name = format!("{}_stream", all_fn_name);
+                    if fn_names.contains(&(stream_fn_name.clone() + &tag)) {
+                        stream_fn_name = format!("{}_all_stream", all_fn_name);
+                    }
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    let next = if optional {
+                        "resp.response_metadata.map(|m| m.next_cursor)"
+                    } else {
+                        "Some(resp.response_metadata.next_cursor)"
+                    };
+
+                    out.add_content(&print_stream_fn(
+                        &get_fn_docs_stream(o, m, p, &fn_name)?,
+                        &fn_params_str,
+                        &items_type,
+                        &template,
+                        &get_cursor_stream_inner(&response_type, &items_property, "cursor", next),
+                        &stream_fn_name,
+                    ));
+                }
             }
 
             // Add this to our map of functions based on the tag name.
@@ -689,6 +921,46 @@ fn get_response_type(
     ))
 }
 
+/*
+ * Get the `Accept` header for an operation that responds with a file or another
+ * body that isn't JSON, or `None` if it responds with JSON.
+ */
+fn get_download_accept(config: &Config, m: &str, o: &openapiv3::Operation) -> Option<String> {
+    let (code, r) = o.responses.responses.first()?;
+
+    // GitHub redirects to a short lived URL to download archives and logs from.
+    if *code == openapiv3::StatusCode::Code(302) {
+        return Some("*/*".to_string());
+    }
+
+    let r = r.item().ok()?;
+    if r.content.is_empty() {
+        // Google's media downloads, like exporting a Drive file, don't
+        // document their content.
+        if config.functions.untyped_gets_are_downloads && http::Method::GET == m {
+            return Some("*/*".to_string());
+        }
+        return None;
+    }
+
+    if r.content.keys().all(|ct| is_download_media_type(ct)) {
+        Some(r.content.keys().cloned().collect::<Vec<_>>().join(", "))
+    } else {
+        None
+    }
+}
+
+fn is_download_media_type(ct: &str) -> bool {
+    ct == "application/octet-stream"
+        || ct == "application/zip"
+        || ct == "application/gzip"
+        || ct == "application/x-tar"
+        || ct == "application/pdf"
+        || ct.starts_with("image/")
+        || ct.starts_with("audio/")
+        || ct.starts_with("video/")
+}
+
 #[allow(clippy::type_complexity)]
 fn get_fn_params(
     ts: &mut TypeSpace,
@@ -696,8 +968,13 @@ fn get_fn_params(
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
     all_pages: bool,
     global_params: Vec<openapiv3::ReferenceOr<openapiv3::Parameter>>,
-    proper_name: &str,
-) -> Result<(Vec<String>, BTreeMap<String, (String, String)>)> {
+    config: &Config,
+    params_name: Option<&str>,
+) -> Result<(
+    Vec<String>,
+    BTreeMap<String, (String, String)>,
+    Vec<OptionalParam>,
+)> {
     /*
      * Query parameters are sorted lexicographically to ensure a stable
      * order in the generated code.
@@ -705,6 +982,7 @@ fn get_fn_params(
     let mut fn_params_str: Vec<String> = Default::default();
     let mut fn_params: Vec<String> = Default::default();
     let mut query_params: BTreeMap<String, (String, String)> = Default::default();
+    let mut optional_params: Vec<OptionalParam> = Default::default();
     let mut gp = global_params;
     let mut op = o.parameters.clone();
     gp.append(&mut op);
@@ -728,29 +1006,45 @@ fn get_fn_params(
 
         if !fn_params.contains(nam) && !fn_params.contains(&format!("{}_", nam)) {
             let typ = parameter_data.render_type(&param_name, ts)?;
-            if nam == "ref"
-                || nam == "type"
-                || nam == "foo"
-                || nam == "enum"
-                || nam == "const"
-                || nam == "use"
+
+            // Optional query parameters are set through the params builder, so
+            // they are only sent when the caller asked for them.
+            if params_name.is_some()
+                && !parameter_data.required
+                && matches!(item, openapiv3::Parameter::Query { .. })
             {
+                let field = if is_keyword_param(nam) {
+                    format!("{}_", nam)
+                } else if nam == "i_ds" {
+                    "ids".to_string()
+                } else {
+                    nam.to_string()
+                };
+                fn_params.push(field.clone());
+
+                if is_keyword_param(nam) || nam == "i_ds" || is_wanted_param(nam, all_pages, config)
+                {
+                    let typ = optional_param_type(&typ);
+                    query_params.insert(
+                        format!("params.{}", field),
+                        (format!("Option<{}>", typ), parameter_data.name.to_string()),
+                    );
+                    optional_params.push(OptionalParam {
+                        name: field,
+                        typ,
+                        description: parameter_data.description.clone().unwrap_or_default(),
+                    });
+                }
+                continue;
+            }
+
+            if is_keyword_param(nam) {
                 fn_params_str.push(format!("{}_: {},", nam, typ));
                 fn_params.push(nam.to_string() + "_");
             } else if nam == "i_ds" {
                 fn_params_str.push(format!("ids: {},", typ));
                 fn_params.push("ids".to_string());
-            } else if (!all_pages || !is_page_param(nam, proper_name))
-                && (nam != "authorization" || proper_name == "Stripe")
-                && !nam.starts_with("authorization_bearer")
-                && (!proper_name.starts_with("Google")
-                    || !is_google_unnecessary_param(proper_name, nam))
-                && (proper_name != "SendGrid" || !is_sendgrid_unnecessary_param(nam))
-                && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
-                && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
-                && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
-            {
+            } else if is_wanted_param(nam, all_pages, config) {
                 if typ == "chrono::DateTime<chrono::Utc>" {
                     fn_params_str.push(format!("{}: Option<{}>,", nam, typ));
                     fn_params.push(nam.to_string());
@@ -775,13 +1069,7 @@ fn get_fn_params(
                 allow_empty_value: _,
             } = item
             {
-                if nam == "ref"
-                    || nam == "type"
-                    || nam == "foo"
-                    || nam == "enum"
-                    || nam == "const"
-                    || nam == "use"
-                {
+                if is_keyword_param(nam) {
                     query_params.insert(
                         format!("{}_", nam),
                         (typ.to_string(), parameter_data.name.to_string()),
@@ -791,17 +1079,7 @@ fn get_fn_params(
                         "ids".to_string(),
                         (typ.to_string(), parameter_data.name.to_string()),
                     );
-                } else if (!all_pages || !is_page_param(nam, proper_name))
-                    && (nam != "authorization" || proper_name == "Stripe")
-                    && !nam.starts_with("authorization_bearer")
-                    && (!proper_name.starts_with("Google")
-                        || !is_google_unnecessary_param(proper_name, nam))
-                    && (proper_name != "SendGrid" || !is_sendgrid_unnecessary_param(nam))
-                    && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
-                    && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
-                    && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                    && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
-                {
+                } else if is_wanted_param(nam, all_pages, config) {
                     if typ == "chrono::DateTime<chrono::Utc>" {
                         query_params.insert(
                             nam.to_string(),
@@ -818,7 +1096,100 @@ fn get_fn_params(
         }
     }
 
-    Ok((fn_params_str, query_params))
+    if let Some(params_name) = params_name {
+        if !optional_params.is_empty() {
+            fn_params_str.push(format!("params: &{},", params_name));
+        }
+    }
+
+    Ok((fn_params_str, query_params, optional_params))
+}
+
+/// An optional query parameter, set through the params builder of its operation.
+struct OptionalParam {
+    name: String,
+    typ: String,
+    description: String,
+}
+
+fn is_keyword_param(nam: &str) -> bool {
+    nam == "ref" || nam == "type" || nam == "foo" || nam == "enum" || nam == "const" || nam == "use"
+}
+
+fn is_wanted_param(nam: &str, all_pages: bool, config: &Config) -> bool {
+    (!all_pages || !is_page_param(nam, config))
+        && !nam.starts_with("authorization_bearer")
+        && !config.is_ignored_param(nam)
+}
+
+/// The owned type a params builder stores for a parameter rendered as `typ`.
+fn optional_param_type(typ: &str) -> String {
+    if typ == "&str" {
+        "String".to_string()
+    } else if let Some(inner) = typ.strip_prefix("&[").and_then(|t| t.strip_suffix(']')) {
+        format!("Vec<{}>", inner)
+    } else if let Some(inner) = typ
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        inner.to_string()
+    } else {
+        typ.trim_start_matches('&').to_string()
+    }
+}
+
+/*
+ * Generate the builder for the optional query parameters of an operation.
+ */
+fn generate_params_struct(params_name: &str, fn_path: &str, params: &[OptionalParam]) -> String {
+    let mut fields = String::new();
+    let mut setters = String::new();
+
+    for p in params {
+        let docs = if p.description.is_empty() {
+            format!("/// Set the `{}` query parameter.", p.name)
+        } else {
+            format!("/// {}", p.description.trim().replace('\n', "\n/// "))
+        };
+
+        let (setter_type, setter_value) = if p.typ == "String" {
+            ("impl Into<String>", "value.into()")
+        } else {
+            (p.typ.as_str(), "value")
+        };
+
+        fields.push_str(&format!("{}: Option<{}>,\n", p.name, p.typ));
+
+        setters.push_str(&format!(
+            r#"{}
+pub fn {}(mut self, value: {}) -> Self {{
+    self.{} = Some({});
+    self
+}}
+
+"#,
+            docs, p.name, setter_type, p.name, setter_value
+        ));
+    }
+
+    format!(
+        r#"/// The optional query parameters sent by [`{fn_path}`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct {params_name} {{
+{fields}}}
+
+impl {params_name} {{
+pub fn new() -> Self {{
+    Default::default()
+}}
+
+{setters}}}
+
+"#
+    )
 }
 
 /*
@@ -827,7 +1198,7 @@ fn get_fn_params(
 // TODO: Fix this
 #[allow(clippy::too_many_arguments)]
 fn get_fn_inner(
-    proper_name: &str,
+    config: &Config,
     oid: &str,
     m: &str,
     body_func: &Option<String>,
@@ -837,9 +1208,18 @@ fn get_fn_inner(
     all_pages: bool,
     content_type: Option<&str>,
 ) -> Result<String> {
+    if body_func.as_deref() == Some("multipart") {
+        return Ok(format!(
+            "self.client.request_form(reqwest::Method::{}, &url, body.into_form().await?).await",
+            m
+        ));
+    }
+
     let body = if let Some(f) = &body_func {
         if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
+        } else if f == "form" {
+            "Some(reqwest::Body::from(crate::utils::form_urlencode(body)?))"
         } else {
             "Some(body.into())"
         }
@@ -853,7 +1233,7 @@ fn get_fn_inner(
 
     if all_pages && pagination_property.is_empty() {
         return Ok(format!("self.client.get_all_pages(&url, crate::Message {{ body: {}, content_type: None }}).await", body));
-    } else if all_pages && proper_name.starts_with("Stripe") {
+    } else if all_pages && config.pagination.style == Some(PaginationStyle::Stripe) {
         // We will do a custom function here.
         let inner = format!(
             r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
@@ -875,7 +1255,7 @@ fn get_fn_inner(
                 }}
 
                 if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
+                    resp = self.client.{}(&format!("{{}}?starting_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
                 }} else {{
                     resp = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
                 }}
@@ -905,7 +1285,7 @@ fn get_fn_inner(
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name.starts_with("Google") {
+    } else if all_pages && config.pagination.style == Some(PaginationStyle::Google) {
         // We will do a custom function here.
         let inner = format!(
             r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
@@ -948,7 +1328,7 @@ fn get_fn_inner(
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name == "Ramp" {
+    } else if all_pages && config.pagination.style == Some(PaginationStyle::Ramp) {
         // We will do a custom function here.
         let inner = format!(
             r#"let resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
@@ -994,7 +1374,7 @@ fn get_fn_inner(
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name == "TripActions" {
+    } else if all_pages && config.pagination.style == Some(PaginationStyle::TripActions) {
         // We will do a custom function here.
         let inner = format!(
             r#"
@@ -1039,7 +1419,7 @@ fn get_fn_inner(
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name == "Zoom" {
+    } else if all_pages && config.pagination.style == Some(PaginationStyle::Zoom) {
         // We will do a custom function here.
         let inner = format!(
             r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
@@ -1085,7 +1465,7 @@ fn get_fn_inner(
     } else if all_pages && !pagination_property.is_empty() {
         bail!(
             "must implement custom pagination function for {} {}",
-            proper_name,
+            config.proper_name,
             pagination_property
         );
     }
@@ -1142,12 +1522,327 @@ fn get_fn_inner(
     ))
 }
 
+/*
+ * Print the mock of a function, taking its path parameters and returning a
+ * `crate::mock::MockOperation` for its request.
+ */
+fn print_mock_fn(
+    tag: &str,
+    fn_name: &str,
+    m: &str,
+    p: &str,
+    template: &Template,
+    fn_params_str: &[String],
+    response_type: &str,
+) -> String {
+    let parameters = template.parameters();
+    let args = fn_params_str
+        .iter()
+        .filter(|a| {
+            a.split_once(':')
+                .map(|(n, _)| parameters.iter().any(|p| p == n.trim()))
+                .unwrap_or_default()
+        })
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        r#"
+/// Mock [`{tag_struct}::{fn_name}`](crate::{tag}::{tag_struct}::{fn_name}), a `{m}` to `{p}`.
+pub fn {fn_name}(&self, {args}) -> crate::mock::MockOperation<'a, {response_type}> {{
+    self.mock.operation(reqwest::Method::{m}, {path})
+}}
+"#,
+        tag_struct = struct_name(tag),
+        path = template.compile_path(),
+    )
+}
+
+/*
+ * Print a function returning a stream of the items of a paginated request.
+ *
+ * The stream inner gets the `client`, the `url` of the first page and the
+ * `page` token to fetch, and returns a `crate::utils::Page` of the items.
+ */
+fn print_stream_fn(
+    docs: &str,
+    fn_params_str: &[String],
+    item_type: &str,
+    template: &str,
+    stream_inner: &str,
+    fn_name: &str,
+) -> String {
+    format!(
+        r#"{}
+pub fn {}(&self, {} page_cursor: Option<crate::utils::PageCursor>) -> crate::utils::Pages<{}> {{
+    {}
+    let client = self.client.clone();
+    crate::utils::Pages::new(page_cursor, move |page| {{
+        let client = client.clone();
+        let url = url.clone();
+        async move {{
+            {}
+        }}
+    }})
+}}
+"#,
+        docs,
+        fn_name,
+        fn_params_str.join(" "),
+        item_type,
+        template,
+        stream_inner,
+    )
+}
+
+/*
+ * Fetch a single page for a `*_stream` function, see `print_stream_fn`.
+ */
+fn get_stream_inner(
+    ts: &TypeSpace,
+    tid: &TypeId,
+    config: &Config,
+    response_type: &str,
+    pagination_property: &str,
+) -> Result<String> {
+    if pagination_property.is_empty() {
+        return Ok(format!(
+            r#"// The page token is the URL of the next page from the link header.
+            let (next, items) = match page {{
+                Some(page) => client.get_pages_url::<{}>(&reqwest::Url::parse(&page)?).await?,
+                None => client.get_pages::<{}>(&url).await?,
+            }};
+
+            Ok(crate::utils::Page {{
+                items,
+                next: next.map(|n| n.0),
+            }})"#,
+            vec_item_type(response_type),
+            vec_item_type(response_type),
+        ));
+    } else if config.pagination.style == Some(PaginationStyle::Stripe) {
+        let last_id = if items_have_id(ts, tid, pagination_property) {
+            format!(
+                "resp.{}.last().map(|last| last.id.clone())",
+                pagination_property
+            )
+        } else {
+            // The items are one of a few types, which all have an id.
+            format!(
+                r#"resp.{}.last().and_then(|last| match serde_json::json!(last) {{
+                    serde_json::Value::Object(o) => o.get("id").and_then(|id| id.as_str()).map(|id| id.to_string()),
+                    _ => None,
+                }})"#,
+                pagination_property
+            )
+        };
+        return Ok(format!(
+            r#"let url = match page {{
+                Some(page) => format!("{{}}{{}}starting_after={{}}", url, if url.contains('?') {{ '&' }} else {{ '?' }}, page),
+                None => url,
+            }};
+            let resp: {} = client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            // The next page starts after the last item of this one.
+            let next = if resp.has_more {{
+                {}
+            }} else {{
+                None
+            }};
+
+            Ok(crate::utils::Page {{
+                items: resp.{},
+                next,
+            }})"#,
+            response_type, last_id, pagination_property,
+        ));
+    } else if config.pagination.style == Some(PaginationStyle::Google) {
+        return Ok(get_cursor_stream_inner(
+            response_type,
+            pagination_property,
+            "pageToken",
+            "Some(resp.next_page_token)",
+        ));
+    } else if config.pagination.style == Some(PaginationStyle::Ramp) {
+        return Ok(format!(
+            r#"let resp: {} = match page {{
+                // The page token is the full URL of the next page.
+                Some(page) => match client.get(page.trim_start_matches(&client.host), crate::Message {{ body: None, content_type: None }}).await {{
+                    Ok(resp) => resp,
+                    // Ramp returns a 404 for the page after the last one.
+                    Err(e) if e.downcast_ref::<crate::ClientError>().and_then(|e| e.status()) == Some(reqwest::StatusCode::NOT_FOUND) => {{
+                        return Ok(crate::utils::Page {{
+                            items: Vec::new(),
+                            next: None,
+                        }});
+                    }}
+                    Err(e) => return Err(e),
+                }},
+                None => client.get(&url, crate::Message {{ body: None, content_type: None }}).await?,
+            }};
+
+            Ok(crate::utils::Page {{
+                items: resp.{},
+                next: Some(resp.page.next),
+            }})"#,
+            response_type, pagination_property,
+        ));
+    } else if config.pagination.style == Some(PaginationStyle::TripActions) {
+        return Ok(format!(
+            r#"let page = page.unwrap_or_else(|| "0".to_string());
+            let url = format!("{{}}{{}}page={{}}&size=100", url, if url.contains('?') {{ '&' }} else {{ '?' }}, page);
+            let resp: {} = client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            let next = if resp.page.current_page + 1 < resp.page.total_pages {{
+                Some((resp.page.current_page + 1).to_string())
+            }} else {{
+                None
+            }};
+
+            Ok(crate::utils::Page {{
+                items: resp.{},
+                next,
+            }})"#,
+            response_type, pagination_property,
+        ));
+    } else if config.pagination.style == Some(PaginationStyle::Zoom) {
+        return Ok(get_cursor_stream_inner(
+            response_type,
+            pagination_property,
+            "next_page_token",
+            "Some(resp.next_page_token)",
+        ));
+    }
+
+    bail!(
+        "must implement custom pagination stream for {} {}",
+        config.proper_name,
+        pagination_property
+    );
+}
+
+/*
+ * Fetch a single page for APIs passing the token of the next page in the
+ * response and taking it back as the `param` query parameter.
+ */
+fn get_cursor_stream_inner(
+    response_type: &str,
+    items_property: &str,
+    param: &str,
+    next: &str,
+) -> String {
+    format!(
+        r#"let url = match page {{
+            Some(page) => format!("{{}}{{}}{}={{}}", url, if url.contains('?') {{ '&' }} else {{ '?' }}, page),
+            None => url,
+        }};
+        let resp: {} = client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+        Ok(crate::utils::Page {{
+            next: {},
+            items: resp.{},
+        }})"#,
+        param, response_type, next, items_property,
+    )
+}
+
+/*
+ * Get the type and name of the vector of items in a Slack response that
+ * paginates with a cursor, and whether its `response_metadata` is optional.
+ */
+/*
+ * Check if the items of the paginated property are objects with an `id`.
+ */
+fn items_have_id(ts: &TypeSpace, tid: &TypeId, pagination_property: &str) -> bool {
+    fn resolve<'a>(ts: &'a TypeSpace, mut id: &'a TypeId) -> Option<&'a crate::TypeDetails> {
+        while let Some(et) = ts.id_to_entry.get(id) {
+            match &et.details {
+                crate::TypeDetails::NamedType(i, _) | crate::TypeDetails::Optional(i, _) => id = i,
+                details => return Some(details),
+            }
+        }
+        None
+    }
+
+    let items = match resolve(ts, tid) {
+        Some(crate::TypeDetails::Object(p, _)) => p.get(pagination_property),
+        _ => None,
+    };
+    match items.and_then(|i| resolve(ts, i)) {
+        Some(crate::TypeDetails::Array(i, _)) => {
+            matches!(resolve(ts, i), Some(crate::TypeDetails::Object(p, _)) if p.contains_key("id"))
+        }
+        _ => false,
+    }
+}
+
+fn get_slack_paging(ts: &TypeSpace, tid: &TypeId) -> Result<Option<(String, String, bool)>> {
+    let mut et = if let Some(et) = ts.id_to_entry.get(tid) {
+        et
+    } else {
+        return Ok(None);
+    };
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
+    let p = if let crate::TypeDetails::Object(p, _) = &et.details {
+        p
+    } else {
+        return Ok(None);
+    };
+
+    let optional = if let Some(id) = p.get("response_metadata") {
+        match ts.render_type(id, false)?.as_str() {
+            "crate::types::NewPagingStyle" => false,
+            "Option<crate::types::NewPagingStyle>" => true,
+            _ => return Ok(None),
+        }
+    } else {
+        return Ok(None);
+    };
+
+    let mut items = None;
+    for (n, id) in p {
+        let rt = ts.render_type(id, false)?;
+        if rt.starts_with("Vec<") {
+            if items.is_some() {
+                // We can't tell which of the vectors is the one that paginates.
+                return Ok(None);
+            }
+            items = Some((vec_item_type(&rt).to_string(), to_snake_case(n), optional));
+        }
+    }
+
+    Ok(items)
+}
+
+/*
+ * Get the type of the items of a `Vec<T>`, keeping any nested vectors intact.
+ */
+fn vec_item_type(rt: &str) -> &str {
+    rt.strip_prefix("Vec<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(rt)
+}
+
+fn has_param(o: &openapiv3::Operation, name: &str) -> bool {
+    o.parameters.iter().any(|p| match p {
+        openapiv3::ReferenceOr::Item(item) => get_parameter_data(item)
+            .map(|d| d.name == name)
+            .unwrap_or(false),
+        openapiv3::ReferenceOr::Reference { .. } => false,
+    })
+}
+
 fn get_fn_docs(
     o: &openapiv3::Operation,
     m: &str,
     p: &str,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
     ts: &mut TypeSpace,
+    params_name: Option<&str>,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -1196,6 +1891,14 @@ fn get_fn_docs(
 
         let parameter_data = get_parameter_data(item).unwrap();
 
+        // These are documented on the params builder instead.
+        if params_name.is_some()
+            && !parameter_data.required
+            && matches!(item, openapiv3::Parameter::Query { .. })
+        {
+            continue;
+        }
+
         let pid = ts.select_param(None, par)?;
         let mut docs = ts.render_docs(&pid);
         if let Some(d) = &parameter_data.description {
@@ -1229,12 +1932,44 @@ fn get_fn_docs(
             a(&format!("* * `{}: {}`{}", nam, typ, docs));
         }
     }
+    if let Some(params_name) = params_name {
+        a(&format!(
+            "* * `params: &{}` -- The optional query parameters.",
+            params_name
+        ));
+    }
     a("*/");
 
     Ok(out.trim().to_string())
 }
 
 fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    get_fn_docs_paginated(
+        o,
+        m,
+        p,
+        &format!(
+            "* As opposed to `{}`, this function returns all the pages of the request at once.",
+            fn_name
+        ),
+    )
+}
+
+fn get_fn_docs_stream(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    get_fn_docs_paginated(
+        o,
+        m,
+        p,
+        &format!(
+            r#"* As opposed to `{}`, this function returns a stream of the items that only fetches
+* the next page once the items of the previous one have been consumed.
+* Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off."#,
+            fn_name
+        ),
+    )
+}
+
+fn get_fn_docs_paginated(o: &openapiv3::Operation, m: &str, p: &str, note: &str) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -1252,10 +1987,7 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
         m, p
     ));
     a("*");
-    a(&format!(
-        "* As opposed to `{}`, this function returns all the pages of the request at once.",
-        fn_name
-    ));
+    a(note);
     if let Some(description) = &o.description {
         a("*");
         a(&format!("* {}", description.replace('\n', "\n* ")));
@@ -1269,7 +2001,38 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
-fn is_page_param(s: &str, proper_name: &str) -> bool {
+fn get_all_pages_fn_name(config: &Config, oid: &str, tag: &str) -> String {
+    let mut fn_name = oid
+        .replace("_get_", "_get_all_")
+        .replace("_list_", "_list_all_")
+        .trim_start_matches(tag)
+        .trim_start_matches('_')
+        .to_string();
+
+    if fn_name == "list" {
+        fn_name = "list_all".to_string();
+    } else if fn_name == "get" {
+        fn_name = "get_all".to_string();
+    } else if fn_name.starts_with("get_") && !fn_name.starts_with("get_all") {
+        fn_name = fn_name.replace("get_", "get_all_");
+    } else if fn_name.starts_with("list_") && !fn_name.starts_with("list_all") {
+        fn_name = fn_name.replace("list_", "list_all_");
+    } else if !fn_name.contains("get")
+        && !fn_name.contains("get_all")
+        && !fn_name.contains("list")
+        && !fn_name.contains("list_all")
+    {
+        fn_name = format!("get_all_{}", fn_name);
+    }
+
+    if fn_name != "get_all" && fn_name != "list_all" && fn_name != "get" && fn_name != "list" {
+        fn_name = make_plural(config, &fn_name);
+    }
+
+    fn_name
+}
+
+fn is_page_param(s: &str, config: &Config) -> bool {
     s == "page"
         || s == "per_page"
         || s == "per"
@@ -1283,50 +2046,214 @@ fn is_page_param(s: &str, proper_name: &str) -> bool {
         || s == "sync_token"
         || s == "limit"
         || s == "ending_before"
-        || (s == "after" && proper_name == "Okta")
-        || (s == "starting_after" && proper_name == "Stripe")
+        || config.is_page_param(s)
 }
 
-fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
-    // These are either dumb or depreciated.
-    s == "access_token"
-        || s == "oauth_token"
-        || s == "pretty_print"
-        || s == "xgafv"
-        || s == "custom_field_mask"
-        || s == "sync_token"
-        || s == "user_ip"
-        || s == "quota_user"
-        || s == "key"
-        || s == "fields"
-        || s == "callback"
-        || s == "upload_protocol"
-        || s == "upload_type"
-        || s == "always_include_email"
-        || s == "enforce_single_parent"
-        || s == "corpus"
-        || (s == "alt" && proper_name != "Google Groups Settings")
-}
+/*
+ * Find the schema of the `multipart/form-data` part of a request body.
+ *
+ * Slack documents `files.upload` as form encoded, but the file itself has to be
+ * sent as multipart, so a form encoded body with a file property counts too.
+ */
+pub fn get_multipart_schema(
+    api: &openapiv3::OpenAPI,
+    body: &openapiv3::RequestBody,
+) -> Option<openapiv3::Schema> {
+    if let Some(mt) = body.content.get("multipart/form-data") {
+        return mt.schema.as_ref().and_then(|s| resolve_schema(api, s));
+    }
 
-fn is_slack_unnecessary_param(s: &str) -> bool {
-    s == "token"
+    if let Some(mt) = body.content.get("application/x-www-form-urlencoded") {
+        let s = mt.schema.as_ref().and_then(|s| resolve_schema(api, s))?;
+        if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &s.schema_kind {
+            if o.properties.values().any(is_file_property) {
+                return Some(s);
+            }
+        }
+    }
+
+    None
 }
 
-fn is_sendgrid_unnecessary_param(s: &str) -> bool {
-    s == "on_behalf_of" || s == "accept" || s == "x_query_id" || s == "x_cursor"
+fn resolve_schema(
+    api: &openapiv3::OpenAPI,
+    s: &openapiv3::ReferenceOr<openapiv3::Schema>,
+) -> Option<openapiv3::Schema> {
+    match s {
+        openapiv3::ReferenceOr::Item(s) => Some(s.clone()),
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            let components = api.components.as_ref()?;
+            resolve_schema(api, components.schemas.get(name)?)
+        }
+    }
 }
 
-fn is_okta_unnecessary_param(s: &str) -> bool {
-    s == "okta_access_gateway_agent"
-        || s == "x_forwarded_for"
-        || s == "user_agent"
-        || s == "accept_language"
+/*
+ * A property of a multipart body is sent as a file if it is binary.
+ */
+pub fn is_file_property(p: &openapiv3::ReferenceOr<Box<openapiv3::Schema>>) -> bool {
+    use openapiv3::{SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty::Item};
+
+    if let openapiv3::ReferenceOr::Item(s) = p {
+        if let SchemaKind::Type(Type::String(st)) = &s.schema_kind {
+            return matches!(st.format, Item(StringFormat::Binary))
+                || s.schema_data
+                    .description
+                    .as_deref()
+                    .unwrap_or_default()
+                    .contains("via `multipart/form-data`");
+        }
+    }
+
+    false
 }
 
-fn is_shipbob_unnecessary_param(s: &str) -> bool {
-    s == "shipbob_channel_id"
+/*
+ * The name of the type a multipart text property is rendered as.
+ */
+pub fn multipart_property_type_name(od: &str, property: &str) -> String {
+    format!("{} form {}", oid_to_object_name(od), property)
 }
 
-fn is_stripe_unnecessary_param(s: &str) -> bool {
-    s == "expand"
+/*
+ * Generate a builder for a `multipart/form-data` request body.
+ *
+ * File properties take a `crate::utils::FilePart`, every other property is
+ * sent as a text part. Returns the code for the builder.
+ */
+fn generate_multipart_form(
+    ts: &mut TypeSpace,
+    od: &str,
+    form_name: &str,
+    fn_path: &str,
+    schema: &openapiv3::Schema,
+) -> Result<String> {
+    let o = if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &schema.schema_kind {
+        o
+    } else {
+        bail!("multipart body of {} is not an object", od);
+    };
+
+    let mut fields = String::new();
+    let mut setters = String::new();
+    let mut parts = String::new();
+
+    for (name, p) in o.properties.iter() {
+        let mut field = to_snake_case(&clean_name(name));
+        if field == "type" || field == "ref" || field == "self" || field == "use" {
+            field = format!("{}_", field);
+        }
+
+        let description = match p {
+            openapiv3::ReferenceOr::Item(s) => s.schema_data.description.clone(),
+            openapiv3::ReferenceOr::Reference { .. } => None,
+        }
+        .unwrap_or_default();
+        let docs = if description.is_empty() {
+            format!("/// Set the `{}` part of the form.", name)
+        } else {
+            format!("/// {}", description.trim().replace('\n', "\n/// "))
+        };
+
+        let required = o.required.contains(name);
+
+        let (field_type, setter_type, setter_value, part) = if is_file_property(p) {
+            (
+                "crate::utils::FilePart".to_string(),
+                "crate::utils::FilePart".to_string(),
+                "value".to_string(),
+                format!(r#"form = form.part("{}", value.into_part().await?);"#, name),
+            )
+        } else {
+            let tid = ts.select_box(Some(&multipart_property_type_name(od, name)), p, "")?;
+            let rt = ts.render_type(&tid, false)?;
+            let te = ts.id_to_entry.get(&tid).unwrap();
+            let is_text = match &te.details {
+                crate::TypeDetails::Basic(..) | crate::TypeDetails::Enum(..) => true,
+                crate::TypeDetails::NamedType(itid, _) => matches!(
+                    ts.id_to_entry.get(itid).map(|e| &e.details),
+                    Some(crate::TypeDetails::Basic(..)) | Some(crate::TypeDetails::Enum(..))
+                ),
+                _ => false,
+            };
+
+            let part = if rt == "String" {
+                format!(r#"form = form.text("{}", value);"#, name)
+            } else if is_text {
+                format!(r#"form = form.text("{}", value.to_string());"#, name)
+            } else {
+                format!(
+                    r#"form = form.text("{}", serde_json::to_string(&value)?);"#,
+                    name
+                )
+            };
+
+            if rt == "String" {
+                (
+                    rt,
+                    "impl Into<String>".to_string(),
+                    "value.into()".to_string(),
+                    part,
+                )
+            } else {
+                (rt.clone(), rt, "value".to_string(), part)
+            }
+        };
+
+        fields.push_str(&format!("{}: Option<{}>,\n", field, field_type));
+
+        setters.push_str(&format!(
+            r#"{}
+pub fn {}(mut self, value: {}) -> Self {{
+    self.{} = Some({});
+    self
+}}
+
+"#,
+            docs, field, setter_type, field, setter_value
+        ));
+
+        if required {
+            parts.push_str(&format!(
+                r#"if let Some(value) = self.{} {{
+    {}
+}} else {{
+    anyhow::bail!("missing required multipart field `{}`");
+}}
+"#,
+                field, part, name
+            ));
+        } else {
+            parts.push_str(&format!(
+                r#"if let Some(value) = self.{} {{
+    {}
+}}
+"#,
+                field, part
+            ));
+        }
+    }
+
+    Ok(format!(
+        r#"/// The `multipart/form-data` body sent by [`{fn_path}`].
+#[derive(Default)]
+pub struct {form_name} {{
+{fields}}}
+
+impl {form_name} {{
+pub fn new() -> Self {{
+    Default::default()
+}}
+
+{setters}/// Build the multipart form, opening any files that are uploaded from disk.
+pub async fn into_form(self) -> Result<reqwest::multipart::Form> {{
+    let mut form = reqwest::multipart::Form::new();
+    {parts}
+    Ok(form)
+}}
+}}
+
+"#
+    ))
 }
@@ -0,0 +1,367 @@
+use crate::config::Config;
+
+const HTTP_CACHE_TEMPLATE: &str = r#"//! Implements <https://tools.ietf.org/html/rfc7232> Conditional Requests.
+//!
+//! The responses to `GET` requests are cached with their `ETag` and
+//! `Last-Modified` headers, which are sent back on the next request for the
+//! same URI. A `304 Not Modified` is then answered from the cache.
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    ffi::OsStr,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use http::Uri;
+
+/// A type for an HTTP cache.
+pub type BoxedHttpCache = Arc<dyn HttpCache>;
+
+/// A response cached for a URI.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The body of the response.
+    pub body: bytes::Bytes,
+    /// The `ETag` header of the response.
+    pub etag: Option<String>,
+    /// The `Last-Modified` header of the response.
+    pub last_modified: Option<String>,
+    /// The `Link` header of the response, linking to the other pages.
+    pub link: Option<String>,
+}
+
+impl CachedResponse {
+    /// The response to cache, if the server sent a validator for it.
+    pub fn from_response(headers: &http::HeaderMap, body: bytes::Bytes) -> Option<Self> {
+        let header = |name: http::header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let etag = header(http::header::ETAG);
+        let last_modified = header(http::header::LAST_MODIFIED);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+
+        Some(CachedResponse {
+            body,
+            etag,
+            last_modified,
+            link: header(http::header::LINK),
+        })
+    }
+
+    /// The headers making a request conditional on the response having changed.
+    pub fn conditional_headers(&self) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        let validators = [
+            (http::header::IF_NONE_MATCH, &self.etag),
+            (http::header::IF_MODIFIED_SINCE, &self.last_modified),
+        ];
+        for (name, value) in validators {
+            if let Some(value) = value.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+}
+
+/// Stores the responses to `GET` requests, by URI.
+#[async_trait::async_trait]
+pub trait HttpCache: Debug + Send + Sync {
+    /// The response cached for the URI, if any.
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>>;
+    /// Cache the response for the URI, replacing the one cached before.
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()>;
+}
+"#;
+
+const HTTP_CACHE_BACKENDS_TEMPLATE: &str = r#"
+/// Noop type for no cache.
+#[derive(Clone, Debug)]
+pub struct NoCache;
+
+#[async_trait::async_trait]
+impl HttpCache for NoCache {
+    async fn get(&self, _uri: &str) -> Result<Option<CachedResponse>> {
+        Ok(None)
+    }
+
+    async fn put(&self, _uri: &str, _response: CachedResponse) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// An in-memory cache, evicting the least recently used response once it
+/// holds `capacity` responses.
+#[derive(Clone, Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Arc<Mutex<MemoryEntries>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryEntries {
+    responses: HashMap<String, CachedResponse>,
+    /// The URIs of the responses, the least recently used first.
+    recency: VecDeque<String>,
+}
+
+impl MemoryEntries {
+    fn touch(&mut self, uri: &str) {
+        if let Some(i) = self.recency.iter().position(|u| u == uri) {
+            if let Some(uri) = self.recency.remove(i) {
+                self.recency.push_back(uri);
+            }
+        }
+    }
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> MemoryCache {
+        MemoryCache {
+            capacity,
+            entries: Default::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpCache for MemoryCache {
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>> {
+        let mut entries = self.entries.lock().unwrap();
+        let response = entries.responses.get(uri).cloned();
+        if response.is_some() {
+            entries.touch(uri);
+        }
+        Ok(response)
+    }
+
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let replaced = entries.responses.insert(uri.to_string(), response);
+        if replaced.is_some() {
+            entries.touch(uri);
+        } else {
+            entries.recency.push_back(uri.to_string());
+        }
+        while entries.responses.len() > self.capacity {
+            match entries.recency.pop_front() {
+                Some(evicted) => {
+                    entries.responses.remove(&evicted);
+                }
+                None => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A file based cache. The files are read and written without blocking the
+/// runtime.
+#[derive(Clone, Debug)]
+pub struct FileBasedCache {
+    root: PathBuf,
+}
+
+impl FileBasedCache {
+    pub fn new<P: Into<PathBuf>>(root: P) -> FileBasedCache {
+        FileBasedCache { root: root.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpCache for FileBasedCache {
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>> {
+        let mut path = cache_path(&self.root, uri, "json");
+        let body = match tokio::fs::read(&path).await {
+            Ok(body) => body,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        path.set_extension("etag");
+        let etag = read_optional(&path).await?;
+        path.set_extension("last_modified");
+        let last_modified = read_optional(&path).await?;
+        path.set_extension("link");
+        let link = read_optional(&path).await?;
+
+        Ok(Some(CachedResponse {
+            body: body.into(),
+            etag,
+            last_modified,
+            link,
+        }))
+    }
+
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()> {
+        let mut path = cache_path(&self.root, uri, "json");
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, &response.body).await?;
+        path.set_extension("etag");
+        write_optional(&path, &response.etag).await?;
+        path.set_extension("last_modified");
+        write_optional(&path, &response.last_modified).await?;
+        path.set_extension("link");
+        write_optional(&path, &response.link).await?;
+        Ok(())
+    }
+}
+"#;
+
+const HTTP_CACHE_HELPERS_TEMPLATE: &str = r#"
+/// The response cached for the URI. Failing to read the cache isn't fatal, the
+/// request is just sent unconditionally.
+pub(crate) async fn lookup(cache: &BoxedHttpCache, uri: &str) -> Option<CachedResponse> {
+    match cache.get(uri).await {
+        Ok(response) => response,
+        Err(e) => {
+            log::info!("failed to read the cached response: {}", e);
+            None
+        }
+    }
+}
+
+/// Cache a successful response, if the server sent a validator for it.
+pub(crate) async fn store(
+    cache: &BoxedHttpCache,
+    uri: &str,
+    headers: &http::HeaderMap,
+    body: &bytes::Bytes,
+) {
+    if let Some(response) = CachedResponse::from_response(headers, body.clone()) {
+        if let Err(e) = cache.put(uri, response).await {
+            // failing to cache isn't fatal, so just log & swallow the error
+            log::info!("failed to cache the response: {}", e);
+        }
+    }
+}
+
+async fn read_optional(path: &Path) -> Result<Option<String>> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn write_optional(path: &Path, value: &Option<String>) -> Result<()> {
+    match value {
+        Some(value) => Ok(tokio::fs::write(path, value).await?),
+        // Don't leave the value of an older response behind.
+        None => match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+    }
+}
+
+fn hash1<A: Hash, H: Hasher>(x: A, mut hasher: H) -> String {
+    x.hash(&mut hasher);
+    u64_to_padded_hex(hasher.finish())
+}
+"#;
+
+/// Generate the `http_cache` module, built with the `httpcache` feature.
+pub fn generate_http_cache(config: &Config) -> String {
+    let crate_name = config.name.replace('-', "_");
+    // Like `~/.github/cache` for GitHub.
+    let cache_dir = config
+        .proper_name
+        .to_lowercase()
+        .replace(' ', "-")
+        .replace('.', "");
+
+    format!(
+        r#"{HTTP_CACHE_TEMPLATE}
+impl dyn HttpCache {{
+    pub fn noop() -> BoxedHttpCache {{
+        Arc::new(NoCache)
+    }}
+
+    /// An in-memory cache of the `capacity` most recently used responses.
+    pub fn in_memory(capacity: usize) -> BoxedHttpCache {{
+        Arc::new(MemoryCache::new(capacity))
+    }}
+
+    pub fn in_home_dir() -> BoxedHttpCache {{
+        let mut dir = dirs::home_dir().expect("Expected a home dir");
+        dir.push(".{cache_dir}/cache");
+        Arc::new(FileBasedCache::new(dir))
+    }}
+}}
+{HTTP_CACHE_BACKENDS_TEMPLATE}
+/// Construct the cache path for the given URI and extension, from an initial directory.
+///
+/// # Examples
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use {crate_name}::http_cache::cache_path;
+/// assert_eq!(
+///     cache_path(
+///         &PathBuf::from("/home/.cache"),
+///         "https://example.com/users/dwijnand/repos",
+///         "json"
+///     ),
+///     PathBuf::from("/home/.cache/v1/https/example.com/users/dwijnand/repos.json"),
+/// );
+/// assert_eq!(
+///     cache_path(
+///         &PathBuf::from("/home/.cache"),
+///         "https://example.com/users/dwijnand/repos?page=2",
+///         "json"
+///     ),
+///     PathBuf::from(
+///         "/home/.cache/v1/https/example.com/users/dwijnand/repos/6dd58bde8abb0869.json"
+///     ),
+/// );
+/// ```
+#[doc(hidden)] // public for doc testing only
+pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathBuf {{
+    let uri_encoded = uri.replace(' ', "%20");
+    let uri = uri_encoded
+        .parse::<Uri>()
+        .unwrap_or_else(|_| panic!("Expected a URI, got {{}}", uri_encoded));
+    let parts = uri.clone().into_parts();
+    let mut path = dir.to_path_buf();
+    path.push("v1");
+    path.push(parts.scheme.expect("no URI scheme").as_str()); // https
+    path.push(parts.authority.expect("no URI authority").as_str()); // example.com
+    path.push(Path::new(&uri.path()[1..])); // users/dwijnand/repos
+    if let Some(query) = uri.query() {{
+        path.push(hash1(query, DefaultHasher::new())); // fa269019d5035d5f
+    }}
+    path.set_extension(extension); // .json
+    path
+}}
+{HTTP_CACHE_HELPERS_TEMPLATE}
+/// Construct a 0-padded hex string from a u64.
+///
+/// # Examples
+///
+/// ```
+/// # use {crate_name}::http_cache::u64_to_padded_hex;
+/// assert_eq!(u64_to_padded_hex(0), "0000000000000000");
+/// assert_eq!(u64_to_padded_hex(u64::max_value()), "ffffffffffffffff");
+/// ```
+#[doc(hidden)] // public for doc testing only
+pub fn u64_to_padded_hex(x: u64) -> String {{
+    format!("{{:016x}}", x)
+}}
+"#
+    )
+}
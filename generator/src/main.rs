@@ -1,11 +1,15 @@
+mod auth;
 mod client;
+mod config;
 mod functions;
+mod http_cache;
+mod mock;
 mod template;
 mod types;
 mod utils;
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ffi::OsStr,
     fs::{File, OpenOptions},
     io::Write,
@@ -20,6 +24,8 @@ use openapiv3::OpenAPI;
 use serde::Deserialize;
 
 use client::GeneratedServers;
+use auth::ClientAuth;
+use config::{AuthStyle, Config};
 
 fn save<P>(p: P, data: &str) -> Result<()>
 where
@@ -74,10 +80,6 @@ where
     }
 
     if let Some(components) = api.components.as_ref() {
-        if !components.security_schemes.is_empty() {
-            println!("component security schemes not supported");
-        }
-
         if !components.responses.is_empty() {
             println!("component responses not supported");
         }
@@ -725,6 +727,11 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * The types of form encoded request bodies. Those are mostly optional
+     * fields, so they get a `Default` implementation.
+     */
+    form_bodies: BTreeSet<TypeId>,
 }
 
 impl TypeSpace {
@@ -733,6 +740,7 @@ impl TypeSpace {
             next_id: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            form_bodies: BTreeSet::new(),
         }
     }
 
@@ -2179,7 +2187,14 @@ fn render_param(
         a("*/");
     }
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    // Some specs define an `unknown` value of their own.
+    let unknown = if enums.iter().any(|e| struct_name(e) == "Unknown") {
+        "UnknownValue"
+    } else {
+        "Unknown"
+    };
+
+    a("#[derive(PartialEq, Debug, Clone, JsonSchema)]");
 
     a(&format!("pub enum {} {{", sn));
     for e in &enums {
@@ -2195,9 +2210,10 @@ fn render_param(
         a("Noop,");
     }
 
-    // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
+    // Values the spec doesn't know about yet are kept as they were sent, so
+    // they survive a round-trip.
+    a("#[serde(skip)]");
+    a(&format!("{}(String),", unknown));
 
     a("}");
     a("");
@@ -2216,8 +2232,7 @@ fn render_param(
         a(&format!(r#"{}::Noop => "","#, sn));
     }
 
-    // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a(&format!(r#"{}::{}(s) => s.as_str(),"#, sn, unknown));
 
     a("}");
     a(".fmt(f)");
@@ -2225,6 +2240,38 @@ fn render_param(
     a("}");
     a("");
 
+    a(&format!("impl Serialize for {} {{", sn));
+    a("fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>");
+    a("where");
+    a("S: serde::Serializer,");
+    a("{");
+    a("serializer.serialize_str(&self.to_string())");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!("impl<'de> Deserialize<'de> for {} {{", sn));
+    a("fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>");
+    a("where");
+    a("D: serde::Deserializer<'de>,");
+    a("{");
+    a("let s = String::deserialize(deserializer)?;");
+    a("Ok(match s.as_str() {");
+    for e in &enums {
+        if struct_name(e).is_empty() {
+            continue;
+        }
+        a(&format!(r#""{}" => {}::{},"#, e, sn, struct_name(e)));
+    }
+    if !required && default.is_none() {
+        a(&format!(r#""" => {}::Noop,"#, sn));
+    }
+    a(&format!("_ => {}::{}(s),", sn, unknown));
+    a("})");
+    a("}");
+    a("}");
+    a("");
+
     // Add a default for the enum if it is not required.
     if !required || default.is_some() {
         a(&format!("impl Default for {} {{", sn));
@@ -2245,31 +2292,101 @@ fn render_param(
         a("}");
     }
 
+    a(&format!("impl {} {{", sn));
     // Add a method to check if it is empty if it has this Noop state.
     if !required && default.is_none() {
         a(&format!(
-            r#"impl {} {{
-            pub fn is_noop(&self) -> bool {{
+            r#"pub fn is_noop(&self) -> bool {{
                 matches!(self, {}::Noop)
-            }}
-    }}"#,
-            sn, sn
+            }}"#,
+            sn
         ));
         a("");
     }
+    a(&format!(
+        r#"/// Returns true if the value isn't one the spec defines.
+        pub fn is_unknown(&self) -> bool {{
+            matches!(self, {}::{}(_))
+        }}"#,
+        sn, unknown
+    ));
+    a("}");
+    a("");
 
     out.to_string()
 }
 
+/// Find the named schema the spec uses for the body of each 4xx response, and
+/// of the `default` response (keyed by `None`), which is how APIs like Stripe
+/// declare their errors. When operations disagree for a status code, the most
+/// common schema wins.
+fn error_schemas(api: &OpenAPI, ts: &mut TypeSpace) -> Result<BTreeMap<Option<u16>, String>> {
+    let mut counts: BTreeMap<Option<u16>, BTreeMap<String, usize>> = BTreeMap::new();
+
+    for (_, p) in api.paths.iter() {
+        let p = p.item()?;
+        for (_, o) in p.iter() {
+            let responses = o
+                .responses
+                .responses
+                .iter()
+                .filter_map(|(code, r)| match code {
+                    openapiv3::StatusCode::Code(c) if (400..500).contains(c) && *c != 429 => {
+                        Some((Some(*c), r))
+                    }
+                    _ => None,
+                })
+                .chain(o.responses.default.iter().map(|r| (None, r)));
+
+            for (code, r) in responses {
+                let r = match r {
+                    openapiv3::ReferenceOr::Item(r) => r,
+                    openapiv3::ReferenceOr::Reference { reference } => {
+                        let name = reference.trim_start_matches("#/components/responses/");
+                        match api
+                            .components
+                            .as_ref()
+                            .and_then(|c| c.responses.get(name))
+                            .and_then(|r| r.item().ok())
+                        {
+                            Some(r) => r,
+                            None => continue,
+                        }
+                    }
+                };
+
+                if let Some(openapiv3::ReferenceOr::Reference { reference }) = r
+                    .content
+                    .get("application/json")
+                    .and_then(|mt| mt.schema.as_ref())
+                {
+                    let id = ts.select_ref(None, reference)?;
+                    let rt = ts.render_type(&id, false)?;
+                    *counts.entry(code).or_default().entry(rt).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    Ok(counts
+        .into_iter()
+        .filter_map(|(code, types)| {
+            // Ties are broken by the type name so the output is stable.
+            types
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                .map(|(rt, _)| (code, rt))
+        })
+        .collect())
+}
+
 fn gen(
     api: &OpenAPI,
-    proper_name: &str,
-    host: &str,
+    config: &Config,
+    auth: &ClientAuth,
     tags: Vec<String>,
-    token_endpoint: &str,
-    user_consent_endpoint: &str,
-    add_post_header: &str,
     servers: &GeneratedServers,
+    error_schemas: &BTreeMap<Option<u16>, String>,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -2288,19 +2405,22 @@ fn gen(
     a("#![allow(missing_docs)]"); // TODO: Make this a deny.
     a("#![cfg_attr(docsrs, feature(doc_cfg))]");
     a("");
-    if proper_name == "GitHub" {
-        a("pub mod auth;");
-        a(r#"#[cfg(feature = "httpcache")]"#);
-        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
-        a("pub mod http_cache;");
-    }
-    if proper_name == "Google Drive"
-        || proper_name == "Google Sheets"
-        || proper_name == "SendGrid"
-        || proper_name == "Rev.ai"
-    {
-        a("pub mod traits;");
+    for module in &config.modules {
+        if let Some(feature) = &module.feature {
+            a(&format!(r#"#[cfg(feature = "{}")]"#, feature));
+            a(&format!(
+                r#"#[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#,
+                feature
+            ));
+        }
+        a(&format!("pub mod {};", module.name));
     }
+    a(r#"#[cfg(feature = "httpcache")]"#);
+    a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
+    a("pub mod http_cache;");
+    a(r#"#[cfg(feature = "mock")]"#);
+    a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]"#);
+    a("pub mod mock;");
     // Hopefully there is never a "tag" named after these reserved libs.
     a("pub mod types;");
     a("#[doc(hidden)]");
@@ -2311,11 +2431,8 @@ fn gen(
      * Tags are how functions are grouped.
      */
     for tag in api.tags.iter() {
-        if !tags.contains(&to_snake_case(&clean_name(&tag.name)))
-            && (proper_name == "Zoom" || proper_name == "DocuSign")
-        {
-            // Return early do nothing!
-            // This fixes Zoom and DocuSign where they list tags that have no associated functions.
+        if config.skip_empty_tags && !tags.contains(&to_snake_case(&clean_name(&tag.name))) {
+            // Some APIs list tags that have no associated functions.
             continue;
         }
 
@@ -2350,14 +2467,16 @@ fn gen(
 
     a("");
 
-    a("use anyhow::{anyhow, Error, Result};");
+    a("use anyhow::Result;");
     a("");
 
-    a(&format!(
-        r#"pub const FALLBACK_HOST: &str = "https://{}";"#,
-        host.trim_start_matches("https://")
-    ));
-    a("");
+    if config.host_variable.is_empty() {
+        a(&format!(
+            r#"pub const FALLBACK_HOST: &str = "https://{}";"#,
+            config.host.trim_start_matches("https://")
+        ));
+        a("");
+    }
 
     a("mod progenitor_support {");
     a("    use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};");
@@ -2402,42 +2521,43 @@ pub(crate) struct Message {
 
     a("");
 
+    a(&crate::client::generate_client_error(
+        error_schemas,
+        config.ok_envelope,
+    ));
+
+    a("");
+
+    a(&crate::client::generate_client_builder(config));
+
+    a("");
+
     // Print the client template.
-    if proper_name == "GitHub" {
-        let server_block = if servers.count > 0 {
-            servers.output.as_deref().unwrap()
-        } else {
-            ""
-        };
-        a(server_block);
-        a(crate::client::GITHUB_TEMPLATE);
-    } else if proper_name == "SendGrid"
-        || proper_name == "Giphy"
-        || proper_name == "Rev.ai"
-        || proper_name == "Okta"
-        || proper_name == "ShipBob"
-        || proper_name == "Stripe"
-    {
-        a(&crate::client::generate_client_generic_api_key(
-            proper_name,
-            add_post_header,
-            servers,
-        ));
-    } else if proper_name == "TripActions" {
-        a(&crate::client::generate_client_generic_client_credentials(
-            proper_name,
-            token_endpoint,
-            add_post_header,
-            servers,
-        ));
-    } else {
-        a(&crate::client::generate_client_generic_token(
-            proper_name,
-            token_endpoint,
-            user_consent_endpoint,
-            add_post_header,
-            servers,
-        ));
+    match auth.style {
+        AuthStyle::GitHub => {
+            let server_block = if servers.count > 0 {
+                servers.output.as_deref().unwrap()
+            } else {
+                ""
+            };
+            a(server_block);
+            a(crate::client::GITHUB_TEMPLATE);
+        }
+        AuthStyle::ApiKey => {
+            a(&crate::client::generate_client_generic_api_key(
+                config, auth, servers,
+            ));
+        }
+        AuthStyle::ClientCredentials => {
+            a(&crate::client::generate_client_generic_client_credentials(
+                config, auth, servers,
+            ));
+        }
+        AuthStyle::Token | AuthStyle::Google => {
+            a(&crate::client::generate_client_generic_token(
+                config, auth, servers,
+            ));
+        }
     }
 
     a("");
@@ -2447,11 +2567,8 @@ pub(crate) struct Message {
      * Tags are how functions are grouped.
      */
     for tag in api.tags.iter() {
-        if !tags.contains(&to_snake_case(&tag.name))
-            && (proper_name == "Zoom" || proper_name == "DocuSign")
-        {
-            // Return early do nothing!
-            // This fixes Zoom and DocuSign where they list tags that have no associated functions.
+        if config.skip_empty_tags && !tags.contains(&to_snake_case(&tag.name)) {
+            // Some APIs list tags that have no associated functions.
             continue;
         }
 
@@ -2507,9 +2624,8 @@ pub(crate) struct Message {
     Ok(out)
 }
 
-pub fn make_plural(proper_name: &str, s: &str) -> String {
-    // Only fix the ramp names.
-    if proper_name != "Ramp" && proper_name != "Okta" {
+pub fn make_plural(config: &Config, s: &str) -> String {
+    if !config.functions.make_plural {
         return s.to_string();
     }
 
@@ -2657,23 +2773,15 @@ pub fn path_to_operation_id(path: &str, method: &str) -> String {
     new
 }
 
-pub fn clean_fn_name(proper_name: &str, oid: &str, tag: &str) -> String {
-    if proper_name == "GitHub" {
+pub fn clean_fn_name(config: &Config, oid: &str, tag: &str) -> String {
+    if config.functions.raw_names {
         return to_snake_case(oid).trim_start_matches('_').to_string();
     }
 
-    let mut clean_name = "_".to_string();
-    if proper_name.starts_with("Google") {
-        clean_name = format!(
-            "{}_",
-            to_snake_case(
-                proper_name
-                    .replace("Google", "")
-                    .replace("Admin", "Directory")
-                    .trim()
-            )
-        );
-    }
+    let clean_name = match &config.functions.name_prefix {
+        Some(prefix) => format!("{}_", prefix),
+        None => "_".to_string(),
+    };
 
     let mut o = oid.to_string();
     if o == "listimmessages" {
@@ -2797,40 +2905,10 @@ fn main() -> Result<()> {
     let mut opts = getopts::Options::new();
     opts.parsing_style(getopts::ParsingStyle::StopAtFirstFree);
     opts.reqopt(
-        "i",
-        "",
-        "OpenAPI definition document (JSON | YAML)",
-        "INPUT",
-    );
-    opts.reqopt("o", "", "Generated Rust crate directory", "OUTPUT");
-    opts.reqopt("n", "", "Target Rust crate name", "CRATE");
-    opts.reqopt("v", "", "Target Rust crate version", "VERSION");
-    opts.reqopt("d", "", "Target Rust crate description", "DESCRIPTION");
-    opts.reqopt("", "host", "Target default host", "DEFAULT_HOST");
-    opts.reqopt(
-        "",
-        "proper-name",
-        "Target client proper name",
-        "PROPER_NAME",
-    );
-    opts.reqopt("", "spec-link", "Link to the spec", "SPEC_LINK");
-    opts.optopt(
-        "",
-        "token-endpoint",
-        "Target token endpoint",
-        "TOKEN_ENDPOINT",
-    );
-    opts.optopt(
-        "",
-        "user-consent-endpoint",
-        "Target user consent endpoint",
-        "USER_CONSENT_ENDPOINT",
-    );
-    opts.optopt(
-        "",
-        "add-post-header",
-        "A header to add to post requests",
-        "ADD_POST_HEADER",
+        "c",
+        "config",
+        "The generator.toml of the crate to generate",
+        "CONFIG",
     );
     opts.optflag("", "debug", "Print debug output");
 
@@ -2848,7 +2926,12 @@ fn main() -> Result<()> {
         }
     };
 
-    let api = load_api(args.opt_str("i").unwrap())?;
+    let config = config::Config::load(args.opt_str("c").unwrap())?;
+    let mut api = load_api(&config.spec)?;
+    if let Some(prefix) = &config.functions.version_prefix {
+        functions::collapse_versions(&mut api, prefix);
+    }
+    let auth = auth::client_auth(&config.spec, &config)?;
 
     let servers = client::generate_servers(&api.servers, "Root");
 
@@ -3001,7 +3084,6 @@ fn main() -> Result<()> {
      * In addition to types defined in schemas, types may be defined inline in
      * request and response bodies.
      */
-    let proper_name = args.opt_str("proper-name").unwrap();
     let mut tags: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
         let op = p.item()?;
@@ -3032,8 +3114,12 @@ fn main() -> Result<()> {
                 // future but for now it seems fairly consistent.
                 let mut tags = o.tags.clone();
                 if tags.is_empty() {
-                    // This "x-tags" bullshit is for Gusto.
-                    if let Some(x) = o.extensions.get("x-tags") {
+                    if let Some(x) = config
+                        .functions
+                        .tags_extension
+                        .as_ref()
+                        .and_then(|e| o.extensions.get(e))
+                    {
                         let xtags: Vec<String> = serde_json::from_value(x.clone()).unwrap();
                         tags = xtags;
                     }
@@ -3055,12 +3141,9 @@ fn main() -> Result<()> {
                     tags.push(t);
                 }
 
-                let tag = to_snake_case(&clean_name(&make_plural(
-                    &proper_name,
-                    tags.first().unwrap(),
-                )));
+                let tag = to_snake_case(&clean_name(&make_plural(&config, tags.first().unwrap())));
 
-                let oid = clean_fn_name(&proper_name, &od, &tag);
+                let oid = clean_fn_name(&config, &od, &tag);
 
                 debug("");
                 debug(&oid);
@@ -3070,11 +3153,17 @@ fn main() -> Result<()> {
                  */
                 let mut req: Vec<String> = Default::default();
                 if let Some(openapiv3::ReferenceOr::Item(body)) = &o.request_body {
+                    let multipart = functions::get_multipart_schema(&api, body);
                     for (ct, mt) in &body.content {
-                        if ct == "application/json" {
+                        if ct == "application/json"
+                            || (ct == "application/x-www-form-urlencoded" && multipart.is_none())
+                        {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
+                                if ct == "application/x-www-form-urlencoded" {
+                                    ts.form_bodies.insert(id.clone());
+                                }
                                 let rt = ts.render_type(&id, true)?;
                                 req.push(format!("{} {:?}", rt, id));
                             }
@@ -3082,6 +3171,21 @@ fn main() -> Result<()> {
                             req.push(ct.to_string());
                         }
                     }
+
+                    // The text parts of multipart forms can have types of their own.
+                    if let Some(openapiv3::SchemaKind::Type(openapiv3::Type::Object(ob))) =
+                        multipart.as_ref().map(|s| &s.schema_kind)
+                    {
+                        for (n, p) in ob.properties.iter() {
+                            if !functions::is_file_property(p) {
+                                ts.select_box(
+                                    Some(&functions::multipart_property_type_name(&od, n)),
+                                    p,
+                                    "",
+                                )?;
+                            }
+                        }
+                    }
                 } else if let Some(openapiv3::ReferenceOr::Reference { reference }) =
                     &o.request_body
                 {
@@ -3174,70 +3278,33 @@ fn main() -> Result<()> {
     }
     debug("");
 
-    let name = args.opt_str("n").unwrap();
-    let version = args.opt_str("v").unwrap();
-    let host = args.opt_str("host").unwrap();
-    let output_dir = args.opt_str("o").unwrap();
-    let spec_link = args.opt_str("spec-link").unwrap();
-    let token_endpoint = if let Some(te) = args.opt_str("token-endpoint") {
-        te
-    } else {
-        String::new()
-    };
-    let user_consent_endpoint = if let Some(uce) = args.opt_str("user-consent-endpoint") {
-        uce
-    } else {
-        String::new()
-    };
-
     // Sort our tags and de-duplicate them.
     tags.sort_unstable();
     tags.dedup();
 
-    let add_post_header = if let Some(ph) = args.opt_str("add-post-header") {
-        ph
-    } else {
-        String::new()
-    };
-    let fail = match gen(
-        &api,
-        &proper_name,
-        &host,
-        tags,
-        &token_endpoint,
-        &user_consent_endpoint,
-        &add_post_header,
-        &servers,
-    ) {
+    let error_schemas = error_schemas(&api, &mut ts)?;
+
+    let fail = match gen(&api, &config, &auth, tags, &servers, &error_schemas) {
         Ok(out) => {
-            let description = args.opt_str("d").unwrap();
+            let Config {
+                name,
+                version,
+                description,
+                proper_name,
+                spec_link,
+                output: output_dir,
+                ..
+            } = &config;
 
             /*
              * Create the top-level crate directory:
              */
-            let root = PathBuf::from(&output_dir);
+            let root = PathBuf::from(output_dir);
             std::fs::create_dir_all(&root)?;
 
             /*
              * Write the Cargo.toml file:
              */
-            let mut uuid_lib = "".to_string();
-            let mut yup_oauth2_lib = "".to_string();
-            if proper_name != "GitHub" {
-                uuid_lib = r#"
-bytes = { version = "1", features = ["serde"] }
-async-trait = "^0.1.51"
-uuid = { version = "1.1", features = ["serde", "v4"] }"#
-                    .to_string();
-            }
-
-            if proper_name.starts_with("Google") {
-                yup_oauth2_lib = r#"
-base64 = "^0.13"
-yup-oauth2 = "^8""#
-                    .to_string();
-            }
-
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3255,14 +3322,18 @@ license = "MIT"
 default = ["rustls-tls", "reqwest-tracing/opentelemetry_0_17"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
+# mock the API with a local wiremock server
+mock = ["wiremock"]
 native-tls = ["reqwest/default-tls", "openssl"]
 rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
 
 [dependencies]
 anyhow = "1"
 async-recursion = "^1.0"
+bytes = {{ version = "1", features = ["serde"] }}
 chrono = {{ version = "0.4", default-features = false, features = ["serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
@@ -3271,7 +3342,7 @@ openssl = {{ version = "0.10", default-features = false, optional = true }}
 parse_link_header = "0.3.3"
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.2"
-reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"] }}
+reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart", "stream"] }}
 reqwest-conditional-middleware = "0.1.0"
 reqwest-middleware = "0.1.5"
 reqwest-retry = "0.1.4"
@@ -3281,7 +3352,9 @@ schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+thiserror = "1"
+url = {{ version = "2", features = ["serde"] }}
+wiremock = {{ version = "0.5.17", optional = true }}{}
 tokio = {{ version = "1.25.0", features = ["full"] }}
 
 [dev-dependencies]
@@ -3292,59 +3365,81 @@ rand = "0.8.5"
 rsa = "0.8.1"
 tokio = {{ version = "1.25.0", features = ["test-util"] }}
 wiremock = "0.5.17"
+# The tests use the mocks and the response cache.
+{} = {{ path = ".", features = ["httpcache", "mock"] }}
 
 [package.metadata.docs.rs]
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                auth.dependencies(&config) + &config.dependencies(),
+                name
             );
             save(&toml, tomlout.as_str())?;
 
             /*
              * Generate our documentation for the library.
              */
-            let docs = if proper_name == "GitHub" {
-                template::generate_docs_github(
+            let mut docs = match auth.style {
+                AuthStyle::GitHub => template::generate_docs_github(
                     &api,
-                    &to_snake_case(&name),
-                    &version,
-                    &proper_name,
-                    host.trim_start_matches("https://"),
-                    &spec_link,
-                )
-            } else if proper_name == "SendGrid"
-                || proper_name == "Giphy"
-                || proper_name == "Rev.ai"
-                || proper_name == "Okta"
-                || proper_name == "ShipBob"
-                || proper_name == "Stripe"
-            {
-                template::generate_docs_generic_api_key(
+                    &to_snake_case(name),
+                    version,
+                    proper_name,
+                    config.host.trim_start_matches("https://"),
+                    spec_link,
+                ),
+                AuthStyle::ApiKey => template::generate_docs_generic_api_key(
                     &api,
-                    &to_snake_case(&name),
-                    &version,
-                    &proper_name,
-                    &spec_link,
-                )
-            } else if proper_name == "TripActions" {
-                template::generate_docs_generic_client_credentials(
+                    &to_snake_case(name),
+                    version,
+                    proper_name,
+                    spec_link,
+                ),
+                AuthStyle::ClientCredentials => template::generate_docs_generic_client_credentials(
                     &api,
-                    &to_snake_case(&name),
-                    &version,
-                    &proper_name,
-                    &spec_link,
-                )
-            } else {
-                template::generate_docs_generic_token(
+                    &to_snake_case(name),
+                    version,
+                    proper_name,
+                    spec_link,
+                ),
+                AuthStyle::Token | AuthStyle::Google => template::generate_docs_generic_token(
                     &api,
-                    &to_snake_case(&name),
-                    &version,
-                    &proper_name,
-                    &spec_link,
-                    &add_post_header,
-                )
+                    &to_snake_case(name),
+                    version,
+                    proper_name,
+                    spec_link,
+                    &config.auth.add_post_header,
+                    &config.host_variable,
+                    auth.style == AuthStyle::Google,
+                ),
             };
+            if !config.idempotency_key_header.is_empty() {
+                docs.push_str(&template::generate_docs_idempotency_key(
+                    &config.idempotency_key_header,
+                ));
+            }
+            if config.ok_envelope {
+                docs.push_str(&template::generate_docs_ok_envelope(&to_snake_case(name)));
+            }
+            if config.retry_after {
+                docs.push_str(&template::generate_docs_retry_after());
+            }
+            if !config.call_limit_header.is_empty() {
+                docs.push_str(&template::generate_docs_call_limit(
+                    &config.call_limit_header,
+                ));
+            }
+            for module in &config.modules {
+                if let Some(module_docs) = &module.docs {
+                    docs.push_str(&template::generate_docs_module(module_docs));
+                }
+            }
             let mut readme = root.clone();
             readme.push("README.md");
             save(
@@ -3378,15 +3473,23 @@ rustdoc-args = ["--cfg", "docsrs"]
             /*
              * Create the Rust utils module:
              */
-            let utils = utils::generate_utils(&proper_name);
+            let utils = utils::generate_utils(&config);
             let mut utilsrs = src.clone();
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust module caching responses:
+             */
+            let http_cache = http_cache::generate_http_cache(&config);
+            let mut http_cachers = src.clone();
+            http_cachers.push("http_cache.rs");
+            save(http_cachers, http_cache.as_str())?;
+
             /*
              * Create the Rust source types file containing the generated types:
              */
-            let types = types::generate_types(&mut ts, &proper_name)?;
+            let types = types::generate_types(&mut ts, &config)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
             save(typesrs, types.as_str())?;
@@ -3395,8 +3498,13 @@ rustdoc-args = ["--cfg", "docsrs"]
              * Create the Rust source files for each of the tags functions:
              */
 
-            match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
+            match functions::generate_files(&api, &config, &mut ts, &parameters) {
                 Ok(files) => {
+                    let mock = mock::generate_mock(&files);
+                    let mut mockrs = src.clone();
+                    mockrs.push("mock.rs");
+                    save(mockrs, mock.as_str())?;
+
                     // We have a map of our files, let's write to them.
                     for (f, output) in files {
                         let mut tagrs = src.clone();
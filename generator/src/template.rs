@@ -7,6 +7,9 @@ use inflector::cases::{kebabcase::to_kebab_case, snakecase::to_snake_case};
 enum Component {
     Constant(String),
     Parameter(String),
+    /// A constant right after a parameter, without a slash, like the `.json` of
+    /// Shopify's `/orders/{order_id}.json`.
+    Suffix(String),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -42,6 +45,21 @@ impl Template {
                         r#"if let Some(u) = {} {{ query_args.push(("{}".to_string(), u.to_string())); }}"#,
                         nam, prop
                     ));
+                } else if value == "Option<Vec<String>>" {
+                    a(&format!(
+                        r#"if let Some(v) = &{} {{ query_args.push(("{}".to_string(), v.join(" "))); }}"#,
+                        nam, prop
+                    ));
+                } else if value.starts_with("Option<Vec<") {
+                    a(&format!(
+                        r#"if let Some(v) = &{} {{ query_args.push(("{}".to_string(), v.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" "))); }}"#,
+                        nam, prop
+                    ));
+                } else if value.starts_with("Option<") {
+                    a(&format!(
+                        r#"if let Some(v) = &{} {{ query_args.push(("{}".to_string(), v.to_string())); }}"#,
+                        nam, prop
+                    ));
                 } else if value == "uuid::Uuid" {
                     a(&format!(
                         r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
@@ -97,7 +115,7 @@ impl Template {
         let mut has_params = false;
         for c in self.components.iter() {
             match c {
-                Component::Constant(_) => (),
+                Component::Constant(_) | Component::Suffix(_) => (),
                 Component::Parameter(_) => {
                     has_params = true;
                     break;
@@ -108,10 +126,13 @@ impl Template {
         if !has_params && query_params.is_empty() {
             out.push_str(r#"&""#);
             for c in self.components.iter() {
-                out.push('/');
                 match c {
-                    Component::Constant(n) => out.push_str(n),
-                    Component::Parameter(_) => (),
+                    Component::Constant(n) => {
+                        out.push('/');
+                        out.push_str(n);
+                    }
+                    Component::Parameter(_) => out.push('/'),
+                    Component::Suffix(n) => out.push_str(n),
                 }
             }
             out.push_str(&format!(r#"".to_string(), {server_arg});"#));
@@ -121,12 +142,15 @@ impl Template {
 
         out.push_str("&format!(\"");
         for c in self.components.iter() {
-            out.push('/');
             match c {
-                Component::Constant(n) => out.push_str(n),
+                Component::Constant(n) => {
+                    out.push('/');
+                    out.push_str(n);
+                }
                 Component::Parameter(_) => {
-                    out.push_str("{}");
+                    out.push_str("/{}");
                 }
+                Component::Suffix(n) => out.push_str(n),
             }
         }
 
@@ -135,26 +159,11 @@ impl Template {
         }
 
         out.push_str("\",\n");
-        for c in self.components.iter() {
-            if let Component::Parameter(n) = &c {
-                if n == "type"
-                    || n == "ref"
-                    || n == "foo"
-                    || n == "enum"
-                    || n == "const"
-                    || n == "use"
-                {
-                    out.push_str(&format!(
-                        "crate::progenitor_support::encode_path(&{}_.to_string()),",
-                        to_snake_case(n)
-                    ));
-                } else {
-                    out.push_str(&format!(
-                        "crate::progenitor_support::encode_path(&{}.to_string()),",
-                        to_snake_case(n)
-                    ));
-                }
-            }
+        for n in self.parameters() {
+            out.push_str(&format!(
+                "crate::progenitor_support::encode_path(&{}.to_string()),",
+                n
+            ));
         }
 
         if !query_params.is_empty() {
@@ -165,6 +174,62 @@ impl Template {
 
         out
     }
+
+    /// The names of the path parameters, as the functions take them.
+    pub fn parameters(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .filter_map(|c| match c {
+                Component::Parameter(n)
+                    if n == "type"
+                        || n == "ref"
+                        || n == "foo"
+                        || n == "enum"
+                        || n == "const"
+                        || n == "use" =>
+                {
+                    Some(format!("{}_", to_snake_case(n)))
+                }
+                Component::Parameter(n) => Some(to_snake_case(n)),
+                Component::Constant(_) | Component::Suffix(_) => None,
+            })
+            .collect()
+    }
+
+    /// An expression of the path the functions send the request to, without
+    /// the query.
+    pub fn compile_path(&self) -> String {
+        let mut path = String::new();
+        for c in self.components.iter() {
+            match c {
+                Component::Constant(n) => {
+                    path.push('/');
+                    path.push_str(n);
+                }
+                Component::Parameter(_) => path.push_str("/{}"),
+                Component::Suffix(n) => path.push_str(n),
+            }
+        }
+
+        if path.is_empty() {
+            path.push('/');
+        }
+
+        let parameters = self.parameters();
+        if parameters.is_empty() {
+            return format!("\"{}\".to_string()", path);
+        }
+
+        format!(
+            "format!(\"{}\", {})",
+            path,
+            parameters
+                .iter()
+                .map(|n| format!("crate::progenitor_support::encode_path(&{}.to_string())", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
 }
 
 pub fn parse(t: &str) -> Result<Template> {
@@ -178,6 +243,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         Parameter,
         ParameterSlash,
         Constant,
+        Suffix,
     }
 
     let mut s = State::Start;
@@ -214,6 +280,17 @@ fn parse_inner(t: &str) -> Result<Template> {
                     a.push(c);
                 }
             }
+            State::Suffix => {
+                if c == '/' {
+                    components.push(Component::Suffix(a));
+                    a = String::new();
+                    s = State::ConstantOrParameter;
+                } else if c == '{' || c == '}' {
+                    bail!("unexpected parameter");
+                } else {
+                    a.push(c);
+                }
+            }
             State::Parameter => {
                 if c == '}' {
                     components.push(Component::Parameter(a));
@@ -226,11 +303,14 @@ fn parse_inner(t: &str) -> Result<Template> {
                 }
             }
             State::ParameterSlash => {
-                if c == '/' || c == ':' || c == '.' {
+                if c == '/' {
+                    s = State::ConstantOrParameter;
+                } else if c == ':' || c == '.' {
                     // Google Admin API has ":issueCommand" so we want to allow that!
                     // Shopify sometimes ends after a parameter with ".json", so we want to allow
                     // that.
-                    s = State::ConstantOrParameter;
+                    a.push(c);
+                    s = State::Suffix;
                 } else {
                     bail!("expected a slash after parameter");
                 }
@@ -242,6 +322,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         State::Start => bail!("empty path"),
         State::ConstantOrParameter | State::ParameterSlash => (),
         State::Constant => components.push(Component::Constant(a)),
+        State::Suffix => components.push(Component::Suffix(a)),
         State::Parameter => bail!("unterminated parameter"),
     }
 
@@ -282,6 +363,16 @@ mod test {
                     ],
                 },
             ),
+            (
+                "/orders/{order_id}.json",
+                Template {
+                    components: vec![
+                        Component::Constant("orders".into()),
+                        Component::Parameter("order_id".into()),
+                        Component::Suffix(".json".into()),
+                    ],
+                },
+            ),
         ];
 
         for (path, want) in trials.iter() {
@@ -303,6 +394,79 @@ crate::progenitor_support::encode_path(&number.to_string()),), None);
         assert_eq!(want, &out);
         Ok(())
     }
+
+    #[test]
+    fn compile_keeps_separators() -> Result<()> {
+        let trials = vec![
+            (
+                "/v4/spreadsheets/{spreadsheetId}/values/{range}:append",
+                r#"&format!("/v4/spreadsheets/{}/values/{}:append",
+crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),crate::progenitor_support::encode_path(&range.to_string()),)"#,
+            ),
+            (
+                "/v4/spreadsheets/{spreadsheetId}:batchUpdate",
+                r#"&format!("/v4/spreadsheets/{}:batchUpdate",
+crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),)"#,
+            ),
+            (
+                "/v2/{name}:move",
+                r#"&format!("/v2/{}:move",
+crate::progenitor_support::encode_path(&name.to_string()),)"#,
+            ),
+            (
+                "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand",
+                r#"&format!("/admin/directory/v1/customer/{}/devices/chromeos/{}:issueCommand",
+crate::progenitor_support::encode_path(&customer_id.to_string()),crate::progenitor_support::encode_path(&device_id.to_string()),)"#,
+            ),
+            (
+                "/admin/api/2020-10/orders/{order_id}.json",
+                r#"&format!("/admin/api/2020-10/orders/{}.json",
+crate::progenitor_support::encode_path(&order_id.to_string()),)"#,
+            ),
+        ];
+
+        for (path, url) in trials.iter() {
+            let out = parse(path)?.compile(Default::default(), "None");
+            let want = format!("let url = self.client.url(\n{}, None);\n", url);
+            assert_eq!(want, out, "path {}", path);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_optional_query() -> Result<()> {
+        let t = parse("/pulls")?;
+        let mut query_params = std::collections::BTreeMap::new();
+        query_params.insert(
+            "params.draft".to_string(),
+            ("Option<bool>".to_string(), "draft".to_string()),
+        );
+        let out = t.compile(query_params, "None");
+        let want = r#"let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = &params.draft { query_args.push(("draft".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = self.client.url(
+&format!("/pulls?{}",
+query_), None);
+"#;
+        assert_eq!(want, &out);
+        Ok(())
+    }
+
+    #[test]
+    fn compile_path() -> Result<()> {
+        assert_eq!(parse("/info")?.compile_path(), r#""/info".to_string()"#);
+        assert_eq!(
+            parse("/repos/{owner}/{ref}")?.compile_path(),
+            r#"format!("/repos/{}/{}", crate::progenitor_support::encode_path(&owner.to_string()), crate::progenitor_support::encode_path(&ref_.to_string()))"#
+        );
+        assert_eq!(
+            parse("/devices/{deviceId}:issueCommand")?.compile_path(),
+            r#"format!("/devices/{}:issueCommand", crate::progenitor_support::encode_path(&device_id.to_string()))"#
+        );
+        Ok(())
+    }
 }
 
 pub fn generate_docs_github(
@@ -315,6 +479,7 @@ pub fn generate_docs_github(
 ) -> String {
     let info = generate_docs_openapi_info(api, proper_name, spec_link, name);
     let docs_proper_name = proper_name.to_lowercase();
+    let client_builder = generate_docs_client_builder(name);
 
     format!(
         r#"{info}
@@ -361,16 +526,19 @@ pub fn generate_docs_github(
 //! ```
 //!
 //! Then use the `Client::custom` constructor to provide a cache implementation.
+//! Responses are kept in memory with `<dyn HttpCache>::in_memory`, or in files
+//! with `<dyn HttpCache>::in_home_dir`. Implement `HttpCache` to store them
+//! elsewhere.
 //!
 //! Here is an example:
 //!
 //! ```rust
-//! use {name}::{{auth::Credentials, Client}};
+//! use {name}::{{auth::Credentials, Client, ClientBuilder}};
 //! #[cfg(feature = "httpcache")]
 //! use {name}::http_cache::HttpCache;
 //!
 //! #[cfg(feature = "httpcache")]
-//! let http_cache = HttpCache::in_home_dir();
+//! let http_cache = <dyn HttpCache>::in_home_dir();
 //!
 //! #[cfg(not(feature = "httpcache"))]
 //! let {docs_proper_name} = Client::custom(
@@ -378,7 +546,7 @@ pub fn generate_docs_github(
 //!     Credentials::Token(
 //!       String::from("personal-access-token")
 //!     ),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //! );
 //!
 //! #[cfg(feature = "httpcache")]
@@ -387,7 +555,7 @@ pub fn generate_docs_github(
 //!     Credentials::Token(
 //!       String::from("personal-access-token")
 //!     ),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //!     http_cache
 //! );
 //! ```
@@ -400,7 +568,7 @@ pub fn generate_docs_github(
 //! ```rust
 //! use std::env;
 //!
-//! use {name}::{{Client, auth::{{Credentials, InstallationTokenGenerator, JWTCredentials}}}};
+//! use {name}::{{Client, ClientBuilder, auth::{{Credentials, InstallationTokenGenerator, JWTCredentials}}}};
 //! #[cfg(feature = "httpcache")]
 //! use {name}::http_cache::FileBasedCache;
 //!
@@ -425,7 +593,7 @@ pub fn generate_docs_github(
 //! #[cfg(feature = "httpcache")]
 //! dir.push(".cache/github");
 //! #[cfg(feature = "httpcache")]
-//! let http_cache = Box::new(FileBasedCache::new(dir));
+//! let http_cache = std::sync::Arc::new(FileBasedCache::new(dir));
 //!
 //! let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 //!
@@ -433,18 +601,48 @@ pub fn generate_docs_github(
 //! let {docs_proper_name} = Client::custom(
 //!     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
 //!     Credentials::InstallationToken(token_generator),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //! );
 //!
 //! #[cfg(feature = "httpcache")]
 //! let {docs_proper_name} = Client::custom(
 //!     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
 //!     Credentials::InstallationToken(token_generator),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //!     http_cache,
 //! );
 //! ```
 //!
+//! ## Rate limits
+//!
+//! The client keeps the latest rate limits GitHub reports for each resource,
+//! like `core` or `search`, which can be read with `client.rate_limits()`.
+//! With a policy, it also holds back requests that would be rate limited,
+//! either waiting for the limit to reset or failing fast.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use octorust::{{auth::Credentials, rate_limiter::{{RateLimitPolicy, Resource}}, Client}};
+//!
+//! async fn zen() {{
+//!     let mut github = Client::new(
+//!         String::from("user-agent-name"),
+//!         Credentials::Token(String::from("personal-access-token")),
+//!     )
+//!     .unwrap();
+//!     github.with_rate_limit_policy(RateLimitPolicy::Wait {{
+//!         max_wait: Duration::from_secs(60),
+//!     }});
+//!
+//!     github.meta().get_zen().await.unwrap();
+//!     if let Some(core) = github.rate_limits().get(&Resource::Core) {{
+//!         println!("{{}} requests left", core.remaining);
+//!     }}
+//! }}
+//! ```
+//!
+//!{client_builder}
 //! ## Acknowledgements
 //!
 //! Shout out to [hubcaps](https://github.com/softprops/hubcaps) for paving the
@@ -588,6 +786,7 @@ pub fn generate_docs_openapi_info(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn generate_docs_generic_token(
     api: &openapiv3::OpenAPI,
     name: &str,
@@ -595,9 +794,42 @@ pub fn generate_docs_generic_token(
     proper_name: &str,
     spec_link: &str,
     add_post_header: &str,
+    host_variable: &str,
+    google: bool,
 ) -> String {
     let info = generate_docs_openapi_info(api, proper_name, spec_link, name);
 
+    let service_account = if google {
+        format!(
+            r#"
+//! ## Service accounts
+//!
+//! Headless jobs can authenticate as a service account instead, with its JSON
+//! key. The client mints access tokens for the scopes with the JWT bearer
+//! grant, and mints new ones once they expire. With a subject, the service
+//! account impersonates that user through domain-wide delegation.
+//!
+//! ```no_run
+//! use {}::Client;
+//!
+//! async fn do_call() {{
+//!     let key = yup_oauth2::read_service_account_key("service-account.json")
+//!         .await
+//!         .unwrap();
+//!     let client = Client::new_from_service_account(
+//!         key,
+//!         &["https://www.googleapis.com/auth/cloud-platform"],
+//!         Some("admin@example.com"),
+//!     );
+//! }}
+//! ```
+//!"#,
+            name
+        )
+    } else {
+        String::new()
+    };
+
     let add_post_header_args = if !add_post_header.is_empty() {
         format!(
             ",\n//!     String::from(\"{}\")",
@@ -613,6 +845,35 @@ pub fn generate_docs_generic_token(
         String::new()
     };
 
+    let (host_variable_arg, host_variable_env, unwrap, consent) = if host_variable.is_empty() {
+        (
+            String::new(),
+            String::new(),
+            "",
+            format!(
+                r#"//!     // Get the URL to request consent from the user.
+//!     // You can optionally pass in scopes. If none are provided, then the
+//!     // resulting URL will not have any scopes.
+//!     let user_consent_url = {}.user_consent_url(&["some-scope".to_string()]);"#,
+                proper_name.to_lowercase()
+            ),
+        )
+    } else {
+        (
+            format!(",\n//!     String::from(\"my-{}\")", host_variable),
+            format!(
+                "\n//! - `{}_{}`",
+                proper_name.to_uppercase(),
+                host_variable.to_uppercase()
+            ),
+            ".unwrap()",
+            format!(
+                "//!     // The `{}` module builds the URL to request consent from the user.",
+                host_variable
+            ),
+        )
+    };
+
     format!(
         r#"{}
 //!
@@ -636,8 +897,8 @@ pub fn generate_docs_generic_token(
 //!     String::from("client-secret"),
 //!     String::from("redirect-uri"),
 //!     String::from("token"),
-//!     String::from("refresh-token"){}
-//! );
+//!     String::from("refresh-token"){}{host_variable_arg}
+//! ){unwrap};
 //! ```
 //!
 //! Alternatively, the library can search for most of the variables required for
@@ -645,7 +906,7 @@ pub fn generate_docs_generic_token(
 //!
 //! - `{}_CLIENT_ID`
 //! - `{}_CLIENT_SECRET`
-//! - `{}_REDIRECT_URI`
+//! - `{}_REDIRECT_URI`{host_variable_env}
 //!
 //! And then you can create a client from the environment.
 //!
@@ -655,7 +916,7 @@ pub fn generate_docs_generic_token(
 //! let {} = Client::new_from_env(
 //!     String::from("token"),
 //!     String::from("refresh-token"){}
-//! );
+//! ){unwrap};
 //! ```
 //!
 //! It is okay to pass empty values for `token` and `refresh_token`. In
@@ -667,12 +928,9 @@ pub fn generate_docs_generic_token(
 //! use {}::Client;
 //!
 //! async fn do_call() {{
-//!     let mut {} = Client::new_from_env("", ""{});
+//!     let mut {} = Client::new_from_env("", ""{}){unwrap};
 //!
-//!     // Get the URL to request consent from the user.
-//!     // You can optionally pass in scopes. If none are provided, then the
-//!     // resulting URL will not have any scopes.
-//!     let user_consent_url = {}.user_consent_url(&["some-scope".to_string()]);
+{consent}
 //!
 //!     // In your redirect URL capture the code sent and our state.
 //!     // Send it along to the request for the token.
@@ -685,7 +943,13 @@ pub fn generate_docs_generic_token(
 //!     access_token = {}.refresh_access_token().await.unwrap();
 //! }}
 //! ```
-//!"#,
+//!
+//! To keep the tokens in your database as they change, give the client a
+//! `TokenStore` with `set_token_store`. The client hands it the tokens whenever
+//! it gets new ones, including a refresh token rotated by a refresh. With
+//! `set_auto_access_token_refresh` enabled, a request refused with a 401 is
+//! also retried once after refreshing the access token.
+//!{}{}{}"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
@@ -703,7 +967,9 @@ pub fn generate_docs_generic_token(
         add_post_header_var,
         proper_name.to_lowercase(),
         proper_name.to_lowercase(),
-        proper_name.to_lowercase(),
+        service_account,
+        generate_docs_http_cache(name, version),
+        generate_docs_client_builder(name),
     )
 }
 
@@ -750,7 +1016,7 @@ pub fn generate_docs_generic_api_key(
 //!
 //! let {} = Client::new_from_env();
 //! ```
-//!"#,
+//!{}{}"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
@@ -759,6 +1025,8 @@ pub fn generate_docs_generic_api_key(
         proper_name.to_uppercase(),
         name,
         proper_name.to_lowercase(),
+        generate_docs_http_cache(name, version),
+        generate_docs_client_builder(name),
     )
 }
 
@@ -825,7 +1093,7 @@ pub fn generate_docs_generic_client_credentials(
 //!     let mut access_token = {}.get_access_token().await.unwrap();
 //! }}
 //! ```
-//!"#,
+//!{}{}"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
@@ -838,5 +1106,144 @@ pub fn generate_docs_generic_client_credentials(
         name,
         proper_name.to_lowercase(),
         proper_name.to_lowercase(),
+        generate_docs_http_cache(name, version),
+        generate_docs_client_builder(name),
+    )
+}
+
+/// The docs of the `httpcache` feature of the generic clients.
+fn generate_docs_http_cache(name: &str, version: &str) -> String {
+    format!(
+        r#"
+//! ## Caching responses
+//!
+//! With the `httpcache` feature, the client caches the responses to `GET`
+//! requests and sends their `ETag` and `Last-Modified` back. A `304 Not
+//! Modified` is then answered from the cache.
+//!
+//! ```toml
+//! [dependencies]
+//! {} = {{ version = "{}", features = ["httpcache"] }}
+//! ```
+//!
+//! The cache keeps the most recently used responses in memory, or the
+//! responses in files. Implement `HttpCache` to store them elsewhere.
+//!
+//! ```ignore
+//! use {}::http_cache::HttpCache;
+//!
+//! client.with_http_cache(<dyn HttpCache>::in_memory(1000));
+//! ```
+//!"#,
+        name.replace('_', "-").to_lowercase(),
+        version,
+        name,
+    )
+}
+
+/// The docs of the idempotency keys, for the APIs taking one.
+pub fn generate_docs_idempotency_key(header: &str) -> String {
+    format!(
+        r#"
+//! ## Idempotent requests
+//!
+//! The client sends a new random `{header}` with each `POST` request, so
+//! a request retried after a timeout isn't carried out twice. To send a request
+//! again yourself, give it the same key.
+//!
+//! ```ignore
+//! let client = client.with_idempotency_key("order-1234");
+//! // Send the request with this client, and with it again if it failed.
+//! ```
+//!"#
+    )
+}
+
+/// The docs of the `"ok": false` errors, for the APIs answering failures with
+/// `200 OK`.
+pub fn generate_docs_ok_envelope(name: &str) -> String {
+    format!(
+        r#"
+//! ## Errors
+//!
+//! The API answers most failures with `200 OK` and `"ok": false`. The client
+//! returns them as a `ClientError::NotOk`, with the `error` code of the API, its
+//! `warning` and the `response_metadata.messages`.
+//!
+//! ```ignore
+//! use {name}::ClientError;
+//!
+//! if let Some(ClientError::NotOk {{ error, .. }}) = err.downcast_ref::<ClientError>() {{
+//!     eprintln!("the API said {{}}", error);
+//! }}
+//! ```
+//!"#
+    )
+}
+
+/// The docs of the `Retry-After` waits, for the APIs asking for them.
+pub fn generate_docs_retry_after() -> String {
+    r#"
+//! ## Rate limits
+//!
+//! When the API answers `429 Too Many Requests`, the client waits as long as
+//! its `Retry-After` header says, up to a minute, and sends the request again,
+//! up to 3 times. A request still rate limited fails with a
+//! `ClientError::RateLimited`.
+//!"#
+        .to_string()
+}
+
+/// The docs of the waits for the leaky bucket, for the APIs reporting its fill.
+pub fn generate_docs_call_limit(header: &str) -> String {
+    format!(
+        r#"
+//! ## Call limit
+//!
+//! The API counts the requests in a leaky bucket, and reports its fill in the
+//! `{header}` header. The client keeps track of it,
+//! shares it with its clones, and holds the requests that would overflow the
+//! bucket until it has leaked enough.
+//!"#
+    )
+}
+
+/// The docs of a hand written module, as configured in its `[[modules]]`.
+pub fn generate_docs_module(docs: &str) -> String {
+    let mut section = String::from("\n");
+    for line in docs.trim().lines() {
+        section.push_str(format!("//! {}", line).trim_end());
+        section.push('\n');
+    }
+    section.push_str("//!");
+    section
+}
+
+fn generate_docs_client_builder(name: &str) -> String {
+    format!(
+        r#"
+//! ## Configuring the HTTP client
+//!
+//! The client traces its requests and retries the transient failures up to 3
+//! times, unless sending a request again could repeat its side effects, like
+//! a `POST` without an `Idempotency-Key`. To set timeouts, a proxy, another
+//! retry policy, the user agent, default headers or more middleware, build the
+//! HTTP client with a `ClientBuilder`. Building it returns an error instead of
+//! panicking.
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use {}::ClientBuilder;
+//!
+//! let http = ClientBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .connect_timeout(Duration::from_secs(5))
+//!     .no_retries()
+//!     .build()?;
+//! client.with_http_client(http);
+//! ```
+//!"#,
+        name,
     )
 }
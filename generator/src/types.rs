@@ -3,12 +3,12 @@ use std::collections::BTreeMap;
 use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::{render_param, struct_name, TypeDetails, TypeSpace};
+use crate::{config::Config, render_param, struct_name, TypeDetails, TypeSpace};
 
 /*
  * Declare named types we know about:
  */
-pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
+pub fn generate_types(ts: &mut TypeSpace, config: &Config) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -42,15 +42,15 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                 }
                 TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
                 TypeDetails::AnyOf(omap, _) => {
-                    // When Stripe uses anyof what they really mean is one of.
+                    // Some APIs use anyof when what they really mean is one of.
                     // Because something can not both be a string and an object.
-                    if proper_name == "Stripe" {
+                    if config.types.any_of_as_one_of {
                         a(&do_one_of_type(ts, omap, sn));
                     } else {
-                        a(&do_all_of_type(ts, omap, sn));
+                        a(&do_all_of_type(ts, omap, sn, config));
                     }
                 }
-                TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
+                TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn, config)),
                 TypeDetails::Object(omap, schema_data) => {
                     // panic!("{:#?} {:#?} {:#?}", te.details, omap, schema_data);
                     /*
@@ -76,60 +76,42 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     // this is gated by the oneof types cooperating.
                     if sn == "Page"
                         || sn.ends_with("Page")
-                        || sn == "PagesSourceHash"
-                        || sn == "PagesHttpsCertificate"
-                        || sn == "ErrorDetails"
-                        || sn == "EnvelopeDefinition"
-                        || (sn == "Event" && proper_name != "Stripe")
-                        || sn == "User"
-                        || sn == "Group"
-                        || sn == "CalendarResource"
-                        || sn == "Building"
-                        || sn == "Repo"
-                        || sn == "Payload"
-                        || sn == "Actor"
-                        || sn == "File"
-                        || sn == "PostMailSendRequest"
-                        || sn == "FromEmailObject"
-                        || sn == "Personalizations"
-                        || sn == "DescriptionlessJobOptions"
-                        || sn == "DescriptionlessJobOptionsData"
-                        || sn == "DescriptionlessJobOptionsDataType"
-                        || sn == "SubmitJobOptions"
-                        || sn == "SubmitJobOptionsData"
-                        || sn == "MinimalRepository"
-                        || sn == "WorkflowRun"
-                        || sn == "CheckAnnotation"
+                        || config.derives_default(&sn)
+                        || ts.form_bodies.contains(&te.id)
                     {
-                        a(
+                        a(&format!(
                             "#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, \
-                             JsonSchema)]",
-                        );
+                             JsonSchema{})]",
+                            config.extra_derives(&sn)
+                        ));
                     } else {
-                        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, \
-                             JsonSchema)]");
+                        a(&format!(
+                            "#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, \
+                             JsonSchema{})]",
+                            config.extra_derives(&sn)
+                        ));
                     }
                     a(&format!("pub struct {} {{", sn));
                     for (name, tid) in omap.iter() {
                         if let Ok(mut rt) = ts.render_type(tid, true) {
-                            // Stripe has some really weird recursive types.
-                            if rt.ends_with("AnyOf") && proper_name == "Stripe" {
-                                // Stripe uses anyof, but we want oneof.
+                            // Anyof types rendered as oneof are often recursive.
+                            let any_of = config.types.any_of_as_one_of;
+                            if rt.ends_with("AnyOf") && any_of {
                                 rt = format!("Box<{}>", rt);
-                            } else if ((rt.ends_with("AnyOf>") && rt.starts_with("Option<"))
-                                || rt == "Option<ApiErrors>"
-                                || rt == "Option<PaymentIntent>")
-                                && proper_name == "Stripe"
+                            } else if (rt.ends_with("AnyOf>")
+                                && rt.starts_with("Option<")
+                                && any_of)
+                                || config
+                                    .types
+                                    .boxed
+                                    .iter()
+                                    .any(|b| rt == format!("Option<{}>", b))
                             {
-                                // Stripe uses anyof, but we want oneof.
                                 rt = format!(
                                     "Box<Option<{}>>",
                                     rt.trim_start_matches("Option<").trim_end_matches('>')
                                 );
-                            } else if rt.ends_with("AnyOf>")
-                                && rt.starts_with("Vec<")
-                                && proper_name == "Stripe"
-                            {
+                            } else if rt.ends_with("AnyOf>") && rt.starts_with("Vec<") && any_of {
                                 // Stripe uses anyof, but we want oneof.
                                 rt = format!(
                                     "Box<Vec<{}>>",
@@ -202,25 +184,22 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::date_time_format::deserialize","#);
 
-                                    // Google Calendar is weird and requires a custom format.
-                                    if proper_name == "Google Calendar" {
+                                    // Some APIs require a custom format.
+                                    if let Some(m) = &config.types.date_time_serializer {
                                         // We need to serialize with the right format!
-                                        a(
-                                            r#"serialize_with = "crate::utils::google_calendar_date_time_format::serialize","#,
-                                        );
+                                        a(&format!(
+                                            r#"serialize_with = "crate::utils::{}::serialize","#,
+                                            m
+                                        ));
                                     }
                                 } else if rt.starts_with("Option<") {
-                                    if (prop == "required_pull_request_reviews"
-                                        || prop == "required_status_checks"
-                                        || prop == "restrictions")
-                                        && proper_name == "GitHub"
-                                    {
+                                    if config.types.nullable_fields.contains(&prop) {
                                     } else {
                                         a(r#"skip_serializing_if = "Option::is_none","#);
                                     }
                                 }
                             } else if rt == "bool" {
-                                if sn.ends_with("Request") || proper_name == "Google Drive" {
+                                if sn.ends_with("Request") || config.types.optional_bools {
                                     // We have a request, we want to make sure our bools are
                                     // options so we don't have to always provide them.
                                     a(
@@ -247,7 +226,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 a(r#"#[serde(default,
                                     skip_serializing_if = "crate::utils::zero_f64",
                                     deserialize_with = "crate::utils::deserialize_null_f64::deserialize","#);
-                            } else if rt == "u32" || rt == "u64" {
+                            } else if rt == "u32" || rt == "u64" || rt.starts_with("Box<Option<") {
                                 a(r#"#[serde(default,"#);
                             } else if let TypeDetails::Enum(_, sd) = &te.details {
                                 // We for sure have a default for every single enum, even
@@ -399,6 +378,39 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
+    if flatten {
+        // The enum variants keep values the spec doesn't know about, surface
+        // that here too.
+        let enums = omap
+            .iter()
+            .filter(|tid| {
+                ts.id_to_entry
+                    .get(tid)
+                    .is_some_and(|et| et.details.is_enum())
+            })
+            .map(|tid| {
+                let name = ts.render_type(tid, true).unwrap();
+                format!("{}::{}(e) => e.is_unknown(),", sn, struct_name(&name))
+            })
+            .collect::<Vec<_>>();
+        let rest = if enums.len() < name_map.len() {
+            "_ => false,"
+        } else {
+            ""
+        };
+        a(&format!(
+            r#"/// Returns true if this holds a value the spec doesn't define.
+            pub fn is_unknown(&self) -> bool {{
+                match self {{
+                    {}
+                    {}
+                }}
+            }}"#,
+            enums.join("\n"),
+            rest
+        ));
+        a("");
+    }
     for (fn_name, name) in &name_map {
         if name_map.len() > 1 {
             a(&format!(
@@ -500,7 +512,12 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     out
 }
 
-fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn do_all_of_type(
+    ts: &mut TypeSpace,
+    omap: &[crate::TypeId],
+    sn: String,
+    config: &Config,
+) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -519,10 +536,16 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    if sn == "SubmitJobOptionsAllOf" || sn == "DescriptionlessJobOptionsAllOf" {
-        a("#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone, JsonSchema)]");
+    if config.derives_default(&sn) {
+        a(&format!(
+            "#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone, JsonSchema{})]",
+            config.extra_derives(&sn)
+        ));
     } else {
-        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+        a(&format!(
+            "#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema{})]",
+            config.extra_derives(&sn)
+        ));
     }
     a(&format!("pub struct {} {{", sn));
     let mut name_map: BTreeMap<String, String> = Default::default();
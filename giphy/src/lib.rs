@@ -58,6 +58,48 @@
 //! let giphy = Client::new_from_env();
 //! ```
 //!
+//! ## Caching responses
+//!
+//! With the `httpcache` feature, the client caches the responses to `GET`
+//! requests and sends their `ETag` and `Last-Modified` back. A `304 Not
+//! Modified` is then answered from the cache.
+//!
+//! ```toml
+//! [dependencies]
+//! giphy-api = { version = "0.4.0", features = ["httpcache"] }
+//! ```
+//!
+//! The cache keeps the most recently used responses in memory, or the
+//! responses in files. Implement `HttpCache` to store them elsewhere.
+//!
+//! ```ignore
+//! use giphy_api::http_cache::HttpCache;
+//!
+//! client.with_http_cache(<dyn HttpCache>::in_memory(1000));
+//! ```
+//!
+//! ## Configuring the HTTP client
+//!
+//! The client traces its requests and retries the transient failures up to 3
+//! times, unless sending a request again could repeat its side effects, like
+//! a `POST` without an `Idempotency-Key`. To set timeouts, a proxy, another
+//! retry policy, the user agent, default headers or more middleware, build the
+//! HTTP client with a `ClientBuilder`. Building it returns an error instead of
+//! panicking.
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use giphy_api::ClientBuilder;
+//!
+//! let http = ClientBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .connect_timeout(Duration::from_secs(5))
+//!     .no_retries()
+//!     .build()?;
+//! client.with_http_client(http);
+//! ```
+//!
 #![allow(clippy::derive_partial_eq_without_eq)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -67,12 +109,18 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod gifs;
+#[cfg(feature = "httpcache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+pub mod http_cache;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
 pub mod stickers;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::Result;
 
 pub const FALLBACK_HOST: &str = "https://api.giphy.com/v1";
 
@@ -102,6 +150,248 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// Errors returned by the client.
+///
+/// Every method returns an `anyhow::Result`. When a request fails because of the
+/// API or the transport, the error holds a `ClientError`, which can be recovered
+/// with `err.downcast_ref::<ClientError>()` and matched on.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The API responded with a non-success status code.
+    #[error("code: {status}, error: {body:?}")]
+    HttpError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: String,
+    },
+    /// The API refused the request because a rate limit was exceeded.
+    #[error("code: {status}, rate limit exceeded")]
+    RateLimited {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// When the rate limit resets, if the response said so.
+        reset: Option<std::time::SystemTime>,
+    },
+    /// Sending the request or reading the response failed.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    /// A middleware failed while sending the request.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// The response body could not be deserialized.
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    /// The request URL could not be parsed.
+    #[error(transparent)]
+    UrlParse(#[from] url::ParseError),
+    /// Refreshing the credentials used to authenticate the request failed.
+    #[error("refreshing credentials failed: {0}")]
+    AuthRefresh(anyhow::Error),
+}
+
+impl ClientError {
+    /// The status code the API responded with, if it responded.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            ClientError::HttpError { status, .. } | ClientError::RateLimited { status, .. } => {
+                Some(*status)
+            }
+            ClientError::Reqwest(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Build the error for a response with a non-success status code.
+    pub(crate) fn from_response(
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let retry_after = header("retry-after");
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || header("x-ratelimit-remaining") == Some(0)
+            || (status == reqwest::StatusCode::FORBIDDEN && retry_after.is_some())
+        {
+            let now = std::time::SystemTime::now();
+            let reset = match (retry_after, header("x-ratelimit-reset")) {
+                (Some(secs), _) => Some(now + std::time::Duration::from_secs(secs)),
+                // Some APIs send a unix timestamp, others the number of seconds left.
+                (None, Some(reset)) if reset > 1_000_000_000 => {
+                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+                }
+                (None, Some(secs)) => Some(now + std::time::Duration::from_secs(secs)),
+                (None, None) => None,
+            };
+
+            return ClientError::RateLimited {
+                status,
+                headers,
+                reset,
+            };
+        }
+
+        ClientError::HttpError {
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+}
+
+/// Builds the HTTP client the API client sends its requests with, to hand to
+/// `Client::with_http_client`.
+///
+/// By default, requests are traced and the transient failures are retried up
+/// to 3 times with increasing intervals between attempts. Only the requests
+/// that can safely be sent again are retried: the ones with an idempotent
+/// method, like `GET` or `PUT`, and the ones carrying an `Idempotency-Key`.
+pub struct ClientBuilder {
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<reqwest_retry::policies::ExponentialBackoff>,
+    retry_any_method: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    middleware: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            // Retry up to 3 times with increasing intervals between attempts.
+            retry_policy: Some(
+                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3),
+            ),
+            retry_any_method: false,
+            user_agent: None,
+            default_headers: Default::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Time out the requests, from connecting until the end of the response
+    /// body, after `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time out the connections to the server after `timeout`.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Retry the transient failures with this policy instead of the default one.
+    pub fn retry_policy(mut self, policy: reqwest_retry::policies::ExponentialBackoff) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Don't retry failed requests.
+    pub fn no_retries(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// Also retry the requests that aren't idempotent, like a `POST` without an
+    /// `Idempotency-Key`. A request that timed out may then be carried out twice.
+    pub fn retry_any_method(mut self) -> Self {
+        self.retry_any_method = true;
+        self
+    }
+
+    /// Send this `User-Agent` header with every request.
+    pub fn user_agent<A>(mut self, user_agent: A) -> Self
+    where
+        A: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send these headers with every request, besides the ones of the request.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Add a middleware layer. The layers run in the order they are added, after
+    /// the tracing and each time a request is retried.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the HTTP client. This fails when the user agent isn't a valid header
+    /// value or when the TLS backend can't be initialized.
+    pub fn build(self) -> Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(reqwest::header::HeaderValue::from_str(&user_agent)?);
+        }
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default());
+        if let Some(retry_policy) = self.retry_policy {
+            let retry_any_method = self.retry_any_method;
+            // Retry failed requests.
+            client = client.with(reqwest_conditional_middleware::ConditionalMiddleware::new(
+                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                move |req: &reqwest::Request| {
+                    req.try_clone().is_some() && (retry_any_method || is_idempotent(req))
+                },
+            ));
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+/// Whether sending the request twice has the same effect as sending it once.
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    req.method().is_idempotent() || req.headers().contains_key("idempotency-key")
+}
+
 use std::env;
 
 #[derive(Debug, Default, Clone)]
@@ -113,13 +403,22 @@ impl RootDefaultServer {
     }
 }
 
+/// The credentials sent with each request.
+#[derive(Clone)]
+pub enum Credentials {
+    /// Sent as the `api_key` query parameter.
+    ApiKey(String),
+}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
     host: String,
     host_override: Option<String>,
-    token: String,
+    credentials: Credentials,
 
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -131,32 +430,21 @@ impl Client {
     where
         T: ToString,
     {
-        let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                let host = RootDefaultServer::default().default_url().to_string();
-
-                Client {
-                    host,
-                    host_override: None,
-                    token: token.to_string(),
-
-                    client,
-                }
-            }
+        let client = match ClientBuilder::new().build() {
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        };
+
+        let host = RootDefaultServer::default().default_url().to_string();
+
+        Client {
+            host,
+            host_override: None,
+            credentials: Credentials::ApiKey(token.to_string()),
+
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
         }
     }
 
@@ -179,6 +467,12 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Replace the credentials sent with each request.
+    pub fn with_credentials(&mut self, credentials: Credentials) -> &mut Self {
+        self.credentials = credentials;
+        self
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!(
             "{}{}",
@@ -201,10 +495,18 @@ impl Client {
         Client::new(token)
     }
 
-    async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
-        let parsed_url = uri.parse::<reqwest::Url>();
-        let auth = format!("Bearer {}", self.token);
-        parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+    async fn url_and_auth(
+        &self,
+        uri: &str,
+    ) -> Result<(reqwest::Url, Option<(http::header::HeaderName, String)>)> {
+        let mut url = uri.parse::<reqwest::Url>().map_err(ClientError::from)?;
+        let auth = match &self.credentials {
+            Credentials::ApiKey(token) => {
+                url.query_pairs_mut().append_pair("api_key", token);
+                None
+            }
+        };
+        Ok((url, auth))
     }
 
     async fn request_raw(
@@ -212,6 +514,7 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         message: Message,
+        headers: reqwest::header::HeaderMap,
     ) -> Result<reqwest::Response> {
         let (url, auth) = self.url_and_auth(uri).await?;
         let instance = <&Client>::clone(&self);
@@ -221,6 +524,7 @@ impl Client {
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
+        req = req.headers(headers);
 
         if let Some(content_type) = &message.content_type {
             req = req.header(
@@ -234,13 +538,122 @@ impl Client {
             );
         }
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        Ok(req.send().await?)
+        Ok(req.send().await.map_err(ClientError::from)?)
+    }
+
+    /// Send the requests with this HTTP client, like one built with a
+    /// `ClientBuilder`.
+    pub fn with_http_client(
+        &mut self,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> &mut Self {
+        self.client = client;
+        self
+    }
+
+    /// Cache the responses to `GET` requests, and revalidate them with their `ETag`
+    /// and `Last-Modified` headers.
+    #[cfg(feature = "httpcache")]
+    pub fn with_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) -> &mut Self {
+        self.http_cache = http_cache;
+        self
+    }
+
+    /// Send the request and read the whole response. With the `httpcache` feature,
+    /// a `GET` is answered from the cache when the server says it didn't change.
+    async fn request_body(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        #[cfg(feature = "httpcache")]
+        if method == reqwest::Method::GET && message.body.is_none() {
+            return self.request_cached(uri, message).await;
+        }
+
+        let response = self
+            .request_raw(method, uri, message, reqwest::header::HeaderMap::new())
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        Ok((status, headers, response_body))
+    }
+
+    #[cfg(feature = "httpcache")]
+    async fn request_cached(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        let cached = crate::http_cache::lookup(&self.http_cache, uri).await;
+        let conditional_headers = cached
+            .as_ref()
+            .map(crate::http_cache::CachedResponse::conditional_headers)
+            .unwrap_or_default();
+        let content_type = message.content_type.clone();
+
+        let mut response = self
+            .request_raw(reqwest::Method::GET, uri, message, conditional_headers)
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            match cached {
+                Some(cached) => {
+                    let mut headers = response.headers().clone();
+                    // The other pages are the ones of the cached response.
+                    if let Some(link) = cached.link.as_deref().and_then(|l| l.parse().ok()) {
+                        headers.entry(reqwest::header::LINK).or_insert(link);
+                    }
+                    return Ok((reqwest::StatusCode::OK, headers, cached.body));
+                }
+                // Nothing is cached to answer a 304 with, so ask for the whole response.
+                None => {
+                    log::debug!("received a 304 without a cached response, requesting it again");
+                    let message = Message {
+                        body: None,
+                        content_type,
+                    };
+                    response = self
+                        .request_raw(
+                            reqwest::Method::GET,
+                            uri,
+                            message,
+                            reqwest::header::HeaderMap::new(),
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        if status.is_success() {
+            crate::http_cache::store(&self.http_cache, uri, &headers, &response_body).await;
+        }
+
+        Ok((status, headers, response_body))
     }
 
     async fn request<Out>(
@@ -252,11 +665,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
-
-        let status = response.status();
-
-        let response_body = response.bytes().await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -267,19 +676,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -292,19 +691,15 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
-        let status = response.status();
-        let link = response
-            .headers()
+        let link = headers
             .get(http::header::LINK)
             .and_then(|l| l.to_str().ok())
             .and_then(|l| parse_link_header::parse(l).ok())
             .as_ref()
             .and_then(crate::utils::next_link);
 
-        let response_body = response.bytes().await?;
-
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
 
@@ -315,24 +710,22 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response
+                .map(|out| (link, out))
+                .map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
     /* TODO: make this more DRY */
     #[allow(dead_code)]
-    async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -340,7 +733,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -348,17 +741,18 @@ impl Client {
             reqwest::header::HeaderValue::from_static("application/json"),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -374,19 +768,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -413,15 +797,16 @@ impl Client {
             reqwest::header::HeaderValue::from_str(accept_mime_type)?,
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -437,19 +822,46 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(ClientError::from_response(status, headers, &response_body).into())
+        }
+    }
 
-            Err(error)
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
+        }
+
+        let response = req.send().await.map_err(ClientError::from)?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(crate::utils::Download::new(response))
+        } else {
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await.map_err(ClientError::from)?;
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -491,8 +903,8 @@ impl Client {
                 .unwrap(),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         if content.len() > 1 {
@@ -501,11 +913,12 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -516,19 +929,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -0,0 +1,226 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Gifs`](crate::gifs::Gifs).
+    pub fn gifs(&self) -> Gifs<'_> {
+        Gifs { mock: self }
+    }
+
+    /// Mock the functions of [`Stickers`](crate::stickers::Stickers).
+    pub fn stickers(&self) -> Stickers<'_> {
+        Stickers { mock: self }
+    }
+}
+
+pub struct Gifs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Gifs<'a> {
+    /// Mock [`Gifs::get`](crate::gifs::Gifs::get), a `GET` to `/gifs`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetGifsByResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gifs".to_string())
+    }
+
+    /// Mock [`Gifs::random`](crate::gifs::Gifs::random), a `GET` to `/gifs/random`.
+    pub fn random(&self) -> crate::mock::MockOperation<'a, crate::types::RandomGifResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gifs/random".to_string())
+    }
+
+    /// Mock [`Gifs::search`](crate::gifs::Gifs::search), a `GET` to `/gifs/search`.
+    pub fn search(&self) -> crate::mock::MockOperation<'a, crate::types::GetGifsByResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gifs/search".to_string())
+    }
+
+    /// Mock [`Gifs::translate`](crate::gifs::Gifs::translate), a `GET` to `/gifs/translate`.
+    pub fn translate(&self) -> crate::mock::MockOperation<'a, crate::types::RandomGifResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gifs/translate".to_string())
+    }
+
+    /// Mock [`Gifs::trending`](crate::gifs::Gifs::trending), a `GET` to `/gifs/trending`.
+    pub fn trending(&self) -> crate::mock::MockOperation<'a, crate::types::GetGifsByResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gifs/trending".to_string())
+    }
+
+    /// Mock [`Gifs::get_gifs`](crate::gifs::Gifs::get_gifs), a `GET` to `/gifs/{gifId}`.
+    pub fn get_gifs(
+        &self,
+        gif_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RandomGifResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gifs/{}",
+                crate::progenitor_support::encode_path(&gif_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Stickers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Stickers<'a> {
+    /// Mock [`Stickers::random`](crate::stickers::Stickers::random), a `GET` to `/stickers/random`.
+    pub fn random(&self) -> crate::mock::MockOperation<'a, crate::types::RandomGifResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stickers/random".to_string())
+    }
+
+    /// Mock [`Stickers::search`](crate::stickers::Stickers::search), a `GET` to `/stickers/search`.
+    pub fn search(&self) -> crate::mock::MockOperation<'a, crate::types::GetGifsByResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stickers/search".to_string())
+    }
+
+    /// Mock [`Stickers::translate`](crate::stickers::Stickers::translate), a `GET` to `/stickers/translate`.
+    pub fn translate(&self) -> crate::mock::MockOperation<'a, crate::types::RandomGifResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stickers/translate".to_string())
+    }
+
+    /// Mock [`Stickers::trending`](crate::stickers::Stickers::trending), a `GET` to `/stickers/trending`.
+    pub fn trending(&self) -> crate::mock::MockOperation<'a, crate::types::GetGifsByResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stickers/trending".to_string())
+    }
+}
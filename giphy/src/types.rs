@@ -126,29 +126,57 @@ pub struct Images {
 /**
 * Type of the gif. By default, this is almost always gif
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::Gif => "gif",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "gif" => Type::Gif,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Gif
     }
 }
+impl Type {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Gif {
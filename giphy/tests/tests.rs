@@ -1 +1,30 @@
+use wiremock::{
+    matchers::{header_exists, method, path, query_param},
+    Match, Mock, MockServer, ResponseTemplate,
+};
 
+use giphy_api::Client;
+
+#[tokio::test]
+async fn test_api_key_is_sent_as_a_query_parameter() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/gifs"))
+        .and(query_param("ids", "xT4uQulxzV39haRFjG"))
+        .and(query_param("api_key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-key");
+    client.with_host_override(server.uri());
+
+    client.gifs().get("xT4uQulxzV39haRFjG").await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!header_exists("authorization").matches(&requests[0]));
+}
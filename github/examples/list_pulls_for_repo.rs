@@ -4,8 +4,9 @@ use std::env;
 use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
+    pulls::PullsListParams,
     types::IssuesListState,
-    Client,
+    Client, ClientBuilder,
 };
 
 #[tokio::main]
@@ -25,17 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get the JWT credentials.
     let jwt = JWTCredentials::new(app_id, key.data).unwrap();
 
-    let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-    let client = reqwest_middleware::ClientBuilder::new(http)
-        // Trace HTTP requests. See the tracing crate to make use of these traces.
-        .with(reqwest_tracing::TracingMiddleware::default())
-        // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
-        .build();
+    let client = ClientBuilder::new().build()?;
 
     // Create the HTTP cache.
     #[cfg(feature = "httpcache")]
@@ -43,7 +34,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[cfg(feature = "httpcache")]
     dir.push(".cache/github");
     #[cfg(feature = "httpcache")]
-    let http_cache = Box::new(FileBasedCache::new(dir));
+    let http_cache = std::sync::Arc::new(FileBasedCache::new(dir));
 
     let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 
@@ -68,11 +59,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .list_all(
             "oxidecomputer",
             "rfd",
-            IssuesListState::All,
-            "",                 // head
-            "",                 // base
-            Default::default(), // sort
-            Default::default(), // direction
+            &PullsListParams::new().state(IssuesListState::All),
         )
         .await
         .unwrap();
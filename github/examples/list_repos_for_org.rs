@@ -4,8 +4,9 @@ use std::env;
 use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
+    repos::ReposListForOrgParams,
     types::{Order, ReposListOrgSort, ReposListOrgType},
-    Client,
+    Client, ClientBuilder,
 };
 
 #[tokio::main]
@@ -31,21 +32,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[cfg(feature = "httpcache")]
     dir.push(".cache/github");
     #[cfg(feature = "httpcache")]
-    let http_cache = Box::new(FileBasedCache::new(dir));
+    let http_cache = std::sync::Arc::new(FileBasedCache::new(dir));
 
     let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 
-    let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-    let client = reqwest_middleware::ClientBuilder::new(http)
-        // Trace HTTP requests. See the tracing crate to make use of these traces.
-        .with(reqwest_tracing::TracingMiddleware::default())
-        // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
-        .build();
+    let client = ClientBuilder::new().build()?;
 
     #[cfg(not(feature = "httpcache"))]
     let github = Client::custom(
@@ -67,9 +58,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .repos()
         .list_all_for_org(
             "oxidecomputer",
-            ReposListOrgType::All,
-            ReposListOrgSort::Created,
-            Order::Desc,
+            &ReposListForOrgParams::new()
+                .type_(ReposListOrgType::All)
+                .sort(ReposListOrgSort::Created)
+                .direction(Order::Desc),
         )
         .await
         .unwrap();
@@ -2,6 +2,533 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Actions::list_selected_repositories_enabled_github_actions_organization`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelectedRepositoriesEnabledGithubOrganizationParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListSelectedRepositoriesEnabledGithubOrganizationParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_self_hosted_runner_groups_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelfHostedRunnerGroupsForOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnerGroupsForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_repo_access_to_self_hosted_runner_group_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_self_hosted_runners_in_group_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelfHostedRunnersInGroupForOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersInGroupForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_self_hosted_runners_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelfHostedRunnersForOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_org_secrets`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListOrgSecretsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListOrgSecretsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_selected_repos_for_org_secret`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelectedReposForOrgSecretParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl ActionsListSelectedReposForOrgSecretParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_artifacts_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListArtifactsForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListArtifactsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_self_hosted_runners_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListSelfHostedRunnersForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_workflow_runs_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListWorkflowRunsForRepoParams {
+    actor: Option<String>,
+    branch: Option<String>,
+    event: Option<String>,
+    status: Option<crate::types::WorkflowRunStatus>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    created: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ActionsListWorkflowRunsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
+    pub fn actor(mut self, value: impl Into<String>) -> Self {
+        self.actor = Some(value.into());
+        self
+    }
+
+    /// Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
+    pub fn branch(mut self, value: impl Into<String>) -> Self {
+        self.branch = Some(value.into());
+        self
+    }
+
+    /// Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows)."
+    pub fn event(mut self, value: impl Into<String>) -> Self {
+        self.event = Some(value.into());
+        self
+    }
+
+    /// Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
+    pub fn status(mut self, value: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Set the `created` query parameter.
+    pub fn created(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_workflow_run_artifacts`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListWorkflowRunArtifactsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListWorkflowRunArtifactsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_jobs_for_workflow_run`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListJobsForWorkflowRunParams {
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListJobsForWorkflowRunParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters jobs by their `completed_at` timestamp. Can be one of:  
+    /// \* `latest`: Returns jobs from the most recent execution of the workflow run.  
+    /// \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
+    pub fn filter(mut self, value: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_repo_secrets`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListRepoSecretsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListRepoSecretsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_repo_workflows`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListRepoWorkflowsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListRepoWorkflowsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_workflow_runs`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListWorkflowRunsParams {
+    actor: Option<String>,
+    branch: Option<String>,
+    event: Option<String>,
+    status: Option<crate::types::WorkflowRunStatus>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    created: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ActionsListWorkflowRunsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
+    pub fn actor(mut self, value: impl Into<String>) -> Self {
+        self.actor = Some(value.into());
+        self
+    }
+
+    /// Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
+    pub fn branch(mut self, value: impl Into<String>) -> Self {
+        self.branch = Some(value.into());
+        self
+    }
+
+    /// Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows)."
+    pub fn event(mut self, value: impl Into<String>) -> Self {
+        self.event = Some(value.into());
+        self
+    }
+
+    /// Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
+    pub fn status(mut self, value: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Set the `created` query parameter.
+    pub fn created(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Actions::list_environment_secrets`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActionsListEnvironmentSecretsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActionsListEnvironmentSecretsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Actions {
     pub client: Client,
 }
@@ -101,22 +628,20 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelectedRepositoriesEnabledGithubOrganizationParams` -- The optional query parameters.
      */
     pub async fn list_selected_repositories_enabled_github_actions_organization(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelectedRepositoriesEnabledGithubOrganizationParams,
     ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
     {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -345,21 +870,19 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnerGroupsForOrgParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runner_groups_for_org(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnerGroupsForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -560,22 +1083,20 @@ impl Actions {
      *
      * * `org: &str`
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams` -- The optional query parameters.
      */
     pub async fn list_repo_access_to_self_hosted_runner_group_in_org(
         &self,
         org: &str,
         runner_group_id: i64,
-        page: i64,
-        per_page: i64,
+        params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams,
     ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -747,22 +1268,20 @@ impl Actions {
      *
      * * `org: &str`
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersInGroupForOrgParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runners_in_group_for_org(
         &self,
         org: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnersInGroupForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -931,21 +1450,19 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersForOrgParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runners_for_org(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnersForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1036,6 +1553,62 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_all_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::RunnerApplication> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::RunnerApplication>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::RunnerApplication>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a registration token for an organization.
      *
@@ -1215,21 +1788,19 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListOrgSecretsParams` -- The optional query parameters.
      */
     pub async fn list_org_secrets(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListOrgSecretsParams,
     ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1476,22 +2047,20 @@ impl Actions {
      *
      * * `org: &str`
      * * `secret_name: &str` -- secret_name parameter.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ActionsListSelectedReposForOrgSecretParams` -- The optional query parameters.
      */
     pub async fn list_selected_repos_for_org_secret(
         &self,
         org: &str,
         secret_name: &str,
-        page: i64,
-        per_page: i64,
+        params: &ActionsListSelectedReposForOrgSecretParams,
     ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1644,22 +2213,20 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListArtifactsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_artifacts_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListArtifactsForRepoParams,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1781,7 +2348,7 @@ impl Actions {
         repo: &str,
         artifact_id: i64,
         archive_format: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/artifacts/{}/{}",
@@ -1793,13 +2360,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -1865,7 +2426,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         job_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/jobs/{}/logs",
@@ -1876,13 +2437,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -2063,22 +2618,20 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersForRepoParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runners_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnersForRepoParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2175,6 +2728,64 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_all_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::RunnerApplication> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::RunnerApplication>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::RunnerApplication>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a registration token for a repository.
      *
@@ -2368,47 +2979,35 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `actor: &str` -- Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
-     * * `branch: &str` -- Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
-     * * `event: &str` -- Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
-     * * `status: crate::types::WorkflowRunStatus` -- Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `created: chrono::DateTime<chrono::Utc>`
+     * * `params: &ActionsListWorkflowRunsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_workflow_runs_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        actor: &str,
-        branch: &str,
-        event: &str,
-        status: crate::types::WorkflowRunStatus,
-        per_page: i64,
-        page: i64,
-        created: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ActionsListWorkflowRunsForRepoParams,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+        if let Some(v) = &params.actor {
+            query_args.push(("actor".to_string(), v.to_string()));
         }
-        if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+        if let Some(v) = &params.branch {
+            query_args.push(("branch".to_string(), v.to_string()));
         }
-        if let Some(date) = created {
+        if let Some(date) = params.created {
             query_args.push(("created".to_string(), date.to_rfc3339()));
         }
-        if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+        if let Some(v) = &params.event {
+            query_args.push(("event".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = &params.status {
+            query_args.push(("status".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2583,6 +3182,64 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_all_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::EnvironmentApproval> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/approvals",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::EnvironmentApproval>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::EnvironmentApproval>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Approve a workflow run for a fork pull request.
      *
@@ -2634,23 +3291,21 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListWorkflowRunArtifactsParams` -- The optional query parameters.
      */
     pub async fn list_workflow_run_artifacts(
         &self,
         owner: &str,
         repo: &str,
         run_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListWorkflowRunArtifactsParams,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2722,30 +3377,24 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListJobsForWorkflowRunParams` -- The optional query parameters.
      */
     pub async fn list_jobs_for_workflow_run(
         &self,
         owner: &str,
         repo: &str,
         run_id: i64,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListJobsForWorkflowRunParams,
     ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2791,7 +3440,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         run_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/logs",
@@ -2802,13 +3451,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -2931,6 +3574,66 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all deployment environments for a workflow run that are waiting for protection rules to pass.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_all_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PendingDeployment> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/pending_deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PendingDeployment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PendingDeployment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Review pending deployments for a workflow run.
      *
@@ -3064,22 +3767,20 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListRepoSecretsParams` -- The optional query parameters.
      */
     pub async fn list_repo_secrets(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListRepoSecretsParams,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3346,22 +4047,20 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListRepoWorkflowsParams` -- The optional query parameters.
      */
     pub async fn list_repo_workflows(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListRepoWorkflowsParams,
     ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3558,48 +4257,36 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `workflow_id: &str` -- The ID of the workflow. You can also pass the workflow file name as a string.
-     * * `actor: &str` -- Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
-     * * `branch: &str` -- Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
-     * * `event: &str` -- Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
-     * * `status: crate::types::WorkflowRunStatus` -- Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `created: chrono::DateTime<chrono::Utc>`
+     * * `params: &ActionsListWorkflowRunsParams` -- The optional query parameters.
      */
     pub async fn list_workflow_runs(
         &self,
         owner: &str,
         repo: &str,
         workflow_id: &str,
-        actor: &str,
-        branch: &str,
-        event: &str,
-        status: crate::types::WorkflowRunStatus,
-        per_page: i64,
-        page: i64,
-        created: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ActionsListWorkflowRunsParams,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+        if let Some(v) = &params.actor {
+            query_args.push(("actor".to_string(), v.to_string()));
         }
-        if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+        if let Some(v) = &params.branch {
+            query_args.push(("branch".to_string(), v.to_string()));
         }
-        if let Some(date) = created {
+        if let Some(date) = params.created {
             query_args.push(("created".to_string(), date.to_rfc3339()));
         }
-        if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+        if let Some(v) = &params.event {
+            query_args.push(("event".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = &params.status {
+            query_args.push(("status".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3677,22 +4364,20 @@ impl Actions {
      *
      * * `repository_id: i64`
      * * `environment_name: &str` -- The name of the environment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListEnvironmentSecretsParams` -- The optional query parameters.
      */
     pub async fn list_environment_secrets(
         &self,
         repository_id: i64,
         environment_name: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListEnvironmentSecretsParams,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2,6 +2,566 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Activity::list_public_events`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListPublicEventsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListPublicEventsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_public_events_for_repo_network`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListPublicEventsForRepoNetworkParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListPublicEventsForRepoNetworkParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_notifications_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListNotificationsForAuthenticatedUserParams {
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListNotificationsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// If `true`, show notifications marked as read.
+    pub fn all(mut self, value: bool) -> Self {
+        self.all = Some(value);
+        self
+    }
+
+    /// If `true`, only shows notifications in which the user is directly participating or mentioned.
+    pub fn participating(mut self, value: bool) -> Self {
+        self.participating = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn before(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_public_org_events`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListPublicOrgEventsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListPublicOrgEventsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_repo_events`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListRepoEventsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListRepoEventsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_repo_notifications_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListRepoNotificationsForAuthenticatedUserParams {
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListRepoNotificationsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// If `true`, show notifications marked as read.
+    pub fn all(mut self, value: bool) -> Self {
+        self.all = Some(value);
+        self
+    }
+
+    /// If `true`, only shows notifications in which the user is directly participating or mentioned.
+    pub fn participating(mut self, value: bool) -> Self {
+        self.participating = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn before(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_stargazers_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListStargazersForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListStargazersForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_watchers_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListWatchersForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListWatchersForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_repos_starred_by_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListReposStarredByAuthenticatedUserParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListReposStarredByAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_watched_repos_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListWatchedReposForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListWatchedReposForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_events_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListEventsForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListEventsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_org_events_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListOrgEventsForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListOrgEventsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_public_events_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListPublicEventsForUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListPublicEventsForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_received_events_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListReceivedEventsForUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListReceivedEventsForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_received_public_events_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListReceivedPublicEventsForUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListReceivedPublicEventsForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_repos_starred_by_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListReposStarredByUserParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListReposStarredByUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Activity::list_repos_watched_by_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityListReposWatchedByUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ActivityListReposWatchedByUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Activity {
     pub client: Client,
 }
@@ -23,20 +583,18 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsParams` -- The optional query parameters.
      */
     pub async fn list_public_events(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/events?{}", query_), None);
@@ -73,6 +631,46 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * We delay the public events feed by five minutes, which means the most recent event returned by the public events API actually occurred at least five minutes ago.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_all_public_events_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url("/events", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get feeds.
      *
@@ -117,22 +715,20 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsForRepoNetworkParams` -- The optional query parameters.
      */
     pub async fn list_public_events_for_repo_network(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsForRepoNetworkParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -188,6 +784,56 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_all_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List notifications for the authenticated user.
      *
@@ -199,39 +845,29 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `all: bool` -- If `true`, show notifications marked as read.
-     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListNotificationsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_notifications_for_authenticated_user(
         &self,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
-            query_args.push(("all".to_string(), all.to_string()));
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -259,22 +895,19 @@ impl Activity {
      */
     pub async fn list_all_notifications_for_authenticated_user(
         &self,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
-            query_args.push(("all".to_string(), all.to_string()));
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -289,6 +922,62 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all notifications for the current user, sorted by most recently updated.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_notifications_for_authenticated_user_stream(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Thread> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/notifications?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Thread>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Thread>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Mark notifications as read.
      *
@@ -493,21 +1182,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicOrgEventsParams` -- The optional query parameters.
      */
     pub async fn list_public_org_events(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicOrgEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -557,6 +1244,54 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_all_public_org_events`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_all_public_org_events_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List repository events.
      *
@@ -570,22 +1305,20 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListRepoEventsParams` -- The optional query parameters.
      */
     pub async fn list_repo_events(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListRepoEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -641,6 +1374,56 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_all_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List repository notifications for the authenticated user.
      *
@@ -654,41 +1437,31 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `all: bool` -- If `true`, show notifications marked as read.
-     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListRepoNotificationsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_repo_notifications_for_authenticated_user(
         &self,
         owner: &str,
         repo: &str,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
-            query_args.push(("all".to_string(), all.to_string()));
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -726,22 +1499,19 @@ impl Activity {
         &self,
         owner: &str,
         repo: &str,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
-            query_args.push(("all".to_string(), all.to_string()));
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -764,6 +1534,72 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all notifications for the current user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Thread> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.all {
+            query_args.push(("all".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.participating {
+            query_args.push(("participating".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/notifications?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Thread>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Thread>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Mark repository notifications as read.
      *
@@ -817,22 +1653,20 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListStargazersForRepoParams` -- The optional query parameters.
      */
     pub async fn list_stargazers_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListStargazersForRepoParams,
     ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -867,22 +1701,20 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListWatchersForRepoParams` -- The optional query parameters.
      */
     pub async fn list_watchers_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListWatchersForRepoParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -938,6 +1770,56 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the people watching the specified repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_all_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a repository subscription.
      *
@@ -1059,32 +1941,24 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposStarredByAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_repos_starred_by_authenticated_user(
         &self,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
@@ -1113,15 +1987,14 @@ impl Activity {
      */
     pub async fn list_all_repos_starred_by_authenticated_user(
         &self,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
@@ -1135,6 +2008,58 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists repositories the authenticated user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_all_repos_starred_by_authenticated_user_stream(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Repository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/starred?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Repository>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Repository>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check if a repository is starred by the authenticated user.
      *
@@ -1249,20 +2174,18 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListWatchedReposForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_watched_repos_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListWatchedReposForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -1303,6 +2226,52 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists repositories the authenticated user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_all_watched_repos_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url("/user/subscriptions", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List events for the authenticated user.
      *
@@ -1315,21 +2284,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListEventsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_events_for_authenticated_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListEventsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1382,6 +2349,54 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * If you are authenticated as the given user, you will see your private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_all_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List organization events for the authenticated user.
      *
@@ -1395,22 +2410,20 @@ impl Activity {
      *
      * * `username: &str`
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListOrgEventsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_org_events_for_authenticated_user(
         &self,
         username: &str,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListOrgEventsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1466,6 +2479,56 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * This is the user's organization dashboard. You must be authenticated as the user to view this.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_all_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List public events for a user.
      *
@@ -1478,21 +2541,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsForUserParams` -- The optional query parameters.
      */
     pub async fn list_public_events_for_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsForUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1545,6 +2606,54 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_all_public_events_for_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List events received by the authenticated user.
      *
@@ -1557,21 +2666,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReceivedEventsForUserParams` -- The optional query parameters.
      */
     pub async fn list_received_events_for_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReceivedEventsForUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1624,6 +2731,54 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * These are events that you've received by watching repos and following users. If you are authenticated as the given user, you will see private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_all_received_events_for_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List public events received by a user.
      *
@@ -1636,21 +2791,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReceivedPublicEventsForUserParams` -- The optional query parameters.
      */
     pub async fn list_received_public_events_for_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReceivedPublicEventsForUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1703,6 +2856,54 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_all_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Event>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Event>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List repositories starred by a user.
      *
@@ -1717,33 +2918,25 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposStarredByUserParams` -- The optional query parameters.
      */
     pub async fn list_repos_starred_by_user(
         &self,
         username: &str,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposStarredByUserParams,
     ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1776,21 +2969,19 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposWatchedByUserParams` -- The optional query parameters.
      */
     pub async fn list_repos_watched_by_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposWatchedByUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1843,4 +3034,58 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists repositories a user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_all_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
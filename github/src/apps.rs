@@ -2,6 +2,356 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Apps::list_webhook_deliveries`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListWebhookDeliveriesParams {
+    per_page: Option<i64>,
+    cursor: Option<String>,
+}
+
+impl AppsListWebhookDeliveriesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub fn cursor(mut self, value: impl Into<String>) -> Self {
+        self.cursor = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_installations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListInstallationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    outdated: Option<String>,
+}
+
+impl AppsListInstallationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Set the `outdated` query parameter.
+    pub fn outdated(mut self, value: impl Into<String>) -> Self {
+        self.outdated = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_repos_accessible_to_installation`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListReposAccessibleToInstallationParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListReposAccessibleToInstallationParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_plans`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListPlansParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListPlansParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_accounts_for_plan`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListAccountsForPlanParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListAccountsForPlanParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// To return the oldest accounts first, set to `asc`. Can be one of `asc` or `desc`. Ignored without the `sort` parameter.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_plans_stubbed`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListPlansStubbedParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListPlansStubbedParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_accounts_for_plan_stubbed`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListAccountsForPlanStubbedParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListAccountsForPlanStubbedParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// To return the oldest accounts first, set to `asc`. Can be one of `asc` or `desc`. Ignored without the `sort` parameter.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_installations_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListInstallationsForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListInstallationsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_installation_repos_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListInstallationReposForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListInstallationReposForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_subscriptions_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListSubscriptionsForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListSubscriptionsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Apps::list_subscriptions_for_authenticated_user_stubbed`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct AppsListSubscriptionsForAuthenticatedUserStubbedParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl AppsListSubscriptionsForAuthenticatedUserStubbedParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Apps {
     pub client: Client,
 }
@@ -131,20 +481,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &AppsListWebhookDeliveriesParams` -- The optional query parameters.
      */
     pub async fn list_webhook_deliveries(
         &self,
-        per_page: i64,
-        cursor: &str,
+        params: &AppsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -175,11 +523,11 @@ impl Apps {
      */
     pub async fn list_all_webhook_deliveries(
         &self,
-        cursor: &str,
+        params: &AppsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -195,6 +543,63 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns a list of webhook deliveries for the webhook configured for a GitHub App.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::HookDeliveryItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/hook/deliveries?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::HookDeliveryItem>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::HookDeliveryItem>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a delivery for an app webhook.
      *
@@ -277,29 +682,23 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `outdated: &str`
+     * * `params: &AppsListInstallationsParams` -- The optional query parameters.
      */
     pub async fn list_installations(
         &self,
-        per_page: i64,
-        page: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        outdated: &str,
+        params: &AppsListInstallationsParams,
     ) -> Result<Vec<crate::types::Installation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+        if let Some(v) = &params.outdated {
+            query_args.push(("outdated".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -331,14 +730,13 @@ impl Apps {
      */
     pub async fn list_all_installations(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        outdated: &str,
+        params: &AppsListInstallationsParams,
     ) -> Result<Vec<crate::types::Installation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+        if let Some(v) = &params.outdated {
+            query_args.push(("outdated".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -355,6 +753,62 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * The permissions the installation has are included under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_all_installations_stream(
+        &self,
+        params: &AppsListInstallationsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Installation> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.outdated {
+            query_args.push(("outdated".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/installations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Installation>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Installation>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an installation for the authenticated app.
      *
@@ -907,20 +1361,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListReposAccessibleToInstallationParams` -- The optional query parameters.
      */
     pub async fn list_repos_accessible_to_installation(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListReposAccessibleToInstallationParams,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -1010,20 +1462,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListPlansParams` -- The optional query parameters.
      */
     pub async fn list_plans(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListPlansParams,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -1064,6 +1514,54 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_all_plans_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MarketplaceListingPlan> {
+        let url = self.client.url("/marketplace_listing/plans", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MarketplaceListingPlan>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MarketplaceListingPlan>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List accounts for a plan.
      *
@@ -1078,33 +1576,25 @@ impl Apps {
      * **Parameters:**
      *
      * * `plan_id: i64` -- plan_id parameter.
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListAccountsForPlanParams` -- The optional query parameters.
      */
     pub async fn list_accounts_for_plan(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &AppsListAccountsForPlanParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1141,15 +1631,14 @@ impl Apps {
     pub async fn list_all_accounts_for_plan(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
+        params: &AppsListAccountsForPlanParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1170,6 +1659,72 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns user and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_all_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MarketplacePurchaseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MarketplacePurchaseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MarketplacePurchaseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a subscription plan for an account (stubbed).
      *
@@ -1219,20 +1774,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListPlansStubbedParams` -- The optional query parameters.
      */
     pub async fn list_plans_stubbed(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListPlansStubbedParams,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1276,6 +1829,54 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_all_plans_stubbed_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MarketplaceListingPlan> {
+        let url = self.client.url("/marketplace_listing/stubbed/plans", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MarketplaceListingPlan>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MarketplaceListingPlan>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1290,33 +1891,25 @@ impl Apps {
      * **Parameters:**
      *
      * * `plan_id: i64` -- plan_id parameter.
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListAccountsForPlanStubbedParams` -- The optional query parameters.
      */
     pub async fn list_accounts_for_plan_stubbed(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1353,15 +1946,14 @@ impl Apps {
     pub async fn list_all_accounts_for_plan_stubbed(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
+        params: &AppsListAccountsForPlanStubbedParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1382,6 +1974,72 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns repository and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_all_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MarketplacePurchaseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MarketplacePurchaseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MarketplacePurchaseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an organization installation for the authenticated app.
      *
@@ -1516,20 +2174,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListInstallationsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_installations_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListInstallationsForAuthenticatedUserParams,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -1563,21 +2219,19 @@ impl Apps {
      * **Parameters:**
      *
      * * `installation_id: i64` -- installation_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListInstallationReposForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_installation_repos_for_authenticated_user(
         &self,
         installation_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &AppsListInstallationReposForAuthenticatedUserParams,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1687,20 +2341,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListSubscriptionsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_subscriptions_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListSubscriptionsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -1741,6 +2393,52 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UserMarketplacePurchase> {
+        let url = self.client.url("/user/marketplace_purchases", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::UserMarketplacePurchase>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::UserMarketplacePurchase>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1752,20 +2450,18 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams` -- The optional query parameters.
      */
     pub async fn list_subscriptions_for_authenticated_user_stubbed(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1807,6 +2503,52 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UserMarketplacePurchase> {
+        let url = self.client.url("/user/marketplace_purchases/stubbed", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::UserMarketplacePurchase>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::UserMarketplacePurchase>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a user installation for the authenticated app.
      *
@@ -2,6 +2,181 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Checks::list_annotations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChecksListAnnotationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ChecksListAnnotationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Checks::list_for_suite`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChecksListForSuiteParams {
+    check_name: Option<String>,
+    status: Option<crate::types::JobStatus>,
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ChecksListForSuiteParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns check runs with the specified `name`.
+    pub fn check_name(mut self, value: impl Into<String>) -> Self {
+        self.check_name = Some(value.into());
+        self
+    }
+
+    /// Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
+    pub fn status(mut self, value: crate::types::JobStatus) -> Self {
+        self.status = Some(value);
+        self
+    }
+
+    /// Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+    pub fn filter(mut self, value: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Checks::list_for_ref`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChecksListForRefParams {
+    check_name: Option<String>,
+    status: Option<crate::types::JobStatus>,
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    app_id: Option<i64>,
+}
+
+impl ChecksListForRefParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns check runs with the specified `name`.
+    pub fn check_name(mut self, value: impl Into<String>) -> Self {
+        self.check_name = Some(value.into());
+        self
+    }
+
+    /// Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
+    pub fn status(mut self, value: crate::types::JobStatus) -> Self {
+        self.status = Some(value);
+        self
+    }
+
+    /// Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+    pub fn filter(mut self, value: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Set the `app_id` query parameter.
+    pub fn app_id(mut self, value: i64) -> Self {
+        self.app_id = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Checks::list_suites_for_ref`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChecksListSuitesForRefParams {
+    app_id: Option<i64>,
+    check_name: Option<String>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ChecksListSuitesForRefParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters check suites by GitHub App `id`.
+    pub fn app_id(mut self, value: i64) -> Self {
+        self.app_id = Some(value);
+        self
+    }
+
+    /// Returns check runs with the specified `name`.
+    pub fn check_name(mut self, value: impl Into<String>) -> Self {
+        self.check_name = Some(value.into());
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Checks {
     pub client: Client,
 }
@@ -153,23 +328,21 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `check_run_id: i64` -- check_run_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListAnnotationsParams` -- The optional query parameters.
      */
     pub async fn list_annotations(
         &self,
         owner: &str,
         repo: &str,
         check_run_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ChecksListAnnotationsParams,
     ) -> Result<Vec<crate::types::CheckAnnotation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -228,6 +401,64 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists annotations for a check run using the annotation `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get annotations for a check run. OAuth Apps and authenticated users must have the `repo` scope to get annotations for a check run in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_all_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CheckAnnotation> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CheckAnnotation>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CheckAnnotation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a check suite.
      *
@@ -364,40 +595,30 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `check_suite_id: i64` -- check_suite_id parameter.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `status: crate::types::JobStatus` -- Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListForSuiteParams` -- The optional query parameters.
      */
     pub async fn list_for_suite(
         &self,
         owner: &str,
         repo: &str,
         check_suite_id: i64,
-        check_name: &str,
-        status: crate::types::JobStatus,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
+        params: &ChecksListForSuiteParams,
     ) -> Result<crate::types::ChecksListRefResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+        if let Some(v) = &params.check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = &params.status {
+            query_args.push(("status".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -478,45 +699,33 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `status: crate::types::JobStatus` -- Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `app_id: i64`
+     * * `params: &ChecksListForRefParams` -- The optional query parameters.
      */
     pub async fn list_for_ref(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        check_name: &str,
-        status: crate::types::JobStatus,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
-        app_id: i64,
+        params: &ChecksListForRefParams,
     ) -> Result<crate::types::ChecksListRefResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+        if let Some(v) = &params.app_id {
+            query_args.push(("app_id".to_string(), v.to_string()));
         }
-        if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+        if let Some(v) = &params.check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = &params.status {
+            query_args.push(("status".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -555,33 +764,27 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `app_id: i64` -- Filters check suites by GitHub App `id`.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListSuitesForRefParams` -- The optional query parameters.
      */
     pub async fn list_suites_for_ref(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        app_id: i64,
-        check_name: &str,
-        per_page: i64,
-        page: i64,
+        params: &ChecksListSuitesForRefParams,
     ) -> Result<crate::types::ChecksListSuitesRefResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+        if let Some(v) = &params.app_id {
+            query_args.push(("app_id".to_string(), v.to_string()));
         }
-        if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+        if let Some(v) = &params.check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2,6 +2,174 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`CodeScanning::list_alerts_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CodeScanningListAlertsForRepoParams {
+    tool_name: Option<String>,
+    tool_guid: Option<String>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<String>,
+    state: Option<crate::types::CodeScanningAlertState>,
+}
+
+impl CodeScanningListAlertsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
+    pub fn tool_name(mut self, value: impl Into<String>) -> Self {
+        self.tool_name = Some(value.into());
+        self
+    }
+
+    /// The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
+    pub fn tool_guid(mut self, value: impl Into<String>) -> Self {
+        self.tool_guid = Some(value.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+
+    /// Set to `open`, `fixed`, or `dismissed` to list code scanning alerts in a specific state.
+    pub fn state(mut self, value: crate::types::CodeScanningAlertState) -> Self {
+        self.state = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`CodeScanning::list_alert_instances`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CodeScanningListAlertInstancesParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<String>,
+}
+
+impl CodeScanningListAlertInstancesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`CodeScanning::list_recent_analyses`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CodeScanningListRecentAnalysesParams {
+    tool_name: Option<String>,
+    tool_guid: Option<String>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<String>,
+    sarif_id: Option<String>,
+}
+
+impl CodeScanningListRecentAnalysesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
+    pub fn tool_name(mut self, value: impl Into<String>) -> Self {
+        self.tool_name = Some(value.into());
+        self
+    }
+
+    /// The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
+    pub fn tool_guid(mut self, value: impl Into<String>) -> Self {
+        self.tool_guid = Some(value.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// The Git reference for the analyses you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+
+    /// Filter analyses belonging to the same SARIF upload.
+    pub fn sarif_id(mut self, value: impl Into<String>) -> Self {
+        self.sarif_id = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`CodeScanning::delete_analysis`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CodeScanningDeleteAnalysisParams {
+    confirm_delete: Option<String>,
+}
+
+impl CodeScanningDeleteAnalysisParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Allow deletion if the specified analysis is the last in a set. If you attempt to delete the final analysis in a set without setting this parameter to `true`, you'll get a 400 response with the message: `Analysis is last of its type and deletion may result in the loss of historical alert data. Please specify confirm_delete.`
+    pub fn confirm_delete(mut self, value: impl Into<String>) -> Self {
+        self.confirm_delete = Some(value.into());
+        self
+    }
+}
+
 pub struct CodeScanning {
     pub client: Client,
 }
@@ -33,42 +201,32 @@ impl CodeScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `tool_name: &str` -- The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
-     * * `tool_guid: &str` -- The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
-     * * `state: crate::types::CodeScanningAlertState` -- Set to `open`, `fixed`, or `dismissed` to list code scanning alerts in a specific state.
+     * * `params: &CodeScanningListAlertsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_alerts_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
-        state: crate::types::CodeScanningAlertState,
+        params: &CodeScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
-        if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
         }
-        if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -113,23 +271,20 @@ impl CodeScanning {
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        ref_: &str,
-        state: crate::types::CodeScanningAlertState,
+        params: &CodeScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
-        if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
         }
-        if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -151,6 +306,86 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all open code scanning alerts for the default branch (usually `main`
+     * or `master`). You must use an access token with the `security_events` scope to use
+     * this endpoint. GitHub Apps must have the `security_events` read permission to use
+     * this endpoint.
+     *
+     * The response includes a `most_recent_instance` object.
+     * This provides details of the most recent instance of this alert
+     * for the default branch or for the specified Git reference
+     * (if you used `ref` in the request).
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CodeScanningAlertItems> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CodeScanningAlertItems>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CodeScanningAlertItems>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a code scanning alert.
      *
@@ -249,28 +484,24 @@ impl CodeScanning {
      * * `owner: &str`
      * * `repo: &str`
      * * `alert_number: i64` -- The number that identifies an alert. You can find this at the end of the URL for a code scanning alert within GitHub, and in the `number` field in the response from the `GET /repos/{owner}/{repo}/code-scanning/alerts` operation.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+     * * `params: &CodeScanningListAlertInstancesParams` -- The optional query parameters.
      */
     pub async fn list_alert_instances(
         &self,
         owner: &str,
         repo: &str,
         alert_number: i64,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
+        params: &CodeScanningListAlertInstancesParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -309,11 +540,11 @@ impl CodeScanning {
         owner: &str,
         repo: &str,
         alert_number: i64,
-        ref_: &str,
+        params: &CodeScanningListAlertInstancesParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -336,6 +567,71 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_all_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CodeScanningAlertInstance> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CodeScanningAlertInstance>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CodeScanningAlertInstance>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List code scanning analyses for a repository.
      *
@@ -364,42 +660,32 @@ impl CodeScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `tool_name: &str` -- The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
-     * * `tool_guid: &str` -- The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the analyses you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
-     * * `sarif_id: &str` -- Filter analyses belonging to the same SARIF upload.
+     * * `params: &CodeScanningListRecentAnalysesParams` -- The optional query parameters.
      */
     pub async fn list_recent_analyses(
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
-        sarif_id: &str,
+        params: &CodeScanningListRecentAnalysesParams,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+        if let Some(v) = &params.sarif_id {
+            query_args.push(("sarif_id".to_string(), v.to_string()));
         }
-        if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
         }
-        if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -451,23 +737,20 @@ impl CodeScanning {
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        ref_: &str,
-        sarif_id: &str,
+        params: &CodeScanningListRecentAnalysesParams,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+        if let Some(v) = &params.sarif_id {
+            query_args.push(("sarif_id".to_string(), v.to_string()));
         }
-        if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
         }
-        if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -489,6 +772,93 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the details of all code scanning analyses for a repository,
+     * starting with the most recent.
+     * The response is paginated and you can use the `page` and `per_page` parameters
+     * to list the analyses you're interested in.
+     * By default 30 analyses are listed per page.
+     *
+     * The `rules_count` field in the response give the number of rules
+     * that were run in the analysis.
+     * For very old analyses this data is not available,
+     * and `0` is returned in this field.
+     *
+     * You must use an access token with the `security_events` scope to use this endpoint.
+     * GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * **Deprecation notice**:
+     * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_all_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CodeScanningAnalysis> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sarif_id {
+            query_args.push(("sarif_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CodeScanningAnalysis>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CodeScanningAnalysis>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a code scanning analysis for a repository.
      *
@@ -626,18 +996,18 @@ impl CodeScanning {
      * * `owner: &str`
      * * `repo: &str`
      * * `analysis_id: i64` -- The ID of the analysis, as returned from the `GET /repos/{owner}/{repo}/code-scanning/analyses` operation.
-     * * `confirm_delete: &str` -- Allow deletion if the specified analysis is the last in a set. If you attempt to delete the final analysis in a set without setting this parameter to `true`, you'll get a 400 response with the message: `Analysis is last of its type and deletion may result in the loss of historical alert data. Please specify confirm_delete.`.
+     * * `params: &CodeScanningDeleteAnalysisParams` -- The optional query parameters.
      */
     pub async fn delete_analysis(
         &self,
         owner: &str,
         repo: &str,
         analysis_id: i64,
-        confirm_delete: &str,
+        params: &CodeScanningDeleteAnalysisParams,
     ) -> Result<crate::types::AnalysisDeletion> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !confirm_delete.is_empty() {
-            query_args.push(("confirm_delete".to_string(), confirm_delete.to_string()));
+        if let Some(v) = &params.confirm_delete {
+            query_args.push(("confirm_delete".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -56,6 +56,52 @@ impl CodesOfConduct {
             )
             .await
     }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_all_codes_of_conduct_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CodeOfConduct> {
+        let url = self.client.url("/codes_of_conduct", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CodeOfConduct>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CodeOfConduct>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a code of conduct.
      *
@@ -2,6 +2,315 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsForParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnerGroupsForParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnersInGroupForParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnersInGroupForParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_self_hosted_runners_for_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnersForParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnersForParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::get_audit_log`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminGetAuditLogParams {
+    phrase: Option<String>,
+    include: Option<crate::types::Include>,
+    after: Option<String>,
+    before: Option<String>,
+    order: Option<crate::types::Order>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl EnterpriseAdminGetAuditLogParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
+    pub fn phrase(mut self, value: impl Into<String>) -> Self {
+        self.phrase = Some(value.into());
+        self
+    }
+
+    /// The event types to include:
+    ///
+    /// - `web` - returns web (non-Git) events
+    /// - `git` - returns Git events
+    /// - `all` - returns both web and Git events
+    ///
+    /// The default is `web`.
+    pub fn include(mut self, value: crate::types::Include) -> Self {
+        self.include = Some(value);
+        self
+    }
+
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+
+    /// The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
+    ///
+    /// The default is `desc`.
+    pub fn order(mut self, value: crate::types::Order) -> Self {
+        self.order = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_provisioned_groups_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListProvisionedGroupsParams {
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<String>,
+    excluded_attributes: Option<String>,
+}
+
+impl EnterpriseAdminListProvisionedGroupsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Used for pagination: the index of the first result to return.
+    pub fn start_index(mut self, value: i64) -> Self {
+        self.start_index = Some(value);
+        self
+    }
+
+    /// Used for pagination: the number of results to return.
+    pub fn count(mut self, value: i64) -> Self {
+        self.count = Some(value);
+        self
+    }
+
+    /// filter results
+    pub fn filter(mut self, value: impl Into<String>) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// attributes to exclude
+    pub fn excluded_attributes(mut self, value: impl Into<String>) -> Self {
+        self.excluded_attributes = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::get_provisioning_information_for_enterprise_group`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminGetProvisioningInformationForGroupParams {
+    excluded_attributes: Option<String>,
+}
+
+impl EnterpriseAdminGetProvisioningInformationForGroupParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Attributes to exclude.
+    pub fn excluded_attributes(mut self, value: impl Into<String>) -> Self {
+        self.excluded_attributes = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`EnterpriseAdmin::list_provisioned_identities_enterprise`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct EnterpriseAdminListProvisionedIdentitiesParams {
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<String>,
+}
+
+impl EnterpriseAdminListProvisionedIdentitiesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Used for pagination: the index of the first result to return.
+    pub fn start_index(mut self, value: i64) -> Self {
+        self.start_index = Some(value);
+        self
+    }
+
+    /// Used for pagination: the number of results to return.
+    pub fn count(mut self, value: i64) -> Self {
+        self.count = Some(value);
+        self
+    }
+
+    /// filter results
+    pub fn filter(mut self, value: impl Into<String>) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+}
+
 pub struct EnterpriseAdmin {
     pub client: Client,
 }
@@ -99,21 +408,19 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsParams` -- The optional query parameters.
      */
     pub async fn list_selected_organizations_enabled_github_actions_enterprise(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsParams,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -336,21 +643,19 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnerGroupsForParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runner_groups_for_enterprise(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelfHostedRunnerGroupsForParams,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -541,22 +846,20 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInParams` -- The optional query parameters.
      */
     pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise(
         &self,
         enterprise: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInParams,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -717,22 +1020,20 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnersInGroupForParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runners_in_group_for_enterprise(
         &self,
         enterprise: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelfHostedRunnersInGroupForParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -893,21 +1194,19 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnersForParams` -- The optional query parameters.
      */
     pub async fn list_self_hosted_runners_for_enterprise(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelfHostedRunnersForParams,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -998,6 +1297,62 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_all_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::RunnerApplication> {
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::RunnerApplication>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::RunnerApplication>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a registration token for an enterprise.
      *
@@ -1177,54 +1532,34 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `phrase: &str` -- A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
-     * * `include: crate::types::Include` -- The event types to include:
-     *  
-     *  - `web` - returns web (non-Git) events
-     *  - `git` - returns Git events
-     *  - `all` - returns both web and Git events
-     *  
-     *  The default is `web`.
-     * * `after: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
-     * * `before: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
-     * * `order: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &EnterpriseAdminGetAuditLogParams` -- The optional query parameters.
      */
     pub async fn get_audit_log(
         &self,
         enterprise: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
-        page: i64,
-        per_page: i64,
+        params: &EnterpriseAdminGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
         }
-        if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
         }
-        if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
         }
-        if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1259,27 +1594,23 @@ impl EnterpriseAdmin {
     pub async fn get_all_audit_log(
         &self,
         enterprise: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
+        params: &EnterpriseAdminGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
         }
-        if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
         }
-        if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
         }
-        if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
         }
-        if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1300,6 +1631,79 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AuditLogEvent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::AuditLogEvent>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::AuditLogEvent>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List provisioned SCIM groups for an enterprise.
      *
@@ -1312,34 +1716,25 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- filter results.
-     * * `excluded_attributes: &str` -- attributes to exclude.
+     * * `params: &EnterpriseAdminListProvisionedGroupsParams` -- The optional query parameters.
      */
     pub async fn list_provisioned_groups_enterprise(
         &self,
         enterprise: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
-        excluded_attributes: &str,
+        params: &EnterpriseAdminListProvisionedGroupsParams,
     ) -> Result<crate::types::ScimGroupListEnterprise> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+        if let Some(v) = &params.count {
+            query_args.push(("count".to_string(), v.to_string()));
         }
-        if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+        if let Some(v) = &params.excluded_attributes {
+            query_args.push(("excludedAttributes".to_string(), v.to_string()));
         }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+        if let Some(v) = &params.start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1410,20 +1805,17 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `scim_group_id: &str` -- Identifier generated by the GitHub SCIM endpoint.
-     * * `excluded_attributes: &str` -- Attributes to exclude.
+     * * `params: &EnterpriseAdminGetProvisioningInformationForGroupParams` -- The optional query parameters.
      */
     pub async fn get_provisioning_information_for_enterprise_group(
         &self,
         enterprise: &str,
         scim_group_id: &str,
-        excluded_attributes: &str,
+        params: &EnterpriseAdminGetProvisioningInformationForGroupParams,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+        if let Some(v) = &params.excluded_attributes {
+            query_args.push(("excludedAttributes".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1591,26 +1983,22 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- filter results.
+     * * `params: &EnterpriseAdminListProvisionedIdentitiesParams` -- The optional query parameters.
      */
     pub async fn list_provisioned_identities_enterprise(
         &self,
         enterprise: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
+        params: &EnterpriseAdminListProvisionedIdentitiesParams,
     ) -> Result<crate::types::ScimUserListEnterprise> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+        if let Some(v) = &params.count {
+            query_args.push(("count".to_string(), v.to_string()));
         }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+        if let Some(v) = &params.start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
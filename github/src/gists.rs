@@ -2,6 +2,230 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Gists::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListParams {
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_public`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListPublicParams {
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListPublicParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_starred`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListStarredParams {
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListStarredParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_comments`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListCommentsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListCommentsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_commits`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListCommitsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListCommitsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_forks`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListForksParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListForksParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Gists::list_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GistsListForUserParams {
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GistsListForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Gists {
     pub client: Client,
 }
@@ -23,24 +247,17 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListParams` -- The optional query parameters.
      */
-    pub async fn list(
-        &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::BaseGist>> {
+    pub async fn list(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,12 +283,9 @@ impl Gists {
      *
      * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
      */
-    pub async fn list_all(
-        &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> Result<Vec<crate::types::BaseGist>> {
+    pub async fn list_all(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -86,6 +300,53 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &GistsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BaseGist> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BaseGist>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::BaseGist>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a gist.
      *
@@ -125,24 +386,20 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListPublicParams` -- The optional query parameters.
      */
     pub async fn list_public(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListPublicParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -172,10 +429,10 @@ impl Gists {
      */
     pub async fn list_all_public(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &GistsListPublicParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -190,6 +447,55 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List public gists sorted by most recently updated to least recently updated.
+     *
+     * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        params: &GistsListPublicParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BaseGist> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/public?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BaseGist>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::BaseGist>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List starred gists.
      *
@@ -201,24 +507,20 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListStarredParams` -- The optional query parameters.
      */
     pub async fn list_starred(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListStarredParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -246,10 +548,10 @@ impl Gists {
      */
     pub async fn list_all_starred(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &GistsListStarredParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -264,6 +566,53 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the authenticated user's starred gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_all_starred_stream(
+        &self,
+        params: &GistsListStarredParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BaseGist> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/starred?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BaseGist>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::BaseGist>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a gist.
      *
@@ -364,21 +713,19 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListCommentsParams` -- The optional query parameters.
      */
     pub async fn list_comments(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListCommentsParams,
     ) -> Result<Vec<crate::types::GistComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -428,6 +775,56 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GistComment> {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GistComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::GistComment>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a gist comment.
      *
@@ -583,21 +980,19 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListCommitsParams` -- The optional query parameters.
      */
     pub async fn list_commits(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListCommitsParams,
     ) -> Result<Vec<crate::types::GistCommit>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -647,6 +1042,54 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GistCommit> {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GistCommit>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::GistCommit>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List gist forks.
      *
@@ -659,21 +1102,19 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListForksParams` -- The optional query parameters.
      */
     pub async fn list_forks(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListForksParams,
     ) -> Result<Vec<crate::types::GistSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -723,6 +1164,54 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GistSimple> {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GistSimple>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::GistSimple>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Fork a gist.
      *
@@ -892,25 +1381,21 @@ impl Gists {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListForUserParams` -- The optional query parameters.
      */
     pub async fn list_for_user(
         &self,
         username: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListForUserParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -946,10 +1431,10 @@ impl Gists {
     pub async fn list_all_for_user(
         &self,
         username: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &GistsListForUserParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -971,4 +1456,59 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists public gists for the specified user:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BaseGist> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/gists?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BaseGist>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::BaseGist>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
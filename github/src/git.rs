@@ -2,6 +2,55 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Git::list_matching_refs`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GitListMatchingRefsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl GitListMatchingRefsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Git::get_tree`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct GitGetTreeParams {
+    recursive: Option<String>,
+}
+
+impl GitGetTreeParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Setting this parameter to any value returns the objects or subtrees referenced by the tree specified in `:tree_sha`. For example, setting `recursive` to any of the following will enable returning objects or subtrees: `0`, `1`, `"true"`, and `"false"`. Omit this parameter to prevent recursively returning objects or subtrees.
+    pub fn recursive(mut self, value: impl Into<String>) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+}
+
 pub struct Git {
     pub client: Client,
 }
@@ -248,23 +297,21 @@ impl Git {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GitListMatchingRefsParams` -- The optional query parameters.
      */
     pub async fn list_matching_refs(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
+        params: &GitListMatchingRefsParams,
     ) -> Result<Vec<crate::types::GitRef>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -329,6 +376,64 @@ impl Git {
             )
             .await
     }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns an array of references from your Git database that match the supplied name. The `:ref` in the URL must be formatted as `heads/<branch name>` for branches and `tags/<tag name>` for tags. If the `:ref` doesn't exist in the repository, but existing refs start with `:ref`, they will be returned as an array.
+     *
+     * When you use this endpoint without providing a `:ref`, it will return an array of all the references from your Git database, including notes and stashes if they exist on the server. Anything in the namespace is returned, not just `heads` and `tags`.
+     *
+     * **Note:** You need to explicitly [request a pull request](https://docs.github.com/rest/reference/pulls#get-a-pull-request) to trigger a test merge commit, which checks the mergeability of pull requests. For more information, see "[Checking mergeability of pull requests](https://docs.github.com/rest/guides/getting-started-with-the-git-database-api#checking-mergeability-of-pull-requests)".
+     *
+     * If you request matching references for a branch named `feature` but the branch `feature` doesn't exist, the response can still include other matching head refs that start with the word `feature`, such as `featureA` and `featureB`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_all_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GitRef> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GitRef>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::GitRef>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a reference.
      *
@@ -675,18 +780,18 @@ impl Git {
      * * `owner: &str`
      * * `repo: &str`
      * * `tree_sha: &str`
-     * * `recursive: &str` -- Setting this parameter to any value returns the objects or subtrees referenced by the tree specified in `:tree_sha`. For example, setting `recursive` to any of the following will enable returning objects or subtrees: `0`, `1`, `"true"`, and `"false"`. Omit this parameter to prevent recursively returning objects or subtrees.
+     * * `params: &GitGetTreeParams` -- The optional query parameters.
      */
     pub async fn get_tree(
         &self,
         owner: &str,
         repo: &str,
         tree_sha: &str,
-        recursive: &str,
+        params: &GitGetTreeParams,
     ) -> Result<crate::types::GitTreeData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !recursive.is_empty() {
-            query_args.push(("recursive".to_string(), recursive.to_string()));
+        if let Some(v) = &params.recursive {
+            query_args.push(("recursive".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
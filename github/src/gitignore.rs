@@ -56,6 +56,46 @@ impl Gitignore {
             )
             .await
     }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all templates available to pass as an option when [creating a repository](https://docs.github.com/rest/reference/repos#create-a-repository-for-the-authenticated-user).
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_all_templates_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<String> {
+        let url = self.client.url("/gitignore/templates", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<String>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<String>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a gitignore template.
      *
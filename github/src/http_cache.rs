@@ -1,49 +1,99 @@
 //! Implements <https://tools.ietf.org/html/rfc7232> Conditional Requests.
+//!
+//! The responses to `GET` requests are cached with their `ETag` and
+//! `Last-Modified` headers, which are sent back on the next request for the
+//! same URI. A `304 Not Modified` is then answered from the cache.
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
     ffi::OsStr,
     fmt::Debug,
-    fs,
     hash::{Hash, Hasher},
     io,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
-use anyhow::{Error, Result};
+use anyhow::Result;
 use http::Uri;
 
 /// A type for an HTTP cache.
-pub type BoxedHttpCache = Box<dyn HttpCache + Send + Sync>;
-
-/// Implements a cached response and looking up the etag and next link.
-pub trait HttpCache: HttpCacheClone + Debug {
-    fn cache_response(
-        &self,
-        uri: &str,
-        body: &[u8],
-        etag: &[u8],
-        next_link: &Option<String>,
-    ) -> Result<()>;
-    fn lookup_etag(&self, uri: &str) -> Result<String>;
-    fn lookup_body(&self, uri: &str) -> Result<String>;
-    fn lookup_next_link(&self, uri: &str) -> Result<Option<String>>;
+pub type BoxedHttpCache = Arc<dyn HttpCache>;
+
+/// A response cached for a URI.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The body of the response.
+    pub body: bytes::Bytes,
+    /// The `ETag` header of the response.
+    pub etag: Option<String>,
+    /// The `Last-Modified` header of the response.
+    pub last_modified: Option<String>,
+    /// The `Link` header of the response, linking to the other pages.
+    pub link: Option<String>,
+}
+
+impl CachedResponse {
+    /// The response to cache, if the server sent a validator for it.
+    pub fn from_response(headers: &http::HeaderMap, body: bytes::Bytes) -> Option<Self> {
+        let header = |name: http::header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let etag = header(http::header::ETAG);
+        let last_modified = header(http::header::LAST_MODIFIED);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+
+        Some(CachedResponse {
+            body,
+            etag,
+            last_modified,
+            link: header(http::header::LINK),
+        })
+    }
+
+    /// The headers making a request conditional on the response having changed.
+    pub fn conditional_headers(&self) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        let validators = [
+            (http::header::IF_NONE_MATCH, &self.etag),
+            (http::header::IF_MODIFIED_SINCE, &self.last_modified),
+        ];
+        for (name, value) in validators {
+            if let Some(value) = value.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+}
+
+/// Stores the responses to `GET` requests, by URI.
+#[async_trait::async_trait]
+pub trait HttpCache: Debug + Send + Sync {
+    /// The response cached for the URI, if any.
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>>;
+    /// Cache the response for the URI, replacing the one cached before.
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()>;
 }
 
 impl dyn HttpCache {
     pub fn noop() -> BoxedHttpCache {
-        Box::new(NoCache)
+        Arc::new(NoCache)
+    }
+
+    /// An in-memory cache of the `capacity` most recently used responses.
+    pub fn in_memory(capacity: usize) -> BoxedHttpCache {
+        Arc::new(MemoryCache::new(capacity))
     }
 
     pub fn in_home_dir() -> BoxedHttpCache {
         let mut dir = dirs::home_dir().expect("Expected a home dir");
         dir.push(".github/cache");
-        Box::new(FileBasedCache::new(dir))
-    }
-}
-
-impl Clone for BoxedHttpCache {
-    fn clone(&self) -> Self {
-        self.box_clone()
+        Arc::new(FileBasedCache::new(dir))
     }
 }
 
@@ -51,72 +101,136 @@ impl Clone for BoxedHttpCache {
 #[derive(Clone, Debug)]
 pub struct NoCache;
 
+#[async_trait::async_trait]
 impl HttpCache for NoCache {
-    fn cache_response(&self, _: &str, _: &[u8], _: &[u8], _: &Option<String>) -> Result<()> {
+    async fn get(&self, _uri: &str) -> Result<Option<CachedResponse>> {
+        Ok(None)
+    }
+
+    async fn put(&self, _uri: &str, _response: CachedResponse) -> Result<()> {
         Ok(())
     }
-    fn lookup_etag(&self, _uri: &str) -> Result<String> {
-        no_read("No etag cached")
+}
+
+/// An in-memory cache, evicting the least recently used response once it
+/// holds `capacity` responses.
+#[derive(Clone, Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Arc<Mutex<MemoryEntries>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryEntries {
+    responses: HashMap<String, CachedResponse>,
+    /// The URIs of the responses, the least recently used first.
+    recency: VecDeque<String>,
+}
+
+impl MemoryEntries {
+    fn touch(&mut self, uri: &str) {
+        if let Some(i) = self.recency.iter().position(|u| u == uri) {
+            if let Some(uri) = self.recency.remove(i) {
+                self.recency.push_back(uri);
+            }
+        }
+    }
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> MemoryCache {
+        MemoryCache {
+            capacity,
+            entries: Default::default(),
+        }
     }
-    fn lookup_body(&self, _uri: &str) -> Result<String> {
-        no_read("No body cached")
+}
+
+#[async_trait::async_trait]
+impl HttpCache for MemoryCache {
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>> {
+        let mut entries = self.entries.lock().unwrap();
+        let response = entries.responses.get(uri).cloned();
+        if response.is_some() {
+            entries.touch(uri);
+        }
+        Ok(response)
     }
-    fn lookup_next_link(&self, _uri: &str) -> Result<Option<String>> {
-        no_read("No next link cached")
+
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let replaced = entries.responses.insert(uri.to_string(), response);
+        if replaced.is_some() {
+            entries.touch(uri);
+        } else {
+            entries.recency.push_back(uri.to_string());
+        }
+        while entries.responses.len() > self.capacity {
+            match entries.recency.pop_front() {
+                Some(evicted) => {
+                    entries.responses.remove(&evicted);
+                }
+                None => break,
+            }
+        }
+        Ok(())
     }
 }
 
-/// A file based cache.
+/// A file based cache. The files are read and written without blocking the
+/// runtime.
 #[derive(Clone, Debug)]
 pub struct FileBasedCache {
     root: PathBuf,
 }
 
 impl FileBasedCache {
-    #[doc(hidden)] // public for integration testing only
     pub fn new<P: Into<PathBuf>>(root: P) -> FileBasedCache {
         FileBasedCache { root: root.into() }
     }
 }
 
+#[async_trait::async_trait]
 impl HttpCache for FileBasedCache {
-    fn cache_response(
-        &self,
-        uri: &str,
-        body: &[u8],
-        etag: &[u8],
-        next_link: &Option<String>,
-    ) -> Result<()> {
+    async fn get(&self, uri: &str) -> Result<Option<CachedResponse>> {
         let mut path = cache_path(&self.root, uri, "json");
-        //println!("caching body at path: {}", path.display());
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(&path, body)?;
+        let body = match tokio::fs::read(&path).await {
+            Ok(body) => body,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
         path.set_extension("etag");
-        fs::write(&path, etag)?;
-        if let Some(next_link) = next_link {
-            path.set_extension("next_link");
-            fs::write(&path, next_link)?;
-        }
-        Ok(())
-    }
-
-    fn lookup_etag(&self, uri: &str) -> Result<String> {
-        read_to_string(cache_path(&self.root, uri, "etag"))
-    }
+        let etag = read_optional(&path).await?;
+        path.set_extension("last_modified");
+        let last_modified = read_optional(&path).await?;
+        path.set_extension("link");
+        let link = read_optional(&path).await?;
 
-    fn lookup_body(&self, uri: &str) -> Result<String> {
-        read_to_string(cache_path(&self.root, uri, "json"))
+        Ok(Some(CachedResponse {
+            body: body.into(),
+            etag,
+            last_modified,
+            link,
+        }))
     }
 
-    fn lookup_next_link(&self, uri: &str) -> Result<Option<String>> {
-        let path = cache_path(&self.root, uri, "next_link");
-        if path.exists() {
-            Ok(Some(read_to_string(path)?))
-        } else {
-            Ok(None)
+    async fn put(&self, uri: &str, response: CachedResponse) -> Result<()> {
+        let mut path = cache_path(&self.root, uri, "json");
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
         }
+        tokio::fs::write(&path, &response.body).await?;
+        path.set_extension("etag");
+        write_optional(&path, &response.etag).await?;
+        path.set_extension("last_modified");
+        write_optional(&path, &response.last_modified).await?;
+        path.set_extension("link");
+        write_optional(&path, &response.link).await?;
+        Ok(())
     }
 }
 
@@ -129,38 +243,26 @@ impl HttpCache for FileBasedCache {
 /// # use octorust::http_cache::cache_path;
 /// assert_eq!(
 ///     cache_path(
-///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos",
-///         "json"
-///     ),
-///     PathBuf::from("/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos.json"),
-/// );
-/// assert_eq!(
-///     cache_path(
-///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos?page=2",
+///         &PathBuf::from("/home/.cache"),
+///         "https://example.com/users/dwijnand/repos",
 ///         "json"
 ///     ),
-///     PathBuf::from(
-///         "/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos/6dd58bde8abb0869.\
-///          json"
-///     ),
+///     PathBuf::from("/home/.cache/v1/https/example.com/users/dwijnand/repos.json"),
 /// );
 /// assert_eq!(
 ///     cache_path(
-///         &PathBuf::from("/home/.github/cache"),
-///         "https://api.github.com/users/dwijnand/repos?page=2&per_page=5",
+///         &PathBuf::from("/home/.cache"),
+///         "https://example.com/users/dwijnand/repos?page=2",
 ///         "json"
 ///     ),
 ///     PathBuf::from(
-///         "/home/.github/cache/v1/https/api.github.com/users/dwijnand/repos/d862dcd2d85cebca.\
-///          json"
+///         "/home/.cache/v1/https/example.com/users/dwijnand/repos/6dd58bde8abb0869.json"
 ///     ),
 /// );
 /// ```
 #[doc(hidden)] // public for doc testing only
 pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathBuf {
-    let uri_encoded = uri.replace(" ", "%20");
+    let uri_encoded = uri.replace(' ', "%20");
     let uri = uri_encoded
         .parse::<Uri>()
         .unwrap_or_else(|_| panic!("Expected a URI, got {}", uri_encoded));
@@ -168,7 +270,7 @@ pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathB
     let mut path = dir.to_path_buf();
     path.push("v1");
     path.push(parts.scheme.expect("no URI scheme").as_str()); // https
-    path.push(parts.authority.expect("no URI authority").as_str()); // api.github.com
+    path.push(parts.authority.expect("no URI authority").as_str()); // example.com
     path.push(Path::new(&uri.path()[1..])); // users/dwijnand/repos
     if let Some(query) = uri.query() {
         path.push(hash1(query, DefaultHasher::new())); // fa269019d5035d5f
@@ -177,29 +279,49 @@ pub fn cache_path<S: AsRef<OsStr>>(dir: &Path, uri: &str, extension: S) -> PathB
     path
 }
 
-fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String> {
-    //println!("reading path: {}", path.as_ref().display());
-    fs::read_to_string(path).map_err(Error::from)
+/// The response cached for the URI. Failing to read the cache isn't fatal, the
+/// request is just sent unconditionally.
+pub(crate) async fn lookup(cache: &BoxedHttpCache, uri: &str) -> Option<CachedResponse> {
+    match cache.get(uri).await {
+        Ok(response) => response,
+        Err(e) => {
+            log::info!("failed to read the cached response: {}", e);
+            None
+        }
+    }
 }
 
-fn no_read<T, E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> Result<T> {
-    Err(Error::from(io::Error::new(io::ErrorKind::NotFound, error)))
+/// Cache a successful response, if the server sent a validator for it.
+pub(crate) async fn store(
+    cache: &BoxedHttpCache,
+    uri: &str,
+    headers: &http::HeaderMap,
+    body: &bytes::Bytes,
+) {
+    if let Some(response) = CachedResponse::from_response(headers, body.clone()) {
+        if let Err(e) = cache.put(uri, response).await {
+            // failing to cache isn't fatal, so just log & swallow the error
+            log::info!("failed to cache the response: {}", e);
+        }
+    }
 }
 
-// Separate to provide a blanket implementation for `T: HttpCache + Clone`
-// https://stackoverflow.com/a/30353928/463761
-#[doc(hidden)]
-pub trait HttpCacheClone {
-    #[doc(hidden)]
-    fn box_clone(&self) -> BoxedHttpCache;
+async fn read_optional(path: &Path) -> Result<Option<String>> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
 }
 
-impl<T> HttpCacheClone for T
-where
-    T: 'static + HttpCache + Clone + Send + Sync,
-{
-    fn box_clone(&self) -> BoxedHttpCache {
-        Box::new(self.clone())
+async fn write_optional(path: &Path, value: &Option<String>) -> Result<()> {
+    match value {
+        Some(value) => Ok(tokio::fs::write(path, value).await?),
+        // Don't leave the value of an older response behind.
+        None => match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
     }
 }
 
@@ -2,6 +2,679 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Issues::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListParams {
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<String>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    collab: Option<bool>,
+    orgs: Option<bool>,
+    owned: Option<bool>,
+    pulls: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \* `assigned`: Issues assigned to you  
+    /// \* `created`: Issues created by you  
+    /// \* `mentioned`: Issues mentioning you  
+    /// \* `subscribed`: Issues you're subscribed to updates for  
+    /// \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
+    pub fn filter(mut self, value: crate::types::Filter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// A list of comma separated label names. Example: `bug,ui,@high`
+    pub fn labels(mut self, value: impl Into<String>) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub fn sort(mut self, value: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Set the `collab` query parameter.
+    pub fn collab(mut self, value: bool) -> Self {
+        self.collab = Some(value);
+        self
+    }
+
+    /// Set the `orgs` query parameter.
+    pub fn orgs(mut self, value: bool) -> Self {
+        self.orgs = Some(value);
+        self
+    }
+
+    /// Set the `owned` query parameter.
+    pub fn owned(mut self, value: bool) -> Self {
+        self.owned = Some(value);
+        self
+    }
+
+    /// Set the `pulls` query parameter.
+    pub fn pulls(mut self, value: bool) -> Self {
+        self.pulls = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListForOrgParams {
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<String>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \* `assigned`: Issues assigned to you  
+    /// \* `created`: Issues created by you  
+    /// \* `mentioned`: Issues mentioning you  
+    /// \* `subscribed`: Issues you're subscribed to updates for  
+    /// \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
+    pub fn filter(mut self, value: crate::types::Filter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// A list of comma separated label names. Example: `bug,ui,@high`
+    pub fn labels(mut self, value: impl Into<String>) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub fn sort(mut self, value: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_assignees`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListAssigneesParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListAssigneesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListForRepoParams {
+    milestone: Option<String>,
+    state: Option<crate::types::IssuesListState>,
+    assignee: Option<String>,
+    creator: Option<String>,
+    mentioned: Option<String>,
+    labels: Option<String>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// If an `integer` is passed, it should refer to a milestone by its `number` field. If the string `*` is passed, issues with any milestone are accepted. If the string `none` is passed, issues without milestones are returned.
+    pub fn milestone(mut self, value: impl Into<String>) -> Self {
+        self.milestone = Some(value.into());
+        self
+    }
+
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Can be the name of a user. Pass in `none` for issues with no assigned user, and `*` for issues assigned to any user.
+    pub fn assignee(mut self, value: impl Into<String>) -> Self {
+        self.assignee = Some(value.into());
+        self
+    }
+
+    /// The user that created the issue.
+    pub fn creator(mut self, value: impl Into<String>) -> Self {
+        self.creator = Some(value.into());
+        self
+    }
+
+    /// A user that's mentioned in the issue.
+    pub fn mentioned(mut self, value: impl Into<String>) -> Self {
+        self.mentioned = Some(value.into());
+        self
+    }
+
+    /// A list of comma separated label names. Example: `bug,ui,@high`
+    pub fn labels(mut self, value: impl Into<String>) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub fn sort(mut self, value: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_comments_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListCommentsForRepoParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListCommentsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Either `asc` or `desc`. Ignored without the `sort` parameter.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_events_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListEventsForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListEventsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_comments`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListCommentsParams {
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListCommentsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_events`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListEventsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListEventsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_labels_on_issue`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListLabelsOnIssueParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListLabelsOnIssueParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_events_for_timeline`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListEventsForTimelineParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListEventsForTimelineParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_labels_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListLabelsForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListLabelsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_milestones`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListMilestonesParams {
+    state: Option<crate::types::IssuesListState>,
+    sort: Option<crate::types::IssuesListMilestonesSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListMilestonesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The state of the milestone. Either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// What to sort results by. Either `due_on` or `completeness`.
+    pub fn sort(mut self, value: crate::types::IssuesListMilestonesSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// The direction of the sort. Either `asc` or `desc`.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_labels_for_milestone`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListLabelsForMilestoneParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListLabelsForMilestoneParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Issues::list_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct IssuesListForAuthenticatedUserParams {
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<String>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl IssuesListForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \* `assigned`: Issues assigned to you  
+    /// \* `created`: Issues created by you  
+    /// \* `mentioned`: Issues mentioning you  
+    /// \* `subscribed`: Issues you're subscribed to updates for  
+    /// \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
+    pub fn filter(mut self, value: crate::types::Filter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// A list of comma separated label names. Example: `bug,ui,@high`
+    pub fn labels(mut self, value: impl Into<String>) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub fn sort(mut self, value: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Issues {
     pub client: Client,
 }
@@ -31,77 +704,45 @@ impl Issues {
      *
      * **Parameters:**
      *
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `collab: bool`
-     * * `orgs: bool`
-     * * `owned: bool`
-     * * `pulls: bool`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     */
-    pub async fn list(
-        &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        collab: bool,
-        orgs: bool,
-        owned: bool,
-        pulls: bool,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::Issue>> {
+     * * `params: &IssuesListParams` -- The optional query parameters.
+     */
+    pub async fn list(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collab {
-            query_args.push(("collab".to_string(), collab.to_string()));
+        if let Some(v) = &params.collab {
+            query_args.push(("collab".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if orgs {
-            query_args.push(("orgs".to_string(), orgs.to_string()));
+        if let Some(v) = &params.orgs {
+            query_args.push(("orgs".to_string(), v.to_string()));
         }
-        if owned {
-            query_args.push(("owned".to_string(), owned.to_string()));
+        if let Some(v) = &params.owned {
+            query_args.push(("owned".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if pulls {
-            query_args.push(("pulls".to_string(), pulls.to_string()));
+        if let Some(v) = &params.pulls {
+            query_args.push(("pulls".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/issues?{}", query_), None);
@@ -134,49 +775,37 @@ impl Issues {
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
      */
-    pub async fn list_all(
-        &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        collab: bool,
-        orgs: bool,
-        owned: bool,
-        pulls: bool,
-    ) -> Result<Vec<crate::types::Issue>> {
+    pub async fn list_all(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collab {
-            query_args.push(("collab".to_string(), collab.to_string()));
+        if let Some(v) = &params.collab {
+            query_args.push(("collab".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if orgs {
-            query_args.push(("orgs".to_string(), orgs.to_string()));
+        if let Some(v) = &params.orgs {
+            query_args.push(("orgs".to_string(), v.to_string()));
         }
-        if owned {
-            query_args.push(("owned".to_string(), owned.to_string()));
+        if let Some(v) = &params.owned {
+            query_args.push(("owned".to_string(), v.to_string()));
         }
-        if pulls {
-            query_args.push(("pulls".to_string(), pulls.to_string()));
+        if let Some(v) = &params.pulls {
+            query_args.push(("pulls".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/issues?{}", query_), None);
@@ -190,6 +819,88 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List issues assigned to the authenticated user across all visible repositories including owned repositories, member
+     * repositories, and organization repositories. You can use the `filter` query parameter to fetch issues that are not
+     * necessarily assigned to you.
+     *
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &IssuesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Issue> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.collab {
+            query_args.push(("collab".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.orgs {
+            query_args.push(("orgs".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.owned {
+            query_args.push(("owned".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.pulls {
+            query_args.push(("pulls".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/issues?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Issue>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Issue>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List organization issues assigned to the authenticated user.
      *
@@ -207,58 +918,37 @@ impl Issues {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_org(
         &self,
         org: &str,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForOrgParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -298,31 +988,26 @@ impl Issues {
     pub async fn list_all_for_org(
         &self,
         org: &str,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &IssuesListForOrgParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -343,6 +1028,81 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List issues in an organization assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Issue> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/issues?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Issue>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Issue>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List assignees.
      *
@@ -356,22 +1116,20 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListAssigneesParams` -- The optional query parameters.
      */
     pub async fn list_assignees(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListAssigneesParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -427,6 +1185,56 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the [available assignees](https://help.github.com/articles/assigning-issues-and-pull-requests-to-other-github-users/) for issues in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_all_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check if a user can be assigned.
      *
@@ -489,69 +1297,47 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `milestone: &str` -- If an `integer` is passed, it should refer to a milestone by its `number` field. If the string `*` is passed, issues with any milestone are accepted. If the string `none` is passed, issues without milestones are returned.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `assignee: &str` -- Can be the name of a user. Pass in `none` for issues with no assigned user, and `*` for issues assigned to any user.
-     * * `creator: &str` -- The user that created the issue.
-     * * `mentioned: &str` -- A user that's mentioned in the issue.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForRepoParams` -- The optional query parameters.
      */
     pub async fn list_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        milestone: &str,
-        state: crate::types::IssuesListState,
-        assignee: &str,
-        creator: &str,
-        mentioned: &str,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForRepoParams,
     ) -> Result<Vec<crate::types::IssueSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignee.is_empty() {
-            query_args.push(("assignee".to_string(), assignee.to_string()));
+        if let Some(v) = &params.assignee {
+            query_args.push(("assignee".to_string(), v.to_string()));
         }
-        if !creator.is_empty() {
-            query_args.push(("creator".to_string(), creator.to_string()));
+        if let Some(v) = &params.creator {
+            query_args.push(("creator".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if !mentioned.is_empty() {
-            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+        if let Some(v) = &params.mentioned {
+            query_args.push(("mentioned".to_string(), v.to_string()));
         }
-        if !milestone.is_empty() {
-            query_args.push(("milestone".to_string(), milestone.to_string()));
+        if let Some(v) = &params.milestone {
+            query_args.push(("milestone".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -593,43 +1379,35 @@ impl Issues {
         &self,
         owner: &str,
         repo: &str,
-        milestone: &str,
-        state: crate::types::IssuesListState,
-        assignee: &str,
-        creator: &str,
-        mentioned: &str,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &IssuesListForRepoParams,
     ) -> Result<Vec<crate::types::IssueSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignee.is_empty() {
-            query_args.push(("assignee".to_string(), assignee.to_string()));
+        if let Some(v) = &params.assignee {
+            query_args.push(("assignee".to_string(), v.to_string()));
         }
-        if !creator.is_empty() {
-            query_args.push(("creator".to_string(), creator.to_string()));
+        if let Some(v) = &params.creator {
+            query_args.push(("creator".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if !mentioned.is_empty() {
-            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+        if let Some(v) = &params.mentioned {
+            query_args.push(("mentioned".to_string(), v.to_string()));
         }
-        if !milestone.is_empty() {
-            query_args.push(("milestone".to_string(), milestone.to_string()));
+        if let Some(v) = &params.milestone {
+            query_args.push(("milestone".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -651,6 +1429,94 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List issues in a repository.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssueSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.assignee {
+            query_args.push(("assignee".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.creator {
+            query_args.push(("creator".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.mentioned {
+            query_args.push(("mentioned".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.milestone {
+            query_args.push(("milestone".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IssueSimple>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::IssueSimple>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an issue.
      *
@@ -704,39 +1570,29 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListCommentsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListCommentsForRepoParams,
     ) -> Result<Vec<crate::types::IssueComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -763,29 +1619,77 @@ impl Issues {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
      *
-     * As opposed to `list_comments_for_repo`, this function returns all the pages of the request at once.
+     * As opposed to `list_comments_for_repo`, this function returns all the pages of the request at once.
+     *
+     * By default, Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub async fn list_all_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
      *
      * By default, Issue Comments are ordered by ascending ID.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
      */
-    pub async fn list_all_comments_for_repo(
+    pub fn list_all_comments_for_repo_stream(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> Result<Vec<crate::types::IssueComment>> {
+        params: &IssuesListCommentsForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssueComment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -797,15 +1701,30 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IssueComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::IssueComment>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * Get an issue comment.
@@ -936,22 +1855,20 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_events_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListEventsForRepoParams,
     ) -> Result<Vec<crate::types::IssueEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1007,6 +1924,56 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_all_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssueEvent> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IssueEvent>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::IssueEvent>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an issue event.
      *
@@ -1234,27 +2201,23 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListCommentsParams` -- The optional query parameters.
      */
     pub async fn list_comments(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListCommentsParams,
     ) -> Result<Vec<crate::types::IssueComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1294,10 +2257,10 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &IssuesListCommentsParams,
     ) -> Result<Vec<crate::types::IssueComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1321,6 +2284,67 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssueComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IssueComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::IssueComment>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an issue comment.
      *
@@ -1376,23 +2400,21 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsParams` -- The optional query parameters.
      */
     pub async fn list_events(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListEventsParams,
     ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1451,6 +2473,64 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_all_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssueEventAnyOf> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IssueEventAnyOf>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IssueEventAnyOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List labels for an issue.
      *
@@ -1465,23 +2545,21 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsOnIssueParams` -- The optional query parameters.
      */
     pub async fn list_labels_on_issue(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsOnIssueParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1540,6 +2618,58 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_all_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Label> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Label>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Label>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Set labels for an issue.
      *
@@ -1797,23 +2927,21 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsForTimelineParams` -- The optional query parameters.
      */
     pub async fn list_events_for_timeline(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListEventsForTimelineParams,
     ) -> Result<Vec<crate::types::Data>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1872,6 +3000,58 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_all_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Data> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/timeline",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Data>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Data>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List labels for a repository.
      *
@@ -1885,22 +3065,20 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_labels_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsForRepoParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1956,6 +3134,56 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_all_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Label> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Label>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Label>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a label.
      *
@@ -2123,39 +3351,29 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `sort: crate::types::IssuesListMilestonesSort` -- What to sort results by. Either `due_on` or `completeness`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListMilestonesParams` -- The optional query parameters.
      */
     pub async fn list_milestones(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        sort: crate::types::IssuesListMilestonesSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListMilestonesParams,
     ) -> Result<Vec<crate::types::Milestone>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2192,19 +3410,17 @@ impl Issues {
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        sort: crate::types::IssuesListMilestonesSort,
-        direction: crate::types::Order,
+        params: &IssuesListMilestonesParams,
     ) -> Result<Vec<crate::types::Milestone>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2226,6 +3442,69 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_all_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Milestone> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Milestone>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Milestone>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a milestone.
      *
@@ -2399,23 +3678,21 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `milestone_number: i64` -- milestone_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsForMilestoneParams` -- The optional query parameters.
      */
     pub async fn list_labels_for_milestone(
         &self,
         owner: &str,
         repo: &str,
         milestone_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsForMilestoneParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2474,6 +3751,58 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_all_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Label> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Label>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Label>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List user account issues assigned to the authenticated user.
      *
@@ -2490,57 +3819,36 @@ impl Issues {
      *
      * **Parameters:**
      *
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_for_authenticated_user(
         &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/issues?{}", query_), None);
@@ -2572,31 +3880,26 @@ impl Issues {
      */
     pub async fn list_all_for_authenticated_user(
         &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &IssuesListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !labels.is_empty() {
-            query_args.push(("labels".to_string(), labels.to_string()));
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/issues?{}", query_), None);
@@ -2610,4 +3913,71 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List issues across owned and member repositories assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Issue> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/issues?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Issue>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Issue>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -73,16 +73,19 @@
 //! ```
 //!
 //! Then use the `Client::custom` constructor to provide a cache implementation.
+//! Responses are kept in memory with `<dyn HttpCache>::in_memory`, or in files
+//! with `<dyn HttpCache>::in_home_dir`. Implement `HttpCache` to store them
+//! elsewhere.
 //!
 //! Here is an example:
 //!
 //! ```rust
-//! use octorust::{auth::Credentials, Client};
+//! use octorust::{auth::Credentials, Client, ClientBuilder};
 //! #[cfg(feature = "httpcache")]
 //! use octorust::http_cache::HttpCache;
 //!
 //! #[cfg(feature = "httpcache")]
-//! let http_cache = HttpCache::in_home_dir();
+//! let http_cache = <dyn HttpCache>::in_home_dir();
 //!
 //! #[cfg(not(feature = "httpcache"))]
 //! let github = Client::custom(
@@ -90,7 +93,7 @@
 //!     Credentials::Token(
 //!       String::from("personal-access-token")
 //!     ),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //! );
 //!
 //! #[cfg(feature = "httpcache")]
@@ -99,7 +102,7 @@
 //!     Credentials::Token(
 //!       String::from("personal-access-token")
 //!     ),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //!     http_cache
 //! );
 //! ```
@@ -112,7 +115,7 @@
 //! ```rust
 //! use std::env;
 //!
-//! use octorust::{Client, auth::{Credentials, InstallationTokenGenerator, JWTCredentials}};
+//! use octorust::{Client, ClientBuilder, auth::{Credentials, InstallationTokenGenerator, JWTCredentials}};
 //! #[cfg(feature = "httpcache")]
 //! use octorust::http_cache::FileBasedCache;
 //!
@@ -137,7 +140,7 @@
 //! #[cfg(feature = "httpcache")]
 //! dir.push(".cache/github");
 //! #[cfg(feature = "httpcache")]
-//! let http_cache = Box::new(FileBasedCache::new(dir));
+//! let http_cache = std::sync::Arc::new(FileBasedCache::new(dir));
 //!
 //! let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 //!
@@ -145,18 +148,69 @@
 //! let github = Client::custom(
 //!     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
 //!     Credentials::InstallationToken(token_generator),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //! );
 //!
 //! #[cfg(feature = "httpcache")]
 //! let github = Client::custom(
 //!     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
 //!     Credentials::InstallationToken(token_generator),
-//!     reqwest::Client::builder().build().unwrap(),
+//!     ClientBuilder::new().build().unwrap(),
 //!     http_cache,
 //! );
 //! ```
 //!
+//! ## Rate limits
+//!
+//! The client keeps the latest rate limits GitHub reports for each resource,
+//! like `core` or `search`, which can be read with `client.rate_limits()`.
+//! With a policy, it also holds back requests that would be rate limited,
+//! either waiting for the limit to reset or failing fast.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use octorust::{auth::Credentials, rate_limiter::{RateLimitPolicy, Resource}, Client};
+//!
+//! async fn zen() {
+//!     let mut github = Client::new(
+//!         String::from("user-agent-name"),
+//!         Credentials::Token(String::from("personal-access-token")),
+//!     )
+//!     .unwrap();
+//!     github.with_rate_limit_policy(RateLimitPolicy::Wait {
+//!         max_wait: Duration::from_secs(60),
+//!     });
+//!
+//!     github.meta().get_zen().await.unwrap();
+//!     if let Some(core) = github.rate_limits().get(&Resource::Core) {
+//!         println!("{} requests left", core.remaining);
+//!     }
+//! }
+//! ```
+//!
+//! ## Configuring the HTTP client
+//!
+//! The client traces its requests and retries the transient failures up to 3
+//! times, unless sending a request again could repeat its side effects, like
+//! a `POST` without an `Idempotency-Key`. To set timeouts, a proxy, another
+//! retry policy, the user agent, default headers or more middleware, build the
+//! HTTP client with a `ClientBuilder`. Building it returns an error instead of
+//! panicking.
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use octorust::ClientBuilder;
+//!
+//! let http = ClientBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .connect_timeout(Duration::from_secs(5))
+//!     .no_retries()
+//!     .build()?;
+//! client.with_http_client(http);
+//! ```
+//!
 //! ## Acknowledgements
 //!
 //! Shout out to [hubcaps](https://github.com/softprops/hubcaps) for paving the
@@ -164,6 +218,26 @@
 //! always up to the date with the OpenAPI spec and no longer requires manual
 //! contributions to add new endpoints.
 //!
+//! ## Webhooks
+//!
+//! The `webhooks` module verifies the `X-Hub-Signature-256` of the webhooks
+//! GitHub delivers, and parses them into a `WebhookEvent` by the name of their
+//! event. Events without a variant, like `star`, come back as
+//! `WebhookEvent::Unknown`.
+//!
+//! ```rust
+//! use octorust::webhooks::WebhookEvent;
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//!     if let WebhookEvent::PullRequest(event) =
+//!         WebhookEvent::from_request(b"webhook-secret", headers, body)?
+//!     {
+//!         println!("pull request #{} {}", event.number, event.action);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
 #![allow(clippy::derive_partial_eq_without_eq)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -212,6 +286,9 @@ pub mod markdown;
 pub mod meta;
 /// Move projects to or from GitHub.
 pub mod migrations;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
 /// Manage access of OAuth applications.
 pub mod oauth_authorizations;
 /// Interact with GitHub Orgs.
@@ -224,6 +301,7 @@ pub mod projects;
 pub mod pulls;
 /// Check your current rate limit status.
 pub mod rate_limit;
+pub mod rate_limiter;
 /// Interact with reactions to various GitHub entities.
 pub mod reactions;
 /// Interact with GitHub Repos.
@@ -241,8 +319,9 @@ pub mod types;
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+pub mod webhooks;
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::Result;
 
 pub const FALLBACK_HOST: &str = "https://api.github.com";
 
@@ -272,6 +351,285 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// Errors returned by the client.
+///
+/// Every method returns an `anyhow::Result`. When a request fails because of the
+/// API or the transport, the error holds a `ClientError`, which can be recovered
+/// with `err.downcast_ref::<ClientError>()` and matched on.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The API responded with a non-success status code.
+    #[error("code: {status}, error: {body:?}")]
+    HttpError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: String,
+    },
+    /// The API responded with a non-success status code and a `BasicError` body.
+    #[error("code: {status}, error: {error:?}")]
+    BasicError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: crate::types::BasicError,
+    },
+    /// The API responded with a non-success status code and a `ValidationError` body.
+    #[error("code: {status}, error: {error:?}")]
+    ValidationError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: crate::types::ValidationError,
+    },
+    /// The API refused the request because a rate limit was exceeded.
+    #[error("code: {status}, rate limit exceeded")]
+    RateLimited {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// When the rate limit resets, if the response said so.
+        reset: Option<std::time::SystemTime>,
+    },
+    /// Sending the request or reading the response failed.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    /// A middleware failed while sending the request.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// The response body could not be deserialized.
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    /// The request URL could not be parsed.
+    #[error(transparent)]
+    UrlParse(#[from] url::ParseError),
+    /// Refreshing the credentials used to authenticate the request failed.
+    #[error("refreshing credentials failed: {0}")]
+    AuthRefresh(anyhow::Error),
+}
+
+impl ClientError {
+    /// The status code the API responded with, if it responded.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            ClientError::HttpError { status, .. }
+            | ClientError::BasicError { status, .. }
+            | ClientError::ValidationError { status, .. }
+            | ClientError::RateLimited { status, .. } => Some(*status),
+            ClientError::Reqwest(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Build the error for a response with a non-success status code.
+    pub(crate) fn from_response(
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let retry_after = header("retry-after");
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || header("x-ratelimit-remaining") == Some(0)
+            || (status == reqwest::StatusCode::FORBIDDEN && retry_after.is_some())
+        {
+            let now = std::time::SystemTime::now();
+            let reset = match (retry_after, header("x-ratelimit-reset")) {
+                (Some(secs), _) => Some(now + std::time::Duration::from_secs(secs)),
+                // Some APIs send a unix timestamp, others the number of seconds left.
+                (None, Some(reset)) if reset > 1_000_000_000 => {
+                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+                }
+                (None, Some(secs)) => Some(now + std::time::Duration::from_secs(secs)),
+                (None, None) => None,
+            };
+
+            return ClientError::RateLimited {
+                status,
+                headers,
+                reset,
+            };
+        }
+
+        match status.as_u16() {
+            400 | 401 | 403 | 404 | 409 | 410 => {
+                if let Ok(error) = serde_json::from_slice::<crate::types::BasicError>(body) {
+                    return ClientError::BasicError {
+                        status,
+                        headers,
+                        error,
+                    };
+                }
+            }
+            422 => {
+                if let Ok(error) = serde_json::from_slice::<crate::types::ValidationError>(body) {
+                    return ClientError::ValidationError {
+                        status,
+                        headers,
+                        error,
+                    };
+                }
+            }
+            _ => (),
+        }
+
+        ClientError::HttpError {
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+}
+
+/// Builds the HTTP client the API client sends its requests with, to hand to
+/// `Client::with_http_client`.
+///
+/// By default, requests are traced and the transient failures are retried up
+/// to 3 times with increasing intervals between attempts. Only the requests
+/// that can safely be sent again are retried: the ones with an idempotent
+/// method, like `GET` or `PUT`, and the ones carrying an `Idempotency-Key`.
+pub struct ClientBuilder {
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<reqwest_retry::policies::ExponentialBackoff>,
+    retry_any_method: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    middleware: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            // Retry up to 3 times with increasing intervals between attempts.
+            retry_policy: Some(
+                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3),
+            ),
+            retry_any_method: false,
+            user_agent: None,
+            default_headers: Default::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Time out the requests, from connecting until the end of the response
+    /// body, after `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time out the connections to the server after `timeout`.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Retry the transient failures with this policy instead of the default one.
+    pub fn retry_policy(mut self, policy: reqwest_retry::policies::ExponentialBackoff) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Don't retry failed requests.
+    pub fn no_retries(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// Also retry the requests that aren't idempotent, like a `POST` without an
+    /// `Idempotency-Key`. A request that timed out may then be carried out twice.
+    pub fn retry_any_method(mut self) -> Self {
+        self.retry_any_method = true;
+        self
+    }
+
+    /// Send this `User-Agent` header with every request.
+    pub fn user_agent<A>(mut self, user_agent: A) -> Self
+    where
+        A: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send these headers with every request, besides the ones of the request.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Add a middleware layer. The layers run in the order they are added, after
+    /// the tracing and each time a request is retried.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the HTTP client. This fails when the user agent isn't a valid header
+    /// value or when the TLS backend can't be initialized.
+    pub fn build(self) -> Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(reqwest::header::HeaderValue::from_str(&user_agent)?);
+        }
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default());
+        if let Some(retry_policy) = self.retry_policy {
+            let retry_any_method = self.retry_any_method;
+            // Retry failed requests.
+            client = client.with(reqwest_conditional_middleware::ConditionalMiddleware::new(
+                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                move |req: &reqwest::Request| {
+                    req.try_clone().is_some() && (retry_any_method || is_idempotent(req))
+                },
+            ));
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+/// Whether sending the request twice has the same effect as sending it once.
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    req.method().is_idempotent() || req.headers().contains_key("idempotency-key")
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RootDefaultServer {}
 
@@ -289,6 +647,8 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+    rate_limit_policy: Option<crate::rate_limiter::RateLimitPolicy>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -299,18 +659,7 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().build()?;
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest_middleware::ClientBuilder::new(http)
-            // Trace HTTP requests. See the tracing crate to make use of these traces.
-            .with(reqwest_tracing::TracingMiddleware::default())
-            // Retry failed requests.
-            .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                |req: &reqwest::Request| req.try_clone().is_some(),
-            ))
-            .build();
+        let client = ClientBuilder::new().build()?;
 
         #[cfg(feature = "httpcache")]
         {
@@ -344,6 +693,8 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            rate_limiter: Default::default(),
+            rate_limit_policy: None,
             http_cache,
         }
     }
@@ -364,6 +715,8 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            rate_limiter: Default::default(),
+            rate_limit_policy: None,
         }
     }
 
@@ -386,6 +739,33 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Send the requests with this HTTP client, like one built with a
+    /// `ClientBuilder`.
+    pub fn with_http_client(
+        &mut self,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> &mut Self {
+        self.client = client;
+        self
+    }
+
+    /// Wait for, or fail on, exceeded rate limits instead of sending requests
+    /// that would be refused. This also retries requests refused because of
+    /// a rate limit, after a `Retry-After` or the reset of the limit.
+    pub fn with_rate_limit_policy(
+        &mut self,
+        policy: crate::rate_limiter::RateLimitPolicy,
+    ) -> &mut Self {
+        self.rate_limit_policy = Some(policy);
+        self
+    }
+
+    /// The latest rate limits reported by GitHub, shared with the clones of
+    /// the client.
+    pub fn rate_limits(&self) -> &crate::rate_limiter::RateLimiter {
+        &self.rate_limiter
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!(
             "{}{}",
@@ -441,14 +821,18 @@ impl Client {
                         .append_pair("client_secret", secret);
                     (u, None)
                 })
-                .map_err(Error::from),
+                .map_err(|e| ClientError::from(e).into()),
             Some(&crate::auth::Credentials::Token(ref token)) => {
                 let auth = format!("token {}", token);
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url
+                    .map(|u| (u, Some(auth)))
+                    .map_err(|e| ClientError::from(e).into())
             }
             Some(&crate::auth::Credentials::JWT(ref jwt)) => {
                 let auth = format!("Bearer {}", jwt.token());
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url
+                    .map(|u| (u, Some(auth)))
+                    .map_err(|e| ClientError::from(e).into())
             }
             Some(&crate::auth::Credentials::InstallationToken(ref apptoken)) => {
                 let token = if let Some(token) = apptoken.token().await {
@@ -471,7 +855,8 @@ impl Client {
                                     repository_ids: Default::default(),
                                 },
                             )
-                            .await?;
+                            .await
+                            .map_err(ClientError::AuthRefresh)?;
                         *token_guard = Some(crate::auth::ExpiringInstallationToken::new(
                             token.token.clone(),
                             created_at,
@@ -480,9 +865,13 @@ impl Client {
                     }
                 };
                 let auth = format!("token {}", token);
-                parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+                parsed_url
+                    .map(|u| (u, Some(auth)))
+                    .map_err(|e| ClientError::from(e).into())
             }
-            None => parsed_url.map(|u| (u, None)).map_err(Error::from),
+            None => parsed_url
+                .map(|u| (u, None))
+                .map_err(|e| ClientError::from(e).into()),
         }
     }
 
@@ -497,27 +886,22 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        #[cfg(feature = "httpcache")]
-        let uri2 = uri.to_string();
-
         let (url, auth) = self.url_and_auth(uri, authentication).await?;
+        let resource = crate::rate_limiter::Resource::for_path(url.path());
 
         let instance = <&Client>::clone(&self);
 
-        #[cfg(not(feature = "httpcache"))]
-        let mut req = instance.client.request(method, url);
-
         #[cfg(feature = "httpcache")]
-        let mut req = {
-            let mut req = instance.client.request(method.clone(), url);
-            if method == http::Method::GET {
-                if let Ok(etag) = instance.http_cache.lookup_etag(&uri2) {
-                    req = req.header(http::header::IF_NONE_MATCH, etag);
-                }
-            }
-            req
+        let cacheable = method == http::Method::GET;
+        #[cfg(feature = "httpcache")]
+        let cached = if cacheable {
+            crate::http_cache::lookup(&instance.http_cache, uri).await
+        } else {
+            None
         };
 
+        let mut req = instance.client.request(method, url);
+
         if let Some(content_type) = &message.content_type {
             req = req.header(http::header::CONTENT_TYPE, content_type.clone());
         }
@@ -532,21 +916,36 @@ impl Client {
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        let response = req.send().await?;
 
+        #[allow(unused_mut)]
+        let mut req = req.build().map_err(ClientError::from)?;
         #[cfg(feature = "httpcache")]
-        let instance2 = <&Client>::clone(&self);
-
+        let unconditional = req.try_clone();
         #[cfg(feature = "httpcache")]
-        let uri3 = uri.to_string();
+        if let Some(cached) = &cached {
+            req.headers_mut().extend(cached.conditional_headers());
+        }
 
-        #[cfg(not(feature = "httpcache"))]
-        let (remaining, reset) = crate::utils::get_header_values(response.headers());
+        #[allow(unused_mut)]
+        let mut response = instance
+            .rate_limiter
+            .send(&instance.client, req, &resource, instance.rate_limit_policy)
+            .await?;
 
+        // Nothing is cached to answer a 304 with, so ask for the whole response.
         #[cfg(feature = "httpcache")]
-        let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
+        if response.status() == http::StatusCode::NOT_MODIFIED && cached.is_none() {
+            if let Some(req) = unconditional {
+                log::debug!("received a 304 without a cached response, requesting it again");
+                response = instance
+                    .rate_limiter
+                    .send(&instance.client, req, &resource, instance.rate_limit_policy)
+                    .await?;
+            }
+        }
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -554,23 +953,13 @@ impl Client {
             .and_then(|l| parse_link_header::parse(l).ok());
         let next_link = link.as_ref().and_then(crate::utils::next_link);
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
             #[cfg(feature = "httpcache")]
-            {
-                if let Some(etag) = etag {
-                    if let Err(e) = instance2.http_cache.cache_response(
-                        &uri3,
-                        &response_body,
-                        &etag,
-                        &next_link.as_ref().map(|n| n.0.clone()),
-                    ) {
-                        // failing to cache isn't fatal, so just log & swallow the error
-                        log::info!("failed to cache body & etag: {}", e);
-                    }
-                }
+            if cacheable {
+                crate::http_cache::store(&instance.http_cache, uri, &headers, &response_body).await;
             }
 
             let parsed_response = if status == http::StatusCode::NO_CONTENT
@@ -582,52 +971,28 @@ impl Client {
             };
             parsed_response
                 .map(|out| (next_link, out))
-                .map_err(Error::from)
-        } else if status == http::StatusCode::NOT_MODIFIED {
-            // only supported case is when client provides if-none-match
-            // header when cargo builds with --cfg feature="httpcache"
-            #[cfg(feature = "httpcache")]
-            {
-                let body = instance2.http_cache.lookup_body(&uri3).unwrap();
-                let out = serde_json::from_str::<Out>(&body).unwrap();
-                let link = match next_link {
-                    Some(next_link) => Ok(Some(next_link)),
-                    None => instance2
-                        .http_cache
-                        .lookup_next_link(&uri3)
-                        .map(|next_link| next_link.map(crate::utils::NextLink)),
-                };
-                link.map(|link| (link, out))
-            }
-            #[cfg(not(feature = "httpcache"))]
-            {
-                unreachable!("this should not be reachable without the httpcache feature enabled")
-            }
+                .map_err(|e| ClientError::from(e).into())
         } else {
-            let error = match (remaining, reset) {
-                (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs();
-                    anyhow!(
-                        "rate limit exceeded, will reset in {} seconds",
-                        u64::from(reset) - now
-                    )
-                }
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!(
-                            "code: {}, error: {:?}",
-                            status,
-                            String::from_utf8_lossy(&response_body),
-                        )
-                    }
+            // A 304 is only expected when the request was made conditional on a
+            // cached response, with the `httpcache` feature.
+            #[cfg(feature = "httpcache")]
+            if status == http::StatusCode::NOT_MODIFIED {
+                if let Some(cached) = cached {
+                    let out =
+                        serde_json::from_slice::<Out>(&cached.body).map_err(ClientError::from)?;
+                    let next_link = next_link.or_else(|| {
+                        cached
+                            .link
+                            .as_deref()
+                            .and_then(|l| parse_link_header::parse(l).ok())
+                            .as_ref()
+                            .and_then(crate::utils::next_link)
+                    });
+                    return Ok((next_link, out));
                 }
-            };
-            Err(error)
+            }
+
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -648,6 +1013,51 @@ impl Client {
         Ok(r)
     }
 
+    #[allow(dead_code)]
+    async fn download(
+        &self,
+        method: http::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<crate::utils::Download> {
+        let (url, auth) = self
+            .url_and_auth(uri, crate::auth::AuthenticationConstraint::Unconstrained)
+            .await?;
+        let resource = crate::rate_limiter::Resource::for_path(url.path());
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        req = req.header(http::header::USER_AGENT, &*instance.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            http::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        // Redirects to the download URL are followed by reqwest, which drops the
+        // authorization header if the redirect points to another host.
+        let req = req.build().map_err(ClientError::from)?;
+        let response = instance
+            .rate_limiter
+            .send(&instance.client, req, &resource, instance.rate_limit_policy)
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(crate::utils::Download::new(response))
+        } else {
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await.map_err(ClientError::from)?;
+            Err(ClientError::from_response(status, headers, &response_body).into())
+        }
+    }
+
     async fn get<D>(&self, uri: &str, message: Message) -> Result<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -2,6 +2,41 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Licenses::get_all_commonly_used`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct LicensesGetAllCommonlyUsedParams {
+    featured: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl LicensesGetAllCommonlyUsedParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the `featured` query parameter.
+    pub fn featured(mut self, value: bool) -> Self {
+        self.featured = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Licenses {
     pub client: Client,
 }
@@ -23,25 +58,21 @@ impl Licenses {
      *
      * **Parameters:**
      *
-     * * `featured: bool`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &LicensesGetAllCommonlyUsedParams` -- The optional query parameters.
      */
     pub async fn get_all_commonly_used(
         &self,
-        featured: bool,
-        per_page: i64,
-        page: i64,
+        params: &LicensesGetAllCommonlyUsedParams,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+        if let Some(v) = &params.featured {
+            query_args.push(("featured".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/licenses?{}", query_), None);
@@ -68,11 +99,11 @@ impl Licenses {
      */
     pub async fn get_all_all_commonly_used(
         &self,
-        featured: bool,
+        params: &LicensesGetAllCommonlyUsedParams,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+        if let Some(v) = &params.featured {
+            query_args.push(("featured".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/licenses?{}", query_), None);
@@ -86,6 +117,59 @@ impl Licenses {
             )
             .await
     }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_all_commonly_used_stream(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::LicenseSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.featured {
+            query_args.push(("featured".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/licenses?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::LicenseSimple>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::LicenseSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a license.
      *
@@ -2,6 +2,27 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Meta::get_octocat`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MetaGetOctocatParams {
+    s: Option<String>,
+}
+
+impl MetaGetOctocatParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The words to show in Octocat's speech bubble
+    pub fn s(mut self, value: impl Into<String>) -> Self {
+        self.s = Some(value.into());
+        self
+    }
+}
+
 pub struct Meta {
     pub client: Client,
 }
@@ -67,12 +88,12 @@ impl Meta {
      *
      * **Parameters:**
      *
-     * * `s: &str` -- The words to show in Octocat's speech bubble.
+     * * `params: &MetaGetOctocatParams` -- The optional query parameters.
      */
-    pub async fn get_octocat(&self, s: &str) -> Result<String> {
+    pub async fn get_octocat(&self, params: &MetaGetOctocatParams) -> Result<String> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+        if let Some(v) = &params.s {
+            query_args.push(("s".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/octocat?{}", query_), None);
@@ -2,6 +2,188 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Migrations::list_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsListForOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+    exclude: Option<Vec<String>>,
+}
+
+impl MigrationsListForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Exclude attributes from the API response to improve performance
+    pub fn exclude(mut self, value: Vec<String>) -> Self {
+        self.exclude = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::get_status_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsGetStatusForOrgParams {
+    exclude: Option<Vec<String>>,
+}
+
+impl MigrationsGetStatusForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Exclude attributes from the API response to improve performance
+    pub fn exclude(mut self, value: Vec<String>) -> Self {
+        self.exclude = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::list_repos_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsListReposForOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl MigrationsListReposForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::get_commit_authors`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsGetCommitAuthorsParams {
+    since: Option<i64>,
+}
+
+impl MigrationsGetCommitAuthorsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A user ID. Only return users with an ID greater than this ID.
+    pub fn since(mut self, value: i64) -> Self {
+        self.since = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::list_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsListForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl MigrationsListForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::get_status_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsGetStatusForAuthenticatedUserParams {
+    exclude: Option<Vec<String>>,
+}
+
+impl MigrationsGetStatusForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the `exclude` query parameter.
+    pub fn exclude(mut self, value: Vec<String>) -> Self {
+        self.exclude = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Migrations::list_repos_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationsListReposForUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl MigrationsListReposForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Migrations {
     pub client: Client,
 }
@@ -24,26 +206,22 @@ impl Migrations {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `exclude: &[String]` -- Exclude attributes from the API response to improve performance.
+     * * `params: &MigrationsListForOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_org(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
-        exclude: &[String],
+        params: &MigrationsListForOrgParams,
     ) -> Result<Vec<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        if let Some(v) = &params.exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -78,11 +256,11 @@ impl Migrations {
     pub async fn list_all_for_org(
         &self,
         org: &str,
-        exclude: &[String],
+        params: &MigrationsListForOrgParams,
     ) -> Result<Vec<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        if let Some(v) = &params.exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -103,6 +281,61 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the most recent migrations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Migration> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Migration>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Migration>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Start an organization migration.
      *
@@ -158,17 +391,17 @@ impl Migrations {
      *
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
-     * * `exclude: &[String]` -- Exclude attributes from the API response to improve performance.
+     * * `params: &MigrationsGetStatusForOrgParams` -- The optional query parameters.
      */
     pub async fn get_status_for_org(
         &self,
         org: &str,
         migration_id: i64,
-        exclude: &[String],
+        params: &MigrationsGetStatusForOrgParams,
     ) -> Result<crate::types::Migration> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        if let Some(v) = &params.exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -204,7 +437,11 @@ impl Migrations {
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
      */
-    pub async fn download_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()> {
+    pub async fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations/{}/archive",
@@ -214,13 +451,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -309,22 +540,20 @@ impl Migrations {
      *
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListReposForOrgParams` -- The optional query parameters.
      */
     pub async fn list_repos_for_org(
         &self,
         org: &str,
         migration_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListReposForOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -380,6 +609,62 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all the repositories for this organization migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_all_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an import status.
      *
@@ -571,17 +856,17 @@ impl Migrations {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `since: i64` -- A user ID. Only return users with an ID greater than this ID.
+     * * `params: &MigrationsGetCommitAuthorsParams` -- The optional query parameters.
      */
     pub async fn get_commit_authors(
         &self,
         owner: &str,
         repo: &str,
-        since: i64,
+        params: &MigrationsGetCommitAuthorsParams,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -620,11 +905,11 @@ impl Migrations {
         &self,
         owner: &str,
         repo: &str,
-        since: i64,
+        params: &MigrationsGetCommitAuthorsParams,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -646,6 +931,67 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Each type of source control system represents authors in a different way. For example, a Git commit author has a display name and an email address, but a Subversion commit author just has a username. The GitHub Importer will make the author information valid, but the author might not be correct. For example, it will change the bare Subversion username `hubot` into something like `hubot <hubot@12341234-abab-fefe-8787-fedcba987654>`.
+     *
+     * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_all_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PorterAuthor> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/authors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PorterAuthor>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::PorterAuthor>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Map a commit author.
      *
@@ -758,6 +1104,62 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List files larger than 100MB found during the import
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_all_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PorterLargeFile> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/large_files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PorterLargeFile>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PorterLargeFile>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Update Git LFS preference.
      *
@@ -807,20 +1209,18 @@ impl Migrations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -859,6 +1259,46 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all migrations a user has started.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Migration> {
+        let url = self.client.url("/user/migrations", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Migration>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Migration>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Start a user migration.
      *
@@ -902,16 +1342,16 @@ impl Migrations {
      * **Parameters:**
      *
      * * `migration_id: i64` -- migration_id parameter.
-     * * `exclude: &[String]` -- The list of events for the GitHub app.
+     * * `params: &MigrationsGetStatusForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn get_status_for_authenticated_user(
         &self,
         migration_id: i64,
-        exclude: &[String],
+        params: &MigrationsGetStatusForAuthenticatedUserParams,
     ) -> Result<crate::types::Migration> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        if let Some(v) = &params.exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -965,7 +1405,10 @@ impl Migrations {
      *
      * * `migration_id: i64` -- migration_id parameter.
      */
-    pub async fn get_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()> {
+    pub async fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/user/migrations/{}/archive",
@@ -974,13 +1417,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -1063,21 +1500,19 @@ impl Migrations {
      * **Parameters:**
      *
      * * `migration_id: i64` -- migration_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListReposForUserParams` -- The optional query parameters.
      */
     pub async fn list_repos_for_user(
         &self,
         migration_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListReposForUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1130,4 +1565,58 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all the repositories for this user migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_all_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url(
+            &format!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -0,0 +1,12110 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Actions`](crate::actions::Actions).
+    pub fn actions(&self) -> Actions<'_> {
+        Actions { mock: self }
+    }
+
+    /// Mock the functions of [`Activity`](crate::activity::Activity).
+    pub fn activity(&self) -> Activity<'_> {
+        Activity { mock: self }
+    }
+
+    /// Mock the functions of [`Apps`](crate::apps::Apps).
+    pub fn apps(&self) -> Apps<'_> {
+        Apps { mock: self }
+    }
+
+    /// Mock the functions of [`Billing`](crate::billing::Billing).
+    pub fn billing(&self) -> Billing<'_> {
+        Billing { mock: self }
+    }
+
+    /// Mock the functions of [`Checks`](crate::checks::Checks).
+    pub fn checks(&self) -> Checks<'_> {
+        Checks { mock: self }
+    }
+
+    /// Mock the functions of [`CodeScanning`](crate::code_scanning::CodeScanning).
+    pub fn code_scanning(&self) -> CodeScanning<'_> {
+        CodeScanning { mock: self }
+    }
+
+    /// Mock the functions of [`CodesOfConduct`](crate::codes_of_conduct::CodesOfConduct).
+    pub fn codes_of_conduct(&self) -> CodesOfConduct<'_> {
+        CodesOfConduct { mock: self }
+    }
+
+    /// Mock the functions of [`Emojis`](crate::emojis::Emojis).
+    pub fn emojis(&self) -> Emojis<'_> {
+        Emojis { mock: self }
+    }
+
+    /// Mock the functions of [`EnterpriseAdmin`](crate::enterprise_admin::EnterpriseAdmin).
+    pub fn enterprise_admin(&self) -> EnterpriseAdmin<'_> {
+        EnterpriseAdmin { mock: self }
+    }
+
+    /// Mock the functions of [`Gists`](crate::gists::Gists).
+    pub fn gists(&self) -> Gists<'_> {
+        Gists { mock: self }
+    }
+
+    /// Mock the functions of [`Git`](crate::git::Git).
+    pub fn git(&self) -> Git<'_> {
+        Git { mock: self }
+    }
+
+    /// Mock the functions of [`Gitignore`](crate::gitignore::Gitignore).
+    pub fn gitignore(&self) -> Gitignore<'_> {
+        Gitignore { mock: self }
+    }
+
+    /// Mock the functions of [`Interactions`](crate::interactions::Interactions).
+    pub fn interactions(&self) -> Interactions<'_> {
+        Interactions { mock: self }
+    }
+
+    /// Mock the functions of [`Issues`](crate::issues::Issues).
+    pub fn issues(&self) -> Issues<'_> {
+        Issues { mock: self }
+    }
+
+    /// Mock the functions of [`Licenses`](crate::licenses::Licenses).
+    pub fn licenses(&self) -> Licenses<'_> {
+        Licenses { mock: self }
+    }
+
+    /// Mock the functions of [`Markdown`](crate::markdown::Markdown).
+    pub fn markdown(&self) -> Markdown<'_> {
+        Markdown { mock: self }
+    }
+
+    /// Mock the functions of [`Meta`](crate::meta::Meta).
+    pub fn meta(&self) -> Meta<'_> {
+        Meta { mock: self }
+    }
+
+    /// Mock the functions of [`Migrations`](crate::migrations::Migrations).
+    pub fn migrations(&self) -> Migrations<'_> {
+        Migrations { mock: self }
+    }
+
+    /// Mock the functions of [`OauthAuthorizations`](crate::oauth_authorizations::OauthAuthorizations).
+    pub fn oauth_authorizations(&self) -> OauthAuthorizations<'_> {
+        OauthAuthorizations { mock: self }
+    }
+
+    /// Mock the functions of [`Orgs`](crate::orgs::Orgs).
+    pub fn orgs(&self) -> Orgs<'_> {
+        Orgs { mock: self }
+    }
+
+    /// Mock the functions of [`Packages`](crate::packages::Packages).
+    pub fn packages(&self) -> Packages<'_> {
+        Packages { mock: self }
+    }
+
+    /// Mock the functions of [`Projects`](crate::projects::Projects).
+    pub fn projects(&self) -> Projects<'_> {
+        Projects { mock: self }
+    }
+
+    /// Mock the functions of [`Pulls`](crate::pulls::Pulls).
+    pub fn pulls(&self) -> Pulls<'_> {
+        Pulls { mock: self }
+    }
+
+    /// Mock the functions of [`RateLimit`](crate::rate_limit::RateLimit).
+    pub fn rate_limit(&self) -> RateLimit<'_> {
+        RateLimit { mock: self }
+    }
+
+    /// Mock the functions of [`Reactions`](crate::reactions::Reactions).
+    pub fn reactions(&self) -> Reactions<'_> {
+        Reactions { mock: self }
+    }
+
+    /// Mock the functions of [`Repos`](crate::repos::Repos).
+    pub fn repos(&self) -> Repos<'_> {
+        Repos { mock: self }
+    }
+
+    /// Mock the functions of [`Scim`](crate::scim::Scim).
+    pub fn scim(&self) -> Scim<'_> {
+        Scim { mock: self }
+    }
+
+    /// Mock the functions of [`Search`](crate::search::Search).
+    pub fn search(&self) -> Search<'_> {
+        Search { mock: self }
+    }
+
+    /// Mock the functions of [`SecretScanning`](crate::secret_scanning::SecretScanning).
+    pub fn secret_scanning(&self) -> SecretScanning<'_> {
+        SecretScanning { mock: self }
+    }
+
+    /// Mock the functions of [`Teams`](crate::teams::Teams).
+    pub fn teams(&self) -> Teams<'_> {
+        Teams { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+}
+
+pub struct Actions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Actions<'a> {
+    /// Mock [`Actions::get_github_actions_permissions_organization`](crate::actions::Actions::get_github_actions_permissions_organization), a `GET` to `/orgs/{org}/actions/permissions`.
+    pub fn get_github_actions_permissions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsOrganizationPermissions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/permissions",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_github_actions_permissions_organization`](crate::actions::Actions::set_github_actions_permissions_organization), a `PUT` to `/orgs/{org}/actions/permissions`.
+    pub fn set_github_actions_permissions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/permissions",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_selected_repositories_enabled_github_actions_organization`](crate::actions::Actions::list_selected_repositories_enabled_github_actions_organization), a `GET` to `/orgs/{org}/actions/permissions/repositories`.
+    pub fn list_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/permissions/repositories",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_selected_repositories_enabled_github_actions_organization`](crate::actions::Actions::set_selected_repositories_enabled_github_actions_organization), a `PUT` to `/orgs/{org}/actions/permissions/repositories`.
+    pub fn set_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/permissions/repositories",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::enable_selected_repository_github_actions_organization`](crate::actions::Actions::enable_selected_repository_github_actions_organization), a `PUT` to `/orgs/{org}/actions/permissions/repositories/{repository_id}`.
+    pub fn enable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/permissions/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::disable_selected_repository_github_actions_organization`](crate::actions::Actions::disable_selected_repository_github_actions_organization), a `DELETE` to `/orgs/{org}/actions/permissions/repositories/{repository_id}`.
+    pub fn disable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/permissions/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_allowed_actions_organization`](crate::actions::Actions::get_allowed_actions_organization), a `GET` to `/orgs/{org}/actions/permissions/selected-actions`.
+    pub fn get_allowed_actions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SelectedActions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_allowed_actions_organization`](crate::actions::Actions::set_allowed_actions_organization), a `PUT` to `/orgs/{org}/actions/permissions/selected-actions`.
+    pub fn set_allowed_actions_organization(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_self_hosted_runner_groups_for_org`](crate::actions::Actions::list_self_hosted_runner_groups_for_org), a `GET` to `/orgs/{org}/actions/runner-groups`.
+    pub fn list_self_hosted_runner_groups_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_self_hosted_runner_group_for_org`](crate::actions::Actions::create_self_hosted_runner_group_for_org), a `POST` to `/orgs/{org}/actions/runner-groups`.
+    pub fn create_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsOrg> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_self_hosted_runner_group_for_org`](crate::actions::Actions::get_self_hosted_runner_group_for_org), a `GET` to `/orgs/{org}/actions/runner-groups/{runner_group_id}`.
+    pub fn get_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsOrg> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_self_hosted_runner_group_from_org`](crate::actions::Actions::delete_self_hosted_runner_group_from_org), a `DELETE` to `/orgs/{org}/actions/runner-groups/{runner_group_id}`.
+    pub fn delete_self_hosted_runner_group_from_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::update_self_hosted_runner_group_for_org`](crate::actions::Actions::update_self_hosted_runner_group_for_org), a `PATCH` to `/orgs/{org}/actions/runner-groups/{runner_group_id}`.
+    pub fn update_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsOrg> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_repo_access_to_self_hosted_runner_group_in_org`](crate::actions::Actions::list_repo_access_to_self_hosted_runner_group_in_org), a `GET` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories`.
+    pub fn list_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_repo_access_to_self_hosted_runner_group_in_org`](crate::actions::Actions::set_repo_access_to_self_hosted_runner_group_in_org), a `PUT` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories`.
+    pub fn set_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::add_repo_access_to_self_hosted_runner_group_in_org`](crate::actions::Actions::add_repo_access_to_self_hosted_runner_group_in_org), a `PUT` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}`.
+    pub fn add_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::remove_repo_access_to_self_hosted_runner_group_in_org`](crate::actions::Actions::remove_repo_access_to_self_hosted_runner_group_in_org), a `DELETE` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}`.
+    pub fn remove_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_self_hosted_runners_in_group_for_org`](crate::actions::Actions::list_self_hosted_runners_in_group_for_org), a `GET` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners`.
+    pub fn list_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_self_hosted_runners_in_group_for_org`](crate::actions::Actions::set_self_hosted_runners_in_group_for_org), a `PUT` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners`.
+    pub fn set_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::add_self_hosted_runner_to_group_for_org`](crate::actions::Actions::add_self_hosted_runner_to_group_for_org), a `PUT` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}`.
+    pub fn add_self_hosted_runner_to_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/runners/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::remove_self_hosted_runner_from_group_for_org`](crate::actions::Actions::remove_self_hosted_runner_from_group_for_org), a `DELETE` to `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}`.
+    pub fn remove_self_hosted_runner_from_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/runner-groups/{}/runners/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_self_hosted_runners_for_org`](crate::actions::Actions::list_self_hosted_runners_for_org), a `GET` to `/orgs/{org}/actions/runners`.
+    pub fn list_self_hosted_runners_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runners",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_runner_applications_for_org`](crate::actions::Actions::list_runner_applications_for_org), a `GET` to `/orgs/{org}/actions/runners/downloads`.
+    pub fn list_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::RunnerApplication>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_registration_token_for_org`](crate::actions::Actions::create_registration_token_for_org), a `POST` to `/orgs/{org}/actions/runners/registration-token`.
+    pub fn create_registration_token_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/actions/runners/registration-token",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_remove_token_for_org`](crate::actions::Actions::create_remove_token_for_org), a `POST` to `/orgs/{org}/actions/runners/remove-token`.
+    pub fn create_remove_token_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/actions/runners/remove-token",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_self_hosted_runner_for_org`](crate::actions::Actions::get_self_hosted_runner_for_org), a `GET` to `/orgs/{org}/actions/runners/{runner_id}`.
+    pub fn get_self_hosted_runner_for_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Runner> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_self_hosted_runner_from_org`](crate::actions::Actions::delete_self_hosted_runner_from_org), a `DELETE` to `/orgs/{org}/actions/runners/{runner_id}`.
+    pub fn delete_self_hosted_runner_from_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_org_secrets`](crate::actions::Actions::list_org_secrets), a `GET` to `/orgs/{org}/actions/secrets`.
+    pub fn list_org_secrets(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListOrgSecretsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/secrets",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_org_public_key`](crate::actions::Actions::get_org_public_key), a `GET` to `/orgs/{org}/actions/secrets/public-key`.
+    pub fn get_org_public_key(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsPublicKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/secrets/public-key",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_org_secret`](crate::actions::Actions::get_org_secret), a `GET` to `/orgs/{org}/actions/secrets/{secret_name}`.
+    pub fn get_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrganizationActionsSecret> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_or_update_org_secret`](crate::actions::Actions::create_or_update_org_secret), a `PUT` to `/orgs/{org}/actions/secrets/{secret_name}`.
+    pub fn create_or_update_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_org_secret`](crate::actions::Actions::delete_org_secret), a `DELETE` to `/orgs/{org}/actions/secrets/{secret_name}`.
+    pub fn delete_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_selected_repos_for_org_secret`](crate::actions::Actions::list_selected_repos_for_org_secret), a `GET` to `/orgs/{org}/actions/secrets/{secret_name}/repositories`.
+    pub fn list_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelectedReposOrgSecretResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/actions/secrets/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_selected_repos_for_org_secret`](crate::actions::Actions::set_selected_repos_for_org_secret), a `PUT` to `/orgs/{org}/actions/secrets/{secret_name}/repositories`.
+    pub fn set_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/secrets/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::add_selected_repo_to_org_secret`](crate::actions::Actions::add_selected_repo_to_org_secret), a `PUT` to `/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}`.
+    pub fn add_selected_repo_to_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/actions/secrets/{}/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::remove_selected_repo_from_org_secret`](crate::actions::Actions::remove_selected_repo_from_org_secret), a `DELETE` to `/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}`.
+    pub fn remove_selected_repo_from_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/actions/secrets/{}/repositories/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_artifacts_for_repo`](crate::actions::Actions::list_artifacts_for_repo), a `GET` to `/repos/{owner}/{repo}/actions/artifacts`.
+    pub fn list_artifacts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListArtifactsRepoResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/artifacts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_artifact`](crate::actions::Actions::get_artifact), a `GET` to `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}`.
+    pub fn get_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Artifact> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/artifacts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&artifact_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_artifact`](crate::actions::Actions::delete_artifact), a `DELETE` to `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}`.
+    pub fn delete_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/actions/artifacts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&artifact_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::download_artifact`](crate::actions::Actions::download_artifact), a `GET` to `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}`.
+    pub fn download_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/artifacts/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&artifact_id.to_string()),
+                crate::progenitor_support::encode_path(archive_format)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_job_for_workflow_run`](crate::actions::Actions::get_job_for_workflow_run), a `GET` to `/repos/{owner}/{repo}/actions/jobs/{job_id}`.
+    pub fn get_job_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Job> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/jobs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&job_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::download_job_logs_for_workflow_run`](crate::actions::Actions::download_job_logs_for_workflow_run), a `GET` to `/repos/{owner}/{repo}/actions/jobs/{job_id}/logs`.
+    pub fn download_job_logs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/jobs/{}/logs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&job_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_github_actions_permissions_repository`](crate::actions::Actions::get_github_actions_permissions_repository), a `GET` to `/repos/{owner}/{repo}/actions/permissions`.
+    pub fn get_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsRepositoryPermissions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/permissions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_github_actions_permissions_repository`](crate::actions::Actions::set_github_actions_permissions_repository), a `PUT` to `/repos/{owner}/{repo}/actions/permissions`.
+    pub fn set_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/actions/permissions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_allowed_actions_repository`](crate::actions::Actions::get_allowed_actions_repository), a `GET` to `/repos/{owner}/{repo}/actions/permissions/selected-actions`.
+    pub fn get_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SelectedActions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::set_allowed_actions_repository`](crate::actions::Actions::set_allowed_actions_repository), a `PUT` to `/repos/{owner}/{repo}/actions/permissions/selected-actions`.
+    pub fn set_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_self_hosted_runners_for_repo`](crate::actions::Actions::list_self_hosted_runners_for_repo), a `GET` to `/repos/{owner}/{repo}/actions/runners`.
+    pub fn list_self_hosted_runners_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runners",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_runner_applications_for_repo`](crate::actions::Actions::list_runner_applications_for_repo), a `GET` to `/repos/{owner}/{repo}/actions/runners/downloads`.
+    pub fn list_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::RunnerApplication>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_registration_token_for_repo`](crate::actions::Actions::create_registration_token_for_repo), a `POST` to `/repos/{owner}/{repo}/actions/runners/registration-token`.
+    pub fn create_registration_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runners/registration-token",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_remove_token_for_repo`](crate::actions::Actions::create_remove_token_for_repo), a `POST` to `/repos/{owner}/{repo}/actions/runners/remove-token`.
+    pub fn create_remove_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runners/remove-token",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_self_hosted_runner_for_repo`](crate::actions::Actions::get_self_hosted_runner_for_repo), a `GET` to `/repos/{owner}/{repo}/actions/runners/{runner_id}`.
+    pub fn get_self_hosted_runner_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Runner> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_self_hosted_runner_from_repo`](crate::actions::Actions::delete_self_hosted_runner_from_repo), a `DELETE` to `/repos/{owner}/{repo}/actions/runners/{runner_id}`.
+    pub fn delete_self_hosted_runner_from_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_workflow_runs_for_repo`](crate::actions::Actions::list_workflow_runs_for_repo), a `GET` to `/repos/{owner}/{repo}/actions/runs`.
+    pub fn list_workflow_runs_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListWorkflowRunsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_workflow_run`](crate::actions::Actions::get_workflow_run), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}`.
+    pub fn get_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkflowRun> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_workflow_run`](crate::actions::Actions::delete_workflow_run), a `DELETE` to `/repos/{owner}/{repo}/actions/runs/{run_id}`.
+    pub fn delete_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/actions/runs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_reviews_for_run`](crate::actions::Actions::get_reviews_for_run), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals`.
+    pub fn get_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::EnvironmentApproval>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/approvals",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::approve_workflow_run`](crate::actions::Actions::approve_workflow_run), a `POST` to `/repos/{owner}/{repo}/actions/runs/{run_id}/approve`.
+    pub fn approve_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/approve",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_workflow_run_artifacts`](crate::actions::Actions::list_workflow_run_artifacts), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts`.
+    pub fn list_workflow_run_artifacts(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListArtifactsRepoResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/artifacts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::cancel_workflow_run`](crate::actions::Actions::cancel_workflow_run), a `POST` to `/repos/{owner}/{repo}/actions/runs/{run_id}/cancel`.
+    pub fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/cancel",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_jobs_for_workflow_run`](crate::actions::Actions::list_jobs_for_workflow_run), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/jobs`.
+    pub fn list_jobs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListJobsWorkflowRunResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/jobs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::download_workflow_run_logs`](crate::actions::Actions::download_workflow_run_logs), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/logs`.
+    pub fn download_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/logs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_workflow_run_logs`](crate::actions::Actions::delete_workflow_run_logs), a `DELETE` to `/repos/{owner}/{repo}/actions/runs/{run_id}/logs`.
+    pub fn delete_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/logs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_pending_deployments_for_run`](crate::actions::Actions::get_pending_deployments_for_run), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments`.
+    pub fn get_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PendingDeployment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/pending_deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::review_pending_deployments_for_run`](crate::actions::Actions::review_pending_deployments_for_run), a `POST` to `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments`.
+    pub fn review_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Deployment>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/pending_deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::re_run_workflow`](crate::actions::Actions::re_run_workflow), a `POST` to `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun`.
+    pub fn re_run_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/rerun",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_workflow_run_usage`](crate::actions::Actions::get_workflow_run_usage), a `GET` to `/repos/{owner}/{repo}/actions/runs/{run_id}/timing`.
+    pub fn get_workflow_run_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkflowRunUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/runs/{}/timing",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_repo_secrets`](crate::actions::Actions::list_repo_secrets), a `GET` to `/repos/{owner}/{repo}/actions/secrets`.
+    pub fn list_repo_secrets(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListRepoSecretsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/secrets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_repo_public_key`](crate::actions::Actions::get_repo_public_key), a `GET` to `/repos/{owner}/{repo}/actions/secrets/public-key`.
+    pub fn get_repo_public_key(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsPublicKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/secrets/public-key",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_repo_secret`](crate::actions::Actions::get_repo_secret), a `GET` to `/repos/{owner}/{repo}/actions/secrets/{secret_name}`.
+    pub fn get_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsSecret> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_or_update_repo_secret`](crate::actions::Actions::create_or_update_repo_secret), a `PUT` to `/repos/{owner}/{repo}/actions/secrets/{secret_name}`.
+    pub fn create_or_update_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_repo_secret`](crate::actions::Actions::delete_repo_secret), a `DELETE` to `/repos/{owner}/{repo}/actions/secrets/{secret_name}`.
+    pub fn delete_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/actions/secrets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_repo_workflows`](crate::actions::Actions::list_repo_workflows), a `GET` to `/repos/{owner}/{repo}/actions/workflows`.
+    pub fn list_repo_workflows(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListRepoWorkflowsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/workflows",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_workflow`](crate::actions::Actions::get_workflow), a `GET` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}`.
+    pub fn get_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Workflow> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::disable_workflow`](crate::actions::Actions::disable_workflow), a `PUT` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable`.
+    pub fn disable_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}/disable",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_workflow_dispatch`](crate::actions::Actions::create_workflow_dispatch), a `POST` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches`.
+    pub fn create_workflow_dispatch(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}/dispatches",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::enable_workflow`](crate::actions::Actions::enable_workflow), a `PUT` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable`.
+    pub fn enable_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}/enable",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_workflow_runs`](crate::actions::Actions::list_workflow_runs), a `GET` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs`.
+    pub fn list_workflow_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListWorkflowRunsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}/runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_workflow_usage`](crate::actions::Actions::get_workflow_usage), a `GET` to `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing`.
+    pub fn get_workflow_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WorkflowUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/actions/workflows/{}/timing",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::list_environment_secrets`](crate::actions::Actions::list_environment_secrets), a `GET` to `/repositories/{repository_id}/environments/{environment_name}/secrets`.
+    pub fn list_environment_secrets(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListRepoSecretsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repositories/{}/environments/{}/secrets",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_environment_public_key`](crate::actions::Actions::get_environment_public_key), a `GET` to `/repositories/{repository_id}/environments/{environment_name}/secrets/public-key`.
+    pub fn get_environment_public_key(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsPublicKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repositories/{}/environments/{}/secrets/public-key",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::get_environment_secret`](crate::actions::Actions::get_environment_secret), a `GET` to `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}`.
+    pub fn get_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsSecret> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repositories/{}/environments/{}/secrets/{}",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::create_or_update_environment_secret`](crate::actions::Actions::create_or_update_environment_secret), a `PUT` to `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}`.
+    pub fn create_or_update_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repositories/{}/environments/{}/secrets/{}",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+
+    /// Mock [`Actions::delete_environment_secret`](crate::actions::Actions::delete_environment_secret), a `DELETE` to `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}`.
+    pub fn delete_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repositories/{}/environments/{}/secrets/{}",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name),
+                crate::progenitor_support::encode_path(secret_name)
+            ),
+        )
+    }
+}
+
+pub struct Activity<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Activity<'a> {
+    /// Mock [`Activity::list_public_events`](crate::activity::Activity::list_public_events), a `GET` to `/events`.
+    pub fn list_public_events(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/events".to_string())
+    }
+
+    /// Mock [`Activity::get_feeds`](crate::activity::Activity::get_feeds), a `GET` to `/feeds`.
+    pub fn get_feeds(&self) -> crate::mock::MockOperation<'a, crate::types::Feed> {
+        self.mock
+            .operation(reqwest::Method::GET, "/feeds".to_string())
+    }
+
+    /// Mock [`Activity::list_public_events_for_repo_network`](crate::activity::Activity::list_public_events_for_repo_network), a `GET` to `/networks/{owner}/{repo}/events`.
+    pub fn list_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_notifications_for_authenticated_user`](crate::activity::Activity::list_notifications_for_authenticated_user), a `GET` to `/notifications`.
+    pub fn list_notifications_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Thread>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/notifications".to_string())
+    }
+
+    /// Mock [`Activity::mark_notifications_as_read`](crate::activity::Activity::mark_notifications_as_read), a `PUT` to `/notifications`.
+    pub fn mark_notifications_as_read(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Error> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/notifications".to_string())
+    }
+
+    /// Mock [`Activity::get_thread`](crate::activity::Activity::get_thread), a `GET` to `/notifications/threads/{thread_id}`.
+    pub fn get_thread(
+        &self,
+        thread_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Thread> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/notifications/threads/{}",
+                crate::progenitor_support::encode_path(&thread_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Activity::mark_thread_as_read`](crate::activity::Activity::mark_thread_as_read), a `PATCH` to `/notifications/threads/{thread_id}`.
+    pub fn mark_thread_as_read(&self, thread_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/notifications/threads/{}",
+                crate::progenitor_support::encode_path(&thread_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Activity::get_thread_subscription_for_authenticated_user`](crate::activity::Activity::get_thread_subscription_for_authenticated_user), a `GET` to `/notifications/threads/{thread_id}/subscription`.
+    pub fn get_thread_subscription_for_authenticated_user(
+        &self,
+        thread_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ThreadSubscription> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/notifications/threads/{}/subscription",
+                crate::progenitor_support::encode_path(&thread_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Activity::set_thread_subscription`](crate::activity::Activity::set_thread_subscription), a `PUT` to `/notifications/threads/{thread_id}/subscription`.
+    pub fn set_thread_subscription(
+        &self,
+        thread_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ThreadSubscription> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/notifications/threads/{}/subscription",
+                crate::progenitor_support::encode_path(&thread_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Activity::delete_thread_subscription`](crate::activity::Activity::delete_thread_subscription), a `DELETE` to `/notifications/threads/{thread_id}/subscription`.
+    pub fn delete_thread_subscription(&self, thread_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/notifications/threads/{}/subscription",
+                crate::progenitor_support::encode_path(&thread_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_public_org_events`](crate::activity::Activity::list_public_org_events), a `GET` to `/orgs/{org}/events`.
+    pub fn list_public_org_events(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_repo_events`](crate::activity::Activity::list_repo_events), a `GET` to `/repos/{owner}/{repo}/events`.
+    pub fn list_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_repo_notifications_for_authenticated_user`](crate::activity::Activity::list_repo_notifications_for_authenticated_user), a `GET` to `/repos/{owner}/{repo}/notifications`.
+    pub fn list_repo_notifications_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Thread>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::mark_repo_notifications_as_read`](crate::activity::Activity::mark_repo_notifications_as_read), a `PUT` to `/repos/{owner}/{repo}/notifications`.
+    pub fn mark_repo_notifications_as_read(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullsUpdateBranchResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_stargazers_for_repo`](crate::activity::Activity::list_stargazers_for_repo), a `GET` to `/repos/{owner}/{repo}/stargazers`.
+    pub fn list_stargazers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActivityListStargazersRepoResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stargazers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_watchers_for_repo`](crate::activity::Activity::list_watchers_for_repo), a `GET` to `/repos/{owner}/{repo}/subscribers`.
+    pub fn list_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::get_repo_subscription`](crate::activity::Activity::get_repo_subscription), a `GET` to `/repos/{owner}/{repo}/subscription`.
+    pub fn get_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositorySubscription> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/subscription",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::set_repo_subscription`](crate::activity::Activity::set_repo_subscription), a `PUT` to `/repos/{owner}/{repo}/subscription`.
+    pub fn set_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositorySubscription> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/subscription",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::delete_repo_subscription`](crate::activity::Activity::delete_repo_subscription), a `DELETE` to `/repos/{owner}/{repo}/subscription`.
+    pub fn delete_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/subscription",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_repos_starred_by_authenticated_user`](crate::activity::Activity::list_repos_starred_by_authenticated_user), a `GET` to `/user/starred`.
+    pub fn list_repos_starred_by_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Repository>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/starred".to_string())
+    }
+
+    /// Mock [`Activity::check_repo_is_starred_by_authenticated_user`](crate::activity::Activity::check_repo_is_starred_by_authenticated_user), a `GET` to `/user/starred/{owner}/{repo}`.
+    pub fn check_repo_is_starred_by_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/starred/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::star_repo_for_authenticated_user`](crate::activity::Activity::star_repo_for_authenticated_user), a `PUT` to `/user/starred/{owner}/{repo}`.
+    pub fn star_repo_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/user/starred/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::unstar_repo_for_authenticated_user`](crate::activity::Activity::unstar_repo_for_authenticated_user), a `DELETE` to `/user/starred/{owner}/{repo}`.
+    pub fn unstar_repo_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/starred/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_watched_repos_for_authenticated_user`](crate::activity::Activity::list_watched_repos_for_authenticated_user), a `GET` to `/user/subscriptions`.
+    pub fn list_watched_repos_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/subscriptions".to_string())
+    }
+
+    /// Mock [`Activity::list_events_for_authenticated_user`](crate::activity::Activity::list_events_for_authenticated_user), a `GET` to `/users/{username}/events`.
+    pub fn list_events_for_authenticated_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_org_events_for_authenticated_user`](crate::activity::Activity::list_org_events_for_authenticated_user), a `GET` to `/users/{username}/events/orgs/{org}`.
+    pub fn list_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_public_events_for_user`](crate::activity::Activity::list_public_events_for_user), a `GET` to `/users/{username}/events/public`.
+    pub fn list_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_received_events_for_user`](crate::activity::Activity::list_received_events_for_user), a `GET` to `/users/{username}/received_events`.
+    pub fn list_received_events_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_received_public_events_for_user`](crate::activity::Activity::list_received_public_events_for_user), a `GET` to `/users/{username}/received_events/public`.
+    pub fn list_received_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Event>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_repos_starred_by_user`](crate::activity::Activity::list_repos_starred_by_user), a `GET` to `/users/{username}/starred`.
+    pub fn list_repos_starred_by_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActivityListReposStarredByUserResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/starred",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Activity::list_repos_watched_by_user`](crate::activity::Activity::list_repos_watched_by_user), a `GET` to `/users/{username}/subscriptions`.
+    pub fn list_repos_watched_by_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Apps<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Apps<'a> {
+    /// Mock [`Apps::get_authenticated`](crate::apps::Apps::get_authenticated), a `GET` to `/app`.
+    pub fn get_authenticated(&self) -> crate::mock::MockOperation<'a, crate::types::GitHubApp> {
+        self.mock
+            .operation(reqwest::Method::GET, "/app".to_string())
+    }
+
+    /// Mock [`Apps::create_from_manifest`](crate::apps::Apps::create_from_manifest), a `POST` to `/app-manifests/{code}/conversions`.
+    pub fn create_from_manifest(
+        &self,
+        code: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsCreateFromManifestResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/app-manifests/{}/conversions",
+                crate::progenitor_support::encode_path(code)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::get_webhook_config_for_app`](crate::apps::Apps::get_webhook_config_for_app), a `GET` to `/app/hook/config`.
+    pub fn get_webhook_config_for_app(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock
+            .operation(reqwest::Method::GET, "/app/hook/config".to_string())
+    }
+
+    /// Mock [`Apps::update_webhook_config_for_app`](crate::apps::Apps::update_webhook_config_for_app), a `PATCH` to `/app/hook/config`.
+    pub fn update_webhook_config_for_app(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/app/hook/config".to_string())
+    }
+
+    /// Mock [`Apps::list_webhook_deliveries`](crate::apps::Apps::list_webhook_deliveries), a `GET` to `/app/hook/deliveries`.
+    pub fn list_webhook_deliveries(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::HookDeliveryItem>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/app/hook/deliveries".to_string())
+    }
+
+    /// Mock [`Apps::get_webhook_delivery`](crate::apps::Apps::get_webhook_delivery), a `GET` to `/app/hook/deliveries/{delivery_id}`.
+    pub fn get_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::HookDelivery> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/app/hook/deliveries/{}",
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::redeliver_webhook_delivery`](crate::apps::Apps::redeliver_webhook_delivery), a `POST` to `/app/hook/deliveries/{delivery_id}/attempts`.
+    pub fn redeliver_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/app/hook/deliveries/{}/attempts",
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::list_installations`](crate::apps::Apps::list_installations), a `GET` to `/app/installations`.
+    pub fn list_installations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Installation>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/app/installations".to_string())
+    }
+
+    /// Mock [`Apps::get_installation`](crate::apps::Apps::get_installation), a `GET` to `/app/installations/{installation_id}`.
+    pub fn get_installation(
+        &self,
+        installation_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Installation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/app/installations/{}",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::delete_installation`](crate::apps::Apps::delete_installation), a `DELETE` to `/app/installations/{installation_id}`.
+    pub fn delete_installation(&self, installation_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/app/installations/{}",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::create_installation_access_token`](crate::apps::Apps::create_installation_access_token), a `POST` to `/app/installations/{installation_id}/access_tokens`.
+    pub fn create_installation_access_token(
+        &self,
+        installation_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::InstallationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/app/installations/{}/access_tokens",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::suspend_installation`](crate::apps::Apps::suspend_installation), a `PUT` to `/app/installations/{installation_id}/suspended`.
+    pub fn suspend_installation(&self, installation_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/app/installations/{}/suspended",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::unsuspend_installation`](crate::apps::Apps::unsuspend_installation), a `DELETE` to `/app/installations/{installation_id}/suspended`.
+    pub fn unsuspend_installation(
+        &self,
+        installation_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/app/installations/{}/suspended",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::delete_authorization`](crate::apps::Apps::delete_authorization), a `DELETE` to `/applications/{client_id}/grant`.
+    pub fn delete_authorization(&self, client_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/applications/{}/grant",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::revoke_grant_for_application`](crate::apps::Apps::revoke_grant_for_application), a `DELETE` to `/applications/{client_id}/grants/{access_token}`.
+    pub fn revoke_grant_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/applications/{}/grants/{}",
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(access_token)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::check_token`](crate::apps::Apps::check_token), a `POST` to `/applications/{client_id}/token`.
+    pub fn check_token(
+        &self,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/applications/{}/token",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::delete_token`](crate::apps::Apps::delete_token), a `DELETE` to `/applications/{client_id}/token`.
+    pub fn delete_token(&self, client_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/applications/{}/token",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::reset_token`](crate::apps::Apps::reset_token), a `PATCH` to `/applications/{client_id}/token`.
+    pub fn reset_token(
+        &self,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/applications/{}/token",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::scope_token`](crate::apps::Apps::scope_token), a `POST` to `/applications/{client_id}/token/scoped`.
+    pub fn scope_token(
+        &self,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/applications/{}/token/scoped",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::check_authorization`](crate::apps::Apps::check_authorization), a `GET` to `/applications/{client_id}/tokens/{access_token}`.
+    pub fn check_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/applications/{}/tokens/{}",
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(access_token)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::reset_authorization`](crate::apps::Apps::reset_authorization), a `POST` to `/applications/{client_id}/tokens/{access_token}`.
+    pub fn reset_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/applications/{}/tokens/{}",
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(access_token)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::revoke_authorization_for_application`](crate::apps::Apps::revoke_authorization_for_application), a `DELETE` to `/applications/{client_id}/tokens/{access_token}`.
+    pub fn revoke_authorization_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/applications/{}/tokens/{}",
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(access_token)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::get_by_slug`](crate::apps::Apps::get_by_slug), a `GET` to `/apps/{app_slug}`.
+    pub fn get_by_slug(
+        &self,
+        app_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitHubApp> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/apps/{}", crate::progenitor_support::encode_path(app_slug)),
+        )
+    }
+
+    /// Mock [`Apps::list_repos_accessible_to_installation`](crate::apps::Apps::list_repos_accessible_to_installation), a `GET` to `/installation/repositories`.
+    pub fn list_repos_accessible_to_installation(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsListInstallationReposResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/installation/repositories".to_string(),
+        )
+    }
+
+    /// Mock [`Apps::revoke_installation_access_token`](crate::apps::Apps::revoke_installation_access_token), a `DELETE` to `/installation/token`.
+    pub fn revoke_installation_access_token(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/installation/token".to_string())
+    }
+
+    /// Mock [`Apps::get_subscription_plan_for_account`](crate::apps::Apps::get_subscription_plan_for_account), a `GET` to `/marketplace_listing/accounts/{account_id}`.
+    pub fn get_subscription_plan_for_account(
+        &self,
+        account_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MarketplacePurchaseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketplace_listing/accounts/{}",
+                crate::progenitor_support::encode_path(&account_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::list_plans`](crate::apps::Apps::list_plans), a `GET` to `/marketplace_listing/plans`.
+    pub fn list_plans(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MarketplaceListingPlan>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketplace_listing/plans".to_string(),
+        )
+    }
+
+    /// Mock [`Apps::list_accounts_for_plan`](crate::apps::Apps::list_accounts_for_plan), a `GET` to `/marketplace_listing/plans/{plan_id}/accounts`.
+    pub fn list_accounts_for_plan(
+        &self,
+        plan_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MarketplacePurchaseData>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketplace_listing/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::get_subscription_plan_for_account_stubbed`](crate::apps::Apps::get_subscription_plan_for_account_stubbed), a `GET` to `/marketplace_listing/stubbed/accounts/{account_id}`.
+    pub fn get_subscription_plan_for_account_stubbed(
+        &self,
+        account_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MarketplacePurchaseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketplace_listing/stubbed/accounts/{}",
+                crate::progenitor_support::encode_path(&account_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::list_plans_stubbed`](crate::apps::Apps::list_plans_stubbed), a `GET` to `/marketplace_listing/stubbed/plans`.
+    pub fn list_plans_stubbed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MarketplaceListingPlan>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketplace_listing/stubbed/plans".to_string(),
+        )
+    }
+
+    /// Mock [`Apps::list_accounts_for_plan_stubbed`](crate::apps::Apps::list_accounts_for_plan_stubbed), a `GET` to `/marketplace_listing/stubbed/plans/{plan_id}/accounts`.
+    pub fn list_accounts_for_plan_stubbed(
+        &self,
+        plan_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MarketplacePurchaseData>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::get_org_installation`](crate::apps::Apps::get_org_installation), a `GET` to `/orgs/{org}/installation`.
+    pub fn get_org_installation(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Installation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/installation",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::create_content_attachment`](crate::apps::Apps::create_content_attachment), a `POST` to `/repos/{owner}/{repo}/content_references/{content_reference_id}/attachments`.
+    pub fn create_content_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        content_reference_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContentReferenceAttachment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/content_references/{}/attachments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&content_reference_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::get_repo_installation`](crate::apps::Apps::get_repo_installation), a `GET` to `/repos/{owner}/{repo}/installation`.
+    pub fn get_repo_installation(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Installation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/installation",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Apps::list_installations_for_authenticated_user`](crate::apps::Apps::list_installations_for_authenticated_user), a `GET` to `/user/installations`.
+    pub fn list_installations_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsListInstallationsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/installations".to_string())
+    }
+
+    /// Mock [`Apps::list_installation_repos_for_authenticated_user`](crate::apps::Apps::list_installation_repos_for_authenticated_user), a `GET` to `/user/installations/{installation_id}/repositories`.
+    pub fn list_installation_repos_for_authenticated_user(
+        &self,
+        installation_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsListInstallationReposResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/installations/{}/repositories",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::add_repo_to_installation`](crate::apps::Apps::add_repo_to_installation), a `PUT` to `/user/installations/{installation_id}/repositories/{repository_id}`.
+    pub fn add_repo_to_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/user/installations/{}/repositories/{}",
+                crate::progenitor_support::encode_path(&installation_id.to_string()),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::remove_repo_from_installation`](crate::apps::Apps::remove_repo_from_installation), a `DELETE` to `/user/installations/{installation_id}/repositories/{repository_id}`.
+    pub fn remove_repo_from_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/installations/{}/repositories/{}",
+                crate::progenitor_support::encode_path(&installation_id.to_string()),
+                crate::progenitor_support::encode_path(&repository_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Apps::list_subscriptions_for_authenticated_user`](crate::apps::Apps::list_subscriptions_for_authenticated_user), a `GET` to `/user/marketplace_purchases`.
+    pub fn list_subscriptions_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserMarketplacePurchase>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/marketplace_purchases".to_string(),
+        )
+    }
+
+    /// Mock [`Apps::list_subscriptions_for_authenticated_user_stubbed`](crate::apps::Apps::list_subscriptions_for_authenticated_user_stubbed), a `GET` to `/user/marketplace_purchases/stubbed`.
+    pub fn list_subscriptions_for_authenticated_user_stubbed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserMarketplacePurchase>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/marketplace_purchases/stubbed".to_string(),
+        )
+    }
+
+    /// Mock [`Apps::get_user_installation`](crate::apps::Apps::get_user_installation), a `GET` to `/users/{username}/installation`.
+    pub fn get_user_installation(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Installation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/installation",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Billing<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Billing<'a> {
+    /// Mock [`Billing::get_github_actions_billing_ghe`](crate::billing::Billing::get_github_actions_billing_ghe), a `GET` to `/enterprises/{enterprise}/settings/billing/actions`.
+    pub fn get_github_actions_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/settings/billing/actions",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_github_packages_billing_ghe`](crate::billing::Billing::get_github_packages_billing_ghe), a `GET` to `/enterprises/{enterprise}/settings/billing/packages`.
+    pub fn get_github_packages_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackagesBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/settings/billing/packages",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_shared_storage_billing_ghe`](crate::billing::Billing::get_shared_storage_billing_ghe), a `GET` to `/enterprises/{enterprise}/settings/billing/shared-storage`.
+    pub fn get_shared_storage_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CombinedBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/settings/billing/shared-storage",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_github_actions_billing_org`](crate::billing::Billing::get_github_actions_billing_org), a `GET` to `/orgs/{org}/settings/billing/actions`.
+    pub fn get_github_actions_billing_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/settings/billing/actions",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_github_packages_billing_org`](crate::billing::Billing::get_github_packages_billing_org), a `GET` to `/orgs/{org}/settings/billing/packages`.
+    pub fn get_github_packages_billing_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackagesBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/settings/billing/packages",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_shared_storage_billing_org`](crate::billing::Billing::get_shared_storage_billing_org), a `GET` to `/orgs/{org}/settings/billing/shared-storage`.
+    pub fn get_shared_storage_billing_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CombinedBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/settings/billing/shared-storage",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_github_actions_billing_user`](crate::billing::Billing::get_github_actions_billing_user), a `GET` to `/users/{username}/settings/billing/actions`.
+    pub fn get_github_actions_billing_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings/billing/actions",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_github_packages_billing_user`](crate::billing::Billing::get_github_packages_billing_user), a `GET` to `/users/{username}/settings/billing/packages`.
+    pub fn get_github_packages_billing_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackagesBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings/billing/packages",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_shared_storage_billing_user`](crate::billing::Billing::get_shared_storage_billing_user), a `GET` to `/users/{username}/settings/billing/shared-storage`.
+    pub fn get_shared_storage_billing_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CombinedBillingUsage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings/billing/shared-storage",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Checks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Checks<'a> {
+    /// Mock [`Checks::create`](crate::checks::Checks::create), a `POST` to `/repos/{owner}/{repo}/check-runs`.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckRun> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/check-runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Checks::get`](crate::checks::Checks::get), a `GET` to `/repos/{owner}/{repo}/check-runs/{check_run_id}`.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckRun> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/check-runs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::update`](crate::checks::Checks::update), a `PATCH` to `/repos/{owner}/{repo}/check-runs/{check_run_id}`.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckRun> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/check-runs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::list_annotations`](crate::checks::Checks::list_annotations), a `GET` to `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations`.
+    pub fn list_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CheckAnnotation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::create_suite`](crate::checks::Checks::create_suite), a `POST` to `/repos/{owner}/{repo}/check-suites`.
+    pub fn create_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckSuiteData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/check-suites",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Checks::set_suites_preferences`](crate::checks::Checks::set_suites_preferences), a `PATCH` to `/repos/{owner}/{repo}/check-suites/preferences`.
+    pub fn set_suites_preferences(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckSuitePreference> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/check-suites/preferences",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Checks::get_suite`](crate::checks::Checks::get_suite), a `GET` to `/repos/{owner}/{repo}/check-suites/{check_suite_id}`.
+    pub fn get_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CheckSuiteData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/check-suites/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_suite_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::list_for_suite`](crate::checks::Checks::list_for_suite), a `GET` to `/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs`.
+    pub fn list_for_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChecksListRefResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/check-suites/{}/check-runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_suite_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::rerequest_suite`](crate::checks::Checks::rerequest_suite), a `POST` to `/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest`.
+    pub fn rerequest_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/check-suites/{}/rerequest",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_suite_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Checks::list_for_ref`](crate::checks::Checks::list_for_ref), a `GET` to `/repos/{owner}/{repo}/commits/{ref}/check-runs`.
+    pub fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChecksListRefResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/check-runs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Checks::list_suites_for_ref`](crate::checks::Checks::list_suites_for_ref), a `GET` to `/repos/{owner}/{repo}/commits/{ref}/check-suites`.
+    pub fn list_suites_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChecksListSuitesRefResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/check-suites",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+}
+
+pub struct CodeScanning<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CodeScanning<'a> {
+    /// Mock [`CodeScanning::list_alerts_for_repo`](crate::code_scanning::CodeScanning::list_alerts_for_repo), a `GET` to `/repos/{owner}/{repo}/code-scanning/alerts`.
+    pub fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CodeScanningAlertItems>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::get_alert`](crate::code_scanning::CodeScanning::get_alert), a `GET` to `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}`.
+    pub fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeScanningAlert> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/alerts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::update_alert`](crate::code_scanning::CodeScanning::update_alert), a `PATCH` to `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}`.
+    pub fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeScanningAlert> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/code-scanning/alerts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::list_alert_instances`](crate::code_scanning::CodeScanning::list_alert_instances), a `GET` to `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances`.
+    pub fn list_alert_instances(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CodeScanningAlertInstance>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::list_recent_analyses`](crate::code_scanning::CodeScanning::list_recent_analyses), a `GET` to `/repos/{owner}/{repo}/code-scanning/analyses`.
+    pub fn list_recent_analyses(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CodeScanningAnalysis>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/analyses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::get_analysis`](crate::code_scanning::CodeScanning::get_analysis), a `GET` to `/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}`.
+    pub fn get_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeScanningAnalysis> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/analyses/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&analysis_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::delete_analysis`](crate::code_scanning::CodeScanning::delete_analysis), a `DELETE` to `/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}`.
+    pub fn delete_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::AnalysisDeletion> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/code-scanning/analyses/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&analysis_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::upload_sarif`](crate::code_scanning::CodeScanning::upload_sarif), a `POST` to `/repos/{owner}/{repo}/code-scanning/sarifs`.
+    pub fn upload_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeScanningSarifsReceipt> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/code-scanning/sarifs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`CodeScanning::get_sarif`](crate::code_scanning::CodeScanning::get_sarif), a `GET` to `/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}`.
+    pub fn get_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        sarif_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeScanningSarifsStatus> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/code-scanning/sarifs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(sarif_id)
+            ),
+        )
+    }
+}
+
+pub struct CodesOfConduct<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CodesOfConduct<'a> {
+    /// Mock [`CodesOfConduct::get_all_codes_of_conduct`](crate::codes_of_conduct::CodesOfConduct::get_all_codes_of_conduct), a `GET` to `/codes_of_conduct`.
+    pub fn get_all_codes_of_conduct(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CodeOfConduct>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/codes_of_conduct".to_string())
+    }
+
+    /// Mock [`CodesOfConduct::get_conduct_code`](crate::codes_of_conduct::CodesOfConduct::get_conduct_code), a `GET` to `/codes_of_conduct/{key}`.
+    pub fn get_conduct_code(
+        &self,
+        key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeOfConduct> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/codes_of_conduct/{}",
+                crate::progenitor_support::encode_path(key)
+            ),
+        )
+    }
+
+    /// Mock [`CodesOfConduct::get_for_repo`](crate::codes_of_conduct::CodesOfConduct::get_for_repo), a `GET` to `/repos/{owner}/{repo}/community/code_of_conduct`.
+    pub fn get_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CodeOfConduct> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/community/code_of_conduct",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+}
+
+pub struct Emojis<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Emojis<'a> {
+    /// Mock [`Emojis::get`](crate::emojis::Emojis::get), a `GET` to `/emojis`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock
+            .operation(reqwest::Method::GET, "/emojis".to_string())
+    }
+}
+
+pub struct EnterpriseAdmin<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EnterpriseAdmin<'a> {
+    /// Mock [`EnterpriseAdmin::get_github_actions_permissions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::get_github_actions_permissions_enterprise), a `GET` to `/enterprises/{enterprise}/actions/permissions`.
+    pub fn get_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsEnterprisePermissions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/permissions",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_github_actions_permissions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::set_github_actions_permissions_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/permissions`.
+    pub fn set_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/permissions",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise), a `GET` to `/enterprises/{enterprise}/actions/permissions/organizations`.
+    pub fn list_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/permissions/organizations",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_selected_organizations_enabled_github_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::set_selected_organizations_enabled_github_actions_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/permissions/organizations`.
+    pub fn set_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/permissions/organizations",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::enable_selected_organization_github_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::enable_selected_organization_github_actions_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/permissions/organizations/{org_id}`.
+    pub fn enable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/permissions/organizations/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&org_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::disable_selected_organization_github_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::disable_selected_organization_github_actions_enterprise), a `DELETE` to `/enterprises/{enterprise}/actions/permissions/organizations/{org_id}`.
+    pub fn disable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/enterprises/{}/actions/permissions/organizations/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&org_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_allowed_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::get_allowed_actions_enterprise), a `GET` to `/enterprises/{enterprise}/actions/permissions/selected-actions`.
+    pub fn get_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SelectedActions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_allowed_actions_enterprise`](crate::enterprise_admin::EnterpriseAdmin::set_allowed_actions_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/permissions/selected-actions`.
+    pub fn set_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/permissions/selected-actions",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runner-groups`.
+    pub fn list_self_hosted_runner_groups_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::create_self_hosted_runner_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::create_self_hosted_runner_group_for_enterprise), a `POST` to `/enterprises/{enterprise}/actions/runner-groups`.
+    pub fn create_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsEnterprise> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/enterprises/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_self_hosted_runner_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::get_self_hosted_runner_group_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}`.
+    pub fn get_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsEnterprise> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::delete_self_hosted_runner_group_from_enterprise`](crate::enterprise_admin::EnterpriseAdmin::delete_self_hosted_runner_group_from_enterprise), a `DELETE` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}`.
+    pub fn delete_self_hosted_runner_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::update_self_hosted_runner_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::update_self_hosted_runner_group_for_enterprise), a `PATCH` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}`.
+    pub fn update_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RunnerGroupsEnterprise> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations`.
+    pub fn list_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_org_access_to_self_hosted_runner_group_in_enterprise`](crate::enterprise_admin::EnterpriseAdmin::set_org_access_to_self_hosted_runner_group_in_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations`.
+    pub fn set_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::add_org_access_to_self_hosted_runner_group_in_enterprise`](crate::enterprise_admin::EnterpriseAdmin::add_org_access_to_self_hosted_runner_group_in_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}`.
+    pub fn add_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&org_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::remove_org_access_to_self_hosted_runner_group_in_enterprise`](crate::enterprise_admin::EnterpriseAdmin::remove_org_access_to_self_hosted_runner_group_in_enterprise), a `DELETE` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}`.
+    pub fn remove_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&org_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners`.
+    pub fn list_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_self_hosted_runners_in_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::set_self_hosted_runners_in_group_for_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners`.
+    pub fn set_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::add_self_hosted_runner_to_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::add_self_hosted_runner_to_group_for_enterprise), a `PUT` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}`.
+    pub fn add_self_hosted_runner_to_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::remove_self_hosted_runner_from_group_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::remove_self_hosted_runner_from_group_for_enterprise), a `DELETE` to `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}`.
+    pub fn remove_self_hosted_runner_from_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_self_hosted_runners_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runners`.
+    pub fn list_self_hosted_runners_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnterpriseAdminListSelfHostedRunnersResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runners",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_runner_applications_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_runner_applications_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runners/downloads`.
+    pub fn list_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::RunnerApplication>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::create_registration_token_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::create_registration_token_for_enterprise), a `POST` to `/enterprises/{enterprise}/actions/runners/registration-token`.
+    pub fn create_registration_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/enterprises/{}/actions/runners/registration-token",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::create_remove_token_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::create_remove_token_for_enterprise), a `POST` to `/enterprises/{enterprise}/actions/runners/remove-token`.
+    pub fn create_remove_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/enterprises/{}/actions/runners/remove-token",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_self_hosted_runner_for_enterprise`](crate::enterprise_admin::EnterpriseAdmin::get_self_hosted_runner_for_enterprise), a `GET` to `/enterprises/{enterprise}/actions/runners/{runner_id}`.
+    pub fn get_self_hosted_runner_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Runner> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::delete_self_hosted_runner_from_enterprise`](crate::enterprise_admin::EnterpriseAdmin::delete_self_hosted_runner_from_enterprise), a `DELETE` to `/enterprises/{enterprise}/actions/runners/{runner_id}`.
+    pub fn delete_self_hosted_runner_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/enterprises/{}/actions/runners/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_audit_log`](crate::enterprise_admin::EnterpriseAdmin::get_audit_log), a `GET` to `/enterprises/{enterprise}/audit-log`.
+    pub fn get_audit_log(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AuditLogEvent>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/enterprises/{}/audit-log",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_provisioned_groups_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_provisioned_groups_enterprise), a `GET` to `/scim/v2/enterprises/{enterprise}/Groups`.
+    pub fn list_provisioned_groups_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimGroupListEnterprise> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/enterprises/{}/Groups",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::provision_and_invite_enterprise_group`](crate::enterprise_admin::EnterpriseAdmin::provision_and_invite_enterprise_group), a `POST` to `/scim/v2/enterprises/{enterprise}/Groups`.
+    pub fn provision_and_invite_enterprise_group(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseGroup> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/scim/v2/enterprises/{}/Groups",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_provisioning_information_for_enterprise_group`](crate::enterprise_admin::EnterpriseAdmin::get_provisioning_information_for_enterprise_group), a `GET` to `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}`.
+    pub fn get_provisioning_information_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseGroup> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_information_for_provisioned_enterprise_group`](crate::enterprise_admin::EnterpriseAdmin::set_information_for_provisioned_enterprise_group), a `PUT` to `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}`.
+    pub fn set_information_for_provisioned_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseGroup> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::delete_scim_group_from_enterprise`](crate::enterprise_admin::EnterpriseAdmin::delete_scim_group_from_enterprise), a `DELETE` to `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}`.
+    pub fn delete_scim_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::update_attribute_for_enterprise_group`](crate::enterprise_admin::EnterpriseAdmin::update_attribute_for_enterprise_group), a `PATCH` to `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}`.
+    pub fn update_attribute_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseGroup> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::list_provisioned_identities_enterprise`](crate::enterprise_admin::EnterpriseAdmin::list_provisioned_identities_enterprise), a `GET` to `/scim/v2/enterprises/{enterprise}/Users`.
+    pub fn list_provisioned_identities_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUserListEnterprise> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/enterprises/{}/Users",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::provision_and_invite_enterprise_user`](crate::enterprise_admin::EnterpriseAdmin::provision_and_invite_enterprise_user), a `POST` to `/scim/v2/enterprises/{enterprise}/Users`.
+    pub fn provision_and_invite_enterprise_user(
+        &self,
+        enterprise: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseUser> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/scim/v2/enterprises/{}/Users",
+                crate::progenitor_support::encode_path(enterprise)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::get_provisioning_information_for_enterprise_user`](crate::enterprise_admin::EnterpriseAdmin::get_provisioning_information_for_enterprise_user), a `GET` to `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}`.
+    pub fn get_provisioning_information_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseUser> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/enterprises/{}/Users/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::set_information_for_provisioned_enterprise_user`](crate::enterprise_admin::EnterpriseAdmin::set_information_for_provisioned_enterprise_user), a `PUT` to `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}`.
+    pub fn set_information_for_provisioned_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseUser> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/scim/v2/enterprises/{}/Users/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::delete_user_from_enterprise`](crate::enterprise_admin::EnterpriseAdmin::delete_user_from_enterprise), a `DELETE` to `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}`.
+    pub fn delete_user_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/scim/v2/enterprises/{}/Users/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`EnterpriseAdmin::update_attribute_for_enterprise_user`](crate::enterprise_admin::EnterpriseAdmin::update_attribute_for_enterprise_user), a `PATCH` to `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}`.
+    pub fn update_attribute_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimEnterpriseUser> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/scim/v2/enterprises/{}/Users/{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+}
+
+pub struct Gists<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Gists<'a> {
+    /// Mock [`Gists::list`](crate::gists::Gists::list), a `GET` to `/gists`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::BaseGist>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gists".to_string())
+    }
+
+    /// Mock [`Gists::create`](crate::gists::Gists::create), a `POST` to `/gists`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::GistSimple> {
+        self.mock
+            .operation(reqwest::Method::POST, "/gists".to_string())
+    }
+
+    /// Mock [`Gists::list_public`](crate::gists::Gists::list_public), a `GET` to `/gists/public`.
+    pub fn list_public(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::BaseGist>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gists/public".to_string())
+    }
+
+    /// Mock [`Gists::list_starred`](crate::gists::Gists::list_starred), a `GET` to `/gists/starred`.
+    pub fn list_starred(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::BaseGist>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gists/starred".to_string())
+    }
+
+    /// Mock [`Gists::get`](crate::gists::Gists::get), a `GET` to `/gists/{gist_id}`.
+    pub fn get(&self, gist_id: &str) -> crate::mock::MockOperation<'a, crate::types::GistSimple> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/gists/{}", crate::progenitor_support::encode_path(gist_id)),
+        )
+    }
+
+    /// Mock [`Gists::delete`](crate::gists::Gists::delete), a `DELETE` to `/gists/{gist_id}`.
+    pub fn delete(&self, gist_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/gists/{}", crate::progenitor_support::encode_path(gist_id)),
+        )
+    }
+
+    /// Mock [`Gists::update`](crate::gists::Gists::update), a `PATCH` to `/gists/{gist_id}`.
+    pub fn update(
+        &self,
+        gist_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GistSimple> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/gists/{}", crate::progenitor_support::encode_path(gist_id)),
+        )
+    }
+
+    /// Mock [`Gists::list_comments`](crate::gists::Gists::list_comments), a `GET` to `/gists/{gist_id}/comments`.
+    pub fn list_comments(
+        &self,
+        gist_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GistComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::create_comment`](crate::gists::Gists::create_comment), a `POST` to `/gists/{gist_id}/comments`.
+    pub fn create_comment(
+        &self,
+        gist_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GistComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::get_comment`](crate::gists::Gists::get_comment), a `GET` to `/gists/{gist_id}/comments/{comment_id}`.
+    pub fn get_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GistComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/comments/{}",
+                crate::progenitor_support::encode_path(gist_id),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Gists::delete_comment`](crate::gists::Gists::delete_comment), a `DELETE` to `/gists/{gist_id}/comments/{comment_id}`.
+    pub fn delete_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/gists/{}/comments/{}",
+                crate::progenitor_support::encode_path(gist_id),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Gists::update_comment`](crate::gists::Gists::update_comment), a `PATCH` to `/gists/{gist_id}/comments/{comment_id}`.
+    pub fn update_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GistComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/gists/{}/comments/{}",
+                crate::progenitor_support::encode_path(gist_id),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Gists::list_commits`](crate::gists::Gists::list_commits), a `GET` to `/gists/{gist_id}/commits`.
+    pub fn list_commits(
+        &self,
+        gist_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GistCommit>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::list_forks`](crate::gists::Gists::list_forks), a `GET` to `/gists/{gist_id}/forks`.
+    pub fn list_forks(
+        &self,
+        gist_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GistSimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::fork`](crate::gists::Gists::fork), a `POST` to `/gists/{gist_id}/forks`.
+    pub fn fork(&self, gist_id: &str) -> crate::mock::MockOperation<'a, crate::types::BaseGist> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::check_is_starred`](crate::gists::Gists::check_is_starred), a `GET` to `/gists/{gist_id}/star`.
+    pub fn check_is_starred(&self, gist_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/star",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::star`](crate::gists::Gists::star), a `PUT` to `/gists/{gist_id}/star`.
+    pub fn star(&self, gist_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/gists/{}/star",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::unstar`](crate::gists::Gists::unstar), a `DELETE` to `/gists/{gist_id}/star`.
+    pub fn unstar(&self, gist_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/gists/{}/star",
+                crate::progenitor_support::encode_path(gist_id)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::get_revision`](crate::gists::Gists::get_revision), a `GET` to `/gists/{gist_id}/{sha}`.
+    pub fn get_revision(
+        &self,
+        gist_id: &str,
+        sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GistSimple> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gists/{}/{}",
+                crate::progenitor_support::encode_path(gist_id),
+                crate::progenitor_support::encode_path(sha)
+            ),
+        )
+    }
+
+    /// Mock [`Gists::list_for_user`](crate::gists::Gists::list_for_user), a `GET` to `/users/{username}/gists`.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BaseGist>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/gists",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Git<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Git<'a> {
+    /// Mock [`Git::create_blob`](crate::git::Git::create_blob), a `POST` to `/repos/{owner}/{repo}/git/blobs`.
+    pub fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Tree> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/git/blobs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Git::get_blob`](crate::git::Git::get_blob), a `GET` to `/repos/{owner}/{repo}/git/blobs/{file_sha}`.
+    pub fn get_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        file_sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Blob> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/blobs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(file_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Git::create_commit`](crate::git::Git::create_commit), a `POST` to `/repos/{owner}/{repo}/git/commits`.
+    pub fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitCommit> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/git/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Git::get_commit`](crate::git::Git::get_commit), a `GET` to `/repos/{owner}/{repo}/git/commits/{commit_sha}`.
+    pub fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitCommit> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/commits/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Git::list_matching_refs`](crate::git::Git::list_matching_refs), a `GET` to `/repos/{owner}/{repo}/git/matching-refs/{ref}`.
+    pub fn list_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GitRef>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Git::get_ref`](crate::git::Git::get_ref), a `GET` to `/repos/{owner}/{repo}/git/ref/{ref}`.
+    pub fn get_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitRef> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/ref/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Git::create_ref`](crate::git::Git::create_ref), a `POST` to `/repos/{owner}/{repo}/git/refs`.
+    pub fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitRef> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/git/refs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Git::delete_ref`](crate::git::Git::delete_ref), a `DELETE` to `/repos/{owner}/{repo}/git/refs/{ref}`.
+    pub fn delete_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/git/refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Git::update_ref`](crate::git::Git::update_ref), a `PATCH` to `/repos/{owner}/{repo}/git/refs/{ref}`.
+    pub fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitRef> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/git/refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Git::create_tag`](crate::git::Git::create_tag), a `POST` to `/repos/{owner}/{repo}/git/tags`.
+    pub fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitTag> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/git/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Git::get_tag`](crate::git::Git::get_tag), a `GET` to `/repos/{owner}/{repo}/git/tags/{tag_sha}`.
+    pub fn get_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitTag> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/tags/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(tag_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Git::create_tree`](crate::git::Git::create_tree), a `POST` to `/repos/{owner}/{repo}/git/trees`.
+    pub fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitTreeData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/git/trees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Git::get_tree`](crate::git::Git::get_tree), a `GET` to `/repos/{owner}/{repo}/git/trees/{tree_sha}`.
+    pub fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitTreeData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/git/trees/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(tree_sha)
+            ),
+        )
+    }
+}
+
+pub struct Gitignore<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Gitignore<'a> {
+    /// Mock [`Gitignore::get_all_templates`](crate::gitignore::Gitignore::get_all_templates), a `GET` to `/gitignore/templates`.
+    pub fn get_all_templates(&self) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/gitignore/templates".to_string())
+    }
+
+    /// Mock [`Gitignore::get_template`](crate::gitignore::Gitignore::get_template), a `GET` to `/gitignore/templates/{name}`.
+    pub fn get_template(
+        &self,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GitignoreTemplate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/gitignore/templates/{}",
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+}
+
+pub struct Interactions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Interactions<'a> {
+    /// Mock [`Interactions::get_restrictions_for_org`](crate::interactions::Interactions::get_restrictions_for_org), a `GET` to `/orgs/{org}/interaction-limits`.
+    pub fn get_restrictions_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionsGetRestrictionsResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/interaction-limits",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::set_restrictions_for_org`](crate::interactions::Interactions::set_restrictions_for_org), a `PUT` to `/orgs/{org}/interaction-limits`.
+    pub fn set_restrictions_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionLimits> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/interaction-limits",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::remove_restrictions_for_org`](crate::interactions::Interactions::remove_restrictions_for_org), a `DELETE` to `/orgs/{org}/interaction-limits`.
+    pub fn remove_restrictions_for_org(&self, org: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/interaction-limits",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::get_restrictions_for_repo`](crate::interactions::Interactions::get_restrictions_for_repo), a `GET` to `/repos/{owner}/{repo}/interaction-limits`.
+    pub fn get_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionsGetRestrictionsResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/interaction-limits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::set_restrictions_for_repo`](crate::interactions::Interactions::set_restrictions_for_repo), a `PUT` to `/repos/{owner}/{repo}/interaction-limits`.
+    pub fn set_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionLimits> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/interaction-limits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::remove_restrictions_for_repo`](crate::interactions::Interactions::remove_restrictions_for_repo), a `DELETE` to `/repos/{owner}/{repo}/interaction-limits`.
+    pub fn remove_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/interaction-limits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Interactions::get_restrictions_for_authenticated_user`](crate::interactions::Interactions::get_restrictions_for_authenticated_user), a `GET` to `/user/interaction-limits`.
+    pub fn get_restrictions_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionsGetRestrictionsResponseAnyOf>
+    {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/interaction-limits".to_string())
+    }
+
+    /// Mock [`Interactions::set_restrictions_for_authenticated_user`](crate::interactions::Interactions::set_restrictions_for_authenticated_user), a `PUT` to `/user/interaction-limits`.
+    pub fn set_restrictions_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::InteractionLimits> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/user/interaction-limits".to_string())
+    }
+
+    /// Mock [`Interactions::remove_restrictions_for_authenticated_user`](crate::interactions::Interactions::remove_restrictions_for_authenticated_user), a `DELETE` to `/user/interaction-limits`.
+    pub fn remove_restrictions_for_authenticated_user(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/user/interaction-limits".to_string(),
+        )
+    }
+}
+
+pub struct Issues<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Issues<'a> {
+    /// Mock [`Issues::list`](crate::issues::Issues::list), a `GET` to `/issues`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Issue>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/issues".to_string())
+    }
+
+    /// Mock [`Issues::list_for_org`](crate::issues::Issues::list_for_org), a `GET` to `/orgs/{org}/issues`.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Issue>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/issues",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_assignees`](crate::issues::Issues::list_assignees), a `GET` to `/repos/{owner}/{repo}/assignees`.
+    pub fn list_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::check_user_can_be_assigned`](crate::issues::Issues::check_user_can_be_assigned), a `GET` to `/repos/{owner}/{repo}/assignees/{assignee}`.
+    pub fn check_user_can_be_assigned(
+        &self,
+        owner: &str,
+        repo: &str,
+        assignee: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/assignees/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(assignee)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_for_repo`](crate::issues::Issues::list_for_repo), a `GET` to `/repos/{owner}/{repo}/issues`.
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IssueSimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::create`](crate::issues::Issues::create), a `POST` to `/repos/{owner}/{repo}/issues`.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Issue> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_comments_for_repo`](crate::issues::Issues::list_comments_for_repo), a `GET` to `/repos/{owner}/{repo}/issues/comments`.
+    pub fn list_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IssueComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::get_comment`](crate::issues::Issues::get_comment), a `GET` to `/repos/{owner}/{repo}/issues/comments/{comment_id}`.
+    pub fn get_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::delete_comment`](crate::issues::Issues::delete_comment), a `DELETE` to `/repos/{owner}/{repo}/issues/comments/{comment_id}`.
+    pub fn delete_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::update_comment`](crate::issues::Issues::update_comment), a `PATCH` to `/repos/{owner}/{repo}/issues/comments/{comment_id}`.
+    pub fn update_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/issues/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_events_for_repo`](crate::issues::Issues::list_events_for_repo), a `GET` to `/repos/{owner}/{repo}/issues/events`.
+    pub fn list_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IssueEvent>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::get_event`](crate::issues::Issues::get_event), a `GET` to `/repos/{owner}/{repo}/issues/events/{event_id}`.
+    pub fn get_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueEvent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/events/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&event_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::get`](crate::issues::Issues::get), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}`.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Issue> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::update`](crate::issues::Issues::update), a `PATCH` to `/repos/{owner}/{repo}/issues/{issue_number}`.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Issue> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/issues/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::add_assignees`](crate::issues::Issues::add_assignees), a `POST` to `/repos/{owner}/{repo}/issues/{issue_number}/assignees`.
+    pub fn add_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueSimple> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::remove_assignees`](crate::issues::Issues::remove_assignees), a `DELETE` to `/repos/{owner}/{repo}/issues/{issue_number}/assignees`.
+    pub fn remove_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueSimple> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_comments`](crate::issues::Issues::list_comments), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}/comments`.
+    pub fn list_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IssueComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::create_comment`](crate::issues::Issues::create_comment), a `POST` to `/repos/{owner}/{repo}/issues/{issue_number}/comments`.
+    pub fn create_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssueComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_events`](crate::issues::Issues::list_events), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}/events`.
+    pub fn list_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IssueEventAnyOf>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_labels_on_issue`](crate::issues::Issues::list_labels_on_issue), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}/labels`.
+    pub fn list_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::set_labels`](crate::issues::Issues::set_labels), a `PUT` to `/repos/{owner}/{repo}/issues/{issue_number}/labels`.
+    pub fn set_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::add_labels`](crate::issues::Issues::add_labels), a `POST` to `/repos/{owner}/{repo}/issues/{issue_number}/labels`.
+    pub fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::remove_all_labels`](crate::issues::Issues::remove_all_labels), a `DELETE` to `/repos/{owner}/{repo}/issues/{issue_number}/labels`.
+    pub fn remove_all_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::remove_label`](crate::issues::Issues::remove_label), a `DELETE` to `/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}`.
+    pub fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/{}/labels/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::lock`](crate::issues::Issues::lock), a `PUT` to `/repos/{owner}/{repo}/issues/{issue_number}/lock`.
+    pub fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/issues/{}/lock",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::unlock`](crate::issues::Issues::unlock), a `DELETE` to `/repos/{owner}/{repo}/issues/{issue_number}/lock`.
+    pub fn unlock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/{}/lock",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_events_for_timeline`](crate::issues::Issues::list_events_for_timeline), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}/timeline`.
+    pub fn list_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Data>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}/timeline",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_labels_for_repo`](crate::issues::Issues::list_labels_for_repo), a `GET` to `/repos/{owner}/{repo}/labels`.
+    pub fn list_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::create_label`](crate::issues::Issues::create_label), a `POST` to `/repos/{owner}/{repo}/labels`.
+    pub fn create_label(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Label> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::get_label`](crate::issues::Issues::get_label), a `GET` to `/repos/{owner}/{repo}/labels/{name}`.
+    pub fn get_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Label> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/labels/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::delete_label`](crate::issues::Issues::delete_label), a `DELETE` to `/repos/{owner}/{repo}/labels/{name}`.
+    pub fn delete_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/labels/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::update_label`](crate::issues::Issues::update_label), a `PATCH` to `/repos/{owner}/{repo}/labels/{name}`.
+    pub fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Label> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/labels/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_milestones`](crate::issues::Issues::list_milestones), a `GET` to `/repos/{owner}/{repo}/milestones`.
+    pub fn list_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Milestone>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/milestones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::create_milestone`](crate::issues::Issues::create_milestone), a `POST` to `/repos/{owner}/{repo}/milestones`.
+    pub fn create_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Milestone> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/milestones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Issues::get_milestone`](crate::issues::Issues::get_milestone), a `GET` to `/repos/{owner}/{repo}/milestones/{milestone_number}`.
+    pub fn get_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Milestone> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/milestones/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::delete_milestone`](crate::issues::Issues::delete_milestone), a `DELETE` to `/repos/{owner}/{repo}/milestones/{milestone_number}`.
+    pub fn delete_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/milestones/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::update_milestone`](crate::issues::Issues::update_milestone), a `PATCH` to `/repos/{owner}/{repo}/milestones/{milestone_number}`.
+    pub fn update_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Milestone> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/milestones/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_labels_for_milestone`](crate::issues::Issues::list_labels_for_milestone), a `GET` to `/repos/{owner}/{repo}/milestones/{milestone_number}/labels`.
+    pub fn list_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Label>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/milestones/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Issues::list_for_authenticated_user`](crate::issues::Issues::list_for_authenticated_user), a `GET` to `/user/issues`.
+    pub fn list_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Issue>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/issues".to_string())
+    }
+}
+
+pub struct Licenses<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Licenses<'a> {
+    /// Mock [`Licenses::get_all_commonly_used`](crate::licenses::Licenses::get_all_commonly_used), a `GET` to `/licenses`.
+    pub fn get_all_commonly_used(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::LicenseSimple>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/licenses".to_string())
+    }
+
+    /// Mock [`Licenses::get`](crate::licenses::Licenses::get), a `GET` to `/licenses/{license}`.
+    pub fn get(&self, license: &str) -> crate::mock::MockOperation<'a, crate::types::LicenseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/licenses/{}",
+                crate::progenitor_support::encode_path(license)
+            ),
+        )
+    }
+
+    /// Mock [`Licenses::get_for_repo`](crate::licenses::Licenses::get_for_repo), a `GET` to `/repos/{owner}/{repo}/license`.
+    pub fn get_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LicenseContent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/license",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+}
+
+pub struct Markdown<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Markdown<'a> {
+    /// Mock [`Markdown::render`](crate::markdown::Markdown::render), a `POST` to `/markdown`.
+    pub fn render(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock
+            .operation(reqwest::Method::POST, "/markdown".to_string())
+    }
+
+    /// Mock [`Markdown::render_raw`](crate::markdown::Markdown::render_raw), a `POST` to `/markdown/raw`.
+    pub fn render_raw(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock
+            .operation(reqwest::Method::POST, "/markdown/raw".to_string())
+    }
+}
+
+pub struct Meta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Meta<'a> {
+    /// Mock [`Meta::root`](crate::meta::Meta::root), a `GET` to `/`.
+    pub fn root(&self) -> crate::mock::MockOperation<'a, crate::types::MetaRootResponse> {
+        self.mock.operation(reqwest::Method::GET, "/".to_string())
+    }
+
+    /// Mock [`Meta::get`](crate::meta::Meta::get), a `GET` to `/meta`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::ApiOverview> {
+        self.mock
+            .operation(reqwest::Method::GET, "/meta".to_string())
+    }
+
+    /// Mock [`Meta::get_octocat`](crate::meta::Meta::get_octocat), a `GET` to `/octocat`.
+    pub fn get_octocat(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock
+            .operation(reqwest::Method::GET, "/octocat".to_string())
+    }
+
+    /// Mock [`Meta::get_zen`](crate::meta::Meta::get_zen), a `GET` to `/zen`.
+    pub fn get_zen(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock
+            .operation(reqwest::Method::GET, "/zen".to_string())
+    }
+}
+
+pub struct Migrations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Migrations<'a> {
+    /// Mock [`Migrations::list_for_org`](crate::migrations::Migrations::list_for_org), a `GET` to `/orgs/{org}/migrations`.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Migration>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::start_for_org`](crate::migrations::Migrations::start_for_org), a `POST` to `/orgs/{org}/migrations`.
+    pub fn start_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Migration> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::get_status_for_org`](crate::migrations::Migrations::get_status_for_org), a `GET` to `/orgs/{org}/migrations/{migration_id}`.
+    pub fn get_status_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Migration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/migrations/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::download_archive_for_org`](crate::migrations::Migrations::download_archive_for_org), a `GET` to `/orgs/{org}/migrations/{migration_id}/archive`.
+    pub fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/migrations/{}/archive",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::delete_archive_for_org`](crate::migrations::Migrations::delete_archive_for_org), a `DELETE` to `/orgs/{org}/migrations/{migration_id}/archive`.
+    pub fn delete_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/migrations/{}/archive",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::unlock_repo_for_org`](crate::migrations::Migrations::unlock_repo_for_org), a `DELETE` to `/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock`.
+    pub fn unlock_repo_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/migrations/{}/repos/{}/lock",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                crate::progenitor_support::encode_path(repo_name)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::list_repos_for_org`](crate::migrations::Migrations::list_repos_for_org), a `GET` to `/orgs/{org}/migrations/{migration_id}/repositories`.
+    pub fn list_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::get_import_status`](crate::migrations::Migrations::get_import_status), a `GET` to `/repos/{owner}/{repo}/import`.
+    pub fn get_import_status(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Import> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/import",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::start_import`](crate::migrations::Migrations::start_import), a `PUT` to `/repos/{owner}/{repo}/import`.
+    pub fn start_import(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Import> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/import",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::cancel_import`](crate::migrations::Migrations::cancel_import), a `DELETE` to `/repos/{owner}/{repo}/import`.
+    pub fn cancel_import(&self, owner: &str, repo: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/import",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::update_import`](crate::migrations::Migrations::update_import), a `PATCH` to `/repos/{owner}/{repo}/import`.
+    pub fn update_import(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Import> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/import",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::get_commit_authors`](crate::migrations::Migrations::get_commit_authors), a `GET` to `/repos/{owner}/{repo}/import/authors`.
+    pub fn get_commit_authors(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PorterAuthor>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/import/authors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::map_commit_author`](crate::migrations::Migrations::map_commit_author), a `PATCH` to `/repos/{owner}/{repo}/import/authors/{author_id}`.
+    pub fn map_commit_author(
+        &self,
+        owner: &str,
+        repo: &str,
+        author_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PorterAuthor> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/import/authors/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&author_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::get_large_files`](crate::migrations::Migrations::get_large_files), a `GET` to `/repos/{owner}/{repo}/import/large_files`.
+    pub fn get_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PorterLargeFile>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/import/large_files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::set_lfs_preference`](crate::migrations::Migrations::set_lfs_preference), a `PATCH` to `/repos/{owner}/{repo}/import/lfs`.
+    pub fn set_lfs_preference(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Import> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/import/lfs",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::list_for_authenticated_user`](crate::migrations::Migrations::list_for_authenticated_user), a `GET` to `/user/migrations`.
+    pub fn list_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Migration>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/migrations".to_string())
+    }
+
+    /// Mock [`Migrations::start_for_authenticated_user`](crate::migrations::Migrations::start_for_authenticated_user), a `POST` to `/user/migrations`.
+    pub fn start_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Migration> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/migrations".to_string())
+    }
+
+    /// Mock [`Migrations::get_status_for_authenticated_user`](crate::migrations::Migrations::get_status_for_authenticated_user), a `GET` to `/user/migrations/{migration_id}`.
+    pub fn get_status_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Migration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/migrations/{}",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::get_archive_for_authenticated_user`](crate::migrations::Migrations::get_archive_for_authenticated_user), a `GET` to `/user/migrations/{migration_id}/archive`.
+    pub fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/migrations/{}/archive",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::delete_archive_for_authenticated_user`](crate::migrations::Migrations::delete_archive_for_authenticated_user), a `DELETE` to `/user/migrations/{migration_id}/archive`.
+    pub fn delete_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/migrations/{}/archive",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::unlock_repo_for_authenticated_user`](crate::migrations::Migrations::unlock_repo_for_authenticated_user), a `DELETE` to `/user/migrations/{migration_id}/repos/{repo_name}/lock`.
+    pub fn unlock_repo_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/migrations/{}/repos/{}/lock",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                crate::progenitor_support::encode_path(repo_name)
+            ),
+        )
+    }
+
+    /// Mock [`Migrations::list_repos_for_user`](crate::migrations::Migrations::list_repos_for_user), a `GET` to `/user/migrations/{migration_id}/repositories`.
+    pub fn list_repos_for_user(
+        &self,
+        migration_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct OauthAuthorizations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> OauthAuthorizations<'a> {
+    /// Mock [`OauthAuthorizations::list_grants`](crate::oauth_authorizations::OauthAuthorizations::list_grants), a `GET` to `/applications/grants`.
+    pub fn list_grants(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ApplicationGrant>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/applications/grants".to_string())
+    }
+
+    /// Mock [`OauthAuthorizations::get_grant`](crate::oauth_authorizations::OauthAuthorizations::get_grant), a `GET` to `/applications/grants/{grant_id}`.
+    pub fn get_grant(
+        &self,
+        grant_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplicationGrant> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/applications/grants/{}",
+                crate::progenitor_support::encode_path(&grant_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::delete_grant`](crate::oauth_authorizations::OauthAuthorizations::delete_grant), a `DELETE` to `/applications/grants/{grant_id}`.
+    pub fn delete_grant(&self, grant_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/applications/grants/{}",
+                crate::progenitor_support::encode_path(&grant_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::list_authorizations`](crate::oauth_authorizations::OauthAuthorizations::list_authorizations), a `GET` to `/authorizations`.
+    pub fn list_authorizations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Authorization>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/authorizations".to_string())
+    }
+
+    /// Mock [`OauthAuthorizations::create_authorization`](crate::oauth_authorizations::OauthAuthorizations::create_authorization), a `POST` to `/authorizations`.
+    pub fn create_authorization(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock
+            .operation(reqwest::Method::POST, "/authorizations".to_string())
+    }
+
+    /// Mock [`OauthAuthorizations::get_or_create_authorization_for_app`](crate::oauth_authorizations::OauthAuthorizations::get_or_create_authorization_for_app), a `PUT` to `/authorizations/clients/{client_id}`.
+    pub fn get_or_create_authorization_for_app(
+        &self,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/authorizations/clients/{}",
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::get_or_create_authorization_for_app_and_fingerprint`](crate::oauth_authorizations::OauthAuthorizations::get_or_create_authorization_for_app_and_fingerprint), a `PUT` to `/authorizations/clients/{client_id}/{fingerprint}`.
+    pub fn get_or_create_authorization_for_app_and_fingerprint(
+        &self,
+        client_id: &str,
+        fingerprint: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/authorizations/clients/{}/{}",
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(fingerprint)
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::get_authorization`](crate::oauth_authorizations::OauthAuthorizations::get_authorization), a `GET` to `/authorizations/{authorization_id}`.
+    pub fn get_authorization(
+        &self,
+        authorization_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/authorizations/{}",
+                crate::progenitor_support::encode_path(&authorization_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::delete_authorization`](crate::oauth_authorizations::OauthAuthorizations::delete_authorization), a `DELETE` to `/authorizations/{authorization_id}`.
+    pub fn delete_authorization(
+        &self,
+        authorization_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/authorizations/{}",
+                crate::progenitor_support::encode_path(&authorization_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`OauthAuthorizations::update_authorization`](crate::oauth_authorizations::OauthAuthorizations::update_authorization), a `PATCH` to `/authorizations/{authorization_id}`.
+    pub fn update_authorization(
+        &self,
+        authorization_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Authorization> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/authorizations/{}",
+                crate::progenitor_support::encode_path(&authorization_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Orgs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Orgs<'a> {
+    /// Mock [`Orgs::list`](crate::orgs::Orgs::list), a `GET` to `/organizations`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationSimple>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/organizations".to_string())
+    }
+
+    /// Mock [`Orgs::get`](crate::orgs::Orgs::get), a `GET` to `/orgs/{org}`.
+    pub fn get(&self, org: &str) -> crate::mock::MockOperation<'a, crate::types::OrganizationFull> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/orgs/{}", crate::progenitor_support::encode_path(org)),
+        )
+    }
+
+    /// Mock [`Orgs::update`](crate::orgs::Orgs::update), a `PATCH` to `/orgs/{org}`.
+    pub fn update(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrganizationFull> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/orgs/{}", crate::progenitor_support::encode_path(org)),
+        )
+    }
+
+    /// Mock [`Orgs::get_audit_log`](crate::orgs::Orgs::get_audit_log), a `GET` to `/orgs/{org}/audit-log`.
+    pub fn get_audit_log(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AuditLogEvent>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/audit-log",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_blocked_users`](crate::orgs::Orgs::list_blocked_users), a `GET` to `/orgs/{org}/blocks`.
+    pub fn list_blocked_users(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/blocks",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::check_blocked_user`](crate::orgs::Orgs::check_blocked_user), a `GET` to `/orgs/{org}/blocks/{username}`.
+    pub fn check_blocked_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/blocks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::block_user`](crate::orgs::Orgs::block_user), a `PUT` to `/orgs/{org}/blocks/{username}`.
+    pub fn block_user(&self, org: &str, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/blocks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::unblock_user`](crate::orgs::Orgs::unblock_user), a `DELETE` to `/orgs/{org}/blocks/{username}`.
+    pub fn unblock_user(&self, org: &str, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/blocks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_saml_sso_authorizations`](crate::orgs::Orgs::list_saml_sso_authorizations), a `GET` to `/orgs/{org}/credential-authorizations`.
+    pub fn list_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CredentialAuthorization>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/credential-authorizations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::remove_saml_sso_authorization`](crate::orgs::Orgs::remove_saml_sso_authorization), a `DELETE` to `/orgs/{org}/credential-authorizations/{credential_id}`.
+    pub fn remove_saml_sso_authorization(
+        &self,
+        org: &str,
+        credential_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/credential-authorizations/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&credential_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_failed_invitations`](crate::orgs::Orgs::list_failed_invitations), a `GET` to `/orgs/{org}/failed_invitations`.
+    pub fn list_failed_invitations(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_webhooks`](crate::orgs::Orgs::list_webhooks), a `GET` to `/orgs/{org}/hooks`.
+    pub fn list_webhooks(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrgHook>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::create_webhook`](crate::orgs::Orgs::create_webhook), a `POST` to `/orgs/{org}/hooks`.
+    pub fn create_webhook(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::get_webhook`](crate::orgs::Orgs::get_webhook), a `GET` to `/orgs/{org}/hooks/{hook_id}`.
+    pub fn get_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgHook> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/hooks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::delete_webhook`](crate::orgs::Orgs::delete_webhook), a `DELETE` to `/orgs/{org}/hooks/{hook_id}`.
+    pub fn delete_webhook(&self, org: &str, hook_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/hooks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::update_webhook`](crate::orgs::Orgs::update_webhook), a `PATCH` to `/orgs/{org}/hooks/{hook_id}`.
+    pub fn update_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgHook> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/hooks/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::get_webhook_config_for_org`](crate::orgs::Orgs::get_webhook_config_for_org), a `GET` to `/orgs/{org}/hooks/{hook_id}/config`.
+    pub fn get_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/hooks/{}/config",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::update_webhook_config_for_org`](crate::orgs::Orgs::update_webhook_config_for_org), a `PATCH` to `/orgs/{org}/hooks/{hook_id}/config`.
+    pub fn update_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/hooks/{}/config",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_webhook_deliveries`](crate::orgs::Orgs::list_webhook_deliveries), a `GET` to `/orgs/{org}/hooks/{hook_id}/deliveries`.
+    pub fn list_webhook_deliveries(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::HookDeliveryItem>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::get_webhook_delivery`](crate::orgs::Orgs::get_webhook_delivery), a `GET` to `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}`.
+    pub fn get_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::HookDelivery> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/hooks/{}/deliveries/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::redeliver_webhook_delivery`](crate::orgs::Orgs::redeliver_webhook_delivery), a `POST` to `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts`.
+    pub fn redeliver_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/hooks/{}/deliveries/{}/attempts",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::ping_webhook`](crate::orgs::Orgs::ping_webhook), a `POST` to `/orgs/{org}/hooks/{hook_id}/pings`.
+    pub fn ping_webhook(&self, org: &str, hook_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/hooks/{}/pings",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_app_installations`](crate::orgs::Orgs::list_app_installations), a `GET` to `/orgs/{org}/installations`.
+    pub fn list_app_installations(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsListInstallationsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/installations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_pending_invitations`](crate::orgs::Orgs::list_pending_invitations), a `GET` to `/orgs/{org}/invitations`.
+    pub fn list_pending_invitations(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::create_invitation`](crate::orgs::Orgs::create_invitation), a `POST` to `/orgs/{org}/invitations`.
+    pub fn create_invitation(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrganizationInvitation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::cancel_invitation`](crate::orgs::Orgs::cancel_invitation), a `DELETE` to `/orgs/{org}/invitations/{invitation_id}`.
+    pub fn cancel_invitation(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/invitations/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_invitation_teams`](crate::orgs::Orgs::list_invitation_teams), a `GET` to `/orgs/{org}/invitations/{invitation_id}/teams`.
+    pub fn list_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_members`](crate::orgs::Orgs::list_members), a `GET` to `/orgs/{org}/members`.
+    pub fn list_members(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/members",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::check_membership_for_user`](crate::orgs::Orgs::check_membership_for_user), a `GET` to `/orgs/{org}/members/{username}`.
+    pub fn check_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/members/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::remove_member`](crate::orgs::Orgs::remove_member), a `DELETE` to `/orgs/{org}/members/{username}`.
+    pub fn remove_member(&self, org: &str, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/members/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::get_membership_for_user`](crate::orgs::Orgs::get_membership_for_user), a `GET` to `/orgs/{org}/memberships/{username}`.
+    pub fn get_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgMembership> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::set_membership_for_user`](crate::orgs::Orgs::set_membership_for_user), a `PUT` to `/orgs/{org}/memberships/{username}`.
+    pub fn set_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgMembership> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::remove_membership_for_user`](crate::orgs::Orgs::remove_membership_for_user), a `DELETE` to `/orgs/{org}/memberships/{username}`.
+    pub fn remove_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_outside_collaborators`](crate::orgs::Orgs::list_outside_collaborators), a `GET` to `/orgs/{org}/outside_collaborators`.
+    pub fn list_outside_collaborators(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/outside_collaborators",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::convert_member_to_outside_collaborator`](crate::orgs::Orgs::convert_member_to_outside_collaborator), a `PUT` to `/orgs/{org}/outside_collaborators/{username}`.
+    pub fn convert_member_to_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/outside_collaborators/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::remove_outside_collaborator`](crate::orgs::Orgs::remove_outside_collaborator), a `DELETE` to `/orgs/{org}/outside_collaborators/{username}`.
+    pub fn remove_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/outside_collaborators/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_public_members`](crate::orgs::Orgs::list_public_members), a `GET` to `/orgs/{org}/public_members`.
+    pub fn list_public_members(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::check_public_membership_for_user`](crate::orgs::Orgs::check_public_membership_for_user), a `GET` to `/orgs/{org}/public_members/{username}`.
+    pub fn check_public_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/public_members/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::set_public_membership_for_authenticated_user`](crate::orgs::Orgs::set_public_membership_for_authenticated_user), a `PUT` to `/orgs/{org}/public_members/{username}`.
+    pub fn set_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/public_members/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::remove_public_membership_for_authenticated_user`](crate::orgs::Orgs::remove_public_membership_for_authenticated_user), a `DELETE` to `/orgs/{org}/public_members/{username}`.
+    pub fn remove_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/public_members/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_memberships_for_authenticated_user`](crate::orgs::Orgs::list_memberships_for_authenticated_user), a `GET` to `/user/memberships/orgs`.
+    pub fn list_memberships_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrgMembership>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/memberships/orgs".to_string())
+    }
+
+    /// Mock [`Orgs::get_membership_for_authenticated_user`](crate::orgs::Orgs::get_membership_for_authenticated_user), a `GET` to `/user/memberships/orgs/{org}`.
+    pub fn get_membership_for_authenticated_user(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgMembership> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/memberships/orgs/{}",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::update_membership_for_authenticated_user`](crate::orgs::Orgs::update_membership_for_authenticated_user), a `PATCH` to `/user/memberships/orgs/{org}`.
+    pub fn update_membership_for_authenticated_user(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgMembership> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/user/memberships/orgs/{}",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Orgs::list_for_authenticated_user`](crate::orgs::Orgs::list_for_authenticated_user), a `GET` to `/user/orgs`.
+    pub fn list_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationSimple>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/orgs".to_string())
+    }
+
+    /// Mock [`Orgs::list_for_user`](crate::orgs::Orgs::list_for_user), a `GET` to `/users/{username}/orgs`.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationSimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Packages<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Packages<'a> {
+    /// Mock [`Packages::get_package_for_organization`](crate::packages::Packages::get_package_for_organization), a `GET` to `/orgs/{org}/packages/{package_type}/{package_name}`.
+    pub fn get_package_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Package> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/packages/{}/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::delete_package_for_org`](crate::packages::Packages::delete_package_for_org), a `DELETE` to `/orgs/{org}/packages/{package_type}/{package_name}`.
+    pub fn delete_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/packages/{}/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::restore_package_for_org`](crate::packages::Packages::restore_package_for_org), a `POST` to `/orgs/{org}/packages/{package_type}/{package_name}/restore`.
+    pub fn restore_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_all_package_versions_for_package_owned_by_org`](crate::packages::Packages::get_all_package_versions_for_package_owned_by_org), a `GET` to `/orgs/{org}/packages/{package_type}/{package_name}/versions`.
+    pub fn get_all_package_versions_for_package_owned_by_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PackageVersion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_package_version_for_organization`](crate::packages::Packages::get_package_version_for_organization), a `GET` to `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}`.
+    pub fn get_package_version_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackageVersion> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/packages/{}/{}/versions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::delete_package_version_for_org`](crate::packages::Packages::delete_package_version_for_org), a `DELETE` to `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}`.
+    pub fn delete_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/packages/{}/{}/versions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::restore_package_version_for_org`](crate::packages::Packages::restore_package_version_for_org), a `POST` to `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore`.
+    pub fn restore_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/packages/{}/{}/versions/{}/restore",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_package_for_authenticated_user`](crate::packages::Packages::get_package_for_authenticated_user), a `GET` to `/user/packages/{package_type}/{package_name}`.
+    pub fn get_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Package> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/packages/{}/{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::delete_package_for_authenticated_user`](crate::packages::Packages::delete_package_for_authenticated_user), a `DELETE` to `/user/packages/{package_type}/{package_name}`.
+    pub fn delete_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/packages/{}/{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::restore_package_for_authenticated_user`](crate::packages::Packages::restore_package_for_authenticated_user), a `POST` to `/user/packages/{package_type}/{package_name}/restore`.
+    pub fn restore_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/user/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user`](crate::packages::Packages::get_all_package_versions_for_package_owned_by_authenticated_user), a `GET` to `/user/packages/{package_type}/{package_name}/versions`.
+    pub fn get_all_package_versions_for_package_owned_by_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PackageVersion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_package_version_for_authenticated_user`](crate::packages::Packages::get_package_version_for_authenticated_user), a `GET` to `/user/packages/{package_type}/{package_name}/versions/{package_version_id}`.
+    pub fn get_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackageVersion> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/packages/{}/{}/versions/{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::delete_package_version_for_authenticated_user`](crate::packages::Packages::delete_package_version_for_authenticated_user), a `DELETE` to `/user/packages/{package_type}/{package_name}/versions/{package_version_id}`.
+    pub fn delete_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/packages/{}/{}/versions/{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::restore_package_version_for_authenticated_user`](crate::packages::Packages::restore_package_version_for_authenticated_user), a `POST` to `/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore`.
+    pub fn restore_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/user/packages/{}/{}/versions/{}/restore",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_package_for_user`](crate::packages::Packages::get_package_for_user), a `GET` to `/users/{username}/packages/{package_type}/{package_name}`.
+    pub fn get_package_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Package> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/packages/{}/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_all_package_versions_for_package_owned_by_user`](crate::packages::Packages::get_all_package_versions_for_package_owned_by_user), a `GET` to `/users/{username}/packages/{package_type}/{package_name}/versions`.
+    pub fn get_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PackageVersion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name)
+            ),
+        )
+    }
+
+    /// Mock [`Packages::get_package_version_for_user`](crate::packages::Packages::get_package_version_for_user), a `GET` to `/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}`.
+    pub fn get_package_version_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PackageVersion> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/packages/{}/{}/versions/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                crate::progenitor_support::encode_path(&package_version_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Projects<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Projects<'a> {
+    /// Mock [`Projects::list_for_org`](crate::projects::Projects::list_for_org), a `GET` to `/orgs/{org}/projects`.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Project>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::create_for_org`](crate::projects::Projects::create_for_org), a `POST` to `/orgs/{org}/projects`.
+    pub fn create_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Project> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::get_card`](crate::projects::Projects::get_card), a `GET` to `/projects/columns/cards/{card_id}`.
+    pub fn get_card(
+        &self,
+        card_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectCard> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/columns/cards/{}",
+                crate::progenitor_support::encode_path(&card_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::delete_card`](crate::projects::Projects::delete_card), a `DELETE` to `/projects/columns/cards/{card_id}`.
+    pub fn delete_card(&self, card_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/projects/columns/cards/{}",
+                crate::progenitor_support::encode_path(&card_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::update_card`](crate::projects::Projects::update_card), a `PATCH` to `/projects/columns/cards/{card_id}`.
+    pub fn update_card(
+        &self,
+        card_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectCard> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/projects/columns/cards/{}",
+                crate::progenitor_support::encode_path(&card_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::move_card`](crate::projects::Projects::move_card), a `POST` to `/projects/columns/cards/{card_id}/moves`.
+    pub fn move_card(&self, card_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/projects/columns/cards/{}/moves",
+                crate::progenitor_support::encode_path(&card_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::get_column`](crate::projects::Projects::get_column), a `GET` to `/projects/columns/{column_id}`.
+    pub fn get_column(
+        &self,
+        column_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectColumn> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/columns/{}",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::delete_column`](crate::projects::Projects::delete_column), a `DELETE` to `/projects/columns/{column_id}`.
+    pub fn delete_column(&self, column_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/projects/columns/{}",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::update_column`](crate::projects::Projects::update_column), a `PATCH` to `/projects/columns/{column_id}`.
+    pub fn update_column(
+        &self,
+        column_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectColumn> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/projects/columns/{}",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::list_cards`](crate::projects::Projects::list_cards), a `GET` to `/projects/columns/{column_id}/cards`.
+    pub fn list_cards(
+        &self,
+        column_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ProjectCard>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::create_card`](crate::projects::Projects::create_card), a `POST` to `/projects/columns/{column_id}/cards`.
+    pub fn create_card(
+        &self,
+        column_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectCard> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::move_column`](crate::projects::Projects::move_column), a `POST` to `/projects/columns/{column_id}/moves`.
+    pub fn move_column(&self, column_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/projects/columns/{}/moves",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::get`](crate::projects::Projects::get), a `GET` to `/projects/{project_id}`.
+    pub fn get(&self, project_id: i64) -> crate::mock::MockOperation<'a, crate::types::Project> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/{}",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::delete`](crate::projects::Projects::delete), a `DELETE` to `/projects/{project_id}`.
+    pub fn delete(&self, project_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/projects/{}",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::update`](crate::projects::Projects::update), a `PATCH` to `/projects/{project_id}`.
+    pub fn update(&self, project_id: i64) -> crate::mock::MockOperation<'a, crate::types::Project> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/projects/{}",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::list_collaborators`](crate::projects::Projects::list_collaborators), a `GET` to `/projects/{project_id}/collaborators`.
+    pub fn list_collaborators(
+        &self,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/{}/collaborators",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::add_collaborator`](crate::projects::Projects::add_collaborator), a `PUT` to `/projects/{project_id}/collaborators/{username}`.
+    pub fn add_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/projects/{}/collaborators/{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::remove_collaborator`](crate::projects::Projects::remove_collaborator), a `DELETE` to `/projects/{project_id}/collaborators/{username}`.
+    pub fn remove_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/projects/{}/collaborators/{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::get_permission_for_user`](crate::projects::Projects::get_permission_for_user), a `GET` to `/projects/{project_id}/collaborators/{username}/permission`.
+    pub fn get_permission_for_user(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositoryCollaboratorPermission> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/{}/collaborators/{}/permission",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::list_columns`](crate::projects::Projects::list_columns), a `GET` to `/projects/{project_id}/columns`.
+    pub fn list_columns(
+        &self,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ProjectColumn>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::create_column`](crate::projects::Projects::create_column), a `POST` to `/projects/{project_id}/columns`.
+    pub fn create_column(
+        &self,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProjectColumn> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Projects::list_for_repo`](crate::projects::Projects::list_for_repo), a `GET` to `/repos/{owner}/{repo}/projects`.
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Project>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::create_for_repo`](crate::projects::Projects::create_for_repo), a `POST` to `/repos/{owner}/{repo}/projects`.
+    pub fn create_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Project> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Projects::create_for_authenticated_user`](crate::projects::Projects::create_for_authenticated_user), a `POST` to `/user/projects`.
+    pub fn create_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Project> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/projects".to_string())
+    }
+
+    /// Mock [`Projects::list_for_user`](crate::projects::Projects::list_for_user), a `GET` to `/users/{username}/projects`.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Project>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/projects",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Pulls<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Pulls<'a> {
+    /// Mock [`Pulls::list`](crate::pulls::Pulls::list), a `GET` to `/repos/{owner}/{repo}/pulls`.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PullRequestSimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::create`](crate::pulls::Pulls::create), a `POST` to `/repos/{owner}/{repo}/pulls`.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_review_comments_for_repo`](crate::pulls::Pulls::list_review_comments_for_repo), a `GET` to `/repos/{owner}/{repo}/pulls/comments`.
+    pub fn list_review_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PullRequestReviewComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::get_review_comment`](crate::pulls::Pulls::get_review_comment), a `GET` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}`.
+    pub fn get_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::delete_review_comment`](crate::pulls::Pulls::delete_review_comment), a `DELETE` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}`.
+    pub fn delete_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::update_review_comment`](crate::pulls::Pulls::update_review_comment), a `PATCH` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}`.
+    pub fn update_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::get`](crate::pulls::Pulls::get), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}`.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::update`](crate::pulls::Pulls::update), a `PATCH` to `/repos/{owner}/{repo}/pulls/{pull_number}`.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestData> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/pulls/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_review_comments`](crate::pulls::Pulls::list_review_comments), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/comments`.
+    pub fn list_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PullRequestReviewComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::create_review_comment`](crate::pulls::Pulls::create_review_comment), a `POST` to `/repos/{owner}/{repo}/pulls/{pull_number}/comments`.
+    pub fn create_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::create_reply_for_review_comment`](crate::pulls::Pulls::create_reply_for_review_comment), a `POST` to `/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies`.
+    pub fn create_reply_for_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/{}/comments/{}/replies",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_commits`](crate::pulls::Pulls::list_commits), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/commits`.
+    pub fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CommitDataType>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_files`](crate::pulls::Pulls::list_files), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/files`.
+    pub fn list_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DiffEntry>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::check_if_merged`](crate::pulls::Pulls::check_if_merged), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/merge`.
+    pub fn check_if_merged(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/merge",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::merge`](crate::pulls::Pulls::merge), a `PUT` to `/repos/{owner}/{repo}/pulls/{pull_number}/merge`.
+    pub fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestMergeResult> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/pulls/{}/merge",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_requested_reviewers`](crate::pulls::Pulls::list_requested_reviewers), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers`.
+    pub fn list_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReview> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::request_reviewers`](crate::pulls::Pulls::request_reviewers), a `POST` to `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers`.
+    pub fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestSimple> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::remove_requested_reviewers`](crate::pulls::Pulls::remove_requested_reviewers), a `DELETE` to `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers`.
+    pub fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestSimple> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_reviews`](crate::pulls::Pulls::list_reviews), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews`.
+    pub fn list_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PullRequestReviewData>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::create_review`](crate::pulls::Pulls::create_review), a `POST` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews`.
+    pub fn create_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::get_review`](crate::pulls::Pulls::get_review), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}`.
+    pub fn get_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::update_review`](crate::pulls::Pulls::update_review), a `PUT` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}`.
+    pub fn update_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::delete_pending_review`](crate::pulls::Pulls::delete_pending_review), a `DELETE` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}`.
+    pub fn delete_pending_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::list_comments_for_review`](crate::pulls::Pulls::list_comments_for_review), a `GET` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments`.
+    pub fn list_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ReviewComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::dismiss_review`](crate::pulls::Pulls::dismiss_review), a `PUT` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals`.
+    pub fn dismiss_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/dismissals",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::submit_review`](crate::pulls::Pulls::submit_review), a `POST` to `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events`.
+    pub fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullRequestReviewData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Pulls::update_branch`](crate::pulls::Pulls::update_branch), a `PUT` to `/repos/{owner}/{repo}/pulls/{pull_number}/update-branch`.
+    pub fn update_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PullsUpdateBranchResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/pulls/{}/update-branch",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string())
+            ),
+        )
+    }
+}
+
+pub struct RateLimit<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RateLimit<'a> {
+    /// Mock [`RateLimit::get`](crate::rate_limit::RateLimit::get), a `GET` to `/rate_limit`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::RateLimitOverview> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rate_limit".to_string())
+    }
+}
+
+pub struct Reactions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reactions<'a> {
+    /// Mock [`Reactions::list_for_team_discussion_comment_in_org`](crate::reactions::Reactions::list_for_team_discussion_comment_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions`.
+    pub fn list_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_team_discussion_comment_in_org`](crate::reactions::Reactions::create_for_team_discussion_comment_in_org), a `POST` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions`.
+    pub fn create_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_team_discussion_comment`](crate::reactions::Reactions::delete_for_team_discussion_comment), a `DELETE` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}`.
+    pub fn delete_for_team_discussion_comment(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_team_discussion_in_org`](crate::reactions::Reactions::list_for_team_discussion_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions`.
+    pub fn list_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_team_discussion_in_org`](crate::reactions::Reactions::create_for_team_discussion_in_org), a `POST` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions`.
+    pub fn create_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_team_discussion`](crate::reactions::Reactions::delete_for_team_discussion), a `DELETE` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}`.
+    pub fn delete_for_team_discussion(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_legacy`](crate::reactions::Reactions::delete_legacy), a `DELETE` to `/reactions/{reaction_id}`.
+    pub fn delete_legacy(&self, reaction_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/reactions/{}",
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_commit_comment`](crate::reactions::Reactions::list_for_commit_comment), a `GET` to `/repos/{owner}/{repo}/comments/{comment_id}/reactions`.
+    pub fn list_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_commit_comment`](crate::reactions::Reactions::create_for_commit_comment), a `POST` to `/repos/{owner}/{repo}/comments/{comment_id}/reactions`.
+    pub fn create_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_commit_comment`](crate::reactions::Reactions::delete_for_commit_comment), a `DELETE` to `/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}`.
+    pub fn delete_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/comments/{}/reactions/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_issue_comment`](crate::reactions::Reactions::list_for_issue_comment), a `GET` to `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions`.
+    pub fn list_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_issue_comment`](crate::reactions::Reactions::create_for_issue_comment), a `POST` to `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions`.
+    pub fn create_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_issue_comment`](crate::reactions::Reactions::delete_for_issue_comment), a `DELETE` to `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}`.
+    pub fn delete_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/comments/{}/reactions/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_issue`](crate::reactions::Reactions::list_for_issue), a `GET` to `/repos/{owner}/{repo}/issues/{issue_number}/reactions`.
+    pub fn list_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/issues/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_issue`](crate::reactions::Reactions::create_for_issue), a `POST` to `/repos/{owner}/{repo}/issues/{issue_number}/reactions`.
+    pub fn create_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/issues/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_issue`](crate::reactions::Reactions::delete_for_issue), a `DELETE` to `/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}`.
+    pub fn delete_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/issues/{}/reactions/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_pull_request_review_comment`](crate::reactions::Reactions::list_for_pull_request_review_comment), a `GET` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions`.
+    pub fn list_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_pull_request_review_comment`](crate::reactions::Reactions::create_for_pull_request_review_comment), a `POST` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions`.
+    pub fn create_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::delete_for_pull_request_comment`](crate::reactions::Reactions::delete_for_pull_request_comment), a `DELETE` to `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}`.
+    pub fn delete_for_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                crate::progenitor_support::encode_path(&reaction_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_release`](crate::reactions::Reactions::create_for_release), a `POST` to `/repos/{owner}/{repo}/releases/{release_id}/reactions`.
+    pub fn create_for_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/releases/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_team_discussion_comment_legacy`](crate::reactions::Reactions::list_for_team_discussion_comment_legacy), a `GET` to `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions`.
+    pub fn list_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_team_discussion_comment_legacy`](crate::reactions::Reactions::create_for_team_discussion_comment_legacy), a `POST` to `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions`.
+    pub fn create_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::list_for_team_discussion_legacy`](crate::reactions::Reactions::list_for_team_discussion_legacy), a `GET` to `/teams/{team_id}/discussions/{discussion_number}/reactions`.
+    pub fn list_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Reaction>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Reactions::create_for_team_discussion_legacy`](crate::reactions::Reactions::create_for_team_discussion_legacy), a `POST` to `/teams/{team_id}/discussions/{discussion_number}/reactions`.
+    pub fn create_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/teams/{}/discussions/{}/reactions",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Repos<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Repos<'a> {
+    /// Mock [`Repos::list_for_org`](crate::repos::Repos::list_for_org), a `GET` to `/orgs/{org}/repos`.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/repos",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_in_org`](crate::repos::Repos::create_in_org), a `POST` to `/orgs/{org}/repos`.
+    pub fn create_in_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Repository> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/repos",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get`](crate::repos::Repos::get), a `GET` to `/repos/{owner}/{repo}`.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullRepository> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete`](crate::repos::Repos::delete), a `DELETE` to `/repos/{owner}/{repo}`.
+    pub fn delete(&self, owner: &str, repo: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update`](crate::repos::Repos::update), a `PATCH` to `/repos/{owner}/{repo}`.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullRepository> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_autolinks`](crate::repos::Repos::list_autolinks), a `GET` to `/repos/{owner}/{repo}/autolinks`.
+    pub fn list_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Autolink>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_autolink`](crate::repos::Repos::create_autolink), a `POST` to `/repos/{owner}/{repo}/autolinks`.
+    pub fn create_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Autolink> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_autolink`](crate::repos::Repos::get_autolink), a `GET` to `/repos/{owner}/{repo}/autolinks/{autolink_id}`.
+    pub fn get_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Autolink> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/autolinks/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&autolink_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_autolink`](crate::repos::Repos::delete_autolink), a `DELETE` to `/repos/{owner}/{repo}/autolinks/{autolink_id}`.
+    pub fn delete_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/autolinks/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&autolink_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::enable_automated_security_fixes`](crate::repos::Repos::enable_automated_security_fixes), a `PUT` to `/repos/{owner}/{repo}/automated-security-fixes`.
+    pub fn enable_automated_security_fixes(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/automated-security-fixes",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::disable_automated_security_fixes`](crate::repos::Repos::disable_automated_security_fixes), a `DELETE` to `/repos/{owner}/{repo}/automated-security-fixes`.
+    pub fn disable_automated_security_fixes(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/automated-security-fixes",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_branches`](crate::repos::Repos::list_branches), a `GET` to `/repos/{owner}/{repo}/branches`.
+    pub fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ShortBranch>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_branch`](crate::repos::Repos::get_branch), a `GET` to `/repos/{owner}/{repo}/branches/{branch}`.
+    pub fn get_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BranchWithProtection> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_branch_protection`](crate::repos::Repos::get_branch_protection), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection`.
+    pub fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BranchProtection> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_branch_protection`](crate::repos::Repos::update_branch_protection), a `PUT` to `/repos/{owner}/{repo}/branches/{branch}/protection`.
+    pub fn update_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProtectedBranch> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/branches/{}/protection",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_branch_protection`](crate::repos::Repos::delete_branch_protection), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection`.
+    pub fn delete_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_admin_branch_protection`](crate::repos::Repos::get_admin_branch_protection), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins`.
+    pub fn get_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforceAdmins> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/enforce_admins",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::set_admin_branch_protection`](crate::repos::Repos::set_admin_branch_protection), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins`.
+    pub fn set_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforceAdmins> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/enforce_admins",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_admin_branch_protection`](crate::repos::Repos::delete_admin_branch_protection), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins`.
+    pub fn delete_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/enforce_admins",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_pull_request_review_protection`](crate::repos::Repos::get_pull_request_review_protection), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews`.
+    pub fn get_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProtectedBranchPullRequestReview> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_pull_request_review_protection`](crate::repos::Repos::delete_pull_request_review_protection), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews`.
+    pub fn delete_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_pull_request_review_protection`](crate::repos::Repos::update_pull_request_review_protection), a `PATCH` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews`.
+    pub fn update_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProtectedBranchPullRequestReview> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_commit_signature_protection`](crate::repos::Repos::get_commit_signature_protection), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures`.
+    pub fn get_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforceAdmins> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_signatures",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_commit_signature_protection`](crate::repos::Repos::create_commit_signature_protection), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures`.
+    pub fn create_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforceAdmins> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_signatures",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_commit_signature_protection`](crate::repos::Repos::delete_commit_signature_protection), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures`.
+    pub fn delete_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_signatures",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_status_checks_protection`](crate::repos::Repos::get_status_checks_protection), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks`.
+    pub fn get_status_checks_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::StatusCheckPolicy> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_status_check_protection`](crate::repos::Repos::remove_status_check_protection), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks`.
+    pub fn remove_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_status_check_protection`](crate::repos::Repos::update_status_check_protection), a `PATCH` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks`.
+    pub fn update_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::StatusCheckPolicy> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_all_status_check_contexts`](crate::repos::Repos::get_all_status_check_contexts), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts`.
+    pub fn get_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::set_status_check_contexts`](crate::repos::Repos::set_status_check_contexts), a `PUT` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts`.
+    pub fn set_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::add_status_check_contexts`](crate::repos::Repos::add_status_check_contexts), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts`.
+    pub fn add_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_status_check_contexts`](crate::repos::Repos::remove_status_check_contexts), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts`.
+    pub fn remove_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_access_restrictions`](crate::repos::Repos::get_access_restrictions), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions`.
+    pub fn get_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BranchRestrictionPolicy> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_access_restrictions`](crate::repos::Repos::delete_access_restrictions), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions`.
+    pub fn delete_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_apps_with_access_to_protected_branch`](crate::repos::Repos::get_apps_with_access_to_protected_branch), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps`.
+    pub fn get_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GitHubApp>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::set_app_access_restrictions`](crate::repos::Repos::set_app_access_restrictions), a `PUT` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps`.
+    pub fn set_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GitHubApp>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::add_app_access_restrictions`](crate::repos::Repos::add_app_access_restrictions), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps`.
+    pub fn add_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GitHubApp>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_app_access_restrictions`](crate::repos::Repos::remove_app_access_restrictions), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps`.
+    pub fn remove_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GitHubApp>> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_teams_with_access_to_protected_branch`](crate::repos::Repos::get_teams_with_access_to_protected_branch), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams`.
+    pub fn get_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::set_team_access_restrictions`](crate::repos::Repos::set_team_access_restrictions), a `PUT` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams`.
+    pub fn set_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::add_team_access_restrictions`](crate::repos::Repos::add_team_access_restrictions), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams`.
+    pub fn add_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_team_access_restrictions`](crate::repos::Repos::remove_team_access_restrictions), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams`.
+    pub fn remove_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_users_with_access_to_protected_branch`](crate::repos::Repos::get_users_with_access_to_protected_branch), a `GET` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users`.
+    pub fn get_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::set_user_access_restrictions`](crate::repos::Repos::set_user_access_restrictions), a `PUT` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users`.
+    pub fn set_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::add_user_access_restrictions`](crate::repos::Repos::add_user_access_restrictions), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users`.
+    pub fn add_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_user_access_restrictions`](crate::repos::Repos::remove_user_access_restrictions), a `DELETE` to `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users`.
+    pub fn remove_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::rename_branch`](crate::repos::Repos::rename_branch), a `POST` to `/repos/{owner}/{repo}/branches/{branch}/rename`.
+    pub fn rename_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BranchWithProtection> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/branches/{}/rename",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_collaborators`](crate::repos::Repos::list_collaborators), a `GET` to `/repos/{owner}/{repo}/collaborators`.
+    pub fn list_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Collaborator>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/collaborators",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::check_collaborator`](crate::repos::Repos::check_collaborator), a `GET` to `/repos/{owner}/{repo}/collaborators/{username}`.
+    pub fn check_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/collaborators/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::add_collaborator`](crate::repos::Repos::add_collaborator), a `PUT` to `/repos/{owner}/{repo}/collaborators/{username}`.
+    pub fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositoryInvitation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/collaborators/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::remove_collaborator`](crate::repos::Repos::remove_collaborator), a `DELETE` to `/repos/{owner}/{repo}/collaborators/{username}`.
+    pub fn remove_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/collaborators/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_collaborator_permission_level`](crate::repos::Repos::get_collaborator_permission_level), a `GET` to `/repos/{owner}/{repo}/collaborators/{username}/permission`.
+    pub fn get_collaborator_permission_level(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositoryCollaboratorPermission> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/collaborators/{}/permission",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_commit_comments_for_repo`](crate::repos::Repos::list_commit_comments_for_repo), a `GET` to `/repos/{owner}/{repo}/comments`.
+    pub fn list_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CommitComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_commit_comment`](crate::repos::Repos::get_commit_comment), a `GET` to `/repos/{owner}/{repo}/comments/{comment_id}`.
+    pub fn get_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_commit_comment`](crate::repos::Repos::delete_commit_comment), a `DELETE` to `/repos/{owner}/{repo}/comments/{comment_id}`.
+    pub fn delete_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_commit_comment`](crate::repos::Repos::update_commit_comment), a `PATCH` to `/repos/{owner}/{repo}/comments/{comment_id}`.
+    pub fn update_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/comments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_commits`](crate::repos::Repos::list_commits), a `GET` to `/repos/{owner}/{repo}/commits`.
+    pub fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CommitDataType>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_branches_for_head_commit`](crate::repos::Repos::list_branches_for_head_commit), a `GET` to `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head`.
+    pub fn list_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BranchShort>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/branches-where-head",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_comments_for_commit`](crate::repos::Repos::list_comments_for_commit), a `GET` to `/repos/{owner}/{repo}/commits/{commit_sha}/comments`.
+    pub fn list_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CommitComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_commit_comment`](crate::repos::Repos::create_commit_comment), a `POST` to `/repos/{owner}/{repo}/commits/{commit_sha}/comments`.
+    pub fn create_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_pull_requests_associated_with_commit`](crate::repos::Repos::list_pull_requests_associated_with_commit), a `GET` to `/repos/{owner}/{repo}/commits/{commit_sha}/pulls`.
+    pub fn list_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PullRequestSimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_commit`](crate::repos::Repos::get_commit), a `GET` to `/repos/{owner}/{repo}/commits/{ref}`.
+    pub fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitDataType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_combined_status_for_ref`](crate::repos::Repos::get_combined_status_for_ref), a `GET` to `/repos/{owner}/{repo}/commits/{ref}/status`.
+    pub fn get_combined_status_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CombinedCommitStatus> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/status",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_commit_statuses_for_ref`](crate::repos::Repos::list_commit_statuses_for_ref), a `GET` to `/repos/{owner}/{repo}/commits/{ref}/statuses`.
+    pub fn list_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::StatusData>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/commits/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_community_profile_metrics`](crate::repos::Repos::get_community_profile_metrics), a `GET` to `/repos/{owner}/{repo}/community/profile`.
+    pub fn get_community_profile_metrics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommunityProfile> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/community/profile",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::compare_commits`](crate::repos::Repos::compare_commits), a `GET` to `/repos/{owner}/{repo}/compare/{basehead}`.
+    pub fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        basehead: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitComparison> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/compare/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(basehead)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_content`](crate::repos::Repos::get_content), a `GET` to `/repos/{owner}/{repo}/contents/{path}`.
+    pub fn get_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReposGetContentResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_or_update_file_contents`](crate::repos::Repos::create_or_update_file_contents), a `PUT` to `/repos/{owner}/{repo}/contents/{path}`.
+    pub fn create_or_update_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileCommitData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_file`](crate::repos::Repos::delete_file), a `DELETE` to `/repos/{owner}/{repo}/contents/{path}`.
+    pub fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileCommitData> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/contents/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_contributors`](crate::repos::Repos::list_contributors), a `GET` to `/repos/{owner}/{repo}/contributors`.
+    pub fn list_contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Contributor>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_deployments`](crate::repos::Repos::list_deployments), a `GET` to `/repos/{owner}/{repo}/deployments`.
+    pub fn list_deployments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Deployment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_deployment`](crate::repos::Repos::create_deployment), a `POST` to `/repos/{owner}/{repo}/deployments`.
+    pub fn create_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Deployment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_deployment`](crate::repos::Repos::get_deployment), a `GET` to `/repos/{owner}/{repo}/deployments/{deployment_id}`.
+    pub fn get_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Deployment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/deployments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_deployment`](crate::repos::Repos::delete_deployment), a `DELETE` to `/repos/{owner}/{repo}/deployments/{deployment_id}`.
+    pub fn delete_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/deployments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_deployment_statuses`](crate::repos::Repos::list_deployment_statuses), a `GET` to `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses`.
+    pub fn list_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DeploymentStatus>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/deployments/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_deployment_status`](crate::repos::Repos::create_deployment_status), a `POST` to `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses`.
+    pub fn create_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeploymentStatus> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/deployments/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_deployment_status`](crate::repos::Repos::get_deployment_status), a `GET` to `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}`.
+    pub fn get_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        status_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeploymentStatus> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/deployments/{}/statuses/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string()),
+                crate::progenitor_support::encode_path(&status_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_dispatch_event`](crate::repos::Repos::create_dispatch_event), a `POST` to `/repos/{owner}/{repo}/dispatches`.
+    pub fn create_dispatch_event(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/dispatches",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_all_environments`](crate::repos::Repos::get_all_environments), a `GET` to `/repos/{owner}/{repo}/environments`.
+    pub fn get_all_environments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReposGetAllEnvironmentsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/environments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_environment`](crate::repos::Repos::get_environment), a `GET` to `/repos/{owner}/{repo}/environments/{environment_name}`.
+    pub fn get_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvironmentData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/environments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(environment_name)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_or_update_environment`](crate::repos::Repos::create_or_update_environment), a `PUT` to `/repos/{owner}/{repo}/environments/{environment_name}`.
+    pub fn create_or_update_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnvironmentData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/environments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(environment_name)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_an_environment`](crate::repos::Repos::delete_an_environment), a `DELETE` to `/repos/{owner}/{repo}/environments/{environment_name}`.
+    pub fn delete_an_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/environments/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(environment_name)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_forks`](crate::repos::Repos::list_forks), a `GET` to `/repos/{owner}/{repo}/forks`.
+    pub fn list_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/forks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_fork`](crate::repos::Repos::create_fork), a `POST` to `/repos/{owner}/{repo}/forks`.
+    pub fn create_fork(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullRepository> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/forks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_webhooks`](crate::repos::Repos::list_webhooks), a `GET` to `/repos/{owner}/{repo}/hooks`.
+    pub fn list_webhooks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Hook>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_webhook`](crate::repos::Repos::create_webhook), a `POST` to `/repos/{owner}/{repo}/hooks`.
+    pub fn create_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Hook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_webhook`](crate::repos::Repos::get_webhook), a `GET` to `/repos/{owner}/{repo}/hooks/{hook_id}`.
+    pub fn get_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Hook> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/hooks/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_webhook`](crate::repos::Repos::delete_webhook), a `DELETE` to `/repos/{owner}/{repo}/hooks/{hook_id}`.
+    pub fn delete_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/hooks/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_webhook`](crate::repos::Repos::update_webhook), a `PATCH` to `/repos/{owner}/{repo}/hooks/{hook_id}`.
+    pub fn update_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Hook> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/hooks/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_webhook_config_for_repo`](crate::repos::Repos::get_webhook_config_for_repo), a `GET` to `/repos/{owner}/{repo}/hooks/{hook_id}/config`.
+    pub fn get_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/hooks/{}/config",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_webhook_config_for_repo`](crate::repos::Repos::update_webhook_config_for_repo), a `PATCH` to `/repos/{owner}/{repo}/hooks/{hook_id}/config`.
+    pub fn update_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookConfig> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/hooks/{}/config",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_webhook_deliveries`](crate::repos::Repos::list_webhook_deliveries), a `GET` to `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries`.
+    pub fn list_webhook_deliveries(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::HookDeliveryItem>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_webhook_delivery`](crate::repos::Repos::get_webhook_delivery), a `GET` to `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}`.
+    pub fn get_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::HookDelivery> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/hooks/{}/deliveries/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::redeliver_webhook_delivery`](crate::repos::Repos::redeliver_webhook_delivery), a `POST` to `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts`.
+    pub fn redeliver_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/hooks/{}/deliveries/{}/attempts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                crate::progenitor_support::encode_path(&delivery_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::ping_webhook`](crate::repos::Repos::ping_webhook), a `POST` to `/repos/{owner}/{repo}/hooks/{hook_id}/pings`.
+    pub fn ping_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/hooks/{}/pings",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::test_push_webhook`](crate::repos::Repos::test_push_webhook), a `POST` to `/repos/{owner}/{repo}/hooks/{hook_id}/tests`.
+    pub fn test_push_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/hooks/{}/tests",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_invitations`](crate::repos::Repos::list_invitations), a `GET` to `/repos/{owner}/{repo}/invitations`.
+    pub fn list_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::RepositoryInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/invitations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_invitation`](crate::repos::Repos::delete_invitation), a `DELETE` to `/repos/{owner}/{repo}/invitations/{invitation_id}`.
+    pub fn delete_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/invitations/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_invitation`](crate::repos::Repos::update_invitation), a `PATCH` to `/repos/{owner}/{repo}/invitations/{invitation_id}`.
+    pub fn update_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::RepositoryInvitation> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/invitations/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_deploy_keys`](crate::repos::Repos::list_deploy_keys), a `GET` to `/repos/{owner}/{repo}/keys`.
+    pub fn list_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DeployKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_deploy_key`](crate::repos::Repos::create_deploy_key), a `POST` to `/repos/{owner}/{repo}/keys`.
+    pub fn create_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeployKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_deploy_key`](crate::repos::Repos::get_deploy_key), a `GET` to `/repos/{owner}/{repo}/keys/{key_id}`.
+    pub fn get_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeployKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/keys/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_deploy_key`](crate::repos::Repos::delete_deploy_key), a `DELETE` to `/repos/{owner}/{repo}/keys/{key_id}`.
+    pub fn delete_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/keys/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_languages`](crate::repos::Repos::list_languages), a `GET` to `/repos/{owner}/{repo}/languages`.
+    pub fn list_languages(&self, owner: &str, repo: &str) -> crate::mock::MockOperation<'a, i64> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/languages",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::merge`](crate::repos::Repos::merge), a `POST` to `/repos/{owner}/{repo}/merges`.
+    pub fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CommitDataType> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/merges",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_pages`](crate::repos::Repos::get_pages), a `GET` to `/repos/{owner}/{repo}/pages`.
+    pub fn get_pages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Page> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pages",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_information_about_pages_site`](crate::repos::Repos::update_information_about_pages_site), a `PUT` to `/repos/{owner}/{repo}/pages`.
+    pub fn update_information_about_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/pages",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_pages_site`](crate::repos::Repos::create_pages_site), a `POST` to `/repos/{owner}/{repo}/pages`.
+    pub fn create_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Page> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pages",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_pages_site`](crate::repos::Repos::delete_pages_site), a `DELETE` to `/repos/{owner}/{repo}/pages`.
+    pub fn delete_pages_site(&self, owner: &str, repo: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/pages",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_pages_builds`](crate::repos::Repos::list_pages_builds), a `GET` to `/repos/{owner}/{repo}/pages/builds`.
+    pub fn list_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PageBuild>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::request_pages_build`](crate::repos::Repos::request_pages_build), a `POST` to `/repos/{owner}/{repo}/pages/builds`.
+    pub fn request_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageBuildStatus> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_latest_pages_build`](crate::repos::Repos::get_latest_pages_build), a `GET` to `/repos/{owner}/{repo}/pages/builds/latest`.
+    pub fn get_latest_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageBuild> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pages/builds/latest",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_pages_build`](crate::repos::Repos::get_pages_build), a `GET` to `/repos/{owner}/{repo}/pages/builds/{build_id}`.
+    pub fn get_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        build_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PageBuild> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pages/builds/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&build_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_pages_health_check`](crate::repos::Repos::get_pages_health_check), a `GET` to `/repos/{owner}/{repo}/pages/health`.
+    pub fn get_pages_health_check(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PagesHealthCheck> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/pages/health",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_readme`](crate::repos::Repos::get_readme), a `GET` to `/repos/{owner}/{repo}/readme`.
+    pub fn get_readme(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContentFile> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/readme",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_readme_in_directory`](crate::repos::Repos::get_readme_in_directory), a `GET` to `/repos/{owner}/{repo}/readme/{dir}`.
+    pub fn get_readme_in_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContentFile> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/readme/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(dir)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_releases`](crate::repos::Repos::list_releases), a `GET` to `/repos/{owner}/{repo}/releases`.
+    pub fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Release>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_release`](crate::repos::Repos::create_release), a `POST` to `/repos/{owner}/{repo}/releases`.
+    pub fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Release> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_release_asset`](crate::repos::Repos::get_release_asset), a `GET` to `/repos/{owner}/{repo}/releases/assets/{asset_id}`.
+    pub fn get_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReleaseAsset> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases/assets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&asset_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_release_asset`](crate::repos::Repos::delete_release_asset), a `DELETE` to `/repos/{owner}/{repo}/releases/assets/{asset_id}`.
+    pub fn delete_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/releases/assets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&asset_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_release_asset`](crate::repos::Repos::update_release_asset), a `PATCH` to `/repos/{owner}/{repo}/releases/assets/{asset_id}`.
+    pub fn update_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReleaseAsset> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/releases/assets/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&asset_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_latest_release`](crate::repos::Repos::get_latest_release), a `GET` to `/repos/{owner}/{repo}/releases/latest`.
+    pub fn get_latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Release> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases/latest",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_release_by_tag`](crate::repos::Repos::get_release_by_tag), a `GET` to `/repos/{owner}/{repo}/releases/tags/{tag}`.
+    pub fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Release> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases/tags/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(tag)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_release`](crate::repos::Repos::get_release), a `GET` to `/repos/{owner}/{repo}/releases/{release_id}`.
+    pub fn get_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Release> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::delete_release`](crate::repos::Repos::delete_release), a `DELETE` to `/repos/{owner}/{repo}/releases/{release_id}`.
+    pub fn delete_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/releases/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::update_release`](crate::repos::Repos::update_release), a `PATCH` to `/repos/{owner}/{repo}/releases/{release_id}`.
+    pub fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Release> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/releases/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_release_assets`](crate::repos::Repos::list_release_assets), a `GET` to `/repos/{owner}/{repo}/releases/{release_id}/assets`.
+    pub fn list_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ReleaseAsset>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::upload_release_asset`](crate::repos::Repos::upload_release_asset), a `POST` to `/repos/{owner}/{repo}/releases/{release_id}/assets`.
+    pub fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReleaseAsset> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_code_frequency_stats`](crate::repos::Repos::get_code_frequency_stats), a `GET` to `/repos/{owner}/{repo}/stats/code_frequency`.
+    pub fn get_code_frequency_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<Vec<i64>>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stats/code_frequency",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_commit_activity_stats`](crate::repos::Repos::get_commit_activity_stats), a `GET` to `/repos/{owner}/{repo}/stats/commit_activity`.
+    pub fn get_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CommitActivity>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stats/commit_activity",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_contributors_stats`](crate::repos::Repos::get_contributors_stats), a `GET` to `/repos/{owner}/{repo}/stats/contributors`.
+    pub fn get_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ContributorActivity>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stats/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_participation_stats`](crate::repos::Repos::get_participation_stats), a `GET` to `/repos/{owner}/{repo}/stats/participation`.
+    pub fn get_participation_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParticipationStats> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stats/participation",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_punch_card_stats`](crate::repos::Repos::get_punch_card_stats), a `GET` to `/repos/{owner}/{repo}/stats/punch_card`.
+    pub fn get_punch_card_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<Vec<i64>>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/stats/punch_card",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_commit_status`](crate::repos::Repos::create_commit_status), a `POST` to `/repos/{owner}/{repo}/statuses/{sha}`.
+    pub fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::StatusData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/statuses/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(sha)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_tags`](crate::repos::Repos::list_tags), a `GET` to `/repos/{owner}/{repo}/tags`.
+    pub fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Tag>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::download_tarball_archive`](crate::repos::Repos::download_tarball_archive), a `GET` to `/repos/{owner}/{repo}/tarball/{ref}`.
+    pub fn download_tarball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/tarball/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_teams`](crate::repos::Repos::list_teams), a `GET` to `/repos/{owner}/{repo}/teams`.
+    pub fn list_teams(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_all_topics`](crate::repos::Repos::get_all_topics), a `GET` to `/repos/{owner}/{repo}/topics`.
+    pub fn get_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Topic> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/topics",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::replace_all_topics`](crate::repos::Repos::replace_all_topics), a `PUT` to `/repos/{owner}/{repo}/topics`.
+    pub fn replace_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Topic> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/topics",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_clones`](crate::repos::Repos::get_clones), a `GET` to `/repos/{owner}/{repo}/traffic/clones`.
+    pub fn get_clones(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloneTraffic> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/traffic/clones",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_top_paths`](crate::repos::Repos::get_top_paths), a `GET` to `/repos/{owner}/{repo}/traffic/popular/paths`.
+    pub fn get_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ContentTraffic>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/traffic/popular/paths",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_top_referrers`](crate::repos::Repos::get_top_referrers), a `GET` to `/repos/{owner}/{repo}/traffic/popular/referrers`.
+    pub fn get_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ReferrerTraffic>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/traffic/popular/referrers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::get_views`](crate::repos::Repos::get_views), a `GET` to `/repos/{owner}/{repo}/traffic/views`.
+    pub fn get_views(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ViewTraffic> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/traffic/views",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::transfer`](crate::repos::Repos::transfer), a `POST` to `/repos/{owner}/{repo}/transfer`.
+    pub fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MinimalRepository> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/transfer",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::check_vulnerability_alerts`](crate::repos::Repos::check_vulnerability_alerts), a `GET` to `/repos/{owner}/{repo}/vulnerability-alerts`.
+    pub fn check_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/vulnerability-alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::enable_vulnerability_alerts`](crate::repos::Repos::enable_vulnerability_alerts), a `PUT` to `/repos/{owner}/{repo}/vulnerability-alerts`.
+    pub fn enable_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/repos/{}/{}/vulnerability-alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::disable_vulnerability_alerts`](crate::repos::Repos::disable_vulnerability_alerts), a `DELETE` to `/repos/{owner}/{repo}/vulnerability-alerts`.
+    pub fn disable_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/repos/{}/{}/vulnerability-alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::download_zipball_archive`](crate::repos::Repos::download_zipball_archive), a `GET` to `/repos/{owner}/{repo}/zipball/{ref}`.
+    pub fn download_zipball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/zipball/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::create_using_template`](crate::repos::Repos::create_using_template), a `POST` to `/repos/{template_owner}/{template_repo}/generate`.
+    pub fn create_using_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Repository> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/repos/{}/{}/generate",
+                crate::progenitor_support::encode_path(template_owner),
+                crate::progenitor_support::encode_path(template_repo)
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_public`](crate::repos::Repos::list_public), a `GET` to `/repositories`.
+    pub fn list_public(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/repositories".to_string())
+    }
+
+    /// Mock [`Repos::list_for_authenticated_user`](crate::repos::Repos::list_for_authenticated_user), a `GET` to `/user/repos`.
+    pub fn list_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Repository>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/repos".to_string())
+    }
+
+    /// Mock [`Repos::create_for_authenticated_user`](crate::repos::Repos::create_for_authenticated_user), a `POST` to `/user/repos`.
+    pub fn create_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Repository> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/repos".to_string())
+    }
+
+    /// Mock [`Repos::list_invitations_for_authenticated_user`](crate::repos::Repos::list_invitations_for_authenticated_user), a `GET` to `/user/repository_invitations`.
+    pub fn list_invitations_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::RepositoryInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/repository_invitations".to_string(),
+        )
+    }
+
+    /// Mock [`Repos::decline_invitation`](crate::repos::Repos::decline_invitation), a `DELETE` to `/user/repository_invitations/{invitation_id}`.
+    pub fn decline_invitation(&self, invitation_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/repository_invitations/{}",
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::accept_invitation`](crate::repos::Repos::accept_invitation), a `PATCH` to `/user/repository_invitations/{invitation_id}`.
+    pub fn accept_invitation(&self, invitation_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/user/repository_invitations/{}",
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Repos::list_for_user`](crate::repos::Repos::list_for_user), a `GET` to `/users/{username}/repos`.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/repos",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct Scim<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Scim<'a> {
+    /// Mock [`Scim::list_provisioned_identities`](crate::scim::Scim::list_provisioned_identities), a `GET` to `/scim/v2/organizations/{org}/Users`.
+    pub fn list_provisioned_identities(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUserList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/organizations/{}/Users",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Scim::provision_and_invite_user`](crate::scim::Scim::provision_and_invite_user), a `POST` to `/scim/v2/organizations/{org}/Users`.
+    pub fn provision_and_invite_user(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUser> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/scim/v2/organizations/{}/Users",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Scim::get_provisioning_information_for_user`](crate::scim::Scim::get_provisioning_information_for_user), a `GET` to `/scim/v2/organizations/{org}/Users/{scim_user_id}`.
+    pub fn get_provisioning_information_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUser> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/scim/v2/organizations/{}/Users/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Scim::set_information_for_provisioned_user`](crate::scim::Scim::set_information_for_provisioned_user), a `PUT` to `/scim/v2/organizations/{org}/Users/{scim_user_id}`.
+    pub fn set_information_for_provisioned_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUser> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/scim/v2/organizations/{}/Users/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Scim::delete_user_from_org`](crate::scim::Scim::delete_user_from_org), a `DELETE` to `/scim/v2/organizations/{org}/Users/{scim_user_id}`.
+    pub fn delete_user_from_org(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/scim/v2/organizations/{}/Users/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Scim::update_attribute_for_user`](crate::scim::Scim::update_attribute_for_user), a `PATCH` to `/scim/v2/organizations/{org}/Users/{scim_user_id}`.
+    pub fn update_attribute_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScimUser> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/scim/v2/organizations/{}/Users/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(scim_user_id)
+            ),
+        )
+    }
+}
+
+pub struct Search<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Search<'a> {
+    /// Mock [`Search::code`](crate::search::Search::code), a `GET` to `/search/code`.
+    pub fn code(&self) -> crate::mock::MockOperation<'a, crate::types::SearchCodeResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/code".to_string())
+    }
+
+    /// Mock [`Search::commits`](crate::search::Search::commits), a `GET` to `/search/commits`.
+    pub fn commits(&self) -> crate::mock::MockOperation<'a, crate::types::SearchCommitsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/commits".to_string())
+    }
+
+    /// Mock [`Search::issues_and_pull_requests`](crate::search::Search::issues_and_pull_requests), a `GET` to `/search/issues`.
+    pub fn issues_and_pull_requests(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SearchIssuesPullRequestsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/issues".to_string())
+    }
+
+    /// Mock [`Search::labels`](crate::search::Search::labels), a `GET` to `/search/labels`.
+    pub fn labels(&self) -> crate::mock::MockOperation<'a, crate::types::SearchLabelsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/labels".to_string())
+    }
+
+    /// Mock [`Search::repos`](crate::search::Search::repos), a `GET` to `/search/repositories`.
+    pub fn repos(&self) -> crate::mock::MockOperation<'a, crate::types::SearchReposResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/repositories".to_string())
+    }
+
+    /// Mock [`Search::topics`](crate::search::Search::topics), a `GET` to `/search/topics`.
+    pub fn topics(&self) -> crate::mock::MockOperation<'a, crate::types::SearchTopicsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/topics".to_string())
+    }
+
+    /// Mock [`Search::users`](crate::search::Search::users), a `GET` to `/search/users`.
+    pub fn users(&self) -> crate::mock::MockOperation<'a, crate::types::SearchUsersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search/users".to_string())
+    }
+}
+
+pub struct SecretScanning<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SecretScanning<'a> {
+    /// Mock [`SecretScanning::list_alerts_for_repo`](crate::secret_scanning::SecretScanning::list_alerts_for_repo), a `GET` to `/repos/{owner}/{repo}/secret-scanning/alerts`.
+    pub fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SecretScanningAlert>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/secret-scanning/alerts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`SecretScanning::get_alert`](crate::secret_scanning::SecretScanning::get_alert), a `GET` to `/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}`.
+    pub fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SecretScanningAlert> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/repos/{}/{}/secret-scanning/alerts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`SecretScanning::update_alert`](crate::secret_scanning::SecretScanning::update_alert), a `PATCH` to `/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}`.
+    pub fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SecretScanningAlert> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/repos/{}/{}/secret-scanning/alerts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Teams<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Teams<'a> {
+    /// Mock [`Teams::list_idp_groups_for_org`](crate::teams::Teams::list_idp_groups_for_org), a `GET` to `/orgs/{org}/team-sync/groups`.
+    pub fn list_idp_groups_for_org(
+        &self,
+        org: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMapping> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/team-sync/groups",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list`](crate::teams::Teams::list), a `GET` to `/orgs/{org}/teams`.
+    pub fn list(&self, org: &str) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create`](crate::teams::Teams::create), a `POST` to `/orgs/{org}/teams`.
+    pub fn create(&self, org: &str) -> crate::mock::MockOperation<'a, crate::types::FullTeam> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_by_name`](crate::teams::Teams::get_by_name), a `GET` to `/orgs/{org}/teams/{team_slug}`.
+    pub fn get_by_name(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullTeam> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_in_org`](crate::teams::Teams::delete_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}`.
+    pub fn delete_in_org(&self, org: &str, team_slug: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_in_org`](crate::teams::Teams::update_in_org), a `PATCH` to `/orgs/{org}/teams/{team_slug}`.
+    pub fn update_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullTeam> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/teams/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_discussions_in_org`](crate::teams::Teams::list_discussions_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions`.
+    pub fn list_discussions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamDiscussion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_discussion_in_org`](crate::teams::Teams::create_discussion_in_org), a `POST` to `/orgs/{org}/teams/{team_slug}/discussions`.
+    pub fn create_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/teams/{}/discussions",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_discussion_in_org`](crate::teams::Teams::get_discussion_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}`.
+    pub fn get_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_discussion_in_org`](crate::teams::Teams::delete_discussion_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}`.
+    pub fn delete_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_discussion_in_org`](crate::teams::Teams::update_discussion_in_org), a `PATCH` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}`.
+    pub fn update_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_discussion_comments_in_org`](crate::teams::Teams::list_discussion_comments_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments`.
+    pub fn list_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamDiscussionComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_discussion_comment_in_org`](crate::teams::Teams::create_discussion_comment_in_org), a `POST` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments`.
+    pub fn create_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_discussion_comment_in_org`](crate::teams::Teams::get_discussion_comment_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn get_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_discussion_comment_in_org`](crate::teams::Teams::delete_discussion_comment_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn delete_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_discussion_comment_in_org`](crate::teams::Teams::update_discussion_comment_in_org), a `PATCH` to `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn update_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_pending_invitations_in_org`](crate::teams::Teams::list_pending_invitations_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/invitations`.
+    pub fn list_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_members_in_org`](crate::teams::Teams::list_members_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/members`.
+    pub fn list_members_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/members",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_membership_for_user_in_org`](crate::teams::Teams::get_membership_for_user_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/memberships/{username}`.
+    pub fn get_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamMembership> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_membership_for_user_in_org`](crate::teams::Teams::add_or_update_membership_for_user_in_org), a `PUT` to `/orgs/{org}/teams/{team_slug}/memberships/{username}`.
+    pub fn add_or_update_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamMembership> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_membership_for_user_in_org`](crate::teams::Teams::remove_membership_for_user_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}/memberships/{username}`.
+    pub fn remove_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_projects_in_org`](crate::teams::Teams::list_projects_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/projects`.
+    pub fn list_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamProject>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/projects",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::check_permissions_for_project_in_org`](crate::teams::Teams::check_permissions_for_project_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/projects/{project_id}`.
+    pub fn check_permissions_for_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamProject> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_project_permissions_in_org`](crate::teams::Teams::add_or_update_project_permissions_in_org), a `PUT` to `/orgs/{org}/teams/{team_slug}/projects/{project_id}`.
+    pub fn add_or_update_project_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_project_in_org`](crate::teams::Teams::remove_project_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}/projects/{project_id}`.
+    pub fn remove_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_repos_in_org`](crate::teams::Teams::list_repos_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/repos`.
+    pub fn list_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/repos",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::check_permissions_for_repo_in_org`](crate::teams::Teams::check_permissions_for_repo_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}`.
+    pub fn check_permissions_for_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamRepository> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_repo_permissions_in_org`](crate::teams::Teams::add_or_update_repo_permissions_in_org), a `PUT` to `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}`.
+    pub fn add_or_update_repo_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/orgs/{}/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_repo_in_org`](crate::teams::Teams::remove_repo_in_org), a `DELETE` to `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}`.
+    pub fn remove_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/orgs/{}/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_idp_groups_in_org`](crate::teams::Teams::list_idp_groups_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/team-sync/group-mappings`.
+    pub fn list_idp_groups_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMapping> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/team-sync/group-mappings",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_or_update_idp_group_connections_in_org`](crate::teams::Teams::create_or_update_idp_group_connections_in_org), a `PATCH` to `/orgs/{org}/teams/{team_slug}/team-sync/group-mappings`.
+    pub fn create_or_update_idp_group_connections_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMapping> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/orgs/{}/teams/{}/team-sync/group-mappings",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_child_in_org`](crate::teams::Teams::list_child_in_org), a `GET` to `/orgs/{org}/teams/{team_slug}/teams`.
+    pub fn list_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/orgs/{}/teams/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_legacy`](crate::teams::Teams::get_legacy), a `GET` to `/teams/{team_id}`.
+    pub fn get_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullTeam> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_legacy`](crate::teams::Teams::delete_legacy), a `DELETE` to `/teams/{team_id}`.
+    pub fn delete_legacy(&self, team_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_legacy`](crate::teams::Teams::update_legacy), a `PATCH` to `/teams/{team_id}`.
+    pub fn update_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullTeam> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teams/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_discussions_legacy`](crate::teams::Teams::list_discussions_legacy), a `GET` to `/teams/{team_id}/discussions`.
+    pub fn list_discussions_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamDiscussion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_discussion_legacy`](crate::teams::Teams::create_discussion_legacy), a `POST` to `/teams/{team_id}/discussions`.
+    pub fn create_discussion_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/teams/{}/discussions",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_discussion_legacy`](crate::teams::Teams::get_discussion_legacy), a `GET` to `/teams/{team_id}/discussions/{discussion_number}`.
+    pub fn get_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_discussion_legacy`](crate::teams::Teams::delete_discussion_legacy), a `DELETE` to `/teams/{team_id}/discussions/{discussion_number}`.
+    pub fn delete_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_discussion_legacy`](crate::teams::Teams::update_discussion_legacy), a `PATCH` to `/teams/{team_id}/discussions/{discussion_number}`.
+    pub fn update_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussion> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teams/{}/discussions/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_discussion_comments_legacy`](crate::teams::Teams::list_discussion_comments_legacy), a `GET` to `/teams/{team_id}/discussions/{discussion_number}/comments`.
+    pub fn list_discussion_comments_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamDiscussionComment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_discussion_comment_legacy`](crate::teams::Teams::create_discussion_comment_legacy), a `POST` to `/teams/{team_id}/discussions/{discussion_number}/comments`.
+    pub fn create_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/teams/{}/discussions/{}/comments",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_discussion_comment_legacy`](crate::teams::Teams::get_discussion_comment_legacy), a `GET` to `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn get_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::delete_discussion_comment_legacy`](crate::teams::Teams::delete_discussion_comment_legacy), a `DELETE` to `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn delete_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::update_discussion_comment_legacy`](crate::teams::Teams::update_discussion_comment_legacy), a `PATCH` to `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}`.
+    pub fn update_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDiscussionComment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teams/{}/discussions/{}/comments/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_pending_invitations_legacy`](crate::teams::Teams::list_pending_invitations_legacy), a `GET` to `/teams/{team_id}/invitations`.
+    pub fn list_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrganizationInvitation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/invitations",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_members_legacy`](crate::teams::Teams::list_members_legacy), a `GET` to `/teams/{team_id}/members`.
+    pub fn list_members_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/members",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_member_legacy`](crate::teams::Teams::get_member_legacy), a `GET` to `/teams/{team_id}/members/{username}`.
+    pub fn get_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/members/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_member_legacy`](crate::teams::Teams::add_member_legacy), a `PUT` to `/teams/{team_id}/members/{username}`.
+    pub fn add_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/teams/{}/members/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_member_legacy`](crate::teams::Teams::remove_member_legacy), a `DELETE` to `/teams/{team_id}/members/{username}`.
+    pub fn remove_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/members/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::get_membership_for_user_legacy`](crate::teams::Teams::get_membership_for_user_legacy), a `GET` to `/teams/{team_id}/memberships/{username}`.
+    pub fn get_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamMembership> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_membership_for_user_legacy`](crate::teams::Teams::add_or_update_membership_for_user_legacy), a `PUT` to `/teams/{team_id}/memberships/{username}`.
+    pub fn add_or_update_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamMembership> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_membership_for_user_legacy`](crate::teams::Teams::remove_membership_for_user_legacy), a `DELETE` to `/teams/{team_id}/memberships/{username}`.
+    pub fn remove_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/memberships/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_projects_legacy`](crate::teams::Teams::list_projects_legacy), a `GET` to `/teams/{team_id}/projects`.
+    pub fn list_projects_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TeamProject>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/projects",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::check_permissions_for_project_legacy`](crate::teams::Teams::check_permissions_for_project_legacy), a `GET` to `/teams/{team_id}/projects/{project_id}`.
+    pub fn check_permissions_for_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamProject> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_project_permissions_legacy`](crate::teams::Teams::add_or_update_project_permissions_legacy), a `PUT` to `/teams/{team_id}/projects/{project_id}`.
+    pub fn add_or_update_project_permissions_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_project_legacy`](crate::teams::Teams::remove_project_legacy), a `DELETE` to `/teams/{team_id}/projects/{project_id}`.
+    pub fn remove_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/projects/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_repos_legacy`](crate::teams::Teams::list_repos_legacy), a `GET` to `/teams/{team_id}/repos`.
+    pub fn list_repos_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::MinimalRepository>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/repos",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::check_permissions_for_repo_legacy`](crate::teams::Teams::check_permissions_for_repo_legacy), a `GET` to `/teams/{team_id}/repos/{owner}/{repo}`.
+    pub fn check_permissions_for_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamRepository> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::add_or_update_repo_permissions_legacy`](crate::teams::Teams::add_or_update_repo_permissions_legacy), a `PUT` to `/teams/{team_id}/repos/{owner}/{repo}`.
+    pub fn add_or_update_repo_permissions_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::remove_repo_legacy`](crate::teams::Teams::remove_repo_legacy), a `DELETE` to `/teams/{team_id}/repos/{owner}/{repo}`.
+    pub fn remove_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teams/{}/repos/{}/{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo)
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_idp_groups_for_legacy`](crate::teams::Teams::list_idp_groups_for_legacy), a `GET` to `/teams/{team_id}/team-sync/group-mappings`.
+    pub fn list_idp_groups_for_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMapping> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/team-sync/group-mappings",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::create_or_update_idp_group_connections_legacy`](crate::teams::Teams::create_or_update_idp_group_connections_legacy), a `PATCH` to `/teams/{team_id}/team-sync/group-mappings`.
+    pub fn create_or_update_idp_group_connections_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMapping> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teams/{}/team-sync/group-mappings",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_child_legacy`](crate::teams::Teams::list_child_legacy), a `GET` to `/teams/{team_id}/teams`.
+    pub fn list_child_legacy(
+        &self,
+        team_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Team>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teams/{}/teams",
+                crate::progenitor_support::encode_path(&team_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Teams::list_for_authenticated_user`](crate::teams::Teams::list_for_authenticated_user), a `GET` to `/user/teams`.
+    pub fn list_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::FullTeam>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/teams".to_string())
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::get_authenticated`](crate::users::Users::get_authenticated), a `GET` to `/user`.
+    pub fn get_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersGetByUsernameResponseOneOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user".to_string())
+    }
+
+    /// Mock [`Users::update_authenticated`](crate::users::Users::update_authenticated), a `PATCH` to `/user`.
+    pub fn update_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PrivateUser> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/user".to_string())
+    }
+
+    /// Mock [`Users::list_blocked_by_authenticated`](crate::users::Users::list_blocked_by_authenticated), a `GET` to `/user/blocks`.
+    pub fn list_blocked_by_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/blocks".to_string())
+    }
+
+    /// Mock [`Users::check_blocked`](crate::users::Users::check_blocked), a `GET` to `/user/blocks/{username}`.
+    pub fn check_blocked(&self, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/blocks/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::block`](crate::users::Users::block), a `PUT` to `/user/blocks/{username}`.
+    pub fn block(&self, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/user/blocks/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::unblock`](crate::users::Users::unblock), a `DELETE` to `/user/blocks/{username}`.
+    pub fn unblock(&self, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/blocks/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::set_primary_email_visibility_for_authenticated`](crate::users::Users::set_primary_email_visibility_for_authenticated), a `PATCH` to `/user/email/visibility`.
+    pub fn set_primary_email_visibility_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Email>> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/user/email/visibility".to_string())
+    }
+
+    /// Mock [`Users::list_emails_for_authenticated`](crate::users::Users::list_emails_for_authenticated), a `GET` to `/user/emails`.
+    pub fn list_emails_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Email>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/emails".to_string())
+    }
+
+    /// Mock [`Users::add_email_for_authenticated`](crate::users::Users::add_email_for_authenticated), a `POST` to `/user/emails`.
+    pub fn add_email_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Email>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/emails".to_string())
+    }
+
+    /// Mock [`Users::delete_email_for_authenticated`](crate::users::Users::delete_email_for_authenticated), a `DELETE` to `/user/emails`.
+    pub fn delete_email_for_authenticated(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/user/emails".to_string())
+    }
+
+    /// Mock [`Users::list_followers_for_authenticated_user`](crate::users::Users::list_followers_for_authenticated_user), a `GET` to `/user/followers`.
+    pub fn list_followers_for_authenticated_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/followers".to_string())
+    }
+
+    /// Mock [`Users::list_followed_by_authenticated`](crate::users::Users::list_followed_by_authenticated), a `GET` to `/user/following`.
+    pub fn list_followed_by_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/following".to_string())
+    }
+
+    /// Mock [`Users::check_person_is_followed_by_authenticated`](crate::users::Users::check_person_is_followed_by_authenticated), a `GET` to `/user/following/{username}`.
+    pub fn check_person_is_followed_by_authenticated(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/following/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::follow`](crate::users::Users::follow), a `PUT` to `/user/following/{username}`.
+    pub fn follow(&self, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/user/following/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::unfollow`](crate::users::Users::unfollow), a `DELETE` to `/user/following/{username}`.
+    pub fn unfollow(&self, username: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/following/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_gpg_keys_for_authenticated`](crate::users::Users::list_gpg_keys_for_authenticated), a `GET` to `/user/gpg_keys`.
+    pub fn list_gpg_keys_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GpgKey>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/gpg_keys".to_string())
+    }
+
+    /// Mock [`Users::create_gpg_key_for_authenticated`](crate::users::Users::create_gpg_key_for_authenticated), a `POST` to `/user/gpg_keys`.
+    pub fn create_gpg_key_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GpgKey> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/gpg_keys".to_string())
+    }
+
+    /// Mock [`Users::get_gpg_key_for_authenticated`](crate::users::Users::get_gpg_key_for_authenticated), a `GET` to `/user/gpg_keys/{gpg_key_id}`.
+    pub fn get_gpg_key_for_authenticated(
+        &self,
+        gpg_key_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GpgKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/gpg_keys/{}",
+                crate::progenitor_support::encode_path(&gpg_key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Users::delete_gpg_key_for_authenticated`](crate::users::Users::delete_gpg_key_for_authenticated), a `DELETE` to `/user/gpg_keys/{gpg_key_id}`.
+    pub fn delete_gpg_key_for_authenticated(
+        &self,
+        gpg_key_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/gpg_keys/{}",
+                crate::progenitor_support::encode_path(&gpg_key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_public_ssh_keys_for_authenticated`](crate::users::Users::list_public_ssh_keys_for_authenticated), a `GET` to `/user/keys`.
+    pub fn list_public_ssh_keys_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Key>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/keys".to_string())
+    }
+
+    /// Mock [`Users::create_public_ssh_key_for_authenticated`](crate::users::Users::create_public_ssh_key_for_authenticated), a `POST` to `/user/keys`.
+    pub fn create_public_ssh_key_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Key> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/keys".to_string())
+    }
+
+    /// Mock [`Users::get_public_ssh_key_for_authenticated`](crate::users::Users::get_public_ssh_key_for_authenticated), a `GET` to `/user/keys/{key_id}`.
+    pub fn get_public_ssh_key_for_authenticated(
+        &self,
+        key_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Key> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/keys/{}",
+                crate::progenitor_support::encode_path(&key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Users::delete_public_ssh_key_for_authenticated`](crate::users::Users::delete_public_ssh_key_for_authenticated), a `DELETE` to `/user/keys/{key_id}`.
+    pub fn delete_public_ssh_key_for_authenticated(
+        &self,
+        key_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/keys/{}",
+                crate::progenitor_support::encode_path(&key_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_public_emails_for_authenticated`](crate::users::Users::list_public_emails_for_authenticated), a `GET` to `/user/public_emails`.
+    pub fn list_public_emails_for_authenticated(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Email>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/public_emails".to_string())
+    }
+
+    /// Mock [`Users::list`](crate::users::Users::list), a `GET` to `/users`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users".to_string())
+    }
+
+    /// Mock [`Users::get_by_username`](crate::users::Users::get_by_username), a `GET` to `/users/{username}`.
+    pub fn get_by_username(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersGetByUsernameResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_followers_for_user`](crate::users::Users::list_followers_for_user), a `GET` to `/users/{username}/followers`.
+    pub fn list_followers_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/followers",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_following_for_user`](crate::users::Users::list_following_for_user), a `GET` to `/users/{username}/following`.
+    pub fn list_following_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SimpleUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/following",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::check_following_for_user`](crate::users::Users::check_following_for_user), a `GET` to `/users/{username}/following/{target_user}`.
+    pub fn check_following_for_user(
+        &self,
+        username: &str,
+        target_user: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/following/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(target_user)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_gpg_keys_for_user`](crate::users::Users::list_gpg_keys_for_user), a `GET` to `/users/{username}/gpg_keys`.
+    pub fn list_gpg_keys_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GpgKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/gpg_keys",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get_context_for_user`](crate::users::Users::get_context_for_user), a `GET` to `/users/{username}/hovercard`.
+    pub fn get_context_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Hovercard> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/hovercard",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_public_keys_for_user`](crate::users::Users::list_public_keys_for_user), a `GET` to `/users/{username}/keys`.
+    pub fn list_public_keys_for_user(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::KeySimple>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/keys",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
@@ -2,6 +2,76 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`OauthAuthorizations::list_grants`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OauthAuthorizationsListGrantsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+    client_id: Option<String>,
+}
+
+impl OauthAuthorizationsListGrantsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// The client ID of your GitHub app.
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`OauthAuthorizations::list_authorizations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OauthAuthorizationsListParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+    client_id: Option<String>,
+}
+
+impl OauthAuthorizationsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// The client ID of your GitHub app.
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+}
+
 pub struct OauthAuthorizations {
     pub client: Client,
 }
@@ -25,25 +95,21 @@ impl OauthAuthorizations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `client_id: &str` -- The client ID of your GitHub app.
+     * * `params: &OauthAuthorizationsListGrantsParams` -- The optional query parameters.
      */
     pub async fn list_grants(
         &self,
-        per_page: i64,
-        page: i64,
-        client_id: &str,
+        params: &OauthAuthorizationsListGrantsParams,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -74,11 +140,11 @@ impl OauthAuthorizations {
      */
     pub async fn list_all_grants(
         &self,
-        client_id: &str,
+        params: &OauthAuthorizationsListGrantsParams,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -94,6 +160,63 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations/), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/developers/apps/authorizing-oauth-apps#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ApplicationGrant> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/applications/grants?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ApplicationGrant>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ApplicationGrant>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a single grant.
      *
@@ -169,25 +292,21 @@ impl OauthAuthorizations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `client_id: &str` -- The client ID of your GitHub app.
+     * * `params: &OauthAuthorizationsListParams` -- The optional query parameters.
      */
     pub async fn list_authorizations(
         &self,
-        per_page: i64,
-        page: i64,
-        client_id: &str,
+        params: &OauthAuthorizationsListParams,
     ) -> Result<Vec<crate::types::Authorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -216,11 +335,11 @@ impl OauthAuthorizations {
      */
     pub async fn list_all_authorizations(
         &self,
-        client_id: &str,
+        params: &OauthAuthorizationsListParams,
     ) -> Result<Vec<crate::types::Authorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -236,6 +355,61 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_all_authorizations_stream(
+        &self,
+        params: &OauthAuthorizationsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Authorization> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/authorizations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Authorization>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::Authorization>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a new authorization.
      *
@@ -2,6 +2,476 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Orgs::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListParams {
+    since: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl OrgsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// An organization ID. Only return organizations with an ID greater than this ID.
+    pub fn since(mut self, value: i64) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::get_audit_log`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsGetAuditLogParams {
+    phrase: Option<String>,
+    include: Option<crate::types::Include>,
+    after: Option<String>,
+    before: Option<String>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsGetAuditLogParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
+    pub fn phrase(mut self, value: impl Into<String>) -> Self {
+        self.phrase = Some(value.into());
+        self
+    }
+
+    /// The event types to include:
+    ///
+    /// - `web` - returns web (non-Git) events
+    /// - `git` - returns Git events
+    /// - `all` - returns both web and Git events
+    ///
+    /// The default is `web`.
+    pub fn include(mut self, value: crate::types::Include) -> Self {
+        self.include = Some(value);
+        self
+    }
+
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+
+    /// The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
+    ///
+    /// The default is `desc`.
+    pub fn order(mut self, value: crate::types::Order) -> Self {
+        self.order = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_failed_invitations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListFailedInvitationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListFailedInvitationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_webhooks`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListWebhooksParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListWebhooksParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_webhook_deliveries`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListWebhookDeliveriesParams {
+    per_page: Option<i64>,
+    cursor: Option<String>,
+}
+
+impl OrgsListWebhookDeliveriesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub fn cursor(mut self, value: impl Into<String>) -> Self {
+        self.cursor = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_app_installations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListAppInstallationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListAppInstallationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_pending_invitations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListPendingInvitationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListPendingInvitationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_invitation_teams`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListInvitationTeamsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListInvitationTeamsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_members`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListMembersParams {
+    filter: Option<crate::types::OrgsListMembersFilter>,
+    role: Option<crate::types::OrgsListMembersRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListMembersParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filter members returned in the list. Can be one of:  
+    /// \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
+    /// \* `all` - All members the authenticated user can see.
+    pub fn filter(mut self, value: crate::types::OrgsListMembersFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Filter members returned by their role. Can be one of:  
+    /// \* `all` - All members of the organization, regardless of role.  
+    /// \* `admin` - Organization owners.  
+    /// \* `member` - Non-owner organization members.
+    pub fn role(mut self, value: crate::types::OrgsListMembersRole) -> Self {
+        self.role = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_outside_collaborators`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListOutsideCollaboratorsParams {
+    filter: Option<crate::types::OrgsListMembersFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListOutsideCollaboratorsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filter the list of outside collaborators. Can be one of:  
+    /// \* `2fa_disabled`: Outside collaborators without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled.  
+    /// \* `all`: All outside collaborators.
+    pub fn filter(mut self, value: crate::types::OrgsListMembersFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_public_members`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListPublicMembersParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListPublicMembersParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_memberships_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListMembershipsForAuthenticatedUserParams {
+    state: Option<crate::types::OrgMembershipState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListMembershipsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates the state of the memberships to return. Can be either `active` or `pending`. If not specified, the API returns both active and pending memberships.
+    pub fn state(mut self, value: crate::types::OrgMembershipState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Orgs::list_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct OrgsListForUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl OrgsListForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Orgs {
     pub client: Client,
 }
@@ -25,20 +495,18 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `since: i64` -- An organization ID. Only return organizations with an ID greater than this ID.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &OrgsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
-        since: i64,
-        per_page: i64,
+        params: &OrgsListParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/organizations?{}", query_), None);
@@ -65,10 +533,13 @@ impl Orgs {
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
      */
-    pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::OrganizationSimple>> {
+    pub async fn list_all(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/organizations?{}", query_), None);
@@ -82,6 +553,61 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all organizations, in the order that they were created on GitHub.
+     *
+     * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &OrgsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/organizations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationSimple>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an organization.
      *
@@ -160,54 +686,34 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `phrase: &str` -- A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
-     * * `include: crate::types::Include` -- The event types to include:
-     *  
-     *  - `web` - returns web (non-Git) events
-     *  - `git` - returns Git events
-     *  - `all` - returns both web and Git events
-     *  
-     *  The default is `web`.
-     * * `after: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
-     * * `before: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
-     * * `order: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsGetAuditLogParams` -- The optional query parameters.
      */
     pub async fn get_audit_log(
         &self,
         org: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &OrgsGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
         }
-        if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
         }
-        if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
         }
-        if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -244,27 +750,23 @@ impl Orgs {
     pub async fn get_all_audit_log(
         &self,
         org: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
+        params: &OrgsGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
         }
-        if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
         }
-        if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
         }
-        if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
         }
-        if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -285,6 +787,81 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Gets the audit log for an organization. For more information, see "[Reviewing the audit log for your organization](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization)."
+     *
+     * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AuditLogEvent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.before {
+            query_args.push(("before".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::AuditLogEvent>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::AuditLogEvent>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List users blocked by an organization.
      *
@@ -345,6 +922,54 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the users blocked by an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_all_blocked_users_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/blocks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check if a user is blocked by an organization.
      *
@@ -514,6 +1139,62 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Listing and deleting credential authorizations is available to organizations with GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products).
+     *
+     * An authenticated organization owner with the `read:org` scope can list all credential authorizations for an organization that uses SAML single sign-on (SSO). The credentials are either personal access tokens or SSH keys that organization members have authorized for the organization. For more information, see [About authentication with SAML single sign-on](https://help.github.com/en/articles/about-authentication-with-saml-single-sign-on).
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_all_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CredentialAuthorization> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/credential-authorizations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CredentialAuthorization>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CredentialAuthorization>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Remove a SAML SSO authorization for an organization.
      *
@@ -561,21 +1242,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListFailedInvitationsParams` -- The optional query parameters.
      */
     pub async fn list_failed_invitations(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListFailedInvitationsParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -628,6 +1307,60 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * The return hash contains `failed_at` and `failed_reason` fields which represent the time at which the invitation failed and the reason for the failure.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_all_failed_invitations_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationInvitation> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List organization webhooks.
      *
@@ -640,21 +1373,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListWebhooksParams` -- The optional query parameters.
      */
     pub async fn list_webhooks(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListWebhooksParams,
     ) -> Result<Vec<crate::types::OrgHook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -704,6 +1435,54 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrgHook> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrgHook>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::OrgHook>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an organization webhook.
      *
@@ -935,22 +1714,20 @@ impl Orgs {
      *
      * * `org: &str`
      * * `hook_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &OrgsListWebhookDeliveriesParams` -- The optional query parameters.
      */
     pub async fn list_webhook_deliveries(
         &self,
         org: &str,
         hook_id: i64,
-        per_page: i64,
-        cursor: &str,
+        params: &OrgsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -987,11 +1764,11 @@ impl Orgs {
         &self,
         org: &str,
         hook_id: i64,
-        cursor: &str,
+        params: &OrgsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1013,6 +1790,69 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::HookDeliveryItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::HookDeliveryItem>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::HookDeliveryItem>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a webhook delivery for an organization webhook.
      *
@@ -1138,21 +1978,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListAppInstallationsParams` -- The optional query parameters.
      */
     pub async fn list_app_installations(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListAppInstallationsParams,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1185,21 +2023,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListPendingInvitationsParams` -- The optional query parameters.
      */
     pub async fn list_pending_invitations(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListPendingInvitationsParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1252,6 +2088,60 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_all_pending_invitations_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationInvitation> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an organization invitation.
      *
@@ -1337,22 +2227,20 @@ impl Orgs {
      *
      * * `org: &str`
      * * `invitation_id: i64` -- invitation_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListInvitationTeamsParams` -- The optional query parameters.
      */
     pub async fn list_invitation_teams(
         &self,
         org: &str,
         invitation_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListInvitationTeamsParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1408,6 +2296,56 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all teams associated with an invitation. In order to see invitations in an organization, the authenticated user must be an organization owner.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_all_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List organization members.
      *
@@ -1420,36 +2358,25 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::OrgsListMembersFilter` -- Filter members returned in the list. Can be one of:  
-     *  \\* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
-     *  \\* `all` - All members the authenticated user can see.
-     * * `role: crate::types::OrgsListMembersRole` -- Filter members returned by their role. Can be one of:  
-     *  \\* `all` - All members of the organization, regardless of role.  
-     *  \\* `admin` - Organization owners.  
-     *  \\* `member` - Non-owner organization members.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListMembersParams` -- The optional query parameters.
      */
     pub async fn list_members(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
-        role: crate::types::OrgsListMembersRole,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListMembersParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1484,15 +2411,14 @@ impl Orgs {
     pub async fn list_all_members(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
-        role: crate::types::OrgsListMembersRole,
+        params: &OrgsListMembersParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1513,6 +2439,64 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_all_members_stream(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check organization membership for a user.
      *
@@ -1709,28 +2693,22 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::OrgsListMembersFilter` -- Filter members returned in the list. Can be one of:  
-     *  \\* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
-     *  \\* `all` - All members the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListOutsideCollaboratorsParams` -- The optional query parameters.
      */
     pub async fn list_outside_collaborators(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListOutsideCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1765,11 +2743,11 @@ impl Orgs {
     pub async fn list_all_outside_collaborators(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
+        params: &OrgsListOutsideCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1790,6 +2768,61 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all users who are outside collaborators of an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_all_outside_collaborators_stream(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/outside_collaborators?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Convert an organization member to outside collaborator.
      *
@@ -1872,21 +2905,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListPublicMembersParams` -- The optional query parameters.
      */
     pub async fn list_public_members(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListPublicMembersParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1939,6 +2970,54 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Members of an organization can choose to have their membership publicized or not.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_all_public_members_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check public organization membership for a user.
      *
@@ -2059,25 +3138,21 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `state: crate::types::OrgMembershipState` -- Indicates the state of the memberships to return. Can be either `active` or `pending`. If not specified, the API returns both active and pending memberships.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListMembershipsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_memberships_for_authenticated_user(
         &self,
-        state: crate::types::OrgMembershipState,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrgMembership>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -2106,11 +3181,11 @@ impl Orgs {
      */
     pub async fn list_all_memberships_for_authenticated_user(
         &self,
-        state: crate::types::OrgMembershipState,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrgMembership>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -2126,6 +3201,61 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_all_memberships_for_authenticated_user_stream(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrgMembership> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/user/memberships/orgs?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrgMembership>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrgMembership>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an organization membership for the authenticated user.
      *
@@ -2210,20 +3340,18 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/orgs?{}", query_), None);
@@ -2266,6 +3394,56 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List organizations for the authenticated user.
+     *
+     * **OAuth scope requirements**
+     *
+     * This only lists organizations that your authorization allows you to operate on in some way (e.g., you can list teams with `read:org` scope, you can publicize your organization membership with `user` scope, etc.). Therefore, this API requires at least `user` or `read:org` scope. OAuth requests with insufficient scope receive a `403 Forbidden` response.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationSimple> {
+        let url = self.client.url("/user/orgs", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationSimple>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List organizations for a user.
      *
@@ -2280,21 +3458,19 @@ impl Orgs {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListForUserParams` -- The optional query parameters.
      */
     pub async fn list_for_user(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListForUserParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2349,4 +3525,60 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List [public organization memberships](https://help.github.com/articles/publicizing-or-concealing-organization-membership) for the specified user.
+     *
+     * This method only lists _public_ memberships, regardless of authentication. If you need to fetch all of the organization memberships (public and private) for the authenticated user, use the [List organizations for the authenticated user](https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user) API instead.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationSimple> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationSimple>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
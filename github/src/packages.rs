@@ -2,6 +2,124 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Packages::restore_package_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PackagesRestorePackageForOrgParams {
+    token: Option<String>,
+}
+
+impl PackagesRestorePackageForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// package token
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Packages::get_all_package_versions_for_package_owned_by_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PackagesGetAllPackageVersionsForOwnedByOrgParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByOrgState>,
+}
+
+impl PackagesGetAllPackageVersionsForOwnedByOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// The state of the package, either active or deleted.
+    pub fn state(
+        mut self,
+        value: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.state = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Packages::restore_package_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PackagesRestorePackageForAuthenticatedUserParams {
+    token: Option<String>,
+}
+
+impl PackagesRestorePackageForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// package token
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByOrgState>,
+}
+
+impl PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// The state of the package, either active or deleted.
+    pub fn state(
+        mut self,
+        value: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.state = Some(value);
+        self
+    }
+}
+
 pub struct Packages {
     pub client: Client,
 }
@@ -121,18 +239,18 @@ impl Packages {
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
      * * `org: &str`
-     * * `token: &str` -- package token.
+     * * `params: &PackagesRestorePackageForOrgParams` -- The optional query parameters.
      */
     pub async fn restore_package_for_org(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
         org: &str,
-        token: &str,
+        params: &PackagesRestorePackageForOrgParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+        if let Some(v) = &params.token {
+            query_args.push(("token".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -172,28 +290,24 @@ impl Packages {
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
      * * `org: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState` -- The state of the package, either active or deleted.
+     * * `params: &PackagesGetAllPackageVersionsForOwnedByOrgParams` -- The optional query parameters.
      */
     pub async fn get_all_package_versions_for_package_owned_by_org(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
         org: &str,
-        page: i64,
-        per_page: i64,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForOwnedByOrgParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -235,11 +349,11 @@ impl Packages {
         package_type: crate::types::PackageType,
         package_name: &str,
         org: &str,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForOwnedByOrgParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -262,6 +376,74 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns all package versions for a package owned by an organization.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForOwnedByOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PackageVersion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PackageVersion>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PackageVersion>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a package version for an organization.
      *
@@ -505,17 +687,17 @@ impl Packages {
      *
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
-     * * `token: &str` -- package token.
+     * * `params: &PackagesRestorePackageForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn restore_package_for_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        token: &str,
+        params: &PackagesRestorePackageForAuthenticatedUserParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+        if let Some(v) = &params.token {
+            query_args.push(("token".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -553,27 +735,23 @@ impl Packages {
      *
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState` -- The state of the package, either active or deleted.
+     * * `params: &PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn get_all_package_versions_for_package_owned_by_authenticated_user(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        page: i64,
-        per_page: i64,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -613,11 +791,11 @@ impl Packages {
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -639,6 +817,72 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns all package versions for a package owned by the authenticated user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForOwnedByAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PackageVersion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/user/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PackageVersion>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PackageVersion>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a package version for the authenticated user.
      *
@@ -896,6 +1140,67 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns all package versions for a public package owned by a specified user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PackageVersion> {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PackageVersion>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PackageVersion>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a package version for a user.
      *
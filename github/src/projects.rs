@@ -2,6 +2,212 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Projects::list_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListForOrgParams {
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Projects::list_cards`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListCardsParams {
+    archived_state: Option<crate::types::ArchivedState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListCardsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
+    pub fn archived_state(mut self, value: crate::types::ArchivedState) -> Self {
+        self.archived_state = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Projects::list_collaborators`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListCollaboratorsParams {
+    affiliation: Option<crate::types::Affiliation>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListCollaboratorsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters the collaborators by their affiliation. Can be one of:  
+    /// \* `outside`: Outside collaborators of a project that are not a member of the project's organization.  
+    /// \* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
+    /// \* `all`: All collaborators the authenticated user can see.
+    pub fn affiliation(mut self, value: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Projects::list_columns`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListColumnsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListColumnsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Projects::list_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListForRepoParams {
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Projects::list_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectsListForUserParams {
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ProjectsListForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Projects {
     pub client: Client,
 }
@@ -24,26 +230,22 @@ impl Projects {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_org(
         &self,
         org: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForOrgParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -78,11 +280,11 @@ impl Projects {
     pub async fn list_all_for_org(
         &self,
         org: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForOrgParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -103,6 +305,61 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/projects?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Project>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Project>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an organization project.
      *
@@ -379,26 +636,22 @@ impl Projects {
      * **Parameters:**
      *
      * * `column_id: i64` -- column_id parameter.
-     * * `archived_state: crate::types::ArchivedState` -- Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListCardsParams` -- The optional query parameters.
      */
     pub async fn list_cards(
         &self,
         column_id: i64,
-        archived_state: crate::types::ArchivedState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListCardsParams,
     ) -> Result<Vec<crate::types::ProjectCard>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+        if let Some(v) = &params.archived_state {
+            query_args.push(("archived_state".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -433,11 +686,11 @@ impl Projects {
     pub async fn list_all_cards(
         &self,
         column_id: i64,
-        archived_state: crate::types::ArchivedState,
+        params: &ProjectsListCardsParams,
     ) -> Result<Vec<crate::types::ProjectCard>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+        if let Some(v) = &params.archived_state {
+            query_args.push(("archived_state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -458,6 +711,63 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_all_cards_stream(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ProjectCard> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.archived_state {
+            query_args.push(("archived_state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/columns/{}/cards?{}",
+                crate::progenitor_support::encode_path(&column_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ProjectCard>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::ProjectCard>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a project card.
      *
@@ -637,29 +947,22 @@ impl Projects {
      * **Parameters:**
      *
      * * `project_id: i64`
-     * * `affiliation: crate::types::Affiliation` -- Filters the collaborators by their affiliation. Can be one of:  
-     *  \\* `outside`: Outside collaborators of a project that are not a member of the project's organization.  
-     *  \\* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
-     *  \\* `all`: All collaborators the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListCollaboratorsParams` -- The optional query parameters.
      */
     pub async fn list_collaborators(
         &self,
         project_id: i64,
-        affiliation: crate::types::Affiliation,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -694,11 +997,11 @@ impl Projects {
     pub async fn list_all_collaborators(
         &self,
         project_id: i64,
-        affiliation: crate::types::Affiliation,
+        params: &ProjectsListCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -719,6 +1022,61 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add project collaborator.
      *
@@ -839,21 +1197,19 @@ impl Projects {
      * **Parameters:**
      *
      * * `project_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListColumnsParams` -- The optional query parameters.
      */
     pub async fn list_columns(
         &self,
         project_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListColumnsParams,
     ) -> Result<Vec<crate::types::ProjectColumn>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -906,6 +1262,60 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_all_columns_stream(
+        &self,
+        project_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ProjectColumn> {
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ProjectColumn>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ProjectColumn>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a project column.
      *
@@ -954,27 +1364,23 @@ impl Projects {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForRepoParams` -- The optional query parameters.
      */
     pub async fn list_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForRepoParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1011,11 +1417,11 @@ impl Projects {
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForRepoParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1037,6 +1443,63 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/projects?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Project>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Project>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a repository project.
      *
@@ -1111,26 +1574,22 @@ impl Projects {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForUserParams` -- The optional query parameters.
      */
     pub async fn list_for_user(
         &self,
         username: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForUserParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1165,11 +1624,11 @@ impl Projects {
     pub async fn list_all_for_user(
         &self,
         username: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForUserParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1190,4 +1649,59 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/projects?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Project>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Project>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -2,6 +2,307 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Pulls::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListParams {
+    state: Option<crate::types::IssuesListState>,
+    head: Option<String>,
+    base: Option<String>,
+    sort: Option<crate::types::PullsListSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Either `open`, `closed`, or `all` to filter by state.
+    pub fn state(mut self, value: crate::types::IssuesListState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// Filter pulls by head user or head organization and branch name in the format of `user:ref-name` or `organization:ref-name`. For example: `github:new-script-format` or `octocat:test-branch`.
+    pub fn head(mut self, value: impl Into<String>) -> Self {
+        self.head = Some(value.into());
+        self
+    }
+
+    /// Filter pulls by base branch name. Example: `gh-pages`.
+    pub fn base(mut self, value: impl Into<String>) -> Self {
+        self.base = Some(value.into());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
+    pub fn sort(mut self, value: crate::types::PullsListSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// The direction of the sort. Can be either `asc` or `desc`. Default: `desc` when sort is `created` or sort is not specified, otherwise `asc`.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_review_comments_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListReviewCommentsForRepoParams {
+    sort: Option<crate::types::PullsListReviewCommentsRepoSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListReviewCommentsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the `sort` query parameter.
+    pub fn sort(mut self, value: crate::types::PullsListReviewCommentsRepoSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Can be either `asc` or `desc`. Ignored without `sort` parameter.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_review_comments`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListReviewCommentsParams {
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListReviewCommentsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
+    pub fn sort(mut self, value: crate::types::Sort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Can be either `asc` or `desc`. Ignored without `sort` parameter.
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_commits`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListCommitsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListCommitsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_files`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListFilesParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListFilesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_requested_reviewers`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListRequestedReviewersParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListRequestedReviewersParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_reviews`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListReviewsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListReviewsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Pulls::list_comments_for_review`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PullsListCommentsForReviewParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl PullsListCommentsForReviewParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Pulls {
     pub client: Client,
 }
@@ -25,49 +326,35 @@ impl Pulls {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `head: &str` -- Filter pulls by head user or head organization and branch name in the format of `user:ref-name` or `organization:ref-name`. For example: `github:new-script-format` or `octocat:test-branch`.
-     * * `base: &str` -- Filter pulls by base branch name. Example: `gh-pages`.
-     * * `sort: crate::types::PullsListSort` -- What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        head: &str,
-        base: &str,
-        sort: crate::types::PullsListSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &PullsListParams,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !base.is_empty() {
-            query_args.push(("base".to_string(), base.to_string()));
+        if let Some(v) = &params.base {
+            query_args.push(("base".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !head.is_empty() {
-            query_args.push(("head".to_string(), head.to_string()));
+        if let Some(v) = &params.head {
+            query_args.push(("head".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -104,27 +391,23 @@ impl Pulls {
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        head: &str,
-        base: &str,
-        sort: crate::types::PullsListSort,
-        direction: crate::types::Order,
+        params: &PullsListParams,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !base.is_empty() {
-            query_args.push(("base".to_string(), base.to_string()));
+        if let Some(v) = &params.base {
+            query_args.push(("base".to_string(), v.to_string()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !head.is_empty() {
-            query_args.push(("head".to_string(), head.to_string()));
+        if let Some(v) = &params.head {
+            query_args.push(("head".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -146,6 +429,81 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_all_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PullRequestSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.base {
+            query_args.push(("base".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.head {
+            query_args.push(("head".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PullRequestSimple>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PullRequestSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a pull request.
      *
@@ -203,39 +561,29 @@ impl Pulls {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::PullsListReviewCommentsRepoSort`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewCommentsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_review_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::PullsListReviewCommentsRepoSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewCommentsForRepoParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -272,19 +620,17 @@ impl Pulls {
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::PullsListReviewCommentsRepoSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &PullsListReviewCommentsForRepoParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -306,6 +652,75 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_all_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PullRequestReviewComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PullRequestReviewComment>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PullRequestReviewComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a review comment for a pull request.
      *
@@ -538,40 +953,30 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewCommentsParams` -- The optional query parameters.
      */
     pub async fn list_review_comments(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewCommentsParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -610,19 +1015,17 @@ impl Pulls {
         owner: &str,
         repo: &str,
         pull_number: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &PullsListReviewCommentsParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -645,6 +1048,77 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_all_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PullRequestReviewComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PullRequestReviewComment>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PullRequestReviewComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a review comment for a pull request.
      *
@@ -753,23 +1227,21 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListCommitsParams` -- The optional query parameters.
      */
     pub async fn list_commits(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListCommitsParams,
     ) -> Result<Vec<crate::types::CommitDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -828,6 +1300,64 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a maximum of 250 commits for a pull request. To receive a complete commit list for pull requests with more than 250 commits, use the [List commits](https://docs.github.com/rest/reference/repos#list-commits) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CommitDataType> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CommitDataType>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CommitDataType>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List pull requests files.
      *
@@ -842,23 +1372,21 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListFilesParams` -- The optional query parameters.
      */
     pub async fn list_files(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListFilesParams,
     ) -> Result<Vec<crate::types::DiffEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -917,6 +1445,58 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Note:** Responses include a maximum of 3000 files. The paginated response returns 30 files per page by default.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_all_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DiffEntry> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::DiffEntry>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::DiffEntry>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check if a pull request has been merged.
      *
@@ -1007,23 +1587,21 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListRequestedReviewersParams` -- The optional query parameters.
      */
     pub async fn list_requested_reviewers(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListRequestedReviewersParams,
     ) -> Result<crate::types::PullRequestReview> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1142,23 +1720,21 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewsParams` -- The optional query parameters.
      */
     pub async fn list_reviews(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewsParams,
     ) -> Result<Vec<crate::types::PullRequestReviewData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1217,6 +1793,64 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * The list of reviews returns in chronological order.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_all_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PullRequestReviewData> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PullRequestReviewData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PullRequestReviewData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a review for a pull request.
      *
@@ -1409,8 +2043,7 @@ impl Pulls {
      * * `repo: &str`
      * * `pull_number: i64`
      * * `review_id: i64` -- review_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListCommentsForReviewParams` -- The optional query parameters.
      */
     pub async fn list_comments_for_review(
         &self,
@@ -1418,15 +2051,14 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
         review_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListCommentsForReviewParams,
     ) -> Result<Vec<crate::types::ReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1488,6 +2120,66 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List comments for a specific pull request review.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_all_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ReviewComment> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ReviewComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ReviewComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Dismiss a review for a pull request.
      *
@@ -0,0 +1,285 @@
+//! Tracks the rate limits GitHub reports, and waits for them to reset.
+//!
+//! Every response carries the state of the rate limit of the resource it
+//! counted against, in its `x-ratelimit-*` headers. The client keeps the
+//! latest state of each resource, which can be read with
+//! [`Client::rate_limits`](crate::Client::rate_limits) without calling
+//! [`RateLimit::get`](crate::rate_limit::RateLimit::get).
+//!
+//! With a [`RateLimitPolicy`] set through
+//! [`Client::with_rate_limit_policy`](crate::Client::with_rate_limit_policy),
+//! the client also stops sending requests that are known to be rate limited,
+//! either waiting for the limit to reset or failing fast.
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const X_RATELIMIT_RESET: &str = "x-ratelimit-reset";
+const X_RATELIMIT_USED: &str = "x-ratelimit-used";
+const X_RATELIMIT_RESOURCE: &str = "x-ratelimit-resource";
+const RETRY_AFTER: &str = "retry-after";
+
+/// How many times a rate limited request is retried after waiting.
+const MAX_RETRIES: usize = 3;
+
+/// What the client does with a request that would be rate limited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateLimitPolicy {
+    /// Fail with a [`RateLimitExceeded`] error without sending the request.
+    FailFast,
+    /// Sleep until the rate limit resets, then send the request. Requests
+    /// that would have to wait longer than `max_wait` fail instead.
+    Wait { max_wait: Duration },
+}
+
+/// A group of endpoints sharing a rate limit.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Resource {
+    /// The REST API, apart from search.
+    Core,
+    /// The search endpoints.
+    Search,
+    /// The GraphQL API.
+    Graphql,
+    /// Any other resource GitHub reports, like `integration_manifest`.
+    Other(String),
+}
+
+impl Resource {
+    /// The resource named by the `x-ratelimit-resource` header.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "core" => Resource::Core,
+            "search" => Resource::Search,
+            "graphql" => Resource::Graphql,
+            name => Resource::Other(name.to_string()),
+        }
+    }
+
+    /// The resource a request to this path counts against.
+    pub(crate) fn for_path(path: &str) -> Self {
+        // GitHub Enterprise Server serves the API under `/api/v3`.
+        let rest = path.strip_prefix("/api/v3").unwrap_or(path);
+        if rest.starts_with("/search/") {
+            Resource::Search
+        } else if path == "/graphql" || path == "/api/graphql" {
+            Resource::Graphql
+        } else {
+            Resource::Core
+        }
+    }
+}
+
+/// The state of a rate limit, as of the last response counting against it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The number of requests allowed per window.
+    pub limit: u32,
+    /// The number of requests left in the current window.
+    pub remaining: u32,
+    /// The number of requests made in the current window.
+    pub used: u32,
+    /// When the current window ends.
+    pub reset: SystemTime,
+}
+
+impl RateLimitStatus {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let remaining = header(headers, X_RATELIMIT_REMAINING)?;
+        let reset = header(headers, X_RATELIMIT_RESET)?;
+        let limit = header(headers, X_RATELIMIT_LIMIT).unwrap_or(remaining);
+
+        Some(RateLimitStatus {
+            limit: limit as u32,
+            remaining: remaining as u32,
+            used: header(headers, X_RATELIMIT_USED).unwrap_or(limit.saturating_sub(remaining))
+                as u32,
+            reset: UNIX_EPOCH + Duration::from_secs(reset),
+        })
+    }
+}
+
+/// The error of a request that was not sent, because it would have been rate
+/// limited.
+#[derive(Debug, thiserror::Error)]
+#[error("rate limit of {resource:?} exceeded until {reset:?}")]
+pub struct RateLimitExceeded {
+    /// The resource that was rate limited.
+    pub resource: Resource,
+    /// When the request can be sent again.
+    pub reset: SystemTime,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    limits: BTreeMap<Resource, RateLimitStatus>,
+    /// Set by a `retry-after` header. Secondary rate limits aren't reported
+    /// per resource, so this holds back every request.
+    retry_after: Option<SystemTime>,
+}
+
+/// The latest rate limits of a client, shared with its clones.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimiter {
+    /// The latest state of every resource the client made requests to.
+    pub fn snapshot(&self) -> BTreeMap<Resource, RateLimitStatus> {
+        self.state.lock().unwrap().limits.clone()
+    }
+
+    /// The latest state of a resource, if the client made a request to it.
+    pub fn get(&self, resource: &Resource) -> Option<RateLimitStatus> {
+        self.state.lock().unwrap().limits.get(resource).cloned()
+    }
+
+    /// Record the rate limit reported by a response to a request made to
+    /// `resource`.
+    pub(crate) fn update(
+        &self,
+        resource: &Resource,
+        status: reqwest::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+    ) {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(limit) = RateLimitStatus::from_headers(headers) {
+            let resource = headers
+                .get(X_RATELIMIT_RESOURCE)
+                .and_then(|v| v.to_str().ok())
+                .map(Resource::from_name)
+                .unwrap_or_else(|| resource.clone());
+            state.limits.insert(resource, limit);
+        }
+
+        // Other responses don't lift a secondary rate limit, so it holds
+        // until its deadline passes.
+        if let Some(secs) = header(headers, RETRY_AFTER).filter(|_| is_rate_limit_status(status)) {
+            state.retry_after = Some(SystemTime::now() + Duration::from_secs(secs));
+        }
+    }
+
+    /// When a request to `resource` can be sent, if it would be rate limited
+    /// now.
+    pub(crate) fn blocked_until(&self, resource: &Resource) -> Option<SystemTime> {
+        let state = self.state.lock().unwrap();
+        let now = SystemTime::now();
+
+        let exhausted = state
+            .limits
+            .get(resource)
+            .filter(|limit| limit.remaining == 0)
+            .map(|limit| limit.reset);
+
+        exhausted
+            .into_iter()
+            .chain(state.retry_after)
+            .filter(|until| *until > now)
+            .max()
+    }
+
+    /// How long a request to `resource` has to wait before it can be sent.
+    fn wait(&self, resource: &Resource) -> Duration {
+        self.blocked_until(resource)
+            .and_then(|until| until.duration_since(SystemTime::now()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Apply the policy to a request to `resource`, waiting until it can be
+    /// sent or failing if it can't be sent in time.
+    pub(crate) async fn acquire(
+        &self,
+        resource: &Resource,
+        policy: RateLimitPolicy,
+    ) -> Result<(), RateLimitExceeded> {
+        let until = match self.blocked_until(resource) {
+            Some(until) => until,
+            None => return Ok(()),
+        };
+        let exceeded = RateLimitExceeded {
+            resource: resource.clone(),
+            reset: until,
+        };
+
+        match policy {
+            RateLimitPolicy::FailFast => Err(exceeded),
+            RateLimitPolicy::Wait { max_wait } => {
+                let wait = self.wait(resource);
+                if wait > max_wait {
+                    return Err(exceeded);
+                }
+
+                log::debug!("rate limit of {:?} exceeded, waiting {:?}", resource, wait);
+                tokio::time::sleep(wait).await;
+                Ok(())
+            }
+        }
+    }
+
+    /// Send a request with the policy, retrying it after waiting if the
+    /// response says it was rate limited.
+    pub(crate) async fn send(
+        &self,
+        client: &reqwest_middleware::ClientWithMiddleware,
+        mut req: reqwest::Request,
+        resource: &Resource,
+        policy: Option<RateLimitPolicy>,
+    ) -> anyhow::Result<reqwest::Response> {
+        let mut retries = 0;
+        loop {
+            if let Some(policy) = policy {
+                self.acquire(resource, policy).await?;
+            }
+
+            // Streamed bodies can't be sent twice, so those requests are never
+            // retried.
+            let retry = match policy {
+                Some(RateLimitPolicy::Wait { .. }) if retries < MAX_RETRIES => req.try_clone(),
+                _ => None,
+            };
+
+            let response = client
+                .execute(req)
+                .await
+                .map_err(crate::ClientError::from)?;
+            self.update(resource, response.status(), response.headers());
+
+            // A response over the limit is returned as is, to become a
+            // `ClientError::RateLimited`, unless its reset can be waited for.
+            match (retry, policy) {
+                (Some(retry), Some(RateLimitPolicy::Wait { max_wait }))
+                    if is_rate_limited(&response) && self.wait(resource) <= max_wait =>
+                {
+                    req = retry;
+                    retries += 1;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+/// GitHub responds to rate limited requests with a 403, or sometimes a 429,
+/// saying either when to retry or that no requests are left.
+fn is_rate_limited(response: &reqwest::Response) -> bool {
+    let headers = response.headers();
+    is_rate_limit_status(response.status())
+        && (headers.contains_key(RETRY_AFTER) || header(headers, X_RATELIMIT_REMAINING) == Some(0))
+}
+
+fn is_rate_limit_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+fn header(headers: &reqwest::header::HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+}
@@ -2,6 +2,286 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Reactions::list_for_team_discussion_comment_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForTeamDiscussionCommentInOrgParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionCommentInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_team_discussion_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForTeamDiscussionInOrgParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_commit_comment`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForCommitCommentParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForCommitCommentParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a commit comment.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_issue_comment`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForIssueCommentParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForIssueCommentParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue comment.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_issue`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForIssueParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForIssueParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_pull_request_review_comment`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForPullRequestReviewCommentParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForPullRequestReviewCommentParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_team_discussion_comment_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForTeamDiscussionCommentLegacyParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionCommentLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Reactions::list_for_team_discussion_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReactionsListForTeamDiscussionLegacyParams {
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
+    pub fn content(mut self, value: crate::types::Content) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Reactions {
     pub client: Client,
 }
@@ -29,9 +309,7 @@ impl Reactions {
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
      * * `comment_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionCommentInOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_team_discussion_comment_in_org(
         &self,
@@ -39,19 +317,17 @@ impl Reactions {
         team_slug: &str,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -94,11 +370,11 @@ impl Reactions {
         team_slug: &str,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -122,6 +398,69 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments/). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_all_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a team discussion comment.
      *
@@ -232,28 +571,24 @@ impl Reactions {
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionInOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_team_discussion_in_org(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -294,11 +629,11 @@ impl Reactions {
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -321,6 +656,67 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_all_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a team discussion.
      *
@@ -456,28 +852,24 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a commit comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForCommitCommentParams` -- The optional query parameters.
      */
     pub async fn list_for_commit_comment(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForCommitCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -516,11 +908,11 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForCommitCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -543,6 +935,65 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub fn list_all_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a commit comment.
      *
@@ -643,28 +1094,24 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForIssueCommentParams` -- The optional query parameters.
      */
     pub async fn list_for_issue_comment(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForIssueCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -703,11 +1150,11 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForIssueCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -730,6 +1177,65 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_all_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for an issue comment.
      *
@@ -830,28 +1336,24 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForIssueParams` -- The optional query parameters.
      */
     pub async fn list_for_issue(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForIssueParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -890,11 +1392,11 @@ impl Reactions {
         owner: &str,
         repo: &str,
         issue_number: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForIssueParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -917,6 +1419,65 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_all_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for an issue.
      *
@@ -1017,28 +1578,24 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForPullRequestReviewCommentParams` -- The optional query parameters.
      */
     pub async fn list_for_pull_request_review_comment(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1077,11 +1634,11 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForPullRequestReviewCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1104,6 +1661,65 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_all_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a pull request review comment.
      *
@@ -1247,28 +1863,24 @@ impl Reactions {
      * * `team_id: i64`
      * * `discussion_number: i64`
      * * `comment_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionCommentLegacyParams` -- The optional query parameters.
      */
     pub async fn list_for_team_discussion_comment_legacy(
         &self,
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1309,11 +1921,11 @@ impl Reactions {
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1336,6 +1948,67 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion comment`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment) endpoint.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_all_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a team discussion comment (Legacy).
      *
@@ -1394,27 +2067,23 @@ impl Reactions {
      *
      * * `team_id: i64`
      * * `discussion_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionLegacyParams` -- The optional query parameters.
      */
     pub async fn list_for_team_discussion_legacy(
         &self,
         team_id: i64,
         discussion_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1453,11 +2122,11 @@ impl Reactions {
         &self,
         team_id: i64,
         discussion_number: i64,
-        content: crate::types::Content,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
-            query_args.push(("content".to_string(), content.to_string()));
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1479,6 +2148,65 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion) endpoint.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_all_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Reaction>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Reaction>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create reaction for a team discussion (Legacy).
      *
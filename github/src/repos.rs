@@ -2,6 +2,821 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Repos::list_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListForOrgParams {
+    type_: Option<crate::types::ReposListOrgType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
+    pub fn type_(mut self, value: crate::types::ReposListOrgType) -> Self {
+        self.type_ = Some(value);
+        self
+    }
+
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub fn sort(mut self, value: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Can be one of `asc` or `desc`. Default: when using `full_name`: `asc`, otherwise `desc`
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_autolinks`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListAutolinksParams {
+    page: Option<i64>,
+}
+
+impl ReposListAutolinksParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_branches`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListBranchesParams {
+    protected: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListBranchesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Setting to `true` returns only protected branches. When set to `false`, only unprotected branches are returned. Omitting this parameter returns all branches.
+    pub fn protected(mut self, value: bool) -> Self {
+        self.protected = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_collaborators`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListCollaboratorsParams {
+    affiliation: Option<crate::types::Affiliation>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListCollaboratorsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filter collaborators returned by their affiliation. Can be one of:  
+    /// \* `outside`: All outside collaborators of an organization-owned repository.  
+    /// \* `direct`: All collaborators with permissions to an organization-owned repository, regardless of organization membership status.  
+    /// \* `all`: All collaborators the authenticated user can see.
+    pub fn affiliation(mut self, value: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_commit_comments_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListCommitCommentsForRepoParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListCommitCommentsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_commits`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListCommitsParams {
+    sha: Option<String>,
+    path: Option<String>,
+    author: Option<String>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListCommitsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
+    pub fn sha(mut self, value: impl Into<String>) -> Self {
+        self.sha = Some(value.into());
+        self
+    }
+
+    /// Only commits containing this file path will be returned.
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// GitHub login or email address by which to filter by commit author.
+    pub fn author(mut self, value: impl Into<String>) -> Self {
+        self.author = Some(value.into());
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn until(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.until = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_comments_for_commit`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListCommentsForCommitParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListCommentsForCommitParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_pull_requests_associated_with_commit`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListPullRequestsAssociatedWithCommitParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListPullRequestsAssociatedWithCommitParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_commit`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetCommitParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl ReposGetCommitParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_combined_status_for_ref`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetCombinedStatusForRefParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposGetCombinedStatusForRefParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_commit_statuses_for_ref`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListCommitStatusesForRefParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListCommitStatusesForRefParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::compare_commits`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposCompareCommitsParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl ReposCompareCommitsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_content`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetContentParams {
+    ref_: Option<String>,
+}
+
+impl ReposGetContentParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`)
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_contributors`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListContributorsParams {
+    anon: Option<String>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListContributorsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set to `1` or `true` to include anonymous contributors in results.
+    pub fn anon(mut self, value: impl Into<String>) -> Self {
+        self.anon = Some(value.into());
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_deployments`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListDeploymentsParams {
+    sha: Option<String>,
+    ref_: Option<String>,
+    task: Option<String>,
+    environment: Option<String>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListDeploymentsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The SHA recorded at creation time.
+    pub fn sha(mut self, value: impl Into<String>) -> Self {
+        self.sha = Some(value.into());
+        self
+    }
+
+    /// The name of the ref. This can be a branch, tag, or SHA.
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+
+    /// The name of the task for the deployment (e.g., `deploy` or `deploy:migrations`).
+    pub fn task(mut self, value: impl Into<String>) -> Self {
+        self.task = Some(value.into());
+        self
+    }
+
+    /// The name of the environment that was deployed to (e.g., `staging` or `production`).
+    pub fn environment(mut self, value: impl Into<String>) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_deployment_statuses`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListDeploymentStatusesParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListDeploymentStatusesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_forks`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListForksParams {
+    sort: Option<crate::types::ReposListForksSort>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListForksParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The sort order. Can be either `newest`, `oldest`, or `stargazers`.
+    pub fn sort(mut self, value: crate::types::ReposListForksSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_webhooks`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListWebhooksParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListWebhooksParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_webhook_deliveries`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListWebhookDeliveriesParams {
+    per_page: Option<i64>,
+    cursor: Option<String>,
+}
+
+impl ReposListWebhookDeliveriesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub fn cursor(mut self, value: impl Into<String>) -> Self {
+        self.cursor = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_invitations`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListInvitationsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListInvitationsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_deploy_keys`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListDeployKeysParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListDeployKeysParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_pages_builds`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListPagesBuildsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListPagesBuildsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_readme`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetReadmeParams {
+    ref_: Option<String>,
+}
+
+impl ReposGetReadmeParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`)
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_readme_in_directory`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetReadmeInDirectoryParams {
+    ref_: Option<String>,
+}
+
+impl ReposGetReadmeInDirectoryParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`)
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.ref_ = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_releases`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListReleasesParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListReleasesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_release_assets`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListReleaseAssetsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListReleaseAssetsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ReposUploadReleaseAssetDefaultServer {}
 
@@ -10,6 +825,332 @@ impl ReposUploadReleaseAssetDefaultServer {
         "https://uploads.github.com"
     }
 }
+/// The optional query parameters sent by [`Repos::upload_release_asset`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposUploadReleaseAssetParams {
+    label: Option<String>,
+}
+
+impl ReposUploadReleaseAssetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the `label` query parameter.
+    pub fn label(mut self, value: impl Into<String>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_tags`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListTagsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListTagsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_teams`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListTeamsParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListTeamsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_all_topics`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetAllTopicsParams {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl ReposGetAllTopicsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_clones`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetClonesParams {
+    per: Option<crate::types::Per>,
+}
+
+impl ReposGetClonesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Must be one of: `day`, `week`.
+    pub fn per(mut self, value: crate::types::Per) -> Self {
+        self.per = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::get_views`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposGetViewsParams {
+    per: Option<crate::types::Per>,
+}
+
+impl ReposGetViewsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Must be one of: `day`, `week`.
+    pub fn per(mut self, value: crate::types::Per) -> Self {
+        self.per = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_public`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListPublicParams {
+    since: Option<i64>,
+}
+
+impl ReposListPublicParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A repository ID. Only return repositories with an ID greater than this ID.
+    pub fn since(mut self, value: i64) -> Self {
+        self.since = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListForAuthenticatedUserParams {
+    visibility: Option<crate::types::ReposListVisibility>,
+    affiliation: Option<String>,
+    type_: Option<crate::types::ReposListType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ReposListForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
+    pub fn visibility(mut self, value: crate::types::ReposListVisibility) -> Self {
+        self.visibility = Some(value);
+        self
+    }
+
+    /// Comma-separated list of values. Can include:  
+    /// \* `owner`: Repositories that are owned by the authenticated user.  
+    /// \* `collaborator`: Repositories that the user has been added to as a collaborator.  
+    /// \* `organization_member`: Repositories that the user has access to through being a member of an organization. This includes every repository on every team that the user is on.
+    pub fn affiliation(mut self, value: impl Into<String>) -> Self {
+        self.affiliation = Some(value.into());
+        self
+    }
+
+    /// Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
+    ///   
+    /// Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
+    pub fn type_(mut self, value: crate::types::ReposListType) -> Self {
+        self.type_ = Some(value);
+        self
+    }
+
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub fn sort(mut self, value: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn since(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn before(mut self, value: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_invitations_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListInvitationsForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListInvitationsForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Repos::list_for_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ReposListForUserParams {
+    type_: Option<crate::types::ReposListUserType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl ReposListForUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Can be one of `all`, `owner`, `member`.
+    pub fn type_(mut self, value: crate::types::ReposListUserType) -> Self {
+        self.type_ = Some(value);
+        self
+    }
+
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub fn sort(mut self, value: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(value);
+        self
+    }
+
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
 
 pub struct Repos {
     pub client: Client,
@@ -33,38 +1174,28 @@ impl Repos {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `type_: crate::types::ReposListOrgType` -- Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForOrgParams` -- The optional query parameters.
      */
     pub async fn list_for_org(
         &self,
         org: &str,
-        type_: crate::types::ReposListOrgType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -99,19 +1230,17 @@ impl Repos {
     pub async fn list_all_for_org(
         &self,
         org: &str,
-        type_: crate::types::ReposListOrgType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
+        params: &ReposListForOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -132,6 +1261,73 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists repositories for the specified organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/repos?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an organization repository.
      *
@@ -298,17 +1494,17 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListAutolinksParams` -- The optional query parameters.
      */
     pub async fn list_autolinks(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
+        params: &ReposListAutolinksParams,
     ) -> Result<Vec<crate::types::Autolink>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -366,6 +1562,58 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * This returns a list of autolinks configured for the given repository.
+     *
+     * Information about autolinks are only available to repository administrators.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_all_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Autolink> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Autolink>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Autolink>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an autolink reference for a repository.
      *
@@ -562,27 +1810,23 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `protected: bool` -- Setting to `true` returns only protected branches. When set to `false`, only unprotected branches are returned. Omitting this parameter returns all branches.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListBranchesParams` -- The optional query parameters.
      */
     pub async fn list_branches(
         &self,
         owner: &str,
         repo: &str,
-        protected: bool,
-        per_page: i64,
-        page: i64,
+        params: &ReposListBranchesParams,
     ) -> Result<Vec<crate::types::ShortBranch>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if protected {
-            query_args.push(("protected".to_string(), protected.to_string()));
+        if let Some(v) = &params.protected {
+            query_args.push(("protected".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -619,11 +1863,11 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        protected: bool,
+        params: &ReposListBranchesParams,
     ) -> Result<Vec<crate::types::ShortBranch>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if protected {
-            query_args.push(("protected".to_string(), protected.to_string()));
+        if let Some(v) = &params.protected {
+            query_args.push(("protected".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -645,6 +1889,65 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_all_branches`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_all_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ShortBranch> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.protected {
+            query_args.push(("protected".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ShortBranch>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::ShortBranch>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a branch.
      *
@@ -1357,18 +2660,57 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
      *
-     * As opposed to `get_all_status_check_contexts`, this function returns all the pages of the request at once.
+     * As opposed to `get_all_status_check_contexts`, this function returns all the pages of the request at once.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub async fn get_all_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<String>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
      *
      * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
      */
-    pub async fn get_all_all_status_check_contexts(
+    pub fn get_all_all_status_check_contexts_stream(
         &self,
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<Vec<String>> {
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<String> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1378,15 +2720,28 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<String>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<String>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * Set status check contexts.
@@ -1677,6 +3032,60 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the GitHub Apps that have push access to this branch. Only installed GitHub Apps with `write` access to the `contents` permission can be added as authorized actors on a protected branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GitHubApp> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GitHubApp>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::GitHubApp>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Set app access restrictions.
      *
@@ -1898,6 +3307,60 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the teams who have push access to this branch. The list includes child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Set team access restrictions.
      *
@@ -2119,6 +3582,60 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the people who have push access to this branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Set user access restrictions.
      *
@@ -2330,30 +3847,23 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `affiliation: crate::types::Affiliation` -- Filters the collaborators by their affiliation. Can be one of:  
-     *  \\* `outside`: Outside collaborators of a project that are not a member of the project's organization.  
-     *  \\* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
-     *  \\* `all`: All collaborators the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCollaboratorsParams` -- The optional query parameters.
      */
     pub async fn list_collaborators(
         &self,
         owner: &str,
         repo: &str,
-        affiliation: crate::types::Affiliation,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCollaboratorsParams,
     ) -> Result<Vec<crate::types::Collaborator>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2392,11 +3902,11 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        affiliation: crate::types::Affiliation,
+        params: &ReposListCollaboratorsParams,
     ) -> Result<Vec<crate::types::Collaborator>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2418,6 +3928,67 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Collaborator> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Collaborator>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Collaborator>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check if a user is a repository collaborator.
      *
@@ -2596,22 +4167,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitCommentsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_commit_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCommitCommentsForRepoParams,
     ) -> Result<Vec<crate::types::CommitComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2669,6 +4238,64 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Commit Comments use [these custom media types](https://docs.github.com/rest/reference/repos#custom-media-types). You can read more about the use of media types in the API [here](https://docs.github.com/rest/overview/media-types/).
+     *
+     * Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_all_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CommitComment> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CommitComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CommitComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a commit comment.
      *
@@ -2830,46 +4457,34 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sha: &str` -- SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
-     * * `path: &str` -- Only commits containing this file path will be returned.
-     * * `author: &str` -- GitHub login or email address by which to filter by commit author.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `until: chrono::DateTime<chrono::Utc>` -- Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitsParams` -- The optional query parameters.
      */
     pub async fn list_commits(
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        path: &str,
-        author: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        until: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCommitsParams,
     ) -> Result<Vec<crate::types::CommitDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+        if let Some(v) = &params.author {
+            query_args.push(("author".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+        if let Some(v) = &params.path {
+            query_args.push(("path".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if let Some(date) = until {
+        if let Some(date) = params.until {
             query_args.push(("until".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2934,26 +4549,22 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        path: &str,
-        author: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        until: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReposListCommitsParams,
     ) -> Result<Vec<crate::types::CommitDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+        if let Some(v) = &params.author {
+            query_args.push(("author".to_string(), v.to_string()));
         }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+        if let Some(v) = &params.path {
+            query_args.push(("path".to_string(), v.to_string()));
         }
-        if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if let Some(date) = until {
+        if let Some(date) = params.until {
             query_args.push(("until".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2976,6 +4587,108 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CommitDataType> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = params.until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CommitDataType>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CommitDataType>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List branches for HEAD commit.
      *
@@ -2993,7 +4706,45 @@ impl Repos {
      * * `repo: &str`
      * * `commit_sha: &str` -- commit_sha parameter.
      */
-    pub async fn list_branches_for_head_commit(
+    pub async fn list_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::BranchShort>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/branches-where-head",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     *
+     * As opposed to `list_branches_for_head_commit`, this function returns all the pages of the request at once.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Returns all branches where the given commit SHA is the HEAD, or latest commit for the branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub async fn list_all_branches_for_head_commit(
         &self,
         owner: &str,
         repo: &str,
@@ -3009,7 +4760,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -3023,7 +4774,9 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
      *
-     * As opposed to `list_branches_for_head_commit`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
      *
      * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
@@ -3031,12 +4784,13 @@ impl Repos {
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
      */
-    pub async fn list_all_branches_for_head_commit(
+    pub fn list_all_branches_for_head_commit_stream(
         &self,
         owner: &str,
         repo: &str,
         commit_sha: &str,
-    ) -> Result<Vec<crate::types::BranchShort>> {
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BranchShort> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/branches-where-head",
@@ -3046,15 +4800,30 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BranchShort>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::BranchShort>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * List commit comments.
@@ -3070,23 +4839,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `commit_sha: &str` -- commit_sha parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommentsForCommitParams` -- The optional query parameters.
      */
     pub async fn list_comments_for_commit(
         &self,
         owner: &str,
         repo: &str,
         commit_sha: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCommentsForCommitParams,
     ) -> Result<Vec<crate::types::CommitComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3145,6 +4912,64 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_all_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CommitComment> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CommitComment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CommitComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a commit comment.
      *
@@ -3202,23 +5027,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `commit_sha: &str` -- commit_sha parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListPullRequestsAssociatedWithCommitParams` -- The optional query parameters.
      */
     pub async fn list_pull_requests_associated_with_commit(
         &self,
         owner: &str,
         repo: &str,
         commit_sha: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListPullRequestsAssociatedWithCommitParams,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3277,6 +5100,64 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the merged pull request that introduced the commit to the repository. If the commit is not present in the default branch, additionally returns open pull requests associated with the commit. The results may include open and closed pull requests. Additional preview headers may be required to see certain details for associated pull requests, such as whether a pull request is in a draft state. For more information about previews that might affect this endpoint, see the [List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_all_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PullRequestSimple> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PullRequestSimple>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::PullRequestSimple>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a commit.
      *
@@ -3325,24 +5206,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
      * * `ref_: &str` -- ref parameter.
+     * * `params: &ReposGetCommitParams` -- The optional query parameters.
      */
     pub async fn get_commit(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
         ref_: &str,
+        params: &ReposGetCommitParams,
     ) -> Result<crate::types::CommitDataType> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3387,23 +5266,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposGetCombinedStatusForRefParams` -- The optional query parameters.
      */
     pub async fn get_combined_status_for_ref(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposGetCombinedStatusForRefParams,
     ) -> Result<crate::types::CombinedCommitStatus> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3442,23 +5319,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitStatusesForRefParams` -- The optional query parameters.
      */
     pub async fn list_commit_statuses_for_ref(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCommitStatusesForRefParams,
     ) -> Result<Vec<crate::types::StatusData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3519,6 +5394,60 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Users with pull access in a repository can view commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name. Statuses are returned in reverse chronological order. The first status in the list will be the latest one.
+     *
+     * This resource is also available via a legacy route: `GET /repos/:owner/:repo/statuses/:ref`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_all_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::StatusData> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::StatusData>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::StatusData>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get community profile metrics.
      *
@@ -3619,24 +5548,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
      * * `basehead: &str` -- The base branch and head branch to compare. This parameter expects the format `{base}...{head}`.
+     * * `params: &ReposCompareCommitsParams` -- The optional query parameters.
      */
     pub async fn compare_commits(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
         basehead: &str,
+        params: &ReposCompareCommitsParams,
     ) -> Result<crate::types::CommitComparison> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3704,18 +5631,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The optional query parameters.
      */
     pub async fn get_content_vec_entries(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<Vec<crate::types::Entries>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3783,18 +5710,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The optional query parameters.
      */
     pub async fn get_content_file(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3862,18 +5789,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The optional query parameters.
      */
     pub async fn get_content_symlink(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::SymlinkContent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3941,18 +5868,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The optional query parameters.
      */
     pub async fn get_content_submodule(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ContentSubmodule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4020,18 +5947,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The optional query parameters.
      */
     pub async fn get_content(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ReposGetContentResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4157,27 +6084,23 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `anon: &str` -- Set to `1` or `true` to include anonymous contributors in results.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListContributorsParams` -- The optional query parameters.
      */
     pub async fn list_contributors(
         &self,
         owner: &str,
         repo: &str,
-        anon: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListContributorsParams,
     ) -> Result<Vec<crate::types::Contributor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !anon.is_empty() {
-            query_args.push(("anon".to_string(), anon.to_string()));
+        if let Some(v) = &params.anon {
+            query_args.push(("anon".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4216,11 +6139,11 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        anon: &str,
+        params: &ReposListContributorsParams,
     ) -> Result<Vec<crate::types::Contributor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !anon.is_empty() {
-            query_args.push(("anon".to_string(), anon.to_string()));
+        if let Some(v) = &params.anon {
+            query_args.push(("anon".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4242,6 +6165,67 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists contributors to the specified repository and sorts them by the number of commits per contributor in descending order. This endpoint may return information that is a few hours old because the GitHub REST API v3 caches contributor data to improve performance.
+     *
+     * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_all_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Contributor> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.anon {
+            query_args.push(("anon".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/contributors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Contributor>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Contributor>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List deployments.
      *
@@ -4255,42 +6239,32 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sha: &str` -- The SHA recorded at creation time.
-     * * `ref_: &str` -- The name of the ref. This can be a branch, tag, or SHA.
-     * * `task: &str` -- The name of the task for the deployment (e.g., `deploy` or `deploy:migrations`).
-     * * `environment: &str` -- The name of the environment that was deployed to (e.g., `staging` or `production`).
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeploymentsParams` -- The optional query parameters.
      */
     pub async fn list_deployments(
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        ref_: &str,
-        task: &str,
-        environment: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeploymentsParams,
     ) -> Result<Vec<crate::types::Deployment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !environment.is_empty() {
-            query_args.push(("environment".to_string(), environment.to_string()));
+        if let Some(v) = &params.environment {
+            query_args.push(("environment".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
         }
-        if !task.is_empty() {
-            query_args.push(("task".to_string(), task.to_string()));
+        if let Some(v) = &params.task {
+            query_args.push(("task".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4327,23 +6301,20 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        ref_: &str,
-        task: &str,
-        environment: &str,
+        params: &ReposListDeploymentsParams,
     ) -> Result<Vec<crate::types::Deployment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !environment.is_empty() {
-            query_args.push(("environment".to_string(), environment.to_string()));
+        if let Some(v) = &params.environment {
+            query_args.push(("environment".to_string(), v.to_string()));
         }
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
-        if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
         }
-        if !task.is_empty() {
-            query_args.push(("task".to_string(), task.to_string()));
+        if let Some(v) = &params.task {
+            query_args.push(("task".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4365,6 +6336,72 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Simple filtering of deployments is available via query parameters:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_all_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Deployment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.environment {
+            query_args.push(("environment".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sha {
+            query_args.push(("sha".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.task {
+            query_args.push(("task".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Deployment>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Deployment>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a deployment.
      *
@@ -4548,23 +6585,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `deployment_id: i64` -- deployment_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeploymentStatusesParams` -- The optional query parameters.
      */
     pub async fn list_deployment_statuses(
         &self,
         owner: &str,
         repo: &str,
         deployment_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeploymentStatusesParams,
     ) -> Result<Vec<crate::types::DeploymentStatus>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4623,6 +6658,64 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Users with pull access can view deployment statuses for a deployment:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_all_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DeploymentStatus> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::DeploymentStatus>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::DeploymentStatus>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a deployment status.
      *
@@ -4935,27 +7028,23 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::ReposListForksSort` -- The sort order. Can be either `newest`, `oldest`, or `stargazers`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForksParams` -- The optional query parameters.
      */
     pub async fn list_forks(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::ReposListForksSort,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForksParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -4992,11 +7081,11 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::ReposListForksSort,
+        params: &ReposListForksParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5018,6 +7107,69 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/forks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a fork.
      *
@@ -5071,22 +7223,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListWebhooksParams` -- The optional query parameters.
      */
     pub async fn list_webhooks(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListWebhooksParams,
     ) -> Result<Vec<crate::types::Hook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5123,7 +7273,44 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<Vec<crate::types::Hook>> {
+    ) -> Result<Vec<crate::types::Hook>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Hook> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks",
@@ -5132,15 +7319,28 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Hook>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Hook>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * Create a repository webhook.
@@ -5396,23 +7596,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `hook_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &ReposListWebhookDeliveriesParams` -- The optional query parameters.
      */
     pub async fn list_webhook_deliveries(
         &self,
         owner: &str,
         repo: &str,
         hook_id: i64,
-        per_page: i64,
-        cursor: &str,
+        params: &ReposListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5451,11 +7649,11 @@ impl Repos {
         owner: &str,
         repo: &str,
         hook_id: i64,
-        cursor: &str,
+        params: &ReposListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5478,6 +7676,71 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::HookDeliveryItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::HookDeliveryItem>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::HookDeliveryItem>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a delivery for a repository webhook.
      *
@@ -5649,22 +7912,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListInvitationsParams` -- The optional query parameters.
      */
     pub async fn list_invitations(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListInvitationsParams,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5720,6 +7981,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * When authenticating as a user with admin rights to a repository, this endpoint will list all currently open repository invitations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_all_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::RepositoryInvitation> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/invitations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::RepositoryInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::RepositoryInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete a repository invitation.
      *
@@ -5814,22 +8131,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeployKeysParams` -- The optional query parameters.
      */
     pub async fn list_deploy_keys(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeployKeysParams,
     ) -> Result<Vec<crate::types::DeployKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -5885,6 +8200,56 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_all_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DeployKey> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::DeployKey>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::DeployKey>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a deploy key.
      *
@@ -6224,22 +8589,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListPagesBuildsParams` -- The optional query parameters.
      */
     pub async fn list_pages_builds(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListPagesBuildsParams,
     ) -> Result<Vec<crate::types::PageBuild>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -6295,6 +8658,56 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_all_pages_builds`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_all_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PageBuild> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PageBuild>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::PageBuild>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Request a GitHub Pages build.
      *
@@ -6467,17 +8880,17 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetReadmeParams` -- The optional query parameters.
      */
     pub async fn get_readme(
         &self,
         owner: &str,
         repo: &str,
-        ref_: &str,
+        params: &ReposGetReadmeParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -6515,18 +8928,18 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `dir: &str` -- The alternate path to look for a README file.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetReadmeInDirectoryParams` -- The optional query parameters.
      */
     pub async fn get_readme_in_directory(
         &self,
         owner: &str,
         repo: &str,
         dir: &str,
-        ref_: &str,
+        params: &ReposGetReadmeInDirectoryParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+        if let Some(v) = &params.ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -6564,22 +8977,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListReleasesParams` -- The optional query parameters.
      */
     pub async fn list_releases(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListReleasesParams,
     ) -> Result<Vec<crate::types::Release>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -6637,6 +9048,58 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_all_releases`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * This returns a list of releases, which does not include regular Git tags that have not been associated with a release. To get a list of Git tags, use the [Repository Tags API](https://docs.github.com/rest/reference/repos#list-repository-tags).
+     *
+     * Information about published releases are available to everyone. Only users with push access will receive listings for draft releases.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_all_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Release> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Release>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Release>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a release.
      *
@@ -7002,23 +9465,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `release_id: i64` -- release_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListReleaseAssetsParams` -- The optional query parameters.
      */
     pub async fn list_release_assets(
         &self,
         owner: &str,
         repo: &str,
         release_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ReposListReleaseAssetsParams,
     ) -> Result<Vec<crate::types::ReleaseAsset>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -7077,6 +9538,60 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_all_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ReleaseAsset> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ReleaseAsset>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::ReleaseAsset>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Upload a release asset.
      *
@@ -7109,7 +9624,7 @@ impl Repos {
      * * `repo: &str`
      * * `release_id: i64` -- release_id parameter.
      * * `name: &str`
-     * * `label: &str`
+     * * `params: &ReposUploadReleaseAssetParams` -- The optional query parameters.
      */
     pub async fn upload_release_asset<B: Into<reqwest::Body>>(
         &self,
@@ -7117,16 +9632,16 @@ impl Repos {
         repo: &str,
         release_id: i64,
         name: &str,
-        label: &str,
+        params: &ReposUploadReleaseAssetParams,
         body: B,
     ) -> Result<crate::types::ReleaseAsset> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !label.is_empty() {
-            query_args.push(("label".to_string(), label.to_string()));
-        }
         if !name.is_empty() {
             query_args.push(("name".to_string(), name.to_string()));
         }
+        if let Some(v) = &params.label {
+            query_args.push(("label".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -7215,6 +9730,56 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns a weekly aggregate of the number of additions and deletions pushed to a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_all_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<Vec<i64>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/code_frequency",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<Vec<i64>>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<Vec<i64>>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get the last year of commit activity.
      *
@@ -7286,6 +9851,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns the last year of commit activity grouped by week. The `days` array is a group of commits per day, starting on `Sunday`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_all_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CommitActivity> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/commit_activity",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CommitActivity>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CommitActivity>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get all contributor commit activity.
      *
@@ -7306,7 +9927,47 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      */
-    pub async fn get_contributors_stats(
+    pub async fn get_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContributorActivity>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Get all contributor commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_contributors_stats`, this function returns all the pages of the request at once.
+     *
+     *
+     * Returns the `total` number of commits authored by the contributor. In addition, the response includes a Weekly Hash (`weeks` array) with the following information:
+     *
+     * *   `w` - Start of the week, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     * *   `a` - Number of additions
+     * *   `d` - Number of deletions
+     * *   `c` - Number of commits
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub async fn get_all_contributors_stats(
         &self,
         owner: &str,
         repo: &str,
@@ -7320,7 +9981,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -7334,7 +9995,9 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
      *
-     * As opposed to `get_contributors_stats`, this function returns all the pages of the request at once.
+     * As opposed to `get_all_contributors_stats`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
      *
      *
      * Returns the `total` number of commits authored by the contributor. In addition, the response includes a Weekly Hash (`weeks` array) with the following information:
@@ -7346,11 +10009,12 @@ impl Repos {
      *
      * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
      */
-    pub async fn get_all_contributors_stats(
+    pub fn get_all_contributors_stats_stream(
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<Vec<crate::types::ContributorActivity>> {
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ContributorActivity> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/contributors",
@@ -7359,15 +10023,34 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ContributorActivity>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ContributorActivity>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * Get the weekly commit count.
@@ -7483,6 +10166,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Each array contains the day number, hour number, and number of commits:
+     *
+     * *   `0-6`: Sunday - Saturday
+     * *   `0-23`: Hour of day
+     * *   Number of commits
+     *
+     * For example, `[2, 14, 25]` indicates that there were 25 total commits, during the 2:00pm hour on Tuesdays. All times are based on the time zone of individual commits.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_all_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<Vec<i64>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/punch_card",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<Vec<i64>>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<Vec<i64>>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a commit status.
      *
@@ -7539,22 +10278,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListTagsParams` -- The optional query parameters.
      */
     pub async fn list_tags(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListTagsParams,
     ) -> Result<Vec<crate::types::Tag>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -7606,6 +10343,56 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_all_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Tag> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Tag>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Tag>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Download a repository archive (tar).
      *
@@ -7629,7 +10416,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         ref_: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/tarball/{}",
@@ -7640,13 +10427,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -7662,22 +10443,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListTeamsParams` -- The optional query parameters.
      */
     pub async fn list_teams(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListTeamsParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -7729,6 +10508,56 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_all_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get all repository topics.
      *
@@ -7742,22 +10571,20 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ReposGetAllTopicsParams` -- The optional query parameters.
      */
     pub async fn get_all_topics(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
+        params: &ReposGetAllTopicsParams,
     ) -> Result<crate::types::Topic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -7830,17 +10657,17 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per: crate::types::Per` -- Must be one of: `day`, `week`.
+     * * `params: &ReposGetClonesParams` -- The optional query parameters.
      */
     pub async fn get_clones(
         &self,
         owner: &str,
         repo: &str,
-        per: crate::types::Per,
+        params: &ReposGetClonesParams,
     ) -> Result<crate::types::CloneTraffic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+        if let Some(v) = &params.per {
+            query_args.push(("per".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -7933,6 +10760,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get the top 10 popular contents over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_all_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ContentTraffic> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/paths",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ContentTraffic>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ContentTraffic>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get top referral sources.
      *
@@ -8004,6 +10887,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get the top 10 referrers over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_all_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ReferrerTraffic> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/referrers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ReferrerTraffic>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ReferrerTraffic>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get page views.
      *
@@ -8017,17 +10956,17 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per: crate::types::Per` -- Must be one of: `day`, `week`.
+     * * `params: &ReposGetViewsParams` -- The optional query parameters.
      */
     pub async fn get_views(
         &self,
         owner: &str,
         repo: &str,
-        per: crate::types::Per,
+        params: &ReposGetViewsParams,
     ) -> Result<crate::types::ViewTraffic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+        if let Some(v) = &params.per {
+            query_args.push(("per".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -8209,7 +11148,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         ref_: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/zipball/{}",
@@ -8220,13 +11159,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -8289,12 +11222,15 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `since: i64` -- A repository ID. Only return repositories with an ID greater than this ID.
+     * * `params: &ReposListPublicParams` -- The optional query parameters.
      */
-    pub async fn list_public(&self, since: i64) -> Result<Vec<crate::types::MinimalRepository>> {
+    pub async fn list_public(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/repositories?{}", query_), None);
@@ -8325,11 +11261,11 @@ impl Repos {
      */
     pub async fn list_all_public(
         &self,
-        since: i64,
+        params: &ReposListPublicParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/repositories?{}", query_), None);
@@ -8343,6 +11279,63 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all public repositories in the order that they were created.
+     *
+     * Note:
+     * - For GitHub Enterprise Server, this endpoint will only list repositories available to all users on the enterprise.
+     * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        params: &ReposListPublicParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/repositories?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List repositories for the authenticated user.
      *
@@ -8356,62 +11349,39 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `visibility: crate::types::ReposListVisibility` -- Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
-     * * `affiliation: &str` -- Comma-separated list of values. Can include:  
-     *   \* `owner`: Repositories that are owned by the authenticated user.  
-     *   \* `collaborator`: Repositories that the user has been added to as a collaborator.  
-     *   \* `organization_member`: Repositories that the user has access to through being a member of an organization. This includes every repository on every team that the user is on.
-     * * `type_: crate::types::ReposListType` -- Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
-     *    
-     *  Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*. Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `params: &ReposListForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_for_authenticated_user(
         &self,
-        visibility: crate::types::ReposListVisibility,
-        affiliation: &str,
-        type_: crate::types::ReposListType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReposListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
-        if !visibility.to_string().is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+        if let Some(v) = &params.visibility {
+            query_args.push(("visibility".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/repos?{}", query_), None);
@@ -8440,35 +11410,29 @@ impl Repos {
      */
     pub async fn list_all_for_authenticated_user(
         &self,
-        visibility: crate::types::ReposListVisibility,
-        affiliation: &str,
-        type_: crate::types::ReposListType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReposListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
         }
-        if let Some(date) = before {
+        if let Some(date) = params.before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if let Some(date) = since {
+        if let Some(date) = params.since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
-        if !visibility.to_string().is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+        if let Some(v) = &params.visibility {
+            query_args.push(("visibility".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/repos?{}", query_), None);
@@ -8482,6 +11446,73 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Repository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(date) = params.since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.visibility {
+            query_args.push(("visibility".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/repos?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Repository>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Repository>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a repository for the authenticated user.
      *
@@ -8524,20 +11555,18 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListInvitationsForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_invitations_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ReposListInvitationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -8578,6 +11607,52 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * When authenticating as a user, this endpoint will list all currently open repository invitations for that user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_all_invitations_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::RepositoryInvitation> {
+        let url = self.client.url("/user/repository_invitations", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::RepositoryInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::RepositoryInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Decline a repository invitation.
      *
@@ -8652,38 +11727,28 @@ impl Repos {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `type_: crate::types::ReposListUserType` -- Can be one of `all`, `owner`, `member`.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForUserParams` -- The optional query parameters.
      */
     pub async fn list_for_user(
         &self,
         username: &str,
-        type_: crate::types::ReposListUserType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -8718,19 +11783,17 @@ impl Repos {
     pub async fn list_all_for_user(
         &self,
         username: &str,
-        type_: crate::types::ReposListUserType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
+        params: &ReposListForUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -8751,4 +11814,71 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/repos?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -2,6 +2,47 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Scim::list_provisioned_identities`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ScimListProvisionedIdentitiesParams {
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<String>,
+}
+
+impl ScimListProvisionedIdentitiesParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Used for pagination: the index of the first result to return.
+    pub fn start_index(mut self, value: i64) -> Self {
+        self.start_index = Some(value);
+        self
+    }
+
+    /// Used for pagination: the number of results to return.
+    pub fn count(mut self, value: i64) -> Self {
+        self.count = Some(value);
+        self
+    }
+
+    /// Filters results using the equals query parameter operator (`eq`). You can filter results that are equal to `id`, `userName`, `emails`, and `external_id`. For example, to search for an identity with the `userName` Octocat, you would use this query:
+    ///
+    /// `?filter=userName%20eq%20\"Octocat\"`.
+    ///
+    /// To filter results for the identity with the email `octocat@github.com`, you would use this query:
+    ///
+    /// `?filter=emails%20eq%20\"octocat@github.com\"`.
+    pub fn filter(mut self, value: impl Into<String>) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+}
+
 pub struct Scim {
     pub client: Client,
 }
@@ -39,32 +80,22 @@ impl Scim {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- Filters results using the equals query parameter operator (`eq`). You can filter results that are equal to `id`, `userName`, `emails`, and `external_id`. For example, to search for an identity with the `userName` Octocat, you would use this query:
-     *   
-     *   `?filter=userName%20eq%20\"Octocat\"`.
-     *   
-     *   To filter results for the identity with the email `octocat@github.com`, you would use this query:
-     *   
-     *   `?filter=emails%20eq%20\"octocat@github.com\"`.
+     * * `params: &ScimListProvisionedIdentitiesParams` -- The optional query parameters.
      */
     pub async fn list_provisioned_identities(
         &self,
         org: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
+        params: &ScimListProvisionedIdentitiesParams,
     ) -> Result<crate::types::ScimUserList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+        if let Some(v) = &params.count {
+            query_args.push(("count".to_string(), v.to_string()));
         }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+        if let Some(v) = &params.filter {
+            query_args.push(("filter".to_string(), v.to_string()));
         }
-        if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+        if let Some(v) = &params.start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2,6 +2,48 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`SecretScanning::list_alerts_for_repo`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct SecretScanningListAlertsForRepoParams {
+    state: Option<crate::types::SecretScanningAlertState>,
+    secret_type: Option<String>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl SecretScanningListAlertsForRepoParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set to `open` or `resolved` to only list secret scanning alerts in a specific state.
+    pub fn state(mut self, value: crate::types::SecretScanningAlertState) -> Self {
+        self.state = Some(value);
+        self
+    }
+
+    /// A comma separated list of secret types to return. By default all secret types are returned. See "[About secret scanning for private repositories](https://docs.github.com/code-security/secret-security/about-secret-scanning#about-secret-scanning-for-private-repositories)" for a complete list of secret types (API slug).
+    pub fn secret_type(mut self, value: impl Into<String>) -> Self {
+        self.secret_type = Some(value.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+}
+
 pub struct SecretScanning {
     pub client: Client,
 }
@@ -27,32 +69,26 @@ impl SecretScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::SecretScanningAlertState` -- Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
-     * * `secret_type: &str` -- A comma separated list of secret types to return. By default all secret types are returned. See "[About secret scanning for private repositories](https://docs.github.com/code-security/secret-security/about-secret-scanning#about-secret-scanning-for-private-repositories)" for a complete list of secret types (API slug).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &SecretScanningListAlertsForRepoParams` -- The optional query parameters.
      */
     pub async fn list_alerts_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::SecretScanningAlertState,
-        secret_type: &str,
-        page: i64,
-        per_page: i64,
+        params: &SecretScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::SecretScanningAlert>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !secret_type.is_empty() {
-            query_args.push(("secret_type".to_string(), secret_type.to_string()));
+        if let Some(v) = &params.secret_type {
+            query_args.push(("secret_type".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -91,15 +127,14 @@ impl SecretScanning {
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::SecretScanningAlertState,
-        secret_type: &str,
+        params: &SecretScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::SecretScanningAlert>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !secret_type.is_empty() {
-            query_args.push(("secret_type".to_string(), secret_type.to_string()));
+        if let Some(v) = &params.secret_type {
+            query_args.push(("secret_type".to_string(), v.to_string()));
         }
-        if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -121,6 +156,74 @@ impl SecretScanning {
             )
             .await
     }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all secret scanning alerts for a private repository, from newest to oldest. To use this endpoint, you must be an administrator for the repository or organization, and you must use an access token with the `repo` scope or `security_events` scope.
+     *
+     * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SecretScanningAlert> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.secret_type {
+            query_args.push(("secret_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/secret-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SecretScanningAlert>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SecretScanningAlert>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a secret scanning alert.
      *
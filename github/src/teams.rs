@@ -2,6 +2,537 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Teams::list_idp_groups_for_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListIdpGroupsForOrgParams {
+    per_page: Option<i64>,
+    page: Option<String>,
+}
+
+impl TeamsListIdpGroupsForOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page token
+    pub fn page(mut self, value: impl Into<String>) -> Self {
+        self.page = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_discussions_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListDiscussionsInOrgParams {
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    pinned: Option<String>,
+}
+
+impl TeamsListDiscussionsInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    /// Pinned discussions only filter
+    pub fn pinned(mut self, value: impl Into<String>) -> Self {
+        self.pinned = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_discussion_comments_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListDiscussionCommentsInOrgParams {
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListDiscussionCommentsInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_pending_invitations_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListPendingInvitationsInOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListPendingInvitationsInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_members_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListMembersInOrgParams {
+    role: Option<crate::types::TeamsListMembersInOrgRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListMembersInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters members returned by their role in the team. Can be one of:  
+    /// \* `member` - normal members of the team.  
+    /// \* `maintainer` - team maintainers.  
+    /// \* `all` - all members of the team.
+    pub fn role(mut self, value: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.role = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_projects_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListProjectsInOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListProjectsInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_repos_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListReposInOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListReposInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_child_in_org`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListChildInOrgParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListChildInOrgParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_discussions_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListDiscussionsLegacyParams {
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListDiscussionsLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_discussion_comments_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListDiscussionCommentsLegacyParams {
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListDiscussionCommentsLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// One of `asc` (ascending) or `desc` (descending).
+    pub fn direction(mut self, value: crate::types::Order) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_pending_invitations_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListPendingInvitationsLegacyParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListPendingInvitationsLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_members_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListMembersLegacyParams {
+    role: Option<crate::types::TeamsListMembersInOrgRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListMembersLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Filters members returned by their role in the team. Can be one of:  
+    /// \* `member` - normal members of the team.  
+    /// \* `maintainer` - team maintainers.  
+    /// \* `all` - all members of the team.
+    pub fn role(mut self, value: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.role = Some(value);
+        self
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_projects_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListProjectsLegacyParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListProjectsLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_repos_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListReposLegacyParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListReposLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_child_legacy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListChildLegacyParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListChildLegacyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teams::list_for_authenticated_user`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamsListForAuthenticatedUserParams {
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl TeamsListForAuthenticatedUserParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Results per page (max 100)
+    pub fn per_page(mut self, value: i64) -> Self {
+        self.per_page = Some(value);
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, value: i64) -> Self {
+        self.page = Some(value);
+        self
+    }
+}
+
 pub struct Teams {
     pub client: Client,
 }
@@ -28,21 +559,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: &str` -- Page token.
+     * * `params: &TeamsListIdpGroupsForOrgParams` -- The optional query parameters.
      */
     pub async fn list_idp_groups_for_org(
         &self,
         org: &str,
-        per_page: i64,
-        page: &str,
+        params: &TeamsListIdpGroupsForOrgParams,
     ) -> Result<crate::types::GroupMapping> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -75,21 +604,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -139,6 +666,54 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all teams in an organization that are visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_all_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a team.
      *
@@ -303,34 +878,26 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `pinned: &str` -- Pinned discussions only filter.
+     * * `params: &TeamsListDiscussionsInOrgParams` -- The optional query parameters.
      */
     pub async fn list_discussions_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
-        pinned: &str,
+        params: &TeamsListDiscussionsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !pinned.is_empty() {
-            query_args.push(("pinned".to_string(), pinned.to_string()));
+        if let Some(v) = &params.pinned {
+            query_args.push(("pinned".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -369,15 +936,14 @@ impl Teams {
         &self,
         org: &str,
         team_slug: &str,
-        direction: crate::types::Order,
-        pinned: &str,
+        params: &TeamsListDiscussionsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if !pinned.is_empty() {
-            query_args.push(("pinned".to_string(), pinned.to_string()));
+        if let Some(v) = &params.pinned {
+            query_args.push(("pinned".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -399,6 +965,74 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_all_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamDiscussion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.pinned {
+            query_args.push(("pinned".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamDiscussion>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::TeamDiscussion>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a discussion.
      *
@@ -584,30 +1218,24 @@ impl Teams {
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionCommentsInOrgParams` -- The optional query parameters.
      */
     pub async fn list_discussion_comments_in_org(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -648,11 +1276,11 @@ impl Teams {
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionCommentsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -675,6 +1303,73 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_all_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamDiscussionComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamDiscussionComment>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::TeamDiscussionComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a discussion comment.
      *
@@ -871,22 +1566,20 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListPendingInvitationsInOrgParams` -- The optional query parameters.
      */
     pub async fn list_pending_invitations_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListPendingInvitationsInOrgParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -944,6 +1637,64 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/invitations`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_all_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationInvitation> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List team members.
      *
@@ -959,30 +1710,23 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `role: crate::types::TeamsListMembersInOrgRole` -- Filters members returned by their role in the team. Can be one of:  
-     *  \\* `member` - normal members of the team.  
-     *  \\* `maintainer` - team maintainers.  
-     *  \\* `all` - all members of the team.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListMembersInOrgParams` -- The optional query parameters.
      */
     pub async fn list_members_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        role: crate::types::TeamsListMembersInOrgRole,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListMembersInOrgParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1021,11 +1765,11 @@ impl Teams {
         &self,
         org: &str,
         team_slug: &str,
-        role: crate::types::TeamsListMembersInOrgRole,
+        params: &TeamsListMembersInOrgParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1047,6 +1791,65 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_all_members_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Team members will include the members of child teams.
+     *
+     * To list members in a team, the team must be visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_all_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get team membership for a user.
      *
@@ -1208,22 +2011,20 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListProjectsInOrgParams` -- The optional query parameters.
      */
     pub async fn list_projects_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListProjectsInOrgParams,
     ) -> Result<Vec<crate::types::TeamProject>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1262,7 +2063,46 @@ impl Teams {
         &self,
         org: &str,
         team_slug: &str,
-    ) -> Result<Vec<crate::types::TeamProject>> {
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/projects",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the organization projects for a team.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/projects`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_all_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamProject> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/projects",
@@ -1271,15 +2111,30 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamProject>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::TeamProject>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
     }
     /**
      * Check team permissions for a project.
@@ -1423,22 +2278,20 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListReposInOrgParams` -- The optional query parameters.
      */
     pub async fn list_repos_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListReposInOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1496,6 +2349,64 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a team's repositories visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/repos`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_all_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/repos",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check team permissions for a repository.
      *
@@ -1736,22 +2647,20 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListChildInOrgParams` -- The optional query parameters.
      */
     pub async fn list_child_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListChildInOrgParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1809,6 +2718,58 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_in_org`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_all_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a team (Legacy).
      *
@@ -1928,28 +2889,22 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionsLegacyParams` -- The optional query parameters.
      */
     pub async fn list_discussions_legacy(
         &self,
         team_id: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1986,11 +2941,11 @@ impl Teams {
     pub async fn list_all_discussions_legacy(
         &self,
         team_id: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2011,6 +2966,69 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List discussions`](https://docs.github.com/rest/reference/teams#list-discussions) endpoint.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_all_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamDiscussion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamDiscussion>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::TeamDiscussion>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a discussion (Legacy).
      *
@@ -2183,29 +3201,23 @@ impl Teams {
      *
      * * `team_id: i64`
      * * `discussion_number: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionCommentsLegacyParams` -- The optional query parameters.
      */
     pub async fn list_discussion_comments_legacy(
         &self,
         team_id: i64,
         discussion_number: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2244,11 +3256,11 @@ impl Teams {
         &self,
         team_id: i64,
         discussion_number: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionCommentsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2270,6 +3282,71 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List discussion comments](https://docs.github.com/rest/reference/teams#list-discussion-comments) endpoint.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_all_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamDiscussionComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamDiscussionComment>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::TeamDiscussionComment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a discussion comment (Legacy).
      *
@@ -2453,21 +3530,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListPendingInvitationsLegacyParams` -- The optional query parameters.
      */
     pub async fn list_pending_invitations_legacy(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListPendingInvitationsLegacyParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2522,6 +3597,62 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List pending team invitations`](https://docs.github.com/rest/reference/teams#list-pending-team-invitations) endpoint.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_all_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrganizationInvitation> {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/invitations",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrganizationInvitation>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrganizationInvitation>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List team members (Legacy).
      *
@@ -2536,29 +3667,22 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `role: crate::types::TeamsListMembersInOrgRole` -- Filters members returned by their role in the team. Can be one of:  
-     *  \\* `member` - normal members of the team.  
-     *  \\* `maintainer` - team maintainers.  
-     *  \\* `all` - all members of the team.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListMembersLegacyParams` -- The optional query parameters.
      */
     pub async fn list_members_legacy(
         &self,
         team_id: i64,
-        role: crate::types::TeamsListMembersInOrgRole,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListMembersLegacyParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2595,11 +3719,11 @@ impl Teams {
     pub async fn list_all_members_legacy(
         &self,
         team_id: i64,
-        role: crate::types::TeamsListMembersInOrgRole,
+        params: &TeamsListMembersLegacyParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2620,6 +3744,63 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team members`](https://docs.github.com/rest/reference/teams#list-team-members) endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_all_members_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SimpleUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SimpleUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get team member (Legacy).
      *
@@ -2892,21 +4073,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListProjectsLegacyParams` -- The optional query parameters.
      */
     pub async fn list_projects_legacy(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListProjectsLegacyParams,
     ) -> Result<Vec<crate::types::TeamProject>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2961,6 +4140,58 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_all_projects_legacy_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamProject> {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/projects",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TeamProject>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::TeamProject>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check team permissions for a project (Legacy).
      *
@@ -3087,21 +4318,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListReposLegacyParams` -- The optional query parameters.
      */
     pub async fn list_repos_legacy(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListReposLegacyParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3154,6 +4383,60 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List team repositories](https://docs.github.com/rest/reference/teams#list-team-repositories) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_all_repos_legacy_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MinimalRepository> {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/repos",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::MinimalRepository>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::MinimalRepository>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Check team permissions for a repository (Legacy).
      *
@@ -3369,21 +4652,19 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListChildLegacyParams` -- The optional query parameters.
      */
     pub async fn list_child_legacy(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListChildLegacyParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -3433,6 +4714,54 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List child teams`](https://docs.github.com/rest/reference/teams#list-child-teams) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_all_child_legacy_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Team> {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/teams",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Team>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Team>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * List teams for the authenticated user.
      *
@@ -3444,20 +4773,18 @@ impl Teams {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListForAuthenticatedUserParams` -- The optional query parameters.
      */
     pub async fn list_for_authenticated_user(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::FullTeam>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+        if let Some(v) = &params.page {
+            query_args.push(("page".to_string(), v.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        if let Some(v) = &params.per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/teams?{}", query_), None);
@@ -3494,4 +4821,44 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::FullTeam> {
+        let url = self.client.url("/user/teams", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::FullTeam>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::FullTeam>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -845,7 +845,7 @@ pub struct Enterprise {
 /**
 * The level of permission to grant the access token to retrieve Pages statuses, configuration, and builds, as well as create new builds. Can be one of: `read` or `write`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Pages {
     #[serde(rename = "read")]
     Read,
@@ -853,8 +853,8 @@ pub enum Pages {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Pages {
@@ -863,12 +863,36 @@ impl std::fmt::Display for Pages {
             Pages::Read => "read",
             Pages::Write => "write",
             Pages::Noop => "",
-            Pages::FallthroughString => "*",
+            Pages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Pages {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Pages {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "read" => Pages::Read,
+            "write" => Pages::Write,
+            "" => Pages::Noop,
+            _ => Pages::Unknown(s),
+        })
+    }
+}
+
 impl Default for Pages {
     fn default() -> Pages {
         Pages::Noop
@@ -878,12 +902,17 @@ impl Pages {
     pub fn is_noop(&self) -> bool {
         matches!(self, Pages::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Pages::Unknown(_))
+    }
 }
 
 /**
 * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RepositoryProjects {
     #[serde(rename = "admin")]
     Admin,
@@ -893,8 +922,8 @@ pub enum RepositoryProjects {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryProjects {
@@ -904,12 +933,37 @@ impl std::fmt::Display for RepositoryProjects {
             RepositoryProjects::Read => "read",
             RepositoryProjects::Write => "write",
             RepositoryProjects::Noop => "",
-            RepositoryProjects::FallthroughString => "*",
+            RepositoryProjects::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RepositoryProjects {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositoryProjects {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => RepositoryProjects::Admin,
+            "read" => RepositoryProjects::Read,
+            "write" => RepositoryProjects::Write,
+            "" => RepositoryProjects::Noop,
+            _ => RepositoryProjects::Unknown(s),
+        })
+    }
+}
+
 impl Default for RepositoryProjects {
     fn default() -> RepositoryProjects {
         RepositoryProjects::Noop
@@ -919,19 +973,24 @@ impl RepositoryProjects {
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositoryProjects::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RepositoryProjects::Unknown(_))
+    }
 }
 
 /**
 * The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrganizationPlan {
     #[serde(rename = "read")]
     Read,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPlan {
@@ -939,12 +998,35 @@ impl std::fmt::Display for OrganizationPlan {
         match self {
             OrganizationPlan::Read => "read",
             OrganizationPlan::Noop => "",
-            OrganizationPlan::FallthroughString => "*",
+            OrganizationPlan::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrganizationPlan {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrganizationPlan {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "read" => OrganizationPlan::Read,
+            "" => OrganizationPlan::Noop,
+            _ => OrganizationPlan::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrganizationPlan {
     fn default() -> OrganizationPlan {
         OrganizationPlan::Noop
@@ -954,19 +1036,24 @@ impl OrganizationPlan {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrganizationPlan::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrganizationPlan::Unknown(_))
+    }
 }
 
 /**
 * The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Workflows {
     #[serde(rename = "write")]
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Workflows {
@@ -974,12 +1061,35 @@ impl std::fmt::Display for Workflows {
         match self {
             Workflows::Write => "write",
             Workflows::Noop => "",
-            Workflows::FallthroughString => "*",
+            Workflows::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Workflows {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Workflows {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "write" => Workflows::Write,
+            "" => Workflows::Noop,
+            _ => Workflows::Unknown(s),
+        })
+    }
+}
+
 impl Default for Workflows {
     fn default() -> Workflows {
         Workflows::Noop
@@ -989,6 +1099,11 @@ impl Workflows {
     pub fn is_noop(&self) -> bool {
         matches!(self, Workflows::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Workflows::Unknown(_))
+    }
 }
 
 /// The permissions granted to the user-to-server access token.
@@ -1173,7 +1288,7 @@ pub struct AccountAnyOf {
 /**
 * Describe whether all repositories have been selected or there's a selection involved
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RepositorySelection {
     #[serde(rename = "all")]
     All,
@@ -1181,8 +1296,8 @@ pub enum RepositorySelection {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositorySelection {
@@ -1191,12 +1306,36 @@ impl std::fmt::Display for RepositorySelection {
             RepositorySelection::All => "all",
             RepositorySelection::Selected => "selected",
             RepositorySelection::Noop => "",
-            RepositorySelection::FallthroughString => "*",
+            RepositorySelection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RepositorySelection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositorySelection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => RepositorySelection::All,
+            "selected" => RepositorySelection::Selected,
+            "" => RepositorySelection::Noop,
+            _ => RepositorySelection::Unknown(s),
+        })
+    }
+}
+
 impl Default for RepositorySelection {
     fn default() -> RepositorySelection {
         RepositorySelection::Noop
@@ -1206,6 +1345,11 @@ impl RepositorySelection {
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositorySelection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RepositorySelection::Unknown(_))
+    }
 }
 
 /// Installation
@@ -2819,7 +2963,7 @@ pub struct CodeOfConduct {
 /**
 * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EnabledRepositories {
     #[serde(rename = "all")]
     All,
@@ -2829,8 +2973,8 @@ pub enum EnabledRepositories {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledRepositories {
@@ -2840,12 +2984,37 @@ impl std::fmt::Display for EnabledRepositories {
             EnabledRepositories::None => "none",
             EnabledRepositories::Selected => "selected",
             EnabledRepositories::Noop => "",
-            EnabledRepositories::FallthroughString => "*",
+            EnabledRepositories::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EnabledRepositories {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EnabledRepositories {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => EnabledRepositories::All,
+            "none" => EnabledRepositories::None,
+            "selected" => EnabledRepositories::Selected,
+            "" => EnabledRepositories::Noop,
+            _ => EnabledRepositories::Unknown(s),
+        })
+    }
+}
+
 impl Default for EnabledRepositories {
     fn default() -> EnabledRepositories {
         EnabledRepositories::Noop
@@ -2855,12 +3024,17 @@ impl EnabledRepositories {
     pub fn is_noop(&self) -> bool {
         matches!(self, EnabledRepositories::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EnabledRepositories::Unknown(_))
+    }
 }
 
 /**
 * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AllowedActions {
     #[serde(rename = "all")]
     All,
@@ -2870,8 +3044,8 @@ pub enum AllowedActions {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedActions {
@@ -2881,12 +3055,37 @@ impl std::fmt::Display for AllowedActions {
             AllowedActions::LocalOnly => "local_only",
             AllowedActions::Selected => "selected",
             AllowedActions::Noop => "",
-            AllowedActions::FallthroughString => "*",
+            AllowedActions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AllowedActions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedActions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => AllowedActions::All,
+            "local_only" => AllowedActions::LocalOnly,
+            "selected" => AllowedActions::Selected,
+            "" => AllowedActions::Noop,
+            _ => AllowedActions::Unknown(s),
+        })
+    }
+}
+
 impl Default for AllowedActions {
     fn default() -> AllowedActions {
         AllowedActions::Noop
@@ -2896,6 +3095,11 @@ impl AllowedActions {
     pub fn is_noop(&self) -> bool {
         matches!(self, AllowedActions::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AllowedActions::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3071,7 +3275,7 @@ pub struct RunnerGroupsEnterprise {
 /**
 * The type of label. Read-only labels are applied automatically when the runner is configured.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -3079,8 +3283,8 @@ pub enum Type {
     ReadOnly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -3089,12 +3293,36 @@ impl std::fmt::Display for Type {
             Type::Custom => "custom",
             Type::ReadOnly => "read-only",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "custom" => Type::Custom,
+            "read-only" => Type::ReadOnly,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -3104,6 +3332,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3689,14 +3922,14 @@ pub struct Label {
 /**
 * The state of the milestone.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum State {
     #[serde(rename = "closed")]
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -3704,17 +3937,46 @@ impl std::fmt::Display for State {
         match self {
             State::Closed => "closed",
             State::Open => "open",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "closed" => State::Closed,
+            "open" => State::Open,
+            _ => State::Unknown(s),
+        })
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Open
     }
 }
+impl State {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, State::Unknown(_))
+    }
+}
 
 /// A collection of related issues and pull requests.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3815,7 +4077,7 @@ pub struct Milestone {
 /**
 * How the author is associated with the repository.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AuthorAssociation {
     #[serde(rename = "COLLABORATOR")]
     Collaborator,
@@ -3835,8 +4097,8 @@ pub enum AuthorAssociation {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorAssociation {
@@ -3851,12 +4113,42 @@ impl std::fmt::Display for AuthorAssociation {
             AuthorAssociation::None => "NONE",
             AuthorAssociation::Owner => "OWNER",
             AuthorAssociation::Noop => "",
-            AuthorAssociation::FallthroughString => "*",
+            AuthorAssociation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AuthorAssociation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorAssociation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COLLABORATOR" => AuthorAssociation::Collaborator,
+            "CONTRIBUTOR" => AuthorAssociation::Contributor,
+            "FIRST_TIMER" => AuthorAssociation::FirstTimer,
+            "FIRST_TIME_CONTRIBUTOR" => AuthorAssociation::FirstTimeContributor,
+            "MANNEQUIN" => AuthorAssociation::Mannequin,
+            "MEMBER" => AuthorAssociation::Member,
+            "NONE" => AuthorAssociation::None,
+            "OWNER" => AuthorAssociation::Owner,
+            "" => AuthorAssociation::Noop,
+            _ => AuthorAssociation::Unknown(s),
+        })
+    }
+}
+
 impl Default for AuthorAssociation {
     fn default() -> AuthorAssociation {
         AuthorAssociation::Noop
@@ -3866,6 +4158,11 @@ impl AuthorAssociation {
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorAssociation::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorAssociation::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7231,7 +7528,7 @@ pub struct RunnerGroupsOrg {
 /**
 * Visibility of a secret
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "all")]
     All,
@@ -7241,8 +7538,8 @@ pub enum Visibility {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -7252,12 +7549,37 @@ impl std::fmt::Display for Visibility {
             Visibility::Private => "private",
             Visibility::Selected => "selected",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => Visibility::All,
+            "private" => Visibility::Private,
+            "selected" => Visibility::Selected,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -7267,6 +7589,11 @@ impl Visibility {
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Visibility::Unknown(_))
+    }
 }
 
 /// Secrets for GitHub Actions for an organization.
@@ -7598,7 +7925,7 @@ pub struct OrgHook {
 /**
 * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InteractionGroup {
     #[serde(rename = "collaborators_only")]
     CollaboratorsOnly,
@@ -7608,8 +7935,8 @@ pub enum InteractionGroup {
     ExistingUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionGroup {
@@ -7619,12 +7946,37 @@ impl std::fmt::Display for InteractionGroup {
             InteractionGroup::ContributorsOnly => "contributors_only",
             InteractionGroup::ExistingUsers => "existing_users",
             InteractionGroup::Noop => "",
-            InteractionGroup::FallthroughString => "*",
+            InteractionGroup::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InteractionGroup {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionGroup {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "collaborators_only" => InteractionGroup::CollaboratorsOnly,
+            "contributors_only" => InteractionGroup::ContributorsOnly,
+            "existing_users" => InteractionGroup::ExistingUsers,
+            "" => InteractionGroup::Noop,
+            _ => InteractionGroup::Unknown(s),
+        })
+    }
+}
+
 impl Default for InteractionGroup {
     fn default() -> InteractionGroup {
         InteractionGroup::Noop
@@ -7634,6 +7986,11 @@ impl InteractionGroup {
     pub fn is_noop(&self) -> bool {
         matches!(self, InteractionGroup::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InteractionGroup::Unknown(_))
+    }
 }
 
 /// Interaction limit settings.
@@ -7661,7 +8018,7 @@ pub struct InteractionLimits {
 /**
 * The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InteractionExpiry {
     #[serde(rename = "one_day")]
     OneDay,
@@ -7675,8 +8032,8 @@ pub enum InteractionExpiry {
     ThreeDays,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionExpiry {
@@ -7688,12 +8045,39 @@ impl std::fmt::Display for InteractionExpiry {
             InteractionExpiry::SixMonths => "six_months",
             InteractionExpiry::ThreeDays => "three_days",
             InteractionExpiry::Noop => "",
-            InteractionExpiry::FallthroughString => "*",
+            InteractionExpiry::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InteractionExpiry {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionExpiry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "one_day" => InteractionExpiry::OneDay,
+            "one_month" => InteractionExpiry::OneMonth,
+            "one_week" => InteractionExpiry::OneWeek,
+            "six_months" => InteractionExpiry::SixMonths,
+            "three_days" => InteractionExpiry::ThreeDays,
+            "" => InteractionExpiry::Noop,
+            _ => InteractionExpiry::Unknown(s),
+        })
+    }
+}
+
 impl Default for InteractionExpiry {
     fn default() -> InteractionExpiry {
         InteractionExpiry::Noop
@@ -7703,6 +8087,11 @@ impl InteractionExpiry {
     pub fn is_noop(&self) -> bool {
         matches!(self, InteractionExpiry::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InteractionExpiry::Unknown(_))
+    }
 }
 
 /// Limit interactions to a specific type of user for a specified duration
@@ -7907,7 +8296,7 @@ pub struct Team {
 /**
 * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgMembershipState {
     #[serde(rename = "active")]
     Active,
@@ -7915,8 +8304,8 @@ pub enum OrgMembershipState {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgMembershipState {
@@ -7925,12 +8314,36 @@ impl std::fmt::Display for OrgMembershipState {
             OrgMembershipState::Active => "active",
             OrgMembershipState::Pending => "pending",
             OrgMembershipState::Noop => "",
-            OrgMembershipState::FallthroughString => "*",
+            OrgMembershipState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgMembershipState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgMembershipState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => OrgMembershipState::Active,
+            "pending" => OrgMembershipState::Pending,
+            "" => OrgMembershipState::Noop,
+            _ => OrgMembershipState::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgMembershipState {
     fn default() -> OrgMembershipState {
         OrgMembershipState::Noop
@@ -7940,12 +8353,17 @@ impl OrgMembershipState {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrgMembershipState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgMembershipState::Unknown(_))
+    }
 }
 
 /**
 * The user's membership type in the organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -7955,8 +8373,8 @@ pub enum Role {
     Member,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -7966,12 +8384,37 @@ impl std::fmt::Display for Role {
             Role::BillingManager => "billing_manager",
             Role::Member => "member",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => Role::Admin,
+            "billing_manager" => Role::BillingManager,
+            "member" => Role::Member,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        })
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -7981,6 +8424,11 @@ impl Role {
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Role::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8111,7 +8559,7 @@ pub struct Migration {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PackageType {
     #[serde(rename = "container")]
     Container,
@@ -8127,8 +8575,8 @@ pub enum PackageType {
     Rubygems,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageType {
@@ -8141,12 +8589,40 @@ impl std::fmt::Display for PackageType {
             PackageType::Nuget => "nuget",
             PackageType::Rubygems => "rubygems",
             PackageType::Noop => "",
-            PackageType::FallthroughString => "*",
+            PackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PackageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "container" => PackageType::Container,
+            "docker" => PackageType::Docker,
+            "maven" => PackageType::Maven,
+            "npm" => PackageType::Npm,
+            "nuget" => PackageType::Nuget,
+            "rubygems" => PackageType::Rubygems,
+            "" => PackageType::Noop,
+            _ => PackageType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PackageType {
     fn default() -> PackageType {
         PackageType::Noop
@@ -8156,9 +8632,14 @@ impl PackageType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PackageType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PackageVisibility {
     #[serde(rename = "private")]
     Private,
@@ -8166,8 +8647,8 @@ pub enum PackageVisibility {
     Public,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageVisibility {
@@ -8176,12 +8657,36 @@ impl std::fmt::Display for PackageVisibility {
             PackageVisibility::Private => "private",
             PackageVisibility::Public => "public",
             PackageVisibility::Noop => "",
-            PackageVisibility::FallthroughString => "*",
+            PackageVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PackageVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "private" => PackageVisibility::Private,
+            "public" => PackageVisibility::Public,
+            "" => PackageVisibility::Noop,
+            _ => PackageVisibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for PackageVisibility {
     fn default() -> PackageVisibility {
         PackageVisibility::Noop
@@ -8191,6 +8696,11 @@ impl PackageVisibility {
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageVisibility::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PackageVisibility::Unknown(_))
+    }
 }
 
 /// A software package
@@ -8357,7 +8867,7 @@ pub struct PackageVersion {
 /**
 * The baseline permission that all organization members have on this project. Only present if owner is an organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrganizationPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -8369,8 +8879,8 @@ pub enum OrganizationPermission {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPermission {
@@ -8381,12 +8891,38 @@ impl std::fmt::Display for OrganizationPermission {
             OrganizationPermission::Read => "read",
             OrganizationPermission::Write => "write",
             OrganizationPermission::Noop => "",
-            OrganizationPermission::FallthroughString => "*",
+            OrganizationPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrganizationPermission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrganizationPermission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => OrganizationPermission::Admin,
+            "none" => OrganizationPermission::None,
+            "read" => OrganizationPermission::Read,
+            "write" => OrganizationPermission::Write,
+            "" => OrganizationPermission::Noop,
+            _ => OrganizationPermission::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrganizationPermission {
     fn default() -> OrganizationPermission {
         OrganizationPermission::Noop
@@ -8396,6 +8932,11 @@ impl OrganizationPermission {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrganizationPermission::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrganizationPermission::Unknown(_))
+    }
 }
 
 /// Projects are a way to organize columns and cards of work.
@@ -8541,7 +9082,7 @@ pub struct GroupMapping {
 /**
 * The level of privacy this team should have
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Privacy {
     #[serde(rename = "closed")]
     Closed,
@@ -8549,8 +9090,8 @@ pub enum Privacy {
     Secret,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Privacy {
@@ -8559,12 +9100,36 @@ impl std::fmt::Display for Privacy {
             Privacy::Closed => "closed",
             Privacy::Secret => "secret",
             Privacy::Noop => "",
-            Privacy::FallthroughString => "*",
+            Privacy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Privacy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Privacy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "closed" => Privacy::Closed,
+            "secret" => Privacy::Secret,
+            "" => Privacy::Noop,
+            _ => Privacy::Unknown(s),
+        })
+    }
+}
+
 impl Default for Privacy {
     fn default() -> Privacy {
         Privacy::Noop
@@ -8574,6 +9139,11 @@ impl Privacy {
     pub fn is_noop(&self) -> bool {
         matches!(self, Privacy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Privacy::Unknown(_))
+    }
 }
 
 /// Groups of organization members that gives permissions on specified repositories.
@@ -8870,7 +9440,7 @@ pub struct TeamDiscussionComment {
 /**
 * The reaction to use
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Content {
     #[serde(rename = "+1")]
     PlusOne,
@@ -8890,8 +9460,8 @@ pub enum Content {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Content {
@@ -8906,12 +9476,42 @@ impl std::fmt::Display for Content {
             Content::Laugh => "laugh",
             Content::Rocket => "rocket",
             Content::Noop => "",
-            Content::FallthroughString => "*",
+            Content::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Content {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "+1" => Content::PlusOne,
+            "-1" => Content::MinusOne,
+            "confused" => Content::Confused,
+            "eyes" => Content::Eyes,
+            "heart" => Content::Heart,
+            "hooray" => Content::Hooray,
+            "laugh" => Content::Laugh,
+            "rocket" => Content::Rocket,
+            "" => Content::Noop,
+            _ => Content::Unknown(s),
+        })
+    }
+}
+
 impl Default for Content {
     fn default() -> Content {
         Content::Noop
@@ -8921,6 +9521,11 @@ impl Content {
     pub fn is_noop(&self) -> bool {
         matches!(self, Content::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Content::Unknown(_))
+    }
 }
 
 /// Reactions to conversations provide a way to help people express their feelings more simply and effectively.
@@ -8956,14 +9561,14 @@ pub struct Reaction {
 /**
 * The role of the user in the team.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TeamMembershipRole {
     #[serde(rename = "maintainer")]
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamMembershipRole {
@@ -8971,17 +9576,46 @@ impl std::fmt::Display for TeamMembershipRole {
         match self {
             TeamMembershipRole::Maintainer => "maintainer",
             TeamMembershipRole::Member => "member",
-            TeamMembershipRole::FallthroughString => "*",
+            TeamMembershipRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TeamMembershipRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamMembershipRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "maintainer" => TeamMembershipRole::Maintainer,
+            "member" => TeamMembershipRole::Member,
+            _ => TeamMembershipRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for TeamMembershipRole {
     fn default() -> TeamMembershipRole {
         TeamMembershipRole::Member
     }
 }
+impl TeamMembershipRole {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TeamMembershipRole::Unknown(_))
+    }
+}
 
 /// Team Membership
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9898,7 +10532,7 @@ pub struct FullRepositoryPermissions {
     pub push: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "disabled")]
     Disabled,
@@ -9906,8 +10540,8 @@ pub enum Status {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -9916,12 +10550,36 @@ impl std::fmt::Display for Status {
             Status::Disabled => "disabled",
             Status::Enabled => "enabled",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "disabled" => Status::Disabled,
+            "enabled" => Status::Enabled,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -9931,6 +10589,11 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10567,7 +11230,7 @@ pub struct Artifact {
 /**
 * The phase of the lifecycle that the job is currently in.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum JobStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -10577,8 +11240,8 @@ pub enum JobStatus {
     Queued,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for JobStatus {
@@ -10588,12 +11251,37 @@ impl std::fmt::Display for JobStatus {
             JobStatus::InProgress => "in_progress",
             JobStatus::Queued => "queued",
             JobStatus::Noop => "",
-            JobStatus::FallthroughString => "*",
+            JobStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "completed" => JobStatus::Completed,
+            "in_progress" => JobStatus::InProgress,
+            "queued" => JobStatus::Queued,
+            "" => JobStatus::Noop,
+            _ => JobStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for JobStatus {
     fn default() -> JobStatus {
         JobStatus::Noop
@@ -10603,6 +11291,11 @@ impl JobStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, JobStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, JobStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11071,7 +11764,7 @@ pub struct Environments {
 /**
 * Whether deployment to the environment(s) was approved or rejected
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EnvironmentApprovalState {
     #[serde(rename = "approved")]
     Approved,
@@ -11079,8 +11772,8 @@ pub enum EnvironmentApprovalState {
     Rejected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnvironmentApprovalState {
@@ -11089,12 +11782,36 @@ impl std::fmt::Display for EnvironmentApprovalState {
             EnvironmentApprovalState::Approved => "approved",
             EnvironmentApprovalState::Rejected => "rejected",
             EnvironmentApprovalState::Noop => "",
-            EnvironmentApprovalState::FallthroughString => "*",
+            EnvironmentApprovalState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EnvironmentApprovalState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EnvironmentApprovalState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approved" => EnvironmentApprovalState::Approved,
+            "rejected" => EnvironmentApprovalState::Rejected,
+            "" => EnvironmentApprovalState::Noop,
+            _ => EnvironmentApprovalState::Unknown(s),
+        })
+    }
+}
+
 impl Default for EnvironmentApprovalState {
     fn default() -> EnvironmentApprovalState {
         EnvironmentApprovalState::Noop
@@ -11104,6 +11821,11 @@ impl EnvironmentApprovalState {
     pub fn is_noop(&self) -> bool {
         matches!(self, EnvironmentApprovalState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EnvironmentApprovalState::Unknown(_))
+    }
 }
 
 /// An entry in the reviews log for environment deployments
@@ -11139,7 +11861,7 @@ pub struct EnvironmentApproval {
 /**
 * The type of reviewer. Must be one of: `User` or `Team`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DeploymentReviewerType {
     #[serde(rename = "Team")]
     Team,
@@ -11147,8 +11869,8 @@ pub enum DeploymentReviewerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentReviewerType {
@@ -11157,12 +11879,36 @@ impl std::fmt::Display for DeploymentReviewerType {
             DeploymentReviewerType::Team => "Team",
             DeploymentReviewerType::User => "User",
             DeploymentReviewerType::Noop => "",
-            DeploymentReviewerType::FallthroughString => "*",
+            DeploymentReviewerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DeploymentReviewerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentReviewerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Team" => DeploymentReviewerType::Team,
+            "User" => DeploymentReviewerType::User,
+            "" => DeploymentReviewerType::Noop,
+            _ => DeploymentReviewerType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DeploymentReviewerType {
     fn default() -> DeploymentReviewerType {
         DeploymentReviewerType::Noop
@@ -11172,6 +11918,11 @@ impl DeploymentReviewerType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DeploymentReviewerType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DeploymentReviewerType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11484,7 +12235,7 @@ pub struct ActionsSecret {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WorkflowState {
     #[serde(rename = "active")]
     Active,
@@ -11498,8 +12249,8 @@ pub enum WorkflowState {
     DisabledManually,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowState {
@@ -11511,12 +12262,39 @@ impl std::fmt::Display for WorkflowState {
             WorkflowState::DisabledInactivity => "disabled_inactivity",
             WorkflowState::DisabledManually => "disabled_manually",
             WorkflowState::Noop => "",
-            WorkflowState::FallthroughString => "*",
+            WorkflowState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WorkflowState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => WorkflowState::Active,
+            "deleted" => WorkflowState::Deleted,
+            "disabled_fork" => WorkflowState::DisabledFork,
+            "disabled_inactivity" => WorkflowState::DisabledInactivity,
+            "disabled_manually" => WorkflowState::DisabledManually,
+            "" => WorkflowState::Noop,
+            _ => WorkflowState::Unknown(s),
+        })
+    }
+}
+
 impl Default for WorkflowState {
     fn default() -> WorkflowState {
         WorkflowState::Noop
@@ -11526,6 +12304,11 @@ impl WorkflowState {
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WorkflowState::Unknown(_))
+    }
 }
 
 /// A GitHub Actions workflow
@@ -12814,7 +13597,7 @@ pub struct DeploymentSimple {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Conclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -12832,8 +13615,8 @@ pub enum Conclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Conclusion {
@@ -12847,12 +13630,41 @@ impl std::fmt::Display for Conclusion {
             Conclusion::Success => "success",
             Conclusion::TimedOut => "timed_out",
             Conclusion::Noop => "",
-            Conclusion::FallthroughString => "*",
+            Conclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Conclusion {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Conclusion {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "action_required" => Conclusion::ActionRequired,
+            "cancelled" => Conclusion::Cancelled,
+            "failure" => Conclusion::Failure,
+            "neutral" => Conclusion::Neutral,
+            "skipped" => Conclusion::Skipped,
+            "success" => Conclusion::Success,
+            "timed_out" => Conclusion::TimedOut,
+            "" => Conclusion::Noop,
+            _ => Conclusion::Unknown(s),
+        })
+    }
+}
+
 impl Default for Conclusion {
     fn default() -> Conclusion {
         Conclusion::Noop
@@ -12862,6 +13674,11 @@ impl Conclusion {
     pub fn is_noop(&self) -> bool {
         matches!(self, Conclusion::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Conclusion::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13198,7 +14015,7 @@ pub struct CheckSuitePreference {
 /**
 * State of a code scanning alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CodeScanningAlertState {
     #[serde(rename = "closed")]
     Closed,
@@ -13210,8 +14027,8 @@ pub enum CodeScanningAlertState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertState {
@@ -13222,12 +14039,38 @@ impl std::fmt::Display for CodeScanningAlertState {
             CodeScanningAlertState::Fixed => "fixed",
             CodeScanningAlertState::Open => "open",
             CodeScanningAlertState::Noop => "",
-            CodeScanningAlertState::FallthroughString => "*",
+            CodeScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CodeScanningAlertState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "closed" => CodeScanningAlertState::Closed,
+            "dismissed" => CodeScanningAlertState::Dismissed,
+            "fixed" => CodeScanningAlertState::Fixed,
+            "open" => CodeScanningAlertState::Open,
+            "" => CodeScanningAlertState::Noop,
+            _ => CodeScanningAlertState::Unknown(s),
+        })
+    }
+}
+
 impl Default for CodeScanningAlertState {
     fn default() -> CodeScanningAlertState {
         CodeScanningAlertState::Noop
@@ -13237,12 +14080,17 @@ impl CodeScanningAlertState {
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CodeScanningAlertState::Unknown(_))
+    }
 }
 
 /**
 * **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CodeScanningAlertDismissedReason {
     #[serde(rename = "false positive")]
     FalsePositive,
@@ -13252,8 +14100,8 @@ pub enum CodeScanningAlertDismissedReason {
     WonTFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertDismissedReason {
@@ -13263,27 +14111,57 @@ impl std::fmt::Display for CodeScanningAlertDismissedReason {
             CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
             CodeScanningAlertDismissedReason::WonTFix => "won't fix",
             CodeScanningAlertDismissedReason::Noop => "",
-            CodeScanningAlertDismissedReason::FallthroughString => "*",
+            CodeScanningAlertDismissedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for CodeScanningAlertDismissedReason {
-    fn default() -> CodeScanningAlertDismissedReason {
-        CodeScanningAlertDismissedReason::Noop
+impl Serialize for CodeScanningAlertDismissedReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertDismissedReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "false positive" => CodeScanningAlertDismissedReason::FalsePositive,
+            "used in tests" => CodeScanningAlertDismissedReason::UsedInTests,
+            "won't fix" => CodeScanningAlertDismissedReason::WonTFix,
+            "" => CodeScanningAlertDismissedReason::Noop,
+            _ => CodeScanningAlertDismissedReason::Unknown(s),
+        })
+    }
+}
+
+impl Default for CodeScanningAlertDismissedReason {
+    fn default() -> CodeScanningAlertDismissedReason {
+        CodeScanningAlertDismissedReason::Noop
     }
 }
 impl CodeScanningAlertDismissedReason {
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertDismissedReason::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CodeScanningAlertDismissedReason::Unknown(_))
+    }
 }
 
 /**
 * The severity of the alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Severity {
     #[serde(rename = "error")]
     Error,
@@ -13295,8 +14173,8 @@ pub enum Severity {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -13307,12 +14185,38 @@ impl std::fmt::Display for Severity {
             Severity::Note => "note",
             Severity::Warning => "warning",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "error" => Severity::Error,
+            "none" => Severity::None,
+            "note" => Severity::Note,
+            "warning" => Severity::Warning,
+            "" => Severity::Noop,
+            _ => Severity::Unknown(s),
+        })
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -13322,6 +14226,11 @@ impl Severity {
     pub fn is_noop(&self) -> bool {
         matches!(self, Severity::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Severity::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13423,7 +14332,7 @@ pub struct CodeScanningAlertLocation {
 /**
 * A classification of the file. For example to identify it as generated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CodeScanningAlertClassification {
     #[serde(rename = "generated")]
     Generated,
@@ -13435,8 +14344,8 @@ pub enum CodeScanningAlertClassification {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertClassification {
@@ -13447,12 +14356,38 @@ impl std::fmt::Display for CodeScanningAlertClassification {
             CodeScanningAlertClassification::Source => "source",
             CodeScanningAlertClassification::Test => "test",
             CodeScanningAlertClassification::Noop => "",
-            CodeScanningAlertClassification::FallthroughString => "*",
+            CodeScanningAlertClassification::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CodeScanningAlertClassification {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertClassification {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "generated" => CodeScanningAlertClassification::Generated,
+            "library" => CodeScanningAlertClassification::Library,
+            "source" => CodeScanningAlertClassification::Source,
+            "test" => CodeScanningAlertClassification::Test,
+            "" => CodeScanningAlertClassification::Noop,
+            _ => CodeScanningAlertClassification::Unknown(s),
+        })
+    }
+}
+
 impl Default for CodeScanningAlertClassification {
     fn default() -> CodeScanningAlertClassification {
         CodeScanningAlertClassification::Noop
@@ -13462,6 +14397,11 @@ impl CodeScanningAlertClassification {
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertClassification::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CodeScanningAlertClassification::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13595,7 +14535,7 @@ pub struct CodeScanningAlertItems {
 /**
 * The security severity of the alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SecuritySeverityLevel {
     #[serde(rename = "critical")]
     Critical,
@@ -13607,8 +14547,8 @@ pub enum SecuritySeverityLevel {
     Medium,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecuritySeverityLevel {
@@ -13619,12 +14559,38 @@ impl std::fmt::Display for SecuritySeverityLevel {
             SecuritySeverityLevel::Low => "low",
             SecuritySeverityLevel::Medium => "medium",
             SecuritySeverityLevel::Noop => "",
-            SecuritySeverityLevel::FallthroughString => "*",
+            SecuritySeverityLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SecuritySeverityLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecuritySeverityLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "critical" => SecuritySeverityLevel::Critical,
+            "high" => SecuritySeverityLevel::High,
+            "low" => SecuritySeverityLevel::Low,
+            "medium" => SecuritySeverityLevel::Medium,
+            "" => SecuritySeverityLevel::Noop,
+            _ => SecuritySeverityLevel::Unknown(s),
+        })
+    }
+}
+
 impl Default for SecuritySeverityLevel {
     fn default() -> SecuritySeverityLevel {
         SecuritySeverityLevel::Noop
@@ -13634,6 +14600,11 @@ impl SecuritySeverityLevel {
     pub fn is_noop(&self) -> bool {
         matches!(self, SecuritySeverityLevel::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SecuritySeverityLevel::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13755,7 +14726,7 @@ pub struct CodeScanningAlert {
 /**
 * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CodeScanningAlertSetState {
     #[serde(rename = "dismissed")]
     Dismissed,
@@ -13763,8 +14734,8 @@ pub enum CodeScanningAlertSetState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertSetState {
@@ -13773,12 +14744,36 @@ impl std::fmt::Display for CodeScanningAlertSetState {
             CodeScanningAlertSetState::Dismissed => "dismissed",
             CodeScanningAlertSetState::Open => "open",
             CodeScanningAlertSetState::Noop => "",
-            CodeScanningAlertSetState::FallthroughString => "*",
+            CodeScanningAlertSetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CodeScanningAlertSetState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertSetState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "dismissed" => CodeScanningAlertSetState::Dismissed,
+            "open" => CodeScanningAlertSetState::Open,
+            "" => CodeScanningAlertSetState::Noop,
+            _ => CodeScanningAlertSetState::Unknown(s),
+        })
+    }
+}
+
 impl Default for CodeScanningAlertSetState {
     fn default() -> CodeScanningAlertSetState {
         CodeScanningAlertSetState::Noop
@@ -13788,6 +14783,11 @@ impl CodeScanningAlertSetState {
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertSetState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CodeScanningAlertSetState::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13922,7 +14922,7 @@ pub struct CodeScanningSarifsReceipt {
 /**
 * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProcessingStatus {
     #[serde(rename = "complete")]
     Complete,
@@ -13930,8 +14930,8 @@ pub enum ProcessingStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProcessingStatus {
@@ -13940,12 +14940,36 @@ impl std::fmt::Display for ProcessingStatus {
             ProcessingStatus::Complete => "complete",
             ProcessingStatus::Pending => "pending",
             ProcessingStatus::Noop => "",
-            ProcessingStatus::FallthroughString => "*",
+            ProcessingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProcessingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProcessingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "complete" => ProcessingStatus::Complete,
+            "pending" => ProcessingStatus::Pending,
+            "" => ProcessingStatus::Noop,
+            _ => ProcessingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProcessingStatus {
     fn default() -> ProcessingStatus {
         ProcessingStatus::Noop
@@ -13955,6 +14979,11 @@ impl ProcessingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProcessingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProcessingStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14105,7 +15134,7 @@ pub struct Collaborator {
 /**
 * The permission associated with the invitation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RepositoryInvitationPermissions {
     #[serde(rename = "admin")]
     Admin,
@@ -14119,8 +15148,8 @@ pub enum RepositoryInvitationPermissions {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryInvitationPermissions {
@@ -14132,12 +15161,39 @@ impl std::fmt::Display for RepositoryInvitationPermissions {
             RepositoryInvitationPermissions::Triage => "triage",
             RepositoryInvitationPermissions::Write => "write",
             RepositoryInvitationPermissions::Noop => "",
-            RepositoryInvitationPermissions::FallthroughString => "*",
+            RepositoryInvitationPermissions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RepositoryInvitationPermissions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositoryInvitationPermissions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => RepositoryInvitationPermissions::Admin,
+            "maintain" => RepositoryInvitationPermissions::Maintain,
+            "read" => RepositoryInvitationPermissions::Read,
+            "triage" => RepositoryInvitationPermissions::Triage,
+            "write" => RepositoryInvitationPermissions::Write,
+            "" => RepositoryInvitationPermissions::Noop,
+            _ => RepositoryInvitationPermissions::Unknown(s),
+        })
+    }
+}
+
 impl Default for RepositoryInvitationPermissions {
     fn default() -> RepositoryInvitationPermissions {
         RepositoryInvitationPermissions::Noop
@@ -14147,6 +15203,11 @@ impl RepositoryInvitationPermissions {
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositoryInvitationPermissions::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RepositoryInvitationPermissions::Unknown(_))
+    }
 }
 
 /// Repository invitations let you manage who you collaborate with.
@@ -14324,7 +15385,7 @@ pub struct Link {
 /**
 * The merge method to use.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MergeMethod {
     #[serde(rename = "merge")]
     Merge,
@@ -14334,8 +15395,8 @@ pub enum MergeMethod {
     Squash,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeMethod {
@@ -14345,12 +15406,37 @@ impl std::fmt::Display for MergeMethod {
             MergeMethod::Rebase => "rebase",
             MergeMethod::Squash => "squash",
             MergeMethod::Noop => "",
-            MergeMethod::FallthroughString => "*",
+            MergeMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MergeMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "merge" => MergeMethod::Merge,
+            "rebase" => MergeMethod::Rebase,
+            "squash" => MergeMethod::Squash,
+            "" => MergeMethod::Noop,
+            _ => MergeMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for MergeMethod {
     fn default() -> MergeMethod {
         MergeMethod::Noop
@@ -14360,6 +15446,11 @@ impl MergeMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MergeMethod::Unknown(_))
+    }
 }
 
 /// The status of auto merging a pull request.
@@ -15024,7 +16115,7 @@ pub struct DiffEntry {
     pub status: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CommitComparisonStatus {
     #[serde(rename = "ahead")]
     Ahead,
@@ -15036,8 +16127,8 @@ pub enum CommitComparisonStatus {
     Identical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CommitComparisonStatus {
@@ -15048,12 +16139,38 @@ impl std::fmt::Display for CommitComparisonStatus {
             CommitComparisonStatus::Diverged => "diverged",
             CommitComparisonStatus::Identical => "identical",
             CommitComparisonStatus::Noop => "",
-            CommitComparisonStatus::FallthroughString => "*",
+            CommitComparisonStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CommitComparisonStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitComparisonStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ahead" => CommitComparisonStatus::Ahead,
+            "behind" => CommitComparisonStatus::Behind,
+            "diverged" => CommitComparisonStatus::Diverged,
+            "identical" => CommitComparisonStatus::Identical,
+            "" => CommitComparisonStatus::Noop,
+            _ => CommitComparisonStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for CommitComparisonStatus {
     fn default() -> CommitComparisonStatus {
         CommitComparisonStatus::Noop
@@ -15063,6 +16180,11 @@ impl CommitComparisonStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, CommitComparisonStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CommitComparisonStatus::Unknown(_))
+    }
 }
 
 /// Commit Comparison
@@ -15842,7 +16964,7 @@ pub struct Contributor {
 /**
 * The state of the status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DeploymentStatusState {
     #[serde(rename = "error")]
     Error,
@@ -15860,8 +16982,8 @@ pub enum DeploymentStatusState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentStatusState {
@@ -15875,12 +16997,41 @@ impl std::fmt::Display for DeploymentStatusState {
             DeploymentStatusState::Queued => "queued",
             DeploymentStatusState::Success => "success",
             DeploymentStatusState::Noop => "",
-            DeploymentStatusState::FallthroughString => "*",
+            DeploymentStatusState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DeploymentStatusState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentStatusState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "error" => DeploymentStatusState::Error,
+            "failure" => DeploymentStatusState::Failure,
+            "in_progress" => DeploymentStatusState::InProgress,
+            "inactive" => DeploymentStatusState::Inactive,
+            "pending" => DeploymentStatusState::Pending,
+            "queued" => DeploymentStatusState::Queued,
+            "success" => DeploymentStatusState::Success,
+            "" => DeploymentStatusState::Noop,
+            _ => DeploymentStatusState::Unknown(s),
+        })
+    }
+}
+
 impl Default for DeploymentStatusState {
     fn default() -> DeploymentStatusState {
         DeploymentStatusState::Noop
@@ -15890,6 +17041,11 @@ impl DeploymentStatusState {
     pub fn is_noop(&self) -> bool {
         matches!(self, DeploymentStatusState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DeploymentStatusState::Unknown(_))
+    }
 }
 
 /// The status of a deployment.
@@ -16604,7 +17760,7 @@ pub struct Hook {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImportStatus {
     #[serde(rename = "auth")]
     Auth,
@@ -16640,8 +17796,8 @@ pub enum ImportStatus {
     WaitingToPush,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImportStatus {
@@ -16664,12 +17820,50 @@ impl std::fmt::Display for ImportStatus {
             ImportStatus::Unknown => "unknown",
             ImportStatus::WaitingToPush => "waiting_to_push",
             ImportStatus::Noop => "",
-            ImportStatus::FallthroughString => "*",
+            ImportStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auth" => ImportStatus::Auth,
+            "auth_failed" => ImportStatus::AuthFailed,
+            "choose" => ImportStatus::Choose,
+            "complete" => ImportStatus::Complete,
+            "detecting" => ImportStatus::Detecting,
+            "detection_found_multiple" => ImportStatus::DetectionFoundMultiple,
+            "detection_found_nothing" => ImportStatus::DetectionFoundNothing,
+            "detection_needs_auth" => ImportStatus::DetectionNeedsAuth,
+            "error" => ImportStatus::Error,
+            "importing" => ImportStatus::Importing,
+            "mapping" => ImportStatus::Mapping,
+            "none" => ImportStatus::None,
+            "pushing" => ImportStatus::Pushing,
+            "setup" => ImportStatus::Setup,
+            "unknown" => ImportStatus::Unknown,
+            "waiting_to_push" => ImportStatus::WaitingToPush,
+            "" => ImportStatus::Noop,
+            _ => ImportStatus::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for ImportStatus {
     fn default() -> ImportStatus {
         ImportStatus::Noop
@@ -16679,6 +17873,11 @@ impl ImportStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ImportStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImportStatus::UnknownValue(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18332,14 +19531,14 @@ pub struct PullRequestReviewCommentLinks {
 /**
 * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Side {
     #[serde(rename = "LEFT")]
     Left,
     #[serde(rename = "RIGHT")]
     Right,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Side {
@@ -18347,17 +19546,46 @@ impl std::fmt::Display for Side {
         match self {
             Side::Left => "LEFT",
             Side::Right => "RIGHT",
-            Side::FallthroughString => "*",
+            Side::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Side {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LEFT" => Side::Left,
+            "RIGHT" => Side::Right,
+            _ => Side::Unknown(s),
+        })
+    }
+}
+
 impl Default for Side {
     fn default() -> Side {
         Side::Right
     }
 }
+impl Side {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Side::Unknown(_))
+    }
+}
 
 /// Pull Request Review Comments are comments on a portion of the Pull Request's diff.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18795,7 +20023,7 @@ pub struct PagesSourceHash {
     pub path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PagesHttpsCertificateState {
     #[serde(rename = "approved")]
     Approved,
@@ -18823,8 +20051,8 @@ pub enum PagesHttpsCertificateState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PagesHttpsCertificateState {
@@ -18843,12 +20071,46 @@ impl std::fmt::Display for PagesHttpsCertificateState {
             PagesHttpsCertificateState::New => "new",
             PagesHttpsCertificateState::Uploaded => "uploaded",
             PagesHttpsCertificateState::Noop => "",
-            PagesHttpsCertificateState::FallthroughString => "*",
+            PagesHttpsCertificateState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PagesHttpsCertificateState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PagesHttpsCertificateState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approved" => PagesHttpsCertificateState::Approved,
+            "authorization_created" => PagesHttpsCertificateState::AuthorizationCreated,
+            "authorization_pending" => PagesHttpsCertificateState::AuthorizationPending,
+            "authorization_revoked" => PagesHttpsCertificateState::AuthorizationRevoked,
+            "authorized" => PagesHttpsCertificateState::Authorized,
+            "bad_authz" => PagesHttpsCertificateState::BadAuthz,
+            "destroy_pending" => PagesHttpsCertificateState::DestroyPending,
+            "dns_changed" => PagesHttpsCertificateState::DnsChanged,
+            "errored" => PagesHttpsCertificateState::Errored,
+            "issued" => PagesHttpsCertificateState::Issued,
+            "new" => PagesHttpsCertificateState::New,
+            "uploaded" => PagesHttpsCertificateState::Uploaded,
+            "" => PagesHttpsCertificateState::Noop,
+            _ => PagesHttpsCertificateState::Unknown(s),
+        })
+    }
+}
+
 impl Default for PagesHttpsCertificateState {
     fn default() -> PagesHttpsCertificateState {
         PagesHttpsCertificateState::Noop
@@ -18858,6 +20120,11 @@ impl PagesHttpsCertificateState {
     pub fn is_noop(&self) -> bool {
         matches!(self, PagesHttpsCertificateState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PagesHttpsCertificateState::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18890,7 +20157,7 @@ pub struct PagesHttpsCertificate {
 /**
 * The status of the most recent build of the Page.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PageStatus {
     #[serde(rename = "building")]
     Building,
@@ -18900,8 +20167,8 @@ pub enum PageStatus {
     Errored,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PageStatus {
@@ -18911,12 +20178,37 @@ impl std::fmt::Display for PageStatus {
             PageStatus::Built => "built",
             PageStatus::Errored => "errored",
             PageStatus::Noop => "",
-            PageStatus::FallthroughString => "*",
+            PageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "building" => PageStatus::Building,
+            "built" => PageStatus::Built,
+            "errored" => PageStatus::Errored,
+            "" => PageStatus::Noop,
+            _ => PageStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PageStatus {
     fn default() -> PageStatus {
         PageStatus::Noop
@@ -18926,6 +20218,11 @@ impl PageStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PageStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PageStatus::Unknown(_))
+    }
 }
 
 /// The configuration for GitHub Pages for a repository.
@@ -21027,7 +22324,7 @@ pub struct ReviewComment {
 /**
 * State of the release asset.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReleaseAssetState {
     #[serde(rename = "open")]
     Open,
@@ -21035,8 +22332,8 @@ pub enum ReleaseAssetState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReleaseAssetState {
@@ -21045,12 +22342,36 @@ impl std::fmt::Display for ReleaseAssetState {
             ReleaseAssetState::Open => "open",
             ReleaseAssetState::Uploaded => "uploaded",
             ReleaseAssetState::Noop => "",
-            ReleaseAssetState::FallthroughString => "*",
+            ReleaseAssetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReleaseAssetState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReleaseAssetState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "open" => ReleaseAssetState::Open,
+            "uploaded" => ReleaseAssetState::Uploaded,
+            "" => ReleaseAssetState::Noop,
+            _ => ReleaseAssetState::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReleaseAssetState {
     fn default() -> ReleaseAssetState {
         ReleaseAssetState::Noop
@@ -21060,6 +22381,11 @@ impl ReleaseAssetState {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReleaseAssetState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReleaseAssetState::Unknown(_))
+    }
 }
 
 /// Data related to a release.
@@ -21285,7 +22611,7 @@ pub struct Release {
 /**
 * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SecretScanningAlertState {
     #[serde(rename = "open")]
     Open,
@@ -21293,8 +22619,8 @@ pub enum SecretScanningAlertState {
     Resolved,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertState {
@@ -21303,12 +22629,36 @@ impl std::fmt::Display for SecretScanningAlertState {
             SecretScanningAlertState::Open => "open",
             SecretScanningAlertState::Resolved => "resolved",
             SecretScanningAlertState::Noop => "",
-            SecretScanningAlertState::FallthroughString => "*",
+            SecretScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SecretScanningAlertState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretScanningAlertState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "open" => SecretScanningAlertState::Open,
+            "resolved" => SecretScanningAlertState::Resolved,
+            "" => SecretScanningAlertState::Noop,
+            _ => SecretScanningAlertState::Unknown(s),
+        })
+    }
+}
+
 impl Default for SecretScanningAlertState {
     fn default() -> SecretScanningAlertState {
         SecretScanningAlertState::Noop
@@ -21318,12 +22668,17 @@ impl SecretScanningAlertState {
     pub fn is_noop(&self) -> bool {
         matches!(self, SecretScanningAlertState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SecretScanningAlertState::Unknown(_))
+    }
 }
 
 /**
 * **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SecretScanningAlertResolution {
     #[serde(rename = "false_positive")]
     FalsePositive,
@@ -21335,8 +22690,8 @@ pub enum SecretScanningAlertResolution {
     WontFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertResolution {
@@ -21347,12 +22702,38 @@ impl std::fmt::Display for SecretScanningAlertResolution {
             SecretScanningAlertResolution::UsedInTests => "used_in_tests",
             SecretScanningAlertResolution::WontFix => "wont_fix",
             SecretScanningAlertResolution::Noop => "",
-            SecretScanningAlertResolution::FallthroughString => "*",
+            SecretScanningAlertResolution::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SecretScanningAlertResolution {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretScanningAlertResolution {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "false_positive" => SecretScanningAlertResolution::FalsePositive,
+            "revoked" => SecretScanningAlertResolution::Revoked,
+            "used_in_tests" => SecretScanningAlertResolution::UsedInTests,
+            "wont_fix" => SecretScanningAlertResolution::WontFix,
+            "" => SecretScanningAlertResolution::Noop,
+            _ => SecretScanningAlertResolution::Unknown(s),
+        })
+    }
+}
+
 impl Default for SecretScanningAlertResolution {
     fn default() -> SecretScanningAlertResolution {
         SecretScanningAlertResolution::Noop
@@ -21362,6 +22743,11 @@ impl SecretScanningAlertResolution {
     pub fn is_noop(&self) -> bool {
         matches!(self, SecretScanningAlertResolution::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SecretScanningAlertResolution::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22086,7 +23472,7 @@ pub struct ScimUserMeta {
     pub resource_type: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Op {
     #[serde(rename = "add")]
     Add,
@@ -22096,8 +23482,8 @@ pub enum Op {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -22107,12 +23493,37 @@ impl std::fmt::Display for Op {
             Op::Remove => "remove",
             Op::Replace => "replace",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "" => Op::Noop,
+            _ => Op::Unknown(s),
+        })
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -22122,6 +23533,11 @@ impl Op {
     pub fn is_noop(&self) -> bool {
         matches!(self, Op::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Op::Unknown(_))
+    }
 }
 
 /// All of the following types:
@@ -24381,7 +25797,7 @@ pub struct KeySimple {
 *   
 *   The default is `web`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Include {
     #[serde(rename = "all")]
     All,
@@ -24391,8 +25807,8 @@ pub enum Include {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -24402,12 +25818,37 @@ impl std::fmt::Display for Include {
             Include::Git => "git",
             Include::Web => "web",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Include {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Include {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => Include::All,
+            "git" => Include::Git,
+            "web" => Include::Web,
+            "" => Include::Noop,
+            _ => Include::Unknown(s),
+        })
+    }
+}
+
 impl Default for Include {
     fn default() -> Include {
         Include::Noop
@@ -24417,6 +25858,11 @@ impl Include {
     pub fn is_noop(&self) -> bool {
         matches!(self, Include::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Include::Unknown(_))
+    }
 }
 
 /**
@@ -24424,7 +25870,7 @@ impl Include {
 *   
 *   The default is `desc`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Order {
     #[serde(rename = "asc")]
     Asc,
@@ -24432,8 +25878,8 @@ pub enum Order {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Order {
@@ -24442,12 +25888,36 @@ impl std::fmt::Display for Order {
             Order::Asc => "asc",
             Order::Desc => "desc",
             Order::Noop => "",
-            Order::FallthroughString => "*",
+            Order::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Order {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "asc" => Order::Asc,
+            "desc" => Order::Desc,
+            "" => Order::Noop,
+            _ => Order::Unknown(s),
+        })
+    }
+}
+
 impl Default for Order {
     fn default() -> Order {
         Order::Noop
@@ -24457,19 +25927,24 @@ impl Order {
     pub fn is_noop(&self) -> bool {
         matches!(self, Order::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Order::Unknown(_))
+    }
 }
 
 /**
 * One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Sort {
     #[serde(rename = "created")]
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Sort {
@@ -24477,22 +25952,51 @@ impl std::fmt::Display for Sort {
         match self {
             Sort::Created => "created",
             Sort::Updated => "updated",
-            Sort::FallthroughString => "*",
+            Sort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Sort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Sort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created" => Sort::Created,
+            "updated" => Sort::Updated,
+            _ => Sort::Unknown(s),
+        })
+    }
+}
+
 impl Default for Sort {
     fn default() -> Sort {
         Sort::Created
     }
 }
+impl Sort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Sort::Unknown(_))
+    }
+}
 
 /**
 * Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WorkflowRunStatus {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -24522,8 +26026,8 @@ pub enum WorkflowRunStatus {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowRunStatus {
@@ -24543,12 +26047,47 @@ impl std::fmt::Display for WorkflowRunStatus {
             WorkflowRunStatus::TimedOut => "timed_out",
             WorkflowRunStatus::Waiting => "waiting",
             WorkflowRunStatus::Noop => "",
-            WorkflowRunStatus::FallthroughString => "*",
+            WorkflowRunStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WorkflowRunStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowRunStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "action_required" => WorkflowRunStatus::ActionRequired,
+            "cancelled" => WorkflowRunStatus::Cancelled,
+            "completed" => WorkflowRunStatus::Completed,
+            "failure" => WorkflowRunStatus::Failure,
+            "in_progress" => WorkflowRunStatus::InProgress,
+            "neutral" => WorkflowRunStatus::Neutral,
+            "queued" => WorkflowRunStatus::Queued,
+            "requested" => WorkflowRunStatus::Requested,
+            "skipped" => WorkflowRunStatus::Skipped,
+            "stale" => WorkflowRunStatus::Stale,
+            "success" => WorkflowRunStatus::Success,
+            "timed_out" => WorkflowRunStatus::TimedOut,
+            "waiting" => WorkflowRunStatus::Waiting,
+            "" => WorkflowRunStatus::Noop,
+            _ => WorkflowRunStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for WorkflowRunStatus {
     fn default() -> WorkflowRunStatus {
         WorkflowRunStatus::Noop
@@ -24558,6 +26097,11 @@ impl WorkflowRunStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowRunStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WorkflowRunStatus::Unknown(_))
+    }
 }
 
 /// All of the following types:
@@ -24617,14 +26161,14 @@ impl std::convert::From<TitleOneOf> for String {
 /**
 * Must be one of: `day`, `week`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Per {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "week")]
     Week,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Per {
@@ -24632,17 +26176,46 @@ impl std::fmt::Display for Per {
         match self {
             Per::Day => "day",
             Per::Week => "week",
-            Per::FallthroughString => "*",
+            Per::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Per {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Per {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "day" => Per::Day,
+            "week" => Per::Week,
+            _ => Per::Unknown(s),
+        })
+    }
+}
+
 impl Default for Per {
     fn default() -> Per {
         Per::Day
     }
 }
+impl Per {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Per::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsMergeResponse {
@@ -25397,14 +26970,14 @@ pub struct FilesAdditionalPropertiesData {
     pub content: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Public {
     #[serde(rename = "false")]
     False,
     #[serde(rename = "true")]
     True,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Public {
@@ -25412,17 +26985,46 @@ impl std::fmt::Display for Public {
         match self {
             Public::False => "false",
             Public::True => "true",
-            Public::FallthroughString => "*",
+            Public::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Public {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Public {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "false" => Public::False,
+            "true" => Public::True,
+            _ => Public::Unknown(s),
+        })
+    }
+}
+
 impl Default for Public {
     fn default() -> Public {
         Public::False
     }
 }
+impl Public {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Public::Unknown(_))
+    }
+}
 
 /// All of the following types:
 ///
@@ -25552,7 +27154,7 @@ pub struct AppsListInstallationReposResponse {
 *   \* `subscribed`: Issues you're subscribed to updates for  
 *   \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Filter {
     #[serde(rename = "all")]
     All,
@@ -25566,8 +27168,8 @@ pub enum Filter {
     Repos,
     #[serde(rename = "subscribed")]
     Subscribed,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Filter {
@@ -25579,22 +27181,55 @@ impl std::fmt::Display for Filter {
             Filter::Mentioned => "mentioned",
             Filter::Repos => "repos",
             Filter::Subscribed => "subscribed",
-            Filter::FallthroughString => "*",
+            Filter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Filter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Filter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => Filter::All,
+            "assigned" => Filter::Assigned,
+            "created" => Filter::Created,
+            "mentioned" => Filter::Mentioned,
+            "repos" => Filter::Repos,
+            "subscribed" => Filter::Subscribed,
+            _ => Filter::Unknown(s),
+        })
+    }
+}
+
 impl Default for Filter {
     fn default() -> Filter {
         Filter::Assigned
     }
 }
+impl Filter {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Filter::Unknown(_))
+    }
+}
 
 /**
 * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IssuesListState {
     #[serde(rename = "all")]
     All,
@@ -25602,8 +27237,8 @@ pub enum IssuesListState {
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListState {
@@ -25612,22 +27247,52 @@ impl std::fmt::Display for IssuesListState {
             IssuesListState::All => "all",
             IssuesListState::Closed => "closed",
             IssuesListState::Open => "open",
-            IssuesListState::FallthroughString => "*",
+            IssuesListState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IssuesListState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => IssuesListState::All,
+            "closed" => IssuesListState::Closed,
+            "open" => IssuesListState::Open,
+            _ => IssuesListState::Unknown(s),
+        })
+    }
+}
+
 impl Default for IssuesListState {
     fn default() -> IssuesListState {
         IssuesListState::Open
     }
 }
+impl IssuesListState {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IssuesListState::Unknown(_))
+    }
+}
 
 /**
 * What to sort results by. Can be either `created`, `updated`, `comments`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IssuesListSort {
     #[serde(rename = "comments")]
     Comments,
@@ -25635,8 +27300,8 @@ pub enum IssuesListSort {
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListSort {
@@ -25645,29 +27310,59 @@ impl std::fmt::Display for IssuesListSort {
             IssuesListSort::Comments => "comments",
             IssuesListSort::Created => "created",
             IssuesListSort::Updated => "updated",
-            IssuesListSort::FallthroughString => "*",
+            IssuesListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IssuesListSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "comments" => IssuesListSort::Comments,
+            "created" => IssuesListSort::Created,
+            "updated" => IssuesListSort::Updated,
+            _ => IssuesListSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for IssuesListSort {
     fn default() -> IssuesListSort {
         IssuesListSort::Created
     }
 }
+impl IssuesListSort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IssuesListSort::Unknown(_))
+    }
+}
 
 /**
 * The rendering mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Mode {
     #[serde(rename = "gfm")]
     Gfm,
     #[serde(rename = "markdown")]
     Markdown,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -25675,17 +27370,46 @@ impl std::fmt::Display for Mode {
         match self {
             Mode::Gfm => "gfm",
             Mode::Markdown => "markdown",
-            Mode::FallthroughString => "*",
+            Mode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Mode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Mode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "gfm" => Mode::Gfm,
+            "markdown" => Mode::Markdown,
+            _ => Mode::Unknown(s),
+        })
+    }
+}
+
 impl Default for Mode {
     fn default() -> Mode {
         Mode::Markdown
     }
 }
+impl Mode {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Mode::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MarkdownRenderRequest {
@@ -25733,7 +27457,7 @@ pub struct ActivitySetThreadSubscriptionRequest {
 *   \* `none` - only admin members can create repositories.  
 *   **Note:** This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MembersAllowedRepositoryCreationType {
     #[serde(rename = "all")]
     All,
@@ -25743,8 +27467,8 @@ pub enum MembersAllowedRepositoryCreationType {
     Private,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersAllowedRepositoryCreationType {
@@ -25754,21 +27478,51 @@ impl std::fmt::Display for MembersAllowedRepositoryCreationType {
             MembersAllowedRepositoryCreationType::None => "none",
             MembersAllowedRepositoryCreationType::Private => "private",
             MembersAllowedRepositoryCreationType::Noop => "",
-            MembersAllowedRepositoryCreationType::FallthroughString => "*",
+            MembersAllowedRepositoryCreationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for MembersAllowedRepositoryCreationType {
-    fn default() -> MembersAllowedRepositoryCreationType {
-        MembersAllowedRepositoryCreationType::Noop
+impl Serialize for MembersAllowedRepositoryCreationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
     }
 }
-impl MembersAllowedRepositoryCreationType {
+
+impl<'de> Deserialize<'de> for MembersAllowedRepositoryCreationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => MembersAllowedRepositoryCreationType::All,
+            "none" => MembersAllowedRepositoryCreationType::None,
+            "private" => MembersAllowedRepositoryCreationType::Private,
+            "" => MembersAllowedRepositoryCreationType::Noop,
+            _ => MembersAllowedRepositoryCreationType::Unknown(s),
+        })
+    }
+}
+
+impl Default for MembersAllowedRepositoryCreationType {
+    fn default() -> MembersAllowedRepositoryCreationType {
+        MembersAllowedRepositoryCreationType::Noop
+    }
+}
+impl MembersAllowedRepositoryCreationType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MembersAllowedRepositoryCreationType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MembersAllowedRepositoryCreationType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26263,7 +28017,7 @@ pub struct InteractionsGetRestrictionsResponseAnyOf {
 *   \* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
 *   \* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgsCreateInvitationRequestRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26271,8 +28025,8 @@ pub enum OrgsCreateInvitationRequestRole {
     BillingManager,
     #[serde(rename = "direct_member")]
     DirectMember,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsCreateInvitationRequestRole {
@@ -26281,17 +28035,47 @@ impl std::fmt::Display for OrgsCreateInvitationRequestRole {
             OrgsCreateInvitationRequestRole::Admin => "admin",
             OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
             OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
-            OrgsCreateInvitationRequestRole::FallthroughString => "*",
+            OrgsCreateInvitationRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgsCreateInvitationRequestRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsCreateInvitationRequestRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => OrgsCreateInvitationRequestRole::Admin,
+            "billing_manager" => OrgsCreateInvitationRequestRole::BillingManager,
+            "direct_member" => OrgsCreateInvitationRequestRole::DirectMember,
+            _ => OrgsCreateInvitationRequestRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgsCreateInvitationRequestRole {
     fn default() -> OrgsCreateInvitationRequestRole {
         OrgsCreateInvitationRequestRole::DirectMember
     }
 }
+impl OrgsCreateInvitationRequestRole {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgsCreateInvitationRequestRole::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsCreateInvitationRequest {
@@ -26331,14 +28115,14 @@ pub struct OrgsCreateInvitationRequest {
 *   \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
 *   \* `all` - All members the authenticated user can see.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgsListMembersFilter {
     #[serde(rename = "2fa_disabled")]
     TwoFaDisabled,
     #[serde(rename = "all")]
     All,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersFilter {
@@ -26346,17 +28130,46 @@ impl std::fmt::Display for OrgsListMembersFilter {
         match self {
             OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
             OrgsListMembersFilter::All => "all",
-            OrgsListMembersFilter::FallthroughString => "*",
+            OrgsListMembersFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgsListMembersFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsListMembersFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "2fa_disabled" => OrgsListMembersFilter::TwoFaDisabled,
+            "all" => OrgsListMembersFilter::All,
+            _ => OrgsListMembersFilter::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgsListMembersFilter {
     fn default() -> OrgsListMembersFilter {
         OrgsListMembersFilter::All
     }
 }
+impl OrgsListMembersFilter {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgsListMembersFilter::Unknown(_))
+    }
+}
 
 /**
 * Filter members returned by their role. Can be one of:  
@@ -26364,7 +28177,7 @@ impl Default for OrgsListMembersFilter {
 *   \* `admin` - Organization owners.  
 *   \* `member` - Non-owner organization members.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgsListMembersRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26372,8 +28185,8 @@ pub enum OrgsListMembersRole {
     All,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersRole {
@@ -26382,31 +28195,61 @@ impl std::fmt::Display for OrgsListMembersRole {
             OrgsListMembersRole::Admin => "admin",
             OrgsListMembersRole::All => "all",
             OrgsListMembersRole::Member => "member",
-            OrgsListMembersRole::FallthroughString => "*",
+            OrgsListMembersRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgsListMembersRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsListMembersRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => OrgsListMembersRole::Admin,
+            "all" => OrgsListMembersRole::All,
+            "member" => OrgsListMembersRole::Member,
+            _ => OrgsListMembersRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgsListMembersRole {
     fn default() -> OrgsListMembersRole {
         OrgsListMembersRole::All
     }
 }
+impl OrgsListMembersRole {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgsListMembersRole::Unknown(_))
+    }
+}
 
 /**
 * The role to give the user in the organization. Can be one of:  
 *   \* `admin` - The user will become an owner of the organization.  
 *   \* `member` - The user will become a non-owner member of the organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgsSetMembershipUserRequestRole {
     #[serde(rename = "admin")]
     Admin,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
@@ -26414,17 +28257,46 @@ impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
         match self {
             OrgsSetMembershipUserRequestRole::Admin => "admin",
             OrgsSetMembershipUserRequestRole::Member => "member",
-            OrgsSetMembershipUserRequestRole::FallthroughString => "*",
+            OrgsSetMembershipUserRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgsSetMembershipUserRequestRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsSetMembershipUserRequestRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => OrgsSetMembershipUserRequestRole::Admin,
+            "member" => OrgsSetMembershipUserRequestRole::Member,
+            _ => OrgsSetMembershipUserRequestRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgsSetMembershipUserRequestRole {
     fn default() -> OrgsSetMembershipUserRequestRole {
         OrgsSetMembershipUserRequestRole::Member
     }
 }
+impl OrgsSetMembershipUserRequestRole {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgsSetMembershipUserRequestRole::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsSetMembershipUserRequest {
@@ -26440,14 +28312,14 @@ pub struct OrgsSetMembershipUserRequest {
 /**
 * Allowed values that can be passed to the exclude param.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Exclude {
     #[serde(rename = "repositories")]
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Exclude {
@@ -26455,12 +28327,35 @@ impl std::fmt::Display for Exclude {
         match self {
             Exclude::Repositories => "repositories",
             Exclude::Noop => "",
-            Exclude::FallthroughString => "*",
+            Exclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Exclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Exclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "repositories" => Exclude::Repositories,
+            "" => Exclude::Noop,
+            _ => Exclude::Unknown(s),
+        })
+    }
+}
+
 impl Default for Exclude {
     fn default() -> Exclude {
         Exclude::Noop
@@ -26470,6 +28365,11 @@ impl Exclude {
     pub fn is_noop(&self) -> bool {
         matches!(self, Exclude::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Exclude::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26501,14 +28401,14 @@ pub struct MigrationsStartRequest {
 /**
 * The state of the package, either active or deleted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "deleted")]
     Deleted,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
@@ -26516,17 +28416,49 @@ impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
         match self {
             PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
             PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
-            PackagesGetAllPackageVersionsOwnedByOrgState::FallthroughString => "*",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => PackagesGetAllPackageVersionsOwnedByOrgState::Active,
+            "deleted" => PackagesGetAllPackageVersionsOwnedByOrgState::Deleted,
+            _ => PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s),
+        })
+    }
+}
+
 impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
     fn default() -> PackagesGetAllPackageVersionsOwnedByOrgState {
         PackagesGetAllPackageVersionsOwnedByOrgState::Active
     }
 }
+impl PackagesGetAllPackageVersionsOwnedByOrgState {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(_)
+        )
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateRequest {
@@ -26547,7 +28479,7 @@ pub struct ProjectsCreateRequest {
 /**
 * Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListOrgType {
     #[serde(rename = "all")]
     All,
@@ -26565,8 +28497,8 @@ pub enum ReposListOrgType {
     Sources,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgType {
@@ -26580,12 +28512,41 @@ impl std::fmt::Display for ReposListOrgType {
             ReposListOrgType::Public => "public",
             ReposListOrgType::Sources => "sources",
             ReposListOrgType::Noop => "",
-            ReposListOrgType::FallthroughString => "*",
+            ReposListOrgType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListOrgType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListOrgType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ReposListOrgType::All,
+            "forks" => ReposListOrgType::Forks,
+            "internal" => ReposListOrgType::Internal,
+            "member" => ReposListOrgType::Member,
+            "private" => ReposListOrgType::Private,
+            "public" => ReposListOrgType::Public,
+            "sources" => ReposListOrgType::Sources,
+            "" => ReposListOrgType::Noop,
+            _ => ReposListOrgType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListOrgType {
     fn default() -> ReposListOrgType {
         ReposListOrgType::Noop
@@ -26595,12 +28556,17 @@ impl ReposListOrgType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposListOrgType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListOrgType::Unknown(_))
+    }
 }
 
 /**
 * Can be one of `created`, `updated`, `pushed`, `full_name`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListOrgSort {
     #[serde(rename = "created")]
     Created,
@@ -26610,8 +28576,8 @@ pub enum ReposListOrgSort {
     Pushed,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgSort {
@@ -26621,23 +28587,54 @@ impl std::fmt::Display for ReposListOrgSort {
             ReposListOrgSort::FullName => "full_name",
             ReposListOrgSort::Pushed => "pushed",
             ReposListOrgSort::Updated => "updated",
-            ReposListOrgSort::FallthroughString => "*",
+            ReposListOrgSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListOrgSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListOrgSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created" => ReposListOrgSort::Created,
+            "full_name" => ReposListOrgSort::FullName,
+            "pushed" => ReposListOrgSort::Pushed,
+            "updated" => ReposListOrgSort::Updated,
+            _ => ReposListOrgSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListOrgSort {
     fn default() -> ReposListOrgSort {
         ReposListOrgSort::Created
     }
 }
+impl ReposListOrgSort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListOrgSort::Unknown(_))
+    }
+}
 
 /**
 * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
 *   The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposCreateInOrgRequestVisibility {
     #[serde(rename = "internal")]
     Internal,
@@ -26649,8 +28646,8 @@ pub enum ReposCreateInOrgRequestVisibility {
     Visibility,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
@@ -26661,12 +28658,38 @@ impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
             ReposCreateInOrgRequestVisibility::Public => "public",
             ReposCreateInOrgRequestVisibility::Visibility => "visibility",
             ReposCreateInOrgRequestVisibility::Noop => "",
-            ReposCreateInOrgRequestVisibility::FallthroughString => "*",
+            ReposCreateInOrgRequestVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposCreateInOrgRequestVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateInOrgRequestVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "internal" => ReposCreateInOrgRequestVisibility::Internal,
+            "private" => ReposCreateInOrgRequestVisibility::Private,
+            "public" => ReposCreateInOrgRequestVisibility::Public,
+            "visibility" => ReposCreateInOrgRequestVisibility::Visibility,
+            "" => ReposCreateInOrgRequestVisibility::Noop,
+            _ => ReposCreateInOrgRequestVisibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposCreateInOrgRequestVisibility {
     fn default() -> ReposCreateInOrgRequestVisibility {
         ReposCreateInOrgRequestVisibility::Noop
@@ -26676,6 +28699,11 @@ impl ReposCreateInOrgRequestVisibility {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateInOrgRequestVisibility::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposCreateInOrgRequestVisibility::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26752,7 +28780,7 @@ pub struct ReposCreateInOrgRequest {
 *   \* `push` - team members can pull and push, but not administer newly-added repositories.  
 *   \* `admin` - team members can pull, push and administer newly-added repositories.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Permission {
     #[serde(rename = "admin")]
     Admin,
@@ -26760,8 +28788,8 @@ pub enum Permission {
     Pull,
     #[serde(rename = "push")]
     Push,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Permission {
@@ -26770,17 +28798,47 @@ impl std::fmt::Display for Permission {
             Permission::Admin => "admin",
             Permission::Pull => "pull",
             Permission::Push => "push",
-            Permission::FallthroughString => "*",
+            Permission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => Permission::Admin,
+            "pull" => Permission::Pull,
+            "push" => Permission::Push,
+            _ => Permission::Unknown(s),
+        })
+    }
+}
+
 impl Default for Permission {
     fn default() -> Permission {
         Permission::Pull
     }
 }
+impl Permission {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Permission::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsCreateRequest {
@@ -26919,7 +28977,7 @@ pub struct ReactionsCreateIssueRequest {
 *   \* `maintainer` - team maintainers.  
 *   \* `all` - all members of the team.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TeamsListMembersInOrgRole {
     #[serde(rename = "all")]
     All,
@@ -26927,8 +28985,8 @@ pub enum TeamsListMembersInOrgRole {
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsListMembersInOrgRole {
@@ -26937,17 +28995,47 @@ impl std::fmt::Display for TeamsListMembersInOrgRole {
             TeamsListMembersInOrgRole::All => "all",
             TeamsListMembersInOrgRole::Maintainer => "maintainer",
             TeamsListMembersInOrgRole::Member => "member",
-            TeamsListMembersInOrgRole::FallthroughString => "*",
+            TeamsListMembersInOrgRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TeamsListMembersInOrgRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamsListMembersInOrgRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => TeamsListMembersInOrgRole::All,
+            "maintainer" => TeamsListMembersInOrgRole::Maintainer,
+            "member" => TeamsListMembersInOrgRole::Member,
+            _ => TeamsListMembersInOrgRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for TeamsListMembersInOrgRole {
     fn default() -> TeamsListMembersInOrgRole {
         TeamsListMembersInOrgRole::All
     }
 }
+impl TeamsListMembersInOrgRole {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TeamsListMembersInOrgRole::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsAddUpdateMembershipUserInOrgRequest {
@@ -26974,7 +29062,7 @@ pub struct ProjectsAddCollaboratorRequest {
 *     
 *   If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -26988,8 +29076,8 @@ pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     Triage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
@@ -27001,12 +29089,39 @@ impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push => "push",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage => "triage",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop => "",
-            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::FallthroughString => "*",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Admin,
+            "maintain" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain,
+            "pull" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Pull,
+            "push" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push,
+            "triage" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage,
+            "" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop,
+            _ => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s),
+        })
+    }
+}
+
 impl Default for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     fn default() -> TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
         TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop
@@ -27019,6 +29134,14 @@ impl TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop
         )
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27232,7 +29355,7 @@ pub struct ProjectsUpdateColumnRequest {
 /**
 * Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ArchivedState {
     #[serde(rename = "all")]
     All,
@@ -27240,8 +29363,8 @@ pub enum ArchivedState {
     Archived,
     #[serde(rename = "not_archived")]
     NotArchived,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchivedState {
@@ -27250,17 +29373,47 @@ impl std::fmt::Display for ArchivedState {
             ArchivedState::All => "all",
             ArchivedState::Archived => "archived",
             ArchivedState::NotArchived => "not_archived",
-            ArchivedState::FallthroughString => "*",
+            ArchivedState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ArchivedState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ArchivedState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ArchivedState::All,
+            "archived" => ArchivedState::Archived,
+            "not_archived" => ArchivedState::NotArchived,
+            _ => ArchivedState::Unknown(s),
+        })
+    }
+}
+
 impl Default for ArchivedState {
     fn default() -> ArchivedState {
         ArchivedState::NotArchived
     }
 }
+impl ArchivedState {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ArchivedState::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateCardRequest {
@@ -27363,7 +29516,7 @@ pub struct ProjectsUpdateRequest {
 *   \* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
 *   \* `all`: All collaborators the authenticated user can see.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Affiliation {
     #[serde(rename = "all")]
     All,
@@ -27371,8 +29524,8 @@ pub enum Affiliation {
     Direct,
     #[serde(rename = "outside")]
     Outside,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Affiliation {
@@ -27381,17 +29534,47 @@ impl std::fmt::Display for Affiliation {
             Affiliation::All => "all",
             Affiliation::Direct => "direct",
             Affiliation::Outside => "outside",
-            Affiliation::FallthroughString => "*",
+            Affiliation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Affiliation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Affiliation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => Affiliation::All,
+            "direct" => Affiliation::Direct,
+            "outside" => Affiliation::Outside,
+            _ => Affiliation::Unknown(s),
+        })
+    }
+}
+
 impl Default for Affiliation {
     fn default() -> Affiliation {
         Affiliation::All
     }
 }
+impl Affiliation {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Affiliation::Unknown(_))
+    }
+}
 
 /// Use the `status` property to enable or disable GitHub Advanced Security for this repository. For more information, see "[About GitHub Advanced Security](/github/getting-started-with-github/learning-about-github/about-github-advanced-security)."
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27528,14 +29711,14 @@ pub struct ActionsListWorkflowRunsResponse {
 *   \* `latest`: Returns jobs from the most recent execution of the workflow run.  
 *   \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ActionsListJobsWorkflowRunFilter {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "latest")]
     Latest,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
@@ -27543,17 +29726,46 @@ impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
         match self {
             ActionsListJobsWorkflowRunFilter::All => "all",
             ActionsListJobsWorkflowRunFilter::Latest => "latest",
-            ActionsListJobsWorkflowRunFilter::FallthroughString => "*",
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ActionsListJobsWorkflowRunFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ActionsListJobsWorkflowRunFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ActionsListJobsWorkflowRunFilter::All,
+            "latest" => ActionsListJobsWorkflowRunFilter::Latest,
+            _ => ActionsListJobsWorkflowRunFilter::Unknown(s),
+        })
+    }
+}
+
 impl Default for ActionsListJobsWorkflowRunFilter {
     fn default() -> ActionsListJobsWorkflowRunFilter {
         ActionsListJobsWorkflowRunFilter::Latest
     }
 }
+impl ActionsListJobsWorkflowRunFilter {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ActionsListJobsWorkflowRunFilter::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsListJobsWorkflowRunResponse {
@@ -28122,7 +30334,7 @@ pub struct ReposRenameBranchRequest {
 * **Required if you provide `completed_at` or a `status` of `completed`**. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`. When the conclusion is `action_required`, additional details should be provided on the site specified by `details_url`.  
 *   **Note:** Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ChecksCreateRequestConclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -28142,8 +30354,8 @@ pub enum ChecksCreateRequestConclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChecksCreateRequestConclusion {
@@ -28158,12 +30370,42 @@ impl std::fmt::Display for ChecksCreateRequestConclusion {
             ChecksCreateRequestConclusion::Success => "success",
             ChecksCreateRequestConclusion::TimedOut => "timed_out",
             ChecksCreateRequestConclusion::Noop => "",
-            ChecksCreateRequestConclusion::FallthroughString => "*",
+            ChecksCreateRequestConclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ChecksCreateRequestConclusion {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChecksCreateRequestConclusion {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "action_required" => ChecksCreateRequestConclusion::ActionRequired,
+            "cancelled" => ChecksCreateRequestConclusion::Cancelled,
+            "failure" => ChecksCreateRequestConclusion::Failure,
+            "neutral" => ChecksCreateRequestConclusion::Neutral,
+            "skipped" => ChecksCreateRequestConclusion::Skipped,
+            "stale" => ChecksCreateRequestConclusion::Stale,
+            "success" => ChecksCreateRequestConclusion::Success,
+            "timed_out" => ChecksCreateRequestConclusion::TimedOut,
+            "" => ChecksCreateRequestConclusion::Noop,
+            _ => ChecksCreateRequestConclusion::Unknown(s),
+        })
+    }
+}
+
 impl Default for ChecksCreateRequestConclusion {
     fn default() -> ChecksCreateRequestConclusion {
         ChecksCreateRequestConclusion::Noop
@@ -28173,12 +30415,17 @@ impl ChecksCreateRequestConclusion {
     pub fn is_noop(&self) -> bool {
         matches!(self, ChecksCreateRequestConclusion::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ChecksCreateRequestConclusion::Unknown(_))
+    }
 }
 
 /**
 * The level of the annotation. Can be one of `notice`, `warning`, or `failure`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AnnotationLevel {
     #[serde(rename = "failure")]
     Failure,
@@ -28188,8 +30435,8 @@ pub enum AnnotationLevel {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnnotationLevel {
@@ -28199,12 +30446,37 @@ impl std::fmt::Display for AnnotationLevel {
             AnnotationLevel::Notice => "notice",
             AnnotationLevel::Warning => "warning",
             AnnotationLevel::Noop => "",
-            AnnotationLevel::FallthroughString => "*",
+            AnnotationLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AnnotationLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AnnotationLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "failure" => AnnotationLevel::Failure,
+            "notice" => AnnotationLevel::Notice,
+            "warning" => AnnotationLevel::Warning,
+            "" => AnnotationLevel::Noop,
+            _ => AnnotationLevel::Unknown(s),
+        })
+    }
+}
+
 impl Default for AnnotationLevel {
     fn default() -> AnnotationLevel {
         AnnotationLevel::Noop
@@ -28214,6 +30486,11 @@ impl AnnotationLevel {
     pub fn is_noop(&self) -> bool {
         matches!(self, AnnotationLevel::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AnnotationLevel::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28846,7 +31123,7 @@ pub struct ReposCreateDeploymentRequest {
 /**
 * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. **Note:** This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposCreateDeploymentStatusRequestEnvironment {
     #[serde(rename = "production")]
     Production,
@@ -28856,8 +31133,8 @@ pub enum ReposCreateDeploymentStatusRequestEnvironment {
     Staging,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
@@ -28867,12 +31144,37 @@ impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
             ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
             ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
             ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
-            ReposCreateDeploymentStatusRequestEnvironment::FallthroughString => "*",
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposCreateDeploymentStatusRequestEnvironment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateDeploymentStatusRequestEnvironment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "production" => ReposCreateDeploymentStatusRequestEnvironment::Production,
+            "qa" => ReposCreateDeploymentStatusRequestEnvironment::Qa,
+            "staging" => ReposCreateDeploymentStatusRequestEnvironment::Staging,
+            "" => ReposCreateDeploymentStatusRequestEnvironment::Noop,
+            _ => ReposCreateDeploymentStatusRequestEnvironment::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposCreateDeploymentStatusRequestEnvironment {
     fn default() -> ReposCreateDeploymentStatusRequestEnvironment {
         ReposCreateDeploymentStatusRequestEnvironment::Noop
@@ -28882,6 +31184,14 @@ impl ReposCreateDeploymentStatusRequestEnvironment {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateDeploymentStatusRequestEnvironment::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28994,7 +31304,7 @@ pub struct ReposCreateUpdateEnvironmentRequest {
 /**
 * The sort order. Can be either `newest`, `oldest`, or `stargazers`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListForksSort {
     #[serde(rename = "newest")]
     Newest,
@@ -29004,8 +31314,8 @@ pub enum ReposListForksSort {
     Stargazers,
     #[serde(rename = "watchers")]
     Watchers,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListForksSort {
@@ -29015,17 +31325,48 @@ impl std::fmt::Display for ReposListForksSort {
             ReposListForksSort::Oldest => "oldest",
             ReposListForksSort::Stargazers => "stargazers",
             ReposListForksSort::Watchers => "watchers",
-            ReposListForksSort::FallthroughString => "*",
+            ReposListForksSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListForksSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListForksSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "newest" => ReposListForksSort::Newest,
+            "oldest" => ReposListForksSort::Oldest,
+            "stargazers" => ReposListForksSort::Stargazers,
+            "watchers" => ReposListForksSort::Watchers,
+            _ => ReposListForksSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListForksSort {
     fn default() -> ReposListForksSort {
         ReposListForksSort::Newest
     }
 }
+impl ReposListForksSort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListForksSort::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateForkRequest {
@@ -29184,7 +31525,7 @@ pub struct GitUpdateRefRequest {
 /**
 * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GitCreateTagRequestType {
     #[serde(rename = "blob")]
     Blob,
@@ -29194,8 +31535,8 @@ pub enum GitCreateTagRequestType {
     Tree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTagRequestType {
@@ -29205,12 +31546,37 @@ impl std::fmt::Display for GitCreateTagRequestType {
             GitCreateTagRequestType::Commit => "commit",
             GitCreateTagRequestType::Tree => "tree",
             GitCreateTagRequestType::Noop => "",
-            GitCreateTagRequestType::FallthroughString => "*",
+            GitCreateTagRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GitCreateTagRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GitCreateTagRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blob" => GitCreateTagRequestType::Blob,
+            "commit" => GitCreateTagRequestType::Commit,
+            "tree" => GitCreateTagRequestType::Tree,
+            "" => GitCreateTagRequestType::Noop,
+            _ => GitCreateTagRequestType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GitCreateTagRequestType {
     fn default() -> GitCreateTagRequestType {
         GitCreateTagRequestType::Noop
@@ -29220,6 +31586,11 @@ impl GitCreateTagRequestType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GitCreateTagRequestType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GitCreateTagRequestType::Unknown(_))
+    }
 }
 
 /// An object with information about the individual creating the tag.
@@ -29287,7 +31658,7 @@ pub struct GitCreateTagRequest {
 /**
 * The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GitCreateTreeRequestMode {
     #[serde(rename = "040000")]
     SubdirectoryTree,
@@ -29301,8 +31672,8 @@ pub enum GitCreateTreeRequestMode {
     SubmoduleCommit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTreeRequestMode {
@@ -29314,12 +31685,39 @@ impl std::fmt::Display for GitCreateTreeRequestMode {
             GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
             GitCreateTreeRequestMode::SubmoduleCommit => "160000",
             GitCreateTreeRequestMode::Noop => "",
-            GitCreateTreeRequestMode::FallthroughString => "*",
+            GitCreateTreeRequestMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GitCreateTreeRequestMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GitCreateTreeRequestMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "040000" => GitCreateTreeRequestMode::SubdirectoryTree,
+            "100644" => GitCreateTreeRequestMode::FileBlob,
+            "100755" => GitCreateTreeRequestMode::ExecutableBlob,
+            "120000" => GitCreateTreeRequestMode::SymlinkPathBlob,
+            "160000" => GitCreateTreeRequestMode::SubmoduleCommit,
+            "" => GitCreateTreeRequestMode::Noop,
+            _ => GitCreateTreeRequestMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for GitCreateTreeRequestMode {
     fn default() -> GitCreateTreeRequestMode {
         GitCreateTreeRequestMode::Noop
@@ -29329,6 +31727,11 @@ impl GitCreateTreeRequestMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, GitCreateTreeRequestMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GitCreateTreeRequestMode::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29523,7 +31926,7 @@ pub struct ReposUpdateWebhookRequest {
 /**
 * The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Vcs {
     #[serde(rename = "git")]
     Git,
@@ -29535,8 +31938,8 @@ pub enum Vcs {
     Tfvc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Vcs {
@@ -29547,12 +31950,38 @@ impl std::fmt::Display for Vcs {
             Vcs::Subversion => "subversion",
             Vcs::Tfvc => "tfvc",
             Vcs::Noop => "",
-            Vcs::FallthroughString => "*",
+            Vcs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Vcs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Vcs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "git" => Vcs::Git,
+            "mercurial" => Vcs::Mercurial,
+            "subversion" => Vcs::Subversion,
+            "tfvc" => Vcs::Tfvc,
+            "" => Vcs::Noop,
+            _ => Vcs::Unknown(s),
+        })
+    }
+}
+
 impl Default for Vcs {
     fn default() -> Vcs {
         Vcs::Noop
@@ -29562,6 +31991,11 @@ impl Vcs {
     pub fn is_noop(&self) -> bool {
         matches!(self, Vcs::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Vcs::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29628,7 +32062,7 @@ pub struct MigrationsUpdateImportRequest {
 /**
 * Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UseLfs {
     #[serde(rename = "opt_in")]
     OptIn,
@@ -29636,8 +32070,8 @@ pub enum UseLfs {
     OptOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UseLfs {
@@ -29646,12 +32080,36 @@ impl std::fmt::Display for UseLfs {
             UseLfs::OptIn => "opt_in",
             UseLfs::OptOut => "opt_out",
             UseLfs::Noop => "",
-            UseLfs::FallthroughString => "*",
+            UseLfs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UseLfs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UseLfs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "opt_in" => UseLfs::OptIn,
+            "opt_out" => UseLfs::OptOut,
+            "" => UseLfs::Noop,
+            _ => UseLfs::Unknown(s),
+        })
+    }
+}
+
 impl Default for UseLfs {
     fn default() -> UseLfs {
         UseLfs::Noop
@@ -29661,6 +32119,11 @@ impl UseLfs {
     pub fn is_noop(&self) -> bool {
         matches!(self, UseLfs::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UseLfs::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29996,7 +32459,7 @@ pub struct IssuesSetLabelsRequestAnyOf {
 *   \* `resolved`  
 *   \* `spam`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LockReason {
     #[serde(rename = "off-topic")]
     OffTopic,
@@ -30008,8 +32471,8 @@ pub enum LockReason {
     TooHeated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LockReason {
@@ -30020,12 +32483,38 @@ impl std::fmt::Display for LockReason {
             LockReason::Spam => "spam",
             LockReason::TooHeated => "too heated",
             LockReason::Noop => "",
-            LockReason::FallthroughString => "*",
+            LockReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LockReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LockReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "off-topic" => LockReason::OffTopic,
+            "resolved" => LockReason::Resolved,
+            "spam" => LockReason::Spam,
+            "too heated" => LockReason::TooHeated,
+            "" => LockReason::Noop,
+            _ => LockReason::Unknown(s),
+        })
+    }
+}
+
 impl Default for LockReason {
     fn default() -> LockReason {
         LockReason::Noop
@@ -30035,6 +32524,11 @@ impl LockReason {
     pub fn is_noop(&self) -> bool {
         matches!(self, LockReason::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LockReason::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30130,14 +32624,14 @@ pub struct ReposMergeRequest {
 /**
 * What to sort results by. Either `due_on` or `completeness`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IssuesListMilestonesSort {
     #[serde(rename = "completeness")]
     Completeness,
     #[serde(rename = "due_on")]
     DueOn,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListMilestonesSort {
@@ -30145,17 +32639,46 @@ impl std::fmt::Display for IssuesListMilestonesSort {
         match self {
             IssuesListMilestonesSort::Completeness => "completeness",
             IssuesListMilestonesSort::DueOn => "due_on",
-            IssuesListMilestonesSort::FallthroughString => "*",
+            IssuesListMilestonesSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IssuesListMilestonesSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListMilestonesSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "completeness" => IssuesListMilestonesSort::Completeness,
+            "due_on" => IssuesListMilestonesSort::DueOn,
+            _ => IssuesListMilestonesSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for IssuesListMilestonesSort {
     fn default() -> IssuesListMilestonesSort {
         IssuesListMilestonesSort::DueOn
     }
 }
+impl IssuesListMilestonesSort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IssuesListMilestonesSort::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesCreateMilestoneRequest {
@@ -30213,14 +32736,14 @@ pub struct PullsUpdateBranchResponse {
 /**
 * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Path {
     #[serde(rename = "/")]
     Root,
     #[serde(rename = "/docs")]
     Docs,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Path {
@@ -30228,17 +32751,46 @@ impl std::fmt::Display for Path {
         match self {
             Path::Root => "/",
             Path::Docs => "/docs",
-            Path::FallthroughString => "*",
+            Path::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "/" => Path::Root,
+            "/docs" => Path::Docs,
+            _ => Path::Unknown(s),
+        })
+    }
+}
+
 impl Default for Path {
     fn default() -> Path {
         Path::Root
     }
 }
+impl Path {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Path::Unknown(_))
+    }
+}
 
 /// The source branch and directory used to publish your Pages site.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30269,7 +32821,7 @@ pub struct ReposCreatePagesSiteRequest {
 /**
 * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SourceData {
     #[serde(rename = "gh-pages")]
     GhPages,
@@ -30279,8 +32831,8 @@ pub enum SourceData {
     MasterDocs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceData {
@@ -30290,12 +32842,37 @@ impl std::fmt::Display for SourceData {
             SourceData::Master => "master",
             SourceData::MasterDocs => "master /docs",
             SourceData::Noop => "",
-            SourceData::FallthroughString => "*",
+            SourceData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SourceData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "gh-pages" => SourceData::GhPages,
+            "master" => SourceData::Master,
+            "master /docs" => SourceData::MasterDocs,
+            "" => SourceData::Noop,
+            _ => SourceData::Unknown(s),
+        })
+    }
+}
+
 impl Default for SourceData {
     fn default() -> SourceData {
         SourceData::Noop
@@ -30305,6 +32882,11 @@ impl SourceData {
     pub fn is_noop(&self) -> bool {
         matches!(self, SourceData::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SourceData::Unknown(_))
+    }
 }
 
 /// Update the source for the repository. Must include the branch name and path.
@@ -30361,7 +32943,7 @@ pub struct ReposUpdateInformationAboutPagesSiteRequest {
 /**
 * What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PullsListSort {
     #[serde(rename = "created")]
     Created,
@@ -30371,8 +32953,8 @@ pub enum PullsListSort {
     Popularity,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListSort {
@@ -30382,17 +32964,48 @@ impl std::fmt::Display for PullsListSort {
             PullsListSort::LongRunning => "long-running",
             PullsListSort::Popularity => "popularity",
             PullsListSort::Updated => "updated",
-            PullsListSort::FallthroughString => "*",
+            PullsListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PullsListSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsListSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created" => PullsListSort::Created,
+            "long-running" => PullsListSort::LongRunning,
+            "popularity" => PullsListSort::Popularity,
+            "updated" => PullsListSort::Updated,
+            _ => PullsListSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for PullsListSort {
     fn default() -> PullsListSort {
         PullsListSort::Created
     }
 }
+impl PullsListSort {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PullsListSort::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsCreateRequest {
@@ -30432,7 +33045,7 @@ pub struct PullsCreateRequest {
     pub title: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PullsListReviewCommentsRepoSort {
     #[serde(rename = "created")]
     Created,
@@ -30442,8 +33055,8 @@ pub enum PullsListReviewCommentsRepoSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListReviewCommentsRepoSort {
@@ -30453,12 +33066,37 @@ impl std::fmt::Display for PullsListReviewCommentsRepoSort {
             PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
             PullsListReviewCommentsRepoSort::Updated => "updated",
             PullsListReviewCommentsRepoSort::Noop => "",
-            PullsListReviewCommentsRepoSort::FallthroughString => "*",
+            PullsListReviewCommentsRepoSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PullsListReviewCommentsRepoSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsListReviewCommentsRepoSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created" => PullsListReviewCommentsRepoSort::Created,
+            "created_at" => PullsListReviewCommentsRepoSort::CreatedAt,
+            "updated" => PullsListReviewCommentsRepoSort::Updated,
+            "" => PullsListReviewCommentsRepoSort::Noop,
+            _ => PullsListReviewCommentsRepoSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for PullsListReviewCommentsRepoSort {
     fn default() -> PullsListReviewCommentsRepoSort {
         PullsListReviewCommentsRepoSort::Noop
@@ -30468,6 +33106,11 @@ impl PullsListReviewCommentsRepoSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsListReviewCommentsRepoSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PullsListReviewCommentsRepoSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30502,7 +33145,7 @@ pub struct PullsUpdateRequest {
 /**
 * **Required when using multi-line comments**. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PullsCreateReviewCommentRequestStartSide {
     #[serde(rename = "LEFT")]
     Left,
@@ -30512,8 +33155,8 @@ pub enum PullsCreateReviewCommentRequestStartSide {
     Side,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
@@ -30523,12 +33166,37 @@ impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
             PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
             PullsCreateReviewCommentRequestStartSide::Side => "side",
             PullsCreateReviewCommentRequestStartSide::Noop => "",
-            PullsCreateReviewCommentRequestStartSide::FallthroughString => "*",
+            PullsCreateReviewCommentRequestStartSide::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PullsCreateReviewCommentRequestStartSide {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsCreateReviewCommentRequestStartSide {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LEFT" => PullsCreateReviewCommentRequestStartSide::Left,
+            "RIGHT" => PullsCreateReviewCommentRequestStartSide::Right,
+            "side" => PullsCreateReviewCommentRequestStartSide::Side,
+            "" => PullsCreateReviewCommentRequestStartSide::Noop,
+            _ => PullsCreateReviewCommentRequestStartSide::Unknown(s),
+        })
+    }
+}
+
 impl Default for PullsCreateReviewCommentRequestStartSide {
     fn default() -> PullsCreateReviewCommentRequestStartSide {
         PullsCreateReviewCommentRequestStartSide::Noop
@@ -30538,6 +33206,11 @@ impl PullsCreateReviewCommentRequestStartSide {
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsCreateReviewCommentRequestStartSide::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PullsCreateReviewCommentRequestStartSide::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30667,7 +33340,7 @@ pub struct PullsRemoveRequestedReviewersRequest {
 /**
 * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PullsCreateReviewRequestEvent {
     #[serde(rename = "APPROVE")]
     Approve,
@@ -30677,8 +33350,8 @@ pub enum PullsCreateReviewRequestEvent {
     RequestChanges,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewRequestEvent {
@@ -30688,12 +33361,37 @@ impl std::fmt::Display for PullsCreateReviewRequestEvent {
             PullsCreateReviewRequestEvent::Comment => "COMMENT",
             PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
             PullsCreateReviewRequestEvent::Noop => "",
-            PullsCreateReviewRequestEvent::FallthroughString => "*",
+            PullsCreateReviewRequestEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PullsCreateReviewRequestEvent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsCreateReviewRequestEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "APPROVE" => PullsCreateReviewRequestEvent::Approve,
+            "COMMENT" => PullsCreateReviewRequestEvent::Comment,
+            "REQUEST_CHANGES" => PullsCreateReviewRequestEvent::RequestChanges,
+            "" => PullsCreateReviewRequestEvent::Noop,
+            _ => PullsCreateReviewRequestEvent::Unknown(s),
+        })
+    }
+}
+
 impl Default for PullsCreateReviewRequestEvent {
     fn default() -> PullsCreateReviewRequestEvent {
         PullsCreateReviewRequestEvent::Noop
@@ -30703,6 +33401,11 @@ impl PullsCreateReviewRequestEvent {
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsCreateReviewRequestEvent::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PullsCreateReviewRequestEvent::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30888,7 +33591,7 @@ pub struct ReposUpdateReleaseAssetRequest {
 /**
 * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReactionsCreateReleaseRequestContent {
     #[serde(rename = "+1")]
     PlusOne,
@@ -30904,8 +33607,8 @@ pub enum ReactionsCreateReleaseRequestContent {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
@@ -30918,12 +33621,40 @@ impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
             ReactionsCreateReleaseRequestContent::Laugh => "laugh",
             ReactionsCreateReleaseRequestContent::Rocket => "rocket",
             ReactionsCreateReleaseRequestContent::Noop => "",
-            ReactionsCreateReleaseRequestContent::FallthroughString => "*",
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReactionsCreateReleaseRequestContent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactionsCreateReleaseRequestContent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "+1" => ReactionsCreateReleaseRequestContent::PlusOne,
+            "eyes" => ReactionsCreateReleaseRequestContent::Eyes,
+            "heart" => ReactionsCreateReleaseRequestContent::Heart,
+            "hooray" => ReactionsCreateReleaseRequestContent::Hooray,
+            "laugh" => ReactionsCreateReleaseRequestContent::Laugh,
+            "rocket" => ReactionsCreateReleaseRequestContent::Rocket,
+            "" => ReactionsCreateReleaseRequestContent::Noop,
+            _ => ReactionsCreateReleaseRequestContent::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReactionsCreateReleaseRequestContent {
     fn default() -> ReactionsCreateReleaseRequestContent {
         ReactionsCreateReleaseRequestContent::Noop
@@ -30933,6 +33664,11 @@ impl ReactionsCreateReleaseRequestContent {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReactionsCreateReleaseRequestContent::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReactionsCreateReleaseRequestContent::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30977,7 +33713,7 @@ pub struct ActivityListStargazersRepoResponseAnyOf {
 /**
 * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposCreateCommitStatusRequestState {
     #[serde(rename = "error")]
     Error,
@@ -30989,8 +33725,8 @@ pub enum ReposCreateCommitStatusRequestState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateCommitStatusRequestState {
@@ -31001,12 +33737,38 @@ impl std::fmt::Display for ReposCreateCommitStatusRequestState {
             ReposCreateCommitStatusRequestState::Pending => "pending",
             ReposCreateCommitStatusRequestState::Success => "success",
             ReposCreateCommitStatusRequestState::Noop => "",
-            ReposCreateCommitStatusRequestState::FallthroughString => "*",
+            ReposCreateCommitStatusRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposCreateCommitStatusRequestState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateCommitStatusRequestState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "error" => ReposCreateCommitStatusRequestState::Error,
+            "failure" => ReposCreateCommitStatusRequestState::Failure,
+            "pending" => ReposCreateCommitStatusRequestState::Pending,
+            "success" => ReposCreateCommitStatusRequestState::Success,
+            "" => ReposCreateCommitStatusRequestState::Noop,
+            _ => ReposCreateCommitStatusRequestState::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposCreateCommitStatusRequestState {
     fn default() -> ReposCreateCommitStatusRequestState {
         ReposCreateCommitStatusRequestState::Noop
@@ -31016,6 +33778,11 @@ impl ReposCreateCommitStatusRequestState {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateCommitStatusRequestState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposCreateCommitStatusRequestState::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31127,7 +33894,7 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
     pub schemas: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     #[serde(rename = "Add")]
     Add,
@@ -31137,8 +33904,8 @@ pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
@@ -31148,12 +33915,37 @@ impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsO
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
-            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::FallthroughString => "*",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Add" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add,
+            "Remove" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove,
+            "Replace" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace,
+            "" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop,
+            _ => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s),
+        })
+    }
+}
+
 impl Default for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     fn default() -> EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
         EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
@@ -31166,6 +33958,14 @@ impl EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
         )
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31491,14 +34291,14 @@ pub struct ScimUpdateAttributeUserRequest {
 /**
 * Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SearchCodeSort {
     #[serde(rename = "indexed")]
     Indexed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCodeSort {
@@ -31506,12 +34306,35 @@ impl std::fmt::Display for SearchCodeSort {
         match self {
             SearchCodeSort::Indexed => "indexed",
             SearchCodeSort::Noop => "",
-            SearchCodeSort::FallthroughString => "*",
+            SearchCodeSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SearchCodeSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchCodeSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "indexed" => SearchCodeSort::Indexed,
+            "" => SearchCodeSort::Noop,
+            _ => SearchCodeSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for SearchCodeSort {
     fn default() -> SearchCodeSort {
         SearchCodeSort::Noop
@@ -31521,6 +34344,11 @@ impl SearchCodeSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchCodeSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SearchCodeSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31547,7 +34375,7 @@ pub struct SearchCodeResponse {
 /**
 * Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SearchCommitsSort {
     #[serde(rename = "author-date")]
     AuthorDate,
@@ -31555,8 +34383,8 @@ pub enum SearchCommitsSort {
     CommitterDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCommitsSort {
@@ -31565,12 +34393,36 @@ impl std::fmt::Display for SearchCommitsSort {
             SearchCommitsSort::AuthorDate => "author-date",
             SearchCommitsSort::CommitterDate => "committer-date",
             SearchCommitsSort::Noop => "",
-            SearchCommitsSort::FallthroughString => "*",
+            SearchCommitsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SearchCommitsSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchCommitsSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "author-date" => SearchCommitsSort::AuthorDate,
+            "committer-date" => SearchCommitsSort::CommitterDate,
+            "" => SearchCommitsSort::Noop,
+            _ => SearchCommitsSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for SearchCommitsSort {
     fn default() -> SearchCommitsSort {
         SearchCommitsSort::Noop
@@ -31580,6 +34432,11 @@ impl SearchCommitsSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchCommitsSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SearchCommitsSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31606,7 +34463,7 @@ pub struct SearchCommitsResponse {
 /**
 * Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SearchIssuesPullRequestsSort {
     #[serde(rename = "comments")]
     Comments,
@@ -31632,8 +34489,8 @@ pub enum SearchIssuesPullRequestsSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchIssuesPullRequestsSort {
@@ -31651,12 +34508,45 @@ impl std::fmt::Display for SearchIssuesPullRequestsSort {
             SearchIssuesPullRequestsSort::ReactionsThinkingFace => "reactions-thinking_face",
             SearchIssuesPullRequestsSort::Updated => "updated",
             SearchIssuesPullRequestsSort::Noop => "",
-            SearchIssuesPullRequestsSort::FallthroughString => "*",
+            SearchIssuesPullRequestsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SearchIssuesPullRequestsSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchIssuesPullRequestsSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "comments" => SearchIssuesPullRequestsSort::Comments,
+            "created" => SearchIssuesPullRequestsSort::Created,
+            "interactions" => SearchIssuesPullRequestsSort::Interactions,
+            "reactions" => SearchIssuesPullRequestsSort::Reactions,
+            "reactions-+1" => SearchIssuesPullRequestsSort::ReactionsPlusOne,
+            "reactions--1" => SearchIssuesPullRequestsSort::ReactionsMinusOne,
+            "reactions-heart" => SearchIssuesPullRequestsSort::ReactionsHeart,
+            "reactions-smile" => SearchIssuesPullRequestsSort::ReactionsSmile,
+            "reactions-tada" => SearchIssuesPullRequestsSort::ReactionsTada,
+            "reactions-thinking_face" => SearchIssuesPullRequestsSort::ReactionsThinkingFace,
+            "updated" => SearchIssuesPullRequestsSort::Updated,
+            "" => SearchIssuesPullRequestsSort::Noop,
+            _ => SearchIssuesPullRequestsSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for SearchIssuesPullRequestsSort {
     fn default() -> SearchIssuesPullRequestsSort {
         SearchIssuesPullRequestsSort::Noop
@@ -31666,6 +34556,11 @@ impl SearchIssuesPullRequestsSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchIssuesPullRequestsSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SearchIssuesPullRequestsSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31713,7 +34608,7 @@ pub struct SearchLabelsResponse {
 /**
 * Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SearchReposSort {
     #[serde(rename = "forks")]
     Forks,
@@ -31725,8 +34620,8 @@ pub enum SearchReposSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchReposSort {
@@ -31737,12 +34632,38 @@ impl std::fmt::Display for SearchReposSort {
             SearchReposSort::Stars => "stars",
             SearchReposSort::Updated => "updated",
             SearchReposSort::Noop => "",
-            SearchReposSort::FallthroughString => "*",
+            SearchReposSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SearchReposSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchReposSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "forks" => SearchReposSort::Forks,
+            "help-wanted-issues" => SearchReposSort::HelpWantedIssues,
+            "stars" => SearchReposSort::Stars,
+            "updated" => SearchReposSort::Updated,
+            "" => SearchReposSort::Noop,
+            _ => SearchReposSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for SearchReposSort {
     fn default() -> SearchReposSort {
         SearchReposSort::Noop
@@ -31752,6 +34673,11 @@ impl SearchReposSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchReposSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SearchReposSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31799,7 +34725,7 @@ pub struct SearchTopicsResponse {
 /**
 * Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SearchUsersSort {
     #[serde(rename = "followers")]
     Followers,
@@ -31809,8 +34735,8 @@ pub enum SearchUsersSort {
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchUsersSort {
@@ -31820,12 +34746,37 @@ impl std::fmt::Display for SearchUsersSort {
             SearchUsersSort::Joined => "joined",
             SearchUsersSort::Repositories => "repositories",
             SearchUsersSort::Noop => "",
-            SearchUsersSort::FallthroughString => "*",
+            SearchUsersSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SearchUsersSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchUsersSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "followers" => SearchUsersSort::Followers,
+            "joined" => SearchUsersSort::Joined,
+            "repositories" => SearchUsersSort::Repositories,
+            "" => SearchUsersSort::Noop,
+            _ => SearchUsersSort::Unknown(s),
+        })
+    }
+}
+
 impl Default for SearchUsersSort {
     fn default() -> SearchUsersSort {
         SearchUsersSort::Noop
@@ -31835,6 +34786,11 @@ impl SearchUsersSort {
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchUsersSort::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SearchUsersSort::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32140,14 +35096,14 @@ pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
 /**
 * The state that the membership should be in. Only `"active"` will be accepted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrgsUpdateMembershipRequestState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsUpdateMembershipRequestState {
@@ -32155,12 +35111,35 @@ impl std::fmt::Display for OrgsUpdateMembershipRequestState {
         match self {
             OrgsUpdateMembershipRequestState::Active => "active",
             OrgsUpdateMembershipRequestState::Noop => "",
-            OrgsUpdateMembershipRequestState::FallthroughString => "*",
+            OrgsUpdateMembershipRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrgsUpdateMembershipRequestState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsUpdateMembershipRequestState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => OrgsUpdateMembershipRequestState::Active,
+            "" => OrgsUpdateMembershipRequestState::Noop,
+            _ => OrgsUpdateMembershipRequestState::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrgsUpdateMembershipRequestState {
     fn default() -> OrgsUpdateMembershipRequestState {
         OrgsUpdateMembershipRequestState::Noop
@@ -32170,6 +35149,11 @@ impl OrgsUpdateMembershipRequestState {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrgsUpdateMembershipRequestState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrgsUpdateMembershipRequestState::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32187,7 +35171,7 @@ pub struct OrgsUpdateMembershipRequest {
 /**
 * Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListVisibility {
     #[serde(rename = "all")]
     All,
@@ -32195,8 +35179,8 @@ pub enum ReposListVisibility {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListVisibility {
@@ -32205,24 +35189,54 @@ impl std::fmt::Display for ReposListVisibility {
             ReposListVisibility::All => "all",
             ReposListVisibility::Private => "private",
             ReposListVisibility::Public => "public",
-            ReposListVisibility::FallthroughString => "*",
+            ReposListVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ReposListVisibility::All,
+            "private" => ReposListVisibility::Private,
+            "public" => ReposListVisibility::Public,
+            _ => ReposListVisibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListVisibility {
     fn default() -> ReposListVisibility {
         ReposListVisibility::All
     }
 }
+impl ReposListVisibility {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListVisibility::Unknown(_))
+    }
+}
 
 /**
 * Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
 *     
 *   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListType {
     #[serde(rename = "all")]
     All,
@@ -32234,8 +35248,8 @@ pub enum ReposListType {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListType {
@@ -32246,17 +35260,49 @@ impl std::fmt::Display for ReposListType {
             ReposListType::Owner => "owner",
             ReposListType::Private => "private",
             ReposListType::Public => "public",
-            ReposListType::FallthroughString => "*",
+            ReposListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ReposListType::All,
+            "member" => ReposListType::Member,
+            "owner" => ReposListType::Owner,
+            "private" => ReposListType::Private,
+            "public" => ReposListType::Public,
+            _ => ReposListType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListType {
     fn default() -> ReposListType {
         ReposListType::All
     }
 }
+impl ReposListType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateRequest {
@@ -32325,7 +35371,7 @@ pub struct ReposCreateRequest {
 /**
 * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SubjectType {
     #[serde(rename = "issue")]
     Issue,
@@ -32337,8 +35383,8 @@ pub enum SubjectType {
     Repository,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubjectType {
@@ -32349,12 +35395,38 @@ impl std::fmt::Display for SubjectType {
             SubjectType::PullRequest => "pull_request",
             SubjectType::Repository => "repository",
             SubjectType::Noop => "",
-            SubjectType::FallthroughString => "*",
+            SubjectType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SubjectType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SubjectType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "issue" => SubjectType::Issue,
+            "organization" => SubjectType::Organization,
+            "pull_request" => SubjectType::PullRequest,
+            "repository" => SubjectType::Repository,
+            "" => SubjectType::Noop,
+            _ => SubjectType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SubjectType {
     fn default() -> SubjectType {
         SubjectType::Noop
@@ -32364,12 +35436,17 @@ impl SubjectType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SubjectType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SubjectType::Unknown(_))
+    }
 }
 
 /**
 * Can be one of `all`, `owner`, `member`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReposListUserType {
     #[serde(rename = "all")]
     All,
@@ -32377,8 +35454,8 @@ pub enum ReposListUserType {
     Member,
     #[serde(rename = "owner")]
     Owner,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListUserType {
@@ -32387,17 +35464,47 @@ impl std::fmt::Display for ReposListUserType {
             ReposListUserType::All => "all",
             ReposListUserType::Member => "member",
             ReposListUserType::Owner => "owner",
-            ReposListUserType::FallthroughString => "*",
+            ReposListUserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReposListUserType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListUserType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ReposListUserType::All,
+            "member" => ReposListUserType::Member,
+            "owner" => ReposListUserType::Owner,
+            _ => ReposListUserType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReposListUserType {
     fn default() -> ReposListUserType {
         ReposListUserType::Owner
     }
 }
+impl ReposListUserType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReposListUserType::Unknown(_))
+    }
+}
 
 /// All of the following types are flattened into one object:
 ///
@@ -0,0 +1,278 @@
+//! Verifies and parses the webhooks GitHub delivers.
+//!
+//! GitHub signs the raw body of each delivery with the secret of the webhook,
+//! in the `X-Hub-Signature-256` header, and names the event in the
+//! `X-GitHub-Event` header. [`WebhookEvent::from_request`] checks the
+//! signature before parsing the body:
+//!
+//! ```
+//! use octorust::webhooks::WebhookEvent;
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) {
+//!     match WebhookEvent::from_request(b"webhook-secret", headers, body) {
+//!         Ok(WebhookEvent::Issues(event)) if event.action == "opened" => {
+//!             println!("#{} was opened", event.issue.number);
+//!         }
+//!         Ok(event) => println!("ignoring {} {:?}", event.name(), event.action()),
+//!         Err(e) => println!("rejecting the delivery: {}", e),
+//!     }
+//! }
+//! ```
+//!
+//! Events without a variant here, like `star` or `workflow_run`, still parse:
+//! they come back as [`WebhookEvent::Unknown`], with their name and payload.
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::types;
+
+const X_GITHUB_EVENT: &str = "x-github-event";
+const X_HUB_SIGNATURE_256: &str = "x-hub-signature-256";
+
+/// Why [`WebhookEvent::from_request`] refused a delivery.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The delivery is missing a header GitHub always sends.
+    #[error("missing the {0} header")]
+    MissingHeader(&'static str),
+    /// The body wasn't signed with the secret of the webhook.
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The body isn't the payload of the event.
+    #[error("invalid payload: {0}")]
+    Payload(#[from] serde_json::Error),
+}
+
+/// Check the `X-Hub-Signature-256` header of a delivery, like
+/// `sha256=757107ea…`, against the HMAC-SHA256 of its body. The body must be
+/// the bytes GitHub sent, not JSON parsed and encoded again.
+pub fn verify_signature(secret: &[u8], body: &[u8], signature: &str) -> Result<(), WebhookError> {
+    let signature = signature
+        .strip_prefix("sha256=")
+        .and_then(|s| hex::decode(s).ok())
+        .ok_or(WebhookError::InvalidSignature)?;
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret).map_err(|_| WebhookError::InvalidSignature)?;
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// The installation of the GitHub App an event was delivered to.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct SimpleInstallation {
+    pub id: i64,
+    #[serde(default)]
+    pub node_id: String,
+}
+
+/// Sent when a webhook is created.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PingEvent {
+    #[serde(default)]
+    pub zen: String,
+    #[serde(default)]
+    pub hook_id: i64,
+    #[serde(default)]
+    pub repository: Option<types::Repository>,
+    #[serde(default)]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity on an issue, like `opened`, `edited` or `closed`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct IssuesEvent {
+    pub action: String,
+    pub issue: types::Issue,
+    /// The previous values of the edited fields.
+    #[serde(default)]
+    pub changes: Option<serde_json::Value>,
+    pub repository: types::Repository,
+    pub sender: types::SimpleUser,
+    #[serde(default)]
+    pub installation: Option<SimpleInstallation>,
+}
+
+/// Activity on a comment of an issue or a pull request, like `created`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct IssueCommentEvent {
+    pub action: String,
+    pub issue: types::Issue,
+    pub comment: types::IssueComment,
+    /// The previous values of the edited fields.
+    #[serde(default)]
+    pub changes: Option<serde_json::Value>,
+    pub repository: types::Repository,
+    pub sender: types::SimpleUser,
+    #[serde(default)]
+    pub installation: Option<SimpleInstallation>,
+}
+
+/// Activity on a pull request, like `opened`, `synchronize` or `closed`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PullRequestEvent {
+    pub action: String,
+    pub number: i64,
+    /// The whole pull request, which the `types::PullRequest` of an issue only
+    /// links to.
+    pub pull_request: types::PullRequestData,
+    /// The previous values of the edited fields.
+    #[serde(default)]
+    pub changes: Option<serde_json::Value>,
+    pub repository: types::Repository,
+    pub sender: types::SimpleUser,
+    #[serde(default)]
+    pub installation: Option<SimpleInstallation>,
+}
+
+/// Activity on an installation of a GitHub App, like `created` or `deleted`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct InstallationEvent {
+    pub action: String,
+    pub installation: types::Installation,
+    /// The repositories the installation can access.
+    #[serde(default)]
+    pub repositories: Vec<types::Repository>,
+    pub sender: types::SimpleUser,
+}
+
+/// Repositories added to or removed from an installation of a GitHub App.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct InstallationRepositoriesEvent {
+    pub action: String,
+    pub installation: types::Installation,
+    #[serde(default)]
+    pub repository_selection: types::RepositorySelection,
+    #[serde(default)]
+    pub repositories_added: Vec<types::Repository>,
+    #[serde(default)]
+    pub repositories_removed: Vec<types::Repository>,
+    pub sender: types::SimpleUser,
+}
+
+/// A webhook delivery, by the name of its event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebhookEvent {
+    Ping(PingEvent),
+    Issues(IssuesEvent),
+    IssueComment(IssueCommentEvent),
+    PullRequest(PullRequestEvent),
+    Installation(InstallationEvent),
+    InstallationRepositories(InstallationRepositoriesEvent),
+    /// An event without a variant here, by its `X-GitHub-Event` name.
+    Unknown {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Parse the body of a delivery of the `event` named by its
+    /// `X-GitHub-Event` header. The signature isn't checked.
+    pub fn parse(event: &str, body: &[u8]) -> Result<Self, WebhookError> {
+        Ok(match event {
+            "ping" => WebhookEvent::Ping(serde_json::from_slice(body)?),
+            "issues" => WebhookEvent::Issues(serde_json::from_slice(body)?),
+            "issue_comment" => WebhookEvent::IssueComment(serde_json::from_slice(body)?),
+            "pull_request" => WebhookEvent::PullRequest(serde_json::from_slice(body)?),
+            "installation" => WebhookEvent::Installation(serde_json::from_slice(body)?),
+            "installation_repositories" => {
+                WebhookEvent::InstallationRepositories(serde_json::from_slice(body)?)
+            }
+            _ => WebhookEvent::Unknown {
+                event: event.to_string(),
+                payload: serde_json::from_slice(body)?,
+            },
+        })
+    }
+
+    /// Verify the signature of a delivery with the secret of the webhook, then
+    /// parse its body.
+    pub fn from_request(
+        secret: &[u8],
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self, WebhookError> {
+        let signature = headers
+            .get(X_HUB_SIGNATURE_256)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(WebhookError::MissingHeader(X_HUB_SIGNATURE_256))?;
+        verify_signature(secret, body, signature)?;
+
+        let event = headers
+            .get(X_GITHUB_EVENT)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(WebhookError::MissingHeader(X_GITHUB_EVENT))?;
+        WebhookEvent::parse(event, body)
+    }
+
+    /// The name of the event, as in the `X-GitHub-Event` header.
+    pub fn name(&self) -> &str {
+        match self {
+            WebhookEvent::Ping(_) => "ping",
+            WebhookEvent::Issues(_) => "issues",
+            WebhookEvent::IssueComment(_) => "issue_comment",
+            WebhookEvent::PullRequest(_) => "pull_request",
+            WebhookEvent::Installation(_) => "installation",
+            WebhookEvent::InstallationRepositories(_) => "installation_repositories",
+            WebhookEvent::Unknown { event, .. } => event,
+        }
+    }
+
+    /// What happened, for the events that have actions.
+    pub fn action(&self) -> Option<&str> {
+        match self {
+            WebhookEvent::Ping(_) => None,
+            WebhookEvent::Issues(e) => Some(&e.action),
+            WebhookEvent::IssueComment(e) => Some(&e.action),
+            WebhookEvent::PullRequest(e) => Some(&e.action),
+            WebhookEvent::Installation(e) => Some(&e.action),
+            WebhookEvent::InstallationRepositories(e) => Some(&e.action),
+            WebhookEvent::Unknown { payload, .. } => payload.get("action").and_then(|a| a.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries
+    const SECRET: &[u8] = b"It's a Secret to Everybody";
+    const BODY: &[u8] = b"Hello, World!";
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn verifies_the_documented_example() {
+        verify_signature(SECRET, BODY, SIGNATURE).unwrap();
+    }
+
+    #[test]
+    fn rejects_other_secrets_bodies_and_algorithms() {
+        for signature in [
+            "sha256=0000000000000000000000000000000000000000000000000000000000000000",
+            "sha1=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+            "sha256=not-hex",
+            "",
+        ] {
+            assert!(matches!(
+                verify_signature(SECRET, BODY, signature),
+                Err(WebhookError::InvalidSignature)
+            ));
+        }
+        assert!(verify_signature(b"another secret", BODY, SIGNATURE).is_err());
+        assert!(verify_signature(SECRET, b"Hello, World?", SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn parses_events_without_a_variant() {
+        let event =
+            WebhookEvent::parse("star", br#"{"action": "created", "starred_at": null}"#).unwrap();
+        assert_eq!(event.name(), "star");
+        assert_eq!(event.action(), Some("created"));
+        assert!(matches!(event, WebhookEvent::Unknown { .. }));
+    }
+}
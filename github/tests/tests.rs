@@ -1,17 +1,22 @@
+use futures::StreamExt;
 use rand::RngCore;
 use rsa::{pkcs1::EncodeRsaPrivateKey, RsaPrivateKey};
 use std::{mem, time::Duration};
 
 use wiremock::{
     http::{HeaderName, HeaderValue},
-    matchers::{bearer_token, header, method, path, query_param},
+    matchers::{
+        bearer_token, header, header_exists, method, path, query_param, query_param_is_missing,
+    },
     Mock, MockServer, ResponseTemplate,
 };
 
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
+    http_cache::FileBasedCache,
+    rate_limiter::{RateLimitExceeded, RateLimitPolicy, Resource},
     types::InstallationToken,
-    Client,
+    Client, ClientError,
 };
 
 fn app_id() -> i64 {
@@ -147,16 +152,11 @@ async fn test_follows_next_links_during_unfold() {
     let res = client
         .issues()
         .list_all(
-            octorust::types::Filter::All,
-            octorust::types::IssuesListState::All,
-            "",
-            octorust::types::IssuesListSort::Created,
-            octorust::types::Order::Asc,
-            None,
-            false,
-            false,
-            false,
-            false,
+            &octorust::issues::IssuesListParams::new()
+                .filter(octorust::types::Filter::All)
+                .state(octorust::types::IssuesListState::All)
+                .sort(octorust::types::IssuesListSort::Created)
+                .direction(octorust::types::Order::Asc),
         )
         .await;
 
@@ -223,3 +223,569 @@ async fn test_refreshes_installation_token_once() {
     // Ensure the requests both completed successfully.
     result.expect("Should get zen successfully");
 }
+
+#[tokio::test]
+async fn test_client_error_is_structured() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/octocat"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .append_header(
+                    HeaderName::from_bytes("x-ratelimit-remaining".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes("0".as_bytes().to_vec()).unwrap(),
+                )
+                .append_header(
+                    HeaderName::from_bytes("x-ratelimit-reset".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes("1700000000".as_bytes().to_vec()).unwrap(),
+                )
+                .set_body_json(serde_json::json!({ "message": "API rate limit exceeded" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    let err = client.meta().get_zen().await.unwrap_err();
+    match err.downcast_ref::<ClientError>() {
+        Some(ClientError::BasicError { status, error, .. }) => {
+            assert_eq!(status.as_u16(), 404);
+            assert_eq!(error.message, "Not Found");
+        }
+        other => panic!("expected a BasicError, got {:?}", other),
+    }
+
+    let err = client
+        .meta()
+        .get_octocat(&octorust::meta::MetaGetOctocatParams::new())
+        .await
+        .unwrap_err();
+    match err.downcast_ref::<ClientError>() {
+        Some(ClientError::RateLimited { reset, .. }) => {
+            assert_eq!(
+                *reset,
+                Some(std::time::UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            );
+        }
+        other => panic!("expected RateLimited, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_streams_pages_and_resumes_from_cursor() {
+    let server = MockServer::start().await;
+
+    let issue = |number| octorust::types::Issue {
+        number,
+        ..empty_issue()
+    };
+
+    let next_url = format!("<{}/issues?page=2>; rel=\"next\"", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/issues"))
+        .and(query_param("state", "all"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .append_header(
+                    HeaderName::from_bytes("link".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes(next_url.into_bytes()).unwrap(),
+                )
+                .set_body_json(vec![issue(1), issue(2)]),
+        )
+        .expect(2)
+        .named("Get first page")
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![issue(3)]))
+        .expect(2)
+        .named("Get second page")
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    let stream = |cursor| {
+        client.issues().list_all_stream(
+            &octorust::issues::IssuesListParams::new()
+                .filter(octorust::types::Filter::All)
+                .state(octorust::types::IssuesListState::All)
+                .sort(octorust::types::IssuesListSort::Created)
+                .direction(octorust::types::Order::Asc),
+            cursor,
+        )
+    };
+
+    let mut issues = stream(None);
+    let first = issues.next().await.unwrap().unwrap();
+    assert_eq!(first.number, 1);
+    let cursor = issues.cursor().clone();
+    assert_eq!(cursor.page, None);
+    assert_eq!(cursor.offset, 1);
+
+    let rest: Vec<i64> = issues.map(|issue| issue.unwrap().number).collect().await;
+    assert_eq!(rest, vec![2, 3]);
+
+    let resumed: Vec<i64> = stream(Some(cursor))
+        .map(|issue| issue.unwrap().number)
+        .collect()
+        .await;
+    assert_eq!(resumed, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn test_download_follows_redirect_to_file() {
+    let server = MockServer::start().await;
+
+    let location = format!("{}/blobs/artifact.zip", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/actions/artifacts/1/zip"))
+        .and(header("authorization", "token test-token"))
+        .respond_with(ResponseTemplate::new(302).append_header(
+            HeaderName::from_bytes("location".as_bytes().to_vec()).unwrap(),
+            HeaderValue::from_bytes(location.into_bytes()).unwrap(),
+        ))
+        .expect(2)
+        .named("Redirect to the artifact")
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/blobs/artifact.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(b"PK\x03\x04 zipped".to_vec(), "application/zip"),
+        )
+        .expect(2)
+        .named("Serve the artifact")
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    let download = client
+        .actions()
+        .download_artifact("owner", "repo", 1, "zip")
+        .await
+        .unwrap();
+    assert_eq!(download.content_type(), Some("application/zip"));
+    assert_eq!(download.content_length(), Some(11));
+    assert_eq!(
+        download.bytes().await.unwrap().as_ref(),
+        b"PK\x03\x04 zipped"
+    );
+
+    let mut file = Vec::new();
+    let written = client
+        .actions()
+        .download_artifact("owner", "repo", 1, "zip")
+        .await
+        .unwrap()
+        .write_to(&mut file)
+        .await
+        .unwrap();
+    assert_eq!(written, 11);
+    assert_eq!(file, b"PK\x03\x04 zipped");
+}
+
+#[tokio::test]
+async fn test_optional_params_are_sent_only_when_set() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/issues"))
+        .and(query_param("pulls", "false"))
+        .and(query_param("per_page", "0"))
+        .and(query_param("labels", ""))
+        .and(query_param_is_missing("state"))
+        .and(query_param_is_missing("since"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![empty_issue()]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    let issues = client
+        .issues()
+        .list(
+            &octorust::issues::IssuesListParams::new()
+                .pulls(false)
+                .per_page(0)
+                .labels(""),
+        )
+        .await
+        .unwrap();
+    assert_eq!(issues.len(), 1);
+}
+
+#[test]
+fn test_unknown_enum_values_round_trip() {
+    use octorust::types::Order;
+
+    let order: Order = serde_json::from_str(r#""sideways""#).unwrap();
+    assert_eq!(order, Order::Unknown("sideways".to_string()));
+    assert!(order.is_unknown());
+    assert_eq!(order.to_string(), "sideways");
+    assert_eq!(serde_json::to_string(&order).unwrap(), r#""sideways""#);
+
+    let order: Order = serde_json::from_str(r#""asc""#).unwrap();
+    assert_eq!(order, Order::Asc);
+    assert!(!order.is_unknown());
+}
+
+#[tokio::test]
+async fn test_generated_mocks() {
+    let mock = octorust::mock::Mock::start().await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    mock.point(&mut client);
+
+    mock.pulls()
+        .list("octo org", "hello-world")
+        .with_query("state", "closed")
+        .expect(1)
+        .responds_with(vec![])
+        .await;
+    mock.meta()
+        .get_zen()
+        .responds_with("Keep it logically awesome.".to_string())
+        .await;
+
+    let pulls = client
+        .pulls()
+        .list(
+            "octo org",
+            "hello-world",
+            &octorust::pulls::PullsListParams::new()
+                .state(octorust::types::IssuesListState::Closed),
+        )
+        .await
+        .unwrap();
+    assert!(pulls.is_empty());
+
+    let zen = client.meta().get_zen().await.unwrap();
+    assert_eq!(zen, "Keep it logically awesome.");
+}
+
+fn rate_limit_headers(response: ResponseTemplate, remaining: u32, reset: u64) -> ResponseTemplate {
+    [
+        ("x-ratelimit-limit", "60".to_string()),
+        ("x-ratelimit-remaining", remaining.to_string()),
+        ("x-ratelimit-used", (60 - remaining).to_string()),
+        ("x-ratelimit-reset", reset.to_string()),
+        ("x-ratelimit-resource", "core".to_string()),
+    ]
+    .into_iter()
+    .fold(response, |response, (name, value)| {
+        response.append_header(
+            HeaderName::from_bytes(name.as_bytes().to_vec()).unwrap(),
+            HeaderValue::from_bytes(value.as_bytes().to_vec()).unwrap(),
+        )
+    })
+}
+
+#[tokio::test]
+async fn test_tracks_rate_limits_and_fails_fast() {
+    let server = MockServer::start().await;
+    let reset = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 3600;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(rate_limit_headers(
+            ResponseTemplate::new(200).set_body_json("Design for failure."),
+            0,
+            reset,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client
+        .with_host_override(server.uri())
+        .with_rate_limit_policy(RateLimitPolicy::FailFast);
+
+    assert!(client.rate_limits().snapshot().is_empty());
+    client.meta().get_zen().await.unwrap();
+
+    let core = client.rate_limits().get(&Resource::Core).unwrap();
+    assert_eq!(core.limit, 60);
+    assert_eq!(core.remaining, 0);
+    assert_eq!(core.used, 60);
+    assert_eq!(
+        core.reset,
+        std::time::UNIX_EPOCH + Duration::from_secs(reset)
+    );
+    assert_eq!(client.rate_limits().snapshot().len(), 1);
+
+    // The limit is exhausted, so the request isn't sent.
+    let err = client.meta().get_zen().await.unwrap_err();
+    match err.downcast_ref::<RateLimitExceeded>() {
+        Some(exceeded) => {
+            assert_eq!(exceeded.resource, Resource::Core);
+            assert_eq!(exceeded.reset, core.reset);
+        }
+        other => panic!("expected RateLimitExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_waits_for_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .append_header(
+                    HeaderName::from_bytes("retry-after".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes("1".as_bytes().to_vec()).unwrap(),
+                )
+                .set_body_json(serde_json::json!({
+                    "message": "You have exceeded a secondary rate limit."
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json("Keep it logically awesome."))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    // Without a policy the response is an error. The clones of the client
+    // share what it tells about the rate limit.
+    let err = client.clone().meta().get_zen().await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ClientError>(),
+        Some(ClientError::RateLimited { .. })
+    ));
+
+    // A policy that can't wait long enough fails fast.
+    let mut impatient = client.clone();
+    impatient.with_rate_limit_policy(RateLimitPolicy::Wait {
+        max_wait: Duration::from_millis(10),
+    });
+    let err = impatient.meta().get_zen().await.unwrap_err();
+    assert!(err.downcast_ref::<RateLimitExceeded>().is_some());
+
+    // Otherwise the request is sent once the secondary rate limit is over.
+    client.with_rate_limit_policy(RateLimitPolicy::Wait {
+        max_wait: Duration::from_secs(5),
+    });
+    let started = std::time::Instant::now();
+    let zen = client.meta().get_zen().await.unwrap();
+    assert_eq!(zen, "Keep it logically awesome.");
+    assert!(started.elapsed() >= Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn test_keeps_retry_after_until_it_passes() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .append_header(
+                    HeaderName::from_bytes("retry-after".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes("60".as_bytes().to_vec()).unwrap(),
+                )
+                .set_body_json(serde_json::json!({
+                    "message": "You have exceeded a secondary rate limit."
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/octocat"))
+        .respond_with(ResponseTemplate::new(200).set_body_json("MMM."))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    client.meta().get_zen().await.unwrap_err();
+
+    // A response that wasn't rate limited doesn't lift the secondary rate
+    // limit.
+    let octocat = client
+        .meta()
+        .get_octocat(&Default::default())
+        .await
+        .unwrap();
+    assert_eq!(octocat, "MMM.");
+
+    client.with_rate_limit_policy(RateLimitPolicy::FailFast);
+    let err = client.meta().get_zen().await.unwrap_err();
+    match err.downcast_ref::<RateLimitExceeded>() {
+        Some(exceeded) => {
+            assert!(exceeded.reset > std::time::SystemTime::now() + Duration::from_secs(50))
+        }
+        other => panic!("expected RateLimitExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_revalidates_cached_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header_exists("if-modified-since"))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .append_header(
+                    HeaderName::from_bytes("last-modified".as_bytes().to_vec()).unwrap(),
+                    HeaderValue::from_bytes("Wed, 21 Oct 2015 07:28:00 GMT".as_bytes().to_vec())
+                        .unwrap(),
+                )
+                .set_body_json("Practicality beats purity."),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!(
+        "octorust-http-cache-{}",
+        rand::thread_rng().next_u64()
+    ));
+    let mut client = Client::custom(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+        reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build(),
+        std::sync::Arc::new(FileBasedCache::new(&dir)),
+    );
+    client.with_host_override(server.uri());
+
+    // The second response is read from the files of the first one.
+    for _ in 0..2 {
+        let zen = client.meta().get_zen().await.unwrap();
+        assert_eq!(zen, "Practicality beats purity.");
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parses_signed_issue_deliveries() {
+    use hmac::Mac;
+    use octorust::webhooks::{WebhookError, WebhookEvent};
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "action": "opened",
+        "issue": {
+            "id": 1,
+            "number": 42,
+            "title": "Found a bug",
+            "state": "open",
+            "author_association": "CONTRIBUTOR",
+        },
+        "repository": { "id": 2, "name": "octorust", "full_name": "oxidecomputer/octorust" },
+        "sender": { "id": 3, "login": "octocat" },
+        "installation": { "id": 4, "node_id": "MDIzOkludGVncmF0aW9uSW5zdGFsbGF0aW9uNA==" },
+    }))
+    .unwrap();
+
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(b"webhook-secret").unwrap();
+    mac.update(&body);
+    let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-github-event", "issues".parse().unwrap());
+    headers.insert("x-hub-signature-256", signature.parse().unwrap());
+
+    let event = WebhookEvent::from_request(b"webhook-secret", &headers, &body).unwrap();
+    assert_eq!(event.name(), "issues");
+    assert_eq!(event.action(), Some("opened"));
+    match event {
+        WebhookEvent::Issues(event) => {
+            assert_eq!(event.issue.number, 42);
+            assert_eq!(event.repository.full_name, "oxidecomputer/octorust");
+            assert_eq!(event.sender.login, "octocat");
+            assert_eq!(event.installation.unwrap().id, 4);
+        }
+        other => panic!("expected an issues event, got {:?}", other),
+    }
+
+    let err = WebhookEvent::from_request(b"another-secret", &headers, &body).unwrap_err();
+    assert!(matches!(err, WebhookError::InvalidSignature));
+
+    headers.remove("x-github-event");
+    let err = WebhookEvent::from_request(b"webhook-secret", &headers, &body).unwrap_err();
+    assert!(matches!(err, WebhookError::MissingHeader("x-github-event")));
+}
@@ -337,6 +337,70 @@ impl Customers {
         // Return our response data.
         Ok(printers)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List printers configs.
+     */
+    pub fn admin_chrome_printers_list_all_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Printer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !org_unit_id.is_empty() {
+            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListPrintersResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.printers,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -559,4 +623,64 @@ impl Customers {
         // Return our response data.
         Ok(printer_models)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the supported printer models.
+     */
+    pub fn admin_chrome_printers_list_all_printer_models_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PrinterModel> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListPrinterModelsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.printer_models,
+                })
+            }
+        })
+    }
 }
@@ -173,6 +173,80 @@ impl Groups {
         // Return our response data.
         Ok(groups)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Group> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !user_key.is_empty() {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/groups?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Groups = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.groups,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
      *
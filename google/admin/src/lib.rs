@@ -26,7 +26,7 @@
 //! ## Client Details
 //!
 //! This client is generated from the [Google Admin OpenAPI
-//! specs](https://admin.googleapis.com/iscovery/rest?version=directory_v1) based on API spec version `directory_v1`. This way it will remain
+//! specs](https://admin.googleapis.com/$discovery/rest?version=directory_v1) based on API spec version `directory_v1`. This way it will remain
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
@@ -101,6 +101,76 @@
 //! }
 //! ```
 //!
+//! To keep the tokens in your database as they change, give the client a
+//! `TokenStore` with `set_token_store`. The client hands it the tokens whenever
+//! it gets new ones, including a refresh token rotated by a refresh. With
+//! `set_auto_access_token_refresh` enabled, a request refused with a 401 is
+//! also retried once after refreshing the access token.
+//!
+//! ## Service accounts
+//!
+//! Headless jobs can authenticate as a service account instead, with its JSON
+//! key. The client mints access tokens for the scopes with the JWT bearer
+//! grant, and mints new ones once they expire. With a subject, the service
+//! account impersonates that user through domain-wide delegation.
+//!
+//! ```no_run
+//! use gsuite_api::Client;
+//!
+//! async fn do_call() {
+//!     let key = yup_oauth2::read_service_account_key("service-account.json")
+//!         .await
+//!         .unwrap();
+//!     let client = Client::new_from_service_account(
+//!         key,
+//!         &["https://www.googleapis.com/auth/cloud-platform"],
+//!         Some("admin@example.com"),
+//!     );
+//! }
+//! ```
+//!
+//! ## Caching responses
+//!
+//! With the `httpcache` feature, the client caches the responses to `GET`
+//! requests and sends their `ETag` and `Last-Modified` back. A `304 Not
+//! Modified` is then answered from the cache.
+//!
+//! ```toml
+//! [dependencies]
+//! gsuite-api = { version = "0.6.0", features = ["httpcache"] }
+//! ```
+//!
+//! The cache keeps the most recently used responses in memory, or the
+//! responses in files. Implement `HttpCache` to store them elsewhere.
+//!
+//! ```ignore
+//! use gsuite_api::http_cache::HttpCache;
+//!
+//! client.with_http_cache(<dyn HttpCache>::in_memory(1000));
+//! ```
+//!
+//! ## Configuring the HTTP client
+//!
+//! The client traces its requests and retries the transient failures up to 3
+//! times, unless sending a request again could repeat its side effects, like
+//! a `POST` without an `Idempotency-Key`. To set timeouts, a proxy, another
+//! retry policy, the user agent, default headers or more middleware, build the
+//! HTTP client with a `ClientBuilder`. Building it returns an error instead of
+//! panicking.
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use gsuite_api::ClientBuilder;
+//!
+//! let http = ClientBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .connect_timeout(Duration::from_secs(5))
+//!     .no_retries()
+//!     .build()?;
+//! client.with_http_client(http);
+//! ```
+//!
 #![allow(clippy::derive_partial_eq_without_eq)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -117,8 +187,14 @@ pub mod customers;
 pub mod domain_aliases;
 pub mod domains;
 pub mod groups;
+#[cfg(feature = "httpcache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+pub mod http_cache;
 pub mod members;
 pub mod mobiledevices;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
 pub mod orgunits;
 pub mod privileges;
 pub mod resources;
@@ -133,7 +209,7 @@ pub mod users;
 pub mod utils;
 pub mod verification_codes;
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::Result;
 
 pub const FALLBACK_HOST: &str = "https://www.googleapis.com";
 
@@ -163,6 +239,248 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// Errors returned by the client.
+///
+/// Every method returns an `anyhow::Result`. When a request fails because of the
+/// API or the transport, the error holds a `ClientError`, which can be recovered
+/// with `err.downcast_ref::<ClientError>()` and matched on.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The API responded with a non-success status code.
+    #[error("code: {status}, error: {body:?}")]
+    HttpError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: String,
+    },
+    /// The API refused the request because a rate limit was exceeded.
+    #[error("code: {status}, rate limit exceeded")]
+    RateLimited {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// When the rate limit resets, if the response said so.
+        reset: Option<std::time::SystemTime>,
+    },
+    /// Sending the request or reading the response failed.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    /// A middleware failed while sending the request.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// The response body could not be deserialized.
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    /// The request URL could not be parsed.
+    #[error(transparent)]
+    UrlParse(#[from] url::ParseError),
+    /// Refreshing the credentials used to authenticate the request failed.
+    #[error("refreshing credentials failed: {0}")]
+    AuthRefresh(anyhow::Error),
+}
+
+impl ClientError {
+    /// The status code the API responded with, if it responded.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            ClientError::HttpError { status, .. } | ClientError::RateLimited { status, .. } => {
+                Some(*status)
+            }
+            ClientError::Reqwest(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Build the error for a response with a non-success status code.
+    pub(crate) fn from_response(
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let retry_after = header("retry-after");
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || header("x-ratelimit-remaining") == Some(0)
+            || (status == reqwest::StatusCode::FORBIDDEN && retry_after.is_some())
+        {
+            let now = std::time::SystemTime::now();
+            let reset = match (retry_after, header("x-ratelimit-reset")) {
+                (Some(secs), _) => Some(now + std::time::Duration::from_secs(secs)),
+                // Some APIs send a unix timestamp, others the number of seconds left.
+                (None, Some(reset)) if reset > 1_000_000_000 => {
+                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+                }
+                (None, Some(secs)) => Some(now + std::time::Duration::from_secs(secs)),
+                (None, None) => None,
+            };
+
+            return ClientError::RateLimited {
+                status,
+                headers,
+                reset,
+            };
+        }
+
+        ClientError::HttpError {
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+}
+
+/// Builds the HTTP client the API client sends its requests with, to hand to
+/// `Client::with_http_client`.
+///
+/// By default, requests are traced and the transient failures are retried up
+/// to 3 times with increasing intervals between attempts. Only the requests
+/// that can safely be sent again are retried: the ones with an idempotent
+/// method, like `GET` or `PUT`, and the ones carrying an `Idempotency-Key`.
+pub struct ClientBuilder {
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<reqwest_retry::policies::ExponentialBackoff>,
+    retry_any_method: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    middleware: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            // Retry up to 3 times with increasing intervals between attempts.
+            retry_policy: Some(
+                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3),
+            ),
+            retry_any_method: false,
+            user_agent: None,
+            default_headers: Default::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Time out the requests, from connecting until the end of the response
+    /// body, after `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time out the connections to the server after `timeout`.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Retry the transient failures with this policy instead of the default one.
+    pub fn retry_policy(mut self, policy: reqwest_retry::policies::ExponentialBackoff) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Don't retry failed requests.
+    pub fn no_retries(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// Also retry the requests that aren't idempotent, like a `POST` without an
+    /// `Idempotency-Key`. A request that timed out may then be carried out twice.
+    pub fn retry_any_method(mut self) -> Self {
+        self.retry_any_method = true;
+        self
+    }
+
+    /// Send this `User-Agent` header with every request.
+    pub fn user_agent<A>(mut self, user_agent: A) -> Self
+    where
+        A: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send these headers with every request, besides the ones of the request.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Add a middleware layer. The layers run in the order they are added, after
+    /// the tracing and each time a request is retried.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the HTTP client. This fails when the user agent isn't a valid header
+    /// value or when the TLS backend can't be initialized.
+    pub fn build(self) -> Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(reqwest::header::HeaderValue::from_str(&user_agent)?);
+        }
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default());
+        if let Some(retry_policy) = self.retry_policy {
+            let retry_any_method = self.retry_any_method;
+            // Retry failed requests.
+            client = client.with(reqwest_conditional_middleware::ConditionalMiddleware::new(
+                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                move |req: &reqwest::Request| {
+                    req.try_clone().is_some() && (retry_any_method || is_idempotent(req))
+                },
+            ));
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+/// Whether sending the request twice has the same effect as sending it once.
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    req.method().is_idempotent() || req.headers().contains_key("idempotency-key")
+}
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -188,11 +506,17 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: Arc<RwLock<InnerToken>>,
+    token_endpoint: String,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
 
     auto_refresh: bool,
+    token_store: Option<Arc<dyn TokenStore>>,
+    refreshing: Arc<tokio::sync::Mutex<()>>,
+    service_account: Option<ServiceAccount>,
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -234,6 +558,34 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+/// A service account the client authenticates as.
+#[derive(Debug, Clone)]
+struct ServiceAccount {
+    key: yup_oauth2::ServiceAccountKey,
+    scopes: Vec<String>,
+    subject: Option<String>,
+}
+
+/// The claims of the JWT a service account exchanges for an access token.
+#[derive(serde::Serialize)]
+struct ServiceAccountClaims<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+}
+
+/// Persists the tokens of a client. The client hands over its tokens whenever
+/// it gets new ones, so a refresh token rotated by a refresh isn't lost.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Save the tokens the client got.
+    async fn store(&self, token: &AccessToken) -> anyhow::Result<()>;
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -264,41 +616,33 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                let host = RootDefaultServer::default().default_url().to_string();
-
-                Client {
-                    host,
-                    host_override: None,
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        let client = match ClientBuilder::new().build() {
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        };
+
+        let host = RootDefaultServer::default().default_url().to_string();
+
+        Client {
+            host,
+            host_override: None,
+            token_endpoint: TOKEN_ENDPOINT.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+
+            auto_refresh: false,
+            token_store: None,
+            refreshing: Default::default(),
+            service_account: None,
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
         }
     }
 
@@ -308,6 +652,34 @@ impl Client {
         self
     }
 
+    /// Sets the store the client saves its tokens to whenever it gets new ones,
+    /// including on automatic refreshes.
+    pub fn set_token_store<S>(&mut self, store: S) -> &mut Self
+    where
+        S: TokenStore + 'static,
+    {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Keep the tokens in the client and hand them to the token store.
+    async fn save_token(&self, t: &AccessToken) -> Result<()> {
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        if let Some(store) = &self.token_store {
+            store
+                .store(t)
+                .await
+                .map_err(|e| e.context("storing the tokens failed"))?;
+        }
+
+        Ok(())
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -391,6 +763,16 @@ impl Client {
         )
     }
 
+    /// Override the endpoint access tokens are requested from, like the one of
+    /// a sandbox.
+    pub fn with_token_endpoint<E>(&mut self, endpoint: E) -> &mut Self
+    where
+        E: ToString,
+    {
+        self.token_endpoint = endpoint.to_string();
+        self
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -407,43 +789,118 @@ impl Client {
         let secret = yup_oauth2::parse_application_secret(decoded_google_key)
             .expect("failed to read from google credential env var");
 
-        let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                let host = RootDefaultServer::default().default_url().to_string();
-
-                Client {
-                    host,
-                    host_override: None,
-                    client_id: secret.client_id.to_string(),
-                    client_secret: secret.client_secret.to_string(),
-                    redirect_uri: secret.redirect_uris[0].to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        let client = match ClientBuilder::new().build() {
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        };
+
+        let host = RootDefaultServer::default().default_url().to_string();
+
+        Client {
+            host,
+            host_override: None,
+            token_endpoint: TOKEN_ENDPOINT.to_string(),
+            client_id: secret.client_id.to_string(),
+            client_secret: secret.client_secret.to_string(),
+            redirect_uri: secret.redirect_uris[0].to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+            auto_refresh: false,
+            token_store: None,
+            refreshing: Default::default(),
+            service_account: None,
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
         }
     }
 
+    /// Create a new Client struct authenticated as a service account, from its
+    /// JSON key. Access tokens for the scopes are minted with the JWT bearer grant,
+    /// kept until they expire and minted again. With a `subject`, the service
+    /// account impersonates that user through domain-wide delegation.
+    pub fn new_from_service_account<S>(
+        key: yup_oauth2::ServiceAccountKey,
+        scopes: &[S],
+        subject: Option<&str>,
+    ) -> Self
+    where
+        S: ToString,
+    {
+        let mut client = Client::new(key.client_id.clone().unwrap_or_default(), "", "", "", "");
+        client.token_endpoint = key.token_uri.clone();
+        // There is no token yet, so one is minted before the first request.
+        client.token = Arc::new(RwLock::new(InnerToken {
+            access_token: String::new(),
+            refresh_token: String::new(),
+            expires_at: Some(Instant::now()),
+        }));
+        client.auto_refresh = true;
+        client.service_account = Some(ServiceAccount {
+            key,
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            subject: subject.map(|s| s.to_string()),
+        });
+        client
+    }
+
+    /// Mint an access token for the service account, exchanging a JWT signed with
+    /// its key.
+    async fn get_service_account_access_token(
+        &self,
+        service_account: &ServiceAccount,
+    ) -> Result<AccessToken> {
+        let iat = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs();
+        let claims = ServiceAccountClaims {
+            iss: &service_account.key.client_email,
+            scope: service_account.scopes.join(" "),
+            aud: &service_account.key.token_uri,
+            iat,
+            exp: iat + 3600,
+            sub: service_account.subject.as_deref(),
+        };
+
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = service_account.key.private_key_id.clone();
+        let key =
+            jsonwebtoken::EncodingKey::from_rsa_pem(service_account.key.private_key.as_bytes())?;
+        let assertion = jsonwebtoken::encode(&header, &claims, &key)?;
+
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", &assertion),
+        ];
+        let client = &self.client;
+        let resp = client
+            .post(&self.token_endpoint)
+            .header(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .form(&params)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?;
+            return Err(ClientError::from_response(status, headers, &body).into());
+        }
+
+        // Unwrap the response.
+        let t: AccessToken = resp.json().await?;
+
+        self.save_token(&t).await?;
+
+        Ok(t)
+    }
+
     /// Return a user consent url with an optional set of scopes.
     /// If no scopes are provided, they will not be passed in the url.
     pub fn user_consent_url(&self, scopes: &[String]) -> String {
@@ -465,11 +922,32 @@ impl Client {
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let _refreshing = self.refreshing.lock().await;
+        self.fetch_refreshed_access_token().await
+    }
+
+    /// Refresh the access token, unless another request replaced `stale` while
+    /// this one waited for its turn. Refreshes run one at a time, so a refresh
+    /// token rotated by the provider is only spent once.
+    async fn refresh_stale_access_token(&self, stale: &str) -> Result<()> {
+        let _refreshing = self.refreshing.lock().await;
+        if self.token.read().await.access_token != stale {
+            return Ok(());
+        }
+        self.fetch_refreshed_access_token().await?;
+        Ok(())
+    }
+
+    async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
+        if let Some(service_account) = &self.service_account {
+            return self.get_service_account_access_token(service_account).await;
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                anyhow::bail!("refresh token cannot be empty");
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -485,9 +963,9 @@ impl Client {
                 ("client_secret", &self.client_secret),
                 ("redirect_uri", &self.redirect_uri),
             ];
-            let client = reqwest::Client::new();
+            let client = &self.client;
             client
-                .post(TOKEN_ENDPOINT)
+                .post(&self.token_endpoint)
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -495,16 +973,23 @@ impl Client {
                 .await?
         };
 
+        let status = response.status();
+        if !status.is_success() {
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+            return Err(ClientError::from_response(status, headers, &body).into());
+        }
+
         // Unwrap the response.
-        let t: AccessToken = response.json().await?;
+        let mut t: AccessToken = response.json().await?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Providers that rotate refresh tokens send a new one, which replaces the
+        // one just used. The others keep accepting the current one.
+        if t.refresh_token.is_empty() {
+            t.refresh_token = self.token.read().await.refresh_token.clone();
+        }
 
-        *self.token.write().await = InnerToken {
-            access_token: t.access_token.clone(),
-            refresh_token,
-            expires_at: Self::compute_expires_at(t.expires_in),
-        };
+        self.save_token(&t).await?;
 
         Ok(t)
     }
@@ -526,32 +1011,40 @@ impl Client {
             ("redirect_uri", &self.redirect_uri),
             ("state", state),
         ];
-        let client = reqwest::Client::new();
+        let client = &self.client;
         let resp = client
-            .post(TOKEN_ENDPOINT)
+            .post(&self.token_endpoint)
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
             .send()
             .await?;
 
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?;
+            return Err(ClientError::from_response(status, headers, &body).into());
+        }
+
         // Unwrap the response.
         let t: AccessToken = resp.json().await?;
 
-        *self.token.write().await = InnerToken {
-            access_token: t.access_token.clone(),
-            refresh_token: t.refresh_token.clone(),
-            expires_at: Self::compute_expires_at(t.expires_in),
-        };
+        self.save_token(&t).await?;
 
         Ok(t)
     }
 
-    async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
+    async fn url_and_auth(
+        &self,
+        uri: &str,
+    ) -> Result<(reqwest::Url, Option<(http::header::HeaderName, String)>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
         let auth = format!("Bearer {}", self.token.read().await.access_token);
-        parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+        parsed_url
+            .map(|u| (u, Some((http::header::AUTHORIZATION, auth))))
+            .map_err(|e| ClientError::from(e).into())
     }
 
     async fn make_request(
@@ -559,6 +1052,7 @@ impl Client {
         method: &reqwest::Method,
         uri: &str,
         message: Message,
+        headers: reqwest::header::HeaderMap,
     ) -> Result<reqwest::Request> {
         let (url, auth) = self.url_and_auth(uri).await?;
 
@@ -571,6 +1065,7 @@ impl Client {
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
+        req = req.headers(headers);
 
         if let Some(content_type) = &message.content_type {
             req = req.header(
@@ -584,15 +1079,15 @@ impl Client {
             );
         }
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         if let Some(body) = message.body {
             req = req.body(body);
         }
 
-        Ok(req.build()?)
+        Ok(req.build().map_err(ClientError::from)?)
     }
 
     async fn request_raw(
@@ -600,7 +1095,9 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         message: Message,
+        headers: reqwest::header::HeaderMap,
     ) -> Result<reqwest::Response> {
+        let mut refreshed = false;
         if self.auto_refresh {
             let expired = self.is_expired().await;
 
@@ -608,7 +1105,11 @@ impl Client {
                 // We have a known expired token, we know we need to perform a refresh prior to
                 // attempting to make a request
                 Some(true) => {
-                    self.refresh_access_token().await?;
+                    let stale = self.token.read().await.access_token.clone();
+                    self.refresh_stale_access_token(&stale)
+                        .await
+                        .map_err(ClientError::AuthRefresh)?;
+                    refreshed = true;
                 }
 
                 // We have a (theoretically) known good token available. We make an optimistic
@@ -628,10 +1129,142 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
-        let resp = self.client.execute(req).await?;
+        let sent_token = self.token.read().await.access_token.clone();
+        let req = self.make_request(&method, uri, message, headers).await?;
+        let retry = req.try_clone();
+        let resp = self.client.execute(req).await.map_err(ClientError::from)?;
+
+        // The token was revoked or expired early. Refresh it and try once more,
+        // unless it was just refreshed or the body can't be sent again.
+        match retry {
+            Some(mut req)
+                if resp.status() == reqwest::StatusCode::UNAUTHORIZED
+                    && self.auto_refresh
+                    && !refreshed =>
+            {
+                log::debug!("request was unauthorized, refreshing the access token");
+                self.refresh_stale_access_token(&sent_token)
+                    .await
+                    .map_err(ClientError::AuthRefresh)?;
+
+                let (_, auth) = self.url_and_auth(uri).await?;
+                if let Some((name, value)) = auth {
+                    req.headers_mut()
+                        .insert(name, reqwest::header::HeaderValue::from_str(&value)?);
+                }
+                Ok(self.client.execute(req).await.map_err(ClientError::from)?)
+            }
+            _ => Ok(resp),
+        }
+    }
+
+    /// Send the requests with this HTTP client, like one built with a
+    /// `ClientBuilder`.
+    pub fn with_http_client(
+        &mut self,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> &mut Self {
+        self.client = client;
+        self
+    }
+
+    /// Cache the responses to `GET` requests, and revalidate them with their `ETag`
+    /// and `Last-Modified` headers.
+    #[cfg(feature = "httpcache")]
+    pub fn with_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) -> &mut Self {
+        self.http_cache = http_cache;
+        self
+    }
+
+    /// Send the request and read the whole response. With the `httpcache` feature,
+    /// a `GET` is answered from the cache when the server says it didn't change.
+    async fn request_body(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        #[cfg(feature = "httpcache")]
+        if method == reqwest::Method::GET && message.body.is_none() {
+            return self.request_cached(uri, message).await;
+        }
+
+        let response = self
+            .request_raw(method, uri, message, reqwest::header::HeaderMap::new())
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        Ok((status, headers, response_body))
+    }
+
+    #[cfg(feature = "httpcache")]
+    async fn request_cached(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        let cached = crate::http_cache::lookup(&self.http_cache, uri).await;
+        let conditional_headers = cached
+            .as_ref()
+            .map(crate::http_cache::CachedResponse::conditional_headers)
+            .unwrap_or_default();
+        let content_type = message.content_type.clone();
+
+        let mut response = self
+            .request_raw(reqwest::Method::GET, uri, message, conditional_headers)
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            match cached {
+                Some(cached) => {
+                    let mut headers = response.headers().clone();
+                    // The other pages are the ones of the cached response.
+                    if let Some(link) = cached.link.as_deref().and_then(|l| l.parse().ok()) {
+                        headers.entry(reqwest::header::LINK).or_insert(link);
+                    }
+                    return Ok((reqwest::StatusCode::OK, headers, cached.body));
+                }
+                // Nothing is cached to answer a 304 with, so ask for the whole response.
+                None => {
+                    log::debug!("received a 304 without a cached response, requesting it again");
+                    let message = Message {
+                        body: None,
+                        content_type,
+                    };
+                    response = self
+                        .request_raw(
+                            reqwest::Method::GET,
+                            uri,
+                            message,
+                            reqwest::header::HeaderMap::new(),
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        if status.is_success() {
+            crate::http_cache::store(&self.http_cache, uri, &headers, &response_body).await;
+        }
 
-        Ok(resp)
+        Ok((status, headers, response_body))
     }
 
     async fn request<Out>(
@@ -643,11 +1276,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
-
-        let status = response.status();
-
-        let response_body = response.bytes().await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -658,19 +1287,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -683,19 +1302,15 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
-        let status = response.status();
-        let link = response
-            .headers()
+        let link = headers
             .get(http::header::LINK)
             .and_then(|l| l.to_str().ok())
             .and_then(|l| parse_link_header::parse(l).ok())
             .as_ref()
             .and_then(crate::utils::next_link);
 
-        let response_body = response.bytes().await?;
-
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
 
@@ -706,24 +1321,22 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response
+                .map(|out| (link, out))
+                .map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
     /* TODO: make this more DRY */
     #[allow(dead_code)]
-    async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -731,7 +1344,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -739,17 +1352,18 @@ impl Client {
             reqwest::header::HeaderValue::from_static("application/json"),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -765,19 +1379,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -804,15 +1408,16 @@ impl Client {
             reqwest::header::HeaderValue::from_str(accept_mime_type)?,
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -828,19 +1433,46 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(ClientError::from_response(status, headers, &response_body).into())
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
 
-            Err(error)
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
+        }
+
+        let response = req.send().await.map_err(ClientError::from)?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(crate::utils::Download::new(response))
+        } else {
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await.map_err(ClientError::from)?;
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -882,8 +1514,8 @@ impl Client {
                 .unwrap(),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         if content.len() > 1 {
@@ -892,11 +1524,12 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(ClientError::from)?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -907,19 +1540,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
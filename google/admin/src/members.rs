@@ -189,6 +189,73 @@ impl Members {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieves a paginated list of all members in a group.
+     */
+    pub fn list_all_stream(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Member> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_derived_membership {
+            query_args.push((
+                "includeDerivedMembership".to_string(),
+                include_derived_membership.to_string(),
+            ));
+        }
+        if !roles.is_empty() {
+            query_args.push(("roles".to_string(), roles.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/groups/{}/members?{}",
+                crate::progenitor_support::encode_path(group_key),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Members = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.members,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
@@ -168,6 +168,78 @@ impl Mobiledevices {
         // Return our response data.
         Ok(mobiledevices)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieves a paginated list of all mobile devices for an account.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::MobileDevice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::MobileDevices = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.mobiledevices,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
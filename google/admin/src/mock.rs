@@ -0,0 +1,1979 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Asps`](crate::asps::Asps).
+    pub fn asps(&self) -> Asps<'_> {
+        Asps { mock: self }
+    }
+
+    /// Mock the functions of [`Channels`](crate::channels::Channels).
+    pub fn channels(&self) -> Channels<'_> {
+        Channels { mock: self }
+    }
+
+    /// Mock the functions of [`Chromeosdevices`](crate::chromeosdevices::Chromeosdevices).
+    pub fn chromeosdevices(&self) -> Chromeosdevices<'_> {
+        Chromeosdevices { mock: self }
+    }
+
+    /// Mock the functions of [`Customer`](crate::customer::Customer).
+    pub fn customer(&self) -> Customer<'_> {
+        Customer { mock: self }
+    }
+
+    /// Mock the functions of [`Customers`](crate::customers::Customers).
+    pub fn customers(&self) -> Customers<'_> {
+        Customers { mock: self }
+    }
+
+    /// Mock the functions of [`DomainAliases`](crate::domain_aliases::DomainAliases).
+    pub fn domain_aliases(&self) -> DomainAliases<'_> {
+        DomainAliases { mock: self }
+    }
+
+    /// Mock the functions of [`Domains`](crate::domains::Domains).
+    pub fn domains(&self) -> Domains<'_> {
+        Domains { mock: self }
+    }
+
+    /// Mock the functions of [`Groups`](crate::groups::Groups).
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { mock: self }
+    }
+
+    /// Mock the functions of [`Members`](crate::members::Members).
+    pub fn members(&self) -> Members<'_> {
+        Members { mock: self }
+    }
+
+    /// Mock the functions of [`Mobiledevices`](crate::mobiledevices::Mobiledevices).
+    pub fn mobiledevices(&self) -> Mobiledevices<'_> {
+        Mobiledevices { mock: self }
+    }
+
+    /// Mock the functions of [`Orgunits`](crate::orgunits::Orgunits).
+    pub fn orgunits(&self) -> Orgunits<'_> {
+        Orgunits { mock: self }
+    }
+
+    /// Mock the functions of [`Privileges`](crate::privileges::Privileges).
+    pub fn privileges(&self) -> Privileges<'_> {
+        Privileges { mock: self }
+    }
+
+    /// Mock the functions of [`Resources`](crate::resources::Resources).
+    pub fn resources(&self) -> Resources<'_> {
+        Resources { mock: self }
+    }
+
+    /// Mock the functions of [`RoleAssignments`](crate::role_assignments::RoleAssignments).
+    pub fn role_assignments(&self) -> RoleAssignments<'_> {
+        RoleAssignments { mock: self }
+    }
+
+    /// Mock the functions of [`Roles`](crate::roles::Roles).
+    pub fn roles(&self) -> Roles<'_> {
+        Roles { mock: self }
+    }
+
+    /// Mock the functions of [`Schemas`](crate::schemas::Schemas).
+    pub fn schemas(&self) -> Schemas<'_> {
+        Schemas { mock: self }
+    }
+
+    /// Mock the functions of [`Tokens`](crate::tokens::Tokens).
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens { mock: self }
+    }
+
+    /// Mock the functions of [`TwoStepVerification`](crate::two_step_verification::TwoStepVerification).
+    pub fn two_step_verification(&self) -> TwoStepVerification<'_> {
+        TwoStepVerification { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+
+    /// Mock the functions of [`VerificationCodes`](crate::verification_codes::VerificationCodes).
+    pub fn verification_codes(&self) -> VerificationCodes<'_> {
+        VerificationCodes { mock: self }
+    }
+}
+
+pub struct Asps<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Asps<'a> {
+    /// Mock [`Asps::list`](crate::asps::Asps::list), a `GET` to `/admin/directory/v1/users/{userKey}/asps`.
+    pub fn list(&self, user_key: &str) -> crate::mock::MockOperation<'a, crate::types::Asps> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/asps",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Asps::get`](crate::asps::Asps::get), a `GET` to `/admin/directory/v1/users/{userKey}/asps/{codeId}`.
+    pub fn get(
+        &self,
+        user_key: &str,
+        code_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Asp> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/asps/{}",
+                crate::progenitor_support::encode_path(user_key),
+                crate::progenitor_support::encode_path(&code_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Asps::delete`](crate::asps::Asps::delete), a `DELETE` to `/admin/directory/v1/users/{userKey}/asps/{codeId}`.
+    pub fn delete(&self, user_key: &str, code_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/users/{}/asps/{}",
+                crate::progenitor_support::encode_path(user_key),
+                crate::progenitor_support::encode_path(&code_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Channels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Channels<'a> {
+    /// Mock [`Channels::admin_stop`](crate::channels::Channels::admin_stop), a `POST` to `/admin/directory_v1/channels/stop`.
+    pub fn admin_stop(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/directory_v1/channels/stop".to_string(),
+        )
+    }
+}
+
+pub struct Chromeosdevices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Chromeosdevices<'a> {
+    /// Mock [`Chromeosdevices::list`](crate::chromeosdevices::Chromeosdevices::list), a `GET` to `/admin/directory/v1/customer/{customerId}/devices/chromeos`.
+    pub fn list(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChromeOsDevices> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Chromeosdevices::move_devices_ou`](crate::chromeosdevices::Chromeosdevices::move_devices_ou), a `POST` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu`.
+    pub fn move_devices_ou(&self, customer_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Chromeosdevices::get`](crate::chromeosdevices::Chromeosdevices::get), a `GET` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}`.
+    pub fn get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChromeOsDevice> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`Chromeosdevices::update`](crate::chromeosdevices::Chromeosdevices::update), a `PUT` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}`.
+    pub fn update(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChromeOsDevice> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`Chromeosdevices::patch`](crate::chromeosdevices::Chromeosdevices::patch), a `PATCH` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}`.
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChromeOsDevice> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`Chromeosdevices::action`](crate::chromeosdevices::Chromeosdevices::action), a `POST` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action`.
+    pub fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}/action",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id)
+            ),
+        )
+    }
+}
+
+pub struct Customer<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Customer<'a> {
+    /// Mock [`Customer::admin_devices_chromeos_commands_get`](crate::customer::Customer::admin_devices_chromeos_commands_get), a `GET` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}`.
+    pub fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DirectoryChromeosdevicesCommand> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+                crate::progenitor_support::encode_path(command_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customer::admin_devices_chromeos_issue_command`](crate::customer::Customer::admin_devices_chromeos_issue_command), a `POST` to `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand`.
+    pub fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DirectoryChromeosdevicesIssueCommandResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}:issueCommand",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+}
+
+pub struct Customers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Customers<'a> {
+    /// Mock [`Customers::get`](crate::customers::Customers::get), a `GET` to `/admin/directory/v1/customers/{customerKey}`.
+    pub fn get(
+        &self,
+        customer_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer_key)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update`](crate::customers::Customers::update), a `PUT` to `/admin/directory/v1/customers/{customerKey}`.
+    pub fn update(
+        &self,
+        customer_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer_key)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::patch`](crate::customers::Customers::patch), a `PATCH` to `/admin/directory/v1/customers/{customerKey}`.
+    pub fn patch(
+        &self,
+        customer_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer_key)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_get`](crate::customers::Customers::admin_chrome_printers_get), a `GET` to `/admin/directory/v1/{name}`.
+    pub fn admin_chrome_printers_get(
+        &self,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Printer> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_delete`](crate::customers::Customers::admin_chrome_printers_delete), a `DELETE` to `/admin/directory/v1/{name}`.
+    pub fn admin_chrome_printers_delete(
+        &self,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Empty> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_patch`](crate::customers::Customers::admin_chrome_printers_patch), a `PATCH` to `/admin/directory/v1/{name}`.
+    pub fn admin_chrome_printers_patch(
+        &self,
+        name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Printer> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_list`](crate::customers::Customers::admin_chrome_printers_list), a `GET` to `/admin/directory/v1/{parent}/chrome/printers`.
+    pub fn admin_chrome_printers_list(
+        &self,
+        parent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPrintersResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_path(parent)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_create`](crate::customers::Customers::admin_chrome_printers_create), a `POST` to `/admin/directory/v1/{parent}/chrome/printers`.
+    pub fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Printer> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_path(parent)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_batch_create`](crate::customers::Customers::admin_chrome_printers_batch_create), a `POST` to `/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters`.
+    pub fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchCreatePrintersResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters",
+                crate::progenitor_support::encode_path(parent)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_batch_delete`](crate::customers::Customers::admin_chrome_printers_batch_delete), a `POST` to `/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters`.
+    pub fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchDeletePrintersResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters",
+                crate::progenitor_support::encode_path(parent)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::admin_chrome_printers_list_printer_models`](crate::customers::Customers::admin_chrome_printers_list_printer_models), a `GET` to `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels`.
+    pub fn admin_chrome_printers_list_printer_models(
+        &self,
+        parent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPrinterModelsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels",
+                crate::progenitor_support::encode_path(parent)
+            ),
+        )
+    }
+}
+
+pub struct DomainAliases<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DomainAliases<'a> {
+    /// Mock [`DomainAliases::list`](crate::domain_aliases::DomainAliases::list), a `GET` to `/admin/directory/v1/customer/{customer}/domainaliases`.
+    pub fn list(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAliases> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/domainaliases",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAliases::insert`](crate::domain_aliases::DomainAliases::insert), a `POST` to `/admin/directory/v1/customer/{customer}/domainaliases`.
+    pub fn insert(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAlias> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/domainaliases",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAliases::get`](crate::domain_aliases::DomainAliases::get), a `GET` to `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}`.
+    pub fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAlias> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/domainaliases/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(domain_alias_name)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAliases::delete`](crate::domain_aliases::DomainAliases::delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}`.
+    pub fn delete(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/domainaliases/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(domain_alias_name)
+            ),
+        )
+    }
+}
+
+pub struct Domains<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Domains<'a> {
+    /// Mock [`Domains::list`](crate::domains::Domains::list), a `GET` to `/admin/directory/v1/customer/{customer}/domains`.
+    pub fn list(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Domains2> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/domains",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::insert`](crate::domains::Domains::insert), a `POST` to `/admin/directory/v1/customer/{customer}/domains`.
+    pub fn insert(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/domains",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::get`](crate::domains::Domains::get), a `GET` to `/admin/directory/v1/customer/{customer}/domains/{domainName}`.
+    pub fn get(
+        &self,
+        customer: &str,
+        domain_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/domains/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(domain_name)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::delete`](crate::domains::Domains::delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/domains/{domainName}`.
+    pub fn delete(&self, customer: &str, domain_name: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/domains/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(domain_name)
+            ),
+        )
+    }
+}
+
+pub struct Groups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Groups<'a> {
+    /// Mock [`Groups::list`](crate::groups::Groups::list), a `GET` to `/admin/directory/v1/groups`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::Groups> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/directory/v1/groups".to_string(),
+        )
+    }
+
+    /// Mock [`Groups::insert`](crate::groups::Groups::insert), a `POST` to `/admin/directory/v1/groups`.
+    pub fn insert(&self) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/directory/v1/groups".to_string(),
+        )
+    }
+
+    /// Mock [`Groups::get`](crate::groups::Groups::get), a `GET` to `/admin/directory/v1/groups/{groupKey}`.
+    pub fn get(&self, group_key: &str) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update`](crate::groups::Groups::update), a `PUT` to `/admin/directory/v1/groups/{groupKey}`.
+    pub fn update(&self, group_key: &str) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::delete`](crate::groups::Groups::delete), a `DELETE` to `/admin/directory/v1/groups/{groupKey}`.
+    pub fn delete(&self, group_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::patch`](crate::groups::Groups::patch), a `PATCH` to `/admin/directory/v1/groups/{groupKey}`.
+    pub fn patch(&self, group_key: &str) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::aliases_list`](crate::groups::Groups::aliases_list), a `GET` to `/admin/directory/v1/groups/{groupKey}/aliases`.
+    pub fn aliases_list(
+        &self,
+        group_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Aliases> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/groups/{}/aliases",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::aliases_insert`](crate::groups::Groups::aliases_insert), a `POST` to `/admin/directory/v1/groups/{groupKey}/aliases`.
+    pub fn aliases_insert(
+        &self,
+        group_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Alias> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/groups/{}/aliases",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::aliases_delete`](crate::groups::Groups::aliases_delete), a `DELETE` to `/admin/directory/v1/groups/{groupKey}/aliases/{alias}`.
+    pub fn aliases_delete(
+        &self,
+        group_key: &str,
+        alias: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/groups/{}/aliases/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(alias)
+            ),
+        )
+    }
+}
+
+pub struct Members<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Members<'a> {
+    /// Mock [`Members::has`](crate::members::Members::has), a `GET` to `/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}`.
+    pub fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MembersHasMember> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/groups/{}/hasMember/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(member_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::list`](crate::members::Members::list), a `GET` to `/admin/directory/v1/groups/{groupKey}/members`.
+    pub fn list(&self, group_key: &str) -> crate::mock::MockOperation<'a, crate::types::Members> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/groups/{}/members",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::insert`](crate::members::Members::insert), a `POST` to `/admin/directory/v1/groups/{groupKey}/members`.
+    pub fn insert(&self, group_key: &str) -> crate::mock::MockOperation<'a, crate::types::Member> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/groups/{}/members",
+                crate::progenitor_support::encode_path(group_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::get`](crate::members::Members::get), a `GET` to `/admin/directory/v1/groups/{groupKey}/members/{memberKey}`.
+    pub fn get(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Member> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(member_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::update`](crate::members::Members::update), a `PUT` to `/admin/directory/v1/groups/{groupKey}/members/{memberKey}`.
+    pub fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Member> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(member_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::delete`](crate::members::Members::delete), a `DELETE` to `/admin/directory/v1/groups/{groupKey}/members/{memberKey}`.
+    pub fn delete(&self, group_key: &str, member_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(member_key)
+            ),
+        )
+    }
+
+    /// Mock [`Members::patch`](crate::members::Members::patch), a `PATCH` to `/admin/directory/v1/groups/{groupKey}/members/{memberKey}`.
+    pub fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Member> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_key),
+                crate::progenitor_support::encode_path(member_key)
+            ),
+        )
+    }
+}
+
+pub struct Mobiledevices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Mobiledevices<'a> {
+    /// Mock [`Mobiledevices::list`](crate::mobiledevices::Mobiledevices::list), a `GET` to `/admin/directory/v1/customer/{customerId}/devices/mobile`.
+    pub fn list(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MobileDevices> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/mobile",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Mobiledevices::get`](crate::mobiledevices::Mobiledevices::get), a `GET` to `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}`.
+    pub fn get(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MobileDevice> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Mobiledevices::delete`](crate::mobiledevices::Mobiledevices::delete), a `DELETE` to `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}`.
+    pub fn delete(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Mobiledevices::action`](crate::mobiledevices::Mobiledevices::action), a `POST` to `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action`.
+    pub fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}/action",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id)
+            ),
+        )
+    }
+}
+
+pub struct Orgunits<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Orgunits<'a> {
+    /// Mock [`Orgunits::list`](crate::orgunits::Orgunits::list), a `GET` to `/admin/directory/v1/customer/{customerId}/orgunits`.
+    pub fn list(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgUnits> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orgunits::insert`](crate::orgunits::Orgunits::insert), a `POST` to `/admin/directory/v1/customer/{customerId}/orgunits`.
+    pub fn insert(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgUnit> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orgunits::get`](crate::orgunits::Orgunits::get), a `GET` to `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}`.
+    pub fn get(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgUnit> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(org_unit_path)
+            ),
+        )
+    }
+
+    /// Mock [`Orgunits::update`](crate::orgunits::Orgunits::update), a `PUT` to `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}`.
+    pub fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgUnit> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(org_unit_path)
+            ),
+        )
+    }
+
+    /// Mock [`Orgunits::delete`](crate::orgunits::Orgunits::delete), a `DELETE` to `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}`.
+    pub fn delete(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(org_unit_path)
+            ),
+        )
+    }
+
+    /// Mock [`Orgunits::patch`](crate::orgunits::Orgunits::patch), a `PATCH` to `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}`.
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrgUnit> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/orgunits/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(org_unit_path)
+            ),
+        )
+    }
+}
+
+pub struct Privileges<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Privileges<'a> {
+    /// Mock [`Privileges::list`](crate::privileges::Privileges::list), a `GET` to `/admin/directory/v1/customer/{customer}/roles/ALL/privileges`.
+    pub fn list(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Privileges> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/roles/ALL/privileges",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+}
+
+pub struct Resources<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Resources<'a> {
+    /// Mock [`Resources::buildings_list`](crate::resources::Resources::buildings_list), a `GET` to `/admin/directory/v1/customer/{customer}/resources/buildings`.
+    pub fn buildings_list(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Buildings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::buildings_insert`](crate::resources::Resources::buildings_insert), a `POST` to `/admin/directory/v1/customer/{customer}/resources/buildings`.
+    pub fn buildings_insert(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Building> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::buildings_get`](crate::resources::Resources::buildings_get), a `GET` to `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}`.
+    pub fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Building> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::buildings_update`](crate::resources::Resources::buildings_update), a `PUT` to `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}`.
+    pub fn buildings_update(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Building> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::buildings_delete`](crate::resources::Resources::buildings_delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}`.
+    pub fn buildings_delete(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::buildings_patch`](crate::resources::Resources::buildings_patch), a `PATCH` to `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}`.
+    pub fn buildings_patch(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Building> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_list`](crate::resources::Resources::calendars_list), a `GET` to `/admin/directory/v1/customer/{customer}/resources/calendars`.
+    pub fn calendars_list(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarResources> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_insert`](crate::resources::Resources::calendars_insert), a `POST` to `/admin/directory/v1/customer/{customer}/resources/calendars`.
+    pub fn calendars_insert(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarResource> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_get`](crate::resources::Resources::calendars_get), a `GET` to `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}`.
+    pub fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarResource> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(calendar_resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_update`](crate::resources::Resources::calendars_update), a `PUT` to `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}`.
+    pub fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarResource> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(calendar_resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_delete`](crate::resources::Resources::calendars_delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}`.
+    pub fn calendars_delete(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(calendar_resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::calendars_patch`](crate::resources::Resources::calendars_patch), a `PATCH` to `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}`.
+    pub fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarResource> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(calendar_resource_id)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_list`](crate::resources::Resources::features_list), a `GET` to `/admin/directory/v1/customer/{customer}/resources/features`.
+    pub fn features_list(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Features> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_insert`](crate::resources::Resources::features_insert), a `POST` to `/admin/directory/v1/customer/{customer}/resources/features`.
+    pub fn features_insert(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_get`](crate::resources::Resources::features_get), a `GET` to `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}`.
+    pub fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(feature_key)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_update`](crate::resources::Resources::features_update), a `PUT` to `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}`.
+    pub fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(feature_key)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_delete`](crate::resources::Resources::features_delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}`.
+    pub fn features_delete(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(feature_key)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_patch`](crate::resources::Resources::features_patch), a `PATCH` to `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}`.
+    pub fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(feature_key)
+            ),
+        )
+    }
+
+    /// Mock [`Resources::features_rename`](crate::resources::Resources::features_rename), a `POST` to `/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename`.
+    pub fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/resources/features/{}/rename",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(old_name)
+            ),
+        )
+    }
+}
+
+pub struct RoleAssignments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RoleAssignments<'a> {
+    /// Mock [`RoleAssignments::list`](crate::role_assignments::RoleAssignments::list), a `GET` to `/admin/directory/v1/customer/{customer}/roleassignments`.
+    pub fn list(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RoleAssignments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`RoleAssignments::insert`](crate::role_assignments::RoleAssignments::insert), a `POST` to `/admin/directory/v1/customer/{customer}/roleassignments`.
+    pub fn insert(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RoleAssignment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`RoleAssignments::get`](crate::role_assignments::RoleAssignments::get), a `GET` to `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}`.
+    pub fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RoleAssignment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/roleassignments/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_assignment_id)
+            ),
+        )
+    }
+
+    /// Mock [`RoleAssignments::delete`](crate::role_assignments::RoleAssignments::delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}`.
+    pub fn delete(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/roleassignments/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_assignment_id)
+            ),
+        )
+    }
+}
+
+pub struct Roles<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Roles<'a> {
+    /// Mock [`Roles::list`](crate::roles::Roles::list), a `GET` to `/admin/directory/v1/customer/{customer}/roles`.
+    pub fn list(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Roles> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/roles",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::insert`](crate::roles::Roles::insert), a `POST` to `/admin/directory/v1/customer/{customer}/roles`.
+    pub fn insert(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/roles",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::get`](crate::roles::Roles::get), a `GET` to `/admin/directory/v1/customer/{customer}/roles/{roleId}`.
+    pub fn get(
+        &self,
+        customer: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/roles/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::update`](crate::roles::Roles::update), a `PUT` to `/admin/directory/v1/customer/{customer}/roles/{roleId}`.
+    pub fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/roles/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::delete`](crate::roles::Roles::delete), a `DELETE` to `/admin/directory/v1/customer/{customer}/roles/{roleId}`.
+    pub fn delete(&self, customer: &str, role_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/roles/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::patch`](crate::roles::Roles::patch), a `PATCH` to `/admin/directory/v1/customer/{customer}/roles/{roleId}`.
+    pub fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/roles/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+}
+
+pub struct Schemas<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Schemas<'a> {
+    /// Mock [`Schemas::list`](crate::schemas::Schemas::list), a `GET` to `/admin/directory/v1/customer/{customerId}/schemas`.
+    pub fn list(&self, customer_id: &str) -> crate::mock::MockOperation<'a, crate::types::Schemas> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Schemas::insert`](crate::schemas::Schemas::insert), a `POST` to `/admin/directory/v1/customer/{customerId}/schemas`.
+    pub fn insert(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Schema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Schemas::get`](crate::schemas::Schemas::get), a `GET` to `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}`.
+    pub fn get(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Schema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(schema_key)
+            ),
+        )
+    }
+
+    /// Mock [`Schemas::update`](crate::schemas::Schemas::update), a `PUT` to `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}`.
+    pub fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Schema> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(schema_key)
+            ),
+        )
+    }
+
+    /// Mock [`Schemas::delete`](crate::schemas::Schemas::delete), a `DELETE` to `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}`.
+    pub fn delete(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(schema_key)
+            ),
+        )
+    }
+
+    /// Mock [`Schemas::patch`](crate::schemas::Schemas::patch), a `PATCH` to `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}`.
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Schema> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/customer/{}/schemas/{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(schema_key)
+            ),
+        )
+    }
+}
+
+pub struct Tokens<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Tokens<'a> {
+    /// Mock [`Tokens::list`](crate::tokens::Tokens::list), a `GET` to `/admin/directory/v1/users/{userKey}/tokens`.
+    pub fn list(&self, user_key: &str) -> crate::mock::MockOperation<'a, crate::types::Tokens> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/tokens",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Tokens::get`](crate::tokens::Tokens::get), a `GET` to `/admin/directory/v1/users/{userKey}/tokens/{clientId}`.
+    pub fn get(
+        &self,
+        user_key: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Token> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/tokens/{}",
+                crate::progenitor_support::encode_path(user_key),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tokens::delete`](crate::tokens::Tokens::delete), a `DELETE` to `/admin/directory/v1/users/{userKey}/tokens/{clientId}`.
+    pub fn delete(&self, user_key: &str, client_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/users/{}/tokens/{}",
+                crate::progenitor_support::encode_path(user_key),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+}
+
+pub struct TwoStepVerification<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TwoStepVerification<'a> {
+    /// Mock [`TwoStepVerification::turn_off`](crate::two_step_verification::TwoStepVerification::turn_off), a `POST` to `/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff`.
+    pub fn turn_off(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/twoStepVerification/turnOff",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::list`](crate::users::Users::list), a `GET` to `/admin/directory/v1/users`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::Users> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/directory/v1/users".to_string(),
+        )
+    }
+
+    /// Mock [`Users::insert`](crate::users::Users::insert), a `POST` to `/admin/directory/v1/users`.
+    pub fn insert(&self) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/directory/v1/users".to_string(),
+        )
+    }
+
+    /// Mock [`Users::watch`](crate::users::Users::watch), a `POST` to `/admin/directory/v1/users/watch`.
+    pub fn watch(&self) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/directory/v1/users/watch".to_string(),
+        )
+    }
+
+    /// Mock [`Users::get`](crate::users::Users::get), a `GET` to `/admin/directory/v1/users/{userKey}`.
+    pub fn get(&self, user_key: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::update`](crate::users::Users::update), a `PUT` to `/admin/directory/v1/users/{userKey}`.
+    pub fn update(&self, user_key: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/users/{}",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::delete`](crate::users::Users::delete), a `DELETE` to `/admin/directory/v1/users/{userKey}`.
+    pub fn delete(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/users/{}",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::patch`](crate::users::Users::patch), a `PATCH` to `/admin/directory/v1/users/{userKey}`.
+    pub fn patch(&self, user_key: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/users/{}",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::aliases_list`](crate::users::Users::aliases_list), a `GET` to `/admin/directory/v1/users/{userKey}/aliases`.
+    pub fn aliases_list(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Aliases> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/aliases",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::aliases_insert`](crate::users::Users::aliases_insert), a `POST` to `/admin/directory/v1/users/{userKey}/aliases`.
+    pub fn aliases_insert(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Alias> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/aliases",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::aliases_watch`](crate::users::Users::aliases_watch), a `POST` to `/admin/directory/v1/users/{userKey}/aliases/watch`.
+    pub fn aliases_watch(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/aliases/watch",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::aliases_delete`](crate::users::Users::aliases_delete), a `DELETE` to `/admin/directory/v1/users/{userKey}/aliases/{alias}`.
+    pub fn aliases_delete(
+        &self,
+        user_key: &str,
+        alias: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/users/{}/aliases/{}",
+                crate::progenitor_support::encode_path(user_key),
+                crate::progenitor_support::encode_path(alias)
+            ),
+        )
+    }
+
+    /// Mock [`Users::make_admin`](crate::users::Users::make_admin), a `POST` to `/admin/directory/v1/users/{userKey}/makeAdmin`.
+    pub fn make_admin(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/makeAdmin",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::photos_get`](crate::users::Users::photos_get), a `GET` to `/admin/directory/v1/users/{userKey}/photos/thumbnail`.
+    pub fn photos_get(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPhoto> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/photos/thumbnail",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::photos_update`](crate::users::Users::photos_update), a `PUT` to `/admin/directory/v1/users/{userKey}/photos/thumbnail`.
+    pub fn photos_update(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPhoto> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/directory/v1/users/{}/photos/thumbnail",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::photos_delete`](crate::users::Users::photos_delete), a `DELETE` to `/admin/directory/v1/users/{userKey}/photos/thumbnail`.
+    pub fn photos_delete(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/directory/v1/users/{}/photos/thumbnail",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::photos_patch`](crate::users::Users::photos_patch), a `PATCH` to `/admin/directory/v1/users/{userKey}/photos/thumbnail`.
+    pub fn photos_patch(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPhoto> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/admin/directory/v1/users/{}/photos/thumbnail",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::sign_out`](crate::users::Users::sign_out), a `POST` to `/admin/directory/v1/users/{userKey}/signOut`.
+    pub fn sign_out(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/signOut",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`Users::undelete`](crate::users::Users::undelete), a `POST` to `/admin/directory/v1/users/{userKey}/undelete`.
+    pub fn undelete(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/undelete",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+}
+
+pub struct VerificationCodes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> VerificationCodes<'a> {
+    /// Mock [`VerificationCodes::list`](crate::verification_codes::VerificationCodes::list), a `GET` to `/admin/directory/v1/users/{userKey}/verificationCodes`.
+    pub fn list(
+        &self,
+        user_key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerificationCodes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/directory/v1/users/{}/verificationCodes",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`VerificationCodes::generate`](crate::verification_codes::VerificationCodes::generate), a `POST` to `/admin/directory/v1/users/{userKey}/verificationCodes/generate`.
+    pub fn generate(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/verificationCodes/generate",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+
+    /// Mock [`VerificationCodes::invalidate`](crate::verification_codes::VerificationCodes::invalidate), a `POST` to `/admin/directory/v1/users/{userKey}/verificationCodes/invalidate`.
+    pub fn invalidate(&self, user_key: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/directory/v1/users/{}/verificationCodes/invalidate",
+                crate::progenitor_support::encode_path(user_key)
+            ),
+        )
+    }
+}
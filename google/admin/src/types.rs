@@ -185,7 +185,7 @@ pub struct Asps {
 /**
 * Message severity
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Severity {
     #[serde(rename = "SEVERITY_ERROR")]
     SeverityError,
@@ -197,8 +197,8 @@ pub enum Severity {
     SeverityWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -209,12 +209,38 @@ impl std::fmt::Display for Severity {
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SEVERITY_ERROR" => Severity::SeverityError,
+            "SEVERITY_INFO" => Severity::SeverityInfo,
+            "SEVERITY_UNSPECIFIED" => Severity::SeverityUnspecified,
+            "SEVERITY_WARNING" => Severity::SeverityWarning,
+            "" => Severity::Noop,
+            _ => Severity::Unknown(s),
+        })
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -224,6 +250,11 @@ impl Severity {
     pub fn is_noop(&self) -> bool {
         matches!(self, Severity::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Severity::Unknown(_))
+    }
 }
 
 /// Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
@@ -1697,7 +1728,7 @@ pub struct CustomerPostalAddress {
 /**
 * Indicates the command state.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum State {
     #[serde(rename = "ACKED_BY_CLIENT")]
     AckedByClient,
@@ -1715,8 +1746,8 @@ pub enum State {
     StateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -1730,12 +1761,41 @@ impl std::fmt::Display for State {
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACKED_BY_CLIENT" => State::AckedByClient,
+            "CANCELLED" => State::Cancelled,
+            "EXECUTED_BY_CLIENT" => State::ExecutedByClient,
+            "EXPIRED" => State::Expired,
+            "PENDING" => State::Pending,
+            "SENT_TO_CLIENT" => State::SentToClient,
+            "STATE_UNSPECIFIED" => State::StateUnspecified,
+            "" => State::Noop,
+            _ => State::Unknown(s),
+        })
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -1745,12 +1805,17 @@ impl State {
     pub fn is_noop(&self) -> bool {
         matches!(self, State::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, State::Unknown(_))
+    }
 }
 
 /**
 * The type of the command.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
     CommandTypeUnspecified,
@@ -1766,8 +1831,8 @@ pub enum Type {
     WipeUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1780,12 +1845,40 @@ impl std::fmt::Display for Type {
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COMMAND_TYPE_UNSPECIFIED" => Type::CommandTypeUnspecified,
+            "REBOOT" => Type::Reboot,
+            "REMOTE_POWERWASH" => Type::RemotePowerwash,
+            "SET_VOLUME" => Type::SetVolume,
+            "TAKE_A_SCREENSHOT" => Type::TakeAScreenshot,
+            "WIPE_USERS" => Type::WipeUsers,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1795,6 +1888,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// Information regarding a command that was issued to a device.
@@ -1863,7 +1961,7 @@ pub struct DirectoryChromeosdevicesCommand {
 /**
 * The result of the command.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Result {
     #[serde(rename = "COMMAND_RESULT_TYPE_UNSPECIFIED")]
     CommandResultTypeUnspecified,
@@ -1875,8 +1973,8 @@ pub enum Result {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -1887,12 +1985,38 @@ impl std::fmt::Display for Result {
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Result {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Result {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COMMAND_RESULT_TYPE_UNSPECIFIED" => Result::CommandResultTypeUnspecified,
+            "FAILURE" => Result::Failure,
+            "IGNORED" => Result::Ignored,
+            "SUCCESS" => Result::Success,
+            "" => Result::Noop,
+            _ => Result::Unknown(s),
+        })
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -1902,6 +2026,11 @@ impl Result {
     pub fn is_noop(&self) -> bool {
         matches!(self, Result::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Result::Unknown(_))
+    }
 }
 
 /// The result of executing a command.
@@ -2138,7 +2267,7 @@ pub struct Empty {}
 /**
 * Canonical code for why the update failed to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "ABORTED")]
     Aborted,
@@ -2176,8 +2305,8 @@ pub enum ErrorCode {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -2201,12 +2330,51 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
             ErrorCode::Unknown => "UNKNOWN",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ABORTED" => ErrorCode::Aborted,
+            "ALREADY_EXISTS" => ErrorCode::AlreadyExists,
+            "CANCELLED" => ErrorCode::Cancelled,
+            "DATA_LOSS" => ErrorCode::DataLoss,
+            "DEADLINE_EXCEEDED" => ErrorCode::DeadlineExceeded,
+            "FAILED_PRECONDITION" => ErrorCode::FailedPrecondition,
+            "INTERNAL" => ErrorCode::Internal,
+            "INVALID_ARGUMENT" => ErrorCode::InvalidArgument,
+            "NOT_FOUND" => ErrorCode::NotFound,
+            "OK" => ErrorCode::Ok,
+            "OUT_OF_RANGE" => ErrorCode::OutOfRange,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "RESOURCE_EXHAUSTED" => ErrorCode::ResourceExhausted,
+            "UNAUTHENTICATED" => ErrorCode::Unauthenticated,
+            "UNAVAILABLE" => ErrorCode::Unavailable,
+            "UNIMPLEMENTED" => ErrorCode::Unimplemented,
+            "UNKNOWN" => ErrorCode::Unknown,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -2216,6 +2384,11 @@ impl ErrorCode {
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ErrorCode::UnknownValue(_))
+    }
 }
 
 /// Info about failures
@@ -5470,7 +5643,7 @@ pub struct VerificationCodes {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -5478,8 +5651,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -5488,12 +5661,36 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        })
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -5503,12 +5700,17 @@ impl Xgafv {
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Xgafv::Unknown(_))
+    }
 }
 
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -5518,8 +5720,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -5529,12 +5731,37 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        })
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -5544,12 +5771,17 @@ impl Alt {
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Alt::Unknown(_))
+    }
 }
 
 /**
 * Device property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrderBy {
     #[serde(rename = "annotatedLocation")]
     AnnotatedLocation,
@@ -5567,8 +5799,8 @@ pub enum OrderBy {
     SupportEndDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -5582,12 +5814,41 @@ impl std::fmt::Display for OrderBy {
             OrderBy::Status => "status",
             OrderBy::SupportEndDate => "supportEndDate",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "annotatedLocation" => OrderBy::AnnotatedLocation,
+            "annotatedUser" => OrderBy::AnnotatedUser,
+            "lastSync" => OrderBy::LastSync,
+            "notes" => OrderBy::Notes,
+            "serialNumber" => OrderBy::SerialNumber,
+            "status" => OrderBy::Status,
+            "supportEndDate" => OrderBy::SupportEndDate,
+            "" => OrderBy::Noop,
+            _ => OrderBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -5597,12 +5858,17 @@ impl OrderBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrderBy::Unknown(_))
+    }
 }
 
 /**
 * Restrict information returned to a set of selected fields.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Projection {
     #[serde(rename = "BASIC")]
     Basic,
@@ -5610,8 +5876,8 @@ pub enum Projection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Projection {
@@ -5620,12 +5886,36 @@ impl std::fmt::Display for Projection {
             Projection::Basic => "BASIC",
             Projection::Full => "FULL",
             Projection::Noop => "",
-            Projection::FallthroughString => "*",
+            Projection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Projection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Projection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BASIC" => Projection::Basic,
+            "FULL" => Projection::Full,
+            "" => Projection::Noop,
+            _ => Projection::Unknown(s),
+        })
+    }
+}
+
 impl Default for Projection {
     fn default() -> Projection {
         Projection::Noop
@@ -5635,12 +5925,17 @@ impl Projection {
     pub fn is_noop(&self) -> bool {
         matches!(self, Projection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Projection::Unknown(_))
+    }
 }
 
 /**
 * Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -5648,8 +5943,8 @@ pub enum SortOrder {
     Descending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -5658,12 +5953,36 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -5673,12 +5992,17 @@ impl SortOrder {
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortOrder::Unknown(_))
+    }
 }
 
 /**
 * Device property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryMobiledevicesListOrderBy {
     #[serde(rename = "deviceId")]
     DeviceId,
@@ -5698,8 +6022,8 @@ pub enum DirectoryMobiledevicesListOrderBy {
     Type,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
@@ -5714,12 +6038,42 @@ impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
             DirectoryMobiledevicesListOrderBy::Status => "status",
             DirectoryMobiledevicesListOrderBy::Type => "type",
             DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryMobiledevicesListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryMobiledevicesListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "deviceId" => DirectoryMobiledevicesListOrderBy::DeviceId,
+            "email" => DirectoryMobiledevicesListOrderBy::Email,
+            "lastSync" => DirectoryMobiledevicesListOrderBy::LastSync,
+            "model" => DirectoryMobiledevicesListOrderBy::Model,
+            "name" => DirectoryMobiledevicesListOrderBy::Name,
+            "os" => DirectoryMobiledevicesListOrderBy::Os,
+            "status" => DirectoryMobiledevicesListOrderBy::Status,
+            "type" => DirectoryMobiledevicesListOrderBy::Type,
+            "" => DirectoryMobiledevicesListOrderBy::Noop,
+            _ => DirectoryMobiledevicesListOrderBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryMobiledevicesListOrderBy {
     fn default() -> DirectoryMobiledevicesListOrderBy {
         DirectoryMobiledevicesListOrderBy::Noop
@@ -5729,12 +6083,17 @@ impl DirectoryMobiledevicesListOrderBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryMobiledevicesListOrderBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryMobiledevicesListOrderBy::Unknown(_))
+    }
 }
 
 /**
 * Whether to return all sub-organizations or just immediate children.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryOrgunitsListType {
     #[serde(rename = "all")]
     All,
@@ -5742,8 +6101,8 @@ pub enum DirectoryOrgunitsListType {
     Children,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryOrgunitsListType {
@@ -5752,12 +6111,36 @@ impl std::fmt::Display for DirectoryOrgunitsListType {
             DirectoryOrgunitsListType::All => "all",
             DirectoryOrgunitsListType::Children => "children",
             DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
+            DirectoryOrgunitsListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryOrgunitsListType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryOrgunitsListType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => DirectoryOrgunitsListType::All,
+            "children" => DirectoryOrgunitsListType::Children,
+            "" => DirectoryOrgunitsListType::Noop,
+            _ => DirectoryOrgunitsListType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryOrgunitsListType {
     fn default() -> DirectoryOrgunitsListType {
         DirectoryOrgunitsListType::Noop
@@ -5767,12 +6150,17 @@ impl DirectoryOrgunitsListType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryOrgunitsListType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryOrgunitsListType::Unknown(_))
+    }
 }
 
 /**
 * Source from which Building.coordinates are derived.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CoordinatesSource {
     #[serde(rename = "CLIENT_SPECIFIED")]
     ClientSpecified,
@@ -5782,8 +6170,8 @@ pub enum CoordinatesSource {
     SourceUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CoordinatesSource {
@@ -5793,12 +6181,37 @@ impl std::fmt::Display for CoordinatesSource {
             CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
             CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
             CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
+            CoordinatesSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CoordinatesSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CoordinatesSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CLIENT_SPECIFIED" => CoordinatesSource::ClientSpecified,
+            "RESOLVED_FROM_ADDRESS" => CoordinatesSource::ResolvedFromAddress,
+            "SOURCE_UNSPECIFIED" => CoordinatesSource::SourceUnspecified,
+            "" => CoordinatesSource::Noop,
+            _ => CoordinatesSource::Unknown(s),
+        })
+    }
+}
+
 impl Default for CoordinatesSource {
     fn default() -> CoordinatesSource {
         CoordinatesSource::Noop
@@ -5808,19 +6221,24 @@ impl CoordinatesSource {
     pub fn is_noop(&self) -> bool {
         matches!(self, CoordinatesSource::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CoordinatesSource::Unknown(_))
+    }
 }
 
 /**
 * Column to use for sorting results
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryGroupsListOrderBy {
     #[serde(rename = "email")]
     Email,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryGroupsListOrderBy {
@@ -5828,12 +6246,35 @@ impl std::fmt::Display for DirectoryGroupsListOrderBy {
         match self {
             DirectoryGroupsListOrderBy::Email => "email",
             DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
+            DirectoryGroupsListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryGroupsListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryGroupsListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "email" => DirectoryGroupsListOrderBy::Email,
+            "" => DirectoryGroupsListOrderBy::Noop,
+            _ => DirectoryGroupsListOrderBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryGroupsListOrderBy {
     fn default() -> DirectoryGroupsListOrderBy {
         DirectoryGroupsListOrderBy::Noop
@@ -5843,12 +6284,17 @@ impl DirectoryGroupsListOrderBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryGroupsListOrderBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryGroupsListOrderBy::Unknown(_))
+    }
 }
 
 /**
 * Event on which subscription is intended (if subscribing)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Event {
     #[serde(rename = "add")]
     Add,
@@ -5862,8 +6308,8 @@ pub enum Event {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Event {
@@ -5875,12 +6321,39 @@ impl std::fmt::Display for Event {
             Event::Undelete => "undelete",
             Event::Update => "update",
             Event::Noop => "",
-            Event::FallthroughString => "*",
+            Event::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "add" => Event::Add,
+            "delete" => Event::Delete,
+            "makeAdmin" => Event::MakeAdmin,
+            "undelete" => Event::Undelete,
+            "update" => Event::Update,
+            "" => Event::Noop,
+            _ => Event::Unknown(s),
+        })
+    }
+}
+
 impl Default for Event {
     fn default() -> Event {
         Event::Noop
@@ -5890,12 +6363,17 @@ impl Event {
     pub fn is_noop(&self) -> bool {
         matches!(self, Event::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Event::Unknown(_))
+    }
 }
 
 /**
 * Property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryUsersListOrderBy {
     #[serde(rename = "email")]
     Email,
@@ -5905,8 +6383,8 @@ pub enum DirectoryUsersListOrderBy {
     GivenName,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListOrderBy {
@@ -5916,12 +6394,37 @@ impl std::fmt::Display for DirectoryUsersListOrderBy {
             DirectoryUsersListOrderBy::FamilyName => "familyName",
             DirectoryUsersListOrderBy::GivenName => "givenName",
             DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
+            DirectoryUsersListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryUsersListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "email" => DirectoryUsersListOrderBy::Email,
+            "familyName" => DirectoryUsersListOrderBy::FamilyName,
+            "givenName" => DirectoryUsersListOrderBy::GivenName,
+            "" => DirectoryUsersListOrderBy::Noop,
+            _ => DirectoryUsersListOrderBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryUsersListOrderBy {
     fn default() -> DirectoryUsersListOrderBy {
         DirectoryUsersListOrderBy::Noop
@@ -5931,12 +6434,17 @@ impl DirectoryUsersListOrderBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersListOrderBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryUsersListOrderBy::Unknown(_))
+    }
 }
 
 /**
 * What subset of fields to fetch for this user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryUsersListProjection {
     #[serde(rename = "basic")]
     Basic,
@@ -5946,8 +6454,8 @@ pub enum DirectoryUsersListProjection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListProjection {
@@ -5957,12 +6465,37 @@ impl std::fmt::Display for DirectoryUsersListProjection {
             DirectoryUsersListProjection::Custom => "custom",
             DirectoryUsersListProjection::Full => "full",
             DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
+            DirectoryUsersListProjection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryUsersListProjection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersListProjection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "basic" => DirectoryUsersListProjection::Basic,
+            "custom" => DirectoryUsersListProjection::Custom,
+            "full" => DirectoryUsersListProjection::Full,
+            "" => DirectoryUsersListProjection::Noop,
+            _ => DirectoryUsersListProjection::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryUsersListProjection {
     fn default() -> DirectoryUsersListProjection {
         DirectoryUsersListProjection::Noop
@@ -5972,12 +6505,17 @@ impl DirectoryUsersListProjection {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersListProjection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryUsersListProjection::Unknown(_))
+    }
 }
 
 /**
 * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ViewType {
     #[serde(rename = "admin_view")]
     AdminView,
@@ -5985,8 +6523,8 @@ pub enum ViewType {
     DomainPublic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewType {
@@ -5995,12 +6533,36 @@ impl std::fmt::Display for ViewType {
             ViewType::AdminView => "admin_view",
             ViewType::DomainPublic => "domain_public",
             ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
+            ViewType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ViewType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin_view" => ViewType::AdminView,
+            "domain_public" => ViewType::DomainPublic,
+            "" => ViewType::Noop,
+            _ => ViewType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ViewType {
     fn default() -> ViewType {
         ViewType::Noop
@@ -6010,12 +6572,17 @@ impl ViewType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ViewType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ViewType::Unknown(_))
+    }
 }
 
 /**
 * Events to watch for.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DirectoryUsersAliasesListEvent {
     #[serde(rename = "add")]
     Add,
@@ -6023,8 +6590,8 @@ pub enum DirectoryUsersAliasesListEvent {
     Delete,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersAliasesListEvent {
@@ -6033,12 +6600,36 @@ impl std::fmt::Display for DirectoryUsersAliasesListEvent {
             DirectoryUsersAliasesListEvent::Add => "add",
             DirectoryUsersAliasesListEvent::Delete => "delete",
             DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+            DirectoryUsersAliasesListEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DirectoryUsersAliasesListEvent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersAliasesListEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "add" => DirectoryUsersAliasesListEvent::Add,
+            "delete" => DirectoryUsersAliasesListEvent::Delete,
+            "" => DirectoryUsersAliasesListEvent::Noop,
+            _ => DirectoryUsersAliasesListEvent::Unknown(s),
+        })
+    }
+}
+
 impl Default for DirectoryUsersAliasesListEvent {
     fn default() -> DirectoryUsersAliasesListEvent {
         DirectoryUsersAliasesListEvent::Noop
@@ -6048,4 +6639,9 @@ impl DirectoryUsersAliasesListEvent {
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersAliasesListEvent::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DirectoryUsersAliasesListEvent::Unknown(_))
+    }
 }
@@ -1 +1,104 @@
+use rsa::{
+    pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding},
+    RsaPrivateKey,
+};
+use wiremock::{
+    matchers::{bearer_token, body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[derive(Debug, serde::Deserialize)]
+struct Claims {
+    iss: String,
+    scope: String,
+    aud: String,
+    sub: Option<String>,
+}
+
+#[tokio::test]
+async fn test_service_account_mints_and_caches_delegated_token() {
+    let server = MockServer::start().await;
+    let token_uri = format!("{}/token", server.uri());
+
+    let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).unwrap();
+    let public_key = private_key
+        .to_public_key()
+        .to_public_key_pem(LineEnding::LF)
+        .unwrap();
+    let key = yup_oauth2::parse_service_account_key(
+        serde_json::json!({
+            "type": "service_account",
+            "private_key_id": "key-id",
+            "private_key": *private_key.to_pkcs8_pem(LineEnding::LF).unwrap(),
+            "client_email": "automation@project.iam.gserviceaccount.com",
+            "client_id": "1234",
+            "token_uri": token_uri,
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains(
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "service-account-token",
+            "expires_in": 3599,
+            "token_type": "Bearer",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/admin/directory/v1/customer/my_customer/domains/example.com",
+        ))
+        .and(bearer_token("service-account-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "domainName": "example.com",
+            "verified": true,
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut client = gsuite_api::Client::new_from_service_account(
+        key,
+        &["https://www.googleapis.com/auth/admin.directory.domain.readonly"],
+        Some("admin@example.com"),
+    );
+    client.with_host_override(server.uri());
+
+    // The token is minted once, then reused.
+    for _ in 0..2 {
+        let domain = client
+            .domains()
+            .get("my_customer", "example.com")
+            .await
+            .unwrap();
+        assert_eq!(domain.domain_name, "example.com");
+    }
+
+    let requests = server.received_requests().await.unwrap();
+    let form: std::collections::HashMap<String, String> =
+        serde_urlencoded::from_bytes(&requests[0].body).unwrap();
+    let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
+    validation.set_audience(&[&token_uri]);
+    let jwt = jsonwebtoken::decode::<Claims>(
+        &form["assertion"],
+        &jsonwebtoken::DecodingKey::from_rsa_pem(public_key.as_bytes()).unwrap(),
+        &validation,
+    )
+    .unwrap();
+
+    assert_eq!(jwt.header.kid.as_deref(), Some("key-id"));
+    assert_eq!(jwt.claims.iss, "automation@project.iam.gserviceaccount.com");
+    assert_eq!(
+        jwt.claims.scope,
+        "https://www.googleapis.com/auth/admin.directory.domain.readonly"
+    );
+    assert_eq!(jwt.claims.aud, token_uri);
+    assert_eq!(jwt.claims.sub.as_deref(), Some("admin@example.com"));
+}
@@ -145,6 +145,66 @@ impl Acl {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AclRule> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Acl = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.items,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
      *
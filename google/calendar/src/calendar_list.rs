@@ -151,6 +151,68 @@ impl CalendarList {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub fn list_all_stream(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CalendarListEntry> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !min_access_role.to_string().is_empty() {
+            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::CalendarList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.items,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/users/me/calendarList` endpoint.
      *
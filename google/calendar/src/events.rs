@@ -282,6 +282,123 @@ impl Events {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !i_cal_uid.is_empty() {
+            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !private_extended_property.is_empty() {
+            query_args.push((
+                "privateExtendedProperty".to_string(),
+                private_extended_property.join(" "),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !shared_extended_property.is_empty() {
+            query_args.push((
+                "sharedExtendedProperty".to_string(),
+                shared_extended_property.join(" "),
+            ));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden_invitations {
+            query_args.push((
+                "showHiddenInvitations".to_string(),
+                show_hidden_invitations.to_string(),
+            ));
+        }
+        if single_events {
+            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        if !updated_min.is_empty() {
+            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Events = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.items,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -1014,6 +1131,88 @@ impl Events {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns instances of the specified recurring event.
+     */
+    pub fn get_all_instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Event> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !original_start.is_empty() {
+            query_args.push(("originalStart".to_string(), original_start.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events/{}/instances?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Events = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.items,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
      *
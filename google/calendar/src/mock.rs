@@ -0,0 +1,659 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Acl`](crate::acl::Acl).
+    pub fn acl(&self) -> Acl<'_> {
+        Acl { mock: self }
+    }
+
+    /// Mock the functions of [`CalendarList`](crate::calendar_list::CalendarList).
+    pub fn calendar_list(&self) -> CalendarList<'_> {
+        CalendarList { mock: self }
+    }
+
+    /// Mock the functions of [`Calendars`](crate::calendars::Calendars).
+    pub fn calendars(&self) -> Calendars<'_> {
+        Calendars { mock: self }
+    }
+
+    /// Mock the functions of [`Channels`](crate::channels::Channels).
+    pub fn channels(&self) -> Channels<'_> {
+        Channels { mock: self }
+    }
+
+    /// Mock the functions of [`Colors`](crate::colors::Colors).
+    pub fn colors(&self) -> Colors<'_> {
+        Colors { mock: self }
+    }
+
+    /// Mock the functions of [`Events`](crate::events::Events).
+    pub fn events(&self) -> Events<'_> {
+        Events { mock: self }
+    }
+
+    /// Mock the functions of [`Freebusy`](crate::freebusy::Freebusy).
+    pub fn freebusy(&self) -> Freebusy<'_> {
+        Freebusy { mock: self }
+    }
+
+    /// Mock the functions of [`Settings`](crate::settings::Settings).
+    pub fn settings(&self) -> Settings<'_> {
+        Settings { mock: self }
+    }
+}
+
+pub struct Acl<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Acl<'a> {
+    /// Mock [`Acl::list`](crate::acl::Acl::list), a `GET` to `/calendars/{calendarId}/acl`.
+    pub fn list(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, crate::types::Acl> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}/acl",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::insert`](crate::acl::Acl::insert), a `POST` to `/calendars/{calendarId}/acl`.
+    pub fn insert(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AclRule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/acl",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::watch`](crate::acl::Acl::watch), a `POST` to `/calendars/{calendarId}/acl/watch`.
+    pub fn watch(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/acl/watch",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::get`](crate::acl::Acl::get), a `GET` to `/calendars/{calendarId}/acl/{ruleId}`.
+    pub fn get(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AclRule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::update`](crate::acl::Acl::update), a `PUT` to `/calendars/{calendarId}/acl/{ruleId}`.
+    pub fn update(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AclRule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::delete`](crate::acl::Acl::delete), a `DELETE` to `/calendars/{calendarId}/acl/{ruleId}`.
+    pub fn delete(&self, calendar_id: &str, rule_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Acl::patch`](crate::acl::Acl::patch), a `PATCH` to `/calendars/{calendarId}/acl/{ruleId}`.
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AclRule> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/calendars/{}/acl/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+}
+
+pub struct CalendarList<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CalendarList<'a> {
+    /// Mock [`CalendarList::list`](crate::calendar_list::CalendarList::list), a `GET` to `/users/me/calendarList`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::CalendarList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users/me/calendarList".to_string())
+    }
+
+    /// Mock [`CalendarList::list_insert`](crate::calendar_list::CalendarList::list_insert), a `POST` to `/users/me/calendarList`.
+    pub fn list_insert(&self) -> crate::mock::MockOperation<'a, crate::types::CalendarListEntry> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users/me/calendarList".to_string())
+    }
+
+    /// Mock [`CalendarList::list_watch`](crate::calendar_list::CalendarList::list_watch), a `POST` to `/users/me/calendarList/watch`.
+    pub fn list_watch(&self) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/users/me/calendarList/watch".to_string(),
+        )
+    }
+
+    /// Mock [`CalendarList::list_get`](crate::calendar_list::CalendarList::list_get), a `GET` to `/users/me/calendarList/{calendarId}`.
+    pub fn list_get(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarListEntry> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`CalendarList::list_update`](crate::calendar_list::CalendarList::list_update), a `PUT` to `/users/me/calendarList/{calendarId}`.
+    pub fn list_update(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarListEntry> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`CalendarList::list_delete`](crate::calendar_list::CalendarList::list_delete), a `DELETE` to `/users/me/calendarList/{calendarId}`.
+    pub fn list_delete(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`CalendarList::list_patch`](crate::calendar_list::CalendarList::list_patch), a `PATCH` to `/users/me/calendarList/{calendarId}`.
+    pub fn list_patch(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CalendarListEntry> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/users/me/calendarList/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+}
+
+pub struct Calendars<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Calendars<'a> {
+    /// Mock [`Calendars::insert`](crate::calendars::Calendars::insert), a `POST` to `/calendars`.
+    pub fn insert(&self) -> crate::mock::MockOperation<'a, crate::types::Calendar> {
+        self.mock
+            .operation(reqwest::Method::POST, "/calendars".to_string())
+    }
+
+    /// Mock [`Calendars::get`](crate::calendars::Calendars::get), a `GET` to `/calendars/{calendarId}`.
+    pub fn get(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, crate::types::Calendar> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Calendars::update`](crate::calendars::Calendars::update), a `PUT` to `/calendars/{calendarId}`.
+    pub fn update(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Calendar> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/calendars/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Calendars::delete`](crate::calendars::Calendars::delete), a `DELETE` to `/calendars/{calendarId}`.
+    pub fn delete(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/calendars/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Calendars::patch`](crate::calendars::Calendars::patch), a `PATCH` to `/calendars/{calendarId}`.
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Calendar> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/calendars/{}",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Calendars::clear`](crate::calendars::Calendars::clear), a `POST` to `/calendars/{calendarId}/clear`.
+    pub fn clear(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/clear",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+}
+
+pub struct Channels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Channels<'a> {
+    /// Mock [`Channels::stop`](crate::channels::Channels::stop), a `POST` to `/channels/stop`.
+    pub fn stop(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/channels/stop".to_string())
+    }
+}
+
+pub struct Colors<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Colors<'a> {
+    /// Mock [`Colors::get`](crate::colors::Colors::get), a `GET` to `/colors`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Colors> {
+        self.mock
+            .operation(reqwest::Method::GET, "/colors".to_string())
+    }
+}
+
+pub struct Events<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Events<'a> {
+    /// Mock [`Events::list`](crate::events::Events::list), a `GET` to `/calendars/{calendarId}/events`.
+    pub fn list(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, crate::types::Events> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}/events",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::insert`](crate::events::Events::insert), a `POST` to `/calendars/{calendarId}/events`.
+    pub fn insert(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/events",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::import`](crate::events::Events::import), a `POST` to `/calendars/{calendarId}/events/import`.
+    pub fn import(&self, calendar_id: &str) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/events/import",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::quick_add`](crate::events::Events::quick_add), a `POST` to `/calendars/{calendarId}/events/quickAdd`.
+    pub fn quick_add(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/events/quickAdd",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::watch`](crate::events::Events::watch), a `POST` to `/calendars/{calendarId}/events/watch`.
+    pub fn watch(
+        &self,
+        calendar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/events/watch",
+                crate::progenitor_support::encode_path(calendar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::get`](crate::events::Events::get), a `GET` to `/calendars/{calendarId}/events/{eventId}`.
+    pub fn get(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::update`](crate::events::Events::update), a `PUT` to `/calendars/{calendarId}/events/{eventId}`.
+    pub fn update(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::delete`](crate::events::Events::delete), a `DELETE` to `/calendars/{calendarId}/events/{eventId}`.
+    pub fn delete(&self, calendar_id: &str, event_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::patch`](crate::events::Events::patch), a `PATCH` to `/calendars/{calendarId}/events/{eventId}`.
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/calendars/{}/events/{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::instances`](crate::events::Events::instances), a `GET` to `/calendars/{calendarId}/events/{eventId}/instances`.
+    pub fn instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Events> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/calendars/{}/events/{}/instances",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::mv`](crate::events::Events::mv), a `POST` to `/calendars/{calendarId}/events/{eventId}/move`.
+    pub fn mv(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/calendars/{}/events/{}/move",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+}
+
+pub struct Freebusy<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Freebusy<'a> {
+    /// Mock [`Freebusy::query`](crate::freebusy::Freebusy::query), a `POST` to `/freeBusy`.
+    pub fn query(&self) -> crate::mock::MockOperation<'a, crate::types::FreeBusyResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/freeBusy".to_string())
+    }
+}
+
+pub struct Settings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Settings<'a> {
+    /// Mock [`Settings::list`](crate::settings::Settings::list), a `GET` to `/users/me/settings`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::Settings> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users/me/settings".to_string())
+    }
+
+    /// Mock [`Settings::watch`](crate::settings::Settings::watch), a `POST` to `/users/me/settings/watch`.
+    pub fn watch(&self) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/users/me/settings/watch".to_string(),
+        )
+    }
+
+    /// Mock [`Settings::get`](crate::settings::Settings::get), a `GET` to `/users/me/settings/{setting}`.
+    pub fn get(&self, setting: &str) -> crate::mock::MockOperation<'a, crate::types::Setting> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/me/settings/{}",
+                crate::progenitor_support::encode_path(setting)
+            ),
+        )
+    }
+}
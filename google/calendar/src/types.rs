@@ -2062,14 +2062,14 @@ pub struct TimePeriod {
 /**
 * Data format for the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -2077,12 +2077,35 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        })
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -2092,12 +2115,17 @@ impl Alt {
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Alt::Unknown(_))
+    }
 }
 
 /**
 * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrderBy {
     #[serde(rename = "startTime")]
     StartTime,
@@ -2105,8 +2133,8 @@ pub enum OrderBy {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -2115,12 +2143,36 @@ impl std::fmt::Display for OrderBy {
             OrderBy::StartTime => "startTime",
             OrderBy::Updated => "updated",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "startTime" => OrderBy::StartTime,
+            "updated" => OrderBy::Updated,
+            "" => OrderBy::Noop,
+            _ => OrderBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -2130,12 +2182,17 @@ impl OrderBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrderBy::Unknown(_))
+    }
 }
 
 /**
 * Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SendUpdates {
     #[serde(rename = "all")]
     All,
@@ -2145,8 +2202,8 @@ pub enum SendUpdates {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendUpdates {
@@ -2156,12 +2213,37 @@ impl std::fmt::Display for SendUpdates {
             SendUpdates::ExternalOnly => "externalOnly",
             SendUpdates::None => "none",
             SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
+            SendUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SendUpdates {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SendUpdates {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => SendUpdates::All,
+            "externalOnly" => SendUpdates::ExternalOnly,
+            "none" => SendUpdates::None,
+            "" => SendUpdates::Noop,
+            _ => SendUpdates::Unknown(s),
+        })
+    }
+}
+
 impl Default for SendUpdates {
     fn default() -> SendUpdates {
         SendUpdates::Noop
@@ -2171,12 +2253,17 @@ impl SendUpdates {
     pub fn is_noop(&self) -> bool {
         matches!(self, SendUpdates::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SendUpdates::Unknown(_))
+    }
 }
 
 /**
 * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MinAccessRole {
     #[serde(rename = "freeBusyReader")]
     FreeBusyReader,
@@ -2188,8 +2275,8 @@ pub enum MinAccessRole {
     Writer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MinAccessRole {
@@ -2200,12 +2287,38 @@ impl std::fmt::Display for MinAccessRole {
             MinAccessRole::Reader => "reader",
             MinAccessRole::Writer => "writer",
             MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
+            MinAccessRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MinAccessRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MinAccessRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "freeBusyReader" => MinAccessRole::FreeBusyReader,
+            "owner" => MinAccessRole::Owner,
+            "reader" => MinAccessRole::Reader,
+            "writer" => MinAccessRole::Writer,
+            "" => MinAccessRole::Noop,
+            _ => MinAccessRole::Unknown(s),
+        })
+    }
+}
+
 impl Default for MinAccessRole {
     fn default() -> MinAccessRole {
         MinAccessRole::Noop
@@ -2215,4 +2328,9 @@ impl MinAccessRole {
     pub fn is_noop(&self) -> bool {
         matches!(self, MinAccessRole::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MinAccessRole::Unknown(_))
+    }
 }
@@ -133,6 +133,62 @@ impl Folders {
         // Return our response data.
         Ok(folders)
     }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     */
+    pub fn list_all_stream(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Folder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent.is_empty() {
+            query_args.push(("parent".to_string(), parent.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v2/folders?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListFoldersResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.folders,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v2/folders` endpoint.
      *
@@ -289,7 +345,7 @@ impl Folders {
         body: &crate::types::MoveFolderRequest,
     ) -> Result<crate::types::Operation> {
         let url = self.client.url(
-            &format!("/v2/{}/move", crate::progenitor_support::encode_path(name),),
+            &format!("/v2/{}:move", crate::progenitor_support::encode_path(name),),
             None,
         );
         self.client
@@ -318,7 +374,7 @@ impl Folders {
     ) -> Result<crate::types::Folder> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/undelete",
+                "/v2/{}:undelete",
                 crate::progenitor_support::encode_path(name),
             ),
             None,
@@ -349,7 +405,7 @@ impl Folders {
     ) -> Result<crate::types::Policy> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/getIamPolicy",
+                "/v2/{}:getIamPolicy",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
@@ -380,7 +436,7 @@ impl Folders {
     ) -> Result<crate::types::Policy> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/setIamPolicy",
+                "/v2/{}:setIamPolicy",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
@@ -411,7 +467,7 @@ impl Folders {
     ) -> Result<crate::types::TestIamPermissionsResponse> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/testIamPermissions",
+                "/v2/{}:testIamPermissions",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
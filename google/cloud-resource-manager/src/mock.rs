@@ -0,0 +1,269 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Folders`](crate::folders::Folders).
+    pub fn folders(&self) -> Folders<'_> {
+        Folders { mock: self }
+    }
+
+    /// Mock the functions of [`Operations`](crate::operations::Operations).
+    pub fn operations(&self) -> Operations<'_> {
+        Operations { mock: self }
+    }
+}
+
+pub struct Folders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Folders<'a> {
+    /// Mock [`Folders::list`](crate::folders::Folders::list), a `GET` to `/v2/folders`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::ListFoldersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v2/folders".to_string())
+    }
+
+    /// Mock [`Folders::create`](crate::folders::Folders::create), a `POST` to `/v2/folders`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Operation> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v2/folders".to_string())
+    }
+
+    /// Mock [`Folders::search`](crate::folders::Folders::search), a `POST` to `/v2/folders:search`.
+    pub fn search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchFoldersResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v2/folders:search".to_string())
+    }
+
+    /// Mock [`Folders::get`](crate::folders::Folders::get), a `GET` to `/v2/{name}`.
+    pub fn get(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Folder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v2/{}", crate::progenitor_support::encode_path(name)),
+        )
+    }
+
+    /// Mock [`Folders::delete`](crate::folders::Folders::delete), a `DELETE` to `/v2/{name}`.
+    pub fn delete(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Folder> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/v2/{}", crate::progenitor_support::encode_path(name)),
+        )
+    }
+
+    /// Mock [`Folders::patch`](crate::folders::Folders::patch), a `PATCH` to `/v2/{name}`.
+    pub fn patch(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Folder> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/v2/{}", crate::progenitor_support::encode_path(name)),
+        )
+    }
+
+    /// Mock [`Folders::mv`](crate::folders::Folders::mv), a `POST` to `/v2/{name}:move`.
+    pub fn mv(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Operation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/v2/{}:move", crate::progenitor_support::encode_path(name)),
+        )
+    }
+
+    /// Mock [`Folders::undelete`](crate::folders::Folders::undelete), a `POST` to `/v2/{name}:undelete`.
+    pub fn undelete(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Folder> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2/{}:undelete",
+                crate::progenitor_support::encode_path(name)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::get_iam_policy`](crate::folders::Folders::get_iam_policy), a `POST` to `/v2/{resource}:getIamPolicy`.
+    pub fn get_iam_policy(
+        &self,
+        resource: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Policy> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2/{}:getIamPolicy",
+                crate::progenitor_support::encode_path(resource)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::set_iam_policy`](crate::folders::Folders::set_iam_policy), a `POST` to `/v2/{resource}:setIamPolicy`.
+    pub fn set_iam_policy(
+        &self,
+        resource: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Policy> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2/{}:setIamPolicy",
+                crate::progenitor_support::encode_path(resource)
+            ),
+        )
+    }
+
+    /// Mock [`Folders::test_iam_permissions`](crate::folders::Folders::test_iam_permissions), a `POST` to `/v2/{resource}:testIamPermissions`.
+    pub fn test_iam_permissions(
+        &self,
+        resource: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TestIamPermissionsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v2/{}:testIamPermissions",
+                crate::progenitor_support::encode_path(resource)
+            ),
+        )
+    }
+}
+
+pub struct Operations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Operations<'a> {
+    /// Mock [`Operations::get`](crate::operations::Operations::get), a `GET` to `/v1/{name}`.
+    pub fn get(&self, name: &str) -> crate::mock::MockOperation<'a, crate::types::Operation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/{}", crate::progenitor_support::encode_path(name)),
+        )
+    }
+}
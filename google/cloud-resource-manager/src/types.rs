@@ -29,7 +29,7 @@ pub struct AuditConfig {
 /**
 * The log type that this config enables.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LogType {
     #[serde(rename = "ADMIN_READ")]
     AdminRead,
@@ -41,8 +41,8 @@ pub enum LogType {
     LogTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogType {
@@ -53,12 +53,38 @@ impl std::fmt::Display for LogType {
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
             LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ADMIN_READ" => LogType::AdminRead,
+            "DATA_READ" => LogType::DataRead,
+            "DATA_WRITE" => LogType::DataWrite,
+            "LOG_TYPE_UNSPECIFIED" => LogType::LogTypeUnspecified,
+            "" => LogType::Noop,
+            _ => LogType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LogType {
     fn default() -> LogType {
         LogType::Noop
@@ -68,6 +94,11 @@ impl LogType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LogType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LogType::Unknown(_))
+    }
 }
 
 /// Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
@@ -121,7 +152,7 @@ pub struct Binding {
 /**
 * The type of this operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OperationType {
     #[serde(rename = "CREATE")]
     Create,
@@ -131,8 +162,8 @@ pub enum OperationType {
     OperationTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OperationType {
@@ -142,12 +173,37 @@ impl std::fmt::Display for OperationType {
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
             OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CREATE" => OperationType::Create,
+            "MOVE" => OperationType::Move,
+            "OPERATION_TYPE_UNSPECIFIED" => OperationType::OperationTypeUnspecified,
+            "" => OperationType::Noop,
+            _ => OperationType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OperationType {
     fn default() -> OperationType {
         OperationType::Noop
@@ -157,6 +213,11 @@ impl OperationType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OperationType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OperationType::Unknown(_))
+    }
 }
 
 /// Metadata describing a long running folder operation
@@ -306,7 +367,7 @@ pub struct Expr {
 /**
 * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LifecycleState {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -316,8 +377,8 @@ pub enum LifecycleState {
     LifecycleStateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -327,12 +388,37 @@ impl std::fmt::Display for LifecycleState {
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
             LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LifecycleState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LifecycleState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => LifecycleState::Active,
+            "DELETE_REQUESTED" => LifecycleState::DeleteRequested,
+            "LIFECYCLE_STATE_UNSPECIFIED" => LifecycleState::LifecycleStateUnspecified,
+            "" => LifecycleState::Noop,
+            _ => LifecycleState::Unknown(s),
+        })
+    }
+}
+
 impl Default for LifecycleState {
     fn default() -> LifecycleState {
         LifecycleState::Noop
@@ -342,6 +428,11 @@ impl LifecycleState {
     pub fn is_noop(&self) -> bool {
         matches!(self, LifecycleState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LifecycleState::Unknown(_))
+    }
 }
 
 /// A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
@@ -399,7 +490,7 @@ pub struct Folder {
 /**
 * The type of operation error experienced.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ErrorMessageId {
     #[serde(rename = "ACTIVE_FOLDER_HEIGHT_VIOLATION")]
     ActiveFolderHeightViolation,
@@ -423,8 +514,8 @@ pub enum ErrorMessageId {
     ResourceDeletedViolation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorMessageId {
@@ -443,12 +534,46 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
             ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ErrorMessageId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorMessageId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::ActiveFolderHeightViolation,
+            "CYCLE_INTRODUCED_VIOLATION" => ErrorMessageId::CycleIntroducedViolation,
+            "DELETED_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::DeletedFolderHeightViolation,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorMessageId::ErrorTypeUnspecified,
+            "FOLDER_BEING_MOVED_VIOLATION" => ErrorMessageId::FolderBeingMovedViolation,
+            "FOLDER_NAME_UNIQUENESS_VIOLATION" => ErrorMessageId::FolderNameUniquenessViolation,
+            "FOLDER_TO_DELETE_NON_EMPTY_VIOLATION" => {
+                ErrorMessageId::FolderToDeleteNonEmptyViolation
+            }
+            "MAX_CHILD_FOLDERS_VIOLATION" => ErrorMessageId::MaxChildFoldersViolation,
+            "PARENT_DELETED_VIOLATION" => ErrorMessageId::ParentDeletedViolation,
+            "RESOURCE_DELETED_VIOLATION" => ErrorMessageId::ResourceDeletedViolation,
+            "" => ErrorMessageId::Noop,
+            _ => ErrorMessageId::Unknown(s),
+        })
+    }
+}
+
 impl Default for ErrorMessageId {
     fn default() -> ErrorMessageId {
         ErrorMessageId::Noop
@@ -458,6 +583,11 @@ impl ErrorMessageId {
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorMessageId::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ErrorMessageId::Unknown(_))
+    }
 }
 
 /// A classification of the Folder Operation error.
@@ -789,7 +919,7 @@ pub struct TestIamPermissionsResponse {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -797,8 +927,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -807,12 +937,36 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        })
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -822,12 +976,17 @@ impl Xgafv {
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Xgafv::Unknown(_))
+    }
 }
 
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -837,8 +996,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -848,12 +1007,37 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        })
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -863,4 +1047,9 @@ impl Alt {
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Alt::Unknown(_))
+    }
 }
@@ -2,6 +2,244 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Changes::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChangesListParams {
+    drive_id: Option<String>,
+    include_corpus_removals: Option<bool>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    include_removed: Option<bool>,
+    include_team_drive_items: Option<bool>,
+    page_size: Option<i64>,
+    restrict_to_my_drive: Option<bool>,
+    spaces: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<String>,
+}
+
+impl ChangesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
+    pub fn drive_id(mut self, value: impl Into<String>) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+
+    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
+    pub fn include_corpus_removals(mut self, value: bool) -> Self {
+        self.include_corpus_removals = Some(value);
+        self
+    }
+
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub fn include_items_from_all_drives(mut self, value: bool) -> Self {
+        self.include_items_from_all_drives = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
+    pub fn include_removed(mut self, value: bool) -> Self {
+        self.include_removed = Some(value);
+        self
+    }
+
+    /// Deprecated use includeItemsFromAllDrives instead.
+    pub fn include_team_drive_items(mut self, value: bool) -> Self {
+        self.include_team_drive_items = Some(value);
+        self
+    }
+
+    /// The maximum number of changes to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
+    pub fn restrict_to_my_drive(mut self, value: bool) -> Self {
+        self.restrict_to_my_drive = Some(value);
+        self
+    }
+
+    /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
+    pub fn spaces(mut self, value: impl Into<String>) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use driveId instead.
+    pub fn team_drive_id(mut self, value: impl Into<String>) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Changes::get_start_page_token`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChangesGetStartPageTokenParams {
+    drive_id: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<String>,
+}
+
+impl ChangesGetStartPageTokenParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
+    pub fn drive_id(mut self, value: impl Into<String>) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use driveId instead.
+    pub fn team_drive_id(mut self, value: impl Into<String>) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Changes::watch`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct ChangesWatchParams {
+    drive_id: Option<String>,
+    include_corpus_removals: Option<bool>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    include_removed: Option<bool>,
+    include_team_drive_items: Option<bool>,
+    page_size: Option<i64>,
+    restrict_to_my_drive: Option<bool>,
+    spaces: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<String>,
+}
+
+impl ChangesWatchParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
+    pub fn drive_id(mut self, value: impl Into<String>) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+
+    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
+    pub fn include_corpus_removals(mut self, value: bool) -> Self {
+        self.include_corpus_removals = Some(value);
+        self
+    }
+
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub fn include_items_from_all_drives(mut self, value: bool) -> Self {
+        self.include_items_from_all_drives = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
+    pub fn include_removed(mut self, value: bool) -> Self {
+        self.include_removed = Some(value);
+        self
+    }
+
+    /// Deprecated use includeItemsFromAllDrives instead.
+    pub fn include_team_drive_items(mut self, value: bool) -> Self {
+        self.include_team_drive_items = Some(value);
+        self
+    }
+
+    /// The maximum number of changes to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
+    pub fn restrict_to_my_drive(mut self, value: bool) -> Self {
+        self.restrict_to_my_drive = Some(value);
+        self
+    }
+
+    /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
+    pub fn spaces(mut self, value: impl Into<String>) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use driveId instead.
+    pub fn team_drive_id(mut self, value: impl Into<String>) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
 pub struct Changes {
     pub client: Client,
 }
@@ -20,95 +258,52 @@ impl Changes {
      * **Parameters:**
      *
      * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
-     * * `drive_id: &str` -- The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
-     * * `include_corpus_removals: bool` -- Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_removed: bool` -- Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `restrict_to_my_drive: bool` -- Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         page_token: &str,
-        drive_id: &str,
-        include_corpus_removals: bool,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_removed: bool,
-        include_team_drive_items: bool,
-        page_size: i64,
-        restrict_to_my_drive: bool,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
+        params: &ChangesListParams,
     ) -> Result<Vec<crate::types::Change>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
-        }
-        if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
-        }
-        if include_items_from_all_drives {
-            query_args.push((
-                "includeItemsFromAllDrives".to_string(),
-                include_items_from_all_drives.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
-        }
-        if include_team_drive_items {
-            query_args.push((
-                "includeTeamDriveItems".to_string(),
-                include_team_drive_items.to_string(),
-            ));
-        }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
-        }
         if !page_token.is_empty() {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
         }
-        if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+        if let Some(v) = &params.restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
         }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
         }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes?{}", query_), None);
@@ -133,74 +328,40 @@ impl Changes {
      *
      * Lists the changes for a user or shared drive.
      */
-    pub async fn list_all(
-        &self,
-        drive_id: &str,
-        include_corpus_removals: bool,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_removed: bool,
-        include_team_drive_items: bool,
-        restrict_to_my_drive: bool,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
-    ) -> Result<Vec<crate::types::Change>> {
+    pub async fn list_all(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
-        }
-        if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
-        }
-        if include_items_from_all_drives {
-            query_args.push((
-                "includeItemsFromAllDrives".to_string(),
-                include_items_from_all_drives.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
-        }
-        if include_team_drive_items {
-            query_args.push((
-                "includeTeamDriveItems".to_string(),
-                include_team_drive_items.to_string(),
-            ));
-        }
-        if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
-        }
-        if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes?{}", query_), None);
@@ -256,6 +417,88 @@ impl Changes {
         // Return our response data.
         Ok(changes)
     }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the changes for a user or shared drive.
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &ChangesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Change> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/changes?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ChangeList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.changes,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/changes/startPageToken` endpoint.
      *
@@ -263,36 +506,24 @@ impl Changes {
      *
      * **Parameters:**
      *
-     * * `drive_id: &str` -- The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesGetStartPageTokenParams` -- The optional query parameters.
      */
     pub async fn get_start_page_token(
         &self,
-        drive_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
+        params: &ChangesGetStartPageTokenParams,
     ) -> Result<crate::types::StartPageToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
         }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
         }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -316,96 +547,53 @@ impl Changes {
      * **Parameters:**
      *
      * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
-     * * `drive_id: &str` -- The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
-     * * `include_corpus_removals: bool` -- Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_removed: bool` -- Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `restrict_to_my_drive: bool` -- Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesWatchParams` -- The optional query parameters.
      */
     pub async fn watch(
         &self,
         page_token: &str,
-        drive_id: &str,
-        include_corpus_removals: bool,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_removed: bool,
-        include_team_drive_items: bool,
-        page_size: i64,
-        restrict_to_my_drive: bool,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
+        params: &ChangesWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
-        }
-        if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
-        }
-        if include_items_from_all_drives {
-            query_args.push((
-                "includeItemsFromAllDrives".to_string(),
-                include_items_from_all_drives.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
-        }
-        if include_team_drive_items {
-            query_args.push((
-                "includeTeamDriveItems".to_string(),
-                include_team_drive_items.to_string(),
-            ));
-        }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
-        }
         if !page_token.is_empty() {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
-        }
-        if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes/watch?{}", query_), None);
@@ -2,6 +2,69 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Comments::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CommentsListParams {
+    include_deleted: Option<bool>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    start_modified_time: Option<String>,
+}
+
+impl CommentsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to include deleted comments. Deleted comments will not include their original content.
+    pub fn include_deleted(mut self, value: bool) -> Self {
+        self.include_deleted = Some(value);
+        self
+    }
+
+    /// The maximum number of comments to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// The minimum value of 'modifiedTime' for the result comments (RFC 3339 date-time).
+    pub fn start_modified_time(mut self, value: impl Into<String>) -> Self {
+        self.start_modified_time = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Comments::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct CommentsGetParams {
+    include_deleted: Option<bool>,
+}
+
+impl CommentsGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to return deleted comments. Deleted comments will not include their original content.
+    pub fn include_deleted(mut self, value: bool) -> Self {
+        self.include_deleted = Some(value);
+        self
+    }
+}
+
 pub struct Comments {
     pub client: Client,
 }
@@ -20,34 +83,25 @@ impl Comments {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to include deleted comments. Deleted comments will not include their original content.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `start_modified_time: &str` -- The minimum value of 'modifiedTime' for the result comments (RFC 3339 date-time).
+     * * `params: &CommentsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         file_id: &str,
-        include_deleted: bool,
-        page_size: i64,
-        page_token: &str,
-        start_modified_time: &str,
+        params: &CommentsListParams,
     ) -> Result<Vec<crate::types::Comment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
-        if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+        if let Some(v) = &params.start_modified_time {
+            query_args.push(("startModifiedTime".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -82,18 +136,14 @@ impl Comments {
     pub async fn list_all(
         &self,
         file_id: &str,
-        include_deleted: bool,
-        start_modified_time: &str,
+        params: &CommentsListParams,
     ) -> Result<Vec<crate::types::Comment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
-        if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+        if let Some(v) = &params.start_modified_time {
+            query_args.push(("startModifiedTime".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -156,6 +206,69 @@ impl Comments {
         // Return our response data.
         Ok(comments)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a file's comments.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Comment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.start_modified_time {
+            query_args.push(("startModifiedTime".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/comments?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::CommentList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.comments,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments` endpoint.
      *
@@ -196,17 +309,17 @@ impl Comments {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to return deleted comments. Deleted comments will not include their original content.
+     * * `params: &CommentsGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
+        params: &CommentsGetParams,
     ) -> Result<crate::types::Comment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
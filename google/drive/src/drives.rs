@@ -2,6 +2,90 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Drives::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct DrivesListParams {
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    q: Option<String>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Maximum number of shared drives to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Page token for shared drives.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Query string for searching shared drives.
+    pub fn q(mut self, value: impl Into<String>) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then all shared drives of the domain in which the requester is an administrator are returned.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Drives::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct DrivesGetParams {
+    use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Drives::update`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct DrivesUpdateParams {
+    use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesUpdateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
 pub struct Drives {
     pub client: Client,
 }
@@ -19,33 +103,21 @@ impl Drives {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- A link to this theme's background image.
-     * * `q: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then all shared drives of the domain in which the requester is an administrator are returned.
+     * * `params: &DrivesListParams` -- The optional query parameters.
      */
-    pub async fn list(
-        &self,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        use_domain_admin_access: bool,
-    ) -> Result<Vec<crate::types::Drive>> {
+    pub async fn list(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
         }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/drives?{}", query_), None);
@@ -70,20 +142,13 @@ impl Drives {
      *
      * Lists the user's shared drives.
      */
-    pub async fn list_all(
-        &self,
-        q: &str,
-        use_domain_admin_access: bool,
-    ) -> Result<Vec<crate::types::Drive>> {
+    pub async fn list_all(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
         }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/drives?{}", query_), None);
@@ -139,6 +204,61 @@ impl Drives {
         // Return our response data.
         Ok(drives)
     }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists the user's shared drives.
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &DrivesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Drive> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/drives?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::DriveList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.drives,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/drives` endpoint.
      *
@@ -177,19 +297,16 @@ impl Drives {
      * **Parameters:**
      *
      * * `drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     * * `params: &DrivesGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &DrivesGetParams,
     ) -> Result<crate::types::Drive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -245,20 +362,17 @@ impl Drives {
      * **Parameters:**
      *
      * * `drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     * * `params: &DrivesUpdateParams` -- The optional query parameters.
      */
     pub async fn update(
         &self,
         drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &DrivesUpdateParams,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
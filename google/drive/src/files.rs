@@ -2,6 +2,447 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Files::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesListParams {
+    corpora: Option<String>,
+    drive_id: Option<String>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    include_team_drive_items: Option<bool>,
+    order_by: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    q: Option<String>,
+    spaces: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<String>,
+}
+
+impl FilesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
+    pub fn corpora(mut self, value: impl Into<String>) -> Self {
+        self.corpora = Some(value.into());
+        self
+    }
+
+    /// ID of the shared drive to search.
+    pub fn drive_id(mut self, value: impl Into<String>) -> Self {
+        self.drive_id = Some(value.into());
+        self
+    }
+
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub fn include_items_from_all_drives(mut self, value: bool) -> Self {
+        self.include_items_from_all_drives = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Deprecated use includeItemsFromAllDrives instead.
+    pub fn include_team_drive_items(mut self, value: bool) -> Self {
+        self.include_team_drive_items = Some(value);
+        self
+    }
+
+    /// A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
+    pub fn order_by(mut self, value: impl Into<String>) -> Self {
+        self.order_by = Some(value.into());
+        self
+    }
+
+    /// The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// A query for filtering the file results. See the "Search for Files" guide for supported syntax.
+    pub fn q(mut self, value: impl Into<String>) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+
+    /// A comma-separated list of spaces to query within the corpus. Supported values are 'drive' and 'appDataFolder'.
+    pub fn spaces(mut self, value: impl Into<String>) -> Self {
+        self.spaces = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use driveId instead.
+    pub fn team_drive_id(mut self, value: impl Into<String>) -> Self {
+        self.team_drive_id = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::create`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesCreateParams {
+    ignore_default_visibility: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_content_as_indexable_text: Option<bool>,
+}
+
+impl FilesCreateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+    pub fn ignore_default_visibility(mut self, value: bool) -> Self {
+        self.ignore_default_visibility = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub fn keep_revision_forever(mut self, value: bool) -> Self {
+        self.keep_revision_forever = Some(value);
+        self
+    }
+
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub fn ocr_language(mut self, value: impl Into<String>) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Whether to use the uploaded content as indexable text.
+    pub fn use_content_as_indexable_text(mut self, value: bool) -> Self {
+        self.use_content_as_indexable_text = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::generate_id`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesGenerateIdsParams {
+    count: Option<i64>,
+    space: Option<String>,
+    type_: Option<String>,
+}
+
+impl FilesGenerateIdsParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The number of IDs to return.
+    pub fn count(mut self, value: i64) -> Self {
+        self.count = Some(value);
+        self
+    }
+
+    /// The space in which the IDs can be used to create new files. Supported values are 'drive' and 'appDataFolder'. (Default: 'drive')
+    pub fn space(mut self, value: impl Into<String>) -> Self {
+        self.space = Some(value.into());
+        self
+    }
+
+    /// The type of items which the IDs can be used for. Supported values are 'files' and 'shortcuts'. Note that 'shortcuts' are only supported in the drive 'space'. (Default: 'files')
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesGetParams {
+    acknowledge_abuse: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl FilesGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub fn acknowledge_abuse(mut self, value: bool) -> Self {
+        self.acknowledge_abuse = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::delete`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesDeleteParams {
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl FilesDeleteParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::update`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesUpdateParams {
+    add_parents: Option<String>,
+    include_permissions_for_view: Option<String>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<String>,
+    remove_parents: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_content_as_indexable_text: Option<bool>,
+}
+
+impl FilesUpdateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A comma-separated list of parent IDs to add.
+    pub fn add_parents(mut self, value: impl Into<String>) -> Self {
+        self.add_parents = Some(value.into());
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub fn keep_revision_forever(mut self, value: bool) -> Self {
+        self.keep_revision_forever = Some(value);
+        self
+    }
+
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub fn ocr_language(mut self, value: impl Into<String>) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+
+    /// A comma-separated list of parent IDs to remove.
+    pub fn remove_parents(mut self, value: impl Into<String>) -> Self {
+        self.remove_parents = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Whether to use the uploaded content as indexable text.
+    pub fn use_content_as_indexable_text(mut self, value: bool) -> Self {
+        self.use_content_as_indexable_text = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::copy`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesCopyParams {
+    ignore_default_visibility: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl FilesCopyParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+    pub fn ignore_default_visibility(mut self, value: bool) -> Self {
+        self.ignore_default_visibility = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub fn keep_revision_forever(mut self, value: bool) -> Self {
+        self.keep_revision_forever = Some(value);
+        self
+    }
+
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub fn ocr_language(mut self, value: impl Into<String>) -> Self {
+        self.ocr_language = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Files::watch`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct FilesWatchParams {
+    acknowledge_abuse: Option<bool>,
+    include_permissions_for_view: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl FilesWatchParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub fn acknowledge_abuse(mut self, value: bool) -> Self {
+        self.acknowledge_abuse = Some(value);
+        self
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+}
+
 pub struct Files {
     pub client: Client,
 }
@@ -19,91 +460,48 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `corpora: &str` -- Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
-     * * `corpus: crate::types::Corpus` -- The source of files to list. Deprecated: use 'corpora' instead.
-     * * `drive_id: &str` -- A link to this theme's background image.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `order_by: &str` -- A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
-     * * `page_size: i64` -- The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `q: &str` -- A query for filtering the file results. See the "Search for Files" guide for supported syntax.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the corpus. Supported values are 'drive' and 'appDataFolder'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &FilesListParams` -- The optional query parameters.
      */
-    pub async fn list(
-        &self,
-        corpora: &str,
-        drive_id: &str,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_team_drive_items: bool,
-        order_by: &str,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
-    ) -> Result<Vec<crate::types::File>> {
+    pub async fn list(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
+        if let Some(v) = &params.corpora {
+            query_args.push(("corpora".to_string(), v.to_string()));
         }
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
         }
-        if include_items_from_all_drives {
-            query_args.push((
-                "includeItemsFromAllDrives".to_string(),
-                include_items_from_all_drives.to_string(),
-            ));
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
         }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
         }
-        if include_team_drive_items {
-            query_args.push((
-                "includeTeamDriveItems".to_string(),
-                include_team_drive_items.to_string(),
-            ));
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
         }
-        if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        if let Some(v) = &params.order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
         }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
         }
-        if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
         }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
         }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
@@ -128,68 +526,40 @@ impl Files {
      *
      * Lists or searches files.
      */
-    pub async fn list_all(
-        &self,
-        corpora: &str,
-        drive_id: &str,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_team_drive_items: bool,
-        order_by: &str,
-        q: &str,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
-    ) -> Result<Vec<crate::types::File>> {
+    pub async fn list_all(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
-        }
-        if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
-        }
-        if include_items_from_all_drives {
-            query_args.push((
-                "includeItemsFromAllDrives".to_string(),
-                include_items_from_all_drives.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if include_team_drive_items {
-            query_args.push((
-                "includeTeamDriveItems".to_string(),
-                include_team_drive_items.to_string(),
-            ));
-        }
-        if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
-        }
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
-        }
-        if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        if let Some(v) = &params.corpora {
+            query_args.push(("corpora".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
@@ -245,6 +615,88 @@ impl Files {
         // Return our response data.
         Ok(files)
     }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists or searches files.
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &FilesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::File> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.corpora {
+            query_args.push(("corpora".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::FileList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.files,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/files` endpoint.
      *
@@ -252,65 +704,34 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `enforce_single_parent: bool` -- Deprecated. Creating files in multiple folders is no longer supported.
-     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     * * `params: &FilesCreateParams` -- The optional query parameters.
      */
     pub async fn create(
         &self,
-        ignore_default_visibility: bool,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_content_as_indexable_text: bool,
+        params: &FilesCreateParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ignore_default_visibility {
-            query_args.push((
-                "ignoreDefaultVisibility".to_string(),
-                ignore_default_visibility.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
-        }
-        if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_content_as_indexable_text {
-            query_args.push((
-                "useContentAsIndexableText".to_string(),
-                use_content_as_indexable_text.to_string(),
-            ));
+        if let Some(v) = &params.ignore_default_visibility {
+            query_args.push(("ignoreDefaultVisibility".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_content_as_indexable_text {
+            query_args.push(("useContentAsIndexableText".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
@@ -331,25 +752,21 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `count: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `space: &str` -- The space in which the IDs can be used to create new files. Supported values are 'drive' and 'appDataFolder'. (Default: 'drive').
-     * * `type_: &str` -- The type of items which the IDs can be used for. Supported values are 'files' and 'shortcuts'. Note that 'shortcuts' are only supported in the drive 'space'. (Default: 'files').
+     * * `params: &FilesGenerateIdsParams` -- The optional query parameters.
      */
     pub async fn generate_id(
         &self,
-        count: i64,
-        space: &str,
-        type_: &str,
+        params: &FilesGenerateIdsParams,
     ) -> Result<crate::types::GeneratedIds> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+        if let Some(v) = &params.count {
+            query_args.push(("count".to_string(), v.to_string()));
         }
-        if !space.is_empty() {
-            query_args.push(("space".to_string(), space.to_string()));
+        if let Some(v) = &params.space {
+            query_args.push(("space".to_string(), v.to_string()));
         }
-        if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = &params.type_ {
+            query_args.push(("type".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
@@ -394,43 +811,21 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesGetParams` -- The optional query parameters.
      */
-    pub async fn get(
-        &self,
-        file_id: &str,
-        acknowledge_abuse: bool,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-    ) -> Result<crate::types::File> {
+    pub async fn get(&self, file_id: &str, params: &FilesGetParams) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -459,28 +854,15 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `enforce_single_parent: bool` -- Deprecated. If an item is not in a shared drive and its last parent is deleted but the item itself is not, the item will be placed under its owner's root.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesDeleteParams` -- The optional query parameters.
      */
-    pub async fn delete(
-        &self,
-        file_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-    ) -> Result<()> {
+    pub async fn delete(&self, file_id: &str, params: &FilesDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -509,68 +891,38 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `add_parents: &str` -- A comma-separated list of parent IDs to add.
-     * * `enforce_single_parent: bool` -- Deprecated. Adding files to multiple folders is no longer supported. Use shortcuts instead.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `remove_parents: &str` -- A comma-separated list of parent IDs to remove.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     * * `params: &FilesUpdateParams` -- The optional query parameters.
      */
     pub async fn update(
         &self,
         file_id: &str,
-        add_parents: &str,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        remove_parents: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_content_as_indexable_text: bool,
+        params: &FilesUpdateParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !add_parents.is_empty() {
-            query_args.push(("addParents".to_string(), add_parents.to_string()));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
-        }
-        if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
-        }
-        if !remove_parents.is_empty() {
-            query_args.push(("removeParents".to_string(), remove_parents.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_content_as_indexable_text {
-            query_args.push((
-                "useContentAsIndexableText".to_string(),
-                use_content_as_indexable_text.to_string(),
-            ));
+        if let Some(v) = &params.add_parents {
+            query_args.push(("addParents".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.remove_parents {
+            query_args.push(("removeParents".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_content_as_indexable_text {
+            query_args.push(("useContentAsIndexableText".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -599,58 +951,32 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `enforce_single_parent: bool` -- Deprecated. Copying files into multiple folders is no longer supported. Use shortcuts instead.
-     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesCopyParams` -- The optional query parameters.
      */
     pub async fn copy(
         &self,
         file_id: &str,
-        ignore_default_visibility: bool,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
+        params: &FilesCopyParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ignore_default_visibility {
-            query_args.push((
-                "ignoreDefaultVisibility".to_string(),
-                ignore_default_visibility.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
-        }
-        if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.ignore_default_visibility {
+            query_args.push(("ignoreDefaultVisibility".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -681,7 +1007,7 @@ impl Files {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `mime_type: &str` -- The MIME type of the format requested for this export.
      */
-    pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<()> {
+    pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !mime_type.is_empty() {
             query_args.push(("mimeType".to_string(), mime_type.to_string()));
@@ -696,13 +1022,7 @@ impl Files {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
     /**
@@ -713,44 +1033,26 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesWatchParams` -- The optional query parameters.
      */
     pub async fn watch(
         &self,
         file_id: &str,
-        acknowledge_abuse: bool,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
+        params: &FilesWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
-        }
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+        if let Some(v) = &params.acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
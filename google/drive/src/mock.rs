@@ -0,0 +1,759 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`About`](crate::about::About).
+    pub fn about(&self) -> About<'_> {
+        About { mock: self }
+    }
+
+    /// Mock the functions of [`Changes`](crate::changes::Changes).
+    pub fn changes(&self) -> Changes<'_> {
+        Changes { mock: self }
+    }
+
+    /// Mock the functions of [`Channels`](crate::channels::Channels).
+    pub fn channels(&self) -> Channels<'_> {
+        Channels { mock: self }
+    }
+
+    /// Mock the functions of [`Comments`](crate::comments::Comments).
+    pub fn comments(&self) -> Comments<'_> {
+        Comments { mock: self }
+    }
+
+    /// Mock the functions of [`Drives`](crate::drives::Drives).
+    pub fn drives(&self) -> Drives<'_> {
+        Drives { mock: self }
+    }
+
+    /// Mock the functions of [`Files`](crate::files::Files).
+    pub fn files(&self) -> Files<'_> {
+        Files { mock: self }
+    }
+
+    /// Mock the functions of [`Permissions`](crate::permissions::Permissions).
+    pub fn permissions(&self) -> Permissions<'_> {
+        Permissions { mock: self }
+    }
+
+    /// Mock the functions of [`Replies`](crate::replies::Replies).
+    pub fn replies(&self) -> Replies<'_> {
+        Replies { mock: self }
+    }
+
+    /// Mock the functions of [`Revisions`](crate::revisions::Revisions).
+    pub fn revisions(&self) -> Revisions<'_> {
+        Revisions { mock: self }
+    }
+
+    /// Mock the functions of [`Teamdrives`](crate::teamdrives::Teamdrives).
+    pub fn teamdrives(&self) -> Teamdrives<'_> {
+        Teamdrives { mock: self }
+    }
+}
+
+pub struct About<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> About<'a> {
+    /// Mock [`About::get`](crate::about::About::get), a `GET` to `/about`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::About> {
+        self.mock
+            .operation(reqwest::Method::GET, "/about".to_string())
+    }
+}
+
+pub struct Changes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Changes<'a> {
+    /// Mock [`Changes::list`](crate::changes::Changes::list), a `GET` to `/changes`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::ChangeList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/changes".to_string())
+    }
+
+    /// Mock [`Changes::get_start_page_token`](crate::changes::Changes::get_start_page_token), a `GET` to `/changes/startPageToken`.
+    pub fn get_start_page_token(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::StartPageToken> {
+        self.mock
+            .operation(reqwest::Method::GET, "/changes/startPageToken".to_string())
+    }
+
+    /// Mock [`Changes::watch`](crate::changes::Changes::watch), a `POST` to `/changes/watch`.
+    pub fn watch(&self) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock
+            .operation(reqwest::Method::POST, "/changes/watch".to_string())
+    }
+}
+
+pub struct Channels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Channels<'a> {
+    /// Mock [`Channels::stop`](crate::channels::Channels::stop), a `POST` to `/channels/stop`.
+    pub fn stop(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/channels/stop".to_string())
+    }
+}
+
+pub struct Comments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Comments<'a> {
+    /// Mock [`Comments::list`](crate::comments::Comments::list), a `GET` to `/files/{fileId}/comments`.
+    pub fn list(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::CommentList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/comments",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Comments::create`](crate::comments::Comments::create), a `POST` to `/files/{fileId}/comments`.
+    pub fn create(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::Comment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/files/{}/comments",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Comments::get`](crate::comments::Comments::get), a `GET` to `/files/{fileId}/comments/{commentId}`.
+    pub fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Comment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/comments/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Comments::delete`](crate::comments::Comments::delete), a `DELETE` to `/files/{fileId}/comments/{commentId}`.
+    pub fn delete(&self, file_id: &str, comment_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/files/{}/comments/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Comments::update`](crate::comments::Comments::update), a `PATCH` to `/files/{fileId}/comments/{commentId}`.
+    pub fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Comment> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/files/{}/comments/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+}
+
+pub struct Drives<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Drives<'a> {
+    /// Mock [`Drives::list`](crate::drives::Drives::list), a `GET` to `/drives`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DriveList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/drives".to_string())
+    }
+
+    /// Mock [`Drives::create`](crate::drives::Drives::create), a `POST` to `/drives`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Drive> {
+        self.mock
+            .operation(reqwest::Method::POST, "/drives".to_string())
+    }
+
+    /// Mock [`Drives::get`](crate::drives::Drives::get), a `GET` to `/drives/{driveId}`.
+    pub fn get(&self, drive_id: &str) -> crate::mock::MockOperation<'a, crate::types::Drive> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/drives/{}",
+                crate::progenitor_support::encode_path(drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Drives::delete`](crate::drives::Drives::delete), a `DELETE` to `/drives/{driveId}`.
+    pub fn delete(&self, drive_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/drives/{}",
+                crate::progenitor_support::encode_path(drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Drives::update`](crate::drives::Drives::update), a `PATCH` to `/drives/{driveId}`.
+    pub fn update(&self, drive_id: &str) -> crate::mock::MockOperation<'a, crate::types::Drive> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/drives/{}",
+                crate::progenitor_support::encode_path(drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Drives::hide`](crate::drives::Drives::hide), a `POST` to `/drives/{driveId}/hide`.
+    pub fn hide(&self, drive_id: &str) -> crate::mock::MockOperation<'a, crate::types::Drive> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/drives/{}/hide",
+                crate::progenitor_support::encode_path(drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Drives::unhide`](crate::drives::Drives::unhide), a `POST` to `/drives/{driveId}/unhide`.
+    pub fn unhide(&self, drive_id: &str) -> crate::mock::MockOperation<'a, crate::types::Drive> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/drives/{}/unhide",
+                crate::progenitor_support::encode_path(drive_id)
+            ),
+        )
+    }
+}
+
+pub struct Files<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Files<'a> {
+    /// Mock [`Files::list`](crate::files::Files::list), a `GET` to `/files`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::FileList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files".to_string())
+    }
+
+    /// Mock [`Files::create`](crate::files::Files::create), a `POST` to `/files`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files".to_string())
+    }
+
+    /// Mock [`Files::generate_id`](crate::files::Files::generate_id), a `GET` to `/files/generateIds`.
+    pub fn generate_id(&self) -> crate::mock::MockOperation<'a, crate::types::GeneratedIds> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files/generateIds".to_string())
+    }
+
+    /// Mock [`Files::empty_trash`](crate::files::Files::empty_trash), a `DELETE` to `/files/trash`.
+    pub fn empty_trash(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/files/trash".to_string())
+    }
+
+    /// Mock [`Files::get`](crate::files::Files::get), a `GET` to `/files/{fileId}`.
+    pub fn get(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/files/{}", crate::progenitor_support::encode_path(file_id)),
+        )
+    }
+
+    /// Mock [`Files::delete`](crate::files::Files::delete), a `DELETE` to `/files/{fileId}`.
+    pub fn delete(&self, file_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/files/{}", crate::progenitor_support::encode_path(file_id)),
+        )
+    }
+
+    /// Mock [`Files::update`](crate::files::Files::update), a `PATCH` to `/files/{fileId}`.
+    pub fn update(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/files/{}", crate::progenitor_support::encode_path(file_id)),
+        )
+    }
+
+    /// Mock [`Files::copy`](crate::files::Files::copy), a `POST` to `/files/{fileId}/copy`.
+    pub fn copy(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/files/{}/copy",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Files::export`](crate::files::Files::export), a `GET` to `/files/{fileId}/export`.
+    pub fn export(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/export",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Files::watch`](crate::files::Files::watch), a `POST` to `/files/{fileId}/watch`.
+    pub fn watch(&self, file_id: &str) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/files/{}/watch",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+}
+
+pub struct Permissions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Permissions<'a> {
+    /// Mock [`Permissions::list`](crate::permissions::Permissions::list), a `GET` to `/files/{fileId}/permissions`.
+    pub fn list(
+        &self,
+        file_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PermissionList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/permissions",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Permissions::create`](crate::permissions::Permissions::create), a `POST` to `/files/{fileId}/permissions`.
+    pub fn create(
+        &self,
+        file_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Permission> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/files/{}/permissions",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Permissions::get`](crate::permissions::Permissions::get), a `GET` to `/files/{fileId}/permissions/{permissionId}`.
+    pub fn get(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Permission> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id)
+            ),
+        )
+    }
+
+    /// Mock [`Permissions::delete`](crate::permissions::Permissions::delete), a `DELETE` to `/files/{fileId}/permissions/{permissionId}`.
+    pub fn delete(&self, file_id: &str, permission_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id)
+            ),
+        )
+    }
+
+    /// Mock [`Permissions::update`](crate::permissions::Permissions::update), a `PATCH` to `/files/{fileId}/permissions/{permissionId}`.
+    pub fn update(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Permission> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/files/{}/permissions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id)
+            ),
+        )
+    }
+}
+
+pub struct Replies<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Replies<'a> {
+    /// Mock [`Replies::list`](crate::replies::Replies::list), a `GET` to `/files/{fileId}/comments/{commentId}/replies`.
+    pub fn list(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReplyList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/comments/{}/replies",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Replies::create`](crate::replies::Replies::create), a `POST` to `/files/{fileId}/comments/{commentId}/replies`.
+    pub fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reply> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/files/{}/comments/{}/replies",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Replies::get`](crate::replies::Replies::get), a `GET` to `/files/{fileId}/comments/{commentId}/replies/{replyId}`.
+    pub fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reply> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/comments/{}/replies/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                crate::progenitor_support::encode_path(reply_id)
+            ),
+        )
+    }
+
+    /// Mock [`Replies::delete`](crate::replies::Replies::delete), a `DELETE` to `/files/{fileId}/comments/{commentId}/replies/{replyId}`.
+    pub fn delete(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/files/{}/comments/{}/replies/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                crate::progenitor_support::encode_path(reply_id)
+            ),
+        )
+    }
+
+    /// Mock [`Replies::update`](crate::replies::Replies::update), a `PATCH` to `/files/{fileId}/comments/{commentId}/replies/{replyId}`.
+    pub fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reply> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/files/{}/comments/{}/replies/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                crate::progenitor_support::encode_path(reply_id)
+            ),
+        )
+    }
+}
+
+pub struct Revisions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Revisions<'a> {
+    /// Mock [`Revisions::list`](crate::revisions::Revisions::list), a `GET` to `/files/{fileId}/revisions`.
+    pub fn list(
+        &self,
+        file_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RevisionList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/revisions",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`Revisions::get`](crate::revisions::Revisions::get), a `GET` to `/files/{fileId}/revisions/{revisionId}`.
+    pub fn get(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Revision> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/files/{}/revisions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id)
+            ),
+        )
+    }
+
+    /// Mock [`Revisions::delete`](crate::revisions::Revisions::delete), a `DELETE` to `/files/{fileId}/revisions/{revisionId}`.
+    pub fn delete(&self, file_id: &str, revision_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/files/{}/revisions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id)
+            ),
+        )
+    }
+
+    /// Mock [`Revisions::update`](crate::revisions::Revisions::update), a `PATCH` to `/files/{fileId}/revisions/{revisionId}`.
+    pub fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Revision> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/files/{}/revisions/{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id)
+            ),
+        )
+    }
+}
+
+pub struct Teamdrives<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Teamdrives<'a> {
+    /// Mock [`Teamdrives::list`](crate::teamdrives::Teamdrives::list), a `GET` to `/teamdrives`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::TeamDriveList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/teamdrives".to_string())
+    }
+
+    /// Mock [`Teamdrives::create`](crate::teamdrives::Teamdrives::create), a `POST` to `/teamdrives`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::TeamDrive> {
+        self.mock
+            .operation(reqwest::Method::POST, "/teamdrives".to_string())
+    }
+
+    /// Mock [`Teamdrives::get`](crate::teamdrives::Teamdrives::get), a `GET` to `/teamdrives/{teamDriveId}`.
+    pub fn get(
+        &self,
+        team_drive_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDrive> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teamdrives/{}",
+                crate::progenitor_support::encode_path(team_drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Teamdrives::delete`](crate::teamdrives::Teamdrives::delete), a `DELETE` to `/teamdrives/{teamDriveId}`.
+    pub fn delete(&self, team_drive_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teamdrives/{}",
+                crate::progenitor_support::encode_path(team_drive_id)
+            ),
+        )
+    }
+
+    /// Mock [`Teamdrives::update`](crate::teamdrives::Teamdrives::update), a `PATCH` to `/teamdrives/{teamDriveId}`.
+    pub fn update(
+        &self,
+        team_drive_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamDrive> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teamdrives/{}",
+                crate::progenitor_support::encode_path(team_drive_id)
+            ),
+        )
+    }
+}
@@ -2,6 +2,244 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Permissions::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PermissionsListParams {
+    include_permissions_for_view: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub fn include_permissions_for_view(mut self, value: impl Into<String>) -> Self {
+        self.include_permissions_for_view = Some(value.into());
+        self
+    }
+
+    /// The maximum number of permissions to return per page. When not set for files in a shared drive, at most 100 results will be returned. When not set for files that are not in a shared drive, the entire list will be returned.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Permissions::create`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PermissionsCreateParams {
+    email_message: Option<String>,
+    move_to_new_owners_root: Option<bool>,
+    send_notification_email: Option<bool>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    transfer_ownership: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsCreateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A plain text custom message to include in the notification email.
+    pub fn email_message(mut self, value: impl Into<String>) -> Self {
+        self.email_message = Some(value.into());
+        self
+    }
+
+    /// This parameter will only take effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. If set to true, the item will be moved to the new owner's My Drive root folder and all prior parents removed. If set to false, parents are not changed.
+    pub fn move_to_new_owners_root(mut self, value: bool) -> Self {
+        self.move_to_new_owners_root = Some(value);
+        self
+    }
+
+    /// Whether to send a notification email when sharing to users or groups. This defaults to true for users and groups, and is not allowed for other requests. It must not be disabled for ownership transfers.
+    pub fn send_notification_email(mut self, value: bool) -> Self {
+        self.send_notification_email = Some(value);
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+    pub fn transfer_ownership(mut self, value: bool) -> Self {
+        self.transfer_ownership = Some(value);
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Permissions::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PermissionsGetParams {
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Permissions::delete`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PermissionsDeleteParams {
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsDeleteParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Permissions::update`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct PermissionsUpdateParams {
+    remove_expiration: Option<bool>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    transfer_ownership: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsUpdateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to remove the expiration date.
+    pub fn remove_expiration(mut self, value: bool) -> Self {
+        self.remove_expiration = Some(value);
+        self
+    }
+
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub fn supports_all_drives(mut self, value: bool) -> Self {
+        self.supports_all_drives = Some(value);
+        self
+    }
+
+    /// Deprecated use supportsAllDrives instead.
+    pub fn supports_team_drives(mut self, value: bool) -> Self {
+        self.supports_team_drives = Some(value);
+        self
+    }
+
+    /// Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+    pub fn transfer_ownership(mut self, value: bool) -> Self {
+        self.transfer_ownership = Some(value);
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
 pub struct Permissions {
     pub client: Client,
 }
@@ -20,53 +258,31 @@ impl Permissions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `page_size: i64` -- The maximum number of permissions to return per page. When not set for files in a shared drive, at most 100 results will be returned. When not set for files that are not in a shared drive, the entire list will be returned.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         file_id: &str,
-        include_permissions_for_view: &str,
-        page_size: i64,
-        page_token: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsListParams,
     ) -> Result<Vec<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
-        }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -101,35 +317,20 @@ impl Permissions {
     pub async fn list_all(
         &self,
         file_id: &str,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsListParams,
     ) -> Result<Vec<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_permissions_for_view.is_empty() {
-            query_args.push((
-                "includePermissionsForView".to_string(),
-                include_permissions_for_view.to_string(),
-            ));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -192,6 +393,75 @@ impl Permissions {
         // Return our response data.
         Ok(permissions)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a file's or shared drive's permissions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Permission> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/permissions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PermissionList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.permissions,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
      *
@@ -200,64 +470,35 @@ impl Permissions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `email_message: &str` -- A plain text custom message to include in the notification email.
-     * * `enforce_single_parent: bool` -- Whether the user has installed the requesting app.
-     * * `move_to_new_owners_root: bool` -- This parameter will only take effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. If set to true, the item will be moved to the new owner's My Drive root folder and all prior parents removed. If set to false, parents are not changed.
-     * * `send_notification_email: bool` -- Whether to send a notification email when sharing to users or groups. This defaults to true for users and groups, and is not allowed for other requests. It must not be disabled for ownership transfers.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsCreateParams` -- The optional query parameters.
      */
     pub async fn create(
         &self,
         file_id: &str,
-        email_message: &str,
-        move_to_new_owners_root: bool,
-        send_notification_email: bool,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        transfer_ownership: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsCreateParams,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email_message.is_empty() {
-            query_args.push(("emailMessage".to_string(), email_message.to_string()));
-        }
-        if move_to_new_owners_root {
-            query_args.push((
-                "moveToNewOwnersRoot".to_string(),
-                move_to_new_owners_root.to_string(),
-            ));
-        }
-        query_args.push((
-            "sendNotificationEmail".to_string(),
-            send_notification_email.to_string(),
-        ));
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.email_message {
+            query_args.push(("emailMessage".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.move_to_new_owners_root {
+            query_args.push(("moveToNewOwnersRoot".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.send_notification_email {
+            query_args.push(("sendNotificationEmail".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.transfer_ownership {
+            query_args.push(("transferOwnership".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -287,36 +528,23 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         file_id: &str,
         permission_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsGetParams,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -347,36 +575,23 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsDeleteParams` -- The optional query parameters.
      */
     pub async fn delete(
         &self,
         file_id: &str,
         permission_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -407,53 +622,30 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `remove_expiration: bool` -- Whether the user has installed the requesting app.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsUpdateParams` -- The optional query parameters.
      */
     pub async fn update(
         &self,
         file_id: &str,
         permission_id: &str,
-        remove_expiration: bool,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        transfer_ownership: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsUpdateParams,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if remove_expiration {
-            query_args.push((
-                "removeExpiration".to_string(),
-                remove_expiration.to_string(),
-            ));
-        }
-        if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
-        }
-        if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
-        }
-        if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
-        }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.remove_expiration {
+            query_args.push(("removeExpiration".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.transfer_ownership {
+            query_args.push(("transferOwnership".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
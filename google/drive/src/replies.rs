@@ -2,6 +2,62 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Replies::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct RepliesListParams {
+    include_deleted: Option<bool>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl RepliesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to include deleted replies. Deleted replies will not include their original content.
+    pub fn include_deleted(mut self, value: bool) -> Self {
+        self.include_deleted = Some(value);
+        self
+    }
+
+    /// The maximum number of replies to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Replies::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct RepliesGetParams {
+    include_deleted: Option<bool>,
+}
+
+impl RepliesGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether to return deleted replies. Deleted replies will not include their original content.
+    pub fn include_deleted(mut self, value: bool) -> Self {
+        self.include_deleted = Some(value);
+        self
+    }
+}
+
 pub struct Replies {
     pub client: Client,
 }
@@ -21,27 +77,23 @@ impl Replies {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to include deleted replies. Deleted replies will not include their original content.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+     * * `params: &RepliesListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
-        page_size: i64,
-        page_token: &str,
+        params: &RepliesListParams,
     ) -> Result<Vec<crate::types::Reply>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -78,11 +130,11 @@ impl Replies {
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
+        params: &RepliesListParams,
     ) -> Result<Vec<crate::types::Reply>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -146,6 +198,68 @@ impl Replies {
         // Return our response data.
         Ok(replies)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a comment's replies.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Reply> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/comments/{}/replies?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ReplyList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.replies,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
@@ -190,18 +304,18 @@ impl Replies {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
      * * `reply_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to return deleted replies. Deleted replies will not include their original content.
+     * * `params: &RepliesGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         file_id: &str,
         comment_id: &str,
         reply_id: &str,
-        include_deleted: bool,
+        params: &RepliesGetParams,
     ) -> Result<crate::types::Reply> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        if let Some(v) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2,6 +2,55 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Revisions::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct RevisionsListParams {
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl RevisionsListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The maximum number of revisions to return per page.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Revisions::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct RevisionsGetParams {
+    acknowledge_abuse: Option<bool>,
+}
+
+impl RevisionsGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub fn acknowledge_abuse(mut self, value: bool) -> Self {
+        self.acknowledge_abuse = Some(value);
+        self
+    }
+}
+
 pub struct Revisions {
     pub client: Client,
 }
@@ -20,21 +69,19 @@ impl Revisions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+     * * `params: &RevisionsListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
         file_id: &str,
-        page_size: i64,
-        page_token: &str,
+        params: &RevisionsListParams,
     ) -> Result<Vec<crate::types::Revision>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -126,6 +173,59 @@ impl Revisions {
         // Return our response data.
         Ok(revisions)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists a file's revisions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Revision> {
+        let url = self.client.url(
+            &format!(
+                "/files/{}/revisions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::RevisionList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.revisions,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -135,20 +235,17 @@ impl Revisions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `revision_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+     * * `params: &RevisionsGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         file_id: &str,
         revision_id: &str,
-        acknowledge_abuse: bool,
+        params: &RevisionsGetParams,
     ) -> Result<crate::types::Revision> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+        if let Some(v) = &params.acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -2,6 +2,90 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The optional query parameters sent by [`Teamdrives::list`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamdrivesListParams {
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    q: Option<String>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesListParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Maximum number of Team Drives to return.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Page token for Team Drives.
+    pub fn page_token(mut self, value: impl Into<String>) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Query string for searching Team Drives.
+    pub fn q(mut self, value: impl Into<String>) -> Self {
+        self.q = Some(value.into());
+        self
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then all Team Drives of the domain in which the requester is an administrator are returned.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teamdrives::get`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamdrivesGetParams {
+    use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesGetParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
+/// The optional query parameters sent by [`Teamdrives::update`].
+///
+/// Only the parameters that are set are sent, so `false`, `0` and empty values
+/// can be passed explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct TeamdrivesUpdateParams {
+    use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesUpdateParams {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+    pub fn use_domain_admin_access(mut self, value: bool) -> Self {
+        self.use_domain_admin_access = Some(value);
+        self
+    }
+}
+
 pub struct Teamdrives {
     pub client: Client,
 }
@@ -19,33 +103,24 @@ impl Teamdrives {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- A link to this theme's background image.
-     * * `q: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then all Team Drives of the domain in which the requester is an administrator are returned.
+     * * `params: &TeamdrivesListParams` -- The optional query parameters.
      */
     pub async fn list(
         &self,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesListParams,
     ) -> Result<Vec<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+        if let Some(v) = &params.page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
         }
-        if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        if let Some(v) = &params.page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
         }
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
         }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
@@ -72,18 +147,14 @@ impl Teamdrives {
      */
     pub async fn list_all(
         &self,
-        q: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesListParams,
     ) -> Result<Vec<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
         }
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
@@ -139,6 +210,61 @@ impl Teamdrives {
         // Return our response data.
         Ok(team_drives)
     }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Deprecated use drives.list instead.
+     */
+    pub fn list_all_stream(
+        &self,
+        params: &TeamdrivesListParams,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TeamDrive> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &params.q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/teamdrives?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}pageToken={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TeamDriveList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.team_drives,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/teamdrives` endpoint.
      *
@@ -177,19 +303,16 @@ impl Teamdrives {
      * **Parameters:**
      *
      * * `team_drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     * * `params: &TeamdrivesGetParams` -- The optional query parameters.
      */
     pub async fn get(
         &self,
         team_drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesGetParams,
     ) -> Result<crate::types::TeamDrive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -245,20 +368,17 @@ impl Teamdrives {
      * **Parameters:**
      *
      * * `team_drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     * * `params: &TeamdrivesUpdateParams` -- The optional query parameters.
      */
     pub async fn update(
         &self,
         team_drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesUpdateParams,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+        if let Some(v) = &params.use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -64,10 +64,10 @@ impl PermissionOps for crate::permissions::Permissions {
         let perms = self
             .list_all(
                 file_id,
-                "",   // include_permissions_for_view
-                true, // supports_all_drives
-                true, // supports_team_drives
-                use_domain_admin_access,
+                &crate::permissions::PermissionsListParams::new()
+                    .supports_all_drives(true)
+                    .supports_team_drives(true)
+                    .use_domain_admin_access(use_domain_admin_access),
             )
             .await?;
 
@@ -100,13 +100,12 @@ impl PermissionOps for crate::permissions::Permissions {
         // Create the permission and return it.
         self.create(
             file_id,
-            email_message,
-            false, // move_to_new_owners_root
-            send_notification_email,
-            true,  // supports_all_drives
-            true,  // supports_team_drives
-            false, // transfer_ownership
-            use_domain_admin_access,
+            &crate::permissions::PermissionsCreateParams::new()
+                .email_message(email_message)
+                .send_notification_email(send_notification_email)
+                .supports_all_drives(true)
+                .supports_team_drives(true)
+                .use_domain_admin_access(use_domain_admin_access),
             &perm,
         )
         .await
@@ -163,17 +162,12 @@ impl FileOps for crate::files::Files {
         }
 
         self.list_all(
-            "drive",  // corpora
-            drive_id, // drive id
-            true,     // include_items_from_all_drives
-            "",       // include_permissions_for_view
-            false,    // include_team_drive_items
-            "",       // order_by
-            &query,   // query
-            "",       // spaces
-            true,     // supports_all_drives
-            false,    // supports_team_drives
-            "",       // team_drive_id
+            &crate::files::FilesListParams::new()
+                .corpora("drive")
+                .drive_id(drive_id)
+                .include_items_from_all_drives(true)
+                .q(query)
+                .supports_all_drives(true),
         )
         .await
     }
@@ -249,6 +243,7 @@ impl FileOps for crate::files::Files {
                     body: Some(reqwest::Body::from(serde_json::to_vec(&f)?)),
                     content_type: None,
                 },
+                reqwest::header::HeaderMap::new(),
             )
             .await?;
 
@@ -272,6 +267,7 @@ impl FileOps for crate::files::Files {
                 reqwest::Method::GET,
                 &format!("/files/{}?supportsAllDrives=true&alt=media", id),
                 crate::Message::default(),
+                reqwest::header::HeaderMap::new(),
             )
             .await?;
 
@@ -302,17 +298,12 @@ impl FileOps for crate::files::Files {
         // Check if the folder exists.
         let folders = self
             .list_all(
-                "drive",  // corpora
-                drive_id, // drive id
-                true,     // include_items_from_all_drives
-                "",       // include_permissions_for_view
-                false,    // include_team_drive_items
-                "",       // order_by
-                &query,   // query
-                "",       // spaces
-                true,     // supports_all_drives
-                false,    // supports_team_drives
-                "",       // team_drive_id
+                &crate::files::FilesListParams::new()
+                    .corpora("drive")
+                    .drive_id(drive_id)
+                    .include_items_from_all_drives(true)
+                    .q(query)
+                    .supports_all_drives(true),
             )
             .await
             .unwrap_or_default();
@@ -354,7 +345,12 @@ impl FileOps for crate::files::Files {
         );
         let resp = self
             .client
-            .request_raw(reqwest::Method::GET, &url, crate::Message::default())
+            .request_raw(
+                reqwest::Method::GET,
+                &url,
+                crate::Message::default(),
+                reqwest::header::HeaderMap::new(),
+            )
             .await?;
 
         Ok(resp.text().await?)
@@ -375,8 +371,9 @@ impl FileOps for crate::files::Files {
         // Delete the file.
         self.delete(
             &files.get(0).unwrap().id,
-            true, // supports all drives
-            true, // supports team drives
+            &crate::files::FilesDeleteParams::new()
+                .supports_all_drives(true)
+                .supports_team_drives(true),
         )
         .await
     }
@@ -393,10 +390,7 @@ impl DriveOps for crate::drives::Drives {
     /// Get a drive by it's name.
     async fn get_by_name(&self, name: &str) -> Result<crate::types::Drive> {
         let drives = self
-            .list_all(
-                //&format!("name = '{}'", name), // query
-                "", true, // use domain admin access
-            )
+            .list_all(&crate::drives::DrivesListParams::new().use_domain_admin_access(true))
             .await?;
 
         for drive in drives {
@@ -3256,14 +3256,14 @@ pub struct User {
 /**
 * Data format for the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -3271,12 +3271,35 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        })
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -3286,12 +3309,17 @@ impl Alt {
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Alt::Unknown(_))
+    }
 }
 
 /**
 * The source of files to list. Deprecated: use 'corpora' instead.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Corpus {
     #[serde(rename = "domain")]
     Domain,
@@ -3299,8 +3327,8 @@ pub enum Corpus {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Corpus {
@@ -3309,12 +3337,36 @@ impl std::fmt::Display for Corpus {
             Corpus::Domain => "domain",
             Corpus::User => "user",
             Corpus::Noop => "",
-            Corpus::FallthroughString => "*",
+            Corpus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Corpus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Corpus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "domain" => Corpus::Domain,
+            "user" => Corpus::User,
+            "" => Corpus::Noop,
+            _ => Corpus::Unknown(s),
+        })
+    }
+}
+
 impl Default for Corpus {
     fn default() -> Corpus {
         Corpus::Noop
@@ -3324,4 +3376,9 @@ impl Corpus {
     pub fn is_noop(&self) -> bool {
         matches!(self, Corpus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Corpus::Unknown(_))
+    }
 }
@@ -0,0 +1,389 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Spreadsheets`](crate::spreadsheets::Spreadsheets).
+    pub fn spreadsheets(&self) -> Spreadsheets<'_> {
+        Spreadsheets { mock: self }
+    }
+}
+
+pub struct Spreadsheets<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Spreadsheets<'a> {
+    /// Mock [`Spreadsheets::create`](crate::spreadsheets::Spreadsheets::create), a `POST` to `/v4/spreadsheets`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Spreadsheet> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v4/spreadsheets".to_string())
+    }
+
+    /// Mock [`Spreadsheets::get`](crate::spreadsheets::Spreadsheets::get), a `GET` to `/v4/spreadsheets/{spreadsheetId}`.
+    pub fn get(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Spreadsheet> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v4/spreadsheets/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::developer_metadata_get`](crate::spreadsheets::Spreadsheets::developer_metadata_get), a `GET` to `/v4/spreadsheets/{spreadsheetId}/developerMetadata/{metadataId}`.
+    pub fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeveloperMetadata> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v4/spreadsheets/{}/developerMetadata/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(&metadata_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::developer_metadata_search`](crate::spreadsheets::Spreadsheets::developer_metadata_search), a `POST` to `/v4/spreadsheets/{spreadsheetId}/developerMetadata:search`.
+    pub fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SearchDeveloperMetadataResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/developerMetadata:search",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::sheets_copy`](crate::spreadsheets::Spreadsheets::sheets_copy), a `POST` to `/v4/spreadsheets/{spreadsheetId}/sheets/{sheetId}:copyTo`.
+    pub fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SheetProperties> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/sheets/{}:copyTo",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(&sheet_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_get`](crate::spreadsheets::Spreadsheets::values_get), a `GET` to `/v4/spreadsheets/{spreadsheetId}/values/{range}`.
+    pub fn values_get(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ValueRange> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v4/spreadsheets/{}/values/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_update`](crate::spreadsheets::Spreadsheets::values_update), a `PUT` to `/v4/spreadsheets/{spreadsheetId}/values/{range}`.
+    pub fn values_update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UpdateValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v4/spreadsheets/{}/values/{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_append`](crate::spreadsheets::Spreadsheets::values_append), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values/{range}:append`.
+    pub fn values_append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppendValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values/{}:append",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_clear`](crate::spreadsheets::Spreadsheets::values_clear), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values/{range}:clear`.
+    pub fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClearValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values/{}:clear",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_clear`](crate::spreadsheets::Spreadsheets::values_batch_clear), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values:batchClear`.
+    pub fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchClearValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values:batchClear",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_clear_data_filter`](crate::spreadsheets::Spreadsheets::values_batch_clear_data_filter), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values:batchClearByDataFilter`.
+    pub fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchClearValuesByDataFilterResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values:batchClearByDataFilter",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_get`](crate::spreadsheets::Spreadsheets::values_batch_get), a `GET` to `/v4/spreadsheets/{spreadsheetId}/values:batchGet`.
+    pub fn values_batch_get(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchGetValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v4/spreadsheets/{}/values:batchGet",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_get_data_filter`](crate::spreadsheets::Spreadsheets::values_batch_get_data_filter), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values:batchGetByDataFilter`.
+    pub fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchGetValuesByDataFilterResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values:batchGetByDataFilter",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_update`](crate::spreadsheets::Spreadsheets::values_batch_update), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values:batchUpdate`.
+    pub fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchUpdateValuesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values:batchUpdate",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::values_batch_update_data_filter`](crate::spreadsheets::Spreadsheets::values_batch_update_data_filter), a `POST` to `/v4/spreadsheets/{spreadsheetId}/values:batchUpdateByDataFilter`.
+    pub fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchUpdateValuesByDataFilterResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}/values:batchUpdateByDataFilter",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::batch_update`](crate::spreadsheets::Spreadsheets::batch_update), a `POST` to `/v4/spreadsheets/{spreadsheetId}:batchUpdate`.
+    pub fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchUpdateSpreadsheetResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}:batchUpdate",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+
+    /// Mock [`Spreadsheets::get_data_filter`](crate::spreadsheets::Spreadsheets::get_data_filter), a `POST` to `/v4/spreadsheets/{spreadsheetId}:getByDataFilter`.
+    pub fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Spreadsheet> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v4/spreadsheets/{}:getByDataFilter",
+                crate::progenitor_support::encode_path(spreadsheet_id)
+            ),
+        )
+    }
+}
@@ -283,7 +283,7 @@ pub struct AppendCellsRequest {
 /**
 * Whether rows or columns should be appended.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Dimension {
     #[serde(rename = "COLUMNS")]
     Columns,
@@ -293,8 +293,8 @@ pub enum Dimension {
     Rows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Dimension {
@@ -304,12 +304,37 @@ impl std::fmt::Display for Dimension {
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
             Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Dimension {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimension {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COLUMNS" => Dimension::Columns,
+            "DIMENSION_UNSPECIFIED" => Dimension::DimensionUnspecified,
+            "ROWS" => Dimension::Rows,
+            "" => Dimension::Noop,
+            _ => Dimension::Unknown(s),
+        })
+    }
+}
+
 impl Default for Dimension {
     fn default() -> Dimension {
         Dimension::Noop
@@ -319,6 +344,11 @@ impl Dimension {
     pub fn is_noop(&self) -> bool {
         matches!(self, Dimension::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Dimension::Unknown(_))
+    }
 }
 
 /// Appends rows or columns to the end of a sheet.
@@ -545,7 +575,7 @@ pub struct BandingProperties {
 /**
 * The comparison type of key value with baseline value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ComparisonType {
     #[serde(rename = "ABSOLUTE_DIFFERENCE")]
     AbsoluteDifference,
@@ -555,8 +585,8 @@ pub enum ComparisonType {
     PercentageDifference,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ComparisonType {
@@ -566,12 +596,37 @@ impl std::fmt::Display for ComparisonType {
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
             ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ComparisonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ComparisonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ABSOLUTE_DIFFERENCE" => ComparisonType::AbsoluteDifference,
+            "COMPARISON_TYPE_UNDEFINED" => ComparisonType::ComparisonTypeUndefined,
+            "PERCENTAGE_DIFFERENCE" => ComparisonType::PercentageDifference,
+            "" => ComparisonType::Noop,
+            _ => ComparisonType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ComparisonType {
     fn default() -> ComparisonType {
         ComparisonType::Noop
@@ -581,6 +636,11 @@ impl ComparisonType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ComparisonType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ComparisonType::Unknown(_))
+    }
 }
 
 /// Formatting options for baseline value.
@@ -659,7 +719,7 @@ pub struct BaselineValueFormat {
 /**
 * The position of this axis.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Position {
     #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
     BasicChartAxisPositionUnspecified,
@@ -671,8 +731,8 @@ pub enum Position {
     RightAxis,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Position {
@@ -683,12 +743,38 @@ impl std::fmt::Display for Position {
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
             Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BASIC_CHART_AXIS_POSITION_UNSPECIFIED" => Position::BasicChartAxisPositionUnspecified,
+            "BOTTOM_AXIS" => Position::BottomAxis,
+            "LEFT_AXIS" => Position::LeftAxis,
+            "RIGHT_AXIS" => Position::RightAxis,
+            "" => Position::Noop,
+            _ => Position::Unknown(s),
+        })
+    }
+}
+
 impl Default for Position {
     fn default() -> Position {
         Position::Noop
@@ -698,6 +784,11 @@ impl Position {
     pub fn is_noop(&self) -> bool {
         matches!(self, Position::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Position::Unknown(_))
+    }
 }
 
 /// An axis of the chart. A chart may not have more than one axis per axis position.
@@ -763,7 +854,7 @@ pub struct BasicChartDomain {
 /**
 * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "AREA")]
     Area,
@@ -783,8 +874,8 @@ pub enum Type {
     SteppedArea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -799,12 +890,42 @@ impl std::fmt::Display for Type {
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AREA" => Type::Area,
+            "BAR" => Type::Bar,
+            "BASIC_CHART_TYPE_UNSPECIFIED" => Type::BasicChartTypeUnspecified,
+            "COLUMN" => Type::Column,
+            "COMBO" => Type::Combo,
+            "LINE" => Type::Line,
+            "SCATTER" => Type::Scatter,
+            "STEPPED_AREA" => Type::SteppedArea,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -814,6 +935,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
@@ -886,7 +1012,7 @@ pub struct BasicChartSeries {
 /**
 * The behavior of tooltips and data highlighting when hovering on data and chart area.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CompareMode {
     #[serde(rename = "BASIC_CHART_COMPARE_MODE_UNSPECIFIED")]
     BasicChartCompareModeUnspecified,
@@ -896,8 +1022,8 @@ pub enum CompareMode {
     Datum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompareMode {
@@ -907,12 +1033,37 @@ impl std::fmt::Display for CompareMode {
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
             CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CompareMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompareMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BASIC_CHART_COMPARE_MODE_UNSPECIFIED" => CompareMode::BasicChartCompareModeUnspecified,
+            "CATEGORY" => CompareMode::Category,
+            "DATUM" => CompareMode::Datum,
+            "" => CompareMode::Noop,
+            _ => CompareMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for CompareMode {
     fn default() -> CompareMode {
         CompareMode::Noop
@@ -922,12 +1073,17 @@ impl CompareMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, CompareMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CompareMode::Unknown(_))
+    }
 }
 
 /**
 * The position of the chart legend.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LegendPosition {
     #[serde(rename = "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED")]
     BasicChartLegendPositionUnspecified,
@@ -943,8 +1099,8 @@ pub enum LegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LegendPosition {
@@ -959,12 +1115,42 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
             LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                LegendPosition::BasicChartLegendPositionUnspecified
+            }
+            "BOTTOM_LEGEND" => LegendPosition::BottomLegend,
+            "LEFT_LEGEND" => LegendPosition::LeftLegend,
+            "NO_LEGEND" => LegendPosition::NoLegend,
+            "RIGHT_LEGEND" => LegendPosition::RightLegend,
+            "TOP_LEGEND" => LegendPosition::TopLegend,
+            "" => LegendPosition::Noop,
+            _ => LegendPosition::Unknown(s),
+        })
+    }
+}
+
 impl Default for LegendPosition {
     fn default() -> LegendPosition {
         LegendPosition::Noop
@@ -974,12 +1160,17 @@ impl LegendPosition {
     pub fn is_noop(&self) -> bool {
         matches!(self, LegendPosition::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LegendPosition::Unknown(_))
+    }
 }
 
 /**
 * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum StackedType {
     #[serde(rename = "BASIC_CHART_STACKED_TYPE_UNSPECIFIED")]
     BasicChartStackedTypeUnspecified,
@@ -991,8 +1182,8 @@ pub enum StackedType {
     Stacked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StackedType {
@@ -1003,12 +1194,38 @@ impl std::fmt::Display for StackedType {
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
             StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for StackedType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for StackedType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BASIC_CHART_STACKED_TYPE_UNSPECIFIED" => StackedType::BasicChartStackedTypeUnspecified,
+            "NOT_STACKED" => StackedType::NotStacked,
+            "PERCENT_STACKED" => StackedType::PercentStacked,
+            "STACKED" => StackedType::Stacked,
+            "" => StackedType::Noop,
+            _ => StackedType::Unknown(s),
+        })
+    }
+}
+
 impl Default for StackedType {
     fn default() -> StackedType {
         StackedType::Noop
@@ -1018,6 +1235,11 @@ impl StackedType {
     pub fn is_noop(&self) -> bool {
         matches!(self, StackedType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, StackedType::Unknown(_))
+    }
 }
 
 /// The specification for a basic chart. See BasicChartType for the list of charts this supports.
@@ -1284,7 +1506,7 @@ pub struct BatchClearValuesResponse {
 /**
 * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateTimeRenderOption {
     #[serde(rename = "FORMATTED_STRING")]
     FormattedString,
@@ -1292,8 +1514,8 @@ pub enum DateTimeRenderOption {
     SerialNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRenderOption {
@@ -1302,12 +1524,36 @@ impl std::fmt::Display for DateTimeRenderOption {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
             DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateTimeRenderOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeRenderOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "FORMATTED_STRING" => DateTimeRenderOption::FormattedString,
+            "SERIAL_NUMBER" => DateTimeRenderOption::SerialNumber,
+            "" => DateTimeRenderOption::Noop,
+            _ => DateTimeRenderOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateTimeRenderOption {
     fn default() -> DateTimeRenderOption {
         DateTimeRenderOption::Noop
@@ -1317,12 +1563,17 @@ impl DateTimeRenderOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateTimeRenderOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateTimeRenderOption::Unknown(_))
+    }
 }
 
 /**
 * How values should be represented in the output. The default render option is FORMATTED_VALUE.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ValueRenderOption {
     #[serde(rename = "FORMATTED_VALUE")]
     FormattedValue,
@@ -1332,8 +1583,8 @@ pub enum ValueRenderOption {
     UnformattedValue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueRenderOption {
@@ -1343,12 +1594,37 @@ impl std::fmt::Display for ValueRenderOption {
             ValueRenderOption::Formula => "FORMULA",
             ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
             ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
+            ValueRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ValueRenderOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueRenderOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "FORMATTED_VALUE" => ValueRenderOption::FormattedValue,
+            "FORMULA" => ValueRenderOption::Formula,
+            "UNFORMATTED_VALUE" => ValueRenderOption::UnformattedValue,
+            "" => ValueRenderOption::Noop,
+            _ => ValueRenderOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for ValueRenderOption {
     fn default() -> ValueRenderOption {
         ValueRenderOption::Noop
@@ -1358,6 +1634,11 @@ impl ValueRenderOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueRenderOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ValueRenderOption::Unknown(_))
+    }
 }
 
 /// The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
@@ -1530,7 +1811,7 @@ pub struct BatchUpdateSpreadsheetResponse {
 /**
 * How the input data should be interpreted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ValueInputOption {
     #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
     InputValueOptionUnspecified,
@@ -1540,8 +1821,8 @@ pub enum ValueInputOption {
     UserEntered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueInputOption {
@@ -1551,12 +1832,37 @@ impl std::fmt::Display for ValueInputOption {
             ValueInputOption::Raw => "RAW",
             ValueInputOption::UserEntered => "USER_ENTERED",
             ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+            ValueInputOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ValueInputOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueInputOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "INPUT_VALUE_OPTION_UNSPECIFIED" => ValueInputOption::InputValueOptionUnspecified,
+            "RAW" => ValueInputOption::Raw,
+            "USER_ENTERED" => ValueInputOption::UserEntered,
+            "" => ValueInputOption::Noop,
+            _ => ValueInputOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for ValueInputOption {
     fn default() -> ValueInputOption {
         ValueInputOption::Noop
@@ -1566,6 +1872,11 @@ impl ValueInputOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueInputOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ValueInputOption::Unknown(_))
+    }
 }
 
 /// The request for updating more than one range of values in a spreadsheet.
@@ -1874,7 +2185,7 @@ pub struct BigQueryTableSpec {
 /**
 * The type of condition.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BooleanConditionType {
     #[serde(rename = "BLANK")]
     Blank,
@@ -1942,8 +2253,8 @@ pub enum BooleanConditionType {
     TextStartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BooleanConditionType {
@@ -1982,12 +2293,66 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
             BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BooleanConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BooleanConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BLANK" => BooleanConditionType::Blank,
+            "BOOLEAN" => BooleanConditionType::Boolean,
+            "CONDITION_TYPE_UNSPECIFIED" => BooleanConditionType::ConditionTypeUnspecified,
+            "CUSTOM_FORMULA" => BooleanConditionType::CustomFormula,
+            "DATE_AFTER" => BooleanConditionType::DateAfter,
+            "DATE_BEFORE" => BooleanConditionType::DateBefore,
+            "DATE_BETWEEN" => BooleanConditionType::DateBetween,
+            "DATE_EQ" => BooleanConditionType::DateEq,
+            "DATE_IS_VALID" => BooleanConditionType::DateIsValid,
+            "DATE_NOT_BETWEEN" => BooleanConditionType::DateNotBetween,
+            "DATE_NOT_EQ" => BooleanConditionType::DateNotEq,
+            "DATE_ON_OR_AFTER" => BooleanConditionType::DateOnOrAfter,
+            "DATE_ON_OR_BEFORE" => BooleanConditionType::DateOnOrBefore,
+            "NOT_BLANK" => BooleanConditionType::NotBlank,
+            "NUMBER_BETWEEN" => BooleanConditionType::NumberBetween,
+            "NUMBER_EQ" => BooleanConditionType::NumberEq,
+            "NUMBER_GREATER" => BooleanConditionType::NumberGreater,
+            "NUMBER_GREATER_THAN_EQ" => BooleanConditionType::NumberGreaterThanEq,
+            "NUMBER_LESS" => BooleanConditionType::NumberLess,
+            "NUMBER_LESS_THAN_EQ" => BooleanConditionType::NumberLessThanEq,
+            "NUMBER_NOT_BETWEEN" => BooleanConditionType::NumberNotBetween,
+            "NUMBER_NOT_EQ" => BooleanConditionType::NumberNotEq,
+            "ONE_OF_LIST" => BooleanConditionType::OneOfList,
+            "ONE_OF_RANGE" => BooleanConditionType::OneOfRange,
+            "TEXT_CONTAINS" => BooleanConditionType::TextContains,
+            "TEXT_ENDS_WITH" => BooleanConditionType::TextEndsWith,
+            "TEXT_EQ" => BooleanConditionType::TextEq,
+            "TEXT_IS_EMAIL" => BooleanConditionType::TextIsEmail,
+            "TEXT_IS_URL" => BooleanConditionType::TextIsUrl,
+            "TEXT_NOT_CONTAINS" => BooleanConditionType::TextNotContains,
+            "TEXT_NOT_EQ" => BooleanConditionType::TextNotEq,
+            "TEXT_STARTS_WITH" => BooleanConditionType::TextStartsWith,
+            "" => BooleanConditionType::Noop,
+            _ => BooleanConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for BooleanConditionType {
     fn default() -> BooleanConditionType {
         BooleanConditionType::Noop
@@ -1997,6 +2362,11 @@ impl BooleanConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, BooleanConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BooleanConditionType::Unknown(_))
+    }
 }
 
 /// A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
@@ -2036,7 +2406,7 @@ pub struct BooleanRule {
 /**
 * The style of the border.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Style {
     #[serde(rename = "DASHED")]
     Dashed,
@@ -2056,8 +2426,8 @@ pub enum Style {
     StyleUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Style {
@@ -2072,12 +2442,42 @@ impl std::fmt::Display for Style {
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
             Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Style {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Style {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DASHED" => Style::Dashed,
+            "DOTTED" => Style::Dotted,
+            "DOUBLE" => Style::Double,
+            "NONE" => Style::None,
+            "SOLID" => Style::Solid,
+            "SOLID_MEDIUM" => Style::SolidMedium,
+            "SOLID_THICK" => Style::SolidThick,
+            "STYLE_UNSPECIFIED" => Style::StyleUnspecified,
+            "" => Style::Noop,
+            _ => Style::Unknown(s),
+        })
+    }
+}
+
 impl Default for Style {
     fn default() -> Style {
         Style::Noop
@@ -2087,6 +2487,11 @@ impl Style {
     pub fn is_noop(&self) -> bool {
         matches!(self, Style::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Style::Unknown(_))
+    }
 }
 
 /// A border along a cell.
@@ -2150,7 +2555,7 @@ pub struct Borders {
 /**
 * Where the legend of the chart should be drawn.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -2168,8 +2573,8 @@ pub enum BubbleChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BubbleChartSpecLegendPosition {
@@ -2185,12 +2590,43 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BubbleChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BubbleChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BOTTOM_LEGEND" => BubbleChartSpecLegendPosition::BottomLegend,
+            "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => BubbleChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => BubbleChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => BubbleChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => BubbleChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => BubbleChartSpecLegendPosition::TopLegend,
+            "" => BubbleChartSpecLegendPosition::Noop,
+            _ => BubbleChartSpecLegendPosition::Unknown(s),
+        })
+    }
+}
+
 impl Default for BubbleChartSpecLegendPosition {
     fn default() -> BubbleChartSpecLegendPosition {
         BubbleChartSpecLegendPosition::Noop
@@ -2200,6 +2636,11 @@ impl BubbleChartSpecLegendPosition {
     pub fn is_noop(&self) -> bool {
         matches!(self, BubbleChartSpecLegendPosition::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BubbleChartSpecLegendPosition::Unknown(_))
+    }
 }
 
 /// A bubble chart.
@@ -2508,7 +2949,7 @@ pub struct CellData {
 /**
 * The horizontal alignment of the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HorizontalAlignment {
     #[serde(rename = "CENTER")]
     Center,
@@ -2520,8 +2961,8 @@ pub enum HorizontalAlignment {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HorizontalAlignment {
@@ -2532,12 +2973,38 @@ impl std::fmt::Display for HorizontalAlignment {
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
             HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HorizontalAlignment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HorizontalAlignment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CENTER" => HorizontalAlignment::Center,
+            "HORIZONTAL_ALIGN_UNSPECIFIED" => HorizontalAlignment::HorizontalAlignUnspecified,
+            "LEFT" => HorizontalAlignment::Left,
+            "RIGHT" => HorizontalAlignment::Right,
+            "" => HorizontalAlignment::Noop,
+            _ => HorizontalAlignment::Unknown(s),
+        })
+    }
+}
+
 impl Default for HorizontalAlignment {
     fn default() -> HorizontalAlignment {
         HorizontalAlignment::Noop
@@ -2547,12 +3014,17 @@ impl HorizontalAlignment {
     pub fn is_noop(&self) -> bool {
         matches!(self, HorizontalAlignment::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HorizontalAlignment::Unknown(_))
+    }
 }
 
 /**
 * How a hyperlink, if it exists, should be displayed in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HyperlinkDisplayType {
     #[serde(rename = "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED")]
     HyperlinkDisplayTypeUnspecified,
@@ -2562,8 +3034,8 @@ pub enum HyperlinkDisplayType {
     PlainText,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HyperlinkDisplayType {
@@ -2575,12 +3047,39 @@ impl std::fmt::Display for HyperlinkDisplayType {
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
             HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HyperlinkDisplayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HyperlinkDisplayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED" => {
+                HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified
+            }
+            "LINKED" => HyperlinkDisplayType::Linked,
+            "PLAIN_TEXT" => HyperlinkDisplayType::PlainText,
+            "" => HyperlinkDisplayType::Noop,
+            _ => HyperlinkDisplayType::Unknown(s),
+        })
+    }
+}
+
 impl Default for HyperlinkDisplayType {
     fn default() -> HyperlinkDisplayType {
         HyperlinkDisplayType::Noop
@@ -2590,12 +3089,17 @@ impl HyperlinkDisplayType {
     pub fn is_noop(&self) -> bool {
         matches!(self, HyperlinkDisplayType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HyperlinkDisplayType::Unknown(_))
+    }
 }
 
 /**
 * The direction of the text in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TextDirection {
     #[serde(rename = "LEFT_TO_RIGHT")]
     LeftToRight,
@@ -2605,8 +3109,8 @@ pub enum TextDirection {
     TextDirectionUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextDirection {
@@ -2616,12 +3120,37 @@ impl std::fmt::Display for TextDirection {
             TextDirection::RightToLeft => "RIGHT_TO_LEFT",
             TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
             TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
+            TextDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TextDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TextDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LEFT_TO_RIGHT" => TextDirection::LeftToRight,
+            "RIGHT_TO_LEFT" => TextDirection::RightToLeft,
+            "TEXT_DIRECTION_UNSPECIFIED" => TextDirection::TextDirectionUnspecified,
+            "" => TextDirection::Noop,
+            _ => TextDirection::Unknown(s),
+        })
+    }
+}
+
 impl Default for TextDirection {
     fn default() -> TextDirection {
         TextDirection::Noop
@@ -2631,12 +3160,17 @@ impl TextDirection {
     pub fn is_noop(&self) -> bool {
         matches!(self, TextDirection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TextDirection::Unknown(_))
+    }
 }
 
 /**
 * The vertical alignment of the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum VerticalAlignment {
     #[serde(rename = "BOTTOM")]
     Bottom,
@@ -2648,8 +3182,8 @@ pub enum VerticalAlignment {
     VerticalAlignUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerticalAlignment {
@@ -2660,12 +3194,38 @@ impl std::fmt::Display for VerticalAlignment {
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
             VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for VerticalAlignment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VerticalAlignment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BOTTOM" => VerticalAlignment::Bottom,
+            "MIDDLE" => VerticalAlignment::Middle,
+            "TOP" => VerticalAlignment::Top,
+            "VERTICAL_ALIGN_UNSPECIFIED" => VerticalAlignment::VerticalAlignUnspecified,
+            "" => VerticalAlignment::Noop,
+            _ => VerticalAlignment::Unknown(s),
+        })
+    }
+}
+
 impl Default for VerticalAlignment {
     fn default() -> VerticalAlignment {
         VerticalAlignment::Noop
@@ -2675,12 +3235,17 @@ impl VerticalAlignment {
     pub fn is_noop(&self) -> bool {
         matches!(self, VerticalAlignment::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, VerticalAlignment::Unknown(_))
+    }
 }
 
 /**
 * The wrap strategy for the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WrapStrategy {
     #[serde(rename = "CLIP")]
     Clip,
@@ -2694,8 +3259,8 @@ pub enum WrapStrategy {
     WrapStrategyUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WrapStrategy {
@@ -2707,12 +3272,39 @@ impl std::fmt::Display for WrapStrategy {
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
             WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WrapStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WrapStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CLIP" => WrapStrategy::Clip,
+            "LEGACY_WRAP" => WrapStrategy::LegacyWrap,
+            "OVERFLOW_CELL" => WrapStrategy::OverflowCell,
+            "WRAP" => WrapStrategy::Wrap,
+            "WRAP_STRATEGY_UNSPECIFIED" => WrapStrategy::WrapStrategyUnspecified,
+            "" => WrapStrategy::Noop,
+            _ => WrapStrategy::Unknown(s),
+        })
+    }
+}
+
 impl Default for WrapStrategy {
     fn default() -> WrapStrategy {
         WrapStrategy::Noop
@@ -2722,6 +3314,11 @@ impl WrapStrategy {
     pub fn is_noop(&self) -> bool {
         matches!(self, WrapStrategy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WrapStrategy::Unknown(_))
+    }
 }
 
 /// The format of a cell.
@@ -2832,7 +3429,7 @@ pub struct CellFormat {
 /**
 * The view window's mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ViewWindowMode {
     #[serde(rename = "DEFAULT_VIEW_WINDOW_MODE")]
     DefaultViewWindowMode,
@@ -2844,8 +3441,8 @@ pub enum ViewWindowMode {
     ViewWindowModeUnsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewWindowMode {
@@ -2856,12 +3453,38 @@ impl std::fmt::Display for ViewWindowMode {
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
             ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ViewWindowMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewWindowMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DEFAULT_VIEW_WINDOW_MODE" => ViewWindowMode::DefaultViewWindowMode,
+            "EXPLICIT" => ViewWindowMode::Explicit,
+            "PRETTY" => ViewWindowMode::Pretty,
+            "VIEW_WINDOW_MODE_UNSUPPORTED" => ViewWindowMode::ViewWindowModeUnsupported,
+            "" => ViewWindowMode::Noop,
+            _ => ViewWindowMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for ViewWindowMode {
     fn default() -> ViewWindowMode {
         ViewWindowMode::Noop
@@ -2871,6 +3494,11 @@ impl ViewWindowMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, ViewWindowMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ViewWindowMode::Unknown(_))
+    }
 }
 
 /// The options that define a "view window" for a chart (such as the visible values in an axis).
@@ -2933,7 +3561,7 @@ pub struct ChartCustomNumberFormatOptions {
 /**
 * The aggregation type for the series of a data source chart. Only supported for data source charts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AggregateType {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -2951,8 +3579,8 @@ pub enum AggregateType {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateType {
@@ -2966,12 +3594,41 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
             AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AggregateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AVERAGE" => AggregateType::Average,
+            "CHART_AGGREGATE_TYPE_UNSPECIFIED" => AggregateType::ChartAggregateTypeUnspecified,
+            "COUNT" => AggregateType::Count,
+            "MAX" => AggregateType::Max,
+            "MEDIAN" => AggregateType::Median,
+            "MIN" => AggregateType::Min,
+            "SUM" => AggregateType::Sum,
+            "" => AggregateType::Noop,
+            _ => AggregateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AggregateType {
     fn default() -> AggregateType {
         AggregateType::Noop
@@ -2981,6 +3638,11 @@ impl AggregateType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AggregateType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AggregateType::Unknown(_))
+    }
 }
 
 /// The data included in a domain or series.
@@ -3023,7 +3685,7 @@ pub struct ChartData {
 /**
 * The type of date-time grouping to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ChartDateTimeRuleType {
     #[serde(rename = "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     ChartDateTimeRuleTypeUnspecified,
@@ -3059,8 +3721,8 @@ pub enum ChartDateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChartDateTimeRuleType {
@@ -3085,12 +3747,52 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ChartDateTimeRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChartDateTimeRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED" => {
+                ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified
+            }
+            "DAY_MONTH" => ChartDateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => ChartDateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => ChartDateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => ChartDateTimeRuleType::DayOfYear,
+            "HOUR" => ChartDateTimeRuleType::Hour,
+            "HOUR_MINUTE" => ChartDateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => ChartDateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => ChartDateTimeRuleType::Minute,
+            "MONTH" => ChartDateTimeRuleType::Month,
+            "QUARTER" => ChartDateTimeRuleType::Quarter,
+            "SECOND" => ChartDateTimeRuleType::Second,
+            "YEAR" => ChartDateTimeRuleType::Year,
+            "YEAR_MONTH" => ChartDateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => ChartDateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => ChartDateTimeRuleType::YearQuarter,
+            "" => ChartDateTimeRuleType::Noop,
+            _ => ChartDateTimeRuleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ChartDateTimeRuleType {
     fn default() -> ChartDateTimeRuleType {
         ChartDateTimeRuleType::Noop
@@ -3100,6 +3802,11 @@ impl ChartDateTimeRuleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ChartDateTimeRuleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ChartDateTimeRuleType::Unknown(_))
+    }
 }
 
 /// Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values.
@@ -3187,7 +3894,7 @@ pub struct ChartSourceRange {
 /**
 * Determines how the charts will use hidden rows or columns.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HiddenDimensionStrategy {
     #[serde(rename = "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED")]
     ChartHiddenDimensionStrategyUnspecified,
@@ -3201,8 +3908,8 @@ pub enum HiddenDimensionStrategy {
     SkipHiddenRowsAndColumns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HiddenDimensionStrategy {
@@ -3216,12 +3923,41 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
             HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HiddenDimensionStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HiddenDimensionStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED" => {
+                HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified
+            }
+            "SHOW_ALL" => HiddenDimensionStrategy::ShowAll,
+            "SKIP_HIDDEN_COLUMNS" => HiddenDimensionStrategy::SkipHiddenColumns,
+            "SKIP_HIDDEN_ROWS" => HiddenDimensionStrategy::SkipHiddenRows,
+            "SKIP_HIDDEN_ROWS_AND_COLUMNS" => HiddenDimensionStrategy::SkipHiddenRowsAndColumns,
+            "" => HiddenDimensionStrategy::Noop,
+            _ => HiddenDimensionStrategy::Unknown(s),
+        })
+    }
+}
+
 impl Default for HiddenDimensionStrategy {
     fn default() -> HiddenDimensionStrategy {
         HiddenDimensionStrategy::Noop
@@ -3231,6 +3967,11 @@ impl HiddenDimensionStrategy {
     pub fn is_noop(&self) -> bool {
         matches!(self, HiddenDimensionStrategy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HiddenDimensionStrategy::Unknown(_))
+    }
 }
 
 /// The specifications of a chart.
@@ -3537,7 +4278,7 @@ pub struct Color {
 /**
 * The type of the spreadsheet theme color.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ColorType {
     #[serde(rename = "ACCENT1")]
     Accent1,
@@ -3561,8 +4302,8 @@ pub enum ColorType {
     ThemeColorTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColorType {
@@ -3579,12 +4320,44 @@ impl std::fmt::Display for ColorType {
             ColorType::Text => "TEXT",
             ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
             ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
+            ColorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ColorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACCENT1" => ColorType::Accent1,
+            "ACCENT2" => ColorType::Accent2,
+            "ACCENT3" => ColorType::Accent3,
+            "ACCENT4" => ColorType::Accent4,
+            "ACCENT5" => ColorType::Accent5,
+            "ACCENT6" => ColorType::Accent6,
+            "BACKGROUND" => ColorType::Background,
+            "LINK" => ColorType::Link,
+            "TEXT" => ColorType::Text,
+            "THEME_COLOR_TYPE_UNSPECIFIED" => ColorType::ThemeColorTypeUnspecified,
+            "" => ColorType::Noop,
+            _ => ColorType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ColorType {
     fn default() -> ColorType {
         ColorType::Noop
@@ -3594,6 +4367,11 @@ impl ColorType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ColorType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ColorType::Unknown(_))
+    }
 }
 
 /// A color value.
@@ -3618,7 +4396,7 @@ pub struct ColorStyle {
 /**
 * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RelativeDate {
     #[serde(rename = "PAST_MONTH")]
     PastMonth,
@@ -3636,8 +4414,8 @@ pub enum RelativeDate {
     Yesterday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RelativeDate {
@@ -3651,12 +4429,41 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
             RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RelativeDate {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativeDate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PAST_MONTH" => RelativeDate::PastMonth,
+            "PAST_WEEK" => RelativeDate::PastWeek,
+            "PAST_YEAR" => RelativeDate::PastYear,
+            "RELATIVE_DATE_UNSPECIFIED" => RelativeDate::RelativeDateUnspecified,
+            "TODAY" => RelativeDate::Today,
+            "TOMORROW" => RelativeDate::Tomorrow,
+            "YESTERDAY" => RelativeDate::Yesterday,
+            "" => RelativeDate::Noop,
+            _ => RelativeDate::Unknown(s),
+        })
+    }
+}
+
 impl Default for RelativeDate {
     fn default() -> RelativeDate {
         RelativeDate::Noop
@@ -3666,6 +4473,11 @@ impl RelativeDate {
     pub fn is_noop(&self) -> bool {
         matches!(self, RelativeDate::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RelativeDate::Unknown(_))
+    }
 }
 
 /// The value of the condition.
@@ -3727,7 +4539,7 @@ pub struct ConditionalFormatRule {
 /**
 * How that data should be oriented when pasting.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PasteOrientation {
     #[serde(rename = "NORMAL")]
     Normal,
@@ -3735,8 +4547,8 @@ pub enum PasteOrientation {
     Transpose,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteOrientation {
@@ -3745,12 +4557,36 @@ impl std::fmt::Display for PasteOrientation {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
             PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PasteOrientation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PasteOrientation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "NORMAL" => PasteOrientation::Normal,
+            "TRANSPOSE" => PasteOrientation::Transpose,
+            "" => PasteOrientation::Noop,
+            _ => PasteOrientation::Unknown(s),
+        })
+    }
+}
+
 impl Default for PasteOrientation {
     fn default() -> PasteOrientation {
         PasteOrientation::Noop
@@ -3760,12 +4596,17 @@ impl PasteOrientation {
     pub fn is_noop(&self) -> bool {
         matches!(self, PasteOrientation::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PasteOrientation::Unknown(_))
+    }
 }
 
 /**
 * What kind of data to paste.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PasteType {
     #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
     PasteConditionalFormatting,
@@ -3783,8 +4624,8 @@ pub enum PasteType {
     PasteValues,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteType {
@@ -3798,12 +4639,41 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
             PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PasteType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PasteType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PASTE_CONDITIONAL_FORMATTING" => PasteType::PasteConditionalFormatting,
+            "PASTE_DATA_VALIDATION" => PasteType::PasteDataValidation,
+            "PASTE_FORMAT" => PasteType::PasteFormat,
+            "PASTE_FORMULA" => PasteType::PasteFormula,
+            "PASTE_NORMAL" => PasteType::PasteNormal,
+            "PASTE_NO_BORDERS" => PasteType::PasteNoBorders,
+            "PASTE_VALUES" => PasteType::PasteValues,
+            "" => PasteType::Noop,
+            _ => PasteType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PasteType {
     fn default() -> PasteType {
         PasteType::Noop
@@ -3813,6 +4683,11 @@ impl PasteType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PasteType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PasteType::Unknown(_))
+    }
 }
 
 /// Copies data from the source to the destination.
@@ -3910,7 +4785,7 @@ pub struct CutPasteRequest {
 /**
 * The error code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "CONCURRENT_QUERY")]
     ConcurrentQuery,
@@ -3950,8 +4825,8 @@ pub enum ErrorCode {
     UnsupportedDataType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -3976,12 +4851,52 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CONCURRENT_QUERY" => ErrorCode::ConcurrentQuery,
+            "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED" => ErrorCode::DataExecutionErrorCodeUnspecified,
+            "DATA_NOT_FOUND" => ErrorCode::DataNotFound,
+            "DUPLICATE_COLUMN_NAMES" => ErrorCode::DuplicateColumnNames,
+            "ENGINE" => ErrorCode::Engine,
+            "INTERRUPTED" => ErrorCode::Interrupted,
+            "MISSING_COLUMN_ALIAS" => ErrorCode::MissingColumnAlias,
+            "OBJECT_IN_ERROR_STATE" => ErrorCode::ObjectInErrorState,
+            "OBJECT_NOT_FOUND" => ErrorCode::ObjectNotFound,
+            "OBJECT_SPEC_INVALID" => ErrorCode::ObjectSpecInvalid,
+            "OTHER" => ErrorCode::Other,
+            "PARAMETER_INVALID" => ErrorCode::ParameterInvalid,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "TIMED_OUT" => ErrorCode::TimedOut,
+            "TOO_MANY_CELLS" => ErrorCode::TooManyCells,
+            "TOO_MANY_CHARS_PER_CELL" => ErrorCode::TooManyCharsPerCell,
+            "TOO_MANY_ROWS" => ErrorCode::TooManyRows,
+            "UNSUPPORTED_DATA_TYPE" => ErrorCode::UnsupportedDataType,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::Unknown(s),
+        })
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -3991,12 +4906,17 @@ impl ErrorCode {
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ErrorCode::Unknown(_))
+    }
 }
 
 /**
 * The state of the data execution.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum State {
     #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     DataExecutionStateUnspecified,
@@ -4010,8 +4930,8 @@ pub enum State {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -4023,12 +4943,39 @@ impl std::fmt::Display for State {
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DATA_EXECUTION_STATE_UNSPECIFIED" => State::DataExecutionStateUnspecified,
+            "FAILED" => State::Failed,
+            "NOT_STARTED" => State::NotStarted,
+            "RUNNING" => State::Running,
+            "SUCCEEDED" => State::Succeeded,
+            "" => State::Noop,
+            _ => State::Unknown(s),
+        })
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -4038,6 +4985,11 @@ impl State {
     pub fn is_noop(&self) -> bool {
         matches!(self, State::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, State::Unknown(_))
+    }
 }
 
 /// The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: * Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. * Updating a data source creates a data execution to refresh the associated data source sheet similarly. * You can send refresh request to explicitly refresh one or multiple data source objects.
@@ -4139,7 +5091,7 @@ pub struct DataFilterValueRange {
 /**
 * The placement of the data label relative to the labeled data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Placement {
     #[serde(rename = "ABOVE")]
     Above,
@@ -4161,8 +5113,8 @@ pub enum Placement {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Placement {
@@ -4178,12 +5130,43 @@ impl std::fmt::Display for Placement {
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
             Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Placement {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Placement {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ABOVE" => Placement::Above,
+            "BELOW" => Placement::Below,
+            "CENTER" => Placement::Center,
+            "DATA_LABEL_PLACEMENT_UNSPECIFIED" => Placement::DataLabelPlacementUnspecified,
+            "INSIDE_BASE" => Placement::InsideBase,
+            "INSIDE_END" => Placement::InsideEnd,
+            "LEFT" => Placement::Left,
+            "OUTSIDE_END" => Placement::OutsideEnd,
+            "RIGHT" => Placement::Right,
+            "" => Placement::Noop,
+            _ => Placement::Unknown(s),
+        })
+    }
+}
+
 impl Default for Placement {
     fn default() -> Placement {
         Placement::Noop
@@ -4193,12 +5176,17 @@ impl Placement {
     pub fn is_noop(&self) -> bool {
         matches!(self, Placement::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Placement::Unknown(_))
+    }
 }
 
 /**
 * The type of the data label.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DataLabelType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -4210,8 +5198,8 @@ pub enum DataLabelType {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DataLabelType {
@@ -4222,12 +5210,38 @@ impl std::fmt::Display for DataLabelType {
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
             DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DataLabelType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DataLabelType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM" => DataLabelType::Custom,
+            "DATA" => DataLabelType::Data,
+            "DATA_LABEL_TYPE_UNSPECIFIED" => DataLabelType::DataLabelTypeUnspecified,
+            "NONE" => DataLabelType::None,
+            "" => DataLabelType::Noop,
+            _ => DataLabelType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DataLabelType {
     fn default() -> DataLabelType {
         DataLabelType::Noop
@@ -4237,6 +5251,11 @@ impl DataLabelType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DataLabelType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DataLabelType::Unknown(_))
+    }
 }
 
 /// Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
@@ -4521,7 +5540,7 @@ pub struct DataSourceRefreshMonthlySchedule {
 /**
 * The scope of the refresh. Must be ALL_DATA_SOURCES.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RefreshScope {
     #[serde(rename = "ALL_DATA_SOURCES")]
     AllDataSources,
@@ -4529,8 +5548,8 @@ pub enum RefreshScope {
     DataSourceRefreshScopeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefreshScope {
@@ -4540,10 +5559,36 @@ impl std::fmt::Display for RefreshScope {
             RefreshScope::DataSourceRefreshScopeUnspecified => {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
-            RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
-        }
-        .fmt(f)
+            RefreshScope::Noop => "",
+            RefreshScope::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Serialize for RefreshScope {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RefreshScope {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ALL_DATA_SOURCES" => RefreshScope::AllDataSources,
+            "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED" => {
+                RefreshScope::DataSourceRefreshScopeUnspecified
+            }
+            "" => RefreshScope::Noop,
+            _ => RefreshScope::Unknown(s),
+        })
     }
 }
 
@@ -4556,6 +5601,11 @@ impl RefreshScope {
     pub fn is_noop(&self) -> bool {
         matches!(self, RefreshScope::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RefreshScope::Unknown(_))
+    }
 }
 
 /// Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
@@ -4612,7 +5662,7 @@ pub struct DataSourceRefreshSchedule {
     pub weekly_schedule: Option<DataSourceRefreshWeeklySchedule>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DaysOfWeek {
     #[serde(rename = "DAY_OF_WEEK_UNSPECIFIED")]
     DayOfWeekUnspecified,
@@ -4632,8 +5682,8 @@ pub enum DaysOfWeek {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DaysOfWeek {
@@ -4648,12 +5698,42 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
             DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DaysOfWeek {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DaysOfWeek {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DAY_OF_WEEK_UNSPECIFIED" => DaysOfWeek::DayOfWeekUnspecified,
+            "FRIDAY" => DaysOfWeek::Friday,
+            "MONDAY" => DaysOfWeek::Monday,
+            "SATURDAY" => DaysOfWeek::Saturday,
+            "SUNDAY" => DaysOfWeek::Sunday,
+            "THURSDAY" => DaysOfWeek::Thursday,
+            "TUESDAY" => DaysOfWeek::Tuesday,
+            "WEDNESDAY" => DaysOfWeek::Wednesday,
+            "" => DaysOfWeek::Noop,
+            _ => DaysOfWeek::Unknown(s),
+        })
+    }
+}
+
 impl Default for DaysOfWeek {
     fn default() -> DaysOfWeek {
         DaysOfWeek::Noop
@@ -4663,6 +5743,11 @@ impl DaysOfWeek {
     pub fn is_noop(&self) -> bool {
         matches!(self, DaysOfWeek::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DaysOfWeek::Unknown(_))
+    }
 }
 
 /// A weekly schedule for data to refresh on specific days in a given time interval.
@@ -4765,7 +5850,7 @@ pub struct DataSourceSpec {
 /**
 * The type to select columns for the data source table. Defaults to SELECTED.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ColumnSelectionType {
     #[serde(rename = "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED")]
     DataSourceTableColumnSelectionTypeUnspecified,
@@ -4775,8 +5860,8 @@ pub enum ColumnSelectionType {
     SyncAll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColumnSelectionType {
@@ -4788,12 +5873,39 @@ impl std::fmt::Display for ColumnSelectionType {
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
             ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ColumnSelectionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColumnSelectionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED" => {
+                ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified
+            }
+            "SELECTED" => ColumnSelectionType::Selected,
+            "SYNC_ALL" => ColumnSelectionType::SyncAll,
+            "" => ColumnSelectionType::Noop,
+            _ => ColumnSelectionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ColumnSelectionType {
     fn default() -> ColumnSelectionType {
         ColumnSelectionType::Noop
@@ -4803,6 +5915,11 @@ impl ColumnSelectionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ColumnSelectionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ColumnSelectionType::Unknown(_))
+    }
 }
 
 /// A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
@@ -4917,7 +6034,7 @@ pub struct DataValidationRule {
 /**
 * The type of date-time grouping to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateTimeRuleType {
     #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     DateTimeRuleTypeUnspecified,
@@ -4953,8 +6070,8 @@ pub enum DateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRuleType {
@@ -4977,12 +6094,50 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateTimeRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DATE_TIME_RULE_TYPE_UNSPECIFIED" => DateTimeRuleType::DateTimeRuleTypeUnspecified,
+            "DAY_MONTH" => DateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => DateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => DateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => DateTimeRuleType::DayOfYear,
+            "HOUR" => DateTimeRuleType::Hour,
+            "HOUR_MINUTE" => DateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => DateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => DateTimeRuleType::Minute,
+            "MONTH" => DateTimeRuleType::Month,
+            "QUARTER" => DateTimeRuleType::Quarter,
+            "SECOND" => DateTimeRuleType::Second,
+            "YEAR" => DateTimeRuleType::Year,
+            "YEAR_MONTH" => DateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => DateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => DateTimeRuleType::YearQuarter,
+            "" => DateTimeRuleType::Noop,
+            _ => DateTimeRuleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateTimeRuleType {
     fn default() -> DateTimeRuleType {
         DateTimeRuleType::Noop
@@ -4992,6 +6147,11 @@ impl DateTimeRuleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateTimeRuleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateTimeRuleType::Unknown(_))
+    }
 }
 
 /// Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values. For example, consider a pivot table showing sales transactions by date: +----------+--------------+ | Date | SUM of Sales | +----------+--------------+ | 1/1/2017 | $621.14 | | 2/3/2017 | $708.84 | | 5/8/2017 | $326.84 | ... +----------+--------------+ Applying a date-time group rule with a DateTimeRuleType of YEAR_MONTH results in the following pivot table. +--------------+--------------+ | Grouped Date | SUM of Sales | +--------------+--------------+ | 2017-Jan | $53,731.78 | | 2017-Feb | $83,475.32 | | 2017-Mar | $94,385.05 | ... +--------------+--------------+
@@ -5264,7 +6424,7 @@ pub struct DeleteSheetRequest {
 /**
 * The metadata visibility. Developer metadata must always have a visibility specified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED")]
     DeveloperMetadataVisibilityUnspecified,
@@ -5274,8 +6434,8 @@ pub enum Visibility {
     Project,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -5287,12 +6447,39 @@ impl std::fmt::Display for Visibility {
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED" => {
+                Visibility::DeveloperMetadataVisibilityUnspecified
+            }
+            "DOCUMENT" => Visibility::Document,
+            "PROJECT" => Visibility::Project,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -5302,6 +6489,11 @@ impl Visibility {
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Visibility::Unknown(_))
+    }
 }
 
 /// Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
@@ -5352,7 +6544,7 @@ pub struct DeveloperMetadata {
 /**
 * The type of location this object represents. This field is read-only.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LocationType {
     #[serde(rename = "COLUMN")]
     Column,
@@ -5366,8 +6558,8 @@ pub enum LocationType {
     Spreadsheet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationType {
@@ -5381,12 +6573,41 @@ impl std::fmt::Display for LocationType {
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
             LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LocationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COLUMN" => LocationType::Column,
+            "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED" => {
+                LocationType::DeveloperMetadataLocationTypeUnspecified
+            }
+            "ROW" => LocationType::Row,
+            "SHEET" => LocationType::Sheet,
+            "SPREADSHEET" => LocationType::Spreadsheet,
+            "" => LocationType::Noop,
+            _ => LocationType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LocationType {
     fn default() -> LocationType {
         LocationType::Noop
@@ -5396,6 +6617,11 @@ impl LocationType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LocationType::Unknown(_))
+    }
 }
 
 /// A location where metadata may be associated in a spreadsheet.
@@ -5442,7 +6668,7 @@ pub struct DeveloperMetadataLocation {
 /**
 * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LocationMatchingStrategy {
     #[serde(rename = "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED")]
     DeveloperMetadataLocationMatchingStrategyUnspecified,
@@ -5452,8 +6678,8 @@ pub enum LocationMatchingStrategy {
     IntersectingLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationMatchingStrategy {
@@ -5465,12 +6691,39 @@ impl std::fmt::Display for LocationMatchingStrategy {
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
             LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LocationMatchingStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationMatchingStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED" => {
+                LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified
+            }
+            "EXACT_LOCATION" => LocationMatchingStrategy::ExactLocation,
+            "INTERSECTING_LOCATION" => LocationMatchingStrategy::IntersectingLocation,
+            "" => LocationMatchingStrategy::Noop,
+            _ => LocationMatchingStrategy::Unknown(s),
+        })
+    }
+}
+
 impl Default for LocationMatchingStrategy {
     fn default() -> LocationMatchingStrategy {
         LocationMatchingStrategy::Noop
@@ -5480,6 +6733,11 @@ impl LocationMatchingStrategy {
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationMatchingStrategy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LocationMatchingStrategy::Unknown(_))
+    }
 }
 
 /// Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
@@ -5865,7 +7123,7 @@ pub struct EmbeddedObjectPosition {
 /**
 * The type of error.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ErrorValueType {
     #[serde(rename = "DIVIDE_BY_ZERO")]
     DivideByZero,
@@ -5889,8 +7147,8 @@ pub enum ErrorValueType {
     Value,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorValueType {
@@ -5907,12 +7165,44 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
             ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ErrorValueType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorValueType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DIVIDE_BY_ZERO" => ErrorValueType::DivideByZero,
+            "ERROR" => ErrorValueType::Error,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorValueType::ErrorTypeUnspecified,
+            "LOADING" => ErrorValueType::Loading,
+            "NAME" => ErrorValueType::Name,
+            "NULL_VALUE" => ErrorValueType::NullValue,
+            "NUM" => ErrorValueType::Num,
+            "N_A" => ErrorValueType::NA,
+            "REF" => ErrorValueType::Ref,
+            "VALUE" => ErrorValueType::Value,
+            "" => ErrorValueType::Noop,
+            _ => ErrorValueType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ErrorValueType {
     fn default() -> ErrorValueType {
         ErrorValueType::Noop
@@ -5922,6 +7212,11 @@ impl ErrorValueType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorValueType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ErrorValueType::Unknown(_))
+    }
 }
 
 /// An error in a cell.
@@ -6543,7 +7838,7 @@ pub struct GridRange {
 /**
 * The position of the chart legend.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -6561,8 +7856,8 @@ pub enum HistogramChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HistogramChartSpecLegendPosition {
@@ -6578,12 +7873,43 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HistogramChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HistogramChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BOTTOM_LEGEND" => HistogramChartSpecLegendPosition::BottomLegend,
+            "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => HistogramChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => HistogramChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => HistogramChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => HistogramChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => HistogramChartSpecLegendPosition::TopLegend,
+            "" => HistogramChartSpecLegendPosition::Noop,
+            _ => HistogramChartSpecLegendPosition::Unknown(s),
+        })
+    }
+}
+
 impl Default for HistogramChartSpecLegendPosition {
     fn default() -> HistogramChartSpecLegendPosition {
         HistogramChartSpecLegendPosition::Noop
@@ -6593,6 +7919,11 @@ impl HistogramChartSpecLegendPosition {
     pub fn is_noop(&self) -> bool {
         matches!(self, HistogramChartSpecLegendPosition::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HistogramChartSpecLegendPosition::Unknown(_))
+    }
 }
 
 /// A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
@@ -6744,7 +8075,7 @@ pub struct InsertRangeRequest {
 /**
 * How the value should be interpreted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InterpolationPointType {
     #[serde(rename = "INTERPOLATION_POINT_TYPE_UNSPECIFIED")]
     InterpolationPointTypeUnspecified,
@@ -6760,8 +8091,8 @@ pub enum InterpolationPointType {
     Percentile,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterpolationPointType {
@@ -6776,12 +8107,42 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
             InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InterpolationPointType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InterpolationPointType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "INTERPOLATION_POINT_TYPE_UNSPECIFIED" => {
+                InterpolationPointType::InterpolationPointTypeUnspecified
+            }
+            "MAX" => InterpolationPointType::Max,
+            "MIN" => InterpolationPointType::Min,
+            "NUMBER" => InterpolationPointType::Number,
+            "PERCENT" => InterpolationPointType::Percent,
+            "PERCENTILE" => InterpolationPointType::Percentile,
+            "" => InterpolationPointType::Noop,
+            _ => InterpolationPointType::Unknown(s),
+        })
+    }
+}
+
 impl Default for InterpolationPointType {
     fn default() -> InterpolationPointType {
         InterpolationPointType::Noop
@@ -6791,6 +8152,11 @@ impl InterpolationPointType {
     pub fn is_noop(&self) -> bool {
         matches!(self, InterpolationPointType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InterpolationPointType::Unknown(_))
+    }
 }
 
 /// A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
@@ -6898,7 +8264,7 @@ pub struct KeyValueFormat {
 /**
 * The dash type of the line.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LineStyleType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -6920,8 +8286,8 @@ pub enum LineStyleType {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineStyleType {
@@ -6937,12 +8303,43 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
             LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LineStyleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LineStyleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM" => LineStyleType::Custom,
+            "DOTTED" => LineStyleType::Dotted,
+            "INVISIBLE" => LineStyleType::Invisible,
+            "LINE_DASH_TYPE_UNSPECIFIED" => LineStyleType::LineDashTypeUnspecified,
+            "LONG_DASHED" => LineStyleType::LongDashed,
+            "LONG_DASHED_DOTTED" => LineStyleType::LongDashedDotted,
+            "MEDIUM_DASHED" => LineStyleType::MediumDashed,
+            "MEDIUM_DASHED_DOTTED" => LineStyleType::MediumDashedDotted,
+            "SOLID" => LineStyleType::Solid,
+            "" => LineStyleType::Noop,
+            _ => LineStyleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LineStyleType {
     fn default() -> LineStyleType {
         LineStyleType::Noop
@@ -6952,6 +8349,11 @@ impl LineStyleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LineStyleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LineStyleType::Unknown(_))
+    }
 }
 
 /// Properties that describe the style of a line.
@@ -7071,7 +8473,7 @@ pub struct MatchedValueRange {
 /**
 * How the cells should be merged.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MergeType {
     #[serde(rename = "MERGE_ALL")]
     MergeAll,
@@ -7081,8 +8483,8 @@ pub enum MergeType {
     MergeRows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeType {
@@ -7092,12 +8494,37 @@ impl std::fmt::Display for MergeType {
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
             MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MergeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "MERGE_ALL" => MergeType::MergeAll,
+            "MERGE_COLUMNS" => MergeType::MergeColumns,
+            "MERGE_ROWS" => MergeType::MergeRows,
+            "" => MergeType::Noop,
+            _ => MergeType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MergeType {
     fn default() -> MergeType {
         MergeType::Noop
@@ -7107,6 +8534,11 @@ impl MergeType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MergeType::Unknown(_))
+    }
 }
 
 /// Merges all cells in the range.
@@ -7176,7 +8608,7 @@ pub struct NamedRange {
 /**
 * The type of the number format. When writing, this field must be set.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NumberFormatType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -7198,8 +8630,8 @@ pub enum NumberFormatType {
     Time,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatType {
@@ -7215,12 +8647,43 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
             NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NumberFormatType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberFormatType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CURRENCY" => NumberFormatType::Currency,
+            "DATE" => NumberFormatType::Date,
+            "DATE_TIME" => NumberFormatType::DateTime,
+            "NUMBER" => NumberFormatType::Number,
+            "NUMBER_FORMAT_TYPE_UNSPECIFIED" => NumberFormatType::NumberFormatTypeUnspecified,
+            "PERCENT" => NumberFormatType::Percent,
+            "SCIENTIFIC" => NumberFormatType::Scientific,
+            "TEXT" => NumberFormatType::Text,
+            "TIME" => NumberFormatType::Time,
+            "" => NumberFormatType::Noop,
+            _ => NumberFormatType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NumberFormatType {
     fn default() -> NumberFormatType {
         NumberFormatType::Noop
@@ -7230,6 +8693,11 @@ impl NumberFormatType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NumberFormatType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NumberFormatType::Unknown(_))
+    }
 }
 
 /// The number format of a cell.
@@ -7254,7 +8722,7 @@ pub struct NumberFormat {
 /**
 * The size of the org chart nodes.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NodeSize {
     #[serde(rename = "LARGE")]
     Large,
@@ -7266,8 +8734,8 @@ pub enum NodeSize {
     Small,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NodeSize {
@@ -7278,12 +8746,38 @@ impl std::fmt::Display for NodeSize {
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
             NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NodeSize {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeSize {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LARGE" => NodeSize::Large,
+            "MEDIUM" => NodeSize::Medium,
+            "ORG_CHART_LABEL_SIZE_UNSPECIFIED" => NodeSize::OrgChartLabelSizeUnspecified,
+            "SMALL" => NodeSize::Small,
+            "" => NodeSize::Noop,
+            _ => NodeSize::Unknown(s),
+        })
+    }
+}
+
 impl Default for NodeSize {
     fn default() -> NodeSize {
         NodeSize::Noop
@@ -7293,6 +8787,11 @@ impl NodeSize {
     pub fn is_noop(&self) -> bool {
         matches!(self, NodeSize::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NodeSize::Unknown(_))
+    }
 }
 
 /// An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
@@ -7492,7 +8991,7 @@ pub struct PasteDataRequest {
 /**
 * Where the legend of the pie chart should be drawn.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PieChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -7510,8 +9009,8 @@ pub enum PieChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PieChartSpecLegendPosition {
@@ -7527,12 +9026,43 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PieChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PieChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BOTTOM_LEGEND" => PieChartSpecLegendPosition::BottomLegend,
+            "LABELED_LEGEND" => PieChartSpecLegendPosition::LabeledLegend,
+            "LEFT_LEGEND" => PieChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => PieChartSpecLegendPosition::NoLegend,
+            "PIE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                PieChartSpecLegendPosition::PieChartLegendPositionUnspecified
+            }
+            "RIGHT_LEGEND" => PieChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => PieChartSpecLegendPosition::TopLegend,
+            "" => PieChartSpecLegendPosition::Noop,
+            _ => PieChartSpecLegendPosition::Unknown(s),
+        })
+    }
+}
+
 impl Default for PieChartSpecLegendPosition {
     fn default() -> PieChartSpecLegendPosition {
         PieChartSpecLegendPosition::Noop
@@ -7542,6 +9072,11 @@ impl PieChartSpecLegendPosition {
     pub fn is_noop(&self) -> bool {
         matches!(self, PieChartSpecLegendPosition::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PieChartSpecLegendPosition::Unknown(_))
+    }
 }
 
 /// A pie chart.
@@ -7652,7 +9187,7 @@ pub struct PivotFilterSpec {
 /**
 * The order the values in this group should be sorted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -7662,8 +9197,8 @@ pub enum SortOrder {
     SortOrderUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -7673,12 +9208,37 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "SORT_ORDER_UNSPECIFIED" => SortOrder::SortOrderUnspecified,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -7688,6 +9248,11 @@ impl SortOrder {
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortOrder::Unknown(_))
+    }
 }
 
 /// A single grouping (either row or column) in a pivot table.
@@ -7881,7 +9446,7 @@ pub struct PivotGroupValueMetadata {
 /**
 * Whether values should be listed horizontally (as columns) or vertically (as rows).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ValueLayout {
     #[serde(rename = "HORIZONTAL")]
     Horizontal,
@@ -7889,8 +9454,8 @@ pub enum ValueLayout {
     Vertical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueLayout {
@@ -7899,12 +9464,36 @@ impl std::fmt::Display for ValueLayout {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
             ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ValueLayout {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueLayout {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HORIZONTAL" => ValueLayout::Horizontal,
+            "VERTICAL" => ValueLayout::Vertical,
+            "" => ValueLayout::Noop,
+            _ => ValueLayout::Unknown(s),
+        })
+    }
+}
+
 impl Default for ValueLayout {
     fn default() -> ValueLayout {
         ValueLayout::Noop
@@ -7914,6 +9503,11 @@ impl ValueLayout {
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueLayout::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ValueLayout::Unknown(_))
+    }
 }
 
 /// A pivot table.
@@ -7999,7 +9593,7 @@ pub struct PivotTable {
 /**
 * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CalculatedDisplayType {
     #[serde(rename = "PERCENT_OF_COLUMN_TOTAL")]
     PercentOfColumnTotal,
@@ -8011,8 +9605,8 @@ pub enum CalculatedDisplayType {
     PivotValueCalculatedDisplayTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CalculatedDisplayType {
@@ -8025,12 +9619,40 @@ impl std::fmt::Display for CalculatedDisplayType {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
             CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CalculatedDisplayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CalculatedDisplayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PERCENT_OF_COLUMN_TOTAL" => CalculatedDisplayType::PercentOfColumnTotal,
+            "PERCENT_OF_GRAND_TOTAL" => CalculatedDisplayType::PercentOfGrandTotal,
+            "PERCENT_OF_ROW_TOTAL" => CalculatedDisplayType::PercentOfRowTotal,
+            "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED" => {
+                CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified
+            }
+            "" => CalculatedDisplayType::Noop,
+            _ => CalculatedDisplayType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CalculatedDisplayType {
     fn default() -> CalculatedDisplayType {
         CalculatedDisplayType::Noop
@@ -8040,12 +9662,17 @@ impl CalculatedDisplayType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CalculatedDisplayType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CalculatedDisplayType::Unknown(_))
+    }
 }
 
 /**
 * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SummarizeFunction {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -8079,8 +9706,8 @@ pub enum SummarizeFunction {
     Varp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SummarizeFunction {
@@ -8104,12 +9731,51 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
             SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SummarizeFunction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SummarizeFunction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AVERAGE" => SummarizeFunction::Average,
+            "COUNT" => SummarizeFunction::Count,
+            "COUNTA" => SummarizeFunction::Counta,
+            "COUNTUNIQUE" => SummarizeFunction::Countunique,
+            "CUSTOM" => SummarizeFunction::Custom,
+            "MAX" => SummarizeFunction::Max,
+            "MEDIAN" => SummarizeFunction::Median,
+            "MIN" => SummarizeFunction::Min,
+            "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED" => {
+                SummarizeFunction::PivotStandardValueFunctionUnspecified
+            }
+            "PRODUCT" => SummarizeFunction::Product,
+            "STDEV" => SummarizeFunction::Stdev,
+            "STDEVP" => SummarizeFunction::Stdevp,
+            "SUM" => SummarizeFunction::Sum,
+            "VAR" => SummarizeFunction::Var,
+            "VARP" => SummarizeFunction::Varp,
+            "" => SummarizeFunction::Noop,
+            _ => SummarizeFunction::Unknown(s),
+        })
+    }
+}
+
 impl Default for SummarizeFunction {
     fn default() -> SummarizeFunction {
         SummarizeFunction::Noop
@@ -8119,6 +9785,11 @@ impl SummarizeFunction {
     pub fn is_noop(&self) -> bool {
         matches!(self, SummarizeFunction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SummarizeFunction::Unknown(_))
+    }
 }
 
 /// The definition of how a value in a pivot table should be calculated.
@@ -8184,7 +9855,7 @@ pub struct PivotValue {
 /**
 * The point shape. If empty or unspecified, a default shape is used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Shape {
     #[serde(rename = "CIRCLE")]
     Circle,
@@ -8206,8 +9877,8 @@ pub enum Shape {
     XMark,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shape {
@@ -8223,12 +9894,43 @@ impl std::fmt::Display for Shape {
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
             Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Shape {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Shape {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CIRCLE" => Shape::Circle,
+            "DIAMOND" => Shape::Diamond,
+            "HEXAGON" => Shape::Hexagon,
+            "PENTAGON" => Shape::Pentagon,
+            "POINT_SHAPE_UNSPECIFIED" => Shape::PointShapeUnspecified,
+            "SQUARE" => Shape::Square,
+            "STAR" => Shape::Star,
+            "TRIANGLE" => Shape::Triangle,
+            "X_MARK" => Shape::XMark,
+            "" => Shape::Noop,
+            _ => Shape::Unknown(s),
+        })
+    }
+}
+
 impl Default for Shape {
     fn default() -> Shape {
         Shape::Noop
@@ -8238,6 +9940,11 @@ impl Shape {
     pub fn is_noop(&self) -> bool {
         matches!(self, Shape::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Shape::Unknown(_))
+    }
 }
 
 /// The style of a point on the chart.
@@ -9203,7 +10910,7 @@ pub struct RowData {
 /**
 * The number format source used in the scorecard chart. This field is optional.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NumberFormatSource {
     #[serde(rename = "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED")]
     ChartNumberFormatSourceUndefined,
@@ -9213,8 +10920,8 @@ pub enum NumberFormatSource {
     FromData,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatSource {
@@ -9226,12 +10933,39 @@ impl std::fmt::Display for NumberFormatSource {
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
             NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NumberFormatSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberFormatSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED" => {
+                NumberFormatSource::ChartNumberFormatSourceUndefined
+            }
+            "CUSTOM" => NumberFormatSource::Custom,
+            "FROM_DATA" => NumberFormatSource::FromData,
+            "" => NumberFormatSource::Noop,
+            _ => NumberFormatSource::Unknown(s),
+        })
+    }
+}
+
 impl Default for NumberFormatSource {
     fn default() -> NumberFormatSource {
         NumberFormatSource::Noop
@@ -9241,6 +10975,11 @@ impl NumberFormatSource {
     pub fn is_noop(&self) -> bool {
         matches!(self, NumberFormatSource::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NumberFormatSource::Unknown(_))
+    }
 }
 
 /// A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
@@ -9504,7 +11243,7 @@ pub struct Sheet {
 /**
 * The type of sheet. Defaults to GRID. This field cannot be changed once set.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SheetType {
     #[serde(rename = "DATA_SOURCE")]
     DataSource,
@@ -9516,8 +11255,8 @@ pub enum SheetType {
     SheetTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SheetType {
@@ -9528,12 +11267,38 @@ impl std::fmt::Display for SheetType {
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
             SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SheetType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SheetType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DATA_SOURCE" => SheetType::DataSource,
+            "GRID" => SheetType::Grid,
+            "OBJECT" => SheetType::Object,
+            "SHEET_TYPE_UNSPECIFIED" => SheetType::SheetTypeUnspecified,
+            "" => SheetType::Noop,
+            _ => SheetType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SheetType {
     fn default() -> SheetType {
         SheetType::Noop
@@ -9543,6 +11308,11 @@ impl SheetType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SheetType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SheetType::Unknown(_))
+    }
 }
 
 /// Properties of a sheet.
@@ -9932,7 +11702,7 @@ pub struct Spreadsheet {
 /**
 * The amount of time to wait before volatile functions are recalculated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AutoRecalc {
     #[serde(rename = "HOUR")]
     Hour,
@@ -9944,8 +11714,8 @@ pub enum AutoRecalc {
     RecalculationIntervalUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecalc {
@@ -9956,12 +11726,38 @@ impl std::fmt::Display for AutoRecalc {
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
             AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AutoRecalc {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoRecalc {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HOUR" => AutoRecalc::Hour,
+            "MINUTE" => AutoRecalc::Minute,
+            "ON_CHANGE" => AutoRecalc::OnChange,
+            "RECALCULATION_INTERVAL_UNSPECIFIED" => AutoRecalc::RecalculationIntervalUnspecified,
+            "" => AutoRecalc::Noop,
+            _ => AutoRecalc::Unknown(s),
+        })
+    }
+}
+
 impl Default for AutoRecalc {
     fn default() -> AutoRecalc {
         AutoRecalc::Noop
@@ -9971,6 +11767,11 @@ impl AutoRecalc {
     pub fn is_noop(&self) -> bool {
         matches!(self, AutoRecalc::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AutoRecalc::Unknown(_))
+    }
 }
 
 /// Properties of a spreadsheet.
@@ -10206,7 +12007,7 @@ pub struct TextRotation {
 /**
 * The delimiter type to use.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DelimiterType {
     #[serde(rename = "AUTODETECT")]
     Autodetect,
@@ -10224,8 +12025,8 @@ pub enum DelimiterType {
     Space,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelimiterType {
@@ -10239,12 +12040,41 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
             DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DelimiterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DelimiterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTODETECT" => DelimiterType::Autodetect,
+            "COMMA" => DelimiterType::Comma,
+            "CUSTOM" => DelimiterType::Custom,
+            "DELIMITER_TYPE_UNSPECIFIED" => DelimiterType::DelimiterTypeUnspecified,
+            "PERIOD" => DelimiterType::Period,
+            "SEMICOLON" => DelimiterType::Semicolon,
+            "SPACE" => DelimiterType::Space,
+            "" => DelimiterType::Noop,
+            _ => DelimiterType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DelimiterType {
     fn default() -> DelimiterType {
         DelimiterType::Noop
@@ -10254,6 +12084,11 @@ impl DelimiterType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DelimiterType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DelimiterType::Unknown(_))
+    }
 }
 
 /// Splits a column of text into multiple columns, based on a delimiter in each cell.
@@ -11419,7 +13254,7 @@ pub struct WaterfallChartSeries {
 /**
 * The stacked type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "SEQUENTIAL")]
     Sequential,
@@ -11429,8 +13264,8 @@ pub enum WaterfallChartSpecStackedType {
     WaterfallStackedTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaterfallChartSpecStackedType {
@@ -11442,12 +13277,39 @@ impl std::fmt::Display for WaterfallChartSpecStackedType {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
             WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WaterfallChartSpecStackedType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WaterfallChartSpecStackedType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SEQUENTIAL" => WaterfallChartSpecStackedType::Sequential,
+            "STACKED" => WaterfallChartSpecStackedType::Stacked,
+            "WATERFALL_STACKED_TYPE_UNSPECIFIED" => {
+                WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified
+            }
+            "" => WaterfallChartSpecStackedType::Noop,
+            _ => WaterfallChartSpecStackedType::Unknown(s),
+        })
+    }
+}
+
 impl Default for WaterfallChartSpecStackedType {
     fn default() -> WaterfallChartSpecStackedType {
         WaterfallChartSpecStackedType::Noop
@@ -11457,6 +13319,11 @@ impl WaterfallChartSpecStackedType {
     pub fn is_noop(&self) -> bool {
         matches!(self, WaterfallChartSpecStackedType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WaterfallChartSpecStackedType::Unknown(_))
+    }
 }
 
 /// A waterfall chart.
@@ -11526,7 +13393,7 @@ pub struct WaterfallChartSpec {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -11534,8 +13401,8 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -11544,12 +13411,36 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        })
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -11559,12 +13450,17 @@ impl Xgafv {
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Xgafv::Unknown(_))
+    }
 }
 
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -11574,8 +13470,8 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -11585,12 +13481,37 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        })
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -11600,12 +13521,17 @@ impl Alt {
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Alt::Unknown(_))
+    }
 }
 
 /**
 * How the input data should be inserted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InsertDataOption {
     #[serde(rename = "INSERT_ROWS")]
     InsertRows,
@@ -11613,8 +13539,8 @@ pub enum InsertDataOption {
     Overwrite,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InsertDataOption {
@@ -11623,12 +13549,36 @@ impl std::fmt::Display for InsertDataOption {
             InsertDataOption::InsertRows => "INSERT_ROWS",
             InsertDataOption::Overwrite => "OVERWRITE",
             InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
+            InsertDataOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InsertDataOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InsertDataOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "INSERT_ROWS" => InsertDataOption::InsertRows,
+            "OVERWRITE" => InsertDataOption::Overwrite,
+            "" => InsertDataOption::Noop,
+            _ => InsertDataOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for InsertDataOption {
     fn default() -> InsertDataOption {
         InsertDataOption::Noop
@@ -11638,4 +13588,9 @@ impl InsertDataOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, InsertDataOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InsertDataOption::Unknown(_))
+    }
 }
@@ -71,6 +71,54 @@ impl AdminsBeta {
             )
             .await
     }
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_all_company_admins`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns a list of all the admins at a company
+     */
+    pub fn get_all_company_admins_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Admin> {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/admins",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Admin>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Admin>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an admin for the company.
      *
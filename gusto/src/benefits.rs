@@ -56,6 +56,52 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns all benefits supported by Gusto.
+     *
+     * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
+     */
+    pub fn get_all_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SupportedBenefit> {
+        let url = self.client.url("/v1/benefits", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SupportedBenefit>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SupportedBenefit>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a supported benefit by ID.
      *
@@ -142,6 +188,60 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Company benefits represent the benefits that a company is offering to employees. This ties together a particular supported benefit with the company-specific information for the offering of that benefit.
+     *
+     * Note that company benefits can be deactivated only when no employees are enrolled.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CompanyBenefit> {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/company_benefits",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CompanyBenefit>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CompanyBenefit>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a company benefit.
      *
@@ -296,6 +396,60 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
+     *
+     * Returns an array of all employee benefits for this employee
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::EmployeeBenefit> {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/employee_benefits",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::EmployeeBenefit>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::EmployeeBenefit>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an employee benefit.
      *
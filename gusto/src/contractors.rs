@@ -124,6 +124,52 @@ impl Contractors {
             )
             .await
     }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all contractors, active and inactive, individual and business, for a company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Contractor> {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/contractors",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Contractor>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Contractor>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a contractor.
      *
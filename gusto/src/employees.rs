@@ -175,6 +175,63 @@ impl Employees {
             )
             .await
     }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all of the employees, onboarding, active and terminated, for a given company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Employee> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if terminated {
+            query_args.push(("terminated".to_string(), terminated.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/employees?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Employee>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Employee>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an employee.
      *
@@ -74,6 +74,56 @@ impl JobApplicantsBeta {
             )
             .await
     }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * *This endpoint is in beta - we will be making breaking changes based on developer feedback.
+     *
+     * Returns all job applicants for a company.
+     */
+    pub fn get_all_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::JobApplicant> {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/job_applicants",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::JobApplicant>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::JobApplicant>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a job applicant.
      *
@@ -143,6 +143,52 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all of the jobs that an employee holds.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Job> {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/jobs",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Job>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Job>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a job.
      *
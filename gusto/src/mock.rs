@@ -0,0 +1,1493 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`AdminsBeta`](crate::admins_beta::AdminsBeta).
+    pub fn admins_beta(&self) -> AdminsBeta<'_> {
+        AdminsBeta { mock: self }
+    }
+
+    /// Mock the functions of [`Benefits`](crate::benefits::Benefits).
+    pub fn benefits(&self) -> Benefits<'_> {
+        Benefits { mock: self }
+    }
+
+    /// Mock the functions of [`Companies`](crate::companies::Companies).
+    pub fn companies(&self) -> Companies<'_> {
+        Companies { mock: self }
+    }
+
+    /// Mock the functions of [`CompanyBankAccountsBeta`](crate::company_bank_accounts_beta::CompanyBankAccountsBeta).
+    pub fn company_bank_accounts_beta(&self) -> CompanyBankAccountsBeta<'_> {
+        CompanyBankAccountsBeta { mock: self }
+    }
+
+    /// Mock the functions of [`Compensations`](crate::compensations::Compensations).
+    pub fn compensations(&self) -> Compensations<'_> {
+        Compensations { mock: self }
+    }
+
+    /// Mock the functions of [`ContractorPayments`](crate::contractor_payments::ContractorPayments).
+    pub fn contractor_payments(&self) -> ContractorPayments<'_> {
+        ContractorPayments { mock: self }
+    }
+
+    /// Mock the functions of [`Contractors`](crate::contractors::Contractors).
+    pub fn contractors(&self) -> Contractors<'_> {
+        Contractors { mock: self }
+    }
+
+    /// Mock the functions of [`CurrentUser`](crate::current_user::CurrentUser).
+    pub fn current_user(&self) -> CurrentUser<'_> {
+        CurrentUser { mock: self }
+    }
+
+    /// Mock the functions of [`CustomFields`](crate::custom_fields::CustomFields).
+    pub fn custom_fields(&self) -> CustomFields<'_> {
+        CustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`EarningType`](crate::earning_type::EarningType).
+    pub fn earning_type(&self) -> EarningType<'_> {
+        EarningType { mock: self }
+    }
+
+    /// Mock the functions of [`Employees`](crate::employees::Employees).
+    pub fn employees(&self) -> Employees<'_> {
+        Employees { mock: self }
+    }
+
+    /// Mock the functions of [`FederalTaxDetailsBeta`](crate::federal_tax_details_beta::FederalTaxDetailsBeta).
+    pub fn federal_tax_details_beta(&self) -> FederalTaxDetailsBeta<'_> {
+        FederalTaxDetailsBeta { mock: self }
+    }
+
+    /// Mock the functions of [`Garnishments`](crate::garnishments::Garnishments).
+    pub fn garnishments(&self) -> Garnishments<'_> {
+        Garnishments { mock: self }
+    }
+
+    /// Mock the functions of [`JobApplicantsBeta`](crate::job_applicants_beta::JobApplicantsBeta).
+    pub fn job_applicants_beta(&self) -> JobApplicantsBeta<'_> {
+        JobApplicantsBeta { mock: self }
+    }
+
+    /// Mock the functions of [`Jobs`](crate::jobs::Jobs).
+    pub fn jobs(&self) -> Jobs<'_> {
+        Jobs { mock: self }
+    }
+
+    /// Mock the functions of [`Locations`](crate::locations::Locations).
+    pub fn locations(&self) -> Locations<'_> {
+        Locations { mock: self }
+    }
+
+    /// Mock the functions of [`PaySchedules`](crate::pay_schedules::PaySchedules).
+    pub fn pay_schedules(&self) -> PaySchedules<'_> {
+        PaySchedules { mock: self }
+    }
+
+    /// Mock the functions of [`Payroll`](crate::payroll::Payroll).
+    pub fn payroll(&self) -> Payroll<'_> {
+        Payroll { mock: self }
+    }
+
+    /// Mock the functions of [`Terminations`](crate::terminations::Terminations).
+    pub fn terminations(&self) -> Terminations<'_> {
+        Terminations { mock: self }
+    }
+
+    /// Mock the functions of [`TimeOffRequests`](crate::time_off_requests::TimeOffRequests).
+    pub fn time_off_requests(&self) -> TimeOffRequests<'_> {
+        TimeOffRequests { mock: self }
+    }
+}
+
+pub struct AdminsBeta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminsBeta<'a> {
+    /// Mock [`AdminsBeta::get_company_admins`](crate::admins_beta::AdminsBeta::get_company_admins), a `GET` to `/v1/companies/{company_id}/admins`.
+    pub fn get_company_admins(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Admin>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/admins",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`AdminsBeta::post_company_admin`](crate::admins_beta::AdminsBeta::post_company_admin), a `POST` to `/v1/companies/{company_id}/admins`.
+    pub fn post_company_admin(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Admin> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/admins",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+}
+
+pub struct Benefits<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Benefits<'a> {
+    /// Mock [`Benefits::get_page`](crate::benefits::Benefits::get_page), a `GET` to `/v1/benefits`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::SupportedBenefit>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/benefits".to_string())
+    }
+
+    /// Mock [`Benefits::get`](crate::benefits::Benefits::get), a `GET` to `/v1/benefits/{benefit_id}`.
+    pub fn get(
+        &self,
+        benefit_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SupportedBenefit> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/benefits/{}",
+                crate::progenitor_support::encode_path(benefit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::get_company`](crate::benefits::Benefits::get_company), a `GET` to `/v1/companies/{company_id}/company_benefits`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CompanyBenefit>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/company_benefits",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::post_company`](crate::benefits::Benefits::post_company), a `POST` to `/v1/companies/{company_id}/company_benefits`.
+    pub fn post_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CompanyBenefit> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/company_benefits",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::get_company_benefits`](crate::benefits::Benefits::get_company_benefits), a `GET` to `/v1/company_benefits/{company_benefit_id}`.
+    pub fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CompanyBenefit> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/company_benefits/{}",
+                crate::progenitor_support::encode_path(company_benefit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::put_company`](crate::benefits::Benefits::put_company), a `PUT` to `/v1/company_benefits/{company_benefit_id}`.
+    pub fn put_company(
+        &self,
+        company_benefit_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CompanyBenefit> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/company_benefits/{}",
+                crate::progenitor_support::encode_path(company_benefit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::get_employee`](crate::benefits::Benefits::get_employee), a `GET` to `/v1/employees/{employee_id}/employee_benefits`.
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::EmployeeBenefit>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/employee_benefits",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::post_employee`](crate::benefits::Benefits::post_employee), a `POST` to `/v1/employees/{employee_id}/employee_benefits`.
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmployeeBenefit> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/employees/{}/employee_benefits",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::post_employee_ytd_amounts_from_different_company`](crate::benefits::Benefits::post_employee_ytd_amounts_from_different_company), a `POST` to `/v1/employees/{employee_id}/ytd_benefit_amounts_from_different_company`.
+    pub fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/employees/{}/ytd_benefit_amounts_from_different_company",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::get_employee_benefits`](crate::benefits::Benefits::get_employee_benefits), a `GET` to `/v1/employee_benefits/{employee_benefit_id}`.
+    pub fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmployeeBenefit> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employee_benefits/{}",
+                crate::progenitor_support::encode_path(employee_benefit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::put_employee`](crate::benefits::Benefits::put_employee), a `PUT` to `/v1/employee_benefits/{employee_benefit_id}`.
+    pub fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmployeeBenefit> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/employee_benefits/{}",
+                crate::progenitor_support::encode_path(employee_benefit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Benefits::delete_employee`](crate::benefits::Benefits::delete_employee), a `DELETE` to `/v1/employee_benefits/{employee_benefit_id}`.
+    pub fn delete_employee(&self, employee_benefit_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/employee_benefits/{}",
+                crate::progenitor_support::encode_path(employee_benefit_id)
+            ),
+        )
+    }
+}
+
+pub struct Companies<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Companies<'a> {
+    /// Mock [`Companies::get`](crate::companies::Companies::get), a `GET` to `/v1/companies/{company_id_or_uuid}`.
+    pub fn get(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Company> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Companies::post_partner_managed`](crate::companies::Companies::post_partner_managed), a `POST` to `/v1/partner_managed_companies`.
+    pub fn post_partner_managed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostPartnerManagedCompaniesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/partner_managed_companies".to_string(),
+        )
+    }
+
+    /// Mock [`Companies::post_provision`](crate::companies::Companies::post_provision), a `POST` to `/v1/provision`.
+    pub fn post_provision(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostProvisionResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/provision".to_string())
+    }
+}
+
+pub struct CompanyBankAccountsBeta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CompanyBankAccountsBeta<'a> {
+    /// Mock [`CompanyBankAccountsBeta::get_company_bank_accounts`](crate::company_bank_accounts_beta::CompanyBankAccountsBeta::get_company_bank_accounts), a `GET` to `/v1/companies/{company_id_or_uuid}/bank_accounts`.
+    pub fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CompanyBankAccount>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/bank_accounts",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`CompanyBankAccountsBeta::post_company_bank_account`](crate::company_bank_accounts_beta::CompanyBankAccountsBeta::post_company_bank_account), a `POST` to `/v1/companies/{company_id_or_uuid}/bank_accounts`.
+    pub fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CompanyBankAccount> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/bank_accounts",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`CompanyBankAccountsBeta::put_company_bank_accounts_verify`](crate::company_bank_accounts_beta::CompanyBankAccountsBeta::put_company_bank_accounts_verify), a `PUT` to `/v1/companies/{company_id_or_uuid}/bank_accounts/{bank_account_uuid}/verify`.
+    pub fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CompanyBankAccount> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/bank_accounts/{}/verify",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(bank_account_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Compensations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Compensations<'a> {
+    /// Mock [`Compensations::get`](crate::compensations::Compensations::get), a `GET` to `/v1/compensations/{compensation_id}`.
+    pub fn get(
+        &self,
+        compensation_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Compensation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/compensations/{}",
+                crate::progenitor_support::encode_path(compensation_id)
+            ),
+        )
+    }
+
+    /// Mock [`Compensations::put`](crate::compensations::Compensations::put), a `PUT` to `/v1/compensations/{compensation_id}`.
+    pub fn put(
+        &self,
+        compensation_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Compensation> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/compensations/{}",
+                crate::progenitor_support::encode_path(compensation_id)
+            ),
+        )
+    }
+
+    /// Mock [`Compensations::get_job`](crate::compensations::Compensations::get_job), a `GET` to `/v1/jobs/{job_id}/compensations`.
+    pub fn get_job(
+        &self,
+        job_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Compensation>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/jobs/{}/compensations",
+                crate::progenitor_support::encode_path(job_id)
+            ),
+        )
+    }
+}
+
+pub struct ContractorPayments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ContractorPayments<'a> {
+    /// Mock [`ContractorPayments::get_company`](crate::contractor_payments::ContractorPayments::get_company), a `GET` to `/v1/companies/{company_id}/contractor_payments`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContractorPaymentSummary> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/contractor_payments",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContractorPayments::post_company`](crate::contractor_payments::ContractorPayments::post_company), a `POST` to `/v1/companies/{company_id}/contractor_payments`.
+    pub fn post_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContractorPayment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/contractor_payments",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContractorPayments::get_company_contractor_payments`](crate::contractor_payments::ContractorPayments::get_company_contractor_payments), a `GET` to `/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}`.
+    pub fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContractorPayment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/contractor_payments/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(contractor_payment_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`ContractorPayments::delete_company`](crate::contractor_payments::ContractorPayments::delete_company), a `DELETE` to `/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}`.
+    pub fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/companies/{}/contractor_payments/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(contractor_payment_id_or_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Contractors<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Contractors<'a> {
+    /// Mock [`Contractors::get`](crate::contractors::Contractors::get), a `GET` to `/v1/contractors/{contractor_id_or_uuid}`.
+    pub fn get(
+        &self,
+        contractor_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Contractor> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/contractors/{}",
+                crate::progenitor_support::encode_path(contractor_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Contractors::put`](crate::contractors::Contractors::put), a `PUT` to `/v1/contractors/{contractor_id_or_uuid}`.
+    pub fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Contractor> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/contractors/{}",
+                crate::progenitor_support::encode_path(contractor_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Contractors::get_company`](crate::contractors::Contractors::get_company), a `GET` to `/v1/companies/{company_id_or_uuid}/contractors`.
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Contractor>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/contractors",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Contractors::post_company`](crate::contractors::Contractors::post_company), a `POST` to `/v1/companies/{company_id_or_uuid}/contractors`.
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Contractor> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/contractors",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+}
+
+pub struct CurrentUser<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CurrentUser<'a> {
+    /// Mock [`CurrentUser::get_me`](crate::current_user::CurrentUser::get_me), a `GET` to `/v1/me`.
+    pub fn get_me(&self) -> crate::mock::MockOperation<'a, crate::types::CurrentUser> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/me".to_string())
+    }
+}
+
+pub struct CustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CustomFields<'a> {
+    /// Mock [`CustomFields::get_employee`](crate::custom_fields::CustomFields::get_employee), a `GET` to `/v1/employees/{employee_id}/custom_fields`.
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetEmployeeCustomFieldsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/custom_fields",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomFields::get_company`](crate::custom_fields::CustomFields::get_company), a `GET` to `/v1/companies/{company_id}/custom_fields`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCompanyCustomFieldsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/custom_fields",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+}
+
+pub struct EarningType<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EarningType<'a> {
+    /// Mock [`EarningType::get_company`](crate::earning_type::EarningType::get_company), a `GET` to `/v1/companies/{company_id}/earning_types`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EarningTypeListResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/earning_types",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`EarningType::post_company`](crate::earning_type::EarningType::post_company), a `POST` to `/v1/companies/{company_id}/earning_types`.
+    pub fn post_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EarningType> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/earning_types",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`EarningType::put_company_type`](crate::earning_type::EarningType::put_company_type), a `PUT` to `/v1/companies/{company_id}/earning_types/{earning_type_uuid}`.
+    pub fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EarningType> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/earning_types/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(earning_type_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`EarningType::delete_company_type`](crate::earning_type::EarningType::delete_company_type), a `DELETE` to `/v1/companies/{company_id}/earning_types/{earning_type_uuid}`.
+    pub fn delete_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/companies/{}/earning_types/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(earning_type_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Employees<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Employees<'a> {
+    /// Mock [`Employees::get`](crate::employees::Employees::get), a `GET` to `/v1/employees/{employee_id_or_uuid}`.
+    pub fn get(
+        &self,
+        employee_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Employee> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}",
+                crate::progenitor_support::encode_path(employee_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Employees::put`](crate::employees::Employees::put), a `PUT` to `/v1/employees/{employee_id_or_uuid}`.
+    pub fn put(
+        &self,
+        employee_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Employee> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/employees/{}",
+                crate::progenitor_support::encode_path(employee_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Employees::get_company`](crate::employees::Employees::get_company), a `GET` to `/v1/companies/{company_id_or_uuid}/employees`.
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Employee>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/employees",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Employees::post`](crate::employees::Employees::post), a `POST` to `/v1/companies/{company_id_or_uuid}/employees`.
+    pub fn post(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Employee> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/employees",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Employees::get_home_address`](crate::employees::Employees::get_home_address), a `GET` to `/v1/employees/{employee_id}/home_address`.
+    pub fn get_home_address(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/home_address",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Employees::put_home_address`](crate::employees::Employees::put_home_address), a `PUT` to `/v1/employees/{employee_id}/home_address`.
+    pub fn put_home_address(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/employees/{}/home_address",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+}
+
+pub struct FederalTaxDetailsBeta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FederalTaxDetailsBeta<'a> {
+    /// Mock [`FederalTaxDetailsBeta::get_company_or_federal_tax_details`](crate::federal_tax_details_beta::FederalTaxDetailsBeta::get_company_or_federal_tax_details), a `GET` to `/v1/companies/{company_id_or_uuid}/federal_tax_details`.
+    pub fn get_company_or_federal_tax_details(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCompanyFederalTaxDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/federal_tax_details",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`FederalTaxDetailsBeta::put_company_or_federal_tax_details`](crate::federal_tax_details_beta::FederalTaxDetailsBeta::put_company_or_federal_tax_details), a `PUT` to `/v1/companies/{company_id_or_uuid}/federal_tax_details`.
+    pub fn put_company_or_federal_tax_details(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCompanyFederalTaxDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/federal_tax_details",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Garnishments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Garnishments<'a> {
+    /// Mock [`Garnishments::get_employee`](crate::garnishments::Garnishments::get_employee), a `GET` to `/v1/employees/{employee_id}/garnishments`.
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Garnishment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/garnishments",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Garnishments::post_employee`](crate::garnishments::Garnishments::post_employee), a `POST` to `/v1/employees/{employee_id}/garnishments`.
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Garnishment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/employees/{}/garnishments",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Garnishments::get`](crate::garnishments::Garnishments::get), a `GET` to `/v1/garnishments/{garnishment_id}`.
+    pub fn get(
+        &self,
+        garnishment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Garnishment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/garnishments/{}",
+                crate::progenitor_support::encode_path(garnishment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Garnishments::put`](crate::garnishments::Garnishments::put), a `PUT` to `/v1/garnishments/{garnishment_id}`.
+    pub fn put(
+        &self,
+        garnishment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Garnishment> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/garnishments/{}",
+                crate::progenitor_support::encode_path(garnishment_id)
+            ),
+        )
+    }
+}
+
+pub struct JobApplicantsBeta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> JobApplicantsBeta<'a> {
+    /// Mock [`JobApplicantsBeta::get_company_job_applicants`](crate::job_applicants_beta::JobApplicantsBeta::get_company_job_applicants), a `GET` to `/v1/companies/{company_id}/job_applicants`.
+    pub fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::JobApplicant>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/job_applicants",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`JobApplicantsBeta::post_company_job_applicant`](crate::job_applicants_beta::JobApplicantsBeta::post_company_job_applicant), a `POST` to `/v1/companies/{company_id}/job_applicants`.
+    pub fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JobApplicant> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/job_applicants",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`JobApplicantsBeta::get_company_job_applicants_applicant`](crate::job_applicants_beta::JobApplicantsBeta::get_company_job_applicants_applicant), a `GET` to `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}`.
+    pub fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JobApplicant> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/job_applicants/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(job_applicant_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`JobApplicantsBeta::put_company_job_applicants_applicant`](crate::job_applicants_beta::JobApplicantsBeta::put_company_job_applicants_applicant), a `PUT` to `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}`.
+    pub fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JobApplicant> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/job_applicants/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(job_applicant_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`JobApplicantsBeta::delete_company_job_applicants_applicant`](crate::job_applicants_beta::JobApplicantsBeta::delete_company_job_applicants_applicant), a `DELETE` to `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}`.
+    pub fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/companies/{}/job_applicants/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(job_applicant_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Jobs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Jobs<'a> {
+    /// Mock [`Jobs::get`](crate::jobs::Jobs::get), a `GET` to `/v1/jobs/{job_id}`.
+    pub fn get(&self, job_id: &str) -> crate::mock::MockOperation<'a, crate::types::Job> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/jobs/{}",
+                crate::progenitor_support::encode_path(job_id)
+            ),
+        )
+    }
+
+    /// Mock [`Jobs::put`](crate::jobs::Jobs::put), a `PUT` to `/v1/jobs/{job_id}`.
+    pub fn put(&self, job_id: &str) -> crate::mock::MockOperation<'a, crate::types::Job> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/jobs/{}",
+                crate::progenitor_support::encode_path(job_id)
+            ),
+        )
+    }
+
+    /// Mock [`Jobs::delete`](crate::jobs::Jobs::delete), a `DELETE` to `/v1/jobs/{job_id}`.
+    pub fn delete(&self, job_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/jobs/{}",
+                crate::progenitor_support::encode_path(job_id)
+            ),
+        )
+    }
+
+    /// Mock [`Jobs::get_employee`](crate::jobs::Jobs::get_employee), a `GET` to `/v1/employees/{employee_id}/jobs`.
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Job>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/jobs",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Jobs::post`](crate::jobs::Jobs::post), a `POST` to `/v1/employees/{employee_id}/jobs`.
+    pub fn post(&self, employee_id: &str) -> crate::mock::MockOperation<'a, crate::types::Job> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/employees/{}/jobs",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Jobs::post_compensation`](crate::jobs::Jobs::post_compensation), a `POST` to `/v1/jobs/{job_id}/compensations`.
+    pub fn post_compensation(
+        &self,
+        job_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Compensation> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/jobs/{}/compensations",
+                crate::progenitor_support::encode_path(job_id)
+            ),
+        )
+    }
+}
+
+pub struct Locations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Locations<'a> {
+    /// Mock [`Locations::get_company`](crate::locations::Locations::get_company), a `GET` to `/v1/companies/{company_id_or_uuid}/locations`.
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Location>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/locations",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Locations::post_company`](crate::locations::Locations::post_company), a `POST` to `/v1/companies/{company_id_or_uuid}/locations`.
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/locations",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Locations::get`](crate::locations::Locations::get), a `GET` to `/v1/locations/{location_id}`.
+    pub fn get(&self, location_id: &str) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`Locations::put`](crate::locations::Locations::put), a `PUT` to `/v1/locations/{location_id}`.
+    pub fn put(&self, location_id: &str) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+}
+
+pub struct PaySchedules<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PaySchedules<'a> {
+    /// Mock [`PaySchedules::get_company`](crate::pay_schedules::PaySchedules::get_company), a `GET` to `/v1/companies/{company_id}/pay_schedules`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PaySchedule>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/pay_schedules",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`PaySchedules::get_company_schedule`](crate::pay_schedules::PaySchedules::get_company_schedule), a `GET` to `/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}`.
+    pub fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaySchedule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/pay_schedules/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(pay_schedule_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`PaySchedules::put_company_schedule`](crate::pay_schedules::PaySchedules::put_company_schedule), a `PUT` to `/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}`.
+    pub fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaySchedule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/pay_schedules/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(pay_schedule_id_or_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Payroll<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Payroll<'a> {
+    /// Mock [`Payroll::get_company_pay_periods`](crate::payroll::Payroll::get_company_pay_periods), a `GET` to `/v1/companies/{company_id_or_uuid}/pay_periods`.
+    pub fn get_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PayPeriod>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/pay_periods",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::get_company`](crate::payroll::Payroll::get_company), a `GET` to `/v1/companies/{company_id_or_uuid}/payrolls`.
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PayrollData>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/payrolls",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::post_company`](crate::payroll::Payroll::post_company), a `POST` to `/v1/companies/{company_id_or_uuid}/payrolls`.
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PayrollData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/companies/{}/payrolls",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::get_company_payroll`](crate::payroll::Payroll::get_company_payroll), a `GET` to `/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}`.
+    pub fn get_company_payroll(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PayrollData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/payrolls/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(payroll_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::put_company`](crate::payroll::Payroll::put_company), a `PUT` to `/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}`.
+    pub fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PayrollData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/payrolls/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(payroll_id_or_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::put_company_pay_period_start_date_end`](crate::payroll::Payroll::put_company_pay_period_start_date_end), a `PUT` to `/v1/companies/{company_id_or_uuid}/payrolls/{pay_period_start_date}/{pay_period_end_date}`.
+    pub fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PayrollData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/payrolls/{}/{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(pay_period_start_date),
+                crate::progenitor_support::encode_path(pay_period_end_date)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::put_company_calculate`](crate::payroll::Payroll::put_company_calculate), a `PUT` to `/v1/companies/{company_id}/payrolls/{payroll_id}/calculate`.
+    pub fn put_company_calculate(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/payrolls/{}/calculate",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(payroll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::put_company_submit`](crate::payroll::Payroll::put_company_submit), a `PUT` to `/v1/companies/{company_id}/payrolls/{payroll_Id}/submit`.
+    pub fn put_company_submit(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/payrolls/{}/submit",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(payroll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::put_company_cancel`](crate::payroll::Payroll::put_company_cancel), a `PUT` to `/v1/companies/{company_id}/payrolls/{payroll_id}/cancel`.
+    pub fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PayrollData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/v1/companies/{}/payrolls/{}/cancel",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(payroll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Payroll::get_company_or_reversals`](crate::payroll::Payroll::get_company_or_reversals), a `GET` to `/v1/companies/{company_id_or_uuid}/payroll_reversals`.
+    pub fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCompanyPayrollReversalsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/payroll_reversals",
+                crate::progenitor_support::encode_path(company_id_or_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Terminations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Terminations<'a> {
+    /// Mock [`Terminations::get_employee`](crate::terminations::Terminations::get_employee), a `GET` to `/v1/employees/{employee_id}/terminations`.
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Termination>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/employees/{}/terminations",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+
+    /// Mock [`Terminations::post_employee`](crate::terminations::Terminations::post_employee), a `POST` to `/v1/employees/{employee_id}/terminations`.
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Termination> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/employees/{}/terminations",
+                crate::progenitor_support::encode_path(employee_id)
+            ),
+        )
+    }
+}
+
+pub struct TimeOffRequests<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TimeOffRequests<'a> {
+    /// Mock [`TimeOffRequests::get_company`](crate::time_off_requests::TimeOffRequests::get_company), a `GET` to `/v1/companies/{company_id}/time_off_requests`.
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::TimeOffRequest>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/time_off_requests",
+                crate::progenitor_support::encode_path(company_id)
+            ),
+        )
+    }
+
+    /// Mock [`TimeOffRequests::get_company_request`](crate::time_off_requests::TimeOffRequests::get_company_request), a `GET` to `/v1/companies/{company_id}/time_off_requests/{time_off_request_id}`.
+    pub fn get_company_request(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TimeOffRequest> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/companies/{}/time_off_requests/{}",
+                crate::progenitor_support::encode_path(company_id),
+                crate::progenitor_support::encode_path(time_off_request_id)
+            ),
+        )
+    }
+}
@@ -67,6 +67,54 @@ impl PaySchedules {
             )
             .await
     }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * The pay schedule object in Gusto captures the details of when employees work and when they should be paid. A company can have multiple pay schedules.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PaySchedule> {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/pay_schedules",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::PaySchedule>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::PaySchedule>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a pay schedule.
      *
@@ -131,6 +131,86 @@ impl TimeOffRequests {
             )
             .await
     }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all time off requests, past and present, for a company.
+     *
+     * In order to reduce the number of time off requests returned in a single response, or to retrieve time off requests from a time period of interest, you may use the `start_date` and `end_date` parameters.
+     *
+     * You may provide both or either parameters to scope the returned data. For example:
+     *
+     * `?start_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request start date is equal to or after January 1, 2019.
+     *
+     * `?end_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request end date is equal to or before January 1, 2019.
+     *
+     * `?start_date='2019-05-01'&end_date='2019-08-31'`
+     *
+     * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
+     *
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TimeOffRequest> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/time_off_requests?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::TimeOffRequest>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::TimeOffRequest>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a specific time off request.
      *
@@ -591,7 +591,7 @@ pub struct Termination {
 /**
 * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PaymentUnit {
     #[serde(rename = "Hour")]
     Hour,
@@ -605,8 +605,8 @@ pub enum PaymentUnit {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentUnit {
@@ -618,12 +618,39 @@ impl std::fmt::Display for PaymentUnit {
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
             PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PaymentUnit {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentUnit {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Hour" => PaymentUnit::Hour,
+            "Month" => PaymentUnit::Month,
+            "Paycheck" => PaymentUnit::Paycheck,
+            "Week" => PaymentUnit::Week,
+            "Year" => PaymentUnit::Year,
+            "" => PaymentUnit::Noop,
+            _ => PaymentUnit::Unknown(s),
+        })
+    }
+}
+
 impl Default for PaymentUnit {
     fn default() -> PaymentUnit {
         PaymentUnit::Noop
@@ -633,12 +660,17 @@ impl PaymentUnit {
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentUnit::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PaymentUnit::Unknown(_))
+    }
 }
 
 /**
 * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FlsaStatus {
     #[serde(rename = "Exempt")]
     Exempt,
@@ -650,8 +682,8 @@ pub enum FlsaStatus {
     SalariedNonexempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FlsaStatus {
@@ -662,12 +694,38 @@ impl std::fmt::Display for FlsaStatus {
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
             FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FlsaStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FlsaStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Exempt" => FlsaStatus::Exempt,
+            "Nonexempt" => FlsaStatus::Nonexempt,
+            "Owner" => FlsaStatus::Owner,
+            "Salaried Nonexempt" => FlsaStatus::SalariedNonexempt,
+            "" => FlsaStatus::Noop,
+            _ => FlsaStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for FlsaStatus {
     fn default() -> FlsaStatus {
         FlsaStatus::Noop
@@ -677,6 +735,11 @@ impl FlsaStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, FlsaStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FlsaStatus::Unknown(_))
+    }
 }
 
 /// The representation of compensation in Gusto.
@@ -951,7 +1014,7 @@ pub struct Admin {
 /**
 * The tax payer type of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EntityType {
     #[serde(rename = "Association")]
     Association,
@@ -979,8 +1042,8 @@ pub enum EntityType {
     Trusteeship,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntityType {
@@ -999,12 +1062,46 @@ impl std::fmt::Display for EntityType {
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
             EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Association" => EntityType::Association,
+            "C-Corporation" => EntityType::CCorporation,
+            "Co-ownership" => EntityType::CoOwnership,
+            "General partnership" => EntityType::GeneralPartnership,
+            "Joint venture" => EntityType::JointVenture,
+            "LLC" => EntityType::Llc,
+            "LLP" => EntityType::Llp,
+            "Limited partnership" => EntityType::LimitedPartnership,
+            "Non-Profit" => EntityType::NonProfit,
+            "S-Corporation" => EntityType::SCorporation,
+            "Sole proprietor" => EntityType::SoleProprietor,
+            "Trusteeship" => EntityType::Trusteeship,
+            "" => EntityType::Noop,
+            _ => EntityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EntityType {
     fn default() -> EntityType {
         EntityType::Noop
@@ -1014,12 +1111,17 @@ impl EntityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EntityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EntityType::Unknown(_))
+    }
 }
 
 /**
 * The Gusto product tier of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Tier {
     #[serde(rename = "basic")]
     Basic,
@@ -1033,8 +1135,8 @@ pub enum Tier {
     Core,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Tier {
@@ -1046,12 +1148,39 @@ impl std::fmt::Display for Tier {
             Tier::ContractorOnly => "contractor_only",
             Tier::Core => "core",
             Tier::Noop => "",
-            Tier::FallthroughString => "*",
+            Tier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Tier {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tier {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "basic" => Tier::Basic,
+            "complete" => Tier::Complete,
+            "concierge" => Tier::Concierge,
+            "contractor_only" => Tier::ContractorOnly,
+            "core" => Tier::Core,
+            "" => Tier::Noop,
+            _ => Tier::Unknown(s),
+        })
+    }
+}
+
 impl Default for Tier {
     fn default() -> Tier {
         Tier::Noop
@@ -1061,12 +1190,17 @@ impl Tier {
     pub fn is_noop(&self) -> bool {
         matches!(self, Tier::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Tier::Unknown(_))
+    }
 }
 
 /**
 * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CompanyStatus {
     #[serde(rename = "Approved")]
     Approved,
@@ -1076,8 +1210,8 @@ pub enum CompanyStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompanyStatus {
@@ -1087,12 +1221,37 @@ impl std::fmt::Display for CompanyStatus {
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
             CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CompanyStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompanyStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Approved" => CompanyStatus::Approved,
+            "Not Approved" => CompanyStatus::NotApproved,
+            "Suspended" => CompanyStatus::Suspended,
+            "" => CompanyStatus::Noop,
+            _ => CompanyStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for CompanyStatus {
     fn default() -> CompanyStatus {
         CompanyStatus::Noop
@@ -1102,6 +1261,11 @@ impl CompanyStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, CompanyStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CompanyStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1398,7 +1562,7 @@ pub struct Company {
 /**
 * The contractor's wage type, either "Fixed" or "Hourly".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WageType {
     #[serde(rename = "Fixed")]
     Fixed,
@@ -1406,8 +1570,8 @@ pub enum WageType {
     Hourly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WageType {
@@ -1416,12 +1580,36 @@ impl std::fmt::Display for WageType {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
             WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Fixed" => WageType::Fixed,
+            "Hourly" => WageType::Hourly,
+            "" => WageType::Noop,
+            _ => WageType::Unknown(s),
+        })
+    }
+}
+
 impl Default for WageType {
     fn default() -> WageType {
         WageType::Noop
@@ -1431,12 +1619,17 @@ impl WageType {
     pub fn is_noop(&self) -> bool {
         matches!(self, WageType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WageType::Unknown(_))
+    }
 }
 
 /**
 * The contractor's type, either "Individual" or "Business".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "Business")]
     Business,
@@ -1444,8 +1637,8 @@ pub enum Type {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1454,12 +1647,36 @@ impl std::fmt::Display for Type {
             Type::Business => "Business",
             Type::Individual => "Individual",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Business" => Type::Business,
+            "Individual" => Type::Individual,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1469,6 +1686,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// The contractor’s home address.
@@ -1651,7 +1873,7 @@ pub struct Contractor {
 /**
 * The payment method.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -1663,8 +1885,8 @@ pub enum PaymentMethod {
     HistoricalPayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethod {
@@ -1675,12 +1897,38 @@ impl std::fmt::Display for PaymentMethod {
             PaymentMethod::DirectDeposit => "Direct Deposit",
             PaymentMethod::HistoricalPayment => "Historical Payment",
             PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
+            PaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PaymentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Check" => PaymentMethod::Check,
+            "Correction Payment" => PaymentMethod::CorrectionPayment,
+            "Direct Deposit" => PaymentMethod::DirectDeposit,
+            "Historical Payment" => PaymentMethod::HistoricalPayment,
+            "" => PaymentMethod::Noop,
+            _ => PaymentMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for PaymentMethod {
     fn default() -> PaymentMethod {
         PaymentMethod::Noop
@@ -1690,6 +1938,11 @@ impl PaymentMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PaymentMethod::Unknown(_))
+    }
 }
 
 /// The representation of a single contractor payment.
@@ -1874,7 +2127,7 @@ pub struct ContractorPaymentSummary {
 /**
 * The status of the time off request.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "approved")]
     Approved,
@@ -1884,8 +2137,8 @@ pub enum Status {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1895,12 +2148,37 @@ impl std::fmt::Display for Status {
             Status::Denied => "denied",
             Status::Pending => "pending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approved" => Status::Approved,
+            "denied" => Status::Denied,
+            "pending" => Status::Pending,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1910,12 +2188,17 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /**
 * The type of time off request.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RequestType {
     #[serde(rename = "sick")]
     Sick,
@@ -1923,8 +2206,8 @@ pub enum RequestType {
     Vacation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestType {
@@ -1933,12 +2216,36 @@ impl std::fmt::Display for RequestType {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
             RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "sick" => RequestType::Sick,
+            "vacation" => RequestType::Vacation,
+            "" => RequestType::Noop,
+            _ => RequestType::Unknown(s),
+        })
+    }
+}
+
 impl Default for RequestType {
     fn default() -> RequestType {
         RequestType::Noop
@@ -1948,6 +2255,11 @@ impl RequestType {
     pub fn is_noop(&self) -> bool {
         matches!(self, RequestType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RequestType::Unknown(_))
+    }
 }
 
 /// An object that represents the days in the time off request. The keys of the object are the dates, formatted as a YYYY-MM-DD string. The values of the object are the number of hours requested off for each day, formatted as a string representation of a numeric decimal to the thousands place.
@@ -2161,7 +2473,7 @@ pub struct CurrentUser {
     pub roles: Option<Roles>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Frequency {
     #[serde(rename = "Every other week")]
     EveryOtherWeek,
@@ -2173,8 +2485,8 @@ pub enum Frequency {
     TwicePerMonth,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -2185,12 +2497,38 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Frequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Frequency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Every other week" => Frequency::EveryOtherWeek,
+            "Every week" => Frequency::EveryWeek,
+            "Monthly" => Frequency::Monthly,
+            "Twice per month" => Frequency::TwicePerMonth,
+            "" => Frequency::Noop,
+            _ => Frequency::Unknown(s),
+        })
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -2200,6 +2538,11 @@ impl Frequency {
     pub fn is_noop(&self) -> bool {
         matches!(self, Frequency::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Frequency::Unknown(_))
+    }
 }
 
 /// The representation of a pay schedule.
@@ -2274,7 +2617,7 @@ pub struct PaySchedule {
 /**
 * Bank account type
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountType {
     #[serde(rename = "Checking")]
     Checking,
@@ -2282,8 +2625,8 @@ pub enum AccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -2292,12 +2635,36 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Checking" => AccountType::Checking,
+            "Savings" => AccountType::Savings,
+            "" => AccountType::Noop,
+            _ => AccountType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountType {
     fn default() -> AccountType {
         AccountType::Noop
@@ -2307,6 +2674,11 @@ impl AccountType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AccountType::Unknown(_))
+    }
 }
 
 /**
@@ -2316,7 +2688,7 @@ impl AccountType {
 *   'ready_for_verification' means the micro-deposits are completed and the verification process can begin by using the verify endpoint.
 *   'verified' means the bank account is verified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum VerificationStatus {
     #[serde(rename = "awaiting_deposits")]
     AwaitingDeposits,
@@ -2326,8 +2698,8 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2337,12 +2709,37 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for VerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "awaiting_deposits" => VerificationStatus::AwaitingDeposits,
+            "ready_for_verification" => VerificationStatus::ReadyForVerification,
+            "verified" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2352,6 +2749,11 @@ impl VerificationStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, VerificationStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, VerificationStatus::Unknown(_))
+    }
 }
 
 /// The company bank account
@@ -2594,7 +2996,7 @@ pub struct EarningType {
 /**
 * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DeductionReducesTaxableIncome {
     #[serde(rename = "does_not_reduce_taxable_income")]
     DoesNotReduceTaxableIncome,
@@ -2602,8 +3004,8 @@ pub enum DeductionReducesTaxableIncome {
     ReducesTaxableIncome,
     #[serde(rename = "unset")]
     Unset,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeductionReducesTaxableIncome {
@@ -2614,17 +3016,49 @@ impl std::fmt::Display for DeductionReducesTaxableIncome {
             }
             DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
             DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
+            DeductionReducesTaxableIncome::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DeductionReducesTaxableIncome {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeductionReducesTaxableIncome {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "does_not_reduce_taxable_income" => {
+                DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome
+            }
+            "reduces_taxable_income" => DeductionReducesTaxableIncome::ReducesTaxableIncome,
+            "unset" => DeductionReducesTaxableIncome::Unset,
+            _ => DeductionReducesTaxableIncome::Unknown(s),
+        })
+    }
+}
+
 impl Default for DeductionReducesTaxableIncome {
     fn default() -> DeductionReducesTaxableIncome {
         DeductionReducesTaxableIncome::Unset
     }
 }
+impl DeductionReducesTaxableIncome {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DeductionReducesTaxableIncome::Unknown(_))
+    }
+}
 
 /// The representation of an employee benefit.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3070,7 +3504,7 @@ pub struct Totals {
 /**
 * The employee's compensation payment method. This value is only available for processed payrolls.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PayrollEmployeeCompensationsPaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -3078,8 +3512,8 @@ pub enum PayrollEmployeeCompensationsPaymentMethod {
     DirectDeposit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
@@ -3088,12 +3522,36 @@ impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
             PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
             PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
             PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PayrollEmployeeCompensationsPaymentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PayrollEmployeeCompensationsPaymentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Check" => PayrollEmployeeCompensationsPaymentMethod::Check,
+            "Direct Deposit" => PayrollEmployeeCompensationsPaymentMethod::DirectDeposit,
+            "" => PayrollEmployeeCompensationsPaymentMethod::Noop,
+            _ => PayrollEmployeeCompensationsPaymentMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for PayrollEmployeeCompensationsPaymentMethod {
     fn default() -> PayrollEmployeeCompensationsPaymentMethod {
         PayrollEmployeeCompensationsPaymentMethod::Noop
@@ -3103,6 +3561,11 @@ impl PayrollEmployeeCompensationsPaymentMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, PayrollEmployeeCompensationsPaymentMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PayrollEmployeeCompensationsPaymentMethod::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3468,7 +3931,7 @@ pub struct PayrollData {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CustomFieldType {
     #[serde(rename = "currency")]
     Currency,
@@ -3482,8 +3945,8 @@ pub enum CustomFieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomFieldType {
@@ -3495,12 +3958,39 @@ impl std::fmt::Display for CustomFieldType {
             CustomFieldType::Radio => "radio",
             CustomFieldType::Text => "text",
             CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
+            CustomFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CustomFieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomFieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "currency" => CustomFieldType::Currency,
+            "date" => CustomFieldType::Date,
+            "number" => CustomFieldType::Number,
+            "radio" => CustomFieldType::Radio,
+            "text" => CustomFieldType::Text,
+            "" => CustomFieldType::Noop,
+            _ => CustomFieldType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CustomFieldType {
     fn default() -> CustomFieldType {
         CustomFieldType::Noop
@@ -3510,6 +4000,11 @@ impl CustomFieldType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CustomFieldType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CustomFieldType::Unknown(_))
+    }
 }
 
 /// A custom field of an employee
@@ -3616,7 +4111,7 @@ pub struct CompanyCustomField {
     pub type_: CustomFieldType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GustoPersonType {
     #[serde(rename = "Candidate")]
     Candidate,
@@ -3626,8 +4121,8 @@ pub enum GustoPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GustoPersonType {
@@ -3637,12 +4132,37 @@ impl std::fmt::Display for GustoPersonType {
             GustoPersonType::Contractor => "Contractor",
             GustoPersonType::Employee => "Employee",
             GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
+            GustoPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GustoPersonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GustoPersonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Candidate" => GustoPersonType::Candidate,
+            "Contractor" => GustoPersonType::Contractor,
+            "Employee" => GustoPersonType::Employee,
+            "" => GustoPersonType::Noop,
+            _ => GustoPersonType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GustoPersonType {
     fn default() -> GustoPersonType {
         GustoPersonType::Noop
@@ -3652,6 +4172,11 @@ impl GustoPersonType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GustoPersonType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GustoPersonType::Unknown(_))
+    }
 }
 
 /// The representation of a job applicant in Gusto.
@@ -3870,14 +4395,14 @@ pub struct EarningTypeListResponse {
     pub default: Vec<EarningType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Include {
     #[serde(rename = "custom_fields")]
     CustomFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -3885,12 +4410,35 @@ impl std::fmt::Display for Include {
         match self {
             Include::CustomFields => "custom_fields",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Include {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Include {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "custom_fields" => Include::CustomFields,
+            "" => Include::Noop,
+            _ => Include::Unknown(s),
+        })
+    }
+}
+
 impl Default for Include {
     fn default() -> Include {
         Include::Noop
@@ -3900,6 +4448,11 @@ impl Include {
     pub fn is_noop(&self) -> bool {
         matches!(self, Include::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Include::Unknown(_))
+    }
 }
 
 ///
@@ -5065,7 +5618,7 @@ pub struct PutEmployeeBenefitRequest {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "benefits")]
     Benefits,
@@ -5075,8 +5628,8 @@ pub enum GetCompanyPayrollsInclude {
     Taxes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCompanyPayrollsInclude {
@@ -5086,12 +5639,37 @@ impl std::fmt::Display for GetCompanyPayrollsInclude {
             GetCompanyPayrollsInclude::Deductions => "deductions",
             GetCompanyPayrollsInclude::Taxes => "taxes",
             GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
+            GetCompanyPayrollsInclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetCompanyPayrollsInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCompanyPayrollsInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "benefits" => GetCompanyPayrollsInclude::Benefits,
+            "deductions" => GetCompanyPayrollsInclude::Deductions,
+            "taxes" => GetCompanyPayrollsInclude::Taxes,
+            "" => GetCompanyPayrollsInclude::Noop,
+            _ => GetCompanyPayrollsInclude::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetCompanyPayrollsInclude {
     fn default() -> GetCompanyPayrollsInclude {
         GetCompanyPayrollsInclude::Noop
@@ -5101,9 +5679,14 @@ impl GetCompanyPayrollsInclude {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCompanyPayrollsInclude::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetCompanyPayrollsInclude::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OffCycleReason {
     #[serde(rename = "Bonus")]
     Bonus,
@@ -5111,8 +5694,8 @@ pub enum OffCycleReason {
     Correction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OffCycleReason {
@@ -5121,12 +5704,36 @@ impl std::fmt::Display for OffCycleReason {
             OffCycleReason::Bonus => "Bonus",
             OffCycleReason::Correction => "Correction",
             OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
+            OffCycleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OffCycleReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OffCycleReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Bonus" => OffCycleReason::Bonus,
+            "Correction" => OffCycleReason::Correction,
+            "" => OffCycleReason::Noop,
+            _ => OffCycleReason::Unknown(s),
+        })
+    }
+}
+
 impl Default for OffCycleReason {
     fn default() -> OffCycleReason {
         OffCycleReason::Noop
@@ -5136,6 +5743,11 @@ impl OffCycleReason {
     pub fn is_noop(&self) -> bool {
         matches!(self, OffCycleReason::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OffCycleReason::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5594,7 +6206,7 @@ pub struct GetCompanyCustomFieldsResponse {
 /**
 * Must be "Employee" if send_offer is set to true.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OnboardingPersonType {
     #[serde(rename = "Contractor")]
     Contractor,
@@ -5602,8 +6214,8 @@ pub enum OnboardingPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OnboardingPersonType {
@@ -5612,12 +6224,36 @@ impl std::fmt::Display for OnboardingPersonType {
             OnboardingPersonType::Contractor => "Contractor",
             OnboardingPersonType::Employee => "Employee",
             OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+            OnboardingPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OnboardingPersonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OnboardingPersonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Contractor" => OnboardingPersonType::Contractor,
+            "Employee" => OnboardingPersonType::Employee,
+            "" => OnboardingPersonType::Noop,
+            _ => OnboardingPersonType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OnboardingPersonType {
     fn default() -> OnboardingPersonType {
         OnboardingPersonType::Noop
@@ -5627,6 +6263,11 @@ impl OnboardingPersonType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OnboardingPersonType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OnboardingPersonType::Unknown(_))
+    }
 }
 
 ///
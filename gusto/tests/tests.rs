@@ -135,12 +135,374 @@ fn test_deserialize_employee() {
             .unwrap()
             .clone()
             .to_string(),
-        // TODO: fix this better.
-        //"Commission Only Exempt".to_string()
-        "*".to_string()
+        "Commission Only Exempt".to_string()
     );
     assert_eq!(
         first_compensation.payment_unit.as_ref().unwrap().clone(),
         gusto_api::types::PaymentUnit::Year
     );
 }
+
+#[derive(Clone, Default)]
+struct MemoryTokenStore {
+    tokens: std::sync::Arc<std::sync::Mutex<Vec<gusto_api::AccessToken>>>,
+}
+
+#[async_trait::async_trait]
+impl gusto_api::TokenStore for MemoryTokenStore {
+    async fn store(&self, token: &gusto_api::AccessToken) -> anyhow::Result<()> {
+        self.tokens.lock().unwrap().push(token.clone());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_refreshes_and_stores_rotated_token_on_unauthorized() {
+    use wiremock::{
+        matchers::{bearer_token, body_string_contains, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("refresh_token=old-refresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "new-access",
+            "refresh_token": "rotated-refresh",
+            "expires_in": 7200,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(bearer_token("old-access"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(bearer_token("new-access"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "email": "isom@example.com" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let store = MemoryTokenStore::default();
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "old-access",
+        "old-refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_token_endpoint(format!("{}/oauth/token", server.uri()))
+        .set_auto_access_token_refresh(true)
+        .set_token_store(store.clone());
+
+    let me = client.current_user().get_me().await.unwrap();
+    assert_eq!(me.email, "isom@example.com");
+
+    let stored = store.tokens.lock().unwrap().clone();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].access_token, "new-access");
+    assert_eq!(stored[0].refresh_token, "rotated-refresh");
+
+    // The next refresh uses the rotated refresh token.
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("refresh_token=rotated-refresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "newer-access",
+            "expires_in": 7200,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let token = client.refresh_access_token().await.unwrap();
+    assert_eq!(token.access_token, "newer-access");
+    assert_eq!(token.refresh_token, "rotated-refresh");
+    assert_eq!(store.tokens.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_refreshes_once_for_concurrent_unauthorized_requests() {
+    use wiremock::{
+        matchers::{bearer_token, body_string_contains, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    // The refresh token is rotated, so only the first refresh can succeed.
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("refresh_token=old-refresh"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "access_token": "new-access",
+                    "refresh_token": "rotated-refresh",
+                    "expires_in": 7200,
+                }))
+                .set_delay(std::time::Duration::from_millis(100)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(bearer_token("old-access"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(bearer_token("new-access"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "email": "isom@example.com" })),
+        )
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let store = MemoryTokenStore::default();
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "old-access",
+        "old-refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_token_endpoint(format!("{}/oauth/token", server.uri()))
+        .set_auto_access_token_refresh(true)
+        .set_token_store(store.clone());
+
+    let current_user = client.current_user();
+    let (a, b, c) = tokio::join!(
+        current_user.get_me(),
+        current_user.get_me(),
+        current_user.get_me()
+    );
+    for me in [a, b, c] {
+        assert_eq!(me.unwrap().email, "isom@example.com");
+    }
+
+    let stored = store.tokens.lock().unwrap().clone();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].refresh_token, "rotated-refresh");
+}
+
+#[tokio::test]
+async fn test_keeps_the_token_when_a_refresh_is_rejected() {
+    use wiremock::{
+        matchers::{bearer_token, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(serde_json::json!({ "error": "invalid_grant" })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+    // Both requests go out with the token the client had, not an empty one.
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(bearer_token("old-access"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let store = MemoryTokenStore::default();
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "old-access",
+        "old-refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_token_endpoint(format!("{}/oauth/token", server.uri()))
+        .set_auto_access_token_refresh(true)
+        .set_token_store(store.clone());
+
+    for _ in 0..2 {
+        let err = client.current_user().get_me().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<gusto_api::ClientError>(),
+            Some(gusto_api::ClientError::AuthRefresh(_))
+        ));
+    }
+    assert!(store.tokens.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_revalidates_cached_responses() {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v1\"")
+                .set_body_json(serde_json::json!({ "email": "isom@example.com" })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "access",
+        "refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_http_cache(<dyn gusto_api::http_cache::HttpCache>::in_memory(10));
+
+    // The second response is a 304, answered from the cache.
+    for _ in 0..2 {
+        let me = client.current_user().get_me().await.unwrap();
+        assert_eq!(me.email, "isom@example.com");
+    }
+}
+
+#[tokio::test]
+async fn test_not_modified_without_cached_response_is_requested_again() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .respond_with(ResponseTemplate::new(304))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "email": "isom@example.com" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "access",
+        "refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_http_cache(<dyn gusto_api::http_cache::HttpCache>::in_memory(10));
+
+    let me = client.current_user().get_me().await.unwrap();
+    assert_eq!(me.email, "isom@example.com");
+}
+
+#[tokio::test]
+async fn test_sends_with_the_built_http_client() {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .and(header("user-agent", "gusto-tests"))
+        .and(header("x-request-source", "tests"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "email": "isom@example.com" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        "x-request-source",
+        reqwest::header::HeaderValue::from_static("tests"),
+    );
+    let http = gusto_api::ClientBuilder::new()
+        .timeout(std::time::Duration::from_secs(10))
+        .no_retries()
+        .user_agent("gusto-tests")
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let mut client = gusto_api::Client::new(
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "access",
+        "refresh",
+        gusto_api::RootDemoServer::default(),
+    );
+    client
+        .with_host_override(server.uri())
+        .with_http_client(http);
+
+    let me = client.current_user().get_me().await.unwrap();
+    assert_eq!(me.email, "isom@example.com");
+}
+
+#[test]
+fn test_invalid_user_agent_fails_to_build() {
+    let built = gusto_api::ClientBuilder::new()
+        .user_agent("gusto\ntests")
+        .build();
+    assert!(built.is_err());
+}
@@ -0,0 +1,3853 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`ActivityFeed`](crate::activity_feed::ActivityFeed).
+    pub fn activity_feed(&self) -> ActivityFeed<'_> {
+        ActivityFeed { mock: self }
+    }
+
+    /// Mock the functions of [`AuthorizedApps`](crate::authorized_apps::AuthorizedApps).
+    pub fn authorized_apps(&self) -> AuthorizedApps<'_> {
+        AuthorizedApps { mock: self }
+    }
+
+    /// Mock the functions of [`Automations`](crate::automations::Automations).
+    pub fn automations(&self) -> Automations<'_> {
+        Automations { mock: self }
+    }
+
+    /// Mock the functions of [`BatchWebhooks`](crate::batch_webhooks::BatchWebhooks).
+    pub fn batch_webhooks(&self) -> BatchWebhooks<'_> {
+        BatchWebhooks { mock: self }
+    }
+
+    /// Mock the functions of [`Batches`](crate::batches::Batches).
+    pub fn batches(&self) -> Batches<'_> {
+        Batches { mock: self }
+    }
+
+    /// Mock the functions of [`CampaignFolders`](crate::campaign_folders::CampaignFolders).
+    pub fn campaign_folders(&self) -> CampaignFolders<'_> {
+        CampaignFolders { mock: self }
+    }
+
+    /// Mock the functions of [`Campaigns`](crate::campaigns::Campaigns).
+    pub fn campaigns(&self) -> Campaigns<'_> {
+        Campaigns { mock: self }
+    }
+
+    /// Mock the functions of [`ConnectedSites`](crate::connected_sites::ConnectedSites).
+    pub fn connected_sites(&self) -> ConnectedSites<'_> {
+        ConnectedSites { mock: self }
+    }
+
+    /// Mock the functions of [`Conversations`](crate::conversations::Conversations).
+    pub fn conversations(&self) -> Conversations<'_> {
+        Conversations { mock: self }
+    }
+
+    /// Mock the functions of [`CustomerJourneys`](crate::customer_journeys::CustomerJourneys).
+    pub fn customer_journeys(&self) -> CustomerJourneys<'_> {
+        CustomerJourneys { mock: self }
+    }
+
+    /// Mock the functions of [`Ecommerce`](crate::ecommerce::Ecommerce).
+    pub fn ecommerce(&self) -> Ecommerce<'_> {
+        Ecommerce { mock: self }
+    }
+
+    /// Mock the functions of [`FacebookAds`](crate::facebook_ads::FacebookAds).
+    pub fn facebook_ads(&self) -> FacebookAds<'_> {
+        FacebookAds { mock: self }
+    }
+
+    /// Mock the functions of [`FileManager`](crate::file_manager::FileManager).
+    pub fn file_manager(&self) -> FileManager<'_> {
+        FileManager { mock: self }
+    }
+
+    /// Mock the functions of [`LandingPages`](crate::landing_pages::LandingPages).
+    pub fn landing_pages(&self) -> LandingPages<'_> {
+        LandingPages { mock: self }
+    }
+
+    /// Mock the functions of [`Lists`](crate::lists::Lists).
+    pub fn lists(&self) -> Lists<'_> {
+        Lists { mock: self }
+    }
+
+    /// Mock the functions of [`Ping`](crate::ping::Ping).
+    pub fn ping(&self) -> Ping<'_> {
+        Ping { mock: self }
+    }
+
+    /// Mock the functions of [`Reporting`](crate::reporting::Reporting).
+    pub fn reporting(&self) -> Reporting<'_> {
+        Reporting { mock: self }
+    }
+
+    /// Mock the functions of [`Reports`](crate::reports::Reports).
+    pub fn reports(&self) -> Reports<'_> {
+        Reports { mock: self }
+    }
+
+    /// Mock the functions of [`Root`](crate::root::Root).
+    pub fn root(&self) -> Root<'_> {
+        Root { mock: self }
+    }
+
+    /// Mock the functions of [`SearchCampaigns`](crate::search_campaigns::SearchCampaigns).
+    pub fn search_campaigns(&self) -> SearchCampaigns<'_> {
+        SearchCampaigns { mock: self }
+    }
+
+    /// Mock the functions of [`SearchMembers`](crate::search_members::SearchMembers).
+    pub fn search_members(&self) -> SearchMembers<'_> {
+        SearchMembers { mock: self }
+    }
+
+    /// Mock the functions of [`TemplateFolders`](crate::template_folders::TemplateFolders).
+    pub fn template_folders(&self) -> TemplateFolders<'_> {
+        TemplateFolders { mock: self }
+    }
+
+    /// Mock the functions of [`Templates`](crate::templates::Templates).
+    pub fn templates(&self) -> Templates<'_> {
+        Templates { mock: self }
+    }
+
+    /// Mock the functions of [`VerifiedDomains`](crate::verified_domains::VerifiedDomains).
+    pub fn verified_domains(&self) -> VerifiedDomains<'_> {
+        VerifiedDomains { mock: self }
+    }
+}
+
+pub struct ActivityFeed<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ActivityFeed<'a> {
+    /// Mock [`ActivityFeed::get_chimp_chatter`](crate::activity_feed::ActivityFeed::get_chimp_chatter), a `GET` to `/activity-feed/chimp-chatter`.
+    pub fn get_chimp_chatter(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetActivityFeedChimpChatterResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/activity-feed/chimp-chatter".to_string(),
+        )
+    }
+}
+
+pub struct AuthorizedApps<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AuthorizedApps<'a> {
+    /// Mock [`AuthorizedApps::get`](crate::authorized_apps::AuthorizedApps::get), a `GET` to `/authorized-apps`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetAuthorizedAppsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/authorized-apps".to_string())
+    }
+
+    /// Mock [`AuthorizedApps::get_authorized_apps`](crate::authorized_apps::AuthorizedApps::get_authorized_apps), a `GET` to `/authorized-apps/{app_id}`.
+    pub fn get_authorized_apps(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Apps> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/authorized-apps/{}",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+}
+
+pub struct Automations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Automations<'a> {
+    /// Mock [`Automations::get`](crate::automations::Automations::get), a `GET` to `/automations`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetAutomationsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/automations".to_string())
+    }
+
+    /// Mock [`Automations::post`](crate::automations::Automations::post), a `POST` to `/automations`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Automations> {
+        self.mock
+            .operation(reqwest::Method::POST, "/automations".to_string())
+    }
+
+    /// Mock [`Automations::get_automations`](crate::automations::Automations::get_automations), a `GET` to `/automations/{workflow_id}`.
+    pub fn get_automations(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Automations> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_actions_pause_all_email`](crate::automations::Automations::post_actions_pause_all_email), a `POST` to `/automations/{workflow_id}/actions/pause-all-emails`.
+    pub fn post_actions_pause_all_email(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/actions/pause-all-emails",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_actions_start_all_email`](crate::automations::Automations::post_actions_start_all_email), a `POST` to `/automations/{workflow_id}/actions/start-all-emails`.
+    pub fn post_actions_start_all_email(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/actions/start-all-emails",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::archive`](crate::automations::Automations::archive), a `POST` to `/automations/{workflow_id}/actions/archive`.
+    pub fn archive(&self, workflow_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/actions/archive",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_email`](crate::automations::Automations::get_email), a `GET` to `/automations/{workflow_id}/emails`.
+    pub fn get_email(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AutomationEmails> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/emails",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_email_automations`](crate::automations::Automations::get_email_automations), a `GET` to `/automations/{workflow_id}/emails/{workflow_email_id}`.
+    pub fn get_email_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Emails> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/emails/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::delete_emails`](crate::automations::Automations::delete_emails), a `DELETE` to `/automations/{workflow_id}/emails/{workflow_email_id}`.
+    pub fn delete_emails(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/automations/{}/emails/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::patch_email_workflow`](crate::automations::Automations::patch_email_workflow), a `PATCH` to `/automations/{workflow_id}/emails/{workflow_email_id}`.
+    pub fn patch_email_workflow(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Emails> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/automations/{}/emails/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_emails_queue`](crate::automations::Automations::get_emails_queue), a `GET` to `/automations/{workflow_id}/emails/{workflow_email_id}/queue`.
+    pub fn get_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAutomationsEmailsQueueResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/emails/{}/queue",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_emails_queue`](crate::automations::Automations::post_emails_queue), a `POST` to `/automations/{workflow_id}/emails/{workflow_email_id}/queue`.
+    pub fn post_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriberInAutomationQueueData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/emails/{}/queue",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_emails_queue_automations`](crate::automations::Automations::get_emails_queue_automations), a `GET` to `/automations/{workflow_id}/emails/{workflow_email_id}/queue/{subscriber_hash}`.
+    pub fn get_emails_queue_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriberInAutomationQueueData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/emails/{}/queue/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_emails_actions_pause`](crate::automations::Automations::post_emails_actions_pause), a `POST` to `/automations/{workflow_id}/emails/{workflow_email_id}/actions/pause`.
+    pub fn post_emails_actions_pause(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/emails/{}/actions/pause",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_emails_actions_start`](crate::automations::Automations::post_emails_actions_start), a `POST` to `/automations/{workflow_id}/emails/{workflow_email_id}/actions/start`.
+    pub fn post_emails_actions_start(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/emails/{}/actions/start",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(workflow_email_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_removed_subscriber`](crate::automations::Automations::get_removed_subscriber), a `GET` to `/automations/{workflow_id}/removed-subscribers`.
+    pub fn get_removed_subscriber(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RemovedSubscribers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/removed-subscribers",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::post_removed_subscriber`](crate::automations::Automations::post_removed_subscriber), a `POST` to `/automations/{workflow_id}/removed-subscribers`.
+    pub fn post_removed_subscriber(
+        &self,
+        workflow_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscribers> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/automations/{}/removed-subscribers",
+                crate::progenitor_support::encode_path(workflow_id)
+            ),
+        )
+    }
+
+    /// Mock [`Automations::get_removed_subscriber_automations`](crate::automations::Automations::get_removed_subscriber_automations), a `GET` to `/automations/{workflow_id}/removed-subscribers/{subscriber_hash}`.
+    pub fn get_removed_subscriber_automations(
+        &self,
+        workflow_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscribers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/automations/{}/removed-subscribers/{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+}
+
+pub struct BatchWebhooks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BatchWebhooks<'a> {
+    /// Mock [`BatchWebhooks::get`](crate::batch_webhooks::BatchWebhooks::get), a `GET` to `/batch-webhooks`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::BatchWebhooks> {
+        self.mock
+            .operation(reqwest::Method::GET, "/batch-webhooks".to_string())
+    }
+
+    /// Mock [`BatchWebhooks::post`](crate::batch_webhooks::BatchWebhooks::post), a `POST` to `/batch-webhooks`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Webhooks> {
+        self.mock
+            .operation(reqwest::Method::POST, "/batch-webhooks".to_string())
+    }
+
+    /// Mock [`BatchWebhooks::get_batch_webhooks`](crate::batch_webhooks::BatchWebhooks::get_batch_webhooks), a `GET` to `/batch-webhooks/{batch_webhook_id}`.
+    pub fn get_batch_webhooks(
+        &self,
+        batch_webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Webhooks> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/batch-webhooks/{}",
+                crate::progenitor_support::encode_path(batch_webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`BatchWebhooks::delete`](crate::batch_webhooks::BatchWebhooks::delete), a `DELETE` to `/batch-webhooks/{batch_webhook_id}`.
+    pub fn delete(&self, batch_webhook_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/batch-webhooks/{}",
+                crate::progenitor_support::encode_path(batch_webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`BatchWebhooks::patch`](crate::batch_webhooks::BatchWebhooks::patch), a `PATCH` to `/batch-webhooks/{batch_webhook_id}`.
+    pub fn patch(
+        &self,
+        batch_webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Webhooks> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/batch-webhooks/{}",
+                crate::progenitor_support::encode_path(batch_webhook_id)
+            ),
+        )
+    }
+}
+
+pub struct Batches<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Batches<'a> {
+    /// Mock [`Batches::get`](crate::batches::Batches::get), a `GET` to `/batches`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::BatchOperations> {
+        self.mock
+            .operation(reqwest::Method::GET, "/batches".to_string())
+    }
+
+    /// Mock [`Batches::post`](crate::batches::Batches::post), a `POST` to `/batches`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Batch> {
+        self.mock
+            .operation(reqwest::Method::POST, "/batches".to_string())
+    }
+
+    /// Mock [`Batches::get_batches`](crate::batches::Batches::get_batches), a `GET` to `/batches/{batch_id}`.
+    pub fn get_batches(
+        &self,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Batch> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/batches/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`Batches::delete`](crate::batches::Batches::delete), a `DELETE` to `/batches/{batch_id}`.
+    pub fn delete(&self, batch_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/batches/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+}
+
+pub struct CampaignFolders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CampaignFolders<'a> {
+    /// Mock [`CampaignFolders::get`](crate::campaign_folders::CampaignFolders::get), a `GET` to `/campaign-folders`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::CampaignFolders> {
+        self.mock
+            .operation(reqwest::Method::GET, "/campaign-folders".to_string())
+    }
+
+    /// Mock [`CampaignFolders::post`](crate::campaign_folders::CampaignFolders::post), a `POST` to `/campaign-folders`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::CampaignFolder> {
+        self.mock
+            .operation(reqwest::Method::POST, "/campaign-folders".to_string())
+    }
+
+    /// Mock [`CampaignFolders::get_campaign_folders`](crate::campaign_folders::CampaignFolders::get_campaign_folders), a `GET` to `/campaign-folders/{folder_id}`.
+    pub fn get_campaign_folders(
+        &self,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignFolder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaign-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`CampaignFolders::delete`](crate::campaign_folders::CampaignFolders::delete), a `DELETE` to `/campaign-folders/{folder_id}`.
+    pub fn delete(&self, folder_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/campaign-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`CampaignFolders::patch`](crate::campaign_folders::CampaignFolders::patch), a `PATCH` to `/campaign-folders/{folder_id}`.
+    pub fn patch(
+        &self,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignFolder> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/campaign-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+}
+
+pub struct Campaigns<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Campaigns<'a> {
+    /// Mock [`Campaigns::get`](crate::campaigns::Campaigns::get), a `GET` to `/campaigns`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetCampaignsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/campaigns".to_string())
+    }
+
+    /// Mock [`Campaigns::post`](crate::campaigns::Campaigns::post), a `POST` to `/campaigns`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Campaign> {
+        self.mock
+            .operation(reqwest::Method::POST, "/campaigns".to_string())
+    }
+
+    /// Mock [`Campaigns::get_campaigns`](crate::campaigns::Campaigns::get_campaigns), a `GET` to `/campaigns/{campaign_id}`.
+    pub fn get_campaigns(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Campaign> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::delete`](crate::campaigns::Campaigns::delete), a `DELETE` to `/campaigns/{campaign_id}`.
+    pub fn delete(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::patch`](crate::campaigns::Campaigns::patch), a `PATCH` to `/campaigns/{campaign_id}`.
+    pub fn patch(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Campaign> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_cancel_send`](crate::campaigns::Campaigns::post_actions_cancel_send), a `POST` to `/campaigns/{campaign_id}/actions/cancel-send`.
+    pub fn post_actions_cancel_send(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/cancel-send",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_replicate`](crate::campaigns::Campaigns::post_actions_replicate), a `POST` to `/campaigns/{campaign_id}/actions/replicate`.
+    pub fn post_actions_replicate(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignDataType> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/replicate",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_send`](crate::campaigns::Campaigns::post_actions_send), a `POST` to `/campaigns/{campaign_id}/actions/send`.
+    pub fn post_actions_send(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/send",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_schedule`](crate::campaigns::Campaigns::post_actions_schedule), a `POST` to `/campaigns/{campaign_id}/actions/schedule`.
+    pub fn post_actions_schedule(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/schedule",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_unschedule`](crate::campaigns::Campaigns::post_actions_unschedule), a `POST` to `/campaigns/{campaign_id}/actions/unschedule`.
+    pub fn post_actions_unschedule(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/unschedule",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_test`](crate::campaigns::Campaigns::post_actions_test), a `POST` to `/campaigns/{campaign_id}/actions/test`.
+    pub fn post_actions_test(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/test",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_pause`](crate::campaigns::Campaigns::post_actions_pause), a `POST` to `/campaigns/{campaign_id}/actions/pause`.
+    pub fn post_actions_pause(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/pause",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_resume`](crate::campaigns::Campaigns::post_actions_resume), a `POST` to `/campaigns/{campaign_id}/actions/resume`.
+    pub fn post_actions_resume(&self, campaign_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/resume",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_actions_create_resend`](crate::campaigns::Campaigns::post_actions_create_resend), a `POST` to `/campaigns/{campaign_id}/actions/create-resend`.
+    pub fn post_actions_create_resend(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignDataType> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/actions/create-resend",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::get_content`](crate::campaigns::Campaigns::get_content), a `GET` to `/campaigns/{campaign_id}/content`.
+    pub fn get_content(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignContent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}/content",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::put_content`](crate::campaigns::Campaigns::put_content), a `PUT` to `/campaigns/{campaign_id}/content`.
+    pub fn put_content(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignContent> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/campaigns/{}/content",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::get_feedback`](crate::campaigns::Campaigns::get_feedback), a `GET` to `/campaigns/{campaign_id}/feedback`.
+    pub fn get_feedback(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignReports> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}/feedback",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::post_feedback`](crate::campaigns::Campaigns::post_feedback), a `POST` to `/campaigns/{campaign_id}/feedback`.
+    pub fn post_feedback(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignFeedbackData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/feedback",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::get_feedback_campaigns`](crate::campaigns::Campaigns::get_feedback_campaigns), a `GET` to `/campaigns/{campaign_id}/feedback/{feedback_id}`.
+    pub fn get_feedback_campaigns(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignFeedbackData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}/feedback/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(feedback_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::delete_feedback`](crate::campaigns::Campaigns::delete_feedback), a `DELETE` to `/campaigns/{campaign_id}/feedback/{feedback_id}`.
+    pub fn delete_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/campaigns/{}/feedback/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(feedback_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::patch_feedback`](crate::campaigns::Campaigns::patch_feedback), a `PATCH` to `/campaigns/{campaign_id}/feedback/{feedback_id}`.
+    pub fn patch_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignFeedbackData> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/campaigns/{}/feedback/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(feedback_id)
+            ),
+        )
+    }
+
+    /// Mock [`Campaigns::get_send_checklist`](crate::campaigns::Campaigns::get_send_checklist), a `GET` to `/campaigns/{campaign_id}/send-checklist`.
+    pub fn get_send_checklist(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SendChecklist> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}/send-checklist",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+}
+
+pub struct ConnectedSites<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ConnectedSites<'a> {
+    /// Mock [`ConnectedSites::get`](crate::connected_sites::ConnectedSites::get), a `GET` to `/connected-sites`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::ConnectedSites> {
+        self.mock
+            .operation(reqwest::Method::GET, "/connected-sites".to_string())
+    }
+
+    /// Mock [`ConnectedSites::post`](crate::connected_sites::ConnectedSites::post), a `POST` to `/connected-sites`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Sites> {
+        self.mock
+            .operation(reqwest::Method::POST, "/connected-sites".to_string())
+    }
+
+    /// Mock [`ConnectedSites::get_connected_sites`](crate::connected_sites::ConnectedSites::get_connected_sites), a `GET` to `/connected-sites/{connected_site_id}`.
+    pub fn get_connected_sites(
+        &self,
+        connected_site_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Sites> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/connected-sites/{}",
+                crate::progenitor_support::encode_path(connected_site_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectedSites::delete`](crate::connected_sites::ConnectedSites::delete), a `DELETE` to `/connected-sites/{connected_site_id}`.
+    pub fn delete(&self, connected_site_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/connected-sites/{}",
+                crate::progenitor_support::encode_path(connected_site_id)
+            ),
+        )
+    }
+
+    /// Mock [`ConnectedSites::post_actions_verify_script_installation`](crate::connected_sites::ConnectedSites::post_actions_verify_script_installation), a `POST` to `/connected-sites/{connected_site_id}/actions/verify-script-installation`.
+    pub fn post_actions_verify_script_installation(
+        &self,
+        connected_site_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/connected-sites/{}/actions/verify-script-installation",
+                crate::progenitor_support::encode_path(connected_site_id)
+            ),
+        )
+    }
+}
+
+pub struct Conversations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Conversations<'a> {
+    /// Mock [`Conversations::get`](crate::conversations::Conversations::get), a `GET` to `/conversations`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::TrackedConversations> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations".to_string())
+    }
+
+    /// Mock [`Conversations::get_conversations`](crate::conversations::Conversations::get_conversations), a `GET` to `/conversations/{conversation_id}`.
+    pub fn get_conversations(
+        &self,
+        conversation_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Conversation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/conversations/{}",
+                crate::progenitor_support::encode_path(conversation_id)
+            ),
+        )
+    }
+
+    /// Mock [`Conversations::get_message`](crate::conversations::Conversations::get_message), a `GET` to `/conversations/{conversation_id}/messages`.
+    pub fn get_message(
+        &self,
+        conversation_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfConversationMessages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/conversations/{}/messages",
+                crate::progenitor_support::encode_path(conversation_id)
+            ),
+        )
+    }
+
+    /// Mock [`Conversations::get_message_conversations`](crate::conversations::Conversations::get_message_conversations), a `GET` to `/conversations/{conversation_id}/messages/{message_id}`.
+    pub fn get_message_conversations(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationMessage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/conversations/{}/messages/{}",
+                crate::progenitor_support::encode_path(conversation_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+}
+
+pub struct CustomerJourneys<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CustomerJourneys<'a> {
+    /// Mock [`CustomerJourneys::post_steps_actions_trigger`](crate::customer_journeys::CustomerJourneys::post_steps_actions_trigger), a `POST` to `/customer-journeys/journeys/{journey_id}/steps/{step_id}/actions/trigger`.
+    pub fn post_steps_actions_trigger(
+        &self,
+        journey_id: i64,
+        step_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/customer-journeys/journeys/{}/steps/{}/actions/trigger",
+                crate::progenitor_support::encode_path(&journey_id.to_string()),
+                crate::progenitor_support::encode_path(&step_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Ecommerce<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Ecommerce<'a> {
+    /// Mock [`Ecommerce::get_order`](crate::ecommerce::Ecommerce::get_order), a `GET` to `/ecommerce/orders`.
+    pub fn get_order(&self) -> crate::mock::MockOperation<'a, crate::types::OrdersData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ecommerce/orders".to_string())
+    }
+
+    /// Mock [`Ecommerce::get_store`](crate::ecommerce::Ecommerce::get_store), a `GET` to `/ecommerce/stores`.
+    pub fn get_store(&self) -> crate::mock::MockOperation<'a, crate::types::ECommerceStores> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ecommerce/stores".to_string())
+    }
+
+    /// Mock [`Ecommerce::post_store`](crate::ecommerce::Ecommerce::post_store), a `POST` to `/ecommerce/stores`.
+    pub fn post_store(&self) -> crate::mock::MockOperation<'a, crate::types::Stores> {
+        self.mock
+            .operation(reqwest::Method::POST, "/ecommerce/stores".to_string())
+    }
+
+    /// Mock [`Ecommerce::get_store_ecommerce`](crate::ecommerce::Ecommerce::get_store_ecommerce), a `GET` to `/ecommerce/stores/{store_id}`.
+    pub fn get_store_ecommerce(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Stores> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores`](crate::ecommerce::Ecommerce::delete_stores), a `DELETE` to `/ecommerce/stores/{store_id}`.
+    pub fn delete_stores(&self, store_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores`](crate::ecommerce::Ecommerce::patch_stores), a `PATCH` to `/ecommerce/stores/{store_id}`.
+    pub fn patch_stores(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Stores> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_cart`](crate::ecommerce::Ecommerce::get_stores_cart), a `GET` to `/ecommerce/stores/{store_id}/carts`.
+    pub fn get_stores_cart(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CartsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/carts",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_cart`](crate::ecommerce::Ecommerce::post_stores_cart), a `POST` to `/ecommerce/stores/{store_id}/carts`.
+    pub fn post_stores_cart(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Carts> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/carts",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_cart_ecommerce`](crate::ecommerce::Ecommerce::get_stores_cart_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/carts/{cart_id}`.
+    pub fn get_stores_cart_ecommerce(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Carts> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/carts/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_carts`](crate::ecommerce::Ecommerce::delete_stores_carts), a `DELETE` to `/ecommerce/stores/{store_id}/carts/{cart_id}`.
+    pub fn delete_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/carts/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_carts`](crate::ecommerce::Ecommerce::patch_stores_carts), a `PATCH` to `/ecommerce/stores/{store_id}/carts/{cart_id}`.
+    pub fn patch_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Carts> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/carts/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_carts_line`](crate::ecommerce::Ecommerce::get_stores_carts_line), a `GET` to `/ecommerce/stores/{store_id}/carts/{cart_id}/lines`.
+    pub fn get_stores_carts_line(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CartLines> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/carts/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_carts_line`](crate::ecommerce::Ecommerce::post_stores_carts_line), a `POST` to `/ecommerce/stores/{store_id}/carts/{cart_id}/lines`.
+    pub fn post_stores_carts_line(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceCartLineItem> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/carts/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_carts_line_ecommerce`](crate::ecommerce::Ecommerce::get_stores_carts_line_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}`.
+    pub fn get_stores_carts_line_ecommerce(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceCartLineItem> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_carts_lines`](crate::ecommerce::Ecommerce::delete_stores_carts_lines), a `DELETE` to `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}`.
+    pub fn delete_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_carts_lines`](crate::ecommerce::Ecommerce::patch_stores_carts_lines), a `PATCH` to `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}`.
+    pub fn patch_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceCartLineItem> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_customer`](crate::ecommerce::Ecommerce::get_stores_customer), a `GET` to `/ecommerce/stores/{store_id}/customers`.
+    pub fn get_stores_customer(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/customers",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_customer`](crate::ecommerce::Ecommerce::post_stores_customer), a `POST` to `/ecommerce/stores/{store_id}/customers`.
+    pub fn post_stores_customer(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/customers",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_customer_ecommerce`](crate::ecommerce::Ecommerce::get_stores_customer_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/customers/{customer_id}`.
+    pub fn get_stores_customer_ecommerce(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/customers/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::put_stores_customers`](crate::ecommerce::Ecommerce::put_stores_customers), a `PUT` to `/ecommerce/stores/{store_id}/customers/{customer_id}`.
+    pub fn put_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/ecommerce/stores/{}/customers/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_customers`](crate::ecommerce::Ecommerce::delete_stores_customers), a `DELETE` to `/ecommerce/stores/{store_id}/customers/{customer_id}`.
+    pub fn delete_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/customers/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_customers`](crate::ecommerce::Ecommerce::patch_stores_customers), a `PATCH` to `/ecommerce/stores/{store_id}/customers/{customer_id}`.
+    pub fn patch_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/customers/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_promorule`](crate::ecommerce::Ecommerce::get_stores_promorule), a `GET` to `/ecommerce/stores/{store_id}/promo-rules`.
+    pub fn get_stores_promorule(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoRulesData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/promo-rules",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_promorule`](crate::ecommerce::Ecommerce::post_stores_promorule), a `POST` to `/ecommerce/stores/{store_id}/promo-rules`.
+    pub fn post_stores_promorule(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoRules> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/promo-rules",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_promorule_ecommerce`](crate::ecommerce::Ecommerce::get_stores_promorule_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}`.
+    pub fn get_stores_promorule_ecommerce(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoRules> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_promorules`](crate::ecommerce::Ecommerce::delete_stores_promorules), a `DELETE` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}`.
+    pub fn delete_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_promorules`](crate::ecommerce::Ecommerce::patch_stores_promorules), a `PATCH` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}`.
+    pub fn patch_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoRules> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_promocode`](crate::ecommerce::Ecommerce::get_stores_promocode), a `GET` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes`.
+    pub fn get_stores_promocode(
+        &self,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoCodesData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_promocode`](crate::ecommerce::Ecommerce::post_stores_promocode), a `POST` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes`.
+    pub fn post_stores_promocode(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoCodes> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_promocode_ecommerce`](crate::ecommerce::Ecommerce::get_stores_promocode_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}`.
+    pub fn get_stores_promocode_ecommerce(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoCodes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                crate::progenitor_support::encode_path(promo_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_promocodes`](crate::ecommerce::Ecommerce::delete_stores_promocodes), a `DELETE` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}`.
+    pub fn delete_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                crate::progenitor_support::encode_path(promo_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_promocodes`](crate::ecommerce::Ecommerce::patch_stores_promocodes), a `PATCH` to `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}`.
+    pub fn patch_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromoCodes> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                crate::progenitor_support::encode_path(promo_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_order`](crate::ecommerce::Ecommerce::get_stores_order), a `GET` to `/ecommerce/stores/{store_id}/orders`.
+    pub fn get_stores_order(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersDataType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/orders",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_order`](crate::ecommerce::Ecommerce::post_stores_order), a `POST` to `/ecommerce/stores/{store_id}/orders`.
+    pub fn post_stores_order(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Orders> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/orders",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_order_ecommerce`](crate::ecommerce::Ecommerce::get_stores_order_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/orders/{order_id}`.
+    pub fn get_stores_order_ecommerce(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Orders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/orders/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_orders`](crate::ecommerce::Ecommerce::delete_stores_orders), a `DELETE` to `/ecommerce/stores/{store_id}/orders/{order_id}`.
+    pub fn delete_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/orders/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_orders`](crate::ecommerce::Ecommerce::patch_stores_orders), a `PATCH` to `/ecommerce/stores/{store_id}/orders/{order_id}`.
+    pub fn patch_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Orders> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/orders/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_orders_line`](crate::ecommerce::Ecommerce::get_stores_orders_line), a `GET` to `/ecommerce/stores/{store_id}/orders/{order_id}/lines`.
+    pub fn get_stores_orders_line(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrderLines> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/orders/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_orders_line`](crate::ecommerce::Ecommerce::post_stores_orders_line), a `POST` to `/ecommerce/stores/{store_id}/orders/{order_id}/lines`.
+    pub fn post_stores_orders_line(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Lines> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/orders/{}/lines",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_orders_line_ecommerce`](crate::ecommerce::Ecommerce::get_stores_orders_line_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}`.
+    pub fn get_stores_orders_line_ecommerce(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Lines> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_orders_lines`](crate::ecommerce::Ecommerce::delete_stores_orders_lines), a `DELETE` to `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}`.
+    pub fn delete_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_orders_lines`](crate::ecommerce::Ecommerce::patch_stores_orders_lines), a `PATCH` to `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}`.
+    pub fn patch_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Lines> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(line_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_product`](crate::ecommerce::Ecommerce::get_stores_product), a `GET` to `/ecommerce/stores/{store_id}/products`.
+    pub fn get_stores_product(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProductsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_product`](crate::ecommerce::Ecommerce::post_stores_product), a `POST` to `/ecommerce/stores/{store_id}/products`.
+    pub fn post_stores_product(
+        &self,
+        store_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceProduct> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/products",
+                crate::progenitor_support::encode_path(store_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_product_ecommerce`](crate::ecommerce::Ecommerce::get_stores_product_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/products/{product_id}`.
+    pub fn get_stores_product_ecommerce(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceProduct> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_products`](crate::ecommerce::Ecommerce::delete_stores_products), a `DELETE` to `/ecommerce/stores/{store_id}/products/{product_id}`.
+    pub fn delete_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/products/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_products`](crate::ecommerce::Ecommerce::patch_stores_products), a `PATCH` to `/ecommerce/stores/{store_id}/products/{product_id}`.
+    pub fn patch_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ECommerceProduct> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/products/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_products_variant`](crate::ecommerce::Ecommerce::get_stores_products_variant), a `GET` to `/ecommerce/stores/{store_id}/products/{product_id}/variants`.
+    pub fn get_stores_products_variant(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EcommerceProductVariants> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_products_variant`](crate::ecommerce::Ecommerce::post_stores_products_variant), a `POST` to `/ecommerce/stores/{store_id}/products/{product_id}/variants`.
+    pub fn post_stores_products_variant(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Variants> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_products_variant_ecommerce`](crate::ecommerce::Ecommerce::get_stores_products_variant_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}`.
+    pub fn get_stores_products_variant_ecommerce(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Variants> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::put_stores_products_variants`](crate::ecommerce::Ecommerce::put_stores_products_variants), a `PUT` to `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}`.
+    pub fn put_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Variants> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_products_variants`](crate::ecommerce::Ecommerce::delete_stores_products_variants), a `DELETE` to `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}`.
+    pub fn delete_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_products_variants`](crate::ecommerce::Ecommerce::patch_stores_products_variants), a `PATCH` to `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}`.
+    pub fn patch_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Variants> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_products_image`](crate::ecommerce::Ecommerce::get_stores_products_image), a `GET` to `/ecommerce/stores/{store_id}/products/{product_id}/images`.
+    pub fn get_stores_products_image(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EcommerceProductImages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products/{}/images",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::post_stores_products_image`](crate::ecommerce::Ecommerce::post_stores_products_image), a `POST` to `/ecommerce/stores/{store_id}/products/{product_id}/images`.
+    pub fn post_stores_products_image(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Images> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ecommerce/stores/{}/products/{}/images",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::get_stores_products_image_ecommerce`](crate::ecommerce::Ecommerce::get_stores_products_image_ecommerce), a `GET` to `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}`.
+    pub fn get_stores_products_image_ecommerce(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Images> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ecommerce/stores/{}/products/{}/images/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::delete_stores_products_images`](crate::ecommerce::Ecommerce::delete_stores_products_images), a `DELETE` to `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}`.
+    pub fn delete_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ecommerce/stores/{}/products/{}/images/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+
+    /// Mock [`Ecommerce::patch_stores_products_images`](crate::ecommerce::Ecommerce::patch_stores_products_images), a `PATCH` to `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}`.
+    pub fn patch_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Images> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/ecommerce/stores/{}/products/{}/images/{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+}
+
+pub struct FacebookAds<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FacebookAds<'a> {
+    /// Mock [`FacebookAds::get_all`](crate::facebook_ads::FacebookAds::get_all), a `GET` to `/facebook-ads`.
+    pub fn get_all(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAllFacebookAdsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/facebook-ads".to_string())
+    }
+
+    /// Mock [`FacebookAds::get`](crate::facebook_ads::FacebookAds::get), a `GET` to `/facebook-ads/{outreach_id}`.
+    pub fn get(
+        &self,
+        outreach_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FacebookAdsAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/facebook-ads/{}",
+                crate::progenitor_support::encode_path(outreach_id)
+            ),
+        )
+    }
+}
+
+pub struct FileManager<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FileManager<'a> {
+    /// Mock [`FileManager::get_file`](crate::file_manager::FileManager::get_file), a `GET` to `/file-manager/files`.
+    pub fn get_file(&self) -> crate::mock::MockOperation<'a, crate::types::FileManager> {
+        self.mock
+            .operation(reqwest::Method::GET, "/file-manager/files".to_string())
+    }
+
+    /// Mock [`FileManager::post`](crate::file_manager::FileManager::post), a `POST` to `/file-manager/files`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock
+            .operation(reqwest::Method::POST, "/file-manager/files".to_string())
+    }
+
+    /// Mock [`FileManager::get_file_file_manager`](crate::file_manager::FileManager::get_file_file_manager), a `GET` to `/file-manager/files/{file_id}`.
+    pub fn get_file_file_manager(
+        &self,
+        file_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/file-manager/files/{}",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`FileManager::delete_files`](crate::file_manager::FileManager::delete_files), a `DELETE` to `/file-manager/files/{file_id}`.
+    pub fn delete_files(&self, file_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/file-manager/files/{}",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`FileManager::patch_files`](crate::file_manager::FileManager::patch_files), a `PATCH` to `/file-manager/files/{file_id}`.
+    pub fn patch_files(
+        &self,
+        file_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/file-manager/files/{}",
+                crate::progenitor_support::encode_path(file_id)
+            ),
+        )
+    }
+
+    /// Mock [`FileManager::get_folder`](crate::file_manager::FileManager::get_folder), a `GET` to `/file-manager/folders`.
+    pub fn get_folder(&self) -> crate::mock::MockOperation<'a, crate::types::FileManagerFolders> {
+        self.mock
+            .operation(reqwest::Method::GET, "/file-manager/folders".to_string())
+    }
+
+    /// Mock [`FileManager::post_folder`](crate::file_manager::FileManager::post_folder), a `POST` to `/file-manager/folders`.
+    pub fn post_folder(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileManagerFoldersGalleryFolder> {
+        self.mock
+            .operation(reqwest::Method::POST, "/file-manager/folders".to_string())
+    }
+
+    /// Mock [`FileManager::get_folder_file_manager`](crate::file_manager::FileManager::get_folder_file_manager), a `GET` to `/file-manager/folders/{folder_id}`.
+    pub fn get_folder_file_manager(
+        &self,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileManagerFoldersGalleryFolder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/file-manager/folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`FileManager::delete_folders`](crate::file_manager::FileManager::delete_folders), a `DELETE` to `/file-manager/folders/{folder_id}`.
+    pub fn delete_folders(&self, folder_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/file-manager/folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`FileManager::patch_folders`](crate::file_manager::FileManager::patch_folders), a `PATCH` to `/file-manager/folders/{folder_id}`.
+    pub fn patch_folders(
+        &self,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FileManagerFoldersGalleryFolder> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/file-manager/folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+}
+
+pub struct LandingPages<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> LandingPages<'a> {
+    /// Mock [`LandingPages::get_all`](crate::landing_pages::LandingPages::get_all), a `GET` to `/landing-pages`.
+    pub fn get_all(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAllLandingPagesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/landing-pages".to_string())
+    }
+
+    /// Mock [`LandingPages::post_all`](crate::landing_pages::LandingPages::post_all), a `POST` to `/landing-pages`.
+    pub fn post_all(&self) -> crate::mock::MockOperation<'a, crate::types::LandingPage> {
+        self.mock
+            .operation(reqwest::Method::POST, "/landing-pages".to_string())
+    }
+
+    /// Mock [`LandingPages::get`](crate::landing_pages::LandingPages::get), a `GET` to `/landing-pages/{page_id}`.
+    pub fn get(&self, page_id: &str) -> crate::mock::MockOperation<'a, crate::types::LandingPage> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/landing-pages/{}",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+
+    /// Mock [`LandingPages::delete`](crate::landing_pages::LandingPages::delete), a `DELETE` to `/landing-pages/{page_id}`.
+    pub fn delete(&self, page_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/landing-pages/{}",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+
+    /// Mock [`LandingPages::patch`](crate::landing_pages::LandingPages::patch), a `PATCH` to `/landing-pages/{page_id}`.
+    pub fn patch(
+        &self,
+        page_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LandingPage> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/landing-pages/{}",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+
+    /// Mock [`LandingPages::post_actions_publish`](crate::landing_pages::LandingPages::post_actions_publish), a `POST` to `/landing-pages/{page_id}/actions/publish`.
+    pub fn post_actions_publish(
+        &self,
+        page_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LandingPage> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/landing-pages/{}/actions/publish",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+
+    /// Mock [`LandingPages::post_actions_unpublish`](crate::landing_pages::LandingPages::post_actions_unpublish), a `POST` to `/landing-pages/{page_id}/actions/unpublish`.
+    pub fn post_actions_unpublish(&self, page_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/landing-pages/{}/actions/unpublish",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+
+    /// Mock [`LandingPages::get_content`](crate::landing_pages::LandingPages::get_content), a `GET` to `/landing-pages/{page_id}/content`.
+    pub fn get_content(
+        &self,
+        page_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LandingPageContent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/landing-pages/{}/content",
+                crate::progenitor_support::encode_path(page_id)
+            ),
+        )
+    }
+}
+
+pub struct Lists<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Lists<'a> {
+    /// Mock [`Lists::get`](crate::lists::Lists::get), a `GET` to `/lists`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::SubscriberLists> {
+        self.mock
+            .operation(reqwest::Method::GET, "/lists".to_string())
+    }
+
+    /// Mock [`Lists::post`](crate::lists::Lists::post), a `POST` to `/lists`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Lists> {
+        self.mock
+            .operation(reqwest::Method::POST, "/lists".to_string())
+    }
+
+    /// Mock [`Lists::get_lists`](crate::lists::Lists::get_lists), a `GET` to `/lists/{list_id}`.
+    pub fn get_lists(&self, list_id: &str) -> crate::mock::MockOperation<'a, crate::types::Lists> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/lists/{}", crate::progenitor_support::encode_path(list_id)),
+        )
+    }
+
+    /// Mock [`Lists::post_lists`](crate::lists::Lists::post_lists), a `POST` to `/lists/{list_id}`.
+    pub fn post_lists(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchUpdateListMembers> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/lists/{}", crate::progenitor_support::encode_path(list_id)),
+        )
+    }
+
+    /// Mock [`Lists::delete`](crate::lists::Lists::delete), a `DELETE` to `/lists/{list_id}`.
+    pub fn delete(&self, list_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/lists/{}", crate::progenitor_support::encode_path(list_id)),
+        )
+    }
+
+    /// Mock [`Lists::patch`](crate::lists::Lists::patch), a `PATCH` to `/lists/{list_id}`.
+    pub fn patch(&self, list_id: &str) -> crate::mock::MockOperation<'a, crate::types::Lists> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/lists/{}", crate::progenitor_support::encode_path(list_id)),
+        )
+    }
+
+    /// Mock [`Lists::get_abuse_report`](crate::lists::Lists::get_abuse_report), a `GET` to `/lists/{list_id}/abuse-reports`.
+    pub fn get_abuse_report(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AbuseComplaints> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/abuse-reports",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_abuse_report_lists`](crate::lists::Lists::get_abuse_report_lists), a `GET` to `/lists/{list_id}/abuse-reports/{report_id}`.
+    pub fn get_abuse_report_lists(
+        &self,
+        list_id: &str,
+        report_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AbuseReports> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/abuse-reports/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(report_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_activity`](crate::lists::Lists::get_activity), a `GET` to `/lists/{list_id}/activity`.
+    pub fn get_activity(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListActivity> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/activity",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_client`](crate::lists::Lists::get_client), a `GET` to `/lists/{list_id}/clients`.
+    pub fn get_client(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailClients> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/clients",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_growth_history`](crate::lists::Lists::get_growth_history), a `GET` to `/lists/{list_id}/growth-history`.
+    pub fn get_growth_history(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GrowthHistory> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/growth-history",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_growth_history_lists`](crate::lists::Lists::get_growth_history_lists), a `GET` to `/lists/{list_id}/growth-history/{month}`.
+    pub fn get_growth_history_lists(
+        &self,
+        list_id: &str,
+        month: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::History> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/growth-history/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(month)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_interest_categorie`](crate::lists::Lists::get_interest_categorie), a `GET` to `/lists/{list_id}/interest-categories`.
+    pub fn get_interest_categorie(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InterestGroupings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/interest-categories",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_interest_categorie`](crate::lists::Lists::post_interest_categorie), a `POST` to `/lists/{list_id}/interest-categories`.
+    pub fn post_interest_categorie(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Categories> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/interest-categories",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_interest_categorie_lists`](crate::lists::Lists::get_interest_categorie_lists), a `GET` to `/lists/{list_id}/interest-categories/{interest_category_id}`.
+    pub fn get_interest_categorie_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Categories> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/interest-categories/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_interest_categories`](crate::lists::Lists::delete_interest_categories), a `DELETE` to `/lists/{list_id}/interest-categories/{interest_category_id}`.
+    pub fn delete_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/interest-categories/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_interest_categories`](crate::lists::Lists::patch_interest_categories), a `PATCH` to `/lists/{list_id}/interest-categories/{interest_category_id}`.
+    pub fn patch_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Categories> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/interest-categories/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_interest_categories_interest`](crate::lists::Lists::get_interest_categories_interest), a `GET` to `/lists/{list_id}/interest-categories/{interest_category_id}/interests`.
+    pub fn get_interest_categories_interest(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InterestsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/interest-categories/{}/interests",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_interest_categories`](crate::lists::Lists::post_interest_categories), a `POST` to `/lists/{list_id}/interest-categories/{interest_category_id}/interests`.
+    pub fn post_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InterestsInterest> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/interest-categories/{}/interests",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_interest_categories_interest_lists`](crate::lists::Lists::get_interest_categories_interest_lists), a `GET` to `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}`.
+    pub fn get_interest_categories_interest_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InterestsInterest> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_interest_categories_interests`](crate::lists::Lists::delete_interest_categories_interests), a `DELETE` to `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}`.
+    pub fn delete_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_interest_categories_interests`](crate::lists::Lists::patch_interest_categories_interests), a `PATCH` to `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}`.
+    pub fn patch_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InterestsInterest> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::preview_segment`](crate::lists::Lists::preview_segment), a `GET` to `/lists/{list_id}/segments`.
+    pub fn preview_segment(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfSegments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/segments",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_segment`](crate::lists::Lists::post_segment), a `POST` to `/lists/{list_id}/segments`.
+    pub fn post_segment(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Segments> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/segments",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_segment`](crate::lists::Lists::get_segment), a `GET` to `/lists/{list_id}/segments/{segment_id}`.
+    pub fn get_segment(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Segments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/segments/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_segment_lists`](crate::lists::Lists::post_segment_lists), a `POST` to `/lists/{list_id}/segments/{segment_id}`.
+    pub fn post_segment_lists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BatchAddRemoveListMembersFromStaticSegment>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/segments/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_segments`](crate::lists::Lists::delete_segments), a `DELETE` to `/lists/{list_id}/segments/{segment_id}`.
+    pub fn delete_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/segments/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_segments`](crate::lists::Lists::patch_segments), a `PATCH` to `/lists/{list_id}/segments/{segment_id}`.
+    pub fn patch_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Segments> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/segments/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_segments_member`](crate::lists::Lists::get_segments_member), a `GET` to `/lists/{list_id}/segments/{segment_id}/members`.
+    pub fn get_segments_member(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SegmentMembers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/segments/{}/members",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_segments_member`](crate::lists::Lists::post_segments_member), a `POST` to `/lists/{list_id}/segments/{segment_id}/members`.
+    pub fn post_segments_member(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembers> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/segments/{}/members",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_segments_members`](crate::lists::Lists::delete_segments_members), a `DELETE` to `/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}`.
+    pub fn delete_segments_members(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/segments/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::search_tags_name`](crate::lists::Lists::search_tags_name), a `GET` to `/lists/{list_id}/tag-search`.
+    pub fn search_tags_name(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TagSearchResults> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/tag-search",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_member`](crate::lists::Lists::get_member), a `GET` to `/lists/{list_id}/members`.
+    pub fn get_member(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembersDataType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_member`](crate::lists::Lists::post_member), a `POST` to `/lists/{list_id}/members`.
+    pub fn post_member(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembersData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/members",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_member_lists`](crate::lists::Lists::get_member_lists), a `GET` to `/lists/{list_id}/members/{subscriber_hash}`.
+    pub fn get_member_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembersData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::put_members`](crate::lists::Lists::put_members), a `PUT` to `/lists/{list_id}/members/{subscriber_hash}`.
+    pub fn put_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembersData> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_members`](crate::lists::Lists::delete_members), a `DELETE` to `/lists/{list_id}/members/{subscriber_hash}`.
+    pub fn delete_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_members`](crate::lists::Lists::patch_members), a `PATCH` to `/lists/{list_id}/members/{subscriber_hash}`.
+    pub fn patch_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMembersData> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/members/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_activity`](crate::lists::Lists::get_members_activity), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/activity`.
+    pub fn get_members_activity(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MemberActivityEvents> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/activity",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_activity_feed`](crate::lists::Lists::get_members_activity_feed), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/activity-feed`.
+    pub fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MemberActivityEventsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/activity-feed",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_member_tag`](crate::lists::Lists::get_member_tag), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/tags`.
+    pub fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfTags> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/tags",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_member_tag`](crate::lists::Lists::post_member_tag), a `POST` to `/lists/{list_id}/members/{subscriber_hash}/tags`.
+    pub fn post_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/members/{}/tags",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_event`](crate::lists::Lists::get_members_event), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/events`.
+    pub fn get_members_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfEvents> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/events",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_member_event`](crate::lists::Lists::post_member_event), a `POST` to `/lists/{list_id}/members/{subscriber_hash}/events`.
+    pub fn post_member_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/members/{}/events",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_goal`](crate::lists::Lists::get_members_goal), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/goals`.
+    pub fn get_members_goal(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfMemberActivityEvents> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/goals",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_note`](crate::lists::Lists::get_members_note), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/notes`.
+    pub fn get_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfNotes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/notes",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_members_note`](crate::lists::Lists::post_members_note), a `POST` to `/lists/{list_id}/members/{subscriber_hash}/notes`.
+    pub fn post_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfNotesMember> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/members/{}/notes",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_members_note_lists`](crate::lists::Lists::get_members_note_lists), a `GET` to `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}`.
+    pub fn get_members_note_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfNotesMember> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/members/{}/notes/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                crate::progenitor_support::encode_path(note_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_members_notes`](crate::lists::Lists::delete_members_notes), a `DELETE` to `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}`.
+    pub fn delete_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/members/{}/notes/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                crate::progenitor_support::encode_path(note_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_members_notes`](crate::lists::Lists::patch_members_notes), a `PATCH` to `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}`.
+    pub fn patch_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfNotesMember> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/members/{}/notes/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                crate::progenitor_support::encode_path(note_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_members_hash_actions_delete_permanent`](crate::lists::Lists::post_members_hash_actions_delete_permanent), a `POST` to `/lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent`.
+    pub fn post_members_hash_actions_delete_permanent(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/members/{}/actions/delete-permanent",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_merge_field`](crate::lists::Lists::get_merge_field), a `GET` to `/lists/{list_id}/merge-fields`.
+    pub fn get_merge_field(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CollectionOfMergeFields> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/merge-fields",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_merge_field`](crate::lists::Lists::post_merge_field), a `POST` to `/lists/{list_id}/merge-fields`.
+    pub fn post_merge_field(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MergeField> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/merge-fields",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_merge_field_lists`](crate::lists::Lists::get_merge_field_lists), a `GET` to `/lists/{list_id}/merge-fields/{merge_id}`.
+    pub fn get_merge_field_lists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MergeField> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/merge-fields/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(merge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_merge_fields`](crate::lists::Lists::delete_merge_fields), a `DELETE` to `/lists/{list_id}/merge-fields/{merge_id}`.
+    pub fn delete_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/merge-fields/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(merge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_merge_fields`](crate::lists::Lists::patch_merge_fields), a `PATCH` to `/lists/{list_id}/merge-fields/{merge_id}`.
+    pub fn patch_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MergeField> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/merge-fields/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(merge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_webhook`](crate::lists::Lists::get_webhook), a `GET` to `/lists/{list_id}/webhooks`.
+    pub fn get_webhook(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebhooksData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/webhooks",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_webhook`](crate::lists::Lists::post_webhook), a `POST` to `/lists/{list_id}/webhooks`.
+    pub fn post_webhook(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebhooks> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/webhooks",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_webhook_lists`](crate::lists::Lists::get_webhook_lists), a `GET` to `/lists/{list_id}/webhooks/{webhook_id}`.
+    pub fn get_webhook_lists(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebhooks> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/webhooks/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_webhooks`](crate::lists::Lists::delete_webhooks), a `DELETE` to `/lists/{list_id}/webhooks/{webhook_id}`.
+    pub fn delete_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/lists/{}/webhooks/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_webhooks`](crate::lists::Lists::patch_webhooks), a `PATCH` to `/lists/{list_id}/webhooks/{webhook_id}`.
+    pub fn patch_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebhooks> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/lists/{}/webhooks/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_signup_form`](crate::lists::Lists::get_signup_form), a `GET` to `/lists/{list_id}/signup-forms`.
+    pub fn get_signup_form(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSignupForms> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/signup-forms",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::post_signup_form`](crate::lists::Lists::post_signup_form), a `POST` to `/lists/{list_id}/signup-forms`.
+    pub fn post_signup_form(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SignupForm> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/lists/{}/signup-forms",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_location`](crate::lists::Lists::get_location), a `GET` to `/lists/{list_id}/locations`.
+    pub fn get_location(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListLocations> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/lists/{}/locations",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+}
+
+pub struct Ping<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Ping<'a> {
+    /// Mock [`Ping::get`](crate::ping::Ping::get), a `GET` to `/ping`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::ApiHealthStatus> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ping".to_string())
+    }
+}
+
+pub struct Reporting<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reporting<'a> {
+    /// Mock [`Reporting::get_facebook_ads`](crate::reporting::Reporting::get_facebook_ads), a `GET` to `/reporting/facebook-ads`.
+    pub fn get_facebook_ads(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportingFacebookAdsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reporting/facebook-ads".to_string())
+    }
+
+    /// Mock [`Reporting::get_facebook_ad`](crate::reporting::Reporting::get_facebook_ad), a `GET` to `/reporting/facebook-ads/{outreach_id}`.
+    pub fn get_facebook_ad(
+        &self,
+        outreach_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportingFacebookAdsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reporting/facebook-ads/{}",
+                crate::progenitor_support::encode_path(outreach_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reporting::get_facebook_ads_ecommerce_product_activity`](crate::reporting::Reporting::get_facebook_ads_ecommerce_product_activity), a `GET` to `/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity`.
+    pub fn get_facebook_ads_ecommerce_product_activity(
+        &self,
+        outreach_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportsEcommerceProductActivityResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reporting/facebook-ads/{}/ecommerce-product-activity",
+                crate::progenitor_support::encode_path(outreach_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reporting::get_landing_page`](crate::reporting::Reporting::get_landing_page), a `GET` to `/reporting/landing-pages/{outreach_id}`.
+    pub fn get_landing_page(
+        &self,
+        outreach_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LandingPages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reporting/landing-pages/{}",
+                crate::progenitor_support::encode_path(outreach_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reporting::get_landing_pages`](crate::reporting::Reporting::get_landing_pages), a `GET` to `/reporting/landing-pages`.
+    pub fn get_landing_pages(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportingLandingPagesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reporting/landing-pages".to_string())
+    }
+}
+
+pub struct Reports<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reports<'a> {
+    /// Mock [`Reports::get`](crate::reports::Reports::get), a `GET` to `/reports`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::CampaignReportsData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reports".to_string())
+    }
+
+    /// Mock [`Reports::get_reports`](crate::reports::Reports::get_reports), a `GET` to `/reports/{campaign_id}`.
+    pub fn get_reports(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reports> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_abuse`](crate::reports::Reports::get_abuse), a `GET` to `/reports/{campaign_id}/abuse-reports`.
+    pub fn get_abuse(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AbuseComplaintsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/abuse-reports",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_abuse_reports`](crate::reports::Reports::get_abuse_reports), a `GET` to `/reports/{campaign_id}/abuse-reports/{report_id}`.
+    pub fn get_abuse_reports(
+        &self,
+        campaign_id: &str,
+        report_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AbuseComplaint> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/abuse-reports/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(report_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_advice`](crate::reports::Reports::get_advice), a `GET` to `/reports/{campaign_id}/advice`.
+    pub fn get_advice(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignAdviceReport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/advice",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_click_detail`](crate::reports::Reports::get_click_detail), a `GET` to `/reports/{campaign_id}/click-details`.
+    pub fn get_click_detail(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClickDetailReport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/click-details",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_click_detail_reports`](crate::reports::Reports::get_click_detail_reports), a `GET` to `/reports/{campaign_id}/click-details/{link_id}`.
+    pub fn get_click_detail_reports(
+        &self,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UrlsClicked> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/click-details/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_click_details_member`](crate::reports::Reports::get_click_details_member), a `GET` to `/reports/{campaign_id}/click-details/{link_id}/members`.
+    pub fn get_click_details_member(
+        &self,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClickDetailMembers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/click-details/{}/members",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_click_details_member_reports`](crate::reports::Reports::get_click_details_member_reports), a `GET` to `/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}`.
+    pub fn get_click_details_member_reports(
+        &self,
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClickDetailMember> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/click-details/{}/members/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_open_detail`](crate::reports::Reports::get_open_detail), a `GET` to `/reports/{campaign_id}/open-details`.
+    pub fn get_open_detail(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OpenDetailReport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/open-details",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_open_details_member`](crate::reports::Reports::get_open_details_member), a `GET` to `/reports/{campaign_id}/open-details/{subscriber_hash}`.
+    pub fn get_open_details_member(
+        &self,
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OpenActivity> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/open-details/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_domain_performance`](crate::reports::Reports::get_domain_performance), a `GET` to `/reports/{campaign_id}/domain-performance`.
+    pub fn get_domain_performance(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainPerformance> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/domain-performance",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_eepurl`](crate::reports::Reports::get_eepurl), a `GET` to `/reports/{campaign_id}/eepurl`.
+    pub fn get_eepurl(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EepurlActivity> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/eepurl",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_email_activity`](crate::reports::Reports::get_email_activity), a `GET` to `/reports/{campaign_id}/email-activity`.
+    pub fn get_email_activity(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailActivityData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/email-activity",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_email_activity_reports`](crate::reports::Reports::get_email_activity_reports), a `GET` to `/reports/{campaign_id}/email-activity/{subscriber_hash}`.
+    pub fn get_email_activity_reports(
+        &self,
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EmailActivity> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/email-activity/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_location`](crate::reports::Reports::get_location), a `GET` to `/reports/{campaign_id}/locations`.
+    pub fn get_location(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OpenLocationsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/locations",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_sent`](crate::reports::Reports::get_sent), a `GET` to `/reports/{campaign_id}/sent-to`.
+    pub fn get_sent(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SentData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/sent-to",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_sent_reports`](crate::reports::Reports::get_sent_reports), a `GET` to `/reports/{campaign_id}/sent-to/{subscriber_hash}`.
+    pub fn get_sent_reports(
+        &self,
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SentTo> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/sent-to/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_sub`](crate::reports::Reports::get_sub), a `GET` to `/reports/{campaign_id}/sub-reports`.
+    pub fn get_sub(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignSubReports> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/sub-reports",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_unsubscribed`](crate::reports::Reports::get_unsubscribed), a `GET` to `/reports/{campaign_id}/unsubscribed`.
+    pub fn get_unsubscribed(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UnsubscribesData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/unsubscribed",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_unsubscribed_reports`](crate::reports::Reports::get_unsubscribed_reports), a `GET` to `/reports/{campaign_id}/unsubscribed/{subscriber_hash}`.
+    pub fn get_unsubscribed_reports(
+        &self,
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Unsubscribes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/unsubscribed/{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::get_ecommerce_product_activity`](crate::reports::Reports::get_ecommerce_product_activity), a `GET` to `/reports/{campaign_id}/ecommerce-product-activity`.
+    pub fn get_ecommerce_product_activity(
+        &self,
+        campaign_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportsEcommerceProductActivityResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reports/{}/ecommerce-product-activity",
+                crate::progenitor_support::encode_path(campaign_id)
+            ),
+        )
+    }
+}
+
+pub struct Root<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Root<'a> {
+    /// Mock [`Root::get`](crate::root::Root::get), a `GET` to `/`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::ApiRoot> {
+        self.mock.operation(reqwest::Method::GET, "/".to_string())
+    }
+}
+
+pub struct SearchCampaigns<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SearchCampaigns<'a> {
+    /// Mock [`SearchCampaigns::get`](crate::search_campaigns::SearchCampaigns::get), a `GET` to `/search-campaigns`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Campaigns> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search-campaigns".to_string())
+    }
+}
+
+pub struct SearchMembers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SearchMembers<'a> {
+    /// Mock [`SearchMembers::get`](crate::search_members::SearchMembers::get), a `GET` to `/search-members`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::MembersData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search-members".to_string())
+    }
+}
+
+pub struct TemplateFolders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TemplateFolders<'a> {
+    /// Mock [`TemplateFolders::get`](crate::template_folders::TemplateFolders::get), a `GET` to `/template-folders`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::TemplateFolders> {
+        self.mock
+            .operation(reqwest::Method::GET, "/template-folders".to_string())
+    }
+
+    /// Mock [`TemplateFolders::post`](crate::template_folders::TemplateFolders::post), a `POST` to `/template-folders`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Folders> {
+        self.mock
+            .operation(reqwest::Method::POST, "/template-folders".to_string())
+    }
+
+    /// Mock [`TemplateFolders::get_template_folders`](crate::template_folders::TemplateFolders::get_template_folders), a `GET` to `/template-folders/{folder_id}`.
+    pub fn get_template_folders(
+        &self,
+        folder_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Folders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/template-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateFolders::delete`](crate::template_folders::TemplateFolders::delete), a `DELETE` to `/template-folders/{folder_id}`.
+    pub fn delete(&self, folder_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/template-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+
+    /// Mock [`TemplateFolders::patch`](crate::template_folders::TemplateFolders::patch), a `PATCH` to `/template-folders/{folder_id}`.
+    pub fn patch(&self, folder_id: &str) -> crate::mock::MockOperation<'a, crate::types::Folders> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/template-folders/{}",
+                crate::progenitor_support::encode_path(folder_id)
+            ),
+        )
+    }
+}
+
+pub struct Templates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Templates<'a> {
+    /// Mock [`Templates::get`](crate::templates::Templates::get), a `GET` to `/templates`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::TemplatesData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/templates".to_string())
+    }
+
+    /// Mock [`Templates::post`](crate::templates::Templates::post), a `POST` to `/templates`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Templates> {
+        self.mock
+            .operation(reqwest::Method::POST, "/templates".to_string())
+    }
+
+    /// Mock [`Templates::get_templates`](crate::templates::Templates::get_templates), a `GET` to `/templates/{template_id}`.
+    pub fn get_templates(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Templates> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::delete`](crate::templates::Templates::delete), a `DELETE` to `/templates/{template_id}`.
+    pub fn delete(&self, template_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::patch`](crate::templates::Templates::patch), a `PATCH` to `/templates/{template_id}`.
+    pub fn patch(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Templates> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::get_default_content`](crate::templates::Templates::get_default_content), a `GET` to `/templates/{template_id}/default-content`.
+    pub fn get_default_content(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TemplateDefaultContent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/templates/{}/default-content",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct VerifiedDomains<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> VerifiedDomains<'a> {
+    /// Mock [`VerifiedDomains::get`](crate::verified_domains::VerifiedDomains::get), a `GET` to `/verified-domains/{domain_name}`.
+    pub fn get(
+        &self,
+        domain_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifiedDomains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/verified-domains/{}",
+                crate::progenitor_support::encode_path(domain_name)
+            ),
+        )
+    }
+
+    /// Mock [`VerifiedDomains::delete`](crate::verified_domains::VerifiedDomains::delete), a `DELETE` to `/verified-domains/{domain_name}`.
+    pub fn delete(&self, domain_name: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/verified-domains/{}",
+                crate::progenitor_support::encode_path(domain_name)
+            ),
+        )
+    }
+
+    /// Mock [`VerifiedDomains::verify_domain`](crate::verified_domains::VerifiedDomains::verify_domain), a `POST` to `/verified-domains/{domain_name}/actions/verify`.
+    pub fn verify_domain(
+        &self,
+        domain_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifiedDomains> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/verified-domains/{}/actions/verify",
+                crate::progenitor_support::encode_path(domain_name)
+            ),
+        )
+    }
+
+    /// Mock [`VerifiedDomains::get_verified_domains`](crate::verified_domains::VerifiedDomains::get_verified_domains), a `GET` to `/verified-domains`.
+    pub fn get_verified_domains(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifiedDomainsData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/verified-domains".to_string())
+    }
+
+    /// Mock [`VerifiedDomains::create`](crate::verified_domains::VerifiedDomains::create), a `POST` to `/verified-domains`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::VerifiedDomains> {
+        self.mock
+            .operation(reqwest::Method::POST, "/verified-domains".to_string())
+    }
+}
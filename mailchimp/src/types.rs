@@ -88,7 +88,7 @@ pub struct Interest {
 /**
 * Determines how this category’s interests appear on signup forms.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "checkboxes")]
     Checkboxes,
@@ -100,8 +100,8 @@ pub enum Type {
     Radio,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -112,12 +112,38 @@ impl std::fmt::Display for Type {
             Type::Hidden => "hidden",
             Type::Radio => "radio",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "checkboxes" => Type::Checkboxes,
+            "dropdown" => Type::Dropdown,
+            "hidden" => Type::Hidden,
+            "radio" => Type::Radio,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -127,6 +153,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// Interest categories organize interests, which are used to group subscribers based on their preferences. These correspond to Group Titles the application.
@@ -366,7 +397,7 @@ pub struct ECommerceProductVariant {
 /**
 * The type of pricing plan the account is on.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PricingPlanType {
     #[serde(rename = "forever_free")]
     ForeverFree,
@@ -376,8 +407,8 @@ pub enum PricingPlanType {
     PayAsYouGo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PricingPlanType {
@@ -387,12 +418,37 @@ impl std::fmt::Display for PricingPlanType {
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
             PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PricingPlanType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PricingPlanType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "forever_free" => PricingPlanType::ForeverFree,
+            "monthly" => PricingPlanType::Monthly,
+            "pay_as_you_go" => PricingPlanType::PayAsYouGo,
+            "" => PricingPlanType::Noop,
+            _ => PricingPlanType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PricingPlanType {
     fn default() -> PricingPlanType {
         PricingPlanType::Noop
@@ -402,6 +458,11 @@ impl PricingPlanType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PricingPlanType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PricingPlanType::Unknown(_))
+    }
 }
 
 /// Information about the account contact.
@@ -509,7 +570,7 @@ pub struct IndustryStats {
 /**
 * The HTTP method that should be used when accessing the URL defined in 'href'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Method {
     #[serde(rename = "DELETE")]
     Delete,
@@ -527,8 +588,8 @@ pub enum Method {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -542,12 +603,41 @@ impl std::fmt::Display for Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DELETE" => Method::Delete,
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "OPTIONS" => Method::Options,
+            "PATCH" => Method::Patch,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "" => Method::Noop,
+            _ => Method::Unknown(s),
+        })
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -557,6 +647,11 @@ impl Method {
     pub fn is_noop(&self) -> bool {
         matches!(self, Method::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Method::Unknown(_))
+    }
 }
 
 /// This object represents a link from the resource where it is found to another resource or action that may be performed.
@@ -782,7 +877,7 @@ pub struct ApiRoot {
 /**
 * The type of activity
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "campaigns:facebook-likes")]
     CampaignsFacebookLikes,
@@ -798,8 +893,8 @@ pub enum GetActivityFeedChimpChatterResponseType {
     ListsUnsubscribes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
@@ -816,12 +911,44 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
             GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetActivityFeedChimpChatterResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetActivityFeedChimpChatterResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "campaigns:facebook-likes" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes
+            }
+            "campaigns:forward-to-friend" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend
+            }
+            "lists:imports" => GetActivityFeedChimpChatterResponseType::ListsImports,
+            "lists:new-subscriber" => GetActivityFeedChimpChatterResponseType::ListsNewSubscriber,
+            "lists:profile-updates" => GetActivityFeedChimpChatterResponseType::ListsProfileUpdates,
+            "lists:unsubscribes" => GetActivityFeedChimpChatterResponseType::ListsUnsubscribes,
+            "" => GetActivityFeedChimpChatterResponseType::Noop,
+            _ => GetActivityFeedChimpChatterResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetActivityFeedChimpChatterResponseType {
     fn default() -> GetActivityFeedChimpChatterResponseType {
         GetActivityFeedChimpChatterResponseType::Noop
@@ -831,6 +958,11 @@ impl GetActivityFeedChimpChatterResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetActivityFeedChimpChatterResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetActivityFeedChimpChatterResponseType::Unknown(_))
+    }
 }
 
 /// A Chimp Chatter message
@@ -1017,7 +1149,7 @@ pub struct GetAuthorizedAppsResponse {
 /**
 * Restrict the results to automations with the specified status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "paused")]
     Paused,
@@ -1027,8 +1159,8 @@ pub enum Status {
     Sending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1038,12 +1170,37 @@ impl std::fmt::Display for Status {
             Status::Save => "save",
             Status::Sending => "sending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "paused" => Status::Paused,
+            "save" => Status::Save,
+            "sending" => Status::Sending,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1053,12 +1210,17 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /**
 * Segment match type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Match {
     #[serde(rename = "all")]
     All,
@@ -1066,8 +1228,8 @@ pub enum Match {
     Any,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Match {
@@ -1076,12 +1238,36 @@ impl std::fmt::Display for Match {
             Match::All => "all",
             Match::Any => "any",
             Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Match {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Match {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => Match::All,
+            "any" => Match::Any,
+            "" => Match::Noop,
+            _ => Match::Unknown(s),
+        })
+    }
+}
+
 impl Default for Match {
     fn default() -> Match {
         Match::Noop
@@ -1091,16 +1277,21 @@ impl Match {
     pub fn is_noop(&self) -> bool {
         matches!(self, Match::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Match::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ConditionType {
     #[serde(rename = "Aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConditionType {
@@ -1108,12 +1299,35 @@ impl std::fmt::Display for ConditionType {
         match self {
             ConditionType::Aim => "Aim",
             ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Aim" => ConditionType::Aim,
+            "" => ConditionType::Noop,
+            _ => ConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ConditionType {
     fn default() -> ConditionType {
         ConditionType::Noop
@@ -1123,19 +1337,24 @@ impl ConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by interaction with a specific campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Field {
     #[serde(rename = "aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Field {
@@ -1143,12 +1362,35 @@ impl std::fmt::Display for Field {
         match self {
             Field::Aim => "aim",
             Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "aim" => Field::Aim,
+            "" => Field::Noop,
+            _ => Field::Unknown(s),
+        })
+    }
+}
+
 impl Default for Field {
     fn default() -> Field {
         Field::Noop
@@ -1158,12 +1400,17 @@ impl Field {
     pub fn is_noop(&self) -> bool {
         matches!(self, Field::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Field::Unknown(_))
+    }
 }
 
 /**
 * The status of the member with regard to their campaign interaction. One of the following: opened, clicked, was sent, didn't open, didn't click, or was not sent.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Op {
     #[serde(rename = "click")]
     Click,
@@ -1179,8 +1426,8 @@ pub enum Op {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -1193,12 +1440,40 @@ impl std::fmt::Display for Op {
             Op::Open => "open",
             Op::Sent => "sent",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "click" => Op::Click,
+            "noclick" => Op::Noclick,
+            "noopen" => Op::Noopen,
+            "nosent" => Op::Nosent,
+            "open" => Op::Open,
+            "sent" => Op::Sent,
+            "" => Op::Noop,
+            _ => Op::Unknown(s),
+        })
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -1208,6 +1483,11 @@ impl Op {
     pub fn is_noop(&self) -> bool {
         matches!(self, Op::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Op::Unknown(_))
+    }
 }
 
 /// Segment by interaction with a specific campaign.
@@ -1239,14 +1519,14 @@ pub struct Conditions {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AutomationSegmentConditionType {
     #[serde(rename = "Automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutomationSegmentConditionType {
@@ -1254,12 +1534,35 @@ impl std::fmt::Display for AutomationSegmentConditionType {
         match self {
             AutomationSegmentConditionType::Automation => "Automation",
             AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AutomationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AutomationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Automation" => AutomationSegmentConditionType::Automation,
+            "" => AutomationSegmentConditionType::Noop,
+            _ => AutomationSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AutomationSegmentConditionType {
     fn default() -> AutomationSegmentConditionType {
         AutomationSegmentConditionType::Noop
@@ -1269,19 +1572,24 @@ impl AutomationSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AutomationSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AutomationSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by interaction with an Automation workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SegmentField {
     #[serde(rename = "automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentField {
@@ -1289,12 +1597,35 @@ impl std::fmt::Display for SegmentField {
         match self {
             SegmentField::Automation => "automation",
             SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "automation" => SegmentField::Automation,
+            "" => SegmentField::Noop,
+            _ => SegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SegmentField {
     fn default() -> SegmentField {
         SegmentField::Noop
@@ -1304,12 +1635,17 @@ impl SegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SegmentField::Unknown(_))
+    }
 }
 
 /**
 * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SegmentOperator {
     #[serde(rename = "completed")]
     Completed,
@@ -1321,8 +1657,8 @@ pub enum SegmentOperator {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentOperator {
@@ -1333,12 +1669,38 @@ impl std::fmt::Display for SegmentOperator {
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
             SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "completed" => SegmentOperator::Completed,
+            "not_completed" => SegmentOperator::NotCompleted,
+            "not_started" => SegmentOperator::NotStarted,
+            "started" => SegmentOperator::Started,
+            "" => SegmentOperator::Noop,
+            _ => SegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SegmentOperator {
     fn default() -> SegmentOperator {
         SegmentOperator::Noop
@@ -1348,6 +1710,11 @@ impl SegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by interaction with an Automation workflow.
@@ -1379,14 +1746,14 @@ pub struct AutomationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PollActivitySegmentConditionType {
     #[serde(rename = "CampaignPoll")]
     CampaignPoll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentConditionType {
@@ -1394,12 +1761,35 @@ impl std::fmt::Display for PollActivitySegmentConditionType {
         match self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
             PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PollActivitySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CampaignPoll" => PollActivitySegmentConditionType::CampaignPoll,
+            "" => PollActivitySegmentConditionType::Noop,
+            _ => PollActivitySegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PollActivitySegmentConditionType {
     fn default() -> PollActivitySegmentConditionType {
         PollActivitySegmentConditionType::Noop
@@ -1409,19 +1799,24 @@ impl PollActivitySegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PollActivitySegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by poll activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PollActivitySegmentField {
     #[serde(rename = "poll")]
     Poll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentField {
@@ -1429,12 +1824,35 @@ impl std::fmt::Display for PollActivitySegmentField {
         match self {
             PollActivitySegmentField::Poll => "poll",
             PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PollActivitySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "poll" => PollActivitySegmentField::Poll,
+            "" => PollActivitySegmentField::Noop,
+            _ => PollActivitySegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for PollActivitySegmentField {
     fn default() -> PollActivitySegmentField {
         PollActivitySegmentField::Noop
@@ -1444,12 +1862,17 @@ impl PollActivitySegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PollActivitySegmentField::Unknown(_))
+    }
 }
 
 /**
 * Members have/have not interacted with a specific poll in a Mailchimp email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PollActivitySegmentOperator {
     #[serde(rename = "member")]
     Member,
@@ -1457,8 +1880,8 @@ pub enum PollActivitySegmentOperator {
     Notmember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentOperator {
@@ -1467,12 +1890,36 @@ impl std::fmt::Display for PollActivitySegmentOperator {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
             PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PollActivitySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "member" => PollActivitySegmentOperator::Member,
+            "notmember" => PollActivitySegmentOperator::Notmember,
+            "" => PollActivitySegmentOperator::Noop,
+            _ => PollActivitySegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for PollActivitySegmentOperator {
     fn default() -> PollActivitySegmentOperator {
         PollActivitySegmentOperator::Noop
@@ -1482,6 +1929,11 @@ impl PollActivitySegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PollActivitySegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by poll activity.
@@ -1513,14 +1965,14 @@ pub struct PollActivitySegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ConversationSegmentConditionType {
     #[serde(rename = "Conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentConditionType {
@@ -1528,12 +1980,35 @@ impl std::fmt::Display for ConversationSegmentConditionType {
         match self {
             ConversationSegmentConditionType::Conversation => "Conversation",
             ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ConversationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ConversationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Conversation" => ConversationSegmentConditionType::Conversation,
+            "" => ConversationSegmentConditionType::Noop,
+            _ => ConversationSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ConversationSegmentConditionType {
     fn default() -> ConversationSegmentConditionType {
         ConversationSegmentConditionType::Noop
@@ -1543,19 +2018,24 @@ impl ConversationSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ConversationSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ConversationSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by interaction with a campaign via Conversations.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ConversationSegmentField {
     #[serde(rename = "conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentField {
@@ -1563,12 +2043,35 @@ impl std::fmt::Display for ConversationSegmentField {
         match self {
             ConversationSegmentField::Conversation => "conversation",
             ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ConversationSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ConversationSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "conversation" => ConversationSegmentField::Conversation,
+            "" => ConversationSegmentField::Noop,
+            _ => ConversationSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for ConversationSegmentField {
     fn default() -> ConversationSegmentField {
         ConversationSegmentField::Noop
@@ -1578,6 +2081,11 @@ impl ConversationSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, ConversationSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ConversationSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by interaction with a campaign via Conversations.
@@ -1609,14 +2117,14 @@ pub struct ConversationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateSegmentConditionType {
     #[serde(rename = "Date")]
     Date,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentConditionType {
@@ -1624,12 +2132,35 @@ impl std::fmt::Display for DateSegmentConditionType {
         match self {
             DateSegmentConditionType::Date => "Date",
             DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Date" => DateSegmentConditionType::Date,
+            "" => DateSegmentConditionType::Noop,
+            _ => DateSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateSegmentConditionType {
     fn default() -> DateSegmentConditionType {
         DateSegmentConditionType::Noop
@@ -1639,12 +2170,17 @@ impl DateSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateSegmentField {
     #[serde(rename = "ecomm_date")]
     EcommDate,
@@ -1654,8 +2190,8 @@ pub enum DateSegmentField {
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentField {
@@ -1665,12 +2201,37 @@ impl std::fmt::Display for DateSegmentField {
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
             DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_date" => DateSegmentField::EcommDate,
+            "info_changed" => DateSegmentField::InfoChanged,
+            "timestamp_opt" => DateSegmentField::TimestampOpt,
+            "" => DateSegmentField::Noop,
+            _ => DateSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateSegmentField {
     fn default() -> DateSegmentField {
         DateSegmentField::Noop
@@ -1680,12 +2241,17 @@ impl DateSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateSegmentField::Unknown(_))
+    }
 }
 
 /**
 * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -1705,8 +2271,8 @@ pub enum DateSegmentOperator {
     Within,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentOperator {
@@ -1721,12 +2287,42 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
             DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => DateSegmentOperator::Blank,
+            "blank_not" => DateSegmentOperator::BlankNot,
+            "greater" => DateSegmentOperator::Greater,
+            "is" => DateSegmentOperator::Is,
+            "less" => DateSegmentOperator::Less,
+            "not" => DateSegmentOperator::Not,
+            "notwithin" => DateSegmentOperator::Notwithin,
+            "within" => DateSegmentOperator::Within,
+            "" => DateSegmentOperator::Noop,
+            _ => DateSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateSegmentOperator {
     fn default() -> DateSegmentOperator {
         DateSegmentOperator::Noop
@@ -1736,6 +2332,11 @@ impl DateSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a specific date field.
@@ -1776,14 +2377,14 @@ pub struct DateSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailClientSegmentConditionType {
     #[serde(rename = "EmailClient")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentConditionType {
@@ -1791,12 +2392,35 @@ impl std::fmt::Display for EmailClientSegmentConditionType {
         match self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
             EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailClientSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EmailClient" => EmailClientSegmentConditionType::EmailClient,
+            "" => EmailClientSegmentConditionType::Noop,
+            _ => EmailClientSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailClientSegmentConditionType {
     fn default() -> EmailClientSegmentConditionType {
         EmailClientSegmentConditionType::Noop
@@ -1806,19 +2430,24 @@ impl EmailClientSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailClientSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by use of a particular email client.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailClientSegmentField {
     #[serde(rename = "email_client")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentField {
@@ -1826,12 +2455,35 @@ impl std::fmt::Display for EmailClientSegmentField {
         match self {
             EmailClientSegmentField::EmailClient => "email_client",
             EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailClientSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "email_client" => EmailClientSegmentField::EmailClient,
+            "" => EmailClientSegmentField::Noop,
+            _ => EmailClientSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailClientSegmentField {
     fn default() -> EmailClientSegmentField {
         EmailClientSegmentField::Noop
@@ -1841,12 +2493,17 @@ impl EmailClientSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailClientSegmentField::Unknown(_))
+    }
 }
 
 /**
 * The operation to determine whether we select clients that match the value, or clients that do not match the value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailClientSegmentOperator {
     #[serde(rename = "client_is")]
     ClientIs,
@@ -1854,8 +2511,8 @@ pub enum EmailClientSegmentOperator {
     ClientNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentOperator {
@@ -1864,12 +2521,36 @@ impl std::fmt::Display for EmailClientSegmentOperator {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
             EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailClientSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "client_is" => EmailClientSegmentOperator::ClientIs,
+            "client_not" => EmailClientSegmentOperator::ClientNot,
+            "" => EmailClientSegmentOperator::Noop,
+            _ => EmailClientSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailClientSegmentOperator {
     fn default() -> EmailClientSegmentOperator {
         EmailClientSegmentOperator::Noop
@@ -1879,6 +2560,11 @@ impl EmailClientSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailClientSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by use of a particular email client.
@@ -1910,14 +2596,14 @@ pub struct EmailClientSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LanguageSegmentConditionType {
     #[serde(rename = "Language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentConditionType {
@@ -1925,12 +2611,35 @@ impl std::fmt::Display for LanguageSegmentConditionType {
         match self {
             LanguageSegmentConditionType::Language => "Language",
             LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LanguageSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Language" => LanguageSegmentConditionType::Language,
+            "" => LanguageSegmentConditionType::Noop,
+            _ => LanguageSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LanguageSegmentConditionType {
     fn default() -> LanguageSegmentConditionType {
         LanguageSegmentConditionType::Noop
@@ -1940,19 +2649,24 @@ impl LanguageSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LanguageSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segmenting based off of a subscriber's language.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LanguageSegmentField {
     #[serde(rename = "language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentField {
@@ -1960,12 +2674,35 @@ impl std::fmt::Display for LanguageSegmentField {
         match self {
             LanguageSegmentField::Language => "language",
             LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LanguageSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "language" => LanguageSegmentField::Language,
+            "" => LanguageSegmentField::Noop,
+            _ => LanguageSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for LanguageSegmentField {
     fn default() -> LanguageSegmentField {
         LanguageSegmentField::Noop
@@ -1975,12 +2712,17 @@ impl LanguageSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LanguageSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's language is or is not set to a specific language.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LanguageSegmentOperator {
     #[serde(rename = "is")]
     Is,
@@ -1988,8 +2730,8 @@ pub enum LanguageSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentOperator {
@@ -1998,12 +2740,36 @@ impl std::fmt::Display for LanguageSegmentOperator {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
             LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LanguageSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "is" => LanguageSegmentOperator::Is,
+            "not" => LanguageSegmentOperator::Not,
+            "" => LanguageSegmentOperator::Noop,
+            _ => LanguageSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for LanguageSegmentOperator {
     fn default() -> LanguageSegmentOperator {
         LanguageSegmentOperator::Noop
@@ -2013,6 +2779,11 @@ impl LanguageSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LanguageSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by language.
@@ -2044,14 +2815,14 @@ pub struct LanguageSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "MemberRating")]
     MemberRating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentConditionType {
@@ -2059,12 +2830,35 @@ impl std::fmt::Display for MemberRatingSegmentConditionType {
         match self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
             MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MemberRatingSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "MemberRating" => MemberRatingSegmentConditionType::MemberRating,
+            "" => MemberRatingSegmentConditionType::Noop,
+            _ => MemberRatingSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MemberRatingSegmentConditionType {
     fn default() -> MemberRatingSegmentConditionType {
         MemberRatingSegmentConditionType::Noop
@@ -2074,19 +2868,24 @@ impl MemberRatingSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MemberRatingSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by member rating.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MemberRatingSegmentField {
     #[serde(rename = "rating")]
     Rating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentField {
@@ -2094,12 +2893,35 @@ impl std::fmt::Display for MemberRatingSegmentField {
         match self {
             MemberRatingSegmentField::Rating => "rating",
             MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MemberRatingSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "rating" => MemberRatingSegmentField::Rating,
+            "" => MemberRatingSegmentField::Noop,
+            _ => MemberRatingSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for MemberRatingSegmentField {
     fn default() -> MemberRatingSegmentField {
         MemberRatingSegmentField::Noop
@@ -2109,12 +2931,17 @@ impl MemberRatingSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MemberRatingSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MemberRatingSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -2126,8 +2953,8 @@ pub enum MemberRatingSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentOperator {
@@ -2138,13 +2965,39 @@ impl std::fmt::Display for MemberRatingSegmentOperator {
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
             MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for MemberRatingSegmentOperator {
+impl Serialize for MemberRatingSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "greater" => MemberRatingSegmentOperator::Greater,
+            "is" => MemberRatingSegmentOperator::Is,
+            "less" => MemberRatingSegmentOperator::Less,
+            "not" => MemberRatingSegmentOperator::Not,
+            "" => MemberRatingSegmentOperator::Noop,
+            _ => MemberRatingSegmentOperator::Unknown(s),
+        })
+    }
+}
+
+impl Default for MemberRatingSegmentOperator {
     fn default() -> MemberRatingSegmentOperator {
         MemberRatingSegmentOperator::Noop
     }
@@ -2153,6 +3006,11 @@ impl MemberRatingSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MemberRatingSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by member rating.
@@ -2184,14 +3042,14 @@ pub struct MemberRatingSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SignupSourceSegmentType {
     #[serde(rename = "SignupSource")]
     SignupSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentType {
@@ -2199,12 +3057,35 @@ impl std::fmt::Display for SignupSourceSegmentType {
         match self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
             SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SignupSourceSegmentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SignupSource" => SignupSourceSegmentType::SignupSource,
+            "" => SignupSourceSegmentType::Noop,
+            _ => SignupSourceSegmentType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SignupSourceSegmentType {
     fn default() -> SignupSourceSegmentType {
         SignupSourceSegmentType::Noop
@@ -2214,16 +3095,21 @@ impl SignupSourceSegmentType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SignupSourceSegmentType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SignupSourceSegmentField {
     #[serde(rename = "source")]
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentField {
@@ -2231,12 +3117,35 @@ impl std::fmt::Display for SignupSourceSegmentField {
         match self {
             SignupSourceSegmentField::Source => "source",
             SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SignupSourceSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "source" => SignupSourceSegmentField::Source,
+            "" => SignupSourceSegmentField::Noop,
+            _ => SignupSourceSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SignupSourceSegmentField {
     fn default() -> SignupSourceSegmentField {
         SignupSourceSegmentField::Noop
@@ -2246,12 +3155,17 @@ impl SignupSourceSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SignupSourceSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's signup source was/was not a particular value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SignupSourceSegmentOperator {
     #[serde(rename = "source_is")]
     SourceIs,
@@ -2259,8 +3173,8 @@ pub enum SignupSourceSegmentOperator {
     SourceNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentOperator {
@@ -2269,12 +3183,36 @@ impl std::fmt::Display for SignupSourceSegmentOperator {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
             SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SignupSourceSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "source_is" => SignupSourceSegmentOperator::SourceIs,
+            "source_not" => SignupSourceSegmentOperator::SourceNot,
+            "" => SignupSourceSegmentOperator::Noop,
+            _ => SignupSourceSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SignupSourceSegmentOperator {
     fn default() -> SignupSourceSegmentOperator {
         SignupSourceSegmentOperator::Noop
@@ -2284,6 +3222,11 @@ impl SignupSourceSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SignupSourceSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by signup source.
@@ -2309,14 +3252,14 @@ pub struct SignupSourceSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "SurveyMonkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentConditionType {
@@ -2324,12 +3267,35 @@ impl std::fmt::Display for SurveyMonkeySegmentConditionType {
         match self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
             SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SurveyMonkeySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyMonkeySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SurveyMonkey" => SurveyMonkeySegmentConditionType::SurveyMonkey,
+            "" => SurveyMonkeySegmentConditionType::Noop,
+            _ => SurveyMonkeySegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SurveyMonkeySegmentConditionType {
     fn default() -> SurveyMonkeySegmentConditionType {
         SurveyMonkeySegmentConditionType::Noop
@@ -2339,19 +3305,24 @@ impl SurveyMonkeySegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyMonkeySegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SurveyMonkeySegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by interaction with a SurveyMonkey survey.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SurveyMonkeySegmentField {
     #[serde(rename = "survey_monkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentField {
@@ -2359,12 +3330,35 @@ impl std::fmt::Display for SurveyMonkeySegmentField {
         match self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
             SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SurveyMonkeySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyMonkeySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "survey_monkey" => SurveyMonkeySegmentField::SurveyMonkey,
+            "" => SurveyMonkeySegmentField::Noop,
+            _ => SurveyMonkeySegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SurveyMonkeySegmentField {
     fn default() -> SurveyMonkeySegmentField {
         SurveyMonkeySegmentField::Noop
@@ -2374,6 +3368,11 @@ impl SurveyMonkeySegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyMonkeySegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SurveyMonkeySegmentField::Unknown(_))
+    }
 }
 
 /// Segment by interaction with a SurveyMonkey survey.
@@ -2405,14 +3404,14 @@ pub struct SurveyMonkeySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum VipSegmentConditionType {
     #[serde(rename = "VIP")]
     Vip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentConditionType {
@@ -2420,12 +3419,35 @@ impl std::fmt::Display for VipSegmentConditionType {
         match self {
             VipSegmentConditionType::Vip => "VIP",
             VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for VipSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VipSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "VIP" => VipSegmentConditionType::Vip,
+            "" => VipSegmentConditionType::Noop,
+            _ => VipSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for VipSegmentConditionType {
     fn default() -> VipSegmentConditionType {
         VipSegmentConditionType::Noop
@@ -2435,19 +3457,24 @@ impl VipSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, VipSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, VipSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by VIP status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum VipSegmentField {
     #[serde(rename = "gmonkey")]
     Gmonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentField {
@@ -2455,12 +3482,35 @@ impl std::fmt::Display for VipSegmentField {
         match self {
             VipSegmentField::Gmonkey => "gmonkey",
             VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for VipSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VipSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "gmonkey" => VipSegmentField::Gmonkey,
+            "" => VipSegmentField::Noop,
+            _ => VipSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for VipSegmentField {
     fn default() -> VipSegmentField {
         VipSegmentField::Noop
@@ -2470,6 +3520,11 @@ impl VipSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, VipSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, VipSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by VIP status.
@@ -2492,14 +3547,14 @@ pub struct VipSegment {
     pub op: PollActivitySegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InterestsSegmentConditionType {
     #[serde(rename = "Interests")]
     Interests,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentConditionType {
@@ -2507,12 +3562,35 @@ impl std::fmt::Display for InterestsSegmentConditionType {
         match self {
             InterestsSegmentConditionType::Interests => "Interests",
             InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InterestsSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestsSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Interests" => InterestsSegmentConditionType::Interests,
+            "" => InterestsSegmentConditionType::Noop,
+            _ => InterestsSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for InterestsSegmentConditionType {
     fn default() -> InterestsSegmentConditionType {
         InterestsSegmentConditionType::Noop
@@ -2522,12 +3600,17 @@ impl InterestsSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestsSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InterestsSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member is a part of one, all, or none of the groups.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InterestsSegmentOperator {
     #[serde(rename = "interestcontains")]
     Interestcontains,
@@ -2537,8 +3620,8 @@ pub enum InterestsSegmentOperator {
     Interestnotcontains,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentOperator {
@@ -2548,12 +3631,37 @@ impl std::fmt::Display for InterestsSegmentOperator {
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
             InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InterestsSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestsSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "interestcontains" => InterestsSegmentOperator::Interestcontains,
+            "interestcontainsall" => InterestsSegmentOperator::Interestcontainsall,
+            "interestnotcontains" => InterestsSegmentOperator::Interestnotcontains,
+            "" => InterestsSegmentOperator::Noop,
+            _ => InterestsSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for InterestsSegmentOperator {
     fn default() -> InterestsSegmentOperator {
         InterestsSegmentOperator::Noop
@@ -2563,6 +3671,11 @@ impl InterestsSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestsSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InterestsSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by an interest group merge field.
@@ -2598,14 +3711,14 @@ pub struct InterestsSegment {
     pub value: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "EcommCategory")]
     EcommCategory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentConditionType {
@@ -2613,12 +3726,35 @@ impl std::fmt::Display for EcommerceCategorySegmentConditionType {
         match self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
             EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceCategorySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EcommCategory" => EcommerceCategorySegmentConditionType::EcommCategory,
+            "" => EcommerceCategorySegmentConditionType::Noop,
+            _ => EcommerceCategorySegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceCategorySegmentConditionType {
     fn default() -> EcommerceCategorySegmentConditionType {
         EcommerceCategorySegmentConditionType::Noop
@@ -2628,12 +3764,17 @@ impl EcommerceCategorySegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceCategorySegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by purchases in specific items or categories.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceCategorySegmentField {
     #[serde(rename = "ecomm_cat")]
     EcommCat,
@@ -2641,8 +3782,8 @@ pub enum EcommerceCategorySegmentField {
     EcommProd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentField {
@@ -2651,12 +3792,36 @@ impl std::fmt::Display for EcommerceCategorySegmentField {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
             EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceCategorySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_cat" => EcommerceCategorySegmentField::EcommCat,
+            "ecomm_prod" => EcommerceCategorySegmentField::EcommProd,
+            "" => EcommerceCategorySegmentField::Noop,
+            _ => EcommerceCategorySegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceCategorySegmentField {
     fn default() -> EcommerceCategorySegmentField {
         EcommerceCategorySegmentField::Noop
@@ -2666,12 +3831,17 @@ impl EcommerceCategorySegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceCategorySegmentField::Unknown(_))
+    }
 }
 
 /**
 * A member who has purchased from a category/specific item that is/is not a specific name, where the category/item name contains/doesn't contain a specific phrase or string, or a category/item name that starts/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -2687,8 +3857,8 @@ pub enum EcommerceCategorySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentOperator {
@@ -2701,12 +3871,40 @@ impl std::fmt::Display for EcommerceCategorySegmentOperator {
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
             EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceCategorySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "contains" => EcommerceCategorySegmentOperator::Contains,
+            "ends" => EcommerceCategorySegmentOperator::Ends,
+            "is" => EcommerceCategorySegmentOperator::Is,
+            "not" => EcommerceCategorySegmentOperator::Not,
+            "notcontain" => EcommerceCategorySegmentOperator::Notcontain,
+            "starts" => EcommerceCategorySegmentOperator::Starts,
+            "" => EcommerceCategorySegmentOperator::Noop,
+            _ => EcommerceCategorySegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceCategorySegmentOperator {
     fn default() -> EcommerceCategorySegmentOperator {
         EcommerceCategorySegmentOperator::Noop
@@ -2716,6 +3914,11 @@ impl EcommerceCategorySegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceCategorySegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by purchases in specific items or categories.
@@ -2747,14 +3950,14 @@ pub struct EcommerceCategorySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "EcommNumber")]
     EcommNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentConditionType {
@@ -2762,12 +3965,35 @@ impl std::fmt::Display for EcommerceNumberSegmentConditionType {
         match self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
             EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceNumberSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceNumberSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EcommNumber" => EcommerceNumberSegmentConditionType::EcommNumber,
+            "" => EcommerceNumberSegmentConditionType::Noop,
+            _ => EcommerceNumberSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceNumberSegmentConditionType {
     fn default() -> EcommerceNumberSegmentConditionType {
         EcommerceNumberSegmentConditionType::Noop
@@ -2777,12 +4003,17 @@ impl EcommerceNumberSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceNumberSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceNumberSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceNumberSegmentField {
     #[serde(rename = "ecomm_avg_ord")]
     EcommAvgOrd,
@@ -2794,8 +4025,8 @@ pub enum EcommerceNumberSegmentField {
     EcommSpentAvg,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentField {
@@ -2806,12 +4037,38 @@ impl std::fmt::Display for EcommerceNumberSegmentField {
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
             EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceNumberSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceNumberSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_avg_ord" => EcommerceNumberSegmentField::EcommAvgOrd,
+            "ecomm_orders" => EcommerceNumberSegmentField::EcommOrders,
+            "ecomm_prod_all" => EcommerceNumberSegmentField::EcommProdAll,
+            "ecomm_spent_avg" => EcommerceNumberSegmentField::EcommSpentAvg,
+            "" => EcommerceNumberSegmentField::Noop,
+            _ => EcommerceNumberSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceNumberSegmentField {
     fn default() -> EcommerceNumberSegmentField {
         EcommerceNumberSegmentField::Noop
@@ -2821,6 +4078,11 @@ impl EcommerceNumberSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceNumberSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceNumberSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
@@ -2852,14 +4114,14 @@ pub struct EcommerceNumberSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "EcommPurchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
@@ -2867,12 +4129,35 @@ impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
         match self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
             EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommercePurchasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EcommPurchased" => EcommercePurchasedSegmentConditionType::EcommPurchased,
+            "" => EcommercePurchasedSegmentConditionType::Noop,
+            _ => EcommercePurchasedSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommercePurchasedSegmentConditionType {
     fn default() -> EcommercePurchasedSegmentConditionType {
         EcommercePurchasedSegmentConditionType::Noop
@@ -2882,19 +4167,24 @@ impl EcommercePurchasedSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommercePurchasedSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by whether someone has purchased anything.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "ecomm_purchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentField {
@@ -2902,12 +4192,35 @@ impl std::fmt::Display for EcommercePurchasedSegmentField {
         match self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
             EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommercePurchasedSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_purchased" => EcommercePurchasedSegmentField::EcommPurchased,
+            "" => EcommercePurchasedSegmentField::Noop,
+            _ => EcommercePurchasedSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommercePurchasedSegmentField {
     fn default() -> EcommercePurchasedSegmentField {
         EcommercePurchasedSegmentField::Noop
@@ -2917,6 +4230,11 @@ impl EcommercePurchasedSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommercePurchasedSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by whether someone has purchased anything.
@@ -2939,14 +4257,14 @@ pub struct EcommercePurchasedSegment {
     pub op: Option<PollActivitySegmentOperator>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "EcommSpent")]
     EcommSpent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentConditionType {
@@ -2954,12 +4272,35 @@ impl std::fmt::Display for EcommerceSpentSegmentConditionType {
         match self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
             EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceSpentSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EcommSpent" => EcommerceSpentSegmentConditionType::EcommSpent,
+            "" => EcommerceSpentSegmentConditionType::Noop,
+            _ => EcommerceSpentSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceSpentSegmentConditionType {
     fn default() -> EcommerceSpentSegmentConditionType {
         EcommerceSpentSegmentConditionType::Noop
@@ -2969,12 +4310,17 @@ impl EcommerceSpentSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceSpentSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by amount spent on a single order or across all orders.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceSpentSegmentField {
     #[serde(rename = "ecomm_spent_all")]
     EcommSpentAll,
@@ -2982,8 +4328,8 @@ pub enum EcommerceSpentSegmentField {
     EcommSpentOne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentField {
@@ -2992,12 +4338,36 @@ impl std::fmt::Display for EcommerceSpentSegmentField {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
             EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceSpentSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_spent_all" => EcommerceSpentSegmentField::EcommSpentAll,
+            "ecomm_spent_one" => EcommerceSpentSegmentField::EcommSpentOne,
+            "" => EcommerceSpentSegmentField::Noop,
+            _ => EcommerceSpentSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceSpentSegmentField {
     fn default() -> EcommerceSpentSegmentField {
         EcommerceSpentSegmentField::Noop
@@ -3007,12 +4377,17 @@ impl EcommerceSpentSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceSpentSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Members who have spent 'more' or 'less' than then specified value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3020,8 +4395,8 @@ pub enum EcommerceSpentSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentOperator {
@@ -3030,12 +4405,36 @@ impl std::fmt::Display for EcommerceSpentSegmentOperator {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
             EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceSpentSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "greater" => EcommerceSpentSegmentOperator::Greater,
+            "less" => EcommerceSpentSegmentOperator::Less,
+            "" => EcommerceSpentSegmentOperator::Noop,
+            _ => EcommerceSpentSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceSpentSegmentOperator {
     fn default() -> EcommerceSpentSegmentOperator {
         EcommerceSpentSegmentOperator::Noop
@@ -3045,6 +4444,11 @@ impl EcommerceSpentSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceSpentSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by amount spent on a single order or across all orders.
@@ -3076,14 +4480,14 @@ pub struct EcommerceSpentSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "EcommStore")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
@@ -3091,12 +4495,35 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
         match self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
             EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommercePurchasedStoreSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedStoreSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EcommStore" => EcommercePurchasedStoreSegmentConditionType::EcommStore,
+            "" => EcommercePurchasedStoreSegmentConditionType::Noop,
+            _ => EcommercePurchasedStoreSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentConditionType {
     fn default() -> EcommercePurchasedStoreSegmentConditionType {
         EcommercePurchasedStoreSegmentConditionType::Noop
@@ -3106,19 +4533,27 @@ impl EcommercePurchasedStoreSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedStoreSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            EcommercePurchasedStoreSegmentConditionType::Unknown(_)
+        )
+    }
 }
 
 /**
 * Segment by purchases from a specific store.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "ecomm_store")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
@@ -3126,12 +4561,35 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
         match self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
             EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommercePurchasedStoreSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedStoreSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_store" => EcommercePurchasedStoreSegmentField::EcommStore,
+            "" => EcommercePurchasedStoreSegmentField::Noop,
+            _ => EcommercePurchasedStoreSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentField {
     fn default() -> EcommercePurchasedStoreSegmentField {
         EcommercePurchasedStoreSegmentField::Noop
@@ -3141,6 +4599,11 @@ impl EcommercePurchasedStoreSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedStoreSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommercePurchasedStoreSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by purchases from a specific store.
@@ -3172,14 +4635,14 @@ pub struct EcommercePurchasedStoreSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "GoalActivity")]
     GoalActivity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentConditionType {
@@ -3187,12 +4650,35 @@ impl std::fmt::Display for GoalActivitySegmentConditionType {
         match self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
             GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalActivitySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "GoalActivity" => GoalActivitySegmentConditionType::GoalActivity,
+            "" => GoalActivitySegmentConditionType::Noop,
+            _ => GoalActivitySegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalActivitySegmentConditionType {
     fn default() -> GoalActivitySegmentConditionType {
         GoalActivitySegmentConditionType::Noop
@@ -3202,19 +4688,24 @@ impl GoalActivitySegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalActivitySegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by Goal activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalActivitySegmentField {
     #[serde(rename = "goal")]
     Goal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentField {
@@ -3222,12 +4713,35 @@ impl std::fmt::Display for GoalActivitySegmentField {
         match self {
             GoalActivitySegmentField::Goal => "goal",
             GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalActivitySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "goal" => GoalActivitySegmentField::Goal,
+            "" => GoalActivitySegmentField::Noop,
+            _ => GoalActivitySegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalActivitySegmentField {
     fn default() -> GoalActivitySegmentField {
         GoalActivitySegmentField::Noop
@@ -3237,12 +4751,17 @@ impl GoalActivitySegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalActivitySegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalActivitySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -3258,8 +4777,8 @@ pub enum GoalActivitySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentOperator {
@@ -3272,12 +4791,40 @@ impl std::fmt::Display for GoalActivitySegmentOperator {
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
             GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalActivitySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "contains" => GoalActivitySegmentOperator::Contains,
+            "ends" => GoalActivitySegmentOperator::Ends,
+            "goal_not" => GoalActivitySegmentOperator::GoalNot,
+            "goal_notcontain" => GoalActivitySegmentOperator::GoalNotcontain,
+            "is" => GoalActivitySegmentOperator::Is,
+            "starts" => GoalActivitySegmentOperator::Starts,
+            "" => GoalActivitySegmentOperator::Noop,
+            _ => GoalActivitySegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalActivitySegmentOperator {
     fn default() -> GoalActivitySegmentOperator {
         GoalActivitySegmentOperator::Noop
@@ -3287,6 +4834,11 @@ impl GoalActivitySegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalActivitySegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by Goal activity.
@@ -3318,14 +4870,14 @@ pub struct GoalActivitySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "GoalTimestamp")]
     GoalTimestamp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentConditionType {
@@ -3333,12 +4885,35 @@ impl std::fmt::Display for GoalTimestampSegmentConditionType {
         match self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
             GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalTimestampSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "GoalTimestamp" => GoalTimestampSegmentConditionType::GoalTimestamp,
+            "" => GoalTimestampSegmentConditionType::Noop,
+            _ => GoalTimestampSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalTimestampSegmentConditionType {
     fn default() -> GoalTimestampSegmentConditionType {
         GoalTimestampSegmentConditionType::Noop
@@ -3348,19 +4923,24 @@ impl GoalTimestampSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalTimestampSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by most recent interaction with a website.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalTimestampSegmentField {
     #[serde(rename = "goal_last_visited")]
     GoalLastVisited,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentField {
@@ -3368,12 +4948,35 @@ impl std::fmt::Display for GoalTimestampSegmentField {
         match self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
             GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalTimestampSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "goal_last_visited" => GoalTimestampSegmentField::GoalLastVisited,
+            "" => GoalTimestampSegmentField::Noop,
+            _ => GoalTimestampSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalTimestampSegmentField {
     fn default() -> GoalTimestampSegmentField {
         GoalTimestampSegmentField::Noop
@@ -3383,12 +4986,17 @@ impl GoalTimestampSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalTimestampSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whether the website activity happened after, before, or at a given timestamp.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3398,8 +5006,8 @@ pub enum GoalTimestampSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentOperator {
@@ -3409,12 +5017,37 @@ impl std::fmt::Display for GoalTimestampSegmentOperator {
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
             GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GoalTimestampSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "greater" => GoalTimestampSegmentOperator::Greater,
+            "is" => GoalTimestampSegmentOperator::Is,
+            "less" => GoalTimestampSegmentOperator::Less,
+            "" => GoalTimestampSegmentOperator::Noop,
+            _ => GoalTimestampSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for GoalTimestampSegmentOperator {
     fn default() -> GoalTimestampSegmentOperator {
         GoalTimestampSegmentOperator::Noop
@@ -3424,6 +5057,11 @@ impl GoalTimestampSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GoalTimestampSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by most recent interaction with a website.
@@ -3455,14 +5093,14 @@ pub struct GoalTimestampSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "FuzzySegment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
@@ -3470,12 +5108,35 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
         match self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
             SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SimilarSubscribersSegmentMemberConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "FuzzySegment" => SimilarSubscribersSegmentMemberConditionType::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberConditionType::Noop,
+            _ => SimilarSubscribersSegmentMemberConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberConditionType {
     fn default() -> SimilarSubscribersSegmentMemberConditionType {
         SimilarSubscribersSegmentMemberConditionType::Noop
@@ -3485,19 +5146,27 @@ impl SimilarSubscribersSegmentMemberConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            SimilarSubscribersSegmentMemberConditionType::Unknown(_)
+        )
+    }
 }
 
 /**
 * Segment by similar subscribers.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "fuzzy_segment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
@@ -3505,12 +5174,35 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
         match self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
             SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SimilarSubscribersSegmentMemberField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "fuzzy_segment" => SimilarSubscribersSegmentMemberField::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberField::Noop,
+            _ => SimilarSubscribersSegmentMemberField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberField {
     fn default() -> SimilarSubscribersSegmentMemberField {
         SimilarSubscribersSegmentMemberField::Noop
@@ -3520,12 +5212,17 @@ impl SimilarSubscribersSegmentMemberField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SimilarSubscribersSegmentMemberField::Unknown(_))
+    }
 }
 
 /**
 * Members who are/are not apart of a 'similar subscribers' segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "fuzzy_is")]
     FuzzyIs,
@@ -3533,8 +5230,8 @@ pub enum SimilarSubscribersSegmentMemberOperator {
     FuzzyNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
@@ -3543,12 +5240,36 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
             SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SimilarSubscribersSegmentMemberOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "fuzzy_is" => SimilarSubscribersSegmentMemberOperator::FuzzyIs,
+            "fuzzy_not" => SimilarSubscribersSegmentMemberOperator::FuzzyNot,
+            "" => SimilarSubscribersSegmentMemberOperator::Noop,
+            _ => SimilarSubscribersSegmentMemberOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberOperator {
     fn default() -> SimilarSubscribersSegmentMemberOperator {
         SimilarSubscribersSegmentMemberOperator::Noop
@@ -3558,6 +5279,11 @@ impl SimilarSubscribersSegmentMemberOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SimilarSubscribersSegmentMemberOperator::Unknown(_))
+    }
 }
 
 /// Segment by similar subscribers.
@@ -3595,14 +5321,14 @@ pub struct SimilarSubscribersSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "StaticSegment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberConditionType {
@@ -3610,12 +5336,35 @@ impl std::fmt::Display for StaticSegmentMemberConditionType {
         match self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
             StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for StaticSegmentMemberConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "StaticSegment" => StaticSegmentMemberConditionType::StaticSegment,
+            "" => StaticSegmentMemberConditionType::Noop,
+            _ => StaticSegmentMemberConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for StaticSegmentMemberConditionType {
     fn default() -> StaticSegmentMemberConditionType {
         StaticSegmentMemberConditionType::Noop
@@ -3625,19 +5374,24 @@ impl StaticSegmentMemberConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, StaticSegmentMemberConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by a given static segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum StaticSegmentMemberField {
     #[serde(rename = "static_segment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberField {
@@ -3645,12 +5399,35 @@ impl std::fmt::Display for StaticSegmentMemberField {
         match self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
             StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for StaticSegmentMemberField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "static_segment" => StaticSegmentMemberField::StaticSegment,
+            "" => StaticSegmentMemberField::Noop,
+            _ => StaticSegmentMemberField::Unknown(s),
+        })
+    }
+}
+
 impl Default for StaticSegmentMemberField {
     fn default() -> StaticSegmentMemberField {
         StaticSegmentMemberField::Noop
@@ -3660,12 +5437,17 @@ impl StaticSegmentMemberField {
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, StaticSegmentMemberField::Unknown(_))
+    }
 }
 
 /**
 * Members who are/are not apart of a static segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum StaticSegmentMemberOperator {
     #[serde(rename = "static_is")]
     StaticIs,
@@ -3673,8 +5455,8 @@ pub enum StaticSegmentMemberOperator {
     StaticNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberOperator {
@@ -3683,12 +5465,36 @@ impl std::fmt::Display for StaticSegmentMemberOperator {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
             StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for StaticSegmentMemberOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "static_is" => StaticSegmentMemberOperator::StaticIs,
+            "static_not" => StaticSegmentMemberOperator::StaticNot,
+            "" => StaticSegmentMemberOperator::Noop,
+            _ => StaticSegmentMemberOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for StaticSegmentMemberOperator {
     fn default() -> StaticSegmentMemberOperator {
         StaticSegmentMemberOperator::Noop
@@ -3698,6 +5504,11 @@ impl StaticSegmentMemberOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, StaticSegmentMemberOperator::Unknown(_))
+    }
 }
 
 /// Segment by a given static segment.
@@ -3729,14 +5540,14 @@ pub struct StaticSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoCountryState")]
     IpGeoCountryState,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentConditionType {
@@ -3744,12 +5555,35 @@ impl std::fmt::Display for LocationBasedSegmentConditionType {
         match self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
             LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IPGeoCountryState" => LocationBasedSegmentConditionType::IpGeoCountryState,
+            "" => LocationBasedSegmentConditionType::Noop,
+            _ => LocationBasedSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LocationBasedSegmentConditionType {
     fn default() -> LocationBasedSegmentConditionType {
         LocationBasedSegmentConditionType::Noop
@@ -3759,19 +5593,24 @@ impl LocationBasedSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LocationBasedSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segmenting subscribers who are within a specific location.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LocationBasedSegmentField {
     #[serde(rename = "ipgeo")]
     Ipgeo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentField {
@@ -3779,12 +5618,35 @@ impl std::fmt::Display for LocationBasedSegmentField {
         match self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
             LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LocationBasedSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeo" => LocationBasedSegmentField::Ipgeo,
+            "" => LocationBasedSegmentField::Noop,
+            _ => LocationBasedSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for LocationBasedSegmentField {
     fn default() -> LocationBasedSegmentField {
         LocationBasedSegmentField::Noop
@@ -3794,12 +5656,17 @@ impl LocationBasedSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LocationBasedSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Segment members who are within a specific country or US state.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LocationBasedSegmentOperator {
     #[serde(rename = "ipgeocountry")]
     Ipgeocountry,
@@ -3811,8 +5678,8 @@ pub enum LocationBasedSegmentOperator {
     Ipgeostate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentOperator {
@@ -3823,12 +5690,38 @@ impl std::fmt::Display for LocationBasedSegmentOperator {
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
             LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeocountry" => LocationBasedSegmentOperator::Ipgeocountry,
+            "ipgeonotcountry" => LocationBasedSegmentOperator::Ipgeonotcountry,
+            "ipgeonotstate" => LocationBasedSegmentOperator::Ipgeonotstate,
+            "ipgeostate" => LocationBasedSegmentOperator::Ipgeostate,
+            "" => LocationBasedSegmentOperator::Noop,
+            _ => LocationBasedSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for LocationBasedSegmentOperator {
     fn default() -> LocationBasedSegmentOperator {
         LocationBasedSegmentOperator::Noop
@@ -3838,6 +5731,11 @@ impl LocationBasedSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LocationBasedSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a specific country or US state.
@@ -3869,14 +5767,14 @@ pub struct LocationBasedSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GeolocationSegmentConditionType {
     #[serde(rename = "IPGeoIn")]
     IpGeoIn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentConditionType {
@@ -3884,12 +5782,35 @@ impl std::fmt::Display for GeolocationSegmentConditionType {
         match self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
             GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GeolocationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GeolocationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IPGeoIn" => GeolocationSegmentConditionType::IpGeoIn,
+            "" => GeolocationSegmentConditionType::Noop,
+            _ => GeolocationSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GeolocationSegmentConditionType {
     fn default() -> GeolocationSegmentConditionType {
         GeolocationSegmentConditionType::Noop
@@ -3899,12 +5820,17 @@ impl GeolocationSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GeolocationSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GeolocationSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment members who are within a specific geographic region.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GeolocationSegmentOperator {
     #[serde(rename = "ipgeoin")]
     Ipgeoin,
@@ -3912,8 +5838,8 @@ pub enum GeolocationSegmentOperator {
     Ipgeonotin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentOperator {
@@ -3922,12 +5848,36 @@ impl std::fmt::Display for GeolocationSegmentOperator {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
             GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GeolocationSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GeolocationSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeoin" => GeolocationSegmentOperator::Ipgeoin,
+            "ipgeonotin" => GeolocationSegmentOperator::Ipgeonotin,
+            "" => GeolocationSegmentOperator::Noop,
+            _ => GeolocationSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for GeolocationSegmentOperator {
     fn default() -> GeolocationSegmentOperator {
         GeolocationSegmentOperator::Noop
@@ -3937,6 +5887,11 @@ impl GeolocationSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, GeolocationSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GeolocationSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a specific geographic region.
@@ -3995,14 +5950,14 @@ pub struct GeolocationSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "IPGeoInZip")]
     IpGeoInZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentConditionType {
@@ -4010,12 +5965,35 @@ impl std::fmt::Display for UsZipCodeSegmentConditionType {
         match self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
             UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UsZipCodeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UsZipCodeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IPGeoInZip" => UsZipCodeSegmentConditionType::IpGeoInZip,
+            "" => UsZipCodeSegmentConditionType::Noop,
+            _ => UsZipCodeSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UsZipCodeSegmentConditionType {
     fn default() -> UsZipCodeSegmentConditionType {
         UsZipCodeSegmentConditionType::Noop
@@ -4025,19 +6003,24 @@ impl UsZipCodeSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UsZipCodeSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UsZipCodeSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment members who are within a specific US zip code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "ipgeoinzip")]
     Ipgeoinzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentOperator {
@@ -4045,12 +6028,35 @@ impl std::fmt::Display for UsZipCodeSegmentOperator {
         match self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
             UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UsZipCodeSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UsZipCodeSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeoinzip" => UsZipCodeSegmentOperator::Ipgeoinzip,
+            "" => UsZipCodeSegmentOperator::Noop,
+            _ => UsZipCodeSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for UsZipCodeSegmentOperator {
     fn default() -> UsZipCodeSegmentOperator {
         UsZipCodeSegmentOperator::Noop
@@ -4060,6 +6066,11 @@ impl UsZipCodeSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, UsZipCodeSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UsZipCodeSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a specific US ZIP code.
@@ -4100,14 +6111,14 @@ pub struct UsZipCodeSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoUnknown")]
     IpGeoUnknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
@@ -4115,12 +6126,35 @@ impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
         match self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
             UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UnknownLocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownLocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IPGeoUnknown" => UnknownLocationBasedSegmentConditionType::IpGeoUnknown,
+            "" => UnknownLocationBasedSegmentConditionType::Noop,
+            _ => UnknownLocationBasedSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentConditionType {
     fn default() -> UnknownLocationBasedSegmentConditionType {
         UnknownLocationBasedSegmentConditionType::Noop
@@ -4130,19 +6164,24 @@ impl UnknownLocationBasedSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UnknownLocationBasedSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UnknownLocationBasedSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment members for which location information is unknown.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "ipgeounknown")]
     Ipgeounknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
@@ -4150,12 +6189,35 @@ impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
         match self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
             UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UnknownLocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownLocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeounknown" => UnknownLocationBasedSegmentOperator::Ipgeounknown,
+            "" => UnknownLocationBasedSegmentOperator::Noop,
+            _ => UnknownLocationBasedSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentOperator {
     fn default() -> UnknownLocationBasedSegmentOperator {
         UnknownLocationBasedSegmentOperator::Noop
@@ -4165,6 +6227,11 @@ impl UnknownLocationBasedSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, UnknownLocationBasedSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UnknownLocationBasedSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment members whose location information is unknown.
@@ -4190,14 +6257,14 @@ pub struct UnknownLocationBasedSegment {
     pub op: UnknownLocationBasedSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoZip")]
     IpGeoZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
@@ -4205,12 +6272,35 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
         match self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
             ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ZipCodeLocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ZipCodeLocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IPGeoZip" => ZipCodeLocationBasedSegmentConditionType::IpGeoZip,
+            "" => ZipCodeLocationBasedSegmentConditionType::Noop,
+            _ => ZipCodeLocationBasedSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentConditionType {
     fn default() -> ZipCodeLocationBasedSegmentConditionType {
         ZipCodeLocationBasedSegmentConditionType::Noop
@@ -4220,12 +6310,17 @@ impl ZipCodeLocationBasedSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ZipCodeLocationBasedSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ZipCodeLocationBasedSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment members who are/are not within a specific US zip code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "ipgeoiszip")]
     Ipgeoiszip,
@@ -4233,8 +6328,8 @@ pub enum ZipCodeLocationBasedSegmentOperator {
     Ipgeonotzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
@@ -4243,12 +6338,36 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
             ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ZipCodeLocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ZipCodeLocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ipgeoiszip" => ZipCodeLocationBasedSegmentOperator::Ipgeoiszip,
+            "ipgeonotzip" => ZipCodeLocationBasedSegmentOperator::Ipgeonotzip,
+            "" => ZipCodeLocationBasedSegmentOperator::Noop,
+            _ => ZipCodeLocationBasedSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentOperator {
     fn default() -> ZipCodeLocationBasedSegmentOperator {
         ZipCodeLocationBasedSegmentOperator::Noop
@@ -4258,6 +6377,11 @@ impl ZipCodeLocationBasedSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, ZipCodeLocationBasedSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ZipCodeLocationBasedSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a specific US ZIP code.
@@ -4292,14 +6416,14 @@ pub struct ZipCodeLocationBasedSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "SocialAge")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
@@ -4307,12 +6431,35 @@ impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
         match self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
             SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesAgeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesAgeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SocialAge" => SocialProfilesAgeSegmentConditionType::SocialAge,
+            "" => SocialProfilesAgeSegmentConditionType::Noop,
+            _ => SocialProfilesAgeSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentConditionType {
     fn default() -> SocialProfilesAgeSegmentConditionType {
         SocialProfilesAgeSegmentConditionType::Noop
@@ -4322,19 +6469,24 @@ impl SocialProfilesAgeSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesAgeSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesAgeSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by age ranges in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "social_age")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentField {
@@ -4342,12 +6494,35 @@ impl std::fmt::Display for SocialProfilesAgeSegmentField {
         match self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
             SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesAgeSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesAgeSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "social_age" => SocialProfilesAgeSegmentField::SocialAge,
+            "" => SocialProfilesAgeSegmentField::Noop,
+            _ => SocialProfilesAgeSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentField {
     fn default() -> SocialProfilesAgeSegmentField {
         SocialProfilesAgeSegmentField::Noop
@@ -4357,12 +6532,17 @@ impl SocialProfilesAgeSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesAgeSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesAgeSegmentField::Unknown(_))
+    }
 }
 
 /**
 * The age range to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Value {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -4374,8 +6554,8 @@ pub enum Value {
     FiftyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -4386,12 +6566,38 @@ impl std::fmt::Display for Value {
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "18-24" => Value::OneThousandEightHundredAndTwentyFour,
+            "25-34" => Value::TwoThousandFiveHundredAndThirtyFour,
+            "35-54" => Value::ThreeThousandFiveHundredAndFiftyFour,
+            "55+" => Value::FiftyFive,
+            "" => Value::Noop,
+            _ => Value::Unknown(s),
+        })
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -4401,6 +6607,11 @@ impl Value {
     pub fn is_noop(&self) -> bool {
         matches!(self, Value::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Value::Unknown(_))
+    }
 }
 
 /// Segment by age ranges in Social Profiles data.
@@ -4431,14 +6642,14 @@ pub struct SocialProfilesAgeSegment {
     pub value: Value,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "SocialGender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
@@ -4446,12 +6657,35 @@ impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
         match self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
             SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesGenderSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SocialGender" => SocialProfilesGenderSegmentConditionType::SocialGender,
+            "" => SocialProfilesGenderSegmentConditionType::Noop,
+            _ => SocialProfilesGenderSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentConditionType {
     fn default() -> SocialProfilesGenderSegmentConditionType {
         SocialProfilesGenderSegmentConditionType::Noop
@@ -4461,19 +6695,24 @@ impl SocialProfilesGenderSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesGenderSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by listed gender in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "social_gender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentField {
@@ -4481,12 +6720,35 @@ impl std::fmt::Display for SocialProfilesGenderSegmentField {
         match self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
             SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesGenderSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "social_gender" => SocialProfilesGenderSegmentField::SocialGender,
+            "" => SocialProfilesGenderSegmentField::Noop,
+            _ => SocialProfilesGenderSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentField {
     fn default() -> SocialProfilesGenderSegmentField {
         SocialProfilesGenderSegmentField::Noop
@@ -4496,12 +6758,17 @@ impl SocialProfilesGenderSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesGenderSegmentField::Unknown(_))
+    }
 }
 
 /**
 * The Social Profiles gender to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "female")]
     Female,
@@ -4509,8 +6776,8 @@ pub enum SocialProfilesGenderSegmentOperator {
     Male,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
@@ -4519,12 +6786,36 @@ impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
             SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesGenderSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "female" => SocialProfilesGenderSegmentOperator::Female,
+            "male" => SocialProfilesGenderSegmentOperator::Male,
+            "" => SocialProfilesGenderSegmentOperator::Noop,
+            _ => SocialProfilesGenderSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentOperator {
     fn default() -> SocialProfilesGenderSegmentOperator {
         SocialProfilesGenderSegmentOperator::Noop
@@ -4534,6 +6825,11 @@ impl SocialProfilesGenderSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesGenderSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by listed gender in Social Profiles data.
@@ -4567,14 +6863,14 @@ pub struct SocialProfilesGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "SocialInfluence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
@@ -4582,12 +6878,35 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
         match self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
             SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesInfluenceSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesInfluenceSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SocialInfluence" => SocialProfilesInfluenceSegmentConditionType::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentConditionType::Noop,
+            _ => SocialProfilesInfluenceSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentConditionType {
     fn default() -> SocialProfilesInfluenceSegmentConditionType {
         SocialProfilesInfluenceSegmentConditionType::Noop
@@ -4597,19 +6916,27 @@ impl SocialProfilesInfluenceSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesInfluenceSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            SocialProfilesInfluenceSegmentConditionType::Unknown(_)
+        )
+    }
 }
 
 /**
 * Segment by influence rating in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "social_influence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
@@ -4617,12 +6944,35 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
         match self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
             SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesInfluenceSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesInfluenceSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "social_influence" => SocialProfilesInfluenceSegmentField::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentField::Noop,
+            _ => SocialProfilesInfluenceSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentField {
     fn default() -> SocialProfilesInfluenceSegmentField {
         SocialProfilesInfluenceSegmentField::Noop
@@ -4632,6 +6982,11 @@ impl SocialProfilesInfluenceSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesInfluenceSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesInfluenceSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by influence rating in Social Profiles data.
@@ -4666,14 +7021,14 @@ pub struct SocialProfilesInfluenceSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "SocialNetworkMember")]
     SocialNetworkMember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
@@ -4681,12 +7036,35 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
         match self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
             SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SocialNetworkMember" => SocialProfilesNetworkSegmentConditionType::SocialNetworkMember,
+            "" => SocialProfilesNetworkSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentConditionType {
     fn default() -> SocialProfilesNetworkSegmentConditionType {
         SocialProfilesNetworkSegmentConditionType::Noop
@@ -4696,19 +7074,24 @@ impl SocialProfilesNetworkSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesNetworkSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by social network in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "social_network")]
     SocialNetwork,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentField {
@@ -4716,12 +7099,35 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentField {
         match self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
             SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "social_network" => SocialProfilesNetworkSegmentField::SocialNetwork,
+            "" => SocialProfilesNetworkSegmentField::Noop,
+            _ => SocialProfilesNetworkSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentField {
     fn default() -> SocialProfilesNetworkSegmentField {
         SocialProfilesNetworkSegmentField::Noop
@@ -4731,12 +7137,17 @@ impl SocialProfilesNetworkSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesNetworkSegmentField::Unknown(_))
+    }
 }
 
 /**
 * The social network to segment against.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "facebook")]
     Facebook,
@@ -4762,8 +7173,8 @@ pub enum SocialProfilesNetworkSegmentOperator {
     Youtube,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
@@ -4781,12 +7192,45 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
             SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "facebook" => SocialProfilesNetworkSegmentOperator::Facebook,
+            "flickr" => SocialProfilesNetworkSegmentOperator::Flickr,
+            "foursquare" => SocialProfilesNetworkSegmentOperator::Foursquare,
+            "lastfm" => SocialProfilesNetworkSegmentOperator::Lastfm,
+            "linkedin" => SocialProfilesNetworkSegmentOperator::Linkedin,
+            "myspace" => SocialProfilesNetworkSegmentOperator::Myspace,
+            "quora" => SocialProfilesNetworkSegmentOperator::Quora,
+            "twitter" => SocialProfilesNetworkSegmentOperator::Twitter,
+            "vimeo" => SocialProfilesNetworkSegmentOperator::Vimeo,
+            "yelp" => SocialProfilesNetworkSegmentOperator::Yelp,
+            "youtube" => SocialProfilesNetworkSegmentOperator::Youtube,
+            "" => SocialProfilesNetworkSegmentOperator::Noop,
+            _ => SocialProfilesNetworkSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentOperator {
     fn default() -> SocialProfilesNetworkSegmentOperator {
         SocialProfilesNetworkSegmentOperator::Noop
@@ -4796,6 +7240,11 @@ impl SocialProfilesNetworkSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesNetworkSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by social network in Social Profiles data.
@@ -4829,14 +7278,14 @@ pub struct SocialProfilesNetworkSegment {
     pub value: SocialProfilesNetworkSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "SocialNetworkFollow")]
     SocialNetworkFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
@@ -4846,12 +7295,37 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
                 "SocialNetworkFollow"
             }
             SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkFollowSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SocialNetworkFollow" => {
+                SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow
+            }
+            "" => SocialProfilesNetworkFollowSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkFollowSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentConditionType {
     fn default() -> SocialProfilesNetworkFollowSegmentConditionType {
         SocialProfilesNetworkFollowSegmentConditionType::Noop
@@ -4861,12 +7335,20 @@ impl SocialProfilesNetworkFollowSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(_)
+        )
+    }
 }
 
 /**
 * Members who are/not following a linked account on a given social network.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "follow")]
     Follow,
@@ -4874,8 +7356,8 @@ pub enum SocialProfilesNetworkFollowSegmentOperator {
     Notfollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
@@ -4884,12 +7366,36 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
             SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkFollowSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "follow" => SocialProfilesNetworkFollowSegmentOperator::Follow,
+            "notfollow" => SocialProfilesNetworkFollowSegmentOperator::Notfollow,
+            "" => SocialProfilesNetworkFollowSegmentOperator::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperator {
     fn default() -> SocialProfilesNetworkFollowSegmentOperator {
         SocialProfilesNetworkFollowSegmentOperator::Noop
@@ -4899,19 +7405,24 @@ impl SocialProfilesNetworkFollowSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SocialProfilesNetworkFollowSegmentOperator::Unknown(_))
+    }
 }
 
 /**
 * The social network to segment against.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "twitter_follow")]
     TwitterFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
@@ -4919,12 +7430,35 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
         match self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
             SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "twitter_follow" => SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow,
+            "" => SocialProfilesNetworkFollowSegmentOperatorData::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s),
+        })
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperatorData {
     fn default() -> SocialProfilesNetworkFollowSegmentOperatorData {
         SocialProfilesNetworkFollowSegmentOperatorData::Noop
@@ -4934,6 +7468,14 @@ impl SocialProfilesNetworkFollowSegmentOperatorData {
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentOperatorData::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(_)
+        )
+    }
 }
 
 /// Segment by social network in Social Profiles data.
@@ -4970,14 +7512,14 @@ pub struct SocialProfilesNetworkFollowSegment {
     pub value: SocialProfilesNetworkFollowSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "AddressMerge")]
     AddressMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
@@ -4985,12 +7527,35 @@ impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
         match self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
             AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AddressMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AddressMerge" => AddressMergeFieldSegmentConditionType::AddressMerge,
+            "" => AddressMergeFieldSegmentConditionType::Noop,
+            _ => AddressMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AddressMergeFieldSegmentConditionType {
     fn default() -> AddressMergeFieldSegmentConditionType {
         AddressMergeFieldSegmentConditionType::Noop
@@ -5000,12 +7565,17 @@ impl AddressMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AddressMergeFieldSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's address merge field contains/does not contain a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5017,8 +7587,8 @@ pub enum AddressMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentOperator {
@@ -5029,12 +7599,38 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
             AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AddressMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => AddressMergeFieldSegmentOperator::Blank,
+            "blank_not" => AddressMergeFieldSegmentOperator::BlankNot,
+            "contains" => AddressMergeFieldSegmentOperator::Contains,
+            "notcontain" => AddressMergeFieldSegmentOperator::Notcontain,
+            "" => AddressMergeFieldSegmentOperator::Noop,
+            _ => AddressMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for AddressMergeFieldSegmentOperator {
     fn default() -> AddressMergeFieldSegmentOperator {
         AddressMergeFieldSegmentOperator::Noop
@@ -5044,6 +7640,11 @@ impl AddressMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AddressMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by an address-type merge field.
@@ -5082,14 +7683,14 @@ pub struct AddressMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "ZipMerge")]
     ZipMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
@@ -5097,12 +7698,35 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
         match self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
             AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AddressZipMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressZipMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ZipMerge" => AddressZipMergeFieldSegmentConditionType::ZipMerge,
+            "" => AddressZipMergeFieldSegmentConditionType::Noop,
+            _ => AddressZipMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentConditionType {
     fn default() -> AddressZipMergeFieldSegmentConditionType {
         AddressZipMergeFieldSegmentConditionType::Noop
@@ -5112,19 +7736,24 @@ impl AddressZipMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressZipMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AddressZipMergeFieldSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's address merge field is within a given distance from a city or zip.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "geoin")]
     Geoin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
@@ -5132,12 +7761,35 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
         match self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
             AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AddressZipMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressZipMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "geoin" => AddressZipMergeFieldSegmentOperator::Geoin,
+            "" => AddressZipMergeFieldSegmentOperator::Noop,
+            _ => AddressZipMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentOperator {
     fn default() -> AddressZipMergeFieldSegmentOperator {
         AddressZipMergeFieldSegmentOperator::Noop
@@ -5147,6 +7799,11 @@ impl AddressZipMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressZipMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AddressZipMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by an address-type merge field within a given distance.
@@ -5194,14 +7851,14 @@ pub struct AddressZipMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "BirthdayMerge")]
     BirthdayMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
@@ -5209,12 +7866,35 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
         match self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
             BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BirthdayMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BirthdayMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BirthdayMerge" => BirthdayMergeFieldSegmentConditionType::BirthdayMerge,
+            "" => BirthdayMergeFieldSegmentConditionType::Noop,
+            _ => BirthdayMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentConditionType {
     fn default() -> BirthdayMergeFieldSegmentConditionType {
         BirthdayMergeFieldSegmentConditionType::Noop
@@ -5224,12 +7904,17 @@ impl BirthdayMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, BirthdayMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BirthdayMergeFieldSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5241,8 +7926,8 @@ pub enum BirthdayMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
@@ -5253,12 +7938,38 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
             BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BirthdayMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BirthdayMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => BirthdayMergeFieldSegmentOperator::Blank,
+            "blank_not" => BirthdayMergeFieldSegmentOperator::BlankNot,
+            "is" => BirthdayMergeFieldSegmentOperator::Is,
+            "not" => BirthdayMergeFieldSegmentOperator::Not,
+            "" => BirthdayMergeFieldSegmentOperator::Noop,
+            _ => BirthdayMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentOperator {
     fn default() -> BirthdayMergeFieldSegmentOperator {
         BirthdayMergeFieldSegmentOperator::Noop
@@ -5268,6 +7979,11 @@ impl BirthdayMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, BirthdayMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BirthdayMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a contact's birthday.
@@ -5306,14 +8022,14 @@ pub struct BirthdayMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "DateMerge")]
     DateMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentConditionType {
@@ -5321,12 +8037,35 @@ impl std::fmt::Display for DateMergeFieldSegmentConditionType {
         match self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
             DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DateMerge" => DateMergeFieldSegmentConditionType::DateMerge,
+            "" => DateMergeFieldSegmentConditionType::Noop,
+            _ => DateMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateMergeFieldSegmentConditionType {
     fn default() -> DateMergeFieldSegmentConditionType {
         DateMergeFieldSegmentConditionType::Noop
@@ -5336,12 +8075,17 @@ impl DateMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateMergeFieldSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5357,8 +8101,8 @@ pub enum DateMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentOperator {
@@ -5371,12 +8115,40 @@ impl std::fmt::Display for DateMergeFieldSegmentOperator {
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
             DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DateMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => DateMergeFieldSegmentOperator::Blank,
+            "blank_not" => DateMergeFieldSegmentOperator::BlankNot,
+            "greater" => DateMergeFieldSegmentOperator::Greater,
+            "is" => DateMergeFieldSegmentOperator::Is,
+            "less" => DateMergeFieldSegmentOperator::Less,
+            "not" => DateMergeFieldSegmentOperator::Not,
+            "" => DateMergeFieldSegmentOperator::Noop,
+            _ => DateMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for DateMergeFieldSegmentOperator {
     fn default() -> DateMergeFieldSegmentOperator {
         DateMergeFieldSegmentOperator::Noop
@@ -5386,6 +8158,11 @@ impl DateMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, DateMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DateMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a given date merge field.
@@ -5424,14 +8201,14 @@ pub struct DateMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "SelectMerge")]
     SelectMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
@@ -5439,12 +8216,35 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
         match self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
             DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DropdownRadioMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DropdownRadioMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SelectMerge" => DropdownRadioMergeFieldSegmentConditionType::SelectMerge,
+            "" => DropdownRadioMergeFieldSegmentConditionType::Noop,
+            _ => DropdownRadioMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentConditionType {
     fn default() -> DropdownRadioMergeFieldSegmentConditionType {
         DropdownRadioMergeFieldSegmentConditionType::Noop
@@ -5454,12 +8254,20 @@ impl DropdownRadioMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DropdownRadioMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(_)
+        )
+    }
 }
 
 /**
 * Whether the member's merge information is/is not a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5475,8 +8283,8 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
@@ -5489,12 +8297,40 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
             DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DropdownRadioMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DropdownRadioMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => DropdownRadioMergeFieldSegmentOperator::Blank,
+            "blank_not" => DropdownRadioMergeFieldSegmentOperator::BlankNot,
+            "contains" => DropdownRadioMergeFieldSegmentOperator::Contains,
+            "is" => DropdownRadioMergeFieldSegmentOperator::Is,
+            "not" => DropdownRadioMergeFieldSegmentOperator::Not,
+            "notcontain" => DropdownRadioMergeFieldSegmentOperator::Notcontain,
+            "" => DropdownRadioMergeFieldSegmentOperator::Noop,
+            _ => DropdownRadioMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentOperator {
     fn default() -> DropdownRadioMergeFieldSegmentOperator {
         DropdownRadioMergeFieldSegmentOperator::Noop
@@ -5504,6 +8340,11 @@ impl DropdownRadioMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, DropdownRadioMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DropdownRadioMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// An individual segment condition
@@ -5542,14 +8383,14 @@ pub struct DropdownRadioMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "TextMerge")]
     TextMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
@@ -5557,12 +8398,35 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
         match self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
             TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TextNumberMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TextNumberMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "TextMerge" => TextNumberMergeFieldSegmentConditionType::TextMerge,
+            "" => TextNumberMergeFieldSegmentConditionType::Noop,
+            _ => TextNumberMergeFieldSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentConditionType {
     fn default() -> TextNumberMergeFieldSegmentConditionType {
         TextNumberMergeFieldSegmentConditionType::Noop
@@ -5572,12 +8436,17 @@ impl TextNumberMergeFieldSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TextNumberMergeFieldSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TextNumberMergeFieldSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5601,8 +8470,8 @@ pub enum TextNumberMergeFieldSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
@@ -5619,12 +8488,44 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
             TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TextNumberMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TextNumberMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blank" => TextNumberMergeFieldSegmentOperator::Blank,
+            "blank_not" => TextNumberMergeFieldSegmentOperator::BlankNot,
+            "contains" => TextNumberMergeFieldSegmentOperator::Contains,
+            "ends" => TextNumberMergeFieldSegmentOperator::Ends,
+            "greater" => TextNumberMergeFieldSegmentOperator::Greater,
+            "is" => TextNumberMergeFieldSegmentOperator::Is,
+            "less" => TextNumberMergeFieldSegmentOperator::Less,
+            "not" => TextNumberMergeFieldSegmentOperator::Not,
+            "notcontain" => TextNumberMergeFieldSegmentOperator::Notcontain,
+            "starts" => TextNumberMergeFieldSegmentOperator::Starts,
+            "" => TextNumberMergeFieldSegmentOperator::Noop,
+            _ => TextNumberMergeFieldSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentOperator {
     fn default() -> TextNumberMergeFieldSegmentOperator {
         TextNumberMergeFieldSegmentOperator::Noop
@@ -5634,6 +8535,11 @@ impl TextNumberMergeFieldSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, TextNumberMergeFieldSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TextNumberMergeFieldSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by a given text or number merge field.
@@ -5672,14 +8578,14 @@ pub struct TextNumberMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailSegmentConditionType {
     #[serde(rename = "EmailAddress")]
     EmailAddress,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentConditionType {
@@ -5687,12 +8593,35 @@ impl std::fmt::Display for EmailSegmentConditionType {
         match self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
             EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EmailAddress" => EmailSegmentConditionType::EmailAddress,
+            "" => EmailSegmentConditionType::Noop,
+            _ => EmailSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailSegmentConditionType {
     fn default() -> EmailSegmentConditionType {
         EmailSegmentConditionType::Noop
@@ -5702,12 +8631,17 @@ impl EmailSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segmenting based off of a subscriber's email address.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailSegmentField {
     #[serde(rename = "EMAIL")]
     Email,
@@ -5715,8 +8649,8 @@ pub enum EmailSegmentField {
     Merge0,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentField {
@@ -5725,12 +8659,36 @@ impl std::fmt::Display for EmailSegmentField {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
             EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EMAIL" => EmailSegmentField::Email,
+            "merge0" => EmailSegmentField::Merge0,
+            "" => EmailSegmentField::Noop,
+            _ => EmailSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailSegmentField {
     fn default() -> EmailSegmentField {
         EmailSegmentField::Noop
@@ -5740,12 +8698,17 @@ impl EmailSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailSegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -5765,8 +8728,8 @@ pub enum EmailSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentOperator {
@@ -5781,12 +8744,42 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
             EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "contains" => EmailSegmentOperator::Contains,
+            "ends" => EmailSegmentOperator::Ends,
+            "greater" => EmailSegmentOperator::Greater,
+            "is" => EmailSegmentOperator::Is,
+            "less" => EmailSegmentOperator::Less,
+            "not" => EmailSegmentOperator::Not,
+            "notcontain" => EmailSegmentOperator::Notcontain,
+            "starts" => EmailSegmentOperator::Starts,
+            "" => EmailSegmentOperator::Noop,
+            _ => EmailSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailSegmentOperator {
     fn default() -> EmailSegmentOperator {
         EmailSegmentOperator::Noop
@@ -5796,6 +8789,11 @@ impl EmailSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by email address.
@@ -5827,14 +8825,14 @@ pub struct EmailSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "PredictedGender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentConditionType {
@@ -5842,12 +8840,35 @@ impl std::fmt::Display for PredictedGenderSegmentConditionType {
         match self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
             PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedGenderSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedGenderSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PredictedGender" => PredictedGenderSegmentConditionType::PredictedGender,
+            "" => PredictedGenderSegmentConditionType::Noop,
+            _ => PredictedGenderSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedGenderSegmentConditionType {
     fn default() -> PredictedGenderSegmentConditionType {
         PredictedGenderSegmentConditionType::Noop
@@ -5857,19 +8878,24 @@ impl PredictedGenderSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedGenderSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedGenderSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by predicted gender.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedGenderSegmentField {
     #[serde(rename = "predicted_gender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentField {
@@ -5877,12 +8903,35 @@ impl std::fmt::Display for PredictedGenderSegmentField {
         match self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
             PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedGenderSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedGenderSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "predicted_gender" => PredictedGenderSegmentField::PredictedGender,
+            "" => PredictedGenderSegmentField::Noop,
+            _ => PredictedGenderSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedGenderSegmentField {
     fn default() -> PredictedGenderSegmentField {
         PredictedGenderSegmentField::Noop
@@ -5892,6 +8941,11 @@ impl PredictedGenderSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedGenderSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedGenderSegmentField::Unknown(_))
+    }
 }
 
 /// Segment by predicted gender.
@@ -5922,14 +8976,14 @@ pub struct PredictedGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "PredictedAge")]
     PredictedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentConditionType {
@@ -5937,12 +8991,35 @@ impl std::fmt::Display for PredictedAgeSegmentConditionType {
         match self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
             PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedAgeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PredictedAge" => PredictedAgeSegmentConditionType::PredictedAge,
+            "" => PredictedAgeSegmentConditionType::Noop,
+            _ => PredictedAgeSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedAgeSegmentConditionType {
     fn default() -> PredictedAgeSegmentConditionType {
         PredictedAgeSegmentConditionType::Noop
@@ -5952,19 +9029,24 @@ impl PredictedAgeSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedAgeSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by predicted age.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedAgeSegmentField {
     #[serde(rename = "predicted_age_range")]
     PredictedAgeRange,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentField {
@@ -5972,12 +9054,35 @@ impl std::fmt::Display for PredictedAgeSegmentField {
         match self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
             PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedAgeSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "predicted_age_range" => PredictedAgeSegmentField::PredictedAgeRange,
+            "" => PredictedAgeSegmentField::Noop,
+            _ => PredictedAgeSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedAgeSegmentField {
     fn default() -> PredictedAgeSegmentField {
         PredictedAgeSegmentField::Noop
@@ -5987,19 +9092,24 @@ impl PredictedAgeSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedAgeSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Members who are/not the exact criteria listed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "is")]
     Is,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperator {
@@ -6007,12 +9117,35 @@ impl std::fmt::Display for PredictedAgeSegmentOperator {
         match self {
             PredictedAgeSegmentOperator::Is => "is",
             PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedAgeSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "is" => PredictedAgeSegmentOperator::Is,
+            "" => PredictedAgeSegmentOperator::Noop,
+            _ => PredictedAgeSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedAgeSegmentOperator {
     fn default() -> PredictedAgeSegmentOperator {
         PredictedAgeSegmentOperator::Noop
@@ -6022,12 +9155,17 @@ impl PredictedAgeSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedAgeSegmentOperator::Unknown(_))
+    }
 }
 
 /**
 * The predicted age to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -6043,8 +9181,8 @@ pub enum PredictedAgeSegmentOperatorData {
     SixtyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperatorData {
@@ -6057,12 +9195,40 @@ impl std::fmt::Display for PredictedAgeSegmentOperatorData {
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
             PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PredictedAgeSegmentOperatorData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentOperatorData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "18-24" => PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour,
+            "25-34" => PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour,
+            "35-44" => PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour,
+            "45-54" => PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour,
+            "55-64" => PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour,
+            "65+" => PredictedAgeSegmentOperatorData::SixtyFive,
+            "" => PredictedAgeSegmentOperatorData::Noop,
+            _ => PredictedAgeSegmentOperatorData::Unknown(s),
+        })
+    }
+}
+
 impl Default for PredictedAgeSegmentOperatorData {
     fn default() -> PredictedAgeSegmentOperatorData {
         PredictedAgeSegmentOperatorData::Noop
@@ -6072,6 +9238,11 @@ impl PredictedAgeSegmentOperatorData {
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentOperatorData::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PredictedAgeSegmentOperatorData::Unknown(_))
+    }
 }
 
 /// Segment by predicted age.
@@ -6102,14 +9273,14 @@ pub struct PredictedAgeSegment {
     pub value: PredictedAgeSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "NewSubscribers")]
     NewSubscribers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
@@ -6117,12 +9288,35 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
         match self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
             NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NewSubscribersPrebuiltSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "NewSubscribers" => NewSubscribersPrebuiltSegmentConditionType::NewSubscribers,
+            "" => NewSubscribersPrebuiltSegmentConditionType::Noop,
+            _ => NewSubscribersPrebuiltSegmentConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentConditionType {
     fn default() -> NewSubscribersPrebuiltSegmentConditionType {
         NewSubscribersPrebuiltSegmentConditionType::Noop
@@ -6132,19 +9326,24 @@ impl NewSubscribersPrebuiltSegmentConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NewSubscribersPrebuiltSegmentConditionType::Unknown(_))
+    }
 }
 
 /**
 * Segment by when people subscribed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
@@ -6152,12 +9351,35 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
         match self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
             NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NewSubscribersPrebuiltSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "timestamp_opt" => NewSubscribersPrebuiltSegmentField::TimestampOpt,
+            "" => NewSubscribersPrebuiltSegmentField::Noop,
+            _ => NewSubscribersPrebuiltSegmentField::Unknown(s),
+        })
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentField {
     fn default() -> NewSubscribersPrebuiltSegmentField {
         NewSubscribersPrebuiltSegmentField::Noop
@@ -6167,19 +9389,24 @@ impl NewSubscribersPrebuiltSegmentField {
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NewSubscribersPrebuiltSegmentField::Unknown(_))
+    }
 }
 
 /**
 * Whe the event took place, namely within a time frame.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "date_within")]
     DateWithin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
@@ -6187,12 +9414,35 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
         match self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
             NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NewSubscribersPrebuiltSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "date_within" => NewSubscribersPrebuiltSegmentOperator::DateWithin,
+            "" => NewSubscribersPrebuiltSegmentOperator::Noop,
+            _ => NewSubscribersPrebuiltSegmentOperator::Unknown(s),
+        })
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentOperator {
     fn default() -> NewSubscribersPrebuiltSegmentOperator {
         NewSubscribersPrebuiltSegmentOperator::Noop
@@ -6202,6 +9452,11 @@ impl NewSubscribersPrebuiltSegmentOperator {
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentOperator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NewSubscribersPrebuiltSegmentOperator::Unknown(_))
+    }
 }
 
 /// Segment by when people subscribed.
@@ -6996,7 +10251,7 @@ pub struct Tracking {
 /**
 * The type of Automation workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WorkflowType {
     #[serde(rename = "abandonedBrowse")]
     AbandonedBrowse,
@@ -7034,8 +10289,8 @@ pub enum WorkflowType {
     WelcomeSeries,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -7059,12 +10314,51 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
             WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WorkflowType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "abandonedBrowse" => WorkflowType::AbandonedBrowse,
+            "abandonedCart" => WorkflowType::AbandonedCart,
+            "api" => WorkflowType::Api,
+            "bestCustomers" => WorkflowType::BestCustomers,
+            "categoryFollowup" => WorkflowType::CategoryFollowup,
+            "dateAdded" => WorkflowType::DateAdded,
+            "emailFollowup" => WorkflowType::EmailFollowup,
+            "emailSeries" => WorkflowType::EmailSeries,
+            "groupAdd" => WorkflowType::GroupAdd,
+            "groupRemove" => WorkflowType::GroupRemove,
+            "mandrill" => WorkflowType::Mandrill,
+            "productFollowup" => WorkflowType::ProductFollowup,
+            "purchaseFollowup" => WorkflowType::PurchaseFollowup,
+            "recurringEvent" => WorkflowType::RecurringEvent,
+            "specialEvent" => WorkflowType::SpecialEvent,
+            "visitUrl" => WorkflowType::VisitUrl,
+            "welcomeSeries" => WorkflowType::WelcomeSeries,
+            "" => WorkflowType::Noop,
+            _ => WorkflowType::Unknown(s),
+        })
+    }
+}
+
 impl Default for WorkflowType {
     fn default() -> WorkflowType {
         WorkflowType::Noop
@@ -7074,9 +10368,14 @@ impl WorkflowType {
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WorkflowType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Days {
     #[serde(rename = "friday")]
     Friday,
@@ -7094,8 +10393,8 @@ pub enum Days {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Days {
@@ -7109,12 +10408,41 @@ impl std::fmt::Display for Days {
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
             Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Days {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Days {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "friday" => Days::Friday,
+            "monday" => Days::Monday,
+            "saturday" => Days::Saturday,
+            "sunday" => Days::Sunday,
+            "thursday" => Days::Thursday,
+            "tuesday" => Days::Tuesday,
+            "wednesday" => Days::Wednesday,
+            "" => Days::Noop,
+            _ => Days::Unknown(s),
+        })
+    }
+}
+
 impl Default for Days {
     fn default() -> Days {
         Days::Noop
@@ -7124,12 +10452,17 @@ impl Days {
     pub fn is_noop(&self) -> bool {
         matches!(self, Days::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Days::Unknown(_))
+    }
 }
 
 /**
 * When to send the Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailSendTimeSettings {
     #[serde(rename = "send_asap")]
     SendAsap,
@@ -7139,8 +10472,8 @@ pub enum EmailSendTimeSettings {
     SendBetween,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSendTimeSettings {
@@ -7150,12 +10483,37 @@ impl std::fmt::Display for EmailSendTimeSettings {
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
             EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailSendTimeSettings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSendTimeSettings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "send_asap" => EmailSendTimeSettings::SendAsap,
+            "send_at" => EmailSendTimeSettings::SendAt,
+            "send_between" => EmailSendTimeSettings::SendBetween,
+            "" => EmailSendTimeSettings::Noop,
+            _ => EmailSendTimeSettings::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailSendTimeSettings {
     fn default() -> EmailSendTimeSettings {
         EmailSendTimeSettings::Noop
@@ -7165,6 +10523,11 @@ impl EmailSendTimeSettings {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSendTimeSettings::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailSendTimeSettings::Unknown(_))
+    }
 }
 
 /// The hours an Automation workflow can send.
@@ -7489,7 +10852,7 @@ pub struct AutomationWorkflow {
 /**
 * The type of delay for an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DelayType {
     #[serde(rename = "day")]
     Day,
@@ -7501,8 +10864,8 @@ pub enum DelayType {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayType {
@@ -7513,12 +10876,38 @@ impl std::fmt::Display for DelayType {
             DelayType::Now => "now",
             DelayType::Week => "week",
             DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DelayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "day" => DelayType::Day,
+            "hour" => DelayType::Hour,
+            "now" => DelayType::Now,
+            "week" => DelayType::Week,
+            "" => DelayType::Noop,
+            _ => DelayType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DelayType {
     fn default() -> DelayType {
         DelayType::Noop
@@ -7528,12 +10917,17 @@ impl DelayType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DelayType::Unknown(_))
+    }
 }
 
 /**
 * Whether the delay settings describe before or after the delay action of an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Direction {
     #[serde(rename = "after")]
     After,
@@ -7541,8 +10935,8 @@ pub enum Direction {
     Before,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -7551,12 +10945,36 @@ impl std::fmt::Display for Direction {
             Direction::After => "after",
             Direction::Before => "before",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "after" => Direction::After,
+            "before" => Direction::Before,
+            "" => Direction::Noop,
+            _ => Direction::Unknown(s),
+        })
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -7566,12 +10984,17 @@ impl Direction {
     pub fn is_noop(&self) -> bool {
         matches!(self, Direction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Direction::Unknown(_))
+    }
 }
 
 /**
 * The action that triggers the delay of an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Action {
     #[serde(rename = "annual")]
     Annual,
@@ -7641,8 +11064,8 @@ pub enum Action {
     TagAdd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -7682,12 +11105,67 @@ impl std::fmt::Display for Action {
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "annual" => Action::Annual,
+            "api" => Action::Api,
+            "birthday" => Action::Birthday,
+            "campaign_sent" => Action::CampaignSent,
+            "campaign_specific_clicked" => Action::CampaignSpecificClicked,
+            "clicked_email" => Action::ClickedEmail,
+            "date" => Action::Date,
+            "date_added" => Action::DateAdded,
+            "ecomm_abandoned_cart" => Action::EcommAbandonedCart,
+            "ecomm_bought_any" => Action::EcommBoughtAny,
+            "ecomm_bought_category" => Action::EcommBoughtCategory,
+            "ecomm_bought_product" => Action::EcommBoughtProduct,
+            "ecomm_not_bought_any" => Action::EcommNotBoughtAny,
+            "goal" => Action::Goal,
+            "group_add" => Action::GroupAdd,
+            "group_remove" => Action::GroupRemove,
+            "mandrill_any" => Action::MandrillAny,
+            "mandrill_clicked" => Action::MandrillClicked,
+            "mandrill_opened" => Action::MandrillOpened,
+            "mandrill_sent" => Action::MandrillSent,
+            "manual" => Action::Manual,
+            "merge_changed" => Action::MergeChanged,
+            "not_clicked_email" => Action::NotClickedEmail,
+            "not_opened_email" => Action::NotOpenedEmail,
+            "opened_email" => Action::OpenedEmail,
+            "previous_campaign_clicked_any" => Action::PreviousCampaignClickedAny,
+            "previous_campaign_not_clicked_any" => Action::PreviousCampaignNotClickedAny,
+            "previous_campaign_not_opened" => Action::PreviousCampaignNotOpened,
+            "previous_campaign_opened" => Action::PreviousCampaignOpened,
+            "previous_campaign_sent" => Action::PreviousCampaignSent,
+            "previous_campaign_specific_clicked" => Action::PreviousCampaignSpecificClicked,
+            "signup" => Action::Signup,
+            "tag_add" => Action::TagAdd,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        })
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -7697,6 +11175,11 @@ impl Action {
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Action::Unknown(_))
+    }
 }
 
 /// The delay settings for an Automation email.
@@ -8365,14 +11848,14 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
 /**
 * Whether the delay settings describe before or after the delay action of an automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DelayDirection {
     #[serde(rename = "after")]
     After,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayDirection {
@@ -8380,12 +11863,35 @@ impl std::fmt::Display for DelayDirection {
         match self {
             DelayDirection::After => "after",
             DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DelayDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "after" => DelayDirection::After,
+            "" => DelayDirection::Noop,
+            _ => DelayDirection::Unknown(s),
+        })
+    }
+}
+
 impl Default for DelayDirection {
     fn default() -> DelayDirection {
         DelayDirection::Noop
@@ -8395,12 +11901,17 @@ impl DelayDirection {
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayDirection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DelayDirection::Unknown(_))
+    }
 }
 
 /**
 * The action that triggers the delay of an automation emails.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DelayAction {
     #[serde(rename = "ecomm_abandoned_browse")]
     EcommAbandonedBrowse,
@@ -8410,8 +11921,8 @@ pub enum DelayAction {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayAction {
@@ -8421,12 +11932,37 @@ impl std::fmt::Display for DelayAction {
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
             DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DelayAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecomm_abandoned_browse" => DelayAction::EcommAbandonedBrowse,
+            "ecomm_abandoned_cart" => DelayAction::EcommAbandonedCart,
+            "signup" => DelayAction::Signup,
+            "" => DelayAction::Noop,
+            _ => DelayAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for DelayAction {
     fn default() -> DelayAction {
         DelayAction::Noop
@@ -8436,6 +11972,11 @@ impl DelayAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DelayAction::Unknown(_))
+    }
 }
 
 /// The delay settings for an automation email.
@@ -8775,7 +12316,7 @@ pub struct RemovedSubscribers {
 /**
 * The status of the batch call. [Learn more](https://mailchimp.com/developer/marketing/guides/run-async-requests-batch-endpoint/#check-the-status-of-a-batch-operation) about the batch operation status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BatchOperationsStatus {
     #[serde(rename = "finalizing")]
     Finalizing,
@@ -8789,8 +12330,8 @@ pub enum BatchOperationsStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BatchOperationsStatus {
@@ -8802,12 +12343,39 @@ impl std::fmt::Display for BatchOperationsStatus {
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
             BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BatchOperationsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BatchOperationsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "finalizing" => BatchOperationsStatus::Finalizing,
+            "finished" => BatchOperationsStatus::Finished,
+            "pending" => BatchOperationsStatus::Pending,
+            "preprocessing" => BatchOperationsStatus::Preprocessing,
+            "started" => BatchOperationsStatus::Started,
+            "" => BatchOperationsStatus::Noop,
+            _ => BatchOperationsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for BatchOperationsStatus {
     fn default() -> BatchOperationsStatus {
         BatchOperationsStatus::Noop
@@ -8817,6 +12385,11 @@ impl BatchOperationsStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, BatchOperationsStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BatchOperationsStatus::Unknown(_))
+    }
 }
 
 /// The status of a batch request
@@ -8938,7 +12511,7 @@ pub struct BatchOperations {
 /**
 * The HTTP method to use for the operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HttpMethod {
     #[serde(rename = "DELETE")]
     Delete,
@@ -8952,8 +12525,8 @@ pub enum HttpMethod {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HttpMethod {
@@ -8965,12 +12538,39 @@ impl std::fmt::Display for HttpMethod {
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
             HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HttpMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DELETE" => HttpMethod::Delete,
+            "GET" => HttpMethod::Get,
+            "PATCH" => HttpMethod::Patch,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "" => HttpMethod::Noop,
+            _ => HttpMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for HttpMethod {
     fn default() -> HttpMethod {
         HttpMethod::Noop
@@ -8980,6 +12580,11 @@ impl HttpMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, HttpMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HttpMethod::Unknown(_))
+    }
 }
 
 /// Any request query parameters. Example parameters: {"count":10, "offset":0}
@@ -9264,7 +12869,7 @@ pub struct CampaignFolders {
 /**
 * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CampaignType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -9278,8 +12883,8 @@ pub enum CampaignType {
     Variate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignType {
@@ -9291,12 +12896,39 @@ impl std::fmt::Display for CampaignType {
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
             CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CampaignType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "absplit" => CampaignType::Absplit,
+            "plaintext" => CampaignType::Plaintext,
+            "regular" => CampaignType::Regular,
+            "rss" => CampaignType::Rss,
+            "variate" => CampaignType::Variate,
+            "" => CampaignType::Noop,
+            _ => CampaignType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CampaignType {
     fn default() -> CampaignType {
         CampaignType::Noop
@@ -9306,12 +12938,17 @@ impl CampaignType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CampaignType::Unknown(_))
+    }
 }
 
 /**
 * The status of the campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetCampaignsStatus {
     #[serde(rename = "paused")]
     Paused,
@@ -9325,8 +12962,8 @@ pub enum GetCampaignsStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCampaignsStatus {
@@ -9338,12 +12975,39 @@ impl std::fmt::Display for GetCampaignsStatus {
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
             GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetCampaignsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCampaignsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "paused" => GetCampaignsStatus::Paused,
+            "save" => GetCampaignsStatus::Save,
+            "schedule" => GetCampaignsStatus::Schedule,
+            "sending" => GetCampaignsStatus::Sending,
+            "sent" => GetCampaignsStatus::Sent,
+            "" => GetCampaignsStatus::Noop,
+            _ => GetCampaignsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetCampaignsStatus {
     fn default() -> GetCampaignsStatus {
         GetCampaignsStatus::Noop
@@ -9353,12 +13017,17 @@ impl GetCampaignsStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCampaignsStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetCampaignsStatus::Unknown(_))
+    }
 }
 
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortField {
     #[serde(rename = "create_time")]
     CreateTime,
@@ -9366,8 +13035,8 @@ pub enum SortField {
     SendTime,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortField {
@@ -9376,12 +13045,36 @@ impl std::fmt::Display for SortField {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
             SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "create_time" => SortField::CreateTime,
+            "send_time" => SortField::SendTime,
+            "" => SortField::Noop,
+            _ => SortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortField {
     fn default() -> SortField {
         SortField::Noop
@@ -9391,12 +13084,17 @@ impl SortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, SortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortField::Unknown(_))
+    }
 }
 
 /**
 * Determines the order direction for sorted results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortDir {
     #[serde(rename = "ASC")]
     Asc,
@@ -9404,8 +13102,8 @@ pub enum SortDir {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortDir {
@@ -9414,12 +13112,36 @@ impl std::fmt::Display for SortDir {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
             SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortDir {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortDir {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ASC" => SortDir::Asc,
+            "DESC" => SortDir::Desc,
+            "" => SortDir::Noop,
+            _ => SortDir::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortDir {
     fn default() -> SortDir {
         SortDir::Noop
@@ -9429,12 +13151,17 @@ impl SortDir {
     pub fn is_noop(&self) -> bool {
         matches!(self, SortDir::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortDir::Unknown(_))
+    }
 }
 
 /**
 * The current status of the campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CampaignStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -9454,8 +13181,8 @@ pub enum CampaignStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -9470,12 +13197,42 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
             CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CampaignStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "archived" => CampaignStatus::Archived,
+            "canceled" => CampaignStatus::Canceled,
+            "canceling" => CampaignStatus::Canceling,
+            "paused" => CampaignStatus::Paused,
+            "save" => CampaignStatus::Save,
+            "schedule" => CampaignStatus::Schedule,
+            "sending" => CampaignStatus::Sending,
+            "sent" => CampaignStatus::Sent,
+            "" => CampaignStatus::Noop,
+            _ => CampaignStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for CampaignStatus {
     fn default() -> CampaignStatus {
         CampaignStatus::Noop
@@ -9485,12 +13242,17 @@ impl CampaignStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CampaignStatus::Unknown(_))
+    }
 }
 
 /**
 * How the campaign's content is put together.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ContentType {
     #[serde(rename = "html")]
     Html,
@@ -9502,8 +13264,8 @@ pub enum ContentType {
     Url,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContentType {
@@ -9514,12 +13276,38 @@ impl std::fmt::Display for ContentType {
             ContentType::Template => "template",
             ContentType::Url => "url",
             ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "html" => ContentType::Html,
+            "multichannel" => ContentType::Multichannel,
+            "template" => ContentType::Template,
+            "url" => ContentType::Url,
+            "" => ContentType::Noop,
+            _ => ContentType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ContentType {
     fn default() -> ContentType {
         ContentType::Noop
@@ -9529,6 +13317,11 @@ impl ContentType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ContentType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ContentType::Unknown(_))
+    }
 }
 
 /// The settings for your campaign, including subject, from name, reply-to address, and more.
@@ -9684,7 +13477,7 @@ pub struct GetCampaignsResponseCampaignSettings {
 /**
 * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WinnerCriteria {
     #[serde(rename = "clicks")]
     Clicks,
@@ -9696,8 +13489,8 @@ pub enum WinnerCriteria {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -9708,12 +13501,38 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WinnerCriteria {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WinnerCriteria {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "clicks" => WinnerCriteria::Clicks,
+            "manual" => WinnerCriteria::Manual,
+            "opens" => WinnerCriteria::Opens,
+            "total_revenue" => WinnerCriteria::TotalRevenue,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::Unknown(s),
+        })
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -9723,6 +13542,11 @@ impl WinnerCriteria {
     pub fn is_noop(&self) -> bool {
         matches!(self, WinnerCriteria::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WinnerCriteria::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9895,7 +13719,7 @@ pub struct ABTestOptions {
 /**
 * The frequency of the RSS Campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Frequency {
     #[serde(rename = "daily")]
     Daily,
@@ -9905,8 +13729,8 @@ pub enum Frequency {
     Weekly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -9916,12 +13740,37 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "monthly",
             Frequency::Weekly => "weekly",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Frequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Frequency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "daily" => Frequency::Daily,
+            "monthly" => Frequency::Monthly,
+            "weekly" => Frequency::Weekly,
+            "" => Frequency::Noop,
+            _ => Frequency::Unknown(s),
+        })
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -9931,6 +13780,11 @@ impl Frequency {
     pub fn is_noop(&self) -> bool {
         matches!(self, Frequency::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Frequency::Unknown(_))
+    }
 }
 
 /// The days of the week to send a daily RSS Campaign.
@@ -10071,7 +13925,7 @@ pub struct RssOpts {
 /**
 * The type of AB split to run.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SplitTest {
     #[serde(rename = "from_name")]
     FromName,
@@ -10081,8 +13935,8 @@ pub enum SplitTest {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SplitTest {
@@ -10092,12 +13946,37 @@ impl std::fmt::Display for SplitTest {
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
             SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SplitTest {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SplitTest {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "from_name" => SplitTest::FromName,
+            "schedule" => SplitTest::Schedule,
+            "subject" => SplitTest::Subject,
+            "" => SplitTest::Noop,
+            _ => SplitTest::Unknown(s),
+        })
+    }
+}
+
 impl Default for SplitTest {
     fn default() -> SplitTest {
         SplitTest::Noop
@@ -10107,12 +13986,17 @@ impl SplitTest {
     pub fn is_noop(&self) -> bool {
         matches!(self, SplitTest::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SplitTest::Unknown(_))
+    }
 }
 
 /**
 * How we should evaluate a winner. Based on 'opens', 'clicks', or 'manual'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PickWinner {
     #[serde(rename = "clicks")]
     Clicks,
@@ -10122,8 +14006,8 @@ pub enum PickWinner {
     Opens,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PickWinner {
@@ -10133,12 +14017,37 @@ impl std::fmt::Display for PickWinner {
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
             PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PickWinner {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PickWinner {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "clicks" => PickWinner::Clicks,
+            "manual" => PickWinner::Manual,
+            "opens" => PickWinner::Opens,
+            "" => PickWinner::Noop,
+            _ => PickWinner::Unknown(s),
+        })
+    }
+}
+
 impl Default for PickWinner {
     fn default() -> PickWinner {
         PickWinner::Noop
@@ -10148,12 +14057,17 @@ impl PickWinner {
     pub fn is_noop(&self) -> bool {
         matches!(self, PickWinner::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PickWinner::Unknown(_))
+    }
 }
 
 /**
 * How unit of time for measuring the winner ('hours' or 'days'). This cannot be changed after a campaign is sent.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WaitTime {
     #[serde(rename = "days")]
     Days,
@@ -10161,8 +14075,8 @@ pub enum WaitTime {
     Hours,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaitTime {
@@ -10171,12 +14085,36 @@ impl std::fmt::Display for WaitTime {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
             WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WaitTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WaitTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "days" => WaitTime::Days,
+            "hours" => WaitTime::Hours,
+            "" => WaitTime::Noop,
+            _ => WaitTime::Unknown(s),
+        })
+    }
+}
+
 impl Default for WaitTime {
     fn default() -> WaitTime {
         WaitTime::Noop
@@ -10186,6 +14124,11 @@ impl WaitTime {
     pub fn is_noop(&self) -> bool {
         matches!(self, WaitTime::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WaitTime::Unknown(_))
+    }
 }
 
 /// [A/B Testing](https://mailchimp.com/help/about-ab-testing-campaigns/) options for a campaign.
@@ -10406,7 +14349,7 @@ pub struct GetCampaignsResponseCampaignReportSummary {
 /**
 * The current state of a campaign delivery.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CampaignDeliveryStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -10418,8 +14361,8 @@ pub enum CampaignDeliveryStatus {
     Delivering,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignDeliveryStatus {
@@ -10430,12 +14373,38 @@ impl std::fmt::Display for CampaignDeliveryStatus {
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
             CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CampaignDeliveryStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignDeliveryStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "canceled" => CampaignDeliveryStatus::Canceled,
+            "canceling" => CampaignDeliveryStatus::Canceling,
+            "delivered" => CampaignDeliveryStatus::Delivered,
+            "delivering" => CampaignDeliveryStatus::Delivering,
+            "" => CampaignDeliveryStatus::Noop,
+            _ => CampaignDeliveryStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for CampaignDeliveryStatus {
     fn default() -> CampaignDeliveryStatus {
         CampaignDeliveryStatus::Noop
@@ -10445,6 +14414,11 @@ impl CampaignDeliveryStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignDeliveryStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CampaignDeliveryStatus::Unknown(_))
+    }
 }
 
 /// Updates on campaigns in the process of sending.
@@ -10967,7 +14941,7 @@ pub struct RssOptions {
 /**
 * How the campaign's content is put together. The old drag and drop editor uses 'template' while the new editor uses 'multichannel'. Defaults to template.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CreatedCampaignContentType {
     #[serde(rename = "multichannel")]
     Multichannel,
@@ -10975,8 +14949,8 @@ pub enum CreatedCampaignContentType {
     Template,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreatedCampaignContentType {
@@ -10985,12 +14959,36 @@ impl std::fmt::Display for CreatedCampaignContentType {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
             CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CreatedCampaignContentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CreatedCampaignContentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "multichannel" => CreatedCampaignContentType::Multichannel,
+            "template" => CreatedCampaignContentType::Template,
+            "" => CreatedCampaignContentType::Noop,
+            _ => CreatedCampaignContentType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CreatedCampaignContentType {
     fn default() -> CreatedCampaignContentType {
         CreatedCampaignContentType::Noop
@@ -11000,6 +14998,11 @@ impl CreatedCampaignContentType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CreatedCampaignContentType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CreatedCampaignContentType::Unknown(_))
+    }
 }
 
 /// A summary of an individual campaign's settings and content.
@@ -11742,7 +15745,7 @@ pub struct PostCampaignsActionsScheduleRequest {
 /**
 * Choose the type of test email to send.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SendType {
     #[serde(rename = "html")]
     Html,
@@ -11750,8 +15753,8 @@ pub enum SendType {
     Plaintext,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendType {
@@ -11760,12 +15763,36 @@ impl std::fmt::Display for SendType {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
             SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SendType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SendType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "html" => SendType::Html,
+            "plaintext" => SendType::Plaintext,
+            "" => SendType::Noop,
+            _ => SendType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SendType {
     fn default() -> SendType {
         SendType::Noop
@@ -11775,6 +15802,11 @@ impl SendType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SendType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SendType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11899,7 +15931,7 @@ pub struct Template {
 /**
 * The type of encoded file. Defaults to zip.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ArchiveType {
     #[serde(rename = "tar")]
     Tar,
@@ -11915,8 +15947,8 @@ pub enum ArchiveType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchiveType {
@@ -11929,12 +15961,40 @@ impl std::fmt::Display for ArchiveType {
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
             ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ArchiveType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ArchiveType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "tar" => ArchiveType::Tar,
+            "tar.bz2" => ArchiveType::TarBz2,
+            "tar.gz" => ArchiveType::TarGz,
+            "tbz" => ArchiveType::Tbz,
+            "tgz" => ArchiveType::Tgz,
+            "zip" => ArchiveType::Zip,
+            "" => ArchiveType::Noop,
+            _ => ArchiveType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ArchiveType {
     fn default() -> ArchiveType {
         ArchiveType::Noop
@@ -11944,6 +16004,11 @@ impl ArchiveType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ArchiveType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ArchiveType::Unknown(_))
+    }
 }
 
 /// Available when uploading an archive to create campaign content. The archive should include all campaign content and images. [Learn more](https://mailchimp.com/help/import-a-custom-html-template/).
@@ -12088,7 +16153,7 @@ pub struct CampaignContentData {
 /**
 * The source of the feedback.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Source {
     #[serde(rename = "android")]
     Android,
@@ -12104,8 +16169,8 @@ pub enum Source {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -12118,12 +16183,40 @@ impl std::fmt::Display for Source {
             Source::Sms => "sms",
             Source::Web => "web",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "android" => Source::Android,
+            "api" => Source::Api,
+            "email" => Source::Email,
+            "ios" => Source::Ios,
+            "sms" => Source::Sms,
+            "web" => Source::Web,
+            "" => Source::Noop,
+            _ => Source::Unknown(s),
+        })
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -12133,6 +16226,11 @@ impl Source {
     pub fn is_noop(&self) -> bool {
         matches!(self, Source::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Source::Unknown(_))
+    }
 }
 
 /// A specific feedback message from a specific campaign.
@@ -12442,7 +16540,7 @@ pub struct CampaignFeedbackDataType {
 /**
 * The item type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SendChecklistItemsType {
     #[serde(rename = "error")]
     Error,
@@ -12452,8 +16550,8 @@ pub enum SendChecklistItemsType {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendChecklistItemsType {
@@ -12463,12 +16561,37 @@ impl std::fmt::Display for SendChecklistItemsType {
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
             SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SendChecklistItemsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SendChecklistItemsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "error" => SendChecklistItemsType::Error,
+            "success" => SendChecklistItemsType::Success,
+            "warning" => SendChecklistItemsType::Warning,
+            "" => SendChecklistItemsType::Noop,
+            _ => SendChecklistItemsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SendChecklistItemsType {
     fn default() -> SendChecklistItemsType {
         SendChecklistItemsType::Noop
@@ -12478,6 +16601,11 @@ impl SendChecklistItemsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SendChecklistItemsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SendChecklistItemsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12704,7 +16832,7 @@ pub struct ConnectedSite {
 /**
 * Whether a conversation message has been marked as read.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IsRead {
     #[serde(rename = "false")]
     False,
@@ -12712,8 +16840,8 @@ pub enum IsRead {
     True,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IsRead {
@@ -12722,12 +16850,36 @@ impl std::fmt::Display for IsRead {
             IsRead::False => "false",
             IsRead::True => "true",
             IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IsRead {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IsRead {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "false" => IsRead::False,
+            "true" => IsRead::True,
+            "" => IsRead::Noop,
+            _ => IsRead::Unknown(s),
+        })
+    }
+}
+
 impl Default for IsRead {
     fn default() -> IsRead {
         IsRead::Noop
@@ -12737,6 +16889,11 @@ impl IsRead {
     pub fn is_noop(&self) -> bool {
         matches!(self, IsRead::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IsRead::Unknown(_))
+    }
 }
 
 /// The most recent message in the conversation.
@@ -13062,14 +17219,14 @@ pub struct CollectionOfConversationMessages {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetFileManagerFilesSortField {
     #[serde(rename = "added_date")]
     AddedDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetFileManagerFilesSortField {
@@ -13077,12 +17234,35 @@ impl std::fmt::Display for GetFileManagerFilesSortField {
         match self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
             GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetFileManagerFilesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetFileManagerFilesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "added_date" => GetFileManagerFilesSortField::AddedDate,
+            "" => GetFileManagerFilesSortField::Noop,
+            _ => GetFileManagerFilesSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetFileManagerFilesSortField {
     fn default() -> GetFileManagerFilesSortField {
         GetFileManagerFilesSortField::Noop
@@ -13092,12 +17272,17 @@ impl GetFileManagerFilesSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetFileManagerFilesSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetFileManagerFilesSortField::Unknown(_))
+    }
 }
 
 /**
 * The type of file in the File Manager.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "file")]
     File,
@@ -13105,8 +17290,8 @@ pub enum FileType {
     Image,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -13115,12 +17300,36 @@ impl std::fmt::Display for FileType {
             FileType::File => "file",
             FileType::Image => "image",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "file" => FileType::File,
+            "image" => FileType::Image,
+            "" => FileType::Noop,
+            _ => FileType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -13130,6 +17339,11 @@ impl FileType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FileType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FileType::Unknown(_))
+    }
 }
 
 /// An individual file listed in the File Manager.
@@ -13435,14 +17649,14 @@ pub struct FileManagerFolders {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetListsSortField {
     #[serde(rename = "date_created")]
     DateCreated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsSortField {
@@ -13450,12 +17664,35 @@ impl std::fmt::Display for GetListsSortField {
         match self {
             GetListsSortField::DateCreated => "date_created",
             GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetListsSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "date_created" => GetListsSortField::DateCreated,
+            "" => GetListsSortField::Noop,
+            _ => GetListsSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetListsSortField {
     fn default() -> GetListsSortField {
         GetListsSortField::Noop
@@ -13465,6 +17702,11 @@ impl GetListsSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetListsSortField::Unknown(_))
+    }
 }
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
@@ -13590,7 +17832,7 @@ pub struct CampaignDefaults {
 /**
 * Legacy - visibility settings are no longer used
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "prv")]
     Prv,
@@ -13598,8 +17840,8 @@ pub enum Visibility {
     Pub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -13608,12 +17850,36 @@ impl std::fmt::Display for Visibility {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "prv" => Visibility::Prv,
+            "pub" => Visibility::Pub,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        })
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -13623,6 +17889,11 @@ impl Visibility {
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Visibility::Unknown(_))
+    }
 }
 
 /// Stats for the list. Many of these are cached for at least five minutes.
@@ -14241,7 +18512,7 @@ pub struct SubscriberList {
 /**
 * Subscriber's current status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14253,8 +18524,8 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
@@ -14265,17 +18536,53 @@ impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cleaned" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned,
+            "pending" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending,
+            "subscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed,
+            "unsubscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed,
+            "" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop,
+            _ => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for MembersSubscribeUnsubscribeFromAListInBatchStatus {
     fn default() -> MembersSubscribeUnsubscribeFromAListInBatchStatus {
         MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
     }
 }
+impl MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(_)
+        )
+    }
+}
+
 impl MembersSubscribeUnsubscribeFromAListInBatchStatus {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -14432,7 +18739,7 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
 /**
 * Subscriber's status. This value is required only if the email address is not already present on the list.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum StatusIfNew {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14446,8 +18753,8 @@ pub enum StatusIfNew {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StatusIfNew {
@@ -14459,12 +18766,39 @@ impl std::fmt::Display for StatusIfNew {
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
             StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for StatusIfNew {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusIfNew {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cleaned" => StatusIfNew::Cleaned,
+            "pending" => StatusIfNew::Pending,
+            "subscribed" => StatusIfNew::Subscribed,
+            "transactional" => StatusIfNew::Transactional,
+            "unsubscribed" => StatusIfNew::Unsubscribed,
+            "" => StatusIfNew::Noop,
+            _ => StatusIfNew::Unknown(s),
+        })
+    }
+}
+
 impl Default for StatusIfNew {
     fn default() -> StatusIfNew {
         StatusIfNew::Noop
@@ -14474,6 +18808,11 @@ impl StatusIfNew {
     pub fn is_noop(&self) -> bool {
         matches!(self, StatusIfNew::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, StatusIfNew::Unknown(_))
+    }
 }
 
 /// Open and click rates for this subscriber.
@@ -14815,7 +19154,7 @@ pub struct NewMembers {
 /**
 * A unique code that identifies this specifc error.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "ERROR_CONTACT_EXISTS")]
     ErrorContactExists,
@@ -14823,8 +19162,8 @@ pub enum ErrorCode {
     ErrorGeneric,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -14833,12 +19172,36 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ERROR_CONTACT_EXISTS" => ErrorCode::ErrorContactExists,
+            "ERROR_GENERIC" => ErrorCode::ErrorGeneric,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::Unknown(s),
+        })
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -14848,6 +19211,11 @@ impl ErrorCode {
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ErrorCode::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15447,14 +19815,14 @@ pub struct EmailClients {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "month")]
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsGrowthHistorySortField {
@@ -15462,12 +19830,35 @@ impl std::fmt::Display for GetListsGrowthHistorySortField {
         match self {
             GetListsGrowthHistorySortField::Month => "month",
             GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetListsGrowthHistorySortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsGrowthHistorySortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "month" => GetListsGrowthHistorySortField::Month,
+            "" => GetListsGrowthHistorySortField::Noop,
+            _ => GetListsGrowthHistorySortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetListsGrowthHistorySortField {
     fn default() -> GetListsGrowthHistorySortField {
         GetListsGrowthHistorySortField::Noop
@@ -15477,6 +19868,11 @@ impl GetListsGrowthHistorySortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsGrowthHistorySortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetListsGrowthHistorySortField::Unknown(_))
+    }
 }
 
 /// A summary of a specific list's growth activity for a specific month and year.
@@ -15865,7 +20261,7 @@ pub struct InterestsData {
 /**
 * The type of segment. Static segments are now known as tags. Learn more about [tags](https://mailchimp.com/help/getting-started-tags?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CollectionOfSegmentsType {
     #[serde(rename = "fuzzy")]
     Fuzzy,
@@ -15875,8 +20271,8 @@ pub enum CollectionOfSegmentsType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionOfSegmentsType {
@@ -15886,12 +20282,37 @@ impl std::fmt::Display for CollectionOfSegmentsType {
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
             CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CollectionOfSegmentsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CollectionOfSegmentsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "fuzzy" => CollectionOfSegmentsType::Fuzzy,
+            "saved" => CollectionOfSegmentsType::Saved,
+            "static" => CollectionOfSegmentsType::Static,
+            "" => CollectionOfSegmentsType::Noop,
+            _ => CollectionOfSegmentsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CollectionOfSegmentsType {
     fn default() -> CollectionOfSegmentsType {
         CollectionOfSegmentsType::Noop
@@ -15901,6 +20322,11 @@ impl CollectionOfSegmentsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CollectionOfSegmentsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CollectionOfSegmentsType::Unknown(_))
+    }
 }
 
 /// The conditions of the segment. Static segments (tags) and fuzzy segments don't have conditions.
@@ -16483,7 +20909,7 @@ pub struct TagSearchResults {
 /**
 * The subscriber's status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetListsMembersStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -16499,8 +20925,8 @@ pub enum GetListsMembersStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersStatus {
@@ -16513,12 +20939,40 @@ impl std::fmt::Display for GetListsMembersStatus {
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
             GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetListsMembersStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "archived" => GetListsMembersStatus::Archived,
+            "cleaned" => GetListsMembersStatus::Cleaned,
+            "pending" => GetListsMembersStatus::Pending,
+            "subscribed" => GetListsMembersStatus::Subscribed,
+            "transactional" => GetListsMembersStatus::Transactional,
+            "unsubscribed" => GetListsMembersStatus::Unsubscribed,
+            "" => GetListsMembersStatus::Noop,
+            _ => GetListsMembersStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetListsMembersStatus {
     fn default() -> GetListsMembersStatus {
         GetListsMembersStatus::Noop
@@ -16528,12 +20982,17 @@ impl GetListsMembersStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetListsMembersStatus::Unknown(_))
+    }
 }
 
 /**
 * Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InterestMatch {
     #[serde(rename = "all")]
     All,
@@ -16543,8 +21002,8 @@ pub enum InterestMatch {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestMatch {
@@ -16554,12 +21013,37 @@ impl std::fmt::Display for InterestMatch {
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
             InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InterestMatch {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestMatch {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => InterestMatch::All,
+            "any" => InterestMatch::Any,
+            "none" => InterestMatch::None,
+            "" => InterestMatch::Noop,
+            _ => InterestMatch::Unknown(s),
+        })
+    }
+}
+
 impl Default for InterestMatch {
     fn default() -> InterestMatch {
         InterestMatch::Noop
@@ -16569,12 +21053,17 @@ impl InterestMatch {
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestMatch::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InterestMatch::Unknown(_))
+    }
 }
 
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetListsMembersSortField {
     #[serde(rename = "last_changed")]
     LastChanged,
@@ -16584,8 +21073,8 @@ pub enum GetListsMembersSortField {
     TimestampSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersSortField {
@@ -16595,12 +21084,37 @@ impl std::fmt::Display for GetListsMembersSortField {
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
             GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetListsMembersSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "last_changed" => GetListsMembersSortField::LastChanged,
+            "timestamp_opt" => GetListsMembersSortField::TimestampOpt,
+            "timestamp_signup" => GetListsMembersSortField::TimestampSignup,
+            "" => GetListsMembersSortField::Noop,
+            _ => GetListsMembersSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetListsMembersSortField {
     fn default() -> GetListsMembersSortField {
         GetListsMembersSortField::Noop
@@ -16610,6 +21124,11 @@ impl GetListsMembersSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetListsMembersSortField::Unknown(_))
+    }
 }
 
 /// Ecommerce stats for the list member if the list is attached to a store.
@@ -17464,14 +21983,14 @@ pub struct MemberActivityEvents {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ActivityType {
     #[serde(rename = "open")]
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActivityType {
@@ -17479,12 +21998,35 @@ impl std::fmt::Display for ActivityType {
         match self {
             ActivityType::Open => "open",
             ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "open" => ActivityType::Open,
+            "" => ActivityType::Noop,
+            _ => ActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ActivityType {
     fn default() -> ActivityType {
         ActivityType::Noop
@@ -17494,6 +22036,11 @@ impl ActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing opening an email.
@@ -17536,14 +22083,14 @@ pub struct EmailOpens {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailClicksActivityType {
     #[serde(rename = "click")]
     Click,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClicksActivityType {
@@ -17551,12 +22098,35 @@ impl std::fmt::Display for EmailClicksActivityType {
         match self {
             EmailClicksActivityType::Click => "click",
             EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailClicksActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClicksActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "click" => EmailClicksActivityType::Click,
+            "" => EmailClicksActivityType::Noop,
+            _ => EmailClicksActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailClicksActivityType {
     fn default() -> EmailClicksActivityType {
         EmailClicksActivityType::Noop
@@ -17566,6 +22136,11 @@ impl EmailClicksActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClicksActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailClicksActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing having a link clicked by a contact.
@@ -17617,14 +22192,14 @@ pub struct EmailClicks {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailBouncedActivityType {
     #[serde(rename = "bounce")]
     Bounce,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailBouncedActivityType {
@@ -17632,12 +22207,35 @@ impl std::fmt::Display for EmailBouncedActivityType {
         match self {
             EmailBouncedActivityType::Bounce => "bounce",
             EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailBouncedActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailBouncedActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "bounce" => EmailBouncedActivityType::Bounce,
+            "" => EmailBouncedActivityType::Noop,
+            _ => EmailBouncedActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailBouncedActivityType {
     fn default() -> EmailBouncedActivityType {
         EmailBouncedActivityType::Noop
@@ -17647,12 +22245,17 @@ impl EmailBouncedActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailBouncedActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailBouncedActivityType::Unknown(_))
+    }
 }
 
 /**
 * The type of bounce.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BounceType {
     #[serde(rename = "hard")]
     Hard,
@@ -17660,8 +22263,8 @@ pub enum BounceType {
     Soft,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -17670,12 +22273,36 @@ impl std::fmt::Display for BounceType {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BounceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BounceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "hard" => BounceType::Hard,
+            "soft" => BounceType::Soft,
+            "" => BounceType::Noop,
+            _ => BounceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -17685,6 +22312,11 @@ impl BounceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, BounceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BounceType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing an email to this contact bouncing.
@@ -17740,14 +22372,14 @@ pub struct EmailBounced {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListUnsubscribedActivityType {
     #[serde(rename = "unsub")]
     Unsub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListUnsubscribedActivityType {
@@ -17755,12 +22387,35 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         match self {
             ListUnsubscribedActivityType::Unsub => "unsub",
             ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListUnsubscribedActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListUnsubscribedActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "unsub" => ListUnsubscribedActivityType::Unsub,
+            "" => ListUnsubscribedActivityType::Noop,
+            _ => ListUnsubscribedActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListUnsubscribedActivityType {
     fn default() -> ListUnsubscribedActivityType {
         ListUnsubscribedActivityType::Noop
@@ -17770,6 +22425,11 @@ impl ListUnsubscribedActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListUnsubscribedActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListUnsubscribedActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing this contact unsubscribing from a list.
@@ -17829,14 +22489,14 @@ pub struct ListUnsubscribed {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailSentActivityType {
     #[serde(rename = "sent")]
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSentActivityType {
@@ -17844,12 +22504,35 @@ impl std::fmt::Display for EmailSentActivityType {
         match self {
             EmailSentActivityType::Sent => "sent",
             EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailSentActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSentActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "sent" => EmailSentActivityType::Sent,
+            "" => EmailSentActivityType::Noop,
+            _ => EmailSentActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailSentActivityType {
     fn default() -> EmailSentActivityType {
         EmailSentActivityType::Noop
@@ -17859,6 +22542,11 @@ impl EmailSentActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSentActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailSentActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing having an email sent to the contact.
@@ -17999,14 +22687,14 @@ pub struct EmailConversation {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NoteActivityType {
     #[serde(rename = "note")]
     Note,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NoteActivityType {
@@ -18014,12 +22702,35 @@ impl std::fmt::Display for NoteActivityType {
         match self {
             NoteActivityType::Note => "note",
             NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NoteActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NoteActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "note" => NoteActivityType::Note,
+            "" => NoteActivityType::Noop,
+            _ => NoteActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NoteActivityType {
     fn default() -> NoteActivityType {
         NoteActivityType::Noop
@@ -18029,6 +22740,11 @@ impl NoteActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NoteActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NoteActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing a note on the contact record.
@@ -18098,14 +22814,14 @@ pub struct Note {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MarketingPermissionActivityType {
     #[serde(rename = "marketing_permission")]
     MarketingPermission,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MarketingPermissionActivityType {
@@ -18113,12 +22829,35 @@ impl std::fmt::Display for MarketingPermissionActivityType {
         match self {
             MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
             MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
+            MarketingPermissionActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MarketingPermissionActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MarketingPermissionActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "marketing_permission" => MarketingPermissionActivityType::MarketingPermission,
+            "" => MarketingPermissionActivityType::Noop,
+            _ => MarketingPermissionActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MarketingPermissionActivityType {
     fn default() -> MarketingPermissionActivityType {
         MarketingPermissionActivityType::Noop
@@ -18128,6 +22867,11 @@ impl MarketingPermissionActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MarketingPermissionActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MarketingPermissionActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item indicating if a marketing permission was added or updated.
@@ -18178,14 +22922,14 @@ pub struct MarketingPermissionData {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PostcardSentActivityType {
     #[serde(rename = "postcard_sent")]
     PostcardSent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostcardSentActivityType {
@@ -18193,12 +22937,35 @@ impl std::fmt::Display for PostcardSentActivityType {
         match self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
             PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PostcardSentActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PostcardSentActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "postcard_sent" => PostcardSentActivityType::PostcardSent,
+            "" => PostcardSentActivityType::Noop,
+            _ => PostcardSentActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PostcardSentActivityType {
     fn default() -> PostcardSentActivityType {
         PostcardSentActivityType::Noop
@@ -18208,6 +22975,11 @@ impl PostcardSentActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PostcardSentActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PostcardSentActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item representing a time when a contact was sent a particular postcard.
@@ -18250,14 +23022,14 @@ pub struct PostcardSent {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SquatterSignupActivityType {
     #[serde(rename = "squatter_signup")]
     SquatterSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SquatterSignupActivityType {
@@ -18265,12 +23037,35 @@ impl std::fmt::Display for SquatterSignupActivityType {
         match self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
             SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SquatterSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SquatterSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "squatter_signup" => SquatterSignupActivityType::SquatterSignup,
+            "" => SquatterSignupActivityType::Noop,
+            _ => SquatterSignupActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SquatterSignupActivityType {
     fn default() -> SquatterSignupActivityType {
         SquatterSignupActivityType::Noop
@@ -18280,6 +23075,11 @@ impl SquatterSignupActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SquatterSignupActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SquatterSignupActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item to representing a contact signing up for the audience from a squatter page.
@@ -18322,14 +23122,14 @@ pub struct SquatterSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WebsiteSignupActivityType {
     #[serde(rename = "website_signup")]
     WebsiteSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebsiteSignupActivityType {
@@ -18337,12 +23137,35 @@ impl std::fmt::Display for WebsiteSignupActivityType {
         match self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
             WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WebsiteSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebsiteSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "website_signup" => WebsiteSignupActivityType::WebsiteSignup,
+            "" => WebsiteSignupActivityType::Noop,
+            _ => WebsiteSignupActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for WebsiteSignupActivityType {
     fn default() -> WebsiteSignupActivityType {
         WebsiteSignupActivityType::Noop
@@ -18352,6 +23175,11 @@ impl WebsiteSignupActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, WebsiteSignupActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WebsiteSignupActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item to representing a contact signing up for the contact through a website page.
@@ -18394,14 +23222,14 @@ pub struct WebsiteSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LandingPageSignupActivityType {
     #[serde(rename = "landing_page_signup")]
     LandingPageSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageSignupActivityType {
@@ -18409,12 +23237,35 @@ impl std::fmt::Display for LandingPageSignupActivityType {
         match self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
             LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LandingPageSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "landing_page_signup" => LandingPageSignupActivityType::LandingPageSignup,
+            "" => LandingPageSignupActivityType::Noop,
+            _ => LandingPageSignupActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LandingPageSignupActivityType {
     fn default() -> LandingPageSignupActivityType {
         LandingPageSignupActivityType::Noop
@@ -18424,6 +23275,11 @@ impl LandingPageSignupActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageSignupActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LandingPageSignupActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item to representing a contact signing up for the list via a landing page.
@@ -18466,14 +23322,14 @@ pub struct LandingPageSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceSignupActivityType {
     #[serde(rename = "ecommerce_signup")]
     EcommerceSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSignupActivityType {
@@ -18481,12 +23337,35 @@ impl std::fmt::Display for EcommerceSignupActivityType {
         match self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
             EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ecommerce_signup" => EcommerceSignupActivityType::EcommerceSignup,
+            "" => EcommerceSignupActivityType::Noop,
+            _ => EcommerceSignupActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceSignupActivityType {
     fn default() -> EcommerceSignupActivityType {
         EcommerceSignupActivityType::Noop
@@ -18496,6 +23375,11 @@ impl EcommerceSignupActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSignupActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceSignupActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item to representing a contact signing up for the list via a ecommerce store.
@@ -18529,14 +23413,14 @@ pub struct EcommerceSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GenericSignupActivityType {
     #[serde(rename = "generic_signup")]
     GenericSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GenericSignupActivityType {
@@ -18544,12 +23428,35 @@ impl std::fmt::Display for GenericSignupActivityType {
         match self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
             GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GenericSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GenericSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "generic_signup" => GenericSignupActivityType::GenericSignup,
+            "" => GenericSignupActivityType::Noop,
+            _ => GenericSignupActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GenericSignupActivityType {
     fn default() -> GenericSignupActivityType {
         GenericSignupActivityType::Noop
@@ -18559,6 +23466,11 @@ impl GenericSignupActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GenericSignupActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GenericSignupActivityType::Unknown(_))
+    }
 }
 
 /// Activity feed item that represents a contact signing up for the audience via a generic some generic method (specifically, one we can't link to).
@@ -18592,14 +23504,14 @@ pub struct GenericSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EcommerceOrderActivityType {
     #[serde(rename = "order")]
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceOrderActivityType {
@@ -18607,12 +23519,35 @@ impl std::fmt::Display for EcommerceOrderActivityType {
         match self {
             EcommerceOrderActivityType::Order => "order",
             EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EcommerceOrderActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceOrderActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "order" => EcommerceOrderActivityType::Order,
+            "" => EcommerceOrderActivityType::Noop,
+            _ => EcommerceOrderActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EcommerceOrderActivityType {
     fn default() -> EcommerceOrderActivityType {
         EcommerceOrderActivityType::Noop
@@ -18622,6 +23557,11 @@ impl EcommerceOrderActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceOrderActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EcommerceOrderActivityType::Unknown(_))
+    }
 }
 
 /// Information about a specific order line.
@@ -18814,14 +23754,14 @@ pub struct EcommerceOrder {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ContactActivityEventType {
     #[serde(rename = "event")]
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactActivityEventType {
@@ -18829,12 +23769,35 @@ impl std::fmt::Display for ContactActivityEventType {
         match self {
             ContactActivityEventType::Event => "event",
             ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ContactActivityEventType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContactActivityEventType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "event" => ContactActivityEventType::Event,
+            "" => ContactActivityEventType::Noop,
+            _ => ContactActivityEventType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ContactActivityEventType {
     fn default() -> ContactActivityEventType {
         ContactActivityEventType::Noop
@@ -18844,6 +23807,11 @@ impl ContactActivityEventType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ContactActivityEventType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ContactActivityEventType::Unknown(_))
+    }
 }
 
 /// Activity feed item that represents a generic event.
@@ -18886,14 +23854,14 @@ pub struct ContactActivityEvent {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SurveyResponseActivityType {
     #[serde(rename = "survey_response")]
     SurveyResponse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyResponseActivityType {
@@ -18901,12 +23869,35 @@ impl std::fmt::Display for SurveyResponseActivityType {
         match self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
             SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SurveyResponseActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyResponseActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "survey_response" => SurveyResponseActivityType::SurveyResponse,
+            "" => SurveyResponseActivityType::Noop,
+            _ => SurveyResponseActivityType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SurveyResponseActivityType {
     fn default() -> SurveyResponseActivityType {
         SurveyResponseActivityType::Noop
@@ -18916,6 +23907,11 @@ impl SurveyResponseActivityType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyResponseActivityType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SurveyResponseActivityType::Unknown(_))
+    }
 }
 
 /// Represents when a contact completes and submits a survey
@@ -19249,7 +24245,7 @@ pub struct CollectionOfTags {
 /**
 * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MemberTagsTagStatus {
     #[serde(rename = "active")]
     Active,
@@ -19257,8 +24253,8 @@ pub enum MemberTagsTagStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberTagsTagStatus {
@@ -19267,12 +24263,36 @@ impl std::fmt::Display for MemberTagsTagStatus {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
             MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MemberTagsTagStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberTagsTagStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => MemberTagsTagStatus::Active,
+            "inactive" => MemberTagsTagStatus::Inactive,
+            "" => MemberTagsTagStatus::Noop,
+            _ => MemberTagsTagStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for MemberTagsTagStatus {
     fn default() -> MemberTagsTagStatus {
         MemberTagsTagStatus::Noop
@@ -19282,6 +24302,11 @@ impl MemberTagsTagStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberTagsTagStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MemberTagsTagStatus::Unknown(_))
+    }
 }
 
 /// Add or remove tags on a member by declaring a tag either active or inactive on a member.
@@ -19525,7 +24550,7 @@ pub struct CollectionOfMemberActivityEvents {
 /**
 * Returns notes sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetListsMembersNotesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -19535,8 +24560,8 @@ pub enum GetListsMembersNotesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersNotesSortField {
@@ -19546,12 +24571,37 @@ impl std::fmt::Display for GetListsMembersNotesSortField {
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
             GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetListsMembersNotesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersNotesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created_at" => GetListsMembersNotesSortField::CreatedAt,
+            "note_id" => GetListsMembersNotesSortField::NoteId,
+            "updated_at" => GetListsMembersNotesSortField::UpdatedAt,
+            "" => GetListsMembersNotesSortField::Noop,
+            _ => GetListsMembersNotesSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetListsMembersNotesSortField {
     fn default() -> GetListsMembersNotesSortField {
         GetListsMembersNotesSortField::Noop
@@ -19561,6 +24611,11 @@ impl GetListsMembersNotesSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersNotesSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetListsMembersNotesSortField::Unknown(_))
+    }
 }
 
 /// A specific note for a specific member.
@@ -19695,7 +24750,7 @@ pub struct CollectionOfNotes {
 /**
 * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MergeFieldType {
     #[serde(rename = "address")]
     Address,
@@ -19721,8 +24776,8 @@ pub enum MergeFieldType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeFieldType {
@@ -19740,12 +24795,45 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
             MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MergeFieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeFieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "address" => MergeFieldType::Address,
+            "birthday" => MergeFieldType::Birthday,
+            "date" => MergeFieldType::Date,
+            "dropdown" => MergeFieldType::Dropdown,
+            "imageurl" => MergeFieldType::Imageurl,
+            "number" => MergeFieldType::Number,
+            "phone" => MergeFieldType::Phone,
+            "radio" => MergeFieldType::Radio,
+            "text" => MergeFieldType::Text,
+            "url" => MergeFieldType::Url,
+            "zip" => MergeFieldType::Zip,
+            "" => MergeFieldType::Noop,
+            _ => MergeFieldType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MergeFieldType {
     fn default() -> MergeFieldType {
         MergeFieldType::Noop
@@ -19755,6 +24843,11 @@ impl MergeFieldType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeFieldType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MergeFieldType::Unknown(_))
+    }
 }
 
 /// Extra options for some merge field types.
@@ -20324,7 +25417,7 @@ pub struct ListWebhooksData {
 /**
 * Image alignment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImageAlign {
     #[serde(rename = "center")]
     Center,
@@ -20336,8 +25429,8 @@ pub enum ImageAlign {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageAlign {
@@ -20348,12 +25441,38 @@ impl std::fmt::Display for ImageAlign {
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
             ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImageAlign {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageAlign {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "center" => ImageAlign::Center,
+            "left" => ImageAlign::Left,
+            "none" => ImageAlign::None,
+            "right" => ImageAlign::Right,
+            "" => ImageAlign::Noop,
+            _ => ImageAlign::Unknown(s),
+        })
+    }
+}
+
 impl Default for ImageAlign {
     fn default() -> ImageAlign {
         ImageAlign::Noop
@@ -20363,12 +25482,17 @@ impl ImageAlign {
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageAlign::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImageAlign::Unknown(_))
+    }
 }
 
 /**
 * Image border style.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImageBorderStyle {
     #[serde(rename = "dashed")]
     Dashed,
@@ -20390,8 +25514,8 @@ pub enum ImageBorderStyle {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageBorderStyle {
@@ -20407,12 +25531,43 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
             ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImageBorderStyle {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageBorderStyle {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "dashed" => ImageBorderStyle::Dashed,
+            "dotted" => ImageBorderStyle::Dotted,
+            "double" => ImageBorderStyle::Double,
+            "groove" => ImageBorderStyle::Groove,
+            "inset" => ImageBorderStyle::Inset,
+            "none" => ImageBorderStyle::None,
+            "outset" => ImageBorderStyle::Outset,
+            "ridge" => ImageBorderStyle::Ridge,
+            "solid" => ImageBorderStyle::Solid,
+            "" => ImageBorderStyle::Noop,
+            _ => ImageBorderStyle::Unknown(s),
+        })
+    }
+}
+
 impl Default for ImageBorderStyle {
     fn default() -> ImageBorderStyle {
         ImageBorderStyle::Noop
@@ -20422,12 +25577,17 @@ impl ImageBorderStyle {
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageBorderStyle::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImageBorderStyle::Unknown(_))
+    }
 }
 
 /**
 * Image link target.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImageTarget {
     #[serde(rename = "_blank")]
     Blank,
@@ -20435,8 +25595,8 @@ pub enum ImageTarget {
     Null,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageTarget {
@@ -20445,12 +25605,36 @@ impl std::fmt::Display for ImageTarget {
             ImageTarget::Blank => "_blank",
             ImageTarget::Null => "null",
             ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+            ImageTarget::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImageTarget {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageTarget {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "_blank" => ImageTarget::Blank,
+            "null" => ImageTarget::Null,
+            "" => ImageTarget::Noop,
+            _ => ImageTarget::Unknown(s),
+        })
+    }
+}
+
 impl Default for ImageTarget {
     fn default() -> ImageTarget {
         ImageTarget::Noop
@@ -20460,6 +25644,11 @@ impl ImageTarget {
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageTarget::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImageTarget::Unknown(_))
+    }
 }
 
 /// Options for customizing your signup form header.
@@ -20557,7 +25746,7 @@ pub struct Header {
 /**
 * The content section name.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Section {
     #[serde(rename = "signup_message")]
     SignupMessage,
@@ -20567,8 +25756,8 @@ pub enum Section {
     UnsubMessage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Section {
@@ -20578,12 +25767,37 @@ impl std::fmt::Display for Section {
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
             Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Section {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Section {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "signup_message" => Section::SignupMessage,
+            "signup_thank_you_title" => Section::SignupThankYouTitle,
+            "unsub_message" => Section::UnsubMessage,
+            "" => Section::Noop,
+            _ => Section::Unknown(s),
+        })
+    }
+}
+
 impl Default for Section {
     fn default() -> Section {
         Section::Noop
@@ -20593,6 +25807,11 @@ impl Section {
     pub fn is_noop(&self) -> bool {
         matches!(self, Section::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Section::Unknown(_))
+    }
 }
 
 /// Collection of Content for List Signup Forms.
@@ -20617,7 +25836,7 @@ pub struct Contents {
 /**
 * A string that identifies the element selector.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Selector {
     #[serde(rename = "body_background")]
     BodyBackground,
@@ -20649,8 +25868,8 @@ pub enum Selector {
     PageOuterWrapper,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Selector {
@@ -20671,12 +25890,48 @@ impl std::fmt::Display for Selector {
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
             Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Selector {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Selector {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "body_background" => Selector::BodyBackground,
+            "body_link_style" => Selector::BodyLinkStyle,
+            "forms_buttons" => Selector::FormsButtons,
+            "forms_buttons_hovered" => Selector::FormsButtonsHovered,
+            "forms_errors" => Selector::FormsErrors,
+            "forms_field_label" => Selector::FormsFieldLabel,
+            "forms_field_text" => Selector::FormsFieldText,
+            "forms_help_text" => Selector::FormsHelpText,
+            "forms_required" => Selector::FormsRequired,
+            "forms_required_legend" => Selector::FormsRequiredLegend,
+            "monkey_rewards_badge" => Selector::MonkeyRewardsBadge,
+            "page_background" => Selector::PageBackground,
+            "page_header" => Selector::PageHeader,
+            "page_outer_wrapper" => Selector::PageOuterWrapper,
+            "" => Selector::Noop,
+            _ => Selector::Unknown(s),
+        })
+    }
+}
+
 impl Default for Selector {
     fn default() -> Selector {
         Selector::Noop
@@ -20686,6 +25941,11 @@ impl Selector {
     pub fn is_noop(&self) -> bool {
         matches!(self, Selector::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Selector::Unknown(_))
+    }
 }
 
 /// An option for Signup Form Styles.
@@ -20929,7 +26189,7 @@ pub struct ListLocations {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAllLandingPagesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -20937,8 +26197,8 @@ pub enum GetAllLandingPagesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllLandingPagesSortField {
@@ -20947,12 +26207,36 @@ impl std::fmt::Display for GetAllLandingPagesSortField {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
             GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAllLandingPagesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAllLandingPagesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created_at" => GetAllLandingPagesSortField::CreatedAt,
+            "updated_at" => GetAllLandingPagesSortField::UpdatedAt,
+            "" => GetAllLandingPagesSortField::Noop,
+            _ => GetAllLandingPagesSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAllLandingPagesSortField {
     fn default() -> GetAllLandingPagesSortField {
         GetAllLandingPagesSortField::Noop
@@ -20962,12 +26246,17 @@ impl GetAllLandingPagesSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAllLandingPagesSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetAllLandingPagesSortField::Unknown(_))
+    }
 }
 
 /**
 * The status of this landing page.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LandingPageStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -20977,8 +26266,8 @@ pub enum LandingPageStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageStatus {
@@ -20988,12 +26277,37 @@ impl std::fmt::Display for LandingPageStatus {
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
             LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LandingPageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "draft" => LandingPageStatus::Draft,
+            "published" => LandingPageStatus::Published,
+            "unpublished" => LandingPageStatus::Unpublished,
+            "" => LandingPageStatus::Noop,
+            _ => LandingPageStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for LandingPageStatus {
     fn default() -> LandingPageStatus {
         LandingPageStatus::Noop
@@ -21003,6 +26317,11 @@ impl LandingPageStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LandingPageStatus::Unknown(_))
+    }
 }
 
 /// The tracking settings applied to this landing page.
@@ -21213,7 +26532,7 @@ pub struct GetAllLandingPagesResponse {
 /**
 * The type of template the landing page has.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LandingPageTemplateType {
     #[serde(rename = "product")]
     Product,
@@ -21221,8 +26540,8 @@ pub enum LandingPageTemplateType {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageTemplateType {
@@ -21231,12 +26550,36 @@ impl std::fmt::Display for LandingPageTemplateType {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
             LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LandingPageTemplateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageTemplateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "product" => LandingPageTemplateType::Product,
+            "signup" => LandingPageTemplateType::Signup,
+            "" => LandingPageTemplateType::Noop,
+            _ => LandingPageTemplateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LandingPageTemplateType {
     fn default() -> LandingPageTemplateType {
         LandingPageTemplateType::Noop
@@ -21246,6 +26589,11 @@ impl LandingPageTemplateType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageTemplateType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LandingPageTemplateType::Unknown(_))
+    }
 }
 
 /// A summary of an individual page's properties.
@@ -22433,7 +27781,7 @@ pub struct AbuseComplaintsData {
 /**
 * The sentiment type for a feedback message.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AdviceType {
     #[serde(rename = "negative")]
     Negative,
@@ -22443,8 +27791,8 @@ pub enum AdviceType {
     Positive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AdviceType {
@@ -22454,12 +27802,37 @@ impl std::fmt::Display for AdviceType {
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
             AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AdviceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AdviceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "negative" => AdviceType::Negative,
+            "neutral" => AdviceType::Neutral,
+            "positive" => AdviceType::Positive,
+            "" => AdviceType::Noop,
+            _ => AdviceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AdviceType {
     fn default() -> AdviceType {
         AdviceType::Noop
@@ -22469,6 +27842,11 @@ impl AdviceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AdviceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AdviceType::Unknown(_))
+    }
 }
 
 /// Campaign feedback details.
@@ -24086,7 +29464,7 @@ pub struct UnsubscribesData {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "title")]
     Title,
@@ -24096,8 +29474,8 @@ pub enum GetReportsEcommerceProductActivitySortField {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
@@ -24107,12 +29485,37 @@ impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
             GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetReportsEcommerceProductActivitySortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetReportsEcommerceProductActivitySortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "title" => GetReportsEcommerceProductActivitySortField::Title,
+            "total_purchased" => GetReportsEcommerceProductActivitySortField::TotalPurchased,
+            "total_revenue" => GetReportsEcommerceProductActivitySortField::TotalRevenue,
+            "" => GetReportsEcommerceProductActivitySortField::Noop,
+            _ => GetReportsEcommerceProductActivitySortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetReportsEcommerceProductActivitySortField {
     fn default() -> GetReportsEcommerceProductActivitySortField {
         GetReportsEcommerceProductActivitySortField::Noop
@@ -24122,6 +29525,14 @@ impl GetReportsEcommerceProductActivitySortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetReportsEcommerceProductActivitySortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetReportsEcommerceProductActivitySortField::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24236,7 +29647,7 @@ pub struct GetReportsEcommerceProductActivityResponse {
 /**
 * Returns user templates sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetTemplatesSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -24246,8 +29657,8 @@ pub enum GetTemplatesSortField {
     Name,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTemplatesSortField {
@@ -24257,12 +29668,37 @@ impl std::fmt::Display for GetTemplatesSortField {
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
             GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetTemplatesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTemplatesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "date_created" => GetTemplatesSortField::DateCreated,
+            "date_edited" => GetTemplatesSortField::DateEdited,
+            "name" => GetTemplatesSortField::Name,
+            "" => GetTemplatesSortField::Noop,
+            _ => GetTemplatesSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetTemplatesSortField {
     fn default() -> GetTemplatesSortField {
         GetTemplatesSortField::Noop
@@ -24272,6 +29708,11 @@ impl GetTemplatesSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetTemplatesSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetTemplatesSortField::Unknown(_))
+    }
 }
 
 /// Information about a specific template.
@@ -24657,14 +30098,14 @@ pub struct Customer {
 /**
 * The Mailchimp tracking code for the order. Uses the 'mc_tc' parameter in E-Commerce tracking URLs.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TrackingCode {
     #[serde(rename = "prec")]
     Prec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrackingCode {
@@ -24672,12 +30113,35 @@ impl std::fmt::Display for TrackingCode {
         match self {
             TrackingCode::Prec => "prec",
             TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TrackingCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TrackingCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "prec" => TrackingCode::Prec,
+            "" => TrackingCode::Noop,
+            _ => TrackingCode::Unknown(s),
+        })
+    }
+}
+
 impl Default for TrackingCode {
     fn default() -> TrackingCode {
         TrackingCode::Noop
@@ -24687,6 +30151,11 @@ impl TrackingCode {
     pub fn is_noop(&self) -> bool {
         matches!(self, TrackingCode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TrackingCode::Unknown(_))
+    }
 }
 
 /// The shipping address for the order.
@@ -24940,7 +30409,7 @@ pub struct BillingAddress {
 /**
 * Type of discount. For free shipping set type to fixed
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrdersPromosType {
     #[serde(rename = "fixed")]
     Fixed,
@@ -24948,8 +30417,8 @@ pub enum OrdersPromosType {
     Percentage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersPromosType {
@@ -24958,12 +30427,36 @@ impl std::fmt::Display for OrdersPromosType {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
             OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrdersPromosType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrdersPromosType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "fixed" => OrdersPromosType::Fixed,
+            "percentage" => OrdersPromosType::Percentage,
+            "" => OrdersPromosType::Noop,
+            _ => OrdersPromosType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrdersPromosType {
     fn default() -> OrdersPromosType {
         OrdersPromosType::Noop
@@ -24973,6 +30466,11 @@ impl OrdersPromosType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrdersPromosType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrdersPromosType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26646,7 +32144,7 @@ pub struct ECommerceCustomerDataType {
 /**
 * The target that the discount applies to.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Target {
     #[serde(rename = "per_item")]
     PerItem,
@@ -26656,8 +32154,8 @@ pub enum Target {
     Total,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Target {
@@ -26667,12 +32165,37 @@ impl std::fmt::Display for Target {
             Target::Shipping => "shipping",
             Target::Total => "total",
             Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Target {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Target {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "per_item" => Target::PerItem,
+            "shipping" => Target::Shipping,
+            "total" => Target::Total,
+            "" => Target::Noop,
+            _ => Target::Unknown(s),
+        })
+    }
+}
+
 impl Default for Target {
     fn default() -> Target {
         Target::Noop
@@ -26682,6 +32205,11 @@ impl Target {
     pub fn is_noop(&self) -> bool {
         matches!(self, Target::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Target::Unknown(_))
+    }
 }
 
 /// Information about an Ecommerce Store's specific Promo Rule
@@ -28751,7 +34279,7 @@ pub struct ApiHealthStatus {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -28761,8 +34289,8 @@ pub enum GetAllFacebookAdsSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllFacebookAdsSortField {
@@ -28772,12 +34300,37 @@ impl std::fmt::Display for GetAllFacebookAdsSortField {
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
             GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAllFacebookAdsSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAllFacebookAdsSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "created_at" => GetAllFacebookAdsSortField::CreatedAt,
+            "end_time" => GetAllFacebookAdsSortField::EndTime,
+            "updated_at" => GetAllFacebookAdsSortField::UpdatedAt,
+            "" => GetAllFacebookAdsSortField::Noop,
+            _ => GetAllFacebookAdsSortField::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAllFacebookAdsSortField {
     fn default() -> GetAllFacebookAdsSortField {
         GetAllFacebookAdsSortField::Noop
@@ -28787,12 +34340,17 @@ impl GetAllFacebookAdsSortField {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAllFacebookAdsSortField::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetAllFacebookAdsSortField::Unknown(_))
+    }
 }
 
 /**
 * Supported Campaign, Ad, Page type
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OutreachType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -28824,8 +34382,8 @@ pub enum OutreachType {
     Website,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachType {
@@ -28846,12 +34404,48 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
             OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OutreachType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OutreachType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "absplit" => OutreachType::Absplit,
+            "automation" => OutreachType::Automation,
+            "autoresponder" => OutreachType::Autoresponder,
+            "facebook" => OutreachType::Facebook,
+            "google" => OutreachType::Google,
+            "page" => OutreachType::Page,
+            "plaintext" => OutreachType::Plaintext,
+            "reconfirm" => OutreachType::Reconfirm,
+            "regular" => OutreachType::Regular,
+            "rss" => OutreachType::Rss,
+            "survey" => OutreachType::Survey,
+            "transactional" => OutreachType::Transactional,
+            "variate" => OutreachType::Variate,
+            "website" => OutreachType::Website,
+            "" => OutreachType::Noop,
+            _ => OutreachType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OutreachType {
     fn default() -> OutreachType {
         OutreachType::Noop
@@ -28861,12 +34455,17 @@ impl OutreachType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OutreachType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OutreachType::Unknown(_))
+    }
 }
 
 /**
 * Campaign, Ad, or Page status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OutreachStatus {
     #[serde(rename = "active")]
     Active,
@@ -28904,8 +34503,8 @@ pub enum OutreachStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachStatus {
@@ -28929,12 +34528,51 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
             OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OutreachStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OutreachStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => OutreachStatus::Active,
+            "canceled" => OutreachStatus::Canceled,
+            "canceling" => OutreachStatus::Canceling,
+            "completed" => OutreachStatus::Completed,
+            "disconnected" => OutreachStatus::Disconnected,
+            "draft" => OutreachStatus::Draft,
+            "partialRejected" => OutreachStatus::PartialRejected,
+            "paused" => OutreachStatus::Paused,
+            "pending" => OutreachStatus::Pending,
+            "published" => OutreachStatus::Published,
+            "rejected" => OutreachStatus::Rejected,
+            "save" => OutreachStatus::Save,
+            "schedule" => OutreachStatus::Schedule,
+            "sending" => OutreachStatus::Sending,
+            "sent" => OutreachStatus::Sent,
+            "somepaused" => OutreachStatus::Somepaused,
+            "unpublished" => OutreachStatus::Unpublished,
+            "" => OutreachStatus::Noop,
+            _ => OutreachStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for OutreachStatus {
     fn default() -> OutreachStatus {
         OutreachStatus::Noop
@@ -28944,6 +34582,11 @@ impl OutreachStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, OutreachStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OutreachStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29433,7 +35076,7 @@ pub struct Site {
 /**
 * Type of the audience
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FacebookAdsAudienceType {
     #[serde(rename = "Custom Audience")]
     CustomAudience,
@@ -29443,8 +35086,8 @@ pub enum FacebookAdsAudienceType {
     LookalikeAudience,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FacebookAdsAudienceType {
@@ -29454,12 +35097,37 @@ impl std::fmt::Display for FacebookAdsAudienceType {
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
             FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FacebookAdsAudienceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FacebookAdsAudienceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Custom Audience" => FacebookAdsAudienceType::CustomAudience,
+            "Interest-based Audience" => FacebookAdsAudienceType::InterestBasedAudience,
+            "Lookalike Audience" => FacebookAdsAudienceType::LookalikeAudience,
+            "" => FacebookAdsAudienceType::Noop,
+            _ => FacebookAdsAudienceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FacebookAdsAudienceType {
     fn default() -> FacebookAdsAudienceType {
         FacebookAdsAudienceType::Noop
@@ -29469,12 +35137,17 @@ impl FacebookAdsAudienceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FacebookAdsAudienceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FacebookAdsAudienceType::Unknown(_))
+    }
 }
 
 /**
 * List or Facebook based audience
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SourceType {
     #[serde(rename = "facebook")]
     Facebook,
@@ -29482,8 +35155,8 @@ pub enum SourceType {
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -29492,12 +35165,36 @@ impl std::fmt::Display for SourceType {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "facebook" => SourceType::Facebook,
+            "list" => SourceType::List,
+            "" => SourceType::Noop,
+            _ => SourceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SourceType {
     fn default() -> SourceType {
         SourceType::Noop
@@ -29507,6 +35204,11 @@ impl SourceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SourceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SourceType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
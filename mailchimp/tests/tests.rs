@@ -1 +1,24 @@
+#[tokio::test]
+async fn test_generated_mocks() {
+    let mock = mailchimp_api::mock::Mock::start().await;
 
+    let mut client = mailchimp_api::Client::new("", "", "", "token", "");
+    mock.point(&mut client);
+
+    mock.ping()
+        .get()
+        .expect(1)
+        .responds_with(mailchimp_api::types::ApiHealthStatus {
+            health_status: "Everything's Chimpy!".to_string(),
+        })
+        .await;
+    mock.lists()
+        .delete("57afe96172")
+        .responds_with_status(204)
+        .await;
+
+    let status = client.ping().get().await.unwrap();
+    assert_eq!(status.health_status, "Everything's Chimpy!");
+
+    client.lists().delete("57afe96172").await.unwrap();
+}
@@ -115,6 +115,68 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Application> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if include_non_deleted {
+            query_args.push((
+                "includeNonDeleted".to_string(),
+                include_non_deleted.to_string(),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Application>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Application>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add Application.
      *
@@ -302,6 +364,52 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates Certificate Signing Requests for an application
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        app_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Csr> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Csr>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Csr>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Generate Certificate Signing Request for Application.
      *
@@ -476,6 +584,52 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates key credentials for an application
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        app_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::JsonWebKey> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::JsonWebKey>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::JsonWebKey>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
      *
@@ -661,6 +815,63 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_all_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OAuth2ScopeConsentGrant> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OAuth2ScopeConsentGrant>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OAuth2ScopeConsentGrant>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -858,6 +1069,69 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_all_group_assignments_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ApplicationGroupAssignment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ApplicationGroupAssignment>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::ApplicationGroupAssignment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get Assigned Group for Application.
      *
@@ -1109,6 +1383,59 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_all_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OAuth2Token> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OAuth2Token>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::OAuth2Token>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
@@ -1320,6 +1647,71 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AppUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !query_scope.is_empty() {
+            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/users?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::AppUser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::AppUser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Assign User to Application for SSO & Provisioning.
      *
@@ -0,0 +1,689 @@
+//! Verifies and parses the requests Okta sends to event hooks and inline
+//! hooks, and builds the responses of inline hooks.
+//!
+//! Okta sends the secret set in the `authScheme` of a hook in the
+//! `Authorization` header of each request. Before sending events to an event
+//! hook, Okta checks it with a `GET` carrying an
+//! `X-Okta-Verification-Challenge` header, which [`EventHookVerification`]
+//! answers. The events then come in batches of [`types::LogEvent`]:
+//!
+//! ```
+//! use okta::hooks::{EventHookRequest, EventHookVerification};
+//!
+//! fn handle(
+//!     method: &http::Method,
+//!     headers: &http::HeaderMap,
+//!     body: &[u8],
+//! ) -> http::Response<String> {
+//!     if method == http::Method::GET {
+//!         if let Ok(verification) = EventHookVerification::from_request("secret", headers) {
+//!             return verification.into_response();
+//!         }
+//!     } else if let Ok(request) = EventHookRequest::from_request("secret", headers, body) {
+//!         for event in &request.data.events {
+//!             println!("{}: {}", event.event_type, event.display_message);
+//!         }
+//!         return http::Response::new(String::new());
+//!     }
+//!
+//!     http::Response::builder()
+//!         .status(http::StatusCode::UNAUTHORIZED)
+//!         .body(String::new())
+//!         .unwrap()
+//! }
+//! ```
+//!
+//! Inline hooks wait for the response, which carries the commands changing
+//! what Okta does. [`InlineHookRequest`] parses the request by its type, and
+//! [`InlineHookResponse`] builds the commands:
+//!
+//! ```
+//! use okta::hooks::{InlineHookData, InlineHookRequest, InlineHookResponse};
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) -> anyhow::Result<http::Response<String>> {
+//!     let request = InlineHookRequest::from_request("secret", headers, body)?;
+//!     let response = match &request.data {
+//!         InlineHookData::Token(_) => InlineHookResponse::new().add_access_claim("team", "blue"),
+//!         InlineHookData::Registration(data) if data.user_profile.contains_key("email") => {
+//!             InlineHookResponse::new().allow_registration()
+//!         }
+//!         _ => InlineHookResponse::new(),
+//!     };
+//!     Ok(response.into_response())
+//! }
+//! ```
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::types;
+
+const X_OKTA_VERIFICATION_CHALLENGE: &str = "x-okta-verification-challenge";
+
+/// A hook request without the secret of the hook, or with a body Okta doesn't
+/// send.
+#[derive(Debug, thiserror::Error)]
+pub enum HookError {
+    /// The request is missing a header Okta always sends.
+    #[error("missing the {0} header")]
+    MissingHeader(&'static str),
+    /// The `Authorization` header isn't the secret of the hook.
+    #[error("unauthorized")]
+    Unauthorized,
+    /// The body isn't the JSON Okta sends.
+    #[error("invalid payload: {0}")]
+    Payload(#[from] serde_json::Error),
+}
+
+/// Check the `Authorization` header of a hook request against the secret set
+/// in the `authScheme` of the hook. Okta sends the secret itself, not a
+/// signature, so the header and the secret are both run through an HMAC keyed
+/// with the secret, and the two are compared with `verify_slice`. That doesn't
+/// tell how much of the header matched.
+pub fn verify_authorization(secret: &str, headers: &http::HeaderMap) -> Result<(), HookError> {
+    let authorization = headers
+        .get(http::header::AUTHORIZATION)
+        .ok_or(HookError::MissingHeader("authorization"))?;
+
+    let mut expected =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| HookError::Unauthorized)?;
+    let mut mac = expected.clone();
+    expected.update(secret.as_bytes());
+    mac.update(authorization.as_bytes());
+    mac.verify_slice(&expected.finalize().into_bytes())
+        .map_err(|_| HookError::Unauthorized)
+}
+
+/// The one-time verification of an event hook, a `GET` carrying a challenge
+/// to send back.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct EventHookVerification {
+    pub verification: String,
+}
+
+impl EventHookVerification {
+    /// Verify the `Authorization` header of the request, then read its
+    /// `X-Okta-Verification-Challenge` header.
+    pub fn from_request(secret: &str, headers: &http::HeaderMap) -> Result<Self, HookError> {
+        verify_authorization(secret, headers)?;
+        let challenge = headers
+            .get(X_OKTA_VERIFICATION_CHALLENGE)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(HookError::MissingHeader(X_OKTA_VERIFICATION_CHALLENGE))?;
+        Ok(EventHookVerification {
+            verification: challenge.to_string(),
+        })
+    }
+
+    /// The `200 OK` response sending the challenge back.
+    pub fn into_response(self) -> http::Response<String> {
+        json_response(serde_json::json!(self).to_string())
+    }
+}
+
+/// A batch of events sent to an event hook.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventHookRequest {
+    /// The type of the request, `com.okta.event_hook`.
+    #[serde(default)]
+    pub event_type: String,
+    #[serde(default)]
+    pub event_type_version: String,
+    /// The event hook the request was sent for, as a URL of the API.
+    #[serde(default)]
+    pub source: String,
+    /// The id of the request, the same for each retry of a delivery.
+    #[serde(default)]
+    pub event_id: String,
+    #[serde(default)]
+    pub event_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub data: EventHookData,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct EventHookData {
+    #[serde(default)]
+    pub events: Vec<types::LogEvent>,
+}
+
+impl EventHookRequest {
+    /// Verify the `Authorization` header of the request, then parse its body.
+    pub fn from_request(
+        secret: &str,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self, HookError> {
+        verify_authorization(secret, headers)?;
+        Ok(serde_json::from_slice(body)?)
+    }
+}
+
+/// A request sent to an inline hook, waiting for its commands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlineHookRequest {
+    /// The type of the inline hook, like `com.okta.oauth2.tokens.transform`.
+    pub event_type: types::InlineHookType,
+    pub event_type_version: String,
+    /// The inline hook the request was sent for, as a URL of the API.
+    pub source: String,
+    pub event_id: String,
+    pub event_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The data of the request, by the type of the inline hook.
+    pub data: InlineHookData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InlineHookEnvelope {
+    #[serde(default)]
+    event_type: types::InlineHookType,
+    #[serde(default)]
+    event_type_version: String,
+    #[serde(default)]
+    source: String,
+    #[serde(default)]
+    event_id: String,
+    #[serde(default)]
+    event_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+impl InlineHookRequest {
+    /// Parse the body of a request by its `eventType`. The `Authorization`
+    /// header isn't checked.
+    pub fn parse(body: &[u8]) -> Result<Self, HookError> {
+        let envelope: InlineHookEnvelope = serde_json::from_slice(body)?;
+        let data = match envelope.event_type {
+            types::InlineHookType::ComOktaOauth2TokensTransform => {
+                InlineHookData::Token(serde_json::from_value(envelope.data)?)
+            }
+            types::InlineHookType::ComOktaUserPreRegistration => {
+                InlineHookData::Registration(serde_json::from_value(envelope.data)?)
+            }
+            types::InlineHookType::ComOktaUserCredentialPasswordImport => {
+                InlineHookData::PasswordImport(serde_json::from_value(envelope.data)?)
+            }
+            types::InlineHookType::ComOktaSamlTokensTransform => {
+                InlineHookData::Saml(serde_json::from_value(envelope.data)?)
+            }
+            _ => InlineHookData::Unknown(envelope.data),
+        };
+
+        Ok(InlineHookRequest {
+            event_type: envelope.event_type,
+            event_type_version: envelope.event_type_version,
+            source: envelope.source,
+            event_id: envelope.event_id,
+            event_time: envelope.event_time,
+            data,
+        })
+    }
+
+    /// Verify the `Authorization` header of the request, then parse its body.
+    pub fn from_request(
+        secret: &str,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self, HookError> {
+        verify_authorization(secret, headers)?;
+        InlineHookRequest::parse(body)
+    }
+}
+
+/// The data of an inline hook request, by the type of the inline hook.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InlineHookData {
+    /// `com.okta.oauth2.tokens.transform`, the tokens an authorization server
+    /// is about to issue.
+    Token(TokenHookData),
+    /// `com.okta.user.pre-registration`, the profile of a user signing up.
+    Registration(RegistrationHookData),
+    /// `com.okta.user.credential.password.import`, the password of a user
+    /// still to import.
+    PasswordImport(PasswordImportHookData),
+    /// `com.okta.saml.tokens.transform`, the SAML assertion Okta is about to
+    /// issue.
+    Saml(SamlHookData),
+    /// The data of another type of inline hook, like
+    /// `com.okta.import.transform`.
+    Unknown(serde_json::Value),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TokenHookData {
+    /// The request, the protocol, the session, the user and the policy the
+    /// tokens are issued for.
+    #[serde(default)]
+    pub context: serde_json::Value,
+    /// The ID token, when one is issued.
+    #[serde(default)]
+    pub identity: Option<HookToken>,
+    /// The access token, when one is issued.
+    #[serde(default)]
+    pub access: Option<HookToken>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct HookToken {
+    #[serde(default)]
+    pub claims: serde_json::Map<String, serde_json::Value>,
+    /// The lifetime of the token.
+    #[serde(default)]
+    pub token: serde_json::Value,
+    /// The scopes granted, for an access token.
+    #[serde(default)]
+    pub scopes: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrationHookData {
+    #[serde(default)]
+    pub context: serde_json::Value,
+    /// The attributes of the profile the user filled in, like `email`.
+    #[serde(default)]
+    pub user_profile: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct PasswordImportHookData {
+    pub context: PasswordImportContext,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct PasswordImportContext {
+    #[serde(default)]
+    pub request: serde_json::Value,
+    pub credential: ImportedCredential,
+}
+
+/// The credentials a user signed in with, to check against the system the
+/// user is imported from.
+#[derive(Clone, Default, PartialEq, Deserialize)]
+pub struct ImportedCredential {
+    pub username: String,
+    pub password: String,
+}
+
+impl std::fmt::Debug for ImportedCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImportedCredential")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct SamlHookData {
+    #[serde(default)]
+    pub context: serde_json::Value,
+    /// The subject, the authentication, the conditions, the claims and the
+    /// lifetime of the assertion.
+    #[serde(default)]
+    pub assertion: serde_json::Value,
+}
+
+/// A change to a token or an assertion, at a JSON Pointer like
+/// `/claims/team`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatchOp {
+    Add(serde_json::Value),
+    Replace(serde_json::Value),
+    Remove,
+}
+
+/// A command of an inline hook response, like `com.okta.identity.patch`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Command {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub value: serde_json::Value,
+}
+
+/// The error an inline hook returns to stop the flow, like a registration.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseError {
+    pub error_summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub error_causes: Vec<ErrorCause>,
+}
+
+/// A cause of a [`ResponseError`], like the attribute of the profile that was
+/// rejected.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCause {
+    pub error_summary: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub reason: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub location_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub domain: String,
+}
+
+/// The response of an inline hook, in the `commands` format of Okta. Unlike
+/// [`types::InlineHookResponse`], the values of the commands can be any JSON,
+/// like the claims of a token. An empty response leaves the flow as is.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct InlineHookResponse {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<Command>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+impl InlineHookResponse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the ID token of a token inline hook.
+    pub fn patch_identity<P>(self, path: P, op: PatchOp) -> Self
+    where
+        P: ToString,
+    {
+        self.patch("com.okta.identity.patch", path.to_string(), op)
+    }
+
+    /// Change the access token of a token inline hook.
+    pub fn patch_access<P>(self, path: P, op: PatchOp) -> Self
+    where
+        P: ToString,
+    {
+        self.patch("com.okta.access.patch", path.to_string(), op)
+    }
+
+    /// Change the assertion of a SAML inline hook.
+    pub fn patch_assertion<P>(self, path: P, op: PatchOp) -> Self
+    where
+        P: ToString,
+    {
+        self.patch("com.okta.assertion.patch", path.to_string(), op)
+    }
+
+    /// Add a claim to the ID token of a token inline hook.
+    pub fn add_identity_claim<V>(self, name: &str, value: V) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        self.patch_identity(format!("/claims/{}", name), PatchOp::Add(value.into()))
+    }
+
+    /// Add a claim to the access token of a token inline hook.
+    pub fn add_access_claim<V>(self, name: &str, value: V) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        self.patch_access(format!("/claims/{}", name), PatchOp::Add(value.into()))
+    }
+
+    /// Let the user of a registration inline hook sign up.
+    pub fn allow_registration(self) -> Self {
+        self.update("com.okta.action.update", "registration", "ALLOW".into())
+    }
+
+    /// Stop the user of a registration inline hook from signing up. Add an
+    /// [`error`](Self::error) to tell the user why.
+    pub fn deny_registration(self) -> Self {
+        self.update("com.okta.action.update", "registration", "DENY".into())
+    }
+
+    /// Set an attribute of the profile of the user of a registration inline
+    /// hook.
+    pub fn update_profile<V>(self, attribute: &str, value: V) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        self.update("com.okta.user.profile.update", attribute, value.into())
+    }
+
+    /// Tell a password import inline hook whether the password is the one of
+    /// the user. Okta then keeps it as the password of the user.
+    pub fn verify_credential(self, verified: bool) -> Self {
+        let credential = if verified { "VERIFIED" } else { "UNVERIFIED" };
+        self.update("com.okta.action.update", "credential", credential.into())
+    }
+
+    /// Stop the flow with an error.
+    pub fn error<S>(mut self, summary: S) -> Self
+    where
+        S: ToString,
+    {
+        self.error
+            .get_or_insert_with(Default::default)
+            .error_summary = summary.to_string();
+        self
+    }
+
+    /// Add a cause to the error stopping the flow.
+    pub fn error_cause(mut self, cause: ErrorCause) -> Self {
+        self.error
+            .get_or_insert_with(Default::default)
+            .error_causes
+            .push(cause);
+        self
+    }
+
+    /// The value of the command of this type, added if missing.
+    fn command(&mut self, type_: &str, empty: serde_json::Value) -> &mut serde_json::Value {
+        let i = match self.commands.iter().position(|c| c.type_ == type_) {
+            Some(i) => i,
+            None => {
+                self.commands.push(Command {
+                    type_: type_.to_string(),
+                    value: empty,
+                });
+                self.commands.len() - 1
+            }
+        };
+        &mut self.commands[i].value
+    }
+
+    fn patch(mut self, type_: &str, path: String, op: PatchOp) -> Self {
+        let patch = match op {
+            PatchOp::Add(value) => serde_json::json!({"op": "add", "path": path, "value": value}),
+            PatchOp::Replace(value) => {
+                serde_json::json!({"op": "replace", "path": path, "value": value})
+            }
+            PatchOp::Remove => serde_json::json!({"op": "remove", "path": path}),
+        };
+        if let serde_json::Value::Array(patches) = self.command(type_, serde_json::json!([])) {
+            patches.push(patch);
+        }
+        self
+    }
+
+    fn update(mut self, type_: &str, key: &str, value: serde_json::Value) -> Self {
+        if let serde_json::Value::Object(values) = self.command(type_, serde_json::json!({})) {
+            values.insert(key.to_string(), value);
+        }
+        self
+    }
+
+    /// The JSON body of the response.
+    pub fn body(&self) -> String {
+        serde_json::json!(self).to_string()
+    }
+
+    /// The `200 OK` response carrying the commands.
+    pub fn into_response(self) -> http::Response<String> {
+        json_response(self.body())
+    }
+}
+
+fn json_response(body: String) -> http::Response<String> {
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        // The status and the header are valid.
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(authorization: &str) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::AUTHORIZATION, authorization.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn verifies_authorization() {
+        verify_authorization("secret", &headers("secret")).unwrap();
+        assert!(matches!(
+            verify_authorization("secret", &headers("other")),
+            Err(HookError::Unauthorized)
+        ));
+        assert!(matches!(
+            verify_authorization("secret", &http::HeaderMap::new()),
+            Err(HookError::MissingHeader("authorization"))
+        ));
+    }
+
+    #[test]
+    fn answers_verification_challenges() {
+        let mut headers = headers("secret");
+        headers.insert(
+            X_OKTA_VERIFICATION_CHALLENGE,
+            "2AF7RZUMxStiJ4RdC0rBwUK0dXYmGJe-ZrC19Tll".parse().unwrap(),
+        );
+        let response = EventHookVerification::from_request("secret", &headers)
+            .unwrap()
+            .into_response();
+        assert_eq!(
+            response.body(),
+            r#"{"verification":"2AF7RZUMxStiJ4RdC0rBwUK0dXYmGJe-ZrC19Tll"}"#
+        );
+    }
+
+    #[test]
+    fn parses_event_hooks() {
+        let body = br#"{
+            "eventType": "com.okta.event_hook",
+            "eventTypeVersion": "1.0",
+            "cloudEventsVersion": "0.1",
+            "source": "https://example.okta.com/api/v1/eventHooks/whoql0HfiLGPWc8Ek0g4",
+            "eventId": "b5a188b9-5ece-4636-b041-482ffda96311",
+            "eventTime": "2019-03-27T16:59:53.032Z",
+            "contentType": "application/json",
+            "data": {
+                "events": [{
+                    "uuid": "4ecd5c89-50b3-11e9-a3b3-bf0a1f0d3b2f",
+                    "published": "2019-03-27T16:59:52.814Z",
+                    "eventType": "user.session.start",
+                    "displayMessage": "User login to Okta",
+                    "outcome": {"result": "SUCCESS"}
+                }]
+            }
+        }"#;
+        let request = EventHookRequest::from_request("secret", &headers("secret"), body).unwrap();
+        assert_eq!(request.event_id, "b5a188b9-5ece-4636-b041-482ffda96311");
+        assert_eq!(request.data.events[0].event_type, "user.session.start");
+        assert_eq!(request.data.events[0].display_message, "User login to Okta");
+
+        assert!(matches!(
+            EventHookRequest::from_request("secret", &headers("other"), body),
+            Err(HookError::Unauthorized)
+        ));
+    }
+
+    #[test]
+    fn parses_inline_hooks() {
+        let body = br#"{
+            "eventType": "com.okta.user.credential.password.import",
+            "eventTypeVersion": "1.0",
+            "data": {
+                "context": {
+                    "request": {"method": "POST"},
+                    "credential": {"username": "isaac.brock@example.com", "password": "Okta"}
+                },
+                "action": {"credential": "UNVERIFIED"}
+            }
+        }"#;
+        match InlineHookRequest::parse(body).unwrap().data {
+            InlineHookData::PasswordImport(data) => {
+                assert_eq!(data.context.credential.username, "isaac.brock@example.com");
+                assert!(!format!("{:?}", data).contains("Okta\""));
+            }
+            other => panic!("expected a password import, got {:?}", other),
+        }
+
+        let body = br#"{
+            "eventType": "com.okta.oauth2.tokens.transform",
+            "data": {"access": {"claims": {"sub": "isaac.brock@example.com"}, "scopes": {}}}
+        }"#;
+        match InlineHookRequest::parse(body).unwrap().data {
+            InlineHookData::Token(data) => {
+                assert_eq!(
+                    data.access.unwrap().claims["sub"],
+                    "isaac.brock@example.com"
+                );
+                assert_eq!(data.identity, None);
+            }
+            other => panic!("expected a token, got {:?}", other),
+        }
+
+        let body = br#"{"eventType": "com.okta.import.transform", "data": {"appUser": {}}}"#;
+        assert!(matches!(
+            InlineHookRequest::parse(body).unwrap().data,
+            InlineHookData::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn builds_commands() {
+        let response = InlineHookResponse::new()
+            .add_identity_claim("team", "blue")
+            .patch_identity("/token/lifetime/expiration", PatchOp::Replace(3600.into()))
+            .patch_access("/claims/groups", PatchOp::Remove);
+        assert_eq!(
+            serde_json::json!(response),
+            serde_json::json!({"commands": [
+                {"type": "com.okta.identity.patch", "value": [
+                    {"op": "add", "path": "/claims/team", "value": "blue"},
+                    {"op": "replace", "path": "/token/lifetime/expiration", "value": 3600}
+                ]},
+                {"type": "com.okta.access.patch", "value": [
+                    {"op": "remove", "path": "/claims/groups"}
+                ]}
+            ]})
+        );
+
+        let response = InlineHookResponse::new()
+            .deny_registration()
+            .error("Registration is closed")
+            .error_cause(ErrorCause {
+                error_summary: "Use a company address".to_string(),
+                location: "data.userProfile.email".to_string(),
+                ..Default::default()
+            });
+        assert_eq!(
+            serde_json::json!(response),
+            serde_json::json!({
+                "commands": [{"type": "com.okta.action.update", "value": {"registration": "DENY"}}],
+                "error": {
+                    "errorSummary": "Registration is closed",
+                    "errorCauses": [{
+                        "errorSummary": "Use a company address",
+                        "location": "data.userProfile.email"
+                    }]
+                }
+            })
+        );
+
+        assert_eq!(
+            InlineHookResponse::new().verify_credential(true).body(),
+            r#"{"commands":[{"type":"com.okta.action.update","value":{"credential":"VERIFIED"}}]}"#
+        );
+    }
+}
@@ -91,6 +91,61 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        type_: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IdentityProvider> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IdentityProvider>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IdentityProvider>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add Identity Provider.
      *
@@ -172,6 +227,44 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::JsonWebKey> {
+        let url = self.client.url("/api/v1/idps/credentials/keys", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::JsonWebKey>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::JsonWebKey>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add X.509 Certificate Public Key.
      *
@@ -399,6 +492,52 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates Certificate Signing Requests for an IdP
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Csr> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Csr>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Csr>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Generate Certificate Signing Request for IdP.
      *
@@ -582,6 +721,52 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates signing key credentials for an IdP
+     */
+    pub fn list_all_signing_keys_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::JsonWebKey> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::JsonWebKey>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::JsonWebKey>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Generate New IdP Signing Key Credential.
      *
@@ -820,6 +1005,58 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Find all the users linked to an identity provider
+     */
+    pub fn list_all_application_users_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IdentityProviderApplicationUser> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IdentityProviderApplicationUser>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IdentityProviderApplicationUser>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
      *
@@ -987,4 +1224,58 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Fetches the tokens minted by the Social Authentication Provider when the user authenticates with Okta via Social Auth.
+     */
+    pub fn list_all_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SocialAuthToken> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users/{}/credentials/tokens",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SocialAuthToken>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SocialAuthToken>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
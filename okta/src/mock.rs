@@ -0,0 +1,3901 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Applications`](crate::applications::Applications).
+    pub fn applications(&self) -> Applications<'_> {
+        Applications { mock: self }
+    }
+
+    /// Mock the functions of [`AuthorizationServers`](crate::authorization_servers::AuthorizationServers).
+    pub fn authorization_servers(&self) -> AuthorizationServers<'_> {
+        AuthorizationServers { mock: self }
+    }
+
+    /// Mock the functions of [`Domains`](crate::domains::Domains).
+    pub fn domains(&self) -> Domains<'_> {
+        Domains { mock: self }
+    }
+
+    /// Mock the functions of [`EventHooks`](crate::event_hooks::EventHooks).
+    pub fn event_hooks(&self) -> EventHooks<'_> {
+        EventHooks { mock: self }
+    }
+
+    /// Mock the functions of [`Features`](crate::features::Features).
+    pub fn features(&self) -> Features<'_> {
+        Features { mock: self }
+    }
+
+    /// Mock the functions of [`Groups`](crate::groups::Groups).
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { mock: self }
+    }
+
+    /// Mock the functions of [`IdentityProviders`](crate::identity_providers::IdentityProviders).
+    pub fn identity_providers(&self) -> IdentityProviders<'_> {
+        IdentityProviders { mock: self }
+    }
+
+    /// Mock the functions of [`InlineHooks`](crate::inline_hooks::InlineHooks).
+    pub fn inline_hooks(&self) -> InlineHooks<'_> {
+        InlineHooks { mock: self }
+    }
+
+    /// Mock the functions of [`LinkedObjects`](crate::linked_objects::LinkedObjects).
+    pub fn linked_objects(&self) -> LinkedObjects<'_> {
+        LinkedObjects { mock: self }
+    }
+
+    /// Mock the functions of [`Logs`](crate::logs::Logs).
+    pub fn logs(&self) -> Logs<'_> {
+        Logs { mock: self }
+    }
+
+    /// Mock the functions of [`NetworkZones`](crate::network_zones::NetworkZones).
+    pub fn network_zones(&self) -> NetworkZones<'_> {
+        NetworkZones { mock: self }
+    }
+
+    /// Mock the functions of [`Policies`](crate::policies::Policies).
+    pub fn policies(&self) -> Policies<'_> {
+        Policies { mock: self }
+    }
+
+    /// Mock the functions of [`ProfileMappings`](crate::profile_mappings::ProfileMappings).
+    pub fn profile_mappings(&self) -> ProfileMappings<'_> {
+        ProfileMappings { mock: self }
+    }
+
+    /// Mock the functions of [`Sessions`](crate::sessions::Sessions).
+    pub fn sessions(&self) -> Sessions<'_> {
+        Sessions { mock: self }
+    }
+
+    /// Mock the functions of [`Templates`](crate::templates::Templates).
+    pub fn templates(&self) -> Templates<'_> {
+        Templates { mock: self }
+    }
+
+    /// Mock the functions of [`ThreatInsights`](crate::threat_insights::ThreatInsights).
+    pub fn threat_insights(&self) -> ThreatInsights<'_> {
+        ThreatInsights { mock: self }
+    }
+
+    /// Mock the functions of [`TrustedOrigins`](crate::trusted_origins::TrustedOrigins).
+    pub fn trusted_origins(&self) -> TrustedOrigins<'_> {
+        TrustedOrigins { mock: self }
+    }
+
+    /// Mock the functions of [`UserFactors`](crate::user_factors::UserFactors).
+    pub fn user_factors(&self) -> UserFactors<'_> {
+        UserFactors { mock: self }
+    }
+
+    /// Mock the functions of [`UserSchemas`](crate::user_schemas::UserSchemas).
+    pub fn user_schemas(&self) -> UserSchemas<'_> {
+        UserSchemas { mock: self }
+    }
+
+    /// Mock the functions of [`UserTypes`](crate::user_types::UserTypes).
+    pub fn user_types(&self) -> UserTypes<'_> {
+        UserTypes { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+}
+
+pub struct Applications<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Applications<'a> {
+    /// Mock [`Applications::list`](crate::applications::Applications::list), a `GET` to `/api/v1/apps`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Application>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/apps".to_string())
+    }
+
+    /// Mock [`Applications::create`](crate::applications::Applications::create), a `POST` to `/api/v1/apps`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Application> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/apps".to_string())
+    }
+
+    /// Mock [`Applications::get`](crate::applications::Applications::get), a `GET` to `/api/v1/apps/{appId}`.
+    pub fn get(&self, app_id: &str) -> crate::mock::MockOperation<'a, crate::types::Application> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::update`](crate::applications::Applications::update), a `PUT` to `/api/v1/apps/{appId}`.
+    pub fn update(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Application> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/apps/{}",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::delete`](crate::applications::Applications::delete), a `DELETE` to `/api/v1/apps/{appId}`.
+    pub fn delete(&self, app_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_csrs_fors`](crate::applications::Applications::list_csrs_fors), a `GET` to `/api/v1/apps/{appId}/credentials/csrs`.
+    pub fn list_csrs_fors(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Csr>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::generate_csr_for`](crate::applications::Applications::generate_csr_for), a `POST` to `/api/v1/apps/{appId}/credentials/csrs`.
+    pub fn generate_csr_for(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Csr> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_csr_for`](crate::applications::Applications::get_csr_for), a `GET` to `/api/v1/apps/{appId}/credentials/csrs/{csrId}`.
+    pub fn get_csr_for(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Csr> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/credentials/csrs/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::revoke_csr_from`](crate::applications::Applications::revoke_csr_from), a `DELETE` to `/api/v1/apps/{appId}/credentials/csrs/{csrId}`.
+    pub fn revoke_csr_from(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/credentials/csrs/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::post_app_credentials_csr_lifecycle_publish`](crate::applications::Applications::post_app_credentials_csr_lifecycle_publish), a `POST` to `/api/v1/apps/{appId}/credentials/csrs/{csrId}/lifecycle/publish`.
+    pub fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/credentials/csrs/{}/lifecycle/publish",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_keys`](crate::applications::Applications::list_keys), a `GET` to `/api/v1/apps/{appId}/credentials/keys`.
+    pub fn list_keys(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::JsonWebKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::generate_key`](crate::applications::Applications::generate_key), a `POST` to `/api/v1/apps/{appId}/credentials/keys/generate`.
+    pub fn generate_key(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/credentials/keys/generate",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_key`](crate::applications::Applications::get_key), a `GET` to `/api/v1/apps/{appId}/credentials/keys/{keyId}`.
+    pub fn get_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/credentials/keys/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::clone_key`](crate::applications::Applications::clone_key), a `POST` to `/api/v1/apps/{appId}/credentials/keys/{keyId}/clone`.
+    pub fn clone_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/credentials/keys/{}/clone",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_scope_consent_grants`](crate::applications::Applications::list_scope_consent_grants), a `GET` to `/api/v1/apps/{appId}/grants`.
+    pub fn list_scope_consent_grants(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/grants",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::grant_consent_scope`](crate::applications::Applications::grant_consent_scope), a `POST` to `/api/v1/apps/{appId}/grants`.
+    pub fn grant_consent_scope(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2ScopeConsentGrant> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/grants",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_scope_consent_grant`](crate::applications::Applications::get_scope_consent_grant), a `GET` to `/api/v1/apps/{appId}/grants/{grantId}`.
+    pub fn get_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2ScopeConsentGrant> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/grants/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(grant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::revoke_scope_consent_grant`](crate::applications::Applications::revoke_scope_consent_grant), a `DELETE` to `/api/v1/apps/{appId}/grants/{grantId}`.
+    pub fn revoke_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/grants/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(grant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_group_assignments`](crate::applications::Applications::list_group_assignments), a `GET` to `/api/v1/apps/{appId}/groups`.
+    pub fn list_group_assignments(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ApplicationGroupAssignment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/groups",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_group_assignment`](crate::applications::Applications::get_group_assignment), a `GET` to `/api/v1/apps/{appId}/groups/{groupId}`.
+    pub fn get_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplicationGroupAssignment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/groups/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::create_group_assignment`](crate::applications::Applications::create_group_assignment), a `PUT` to `/api/v1/apps/{appId}/groups/{groupId}`.
+    pub fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplicationGroupAssignment> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/apps/{}/groups/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::delete_group_assignment`](crate::applications::Applications::delete_group_assignment), a `DELETE` to `/api/v1/apps/{appId}/groups/{groupId}`.
+    pub fn delete_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/groups/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::activate`](crate::applications::Applications::activate), a `POST` to `/api/v1/apps/{appId}/lifecycle/activate`.
+    pub fn activate(&self, app_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::deactivate`](crate::applications::Applications::deactivate), a `POST` to `/api/v1/apps/{appId}/lifecycle/deactivate`.
+    pub fn deactivate(&self, app_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_o_auth_2_tokens_fors`](crate::applications::Applications::list_o_auth_2_tokens_fors), a `GET` to `/api/v1/apps/{appId}/tokens`.
+    pub fn list_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2Token>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/tokens",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::revoke_o_auth_2_tokens_for`](crate::applications::Applications::revoke_o_auth_2_tokens_for), a `DELETE` to `/api/v1/apps/{appId}/tokens`.
+    pub fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/tokens",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_o_auth_2_token_for`](crate::applications::Applications::get_o_auth_2_token_for), a `GET` to `/api/v1/apps/{appId}/tokens/{tokenId}`.
+    pub fn get_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Token> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/tokens/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::revoke_o_auth_2_token_for`](crate::applications::Applications::revoke_o_auth_2_token_for), a `DELETE` to `/api/v1/apps/{appId}/tokens/{tokenId}`.
+    pub fn revoke_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/tokens/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::list_users`](crate::applications::Applications::list_users), a `GET` to `/api/v1/apps/{appId}/users`.
+    pub fn list_users(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AppUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/users",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::assign_user`](crate::applications::Applications::assign_user), a `POST` to `/api/v1/apps/{appId}/users`.
+    pub fn assign_user(
+        &self,
+        app_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppUser> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/users",
+                crate::progenitor_support::encode_path(app_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::get_user`](crate::applications::Applications::get_user), a `GET` to `/api/v1/apps/{appId}/users/{userId}`.
+    pub fn get_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppUser> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/apps/{}/users/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::update_user`](crate::applications::Applications::update_user), a `POST` to `/api/v1/apps/{appId}/users/{userId}`.
+    pub fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppUser> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/apps/{}/users/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Applications::delete_user`](crate::applications::Applications::delete_user), a `DELETE` to `/api/v1/apps/{appId}/users/{userId}`.
+    pub fn delete_user(&self, app_id: &str, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/apps/{}/users/{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct AuthorizationServers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AuthorizationServers<'a> {
+    /// Mock [`AuthorizationServers::list`](crate::authorization_servers::AuthorizationServers::list), a `GET` to `/api/v1/authorizationServers`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::AuthorizationServer>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/api/v1/authorizationServers".to_string(),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::create`](crate::authorization_servers::AuthorizationServers::create), a `POST` to `/api/v1/authorizationServers`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServer> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/api/v1/authorizationServers".to_string(),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get`](crate::authorization_servers::AuthorizationServers::get), a `GET` to `/api/v1/authorizationServers/{authServerId}`.
+    pub fn get(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServer> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::update`](crate::authorization_servers::AuthorizationServers::update), a `PUT` to `/api/v1/authorizationServers/{authServerId}`.
+    pub fn update(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServer> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/authorizationServers/{}",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::delete`](crate::authorization_servers::AuthorizationServers::delete), a `DELETE` to `/api/v1/authorizationServers/{authServerId}`.
+    pub fn delete(&self, auth_server_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_o_auth_2_claims`](crate::authorization_servers::AuthorizationServers::list_o_auth_2_claims), a `GET` to `/api/v1/authorizationServers/{authServerId}/claims`.
+    pub fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2Claim>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/claims",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::create_o_auth_2_claim`](crate::authorization_servers::AuthorizationServers::create_o_auth_2_claim), a `POST` to `/api/v1/authorizationServers/{authServerId}/claims`.
+    pub fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Claim> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/claims",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get_o_auth_2_claim`](crate::authorization_servers::AuthorizationServers::get_o_auth_2_claim), a `GET` to `/api/v1/authorizationServers/{authServerId}/claims/{claimId}`.
+    pub fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Claim> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/claims/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(claim_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::update_o_auth_2_claim`](crate::authorization_servers::AuthorizationServers::update_o_auth_2_claim), a `PUT` to `/api/v1/authorizationServers/{authServerId}/claims/{claimId}`.
+    pub fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Claim> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/authorizationServers/{}/claims/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(claim_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::delete_o_auth_2_claim`](crate::authorization_servers::AuthorizationServers::delete_o_auth_2_claim), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/claims/{claimId}`.
+    pub fn delete_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/claims/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(claim_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_o_auth_2_clients_fors`](crate::authorization_servers::AuthorizationServers::list_o_auth_2_clients_fors), a `GET` to `/api/v1/authorizationServers/{authServerId}/clients`.
+    pub fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2Client>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/clients",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_refresh_tokens_for_and_clients`](crate::authorization_servers::AuthorizationServers::list_refresh_tokens_for_and_clients), a `GET` to `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens`.
+    pub fn list_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2RefreshToken>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::revoke_refresh_tokens_for_and_client`](crate::authorization_servers::AuthorizationServers::revoke_refresh_tokens_for_and_client), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens`.
+    pub fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get_refresh_token_for_and_client`](crate::authorization_servers::AuthorizationServers::get_refresh_token_for_and_client), a `GET` to `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}`.
+    pub fn get_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2RefreshToken> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::revoke_refresh_token_for_and_client`](crate::authorization_servers::AuthorizationServers::revoke_refresh_token_for_and_client), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}`.
+    pub fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_keys`](crate::authorization_servers::AuthorizationServers::list_keys), a `GET` to `/api/v1/authorizationServers/{authServerId}/credentials/keys`.
+    pub fn list_keys(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::JsonWebKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/credentials/keys",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::rotate_keys`](crate::authorization_servers::AuthorizationServers::rotate_keys), a `POST` to `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate`.
+    pub fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::JsonWebKey>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/credentials/lifecycle/keyRotate",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::activate`](crate::authorization_servers::AuthorizationServers::activate), a `POST` to `/api/v1/authorizationServers/{authServerId}/lifecycle/activate`.
+    pub fn activate(&self, auth_server_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::deactivate`](crate::authorization_servers::AuthorizationServers::deactivate), a `POST` to `/api/v1/authorizationServers/{authServerId}/lifecycle/deactivate`.
+    pub fn deactivate(&self, auth_server_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_policies`](crate::authorization_servers::AuthorizationServers::list_policies), a `GET` to `/api/v1/authorizationServers/{authServerId}/policies`.
+    pub fn list_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AuthorizationServerPolicy>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/policies",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::create_policy`](crate::authorization_servers::AuthorizationServers::create_policy), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies`.
+    pub fn create_policy(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicy> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get_policy`](crate::authorization_servers::AuthorizationServers::get_policy), a `GET` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}`.
+    pub fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicy> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::update_policy`](crate::authorization_servers::AuthorizationServers::update_policy), a `PUT` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}`.
+    pub fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicy> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::delete_policy`](crate::authorization_servers::AuthorizationServers::delete_policy), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}`.
+    pub fn delete_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::activate_policy`](crate::authorization_servers::AuthorizationServers::activate_policy), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/activate`.
+    pub fn activate_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::deactivate_policy`](crate::authorization_servers::AuthorizationServers::deactivate_policy), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/deactivate`.
+    pub fn deactivate_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_policy_rules`](crate::authorization_servers::AuthorizationServers::list_policy_rules), a `GET` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules`.
+    pub fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AuthorizationServerPolicyRule>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::create_policy_rule`](crate::authorization_servers::AuthorizationServers::create_policy_rule), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules`.
+    pub fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicyRule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get_policy_rule`](crate::authorization_servers::AuthorizationServers::get_policy_rule), a `GET` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}`.
+    pub fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicyRule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::update_policy_rule`](crate::authorization_servers::AuthorizationServers::update_policy_rule), a `PUT` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}`.
+    pub fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthorizationServerPolicyRule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::delete_policy_rule`](crate::authorization_servers::AuthorizationServers::delete_policy_rule), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}`.
+    pub fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::activate_policy_rule`](crate::authorization_servers::AuthorizationServers::activate_policy_rule), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/activate`.
+    pub fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::deactivate_policy_rule`](crate::authorization_servers::AuthorizationServers::deactivate_policy_rule), a `POST` to `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate`.
+    pub fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::list_o_auth_2_scopes`](crate::authorization_servers::AuthorizationServers::list_o_auth_2_scopes), a `GET` to `/api/v1/authorizationServers/{authServerId}/scopes`.
+    pub fn list_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2Scope>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/scopes",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::create_o_auth_2_scope`](crate::authorization_servers::AuthorizationServers::create_o_auth_2_scope), a `POST` to `/api/v1/authorizationServers/{authServerId}/scopes`.
+    pub fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Scope> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/authorizationServers/{}/scopes",
+                crate::progenitor_support::encode_path(auth_server_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::get_o_auth_2_scope`](crate::authorization_servers::AuthorizationServers::get_o_auth_2_scope), a `GET` to `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}`.
+    pub fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Scope> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/authorizationServers/{}/scopes/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(scope_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::update_o_auth_2_scope`](crate::authorization_servers::AuthorizationServers::update_o_auth_2_scope), a `PUT` to `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}`.
+    pub fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2Scope> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/authorizationServers/{}/scopes/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(scope_id)
+            ),
+        )
+    }
+
+    /// Mock [`AuthorizationServers::delete_o_auth_2_scope`](crate::authorization_servers::AuthorizationServers::delete_o_auth_2_scope), a `DELETE` to `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}`.
+    pub fn delete_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/authorizationServers/{}/scopes/{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(scope_id)
+            ),
+        )
+    }
+}
+
+pub struct Domains<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Domains<'a> {
+    /// Mock [`Domains::list`](crate::domains::Domains::list), a `GET` to `/api/v1/domains`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DomainListResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/domains".to_string())
+    }
+
+    /// Mock [`Domains::create`](crate::domains::Domains::create), a `POST` to `/api/v1/domains`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Domain> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/domains".to_string())
+    }
+
+    /// Mock [`Domains::get`](crate::domains::Domains::get), a `GET` to `/api/v1/domains/{domainId}`.
+    pub fn get(&self, domain_id: &str) -> crate::mock::MockOperation<'a, crate::types::Domain> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/domains/{}",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::delete`](crate::domains::Domains::delete), a `DELETE` to `/api/v1/domains/{domainId}`.
+    pub fn delete(&self, domain_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/domains/{}",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::create_certificate`](crate::domains::Domains::create_certificate), a `PUT` to `/api/v1/domains/{domainId}/certificate`.
+    pub fn create_certificate(&self, domain_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/domains/{}/certificate",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`Domains::verify`](crate::domains::Domains::verify), a `POST` to `/api/v1/domains/{domainId}/verify`.
+    pub fn verify(&self, domain_id: &str) -> crate::mock::MockOperation<'a, crate::types::Domain> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/domains/{}/verify",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+}
+
+pub struct EventHooks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EventHooks<'a> {
+    /// Mock [`EventHooks::list`](crate::event_hooks::EventHooks::list), a `GET` to `/api/v1/eventHooks`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::EventHook>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/eventHooks".to_string())
+    }
+
+    /// Mock [`EventHooks::create`](crate::event_hooks::EventHooks::create), a `POST` to `/api/v1/eventHooks`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/eventHooks".to_string())
+    }
+
+    /// Mock [`EventHooks::get`](crate::event_hooks::EventHooks::get), a `GET` to `/api/v1/eventHooks/{eventHookId}`.
+    pub fn get(
+        &self,
+        event_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/eventHooks/{}",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`EventHooks::update`](crate::event_hooks::EventHooks::update), a `PUT` to `/api/v1/eventHooks/{eventHookId}`.
+    pub fn update(
+        &self,
+        event_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/eventHooks/{}",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`EventHooks::delete`](crate::event_hooks::EventHooks::delete), a `DELETE` to `/api/v1/eventHooks/{eventHookId}`.
+    pub fn delete(&self, event_hook_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/eventHooks/{}",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`EventHooks::activate`](crate::event_hooks::EventHooks::activate), a `POST` to `/api/v1/eventHooks/{eventHookId}/lifecycle/activate`.
+    pub fn activate(
+        &self,
+        event_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/eventHooks/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`EventHooks::deactivate`](crate::event_hooks::EventHooks::deactivate), a `POST` to `/api/v1/eventHooks/{eventHookId}/lifecycle/deactivate`.
+    pub fn deactivate(
+        &self,
+        event_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/eventHooks/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`EventHooks::verify`](crate::event_hooks::EventHooks::verify), a `POST` to `/api/v1/eventHooks/{eventHookId}/lifecycle/verify`.
+    pub fn verify(
+        &self,
+        event_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EventHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/eventHooks/{}/lifecycle/verify",
+                crate::progenitor_support::encode_path(event_hook_id)
+            ),
+        )
+    }
+}
+
+pub struct Features<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Features<'a> {
+    /// Mock [`Features::list`](crate::features::Features::list), a `GET` to `/api/v1/features`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Feature>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/features".to_string())
+    }
+
+    /// Mock [`Features::get`](crate::features::Features::get), a `GET` to `/api/v1/features/{featureId}`.
+    pub fn get(&self, feature_id: &str) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/features/{}",
+                crate::progenitor_support::encode_path(feature_id)
+            ),
+        )
+    }
+
+    /// Mock [`Features::list_dependencies`](crate::features::Features::list_dependencies), a `GET` to `/api/v1/features/{featureId}/dependencies`.
+    pub fn list_dependencies(
+        &self,
+        feature_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Feature>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/features/{}/dependencies",
+                crate::progenitor_support::encode_path(feature_id)
+            ),
+        )
+    }
+
+    /// Mock [`Features::list_dependents`](crate::features::Features::list_dependents), a `GET` to `/api/v1/features/{featureId}/dependents`.
+    pub fn list_dependents(
+        &self,
+        feature_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Feature>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/features/{}/dependents",
+                crate::progenitor_support::encode_path(feature_id)
+            ),
+        )
+    }
+
+    /// Mock [`Features::update_lifecycle`](crate::features::Features::update_lifecycle), a `POST` to `/api/v1/features/{featureId}/{lifecycle}`.
+    pub fn update_lifecycle(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Feature> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/features/{}/{}",
+                crate::progenitor_support::encode_path(feature_id),
+                crate::progenitor_support::encode_path(lifecycle)
+            ),
+        )
+    }
+}
+
+pub struct Groups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Groups<'a> {
+    /// Mock [`Groups::list`](crate::groups::Groups::list), a `GET` to `/api/v1/groups`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Group>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/groups".to_string())
+    }
+
+    /// Mock [`Groups::create`](crate::groups::Groups::create), a `POST` to `/api/v1/groups`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/groups".to_string())
+    }
+
+    /// Mock [`Groups::list_rules`](crate::groups::Groups::list_rules), a `GET` to `/api/v1/groups/rules`.
+    pub fn list_rules(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::GroupRule>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/groups/rules".to_string())
+    }
+
+    /// Mock [`Groups::create_rule`](crate::groups::Groups::create_rule), a `POST` to `/api/v1/groups/rules`.
+    pub fn create_rule(&self) -> crate::mock::MockOperation<'a, crate::types::GroupRule> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/groups/rules".to_string())
+    }
+
+    /// Mock [`Groups::get_rule`](crate::groups::Groups::get_rule), a `GET` to `/api/v1/groups/rules/{ruleId}`.
+    pub fn get_rule(
+        &self,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupRule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/rules/{}",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update_rule`](crate::groups::Groups::update_rule), a `PUT` to `/api/v1/groups/rules/{ruleId}`.
+    pub fn update_rule(
+        &self,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupRule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/rules/{}",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::delete_rule`](crate::groups::Groups::delete_rule), a `DELETE` to `/api/v1/groups/rules/{ruleId}`.
+    pub fn delete_rule(&self, rule_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/rules/{}",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::activate_rule`](crate::groups::Groups::activate_rule), a `POST` to `/api/v1/groups/rules/{ruleId}/lifecycle/activate`.
+    pub fn activate_rule(&self, rule_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/groups/rules/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::deactivate_rule`](crate::groups::Groups::deactivate_rule), a `POST` to `/api/v1/groups/rules/{ruleId}/lifecycle/deactivate`.
+    pub fn deactivate_rule(&self, rule_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/groups/rules/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get`](crate::groups::Groups::get), a `GET` to `/api/v1/groups/{groupId}`.
+    pub fn get(&self, group_id: &str) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update`](crate::groups::Groups::update), a `PUT` to `/api/v1/groups/{groupId}`.
+    pub fn update(&self, group_id: &str) -> crate::mock::MockOperation<'a, crate::types::Group> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::delete`](crate::groups::Groups::delete), a `DELETE` to `/api/v1/groups/{groupId}`.
+    pub fn delete(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::list_assigned_applications_fors`](crate::groups::Groups::list_assigned_applications_fors), a `GET` to `/api/v1/groups/{groupId}/apps`.
+    pub fn list_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Application>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/apps",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::list_assigned_roles`](crate::groups::Groups::list_assigned_roles), a `GET` to `/api/v1/groups/{groupId}/roles`.
+    pub fn list_assigned_roles(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Role>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/roles",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::assign_role`](crate::groups::Groups::assign_role), a `POST` to `/api/v1/groups/{groupId}/roles`.
+    pub fn assign_role(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/groups/{}/roles",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_role`](crate::groups::Groups::get_role), a `GET` to `/api/v1/groups/{groupId}/roles/{roleId}`.
+    pub fn get_role(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/roles/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::remove_role_from`](crate::groups::Groups::remove_role_from), a `DELETE` to `/api/v1/groups/{groupId}/roles/{roleId}`.
+    pub fn remove_role_from(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}/roles/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::list_application_targets_for_administrator_roles`](crate::groups::Groups::list_application_targets_for_administrator_roles), a `GET` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps`.
+    pub fn list_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CatalogApplication>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::add_application_target_admin_role_given`](crate::groups::Groups::add_application_target_admin_role_given), a `PUT` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}`.
+    pub fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::remove_application_target_from_administrator_role_given`](crate::groups::Groups::remove_application_target_from_administrator_role_given), a `DELETE` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}`.
+    pub fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::add_application_instance_target_app_admin_role_given`](crate::groups::Groups::add_application_instance_target_app_admin_role_given), a `PUT` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}`.
+    pub fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name),
+                crate::progenitor_support::encode_path(application_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::remove_application_target_from_administrator_role_given_groups`](crate::groups::Groups::remove_application_target_from_administrator_role_given_groups), a `DELETE` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}`.
+    pub fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name),
+                crate::progenitor_support::encode_path(application_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::list_targets_for_roles`](crate::groups::Groups::list_targets_for_roles), a `GET` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups`.
+    pub fn list_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Group>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::add_target_administrator_role_for`](crate::groups::Groups::add_target_administrator_role_for), a `PUT` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}`.
+    pub fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(target_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::remove_target_from_administrator_role_given`](crate::groups::Groups::remove_target_from_administrator_role_given), a `DELETE` to `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}`.
+    pub fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(target_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::list_users`](crate::groups::Groups::list_users), a `GET` to `/api/v1/groups/{groupId}/users`.
+    pub fn list_users(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::User>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/groups/{}/users",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::add_user`](crate::groups::Groups::add_user), a `PUT` to `/api/v1/groups/{groupId}/users/{userId}`.
+    pub fn add_user(&self, group_id: &str, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/groups/{}/users/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::remove_user_from`](crate::groups::Groups::remove_user_from), a `DELETE` to `/api/v1/groups/{groupId}/users/{userId}`.
+    pub fn remove_user_from(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/groups/{}/users/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct IdentityProviders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IdentityProviders<'a> {
+    /// Mock [`IdentityProviders::list`](crate::identity_providers::IdentityProviders::list), a `GET` to `/api/v1/idps`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::IdentityProvider>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/idps".to_string())
+    }
+
+    /// Mock [`IdentityProviders::create`](crate::identity_providers::IdentityProviders::create), a `POST` to `/api/v1/idps`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::IdentityProvider> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/idps".to_string())
+    }
+
+    /// Mock [`IdentityProviders::list_keys`](crate::identity_providers::IdentityProviders::list_keys), a `GET` to `/api/v1/idps/credentials/keys`.
+    pub fn list_keys(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::JsonWebKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/api/v1/idps/credentials/keys".to_string(),
+        )
+    }
+
+    /// Mock [`IdentityProviders::create_key`](crate::identity_providers::IdentityProviders::create_key), a `POST` to `/api/v1/idps/credentials/keys`.
+    pub fn create_key(&self) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/api/v1/idps/credentials/keys".to_string(),
+        )
+    }
+
+    /// Mock [`IdentityProviders::get_key`](crate::identity_providers::IdentityProviders::get_key), a `GET` to `/api/v1/idps/credentials/keys/{keyId}`.
+    pub fn get_key(
+        &self,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/credentials/keys/{}",
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::delete_key`](crate::identity_providers::IdentityProviders::delete_key), a `DELETE` to `/api/v1/idps/credentials/keys/{keyId}`.
+    pub fn delete_key(&self, key_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/idps/credentials/keys/{}",
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::get`](crate::identity_providers::IdentityProviders::get), a `GET` to `/api/v1/idps/{idpId}`.
+    pub fn get(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProvider> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::update`](crate::identity_providers::IdentityProviders::update), a `PUT` to `/api/v1/idps/{idpId}`.
+    pub fn update(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProvider> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/idps/{}",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::delete`](crate::identity_providers::IdentityProviders::delete), a `DELETE` to `/api/v1/idps/{idpId}`.
+    pub fn delete(&self, idp_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/idps/{}",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::list_csrs_fors`](crate::identity_providers::IdentityProviders::list_csrs_fors), a `GET` to `/api/v1/idps/{idpId}/credentials/csrs`.
+    pub fn list_csrs_fors(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Csr>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::generate_csr_for`](crate::identity_providers::IdentityProviders::generate_csr_for), a `POST` to `/api/v1/idps/{idpId}/credentials/csrs`.
+    pub fn generate_csr_for(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Csr> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::get_csr_for`](crate::identity_providers::IdentityProviders::get_csr_for), a `GET` to `/api/v1/idps/{idpId}/credentials/csrs/{csrId}`.
+    pub fn get_csr_for(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Csr> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/credentials/csrs/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::revoke_csr_for`](crate::identity_providers::IdentityProviders::revoke_csr_for), a `DELETE` to `/api/v1/idps/{idpId}/credentials/csrs/{csrId}`.
+    pub fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/idps/{}/credentials/csrs/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::post_idp_credentials_csr_lifecycle_publish`](crate::identity_providers::IdentityProviders::post_idp_credentials_csr_lifecycle_publish), a `POST` to `/api/v1/idps/{idpId}/credentials/csrs/{csrId}/lifecycle/publish`.
+    pub fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/credentials/csrs/{}/lifecycle/publish",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(csr_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::list_signing_keys`](crate::identity_providers::IdentityProviders::list_signing_keys), a `GET` to `/api/v1/idps/{idpId}/credentials/keys`.
+    pub fn list_signing_keys(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::JsonWebKey>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::generate_signing_key`](crate::identity_providers::IdentityProviders::generate_signing_key), a `POST` to `/api/v1/idps/{idpId}/credentials/keys/generate`.
+    pub fn generate_signing_key(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/credentials/keys/generate",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::get_signing_key`](crate::identity_providers::IdentityProviders::get_signing_key), a `GET` to `/api/v1/idps/{idpId}/credentials/keys/{keyId}`.
+    pub fn get_signing_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/credentials/keys/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::clone_key`](crate::identity_providers::IdentityProviders::clone_key), a `POST` to `/api/v1/idps/{idpId}/credentials/keys/{keyId}/clone`.
+    pub fn clone_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JsonWebKey> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/credentials/keys/{}/clone",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(key_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::activate`](crate::identity_providers::IdentityProviders::activate), a `POST` to `/api/v1/idps/{idpId}/lifecycle/activate`.
+    pub fn activate(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProvider> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::deactivate`](crate::identity_providers::IdentityProviders::deactivate), a `POST` to `/api/v1/idps/{idpId}/lifecycle/deactivate`.
+    pub fn deactivate(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProvider> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::list_application_users`](crate::identity_providers::IdentityProviders::list_application_users), a `GET` to `/api/v1/idps/{idpId}/users`.
+    pub fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IdentityProviderApplicationUser>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/users",
+                crate::progenitor_support::encode_path(idp_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::get_application_user`](crate::identity_providers::IdentityProviders::get_application_user), a `GET` to `/api/v1/idps/{idpId}/users/{userId}`.
+    pub fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProviderApplicationUser> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/users/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::link_user`](crate::identity_providers::IdentityProviders::link_user), a `POST` to `/api/v1/idps/{idpId}/users/{userId}`.
+    pub fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IdentityProviderApplicationUser> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/idps/{}/users/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::unlink_user_from`](crate::identity_providers::IdentityProviders::unlink_user_from), a `DELETE` to `/api/v1/idps/{idpId}/users/{userId}`.
+    pub fn unlink_user_from(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/idps/{}/users/{}",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`IdentityProviders::list_social_auth_tokens`](crate::identity_providers::IdentityProviders::list_social_auth_tokens), a `GET` to `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens`.
+    pub fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SocialAuthToken>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/idps/{}/users/{}/credentials/tokens",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct InlineHooks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> InlineHooks<'a> {
+    /// Mock [`InlineHooks::list`](crate::inline_hooks::InlineHooks::list), a `GET` to `/api/v1/inlineHooks`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::InlineHook>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/inlineHooks".to_string())
+    }
+
+    /// Mock [`InlineHooks::create`](crate::inline_hooks::InlineHooks::create), a `POST` to `/api/v1/inlineHooks`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::InlineHook> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/inlineHooks".to_string())
+    }
+
+    /// Mock [`InlineHooks::get`](crate::inline_hooks::InlineHooks::get), a `GET` to `/api/v1/inlineHooks/{inlineHookId}`.
+    pub fn get(
+        &self,
+        inline_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InlineHook> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/inlineHooks/{}",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`InlineHooks::update`](crate::inline_hooks::InlineHooks::update), a `PUT` to `/api/v1/inlineHooks/{inlineHookId}`.
+    pub fn update(
+        &self,
+        inline_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InlineHook> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/inlineHooks/{}",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`InlineHooks::delete`](crate::inline_hooks::InlineHooks::delete), a `DELETE` to `/api/v1/inlineHooks/{inlineHookId}`.
+    pub fn delete(&self, inline_hook_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/inlineHooks/{}",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`InlineHooks::execute`](crate::inline_hooks::InlineHooks::execute), a `POST` to `/api/v1/inlineHooks/{inlineHookId}/execute`.
+    pub fn execute(
+        &self,
+        inline_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InlineHookResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/inlineHooks/{}/execute",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`InlineHooks::activate`](crate::inline_hooks::InlineHooks::activate), a `POST` to `/api/v1/inlineHooks/{inlineHookId}/lifecycle/activate`.
+    pub fn activate(
+        &self,
+        inline_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InlineHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/inlineHooks/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+
+    /// Mock [`InlineHooks::deactivate`](crate::inline_hooks::InlineHooks::deactivate), a `POST` to `/api/v1/inlineHooks/{inlineHookId}/lifecycle/deactivate`.
+    pub fn deactivate(
+        &self,
+        inline_hook_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InlineHook> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/inlineHooks/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(inline_hook_id)
+            ),
+        )
+    }
+}
+
+pub struct LinkedObjects<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> LinkedObjects<'a> {
+    /// Mock [`LinkedObjects::list_definitions`](crate::linked_objects::LinkedObjects::list_definitions), a `GET` to `/api/v1/meta/schemas/user/linkedObjects`.
+    pub fn list_definitions(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::LinkedObject>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/api/v1/meta/schemas/user/linkedObjects".to_string(),
+        )
+    }
+
+    /// Mock [`LinkedObjects::add_definition`](crate::linked_objects::LinkedObjects::add_definition), a `POST` to `/api/v1/meta/schemas/user/linkedObjects`.
+    pub fn add_definition(&self) -> crate::mock::MockOperation<'a, crate::types::LinkedObject> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/api/v1/meta/schemas/user/linkedObjects".to_string(),
+        )
+    }
+
+    /// Mock [`LinkedObjects::get_definition`](crate::linked_objects::LinkedObjects::get_definition), a `GET` to `/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}`.
+    pub fn get_definition(
+        &self,
+        linked_object_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkedObject> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/meta/schemas/user/linkedObjects/{}",
+                crate::progenitor_support::encode_path(linked_object_name)
+            ),
+        )
+    }
+
+    /// Mock [`LinkedObjects::delete_definition`](crate::linked_objects::LinkedObjects::delete_definition), a `DELETE` to `/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}`.
+    pub fn delete_definition(
+        &self,
+        linked_object_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/meta/schemas/user/linkedObjects/{}",
+                crate::progenitor_support::encode_path(linked_object_name)
+            ),
+        )
+    }
+}
+
+pub struct Logs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Logs<'a> {
+    /// Mock [`Logs::get_page`](crate::logs::Logs::get_page), a `GET` to `/api/v1/logs`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::LogEvent>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/logs".to_string())
+    }
+}
+
+pub struct NetworkZones<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> NetworkZones<'a> {
+    /// Mock [`NetworkZones::list`](crate::network_zones::NetworkZones::list), a `GET` to `/api/v1/zones`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::NetworkZone>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/zones".to_string())
+    }
+
+    /// Mock [`NetworkZones::create`](crate::network_zones::NetworkZones::create), a `POST` to `/api/v1/zones`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::NetworkZone> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/zones".to_string())
+    }
+
+    /// Mock [`NetworkZones::get`](crate::network_zones::NetworkZones::get), a `GET` to `/api/v1/zones/{zoneId}`.
+    pub fn get(&self, zone_id: &str) -> crate::mock::MockOperation<'a, crate::types::NetworkZone> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/zones/{}",
+                crate::progenitor_support::encode_path(zone_id)
+            ),
+        )
+    }
+
+    /// Mock [`NetworkZones::update`](crate::network_zones::NetworkZones::update), a `PUT` to `/api/v1/zones/{zoneId}`.
+    pub fn update(
+        &self,
+        zone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NetworkZone> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/zones/{}",
+                crate::progenitor_support::encode_path(zone_id)
+            ),
+        )
+    }
+
+    /// Mock [`NetworkZones::delete`](crate::network_zones::NetworkZones::delete), a `DELETE` to `/api/v1/zones/{zoneId}`.
+    pub fn delete(&self, zone_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/zones/{}",
+                crate::progenitor_support::encode_path(zone_id)
+            ),
+        )
+    }
+
+    /// Mock [`NetworkZones::activate`](crate::network_zones::NetworkZones::activate), a `POST` to `/api/v1/zones/{zoneId}/lifecycle/activate`.
+    pub fn activate(
+        &self,
+        zone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NetworkZone> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/zones/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(zone_id)
+            ),
+        )
+    }
+
+    /// Mock [`NetworkZones::deactivate`](crate::network_zones::NetworkZones::deactivate), a `POST` to `/api/v1/zones/{zoneId}/lifecycle/deactivate`.
+    pub fn deactivate(
+        &self,
+        zone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::NetworkZone> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/zones/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(zone_id)
+            ),
+        )
+    }
+}
+
+pub struct Policies<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Policies<'a> {
+    /// Mock [`Policies::list`](crate::policies::Policies::list), a `GET` to `/api/v1/policies`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Policy>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/policies".to_string())
+    }
+
+    /// Mock [`Policies::create_policy`](crate::policies::Policies::create_policy), a `POST` to `/api/v1/policies`.
+    pub fn create_policy(&self) -> crate::mock::MockOperation<'a, crate::types::Policy> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/policies".to_string())
+    }
+
+    /// Mock [`Policies::get_policy`](crate::policies::Policies::get_policy), a `GET` to `/api/v1/policies/{policyId}`.
+    pub fn get_policy(
+        &self,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Policy> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/policies/{}",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::update_policy`](crate::policies::Policies::update_policy), a `PUT` to `/api/v1/policies/{policyId}`.
+    pub fn update_policy(
+        &self,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Policy> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/policies/{}",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::delete_policy`](crate::policies::Policies::delete_policy), a `DELETE` to `/api/v1/policies/{policyId}`.
+    pub fn delete_policy(&self, policy_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/policies/{}",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::activate_policy`](crate::policies::Policies::activate_policy), a `POST` to `/api/v1/policies/{policyId}/lifecycle/activate`.
+    pub fn activate_policy(&self, policy_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/policies/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::deactivate_policy`](crate::policies::Policies::deactivate_policy), a `POST` to `/api/v1/policies/{policyId}/lifecycle/deactivate`.
+    pub fn deactivate_policy(&self, policy_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/policies/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::list_policy_rules`](crate::policies::Policies::list_policy_rules), a `GET` to `/api/v1/policies/{policyId}/rules`.
+    pub fn list_policy_rules(
+        &self,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::PolicyRule>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/policies/{}/rules",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::create_policy_rule`](crate::policies::Policies::create_policy_rule), a `POST` to `/api/v1/policies/{policyId}/rules`.
+    pub fn create_policy_rule(
+        &self,
+        policy_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PolicyRule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/policies/{}/rules",
+                crate::progenitor_support::encode_path(policy_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::get_policy_rule`](crate::policies::Policies::get_policy_rule), a `GET` to `/api/v1/policies/{policyId}/rules/{ruleId}`.
+    pub fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PolicyRule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::update_policy_rule`](crate::policies::Policies::update_policy_rule), a `PUT` to `/api/v1/policies/{policyId}/rules/{ruleId}`.
+    pub fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PolicyRule> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::delete_policy_rule`](crate::policies::Policies::delete_policy_rule), a `DELETE` to `/api/v1/policies/{policyId}/rules/{ruleId}`.
+    pub fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/policies/{}/rules/{}",
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::activate_policy_rule`](crate::policies::Policies::activate_policy_rule), a `POST` to `/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/activate`.
+    pub fn activate_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/policies/{}/rules/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Policies::deactivate_policy_rule`](crate::policies::Policies::deactivate_policy_rule), a `POST` to `/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate`.
+    pub fn deactivate_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/policies/{}/rules/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(policy_id),
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+}
+
+pub struct ProfileMappings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ProfileMappings<'a> {
+    /// Mock [`ProfileMappings::list`](crate::profile_mappings::ProfileMappings::list), a `GET` to `/api/v1/mappings`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::ProfileMapping>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/mappings".to_string())
+    }
+
+    /// Mock [`ProfileMappings::get`](crate::profile_mappings::ProfileMappings::get), a `GET` to `/api/v1/mappings/{mappingId}`.
+    pub fn get(
+        &self,
+        mapping_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProfileMapping> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/mappings/{}",
+                crate::progenitor_support::encode_path(mapping_id)
+            ),
+        )
+    }
+
+    /// Mock [`ProfileMappings::update`](crate::profile_mappings::ProfileMappings::update), a `POST` to `/api/v1/mappings/{mappingId}`.
+    pub fn update(
+        &self,
+        mapping_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ProfileMapping> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/mappings/{}",
+                crate::progenitor_support::encode_path(mapping_id)
+            ),
+        )
+    }
+}
+
+pub struct Sessions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Sessions<'a> {
+    /// Mock [`Sessions::create`](crate::sessions::Sessions::create), a `POST` to `/api/v1/sessions`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/sessions".to_string())
+    }
+
+    /// Mock [`Sessions::get`](crate::sessions::Sessions::get), a `GET` to `/api/v1/sessions/{sessionId}`.
+    pub fn get(&self, session_id: &str) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/sessions/{}",
+                crate::progenitor_support::encode_path(session_id)
+            ),
+        )
+    }
+
+    /// Mock [`Sessions::end`](crate::sessions::Sessions::end), a `DELETE` to `/api/v1/sessions/{sessionId}`.
+    pub fn end(&self, session_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/sessions/{}",
+                crate::progenitor_support::encode_path(session_id)
+            ),
+        )
+    }
+
+    /// Mock [`Sessions::refresh`](crate::sessions::Sessions::refresh), a `POST` to `/api/v1/sessions/{sessionId}/lifecycle/refresh`.
+    pub fn refresh(
+        &self,
+        session_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/sessions/{}/lifecycle/refresh",
+                crate::progenitor_support::encode_path(session_id)
+            ),
+        )
+    }
+}
+
+pub struct Templates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Templates<'a> {
+    /// Mock [`Templates::list_sms`](crate::templates::Templates::list_sms), a `GET` to `/api/v1/templates/sms`.
+    pub fn list_sms(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::SmsTemplate>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/templates/sms".to_string())
+    }
+
+    /// Mock [`Templates::create_sms`](crate::templates::Templates::create_sms), a `POST` to `/api/v1/templates/sms`.
+    pub fn create_sms(&self) -> crate::mock::MockOperation<'a, crate::types::SmsTemplate> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/templates/sms".to_string())
+    }
+
+    /// Mock [`Templates::get_sm`](crate::templates::Templates::get_sm), a `GET` to `/api/v1/templates/sms/{templateId}`.
+    pub fn get_sm(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SmsTemplate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/templates/sms/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::update_sms`](crate::templates::Templates::update_sms), a `PUT` to `/api/v1/templates/sms/{templateId}`.
+    pub fn update_sms(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SmsTemplate> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/templates/sms/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::partial_update_sms`](crate::templates::Templates::partial_update_sms), a `POST` to `/api/v1/templates/sms/{templateId}`.
+    pub fn partial_update_sms(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SmsTemplate> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/templates/sms/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Templates::delete_sms`](crate::templates::Templates::delete_sms), a `DELETE` to `/api/v1/templates/sms/{templateId}`.
+    pub fn delete_sms(&self, template_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/templates/sms/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct ThreatInsights<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ThreatInsights<'a> {
+    /// Mock [`ThreatInsights::get_current_configuration`](crate::threat_insights::ThreatInsights::get_current_configuration), a `GET` to `/api/v1/threats/configuration`.
+    pub fn get_current_configuration(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ThreatInsightConfiguration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/api/v1/threats/configuration".to_string(),
+        )
+    }
+
+    /// Mock [`ThreatInsights::update_configuration`](crate::threat_insights::ThreatInsights::update_configuration), a `POST` to `/api/v1/threats/configuration`.
+    pub fn update_configuration(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ThreatInsightConfiguration> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/api/v1/threats/configuration".to_string(),
+        )
+    }
+}
+
+pub struct TrustedOrigins<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TrustedOrigins<'a> {
+    /// Mock [`TrustedOrigins::list_origins`](crate::trusted_origins::TrustedOrigins::list_origins), a `GET` to `/api/v1/trustedOrigins`.
+    pub fn list_origins(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::TrustedOrigin>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/trustedOrigins".to_string())
+    }
+
+    /// Mock [`TrustedOrigins::create_origin`](crate::trusted_origins::TrustedOrigins::create_origin), a `POST` to `/api/v1/trustedOrigins`.
+    pub fn create_origin(&self) -> crate::mock::MockOperation<'a, crate::types::TrustedOrigin> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/trustedOrigins".to_string())
+    }
+
+    /// Mock [`TrustedOrigins::get_origin`](crate::trusted_origins::TrustedOrigins::get_origin), a `GET` to `/api/v1/trustedOrigins/{trustedOriginId}`.
+    pub fn get_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrustedOrigin> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/trustedOrigins/{}",
+                crate::progenitor_support::encode_path(trusted_origin_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrustedOrigins::update_origin`](crate::trusted_origins::TrustedOrigins::update_origin), a `PUT` to `/api/v1/trustedOrigins/{trustedOriginId}`.
+    pub fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrustedOrigin> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/trustedOrigins/{}",
+                crate::progenitor_support::encode_path(trusted_origin_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrustedOrigins::delete_origin`](crate::trusted_origins::TrustedOrigins::delete_origin), a `DELETE` to `/api/v1/trustedOrigins/{trustedOriginId}`.
+    pub fn delete_origin(&self, trusted_origin_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/trustedOrigins/{}",
+                crate::progenitor_support::encode_path(trusted_origin_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrustedOrigins::activate_origin`](crate::trusted_origins::TrustedOrigins::activate_origin), a `POST` to `/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/activate`.
+    pub fn activate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrustedOrigin> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/trustedOrigins/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(trusted_origin_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrustedOrigins::deactivate_origin`](crate::trusted_origins::TrustedOrigins::deactivate_origin), a `POST` to `/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/deactivate`.
+    pub fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrustedOrigin> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/trustedOrigins/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(trusted_origin_id)
+            ),
+        )
+    }
+}
+
+pub struct UserFactors<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserFactors<'a> {
+    /// Mock [`UserFactors::list_factors`](crate::user_factors::UserFactors::list_factors), a `GET` to `/api/v1/users/{userId}/factors`.
+    pub fn list_factors(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserFactor>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::enroll_factor`](crate::user_factors::UserFactors::enroll_factor), a `POST` to `/api/v1/users/{userId}/factors`.
+    pub fn enroll_factor(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserFactor> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::list_supported_factors`](crate::user_factors::UserFactors::list_supported_factors), a `GET` to `/api/v1/users/{userId}/factors/catalog`.
+    pub fn list_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserFactor>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/factors/catalog",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::list_supported_security_questions`](crate::user_factors::UserFactors::list_supported_security_questions), a `GET` to `/api/v1/users/{userId}/factors/questions`.
+    pub fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SecurityQuestion>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/factors/questions",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::get_factor`](crate::user_factors::UserFactors::get_factor), a `GET` to `/api/v1/users/{userId}/factors/{factorId}`.
+    pub fn get_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserFactor> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/factors/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::delete_factor`](crate::user_factors::UserFactors::delete_factor), a `DELETE` to `/api/v1/users/{userId}/factors/{factorId}`.
+    pub fn delete_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/factors/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::activate_factor`](crate::user_factors::UserFactors::activate_factor), a `POST` to `/api/v1/users/{userId}/factors/{factorId}/lifecycle/activate`.
+    pub fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserFactor> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/factors/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::get_factor_transaction_status`](crate::user_factors::UserFactors::get_factor_transaction_status), a `GET` to `/api/v1/users/{userId}/factors/{factorId}/transactions/{transactionId}`.
+    pub fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifyUserFactorResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/factors/{}/transactions/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id),
+                crate::progenitor_support::encode_path(transaction_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserFactors::verify_factor`](crate::user_factors::UserFactors::verify_factor), a `POST` to `/api/v1/users/{userId}/factors/{factorId}/verify`.
+    pub fn verify_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifyUserFactorResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/factors/{}/verify",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id)
+            ),
+        )
+    }
+}
+
+pub struct UserSchemas<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserSchemas<'a> {
+    /// Mock [`UserSchemas::get_application`](crate::user_schemas::UserSchemas::get_application), a `GET` to `/api/v1/meta/schemas/apps/{appInstanceId}/default`.
+    pub fn get_application(
+        &self,
+        app_instance_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/meta/schemas/apps/{}/default",
+                crate::progenitor_support::encode_path(app_instance_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSchemas::update_application_user_profile`](crate::user_schemas::UserSchemas::update_application_user_profile), a `POST` to `/api/v1/meta/schemas/apps/{appInstanceId}/default`.
+    pub fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/meta/schemas/apps/{}/default",
+                crate::progenitor_support::encode_path(app_instance_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSchemas::get`](crate::user_schemas::UserSchemas::get), a `GET` to `/api/v1/meta/schemas/user/{schemaId}`.
+    pub fn get(&self, schema_id: &str) -> crate::mock::MockOperation<'a, crate::types::UserSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/meta/schemas/user/{}",
+                crate::progenitor_support::encode_path(schema_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserSchemas::update_user_profile`](crate::user_schemas::UserSchemas::update_user_profile), a `POST` to `/api/v1/meta/schemas/user/{schemaId}`.
+    pub fn update_user_profile(
+        &self,
+        schema_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/meta/schemas/user/{}",
+                crate::progenitor_support::encode_path(schema_id)
+            ),
+        )
+    }
+}
+
+pub struct UserTypes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UserTypes<'a> {
+    /// Mock [`UserTypes::list`](crate::user_types::UserTypes::list), a `GET` to `/api/v1/meta/types/user`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::UserType>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/meta/types/user".to_string())
+    }
+
+    /// Mock [`UserTypes::create`](crate::user_types::UserTypes::create), a `POST` to `/api/v1/meta/types/user`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::UserType> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/meta/types/user".to_string())
+    }
+
+    /// Mock [`UserTypes::get`](crate::user_types::UserTypes::get), a `GET` to `/api/v1/meta/types/user/{typeId}`.
+    pub fn get(&self, type_id: &str) -> crate::mock::MockOperation<'a, crate::types::UserType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/meta/types/user/{}",
+                crate::progenitor_support::encode_path(type_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserTypes::replace`](crate::user_types::UserTypes::replace), a `PUT` to `/api/v1/meta/types/user/{typeId}`.
+    pub fn replace(&self, type_id: &str) -> crate::mock::MockOperation<'a, crate::types::UserType> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/meta/types/user/{}",
+                crate::progenitor_support::encode_path(type_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserTypes::update`](crate::user_types::UserTypes::update), a `POST` to `/api/v1/meta/types/user/{typeId}`.
+    pub fn update(&self, type_id: &str) -> crate::mock::MockOperation<'a, crate::types::UserType> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/meta/types/user/{}",
+                crate::progenitor_support::encode_path(type_id)
+            ),
+        )
+    }
+
+    /// Mock [`UserTypes::delete`](crate::user_types::UserTypes::delete), a `DELETE` to `/api/v1/meta/types/user/{typeId}`.
+    pub fn delete(&self, type_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/meta/types/user/{}",
+                crate::progenitor_support::encode_path(type_id)
+            ),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::list`](crate::users::Users::list), a `GET` to `/api/v1/users`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::User>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api/v1/users".to_string())
+    }
+
+    /// Mock [`Users::create`](crate::users::Users::create), a `POST` to `/api/v1/users`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api/v1/users".to_string())
+    }
+
+    /// Mock [`Users::set_linked_object_for`](crate::users::Users::set_linked_object_for), a `PUT` to `/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}`.
+    pub fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}/linkedObjects/{}/{}",
+                crate::progenitor_support::encode_path(associated_user_id),
+                crate::progenitor_support::encode_path(primary_relationship_name),
+                crate::progenitor_support::encode_path(primary_user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get`](crate::users::Users::get), a `GET` to `/api/v1/users/{userId}`.
+    pub fn get(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::update`](crate::users::Users::update), a `PUT` to `/api/v1/users/{userId}`.
+    pub fn update(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::partial_update`](crate::users::Users::partial_update), a `POST` to `/api/v1/users/{userId}`.
+    pub fn partial_update(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::deactivate_or_delete`](crate::users::Users::deactivate_or_delete), a `DELETE` to `/api/v1/users/{userId}`.
+    pub fn deactivate_or_delete(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_app_links`](crate::users::Users::list_app_links), a `GET` to `/api/v1/users/{userId}/appLinks`.
+    pub fn list_app_links(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::AppLink>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/appLinks",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_clients`](crate::users::Users::list_clients), a `GET` to `/api/v1/users/{userId}/clients`.
+    pub fn list_clients(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2Client>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/clients",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_grants_for_and_clients`](crate::users::Users::list_grants_for_and_clients), a `GET` to `/api/v1/users/{userId}/clients/{clientId}/grants`.
+    pub fn list_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/clients/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::revoke_grants_for_and_client`](crate::users::Users::revoke_grants_for_and_client), a `DELETE` to `/api/v1/users/{userId}/clients/{clientId}/grants`.
+    pub fn revoke_grants_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/clients/{}/grants",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_refresh_tokens_for_and_clients`](crate::users::Users::list_refresh_tokens_for_and_clients), a `GET` to `/api/v1/users/{userId}/clients/{clientId}/tokens`.
+    pub fn list_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2RefreshToken>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::revoke_tokens_for_and_client`](crate::users::Users::revoke_tokens_for_and_client), a `DELETE` to `/api/v1/users/{userId}/clients/{clientId}/tokens`.
+    pub fn revoke_tokens_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get_refresh_token_for_and_client`](crate::users::Users::get_refresh_token_for_and_client), a `GET` to `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}`.
+    pub fn get_refresh_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2RefreshToken> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::revoke_token_for_and_client`](crate::users::Users::revoke_token_for_and_client), a `DELETE` to `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}`.
+    pub fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/clients/{}/tokens/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::change_password`](crate::users::Users::change_password), a `POST` to `/api/v1/users/{userId}/credentials/change_password`.
+    pub fn change_password(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserCredentials> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/credentials/change_password",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::change_recovery_question`](crate::users::Users::change_recovery_question), a `POST` to `/api/v1/users/{userId}/credentials/change_recovery_question`.
+    pub fn change_recovery_question(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserCredentials> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/credentials/change_recovery_question",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::post_credentials_forgot_password`](crate::users::Users::post_credentials_forgot_password), a `POST` to `/api/v1/users/{userId}/credentials/forgot_password`.
+    pub fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ResetPasswordToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/credentials/forgot_password",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_grants`](crate::users::Users::list_grants), a `GET` to `/api/v1/users/{userId}/grants`.
+    pub fn list_grants(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/grants",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::revoke_grants`](crate::users::Users::revoke_grants), a `DELETE` to `/api/v1/users/{userId}/grants`.
+    pub fn revoke_grants(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/grants",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get_grant`](crate::users::Users::get_grant), a `GET` to `/api/v1/users/{userId}/grants/{grantId}`.
+    pub fn get_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OAuth2ScopeConsentGrant> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/grants/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(grant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::revoke_grant`](crate::users::Users::revoke_grant), a `DELETE` to `/api/v1/users/{userId}/grants/{grantId}`.
+    pub fn revoke_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/grants/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(grant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_groups`](crate::users::Users::list_groups), a `GET` to `/api/v1/users/{userId}/groups`.
+    pub fn list_groups(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Group>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/groups",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_identity_providers`](crate::users::Users::list_identity_providers), a `GET` to `/api/v1/users/{userId}/idps`.
+    pub fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IdentityProvider>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/idps",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::activate`](crate::users::Users::activate), a `POST` to `/api/v1/users/{userId}/lifecycle/activate`.
+    pub fn activate(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserActivationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/activate",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::deactivate`](crate::users::Users::deactivate), a `POST` to `/api/v1/users/{userId}/lifecycle/deactivate`.
+    pub fn deactivate(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/deactivate",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::expire_password`](crate::users::Users::expire_password), a `POST` to `/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=false`.
+    pub fn expire_password(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/expire_password?tempPassword=false",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::expire_password_and_get_temporary`](crate::users::Users::expire_password_and_get_temporary), a `POST` to `/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=true`.
+    pub fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TempPassword> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/expire_password?tempPassword=true",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::reactivate`](crate::users::Users::reactivate), a `POST` to `/api/v1/users/{userId}/lifecycle/reactivate`.
+    pub fn reactivate(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserActivationToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/reactivate",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::reset_factors`](crate::users::Users::reset_factors), a `POST` to `/api/v1/users/{userId}/lifecycle/reset_factors`.
+    pub fn reset_factors(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/reset_factors",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::reset_password`](crate::users::Users::reset_password), a `POST` to `/api/v1/users/{userId}/lifecycle/reset_password`.
+    pub fn reset_password(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ResetPasswordToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/reset_password",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::suspend`](crate::users::Users::suspend), a `POST` to `/api/v1/users/{userId}/lifecycle/suspend`.
+    pub fn suspend(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/suspend",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::unlock`](crate::users::Users::unlock), a `POST` to `/api/v1/users/{userId}/lifecycle/unlock`.
+    pub fn unlock(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/unlock",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::unsuspend`](crate::users::Users::unsuspend), a `POST` to `/api/v1/users/{userId}/lifecycle/unsuspend`.
+    pub fn unsuspend(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/lifecycle/unsuspend",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::get_linked_objects_fors`](crate::users::Users::get_linked_objects_fors), a `GET` to `/api/v1/users/{userId}/linkedObjects/{relationshipName}`.
+    pub fn get_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Links>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name)
+            ),
+        )
+    }
+
+    /// Mock [`Users::remove_linked_object_for`](crate::users::Users::remove_linked_object_for), a `DELETE` to `/api/v1/users/{userId}/linkedObjects/{relationshipName}`.
+    pub fn remove_linked_object_for(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_assigned_roles_fors`](crate::users::Users::list_assigned_roles_fors), a `GET` to `/api/v1/users/{userId}/roles`.
+    pub fn list_assigned_roles_fors(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Role>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/roles",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::assign_role`](crate::users::Users::assign_role), a `POST` to `/api/v1/users/{userId}/roles`.
+    pub fn assign_role(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::Role> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/api/v1/users/{}/roles",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::remove_role_from`](crate::users::Users::remove_role_from), a `DELETE` to `/api/v1/users/{userId}/roles/{roleId}`.
+    pub fn remove_role_from(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/roles/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_application_targets_for_administrator_roles`](crate::users::Users::list_application_targets_for_administrator_roles), a `GET` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps`.
+    pub fn list_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::CatalogApplication>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::add_all_apps_as_target_role`](crate::users::Users::add_all_apps_as_target_role), a `PUT` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps`.
+    pub fn add_all_apps_as_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::add_application_target_admin_role_for`](crate::users::Users::add_application_target_admin_role_for), a `PUT` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}`.
+    pub fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name)
+            ),
+        )
+    }
+
+    /// Mock [`Users::remove_application_target_from_administrator_role_for`](crate::users::Users::remove_application_target_from_administrator_role_for), a `DELETE` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}`.
+    pub fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name)
+            ),
+        )
+    }
+
+    /// Mock [`Users::add_application_target_app_admin_role_for`](crate::users::Users::add_application_target_app_admin_role_for), a `PUT` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}`.
+    pub fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name),
+                crate::progenitor_support::encode_path(application_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::remove_application_target_from_administrator_role_for_users`](crate::users::Users::remove_application_target_from_administrator_role_for_users), a `DELETE` to `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}`.
+    pub fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(app_name),
+                crate::progenitor_support::encode_path(application_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::list_group_targets_for_roles`](crate::users::Users::list_group_targets_for_roles), a `GET` to `/api/v1/users/{userId}/roles/{roleId}/targets/groups`.
+    pub fn list_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Group>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::add_group_target_role`](crate::users::Users::add_group_target_role), a `PUT` to `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}`.
+    pub fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/groups/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::remove_group_target_from_role`](crate::users::Users::remove_group_target_from_role), a `DELETE` to `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}`.
+    pub fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/roles/{}/targets/groups/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::clear_sessions`](crate::users::Users::clear_sessions), a `DELETE` to `/api/v1/users/{userId}/sessions`.
+    pub fn clear_sessions(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api/v1/users/{}/sessions",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
@@ -73,6 +73,55 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_all_sms_stream(
+        &self,
+        template_type: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SmsTemplate> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !template_type.is_empty() {
+            query_args.push(("templateType".to_string(), template_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/templates/sms?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SmsTemplate>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::SmsTemplate>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add SMS Template.
      *
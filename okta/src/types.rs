@@ -56,7 +56,7 @@ pub struct ActivateFactorRequest {
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "APP")]
     App,
@@ -64,8 +64,8 @@ pub enum Type {
     AppType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -74,12 +74,36 @@ impl std::fmt::Display for Type {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "APP" => Type::App,
+            "APP_TYPE" => Type::AppType,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -89,6 +113,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -314,7 +343,7 @@ pub struct AppUserPasswordCredential {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -324,8 +353,8 @@ pub enum Status {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -335,12 +364,37 @@ impl std::fmt::Display for Status {
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => Status::Active,
+            "DELETED" => Status::Deleted,
+            "INACTIVE" => Status::Inactive,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -350,6 +404,11 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -477,7 +536,7 @@ pub struct ApplicationCredentialsOAuthClient {
     pub token_endpoint_auth_method: Option<OAuthEndpointAuthenticationMethod>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ApplicationCredentialsScheme {
     #[serde(rename = "ADMIN_SETS_CREDENTIALS")]
     AdminSetsCredentials,
@@ -491,8 +550,8 @@ pub enum ApplicationCredentialsScheme {
     SharedUsernameAndPassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationCredentialsScheme {
@@ -506,12 +565,41 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
             ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ApplicationCredentialsScheme {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCredentialsScheme {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ADMIN_SETS_CREDENTIALS" => ApplicationCredentialsScheme::AdminSetsCredentials,
+            "EDIT_PASSWORD_ONLY" => ApplicationCredentialsScheme::EditPasswordOnly,
+            "EDIT_USERNAME_AND_PASSWORD" => ApplicationCredentialsScheme::EditUsernameAndPassword,
+            "EXTERNAL_PASSWORD_SYNC" => ApplicationCredentialsScheme::ExternalPasswordSync,
+            "SHARED_USERNAME_AND_PASSWORD" => {
+                ApplicationCredentialsScheme::SharedUsernameAndPassword
+            }
+            "" => ApplicationCredentialsScheme::Noop,
+            _ => ApplicationCredentialsScheme::Unknown(s),
+        })
+    }
+}
+
 impl Default for ApplicationCredentialsScheme {
     fn default() -> ApplicationCredentialsScheme {
         ApplicationCredentialsScheme::Noop
@@ -521,6 +609,11 @@ impl ApplicationCredentialsScheme {
     pub fn is_noop(&self) -> bool {
         matches!(self, ApplicationCredentialsScheme::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ApplicationCredentialsScheme::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -556,14 +649,14 @@ pub struct ApplicationCredentialsSigning {
     pub use_: Option<Use>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Use {
     #[serde(rename = "sig")]
     Sig,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Use {
@@ -571,12 +664,35 @@ impl std::fmt::Display for Use {
         match self {
             Use::Sig => "sig",
             Use::Noop => "",
-            Use::FallthroughString => "*",
+            Use::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Use {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Use {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "sig" => Use::Sig,
+            "" => Use::Noop,
+            _ => Use::Unknown(s),
+        })
+    }
+}
+
 impl Default for Use {
     fn default() -> Use {
         Use::Noop
@@ -586,6 +702,11 @@ impl Use {
     pub fn is_noop(&self) -> bool {
         matches!(self, Use::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Use::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -719,7 +840,7 @@ pub struct ApplicationSettingsNotificationsVpnNetwork {
     pub include: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ApplicationSignOnMode {
     #[serde(rename = "AUTO_LOGIN")]
     AutoLogin,
@@ -741,8 +862,8 @@ pub enum ApplicationSignOnMode {
     WsFederation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationSignOnMode {
@@ -758,12 +879,43 @@ impl std::fmt::Display for ApplicationSignOnMode {
             ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
             ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
             ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
+            ApplicationSignOnMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ApplicationSignOnMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationSignOnMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTO_LOGIN" => ApplicationSignOnMode::AutoLogin,
+            "BASIC_AUTH" => ApplicationSignOnMode::BasicAuth,
+            "BOOKMARK" => ApplicationSignOnMode::Bookmark,
+            "BROWSER_PLUGIN" => ApplicationSignOnMode::BrowserPlugin,
+            "OPENID_CONNECT" => ApplicationSignOnMode::OpenidConnect,
+            "SAML_1_1" => ApplicationSignOnMode::Saml1,
+            "SAML_2_0" => ApplicationSignOnMode::Saml20,
+            "SECURE_PASSWORD_STORE" => ApplicationSignOnMode::SecurePasswordStore,
+            "WS_FEDERATION" => ApplicationSignOnMode::WsFederation,
+            "" => ApplicationSignOnMode::Noop,
+            _ => ApplicationSignOnMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for ApplicationSignOnMode {
     fn default() -> ApplicationSignOnMode {
         ApplicationSignOnMode::Noop
@@ -773,6 +925,11 @@ impl ApplicationSignOnMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, ApplicationSignOnMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ApplicationSignOnMode::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -826,7 +983,7 @@ pub struct AuthenticationProvider {
     pub type_: Option<AuthenticationProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AuthenticationProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -842,8 +999,8 @@ pub enum AuthenticationProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationProviderType {
@@ -856,12 +1013,40 @@ impl std::fmt::Display for AuthenticationProviderType {
             AuthenticationProviderType::Okta => "OKTA",
             AuthenticationProviderType::Social => "SOCIAL",
             AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+            AuthenticationProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AuthenticationProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthenticationProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE_DIRECTORY" => AuthenticationProviderType::ActiveDirectory,
+            "FEDERATION" => AuthenticationProviderType::Federation,
+            "IMPORT" => AuthenticationProviderType::Import,
+            "LDAP" => AuthenticationProviderType::Ldap,
+            "OKTA" => AuthenticationProviderType::Okta,
+            "SOCIAL" => AuthenticationProviderType::Social,
+            "" => AuthenticationProviderType::Noop,
+            _ => AuthenticationProviderType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AuthenticationProviderType {
     fn default() -> AuthenticationProviderType {
         AuthenticationProviderType::Noop
@@ -871,9 +1056,14 @@ impl AuthenticationProviderType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthenticationProviderType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthenticationProviderType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IssuerMode {
     #[serde(rename = "CUSTOM_URL")]
     CustomUrl,
@@ -881,8 +1071,8 @@ pub enum IssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerMode {
@@ -891,12 +1081,36 @@ impl std::fmt::Display for IssuerMode {
             IssuerMode::CustomUrl => "CUSTOM_URL",
             IssuerMode::OrgUrl => "ORG_URL",
             IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
+            IssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IssuerMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuerMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM_URL" => IssuerMode::CustomUrl,
+            "ORG_URL" => IssuerMode::OrgUrl,
+            "" => IssuerMode::Noop,
+            _ => IssuerMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for IssuerMode {
     fn default() -> IssuerMode {
         IssuerMode::Noop
@@ -906,9 +1120,14 @@ impl IssuerMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, IssuerMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IssuerMode::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RoleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -916,8 +1135,8 @@ pub enum RoleStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleStatus {
@@ -926,12 +1145,36 @@ impl std::fmt::Display for RoleStatus {
             RoleStatus::Active => "ACTIVE",
             RoleStatus::Inactive => "INACTIVE",
             RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+            RoleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RoleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => RoleStatus::Active,
+            "INACTIVE" => RoleStatus::Inactive,
+            "" => RoleStatus::Noop,
+            _ => RoleStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for RoleStatus {
     fn default() -> RoleStatus {
         RoleStatus::Noop
@@ -941,6 +1184,11 @@ impl RoleStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RoleStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1008,7 +1256,7 @@ pub struct AuthorizationServerCredentials {
     pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AuthorizationServerCredentialsRotationMode {
     #[serde(rename = "AUTO")]
     Auto,
@@ -1016,8 +1264,8 @@ pub enum AuthorizationServerCredentialsRotationMode {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
@@ -1026,12 +1274,36 @@ impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
             AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
             AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
             AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AuthorizationServerCredentialsRotationMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizationServerCredentialsRotationMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTO" => AuthorizationServerCredentialsRotationMode::Auto,
+            "MANUAL" => AuthorizationServerCredentialsRotationMode::Manual,
+            "" => AuthorizationServerCredentialsRotationMode::Noop,
+            _ => AuthorizationServerCredentialsRotationMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for AuthorizationServerCredentialsRotationMode {
     fn default() -> AuthorizationServerCredentialsRotationMode {
         AuthorizationServerCredentialsRotationMode::Noop
@@ -1041,6 +1313,11 @@ impl AuthorizationServerCredentialsRotationMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorizationServerCredentialsRotationMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorizationServerCredentialsRotationMode::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1131,14 +1408,14 @@ pub struct AuthorizationServerPolicy {
     pub type_: Option<PolicyType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "RESOURCE_ACCESS")]
     ResourceAccess,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerPolicyRuleType {
@@ -1146,12 +1423,35 @@ impl std::fmt::Display for AuthorizationServerPolicyRuleType {
         match self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
             AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AuthorizationServerPolicyRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizationServerPolicyRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "RESOURCE_ACCESS" => AuthorizationServerPolicyRuleType::ResourceAccess,
+            "" => AuthorizationServerPolicyRuleType::Noop,
+            _ => AuthorizationServerPolicyRuleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AuthorizationServerPolicyRuleType {
     fn default() -> AuthorizationServerPolicyRuleType {
         AuthorizationServerPolicyRuleType::Noop
@@ -1161,6 +1461,11 @@ impl AuthorizationServerPolicyRuleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorizationServerPolicyRuleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthorizationServerPolicyRuleType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1655,7 +1960,7 @@ pub struct DnsRecord {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DnsRecordType {
     #[serde(rename = "CNAME")]
     Cname,
@@ -1663,8 +1968,8 @@ pub enum DnsRecordType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DnsRecordType {
@@ -1673,12 +1978,36 @@ impl std::fmt::Display for DnsRecordType {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
             DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DnsRecordType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DnsRecordType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CNAME" => DnsRecordType::Cname,
+            "TXT" => DnsRecordType::Txt,
+            "" => DnsRecordType::Noop,
+            _ => DnsRecordType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DnsRecordType {
     fn default() -> DnsRecordType {
         DnsRecordType::Noop
@@ -1688,9 +2017,14 @@ impl DnsRecordType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DnsRecordType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DnsRecordType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TrustLevel {
     #[serde(rename = "ANY")]
     Any,
@@ -1698,8 +2032,8 @@ pub enum TrustLevel {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrustLevel {
@@ -1708,12 +2042,36 @@ impl std::fmt::Display for TrustLevel {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
             TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TrustLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TrustLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY" => TrustLevel::Any,
+            "TRUSTED" => TrustLevel::Trusted,
+            "" => TrustLevel::Noop,
+            _ => TrustLevel::Unknown(s),
+        })
+    }
+}
+
 impl Default for TrustLevel {
     fn default() -> TrustLevel {
         TrustLevel::Noop
@@ -1723,6 +2081,11 @@ impl TrustLevel {
     pub fn is_noop(&self) -> bool {
         matches!(self, TrustLevel::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TrustLevel::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1747,7 +2110,7 @@ pub struct DevicePolicyRuleCondition {
     pub trust_level: Option<TrustLevel>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SupportedMdmFrameworks {
     #[serde(rename = "AFW")]
     Afw,
@@ -1757,8 +2120,8 @@ pub enum SupportedMdmFrameworks {
     Safe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedMdmFrameworks {
@@ -1768,12 +2131,37 @@ impl std::fmt::Display for SupportedMdmFrameworks {
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
             SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SupportedMdmFrameworks {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SupportedMdmFrameworks {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AFW" => SupportedMdmFrameworks::Afw,
+            "NATIVE" => SupportedMdmFrameworks::Native,
+            "SAFE" => SupportedMdmFrameworks::Safe,
+            "" => SupportedMdmFrameworks::Noop,
+            _ => SupportedMdmFrameworks::Unknown(s),
+        })
+    }
+}
+
 impl Default for SupportedMdmFrameworks {
     fn default() -> SupportedMdmFrameworks {
         SupportedMdmFrameworks::Noop
@@ -1783,9 +2171,14 @@ impl SupportedMdmFrameworks {
     pub fn is_noop(&self) -> bool {
         matches!(self, SupportedMdmFrameworks::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SupportedMdmFrameworks::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Types {
     #[serde(rename = "ANDROID")]
     Android,
@@ -1797,8 +2190,8 @@ pub enum Types {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Types {
@@ -1809,12 +2202,38 @@ impl std::fmt::Display for Types {
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
             Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Types {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Types {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANDROID" => Types::Android,
+            "IOS" => Types::Ios,
+            "OSX" => Types::Osx,
+            "WINDOWS" => Types::Windows,
+            "" => Types::Noop,
+            _ => Types::Unknown(s),
+        })
+    }
+}
+
 impl Default for Types {
     fn default() -> Types {
         Types::Noop
@@ -1824,6 +2243,11 @@ impl Types {
     pub fn is_noop(&self) -> bool {
         matches!(self, Types::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Types::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1932,14 +2356,14 @@ pub struct DomainCertificateMetadata {
     pub subject: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DomainCertificateSourceType {
     #[serde(rename = "MANUAL")]
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateSourceType {
@@ -1947,12 +2371,35 @@ impl std::fmt::Display for DomainCertificateSourceType {
         match self {
             DomainCertificateSourceType::Manual => "MANUAL",
             DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DomainCertificateSourceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainCertificateSourceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "MANUAL" => DomainCertificateSourceType::Manual,
+            "" => DomainCertificateSourceType::Noop,
+            _ => DomainCertificateSourceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DomainCertificateSourceType {
     fn default() -> DomainCertificateSourceType {
         DomainCertificateSourceType::Noop
@@ -1962,16 +2409,21 @@ impl DomainCertificateSourceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainCertificateSourceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DomainCertificateSourceType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DomainCertificateType {
     #[serde(rename = "PEM")]
     Pem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateType {
@@ -1979,12 +2431,35 @@ impl std::fmt::Display for DomainCertificateType {
         match self {
             DomainCertificateType::Pem => "PEM",
             DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
+            DomainCertificateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DomainCertificateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainCertificateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PEM" => DomainCertificateType::Pem,
+            "" => DomainCertificateType::Noop,
+            _ => DomainCertificateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DomainCertificateType {
     fn default() -> DomainCertificateType {
         DomainCertificateType::Noop
@@ -1994,6 +2469,11 @@ impl DomainCertificateType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainCertificateType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DomainCertificateType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2006,7 +2486,7 @@ pub struct DomainListResponse {
     pub domains: Vec<Domain>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DomainValidationStatus {
     #[serde(rename = "COMPLETED")]
     Completed,
@@ -2018,8 +2498,8 @@ pub enum DomainValidationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainValidationStatus {
@@ -2030,12 +2510,38 @@ impl std::fmt::Display for DomainValidationStatus {
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
             DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DomainValidationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainValidationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "COMPLETED" => DomainValidationStatus::Completed,
+            "IN_PROGRESS" => DomainValidationStatus::InProgress,
+            "NOT_STARTED" => DomainValidationStatus::NotStarted,
+            "VERIFIED" => DomainValidationStatus::Verified,
+            "" => DomainValidationStatus::Noop,
+            _ => DomainValidationStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for DomainValidationStatus {
     fn default() -> DomainValidationStatus {
         DomainValidationStatus::Noop
@@ -2045,6 +2551,11 @@ impl DomainValidationStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainValidationStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DomainValidationStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2079,7 +2590,7 @@ pub struct EmailUserFactorProfile {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EnabledStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -2087,8 +2598,8 @@ pub enum EnabledStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledStatus {
@@ -2097,12 +2608,36 @@ impl std::fmt::Display for EnabledStatus {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
             EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EnabledStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EnabledStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DISABLED" => EnabledStatus::Disabled,
+            "ENABLED" => EnabledStatus::Enabled,
+            "" => EnabledStatus::Noop,
+            _ => EnabledStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for EnabledStatus {
     fn default() -> EnabledStatus {
         EnabledStatus::Noop
@@ -2112,9 +2647,14 @@ impl EnabledStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, EnabledStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EnabledStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum VerificationStatus {
     #[serde(rename = "UNVERIFIED")]
     Unverified,
@@ -2122,8 +2662,8 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2132,12 +2672,36 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for VerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "UNVERIFIED" => VerificationStatus::Unverified,
+            "VERIFIED" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2147,6 +2711,11 @@ impl VerificationStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, VerificationStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, VerificationStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2199,14 +2768,14 @@ pub struct EventHook {
     pub verification_status: Option<VerificationStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EventHookChannelType {
     #[serde(rename = "HTTP")]
     Http,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelType {
@@ -2214,12 +2783,35 @@ impl std::fmt::Display for EventHookChannelType {
         match self {
             EventHookChannelType::Http => "HTTP",
             EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EventHookChannelType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventHookChannelType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HTTP" => EventHookChannelType::Http,
+            "" => EventHookChannelType::Noop,
+            _ => EventHookChannelType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EventHookChannelType {
     fn default() -> EventHookChannelType {
         EventHookChannelType::Noop
@@ -2229,6 +2821,11 @@ impl EventHookChannelType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EventHookChannelType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EventHookChannelType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2285,14 +2882,14 @@ pub struct EventHookChannelConfigAuthScheme {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EventHookChannelConfigAuthSchemeType {
     #[serde(rename = "HEADER")]
     Header,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
@@ -2300,12 +2897,35 @@ impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
         match self {
             EventHookChannelConfigAuthSchemeType::Header => "HEADER",
             EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EventHookChannelConfigAuthSchemeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventHookChannelConfigAuthSchemeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HEADER" => EventHookChannelConfigAuthSchemeType::Header,
+            "" => EventHookChannelConfigAuthSchemeType::Noop,
+            _ => EventHookChannelConfigAuthSchemeType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EventHookChannelConfigAuthSchemeType {
     fn default() -> EventHookChannelConfigAuthSchemeType {
         EventHookChannelConfigAuthSchemeType::Noop
@@ -2315,6 +2935,11 @@ impl EventHookChannelConfigAuthSchemeType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EventHookChannelConfigAuthSchemeType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EventHookChannelConfigAuthSchemeType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2333,7 +2958,7 @@ pub struct EventHookChannelConfigHeader {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EventSubscriptionsType {
     #[serde(rename = "EVENT_TYPE")]
     EventType,
@@ -2341,8 +2966,8 @@ pub enum EventSubscriptionsType {
     FlowEvent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventSubscriptionsType {
@@ -2351,12 +2976,36 @@ impl std::fmt::Display for EventSubscriptionsType {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
             EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EventSubscriptionsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventSubscriptionsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EVENT_TYPE" => EventSubscriptionsType::EventType,
+            "FLOW_EVENT" => EventSubscriptionsType::FlowEvent,
+            "" => EventSubscriptionsType::Noop,
+            _ => EventSubscriptionsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EventSubscriptionsType {
     fn default() -> EventSubscriptionsType {
         EventSubscriptionsType::Noop
@@ -2366,6 +3015,11 @@ impl EventSubscriptionsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EventSubscriptionsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EventSubscriptionsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2380,7 +3034,7 @@ pub struct EventSubscriptions {
     pub type_: Option<EventSubscriptionsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorProvider {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -2400,8 +3054,8 @@ pub enum FactorProvider {
     Yubico,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorProvider {
@@ -2416,12 +3070,42 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
             FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM" => FactorProvider::Custom,
+            "DUO" => FactorProvider::Duo,
+            "FIDO" => FactorProvider::Fido,
+            "GOOGLE" => FactorProvider::Google,
+            "OKTA" => FactorProvider::Okta,
+            "RSA" => FactorProvider::Rsa,
+            "SYMANTEC" => FactorProvider::Symantec,
+            "YUBICO" => FactorProvider::Yubico,
+            "" => FactorProvider::Noop,
+            _ => FactorProvider::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorProvider {
     fn default() -> FactorProvider {
         FactorProvider::Noop
@@ -2431,9 +3115,14 @@ impl FactorProvider {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorProvider::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorProvider::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorResultType {
     #[serde(rename = "CANCELLED")]
     Cancelled,
@@ -2457,8 +3146,8 @@ pub enum FactorResultType {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResultType {
@@ -2475,12 +3164,44 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
             FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorResultType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorResultType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CANCELLED" => FactorResultType::Cancelled,
+            "CHALLENGE" => FactorResultType::Challenge,
+            "ERROR" => FactorResultType::Error,
+            "FAILED" => FactorResultType::Failed,
+            "PASSCODE_REPLAYED" => FactorResultType::PasscodeReplayed,
+            "REJECTED" => FactorResultType::Rejected,
+            "SUCCESS" => FactorResultType::Success,
+            "TIMEOUT" => FactorResultType::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResultType::TimeWindowExceeded,
+            "WAITING" => FactorResultType::Waiting,
+            "" => FactorResultType::Noop,
+            _ => FactorResultType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorResultType {
     fn default() -> FactorResultType {
         FactorResultType::Noop
@@ -2490,9 +3211,14 @@ impl FactorResultType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorResultType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorResultType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2510,8 +3236,8 @@ pub enum FactorStatus {
     PendingActivation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorStatus {
@@ -2525,12 +3251,41 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
             FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => FactorStatus::Active,
+            "DISABLED" => FactorStatus::Disabled,
+            "ENROLLED" => FactorStatus::Enrolled,
+            "EXPIRED" => FactorStatus::Expired,
+            "INACTIVE" => FactorStatus::Inactive,
+            "NOT_SETUP" => FactorStatus::NotSetup,
+            "PENDING_ACTIVATION" => FactorStatus::PendingActivation,
+            "" => FactorStatus::Noop,
+            _ => FactorStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorStatus {
     fn default() -> FactorStatus {
         FactorStatus::Noop
@@ -2540,9 +3295,14 @@ impl FactorStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorType {
     #[serde(rename = "call")]
     Call,
@@ -2572,8 +3332,8 @@ pub enum FactorType {
     Webauthn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorType {
@@ -2593,12 +3353,47 @@ impl std::fmt::Display for FactorType {
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
             FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "call" => FactorType::Call,
+            "email" => FactorType::Email,
+            "hotp" => FactorType::Hotp,
+            "push" => FactorType::Push,
+            "question" => FactorType::Question,
+            "sms" => FactorType::Sms,
+            "token" => FactorType::Token,
+            "token:hardware" => FactorType::TokenHardware,
+            "token:hotp" => FactorType::TokenHotp,
+            "token:software:totp" => FactorType::TokenSoftwareTotp,
+            "u2f" => FactorType::U2F,
+            "web" => FactorType::Web,
+            "webauthn" => FactorType::Webauthn,
+            "" => FactorType::Noop,
+            _ => FactorType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorType {
     fn default() -> FactorType {
         FactorType::Noop
@@ -2608,6 +3403,11 @@ impl FactorType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2648,7 +3448,7 @@ pub struct FeatureStage {
     pub value: Option<FeatureStageValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FeatureStageState {
     #[serde(rename = "CLOSED")]
     Closed,
@@ -2656,8 +3456,8 @@ pub enum FeatureStageState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageState {
@@ -2666,12 +3466,36 @@ impl std::fmt::Display for FeatureStageState {
             FeatureStageState::Closed => "CLOSED",
             FeatureStageState::Open => "OPEN",
             FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
+            FeatureStageState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FeatureStageState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureStageState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CLOSED" => FeatureStageState::Closed,
+            "OPEN" => FeatureStageState::Open,
+            "" => FeatureStageState::Noop,
+            _ => FeatureStageState::Unknown(s),
+        })
+    }
+}
+
 impl Default for FeatureStageState {
     fn default() -> FeatureStageState {
         FeatureStageState::Noop
@@ -2681,9 +3505,14 @@ impl FeatureStageState {
     pub fn is_noop(&self) -> bool {
         matches!(self, FeatureStageState::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FeatureStageState::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FeatureStageValue {
     #[serde(rename = "BETA")]
     Beta,
@@ -2691,8 +3520,8 @@ pub enum FeatureStageValue {
     Ea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageValue {
@@ -2701,12 +3530,36 @@ impl std::fmt::Display for FeatureStageValue {
             FeatureStageValue::Beta => "BETA",
             FeatureStageValue::Ea => "EA",
             FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
+            FeatureStageValue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FeatureStageValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureStageValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BETA" => FeatureStageValue::Beta,
+            "EA" => FeatureStageValue::Ea,
+            "" => FeatureStageValue::Noop,
+            _ => FeatureStageValue::Unknown(s),
+        })
+    }
+}
+
 impl Default for FeatureStageValue {
     fn default() -> FeatureStageValue {
         FeatureStageValue::Noop
@@ -2716,16 +3569,21 @@ impl FeatureStageValue {
     pub fn is_noop(&self) -> bool {
         matches!(self, FeatureStageValue::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FeatureStageValue::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FeatureType {
     #[serde(rename = "self-service")]
     SelfService,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureType {
@@ -2733,14 +3591,37 @@ impl std::fmt::Display for FeatureType {
         match self {
             FeatureType::SelfService => "self-service",
             FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+            FeatureType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for FeatureType {
-    fn default() -> FeatureType {
+impl Serialize for FeatureType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "self-service" => FeatureType::SelfService,
+            "" => FeatureType::Noop,
+            _ => FeatureType::Unknown(s),
+        })
+    }
+}
+
+impl Default for FeatureType {
+    fn default() -> FeatureType {
         FeatureType::Noop
     }
 }
@@ -2748,6 +3629,11 @@ impl FeatureType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FeatureType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FeatureType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2934,7 +3820,7 @@ pub struct GroupRulePeopleCondition {
     pub users: Option<GroupRuleCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GroupRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2944,8 +3830,8 @@ pub enum GroupRuleStatus {
     Invalid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupRuleStatus {
@@ -2955,12 +3841,37 @@ impl std::fmt::Display for GroupRuleStatus {
             GroupRuleStatus::Inactive => "INACTIVE",
             GroupRuleStatus::Invalid => "INVALID",
             GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+            GroupRuleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GroupRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupRuleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => GroupRuleStatus::Active,
+            "INACTIVE" => GroupRuleStatus::Inactive,
+            "INVALID" => GroupRuleStatus::Invalid,
+            "" => GroupRuleStatus::Noop,
+            _ => GroupRuleStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GroupRuleStatus {
     fn default() -> GroupRuleStatus {
         GroupRuleStatus::Noop
@@ -2970,9 +3881,14 @@ impl GroupRuleStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupRuleStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GroupRuleStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GroupType {
     #[serde(rename = "APP_GROUP")]
     AppGroup,
@@ -2982,8 +3898,8 @@ pub enum GroupType {
     OktaGroup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupType {
@@ -2993,12 +3909,37 @@ impl std::fmt::Display for GroupType {
             GroupType::BuiltIn => "BUILT_IN",
             GroupType::OktaGroup => "OKTA_GROUP",
             GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+            GroupType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GroupType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "APP_GROUP" => GroupType::AppGroup,
+            "BUILT_IN" => GroupType::BuiltIn,
+            "OKTA_GROUP" => GroupType::OktaGroup,
+            "" => GroupType::Noop,
+            _ => GroupType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GroupType {
     fn default() -> GroupType {
         GroupType::Noop
@@ -3008,6 +3949,11 @@ impl GroupType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GroupType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3027,7 +3973,7 @@ pub struct WebUserFactorProfile {
     pub credential_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IdentityProviderIssuerMode {
     #[serde(rename = "CUSTOM_URL_DOMAIN")]
     CustomUrlDomain,
@@ -3035,8 +3981,8 @@ pub enum IdentityProviderIssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderIssuerMode {
@@ -3045,12 +3991,36 @@ impl std::fmt::Display for IdentityProviderIssuerMode {
             IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
             IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
             IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+            IdentityProviderIssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IdentityProviderIssuerMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentityProviderIssuerMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM_URL_DOMAIN" => IdentityProviderIssuerMode::CustomUrlDomain,
+            "ORG_URL" => IdentityProviderIssuerMode::OrgUrl,
+            "" => IdentityProviderIssuerMode::Noop,
+            _ => IdentityProviderIssuerMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for IdentityProviderIssuerMode {
     fn default() -> IdentityProviderIssuerMode {
         IdentityProviderIssuerMode::Noop
@@ -3060,9 +4030,14 @@ impl IdentityProviderIssuerMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, IdentityProviderIssuerMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IdentityProviderIssuerMode::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IdentityProviderType {
     #[serde(rename = "AgentlessDSSO")]
     AgentlessDsso,
@@ -3086,8 +4061,8 @@ pub enum IdentityProviderType {
     X509,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderType {
@@ -3104,12 +4079,44 @@ impl std::fmt::Display for IdentityProviderType {
             IdentityProviderType::Saml2 => "SAML2",
             IdentityProviderType::X509 => "X509",
             IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+            IdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AgentlessDSSO" => IdentityProviderType::AgentlessDsso,
+            "FACEBOOK" => IdentityProviderType::Facebook,
+            "GOOGLE" => IdentityProviderType::Google,
+            "IWA" => IdentityProviderType::Iwa,
+            "LINKEDIN" => IdentityProviderType::Linkedin,
+            "MICROSOFT" => IdentityProviderType::Microsoft,
+            "OIDC" => IdentityProviderType::Oidc,
+            "OKTA" => IdentityProviderType::Okta,
+            "SAML2" => IdentityProviderType::Saml2,
+            "X509" => IdentityProviderType::X509,
+            "" => IdentityProviderType::Noop,
+            _ => IdentityProviderType::Unknown(s),
+        })
+    }
+}
+
 impl Default for IdentityProviderType {
     fn default() -> IdentityProviderType {
         IdentityProviderType::Noop
@@ -3119,6 +4126,11 @@ impl IdentityProviderType {
     pub fn is_noop(&self) -> bool {
         matches!(self, IdentityProviderType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IdentityProviderType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3238,7 +4250,7 @@ pub struct IdentityProviderCredentialsSigning {
     pub kid: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Revocation {
     #[serde(rename = "CRL")]
     Crl,
@@ -3248,8 +4260,8 @@ pub enum Revocation {
     Ocsp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Revocation {
@@ -3259,12 +4271,37 @@ impl std::fmt::Display for Revocation {
             Revocation::DeltaCrl => "DELTA_CRL",
             Revocation::Ocsp => "OCSP",
             Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
+            Revocation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Revocation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Revocation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CRL" => Revocation::Crl,
+            "DELTA_CRL" => Revocation::DeltaCrl,
+            "OCSP" => Revocation::Ocsp,
+            "" => Revocation::Noop,
+            _ => Revocation::Unknown(s),
+        })
+    }
+}
+
 impl Default for Revocation {
     fn default() -> Revocation {
         Revocation::Noop
@@ -3274,6 +4311,11 @@ impl Revocation {
     pub fn is_noop(&self) -> bool {
         matches!(self, Revocation::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Revocation::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3328,7 +4370,7 @@ pub struct IdentityProviderPolicy {
     pub subject: Option<PolicySubject>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Provider {
     #[serde(rename = "ANY")]
     Any,
@@ -3338,8 +4380,8 @@ pub enum Provider {
     SpecificIdp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Provider {
@@ -3349,12 +4391,37 @@ impl std::fmt::Display for Provider {
             Provider::Okta => "OKTA",
             Provider::SpecificIdp => "SPECIFIC_IDP",
             Provider::Noop => "",
-            Provider::FallthroughString => "*",
+            Provider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Provider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Provider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY" => Provider::Any,
+            "OKTA" => Provider::Okta,
+            "SPECIFIC_IDP" => Provider::SpecificIdp,
+            "" => Provider::Noop,
+            _ => Provider::Unknown(s),
+        })
+    }
+}
+
 impl Default for Provider {
     fn default() -> Provider {
         Provider::Noop
@@ -3364,6 +4431,11 @@ impl Provider {
     pub fn is_noop(&self) -> bool {
         matches!(self, Provider::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Provider::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3552,7 +4624,7 @@ pub struct InlineHookResponseCommands {
     pub value: Vec<InlineHookResponseCommandValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum InlineHookType {
     #[serde(rename = "com.okta.import.transform")]
     ComOktaImportTransform,
@@ -3566,8 +4638,8 @@ pub enum InlineHookType {
     ComOktaUserPreRegistration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InlineHookType {
@@ -3581,12 +4653,41 @@ impl std::fmt::Display for InlineHookType {
             }
             InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
             InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
+            InlineHookType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for InlineHookType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineHookType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "com.okta.import.transform" => InlineHookType::ComOktaImportTransform,
+            "com.okta.oauth2.tokens.transform" => InlineHookType::ComOktaOauth2TokensTransform,
+            "com.okta.saml.tokens.transform" => InlineHookType::ComOktaSamlTokensTransform,
+            "com.okta.user.credential.password.import" => {
+                InlineHookType::ComOktaUserCredentialPasswordImport
+            }
+            "com.okta.user.pre-registration" => InlineHookType::ComOktaUserPreRegistration,
+            "" => InlineHookType::Noop,
+            _ => InlineHookType::Unknown(s),
+        })
+    }
+}
+
 impl Default for InlineHookType {
     fn default() -> InlineHookType {
         InlineHookType::Noop
@@ -3596,6 +4697,11 @@ impl InlineHookType {
     pub fn is_noop(&self) -> bool {
         matches!(self, InlineHookType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InlineHookType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3870,14 +4976,14 @@ pub struct LinkedObjectDetails {
     pub type_: Option<LinkedObjectDetailsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LinkedObjectDetailsType {
     #[serde(rename = "USER")]
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkedObjectDetailsType {
@@ -3885,12 +4991,35 @@ impl std::fmt::Display for LinkedObjectDetailsType {
         match self {
             LinkedObjectDetailsType::User => "USER",
             LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
+            LinkedObjectDetailsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LinkedObjectDetailsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkedObjectDetailsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "USER" => LinkedObjectDetailsType::User,
+            "" => LinkedObjectDetailsType::Noop,
+            _ => LinkedObjectDetailsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LinkedObjectDetailsType {
     fn default() -> LinkedObjectDetailsType {
         LinkedObjectDetailsType::Noop
@@ -3900,6 +5029,11 @@ impl LinkedObjectDetailsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LinkedObjectDetailsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LinkedObjectDetailsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3979,7 +5113,7 @@ pub struct LogAuthenticationContext {
     pub issuer: Option<LogIssuer>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LogAuthenticationProvider {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -3995,8 +5129,8 @@ pub enum LogAuthenticationProvider {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogAuthenticationProvider {
@@ -4009,12 +5143,40 @@ impl std::fmt::Display for LogAuthenticationProvider {
             LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
             LogAuthenticationProvider::Social => "SOCIAL",
             LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
+            LogAuthenticationProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LogAuthenticationProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogAuthenticationProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE_DIRECTORY" => LogAuthenticationProvider::ActiveDirectory,
+            "FACTOR_PROVIDER" => LogAuthenticationProvider::FactorProvider,
+            "FEDERATION" => LogAuthenticationProvider::Federation,
+            "LDAP" => LogAuthenticationProvider::Ldap,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogAuthenticationProvider::OktaAuthenticationProvider,
+            "SOCIAL" => LogAuthenticationProvider::Social,
+            "" => LogAuthenticationProvider::Noop,
+            _ => LogAuthenticationProvider::Unknown(s),
+        })
+    }
+}
+
 impl Default for LogAuthenticationProvider {
     fn default() -> LogAuthenticationProvider {
         LogAuthenticationProvider::Noop
@@ -4024,6 +5186,11 @@ impl LogAuthenticationProvider {
     pub fn is_noop(&self) -> bool {
         matches!(self, LogAuthenticationProvider::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LogAuthenticationProvider::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4063,7 +5230,7 @@ pub struct LogClient {
     pub zone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LogCredentialProvider {
     #[serde(rename = "DUO")]
     Duo,
@@ -4081,8 +5248,8 @@ pub enum LogCredentialProvider {
     Yubikey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialProvider {
@@ -4096,12 +5263,41 @@ impl std::fmt::Display for LogCredentialProvider {
             LogCredentialProvider::Symantec => "SYMANTEC",
             LogCredentialProvider::Yubikey => "YUBIKEY",
             LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
+            LogCredentialProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LogCredentialProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogCredentialProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DUO" => LogCredentialProvider::Duo,
+            "GOOGLE" => LogCredentialProvider::Google,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogCredentialProvider::OktaAuthenticationProvider,
+            "OKTA_CREDENTIAL_PROVIDER" => LogCredentialProvider::OktaCredentialProvider,
+            "RSA" => LogCredentialProvider::Rsa,
+            "SYMANTEC" => LogCredentialProvider::Symantec,
+            "YUBIKEY" => LogCredentialProvider::Yubikey,
+            "" => LogCredentialProvider::Noop,
+            _ => LogCredentialProvider::Unknown(s),
+        })
+    }
+}
+
 impl Default for LogCredentialProvider {
     fn default() -> LogCredentialProvider {
         LogCredentialProvider::Noop
@@ -4111,9 +5307,14 @@ impl LogCredentialProvider {
     pub fn is_noop(&self) -> bool {
         matches!(self, LogCredentialProvider::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LogCredentialProvider::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LogCredentialType {
     #[serde(rename = "ASSERTION")]
     Assertion,
@@ -4133,8 +5334,8 @@ pub enum LogCredentialType {
     Sms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialType {
@@ -4149,12 +5350,42 @@ impl std::fmt::Display for LogCredentialType {
             LogCredentialType::Password => "PASSWORD",
             LogCredentialType::Sms => "SMS",
             LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
+            LogCredentialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LogCredentialType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogCredentialType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ASSERTION" => LogCredentialType::Assertion,
+            "EMAIL" => LogCredentialType::Email,
+            "IWA" => LogCredentialType::Iwa,
+            "JWT" => LogCredentialType::Jwt,
+            "OAUTH2" => LogCredentialType::Oauth2,
+            "OTP" => LogCredentialType::Otp,
+            "PASSWORD" => LogCredentialType::Password,
+            "SMS" => LogCredentialType::Sms,
+            "" => LogCredentialType::Noop,
+            _ => LogCredentialType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LogCredentialType {
     fn default() -> LogCredentialType {
         LogCredentialType::Noop
@@ -4164,6 +5395,11 @@ impl LogCredentialType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LogCredentialType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LogCredentialType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4406,7 +5642,7 @@ pub struct LogSecurityContext {
     pub isp: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LogSeverity {
     #[serde(rename = "DEBUG")]
     Debug,
@@ -4418,8 +5654,8 @@ pub enum LogSeverity {
     Warn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogSeverity {
@@ -4430,12 +5666,38 @@ impl std::fmt::Display for LogSeverity {
             LogSeverity::Info => "INFO",
             LogSeverity::Warn => "WARN",
             LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
+            LogSeverity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LogSeverity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogSeverity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DEBUG" => LogSeverity::Debug,
+            "ERROR" => LogSeverity::Error,
+            "INFO" => LogSeverity::Info,
+            "WARN" => LogSeverity::Warn,
+            "" => LogSeverity::Noop,
+            _ => LogSeverity::Unknown(s),
+        })
+    }
+}
+
 impl Default for LogSeverity {
     fn default() -> LogSeverity {
         LogSeverity::Noop
@@ -4445,6 +5707,11 @@ impl LogSeverity {
     pub fn is_noop(&self) -> bool {
         matches!(self, LogSeverity::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LogSeverity::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4526,7 +5793,7 @@ pub struct LogUserAgent {
     pub raw_user_agent: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Enrollment {
     #[serde(rename = "ANY_OR_NONE")]
     AnyOrNone,
@@ -4534,8 +5801,8 @@ pub enum Enrollment {
     Omm,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Enrollment {
@@ -4544,12 +5811,36 @@ impl std::fmt::Display for Enrollment {
             Enrollment::AnyOrNone => "ANY_OR_NONE",
             Enrollment::Omm => "OMM",
             Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
+            Enrollment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Enrollment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Enrollment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY_OR_NONE" => Enrollment::AnyOrNone,
+            "OMM" => Enrollment::Omm,
+            "" => Enrollment::Noop,
+            _ => Enrollment::Unknown(s),
+        })
+    }
+}
+
 impl Default for Enrollment {
     fn default() -> Enrollment {
         Enrollment::Noop
@@ -4559,6 +5850,11 @@ impl Enrollment {
     pub fn is_noop(&self) -> bool {
         matches!(self, Enrollment::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Enrollment::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4658,7 +5954,7 @@ pub struct NetworkZoneAddress {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NetworkZoneAddressType {
     #[serde(rename = "CIDR")]
     Cidr,
@@ -4666,8 +5962,8 @@ pub enum NetworkZoneAddressType {
     Range,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneAddressType {
@@ -4676,12 +5972,36 @@ impl std::fmt::Display for NetworkZoneAddressType {
             NetworkZoneAddressType::Cidr => "CIDR",
             NetworkZoneAddressType::Range => "RANGE",
             NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
+            NetworkZoneAddressType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NetworkZoneAddressType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneAddressType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CIDR" => NetworkZoneAddressType::Cidr,
+            "RANGE" => NetworkZoneAddressType::Range,
+            "" => NetworkZoneAddressType::Noop,
+            _ => NetworkZoneAddressType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NetworkZoneAddressType {
     fn default() -> NetworkZoneAddressType {
         NetworkZoneAddressType::Noop
@@ -4691,6 +6011,11 @@ impl NetworkZoneAddressType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneAddressType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NetworkZoneAddressType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4709,7 +6034,7 @@ pub struct NetworkZoneLocation {
     pub region: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NetworkZoneType {
     #[serde(rename = "DYNAMIC")]
     Dynamic,
@@ -4717,8 +6042,8 @@ pub enum NetworkZoneType {
     Ip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneType {
@@ -4727,12 +6052,36 @@ impl std::fmt::Display for NetworkZoneType {
             NetworkZoneType::Dynamic => "DYNAMIC",
             NetworkZoneType::Ip => "IP",
             NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
+            NetworkZoneType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NetworkZoneType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DYNAMIC" => NetworkZoneType::Dynamic,
+            "IP" => NetworkZoneType::Ip,
+            "" => NetworkZoneType::Noop,
+            _ => NetworkZoneType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NetworkZoneType {
     fn default() -> NetworkZoneType {
         NetworkZoneType::Noop
@@ -4742,9 +6091,14 @@ impl NetworkZoneType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NetworkZoneType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NetworkZoneUsage {
     #[serde(rename = "BLOCKLIST")]
     Blocklist,
@@ -4752,8 +6106,8 @@ pub enum NetworkZoneUsage {
     Policy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneUsage {
@@ -4762,12 +6116,36 @@ impl std::fmt::Display for NetworkZoneUsage {
             NetworkZoneUsage::Blocklist => "BLOCKLIST",
             NetworkZoneUsage::Policy => "POLICY",
             NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
+            NetworkZoneUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NetworkZoneUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BLOCKLIST" => NetworkZoneUsage::Blocklist,
+            "POLICY" => NetworkZoneUsage::Policy,
+            "" => NetworkZoneUsage::Noop,
+            _ => NetworkZoneUsage::Unknown(s),
+        })
+    }
+}
+
 impl Default for NetworkZoneUsage {
     fn default() -> NetworkZoneUsage {
         NetworkZoneUsage::Noop
@@ -4777,6 +6155,11 @@ impl NetworkZoneUsage {
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneUsage::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NetworkZoneUsage::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4796,7 +6179,7 @@ pub struct OAuth2Actor {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ClaimType {
     #[serde(rename = "IDENTITY")]
     Identity,
@@ -4804,8 +6187,8 @@ pub enum ClaimType {
     Resource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClaimType {
@@ -4814,12 +6197,36 @@ impl std::fmt::Display for ClaimType {
             ClaimType::Identity => "IDENTITY",
             ClaimType::Resource => "RESOURCE",
             ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
+            ClaimType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ClaimType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClaimType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IDENTITY" => ClaimType::Identity,
+            "RESOURCE" => ClaimType::Resource,
+            "" => ClaimType::Noop,
+            _ => ClaimType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ClaimType {
     fn default() -> ClaimType {
         ClaimType::Noop
@@ -4829,9 +6236,14 @@ impl ClaimType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ClaimType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ClaimType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GroupFilterType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -4843,8 +6255,8 @@ pub enum GroupFilterType {
     StartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupFilterType {
@@ -4855,12 +6267,38 @@ impl std::fmt::Display for GroupFilterType {
             GroupFilterType::Regex => "REGEX",
             GroupFilterType::StartsWith => "STARTS_WITH",
             GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
+            GroupFilterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GroupFilterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupFilterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CONTAINS" => GroupFilterType::Contains,
+            "EQUALS" => GroupFilterType::Equals,
+            "REGEX" => GroupFilterType::Regex,
+            "STARTS_WITH" => GroupFilterType::StartsWith,
+            "" => GroupFilterType::Noop,
+            _ => GroupFilterType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GroupFilterType {
     fn default() -> GroupFilterType {
         GroupFilterType::Noop
@@ -4870,9 +6308,14 @@ impl GroupFilterType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupFilterType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GroupFilterType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ValueType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -4882,8 +6325,8 @@ pub enum ValueType {
     System,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueType {
@@ -4893,12 +6336,37 @@ impl std::fmt::Display for ValueType {
             ValueType::Groups => "GROUPS",
             ValueType::System => "SYSTEM",
             ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
+            ValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ValueType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EXPRESSION" => ValueType::Expression,
+            "GROUPS" => ValueType::Groups,
+            "SYSTEM" => ValueType::System,
+            "" => ValueType::Noop,
+            _ => ValueType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ValueType {
     fn default() -> ValueType {
         ValueType::Noop
@@ -4908,6 +6376,11 @@ impl ValueType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ValueType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4995,7 +6468,7 @@ pub struct OAuth2Client {
     pub logo_uri: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OAuth2RefreshTokenStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -5003,8 +6476,8 @@ pub enum OAuth2RefreshTokenStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2RefreshTokenStatus {
@@ -5013,12 +6486,36 @@ impl std::fmt::Display for OAuth2RefreshTokenStatus {
             OAuth2RefreshTokenStatus::Active => "ACTIVE",
             OAuth2RefreshTokenStatus::Revoked => "REVOKED",
             OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
+            OAuth2RefreshTokenStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OAuth2RefreshTokenStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuth2RefreshTokenStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => OAuth2RefreshTokenStatus::Active,
+            "REVOKED" => OAuth2RefreshTokenStatus::Revoked,
+            "" => OAuth2RefreshTokenStatus::Noop,
+            _ => OAuth2RefreshTokenStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for OAuth2RefreshTokenStatus {
     fn default() -> OAuth2RefreshTokenStatus {
         OAuth2RefreshTokenStatus::Noop
@@ -5028,6 +6525,11 @@ impl OAuth2RefreshTokenStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuth2RefreshTokenStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OAuth2RefreshTokenStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5094,7 +6596,7 @@ pub struct OAuth2RefreshToken {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Consent {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5104,8 +6606,8 @@ pub enum Consent {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Consent {
@@ -5115,12 +6617,37 @@ impl std::fmt::Display for Consent {
             Consent::Implicit => "IMPLICIT",
             Consent::Required => "REQUIRED",
             Consent::Noop => "",
-            Consent::FallthroughString => "*",
+            Consent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Consent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Consent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ADMIN" => Consent::Admin,
+            "IMPLICIT" => Consent::Implicit,
+            "REQUIRED" => Consent::Required,
+            "" => Consent::Noop,
+            _ => Consent::Unknown(s),
+        })
+    }
+}
+
 impl Default for Consent {
     fn default() -> Consent {
         Consent::Noop
@@ -5130,9 +6657,14 @@ impl Consent {
     pub fn is_noop(&self) -> bool {
         matches!(self, Consent::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Consent::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MetadataPublish {
     #[serde(rename = "ALL_CLIENTS")]
     AllClients,
@@ -5140,8 +6672,8 @@ pub enum MetadataPublish {
     NoClients,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MetadataPublish {
@@ -5150,12 +6682,36 @@ impl std::fmt::Display for MetadataPublish {
             MetadataPublish::AllClients => "ALL_CLIENTS",
             MetadataPublish::NoClients => "NO_CLIENTS",
             MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
+            MetadataPublish::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MetadataPublish {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MetadataPublish {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ALL_CLIENTS" => MetadataPublish::AllClients,
+            "NO_CLIENTS" => MetadataPublish::NoClients,
+            "" => MetadataPublish::Noop,
+            _ => MetadataPublish::Unknown(s),
+        })
+    }
+}
+
 impl Default for MetadataPublish {
     fn default() -> MetadataPublish {
         MetadataPublish::Noop
@@ -5165,6 +6721,11 @@ impl MetadataPublish {
     pub fn is_noop(&self) -> bool {
         matches!(self, MetadataPublish::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MetadataPublish::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5274,7 +6835,7 @@ pub struct OAuth2ScopeConsentGrant {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OAuth2ScopeConsentGrantSource {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5282,8 +6843,8 @@ pub enum OAuth2ScopeConsentGrantSource {
     EndUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
@@ -5292,12 +6853,36 @@ impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
             OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
             OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
             OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OAuth2ScopeConsentGrantSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuth2ScopeConsentGrantSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ADMIN" => OAuth2ScopeConsentGrantSource::Admin,
+            "END_USER" => OAuth2ScopeConsentGrantSource::EndUser,
+            "" => OAuth2ScopeConsentGrantSource::Noop,
+            _ => OAuth2ScopeConsentGrantSource::Unknown(s),
+        })
+    }
+}
+
 impl Default for OAuth2ScopeConsentGrantSource {
     fn default() -> OAuth2ScopeConsentGrantSource {
         OAuth2ScopeConsentGrantSource::Noop
@@ -5307,6 +6892,11 @@ impl OAuth2ScopeConsentGrantSource {
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuth2ScopeConsentGrantSource::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OAuth2ScopeConsentGrantSource::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5391,7 +6981,7 @@ pub struct OAuthApplicationCredentials {
     pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OAuthEndpointAuthenticationMethod {
     #[serde(rename = "client_secret_basic")]
     ClientSecretBasic,
@@ -5405,8 +6995,8 @@ pub enum OAuthEndpointAuthenticationMethod {
     PrivateKeyJwt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
@@ -5418,12 +7008,39 @@ impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
             OAuthEndpointAuthenticationMethod::None => "none",
             OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
             OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OAuthEndpointAuthenticationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthEndpointAuthenticationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "client_secret_basic" => OAuthEndpointAuthenticationMethod::ClientSecretBasic,
+            "client_secret_jwt" => OAuthEndpointAuthenticationMethod::ClientSecretJwt,
+            "client_secret_post" => OAuthEndpointAuthenticationMethod::ClientSecretPost,
+            "none" => OAuthEndpointAuthenticationMethod::None,
+            "private_key_jwt" => OAuthEndpointAuthenticationMethod::PrivateKeyJwt,
+            "" => OAuthEndpointAuthenticationMethod::Noop,
+            _ => OAuthEndpointAuthenticationMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for OAuthEndpointAuthenticationMethod {
     fn default() -> OAuthEndpointAuthenticationMethod {
         OAuthEndpointAuthenticationMethod::Noop
@@ -5433,9 +7050,14 @@ impl OAuthEndpointAuthenticationMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthEndpointAuthenticationMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OAuthEndpointAuthenticationMethod::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OAuthGrantType {
     #[serde(rename = "authorization_code")]
     AuthorizationCode,
@@ -5449,8 +7071,8 @@ pub enum OAuthGrantType {
     RefreshToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthGrantType {
@@ -5462,12 +7084,39 @@ impl std::fmt::Display for OAuthGrantType {
             OAuthGrantType::Password => "password",
             OAuthGrantType::RefreshToken => "refresh_token",
             OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+            OAuthGrantType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OAuthGrantType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthGrantType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "authorization_code" => OAuthGrantType::AuthorizationCode,
+            "client_credentials" => OAuthGrantType::ClientCredentials,
+            "implicit" => OAuthGrantType::Implicit,
+            "password" => OAuthGrantType::Password,
+            "refresh_token" => OAuthGrantType::RefreshToken,
+            "" => OAuthGrantType::Noop,
+            _ => OAuthGrantType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OAuthGrantType {
     fn default() -> OAuthGrantType {
         OAuthGrantType::Noop
@@ -5477,9 +7126,14 @@ impl OAuthGrantType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthGrantType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OAuthGrantType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OAuthResponseType {
     #[serde(rename = "code")]
     Code,
@@ -5489,8 +7143,8 @@ pub enum OAuthResponseType {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthResponseType {
@@ -5500,12 +7154,37 @@ impl std::fmt::Display for OAuthResponseType {
             OAuthResponseType::IdToken => "id_token",
             OAuthResponseType::Token => "token",
             OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+            OAuthResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OAuthResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "code" => OAuthResponseType::Code,
+            "id_token" => OAuthResponseType::IdToken,
+            "token" => OAuthResponseType::Token,
+            "" => OAuthResponseType::Noop,
+            _ => OAuthResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OAuthResponseType {
     fn default() -> OAuthResponseType {
         OAuthResponseType::Noop
@@ -5515,6 +7194,11 @@ impl OAuthResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OAuthResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5563,7 +7247,7 @@ pub struct OktaSignOnPolicyRuleConditions {
     pub people: Option<PolicyPeopleCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Access {
     #[serde(rename = "ALLOW")]
     Allow,
@@ -5571,8 +7255,8 @@ pub enum Access {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Access {
@@ -5581,12 +7265,36 @@ impl std::fmt::Display for Access {
             Access::Allow => "ALLOW",
             Access::Deny => "DENY",
             Access::Noop => "",
-            Access::FallthroughString => "*",
+            Access::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Access {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Access {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ALLOW" => Access::Allow,
+            "DENY" => Access::Deny,
+            "" => Access::Noop,
+            _ => Access::Unknown(s),
+        })
+    }
+}
+
 impl Default for Access {
     fn default() -> Access {
         Access::Noop
@@ -5596,9 +7304,14 @@ impl Access {
     pub fn is_noop(&self) -> bool {
         matches!(self, Access::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Access::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorPromptMode {
     #[serde(rename = "ALWAYS")]
     Always,
@@ -5608,8 +7321,8 @@ pub enum FactorPromptMode {
     Session,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorPromptMode {
@@ -5619,12 +7332,37 @@ impl std::fmt::Display for FactorPromptMode {
             FactorPromptMode::Device => "DEVICE",
             FactorPromptMode::Session => "SESSION",
             FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+            FactorPromptMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorPromptMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorPromptMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ALWAYS" => FactorPromptMode::Always,
+            "DEVICE" => FactorPromptMode::Device,
+            "SESSION" => FactorPromptMode::Session,
+            "" => FactorPromptMode::Noop,
+            _ => FactorPromptMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorPromptMode {
     fn default() -> FactorPromptMode {
         FactorPromptMode::Noop
@@ -5634,6 +7372,11 @@ impl FactorPromptMode {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorPromptMode::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorPromptMode::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5703,7 +7446,7 @@ pub struct OpenConnectApplication {
     pub settings: Option<OpenConnectApplicationSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OpenConnectApplicationConsentMethod {
     #[serde(rename = "REQUIRED")]
     Required,
@@ -5711,8 +7454,8 @@ pub enum OpenConnectApplicationConsentMethod {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationConsentMethod {
@@ -5721,12 +7464,36 @@ impl std::fmt::Display for OpenConnectApplicationConsentMethod {
             OpenConnectApplicationConsentMethod::Required => "REQUIRED",
             OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
             OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+            OpenConnectApplicationConsentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OpenConnectApplicationConsentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectApplicationConsentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "REQUIRED" => OpenConnectApplicationConsentMethod::Required,
+            "TRUSTED" => OpenConnectApplicationConsentMethod::Trusted,
+            "" => OpenConnectApplicationConsentMethod::Noop,
+            _ => OpenConnectApplicationConsentMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for OpenConnectApplicationConsentMethod {
     fn default() -> OpenConnectApplicationConsentMethod {
         OpenConnectApplicationConsentMethod::Noop
@@ -5736,6 +7503,11 @@ impl OpenConnectApplicationConsentMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectApplicationConsentMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OpenConnectApplicationConsentMethod::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5862,7 +7634,7 @@ pub struct OpenConnectApplicationSettingsRefreshToken {
     pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OpenConnectApplicationType {
     #[serde(rename = "browser")]
     Browser,
@@ -5874,8 +7646,8 @@ pub enum OpenConnectApplicationType {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationType {
@@ -5886,12 +7658,38 @@ impl std::fmt::Display for OpenConnectApplicationType {
             OpenConnectApplicationType::Service => "service",
             OpenConnectApplicationType::Web => "web",
             OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+            OpenConnectApplicationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OpenConnectApplicationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectApplicationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "browser" => OpenConnectApplicationType::Browser,
+            "native" => OpenConnectApplicationType::Native,
+            "service" => OpenConnectApplicationType::Service,
+            "web" => OpenConnectApplicationType::Web,
+            "" => OpenConnectApplicationType::Noop,
+            _ => OpenConnectApplicationType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OpenConnectApplicationType {
     fn default() -> OpenConnectApplicationType {
         OpenConnectApplicationType::Noop
@@ -5901,9 +7699,14 @@ impl OpenConnectApplicationType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectApplicationType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OpenConnectApplicationType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OpenConnectRefreshTokenRotationType {
     #[serde(rename = "rotate")]
     Rotate,
@@ -5911,8 +7714,8 @@ pub enum OpenConnectRefreshTokenRotationType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
@@ -5921,12 +7724,36 @@ impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
             OpenConnectRefreshTokenRotationType::Rotate => "rotate",
             OpenConnectRefreshTokenRotationType::Static => "static",
             OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OpenConnectRefreshTokenRotationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectRefreshTokenRotationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "rotate" => OpenConnectRefreshTokenRotationType::Rotate,
+            "static" => OpenConnectRefreshTokenRotationType::Static,
+            "" => OpenConnectRefreshTokenRotationType::Noop,
+            _ => OpenConnectRefreshTokenRotationType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OpenConnectRefreshTokenRotationType {
     fn default() -> OpenConnectRefreshTokenRotationType {
         OpenConnectRefreshTokenRotationType::Noop
@@ -5936,6 +7763,11 @@ impl OpenConnectRefreshTokenRotationType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectRefreshTokenRotationType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OpenConnectRefreshTokenRotationType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5984,7 +7816,7 @@ pub struct PasswordCredentialHash {
     pub work_factor: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PasswordCredentialHashAlgorithm {
     #[serde(rename = "BCRYPT")]
     Bcrypt,
@@ -5998,8 +7830,8 @@ pub enum PasswordCredentialHashAlgorithm {
     Sha512,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordCredentialHashAlgorithm {
@@ -6011,12 +7843,39 @@ impl std::fmt::Display for PasswordCredentialHashAlgorithm {
             PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
             PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
             PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+            PasswordCredentialHashAlgorithm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PasswordCredentialHashAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PasswordCredentialHashAlgorithm {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BCRYPT" => PasswordCredentialHashAlgorithm::Bcrypt,
+            "MD5" => PasswordCredentialHashAlgorithm::Md5,
+            "SHA-1" => PasswordCredentialHashAlgorithm::ShAminusOne,
+            "SHA-256" => PasswordCredentialHashAlgorithm::Sha256,
+            "SHA-512" => PasswordCredentialHashAlgorithm::Sha512,
+            "" => PasswordCredentialHashAlgorithm::Noop,
+            _ => PasswordCredentialHashAlgorithm::Unknown(s),
+        })
+    }
+}
+
 impl Default for PasswordCredentialHashAlgorithm {
     fn default() -> PasswordCredentialHashAlgorithm {
         PasswordCredentialHashAlgorithm::Noop
@@ -6026,6 +7885,11 @@ impl PasswordCredentialHashAlgorithm {
     pub fn is_noop(&self) -> bool {
         matches!(self, PasswordCredentialHashAlgorithm::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PasswordCredentialHashAlgorithm::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6062,7 +7926,7 @@ pub struct PasswordPolicy {
     pub settings: Option<PasswordPolicySettingsData>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PasswordPolicyAuthenticationProviderCondition {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -6074,8 +7938,8 @@ pub enum PasswordPolicyAuthenticationProviderCondition {
     Okta,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
@@ -6086,12 +7950,38 @@ impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
             PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
             PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
             PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PasswordPolicyAuthenticationProviderCondition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PasswordPolicyAuthenticationProviderCondition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE_DIRECTORY" => PasswordPolicyAuthenticationProviderCondition::ActiveDirectory,
+            "ANY" => PasswordPolicyAuthenticationProviderCondition::Any,
+            "LDAP" => PasswordPolicyAuthenticationProviderCondition::Ldap,
+            "OKTA" => PasswordPolicyAuthenticationProviderCondition::Okta,
+            "" => PasswordPolicyAuthenticationProviderCondition::Noop,
+            _ => PasswordPolicyAuthenticationProviderCondition::Unknown(s),
+        })
+    }
+}
+
 impl Default for PasswordPolicyAuthenticationProviderCondition {
     fn default() -> PasswordPolicyAuthenticationProviderCondition {
         PasswordPolicyAuthenticationProviderCondition::Noop
@@ -6101,6 +7991,14 @@ impl PasswordPolicyAuthenticationProviderCondition {
     pub fn is_noop(&self) -> bool {
         matches!(self, PasswordPolicyAuthenticationProviderCondition::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            PasswordPolicyAuthenticationProviderCondition::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6408,7 +8306,7 @@ pub struct PasswordPolicySettingsData {
     pub recovery: Option<PasswordPolicyRecoverySettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PlatformConditionEvaluatorType {
     #[serde(rename = "ANY")]
     Any,
@@ -6420,8 +8318,8 @@ pub enum PlatformConditionEvaluatorType {
     Other,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorType {
@@ -6432,12 +8330,38 @@ impl std::fmt::Display for PlatformConditionEvaluatorType {
             PlatformConditionEvaluatorType::Mobile => "MOBILE",
             PlatformConditionEvaluatorType::Other => "OTHER",
             PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+            PlatformConditionEvaluatorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PlatformConditionEvaluatorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformConditionEvaluatorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY" => PlatformConditionEvaluatorType::Any,
+            "DESKTOP" => PlatformConditionEvaluatorType::Desktop,
+            "MOBILE" => PlatformConditionEvaluatorType::Mobile,
+            "OTHER" => PlatformConditionEvaluatorType::Other,
+            "" => PlatformConditionEvaluatorType::Noop,
+            _ => PlatformConditionEvaluatorType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PlatformConditionEvaluatorType {
     fn default() -> PlatformConditionEvaluatorType {
         PlatformConditionEvaluatorType::Noop
@@ -6447,6 +8371,11 @@ impl PlatformConditionEvaluatorType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PlatformConditionEvaluatorType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PlatformConditionEvaluatorType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6457,7 +8386,7 @@ pub struct PlatformConditionEvaluator {
     pub type_: Option<PlatformConditionEvaluatorType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PlatformConditionEvaluatorOperatingSystemType {
     #[serde(rename = "ANDROID")]
     Android,
@@ -6473,8 +8402,8 @@ pub enum PlatformConditionEvaluatorOperatingSystemType {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
@@ -6487,12 +8416,40 @@ impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
             PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
             PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
             PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PlatformConditionEvaluatorOperatingSystemType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformConditionEvaluatorOperatingSystemType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANDROID" => PlatformConditionEvaluatorOperatingSystemType::Android,
+            "ANY" => PlatformConditionEvaluatorOperatingSystemType::Any,
+            "IOS" => PlatformConditionEvaluatorOperatingSystemType::Ios,
+            "OSX" => PlatformConditionEvaluatorOperatingSystemType::Osx,
+            "OTHER" => PlatformConditionEvaluatorOperatingSystemType::Other,
+            "WINDOWS" => PlatformConditionEvaluatorOperatingSystemType::Windows,
+            "" => PlatformConditionEvaluatorOperatingSystemType::Noop,
+            _ => PlatformConditionEvaluatorOperatingSystemType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PlatformConditionEvaluatorOperatingSystemType {
     fn default() -> PlatformConditionEvaluatorOperatingSystemType {
         PlatformConditionEvaluatorOperatingSystemType::Noop
@@ -6502,6 +8459,14 @@ impl PlatformConditionEvaluatorOperatingSystemType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PlatformConditionEvaluatorOperatingSystemType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6518,7 +8483,7 @@ pub struct PlatformConditionEvaluatorOperatingSystem {
     pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MatchType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -6526,8 +8491,8 @@ pub enum MatchType {
     Semver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -6536,12 +8501,36 @@ impl std::fmt::Display for MatchType {
             MatchType::Expression => "EXPRESSION",
             MatchType::Semver => "SEMVER",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EXPRESSION" => MatchType::Expression,
+            "SEMVER" => MatchType::Semver,
+            "" => MatchType::Noop,
+            _ => MatchType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -6551,6 +8540,11 @@ impl MatchType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MatchType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MatchType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6637,7 +8631,7 @@ pub struct Policy {
     pub type_: Option<PolicyType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Action {
     #[serde(rename = "AUTO")]
     Auto,
@@ -6645,8 +8639,8 @@ pub enum Action {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -6655,12 +8649,36 @@ impl std::fmt::Display for Action {
             Action::Auto => "AUTO",
             Action::Disabled => "DISABLED",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTO" => Action::Auto,
+            "DISABLED" => Action::Disabled,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        })
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -6670,6 +8688,11 @@ impl Action {
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Action::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6686,7 +8709,7 @@ pub struct PolicyAccountLinkFilter {
     pub groups: Option<ClientPolicyCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Connection {
     #[serde(rename = "ANYWHERE")]
     Anywhere,
@@ -6694,8 +8717,8 @@ pub enum Connection {
     Zone,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Connection {
@@ -6704,12 +8727,36 @@ impl std::fmt::Display for Connection {
             Connection::Anywhere => "ANYWHERE",
             Connection::Zone => "ZONE",
             Connection::Noop => "",
-            Connection::FallthroughString => "*",
+            Connection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Connection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Connection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANYWHERE" => Connection::Anywhere,
+            "ZONE" => Connection::Zone,
+            "" => Connection::Noop,
+            _ => Connection::Unknown(s),
+        })
+    }
+}
+
 impl Default for Connection {
     fn default() -> Connection {
         Connection::Noop
@@ -6719,6 +8766,11 @@ impl Connection {
     pub fn is_noop(&self) -> bool {
         matches!(self, Connection::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Connection::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6747,7 +8799,7 @@ pub struct PolicyPeopleCondition {
     pub users: Option<UserCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PolicyRuleType {
     #[serde(rename = "PASSWORD")]
     Password,
@@ -6755,8 +8807,8 @@ pub enum PolicyRuleType {
     SignOn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleType {
@@ -6765,12 +8817,36 @@ impl std::fmt::Display for PolicyRuleType {
             PolicyRuleType::Password => "PASSWORD",
             PolicyRuleType::SignOn => "SIGN_ON",
             PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+            PolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PolicyRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "PASSWORD" => PolicyRuleType::Password,
+            "SIGN_ON" => PolicyRuleType::SignOn,
+            "" => PolicyRuleType::Noop,
+            _ => PolicyRuleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PolicyRuleType {
     fn default() -> PolicyRuleType {
         PolicyRuleType::Noop
@@ -6780,6 +8856,11 @@ impl PolicyRuleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyRuleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PolicyRuleType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6862,7 +8943,7 @@ pub struct PolicyRuleActionsEnroll {
     pub self_: Option<PolicyRuleActionsEnrollSelf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PolicyRuleActionsEnrollSelf {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -6872,8 +8953,8 @@ pub enum PolicyRuleActionsEnrollSelf {
     Never,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
@@ -6883,12 +8964,37 @@ impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
             PolicyRuleActionsEnrollSelf::Login => "LOGIN",
             PolicyRuleActionsEnrollSelf::Never => "NEVER",
             PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PolicyRuleActionsEnrollSelf {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyRuleActionsEnrollSelf {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CHALLENGE" => PolicyRuleActionsEnrollSelf::Challenge,
+            "LOGIN" => PolicyRuleActionsEnrollSelf::Login,
+            "NEVER" => PolicyRuleActionsEnrollSelf::Never,
+            "" => PolicyRuleActionsEnrollSelf::Noop,
+            _ => PolicyRuleActionsEnrollSelf::Unknown(s),
+        })
+    }
+}
+
 impl Default for PolicyRuleActionsEnrollSelf {
     fn default() -> PolicyRuleActionsEnrollSelf {
         PolicyRuleActionsEnrollSelf::Noop
@@ -6898,9 +9004,14 @@ impl PolicyRuleActionsEnrollSelf {
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyRuleActionsEnrollSelf::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PolicyRuleActionsEnrollSelf::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AuthType {
     #[serde(rename = "ANY")]
     Any,
@@ -6908,8 +9019,8 @@ pub enum AuthType {
     Radius,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthType {
@@ -6918,12 +9029,36 @@ impl std::fmt::Display for AuthType {
             AuthType::Any => "ANY",
             AuthType::Radius => "RADIUS",
             AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
+            AuthType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AuthType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY" => AuthType::Any,
+            "RADIUS" => AuthType::Radius,
+            "" => AuthType::Noop,
+            _ => AuthType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AuthType {
     fn default() -> AuthType {
         AuthType::Noop
@@ -6933,6 +9068,11 @@ impl AuthType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AuthType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7050,7 +9190,7 @@ pub struct PolicySubject {
     pub user_name_template: Option<PolicyUserNameTemplate>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PolicySubjectMatchType {
     #[serde(rename = "CUSTOM_ATTRIBUTE")]
     CustomAttribute,
@@ -7062,8 +9202,8 @@ pub enum PolicySubjectMatchType {
     UsernameOrEmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicySubjectMatchType {
@@ -7074,12 +9214,38 @@ impl std::fmt::Display for PolicySubjectMatchType {
             PolicySubjectMatchType::Username => "USERNAME",
             PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
             PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
+            PolicySubjectMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PolicySubjectMatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicySubjectMatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CUSTOM_ATTRIBUTE" => PolicySubjectMatchType::CustomAttribute,
+            "EMAIL" => PolicySubjectMatchType::Email,
+            "USERNAME" => PolicySubjectMatchType::Username,
+            "USERNAME_OR_EMAIL" => PolicySubjectMatchType::UsernameOrEmail,
+            "" => PolicySubjectMatchType::Noop,
+            _ => PolicySubjectMatchType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PolicySubjectMatchType {
     fn default() -> PolicySubjectMatchType {
         PolicySubjectMatchType::Noop
@@ -7089,9 +9255,14 @@ impl PolicySubjectMatchType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicySubjectMatchType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PolicySubjectMatchType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PolicyType {
     #[serde(rename = "IDP_DISCOVERY")]
     IdpDiscovery,
@@ -7103,8 +9274,8 @@ pub enum PolicyType {
     Password,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyType {
@@ -7115,12 +9286,38 @@ impl std::fmt::Display for PolicyType {
             PolicyType::OktaSignOn => "OKTA_SIGN_ON",
             PolicyType::Password => "PASSWORD",
             PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
+            PolicyType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "IDP_DISCOVERY" => PolicyType::IdpDiscovery,
+            "OAUTH_AUTHORIZATION_POLICY" => PolicyType::OauthAuthorizationPolicy,
+            "OKTA_SIGN_ON" => PolicyType::OktaSignOn,
+            "PASSWORD" => PolicyType::Password,
+            "" => PolicyType::Noop,
+            _ => PolicyType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PolicyType {
     fn default() -> PolicyType {
         PolicyType::Noop
@@ -7130,6 +9327,11 @@ impl PolicyType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PolicyType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7201,7 +9403,7 @@ pub struct ProfileMappingSource {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProtocolType {
     #[serde(rename = "MTLS")]
     Mtls,
@@ -7213,8 +9415,8 @@ pub enum ProtocolType {
     Saml2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolType {
@@ -7225,12 +9427,38 @@ impl std::fmt::Display for ProtocolType {
             ProtocolType::Oidc => "OIDC",
             ProtocolType::Saml2 => "SAML2",
             ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
+            ProtocolType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProtocolType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "MTLS" => ProtocolType::Mtls,
+            "OAUTH2" => ProtocolType::Oauth2,
+            "OIDC" => ProtocolType::Oidc,
+            "SAML2" => ProtocolType::Saml2,
+            "" => ProtocolType::Noop,
+            _ => ProtocolType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProtocolType {
     fn default() -> ProtocolType {
         ProtocolType::Noop
@@ -7240,6 +9468,11 @@ impl ProtocolType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProtocolType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7276,7 +9509,7 @@ pub struct ProtocolAlgorithmType {
     pub signature: Option<ProtocolAlgorithmTypeSignature>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Scope {
     #[serde(rename = "ANY")]
     Any,
@@ -7290,8 +9523,8 @@ pub enum Scope {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Scope {
@@ -7303,12 +9536,39 @@ impl std::fmt::Display for Scope {
             Scope::Response => "RESPONSE",
             Scope::Token => "TOKEN",
             Scope::Noop => "",
-            Scope::FallthroughString => "*",
+            Scope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ANY" => Scope::Any,
+            "NONE" => Scope::None,
+            "REQUEST" => Scope::Request,
+            "RESPONSE" => Scope::Response,
+            "TOKEN" => Scope::Token,
+            "" => Scope::Noop,
+            _ => Scope::Unknown(s),
+        })
+    }
+}
+
 impl Default for Scope {
     fn default() -> Scope {
         Scope::Noop
@@ -7318,6 +9578,11 @@ impl Scope {
     pub fn is_noop(&self) -> bool {
         matches!(self, Scope::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Scope::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7340,7 +9605,7 @@ pub struct ProtocolAlgorithms {
     pub response: Option<ProtocolAlgorithmType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Binding {
     #[serde(rename = "HTTP-POST")]
     HttpPost,
@@ -7348,8 +9613,8 @@ pub enum Binding {
     HttpRedirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Binding {
@@ -7358,12 +9623,36 @@ impl std::fmt::Display for Binding {
             Binding::HttpPost => "HTTP-POST",
             Binding::HttpRedirect => "HTTP-REDIRECT",
             Binding::Noop => "",
-            Binding::FallthroughString => "*",
+            Binding::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "HTTP-POST" => Binding::HttpPost,
+            "HTTP-REDIRECT" => Binding::HttpRedirect,
+            "" => Binding::Noop,
+            _ => Binding::Unknown(s),
+        })
+    }
+}
+
 impl Default for Binding {
     fn default() -> Binding {
         Binding::Noop
@@ -7373,9 +9662,14 @@ impl Binding {
     pub fn is_noop(&self) -> bool {
         matches!(self, Binding::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Binding::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProtocolEndpointType {
     #[serde(rename = "INSTANCE")]
     Instance,
@@ -7383,8 +9677,8 @@ pub enum ProtocolEndpointType {
     Org,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolEndpointType {
@@ -7393,12 +9687,36 @@ impl std::fmt::Display for ProtocolEndpointType {
             ProtocolEndpointType::Instance => "INSTANCE",
             ProtocolEndpointType::Org => "ORG",
             ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
+            ProtocolEndpointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProtocolEndpointType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolEndpointType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "INSTANCE" => ProtocolEndpointType::Instance,
+            "ORG" => ProtocolEndpointType::Org,
+            "" => ProtocolEndpointType::Noop,
+            _ => ProtocolEndpointType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProtocolEndpointType {
     fn default() -> ProtocolEndpointType {
         ProtocolEndpointType::Noop
@@ -7408,6 +9726,11 @@ impl ProtocolEndpointType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolEndpointType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProtocolEndpointType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7456,7 +9779,7 @@ pub struct ProtocolRelayState {
     pub format: Option<ProtocolRelayStateFormat>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProtocolRelayStateFormat {
     #[serde(rename = "FROM_URL")]
     FromUrl,
@@ -7464,8 +9787,8 @@ pub enum ProtocolRelayStateFormat {
     Opaque,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolRelayStateFormat {
@@ -7474,12 +9797,36 @@ impl std::fmt::Display for ProtocolRelayStateFormat {
             ProtocolRelayStateFormat::FromUrl => "FROM_URL",
             ProtocolRelayStateFormat::Opaque => "OPAQUE",
             ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
+            ProtocolRelayStateFormat::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProtocolRelayStateFormat {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolRelayStateFormat {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "FROM_URL" => ProtocolRelayStateFormat::FromUrl,
+            "OPAQUE" => ProtocolRelayStateFormat::Opaque,
+            "" => ProtocolRelayStateFormat::Noop,
+            _ => ProtocolRelayStateFormat::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProtocolRelayStateFormat {
     fn default() -> ProtocolRelayStateFormat {
         ProtocolRelayStateFormat::Noop
@@ -7489,6 +9836,11 @@ impl ProtocolRelayStateFormat {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolRelayStateFormat::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProtocolRelayStateFormat::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7502,7 +9854,7 @@ pub struct ProtocolSettings {
     pub name_format: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProvisioningAction {
     #[serde(rename = "AUTO")]
     Auto,
@@ -7512,8 +9864,8 @@ pub enum ProvisioningAction {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningAction {
@@ -7523,12 +9875,37 @@ impl std::fmt::Display for ProvisioningAction {
             ProvisioningAction::Callout => "CALLOUT",
             ProvisioningAction::Disabled => "DISABLED",
             ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+            ProvisioningAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProvisioningAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTO" => ProvisioningAction::Auto,
+            "CALLOUT" => ProvisioningAction::Callout,
+            "DISABLED" => ProvisioningAction::Disabled,
+            "" => ProvisioningAction::Noop,
+            _ => ProvisioningAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProvisioningAction {
     fn default() -> ProvisioningAction {
         ProvisioningAction::Noop
@@ -7538,6 +9915,11 @@ impl ProvisioningAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProvisioningAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7564,7 +9946,7 @@ pub struct ProvisioningConditions {
     pub suspended: Option<ProvisioningSuspendedCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProvisioningDeprovisionedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7572,8 +9954,8 @@ pub enum ProvisioningDeprovisionedConditionAction {
     Reactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
@@ -7582,12 +9964,36 @@ impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
             ProvisioningDeprovisionedConditionAction::None => "NONE",
             ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
             ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProvisioningDeprovisionedConditionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningDeprovisionedConditionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "NONE" => ProvisioningDeprovisionedConditionAction::None,
+            "REACTIVATE" => ProvisioningDeprovisionedConditionAction::Reactivate,
+            "" => ProvisioningDeprovisionedConditionAction::Noop,
+            _ => ProvisioningDeprovisionedConditionAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProvisioningDeprovisionedConditionAction {
     fn default() -> ProvisioningDeprovisionedConditionAction {
         ProvisioningDeprovisionedConditionAction::Noop
@@ -7597,6 +10003,11 @@ impl ProvisioningDeprovisionedConditionAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningDeprovisionedConditionAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProvisioningDeprovisionedConditionAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7605,7 +10016,7 @@ pub struct ProvisioningDeprovisionedCondition {
     pub action: Option<ProvisioningDeprovisionedConditionAction>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProvisioningGroupsAction {
     #[serde(rename = "APPEND")]
     Append,
@@ -7617,8 +10028,8 @@ pub enum ProvisioningGroupsAction {
     Sync,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningGroupsAction {
@@ -7629,12 +10040,38 @@ impl std::fmt::Display for ProvisioningGroupsAction {
             ProvisioningGroupsAction::None => "NONE",
             ProvisioningGroupsAction::Sync => "SYNC",
             ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+            ProvisioningGroupsAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProvisioningGroupsAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningGroupsAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "APPEND" => ProvisioningGroupsAction::Append,
+            "ASSIGN" => ProvisioningGroupsAction::Assign,
+            "NONE" => ProvisioningGroupsAction::None,
+            "SYNC" => ProvisioningGroupsAction::Sync,
+            "" => ProvisioningGroupsAction::Noop,
+            _ => ProvisioningGroupsAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProvisioningGroupsAction {
     fn default() -> ProvisioningGroupsAction {
         ProvisioningGroupsAction::Noop
@@ -7644,6 +10081,11 @@ impl ProvisioningGroupsAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningGroupsAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProvisioningGroupsAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7671,7 +10113,7 @@ pub struct ProvisioningGroups {
     pub source_attribute_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProvisioningSuspendedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7679,8 +10121,8 @@ pub enum ProvisioningSuspendedConditionAction {
     Unsuspend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningSuspendedConditionAction {
@@ -7689,12 +10131,36 @@ impl std::fmt::Display for ProvisioningSuspendedConditionAction {
             ProvisioningSuspendedConditionAction::None => "NONE",
             ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
             ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+            ProvisioningSuspendedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProvisioningSuspendedConditionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningSuspendedConditionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "NONE" => ProvisioningSuspendedConditionAction::None,
+            "UNSUSPEND" => ProvisioningSuspendedConditionAction::Unsuspend,
+            "" => ProvisioningSuspendedConditionAction::Noop,
+            _ => ProvisioningSuspendedConditionAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProvisioningSuspendedConditionAction {
     fn default() -> ProvisioningSuspendedConditionAction {
         ProvisioningSuspendedConditionAction::Noop
@@ -7704,6 +10170,11 @@ impl ProvisioningSuspendedConditionAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningSuspendedConditionAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProvisioningSuspendedConditionAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7859,7 +10330,7 @@ pub struct Role {
     pub type_: Option<RoleType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RoleAssignmentType {
     #[serde(rename = "GROUP")]
     Group,
@@ -7867,8 +10338,8 @@ pub enum RoleAssignmentType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleAssignmentType {
@@ -7877,12 +10348,36 @@ impl std::fmt::Display for RoleAssignmentType {
             RoleAssignmentType::Group => "GROUP",
             RoleAssignmentType::User => "USER",
             RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
+            RoleAssignmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RoleAssignmentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleAssignmentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "GROUP" => RoleAssignmentType::Group,
+            "USER" => RoleAssignmentType::User,
+            "" => RoleAssignmentType::Noop,
+            _ => RoleAssignmentType::Unknown(s),
+        })
+    }
+}
+
 impl Default for RoleAssignmentType {
     fn default() -> RoleAssignmentType {
         RoleAssignmentType::Noop
@@ -7892,9 +10387,14 @@ impl RoleAssignmentType {
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleAssignmentType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RoleAssignmentType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RoleType {
     #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
     ApiAccessManagementAdmin,
@@ -7916,8 +10416,8 @@ pub enum RoleType {
     UserAdmin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleType {
@@ -7933,12 +10433,43 @@ impl std::fmt::Display for RoleType {
             RoleType::SuperAdmin => "SUPER_ADMIN",
             RoleType::UserAdmin => "USER_ADMIN",
             RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+            RoleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RoleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "API_ACCESS_MANAGEMENT_ADMIN" => RoleType::ApiAccessManagementAdmin,
+            "APP_ADMIN" => RoleType::AppAdmin,
+            "HELP_DESK_ADMIN" => RoleType::HelpDeskAdmin,
+            "MOBILE_ADMIN" => RoleType::MobileAdmin,
+            "ORG_ADMIN" => RoleType::OrgAdmin,
+            "READ_ONLY_ADMIN" => RoleType::ReadOnlyAdmin,
+            "REPORT_ADMIN" => RoleType::ReportAdmin,
+            "SUPER_ADMIN" => RoleType::SuperAdmin,
+            "USER_ADMIN" => RoleType::UserAdmin,
+            "" => RoleType::Noop,
+            _ => RoleType::Unknown(s),
+        })
+    }
+}
+
 impl Default for RoleType {
     fn default() -> RoleType {
         RoleType::Noop
@@ -7948,6 +10479,11 @@ impl RoleType {
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RoleType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8177,7 +10713,7 @@ pub struct SamlAttributeStatement {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Value {
     #[serde(rename = "ACTIVATING")]
     Activating,
@@ -8197,8 +10733,8 @@ pub enum Value {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -8213,12 +10749,42 @@ impl std::fmt::Display for Value {
             Value::Pending => "PENDING",
             Value::Suspended => "SUSPENDED",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVATING" => Value::Activating,
+            "ACTIVE" => Value::Active,
+            "DELETED" => Value::Deleted,
+            "DELETING" => Value::Deleting,
+            "EXPIRED_PASSWORD" => Value::ExpiredPassword,
+            "INACTIVE" => Value::Inactive,
+            "PENDING" => Value::Pending,
+            "SUSPENDED" => Value::Suspended,
+            "" => Value::Noop,
+            _ => Value::Unknown(s),
+        })
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -8228,6 +10794,11 @@ impl Value {
     pub fn is_noop(&self) -> bool {
         matches!(self, Value::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Value::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8272,7 +10843,7 @@ pub struct ScopeData {
     pub type_: Option<ScopeType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ScopeType {
     #[serde(rename = "CORS")]
     Cors,
@@ -8280,8 +10851,8 @@ pub enum ScopeType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScopeType {
@@ -8290,12 +10861,36 @@ impl std::fmt::Display for ScopeType {
             ScopeType::Cors => "CORS",
             ScopeType::Redirect => "REDIRECT",
             ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
+            ScopeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ScopeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ScopeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CORS" => ScopeType::Cors,
+            "REDIRECT" => ScopeType::Redirect,
+            "" => ScopeType::Noop,
+            _ => ScopeType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ScopeType {
     fn default() -> ScopeType {
         ScopeType::Noop
@@ -8305,6 +10900,11 @@ impl ScopeType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ScopeType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ScopeType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8481,7 +11081,7 @@ pub struct Session {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SessionAuthenticationMethod {
     #[serde(rename = "fpt")]
     Fpt,
@@ -8505,8 +11105,8 @@ pub enum SessionAuthenticationMethod {
     Tel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionAuthenticationMethod {
@@ -8523,12 +11123,44 @@ impl std::fmt::Display for SessionAuthenticationMethod {
             SessionAuthenticationMethod::Swk => "swk",
             SessionAuthenticationMethod::Tel => "tel",
             SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+            SessionAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SessionAuthenticationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionAuthenticationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "fpt" => SessionAuthenticationMethod::Fpt,
+            "geo" => SessionAuthenticationMethod::Geo,
+            "hwk" => SessionAuthenticationMethod::Hwk,
+            "kba" => SessionAuthenticationMethod::Kba,
+            "mfa" => SessionAuthenticationMethod::Mfa,
+            "otp" => SessionAuthenticationMethod::Otp,
+            "pwd" => SessionAuthenticationMethod::Pwd,
+            "sms" => SessionAuthenticationMethod::Sms,
+            "swk" => SessionAuthenticationMethod::Swk,
+            "tel" => SessionAuthenticationMethod::Tel,
+            "" => SessionAuthenticationMethod::Noop,
+            _ => SessionAuthenticationMethod::Unknown(s),
+        })
+    }
+}
+
 impl Default for SessionAuthenticationMethod {
     fn default() -> SessionAuthenticationMethod {
         SessionAuthenticationMethod::Noop
@@ -8538,6 +11170,11 @@ impl SessionAuthenticationMethod {
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionAuthenticationMethod::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SessionAuthenticationMethod::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8552,7 +11189,7 @@ pub struct SessionIdentityProvider {
     pub type_: Option<SessionIdentityProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SessionIdentityProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -8566,8 +11203,8 @@ pub enum SessionIdentityProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionIdentityProviderType {
@@ -8579,12 +11216,39 @@ impl std::fmt::Display for SessionIdentityProviderType {
             SessionIdentityProviderType::Okta => "OKTA",
             SessionIdentityProviderType::Social => "SOCIAL",
             SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
+            SessionIdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SessionIdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionIdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE_DIRECTORY" => SessionIdentityProviderType::ActiveDirectory,
+            "FEDERATION" => SessionIdentityProviderType::Federation,
+            "LDAP" => SessionIdentityProviderType::Ldap,
+            "OKTA" => SessionIdentityProviderType::Okta,
+            "SOCIAL" => SessionIdentityProviderType::Social,
+            "" => SessionIdentityProviderType::Noop,
+            _ => SessionIdentityProviderType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SessionIdentityProviderType {
     fn default() -> SessionIdentityProviderType {
         SessionIdentityProviderType::Noop
@@ -8594,9 +11258,14 @@ impl SessionIdentityProviderType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionIdentityProviderType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SessionIdentityProviderType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SessionStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -8606,8 +11275,8 @@ pub enum SessionStatus {
     MfaRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -8617,12 +11286,37 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::MfaEnroll => "MFA_ENROLL",
             SessionStatus::MfaRequired => "MFA_REQUIRED",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SessionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => SessionStatus::Active,
+            "MFA_ENROLL" => SessionStatus::MfaEnroll,
+            "MFA_REQUIRED" => SessionStatus::MfaRequired,
+            "" => SessionStatus::Noop,
+            _ => SessionStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for SessionStatus {
     fn default() -> SessionStatus {
         SessionStatus::Noop
@@ -8632,6 +11326,11 @@ impl SessionStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SessionStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8705,14 +11404,14 @@ pub struct SmsTemplate {
     pub type_: Option<SmsTemplateType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SmsTemplateType {
     #[serde(rename = "SMS_VERIFY_CODE")]
     SmsVerifyCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SmsTemplateType {
@@ -8720,12 +11419,35 @@ impl std::fmt::Display for SmsTemplateType {
         match self {
             SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
             SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
+            SmsTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SmsTemplateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SmsTemplateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "SMS_VERIFY_CODE" => SmsTemplateType::SmsVerifyCode,
+            "" => SmsTemplateType::Noop,
+            _ => SmsTemplateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SmsTemplateType {
     fn default() -> SmsTemplateType {
         SmsTemplateType::Noop
@@ -8735,6 +11457,11 @@ impl SmsTemplateType {
     pub fn is_noop(&self) -> bool {
         matches!(self, SmsTemplateType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SmsTemplateType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8754,7 +11481,7 @@ pub struct SmsUserFactorProfile {
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "ACCESS")]
     Access,
@@ -8762,8 +11489,8 @@ pub enum TokenType {
     Refresh,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -8772,12 +11499,36 @@ impl std::fmt::Display for TokenType {
             TokenType::Access => "ACCESS",
             TokenType::Refresh => "REFRESH",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TokenType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACCESS" => TokenType::Access,
+            "REFRESH" => TokenType::Refresh,
+            "" => TokenType::Noop,
+            _ => TokenType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TokenType {
     fn default() -> TokenType {
         TokenType::Noop
@@ -8787,6 +11538,11 @@ impl TokenType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TokenType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TokenType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9257,7 +12013,7 @@ pub struct UserFactor {
     pub verify: Option<VerifyFactorRequest>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -9271,8 +12027,8 @@ pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     Suffix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
@@ -9284,12 +12040,39 @@ impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
             UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
             UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
             UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CONTAINS" => UserIdentifierConditionEvaluatorPatternMatchType::Contains,
+            "EQUALS" => UserIdentifierConditionEvaluatorPatternMatchType::Equals,
+            "EXPRESSION" => UserIdentifierConditionEvaluatorPatternMatchType::Expression,
+            "STARTS_WITH" => UserIdentifierConditionEvaluatorPatternMatchType::StartsWith,
+            "SUFFIX" => UserIdentifierConditionEvaluatorPatternMatchType::Suffix,
+            "" => UserIdentifierConditionEvaluatorPatternMatchType::Noop,
+            _ => UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserIdentifierConditionEvaluatorPatternMatchType {
     fn default() -> UserIdentifierConditionEvaluatorPatternMatchType {
         UserIdentifierConditionEvaluatorPatternMatchType::Noop
@@ -9299,6 +12082,14 @@ impl UserIdentifierConditionEvaluatorPatternMatchType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserIdentifierConditionEvaluatorPatternMatchType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9313,7 +12104,7 @@ pub struct UserIdentifierConditionEvaluatorPattern {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserIdentifierPolicyRuleConditionType {
     #[serde(rename = "ATTRIBUTE")]
     Attribute,
@@ -9321,8 +12112,8 @@ pub enum UserIdentifierPolicyRuleConditionType {
     Identifier,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
@@ -9331,12 +12122,36 @@ impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
             UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
             UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
             UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserIdentifierPolicyRuleConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserIdentifierPolicyRuleConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ATTRIBUTE" => UserIdentifierPolicyRuleConditionType::Attribute,
+            "IDENTIFIER" => UserIdentifierPolicyRuleConditionType::Identifier,
+            "" => UserIdentifierPolicyRuleConditionType::Noop,
+            _ => UserIdentifierPolicyRuleConditionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserIdentifierPolicyRuleConditionType {
     fn default() -> UserIdentifierPolicyRuleConditionType {
         UserIdentifierPolicyRuleConditionType::Noop
@@ -9346,6 +12161,11 @@ impl UserIdentifierPolicyRuleConditionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserIdentifierPolicyRuleConditionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserIdentifierPolicyRuleConditionType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9395,14 +12215,14 @@ pub struct UserLifecycleAttributePolicyRuleCondition {
     pub matching_value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserNextLogin {
     #[serde(rename = "changePassword")]
     ChangePassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserNextLogin {
@@ -9410,12 +12230,35 @@ impl std::fmt::Display for UserNextLogin {
         match self {
             UserNextLogin::ChangePassword => "changePassword",
             UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
+            UserNextLogin::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserNextLogin {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserNextLogin {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "changePassword" => UserNextLogin::ChangePassword,
+            "" => UserNextLogin::Noop,
+            _ => UserNextLogin::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserNextLogin {
     fn default() -> UserNextLogin {
         UserNextLogin::Noop
@@ -9425,6 +12268,11 @@ impl UserNextLogin {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserNextLogin::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserNextLogin::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9979,7 +12827,7 @@ pub struct UserSchemaAttributeMasterPriority {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "OKTA")]
     Okta,
@@ -9989,8 +12837,8 @@ pub enum UserSchemaAttributeMasterType {
     ProfileMaster,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeMasterType {
@@ -10000,12 +12848,37 @@ impl std::fmt::Display for UserSchemaAttributeMasterType {
             UserSchemaAttributeMasterType::Override => "OVERRIDE",
             UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
             UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
+            UserSchemaAttributeMasterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserSchemaAttributeMasterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSchemaAttributeMasterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "OKTA" => UserSchemaAttributeMasterType::Okta,
+            "OVERRIDE" => UserSchemaAttributeMasterType::Override,
+            "PROFILE_MASTER" => UserSchemaAttributeMasterType::ProfileMaster,
+            "" => UserSchemaAttributeMasterType::Noop,
+            _ => UserSchemaAttributeMasterType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserSchemaAttributeMasterType {
     fn default() -> UserSchemaAttributeMasterType {
         UserSchemaAttributeMasterType::Noop
@@ -10015,6 +12888,11 @@ impl UserSchemaAttributeMasterType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSchemaAttributeMasterType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserSchemaAttributeMasterType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10033,7 +12911,7 @@ pub struct UserSchemaAttributePermission {
     pub principal: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserSchemaAttributeType {
     #[serde(rename = "array")]
     Array,
@@ -10047,8 +12925,8 @@ pub enum UserSchemaAttributeType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeType {
@@ -10060,12 +12938,39 @@ impl std::fmt::Display for UserSchemaAttributeType {
             UserSchemaAttributeType::Number => "number",
             UserSchemaAttributeType::String => "string",
             UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
+            UserSchemaAttributeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserSchemaAttributeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSchemaAttributeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "array" => UserSchemaAttributeType::Array,
+            "boolean" => UserSchemaAttributeType::Boolean,
+            "integer" => UserSchemaAttributeType::Integer,
+            "number" => UserSchemaAttributeType::Number,
+            "string" => UserSchemaAttributeType::String,
+            "" => UserSchemaAttributeType::Noop,
+            _ => UserSchemaAttributeType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserSchemaAttributeType {
     fn default() -> UserSchemaAttributeType {
         UserSchemaAttributeType::Noop
@@ -10075,6 +12980,11 @@ impl UserSchemaAttributeType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSchemaAttributeType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserSchemaAttributeType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10285,7 +13195,7 @@ pub struct UserSchemaPublic {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -10305,8 +13215,8 @@ pub enum UserStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatus {
@@ -10321,12 +13231,42 @@ impl std::fmt::Display for UserStatus {
             UserStatus::Staged => "STAGED",
             UserStatus::Suspended => "SUSPENDED",
             UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+            UserStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACTIVE" => UserStatus::Active,
+            "DEPROVISIONED" => UserStatus::Deprovisioned,
+            "LOCKED_OUT" => UserStatus::LockedOut,
+            "PASSWORD_EXPIRED" => UserStatus::PasswordExpired,
+            "PROVISIONED" => UserStatus::Provisioned,
+            "RECOVERY" => UserStatus::Recovery,
+            "STAGED" => UserStatus::Staged,
+            "SUSPENDED" => UserStatus::Suspended,
+            "" => UserStatus::Noop,
+            _ => UserStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserStatus {
     fn default() -> UserStatus {
         UserStatus::Noop
@@ -10336,6 +13276,11 @@ impl UserStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10465,7 +13410,7 @@ pub struct VerifyFactorRequest {
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -10489,8 +13434,8 @@ pub enum FactorResult {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResult {
@@ -10507,12 +13452,44 @@ impl std::fmt::Display for FactorResult {
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
             FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FactorResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CHALLENGE" => FactorResult::Challenge,
+            "ERROR" => FactorResult::Error,
+            "EXPIRED" => FactorResult::Expired,
+            "FAILED" => FactorResult::Failed,
+            "PASSCODE_REPLAYED" => FactorResult::PasscodeReplayed,
+            "REJECTED" => FactorResult::Rejected,
+            "SUCCESS" => FactorResult::Success,
+            "TIMEOUT" => FactorResult::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResult::TimeWindowExceeded,
+            "WAITING" => FactorResult::Waiting,
+            "" => FactorResult::Noop,
+            _ => FactorResult::Unknown(s),
+        })
+    }
+}
+
 impl Default for FactorResult {
     fn default() -> FactorResult {
         FactorResult::Noop
@@ -10522,6 +13499,11 @@ impl FactorResult {
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorResult::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FactorResult::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1 +1,50 @@
+#[test]
+fn test_verifies_and_reads_event_hooks() {
+    use okta::hooks::{EventHookRequest, EventHookVerification, HookError};
 
+    let mut headers = http::HeaderMap::new();
+    headers.insert(http::header::AUTHORIZATION, "hook-secret".parse().unwrap());
+    headers.insert(
+        "x-okta-verification-challenge",
+        "2AF7RZUMxStiJ4RdC0rBwUK0dXYmGJe-ZrC19Tll".parse().unwrap(),
+    );
+
+    let verification = EventHookVerification::from_request("hook-secret", &headers).unwrap();
+    assert_eq!(
+        verification.verification,
+        "2AF7RZUMxStiJ4RdC0rBwUK0dXYmGJe-ZrC19Tll"
+    );
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "eventType": "com.okta.event_hook",
+        "eventTypeVersion": "1.0",
+        "source": "https://example.okta.com/api/v1/eventHooks/whoql0HfiLGPWc8Ek0g4",
+        "eventId": "b5a188b9-5ece-4636-b041-482ffda96311",
+        "data": {
+            "events": [{
+                "uuid": "4ecd5c89-50b3-11e9-a3b3-bf0a1f0d3b2f",
+                "eventType": "user.lifecycle.deactivate",
+                "displayMessage": "Deactivate Okta user"
+            }]
+        }
+    }))
+    .unwrap();
+    let request = EventHookRequest::from_request("hook-secret", &headers, &body).unwrap();
+    assert_eq!(request.data.events.len(), 1);
+    assert_eq!(
+        request.data.events[0].event_type,
+        "user.lifecycle.deactivate"
+    );
+
+    // A prefix of the secret isn't the secret.
+    headers.insert(http::header::AUTHORIZATION, "hook".parse().unwrap());
+    assert!(matches!(
+        EventHookRequest::from_request("hook-secret", &headers, &body),
+        Err(HookError::Unauthorized)
+    ));
+    headers.remove(http::header::AUTHORIZATION);
+    assert!(matches!(
+        EventHookVerification::from_request("hook-secret", &headers),
+        Err(HookError::MissingHeader("authorization"))
+    ));
+}
@@ -52,13 +52,16 @@ impl Auths {
      *
      * * `authorization: &str` -- Basic \<base64-encoded client_id:client_secret\>.
      */
-    pub async fn post_token(&self) -> Result<crate::types::OAuth2Token> {
+    pub async fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> Result<crate::types::OAuth2Token> {
         let url = self.client.url("/token", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -135,6 +135,84 @@ impl Cards {
         // Return our response data.
         Ok(cards)
     }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieve all cards.
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Card> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card_program_id.is_empty() {
+            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id".to_string(), user_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/cards?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let resp: crate::types::GetCardsResponse = match page {
+                    // The page token is the full URL of the next page.
+                    Some(page) => match client
+                        .get(
+                            page.trim_start_matches(&client.host),
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await
+                    {
+                        Ok(resp) => resp,
+                        // Ramp returns a 404 for the page after the last one.
+                        Err(e)
+                            if e.downcast_ref::<crate::ClientError>()
+                                .and_then(|e| e.status())
+                                == Some(reqwest::StatusCode::NOT_FOUND) =>
+                        {
+                            return Ok(crate::utils::Page {
+                                items: Vec::new(),
+                                next: None,
+                            });
+                        }
+                        Err(e) => return Err(e),
+                    },
+                    None => {
+                        client
+                            .get(
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.cards,
+                    next: Some(resp.page.next),
+                })
+            }
+        })
+    }
     /**
      * GET a card.
      *
@@ -113,6 +113,73 @@ impl Departments {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List departments.
+     *
+     * This function performs a `GET` to the `/departments` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieve all departments.
+     */
+    pub fn get_all_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Department> {
+        let url = self.client.url("/departments", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let resp: crate::types::GetDepartmentsResponse = match page {
+                    // The page token is the full URL of the next page.
+                    Some(page) => match client
+                        .get(
+                            page.trim_start_matches(&client.host),
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await
+                    {
+                        Ok(resp) => resp,
+                        // Ramp returns a 404 for the page after the last one.
+                        Err(e)
+                            if e.downcast_ref::<crate::ClientError>()
+                                .and_then(|e| e.status())
+                                == Some(reqwest::StatusCode::NOT_FOUND) =>
+                        {
+                            return Ok(crate::utils::Page {
+                                items: Vec::new(),
+                                next: None,
+                            });
+                        }
+                        Err(e) => return Err(e),
+                    },
+                    None => {
+                        client
+                            .get(
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next: Some(resp.page.next),
+                })
+            }
+        })
+    }
     /**
      * Create department.
      *
@@ -0,0 +1,633 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Auths`](crate::auths::Auths).
+    pub fn auths(&self) -> Auths<'_> {
+        Auths { mock: self }
+    }
+
+    /// Mock the functions of [`Businesses`](crate::businesses::Businesses).
+    pub fn businesses(&self) -> Businesses<'_> {
+        Businesses { mock: self }
+    }
+
+    /// Mock the functions of [`CardPrograms`](crate::card_programs::CardPrograms).
+    pub fn card_programs(&self) -> CardPrograms<'_> {
+        CardPrograms { mock: self }
+    }
+
+    /// Mock the functions of [`Cards`](crate::cards::Cards).
+    pub fn cards(&self) -> Cards<'_> {
+        Cards { mock: self }
+    }
+
+    /// Mock the functions of [`CustomIds`](crate::custom_ids::CustomIds).
+    pub fn custom_ids(&self) -> CustomIds<'_> {
+        CustomIds { mock: self }
+    }
+
+    /// Mock the functions of [`Departments`](crate::departments::Departments).
+    pub fn departments(&self) -> Departments<'_> {
+        Departments { mock: self }
+    }
+
+    /// Mock the functions of [`Locations`](crate::locations::Locations).
+    pub fn locations(&self) -> Locations<'_> {
+        Locations { mock: self }
+    }
+
+    /// Mock the functions of [`Receipts`](crate::receipts::Receipts).
+    pub fn receipts(&self) -> Receipts<'_> {
+        Receipts { mock: self }
+    }
+
+    /// Mock the functions of [`Reimbursements`](crate::reimbursements::Reimbursements).
+    pub fn reimbursements(&self) -> Reimbursements<'_> {
+        Reimbursements { mock: self }
+    }
+
+    /// Mock the functions of [`Transactions`](crate::transactions::Transactions).
+    pub fn transactions(&self) -> Transactions<'_> {
+        Transactions { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+}
+
+pub struct Auths<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Auths<'a> {
+    /// Mock [`Auths::post_token`](crate::auths::Auths::post_token), a `POST` to `/token`.
+    pub fn post_token(&self) -> crate::mock::MockOperation<'a, crate::types::OAuth2Token> {
+        self.mock
+            .operation(reqwest::Method::POST, "/token".to_string())
+    }
+}
+
+pub struct Businesses<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Businesses<'a> {
+    /// Mock [`Businesses::get_resources_busine`](crate::businesses::Businesses::get_resources_busine), a `GET` to `/business`.
+    pub fn get_resources_busine(&self) -> crate::mock::MockOperation<'a, crate::types::Business> {
+        self.mock
+            .operation(reqwest::Method::GET, "/business".to_string())
+    }
+
+    /// Mock [`Businesses::get_resources_business_current`](crate::businesses::Businesses::get_resources_business_current), a `GET` to `/business/balance`.
+    pub fn get_resources_business_current(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::BusinessCurrentStatus> {
+        self.mock
+            .operation(reqwest::Method::GET, "/business/balance".to_string())
+    }
+}
+
+pub struct CardPrograms<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CardPrograms<'a> {
+    /// Mock [`CardPrograms::get_page`](crate::card_programs::CardPrograms::get_page), a `GET` to `/card-programs`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCardProgramsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/card-programs".to_string())
+    }
+
+    /// Mock [`CardPrograms::post_resources`](crate::card_programs::CardPrograms::post_resources), a `POST` to `/card-programs`.
+    pub fn post_resources(&self) -> crate::mock::MockOperation<'a, crate::types::CardProgram> {
+        self.mock
+            .operation(reqwest::Method::POST, "/card-programs".to_string())
+    }
+
+    /// Mock [`CardPrograms::get_program`](crate::card_programs::CardPrograms::get_program), a `GET` to `/card-programs/{id}`.
+    pub fn get_program(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CardProgram> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/card-programs/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Cards<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Cards<'a> {
+    /// Mock [`Cards::get_page`](crate::cards::Cards::get_page), a `GET` to `/cards`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetCardsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/cards".to_string())
+    }
+
+    /// Mock [`Cards::get`](crate::cards::Cards::get), a `GET` to `/cards/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Card> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/cards/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Cards::patch_resources`](crate::cards::Cards::patch_resources), a `PATCH` to `/cards/{id}`.
+    pub fn patch_resources(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/cards/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Cards::post_resources_physical`](crate::cards::Cards::post_resources_physical), a `POST` to `/cards/deferred/physical`.
+    pub fn post_resources_physical(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaskResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/cards/deferred/physical".to_string(),
+        )
+    }
+
+    /// Mock [`Cards::post_resources_virtual`](crate::cards::Cards::post_resources_virtual), a `POST` to `/cards/deferred/virtual`.
+    pub fn post_resources_virtual(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaskResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/cards/deferred/virtual".to_string())
+    }
+
+    /// Mock [`Cards::post_resources_termination`](crate::cards::Cards::post_resources_termination), a `POST` to `/cards/{id}/deferred/termination`.
+    pub fn post_resources_termination(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaskResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/cards/{}/deferred/termination",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Cards::post_resources_suspension`](crate::cards::Cards::post_resources_suspension), a `POST` to `/cards/{id}/deferred/suspension`.
+    pub fn post_resources_suspension(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaskResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/cards/{}/deferred/suspension",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Cards::post_resources_unsuspension`](crate::cards::Cards::post_resources_unsuspension), a `POST` to `/cards/{id}/deferred/unsuspension`.
+    pub fn post_resources_unsuspension(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaskResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/cards/{}/deferred/unsuspension",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Cards::get_resources_deferred`](crate::cards::Cards::get_resources_deferred), a `GET` to `/cards/deferred/status/{id}`.
+    pub fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetResourcesCardsDeferredResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/cards/deferred/status/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct CustomIds<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CustomIds<'a> {
+    /// Mock [`CustomIds::get_custom_provider`](crate::custom_ids::CustomIds::get_custom_provider), a `GET` to `/custom-id-provider`.
+    pub fn get_custom_provider(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCustomProviderResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/custom-id-provider".to_string())
+    }
+
+    /// Mock [`CustomIds::postcustom_provider`](crate::custom_ids::CustomIds::postcustom_provider), a `POST` to `/custom-id-provider`.
+    pub fn postcustom_provider(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostcustomProviderResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/custom-id-provider".to_string())
+    }
+
+    /// Mock [`CustomIds::post_custom_provider_application_link`](crate::custom_ids::CustomIds::post_custom_provider_application_link), a `POST` to `/custom-id-provider/application-link`.
+    pub fn post_custom_provider_application_link(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/custom-id-provider/application-link".to_string(),
+        )
+    }
+
+    /// Mock [`CustomIds::get_entity_type_custom_ramp`](crate::custom_ids::CustomIds::get_entity_type_custom_ramp), a `GET` to `/custom-id-provider/{entity_type}/{custom_id}/ramp-id`.
+    pub fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetEntityTypeCustomRampResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/custom-id-provider/{}/{}/ramp-id",
+                crate::progenitor_support::encode_path(entity_type),
+                crate::progenitor_support::encode_path(custom_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomIds::get_entity_type_ramp_custom`](crate::custom_ids::CustomIds::get_entity_type_ramp_custom), a `GET` to `/custom-id-provider/{entity_type}/{ramp_id}/custom-id`.
+    pub fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetEntityTypeRampCustomResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/custom-id-provider/{}/{}/custom-id",
+                crate::progenitor_support::encode_path(entity_type),
+                crate::progenitor_support::encode_path(ramp_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomIds::post_custom_provider_entity_type_link`](crate::custom_ids::CustomIds::post_custom_provider_entity_type_link), a `POST` to `/custom-id-provider/{entity_type}/custom-id-link`.
+    pub fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/custom-id-provider/{}/custom-id-link",
+                crate::progenitor_support::encode_path(entity_type)
+            ),
+        )
+    }
+}
+
+pub struct Departments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Departments<'a> {
+    /// Mock [`Departments::get_page`](crate::departments::Departments::get_page), a `GET` to `/departments`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetDepartmentsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/departments".to_string())
+    }
+
+    /// Mock [`Departments::post`](crate::departments::Departments::post), a `POST` to `/departments`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Department> {
+        self.mock
+            .operation(reqwest::Method::POST, "/departments".to_string())
+    }
+
+    /// Mock [`Departments::get`](crate::departments::Departments::get), a `GET` to `/departments/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Department> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/departments/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Departments::patch`](crate::departments::Departments::patch), a `PATCH` to `/departments/{id}`.
+    pub fn patch(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Department> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/departments/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Locations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Locations<'a> {
+    /// Mock [`Locations::get_page`](crate::locations::Locations::get_page), a `GET` to `/locations`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetLocationResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/locations".to_string())
+    }
+
+    /// Mock [`Locations::post`](crate::locations::Locations::post), a `POST` to `/locations`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock
+            .operation(reqwest::Method::POST, "/locations".to_string())
+    }
+
+    /// Mock [`Locations::get`](crate::locations::Locations::get), a `GET` to `/locations/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/locations/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Locations::patch`](crate::locations::Locations::patch), a `PATCH` to `/locations/{id}`.
+    pub fn patch(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Location> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/locations/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+}
+
+pub struct Receipts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Receipts<'a> {
+    /// Mock [`Receipts::get_page`](crate::receipts::Receipts::get_page), a `GET` to `/receipts`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetReceiptsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/receipts".to_string())
+    }
+
+    /// Mock [`Receipts::get`](crate::receipts::Receipts::get), a `GET` to `/receipts/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Receipt> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/receipts/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+}
+
+pub struct Reimbursements<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reimbursements<'a> {
+    /// Mock [`Reimbursements::get_page`](crate::reimbursements::Reimbursements::get_page), a `GET` to `/reimbursements`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReimbursementsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reimbursements".to_string())
+    }
+
+    /// Mock [`Reimbursements::get`](crate::reimbursements::Reimbursements::get), a `GET` to `/reimbursements/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Reimbursement> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/reimbursements/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Transactions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Transactions<'a> {
+    /// Mock [`Transactions::get_page`](crate::transactions::Transactions::get_page), a `GET` to `/transactions`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetTransactionResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/transactions".to_string())
+    }
+
+    /// Mock [`Transactions::get_resource`](crate::transactions::Transactions::get_resource), a `GET` to `/transactions/{id}`.
+    pub fn get_resource(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Data> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/transactions/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::get`](crate::users::Users::get), a `GET` to `/users/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/users/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Users::delete`](crate::users::Users::delete), a `DELETE` to `/users/{id}`.
+    pub fn delete(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/users/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Users::patch`](crate::users::Users::patch), a `PATCH` to `/users/{id}`.
+    pub fn patch(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/users/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Users::get_page`](crate::users::Users::get_page), a `GET` to `/users`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetUsersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users".to_string())
+    }
+
+    /// Mock [`Users::post_deferred`](crate::users::Users::post_deferred), a `POST` to `/users/deferred`.
+    pub fn post_deferred(&self) -> crate::mock::MockOperation<'a, crate::types::User> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users/deferred".to_string())
+    }
+
+    /// Mock [`Users::get_deferred_status`](crate::users::Users::get_deferred_status), a `GET` to `/users/deferred/status/{id}`.
+    pub fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUsersDeferredStatusResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/deferred/status/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
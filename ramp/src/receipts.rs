@@ -152,6 +152,92 @@ impl Receipts {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns description of all receipts of a business.
+     */
+    pub fn get_all_stream(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Receipt> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = created_after {
+            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = created_before {
+            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/receipts?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let resp: crate::types::GetReceiptsResponse = match page {
+                    // The page token is the full URL of the next page.
+                    Some(page) => match client
+                        .get(
+                            page.trim_start_matches(&client.host),
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await
+                    {
+                        Ok(resp) => resp,
+                        // Ramp returns a 404 for the page after the last one.
+                        Err(e)
+                            if e.downcast_ref::<crate::ClientError>()
+                                .and_then(|e| e.status())
+                                == Some(reqwest::StatusCode::NOT_FOUND) =>
+                        {
+                            return Ok(crate::utils::Page {
+                                items: Vec::new(),
+                                next: None,
+                            });
+                        }
+                        Err(e) => return Err(e),
+                    },
+                    None => {
+                        client
+                            .get(
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next: Some(resp.page.next),
+                })
+            }
+        })
+    }
     /**
      * Get details for one receipt.
      *
@@ -267,6 +267,144 @@ impl Transactions {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     */
+    pub fn get_all_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Data> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !max_amount.to_string().is_empty() {
+            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+        }
+        if !merchant_id.is_empty() {
+            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+        }
+        if !min_amount.to_string().is_empty() {
+            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+        }
+        if order_by_amount_asc {
+            query_args.push((
+                "order_by_amount_asc".to_string(),
+                order_by_amount_asc.to_string(),
+            ));
+        }
+        if order_by_amount_desc {
+            query_args.push((
+                "order_by_amount_desc".to_string(),
+                order_by_amount_desc.to_string(),
+            ));
+        }
+        if order_by_date_asc {
+            query_args.push((
+                "order_by_date_asc".to_string(),
+                order_by_date_asc.to_string(),
+            ));
+        }
+        if order_by_date_desc {
+            query_args.push((
+                "order_by_date_desc".to_string(),
+                order_by_date_desc.to_string(),
+            ));
+        }
+        if requires_memo {
+            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+        }
+        if !sk_category_id.is_empty() {
+            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+        }
+        if !state.is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/transactions?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let resp: crate::types::GetTransactionResponse = match page {
+                    // The page token is the full URL of the next page.
+                    Some(page) => match client
+                        .get(
+                            page.trim_start_matches(&client.host),
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await
+                    {
+                        Ok(resp) => resp,
+                        // Ramp returns a 404 for the page after the last one.
+                        Err(e)
+                            if e.downcast_ref::<crate::ClientError>()
+                                .and_then(|e| e.status())
+                                == Some(reqwest::StatusCode::NOT_FOUND) =>
+                        {
+                            return Ok(crate::utils::Page {
+                                items: Vec::new(),
+                                next: None,
+                            });
+                        }
+                        Err(e) => return Err(e),
+                    },
+                    None => {
+                        client
+                            .get(
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next: Some(resp.page.next),
+                })
+            }
+        })
+    }
     /**
      * GET a transaction.
      *
@@ -43,7 +43,7 @@ pub struct TaskResponse {
     pub id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Role {
     #[serde(rename = "BUSINESS_ADMIN")]
     BusinessAdmin,
@@ -55,8 +55,8 @@ pub enum Role {
     BusinessUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -67,12 +67,38 @@ impl std::fmt::Display for Role {
             Role::BusinessOwner => "BUSINESS_OWNER",
             Role::BusinessUser => "BUSINESS_USER",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BUSINESS_ADMIN" => Role::BusinessAdmin,
+            "BUSINESS_BOOKKEEPER" => Role::BusinessBookkeeper,
+            "BUSINESS_OWNER" => Role::BusinessOwner,
+            "BUSINESS_USER" => Role::BusinessUser,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        })
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -82,6 +108,11 @@ impl Role {
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Role::Unknown(_))
+    }
 }
 
 /// Ramp User
@@ -307,7 +338,7 @@ pub struct AccountingCategories {
     pub category_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "POLICY_VIOLATION_FROM_ADMIN")]
     PolicyViolationFromAdmin,
@@ -315,8 +346,8 @@ pub enum Type {
     PolicyViolationFromUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -325,12 +356,36 @@ impl std::fmt::Display for Type {
             Type::PolicyViolationFromAdmin => "POLICY_VIOLATION_FROM_ADMIN",
             Type::PolicyViolationFromUser => "POLICY_VIOLATION_FROM_USER",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "POLICY_VIOLATION_FROM_ADMIN" => Type::PolicyViolationFromAdmin,
+            "POLICY_VIOLATION_FROM_USER" => Type::PolicyViolationFromUser,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -340,6 +395,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -372,7 +432,7 @@ pub struct PolicyViolations {
     pub type_: Option<Type>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetTransactionResponseDataDisputesType {
     #[serde(rename = "DISPUTE_CANCELLED")]
     DisputeCancelled,
@@ -384,8 +444,8 @@ pub enum GetTransactionResponseDataDisputesType {
     UnrecognizedCharge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetTransactionResponseDataDisputesType {
@@ -396,12 +456,38 @@ impl std::fmt::Display for GetTransactionResponseDataDisputesType {
             GetTransactionResponseDataDisputesType::Unknown => "UNKNOWN",
             GetTransactionResponseDataDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
             GetTransactionResponseDataDisputesType::Noop => "",
-            GetTransactionResponseDataDisputesType::FallthroughString => "*",
+            GetTransactionResponseDataDisputesType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetTransactionResponseDataDisputesType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTransactionResponseDataDisputesType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DISPUTE_CANCELLED" => GetTransactionResponseDataDisputesType::DisputeCancelled,
+            "MERCHANT_ERROR" => GetTransactionResponseDataDisputesType::MerchantError,
+            "UNKNOWN" => GetTransactionResponseDataDisputesType::Unknown,
+            "UNRECOGNIZED_CHARGE" => GetTransactionResponseDataDisputesType::UnrecognizedCharge,
+            "" => GetTransactionResponseDataDisputesType::Noop,
+            _ => GetTransactionResponseDataDisputesType::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for GetTransactionResponseDataDisputesType {
     fn default() -> GetTransactionResponseDataDisputesType {
         GetTransactionResponseDataDisputesType::Noop
@@ -411,6 +497,14 @@ impl GetTransactionResponseDataDisputesType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetTransactionResponseDataDisputesType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetTransactionResponseDataDisputesType::UnknownValue(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -769,7 +863,7 @@ pub struct Fulfillment {
 /**
 * Time interval to apply limit to.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Interval {
     #[serde(rename = "DAILY")]
     Daily,
@@ -781,8 +875,8 @@ pub enum Interval {
     Yearly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -793,12 +887,38 @@ impl std::fmt::Display for Interval {
             Interval::Total => "TOTAL",
             Interval::Yearly => "YEARLY",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Interval {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DAILY" => Interval::Daily,
+            "MONTHLY" => Interval::Monthly,
+            "TOTAL" => Interval::Total,
+            "YEARLY" => Interval::Yearly,
+            "" => Interval::Noop,
+            _ => Interval::Unknown(s),
+        })
+    }
+}
+
 impl Default for Interval {
     fn default() -> Interval {
         Interval::Noop
@@ -808,6 +928,11 @@ impl Interval {
     pub fn is_noop(&self) -> bool {
         matches!(self, Interval::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Interval::Unknown(_))
+    }
 }
 
 /// Specifies the spend restrictions on a Ramp card.
@@ -1275,14 +1400,124 @@ pub struct PostResourcesCardVirtualRequest {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+pub enum GrantType {
+    #[serde(rename = "authorization_code")]
+    AuthorizationCode,
+    #[serde(rename = "client_credentials")]
+    ClientCredentials,
+    #[serde(rename = "refresh_token")]
+    RefreshToken,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(skip)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for GrantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantType::AuthorizationCode => "authorization_code",
+            GrantType::ClientCredentials => "client_credentials",
+            GrantType::RefreshToken => "refresh_token",
+            GrantType::Noop => "",
+            GrantType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl Serialize for GrantType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GrantType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "authorization_code" => GrantType::AuthorizationCode,
+            "client_credentials" => GrantType::ClientCredentials,
+            "refresh_token" => GrantType::RefreshToken,
+            "" => GrantType::Noop,
+            _ => GrantType::Unknown(s),
+        })
+    }
+}
+
+impl Default for GrantType {
+    fn default() -> GrantType {
+        GrantType::Noop
+    }
+}
+impl GrantType {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, GrantType::Noop)
+    }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GrantType::Unknown(_))
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostTokenRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(default, skip_serializing_if = "GrantType::is_noop")]
+    pub grant_type: GrantType,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub redirect_uri: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub refresh_token: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub scope: String,
+}
+
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "Bearer")]
     Bearer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -1290,12 +1525,35 @@ impl std::fmt::Display for TokenType {
         match self {
             TokenType::Bearer => "Bearer",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TokenType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Bearer" => TokenType::Bearer,
+            "" => TokenType::Noop,
+            _ => TokenType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TokenType {
     fn default() -> TokenType {
         TokenType::Noop
@@ -1305,6 +1563,11 @@ impl TokenType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TokenType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TokenType::Unknown(_))
+    }
 }
 
 ///
@@ -1488,7 +1751,7 @@ pub struct GetResourcesCardsDeferredResponseData {
     pub misc: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "ERROR")]
     Error,
@@ -1500,8 +1763,8 @@ pub enum Status {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1512,12 +1775,38 @@ impl std::fmt::Display for Status {
             Status::Started => "STARTED",
             Status::Success => "SUCCESS",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ERROR" => Status::Error,
+            "IN_PROGRESS" => Status::InProgress,
+            "STARTED" => Status::Started,
+            "SUCCESS" => Status::Success,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1527,6 +1816,11 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1546,7 +1840,7 @@ pub struct GetResourcesCardsDeferredResponse {
     pub status: Option<Status>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Icon {
     #[serde(rename = "AdvertisingIcon")]
     AdvertisingIcon,
@@ -1570,8 +1864,8 @@ pub enum Icon {
     WellnessIcon,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Icon {
@@ -1588,12 +1882,44 @@ impl std::fmt::Display for Icon {
             Icon::TravelExpensesIcon => "TravelExpensesIcon",
             Icon::WellnessIcon => "WellnessIcon",
             Icon::Noop => "",
-            Icon::FallthroughString => "*",
+            Icon::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Icon {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Icon {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AdvertisingIcon" => Icon::AdvertisingIcon,
+            "CardIcon" => Icon::CardIcon,
+            "EducationStipendIcon" => Icon::EducationStipendIcon,
+            "LunchOrderingIcon" => Icon::LunchOrderingIcon,
+            "OnboardingIcon" => Icon::OnboardingIcon,
+            "PerDiemCardIcon" => Icon::PerDiemCardIcon,
+            "SaasSubscriptionIcon" => Icon::SaasSubscriptionIcon,
+            "SoftwareTrialIcon" => Icon::SoftwareTrialIcon,
+            "TravelExpensesIcon" => Icon::TravelExpensesIcon,
+            "WellnessIcon" => Icon::WellnessIcon,
+            "" => Icon::Noop,
+            _ => Icon::Unknown(s),
+        })
+    }
+}
+
 impl Default for Icon {
     fn default() -> Icon {
         Icon::Noop
@@ -1603,6 +1929,11 @@ impl Icon {
     pub fn is_noop(&self) -> bool {
         matches!(self, Icon::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Icon::Unknown(_))
+    }
 }
 
 /// Card Program data that serves as a template for creating new cards.
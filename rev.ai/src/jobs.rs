@@ -2,6 +2,44 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`Jobs::submit_transcription_multipart`].
+#[derive(Default)]
+pub struct SubmitTranscriptionJobForm {
+    media: Option<crate::utils::FilePart>,
+    options: Option<crate::types::SubmitJobOptionsAllOf>,
+}
+
+impl SubmitTranscriptionJobForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Limited to files less than 2GB in size. If the file is larger than 2GB, submit a transcription job using `media_url`. **Note:** Media files longer than 17 hours are not supported for English transcription, and media files longer than 12 hours are not supported for non-English transcription. For non-English jobs, expected turnaround time can be up to 6 hours.
+    pub fn media(mut self, value: crate::utils::FilePart) -> Self {
+        self.media = Some(value);
+        self
+    }
+
+    /// Set the `options` part of the form.
+    pub fn options(mut self, value: crate::types::SubmitJobOptionsAllOf) -> Self {
+        self.options = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.media {
+            form = form.part("media", value.into_part().await?);
+        }
+        if let Some(value) = self.options {
+            form = form.text("options", serde_json::to_string(&value)?);
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct Jobs {
     pub client: Client,
 }
@@ -77,6 +115,51 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     */
+    pub fn get_all_list_all_of_stream(
+        &self,
+        starting_after: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::JobAllOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !starting_after.is_empty() {
+            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/jobs?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::JobAllOf>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::JobAllOf>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Submit Transcription Job.
      *
@@ -99,6 +182,22 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Submit Transcription Job.
+     *
+     * This function performs a `POST` to the `/jobs` endpoint.
+     *
+     * Starts an asynchronous job to transcribe speech-to-text for a media file. Media files can be specified in two ways, either by including a public url to the media in the transcription job `options` or by uploading a local file as part of a multipart/form request.
+     */
+    pub async fn submit_transcription_multipart(
+        &self,
+        body: SubmitTranscriptionJobForm,
+    ) -> Result<crate::types::JobAllOf> {
+        let url = self.client.url("/jobs", None);
+        self.client
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
+            .await
+    }
     /**
      * Get Job By Id.
      *
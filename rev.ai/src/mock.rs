@@ -0,0 +1,236 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Account`](crate::account::Account).
+    pub fn account(&self) -> Account<'_> {
+        Account { mock: self }
+    }
+
+    /// Mock the functions of [`Captions`](crate::captions::Captions).
+    pub fn captions(&self) -> Captions<'_> {
+        Captions { mock: self }
+    }
+
+    /// Mock the functions of [`Jobs`](crate::jobs::Jobs).
+    pub fn jobs(&self) -> Jobs<'_> {
+        Jobs { mock: self }
+    }
+
+    /// Mock the functions of [`Transcript`](crate::transcript::Transcript).
+    pub fn transcript(&self) -> Transcript<'_> {
+        Transcript { mock: self }
+    }
+}
+
+pub struct Account<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Account<'a> {
+    /// Mock [`Account::get`](crate::account::Account::get), a `GET` to `/account`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock
+            .operation(reqwest::Method::GET, "/account".to_string())
+    }
+}
+
+pub struct Captions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Captions<'a> {
+    /// Mock [`Captions::get`](crate::captions::Captions::get), a `GET` to `/jobs/{id}/captions`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/jobs/{}/captions",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Jobs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Jobs<'a> {
+    /// Mock [`Jobs::get_list_of`](crate::jobs::Jobs::get_list_of), a `GET` to `/jobs`.
+    pub fn get_list_of(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::JobAllOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/jobs".to_string())
+    }
+
+    /// Mock [`Jobs::submit_transcription`](crate::jobs::Jobs::submit_transcription), a `POST` to `/jobs`.
+    pub fn submit_transcription(&self) -> crate::mock::MockOperation<'a, crate::types::JobAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/jobs".to_string())
+    }
+
+    /// Mock [`Jobs::get`](crate::jobs::Jobs::get), a `GET` to `/jobs/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::JobAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/jobs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Jobs::delete`](crate::jobs::Jobs::delete), a `DELETE` to `/jobs/{id}`.
+    pub fn delete(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/jobs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+}
+
+pub struct Transcript<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Transcript<'a> {
+    /// Mock [`Transcript::get`](crate::transcript::Transcript::get), a `GET` to `/jobs/{id}/transcript`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/jobs/{}/transcript",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
@@ -51,7 +51,7 @@ pub struct DescriptionlessJobOptionsData {
     pub callback_url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Language {
     #[serde(rename = "ar")]
     Ar,
@@ -115,8 +115,8 @@ pub enum Language {
     Sv,
     #[serde(rename = "tr")]
     Tr,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Language {
@@ -153,17 +153,75 @@ impl std::fmt::Display for Language {
             Language::Sl => "sl",
             Language::Sv => "sv",
             Language::Tr => "tr",
-            Language::FallthroughString => "*",
+            Language::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ar" => Language::Ar,
+            "bg" => Language::Bg,
+            "ca" => Language::Ca,
+            "cmn" => Language::Cmn,
+            "cs" => Language::Cs,
+            "da" => Language::Da,
+            "de" => Language::De,
+            "el" => Language::El,
+            "en" => Language::En,
+            "es" => Language::Es,
+            "fi" => Language::Fi,
+            "fr" => Language::Fr,
+            "hi" => Language::Hi,
+            "hr" => Language::Hr,
+            "hu" => Language::Hu,
+            "it" => Language::It,
+            "ja" => Language::Ja,
+            "ko" => Language::Ko,
+            "lt" => Language::Lt,
+            "lv" => Language::Lv,
+            "ms" => Language::Ms,
+            "nl" => Language::Nl,
+            "no" => Language::No,
+            "pl" => Language::Pl,
+            "pt" => Language::Pt,
+            "ro" => Language::Ro,
+            "ru" => Language::Ru,
+            "sk" => Language::Sk,
+            "sl" => Language::Sl,
+            "sv" => Language::Sv,
+            "tr" => Language::Tr,
+            _ => Language::Unknown(s),
+        })
+    }
+}
+
 impl Default for Language {
     fn default() -> Language {
         Language::En
     }
 }
+impl Language {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Language::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DescriptionlessJobOptionsDataType {
@@ -246,7 +304,7 @@ pub struct DescriptionlessJobOptionsAllOf {
 /**
 * Simple reason of why the transcription job failed. Check `failure_detail` for specific details and solutions
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Failure {
     #[serde(rename = "download_failure")]
     DownloadFailure,
@@ -268,8 +326,8 @@ pub enum Failure {
     Transcription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Failure {
@@ -285,12 +343,43 @@ impl std::fmt::Display for Failure {
             Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
             Failure::Transcription => "transcription",
             Failure::Noop => "",
-            Failure::FallthroughString => "*",
+            Failure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Failure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Failure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "download_failure" => Failure::DownloadFailure,
+            "duration_exceeded" => Failure::DurationExceeded,
+            "duration_too_short" => Failure::DurationTooShort,
+            "empty_media" => Failure::EmptyMedia,
+            "insufficient_balance" => Failure::InsufficientBalance,
+            "internal_processing" => Failure::InternalProcessing,
+            "invalid_media" => Failure::InvalidMedia,
+            "invoicing_limit_exceeded" => Failure::InvoicingLimitExceeded,
+            "transcription" => Failure::Transcription,
+            "" => Failure::Noop,
+            _ => Failure::Unknown(s),
+        })
+    }
+}
+
 impl Default for Failure {
     fn default() -> Failure {
         Failure::Noop
@@ -300,12 +389,17 @@ impl Failure {
     pub fn is_noop(&self) -> bool {
         matches!(self, Failure::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Failure::Unknown(_))
+    }
 }
 
 /**
 * Current status of the job
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "failed")]
     Failed,
@@ -315,8 +409,8 @@ pub enum Status {
     Transcribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -326,12 +420,37 @@ impl std::fmt::Display for Status {
             Status::InProgress => "in_progress",
             Status::Transcribed => "transcribed",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "failed" => Status::Failed,
+            "in_progress" => Status::InProgress,
+            "transcribed" => Status::Transcribed,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -341,19 +460,24 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /**
 * Type of speech recognition performed. Currently the only supported values are 'async' for asynchronous jobs and `stream` for streaming jobs
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "async")]
     Async,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -361,12 +485,35 @@ impl std::fmt::Display for Type {
         match self {
             Type::Async => "async",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "async" => Type::Async,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -376,6 +523,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// Rev.ai Transcription Job
@@ -709,7 +861,7 @@ pub struct SubmitJobOptionsAllOf {
 /**
 * Type of transcript element. If Rev.ai was unable to determine the spoken word, the `type` will be `unknown`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TranscriptMonologuesElementsType {
     #[serde(rename = "punct")]
     Punct,
@@ -719,8 +871,8 @@ pub enum TranscriptMonologuesElementsType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TranscriptMonologuesElementsType {
@@ -730,12 +882,37 @@ impl std::fmt::Display for TranscriptMonologuesElementsType {
             TranscriptMonologuesElementsType::Text => "text",
             TranscriptMonologuesElementsType::Unknown => "unknown",
             TranscriptMonologuesElementsType::Noop => "",
-            TranscriptMonologuesElementsType::FallthroughString => "*",
+            TranscriptMonologuesElementsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TranscriptMonologuesElementsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TranscriptMonologuesElementsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "punct" => TranscriptMonologuesElementsType::Punct,
+            "text" => TranscriptMonologuesElementsType::Text,
+            "unknown" => TranscriptMonologuesElementsType::Unknown,
+            "" => TranscriptMonologuesElementsType::Noop,
+            _ => TranscriptMonologuesElementsType::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for TranscriptMonologuesElementsType {
     fn default() -> TranscriptMonologuesElementsType {
         TranscriptMonologuesElementsType::Noop
@@ -745,6 +922,11 @@ impl TranscriptMonologuesElementsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TranscriptMonologuesElementsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TranscriptMonologuesElementsType::UnknownValue(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -841,7 +1023,7 @@ pub struct Transcript {
 /**
 * MIME type specifying the caption output format
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Accept {
     #[serde(rename = "application/x-subrip")]
     ApplicationXSubrip,
@@ -849,8 +1031,8 @@ pub enum Accept {
     TextVtt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Accept {
@@ -859,12 +1041,36 @@ impl std::fmt::Display for Accept {
             Accept::ApplicationXSubrip => "application/x-subrip",
             Accept::TextVtt => "text/vtt",
             Accept::Noop => "",
-            Accept::FallthroughString => "*",
+            Accept::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Accept {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Accept {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "application/x-subrip" => Accept::ApplicationXSubrip,
+            "text/vtt" => Accept::TextVtt,
+            "" => Accept::Noop,
+            _ => Accept::Unknown(s),
+        })
+    }
+}
+
 impl Default for Accept {
     fn default() -> Accept {
         Accept::Noop
@@ -874,12 +1080,17 @@ impl Accept {
     pub fn is_noop(&self) -> bool {
         matches!(self, Accept::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Accept::Unknown(_))
+    }
 }
 
 /**
 * MIME type specifying the transcription output format
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AcceptTranscript {
     #[serde(rename = "application/vnd.rev.transcript.v1.0+json")]
     ApplicationVndRevTranscript0Json,
@@ -887,8 +1098,8 @@ pub enum AcceptTranscript {
     TextPlain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AcceptTranscript {
@@ -899,12 +1110,38 @@ impl std::fmt::Display for AcceptTranscript {
             }
             AcceptTranscript::TextPlain => "text/plain",
             AcceptTranscript::Noop => "",
-            AcceptTranscript::FallthroughString => "*",
+            AcceptTranscript::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AcceptTranscript {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AcceptTranscript {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "application/vnd.rev.transcript.v1.0+json" => {
+                AcceptTranscript::ApplicationVndRevTranscript0Json
+            }
+            "text/plain" => AcceptTranscript::TextPlain,
+            "" => AcceptTranscript::Noop,
+            _ => AcceptTranscript::Unknown(s),
+        })
+    }
+}
+
 impl Default for AcceptTranscript {
     fn default() -> AcceptTranscript {
         AcceptTranscript::Noop
@@ -914,4 +1151,9 @@ impl AcceptTranscript {
     pub fn is_noop(&self) -> bool {
         matches!(self, AcceptTranscript::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AcceptTranscript::Unknown(_))
+    }
 }
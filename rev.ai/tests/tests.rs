@@ -1 +1,52 @@
+use wiremock::{
+    matchers::{body_string_contains, header, header_regex, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use revai::{jobs::SubmitTranscriptionJobForm, utils::FilePart, Client};
+
+#[tokio::test]
+async fn test_submit_transcription_multipart() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/jobs"))
+        .and(header("authorization", "Bearer test-token"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains(
+            r#"Content-Disposition: form-data; name="media"; filename="call.mp3""#,
+        ))
+        .and(body_string_contains("Content-Type: audio/mpeg"))
+        .and(body_string_contains("not really audio"))
+        .and(body_string_contains(r#"name="options""#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "Umx5c6F7pH7r",
+            "status": "in_progress",
+            "type": "async"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let form = SubmitTranscriptionJobForm::new()
+        .media(
+            FilePart::bytes(&b"not really audio"[..])
+                .file_name("call.mp3")
+                .mime_type("audio/mpeg"),
+        )
+        .options(Default::default());
+
+    let job = client
+        .jobs()
+        .submit_transcription_multipart(form)
+        .await
+        .expect("submitting the job should succeed");
+
+    assert_eq!(job.job.id, "Umx5c6F7pH7r");
+}
@@ -100,6 +100,67 @@ impl BlocksApi {
             )
             .await
     }
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all email addresses that are currently on your blocks list.**
+     */
+    pub fn get_all_suppression_blocks_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BlocksResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/blocks?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BlocksResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::BlocksResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete blocks.
      *
@@ -193,6 +254,58 @@ impl BlocksApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks_email`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a specific email address from your blocks list.**
+     */
+    pub fn get_all_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BlocksResponse> {
+        let url = self.client.url(
+            &format!(
+                "/suppression/blocks/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BlocksResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::BlocksResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete a specific block.
      *
@@ -87,6 +87,63 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all of your bounces.**
+     */
+    pub fn get_all_suppression_bounces_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BounceResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/bounces?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BounceResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::BounceResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete bounces.
      *
@@ -180,6 +237,58 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a specific bounce by email address.**
+     */
+    pub fn get_all_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BounceResponse> {
+        let url = self.client.url(
+            &format!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::BounceResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::BounceResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete a bounce.
      *
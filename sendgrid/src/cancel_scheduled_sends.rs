@@ -91,6 +91,52 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all cancelled and paused scheduled send information.**
+     *
+     * This endpoint will return only the scheduled sends that are associated with a `batch_id`. If you have scheduled a send using the `/mail/send` endpoint and the `send_at` field but no `batch_id`, the send will be scheduled for delivery; however, it will not be returned by this endpoint. For this reason, you should assign a `batch_id` to any scheduled send you may need to pause or cancel in the future.
+     */
+    pub fn get_all_user_scheduled_sends_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        let url = self.client.url("/user/scheduled_sends", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::UserScheduledSendStatusAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::UserScheduledSendStatusAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Cancel or pause a scheduled send.
      *
@@ -220,6 +266,58 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve the cancel/paused scheduled send information for a specific `batch_id`.**
+     */
+    pub fn get_all_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        let url = self.client.url(
+            &format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::UserScheduledSendStatusAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::UserScheduledSendStatusAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete a cancellation or pause from a scheduled send.
      *
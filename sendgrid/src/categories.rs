@@ -87,6 +87,61 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all of your categories.**
+     */
+    pub fn get_all_stream(
+        &self,
+        category: &str,
+        offset: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetCategoriesResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !category.is_empty() {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/categories?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetCategoriesResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetCategoriesResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
      *
@@ -266,4 +321,75 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_all_stats_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CategoryStats> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !categories.is_empty() {
+            query_args.push(("categories".to_string(), categories.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/categories/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::CategoryStats>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::CategoryStats>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
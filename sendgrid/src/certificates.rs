@@ -96,6 +96,60 @@ impl Certificates {
             )
             .await
     }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all your IdP configurations by configuration ID.**
+     *
+     * The `integration_id` expected by this endpoint is the `id` returned in the response by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn get_all_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SsoCertificateBody> {
+        let url = self.client.url(
+            &format!(
+                "/sso/integrations/{}/certificates",
+                crate::progenitor_support::encode_path(integration_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SsoCertificateBody>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SsoCertificateBody>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get an SSO Certificate.
      *
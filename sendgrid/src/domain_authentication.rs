@@ -109,6 +109,71 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     */
+    pub fn get_all_whitelabel_domains_stream(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if exclude_subusers {
+            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::DomainAuthentication200ResponseAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::DomainAuthentication200ResponseAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Authenticate a domain.
      *
@@ -309,6 +374,63 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve the default authentication for a domain.**
+     *
+     * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
+     *
+     * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     */
+    pub fn get_all_whitelabel_domains_default_stream(
+        &self,
+        domain: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains/default?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::DomainAuthentication200ResponseAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::DomainAuthentication200ResponseAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add an IP to an authenticated domain.
      *
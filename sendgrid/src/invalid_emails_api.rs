@@ -100,6 +100,63 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::InvalidEmail> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/invalid_emails?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::InvalidEmail>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::InvalidEmail>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete invalid emails.
      *
@@ -193,6 +250,54 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a specific invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::InvalidEmail> {
+        let url = self.client.url(
+            &format!(
+                "/suppression/invalid_emails/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::InvalidEmail>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::InvalidEmail>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Delete a specific invalid email.
      *
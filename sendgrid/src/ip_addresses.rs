@@ -133,6 +133,83 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all assigned and unassigned IPs.**
+     *
+     * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_stream(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetIpsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_whitelabels {
+            query_args.push((
+                "exclude_whitelabels".to_string(),
+                exclude_whitelabels.to_string(),
+            ));
+        }
+        if !ip.is_empty() {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !sort_by_direction.to_string().is_empty() {
+            query_args.push((
+                "sort_by_direction".to_string(),
+                sort_by_direction.to_string(),
+            ));
+        }
+        if !subuser.is_empty() {
+            query_args.push(("subuser".to_string(), subuser.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/ips?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetIpsResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetIpsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add IPs.
      *
@@ -218,6 +295,52 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve only assigned IP addresses.**
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_assigned_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetIpsAssignedResponse> {
+        let url = self.client.url("/ips/assigned", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetIpsAssignedResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetIpsAssignedResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve all IP pools an IP address belongs to.
      *
@@ -52,6 +52,50 @@ impl IpPools {
             )
             .await
     }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to get all of your IP pools.**
+     */
+    pub fn get_all_ips_pools_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IpPoolsPoolResp> {
+        let url = self.client.url("/ips/pools", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IpPoolsPoolResp>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IpPoolsPoolResp>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an IP pool.
      *
@@ -52,6 +52,50 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
+     */
+    pub fn get_all_ips_warmup_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IpWarmupResponse> {
+        let url = self.client.url("/ips/warmup", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IpWarmupResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IpWarmupResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Start warming up an IP address.
      *
@@ -136,6 +180,60 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve the warmup status for a specific IP address.**
+     *
+     * You can retrieve all of your warming IPs using the "Retrieve all IPs currently in warmup" endpoint.
+     */
+    pub fn get_all_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IpWarmupResponse> {
+        let url = self.client.url(
+            &format!(
+                "/ips/warmup/{}",
+                crate::progenitor_support::encode_path(ip_address),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IpWarmupResponse>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IpWarmupResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Stop warming up an IP address.
      *
@@ -73,6 +73,52 @@ impl LinkBranding {
             )
             .await
     }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all branded links**.
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_all_whitelabel_links_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::LinkBranding200Response> {
+        let url = self.client.url("/whitelabel/links", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::LinkBranding200Response>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::LinkBranding200Response>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a branded link.
      *
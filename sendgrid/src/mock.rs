@@ -0,0 +1,4444 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Alerts`](crate::alerts::Alerts).
+    pub fn alerts(&self) -> Alerts<'_> {
+        Alerts { mock: self }
+    }
+
+    /// Mock the functions of [`ApiKeyPermissions`](crate::api_key_permissions::ApiKeyPermissions).
+    pub fn api_key_permissions(&self) -> ApiKeyPermissions<'_> {
+        ApiKeyPermissions { mock: self }
+    }
+
+    /// Mock the functions of [`ApiKeys`](crate::api_keys::ApiKeys).
+    pub fn api_keys(&self) -> ApiKeys<'_> {
+        ApiKeys { mock: self }
+    }
+
+    /// Mock the functions of [`BlocksApi`](crate::blocks_api::BlocksApi).
+    pub fn blocks_api(&self) -> BlocksApi<'_> {
+        BlocksApi { mock: self }
+    }
+
+    /// Mock the functions of [`BouncesApi`](crate::bounces_api::BouncesApi).
+    pub fn bounces_api(&self) -> BouncesApi<'_> {
+        BouncesApi { mock: self }
+    }
+
+    /// Mock the functions of [`CampaignsApi`](crate::campaigns_api::CampaignsApi).
+    pub fn campaigns_api(&self) -> CampaignsApi<'_> {
+        CampaignsApi { mock: self }
+    }
+
+    /// Mock the functions of [`CancelScheduledSends`](crate::cancel_scheduled_sends::CancelScheduledSends).
+    pub fn cancel_scheduled_sends(&self) -> CancelScheduledSends<'_> {
+        CancelScheduledSends { mock: self }
+    }
+
+    /// Mock the functions of [`Categories`](crate::categories::Categories).
+    pub fn categories(&self) -> Categories<'_> {
+        Categories { mock: self }
+    }
+
+    /// Mock the functions of [`Certificates`](crate::certificates::Certificates).
+    pub fn certificates(&self) -> Certificates<'_> {
+        Certificates { mock: self }
+    }
+
+    /// Mock the functions of [`Contacts`](crate::contacts::Contacts).
+    pub fn contacts(&self) -> Contacts<'_> {
+        Contacts { mock: self }
+    }
+
+    /// Mock the functions of [`ContactsApiCustomFields`](crate::contacts_api_custom_fields::ContactsApiCustomFields).
+    pub fn contacts_api_custom_fields(&self) -> ContactsApiCustomFields<'_> {
+        ContactsApiCustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`ContactsApiLists`](crate::contacts_api_lists::ContactsApiLists).
+    pub fn contacts_api_lists(&self) -> ContactsApiLists<'_> {
+        ContactsApiLists { mock: self }
+    }
+
+    /// Mock the functions of [`ContactsApiRecipients`](crate::contacts_api_recipients::ContactsApiRecipients).
+    pub fn contacts_api_recipients(&self) -> ContactsApiRecipients<'_> {
+        ContactsApiRecipients { mock: self }
+    }
+
+    /// Mock the functions of [`ContactsApiSegments`](crate::contacts_api_segments::ContactsApiSegments).
+    pub fn contacts_api_segments(&self) -> ContactsApiSegments<'_> {
+        ContactsApiSegments { mock: self }
+    }
+
+    /// Mock the functions of [`CsvUiOnly`](crate::csv_ui_only::CsvUiOnly).
+    pub fn csv_ui_only(&self) -> CsvUiOnly<'_> {
+        CsvUiOnly { mock: self }
+    }
+
+    /// Mock the functions of [`CustomFields`](crate::custom_fields::CustomFields).
+    pub fn custom_fields(&self) -> CustomFields<'_> {
+        CustomFields { mock: self }
+    }
+
+    /// Mock the functions of [`DesignsApi`](crate::designs_api::DesignsApi).
+    pub fn designs_api(&self) -> DesignsApi<'_> {
+        DesignsApi { mock: self }
+    }
+
+    /// Mock the functions of [`DomainAuthentication`](crate::domain_authentication::DomainAuthentication).
+    pub fn domain_authentication(&self) -> DomainAuthentication<'_> {
+        DomainAuthentication { mock: self }
+    }
+
+    /// Mock the functions of [`EmailAddressValidation`](crate::email_address_validation::EmailAddressValidation).
+    pub fn email_address_validation(&self) -> EmailAddressValidation<'_> {
+        EmailAddressValidation { mock: self }
+    }
+
+    /// Mock the functions of [`EmailCnameRecords`](crate::email_cname_records::EmailCnameRecords).
+    pub fn email_cname_records(&self) -> EmailCnameRecords<'_> {
+        EmailCnameRecords { mock: self }
+    }
+
+    /// Mock the functions of [`InvalidEmailsApi`](crate::invalid_emails_api::InvalidEmailsApi).
+    pub fn invalid_emails_api(&self) -> InvalidEmailsApi<'_> {
+        InvalidEmailsApi { mock: self }
+    }
+
+    /// Mock the functions of [`IpAccessManagement`](crate::ip_access_management::IpAccessManagement).
+    pub fn ip_access_management(&self) -> IpAccessManagement<'_> {
+        IpAccessManagement { mock: self }
+    }
+
+    /// Mock the functions of [`IpAddresses`](crate::ip_addresses::IpAddresses).
+    pub fn ip_addresses(&self) -> IpAddresses<'_> {
+        IpAddresses { mock: self }
+    }
+
+    /// Mock the functions of [`IpPools`](crate::ip_pools::IpPools).
+    pub fn ip_pools(&self) -> IpPools<'_> {
+        IpPools { mock: self }
+    }
+
+    /// Mock the functions of [`IpWarmup`](crate::ip_warmup::IpWarmup).
+    pub fn ip_warmup(&self) -> IpWarmup<'_> {
+        IpWarmup { mock: self }
+    }
+
+    /// Mock the functions of [`LinkBranding`](crate::link_branding::LinkBranding).
+    pub fn link_branding(&self) -> LinkBranding<'_> {
+        LinkBranding { mock: self }
+    }
+
+    /// Mock the functions of [`Lists`](crate::lists::Lists).
+    pub fn lists(&self) -> Lists<'_> {
+        Lists { mock: self }
+    }
+
+    /// Mock the functions of [`MailSend`](crate::mail_send::MailSend).
+    pub fn mail_send(&self) -> MailSend<'_> {
+        MailSend { mock: self }
+    }
+
+    /// Mock the functions of [`MarketingCampaignsStats`](crate::marketing_campaigns_stats::MarketingCampaignsStats).
+    pub fn marketing_campaigns_stats(&self) -> MarketingCampaignsStats<'_> {
+        MarketingCampaignsStats { mock: self }
+    }
+
+    /// Mock the functions of [`Query`](crate::query::Query).
+    pub fn query(&self) -> Query<'_> {
+        Query { mock: self }
+    }
+
+    /// Mock the functions of [`ReverseDns`](crate::reverse_dns::ReverseDns).
+    pub fn reverse_dns(&self) -> ReverseDns<'_> {
+        ReverseDns { mock: self }
+    }
+
+    /// Mock the functions of [`SegmentingContacts`](crate::segmenting_contacts::SegmentingContacts).
+    pub fn segmenting_contacts(&self) -> SegmentingContacts<'_> {
+        SegmentingContacts { mock: self }
+    }
+
+    /// Mock the functions of [`SegmentingContactsBeta`](crate::segmenting_contacts_beta::SegmentingContactsBeta).
+    pub fn segmenting_contacts_beta(&self) -> SegmentingContactsBeta<'_> {
+        SegmentingContactsBeta { mock: self }
+    }
+
+    /// Mock the functions of [`SendTestEmail`](crate::send_test_email::SendTestEmail).
+    pub fn send_test_email(&self) -> SendTestEmail<'_> {
+        SendTestEmail { mock: self }
+    }
+
+    /// Mock the functions of [`SenderIdentitiesApi`](crate::sender_identities_api::SenderIdentitiesApi).
+    pub fn sender_identities_api(&self) -> SenderIdentitiesApi<'_> {
+        SenderIdentitiesApi { mock: self }
+    }
+
+    /// Mock the functions of [`SenderVerification`](crate::sender_verification::SenderVerification).
+    pub fn sender_verification(&self) -> SenderVerification<'_> {
+        SenderVerification { mock: self }
+    }
+
+    /// Mock the functions of [`Senders`](crate::senders::Senders).
+    pub fn senders(&self) -> Senders<'_> {
+        Senders { mock: self }
+    }
+
+    /// Mock the functions of [`SettingsEnforcedTls`](crate::settings_enforced_tls::SettingsEnforcedTls).
+    pub fn settings_enforced_tls(&self) -> SettingsEnforcedTls<'_> {
+        SettingsEnforcedTls { mock: self }
+    }
+
+    /// Mock the functions of [`SettingsInboundParse`](crate::settings_inbound_parse::SettingsInboundParse).
+    pub fn settings_inbound_parse(&self) -> SettingsInboundParse<'_> {
+        SettingsInboundParse { mock: self }
+    }
+
+    /// Mock the functions of [`SettingsMail`](crate::settings_mail::SettingsMail).
+    pub fn settings_mail(&self) -> SettingsMail<'_> {
+        SettingsMail { mock: self }
+    }
+
+    /// Mock the functions of [`SettingsPartner`](crate::settings_partner::SettingsPartner).
+    pub fn settings_partner(&self) -> SettingsPartner<'_> {
+        SettingsPartner { mock: self }
+    }
+
+    /// Mock the functions of [`SettingsTracking`](crate::settings_tracking::SettingsTracking).
+    pub fn settings_tracking(&self) -> SettingsTracking<'_> {
+        SettingsTracking { mock: self }
+    }
+
+    /// Mock the functions of [`SingleSends`](crate::single_sends::SingleSends).
+    pub fn single_sends(&self) -> SingleSends<'_> {
+        SingleSends { mock: self }
+    }
+
+    /// Mock the functions of [`SingleSignOnSettings`](crate::single_sign_on_settings::SingleSignOnSettings).
+    pub fn single_sign_on_settings(&self) -> SingleSignOnSettings<'_> {
+        SingleSignOnSettings { mock: self }
+    }
+
+    /// Mock the functions of [`SingleSignOnTeammates`](crate::single_sign_on_teammates::SingleSignOnTeammates).
+    pub fn single_sign_on_teammates(&self) -> SingleSignOnTeammates<'_> {
+        SingleSignOnTeammates { mock: self }
+    }
+
+    /// Mock the functions of [`SpamReportsApi`](crate::spam_reports_api::SpamReportsApi).
+    pub fn spam_reports_api(&self) -> SpamReportsApi<'_> {
+        SpamReportsApi { mock: self }
+    }
+
+    /// Mock the functions of [`Stats`](crate::stats::Stats).
+    pub fn stats(&self) -> Stats<'_> {
+        Stats { mock: self }
+    }
+
+    /// Mock the functions of [`SubuserMonitorSettings`](crate::subuser_monitor_settings::SubuserMonitorSettings).
+    pub fn subuser_monitor_settings(&self) -> SubuserMonitorSettings<'_> {
+        SubuserMonitorSettings { mock: self }
+    }
+
+    /// Mock the functions of [`SubuserStatistics`](crate::subuser_statistics::SubuserStatistics).
+    pub fn subuser_statistics(&self) -> SubuserStatistics<'_> {
+        SubuserStatistics { mock: self }
+    }
+
+    /// Mock the functions of [`SubusersApi`](crate::subusers_api::SubusersApi).
+    pub fn subusers_api(&self) -> SubusersApi<'_> {
+        SubusersApi { mock: self }
+    }
+
+    /// Mock the functions of [`Suppressions`](crate::suppressions::Suppressions).
+    pub fn suppressions(&self) -> Suppressions<'_> {
+        Suppressions { mock: self }
+    }
+
+    /// Mock the functions of [`SuppressionsGlobal`](crate::suppressions_global::SuppressionsGlobal).
+    pub fn suppressions_global(&self) -> SuppressionsGlobal<'_> {
+        SuppressionsGlobal { mock: self }
+    }
+
+    /// Mock the functions of [`SuppressionsUnsubscribeGroups`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups).
+    pub fn suppressions_unsubscribe_groups(&self) -> SuppressionsUnsubscribeGroups<'_> {
+        SuppressionsUnsubscribeGroups { mock: self }
+    }
+
+    /// Mock the functions of [`Teammates`](crate::teammates::Teammates).
+    pub fn teammates(&self) -> Teammates<'_> {
+        Teammates { mock: self }
+    }
+
+    /// Mock the functions of [`TransactionalTemplates`](crate::transactional_templates::TransactionalTemplates).
+    pub fn transactional_templates(&self) -> TransactionalTemplates<'_> {
+        TransactionalTemplates { mock: self }
+    }
+
+    /// Mock the functions of [`TransactionalTemplatesVersions`](crate::transactional_templates_versions::TransactionalTemplatesVersions).
+    pub fn transactional_templates_versions(&self) -> TransactionalTemplatesVersions<'_> {
+        TransactionalTemplatesVersions { mock: self }
+    }
+
+    /// Mock the functions of [`UsersApi`](crate::users_api::UsersApi).
+    pub fn users_api(&self) -> UsersApi<'_> {
+        UsersApi { mock: self }
+    }
+
+    /// Mock the functions of [`Webhooks`](crate::webhooks::Webhooks).
+    pub fn webhooks(&self) -> Webhooks<'_> {
+        Webhooks { mock: self }
+    }
+}
+
+pub struct Alerts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Alerts<'a> {
+    /// Mock [`Alerts::get_page`](crate::alerts::Alerts::get_page), a `GET` to `/alerts`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::GetAlertsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/alerts".to_string())
+    }
+
+    /// Mock [`Alerts::post`](crate::alerts::Alerts::post), a `POST` to `/alerts`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PostAlertsResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/alerts".to_string())
+    }
+
+    /// Mock [`Alerts::get`](crate::alerts::Alerts::get), a `GET` to `/alerts/{alert_id}`.
+    pub fn get(
+        &self,
+        alert_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAlertsAlertResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/alerts/{}",
+                crate::progenitor_support::encode_path(&alert_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Alerts::delete`](crate::alerts::Alerts::delete), a `DELETE` to `/alerts/{alert_id}`.
+    pub fn delete(&self, alert_id: i64) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/alerts/{}",
+                crate::progenitor_support::encode_path(&alert_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Alerts::patch`](crate::alerts::Alerts::patch), a `PATCH` to `/alerts/{alert_id}`.
+    pub fn patch(
+        &self,
+        alert_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAlertsAlertResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/alerts/{}",
+                crate::progenitor_support::encode_path(&alert_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct ApiKeyPermissions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ApiKeyPermissions<'a> {
+    /// Mock [`ApiKeyPermissions::get_scopes`](crate::api_key_permissions::ApiKeyPermissions::get_scopes), a `GET` to `/scopes`.
+    pub fn get_scopes(&self) -> crate::mock::MockOperation<'a, crate::types::GetScopesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/scopes".to_string())
+    }
+}
+
+pub struct ApiKeys<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ApiKeys<'a> {
+    /// Mock [`ApiKeys::get`](crate::api_keys::ApiKeys::get), a `GET` to `/api_keys`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetApiKeysResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api_keys".to_string())
+    }
+
+    /// Mock [`ApiKeys::create`](crate::api_keys::ApiKeys::create), a `POST` to `/api_keys`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::CreateApiKeysResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/api_keys".to_string())
+    }
+
+    /// Mock [`ApiKeys::get_key`](crate::api_keys::ApiKeys::get_key), a `GET` to `/api_keys/{api_key_id}`.
+    pub fn get_key(
+        &self,
+        api_key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetApiKeysKeyResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api_keys/{}",
+                crate::progenitor_support::encode_path(api_key_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApiKeys::put_key`](crate::api_keys::ApiKeys::put_key), a `PUT` to `/api_keys/{api_key_id}`.
+    pub fn put_key(
+        &self,
+        api_key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApiKeyNameScopesAllOf> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/api_keys/{}",
+                crate::progenitor_support::encode_path(api_key_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApiKeys::delete_key`](crate::api_keys::ApiKeys::delete_key), a `DELETE` to `/api_keys/{api_key_id}`.
+    pub fn delete_key(&self, api_key_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/api_keys/{}",
+                crate::progenitor_support::encode_path(api_key_id)
+            ),
+        )
+    }
+
+    /// Mock [`ApiKeys::patch_key`](crate::api_keys::ApiKeys::patch_key), a `PATCH` to `/api_keys/{api_key_id}`.
+    pub fn patch_key(
+        &self,
+        api_key_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApiKeyNameId> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/api_keys/{}",
+                crate::progenitor_support::encode_path(api_key_id)
+            ),
+        )
+    }
+}
+
+pub struct BlocksApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BlocksApi<'a> {
+    /// Mock [`BlocksApi::get_suppression_blocks`](crate::blocks_api::BlocksApi::get_suppression_blocks), a `GET` to `/suppression/blocks`.
+    pub fn get_suppression_blocks(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BlocksResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/suppression/blocks".to_string())
+    }
+
+    /// Mock [`BlocksApi::delete_suppression_blocks`](crate::blocks_api::BlocksApi::delete_suppression_blocks), a `DELETE` to `/suppression/blocks`.
+    pub fn delete_suppression_blocks(&self) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/suppression/blocks".to_string())
+    }
+
+    /// Mock [`BlocksApi::get_suppression_blocks_email`](crate::blocks_api::BlocksApi::get_suppression_blocks_email), a `GET` to `/suppression/blocks/{email}`.
+    pub fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BlocksResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/suppression/blocks/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`BlocksApi::delete_suppression_blocks_email`](crate::blocks_api::BlocksApi::delete_suppression_blocks_email), a `DELETE` to `/suppression/blocks/{email}`.
+    pub fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/suppression/blocks/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct BouncesApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BouncesApi<'a> {
+    /// Mock [`BouncesApi::get_suppression_bounces`](crate::bounces_api::BouncesApi::get_suppression_bounces), a `GET` to `/suppression/bounces`.
+    pub fn get_suppression_bounces(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BounceResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/suppression/bounces".to_string())
+    }
+
+    /// Mock [`BouncesApi::delete_suppression_bounces`](crate::bounces_api::BouncesApi::delete_suppression_bounces), a `DELETE` to `/suppression/bounces`.
+    pub fn delete_suppression_bounces(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/suppression/bounces".to_string())
+    }
+
+    /// Mock [`BouncesApi::get_suppression_bounces_email`](crate::bounces_api::BouncesApi::get_suppression_bounces_email), a `GET` to `/suppression/bounces/{email}`.
+    pub fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BounceResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`BouncesApi::delete_suppression_bounces_email`](crate::bounces_api::BouncesApi::delete_suppression_bounces_email), a `DELETE` to `/suppression/bounces/{email}`.
+    pub fn delete_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct CampaignsApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CampaignsApi<'a> {
+    /// Mock [`CampaignsApi::get_campaigns`](crate::campaigns_api::CampaignsApi::get_campaigns), a `GET` to `/campaigns`.
+    pub fn get_campaigns(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCampaignsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/campaigns".to_string())
+    }
+
+    /// Mock [`CampaignsApi::post_campaign`](crate::campaigns_api::CampaignsApi::post_campaign), a `POST` to `/campaigns`.
+    pub fn post_campaign(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/campaigns".to_string())
+    }
+
+    /// Mock [`CampaignsApi::get_campaigns_campaign`](crate::campaigns_api::CampaignsApi::get_campaigns_campaign), a `GET` to `/campaigns/{campaign_id}`.
+    pub fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCampaignsCampaignResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::delete_campaigns_campaign`](crate::campaigns_api::CampaignsApi::delete_campaigns_campaign), a `DELETE` to `/campaigns/{campaign_id}`.
+    pub fn delete_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::patch_campaigns_campaign`](crate::campaigns_api::CampaignsApi::patch_campaigns_campaign), a `PATCH` to `/campaigns/{campaign_id}`.
+    pub fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::CampaignResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/campaigns/{}",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::post_campaigns_campaign_schedules_now`](crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedules_now), a `POST` to `/campaigns/{campaign_id}/schedules/now`.
+    pub fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SendACampaignResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/schedules/now",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::get_campaigns_campaign_schedule`](crate::campaigns_api::CampaignsApi::get_campaigns_campaign_schedule), a `GET` to `/campaigns/{campaign_id}/schedules`.
+    pub fn get_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScheduleACampaignRequest> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/campaigns/{}/schedules",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::post_campaigns_campaign_schedule`](crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedule), a `POST` to `/campaigns/{campaign_id}/schedules`.
+    pub fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScheduleACampaignResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/schedules",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::delete_campaigns_campaign_schedules`](crate::campaigns_api::CampaignsApi::delete_campaigns_campaign_schedules), a `DELETE` to `/campaigns/{campaign_id}/schedules`.
+    pub fn delete_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/campaigns/{}/schedules",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::patch_campaigns_campaign_schedules`](crate::campaigns_api::CampaignsApi::patch_campaigns_campaign_schedules), a `PATCH` to `/campaigns/{campaign_id}/schedules`.
+    pub fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::UpdateAScheduledCampaignResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/campaigns/{}/schedules",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CampaignsApi::post_campaigns_campaign_schedules_test`](crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedules_test), a `POST` to `/campaigns/{campaign_id}/schedules/test`.
+    pub fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SendATestCampaignRequest> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/campaigns/{}/schedules/test",
+                crate::progenitor_support::encode_path(&campaign_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct CancelScheduledSends<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CancelScheduledSends<'a> {
+    /// Mock [`CancelScheduledSends::post_mail_batch`](crate::cancel_scheduled_sends::CancelScheduledSends::post_mail_batch), a `POST` to `/mail/batch`.
+    pub fn post_mail_batch(&self) -> crate::mock::MockOperation<'a, crate::types::MailBatchId> {
+        self.mock
+            .operation(reqwest::Method::POST, "/mail/batch".to_string())
+    }
+
+    /// Mock [`CancelScheduledSends::get_user_scheduled_sends`](crate::cancel_scheduled_sends::CancelScheduledSends::get_user_scheduled_sends), a `GET` to `/user/scheduled_sends`.
+    pub fn get_user_scheduled_sends(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/scheduled_sends".to_string())
+    }
+
+    /// Mock [`CancelScheduledSends::post_user_scheduled_send`](crate::cancel_scheduled_sends::CancelScheduledSends::post_user_scheduled_send), a `POST` to `/user/scheduled_sends`.
+    pub fn post_user_scheduled_send(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserScheduledSendStatusAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/user/scheduled_sends".to_string())
+    }
+
+    /// Mock [`CancelScheduledSends::get_mail_batch`](crate::cancel_scheduled_sends::CancelScheduledSends::get_mail_batch), a `GET` to `/mail/batch/{batch_id}`.
+    pub fn get_mail_batch(
+        &self,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailBatchId> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/mail/batch/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`CancelScheduledSends::get_user_scheduled_sends_batch`](crate::cancel_scheduled_sends::CancelScheduledSends::get_user_scheduled_sends_batch), a `GET` to `/user/scheduled_sends/{batch_id}`.
+    pub fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`CancelScheduledSends::delete_user_scheduled_sends_batch`](crate::cancel_scheduled_sends::CancelScheduledSends::delete_user_scheduled_sends_batch), a `DELETE` to `/user/scheduled_sends/{batch_id}`.
+    pub fn delete_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`CancelScheduledSends::patch_user_scheduled_sends_batch`](crate::cancel_scheduled_sends::CancelScheduledSends::patch_user_scheduled_sends_batch), a `PATCH` to `/user/scheduled_sends/{batch_id}`.
+    pub fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+}
+
+pub struct Categories<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Categories<'a> {
+    /// Mock [`Categories::get_page`](crate::categories::Categories::get_page), a `GET` to `/categories`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetCategoriesResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/categories".to_string())
+    }
+
+    /// Mock [`Categories::get_stats_sum`](crate::categories::Categories::get_stats_sum), a `GET` to `/categories/stats/sums`.
+    pub fn get_stats_sum(&self) -> crate::mock::MockOperation<'a, crate::types::CategoryStats> {
+        self.mock
+            .operation(reqwest::Method::GET, "/categories/stats/sums".to_string())
+    }
+
+    /// Mock [`Categories::get_stats`](crate::categories::Categories::get_stats), a `GET` to `/categories/stats`.
+    pub fn get_stats(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::CategoryStats>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/categories/stats".to_string())
+    }
+}
+
+pub struct Certificates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Certificates<'a> {
+    /// Mock [`Certificates::post_sso`](crate::certificates::Certificates::post_sso), a `POST` to `/sso/certificates`.
+    pub fn post_sso(&self) -> crate::mock::MockOperation<'a, crate::types::SsoCertificateBody> {
+        self.mock
+            .operation(reqwest::Method::POST, "/sso/certificates".to_string())
+    }
+
+    /// Mock [`Certificates::get_sso_integrations_integration`](crate::certificates::Certificates::get_sso_integrations_integration), a `GET` to `/sso/integrations/{integration_id}/certificates`.
+    pub fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SsoCertificateBody>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/sso/integrations/{}/certificates",
+                crate::progenitor_support::encode_path(integration_id)
+            ),
+        )
+    }
+
+    /// Mock [`Certificates::get_sso_cert`](crate::certificates::Certificates::get_sso_cert), a `GET` to `/sso/certificates/{cert_id}`.
+    pub fn get_sso_cert(
+        &self,
+        cert_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoCertificateBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/sso/certificates/{}",
+                crate::progenitor_support::encode_path(cert_id)
+            ),
+        )
+    }
+
+    /// Mock [`Certificates::delete_sso_cert`](crate::certificates::Certificates::delete_sso_cert), a `DELETE` to `/sso/certificates/{cert_id}`.
+    pub fn delete_sso_cert(
+        &self,
+        cert_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoCertificateBody> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/sso/certificates/{}",
+                crate::progenitor_support::encode_path(cert_id)
+            ),
+        )
+    }
+
+    /// Mock [`Certificates::patch_sso_cert`](crate::certificates::Certificates::patch_sso_cert), a `PATCH` to `/sso/certificates/{cert_id}`.
+    pub fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SsoErrorResponse>> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/sso/certificates/{}",
+                crate::progenitor_support::encode_path(cert_id)
+            ),
+        )
+    }
+}
+
+pub struct Contacts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Contacts<'a> {
+    /// Mock [`Contacts::get_mc_contats`](crate::contacts::Contacts::get_mc_contats), a `GET` to `/marketing/contacts`.
+    pub fn get_mc_contats(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMcContatsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/marketing/contacts".to_string())
+    }
+
+    /// Mock [`Contacts::put_mc`](crate::contacts::Contacts::put_mc), a `PUT` to `/marketing/contacts`.
+    pub fn put_mc(&self) -> crate::mock::MockOperation<'a, crate::types::PutMcContactsResponse> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/marketing/contacts".to_string())
+    }
+
+    /// Mock [`Contacts::delete_mc`](crate::contacts::Contacts::delete_mc), a `DELETE` to `/marketing/contacts`.
+    pub fn delete_mc(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteMcContactsResponse> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/marketing/contacts".to_string())
+    }
+
+    /// Mock [`Contacts::get_mc_count`](crate::contacts::Contacts::get_mc_count), a `GET` to `/marketing/contacts/count`.
+    pub fn get_mc_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMcContactsCountResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/contacts/count".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::get_marketing_exports`](crate::contacts::Contacts::get_marketing_exports), a `GET` to `/marketing/contacts/exports`.
+    pub fn get_marketing_exports(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMarketingContactsExportsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/contacts/exports".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::post_mc_export`](crate::contacts::Contacts::post_mc_export), a `POST` to `/marketing/contacts/exports`.
+    pub fn post_mc_export(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMcContactsExportsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/contacts/exports".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::get_mc`](crate::contacts::Contacts::get_mc), a `GET` to `/marketing/contacts/{id}`.
+    pub fn get_mc(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactDetails3> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/contacts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::post_mc_search`](crate::contacts::Contacts::post_mc_search), a `POST` to `/marketing/contacts/search`.
+    pub fn post_mc_search(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMcContactsSearchResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/contacts/search".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::put_mc_imports`](crate::contacts::Contacts::put_mc_imports), a `PUT` to `/marketing/contacts/imports`.
+    pub fn put_mc_imports(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PutMcContactsImportsResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            "/marketing/contacts/imports".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::get_marketing_import`](crate::contacts::Contacts::get_marketing_import), a `GET` to `/marketing/contacts/imports/{id}`.
+    pub fn get_marketing_import(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactImport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/contacts/imports/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::get_mc_export`](crate::contacts::Contacts::get_mc_export), a `GET` to `/marketing/contacts/exports/{id}`.
+    pub fn get_mc_export(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactExport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/contacts/exports/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Contacts::post_marketing_batch`](crate::contacts::Contacts::post_marketing_batch), a `POST` to `/marketing/contacts/batch`.
+    pub fn post_marketing_batch(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMarketingContactsBatchResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/contacts/batch".to_string(),
+        )
+    }
+
+    /// Mock [`Contacts::post_marketing_search_email`](crate::contacts::Contacts::post_marketing_search_email), a `POST` to `/marketing/contacts/search/emails`.
+    pub fn post_marketing_search_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMarketingContactsSearchEmailsResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/contacts/search/emails".to_string(),
+        )
+    }
+}
+
+pub struct ContactsApiCustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ContactsApiCustomFields<'a> {
+    /// Mock [`ContactsApiCustomFields::get_contactdb_custom_fields`](crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_custom_fields), a `GET` to `/contactdb/custom_fields`.
+    pub fn get_contactdb_custom_fields(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAllCustomFieldsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contactdb/custom_fields".to_string())
+    }
+
+    /// Mock [`ContactsApiCustomFields::post_contactdb_custom_field`](crate::contacts_api_custom_fields::ContactsApiCustomFields::post_contactdb_custom_field), a `POST` to `/contactdb/custom_fields`.
+    pub fn post_contactdb_custom_field(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbCustomFieldWithAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/contactdb/custom_fields".to_string(),
+        )
+    }
+
+    /// Mock [`ContactsApiCustomFields::get_contactdb_custom_fields_field`](crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_custom_fields_field), a `GET` to `/contactdb/custom_fields/{custom_field_id}`.
+    pub fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbCustomFieldWithAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/custom_fields/{}",
+                crate::progenitor_support::encode_path(&custom_field_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiCustomFields::delete_contactdb_custom_fields_field`](crate::contacts_api_custom_fields::ContactsApiCustomFields::delete_contactdb_custom_fields_field), a `DELETE` to `/contactdb/custom_fields/{custom_field_id}`.
+    pub fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GlobalErrorResponseSchema> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/contactdb/custom_fields/{}",
+                crate::progenitor_support::encode_path(&custom_field_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiCustomFields::get_contactdb_reserved_fields`](crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_reserved_fields), a `GET` to `/contactdb/reserved_fields`.
+    pub fn get_contactdb_reserved_fields(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetContactdbReservedFieldsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/contactdb/reserved_fields".to_string(),
+        )
+    }
+}
+
+pub struct ContactsApiLists<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ContactsApiLists<'a> {
+    /// Mock [`ContactsApiLists::get_contactdb_lists`](crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists), a `GET` to `/contactdb/lists`.
+    pub fn get_contactdb_lists(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAllListsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contactdb/lists".to_string())
+    }
+
+    /// Mock [`ContactsApiLists::post_contactdb_list`](crate::contacts_api_lists::ContactsApiLists::post_contactdb_list), a `POST` to `/contactdb/lists`.
+    pub fn post_contactdb_list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbList> {
+        self.mock
+            .operation(reqwest::Method::POST, "/contactdb/lists".to_string())
+    }
+
+    /// Mock [`ContactsApiLists::delete_contactdb_lists`](crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists), a `DELETE` to `/contactdb/lists`.
+    pub fn delete_contactdb_lists(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/contactdb/lists".to_string())
+    }
+
+    /// Mock [`ContactsApiLists::get_contactdb_lists_list`](crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists_list), a `GET` to `/contactdb/lists/{list_id}`.
+    pub fn get_contactdb_lists_list(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/lists/{}",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::delete_contactdb_lists_list`](crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists_list), a `DELETE` to `/contactdb/lists/{list_id}`.
+    pub fn delete_contactdb_lists_list(&self, list_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/contactdb/lists/{}",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::patch_contactdb_lists_list`](crate::contacts_api_lists::ContactsApiLists::patch_contactdb_lists_list), a `PATCH` to `/contactdb/lists/{list_id}`.
+    pub fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PatchContactdbListsListResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/contactdb/lists/{}",
+                crate::progenitor_support::encode_path(list_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::get_contactdb_lists_list_recipients`](crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists_list_recipients), a `GET` to `/contactdb/lists/{list_id}/recipients`.
+    pub fn get_contactdb_lists_list_recipients(
+        &self,
+        list_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetContactdbRecipientsSearchResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/lists/{}/recipients",
+                crate::progenitor_support::encode_path(&list_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::post_contactdb_lists_list_recipient`](crate::contacts_api_lists::ContactsApiLists::post_contactdb_lists_list_recipient), a `POST` to `/contactdb/lists/{list_id}/recipients`.
+    pub fn post_contactdb_lists_list_recipient(
+        &self,
+        list_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/contactdb/lists/{}/recipients",
+                crate::progenitor_support::encode_path(&list_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::post_contactdb_lists_list_recipients_recipient`](crate::contacts_api_lists::ContactsApiLists::post_contactdb_lists_list_recipients_recipient), a `POST` to `/contactdb/lists/{list_id}/recipients/{recipient_id}`.
+    pub fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/contactdb/lists/{}/recipients/{}",
+                crate::progenitor_support::encode_path(&list_id.to_string()),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiLists::delete_contactdb_lists_list_recipients_recipient`](crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists_list_recipients_recipient), a `DELETE` to `/contactdb/lists/{list_id}/recipients/{recipient_id}`.
+    pub fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/contactdb/lists/{}/recipients/{}",
+                crate::progenitor_support::encode_path(&list_id.to_string()),
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+}
+
+pub struct ContactsApiRecipients<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ContactsApiRecipients<'a> {
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients), a `GET` to `/contactdb/recipients`.
+    pub fn get_contactdb_recipients(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListRecipientsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contactdb/recipients".to_string())
+    }
+
+    /// Mock [`ContactsApiRecipients::post_contactdb_recipient`](crate::contacts_api_recipients::ContactsApiRecipients::post_contactdb_recipient), a `POST` to `/contactdb/recipients`.
+    pub fn post_contactdb_recipient(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactDbRecipientResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/contactdb/recipients".to_string())
+    }
+
+    /// Mock [`ContactsApiRecipients::delete_contactdb_recipients`](crate::contacts_api_recipients::ContactsApiRecipients::delete_contactdb_recipients), a `DELETE` to `/contactdb/recipients`.
+    pub fn delete_contactdb_recipients(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/contactdb/recipients".to_string())
+    }
+
+    /// Mock [`ContactsApiRecipients::patch_contactdb_recipients`](crate::contacts_api_recipients::ContactsApiRecipients::patch_contactdb_recipients), a `PATCH` to `/contactdb/recipients`.
+    pub fn patch_contactdb_recipients(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactDbRecipientResponse> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/contactdb/recipients".to_string())
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_statu`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_statu), a `GET` to `/contactdb/status`.
+    pub fn get_contactdb_statu(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetContactdbStatusResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contactdb/status".to_string())
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients_recipient`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_recipient), a `GET` to `/contactdb/recipients/{recipient_id}`.
+    pub fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbRecipient> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/recipients/{}",
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::delete_contactdb_recipients_recipient`](crate::contacts_api_recipients::ContactsApiRecipients::delete_contactdb_recipients_recipient), a `DELETE` to `/contactdb/recipients/{recipient_id}`.
+    pub fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/contactdb/recipients/{}",
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients_recipient_lists`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_recipient_lists), a `GET` to `/contactdb/recipients/{recipient_id}/lists`.
+    pub fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetContactdbRecipientsRecipientListsResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/recipients/{}/lists",
+                crate::progenitor_support::encode_path(recipient_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients_billable_count`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_billable_count), a `GET` to `/contactdb/recipients/billable_count`.
+    pub fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbRecipientCount> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/contactdb/recipients/billable_count".to_string(),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients_count`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_count), a `GET` to `/contactdb/recipients/count`.
+    pub fn get_contactdb_recipients_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbRecipientCount> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/contactdb/recipients/count".to_string(),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::get_contactdb_recipients_search`](crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_search), a `GET` to `/contactdb/recipients/search`.
+    pub fn get_contactdb_recipients_search(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetContactdbRecipientsSearchResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/contactdb/recipients/search".to_string(),
+        )
+    }
+
+    /// Mock [`ContactsApiRecipients::post_contactdb_recipients_search`](crate::contacts_api_recipients::ContactsApiRecipients::post_contactdb_recipients_search), a `POST` to `/contactdb/recipients/search`.
+    pub fn post_contactdb_recipients_search(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostContactdbRecipientsSearchResponseData>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/contactdb/recipients/search".to_string(),
+        )
+    }
+}
+
+pub struct ContactsApiSegments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ContactsApiSegments<'a> {
+    /// Mock [`ContactsApiSegments::get_contactdb_segments`](crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments), a `GET` to `/contactdb/segments`.
+    pub fn get_contactdb_segments(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAllSegmentsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contactdb/segments".to_string())
+    }
+
+    /// Mock [`ContactsApiSegments::post_contactdb_segment`](crate::contacts_api_segments::ContactsApiSegments::post_contactdb_segment), a `POST` to `/contactdb/segments`.
+    pub fn post_contactdb_segment(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbSegmentsWithAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/contactdb/segments".to_string())
+    }
+
+    /// Mock [`ContactsApiSegments::get_contactdb_segments_segment`](crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments_segment), a `GET` to `/contactdb/segments/{segment_id}`.
+    pub fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbSegments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiSegments::delete_contactdb_segments_segment`](crate::contacts_api_segments::ContactsApiSegments::delete_contactdb_segments_segment), a `DELETE` to `/contactdb/segments/{segment_id}`.
+    pub fn delete_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/contactdb/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiSegments::patch_contactdb_segments_segment`](crate::contacts_api_segments::ContactsApiSegments::patch_contactdb_segments_segment), a `PATCH` to `/contactdb/segments/{segment_id}`.
+    pub fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ContactdbSegments> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/contactdb/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ContactsApiSegments::get_contactdb_segments_segment_recipients`](crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments_segment_recipients), a `GET` to `/contactdb/segments/{segment_id}/recipients`.
+    pub fn get_contactdb_segments_segment_recipients(
+        &self,
+        segment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListRecipientsOnASegmentResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/contactdb/segments/{}/recipients",
+                crate::progenitor_support::encode_path(&segment_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct CsvUiOnly<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CsvUiOnly<'a> {
+    /// Mock [`CsvUiOnly::post_messages_download`](crate::csv_ui_only::CsvUiOnly::post_messages_download), a `POST` to `/messages/download`.
+    pub fn post_messages_download(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMessagesDownloadResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/messages/download".to_string())
+    }
+
+    /// Mock [`CsvUiOnly::get_messages_download`](crate::csv_ui_only::CsvUiOnly::get_messages_download), a `GET` to `/messages/download/{download_uuid}`.
+    pub fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMessagesDownloadResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/messages/download/{}",
+                crate::progenitor_support::encode_path(download_uuid)
+            ),
+        )
+    }
+}
+
+pub struct CustomFields<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CustomFields<'a> {
+    /// Mock [`CustomFields::get_mc_field_definitions`](crate::custom_fields::CustomFields::get_mc_field_definitions), a `GET` to `/marketing/field_definitions`.
+    pub fn get_mc_field_definitions(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMcFieldDefinitionsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/field_definitions".to_string(),
+        )
+    }
+
+    /// Mock [`CustomFields::post_mc_field_definition`](crate::custom_fields::CustomFields::post_mc_field_definition), a `POST` to `/marketing/field_definitions`.
+    pub fn post_mc_field_definition(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/field_definitions".to_string(),
+        )
+    }
+
+    /// Mock [`CustomFields::delete_mc_field_definitions_custom`](crate::custom_fields::CustomFields::delete_mc_field_definitions_custom), a `DELETE` to `/marketing/field_definitions/{custom_field_id}`.
+    pub fn delete_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/field_definitions/{}",
+                crate::progenitor_support::encode_path(custom_field_id)
+            ),
+        )
+    }
+
+    /// Mock [`CustomFields::patch_mc_field_definitions_custom`](crate::custom_fields::CustomFields::patch_mc_field_definitions_custom), a `PATCH` to `/marketing/field_definitions/{custom_field_id}`.
+    pub fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/marketing/field_definitions/{}",
+                crate::progenitor_support::encode_path(custom_field_id)
+            ),
+        )
+    }
+}
+
+pub struct DesignsApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DesignsApi<'a> {
+    /// Mock [`DesignsApi::get_design`](crate::designs_api::DesignsApi::get_design), a `GET` to `/designs/{id}`.
+    pub fn get_design(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/designs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`DesignsApi::post_duplicate_design`](crate::designs_api::DesignsApi::post_duplicate_design), a `POST` to `/designs/{id}`.
+    pub fn post_duplicate_design(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/designs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`DesignsApi::delete_design`](crate::designs_api::DesignsApi::delete_design), a `DELETE` to `/designs/{id}`.
+    pub fn delete_design(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/designs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`DesignsApi::put_design`](crate::designs_api::DesignsApi::put_design), a `PATCH` to `/designs/{id}`.
+    pub fn put_design(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/designs/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`DesignsApi::list_designs`](crate::designs_api::DesignsApi::list_designs), a `GET` to `/designs`.
+    pub fn list_designs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListDesignsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/designs".to_string())
+    }
+
+    /// Mock [`DesignsApi::post_design`](crate::designs_api::DesignsApi::post_design), a `POST` to `/designs`.
+    pub fn post_design(&self) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/designs".to_string())
+    }
+
+    /// Mock [`DesignsApi::get_sendgrid_pre_built_design`](crate::designs_api::DesignsApi::get_sendgrid_pre_built_design), a `GET` to `/designs/pre-builts/{id}`.
+    pub fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/designs/pre-builts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`DesignsApi::post_sendgrid_pre_built_design`](crate::designs_api::DesignsApi::post_sendgrid_pre_built_design), a `POST` to `/designs/pre-builts/{id}`.
+    pub fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DesignOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/designs/pre-builts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`DesignsApi::list_sendgrid_pre_built_designs`](crate::designs_api::DesignsApi::list_sendgrid_pre_built_designs), a `GET` to `/designs/pre-builts`.
+    pub fn list_sendgrid_pre_built_designs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListDesignsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/designs/pre-builts".to_string())
+    }
+}
+
+pub struct DomainAuthentication<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DomainAuthentication<'a> {
+    /// Mock [`DomainAuthentication::get_whitelabel_domains`](crate::domain_authentication::DomainAuthentication::get_whitelabel_domains), a `GET` to `/whitelabel/domains`.
+    pub fn get_whitelabel_domains(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DomainAuthentication200ResponseAllOf>>
+    {
+        self.mock
+            .operation(reqwest::Method::GET, "/whitelabel/domains".to_string())
+    }
+
+    /// Mock [`DomainAuthentication::post_whitelabel_domain`](crate::domain_authentication::DomainAuthentication::post_whitelabel_domain), a `POST` to `/whitelabel/domains`.
+    pub fn post_whitelabel_domain(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationDomain> {
+        self.mock
+            .operation(reqwest::Method::POST, "/whitelabel/domains".to_string())
+    }
+
+    /// Mock [`DomainAuthentication::get_whitelabel_domains_domain`](crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_domain), a `GET` to `/whitelabel/domains/{domain_id}`.
+    pub fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AuthenticationDomain> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/whitelabel/domains/{}",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::delete_whitelabel_domains_domain`](crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_domain), a `DELETE` to `/whitelabel/domains/{domain_id}`.
+    pub fn delete_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/whitelabel/domains/{}",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::patch_whitelabel_domains_domain`](crate::domain_authentication::DomainAuthentication::patch_whitelabel_domains_domain), a `PATCH` to `/whitelabel/domains/{domain_id}`.
+    pub fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DomainAuthentication200ResponseAllOf>>
+    {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/whitelabel/domains/{}",
+                crate::progenitor_support::encode_path(domain_id)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::get_whitelabel_domains_default`](crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_default), a `GET` to `/whitelabel/domains/default`.
+    pub fn get_whitelabel_domains_default(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::DomainAuthentication200ResponseAllOf>>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/whitelabel/domains/default".to_string(),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::post_whitelabel_domains_ip`](crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_ip), a `POST` to `/whitelabel/domains/{id}/ips`.
+    pub fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAuthentication> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/domains/{}/ips",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::delete_whitelabel_domains_ips_ip`](crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_ips_ip), a `DELETE` to `/whitelabel/domains/{id}/ips/{ip}`.
+    pub fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAuthentication> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/whitelabel/domains/{}/ips/{}",
+                crate::progenitor_support::encode_path(&id.to_string()),
+                crate::progenitor_support::encode_path(ip)
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::post_whitelabel_domains_validate`](crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_validate), a `POST` to `/whitelabel/domains/{id}/validate`.
+    pub fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostWhitelabelDomainsValidateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/domains/{}/validate",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::get_whitelabel_domains_subuser`](crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_subuser), a `GET` to `/whitelabel/domains/subuser`.
+    pub fn get_whitelabel_domains_subuser(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAuthentication> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/whitelabel/domains/subuser".to_string(),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::delete_whitelabel_domains_subuser`](crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_subuser), a `DELETE` to `/whitelabel/domains/subuser`.
+    pub fn delete_whitelabel_domains_subuser(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/whitelabel/domains/subuser".to_string(),
+        )
+    }
+
+    /// Mock [`DomainAuthentication::post_whitelabel_domains_domain_subuser`](crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_domain_subuser), a `POST` to `/whitelabel/domains/{domain_id}/subuser`.
+    pub fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainAuthentication> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/domains/{}/subuser",
+                crate::progenitor_support::encode_path(&domain_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct EmailAddressValidation<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EmailAddressValidation<'a> {
+    /// Mock [`EmailAddressValidation::post_validations_email`](crate::email_address_validation::EmailAddressValidation::post_validations_email), a `POST` to `/validations/email`.
+    pub fn post_validations_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostValidationsEmailResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/validations/email".to_string())
+    }
+}
+
+pub struct EmailCnameRecords<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EmailCnameRecords<'a> {
+    /// Mock [`EmailCnameRecords::post_whitelabel_dns_email`](crate::email_cname_records::EmailCnameRecords::post_whitelabel_dns_email), a `POST` to `/whitelabel/dns/email`.
+    pub fn post_whitelabel_dns_email(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/whitelabel/dns/email".to_string())
+    }
+}
+
+pub struct InvalidEmailsApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> InvalidEmailsApi<'a> {
+    /// Mock [`InvalidEmailsApi::get_suppression_invalid_emails`](crate::invalid_emails_api::InvalidEmailsApi::get_suppression_invalid_emails), a `GET` to `/suppression/invalid_emails`.
+    pub fn get_suppression_invalid_emails(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::InvalidEmail>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/suppression/invalid_emails".to_string(),
+        )
+    }
+
+    /// Mock [`InvalidEmailsApi::delete_suppression_invalid_emails`](crate::invalid_emails_api::InvalidEmailsApi::delete_suppression_invalid_emails), a `DELETE` to `/suppression/invalid_emails`.
+    pub fn delete_suppression_invalid_emails(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/suppression/invalid_emails".to_string(),
+        )
+    }
+
+    /// Mock [`InvalidEmailsApi::get_suppression_invalid_emails_email`](crate::invalid_emails_api::InvalidEmailsApi::get_suppression_invalid_emails_email), a `GET` to `/suppression/invalid_emails/{email}`.
+    pub fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::InvalidEmail>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/suppression/invalid_emails/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`InvalidEmailsApi::delete_suppression_invalid_emails_email`](crate::invalid_emails_api::InvalidEmailsApi::delete_suppression_invalid_emails_email), a `DELETE` to `/suppression/invalid_emails/{email}`.
+    pub fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/suppression/invalid_emails/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct IpAccessManagement<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IpAccessManagement<'a> {
+    /// Mock [`IpAccessManagement::get_access_settings_whitelist`](crate::ip_access_management::IpAccessManagement::get_access_settings_whitelist), a `GET` to `/access_settings/whitelist`.
+    pub fn get_access_settings_whitelist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::IpAccessResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/access_settings/whitelist".to_string(),
+        )
+    }
+
+    /// Mock [`IpAccessManagement::post_access_settings_whitelist`](crate::ip_access_management::IpAccessManagement::post_access_settings_whitelist), a `POST` to `/access_settings/whitelist`.
+    pub fn post_access_settings_whitelist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::IpAccessResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/access_settings/whitelist".to_string(),
+        )
+    }
+
+    /// Mock [`IpAccessManagement::delete_access_settings_whitelist`](crate::ip_access_management::IpAccessManagement::delete_access_settings_whitelist), a `DELETE` to `/access_settings/whitelist`.
+    pub fn delete_access_settings_whitelist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/access_settings/whitelist".to_string(),
+        )
+    }
+
+    /// Mock [`IpAccessManagement::get_access_settings_activity`](crate::ip_access_management::IpAccessManagement::get_access_settings_activity), a `GET` to `/access_settings/activity`.
+    pub fn get_access_settings_activity(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccessSettingsActivityResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/access_settings/activity".to_string(),
+        )
+    }
+
+    /// Mock [`IpAccessManagement::get_access_settings_whitelist_rule`](crate::ip_access_management::IpAccessManagement::get_access_settings_whitelist_rule), a `GET` to `/access_settings/whitelist/{rule_id}`.
+    pub fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IpAccessResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/access_settings/whitelist/{}",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`IpAccessManagement::delete_access_settings_whitelist_rule`](crate::ip_access_management::IpAccessManagement::delete_access_settings_whitelist_rule), a `DELETE` to `/access_settings/whitelist/{rule_id}`.
+    pub fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/access_settings/whitelist/{}",
+                crate::progenitor_support::encode_path(rule_id)
+            ),
+        )
+    }
+}
+
+pub struct IpAddresses<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IpAddresses<'a> {
+    /// Mock [`IpAddresses::get_ips`](crate::ip_addresses::IpAddresses::get_ips), a `GET` to `/ips`.
+    pub fn get_ips(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::GetIpsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ips".to_string())
+    }
+
+    /// Mock [`IpAddresses::post_ip`](crate::ip_addresses::IpAddresses::post_ip), a `POST` to `/ips`.
+    pub fn post_ip(&self) -> crate::mock::MockOperation<'a, crate::types::PostIpsResponseData> {
+        self.mock
+            .operation(reqwest::Method::POST, "/ips".to_string())
+    }
+
+    /// Mock [`IpAddresses::get_ips_remaining`](crate::ip_addresses::IpAddresses::get_ips_remaining), a `GET` to `/ips/remaining`.
+    pub fn get_ips_remaining(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIpsRemainingResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ips/remaining".to_string())
+    }
+
+    /// Mock [`IpAddresses::get_ips_assigned`](crate::ip_addresses::IpAddresses::get_ips_assigned), a `GET` to `/ips/assigned`.
+    pub fn get_ips_assigned(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetIpsAssignedResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ips/assigned".to_string())
+    }
+
+    /// Mock [`IpAddresses::get_ips_ip_address`](crate::ip_addresses::IpAddresses::get_ips_ip_address), a `GET` to `/ips/{ip_address}`.
+    pub fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIpsIpAddressResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ips/{}",
+                crate::progenitor_support::encode_path(ip_address)
+            ),
+        )
+    }
+}
+
+pub struct IpPools<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IpPools<'a> {
+    /// Mock [`IpPools::get_ips_pools`](crate::ip_pools::IpPools::get_ips_pools), a `GET` to `/ips/pools`.
+    pub fn get_ips_pools(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IpPoolsPoolResp>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ips/pools".to_string())
+    }
+
+    /// Mock [`IpPools::post_ips_pool`](crate::ip_pools::IpPools::post_ips_pool), a `POST` to `/ips/pools`.
+    pub fn post_ips_pool(&self) -> crate::mock::MockOperation<'a, crate::types::IpPoolsPoolResp> {
+        self.mock
+            .operation(reqwest::Method::POST, "/ips/pools".to_string())
+    }
+
+    /// Mock [`IpPools::post_ips_pools_pool_name_ip`](crate::ip_pools::IpPools::post_ips_pools_pool_name_ip), a `POST` to `/ips/pools/{pool_name}/ips`.
+    pub fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIpsAssignedResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/ips/pools/{}/ips",
+                crate::progenitor_support::encode_path(pool_name)
+            ),
+        )
+    }
+
+    /// Mock [`IpPools::get_ips_pools_pool_name`](crate::ip_pools::IpPools::get_ips_pools_pool_name), a `GET` to `/ips/pools/{pool_name}`.
+    pub fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIpsPoolsPoolNameResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ips/pools/{}",
+                crate::progenitor_support::encode_path(pool_name)
+            ),
+        )
+    }
+
+    /// Mock [`IpPools::put_ips_pools_pool_name`](crate::ip_pools::IpPools::put_ips_pools_pool_name), a `PUT` to `/ips/pools/{pool_name}`.
+    pub fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IpPoolsPoolResp> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/ips/pools/{}",
+                crate::progenitor_support::encode_path(pool_name)
+            ),
+        )
+    }
+
+    /// Mock [`IpPools::delete_ips_pools_pool_name`](crate::ip_pools::IpPools::delete_ips_pools_pool_name), a `DELETE` to `/ips/pools/{pool_name}`.
+    pub fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ips/pools/{}",
+                crate::progenitor_support::encode_path(pool_name)
+            ),
+        )
+    }
+
+    /// Mock [`IpPools::delete_ips_pools_pool_name_ip`](crate::ip_pools::IpPools::delete_ips_pools_pool_name_ip), a `DELETE` to `/ips/pools/{pool_name}/ips/{ip}`.
+    pub fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ips/pools/{}/ips/{}",
+                crate::progenitor_support::encode_path(pool_name),
+                crate::progenitor_support::encode_path(ip)
+            ),
+        )
+    }
+}
+
+pub struct IpWarmup<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IpWarmup<'a> {
+    /// Mock [`IpWarmup::get_ips_warmup`](crate::ip_warmup::IpWarmup::get_ips_warmup), a `GET` to `/ips/warmup`.
+    pub fn get_ips_warmup(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IpWarmupResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/ips/warmup".to_string())
+    }
+
+    /// Mock [`IpWarmup::post_ips_warmup`](crate::ip_warmup::IpWarmup::post_ips_warmup), a `POST` to `/ips/warmup`.
+    pub fn post_ips_warmup(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IpWarmupResponse>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/ips/warmup".to_string())
+    }
+
+    /// Mock [`IpWarmup::get_ips_warmup_ip_address`](crate::ip_warmup::IpWarmup::get_ips_warmup_ip_address), a `GET` to `/ips/warmup/{ip_address}`.
+    pub fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IpWarmupResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/ips/warmup/{}",
+                crate::progenitor_support::encode_path(ip_address)
+            ),
+        )
+    }
+
+    /// Mock [`IpWarmup::delete_ips_warmup_ip_address`](crate::ip_warmup::IpWarmup::delete_ips_warmup_ip_address), a `DELETE` to `/ips/warmup/{ip_address}`.
+    pub fn delete_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/ips/warmup/{}",
+                crate::progenitor_support::encode_path(ip_address)
+            ),
+        )
+    }
+}
+
+pub struct LinkBranding<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> LinkBranding<'a> {
+    /// Mock [`LinkBranding::get_whitelabel_links`](crate::link_branding::LinkBranding::get_whitelabel_links), a `GET` to `/whitelabel/links`.
+    pub fn get_whitelabel_links(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::LinkBranding200Response>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/whitelabel/links".to_string())
+    }
+
+    /// Mock [`LinkBranding::post_whitelabel_link`](crate::link_branding::LinkBranding::post_whitelabel_link), a `POST` to `/whitelabel/links`.
+    pub fn post_whitelabel_link(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock
+            .operation(reqwest::Method::POST, "/whitelabel/links".to_string())
+    }
+
+    /// Mock [`LinkBranding::post_whitelabel_links_validate`](crate::link_branding::LinkBranding::post_whitelabel_links_validate), a `POST` to `/whitelabel/links/{id}/validate`.
+    pub fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostWhitelabelLinksValidateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/links/{}/validate",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`LinkBranding::post_whitelabel_links_link_subuser`](crate::link_branding::LinkBranding::post_whitelabel_links_link_subuser), a `POST` to `/whitelabel/links/{link_id}/subuser`.
+    pub fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/links/{}/subuser",
+                crate::progenitor_support::encode_path(&link_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`LinkBranding::get_whitelabel_links_link_branding`](crate::link_branding::LinkBranding::get_whitelabel_links_link_branding), a `GET` to `/whitelabel/links/{id}`.
+    pub fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/whitelabel/links/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`LinkBranding::delete_whitelabel_links`](crate::link_branding::LinkBranding::delete_whitelabel_links), a `DELETE` to `/whitelabel/links/{id}`.
+    pub fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/whitelabel/links/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`LinkBranding::patch_whitelabel_links`](crate::link_branding::LinkBranding::patch_whitelabel_links), a `PATCH` to `/whitelabel/links/{id}`.
+    pub fn patch_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/whitelabel/links/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`LinkBranding::get_whitelabel_links_default`](crate::link_branding::LinkBranding::get_whitelabel_links_default), a `GET` to `/whitelabel/links/default`.
+    pub fn get_whitelabel_links_default(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/whitelabel/links/default".to_string(),
+        )
+    }
+
+    /// Mock [`LinkBranding::get_whitelabel_links_subuser`](crate::link_branding::LinkBranding::get_whitelabel_links_subuser), a `GET` to `/whitelabel/links/subuser`.
+    pub fn get_whitelabel_links_subuser(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::LinkBranding200Response> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/whitelabel/links/subuser".to_string(),
+        )
+    }
+
+    /// Mock [`LinkBranding::delete_whitelabel_links_subuser`](crate::link_branding::LinkBranding::delete_whitelabel_links_subuser), a `DELETE` to `/whitelabel/links/subuser`.
+    pub fn delete_whitelabel_links_subuser(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/whitelabel/links/subuser".to_string(),
+        )
+    }
+}
+
+pub struct Lists<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Lists<'a> {
+    /// Mock [`Lists::get_mc`](crate::lists::Lists::get_mc), a `GET` to `/marketing/lists`.
+    pub fn get_mc(&self) -> crate::mock::MockOperation<'a, crate::types::GetMcListsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/marketing/lists".to_string())
+    }
+
+    /// Mock [`Lists::post_mc`](crate::lists::Lists::post_mc), a `POST` to `/marketing/lists`.
+    pub fn post_mc(&self) -> crate::mock::MockOperation<'a, crate::types::List> {
+        self.mock
+            .operation(reqwest::Method::POST, "/marketing/lists".to_string())
+    }
+
+    /// Mock [`Lists::get_mc_contacts_count`](crate::lists::Lists::get_mc_contacts_count), a `GET` to `/marketing/lists/{id}/contacts/count`.
+    pub fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMcListsContactsCountResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/lists/{}/contacts/count",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::get_mc_lists`](crate::lists::Lists::get_mc_lists), a `GET` to `/marketing/lists/{id}`.
+    pub fn get_mc_lists(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMcListsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/lists/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete`](crate::lists::Lists::delete), a `DELETE` to `/marketing/lists/{id}`.
+    pub fn delete(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteListsResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/lists/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::patch_mc`](crate::lists::Lists::patch_mc), a `PATCH` to `/marketing/lists/{id}`.
+    pub fn patch_mc(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::List> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/marketing/lists/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Lists::delete_mc_contacts`](crate::lists::Lists::delete_mc_contacts), a `DELETE` to `/marketing/lists/{id}/contacts`.
+    pub fn delete_mc_contacts(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteMcListsContactsResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/lists/{}/contacts",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct MailSend<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> MailSend<'a> {
+    /// Mock [`MailSend::post`](crate::mail_send::MailSend::post), a `POST` to `/mail/send`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/mail/send".to_string())
+    }
+}
+
+pub struct MarketingCampaignsStats<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> MarketingCampaignsStats<'a> {
+    /// Mock [`MarketingCampaignsStats::getall_automation_stats`](crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_automation_stats), a `GET` to `/marketing/stats/automations`.
+    pub fn getall_automation_stats(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AutomationsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/stats/automations".to_string(),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_automation_stat`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_stat), a `GET` to `/marketing/stats/automations/{id}`.
+    pub fn get_automation_stat(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AutomationsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/stats/automations/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::getall_singlesend_stats`](crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_singlesend_stats), a `GET` to `/marketing/stats/singlesends`.
+    pub fn getall_singlesend_stats(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/stats/singlesends".to_string(),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_singlesend_stat`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stat), a `GET` to `/marketing/stats/singlesends/{id}`.
+    pub fn get_singlesend_stat(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/stats/singlesends/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_automation_link_stat`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_link_stat), a `GET` to `/marketing/stats/automations/{id}/links`.
+    pub fn get_automation_link_stat(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AutomationsLinkStatsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/stats/automations/{}/links",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_singlesend_link_stat`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_link_stat), a `GET` to `/marketing/stats/singlesends/{id}/links`.
+    pub fn get_singlesend_link_stat(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendsLinkStatsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/stats/singlesends/{}/links",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_singlesend_stats_export`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stats_export), a `GET` to `/marketing/stats/singlesends/export`.
+    pub fn get_singlesend_stats_export(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/stats/singlesends/export".to_string(),
+        )
+    }
+
+    /// Mock [`MarketingCampaignsStats::get_automations_stats_export`](crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automations_stats_export), a `GET` to `/marketing/stats/automations/export`.
+    pub fn get_automations_stats_export(&self) -> crate::mock::MockOperation<'a, String> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/stats/automations/export".to_string(),
+        )
+    }
+}
+
+pub struct Query<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Query<'a> {
+    /// Mock [`Query::get_messages`](crate::query::Query::get_messages), a `GET` to `/messages`.
+    pub fn get_messages(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMessagesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/messages".to_string())
+    }
+
+    /// Mock [`Query::get_messages_msg`](crate::query::Query::get_messages_msg), a `GET` to `/messages/{msg_id}`.
+    pub fn get_messages_msg(
+        &self,
+        msg_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Message> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/messages/{}",
+                crate::progenitor_support::encode_path(msg_id)
+            ),
+        )
+    }
+}
+
+pub struct ReverseDns<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ReverseDns<'a> {
+    /// Mock [`ReverseDns::get_whitelabel_ips`](crate::reverse_dns::ReverseDns::get_whitelabel_ips), a `GET` to `/whitelabel/ips`.
+    pub fn get_whitelabel_ips(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ReverseDns>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/whitelabel/ips".to_string())
+    }
+
+    /// Mock [`ReverseDns::post_whitelabel_ip`](crate::reverse_dns::ReverseDns::post_whitelabel_ip), a `POST` to `/whitelabel/ips`.
+    pub fn post_whitelabel_ip(&self) -> crate::mock::MockOperation<'a, crate::types::ReverseDns> {
+        self.mock
+            .operation(reqwest::Method::POST, "/whitelabel/ips".to_string())
+    }
+
+    /// Mock [`ReverseDns::post_whitelabel_ips_validate`](crate::reverse_dns::ReverseDns::post_whitelabel_ips_validate), a `POST` to `/whitelabel/ips/{id}/validate`.
+    pub fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostWhitelabelIpsValidateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/whitelabel/ips/{}/validate",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ReverseDns::get_whitelabel_ip`](crate::reverse_dns::ReverseDns::get_whitelabel_ip), a `GET` to `/whitelabel/ips/{id}`.
+    pub fn get_whitelabel_ip(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReverseDns> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/whitelabel/ips/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ReverseDns::delete_whitelabel_ips`](crate::reverse_dns::ReverseDns::delete_whitelabel_ips), a `DELETE` to `/whitelabel/ips/{id}`.
+    pub fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/whitelabel/ips/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct SegmentingContacts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SegmentingContacts<'a> {
+    /// Mock [`SegmentingContacts::get_marketing_segments`](crate::segmenting_contacts::SegmentingContacts::get_marketing_segments), a `GET` to `/marketing/segments`.
+    pub fn get_marketing_segments(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMarketingSegmentsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/marketing/segments".to_string())
+    }
+
+    /// Mock [`SegmentingContacts::post_marketing_segment`](crate::segmenting_contacts::SegmentingContacts::post_marketing_segment), a `POST` to `/marketing/segments`.
+    pub fn post_marketing_segment(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullSegmentAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/marketing/segments".to_string())
+    }
+
+    /// Mock [`SegmentingContacts::get_marketing_segments_segment`](crate::segmenting_contacts::SegmentingContacts::get_marketing_segments_segment), a `GET` to `/marketing/segments/{segment_id}`.
+    pub fn get_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullSegmentAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SegmentingContacts::delete_marketing_segments_segment`](crate::segmenting_contacts::SegmentingContacts::delete_marketing_segments_segment), a `DELETE` to `/marketing/segments/{segment_id}`.
+    pub fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SegmentingContacts::patch_marketing_segments_segment`](crate::segmenting_contacts::SegmentingContacts::patch_marketing_segments_segment), a `PATCH` to `/marketing/segments/{segment_id}`.
+    pub fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FullSegmentAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/marketing/segments/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SegmentingContacts::post_marketing_segments_delete`](crate::segmenting_contacts::SegmentingContacts::post_marketing_segments_delete), a `POST` to `/marketing/segments/delete`.
+    pub fn post_marketing_segments_delete(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostMarketingSegmentsDeleteResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/segments/delete".to_string(),
+        )
+    }
+}
+
+pub struct SegmentingContactsBeta<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SegmentingContactsBeta<'a> {
+    /// Mock [`SegmentingContactsBeta::get_segments`](crate::segmenting_contacts_v_2_beta::SegmentingContactsBeta::get_segments), a `GET` to `/marketing/segments/2.0`.
+    pub fn get_segments(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AllSegmentsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/marketing/segments/2.0".to_string())
+    }
+
+    /// Mock [`SegmentingContactsBeta::post_segment`](crate::segmenting_contacts_v_2_beta::SegmentingContactsBeta::post_segment), a `POST` to `/marketing/segments/2.0`.
+    pub fn post_segment(&self) -> crate::mock::MockOperation<'a, crate::types::SegmentResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/marketing/segments/2.0".to_string())
+    }
+
+    /// Mock [`SegmentingContactsBeta::get_segments_segment`](crate::segmenting_contacts_v_2_beta::SegmentingContactsBeta::get_segments_segment), a `GET` to `/marketing/segments/2.0/{segment_id}`.
+    pub fn get_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SegmentResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/segments/2.0/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SegmentingContactsBeta::delete_segments_segment`](crate::segmenting_contacts_v_2_beta::SegmentingContactsBeta::delete_segments_segment), a `DELETE` to `/marketing/segments/2.0/{segment_id}`.
+    pub fn delete_segments_segment(&self, segment_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/segments/2.0/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SegmentingContactsBeta::patch_segments_segment`](crate::segmenting_contacts_v_2_beta::SegmentingContactsBeta::patch_segments_segment), a `PATCH` to `/marketing/segments/2.0/{segment_id}`.
+    pub fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SegmentResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/marketing/segments/2.0/{}",
+                crate::progenitor_support::encode_path(segment_id)
+            ),
+        )
+    }
+}
+
+pub struct SendTestEmail<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SendTestEmail<'a> {
+    /// Mock [`SendTestEmail::post_marketing_test_send_email`](crate::send_test_email::SendTestEmail::post_marketing_test_send_email), a `POST` to `/marketing/test/send_email`.
+    pub fn post_marketing_test_send_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/test/send_email".to_string(),
+        )
+    }
+}
+
+pub struct SenderIdentitiesApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SenderIdentitiesApi<'a> {
+    /// Mock [`SenderIdentitiesApi::get_senders`](crate::sender_identities_api::SenderIdentitiesApi::get_senders), a `GET` to `/senders`.
+    pub fn get_senders(&self) -> crate::mock::MockOperation<'a, crate::types::GetSendersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/senders".to_string())
+    }
+
+    /// Mock [`SenderIdentitiesApi::post_sender`](crate::sender_identities_api::SenderIdentitiesApi::post_sender), a `POST` to `/senders`.
+    pub fn post_sender(&self) -> crate::mock::MockOperation<'a, crate::types::SenderAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/senders".to_string())
+    }
+
+    /// Mock [`SenderIdentitiesApi::get_senders_sender`](crate::sender_identities_api::SenderIdentitiesApi::get_senders_sender), a `GET` to `/senders/{sender_id}`.
+    pub fn get_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SenderAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/senders/{}",
+                crate::progenitor_support::encode_path(&sender_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`SenderIdentitiesApi::delete_senders_sender`](crate::sender_identities_api::SenderIdentitiesApi::delete_senders_sender), a `DELETE` to `/senders/{sender_id}`.
+    pub fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/senders/{}",
+                crate::progenitor_support::encode_path(&sender_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`SenderIdentitiesApi::patch_senders_sender`](crate::sender_identities_api::SenderIdentitiesApi::patch_senders_sender), a `PATCH` to `/senders/{sender_id}`.
+    pub fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::SenderAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/senders/{}",
+                crate::progenitor_support::encode_path(&sender_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`SenderIdentitiesApi::post_senders_sender_resend_verification`](crate::sender_identities_api::SenderIdentitiesApi::post_senders_sender_resend_verification), a `POST` to `/senders/{sender_id}/resend_verification`.
+    pub fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/senders/{}/resend_verification",
+                crate::progenitor_support::encode_path(&sender_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct SenderVerification<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SenderVerification<'a> {
+    /// Mock [`SenderVerification::get_verified_senders_domains`](crate::sender_verification::SenderVerification::get_verified_senders_domains), a `GET` to `/verified_senders/domains`.
+    pub fn get_verified_senders_domains(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetVerifiedSendersDomainsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/verified_senders/domains".to_string(),
+        )
+    }
+
+    /// Mock [`SenderVerification::get_verified_senders_steps_completed`](crate::sender_verification::SenderVerification::get_verified_senders_steps_completed), a `GET` to `/verified_senders/steps_completed`.
+    pub fn get_verified_senders_steps_completed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetVerifiedSendersStepsCompletedResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/verified_senders/steps_completed".to_string(),
+        )
+    }
+
+    /// Mock [`SenderVerification::get_verified_senders`](crate::sender_verification::SenderVerification::get_verified_senders), a `GET` to `/verified_senders`.
+    pub fn get_verified_senders(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetVerifiedSendersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/verified_senders".to_string())
+    }
+
+    /// Mock [`SenderVerification::post_verified_sender`](crate::sender_verification::SenderVerification::post_verified_sender), a `POST` to `/verified_senders`.
+    pub fn post_verified_sender(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifiedSenderResponseSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/verified_senders".to_string())
+    }
+
+    /// Mock [`SenderVerification::get_verified_senders_verify_token`](crate::sender_verification::SenderVerification::get_verified_senders_verify_token), a `GET` to `/verified_senders/verify/{token}`.
+    pub fn get_verified_senders_verify_token(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/verified_senders/verify/{}",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SenderVerification::delete_verified_senders`](crate::sender_verification::SenderVerification::delete_verified_senders), a `DELETE` to `/verified_senders/{id}`.
+    pub fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/verified_senders/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SenderVerification::patch_verified_senders`](crate::sender_verification::SenderVerification::patch_verified_senders), a `PATCH` to `/verified_senders/{id}`.
+    pub fn patch_verified_senders(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::VerifiedSenderResponseSchema> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/verified_senders/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SenderVerification::post_verified_senders_resend`](crate::sender_verification::SenderVerification::post_verified_senders_resend), a `POST` to `/verified_senders/resend/{id}`.
+    pub fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/verified_senders/resend/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Senders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Senders<'a> {
+    /// Mock [`Senders::post_marketing`](crate::senders::Senders::post_marketing), a `POST` to `/marketing/senders`.
+    pub fn post_marketing(&self) -> crate::mock::MockOperation<'a, crate::types::SenderAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/marketing/senders".to_string())
+    }
+}
+
+pub struct SettingsEnforcedTls<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SettingsEnforcedTls<'a> {
+    /// Mock [`SettingsEnforcedTls::get_user`](crate::settings_enforced_tls::SettingsEnforcedTls::get_user), a `GET` to `/user/settings/enforced_tls`.
+    pub fn get_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforcedTlsRequestResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/settings/enforced_tls".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsEnforcedTls::patch_user`](crate::settings_enforced_tls::SettingsEnforcedTls::patch_user), a `PATCH` to `/user/settings/enforced_tls`.
+    pub fn patch_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::EnforcedTlsRequestResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/user/settings/enforced_tls".to_string(),
+        )
+    }
+}
+
+pub struct SettingsInboundParse<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SettingsInboundParse<'a> {
+    /// Mock [`SettingsInboundParse::post_user_webhooks_parse_setting`](crate::settings_inbound_parse::SettingsInboundParse::post_user_webhooks_parse_setting), a `POST` to `/user/webhooks/parse/settings`.
+    pub fn post_user_webhooks_parse_setting(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParseSetting> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/user/webhooks/parse/settings".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsInboundParse::get_user_webhooks_parse_settings_hostname`](crate::settings_inbound_parse::SettingsInboundParse::get_user_webhooks_parse_settings_hostname), a `GET` to `/user/webhooks/parse/settings/{hostname}`.
+    pub fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParseSetting> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/user/webhooks/parse/settings/{}",
+                crate::progenitor_support::encode_path(hostname)
+            ),
+        )
+    }
+
+    /// Mock [`SettingsInboundParse::delete_user_webhooks_parse_settings_hostname`](crate::settings_inbound_parse::SettingsInboundParse::delete_user_webhooks_parse_settings_hostname), a `DELETE` to `/user/webhooks/parse/settings/{hostname}`.
+    pub fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/user/webhooks/parse/settings/{}",
+                crate::progenitor_support::encode_path(hostname)
+            ),
+        )
+    }
+
+    /// Mock [`SettingsInboundParse::patch_user_webhooks_parse_settings_hostname`](crate::settings_inbound_parse::SettingsInboundParse::patch_user_webhooks_parse_settings_hostname), a `PATCH` to `/user/webhooks/parse/settings/{hostname}`.
+    pub fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParseSetting> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/user/webhooks/parse/settings/{}",
+                crate::progenitor_support::encode_path(hostname)
+            ),
+        )
+    }
+}
+
+pub struct SettingsMail<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SettingsMail<'a> {
+    /// Mock [`SettingsMail::get_mail_settings`](crate::settings_mail::SettingsMail::get_mail_settings), a `GET` to `/mail_settings`.
+    pub fn get_mail_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMailSettingsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/mail_settings".to_string())
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_address_whitelist`](crate::settings_mail::SettingsMail::get_mail_settings_address_whitelist), a `GET` to `/mail_settings/address_whitelist`.
+    pub fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsAddressWhitelabel> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/mail_settings/address_whitelist".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_address_whitelist`](crate::settings_mail::SettingsMail::patch_mail_settings_address_whitelist), a `PATCH` to `/mail_settings/address_whitelist`.
+    pub fn patch_mail_settings_address_whitelist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsAddressWhitelabel> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/mail_settings/address_whitelist".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_footer`](crate::settings_mail::SettingsMail::get_mail_settings_footer), a `GET` to `/mail_settings/footer`.
+    pub fn get_mail_settings_footer(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsFooter> {
+        self.mock
+            .operation(reqwest::Method::GET, "/mail_settings/footer".to_string())
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_footer`](crate::settings_mail::SettingsMail::patch_mail_settings_footer), a `PATCH` to `/mail_settings/footer`.
+    pub fn patch_mail_settings_footer(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsFooter> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/mail_settings/footer".to_string())
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_forward_spam`](crate::settings_mail::SettingsMail::get_mail_settings_forward_spam), a `GET` to `/mail_settings/forward_spam`.
+    pub fn get_mail_settings_forward_spam(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsForwardSpam> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/mail_settings/forward_spam".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_forward_spam`](crate::settings_mail::SettingsMail::patch_mail_settings_forward_spam), a `PATCH` to `/mail_settings/forward_spam`.
+    pub fn patch_mail_settings_forward_spam(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsForwardSpam> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/mail_settings/forward_spam".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_template`](crate::settings_mail::SettingsMail::get_mail_settings_template), a `GET` to `/mail_settings/template`.
+    pub fn get_mail_settings_template(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsTemplate> {
+        self.mock
+            .operation(reqwest::Method::GET, "/mail_settings/template".to_string())
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_template`](crate::settings_mail::SettingsMail::patch_mail_settings_template), a `PATCH` to `/mail_settings/template`.
+    pub fn patch_mail_settings_template(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PatchMailSettingsTemplateResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/mail_settings/template".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_bounce_purge`](crate::settings_mail::SettingsMail::get_mail_settings_bounce_purge), a `GET` to `/mail_settings/bounce_purge`.
+    pub fn get_mail_settings_bounce_purge(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsBouncePurge> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/mail_settings/bounce_purge".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_bounce_purge`](crate::settings_mail::SettingsMail::patch_mail_settings_bounce_purge), a `PATCH` to `/mail_settings/bounce_purge`.
+    pub fn patch_mail_settings_bounce_purge(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsBouncePurge> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/mail_settings/bounce_purge".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::get_mail_settings_forward_bounce`](crate::settings_mail::SettingsMail::get_mail_settings_forward_bounce), a `GET` to `/mail_settings/forward_bounce`.
+    pub fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsForwardBounce> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/mail_settings/forward_bounce".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsMail::patch_mail_settings_forward_bounce`](crate::settings_mail::SettingsMail::patch_mail_settings_forward_bounce), a `PATCH` to `/mail_settings/forward_bounce`.
+    pub fn patch_mail_settings_forward_bounce(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MailSettingsForwardBounce> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/mail_settings/forward_bounce".to_string(),
+        )
+    }
+}
+
+pub struct SettingsPartner<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SettingsPartner<'a> {
+    /// Mock [`SettingsPartner::get_partner_settings_new_relic`](crate::settings_partner::SettingsPartner::get_partner_settings_new_relic), a `GET` to `/partner_settings/new_relic`.
+    pub fn get_partner_settings_new_relic(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PartnerSettingsNewRelic> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/partner_settings/new_relic".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsPartner::patch_partner_settings_new_relic`](crate::settings_partner::SettingsPartner::patch_partner_settings_new_relic), a `PATCH` to `/partner_settings/new_relic`.
+    pub fn patch_partner_settings_new_relic(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PartnerSettingsNewRelic> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/partner_settings/new_relic".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsPartner::get_partner_settings`](crate::settings_partner::SettingsPartner::get_partner_settings), a `GET` to `/partner_settings`.
+    pub fn get_partner_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPartnerSettingsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/partner_settings".to_string())
+    }
+}
+
+pub struct SettingsTracking<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SettingsTracking<'a> {
+    /// Mock [`SettingsTracking::get_tracking_settings`](crate::settings_tracking::SettingsTracking::get_tracking_settings), a `GET` to `/tracking_settings`.
+    pub fn get_tracking_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTrackingSettingsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/tracking_settings".to_string())
+    }
+
+    /// Mock [`SettingsTracking::get_tracking_settings_click`](crate::settings_tracking::SettingsTracking::get_tracking_settings_click), a `GET` to `/tracking_settings/click`.
+    pub fn get_tracking_settings_click(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClickTracking> {
+        self.mock
+            .operation(reqwest::Method::GET, "/tracking_settings/click".to_string())
+    }
+
+    /// Mock [`SettingsTracking::patch_tracking_settings_click`](crate::settings_tracking::SettingsTracking::patch_tracking_settings_click), a `PATCH` to `/tracking_settings/click`.
+    pub fn patch_tracking_settings_click(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ClickTracking> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/tracking_settings/click".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsTracking::get_tracking_settings_google_analytic`](crate::settings_tracking::SettingsTracking::get_tracking_settings_google_analytic), a `GET` to `/tracking_settings/google_analytics`.
+    pub fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GoogleAnalyticsSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/tracking_settings/google_analytics".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsTracking::patch_tracking_settings_google_analytics`](crate::settings_tracking::SettingsTracking::patch_tracking_settings_google_analytics), a `PATCH` to `/tracking_settings/google_analytics`.
+    pub fn patch_tracking_settings_google_analytics(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GoogleAnalyticsSettings> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/tracking_settings/google_analytics".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsTracking::get_tracking_settings_open`](crate::settings_tracking::SettingsTracking::get_tracking_settings_open), a `GET` to `/tracking_settings/open`.
+    pub fn get_tracking_settings_open(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTrackingSettingsOpenResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/tracking_settings/open".to_string())
+    }
+
+    /// Mock [`SettingsTracking::patch_tracking_settings_open`](crate::settings_tracking::SettingsTracking::patch_tracking_settings_open), a `PATCH` to `/tracking_settings/open`.
+    pub fn patch_tracking_settings_open(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTrackingSettingsOpenResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/tracking_settings/open".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsTracking::get_tracking_settings_subscription`](crate::settings_tracking::SettingsTracking::get_tracking_settings_subscription), a `GET` to `/tracking_settings/subscription`.
+    pub fn get_tracking_settings_subscription(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionTrackingSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/tracking_settings/subscription".to_string(),
+        )
+    }
+
+    /// Mock [`SettingsTracking::patch_tracking_settings_subscription`](crate::settings_tracking::SettingsTracking::patch_tracking_settings_subscription), a `PATCH` to `/tracking_settings/subscription`.
+    pub fn patch_tracking_settings_subscription(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionTrackingSettings> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/tracking_settings/subscription".to_string(),
+        )
+    }
+}
+
+pub struct SingleSends<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SingleSends<'a> {
+    /// Mock [`SingleSends::get_marketing_singlesends`](crate::single_sends::SingleSends::get_marketing_singlesends), a `GET` to `/marketing/singlesends`.
+    pub fn get_marketing_singlesends(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMarketingSinglesendsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/marketing/singlesends".to_string())
+    }
+
+    /// Mock [`SingleSends::post_marketing_singlesend`](crate::single_sends::SingleSends::post_marketing_singlesend), a `POST` to `/marketing/singlesends`.
+    pub fn post_marketing_singlesend(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/marketing/singlesends".to_string())
+    }
+
+    /// Mock [`SingleSends::delete_marketing_singlesends`](crate::single_sends::SingleSends::delete_marketing_singlesends), a `DELETE` to `/marketing/singlesends`.
+    pub fn delete_marketing_singlesends(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/marketing/singlesends".to_string(),
+        )
+    }
+
+    /// Mock [`SingleSends::get_marketing_singlesend`](crate::single_sends::SingleSends::get_marketing_singlesend), a `GET` to `/marketing/singlesends/{id}`.
+    pub fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/marketing/singlesends/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::post_marketing_singlesend_single_sends`](crate::single_sends::SingleSends::post_marketing_singlesend_single_sends), a `POST` to `/marketing/singlesends/{id}`.
+    pub fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/marketing/singlesends/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::delete_marketing_singlesends_single_sends`](crate::single_sends::SingleSends::delete_marketing_singlesends_single_sends), a `DELETE` to `/marketing/singlesends/{id}`.
+    pub fn delete_marketing_singlesends_single_sends(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/singlesends/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::patch_marketing_singlesends`](crate::single_sends::SingleSends::patch_marketing_singlesends), a `PATCH` to `/marketing/singlesends/{id}`.
+    pub fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/marketing/singlesends/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::post_marketing_singlesends_search`](crate::single_sends::SingleSends::post_marketing_singlesends_search), a `POST` to `/marketing/singlesends/search`.
+    pub fn post_marketing_singlesends_search(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMarketingSinglesendsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/marketing/singlesends/search".to_string(),
+        )
+    }
+
+    /// Mock [`SingleSends::put_marketing_singlesends_schedule`](crate::single_sends::SingleSends::put_marketing_singlesends_schedule), a `PUT` to `/marketing/singlesends/{id}/schedule`.
+    pub fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PutMarketingSinglesendsScheduleResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/marketing/singlesends/{}/schedule",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::delete_marketing_singlesends_schedule`](crate::single_sends::SingleSends::delete_marketing_singlesends_schedule), a `DELETE` to `/marketing/singlesends/{id}/schedule`.
+    pub fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SinglesendSchedule> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/marketing/singlesends/{}/schedule",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSends::get_marketing_singlesends_categories`](crate::single_sends::SingleSends::get_marketing_singlesends_categories), a `GET` to `/marketing/singlesends/categories`.
+    pub fn get_marketing_singlesends_categories(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetMarketingSinglesendsCategoriesResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/marketing/singlesends/categories".to_string(),
+        )
+    }
+}
+
+pub struct SingleSignOnSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SingleSignOnSettings<'a> {
+    /// Mock [`SingleSignOnSettings::get_sso_integrations`](crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integrations), a `GET` to `/sso/integrations`.
+    pub fn get_sso_integrations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SsoIntegrationAllOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/sso/integrations".to_string())
+    }
+
+    /// Mock [`SingleSignOnSettings::post_sso_integration`](crate::single_sign_on_settings::SingleSignOnSettings::post_sso_integration), a `POST` to `/sso/integrations`.
+    pub fn post_sso_integration(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoIntegrationAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/sso/integrations".to_string())
+    }
+
+    /// Mock [`SingleSignOnSettings::get_sso_integration`](crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integration), a `GET` to `/sso/integrations/{id}`.
+    pub fn get_sso_integration(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoIntegrationAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/sso/integrations/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSignOnSettings::delete_sso_integrations`](crate::single_sign_on_settings::SingleSignOnSettings::delete_sso_integrations), a `DELETE` to `/sso/integrations/{id}`.
+    pub fn delete_sso_integrations(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/sso/integrations/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`SingleSignOnSettings::patch_sso_integrations`](crate::single_sign_on_settings::SingleSignOnSettings::patch_sso_integrations), a `PATCH` to `/sso/integrations/{id}`.
+    pub fn patch_sso_integrations(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoIntegrationAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/sso/integrations/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct SingleSignOnTeammates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SingleSignOnTeammates<'a> {
+    /// Mock [`SingleSignOnTeammates::post_sso_teammate`](crate::single_sign_on_teammates::SingleSignOnTeammates::post_sso_teammate), a `POST` to `/sso/teammates`.
+    pub fn post_sso_teammate(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoTeammateResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/sso/teammates".to_string())
+    }
+
+    /// Mock [`SingleSignOnTeammates::patch_sso_teammates_username`](crate::single_sign_on_teammates::SingleSignOnTeammates::patch_sso_teammates_username), a `PATCH` to `/sso/teammates/{username}`.
+    pub fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SsoTeammatesPatchResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/sso/teammates/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+}
+
+pub struct SpamReportsApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SpamReportsApi<'a> {
+    /// Mock [`SpamReportsApi::get_suppression_spam_reports`](crate::spam_reports_api::SpamReportsApi::get_suppression_spam_reports), a `GET` to `/suppression/spam_reports`.
+    pub fn get_suppression_spam_reports(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SpamReportsResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/suppression/spam_reports".to_string(),
+        )
+    }
+
+    /// Mock [`SpamReportsApi::delete_suppression_spam_reports`](crate::spam_reports_api::SpamReportsApi::delete_suppression_spam_reports), a `DELETE` to `/suppression/spam_reports`.
+    pub fn delete_suppression_spam_reports(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/suppression/spam_reports".to_string(),
+        )
+    }
+
+    /// Mock [`SpamReportsApi::get_suppression_spam_reports_email`](crate::spam_reports_api::SpamReportsApi::get_suppression_spam_reports_email), a `GET` to `/suppression/spam_reports/{email}`.
+    pub fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SpamReportsResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/suppression/spam_reports/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`SpamReportsApi::delete_suppression_spam_reports_email`](crate::spam_reports_api::SpamReportsApi::delete_suppression_spam_reports_email), a `DELETE` to `/suppression/spam_reports/{email}`.
+    pub fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/suppression/spam_reports/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct Stats<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Stats<'a> {
+    /// Mock [`Stats::get_page`](crate::stats::Stats::get_page), a `GET` to `/stats`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetStatsResponseData>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stats".to_string())
+    }
+
+    /// Mock [`Stats::get_geo`](crate::stats::Stats::get_geo), a `GET` to `/geo/stats`.
+    pub fn get_geo(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetGeoStatsResponseData>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/geo/stats".to_string())
+    }
+
+    /// Mock [`Stats::get_devices`](crate::stats::Stats::get_devices), a `GET` to `/devices/stats`.
+    pub fn get_devices(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetClientsStatsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/devices/stats".to_string())
+    }
+
+    /// Mock [`Stats::get_clients`](crate::stats::Stats::get_clients), a `GET` to `/clients/stats`.
+    pub fn get_clients(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetClientsStatsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/clients/stats".to_string())
+    }
+
+    /// Mock [`Stats::get_clients_client_type`](crate::stats::Stats::get_clients_client_type), a `GET` to `/clients/{client_type}/stats`.
+    pub fn get_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetClientsStatsResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/clients/{}/stats",
+                crate::progenitor_support::encode_path(&client_type.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Stats::get_mailbox_providers`](crate::stats::Stats::get_mailbox_providers), a `GET` to `/mailbox_providers/stats`.
+    pub fn get_mailbox_providers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetMailboxProvidersStatsResponseData>>
+    {
+        self.mock
+            .operation(reqwest::Method::GET, "/mailbox_providers/stats".to_string())
+    }
+
+    /// Mock [`Stats::get_browsers`](crate::stats::Stats::get_browsers), a `GET` to `/browsers/stats`.
+    pub fn get_browsers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetBrowsersStatsResponseData>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/browsers/stats".to_string())
+    }
+}
+
+pub struct SubuserMonitorSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SubuserMonitorSettings<'a> {
+    /// Mock [`SubuserMonitorSettings::get_subusers_subuser_name_monitor`](crate::subuser_monitor_settings::SubuserMonitorSettings::get_subusers_subuser_name_monitor), a `GET` to `/subusers/{subuser_name}/monitor`.
+    pub fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Monitor> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/subusers/{}/monitor",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubuserMonitorSettings::put_subusers_subuser_name_monitor`](crate::subuser_monitor_settings::SubuserMonitorSettings::put_subusers_subuser_name_monitor), a `PUT` to `/subusers/{subuser_name}/monitor`.
+    pub fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Monitor> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/subusers/{}/monitor",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubuserMonitorSettings::post_subusers_subuser_name_monitor`](crate::subuser_monitor_settings::SubuserMonitorSettings::post_subusers_subuser_name_monitor), a `POST` to `/subusers/{subuser_name}/monitor`.
+    pub fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Monitor> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/subusers/{}/monitor",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubuserMonitorSettings::delete_subusers_subuser_name_monitor`](crate::subuser_monitor_settings::SubuserMonitorSettings::delete_subusers_subuser_name_monitor), a `DELETE` to `/subusers/{subuser_name}/monitor`.
+    pub fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/subusers/{}/monitor",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+}
+
+pub struct SubuserStatistics<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SubuserStatistics<'a> {
+    /// Mock [`SubuserStatistics::get_subusers_subuser_name_stats_monthly`](crate::subuser_statistics::SubuserStatistics::get_subusers_subuser_name_stats_monthly), a `GET` to `/subusers/{subuser_name}/stats/monthly`.
+    pub fn get_subusers_subuser_name_stats_monthly(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubuserStatsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/subusers/{}/stats/monthly",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubuserStatistics::get_subusers_stats_monthly`](crate::subuser_statistics::SubuserStatistics::get_subusers_stats_monthly), a `GET` to `/subusers/stats/monthly`.
+    pub fn get_subusers_stats_monthly(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubuserStatsData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/subusers/stats/monthly".to_string())
+    }
+
+    /// Mock [`SubuserStatistics::get_subusers_stats_sum`](crate::subuser_statistics::SubuserStatistics::get_subusers_stats_sum), a `GET` to `/subusers/stats/sums`.
+    pub fn get_subusers_stats_sum(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::CategoryStats> {
+        self.mock
+            .operation(reqwest::Method::GET, "/subusers/stats/sums".to_string())
+    }
+
+    /// Mock [`SubuserStatistics::get_subusers_stat`](crate::subuser_statistics::SubuserStatistics::get_subusers_stat), a `GET` to `/subusers/stats`.
+    pub fn get_subusers_stat(&self) -> crate::mock::MockOperation<'a, crate::types::CategoryStats> {
+        self.mock
+            .operation(reqwest::Method::GET, "/subusers/stats".to_string())
+    }
+}
+
+pub struct SubusersApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SubusersApi<'a> {
+    /// Mock [`SubusersApi::get_subusers`](crate::subusers_api::SubusersApi::get_subusers), a `GET` to `/subusers`.
+    pub fn get_subusers(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Subuser>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/subusers".to_string())
+    }
+
+    /// Mock [`SubusersApi::post_subuser`](crate::subusers_api::SubusersApi::post_subuser), a `POST` to `/subusers`.
+    pub fn post_subuser(&self) -> crate::mock::MockOperation<'a, crate::types::SubuserPost> {
+        self.mock
+            .operation(reqwest::Method::POST, "/subusers".to_string())
+    }
+
+    /// Mock [`SubusersApi::delete_subusers_subuser_name`](crate::subusers_api::SubusersApi::delete_subusers_subuser_name), a `DELETE` to `/subusers/{subuser_name}`.
+    pub fn delete_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/subusers/{}",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubusersApi::patch_subusers_subuser_name`](crate::subusers_api::SubusersApi::patch_subusers_subuser_name), a `PATCH` to `/subusers/{subuser_name}`.
+    pub fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/subusers/{}",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+
+    /// Mock [`SubusersApi::get_subusers_reputations`](crate::subusers_api::SubusersApi::get_subusers_reputations), a `GET` to `/subusers/reputations`.
+    pub fn get_subusers_reputations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetSubusersReputationsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/subusers/reputations".to_string())
+    }
+
+    /// Mock [`SubusersApi::put_subusers_subuser_name_ips`](crate::subusers_api::SubusersApi::put_subusers_subuser_name_ips), a `PUT` to `/subusers/{subuser_name}/ips`.
+    pub fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PutSubusersSubuserNameIpsResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/subusers/{}/ips",
+                crate::progenitor_support::encode_path(subuser_name)
+            ),
+        )
+    }
+}
+
+pub struct Suppressions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Suppressions<'a> {
+    /// Mock [`Suppressions::get_asm_groups_group`](crate::suppressions::Suppressions::get_asm_groups_group), a `GET` to `/asm/groups/{group_id}/suppressions`.
+    pub fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/asm/groups/{}/suppressions",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Suppressions::post_asm_groups_group`](crate::suppressions::Suppressions::post_asm_groups_group), a `POST` to `/asm/groups/{group_id}/suppressions`.
+    pub fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostAsmGroupsGroupSuppressionsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/asm/groups/{}/suppressions",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Suppressions::post_asm_groups_group_search`](crate::suppressions::Suppressions::post_asm_groups_group_search), a `POST` to `/asm/groups/{group_id}/suppressions/search`.
+    pub fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, Vec<String>> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/asm/groups/{}/suppressions/search",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Suppressions::get_asm`](crate::suppressions::Suppressions::get_asm), a `GET` to `/asm/suppressions`.
+    pub fn get_asm(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetAsmSuppressionsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/asm/suppressions".to_string())
+    }
+
+    /// Mock [`Suppressions::get_asm_email`](crate::suppressions::Suppressions::get_asm_email), a `GET` to `/asm/suppressions/{email}`.
+    pub fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAsmSuppressionsEmailResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/asm/suppressions/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`Suppressions::delete_asm_groups_group_email`](crate::suppressions::Suppressions::delete_asm_groups_group_email), a `DELETE` to `/asm/groups/{group_id}/suppressions/{email}`.
+    pub fn delete_asm_groups_group_email(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/asm/groups/{}/suppressions/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct SuppressionsGlobal<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SuppressionsGlobal<'a> {
+    /// Mock [`SuppressionsGlobal::post_asm`](crate::suppressions_global::SuppressionsGlobal::post_asm), a `POST` to `/asm/suppressions/global`.
+    pub fn post_asm(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SuppressionsRequestBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/asm/suppressions/global".to_string(),
+        )
+    }
+
+    /// Mock [`SuppressionsGlobal::get_suppression_unsubscribes`](crate::suppressions_global::SuppressionsGlobal::get_suppression_unsubscribes), a `GET` to `/suppression/unsubscribes`.
+    pub fn get_suppression_unsubscribes(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/suppression/unsubscribes".to_string(),
+        )
+    }
+
+    /// Mock [`SuppressionsGlobal::get_asm_email`](crate::suppressions_global::SuppressionsGlobal::get_asm_email), a `GET` to `/asm/suppressions/global/{email}`.
+    pub fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RetrieveAGlobalSuppressionResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/asm/suppressions/global/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+
+    /// Mock [`SuppressionsGlobal::delete_asm_email`](crate::suppressions_global::SuppressionsGlobal::delete_asm_email), a `DELETE` to `/asm/suppressions/global/{email}`.
+    pub fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/asm/suppressions/global/{}",
+                crate::progenitor_support::encode_path(email)
+            ),
+        )
+    }
+}
+
+pub struct SuppressionsUnsubscribeGroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SuppressionsUnsubscribeGroups<'a> {
+    /// Mock [`SuppressionsUnsubscribeGroups::get_asm_groups`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_asm_groups), a `GET` to `/asm/groups`.
+    pub fn get_asm_groups(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::SuppressionGroup>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/asm/groups".to_string())
+    }
+
+    /// Mock [`SuppressionsUnsubscribeGroups::post_asm_group`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::post_asm_group), a `POST` to `/asm/groups`.
+    pub fn post_asm_group(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostAsmGroupsResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/asm/groups".to_string())
+    }
+
+    /// Mock [`SuppressionsUnsubscribeGroups::get_asm_groups_group`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_asm_groups_group), a `GET` to `/asm/groups/{group_id}`.
+    pub fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAsmGroupsGroupResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/asm/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`SuppressionsUnsubscribeGroups::delete_asm_groups_group`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::delete_asm_groups_group), a `DELETE` to `/asm/groups/{group_id}`.
+    pub fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/asm/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`SuppressionsUnsubscribeGroups::patch_asm_groups_group`](crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::patch_asm_groups_group), a `PATCH` to `/asm/groups/{group_id}`.
+    pub fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SuppressionGroup> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/asm/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+}
+
+pub struct Teammates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Teammates<'a> {
+    /// Mock [`Teammates::get`](crate::teammates::Teammates::get), a `GET` to `/teammates`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GetTeammatesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/teammates".to_string())
+    }
+
+    /// Mock [`Teammates::post`](crate::teammates::Teammates::post), a `POST` to `/teammates`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PostTeammatesResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/teammates".to_string())
+    }
+
+    /// Mock [`Teammates::post_pending_token_resend`](crate::teammates::Teammates::post_pending_token_resend), a `POST` to `/teammates/pending/{token}/resend`.
+    pub fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostTeammatesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/teammates/pending/{}/resend",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::get_scopes_requests`](crate::teammates::Teammates::get_scopes_requests), a `GET` to `/scopes/requests`.
+    pub fn get_scopes_requests(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetScopesRequestsResponse>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/scopes/requests".to_string())
+    }
+
+    /// Mock [`Teammates::get_pending`](crate::teammates::Teammates::get_pending), a `GET` to `/teammates/pending`.
+    pub fn get_pending(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTeammatesPendingResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/teammates/pending".to_string())
+    }
+
+    /// Mock [`Teammates::get_username`](crate::teammates::Teammates::get_username), a `GET` to `/teammates/{username}`.
+    pub fn get_username(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTeammatesUsernameResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/teammates/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::delete_username`](crate::teammates::Teammates::delete_username), a `DELETE` to `/teammates/{username}`.
+    pub fn delete_username(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostSendersResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teammates/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::patch_username`](crate::teammates::Teammates::patch_username), a `PATCH` to `/teammates/{username}`.
+    pub fn patch_username(
+        &self,
+        username: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTeammatesUsernameResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/teammates/{}",
+                crate::progenitor_support::encode_path(username)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::patch_scopes_requests_approve`](crate::teammates::Teammates::patch_scopes_requests_approve), a `PATCH` to `/scopes/requests/{request_id}/approve`.
+    pub fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PatchScopesRequestsApproveResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/scopes/requests/{}/approve",
+                crate::progenitor_support::encode_path(request_id)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::delete_scopes_requests_request`](crate::teammates::Teammates::delete_scopes_requests_request), a `DELETE` to `/scopes/requests/{request_id}`.
+    pub fn delete_scopes_requests_request(
+        &self,
+        request_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/scopes/requests/{}",
+                crate::progenitor_support::encode_path(request_id)
+            ),
+        )
+    }
+
+    /// Mock [`Teammates::delete_pending_token`](crate::teammates::Teammates::delete_pending_token), a `DELETE` to `/teammates/pending/{token}`.
+    pub fn delete_pending_token(&self, token: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/teammates/pending/{}",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+}
+
+pub struct TransactionalTemplates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TransactionalTemplates<'a> {
+    /// Mock [`TransactionalTemplates::get_templates`](crate::transactional_templates::TransactionalTemplates::get_templates), a `GET` to `/templates`.
+    pub fn get_templates(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTemplatesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/templates".to_string())
+    }
+
+    /// Mock [`TransactionalTemplates::post_template`](crate::transactional_templates::TransactionalTemplates::post_template), a `POST` to `/templates`.
+    pub fn post_template(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/templates".to_string())
+    }
+
+    /// Mock [`TransactionalTemplates::get_templates_template`](crate::transactional_templates::TransactionalTemplates::get_templates_template), a `GET` to `/templates/{template_id}`.
+    pub fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplates::post_templates_template`](crate::transactional_templates::TransactionalTemplates::post_templates_template), a `POST` to `/templates/{template_id}`.
+    pub fn post_templates_template(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplates::delete_templates_template`](crate::transactional_templates::TransactionalTemplates::delete_templates_template), a `DELETE` to `/templates/{template_id}`.
+    pub fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplates::patch_templates_template`](crate::transactional_templates::TransactionalTemplates::patch_templates_template), a `PATCH` to `/templates/{template_id}`.
+    pub fn patch_templates_template(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+}
+
+pub struct TransactionalTemplatesVersions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TransactionalTemplatesVersions<'a> {
+    /// Mock [`TransactionalTemplatesVersions::post_templates_template_version`](crate::transactional_templates_versions::TransactionalTemplatesVersions::post_templates_template_version), a `POST` to `/templates/{template_id}/versions`.
+    pub fn post_templates_template_version(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateVersionOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/templates/{}/versions",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplatesVersions::post_templates_template_versions_version_activate`](crate::transactional_templates_versions::TransactionalTemplatesVersions::post_templates_template_versions_version_activate), a `POST` to `/templates/{template_id}/versions/{version_id}/activate`.
+    pub fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateVersionOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/templates/{}/versions/{}/activate",
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(version_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplatesVersions::get_templates_template_versions_version`](crate::transactional_templates_versions::TransactionalTemplatesVersions::get_templates_template_versions_version), a `GET` to `/templates/{template_id}/versions/{version_id}`.
+    pub fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateVersionOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/templates/{}/versions/{}",
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(version_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplatesVersions::delete_templates_template_versions_version`](crate::transactional_templates_versions::TransactionalTemplatesVersions::delete_templates_template_versions_version), a `DELETE` to `/templates/{template_id}/versions/{version_id}`.
+    pub fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/templates/{}/versions/{}",
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(version_id)
+            ),
+        )
+    }
+
+    /// Mock [`TransactionalTemplatesVersions::patch_templates_template_versions_version`](crate::transactional_templates_versions::TransactionalTemplatesVersions::patch_templates_template_versions_version), a `PATCH` to `/templates/{template_id}/versions/{version_id}`.
+    pub fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransactionalTemplateVersionOutputAllOf> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/templates/{}/versions/{}",
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(version_id)
+            ),
+        )
+    }
+}
+
+pub struct UsersApi<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UsersApi<'a> {
+    /// Mock [`UsersApi::get_user_profile`](crate::users_api::UsersApi::get_user_profile), a `GET` to `/user/profile`.
+    pub fn get_user_profile(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserProfileResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/profile".to_string())
+    }
+
+    /// Mock [`UsersApi::patch_user_profile`](crate::users_api::UsersApi::patch_user_profile), a `PATCH` to `/user/profile`.
+    pub fn patch_user_profile(&self) -> crate::mock::MockOperation<'a, crate::types::UserProfile> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/user/profile".to_string())
+    }
+
+    /// Mock [`UsersApi::get_user_account`](crate::users_api::UsersApi::get_user_account), a `GET` to `/user/account`.
+    pub fn get_user_account(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserAccountResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/account".to_string())
+    }
+
+    /// Mock [`UsersApi::get_user_email`](crate::users_api::UsersApi::get_user_email), a `GET` to `/user/email`.
+    pub fn get_user_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserEmailResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/email".to_string())
+    }
+
+    /// Mock [`UsersApi::put_user_email`](crate::users_api::UsersApi::put_user_email), a `PUT` to `/user/email`.
+    pub fn put_user_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserEmailResponse> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/user/email".to_string())
+    }
+
+    /// Mock [`UsersApi::get_user_username`](crate::users_api::UsersApi::get_user_username), a `GET` to `/user/username`.
+    pub fn get_user_username(&self) -> crate::mock::MockOperation<'a, crate::types::Users> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/username".to_string())
+    }
+
+    /// Mock [`UsersApi::put_user_username`](crate::users_api::UsersApi::put_user_username), a `PUT` to `/user/username`.
+    pub fn put_user_username(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PutUserUsernameResponse> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/user/username".to_string())
+    }
+
+    /// Mock [`UsersApi::get_user_credits`](crate::users_api::UsersApi::get_user_credits), a `GET` to `/user/credits`.
+    pub fn get_user_credits(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserCreditsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/user/credits".to_string())
+    }
+
+    /// Mock [`UsersApi::put_user_password`](crate::users_api::UsersApi::put_user_password), a `PUT` to `/user/password`.
+    pub fn put_user_password(&self) -> crate::mock::MockOperation<'a, crate::types::Help> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/user/password".to_string())
+    }
+}
+
+pub struct Webhooks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Webhooks<'a> {
+    /// Mock [`Webhooks::get_user_event_settings`](crate::webhooks::Webhooks::get_user_event_settings), a `GET` to `/user/webhooks/event/settings`.
+    pub fn get_user_event_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhooksEventWebhookResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/webhooks/event/settings".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::patch_user_event_settings`](crate::webhooks::Webhooks::patch_user_event_settings), a `PATCH` to `/user/webhooks/event/settings`.
+    pub fn patch_user_event_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhooksEventWebhookResponse> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/user/webhooks/event/settings".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::get_user_parse_settings`](crate::webhooks::Webhooks::get_user_parse_settings), a `GET` to `/user/webhooks/parse/settings`.
+    pub fn get_user_parse_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserWebhooksParseSettingsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/webhooks/parse/settings".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::get_user_parse_stats`](crate::webhooks::Webhooks::get_user_parse_stats), a `GET` to `/user/webhooks/parse/stats`.
+    pub fn get_user_parse_stats(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::GetUserWebhooksParseStatsResponseData>>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/webhooks/parse/stats".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::get_user_event_settings_signed`](crate::webhooks::Webhooks::get_user_event_settings_signed), a `GET` to `/user/webhooks/event/settings/signed`.
+    pub fn get_user_event_settings_signed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserWebhooksEventSettingsSignedResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/user/webhooks/event/settings/signed".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::patch_user_event_settings_signed`](crate::webhooks::Webhooks::patch_user_event_settings_signed), a `PATCH` to `/user/webhooks/event/settings/signed`.
+    pub fn patch_user_event_settings_signed(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserWebhooksEventSettingsSignedResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/user/webhooks/event/settings/signed".to_string(),
+        )
+    }
+
+    /// Mock [`Webhooks::post_user_event_test`](crate::webhooks::Webhooks::post_user_event_test), a `POST` to `/user/webhooks/event/test`.
+    pub fn post_user_event_test(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/user/webhooks/event/test".to_string(),
+        )
+    }
+}
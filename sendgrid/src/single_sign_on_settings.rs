@@ -80,6 +80,61 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
+     *
+     * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     */
+    pub fn get_all_sso_integrations_stream(
+        &self,
+        si: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SsoIntegrationAllOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if si {
+            query_args.push(("si".to_string(), si.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/sso/integrations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SsoIntegrationAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SsoIntegrationAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create an SSO Integration.
      *
@@ -105,6 +105,71 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
+     *
+     * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     */
+    pub fn get_all_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetStatsResponseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetStatsResponseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetStatsResponseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve email statistics by country and state/province.
      *
@@ -211,6 +276,77 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by country and state/province.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     */
+    pub fn get_all_geo_stream(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetGeoStatsResponseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !country.to_string().is_empty() {
+            query_args.push(("country".to_string(), country.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/geo/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetGeoStatsResponseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetGeoStatsResponseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve email statistics by device type.
      *
@@ -326,6 +462,82 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_all_devices`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Device Types
+     * | **Device** | **Description** | **Example** |
+     * |---|---|---|
+     * | Desktop | Email software on desktop computer. | I.E., Outlook, Sparrow, or Apple Mail. |
+     * | Webmail |	A web-based email client. | I.E., Yahoo, Google, AOL, or Outlook.com. |
+     * | Phone | A smart phone. | iPhone, Android, Blackberry, etc.
+     * | Tablet | A tablet computer. | iPad, android based tablet, etc. |
+     * | Other | An unrecognized device. |
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_devices_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetClientsStatsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/devices/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetClientsStatsResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetClientsStatsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve email statistics by client type.
      *
@@ -413,6 +625,69 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetClientsStatsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/clients/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetClientsStatsResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetClientsStatsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve stats by a specific client type.
      *
@@ -528,6 +803,83 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by a specific client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Client Types
+     * - phone
+     * - tablet
+     * - webmail
+     * - desktop
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetClientsStatsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/clients/{}/stats?{}",
+                crate::progenitor_support::encode_path(&client_type.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetClientsStatsResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetClientsStatsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve email statistics by mailbox provider.
      *
@@ -644,6 +996,82 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_mailbox_providers_stream(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetMailboxProvidersStatsResponseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !mailbox_providers.is_empty() {
+            query_args.push((
+                "mailbox_providers".to_string(),
+                mailbox_providers.to_string(),
+            ));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/mailbox_providers/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetMailboxProvidersStatsResponseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetMailboxProvidersStatsResponseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve email statistics by browser.
      *
@@ -754,4 +1182,77 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by browser type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_browsers_stream(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetBrowsersStatsResponseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !browsers.is_empty() {
+            query_args.push(("browsers".to_string(), browsers.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/browsers/stats?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetBrowsersStatsResponseData>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetBrowsersStatsResponseData>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
@@ -90,6 +90,57 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all of your subusers.**
+     *
+     * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     */
+    pub fn get_all_subusers_stream(
+        &self,
+        username: &str,
+        offset: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Subuser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/subusers?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Subuser>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Subuser>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create Subuser.
      *
@@ -239,6 +290,61 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to request the reputations for your subusers.**
+     *
+     * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     */
+    pub fn get_all_subusers_reputations_stream(
+        &self,
+        usernames: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetSubusersReputationsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !usernames.is_empty() {
+            query_args.push(("usernames".to_string(), usernames.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/subusers/reputations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetSubusersReputationsResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetSubusersReputationsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Update IPs assigned to a subuser.
      *
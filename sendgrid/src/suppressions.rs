@@ -68,6 +68,52 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
+     */
+    pub fn get_all_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<String> {
+        let url = self.client.url(
+            &format!(
+                "/asm/groups/{}/suppressions",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<String>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<String>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add suppressions to a suppression group.
      *
@@ -182,6 +228,50 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all suppressions.**
+     */
+    pub fn get_all_asm_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetAsmSuppressionsResponse> {
+        let url = self.client.url("/asm/suppressions", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::GetAsmSuppressionsResponse>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::GetAsmSuppressionsResponse>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Retrieve all suppression groups for an email address.
      *
@@ -71,6 +71,59 @@ impl SuppressionsUnsubscribeGroups {
             )
             .await
     }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
+     *
+     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     */
+    pub fn get_all_asm_groups_stream(
+        &self,
+        id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SuppressionGroup> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if id > 0 {
+            query_args.push(("id".to_string(), id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/asm/groups?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::SuppressionGroup>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::SuppressionGroup>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a new suppression group.
      *
@@ -72,6 +72,7 @@ impl MailOps for crate::mail_send::MailSend {
                     body: Some(reqwest::Body::from(serde_json::to_vec(&mail).unwrap())),
                     content_type: None,
                 },
+                reqwest::header::HeaderMap::new(),
             )
             .await?;
 
@@ -173,7 +173,7 @@ pub struct CampaignResponseAllOf {
     pub campaign_response: CampaignResponse,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Operator {
     #[serde(rename = "contains")]
     Contains,
@@ -187,8 +187,8 @@ pub enum Operator {
     Ne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Operator {
@@ -200,12 +200,39 @@ impl std::fmt::Display for Operator {
             Operator::Lt => "lt",
             Operator::Ne => "ne",
             Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Operator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Operator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "contains" => Operator::Contains,
+            "eq" => Operator::Eq,
+            "gt" => Operator::Gt,
+            "lt" => Operator::Lt,
+            "ne" => Operator::Ne,
+            "" => Operator::Noop,
+            _ => Operator::Unknown(s),
+        })
+    }
+}
+
 impl Default for Operator {
     fn default() -> Operator {
         Operator::Noop
@@ -215,9 +242,14 @@ impl Operator {
     pub fn is_noop(&self) -> bool {
         matches!(self, Operator::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Operator::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AndOr {
     #[serde(rename = "and")]
     And,
@@ -225,8 +257,8 @@ pub enum AndOr {
     Or,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AndOr {
@@ -235,12 +267,36 @@ impl std::fmt::Display for AndOr {
             AndOr::And => "and",
             AndOr::Or => "or",
             AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AndOr {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AndOr {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "and" => AndOr::And,
+            "or" => AndOr::Or,
+            "" => AndOr::Noop,
+            _ => AndOr::Unknown(s),
+        })
+    }
+}
+
 impl Default for AndOr {
     fn default() -> AndOr {
         AndOr::Noop
@@ -250,6 +306,11 @@ impl AndOr {
     pub fn is_noop(&self) -> bool {
         matches!(self, AndOr::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AndOr::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -575,7 +636,7 @@ pub struct SenderAllOf {
 /**
 * The type of the field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -585,8 +646,8 @@ pub enum Type {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -596,12 +657,37 @@ impl std::fmt::Display for Type {
             Type::Number => "number",
             Type::Text => "text",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "date" => Type::Date,
+            "number" => Type::Number,
+            "text" => Type::Text,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -611,6 +697,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -682,7 +773,7 @@ pub struct MailSettingsAddressWhitelabel {
 /**
 * The type of DNS record that was generated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LinkBranding200ResponseDnsDomainCnameType {
     #[serde(rename = "cname")]
     Cname,
@@ -692,8 +783,8 @@ pub enum LinkBranding200ResponseDnsDomainCnameType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
@@ -703,12 +794,37 @@ impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
             LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
             LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
             LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LinkBranding200ResponseDnsDomainCnameType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkBranding200ResponseDnsDomainCnameType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cname" => LinkBranding200ResponseDnsDomainCnameType::Cname,
+            "mx" => LinkBranding200ResponseDnsDomainCnameType::Mx,
+            "txt" => LinkBranding200ResponseDnsDomainCnameType::Txt,
+            "" => LinkBranding200ResponseDnsDomainCnameType::Noop,
+            _ => LinkBranding200ResponseDnsDomainCnameType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LinkBranding200ResponseDnsDomainCnameType {
     fn default() -> LinkBranding200ResponseDnsDomainCnameType {
         LinkBranding200ResponseDnsDomainCnameType::Noop
@@ -718,6 +834,11 @@ impl LinkBranding200ResponseDnsDomainCnameType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LinkBranding200ResponseDnsDomainCnameType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LinkBranding200ResponseDnsDomainCnameType::Unknown(_))
+    }
 }
 
 /// The DNS record generated to point to your link branding subdomain.
@@ -1847,7 +1968,7 @@ pub struct MailSettingsForwardSpam {
 /**
 * The editor used in the UI.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Editor {
     #[serde(rename = "code")]
     Code,
@@ -1855,8 +1976,8 @@ pub enum Editor {
     Design,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Editor {
@@ -1865,12 +1986,36 @@ impl std::fmt::Display for Editor {
             Editor::Code => "code",
             Editor::Design => "design",
             Editor::Noop => "",
-            Editor::FallthroughString => "*",
+            Editor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Editor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Editor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "code" => Editor::Code,
+            "design" => Editor::Design,
+            "" => Editor::Noop,
+            _ => Editor::Unknown(s),
+        })
+    }
+}
+
 impl Default for Editor {
     fn default() -> Editor {
         Editor::Noop
@@ -1880,6 +2025,11 @@ impl Editor {
     pub fn is_noop(&self) -> bool {
         matches!(self, Editor::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Editor::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2125,7 +2275,7 @@ pub struct SubuserStatsData {
 /**
 * The status of the scheduled send.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "cancel")]
     Cancel,
@@ -2133,8 +2283,8 @@ pub enum Status {
     Pause,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2143,12 +2293,36 @@ impl std::fmt::Display for Status {
             Status::Cancel => "cancel",
             Status::Pause => "pause",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cancel" => Status::Cancel,
+            "pause" => Status::Pause,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -2158,6 +2332,11 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /// The status of the scheduled send.
@@ -2608,7 +2787,7 @@ pub struct TransactionalTemplatesVersionOutputLean {
 /**
 * Defines the generation of the template.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Generation {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -2616,8 +2795,8 @@ pub enum Generation {
     Legacy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generation {
@@ -2626,12 +2805,36 @@ impl std::fmt::Display for Generation {
             Generation::Dynamic => "dynamic",
             Generation::Legacy => "legacy",
             Generation::Noop => "",
-            Generation::FallthroughString => "*",
+            Generation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Generation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Generation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "dynamic" => Generation::Dynamic,
+            "legacy" => Generation::Legacy,
+            "" => Generation::Noop,
+            _ => Generation::Unknown(s),
+        })
+    }
+}
+
 impl Default for Generation {
     fn default() -> Generation {
         Generation::Noop
@@ -2641,6 +2844,11 @@ impl Generation {
     pub fn is_noop(&self) -> bool {
         matches!(self, Generation::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Generation::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3020,7 +3228,7 @@ pub struct SingleContactRequest {
 /**
 * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ContactExportStatus {
     #[serde(rename = "failure")]
     Failure,
@@ -3030,8 +3238,8 @@ pub enum ContactExportStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactExportStatus {
@@ -3041,12 +3249,37 @@ impl std::fmt::Display for ContactExportStatus {
             ContactExportStatus::Pending => "pending",
             ContactExportStatus::Ready => "ready",
             ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
+            ContactExportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ContactExportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContactExportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "failure" => ContactExportStatus::Failure,
+            "pending" => ContactExportStatus::Pending,
+            "ready" => ContactExportStatus::Ready,
+            "" => ContactExportStatus::Noop,
+            _ => ContactExportStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ContactExportStatus {
     fn default() -> ContactExportStatus {
         ContactExportStatus::Noop
@@ -3056,6 +3289,11 @@ impl ContactExportStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ContactExportStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ContactExportStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3525,7 +3763,7 @@ pub struct List {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FieldType {
     #[serde(rename = "Date")]
     Date,
@@ -3535,8 +3773,8 @@ pub enum FieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldType {
@@ -3546,12 +3784,37 @@ impl std::fmt::Display for FieldType {
             FieldType::Number => "Number",
             FieldType::Text => "Text",
             FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
+            FieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Date" => FieldType::Date,
+            "Number" => FieldType::Number,
+            "Text" => FieldType::Text,
+            "" => FieldType::Noop,
+            _ => FieldType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FieldType {
     fn default() -> FieldType {
         FieldType::Noop
@@ -3561,6 +3824,11 @@ impl FieldType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FieldType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FieldType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4450,7 +4718,7 @@ pub struct ErrorsDataType {
 /**
 * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AbPhase {
     #[serde(rename = "all")]
     All,
@@ -4458,8 +4726,8 @@ pub enum AbPhase {
     Send,
     #[serde(rename = "test")]
     Test,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhase {
@@ -4468,17 +4736,47 @@ impl std::fmt::Display for AbPhase {
             AbPhase::All => "all",
             AbPhase::Send => "send",
             AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
+            AbPhase::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AbPhase {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AbPhase {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => AbPhase::All,
+            "send" => AbPhase::Send,
+            "test" => AbPhase::Test,
+            _ => AbPhase::Unknown(s),
+        })
+    }
+}
+
 impl Default for AbPhase {
     fn default() -> AbPhase {
         AbPhase::All
     }
 }
+impl AbPhase {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AbPhase::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendsResponseResults {
@@ -4651,7 +4949,7 @@ pub struct MetricsData {
     pub unsubscribes: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SinglesendSearchStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -4661,8 +4959,8 @@ pub enum SinglesendSearchStatus {
     Triggered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SinglesendSearchStatus {
@@ -4672,12 +4970,37 @@ impl std::fmt::Display for SinglesendSearchStatus {
             SinglesendSearchStatus::Scheduled => "scheduled",
             SinglesendSearchStatus::Triggered => "triggered",
             SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
+            SinglesendSearchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SinglesendSearchStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SinglesendSearchStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "draft" => SinglesendSearchStatus::Draft,
+            "scheduled" => SinglesendSearchStatus::Scheduled,
+            "triggered" => SinglesendSearchStatus::Triggered,
+            "" => SinglesendSearchStatus::Noop,
+            _ => SinglesendSearchStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for SinglesendSearchStatus {
     fn default() -> SinglesendSearchStatus {
         SinglesendSearchStatus::Noop
@@ -4687,6 +5010,11 @@ impl SinglesendSearchStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, SinglesendSearchStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SinglesendSearchStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5318,7 +5646,7 @@ pub struct DomainAuthentication200ResponseAllOf {
 /**
 * What differs between the A/B tests
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AbTestSummaryType {
     #[serde(rename = "content")]
     Content,
@@ -5326,8 +5654,8 @@ pub enum AbTestSummaryType {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbTestSummaryType {
@@ -5336,12 +5664,36 @@ impl std::fmt::Display for AbTestSummaryType {
             AbTestSummaryType::Content => "content",
             AbTestSummaryType::Subject => "subject",
             AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
+            AbTestSummaryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AbTestSummaryType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AbTestSummaryType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "content" => AbTestSummaryType::Content,
+            "subject" => AbTestSummaryType::Subject,
+            "" => AbTestSummaryType::Noop,
+            _ => AbTestSummaryType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AbTestSummaryType {
     fn default() -> AbTestSummaryType {
         AbTestSummaryType::Noop
@@ -5351,12 +5703,17 @@ impl AbTestSummaryType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AbTestSummaryType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AbTestSummaryType::Unknown(_))
+    }
 }
 
 /**
 * How the winner will be decided
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WinnerCriteria {
     #[serde(rename = "click")]
     Click,
@@ -5366,8 +5723,8 @@ pub enum WinnerCriteria {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -5377,12 +5734,37 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Open => "open",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WinnerCriteria {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WinnerCriteria {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "click" => WinnerCriteria::Click,
+            "manual" => WinnerCriteria::Manual,
+            "open" => WinnerCriteria::Open,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::Unknown(s),
+        })
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -5392,6 +5774,11 @@ impl WinnerCriteria {
     pub fn is_noop(&self) -> bool {
         matches!(self, WinnerCriteria::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WinnerCriteria::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6105,7 +6492,7 @@ pub struct InvalidEmail {
 /**
 * The message's status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EmailActivityResponseCommonFieldsStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -6115,8 +6502,8 @@ pub enum EmailActivityResponseCommonFieldsStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
@@ -6126,12 +6513,37 @@ impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
             EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
             EmailActivityResponseCommonFieldsStatus::Processed => "processed",
             EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EmailActivityResponseCommonFieldsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailActivityResponseCommonFieldsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "delivered" => EmailActivityResponseCommonFieldsStatus::Delivered,
+            "not delivered" => EmailActivityResponseCommonFieldsStatus::NotDelivered,
+            "processed" => EmailActivityResponseCommonFieldsStatus::Processed,
+            "" => EmailActivityResponseCommonFieldsStatus::Noop,
+            _ => EmailActivityResponseCommonFieldsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for EmailActivityResponseCommonFieldsStatus {
     fn default() -> EmailActivityResponseCommonFieldsStatus {
         EmailActivityResponseCommonFieldsStatus::Noop
@@ -6141,6 +6553,11 @@ impl EmailActivityResponseCommonFieldsStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailActivityResponseCommonFieldsStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EmailActivityResponseCommonFieldsStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6404,7 +6821,7 @@ pub struct SsoTeammateRequestAllOf {
 /**
 * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserType {
     #[serde(rename = "admin")]
     Admin,
@@ -6414,8 +6831,8 @@ pub enum UserType {
     Teammate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserType {
@@ -6425,12 +6842,37 @@ impl std::fmt::Display for UserType {
             UserType::Owner => "owner",
             UserType::Teammate => "teammate",
             UserType::Noop => "",
-            UserType::FallthroughString => "*",
+            UserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => UserType::Admin,
+            "owner" => UserType::Owner,
+            "teammate" => UserType::Teammate,
+            "" => UserType::Noop,
+            _ => UserType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserType {
     fn default() -> UserType {
         UserType::Noop
@@ -6440,6 +6882,11 @@ impl UserType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7108,7 +7555,7 @@ pub struct SegmentUpdate {
     pub query_dsl: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AbbvMessageStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -7118,8 +7565,8 @@ pub enum AbbvMessageStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbbvMessageStatus {
@@ -7129,12 +7576,37 @@ impl std::fmt::Display for AbbvMessageStatus {
             AbbvMessageStatus::NotDelivered => "not_delivered",
             AbbvMessageStatus::Processed => "processed",
             AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
+            AbbvMessageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AbbvMessageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AbbvMessageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "delivered" => AbbvMessageStatus::Delivered,
+            "not_delivered" => AbbvMessageStatus::NotDelivered,
+            "processed" => AbbvMessageStatus::Processed,
+            "" => AbbvMessageStatus::Noop,
+            _ => AbbvMessageStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for AbbvMessageStatus {
     fn default() -> AbbvMessageStatus {
         AbbvMessageStatus::Noop
@@ -7144,6 +7616,11 @@ impl AbbvMessageStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, AbbvMessageStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AbbvMessageStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7212,7 +7689,7 @@ pub struct Messages {
 /**
 * Name of event
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EventName {
     #[serde(rename = "bounced")]
     Bounced,
@@ -7238,8 +7715,8 @@ pub enum EventName {
     Unsubscribe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventName {
@@ -7257,12 +7734,45 @@ impl std::fmt::Display for EventName {
             EventName::SpamReport => "spam_report",
             EventName::Unsubscribe => "unsubscribe",
             EventName::Noop => "",
-            EventName::FallthroughString => "*",
+            EventName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EventName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "bounced" => EventName::Bounced,
+            "clicked" => EventName::Clicked,
+            "deferred" => EventName::Deferred,
+            "delivered" => EventName::Delivered,
+            "dropped" => EventName::Dropped,
+            "group_resubscribe" => EventName::GroupResubscribe,
+            "group_unsubscribe" => EventName::GroupUnsubscribe,
+            "opened" => EventName::Opened,
+            "processed" => EventName::Processed,
+            "spam_report" => EventName::SpamReport,
+            "unsubscribe" => EventName::Unsubscribe,
+            "" => EventName::Noop,
+            _ => EventName::Unknown(s),
+        })
+    }
+}
+
 impl Default for EventName {
     fn default() -> EventName {
         EventName::Noop
@@ -7272,12 +7782,17 @@ impl EventName {
     pub fn is_noop(&self) -> bool {
         matches!(self, EventName::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EventName::Unknown(_))
+    }
 }
 
 /**
 * Use to distinguish between types of bounces
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BounceType {
     #[serde(rename = "blocked")]
     Blocked,
@@ -7287,8 +7802,8 @@ pub enum BounceType {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -7298,12 +7813,37 @@ impl std::fmt::Display for BounceType {
             BounceType::Bounced => "bounced",
             BounceType::Expired => "expired",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BounceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BounceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blocked" => BounceType::Blocked,
+            "bounced" => BounceType::Bounced,
+            "expired" => BounceType::Expired,
+            "" => BounceType::Noop,
+            _ => BounceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -7313,6 +7853,11 @@ impl BounceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, BounceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BounceType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7380,7 +7925,7 @@ pub struct Event {
 /**
 * Whether or not the outbound IP is dedicated vs shared
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OutboundIpType {
     #[serde(rename = "dedicated")]
     Dedicated,
@@ -7388,8 +7933,8 @@ pub enum OutboundIpType {
     Shared,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutboundIpType {
@@ -7398,12 +7943,36 @@ impl std::fmt::Display for OutboundIpType {
             OutboundIpType::Dedicated => "dedicated",
             OutboundIpType::Shared => "shared",
             OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
+            OutboundIpType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OutboundIpType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OutboundIpType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "dedicated" => OutboundIpType::Dedicated,
+            "shared" => OutboundIpType::Shared,
+            "" => OutboundIpType::Noop,
+            _ => OutboundIpType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OutboundIpType {
     fn default() -> OutboundIpType {
         OutboundIpType::Noop
@@ -7413,6 +7982,11 @@ impl OutboundIpType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OutboundIpType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OutboundIpType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7783,14 +8357,14 @@ pub struct AuthenticationDomain {
 /**
 * Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AggregatedBy {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "total")]
     Total,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregatedBy {
@@ -7798,19 +8372,48 @@ impl std::fmt::Display for AggregatedBy {
         match self {
             AggregatedBy::Day => "day",
             AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
+            AggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AggregatedBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregatedBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "day" => AggregatedBy::Day,
+            "total" => AggregatedBy::Total,
+            _ => AggregatedBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for AggregatedBy {
     fn default() -> AggregatedBy {
         AggregatedBy::Total
     }
 }
+impl AggregatedBy {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AggregatedBy::Unknown(_))
+    }
+}
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GroupBy {
     #[serde(rename = "ab_phase")]
     AbPhase,
@@ -7818,8 +8421,8 @@ pub enum GroupBy {
     AbVariation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupBy {
@@ -7828,12 +8431,36 @@ impl std::fmt::Display for GroupBy {
             GroupBy::AbPhase => "ab_phase",
             GroupBy::AbVariation => "ab_variation",
             GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
+            GroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GroupBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ab_phase" => GroupBy::AbPhase,
+            "ab_variation" => GroupBy::AbVariation,
+            "" => GroupBy::Noop,
+            _ => GroupBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for GroupBy {
     fn default() -> GroupBy {
         GroupBy::Noop
@@ -7843,16 +8470,21 @@ impl GroupBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GroupBy::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TraitAutomationQueryParamsGroupBy {
     #[serde(rename = "step_id")]
     StepId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
@@ -7860,12 +8492,35 @@ impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
         match self {
             TraitAutomationQueryParamsGroupBy::StepId => "step_id",
             TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+            TraitAutomationQueryParamsGroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TraitAutomationQueryParamsGroupBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TraitAutomationQueryParamsGroupBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "step_id" => TraitAutomationQueryParamsGroupBy::StepId,
+            "" => TraitAutomationQueryParamsGroupBy::Noop,
+            _ => TraitAutomationQueryParamsGroupBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for TraitAutomationQueryParamsGroupBy {
     fn default() -> TraitAutomationQueryParamsGroupBy {
         TraitAutomationQueryParamsGroupBy::Noop
@@ -7875,9 +8530,14 @@ impl TraitAutomationQueryParamsGroupBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, TraitAutomationQueryParamsGroupBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TraitAutomationQueryParamsGroupBy::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AbPhaseId {
     #[serde(rename = "send")]
     Send,
@@ -7885,8 +8545,8 @@ pub enum AbPhaseId {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhaseId {
@@ -7895,12 +8555,36 @@ impl std::fmt::Display for AbPhaseId {
             AbPhaseId::Send => "send",
             AbPhaseId::Test => "test",
             AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
+            AbPhaseId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AbPhaseId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AbPhaseId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "send" => AbPhaseId::Send,
+            "test" => AbPhaseId::Test,
+            "" => AbPhaseId::Noop,
+            _ => AbPhaseId::Unknown(s),
+        })
+    }
+}
+
 impl Default for AbPhaseId {
     fn default() -> AbPhaseId {
         AbPhaseId::Noop
@@ -7910,12 +8594,17 @@ impl AbPhaseId {
     pub fn is_noop(&self) -> bool {
         matches!(self, AbPhaseId::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AbPhaseId::Unknown(_))
+    }
 }
 
 /**
 * How to group the statistics. Must be either "day", "week", or "month".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     #[serde(rename = "day")]
     Day,
@@ -7925,8 +8614,8 @@ pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
@@ -7936,12 +8625,37 @@ impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "day" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day,
+            "month" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month,
+            "week" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week,
+            "" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop,
+            _ => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s),
+        })
+    }
+}
+
 impl Default for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     fn default() -> TraitStatsAdvancedBaseQueryStringsAggregatedBy {
         TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop
@@ -7951,6 +8665,14 @@ impl TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     pub fn is_noop(&self) -> bool {
         matches!(self, TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8169,14 +8891,14 @@ pub struct Content {
 /**
 * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Disposition {
     #[serde(rename = "attachment")]
     Attachment,
     #[serde(rename = "inline")]
     Inline,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Disposition {
@@ -8184,17 +8906,46 @@ impl std::fmt::Display for Disposition {
         match self {
             Disposition::Attachment => "attachment",
             Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
+            Disposition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Disposition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Disposition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "attachment" => Disposition::Attachment,
+            "inline" => Disposition::Inline,
+            _ => Disposition::Unknown(s),
+        })
+    }
+}
+
 impl Default for Disposition {
     fn default() -> Disposition {
         Disposition::Attachment
     }
 }
+impl Disposition {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Disposition::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Attachments {
@@ -9598,7 +10349,7 @@ pub struct PatchScopesRequestsApproveResponse {
 /**
 * The type of alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAlertsResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9606,8 +10357,8 @@ pub enum GetAlertsResponseType {
     UsageLimit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsResponseType {
@@ -9616,12 +10367,36 @@ impl std::fmt::Display for GetAlertsResponseType {
             GetAlertsResponseType::StatsNotification => "stats_notification",
             GetAlertsResponseType::UsageLimit => "usage_limit",
             GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
+            GetAlertsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAlertsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAlertsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "stats_notification" => GetAlertsResponseType::StatsNotification,
+            "usage_limit" => GetAlertsResponseType::UsageLimit,
+            "" => GetAlertsResponseType::Noop,
+            _ => GetAlertsResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAlertsResponseType {
     fn default() -> GetAlertsResponseType {
         GetAlertsResponseType::Noop
@@ -9631,6 +10406,11 @@ impl GetAlertsResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAlertsResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetAlertsResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9781,7 +10561,7 @@ pub struct PostAlertsResponse {
 /**
 * The type of alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAlertsAlertResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9789,8 +10569,8 @@ pub enum GetAlertsAlertResponseType {
     UsageAlert,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsAlertResponseType {
@@ -9799,12 +10579,36 @@ impl std::fmt::Display for GetAlertsAlertResponseType {
             GetAlertsAlertResponseType::StatsNotification => "stats_notification",
             GetAlertsAlertResponseType::UsageAlert => "usage_alert",
             GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
+            GetAlertsAlertResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAlertsAlertResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAlertsAlertResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "stats_notification" => GetAlertsAlertResponseType::StatsNotification,
+            "usage_alert" => GetAlertsAlertResponseType::UsageAlert,
+            "" => GetAlertsAlertResponseType::Noop,
+            _ => GetAlertsAlertResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAlertsAlertResponseType {
     fn default() -> GetAlertsAlertResponseType {
         GetAlertsAlertResponseType::Noop
@@ -9814,6 +10618,11 @@ impl GetAlertsAlertResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAlertsAlertResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetAlertsAlertResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9997,7 +10806,7 @@ pub struct GetUserProfileResponse {
 /**
 * The type of account for this user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetUserAccountResponseType {
     #[serde(rename = "free")]
     Free,
@@ -10005,8 +10814,8 @@ pub enum GetUserAccountResponseType {
     Paid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetUserAccountResponseType {
@@ -10015,12 +10824,36 @@ impl std::fmt::Display for GetUserAccountResponseType {
             GetUserAccountResponseType::Free => "free",
             GetUserAccountResponseType::Paid => "paid",
             GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
+            GetUserAccountResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetUserAccountResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetUserAccountResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "free" => GetUserAccountResponseType::Free,
+            "paid" => GetUserAccountResponseType::Paid,
+            "" => GetUserAccountResponseType::Noop,
+            _ => GetUserAccountResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetUserAccountResponseType {
     fn default() -> GetUserAccountResponseType {
         GetUserAccountResponseType::Noop
@@ -10030,6 +10863,11 @@ impl GetUserAccountResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetUserAccountResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetUserAccountResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10261,14 +11099,14 @@ pub struct PutSubusersSubuserNameIpsResponse {
 /**
 * The direction you want to sort.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortByDirection {
     #[serde(rename = "asc")]
     Asc,
     #[serde(rename = "desc")]
     Desc,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByDirection {
@@ -10276,22 +11114,51 @@ impl std::fmt::Display for SortByDirection {
         match self {
             SortByDirection::Asc => "asc",
             SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
+            SortByDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortByDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortByDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "asc" => SortByDirection::Asc,
+            "desc" => SortByDirection::Desc,
+            _ => SortByDirection::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortByDirection {
     fn default() -> SortByDirection {
         SortByDirection::Desc
     }
 }
+impl SortByDirection {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortByDirection::Unknown(_))
+    }
+}
 
 /**
 * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortByMetric {
     #[serde(rename = "blocks")]
     Blocks,
@@ -10311,8 +11178,8 @@ pub enum SortByMetric {
     UniqueOpens,
     #[serde(rename = "unsubscribes")]
     Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByMetric {
@@ -10327,17 +11194,53 @@ impl std::fmt::Display for SortByMetric {
             SortByMetric::UniqueClicks => "unique_clicks",
             SortByMetric::UniqueOpens => "unique_opens",
             SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
+            SortByMetric::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortByMetric {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortByMetric {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "blocks" => SortByMetric::Blocks,
+            "bounces" => SortByMetric::Bounces,
+            "clicks" => SortByMetric::Clicks,
+            "delivered" => SortByMetric::Delivered,
+            "opens" => SortByMetric::Opens,
+            "requests" => SortByMetric::Requests,
+            "unique_clicks" => SortByMetric::UniqueClicks,
+            "unique_opens" => SortByMetric::UniqueOpens,
+            "unsubscribes" => SortByMetric::Unsubscribes,
+            _ => SortByMetric::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortByMetric {
     fn default() -> SortByMetric {
         SortByMetric::Delivered
     }
 }
+impl SortByMetric {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortByMetric::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksRequest {
@@ -10583,7 +11486,7 @@ pub struct PostValidationsEmailRequest {
 /**
 * A generic classification of whether or not the email address is valid.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Verdict {
     #[serde(rename = "Invalid")]
     Invalid,
@@ -10593,8 +11496,8 @@ pub enum Verdict {
     Valid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Verdict {
@@ -10604,12 +11507,37 @@ impl std::fmt::Display for Verdict {
             Verdict::Risky => "Risky",
             Verdict::Valid => "Valid",
             Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
+            Verdict::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Verdict {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Verdict {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Invalid" => Verdict::Invalid,
+            "Risky" => Verdict::Risky,
+            "Valid" => Verdict::Valid,
+            "" => Verdict::Noop,
+            _ => Verdict::Unknown(s),
+        })
+    }
+}
+
 impl Default for Verdict {
     fn default() -> Verdict {
         Verdict::Noop
@@ -10619,6 +11547,11 @@ impl Verdict {
     pub fn is_noop(&self) -> bool {
         matches!(self, Verdict::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Verdict::Unknown(_))
+    }
 }
 
 /// Checks on the domain portion of the email address.
@@ -11866,14 +12799,14 @@ pub struct Notifications {
 /**
 * File type for export file. Choose from `json` or `csv`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "json")]
     Json,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -11881,17 +12814,46 @@ impl std::fmt::Display for FileType {
         match self {
             FileType::Csv => "csv",
             FileType::Json => "json",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "csv" => FileType::Csv,
+            "json" => FileType::Json,
+            _ => FileType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Csv
     }
 }
+impl FileType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FileType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsExportsRequest {
@@ -11980,14 +12942,14 @@ pub struct PostMcContactsSearchResponse {
 /**
 * Upload file type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PutMcContactsImportsRequestFileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMcContactsImportsRequestFileType {
@@ -11995,12 +12957,35 @@ impl std::fmt::Display for PutMcContactsImportsRequestFileType {
         match self {
             PutMcContactsImportsRequestFileType::Csv => "csv",
             PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+            PutMcContactsImportsRequestFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PutMcContactsImportsRequestFileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PutMcContactsImportsRequestFileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "csv" => PutMcContactsImportsRequestFileType::Csv,
+            "" => PutMcContactsImportsRequestFileType::Noop,
+            _ => PutMcContactsImportsRequestFileType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PutMcContactsImportsRequestFileType {
     fn default() -> PutMcContactsImportsRequestFileType {
         PutMcContactsImportsRequestFileType::Noop
@@ -12010,6 +12995,11 @@ impl PutMcContactsImportsRequestFileType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PutMcContactsImportsRequestFileType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PutMcContactsImportsRequestFileType::Unknown(_))
+    }
 }
 
 /// All of the following types are flattened into one object:
@@ -12518,14 +13508,14 @@ pub struct PostMarketingSinglesendsRequest {
 /**
 * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SendAt {
     #[serde(rename = "now")]
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendAt {
@@ -12533,12 +13523,35 @@ impl std::fmt::Display for SendAt {
         match self {
             SendAt::Now => "now",
             SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
+            SendAt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SendAt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SendAt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "now" => SendAt::Now,
+            "" => SendAt::Noop,
+            _ => SendAt::Unknown(s),
+        })
+    }
+}
+
 impl Default for SendAt {
     fn default() -> SendAt {
         SendAt::Noop
@@ -12548,6 +13561,11 @@ impl SendAt {
     pub fn is_noop(&self) -> bool {
         matches!(self, SendAt::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SendAt::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12559,14 +13577,14 @@ pub struct PutMarketingSinglesendsScheduleRequest {
     pub send_at: SendAt,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
@@ -12574,12 +13592,35 @@ impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
         match self {
             PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
             PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PutMarketingSinglesendsScheduleResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PutMarketingSinglesendsScheduleResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "scheduled" => PutMarketingSinglesendsScheduleResponseStatus::Scheduled,
+            "" => PutMarketingSinglesendsScheduleResponseStatus::Noop,
+            _ => PutMarketingSinglesendsScheduleResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PutMarketingSinglesendsScheduleResponseStatus {
     fn default() -> PutMarketingSinglesendsScheduleResponseStatus {
         PutMarketingSinglesendsScheduleResponseStatus::Noop
@@ -12589,6 +13630,14 @@ impl PutMarketingSinglesendsScheduleResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PutMarketingSinglesendsScheduleResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(_)
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13350,14 +14399,14 @@ pub struct ScheduleACampaignRequest {
 /**
 * The status of your campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ScheduleACampaignResponseStatus {
     #[serde(rename = "Scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduleACampaignResponseStatus {
@@ -13365,12 +14414,35 @@ impl std::fmt::Display for ScheduleACampaignResponseStatus {
         match self {
             ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
             ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+            ScheduleACampaignResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ScheduleACampaignResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleACampaignResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Scheduled" => ScheduleACampaignResponseStatus::Scheduled,
+            "" => ScheduleACampaignResponseStatus::Noop,
+            _ => ScheduleACampaignResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ScheduleACampaignResponseStatus {
     fn default() -> ScheduleACampaignResponseStatus {
         ScheduleACampaignResponseStatus::Noop
@@ -13380,6 +14452,11 @@ impl ScheduleACampaignResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ScheduleACampaignResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ScheduleACampaignResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13447,7 +14524,7 @@ pub struct SendATestCampaignRequest {
 /**
 * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Generations {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -13455,8 +14532,8 @@ pub enum Generations {
     Legacy,
     #[serde(rename = "legacy,dynamic")]
     LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generations {
@@ -13465,17 +14542,47 @@ impl std::fmt::Display for Generations {
             Generations::Dynamic => "dynamic",
             Generations::Legacy => "legacy",
             Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
+            Generations::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Generations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Generations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "dynamic" => Generations::Dynamic,
+            "legacy" => Generations::Legacy,
+            "legacy,dynamic" => Generations::LegacyDynamic,
+            _ => Generations::Unknown(s),
+        })
+    }
+}
+
 impl Default for Generations {
     fn default() -> Generations {
         Generations::Legacy
     }
 }
+impl Generations {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Generations::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTemplatesResponse {
@@ -13708,14 +14815,14 @@ pub struct GetMessagesResponse {
     pub messages: Vec<Messages>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PostMessagesDownloadResponseStatus {
     #[serde(rename = "pending")]
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostMessagesDownloadResponseStatus {
@@ -13723,12 +14830,35 @@ impl std::fmt::Display for PostMessagesDownloadResponseStatus {
         match self {
             PostMessagesDownloadResponseStatus::Pending => "pending",
             PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+            PostMessagesDownloadResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PostMessagesDownloadResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PostMessagesDownloadResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "pending" => PostMessagesDownloadResponseStatus::Pending,
+            "" => PostMessagesDownloadResponseStatus::Noop,
+            _ => PostMessagesDownloadResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PostMessagesDownloadResponseStatus {
     fn default() -> PostMessagesDownloadResponseStatus {
         PostMessagesDownloadResponseStatus::Noop
@@ -13738,6 +14868,11 @@ impl PostMessagesDownloadResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PostMessagesDownloadResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PostMessagesDownloadResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13866,7 +15001,7 @@ pub struct GetStatsResponseData {
 /**
 * The country you would like to see statistics for. Currently only supported for US and CA.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Country {
     #[serde(rename = "CA")]
     Ca,
@@ -13874,8 +15009,8 @@ pub enum Country {
     Us,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Country {
@@ -13884,12 +15019,36 @@ impl std::fmt::Display for Country {
             Country::Ca => "CA",
             Country::Us => "US",
             Country::Noop => "",
-            Country::FallthroughString => "*",
+            Country::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Country {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CA" => Country::Ca,
+            "US" => Country::Us,
+            "" => Country::Noop,
+            _ => Country::Unknown(s),
+        })
+    }
+}
+
 impl Default for Country {
     fn default() -> Country {
         Country::Noop
@@ -13899,6 +15058,11 @@ impl Country {
     pub fn is_noop(&self) -> bool {
         matches!(self, Country::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Country::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14001,7 +15165,7 @@ pub struct GetClientsStatsResponse {
 /**
 * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ClientType {
     #[serde(rename = "desktop")]
     Desktop,
@@ -14013,8 +15177,8 @@ pub enum ClientType {
     Webmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClientType {
@@ -14025,12 +15189,38 @@ impl std::fmt::Display for ClientType {
             ClientType::Tablet => "tablet",
             ClientType::Webmail => "webmail",
             ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
+            ClientType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ClientType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClientType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "desktop" => ClientType::Desktop,
+            "phone" => ClientType::Phone,
+            "tablet" => ClientType::Tablet,
+            "webmail" => ClientType::Webmail,
+            "" => ClientType::Noop,
+            _ => ClientType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ClientType {
     fn default() -> ClientType {
         ClientType::Noop
@@ -14040,6 +15230,11 @@ impl ClientType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ClientType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ClientType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1 +1,55 @@
+use std::time::Duration;
 
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use sendgrid_api::{Client, ClientBuilder};
+
+fn client(server: &MockServer) -> Client {
+    // Retry once, without waiting long.
+    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder()
+        .retry_bounds(Duration::from_millis(1), Duration::from_millis(10))
+        .build_with_max_retries(1);
+    let http = ClientBuilder::new()
+        .retry_policy(retry_policy)
+        .build()
+        .unwrap();
+
+    let mut client = Client::new("api-key");
+    client
+        .with_host_override(server.uri())
+        .with_http_client(http);
+    client
+}
+
+#[tokio::test]
+async fn test_retries_idempotent_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/scopes"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let result = client(&server).api_key_permissions().get_scopes().await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_does_not_retry_posts_without_idempotency_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/mail/send"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = client(&server).mail_send().post(&Default::default()).await;
+    assert!(result.is_err());
+}
@@ -147,6 +147,65 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_stream(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Inventory> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_active {
+            query_args.push(("IsActive".to_string(), is_active.to_string()));
+        }
+        if is_digital {
+            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        if !sort.is_empty() {
+            query_args.push(("Sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/inventory?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Inventory>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Inventory>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get a list of inventory items by product id.
      *
@@ -200,4 +259,48 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_product_stream(
+        &self,
+        product_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Inventory> {
+        let url = self.client.url(
+            &format!(
+                "/product/{}/inventory",
+                crate::progenitor_support::encode_path(&product_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Inventory>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Inventory>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
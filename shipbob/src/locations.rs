@@ -92,4 +92,64 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_stream(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IntegrationsLocationInternalAllOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if access_granted {
+            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+        }
+        if include_inactive {
+            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+        }
+        if receiving_enabled {
+            query_args.push((
+                "ReceivingEnabled".to_string(),
+                receiving_enabled.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/location?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::IntegrationsLocationInternalAllOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::IntegrationsLocationInternalAllOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
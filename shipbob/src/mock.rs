@@ -0,0 +1,662 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Channels`](crate::channels::Channels).
+    pub fn channels(&self) -> Channels<'_> {
+        Channels { mock: self }
+    }
+
+    /// Mock the functions of [`Inventory`](crate::inventory::Inventory).
+    pub fn inventory(&self) -> Inventory<'_> {
+        Inventory { mock: self }
+    }
+
+    /// Mock the functions of [`Locations`](crate::locations::Locations).
+    pub fn locations(&self) -> Locations<'_> {
+        Locations { mock: self }
+    }
+
+    /// Mock the functions of [`Orders`](crate::orders::Orders).
+    pub fn orders(&self) -> Orders<'_> {
+        Orders { mock: self }
+    }
+
+    /// Mock the functions of [`Products`](crate::products::Products).
+    pub fn products(&self) -> Products<'_> {
+        Products { mock: self }
+    }
+
+    /// Mock the functions of [`Receiving`](crate::receiving::Receiving).
+    pub fn receiving(&self) -> Receiving<'_> {
+        Receiving { mock: self }
+    }
+
+    /// Mock the functions of [`Returns`](crate::returns::Returns).
+    pub fn returns(&self) -> Returns<'_> {
+        Returns { mock: self }
+    }
+
+    /// Mock the functions of [`Webhooks`](crate::webhooks::Webhooks).
+    pub fn webhooks(&self) -> Webhooks<'_> {
+        Webhooks { mock: self }
+    }
+}
+
+pub struct Channels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Channels<'a> {
+    /// Mock [`Channels::get_page`](crate::channels::Channels::get_page), a `GET` to `/channel`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Channel>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/channel".to_string())
+    }
+}
+
+pub struct Inventory<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Inventory<'a> {
+    /// Mock [`Inventory::get`](crate::inventory::Inventory::get), a `GET` to `/inventory/{inventoryId}`.
+    pub fn get(
+        &self,
+        inventory_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Inventory> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/inventory/{}",
+                crate::progenitor_support::encode_path(&inventory_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Inventory::get_page`](crate::inventory::Inventory::get_page), a `GET` to `/inventory`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Inventory>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/inventory".to_string())
+    }
+
+    /// Mock [`Inventory::get_product`](crate::inventory::Inventory::get_product), a `GET` to `/product/{productId}/inventory`.
+    pub fn get_product(
+        &self,
+        product_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Inventory>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/product/{}/inventory",
+                crate::progenitor_support::encode_path(&product_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Locations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Locations<'a> {
+    /// Mock [`Locations::get_page`](crate::locations::Locations::get_page), a `GET` to `/location`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/location".to_string())
+    }
+}
+
+pub struct Orders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Orders<'a> {
+    /// Mock [`Orders::post_estimate`](crate::orders::Orders::post_estimate), a `POST` to `/order/estimate`.
+    pub fn post_estimate(&self) -> crate::mock::MockOperation<'a, crate::types::OrdersEstimate> {
+        self.mock
+            .operation(reqwest::Method::POST, "/order/estimate".to_string())
+    }
+
+    /// Mock [`Orders::get`](crate::orders::Orders::get), a `GET` to `/order/{orderId}`.
+    pub fn get(&self, order_id: i64) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}",
+                crate::progenitor_support::encode_path(&order_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_page`](crate::orders::Orders::get_page), a `GET` to `/order`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Order>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/order".to_string())
+    }
+
+    /// Mock [`Orders::post`](crate::orders::Orders::post), a `POST` to `/order`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock
+            .operation(reqwest::Method::POST, "/order".to_string())
+    }
+
+    /// Mock [`Orders::post_cancel`](crate::orders::Orders::post_cancel), a `POST` to `/order/{orderId}/cancel`.
+    pub fn post_cancel(
+        &self,
+        order_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersCanceledOrder> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/order/{}/cancel",
+                crate::progenitor_support::encode_path(&order_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_store_json`](crate::orders::Orders::get_store_json), a `GET` to `/order/{orderId}/storeOrderJson`.
+    pub fn get_store_json(&self, order_id: i64) -> crate::mock::MockOperation<'a, String> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}/storeOrderJson",
+                crate::progenitor_support::encode_path(&order_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::post_store_json`](crate::orders::Orders::post_store_json), a `POST` to `/order/{orderId}/storeOrderJson`.
+    pub fn post_store_json(&self, order_id: i64) -> crate::mock::MockOperation<'a, String> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/order/{}/storeOrderJson",
+                crate::progenitor_support::encode_path(&order_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipment`](crate::orders::Orders::get_shipment), a `GET` to `/order/{orderId}/shipment/{shipmentId}`.
+    pub fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersShipment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}/shipment/{}",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::post_shipment_cancel`](crate::orders::Orders::post_shipment_cancel), a `POST` to `/order/{orderId}/shipment/{shipmentId}/cancel`.
+    pub fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersShipment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/order/{}/shipment/{}/cancel",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipment_timeline`](crate::orders::Orders::get_shipment_timeline), a `GET` to `/order/{orderId}/shipment/{shipmentId}/timeline`.
+    pub fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipmentLog>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipments`](crate::orders::Orders::get_shipments), a `GET` to `/order/{orderId}/shipment`.
+    pub fn get_shipments(
+        &self,
+        order_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipment>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}/shipment",
+                crate::progenitor_support::encode_path(&order_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipment_logs`](crate::orders::Orders::get_shipment_logs), a `GET` to `/order/{orderId}/shipment/{shipmentId}/logs`.
+    pub fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipmentLog>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/order/{}/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipment_orders`](crate::orders::Orders::get_shipment_orders), a `GET` to `/shipment/{shipmentId}`.
+    pub fn get_shipment_orders(
+        &self,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersShipment> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/shipment/{}",
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::post_shipment_cancel_orders`](crate::orders::Orders::post_shipment_cancel_orders), a `POST` to `/shipment/{shipmentId}/cancel`.
+    pub fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersShipment> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/shipment/{}/cancel",
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::post_shipment_cancel_bulk`](crate::orders::Orders::post_shipment_cancel_bulk), a `POST` to `/shipment/cancelbulk`.
+    pub fn post_shipment_cancel_bulk(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersCanceledShipments> {
+        self.mock
+            .operation(reqwest::Method::POST, "/shipment/cancelbulk".to_string())
+    }
+
+    /// Mock [`Orders::get_shipment_timeline_orders`](crate::orders::Orders::get_shipment_timeline_orders), a `GET` to `/shipment/{shipmentId}/timeline`.
+    pub fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipmentLog>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipment_logs_orders`](crate::orders::Orders::get_shipment_logs_orders), a `GET` to `/shipment/{shipmentId}/logs`.
+    pub fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipmentLog>> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&shipment_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_shipping_method`](crate::orders::Orders::get_shipping_method), a `GET` to `/shippingmethod`.
+    pub fn get_shipping_method(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::OrdersShipMethodDetail>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/shippingmethod".to_string())
+    }
+}
+
+pub struct Products<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Products<'a> {
+    /// Mock [`Products::get_page`](crate::products::Products::get_page), a `GET` to `/product`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Product>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/product".to_string())
+    }
+
+    /// Mock [`Products::post`](crate::products::Products::post), a `POST` to `/product`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Product>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/product".to_string())
+    }
+
+    /// Mock [`Products::get`](crate::products::Products::get), a `GET` to `/product/{productId}`.
+    pub fn get(&self, product_id: i64) -> crate::mock::MockOperation<'a, crate::types::Product> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/product/{}",
+                crate::progenitor_support::encode_path(&product_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Products::put`](crate::products::Products::put), a `PUT` to `/product/{productId}`.
+    pub fn put(
+        &self,
+        product_id: i64,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::Product>> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/product/{}",
+                crate::progenitor_support::encode_path(&product_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Products::post_batch`](crate::products::Products::post_batch), a `POST` to `/product/batch`.
+    pub fn post_batch(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Product>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/product/batch".to_string())
+    }
+}
+
+pub struct Receiving<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Receiving<'a> {
+    /// Mock [`Receiving::get_fulfillment_center`](crate::receiving::Receiving::get_fulfillment_center), a `GET` to `/fulfillmentCenter`.
+    pub fn get_fulfillment_center(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::ReceivingFulfillmentCenter>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/fulfillmentCenter".to_string())
+    }
+
+    /// Mock [`Receiving::get`](crate::receiving::Receiving::get), a `GET` to `/receiving/{id}`.
+    pub fn get(&self, id: i64) -> crate::mock::MockOperation<'a, crate::types::ReceivingOrder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/receiving/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Receiving::get_label`](crate::receiving::Receiving::get_label), a `GET` to `/receiving/{id}/labels`.
+    pub fn get_label(&self, id: i64) -> crate::mock::MockOperation<'a, bytes::Bytes> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/receiving/{}/labels",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Receiving::post`](crate::receiving::Receiving::post), a `POST` to `/receiving`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::ReceivingOrder> {
+        self.mock
+            .operation(reqwest::Method::POST, "/receiving".to_string())
+    }
+
+    /// Mock [`Receiving::post_cancel`](crate::receiving::Receiving::post_cancel), a `POST` to `/receiving/{id}/cancel`.
+    pub fn post_cancel(&self, id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/receiving/{}/cancel",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Returns<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Returns<'a> {
+    /// Mock [`Returns::get`](crate::returns::Returns::get), a `GET` to `/return/{id}`.
+    pub fn get(&self, id: i64) -> crate::mock::MockOperation<'a, crate::types::ReturnOrder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/return/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Returns::put`](crate::returns::Returns::put), a `PUT` to `/return/{id}`.
+    pub fn put(&self, id: i64) -> crate::mock::MockOperation<'a, crate::types::ReturnOrder> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/return/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Returns::get_page`](crate::returns::Returns::get_page), a `GET` to `/return`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::ReturnOrder>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/return".to_string())
+    }
+
+    /// Mock [`Returns::post`](crate::returns::Returns::post), a `POST` to `/return`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::ReturnOrder> {
+        self.mock
+            .operation(reqwest::Method::POST, "/return".to_string())
+    }
+
+    /// Mock [`Returns::post_cancel`](crate::returns::Returns::post_cancel), a `POST` to `/return/{id}/cancel`.
+    pub fn post_cancel(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReturnOrder> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/return/{}/cancel",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Returns::get_status_history`](crate::returns::Returns::get_status_history), a `GET` to `/return/{id}/statushistory`.
+    pub fn get_status_history(
+        &self,
+        id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReturnOrderStatusHistory> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/return/{}/statushistory",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Webhooks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Webhooks<'a> {
+    /// Mock [`Webhooks::get_page`](crate::webhooks::Webhooks::get_page), a `GET` to `/webhook`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Webhook>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/webhook".to_string())
+    }
+
+    /// Mock [`Webhooks::post`](crate::webhooks::Webhooks::post), a `POST` to `/webhook`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Webhook> {
+        self.mock
+            .operation(reqwest::Method::POST, "/webhook".to_string())
+    }
+
+    /// Mock [`Webhooks::delete`](crate::webhooks::Webhooks::delete), a `DELETE` to `/webhook/{id}`.
+    pub fn delete(&self, id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webhook/{}",
+                crate::progenitor_support::encode_path(&id.to_string())
+            ),
+        )
+    }
+}
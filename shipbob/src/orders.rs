@@ -218,6 +218,86 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Order> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if has_tracking {
+            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_tracking_uploaded {
+            query_args.push((
+                "IsTrackingUploaded".to_string(),
+                is_tracking_uploaded.to_string(),
+            ));
+        }
+        if let Some(date) = last_update_end_date {
+            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = last_update_start_date {
+            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/order?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Order>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Order>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create Order.
      *
@@ -460,6 +540,58 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipmentLog> {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipmentLog>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipmentLog>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get all Shipments for Order.
      *
@@ -516,6 +648,56 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_shipments_stream(
+        &self,
+        order_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipment> {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipment>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipment>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get logs for one Shipment by Order Id and Shipment Id.
      *
@@ -580,6 +762,58 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipmentLog> {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipmentLog>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipmentLog>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get one Shipment by Shipment Id.
      *
@@ -725,6 +959,56 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_shipment_timeline_all_stream(
+        &self,
+        shipment_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipmentLog> {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipmentLog>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipmentLog>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get logs for one Shipment by Shipment Id.
      *
@@ -784,6 +1068,56 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_shipment_logs_all_stream(
+        &self,
+        shipment_id: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipmentLog> {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipmentLog>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipmentLog>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Get shipping methods.
      *
@@ -845,4 +1179,48 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all merchants shipping methods
+     */
+    pub fn get_all_shipping_method_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::OrdersShipMethodDetail> {
+        let url = self.client.url("/shippingmethod", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::OrdersShipMethodDetail>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::OrdersShipMethodDetail>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
 }
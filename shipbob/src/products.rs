@@ -124,6 +124,65 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Product> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !active_status.to_string().is_empty() {
+            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+        }
+        if !bundle_status.to_string().is_empty() {
+            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/product?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Product>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Product>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Add a single product to the store.
      *
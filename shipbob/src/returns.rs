@@ -232,6 +232,93 @@ impl Returns {
             )
             .await
     }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     */
+    pub fn get_all_stream(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ReturnOrder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if !fulfillment_center_ids.is_empty() {
+            query_args.push((
+                "FulfillmentCenterIds".to_string(),
+                fulfillment_center_ids.join(" "),
+            ));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !inventory_ids.is_empty() {
+            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        }
+        if !original_shipment_ids.is_empty() {
+            query_args.push((
+                "OriginalShipmentIds".to_string(),
+                original_shipment_ids.join(" "),
+            ));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        if !status.is_empty() {
+            query_args.push(("Status".to_string(), status.join(" ")));
+        }
+        if !tracking_numbers.is_empty() {
+            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/return?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::ReturnOrder>(&reqwest::Url::parse(
+                                &page,
+                            )?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::ReturnOrder>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create Return Order.
      *
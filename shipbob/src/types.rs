@@ -231,7 +231,7 @@ pub struct InventoryLotQuantity {
 /**
 * Attribute influencing the packaging requirements of this inventory item
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PackagingAttribute {
     #[serde(rename = "Book")]
     Book,
@@ -253,8 +253,8 @@ pub enum PackagingAttribute {
     Stackable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagingAttribute {
@@ -270,12 +270,43 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
             PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PackagingAttribute {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackagingAttribute {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Book" => PackagingAttribute::Book,
+            "CustomDunnage" => PackagingAttribute::CustomDunnage,
+            "CustomPackaging" => PackagingAttribute::CustomPackaging,
+            "Foldable" => PackagingAttribute::Foldable,
+            "Fragile" => PackagingAttribute::Fragile,
+            "MarketingInsert" => PackagingAttribute::MarketingInsert,
+            "None" => PackagingAttribute::None,
+            "Poster" => PackagingAttribute::Poster,
+            "Stackable" => PackagingAttribute::Stackable,
+            "" => PackagingAttribute::Noop,
+            _ => PackagingAttribute::Unknown(s),
+        })
+    }
+}
+
 impl Default for PackagingAttribute {
     fn default() -> PackagingAttribute {
         PackagingAttribute::Noop
@@ -285,6 +316,11 @@ impl PackagingAttribute {
     pub fn is_noop(&self) -> bool {
         matches!(self, PackagingAttribute::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PackagingAttribute::Unknown(_))
+    }
 }
 
 /// Information about an inventory item
@@ -657,7 +693,7 @@ pub struct OrdersChannelInfo {
 *   ShipFrom
 *   MarkFor
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "MarkFor")]
     MarkFor,
@@ -665,8 +701,8 @@ pub enum Type {
     ShipFrom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -675,12 +711,36 @@ impl std::fmt::Display for Type {
             Type::MarkFor => "MarkFor",
             Type::ShipFrom => "ShipFrom",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "MarkFor" => Type::MarkFor,
+            "ShipFrom" => Type::ShipFrom,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -690,6 +750,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 /// Address to used when creating a B2B/DropShip order.
@@ -1165,7 +1230,7 @@ pub struct OrdersMeasurements {
 /**
 * The shipment status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1189,8 +1254,8 @@ pub enum Status {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1207,12 +1272,44 @@ impl std::fmt::Display for Status {
             Status::Pending => "Pending",
             Status::Processing => "Processing",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Cancelled" => Status::Cancelled,
+            "CleanSweeped" => Status::CleanSweeped,
+            "Completed" => Status::Completed,
+            "Exception" => Status::Exception,
+            "ImportReview" => Status::ImportReview,
+            "LabeledCreated" => Status::LabeledCreated,
+            "None" => Status::None,
+            "OnHold" => Status::OnHold,
+            "Pending" => Status::Pending,
+            "Processing" => Status::Processing,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1222,12 +1319,17 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /**
 * Container type for the shipment
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PackageMaterialType {
     #[serde(rename = "Bookfold")]
     Bookfold,
@@ -1251,8 +1353,8 @@ pub enum PackageMaterialType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackageMaterialType {
@@ -1269,12 +1371,44 @@ impl std::fmt::Display for PackageMaterialType {
             PackageMaterialType::Undefined => "Undefined",
             PackageMaterialType::Unknown => "Unknown",
             PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
+            PackageMaterialType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PackageMaterialType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageMaterialType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Bookfold" => PackageMaterialType::Bookfold,
+            "Box" => PackageMaterialType::Box,
+            "BubbleMailer" => PackageMaterialType::BubbleMailer,
+            "Custom" => PackageMaterialType::Custom,
+            "FragileBox" => PackageMaterialType::FragileBox,
+            "OwnContainer" => PackageMaterialType::OwnContainer,
+            "PolyMailer" => PackageMaterialType::PolyMailer,
+            "PosterTube" => PackageMaterialType::PosterTube,
+            "Undefined" => PackageMaterialType::Undefined,
+            "Unknown" => PackageMaterialType::Unknown,
+            "" => PackageMaterialType::Noop,
+            _ => PackageMaterialType::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for PackageMaterialType {
     fn default() -> PackageMaterialType {
         PackageMaterialType::Noop
@@ -1284,12 +1418,17 @@ impl PackageMaterialType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageMaterialType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PackageMaterialType::UnknownValue(_))
+    }
 }
 
 /**
 * Status of ShipBob’s completion of the fulfillment operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EstimatedFulfillmentDateStatus {
     #[serde(rename = "AwaitingInventoryAllocation")]
     AwaitingInventoryAllocation,
@@ -1307,8 +1446,8 @@ pub enum EstimatedFulfillmentDateStatus {
     Unavailable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EstimatedFulfillmentDateStatus {
@@ -1324,12 +1463,43 @@ impl std::fmt::Display for EstimatedFulfillmentDateStatus {
             EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
             EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
             EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+            EstimatedFulfillmentDateStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EstimatedFulfillmentDateStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EstimatedFulfillmentDateStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AwaitingInventoryAllocation" => {
+                EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation
+            }
+            "AwaitingReset" => EstimatedFulfillmentDateStatus::AwaitingReset,
+            "FulfilledLate" => EstimatedFulfillmentDateStatus::FulfilledLate,
+            "FulfilledOnTime" => EstimatedFulfillmentDateStatus::FulfilledOnTime,
+            "PendingLate" => EstimatedFulfillmentDateStatus::PendingLate,
+            "PendingOnTime" => EstimatedFulfillmentDateStatus::PendingOnTime,
+            "Unavailable" => EstimatedFulfillmentDateStatus::Unavailable,
+            "" => EstimatedFulfillmentDateStatus::Noop,
+            _ => EstimatedFulfillmentDateStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for EstimatedFulfillmentDateStatus {
     fn default() -> EstimatedFulfillmentDateStatus {
         EstimatedFulfillmentDateStatus::Noop
@@ -1339,6 +1509,11 @@ impl EstimatedFulfillmentDateStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, EstimatedFulfillmentDateStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EstimatedFulfillmentDateStatus::Unknown(_))
+    }
 }
 
 /// Information about a shipment
@@ -1521,7 +1696,7 @@ pub struct OrdersShipment {
 *   
 *   Freight: Larger boxes, usually transported by truckload.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CarrierType {
     #[serde(rename = "Freight")]
     Freight,
@@ -1529,8 +1704,8 @@ pub enum CarrierType {
     Parcel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CarrierType {
@@ -1539,12 +1714,36 @@ impl std::fmt::Display for CarrierType {
             CarrierType::Freight => "Freight",
             CarrierType::Parcel => "Parcel",
             CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
+            CarrierType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CarrierType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CarrierType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Freight" => CarrierType::Freight,
+            "Parcel" => CarrierType::Parcel,
+            "" => CarrierType::Noop,
+            _ => CarrierType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CarrierType {
     fn default() -> CarrierType {
         CarrierType::Noop
@@ -1554,6 +1753,11 @@ impl CarrierType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CarrierType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CarrierType::Unknown(_))
+    }
 }
 
 /**
@@ -1565,7 +1769,7 @@ impl CarrierType {
 *   
 *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PaymentTerm {
     #[serde(rename = "Collect")]
     Collect,
@@ -1575,8 +1779,8 @@ pub enum PaymentTerm {
     ThirdParty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentTerm {
@@ -1586,12 +1790,37 @@ impl std::fmt::Display for PaymentTerm {
             PaymentTerm::Prepaid => "Prepaid",
             PaymentTerm::ThirdParty => "ThirdParty",
             PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
+            PaymentTerm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PaymentTerm {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentTerm {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Collect" => PaymentTerm::Collect,
+            "Prepaid" => PaymentTerm::Prepaid,
+            "ThirdParty" => PaymentTerm::ThirdParty,
+            "" => PaymentTerm::Noop,
+            _ => PaymentTerm::Unknown(s),
+        })
+    }
+}
+
 impl Default for PaymentTerm {
     fn default() -> PaymentTerm {
         PaymentTerm::Noop
@@ -1601,6 +1830,11 @@ impl PaymentTerm {
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentTerm::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PaymentTerm::Unknown(_))
+    }
 }
 
 /// Contains shipping properties that need to be used for fulfilling an order.
@@ -1681,7 +1915,7 @@ pub struct OrdersRetailerProgramData {
 /**
 * The order status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrderStatus {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1697,8 +1931,8 @@ pub enum OrderStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderStatus {
@@ -1711,12 +1945,40 @@ impl std::fmt::Display for OrderStatus {
             OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
             OrderStatus::Processing => "Processing",
             OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
+            OrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Cancelled" => OrderStatus::Cancelled,
+            "Exception" => OrderStatus::Exception,
+            "Fulfilled" => OrderStatus::Fulfilled,
+            "ImportReview" => OrderStatus::ImportReview,
+            "PartiallyFulfilled" => OrderStatus::PartiallyFulfilled,
+            "Processing" => OrderStatus::Processing,
+            "" => OrderStatus::Noop,
+            _ => OrderStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrderStatus {
     fn default() -> OrderStatus {
         OrderStatus::Noop
@@ -1726,12 +1988,17 @@ impl OrderStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrderStatus::Unknown(_))
+    }
 }
 
 /**
 * Shipment type of the order
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrderType {
     #[serde(rename = "B2B")]
     B2B,
@@ -1741,8 +2008,8 @@ pub enum OrderType {
     DropShip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -1752,12 +2019,37 @@ impl std::fmt::Display for OrderType {
             OrderType::Dtc => "DTC",
             OrderType::DropShip => "DropShip",
             OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
+            OrderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "B2B" => OrderType::B2B,
+            "DTC" => OrderType::Dtc,
+            "DropShip" => OrderType::DropShip,
+            "" => OrderType::Noop,
+            _ => OrderType::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrderType {
     fn default() -> OrderType {
         OrderType::Noop
@@ -1767,6 +2059,11 @@ impl OrderType {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrderType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2074,14 +2371,14 @@ pub struct OrdersCreateOrderModel {
     pub type_: Option<OrderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Action {
     #[serde(rename = "Cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -2089,12 +2386,35 @@ impl std::fmt::Display for Action {
         match self {
             Action::Cancel => "Cancel",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Cancel" => Action::Cancel,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        })
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -2104,6 +2424,11 @@ impl Action {
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Action::Unknown(_))
+    }
 }
 
 ///
@@ -2145,7 +2470,7 @@ pub struct OrdersCanceledShipment {
 /**
 * The overall result of canceling the shipments associated with the order
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OrdersCanceledOrderStatus {
     #[serde(rename = "Failure")]
     Failure,
@@ -2155,8 +2480,8 @@ pub enum OrdersCanceledOrderStatus {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersCanceledOrderStatus {
@@ -2166,12 +2491,37 @@ impl std::fmt::Display for OrdersCanceledOrderStatus {
             OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
             OrdersCanceledOrderStatus::Success => "Success",
             OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
+            OrdersCanceledOrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OrdersCanceledOrderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrdersCanceledOrderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Failure" => OrdersCanceledOrderStatus::Failure,
+            "PartialSuccess" => OrdersCanceledOrderStatus::PartialSuccess,
+            "Success" => OrdersCanceledOrderStatus::Success,
+            "" => OrdersCanceledOrderStatus::Noop,
+            _ => OrdersCanceledOrderStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for OrdersCanceledOrderStatus {
     fn default() -> OrdersCanceledOrderStatus {
         OrdersCanceledOrderStatus::Noop
@@ -2181,6 +2531,11 @@ impl OrdersCanceledOrderStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, OrdersCanceledOrderStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OrdersCanceledOrderStatus::Unknown(_))
+    }
 }
 
 ///
@@ -2369,7 +2724,7 @@ pub struct OrdersShipMethodDetail {
     pub service_level: Option<OrdersServiceLevelDetail>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProductActiveStatus {
     #[serde(rename = "Active")]
     Active,
@@ -2379,8 +2734,8 @@ pub enum ProductActiveStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductActiveStatus {
@@ -2390,12 +2745,37 @@ impl std::fmt::Display for ProductActiveStatus {
             ProductActiveStatus::Any => "Any",
             ProductActiveStatus::Inactive => "Inactive",
             ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+            ProductActiveStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProductActiveStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProductActiveStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Active" => ProductActiveStatus::Active,
+            "Any" => ProductActiveStatus::Any,
+            "Inactive" => ProductActiveStatus::Inactive,
+            "" => ProductActiveStatus::Noop,
+            _ => ProductActiveStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProductActiveStatus {
     fn default() -> ProductActiveStatus {
         ProductActiveStatus::Noop
@@ -2405,9 +2785,14 @@ impl ProductActiveStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProductActiveStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProductActiveStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ProductBundleStatus {
     #[serde(rename = "Any")]
     Any,
@@ -2417,8 +2802,8 @@ pub enum ProductBundleStatus {
     NotBundle,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductBundleStatus {
@@ -2428,12 +2813,37 @@ impl std::fmt::Display for ProductBundleStatus {
             ProductBundleStatus::Bundle => "Bundle",
             ProductBundleStatus::NotBundle => "NotBundle",
             ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+            ProductBundleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ProductBundleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProductBundleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Any" => ProductBundleStatus::Any,
+            "Bundle" => ProductBundleStatus::Bundle,
+            "NotBundle" => ProductBundleStatus::NotBundle,
+            "" => ProductBundleStatus::Noop,
+            _ => ProductBundleStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ProductBundleStatus {
     fn default() -> ProductBundleStatus {
         ProductBundleStatus::Noop
@@ -2443,6 +2853,11 @@ impl ProductBundleStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ProductBundleStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ProductBundleStatus::Unknown(_))
+    }
 }
 
 /// Information about a store channel
@@ -2920,7 +3335,7 @@ pub struct ReceivingFulfillmentCenter {
     pub zip_code: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReceivingStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -2938,8 +3353,8 @@ pub enum ReceivingStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingStatus {
@@ -2953,12 +3368,41 @@ impl std::fmt::Display for ReceivingStatus {
             ReceivingStatus::PartiallyArrived => "PartiallyArrived",
             ReceivingStatus::Processing => "Processing",
             ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+            ReceivingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReceivingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Arrived" => ReceivingStatus::Arrived,
+            "Awaiting" => ReceivingStatus::Awaiting,
+            "Cancelled" => ReceivingStatus::Cancelled,
+            "Completed" => ReceivingStatus::Completed,
+            "Incomplete" => ReceivingStatus::Incomplete,
+            "PartiallyArrived" => ReceivingStatus::PartiallyArrived,
+            "Processing" => ReceivingStatus::Processing,
+            "" => ReceivingStatus::Noop,
+            _ => ReceivingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReceivingStatus {
     fn default() -> ReceivingStatus {
         ReceivingStatus::Noop
@@ -2968,9 +3412,14 @@ impl ReceivingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReceivingStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReceivingPackageType {
     #[serde(rename = "FloorLoadedContainer")]
     FloorLoadedContainer,
@@ -2980,8 +3429,8 @@ pub enum ReceivingPackageType {
     Pallet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackageType {
@@ -2991,12 +3440,37 @@ impl std::fmt::Display for ReceivingPackageType {
             ReceivingPackageType::Package => "Package",
             ReceivingPackageType::Pallet => "Pallet",
             ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
+            ReceivingPackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReceivingPackageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingPackageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "FloorLoadedContainer" => ReceivingPackageType::FloorLoadedContainer,
+            "Package" => ReceivingPackageType::Package,
+            "Pallet" => ReceivingPackageType::Pallet,
+            "" => ReceivingPackageType::Noop,
+            _ => ReceivingPackageType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReceivingPackageType {
     fn default() -> ReceivingPackageType {
         ReceivingPackageType::Noop
@@ -3006,9 +3480,14 @@ impl ReceivingPackageType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingPackageType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReceivingPackageType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReceivingPackingType {
     #[serde(rename = "EverythingInOneBox")]
     EverythingInOneBox,
@@ -3018,8 +3497,8 @@ pub enum ReceivingPackingType {
     OneSkuPerBox,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackingType {
@@ -3029,12 +3508,37 @@ impl std::fmt::Display for ReceivingPackingType {
             ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
             ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
             ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+            ReceivingPackingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReceivingPackingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingPackingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EverythingInOneBox" => ReceivingPackingType::EverythingInOneBox,
+            "MultipleSkuPerBox" => ReceivingPackingType::MultipleSkuPerBox,
+            "OneSkuPerBox" => ReceivingPackingType::OneSkuPerBox,
+            "" => ReceivingPackingType::Noop,
+            _ => ReceivingPackingType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReceivingPackingType {
     fn default() -> ReceivingPackingType {
         ReceivingPackingType::Noop
@@ -3044,9 +3548,14 @@ impl ReceivingPackingType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingPackingType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReceivingPackingType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReceivingBoxStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3062,8 +3571,8 @@ pub enum ReceivingBoxStatus {
     Stowing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingBoxStatus {
@@ -3076,12 +3585,40 @@ impl std::fmt::Display for ReceivingBoxStatus {
             ReceivingBoxStatus::Counting => "Counting",
             ReceivingBoxStatus::Stowing => "Stowing",
             ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
+            ReceivingBoxStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReceivingBoxStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingBoxStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Arrived" => ReceivingBoxStatus::Arrived,
+            "Awaiting" => ReceivingBoxStatus::Awaiting,
+            "Cancelled" => ReceivingBoxStatus::Cancelled,
+            "Completed" => ReceivingBoxStatus::Completed,
+            "Counting" => ReceivingBoxStatus::Counting,
+            "Stowing" => ReceivingBoxStatus::Stowing,
+            "" => ReceivingBoxStatus::Noop,
+            _ => ReceivingBoxStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReceivingBoxStatus {
     fn default() -> ReceivingBoxStatus {
         ReceivingBoxStatus::Noop
@@ -3091,6 +3628,11 @@ impl ReceivingBoxStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingBoxStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReceivingBoxStatus::Unknown(_))
+    }
 }
 
 /// Information about an item contained inside a box as part of a receiving order
@@ -3457,7 +3999,7 @@ pub struct ReceivingCreateOrderModel {
     pub package_type: ReceivingPackageType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReturnStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3471,8 +4013,8 @@ pub enum ReturnStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -3484,12 +4026,39 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::Completed => "Completed",
             ReturnStatus::Processing => "Processing",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReturnStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Arrived" => ReturnStatus::Arrived,
+            "AwaitingArrival" => ReturnStatus::AwaitingArrival,
+            "Cancelled" => ReturnStatus::Cancelled,
+            "Completed" => ReturnStatus::Completed,
+            "Processing" => ReturnStatus::Processing,
+            "" => ReturnStatus::Noop,
+            _ => ReturnStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReturnStatus {
     fn default() -> ReturnStatus {
         ReturnStatus::Noop
@@ -3499,9 +4068,14 @@ impl ReturnStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReturnStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReturnsTransactionLogSource {
     #[serde(rename = "ReturnLabelInvoice")]
     ReturnLabelInvoice,
@@ -3511,8 +4085,8 @@ pub enum ReturnsTransactionLogSource {
     ReturnToSenderFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnsTransactionLogSource {
@@ -3522,12 +4096,37 @@ impl std::fmt::Display for ReturnsTransactionLogSource {
             ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
             ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
             ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
+            ReturnsTransactionLogSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReturnsTransactionLogSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnsTransactionLogSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ReturnLabelInvoice" => ReturnsTransactionLogSource::ReturnLabelInvoice,
+            "ReturnProcessingFee" => ReturnsTransactionLogSource::ReturnProcessingFee,
+            "ReturnToSenderFee" => ReturnsTransactionLogSource::ReturnToSenderFee,
+            "" => ReturnsTransactionLogSource::Noop,
+            _ => ReturnsTransactionLogSource::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReturnsTransactionLogSource {
     fn default() -> ReturnsTransactionLogSource {
         ReturnsTransactionLogSource::Noop
@@ -3537,6 +4136,11 @@ impl ReturnsTransactionLogSource {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnsTransactionLogSource::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReturnsTransactionLogSource::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3577,7 +4181,7 @@ pub struct ReturnsFulfillmentCenter {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReturnAction {
     #[serde(rename = "Default")]
     Default,
@@ -3589,8 +4193,8 @@ pub enum ReturnAction {
     Restock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnAction {
@@ -3601,12 +4205,38 @@ impl std::fmt::Display for ReturnAction {
             ReturnAction::Quarantine => "Quarantine",
             ReturnAction::Restock => "Restock",
             ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+            ReturnAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReturnAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Default" => ReturnAction::Default,
+            "Dispose" => ReturnAction::Dispose,
+            "Quarantine" => ReturnAction::Quarantine,
+            "Restock" => ReturnAction::Restock,
+            "" => ReturnAction::Noop,
+            _ => ReturnAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReturnAction {
     fn default() -> ReturnAction {
         ReturnAction::Noop
@@ -3616,9 +4246,14 @@ impl ReturnAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReturnAction::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReturnActionSource {
     #[serde(rename = "InventoryDefault")]
     InventoryDefault,
@@ -3626,8 +4261,8 @@ pub enum ReturnActionSource {
     Override,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnActionSource {
@@ -3636,12 +4271,36 @@ impl std::fmt::Display for ReturnActionSource {
             ReturnActionSource::InventoryDefault => "InventoryDefault",
             ReturnActionSource::Override => "Override",
             ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+            ReturnActionSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReturnActionSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnActionSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "InventoryDefault" => ReturnActionSource::InventoryDefault,
+            "Override" => ReturnActionSource::Override,
+            "" => ReturnActionSource::Noop,
+            _ => ReturnActionSource::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReturnActionSource {
     fn default() -> ReturnActionSource {
         ReturnActionSource::Noop
@@ -3651,6 +4310,11 @@ impl ReturnActionSource {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnActionSource::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReturnActionSource::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3736,7 +4400,7 @@ pub struct ReturnsInventoryItem {
     pub quantity: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReturnType {
     #[serde(rename = "Regular")]
     Regular,
@@ -3744,8 +4408,8 @@ pub enum ReturnType {
     ReturnToSender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnType {
@@ -3754,12 +4418,36 @@ impl std::fmt::Display for ReturnType {
             ReturnType::Regular => "Regular",
             ReturnType::ReturnToSender => "ReturnToSender",
             ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
+            ReturnType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Regular" => ReturnType::Regular,
+            "ReturnToSender" => ReturnType::ReturnToSender,
+            "" => ReturnType::Noop,
+            _ => ReturnType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReturnType {
     fn default() -> ReturnType {
         ReturnType::Noop
@@ -3769,6 +4457,11 @@ impl ReturnType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReturnType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3933,7 +4626,7 @@ pub struct ReturnsCreateReturn {
 /**
 * Order to sort results in
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "Newest")]
     Newest,
@@ -3941,8 +4634,8 @@ pub enum SortOrder {
     Oldest,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -3951,12 +4644,36 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Newest => "Newest",
             SortOrder::Oldest => "Oldest",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Newest" => SortOrder::Newest,
+            "Oldest" => SortOrder::Oldest,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        })
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -3966,6 +4683,11 @@ impl SortOrder {
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SortOrder::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3983,7 +4705,7 @@ pub struct ReturnOrderStatusHistory {
     pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WebhooksTopics {
     #[serde(rename = "order_shipped")]
     OrderShipped,
@@ -3995,8 +4717,8 @@ pub enum WebhooksTopics {
     ShipmentOnhold,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebhooksTopics {
@@ -4007,12 +4729,38 @@ impl std::fmt::Display for WebhooksTopics {
             WebhooksTopics::ShipmentException => "shipment_exception",
             WebhooksTopics::ShipmentOnhold => "shipment_onhold",
             WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+            WebhooksTopics::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WebhooksTopics {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhooksTopics {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "order_shipped" => WebhooksTopics::OrderShipped,
+            "shipment_delivered" => WebhooksTopics::ShipmentDelivered,
+            "shipment_exception" => WebhooksTopics::ShipmentException,
+            "shipment_onhold" => WebhooksTopics::ShipmentOnhold,
+            "" => WebhooksTopics::Noop,
+            _ => WebhooksTopics::Unknown(s),
+        })
+    }
+}
+
 impl Default for WebhooksTopics {
     fn default() -> WebhooksTopics {
         WebhooksTopics::Noop
@@ -4022,6 +4770,11 @@ impl WebhooksTopics {
     pub fn is_noop(&self) -> bool {
         matches!(self, WebhooksTopics::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WebhooksTopics::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4131,7 +4884,7 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IntegrationsLocationServiceTypeEnum {
     #[serde(rename = "Receiving")]
     Receiving,
@@ -4139,8 +4892,8 @@ pub enum IntegrationsLocationServiceTypeEnum {
     Returns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
@@ -4149,12 +4902,36 @@ impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
             IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
             IntegrationsLocationServiceTypeEnum::Returns => "Returns",
             IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IntegrationsLocationServiceTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IntegrationsLocationServiceTypeEnum {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Receiving" => IntegrationsLocationServiceTypeEnum::Receiving,
+            "Returns" => IntegrationsLocationServiceTypeEnum::Returns,
+            "" => IntegrationsLocationServiceTypeEnum::Noop,
+            _ => IntegrationsLocationServiceTypeEnum::Unknown(s),
+        })
+    }
+}
+
 impl Default for IntegrationsLocationServiceTypeEnum {
     fn default() -> IntegrationsLocationServiceTypeEnum {
         IntegrationsLocationServiceTypeEnum::Noop
@@ -4164,6 +4941,11 @@ impl IntegrationsLocationServiceTypeEnum {
     pub fn is_noop(&self) -> bool {
         matches!(self, IntegrationsLocationServiceTypeEnum::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IntegrationsLocationServiceTypeEnum::Unknown(_))
+    }
 }
 
 /// The service-specific address of the location. Each object contains address type, address1, address2, city, state, country, zip code, phone number, and email
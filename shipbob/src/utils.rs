@@ -1,3 +1,107 @@
+/// The contents of a file sent in a `multipart/form-data` request.
+pub enum FileContents {
+    /// Contents that are already in memory.
+    Bytes(bytes::Bytes),
+    /// A file on disk, opened when the request is sent.
+    Path(std::path::PathBuf),
+    /// A streaming body, for example one built with `reqwest::Body::wrap_stream`.
+    Stream(reqwest::Body),
+}
+
+/// A file part of a `multipart/form-data` request.
+///
+/// The file name and MIME type are sent with the part, so they should describe
+/// the upload, e.g. `FilePart::path("call.mp3").mime_type("audio/mpeg")`.
+pub struct FilePart {
+    contents: FileContents,
+    file_name: Option<String>,
+    mime_type: Option<String>,
+}
+
+impl FilePart {
+    /// Upload the given bytes.
+    pub fn bytes<B: Into<bytes::Bytes>>(bytes: B) -> Self {
+        FilePart {
+            contents: FileContents::Bytes(bytes.into()),
+            file_name: None,
+            mime_type: None,
+        }
+    }
+
+    /// Upload a file from disk. The file name defaults to the name of the file.
+    pub fn path<P: AsRef<std::path::Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        FilePart {
+            contents: FileContents::Path(path.to_path_buf()),
+            file_name: path.file_name().map(|n| n.to_string_lossy().to_string()),
+            mime_type: None,
+        }
+    }
+
+    /// Upload a streaming body.
+    pub fn stream<B: Into<reqwest::Body>>(body: B) -> Self {
+        FilePart {
+            contents: FileContents::Stream(body.into()),
+            file_name: None,
+            mime_type: None,
+        }
+    }
+
+    /// Set the file name sent with the part.
+    pub fn file_name<S: Into<String>>(mut self, file_name: S) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Set the MIME type sent with the part, e.g. `video/mp4`.
+    pub fn mime_type<S: Into<String>>(mut self, mime_type: S) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Turn the file into a part of a `reqwest` multipart form.
+    pub async fn into_part(self) -> anyhow::Result<reqwest::multipart::Part> {
+        let mut part = match self.contents {
+            FileContents::Bytes(b) => {
+                let len = b.len() as u64;
+                reqwest::multipart::Part::stream_with_length(b, len)
+            }
+            FileContents::Path(path) => {
+                let file = tokio::fs::File::open(&path).await?;
+                let len = file.metadata().await?.len();
+                reqwest::multipart::Part::stream_with_length(file, len)
+            }
+            FileContents::Stream(body) => reqwest::multipart::Part::stream(body),
+        };
+
+        if let Some(file_name) = self.file_name {
+            part = part.file_name(file_name);
+        }
+        if let Some(mime_type) = self.mime_type {
+            part = part.mime_str(&mime_type)?;
+        }
+
+        Ok(part)
+    }
+}
+
+#[cfg(test)]
+mod multipart_tests {
+    use super::FilePart;
+
+    #[tokio::test]
+    async fn test_file_part_from_path() {
+        let part = FilePart::path("/does/not/exist/recording.mp4").mime_type("video/mp4");
+        assert_eq!(part.file_name.as_deref(), Some("recording.mp4"));
+        assert!(part.into_part().await.is_err());
+
+        let part = FilePart::bytes(vec![0u8; 4])
+            .file_name("a.bin")
+            .mime_type("not a mime");
+        assert!(part.into_part().await.is_err());
+    }
+}
+
 use std::{fmt, str::FromStr};
 
 use parse_link_header::LinkMap;
@@ -633,9 +737,244 @@ pub mod deserialize_null_vector {
     }
 }
 
+/// Serializes a request body as `application/x-www-form-urlencoded`.
+///
+/// Nested objects and arrays are flattened using bracket notation, so
+/// `{"metadata": {"key": "v"}, "items": [{"price": "p"}]}` is sent as
+/// `metadata[key]=v&items[0][price]=p`. `null` values are skipped, and empty
+/// objects and arrays are sent as an empty value, like `metadata=`, which
+/// clears them.
+pub fn form_urlencode<T: serde::Serialize + ?Sized>(body: &T) -> anyhow::Result<String> {
+    let value = serde_json::to_value(body)?;
+
+    let mut pairs: Vec<(String, String)> = Default::default();
+    flatten_form_value("", &value, &mut pairs);
+
+    Ok(serde_urlencoded::to_string(pairs)?)
+}
+
+fn flatten_form_value(prefix: &str, value: &serde_json::Value, pairs: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Object(map) if map.is_empty() => push_empty_form_value(prefix, pairs),
+        serde_json::Value::Object(map) => {
+            for (k, v) in map {
+                let key = if prefix.is_empty() {
+                    k.to_string()
+                } else {
+                    format!("{}[{}]", prefix, k)
+                };
+                flatten_form_value(&key, v, pairs);
+            }
+        }
+        serde_json::Value::Array(items) if items.is_empty() => push_empty_form_value(prefix, pairs),
+        serde_json::Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                flatten_form_value(&format!("{}[{}]", prefix, i), v, pairs);
+            }
+        }
+        serde_json::Value::String(s) => pairs.push((prefix.to_string(), s.to_string())),
+        v => pairs.push((prefix.to_string(), v.to_string())),
+    }
+}
+
+fn push_empty_form_value(prefix: &str, pairs: &mut Vec<(String, String)>) {
+    // An empty body is no field at all.
+    if !prefix.is_empty() {
+        pairs.push((prefix.to_string(), String::new()));
+    }
+}
+
+/// The position of a [`Pages`] stream in a paginated listing.
+///
+/// Cursors can be serialized, so a long export can checkpoint where it is and
+/// pass the cursor back to the same `*_stream` function to resume from there.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PageCursor {
+    /// The token of the page the next item is on, `None` for the first page.
+    pub page: Option<String>,
+    /// How many items of that page were already yielded.
+    pub offset: usize,
+}
+
+/// A single page of a paginated listing.
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// The token of the next page, `None` if this is the last page.
+    pub next: Option<String>,
+}
+
+type PageFuture<T> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = anyhow::Result<Page<T>>> + Send>>;
+
+/// A stream of the items of a paginated listing.
+///
+/// Pages are only fetched once all the items of the previous page have been
+/// consumed. If fetching a page fails the error is yielded and the stream ends,
+/// pass its [`Pages::cursor`] to the same `*_stream` function to retry from the
+/// failed page.
+pub struct Pages<T> {
+    fetch: Box<dyn FnMut(Option<String>) -> PageFuture<T> + Send>,
+    cursor: PageCursor,
+    items: std::collections::VecDeque<T>,
+    next: Option<Option<String>>,
+    pending: Option<PageFuture<T>>,
+}
+
+impl<T> Pages<T> {
+    #[allow(dead_code)]
+    pub(crate) fn new<F, Fut>(cursor: Option<PageCursor>, mut fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = anyhow::Result<Page<T>>> + Send + 'static,
+    {
+        let cursor = cursor.unwrap_or_default();
+        Pages {
+            fetch: Box::new(move |page| -> PageFuture<T> { Box::pin(fetch(page)) }),
+            next: Some(cursor.page.clone()),
+            cursor,
+            items: Default::default(),
+            pending: None,
+        }
+    }
+
+    /// The position right after the last item yielded by the stream.
+    pub fn cursor(&self) -> &PageCursor {
+        &self.cursor
+    }
+}
+
+// The items are never pinned, they are only moved in and out of the buffer.
+impl<T> Unpin for Pages<T> {}
+
+impl<T> futures::Stream for Pages<T> {
+    type Item = anyhow::Result<T>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(item) = this.items.pop_front() {
+                this.cursor.offset += 1;
+                return std::task::Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(pending) = this.pending.as_mut() {
+                let result = futures::ready!(std::future::Future::poll(pending.as_mut(), cx));
+                this.pending = None;
+
+                match result {
+                    Ok(page) => {
+                        this.items = page.items.into_iter().skip(this.cursor.offset).collect();
+                        // Some APIs hand back the token of the page we just got
+                        // on the last page, so stop there instead of looping.
+                        this.next = page
+                            .next
+                            .filter(|n| !n.is_empty() && this.cursor.page.as_ref() != Some(n))
+                            .map(Some);
+                    }
+                    Err(e) => {
+                        this.next = None;
+                        return std::task::Poll::Ready(Some(Err(e)));
+                    }
+                }
+                continue;
+            }
+
+            match this.next.take() {
+                Some(page) => {
+                    if page != this.cursor.page {
+                        this.cursor = PageCursor {
+                            page: page.clone(),
+                            offset: 0,
+                        };
+                    }
+                    this.pending = Some((this.fetch)(page));
+                }
+                None => return std::task::Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// The body of a response that is not JSON, like a file download.
+///
+/// Nothing but the headers has been read when this is returned, so large
+/// files can be streamed instead of being buffered in memory.
+#[derive(Debug)]
+pub struct Download {
+    response: reqwest::Response,
+}
+
+impl Download {
+    #[allow(dead_code)]
+    pub(crate) fn new(response: reqwest::Response) -> Self {
+        Download { response }
+    }
+
+    /// The `Content-Type` of the body, if the server sent one.
+    pub fn content_type(&self) -> Option<&str> {
+        self.response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    /// The length of the body, if the server sent a `Content-Length`.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// The URL the body is downloaded from, after following any redirects.
+    pub fn url(&self) -> &reqwest::Url {
+        self.response.url()
+    }
+
+    /// Read the whole body into memory.
+    pub async fn bytes(self) -> anyhow::Result<bytes::Bytes> {
+        Ok(self
+            .response
+            .bytes()
+            .await
+            .map_err(crate::ClientError::from)?)
+    }
+
+    /// Stream the body chunk by chunk.
+    pub fn bytes_stream(self) -> impl futures::Stream<Item = anyhow::Result<bytes::Bytes>> {
+        futures::StreamExt::map(self.response.bytes_stream(), |chunk| {
+            chunk.map_err(|e| crate::ClientError::from(e).into())
+        })
+    }
+
+    /// Write the body to `writer` as it is received, returning the number of
+    /// bytes written.
+    pub async fn write_to<W>(mut self, writer: &mut W) -> anyhow::Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut written = 0;
+        while let Some(chunk) = self
+            .response
+            .chunk()
+            .await
+            .map_err(crate::ClientError::from)?
+        {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::next_link;
+    use super::{form_urlencode, next_link, Page, PageCursor, Pages};
 
     #[test]
     fn test_hyperx_next_link_compat() {
@@ -646,4 +985,113 @@ mod tests {
 
         assert_eq!("https://next-link", next);
     }
+
+    #[test]
+    fn test_form_urlencode_bracket_notation() {
+        let body = serde_json::json!({
+            "email": "jane@example.com",
+            "description": null,
+            "metadata": {
+                "order_id": "6735"
+            },
+            "items": [
+                {"price": "price_123", "quantity": 2}
+            ],
+            "off_session": true
+        });
+
+        assert_eq!(
+            form_urlencode(&body).unwrap(),
+            "email=jane%40example.com&items%5B0%5D%5Bprice%5D=price_123&items%5B0%5D%5Bquantity%5D=2&metadata%5Border_id%5D=6735&off_session=true"
+        );
+    }
+
+    #[test]
+    fn test_form_urlencode_empty_values() {
+        let body = serde_json::json!({"metadata": {}, "items": [], "name": ""});
+        assert_eq!(form_urlencode(&body).unwrap(), "items=&metadata=&name=");
+        assert_eq!(form_urlencode(&serde_json::json!({})).unwrap(), "");
+    }
+
+    fn numbers(cursor: Option<PageCursor>) -> Pages<u32> {
+        // Three pages of two numbers each, the page token is the first number.
+        Pages::new(cursor, |page: Option<String>| async move {
+            let start: u32 = page.as_deref().unwrap_or("0").parse()?;
+            Ok(Page {
+                items: vec![start, start + 1],
+                next: if start < 4 {
+                    Some((start + 2).to_string())
+                } else {
+                    None
+                },
+            })
+        })
+    }
+
+    #[test]
+    fn test_pages_resume_from_cursor() {
+        use futures::StreamExt;
+
+        futures::executor::block_on(async {
+            let mut pages = numbers(None);
+            assert_eq!(pages.next().await.unwrap().unwrap(), 0);
+            assert_eq!(pages.next().await.unwrap().unwrap(), 1);
+            assert_eq!(pages.next().await.unwrap().unwrap(), 2);
+
+            let cursor = pages.cursor().clone();
+            assert_eq!(
+                cursor,
+                PageCursor {
+                    page: Some("2".to_string()),
+                    offset: 1
+                }
+            );
+
+            let rest: Vec<u32> = numbers(Some(cursor)).map(|n| n.unwrap()).collect().await;
+            assert_eq!(rest, vec![3, 4, 5]);
+        });
+    }
+
+    #[test]
+    fn test_pages_end_after_error() {
+        use futures::StreamExt;
+
+        let fetches = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let failing = |cursor: Option<PageCursor>| {
+            let fetches = fetches.clone();
+            Pages::new(cursor, move |page: Option<String>| {
+                let fetches = fetches.clone();
+                async move {
+                    fetches.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    match page.as_deref() {
+                        None => Ok(Page {
+                            items: vec![0, 1],
+                            next: Some("2".to_string()),
+                        }),
+                        _ => anyhow::bail!("not found"),
+                    }
+                }
+            })
+        };
+
+        futures::executor::block_on(async {
+            let mut pages = failing(None);
+            let items: Vec<anyhow::Result<u32>> = (&mut pages).collect().await;
+            assert_eq!(items.len(), 3);
+            assert!(items[2].is_err());
+            assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 2);
+            assert_eq!(
+                pages.cursor(),
+                &PageCursor {
+                    page: Some("2".to_string()),
+                    offset: 0
+                }
+            );
+
+            let mut resumed = failing(Some(pages.cursor().clone()));
+            assert!(resumed.next().await.unwrap().is_err());
+            assert!(resumed.next().await.is_none());
+            assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 3);
+        });
+    }
 }
@@ -82,6 +82,51 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Webhook> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !topic.to_string().is_empty() {
+            query_args.push(("Topic".to_string(), topic.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/webhook?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::Webhook>(&reqwest::Url::parse(&page)?)
+                            .await?
+                    }
+                    None => client.get_pages::<crate::types::Webhook>(&url).await?,
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * Create a new webhook subscription.
      *
@@ -19,7 +19,7 @@ impl DeprecatedApiCalls {
      *
      * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-2021-01
      */
-    pub async fn deprecated_202101_get_call(&self) -> Result<()> {
+    pub async fn get_call(&self) -> Result<()> {
         let url = self
             .client
             .url("/admin/api/2021-01/deprecated_api_calls.json", None);
@@ -33,25 +33,4 @@ impl DeprecatedApiCalls {
             )
             .await
     }
-    /**
-     * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/deprecated_api_calls.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-unstable
-     */
-    pub async fn deprecated_unstable_get_call(&self) -> Result<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/deprecated_api_calls.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
 }
@@ -39,8 +39,9 @@
 //!     String::from("client-secret"),
 //!     String::from("redirect-uri"),
 //!     String::from("token"),
-//!     String::from("refresh-token")
-//! );
+//!     String::from("refresh-token"),
+//!     String::from("my-shop")
+//! ).unwrap();
 //! ```
 //!
 //! Alternatively, the library can search for most of the variables required for
@@ -49,6 +50,7 @@
 //! - `SHOPIFY_CLIENT_ID`
 //! - `SHOPIFY_CLIENT_SECRET`
 //! - `SHOPIFY_REDIRECT_URI`
+//! - `SHOPIFY_SHOP`
 //!
 //! And then you can create a client from the environment.
 //!
@@ -58,7 +60,7 @@
 //! let shopify = Client::new_from_env(
 //!     String::from("token"),
 //!     String::from("refresh-token")
-//! );
+//! ).unwrap();
 //! ```
 //!
 //! It is okay to pass empty values for `token` and `refresh_token`. In
@@ -70,12 +72,9 @@
 //! use shopify::Client;
 //!
 //! async fn do_call() {
-//!     let mut shopify = Client::new_from_env("", "");
+//!     let mut shopify = Client::new_from_env("", "").unwrap();
 //!
-//!     // Get the URL to request consent from the user.
-//!     // You can optionally pass in scopes. If none are provided, then the
-//!     // resulting URL will not have any scopes.
-//!     let user_consent_url = shopify.user_consent_url(&["some-scope".to_string()]);
+//!     // The `shop` module builds the URL to request consent from the user.
 //!
 //!     // In your redirect URL capture the code sent and our state.
 //!     // Send it along to the request for the token.
@@ -89,6 +88,139 @@
 //! }
 //! ```
 //!
+//! To keep the tokens in your database as they change, give the client a
+//! `TokenStore` with `set_token_store`. The client hands it the tokens whenever
+//! it gets new ones, including a refresh token rotated by a refresh. With
+//! `set_auto_access_token_refresh` enabled, a request refused with a 401 is
+//! also retried once after refreshing the access token.
+//!
+//! ## Caching responses
+//!
+//! With the `httpcache` feature, the client caches the responses to `GET`
+//! requests and sends their `ETag` and `Last-Modified` back. A `304 Not
+//! Modified` is then answered from the cache.
+//!
+//! ```toml
+//! [dependencies]
+//! shopify = { version = "0.3.0", features = ["httpcache"] }
+//! ```
+//!
+//! The cache keeps the most recently used responses in memory, or the
+//! responses in files. Implement `HttpCache` to store them elsewhere.
+//!
+//! ```ignore
+//! use shopify::http_cache::HttpCache;
+//!
+//! client.with_http_cache(<dyn HttpCache>::in_memory(1000));
+//! ```
+//!
+//! ## Configuring the HTTP client
+//!
+//! The client traces its requests and retries the transient failures up to 3
+//! times, unless sending a request again could repeat its side effects, like
+//! a `POST` without an `Idempotency-Key`. To set timeouts, a proxy, another
+//! retry policy, the user agent, default headers or more middleware, build the
+//! HTTP client with a `ClientBuilder`. Building it returns an error instead of
+//! panicking.
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use shopify::ClientBuilder;
+//!
+//! let http = ClientBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .connect_timeout(Duration::from_secs(5))
+//!     .no_retries()
+//!     .build()?;
+//! client.with_http_client(http);
+//! ```
+//!
+//! ## Rate limits
+//!
+//! When the API answers `429 Too Many Requests`, the client waits as long as
+//! its `Retry-After` header says, up to a minute, and sends the request again,
+//! up to 3 times. A request still rate limited fails with a
+//! `ClientError::RateLimited`.
+//!
+//! ## Call limit
+//!
+//! The API counts the requests in a leaky bucket, and reports its fill in the
+//! `X-Shopify-Shop-Api-Call-Limit` header. The client keeps track of it,
+//! shares it with its clones, and holds the requests that would overflow the
+//! bucket until it has leaked enough.
+//!
+//! ## Shops
+//!
+//! The Admin API and its OAuth endpoints live on the domain of each shop. Create
+//! the client with `Client::new_for_shop`, which takes the name of the shop, like
+//! `my-shop`, or its `myshopify.com` domain, and fails for any other domain. It
+//! sends the access token in the `X-Shopify-Access-Token` header, to the `2026-10`
+//! version of the Admin API unless another is picked with `with_api_version`.
+//!
+//! ```rust
+//! use shopify::{webhooks, Client};
+//!
+//! async fn install(
+//!     shopify: &mut Client,
+//!     callback_query: &str,
+//!     code: &str,
+//!     state: &str,
+//! ) -> anyhow::Result<()> {
+//!     // Send the merchant to install the app, with a state to check in the callback.
+//!     let install_url = shopify.install_url(&["read_orders"], "nonce");
+//!
+//!     // In the callback, check the query string was signed by Shopify and
+//!     // exchange the code for an access token.
+//!     anyhow::ensure!(webhooks::verify_query("client-secret", callback_query) && state == "nonce");
+//!     shopify.get_access_token(code, state).await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Resources
+//!
+//! There is one function per operation of the Admin API, sending its request to
+//! the version of the Admin API of the client, whatever the version of the
+//! operation in the spec. The functions of the orders, products, customers, inventory
+//! and fulfillments take and return the types of the `resources` module:
+//!
+//! ```rust
+//! use shopify::resources::OrderBody;
+//!
+//! async fn tag_order(shopify: &shopify::Client, id: &str) -> anyhow::Result<()> {
+//!     let mut order = shopify.orders().get_param(id, "id,tags").await?.order;
+//!     order.tags = Some(format!("{}, gift", order.tags.unwrap_or_default()));
+//!     shopify
+//!         .orders()
+//!         .update_param(id, &OrderBody { order })
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Webhooks
+//!
+//! The `webhooks` module checks the signatures Shopify puts on the requests it
+//! sends to an app: the `X-Shopify-Hmac-Sha256` header of webhooks, the `hmac`
+//! parameter of the install flow redirects and the `signature` parameter of app
+//! proxy requests. `WebhookRequest::from_request` verifies a webhook and parses it
+//! by its `X-Shopify-Topic` header:
+//!
+//! ```rust
+//! use shopify::webhooks::{Webhook, WebhookRequest};
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) -> anyhow::Result<()> {
+//!     let request = WebhookRequest::from_request("client-secret", headers, body)?;
+//!     match request.webhook {
+//!         Webhook::OrdersCreate(order) => println!("new order {:?}", order.name),
+//!         Webhook::ProductsUpdate(product) => println!("updated {:?}", product.title),
+//!         _ => {}
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
 #![allow(clippy::derive_partial_eq_without_eq)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -104,24 +236,31 @@ pub mod customers;
 pub mod deprecated_api_calls;
 pub mod discounts;
 pub mod events;
+#[cfg(feature = "httpcache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
+pub mod http_cache;
 pub mod inventory;
 pub mod metafield;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
 pub mod online_store;
 pub mod orders;
 pub mod plus;
 pub mod products;
+pub mod resources;
 pub mod sales_channels;
 pub mod shipping_and_fulfillment;
+pub mod shop;
 pub mod shopify_payments;
 pub mod store_properties;
 pub mod tendertransaction;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
+pub mod webhooks;
 
-use anyhow::{anyhow, Error, Result};
-
-pub const FALLBACK_HOST: &str = "https://{shop}.myshopify.com/admin/api/2021-07";
+use anyhow::Result;
 
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
@@ -149,6 +288,279 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// Errors returned by the client.
+///
+/// Every method returns an `anyhow::Result`. When a request fails because of the
+/// API or the transport, the error holds a `ClientError`, which can be recovered
+/// with `err.downcast_ref::<ClientError>()` and matched on.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The API responded with a non-success status code.
+    #[error("code: {status}, error: {body:?}")]
+    HttpError {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: String,
+    },
+    /// The API refused the request because a rate limit was exceeded.
+    #[error("code: {status}, rate limit exceeded")]
+    RateLimited {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// When the rate limit resets, if the response said so.
+        reset: Option<std::time::SystemTime>,
+    },
+    /// Sending the request or reading the response failed.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    /// A middleware failed while sending the request.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// The response body could not be deserialized.
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    /// The request URL could not be parsed.
+    #[error(transparent)]
+    UrlParse(#[from] url::ParseError),
+    /// Refreshing the credentials used to authenticate the request failed.
+    #[error("refreshing credentials failed: {0}")]
+    AuthRefresh(anyhow::Error),
+}
+
+impl ClientError {
+    /// The status code the API responded with, if it responded.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            ClientError::HttpError { status, .. } | ClientError::RateLimited { status, .. } => {
+                Some(*status)
+            }
+            ClientError::Reqwest(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Build the error for a response with a non-success status code.
+    pub(crate) fn from_response(
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let retry_after = header("retry-after");
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || header("x-ratelimit-remaining") == Some(0)
+            || (status == reqwest::StatusCode::FORBIDDEN && retry_after.is_some())
+        {
+            let now = std::time::SystemTime::now();
+            let reset = match (retry_after, header("x-ratelimit-reset")) {
+                (Some(secs), _) => Some(now + std::time::Duration::from_secs(secs)),
+                // Some APIs send a unix timestamp, others the number of seconds left.
+                (None, Some(reset)) if reset > 1_000_000_000 => {
+                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+                }
+                (None, Some(secs)) => Some(now + std::time::Duration::from_secs(secs)),
+                (None, None) => None,
+            };
+
+            return ClientError::RateLimited {
+                status,
+                headers,
+                reset,
+            };
+        }
+
+        ClientError::HttpError {
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        }
+    }
+}
+
+/// Builds the HTTP client the API client sends its requests with, to hand to
+/// `Client::with_http_client`.
+///
+/// By default, requests are traced and the transient failures are retried up
+/// to 3 times with increasing intervals between attempts. Only the requests
+/// that can safely be sent again are retried: the ones with an idempotent
+/// method, like `GET` or `PUT`, and the ones carrying an `Idempotency-Key`.
+pub struct ClientBuilder {
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<reqwest_retry::policies::ExponentialBackoff>,
+    retry_any_method: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    middleware: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            // Retry up to 3 times with increasing intervals between attempts.
+            retry_policy: Some(
+                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3),
+            ),
+            retry_any_method: false,
+            user_agent: None,
+            default_headers: Default::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Time out the requests, from connecting until the end of the response
+    /// body, after `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time out the connections to the server after `timeout`.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through a proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Retry the transient failures with this policy instead of the default one.
+    pub fn retry_policy(mut self, policy: reqwest_retry::policies::ExponentialBackoff) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Don't retry failed requests.
+    pub fn no_retries(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// Also retry the requests that aren't idempotent, like a `POST` without an
+    /// `Idempotency-Key`. A request that timed out may then be carried out twice.
+    pub fn retry_any_method(mut self) -> Self {
+        self.retry_any_method = true;
+        self
+    }
+
+    /// Send this `User-Agent` header with every request.
+    pub fn user_agent<A>(mut self, user_agent: A) -> Self
+    where
+        A: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send these headers with every request, besides the ones of the request.
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Add a middleware layer. The layers run in the order they are added, after
+    /// the tracing and each time a request is retried.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the HTTP client. This fails when the user agent isn't a valid header
+    /// value or when the TLS backend can't be initialized.
+    pub fn build(self) -> Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(reqwest::header::HeaderValue::from_str(&user_agent)?);
+        }
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default());
+        if let Some(retry_policy) = self.retry_policy {
+            let retry_any_method = self.retry_any_method;
+            // Retry failed requests.
+            client = client.with(reqwest_conditional_middleware::ConditionalMiddleware::new(
+                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                move |req: &reqwest::Request| {
+                    req.try_clone().is_some() && (retry_any_method || is_idempotent(req))
+                },
+            ));
+            // Send the rate limited requests again in the client, once their
+            // `Retry-After` has passed, instead of in the retry middleware.
+            client = client.with(PassRateLimited);
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+/// Whether sending the request twice has the same effect as sending it once.
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    req.method().is_idempotent() || req.headers().contains_key("idempotency-key")
+}
+
+/// Hands the `429 Too Many Requests` responses back past the retry middleware,
+/// which would send the request again without waiting out its `Retry-After`.
+struct PassRateLimited;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for PassRateLimited {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let resp = next.run(req, extensions).await?;
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(reqwest_middleware::Error::Middleware(
+                RateLimitedResponse(resp).into(),
+            ));
+        }
+        Ok(resp)
+    }
+}
+
+/// A `429 Too Many Requests` response, on its way back past the retry
+/// middleware.
+#[derive(Debug, thiserror::Error)]
+#[error("rate limited")]
+struct RateLimitedResponse(reqwest::Response);
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -156,8 +568,10 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-const TOKEN_ENDPOINT: &str = "https://{shop}.myshopify.com/admin/oauth/access_token";
-const USER_CONSENT_ENDPOINT: &str = "https://{shop}.myshopify.com/admin/oauth/authorize";
+/// The path of the token endpoint on the host.
+const TOKEN_PATH: &str = "/admin/oauth/access_token";
+/// The path of the user consent endpoint on the host.
+const USER_CONSENT_PATH: &str = "/admin/oauth/authorize";
 
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
@@ -165,11 +579,18 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: Arc<RwLock<InnerToken>>,
+    token_endpoint: String,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
 
     auto_refresh: bool,
+    token_store: Option<Arc<dyn TokenStore>>,
+    refreshing: Arc<tokio::sync::Mutex<()>>,
+    api_version: String,
+    call_limit: CallLimit,
+    #[cfg(feature = "httpcache")]
+    http_cache: crate::http_cache::BoxedHttpCache,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -211,6 +632,109 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+/// The header the API reports the fill of its leaky bucket in, like `32/40`.
+const CALL_LIMIT_HEADER: &str = "x-shopify-shop-api-call-limit";
+
+/// The fill of the leaky bucket the API counts the requests in, shared by the
+/// clones of a client. The bucket is unknown until the first response reports
+/// it, and leaks its whole size in 20 seconds.
+#[derive(Clone, Debug, Default)]
+struct CallLimit(std::sync::Arc<std::sync::Mutex<Option<Bucket>>>);
+
+#[derive(Debug)]
+struct Bucket {
+    used: f64,
+    size: f64,
+    at: std::time::Instant,
+}
+
+impl Bucket {
+    /// Let the requests leak out of the bucket until `now`.
+    fn leak(&mut self, now: std::time::Instant) {
+        let leaked = now.saturating_duration_since(self.at).as_secs_f64() * self.size / 20.0;
+        self.used = (self.used - leaked).max(0.0);
+        self.at = now;
+    }
+}
+
+impl CallLimit {
+    /// Take a place in the bucket for a request, and tell how long to wait
+    /// before sending it so the bucket doesn't overflow. The waiting requests
+    /// hold their place too, so concurrent requests queue up.
+    fn reserve(&self) -> std::time::Duration {
+        let mut bucket = self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let bucket = match bucket.as_mut() {
+            Some(bucket) => bucket,
+            None => return std::time::Duration::ZERO,
+        };
+
+        bucket.leak(std::time::Instant::now());
+        bucket.used += 1.0;
+        let over = bucket.used - bucket.size;
+        if over > 0.0 {
+            std::time::Duration::from_secs_f64(over * 20.0 / bucket.size)
+        } else {
+            std::time::Duration::ZERO
+        }
+    }
+
+    /// Take in the fill of the bucket a response reports. It only ever raises
+    /// the count, which holds the places of the requests still waiting. A
+    /// `429 Too Many Requests` means the bucket is full.
+    fn update(&self, status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap) {
+        let reported = headers
+            .get(CALL_LIMIT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| {
+                let (used, size) = v.split_once('/')?;
+                Some((
+                    used.trim().parse::<f64>().ok()?,
+                    size.trim().parse::<f64>().ok()?,
+                ))
+            })
+            .filter(|(_, size)| *size > 0.0);
+
+        let now = std::time::Instant::now();
+        let mut bucket = self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match (bucket.as_mut(), reported) {
+            (Some(bucket), Some((used, size))) => {
+                bucket.leak(now);
+                bucket.used = bucket.used.max(used);
+                bucket.size = size;
+            }
+            (None, Some((used, size))) => {
+                *bucket = Some(Bucket {
+                    used,
+                    size,
+                    at: now,
+                })
+            }
+            _ => {}
+        }
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            if let Some(bucket) = bucket.as_mut() {
+                bucket.leak(now);
+                bucket.used = bucket.used.max(bucket.size);
+            }
+        }
+    }
+}
+
+/// Persists the tokens of a client. The client hands over its tokens whenever
+/// it gets new ones, so a refresh token rotated by a refresh isn't lost.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Save the tokens the client got.
+    async fn store(&self, token: &AccessToken) -> anyhow::Result<()>;
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -224,16 +748,16 @@ struct InnerToken {
 }
 
 impl Client {
-    /// Create a new Client struct. It takes a type that can convert into
-    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
-    /// given a valid API key your requests will work.
+    /// Create a new Client struct for a shop, which fails if the shop isn't one
+    /// `with_shop` accepts.
     pub fn new<I, K, R, T, Q>(
         client_id: I,
         client_secret: K,
         redirect_uri: R,
         token: T,
         refresh_token: Q,
-    ) -> Self
+        shop: impl ToString,
+    ) -> Result<Self>
     where
         I: ToString,
         K: ToString,
@@ -241,42 +765,38 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                let host = FALLBACK_HOST.to_string();
-
-                Client {
-                    host,
-                    host_override: None,
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        let client = match ClientBuilder::new().build() {
+            Ok(client) => client,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
-        }
+        };
+
+        // Set by `with_shop`.
+        let host = String::new();
+
+        let mut client = Client {
+            host,
+            host_override: None,
+            token_endpoint: String::new(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+
+            auto_refresh: false,
+            token_store: None,
+            refreshing: Default::default(),
+            api_version: "2026-10".to_string(),
+            call_limit: CallLimit::default(),
+            #[cfg(feature = "httpcache")]
+            http_cache: <dyn crate::http_cache::HttpCache>::noop(),
+            client,
+        };
+        client.with_shop(shop)?;
+        Ok(client)
     }
 
     /// Enables or disables the automatic refreshing of access tokens upon expiration
@@ -285,6 +805,34 @@ impl Client {
         self
     }
 
+    /// Sets the store the client saves its tokens to whenever it gets new ones,
+    /// including on automatic refreshes.
+    pub fn set_token_store<S>(&mut self, store: S) -> &mut Self
+    where
+        S: TokenStore + 'static,
+    {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Keep the tokens in the client and hand them to the token store.
+    async fn save_token(&self, t: &AccessToken) -> Result<()> {
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        if let Some(store) = &self.token_store {
+            store
+                .store(t)
+                .await
+                .map_err(|e| e.context("storing the tokens failed"))?;
+        }
+
+        Ok(())
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -359,13 +907,30 @@ impl Client {
     }
 
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
-        format!(
-            "{}{}",
-            self.get_host_override()
-                .or(host)
-                .unwrap_or(self.host.as_str()),
-            path
-        )
+        let host = self
+            .get_host_override()
+            .or(host)
+            .unwrap_or(self.host.as_str());
+        match path.strip_prefix("/admin/api/") {
+            Some(rest) => {
+                let rest = rest
+                    .split_once('/')
+                    .map(|(_, rest)| rest)
+                    .unwrap_or_default();
+                format!("{}/admin/api/{}/{}", host, self.api_version, rest)
+            }
+            None => format!("{}{}", host, path),
+        }
+    }
+
+    /// Override the endpoint access tokens are requested from, like the one of
+    /// a sandbox.
+    pub fn with_token_endpoint<E>(&mut self, endpoint: E) -> &mut Self
+    where
+        E: ToString,
+    {
+        self.token_endpoint = endpoint.to_string();
+        self
     }
 
     /// Create a new Client struct from environment variables. It
@@ -374,7 +939,7 @@ impl Client {
     /// given a valid API key and your requests will work.
     /// We pass in the token and refresh token to the client so if you are storing
     /// it in a database, you can get it first.
-    pub fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
+    pub fn new_from_env<T, R>(token: T, refresh_token: R) -> Result<Self>
     where
         T: ToString,
         R: ToString,
@@ -383,36 +948,43 @@ impl Client {
         let client_secret =
             env::var("SHOPIFY_CLIENT_SECRET").expect("must set SHOPIFY_CLIENT_SECRET");
         let redirect_uri = env::var("SHOPIFY_REDIRECT_URI").expect("must set SHOPIFY_REDIRECT_URI");
+        let shop = env::var("SHOPIFY_SHOP").expect("must set SHOPIFY_SHOP");
 
-        Client::new(client_id, client_secret, redirect_uri, token, refresh_token)
+        Client::new(
+            client_id,
+            client_secret,
+            redirect_uri,
+            token,
+            refresh_token,
+            shop,
+        )
     }
 
-    /// Return a user consent url with an optional set of scopes.
-    /// If no scopes are provided, they will not be passed in the url.
-    pub fn user_consent_url(&self, scopes: &[String]) -> String {
-        let state = uuid::Uuid::new_v4();
-
-        let url = format!(
-            "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
-        );
+    /// Refresh an access token from a refresh token. Client must have a refresh token
+    /// for this to work.
+    pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let _refreshing = self.refreshing.lock().await;
+        self.fetch_refreshed_access_token().await
+    }
 
-        if scopes.is_empty() {
-            return url;
+    /// Refresh the access token, unless another request replaced `stale` while
+    /// this one waited for its turn. Refreshes run one at a time, so a refresh
+    /// token rotated by the provider is only spent once.
+    async fn refresh_stale_access_token(&self, stale: &str) -> Result<()> {
+        let _refreshing = self.refreshing.lock().await;
+        if self.token.read().await.access_token != stale {
+            return Ok(());
         }
-
-        // Add the scopes.
-        format!("{}&scope={}", url, scopes.join(" "))
+        self.fetch_refreshed_access_token().await?;
+        Ok(())
     }
 
-    /// Refresh an access token from a refresh token. Client must have a refresh token
-    /// for this to work.
-    pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+    async fn fetch_refreshed_access_token(&self) -> Result<AccessToken> {
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                anyhow::bail!("refresh token cannot be empty");
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -428,9 +1000,9 @@ impl Client {
                 ("client_secret", &self.client_secret),
                 ("redirect_uri", &self.redirect_uri),
             ];
-            let client = reqwest::Client::new();
+            let client = &self.client;
             client
-                .post(TOKEN_ENDPOINT)
+                .post(&self.token_endpoint)
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -438,16 +1010,23 @@ impl Client {
                 .await?
         };
 
+        let status = response.status();
+        if !status.is_success() {
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+            return Err(ClientError::from_response(status, headers, &body).into());
+        }
+
         // Unwrap the response.
-        let t: AccessToken = response.json().await?;
+        let mut t: AccessToken = response.json().await?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Providers that rotate refresh tokens send a new one, which replaces the
+        // one just used. The others keep accepting the current one.
+        if t.refresh_token.is_empty() {
+            t.refresh_token = self.token.read().await.refresh_token.clone();
+        }
 
-        *self.token.write().await = InnerToken {
-            access_token: t.access_token.clone(),
-            refresh_token,
-            expires_at: Self::compute_expires_at(t.expires_in),
-        };
+        self.save_token(&t).await?;
 
         Ok(t)
     }
@@ -469,32 +1048,48 @@ impl Client {
             ("redirect_uri", &self.redirect_uri),
             ("state", state),
         ];
-        let client = reqwest::Client::new();
+        let client = &self.client;
         let resp = client
-            .post(TOKEN_ENDPOINT)
+            .post(&self.token_endpoint)
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
             .send()
             .await?;
 
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?;
+            return Err(ClientError::from_response(status, headers, &body).into());
+        }
+
         // Unwrap the response.
         let t: AccessToken = resp.json().await?;
 
-        *self.token.write().await = InnerToken {
-            access_token: t.access_token.clone(),
-            refresh_token: t.refresh_token.clone(),
-            expires_at: Self::compute_expires_at(t.expires_in),
-        };
+        self.save_token(&t).await?;
 
         Ok(t)
     }
 
-    async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
+    async fn url_and_auth(
+        &self,
+        uri: &str,
+    ) -> Result<(reqwest::Url, Option<(http::header::HeaderName, String)>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
-        let auth = format!("Bearer {}", self.token.read().await.access_token);
-        parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+        let auth = self.token.read().await.access_token.clone();
+        parsed_url
+            .map(|u| {
+                (
+                    u,
+                    Some((
+                        http::header::HeaderName::from_static("x-shopify-access-token"),
+                        auth,
+                    )),
+                )
+            })
+            .map_err(|e| ClientError::from(e).into())
     }
 
     async fn make_request(
@@ -502,6 +1097,7 @@ impl Client {
         method: &reqwest::Method,
         uri: &str,
         message: Message,
+        headers: reqwest::header::HeaderMap,
     ) -> Result<reqwest::Request> {
         let (url, auth) = self.url_and_auth(uri).await?;
 
@@ -514,6 +1110,7 @@ impl Client {
             reqwest::header::ACCEPT,
             reqwest::header::HeaderValue::from_static("application/json"),
         );
+        req = req.headers(headers);
 
         if let Some(content_type) = &message.content_type {
             req = req.header(
@@ -527,15 +1124,15 @@ impl Client {
             );
         }
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         if let Some(body) = message.body {
             req = req.body(body);
         }
 
-        Ok(req.build()?)
+        Ok(req.build().map_err(ClientError::from)?)
     }
 
     async fn request_raw(
@@ -543,7 +1140,9 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         message: Message,
+        headers: reqwest::header::HeaderMap,
     ) -> Result<reqwest::Response> {
+        let mut refreshed = false;
         if self.auto_refresh {
             let expired = self.is_expired().await;
 
@@ -551,7 +1150,11 @@ impl Client {
                 // We have a known expired token, we know we need to perform a refresh prior to
                 // attempting to make a request
                 Some(true) => {
-                    self.refresh_access_token().await?;
+                    let stale = self.token.read().await.access_token.clone();
+                    self.refresh_stale_access_token(&stale)
+                        .await
+                        .map_err(ClientError::AuthRefresh)?;
+                    refreshed = true;
                 }
 
                 // We have a (theoretically) known good token available. We make an optimistic
@@ -571,12 +1174,205 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
-        let resp = self.client.execute(req).await?;
+        let sent_token = self.token.read().await.access_token.clone();
+        let req = self.make_request(&method, uri, message, headers).await?;
+        let retry = req.try_clone();
+        let resp = self.execute_with_retry_after(req).await?;
+
+        // The token was revoked or expired early. Refresh it and try once more,
+        // unless it was just refreshed or the body can't be sent again.
+        match retry {
+            Some(mut req)
+                if resp.status() == reqwest::StatusCode::UNAUTHORIZED
+                    && self.auto_refresh
+                    && !refreshed =>
+            {
+                log::debug!("request was unauthorized, refreshing the access token");
+                self.refresh_stale_access_token(&sent_token)
+                    .await
+                    .map_err(ClientError::AuthRefresh)?;
+
+                let (_, auth) = self.url_and_auth(uri).await?;
+                if let Some((name, value)) = auth {
+                    req.headers_mut()
+                        .insert(name, reqwest::header::HeaderValue::from_str(&value)?);
+                }
+                Ok(self.execute_with_retry_after(req).await?)
+            }
+            _ => Ok(resp),
+        }
+    }
+
+    /// Send the requests with this HTTP client, like one built with a
+    /// `ClientBuilder`.
+    pub fn with_http_client(
+        &mut self,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> &mut Self {
+        self.client = client;
+        self
+    }
+
+    /// Send a request, and send it again once the `Retry-After` of a `429 Too Many
+    /// Requests` response has passed, waiting up to a minute each time. This is
+    /// done up to 3 times, and not for the requests whose body can't be sent again.
+    async fn execute_with_retry_after(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        let mut retry = req.try_clone();
+        let mut resp = self.execute_with_call_limit(req).await?;
+
+        for _ in 0..3 {
+            let wait = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<f64>().ok());
+            match (retry.take(), wait) {
+                (Some(req), Some(secs))
+                    if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        && (0.0..=60.0).contains(&secs) =>
+                {
+                    log::debug!(
+                        "rate limited, sending the request again in {} seconds",
+                        secs
+                    );
+                    tokio::time::sleep(std::time::Duration::from_secs_f64(secs)).await;
+                    retry = req.try_clone();
+                    resp = self.execute_with_call_limit(req).await?;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(resp)
+    }
+
+    /// Send a request once, taking back the `429 Too Many Requests` response the
+    /// HTTP client handed back as an error, past its retry middleware.
+    async fn execute_once(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        match self.client.execute(req).await {
+            Err(reqwest_middleware::Error::Middleware(e)) => match e
+                .downcast::<RateLimitedResponse>()
+            {
+                Ok(RateLimitedResponse(resp)) => Ok(resp),
+                Err(e) => Err(ClientError::from(reqwest_middleware::Error::Middleware(e)).into()),
+            },
+            resp => Ok(resp.map_err(ClientError::from)?),
+        }
+    }
+
+    /// Send a request once the leaky bucket of the API has room for it, and take in
+    /// the fill the response reports.
+    async fn execute_with_call_limit(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        let wait = self.call_limit.reserve();
+        if !wait.is_zero() {
+            log::debug!("the call limit is near, sending the request in {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
 
+        let resp = self.execute_once(req).await?;
+        self.call_limit.update(resp.status(), resp.headers());
         Ok(resp)
     }
 
+    /// Cache the responses to `GET` requests, and revalidate them with their `ETag`
+    /// and `Last-Modified` headers.
+    #[cfg(feature = "httpcache")]
+    pub fn with_http_cache(&mut self, http_cache: crate::http_cache::BoxedHttpCache) -> &mut Self {
+        self.http_cache = http_cache;
+        self
+    }
+
+    /// Send the request and read the whole response. With the `httpcache` feature,
+    /// a `GET` is answered from the cache when the server says it didn't change.
+    async fn request_body(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        #[cfg(feature = "httpcache")]
+        if method == reqwest::Method::GET && message.body.is_none() {
+            return self.request_cached(uri, message).await;
+        }
+
+        let response = self
+            .request_raw(method, uri, message, reqwest::header::HeaderMap::new())
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        Ok((status, headers, response_body))
+    }
+
+    #[cfg(feature = "httpcache")]
+    async fn request_cached(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> Result<(
+        reqwest::StatusCode,
+        reqwest::header::HeaderMap,
+        bytes::Bytes,
+    )> {
+        let cached = crate::http_cache::lookup(&self.http_cache, uri).await;
+        let conditional_headers = cached
+            .as_ref()
+            .map(crate::http_cache::CachedResponse::conditional_headers)
+            .unwrap_or_default();
+        let content_type = message.content_type.clone();
+
+        let mut response = self
+            .request_raw(reqwest::Method::GET, uri, message, conditional_headers)
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            match cached {
+                Some(cached) => {
+                    let mut headers = response.headers().clone();
+                    // The other pages are the ones of the cached response.
+                    if let Some(link) = cached.link.as_deref().and_then(|l| l.parse().ok()) {
+                        headers.entry(reqwest::header::LINK).or_insert(link);
+                    }
+                    return Ok((reqwest::StatusCode::OK, headers, cached.body));
+                }
+                // Nothing is cached to answer a 304 with, so ask for the whole response.
+                None => {
+                    log::debug!("received a 304 without a cached response, requesting it again");
+                    let message = Message {
+                        body: None,
+                        content_type,
+                    };
+                    response = self
+                        .request_raw(
+                            reqwest::Method::GET,
+                            uri,
+                            message,
+                            reqwest::header::HeaderMap::new(),
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
+
+        if status.is_success() {
+            crate::http_cache::store(&self.http_cache, uri, &headers, &response_body).await;
+        }
+
+        Ok((status, headers, response_body))
+    }
+
     async fn request<Out>(
         &self,
         method: reqwest::Method,
@@ -586,11 +1382,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
-
-        let status = response.status();
-
-        let response_body = response.bytes().await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -601,19 +1393,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -626,19 +1408,15 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let response = self.request_raw(method, uri, message).await?;
+        let (status, headers, response_body) = self.request_body(method, uri, message).await?;
 
-        let status = response.status();
-        let link = response
-            .headers()
+        let link = headers
             .get(http::header::LINK)
             .and_then(|l| l.to_str().ok())
             .and_then(|l| parse_link_header::parse(l).ok())
             .as_ref()
             .and_then(crate::utils::next_link);
 
-        let response_body = response.bytes().await?;
-
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
 
@@ -649,24 +1427,22 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response
+                .map(|out| (link, out))
+                .map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
     /* TODO: make this more DRY */
     #[allow(dead_code)]
-    async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> Result<Out>
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -674,7 +1450,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -682,17 +1458,20 @@ impl Client {
             reqwest::header::HeaderValue::from_static("application/json"),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = self
+            .execute_with_retry_after(req.build().map_err(ClientError::from)?)
+            .await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -708,19 +1487,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -747,15 +1516,18 @@ impl Client {
             reqwest::header::HeaderValue::from_str(accept_mime_type)?,
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
-        let response = req.send().await?;
+        let response = self
+            .execute_with_retry_after(req.build().map_err(ClientError::from)?)
+            .await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -771,19 +1543,48 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(ClientError::from_response(status, headers, &response_body).into())
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
+        }
+
+        let response = self
+            .execute_with_retry_after(req.build().map_err(ClientError::from)?)
+            .await?;
+
+        let status = response.status();
 
-            Err(error)
+        if status.is_success() {
+            Ok(crate::utils::Download::new(response))
+        } else {
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await.map_err(ClientError::from)?;
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
@@ -825,8 +1626,8 @@ impl Client {
                 .unwrap(),
         );
 
-        if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        if let Some((name, value)) = auth {
+            req = req.header(name, &*value);
         }
 
         if content.len() > 1 {
@@ -835,11 +1636,14 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = self
+            .execute_with_retry_after(req.build().map_err(ClientError::from)?)
+            .await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
-        let response_body = response.bytes().await?;
+        let response_body = response.bytes().await.map_err(ClientError::from)?;
 
         if status.is_success() {
             log::debug!("Received successful response. Read payload.");
@@ -850,19 +1654,9 @@ impl Client {
             } else {
                 serde_json::from_slice::<Out>(&response_body)
             };
-            parsed_response.map_err(Error::from)
+            parsed_response.map_err(|e| ClientError::from(e).into())
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body).into())
         }
     }
 
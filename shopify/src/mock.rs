@@ -0,0 +1,3650 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Access`](crate::access::Access).
+    pub fn access(&self) -> Access<'_> {
+        Access { mock: self }
+    }
+
+    /// Mock the functions of [`Analytics`](crate::analytics::Analytics).
+    pub fn analytics(&self) -> Analytics<'_> {
+        Analytics { mock: self }
+    }
+
+    /// Mock the functions of [`Billing`](crate::billing::Billing).
+    pub fn billing(&self) -> Billing<'_> {
+        Billing { mock: self }
+    }
+
+    /// Mock the functions of [`Customers`](crate::customers::Customers).
+    pub fn customers(&self) -> Customers<'_> {
+        Customers { mock: self }
+    }
+
+    /// Mock the functions of [`DeprecatedApiCalls`](crate::deprecated_api_calls::DeprecatedApiCalls).
+    pub fn deprecated_api_calls(&self) -> DeprecatedApiCalls<'_> {
+        DeprecatedApiCalls { mock: self }
+    }
+
+    /// Mock the functions of [`Discounts`](crate::discounts::Discounts).
+    pub fn discounts(&self) -> Discounts<'_> {
+        Discounts { mock: self }
+    }
+
+    /// Mock the functions of [`Events`](crate::events::Events).
+    pub fn events(&self) -> Events<'_> {
+        Events { mock: self }
+    }
+
+    /// Mock the functions of [`Inventory`](crate::inventory::Inventory).
+    pub fn inventory(&self) -> Inventory<'_> {
+        Inventory { mock: self }
+    }
+
+    /// Mock the functions of [`Metafield`](crate::metafield::Metafield).
+    pub fn metafield(&self) -> Metafield<'_> {
+        Metafield { mock: self }
+    }
+
+    /// Mock the functions of [`OnlineStore`](crate::online_store::OnlineStore).
+    pub fn online_store(&self) -> OnlineStore<'_> {
+        OnlineStore { mock: self }
+    }
+
+    /// Mock the functions of [`Orders`](crate::orders::Orders).
+    pub fn orders(&self) -> Orders<'_> {
+        Orders { mock: self }
+    }
+
+    /// Mock the functions of [`Plus`](crate::plus::Plus).
+    pub fn plus(&self) -> Plus<'_> {
+        Plus { mock: self }
+    }
+
+    /// Mock the functions of [`Products`](crate::products::Products).
+    pub fn products(&self) -> Products<'_> {
+        Products { mock: self }
+    }
+
+    /// Mock the functions of [`SalesChannels`](crate::sales_channels::SalesChannels).
+    pub fn sales_channels(&self) -> SalesChannels<'_> {
+        SalesChannels { mock: self }
+    }
+
+    /// Mock the functions of [`ShippingAndFulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment).
+    pub fn shipping_and_fulfillment(&self) -> ShippingAndFulfillment<'_> {
+        ShippingAndFulfillment { mock: self }
+    }
+
+    /// Mock the functions of [`ShopifyPayments`](crate::shopify_payments::ShopifyPayments).
+    pub fn shopify_payments(&self) -> ShopifyPayments<'_> {
+        ShopifyPayments { mock: self }
+    }
+
+    /// Mock the functions of [`StoreProperties`](crate::store_properties::StoreProperties).
+    pub fn store_properties(&self) -> StoreProperties<'_> {
+        StoreProperties { mock: self }
+    }
+
+    /// Mock the functions of [`Tendertransaction`](crate::tendertransaction::Tendertransaction).
+    pub fn tendertransaction(&self) -> Tendertransaction<'_> {
+        Tendertransaction { mock: self }
+    }
+}
+
+pub struct Access<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Access<'a> {
+    /// Mock [`Access::get_admin_oauth_scope`](crate::access::Access::get_admin_oauth_scope), a `GET` to `/admin/oauth/access_scopes.json`.
+    pub fn get_admin_oauth_scope(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/oauth/access_scopes.json".to_string(),
+        )
+    }
+
+    /// Mock [`Access::get_storefront_token`](crate::access::Access::get_storefront_token), a `GET` to `/admin/api/2020-10/storefront_access_tokens.json`.
+    pub fn get_storefront_token(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/storefront_access_tokens.json".to_string(),
+        )
+    }
+
+    /// Mock [`Access::create_storefront_tokens`](crate::access::Access::create_storefront_tokens), a `POST` to `/admin/api/2020-10/storefront_access_tokens.json`.
+    pub fn create_storefront_tokens(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/storefront_access_tokens.json".to_string(),
+        )
+    }
+
+    /// Mock [`Access::delete_storefront_tokens_param_token`](crate::access::Access::delete_storefront_tokens_param_token), a `DELETE` to `/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json`.
+    pub fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/storefront_access_tokens/{}.json",
+                crate::progenitor_support::encode_path(storefront_access_token_id)
+            ),
+        )
+    }
+}
+
+pub struct Analytics<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Analytics<'a> {
+    /// Mock [`Analytics::get_report`](crate::analytics::Analytics::get_report), a `GET` to `/admin/api/2020-10/reports.json`.
+    pub fn get_report(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/reports.json".to_string(),
+        )
+    }
+
+    /// Mock [`Analytics::create_reports`](crate::analytics::Analytics::create_reports), a `POST` to `/admin/api/2020-10/reports.json`.
+    pub fn create_reports(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/reports.json".to_string(),
+        )
+    }
+
+    /// Mock [`Analytics::get_reports_param_report`](crate::analytics::Analytics::get_reports_param_report), a `GET` to `/admin/api/2020-10/reports/{report_id}.json`.
+    pub fn get_reports_param_report(&self, report_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/reports/{}.json",
+                crate::progenitor_support::encode_path(report_id)
+            ),
+        )
+    }
+
+    /// Mock [`Analytics::update_reports_param_report`](crate::analytics::Analytics::update_reports_param_report), a `PUT` to `/admin/api/2020-10/reports/{report_id}.json`.
+    pub fn update_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/reports/{}.json",
+                crate::progenitor_support::encode_path(report_id)
+            ),
+        )
+    }
+
+    /// Mock [`Analytics::delete_reports_param_report`](crate::analytics::Analytics::delete_reports_param_report), a `DELETE` to `/admin/api/2020-10/reports/{report_id}.json`.
+    pub fn delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/reports/{}.json",
+                crate::progenitor_support::encode_path(report_id)
+            ),
+        )
+    }
+}
+
+pub struct Billing<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Billing<'a> {
+    /// Mock [`Billing::get_application_charge`](crate::billing::Billing::get_application_charge), a `GET` to `/admin/api/2020-10/application_charges.json`.
+    pub fn get_application_charge(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/application_charges.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::create_application_charges`](crate::billing::Billing::create_application_charges), a `POST` to `/admin/api/2020-10/application_charges.json`.
+    pub fn create_application_charges(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/application_charges.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::get_application_charges_param_charge`](crate::billing::Billing::get_application_charges_param_charge), a `GET` to `/admin/api/2020-10/application_charges/{application_charge_id}.json`.
+    pub fn get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/application_charges/{}.json",
+                crate::progenitor_support::encode_path(application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::create_application_charges_param_charge_activate`](crate::billing::Billing::create_application_charges_param_charge_activate), a `POST` to `/admin/api/2020-10/application_charges/{application_charge_id}/activate.json`.
+    pub fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/application_charges/{}/activate.json",
+                crate::progenitor_support::encode_path(application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_application_credit`](crate::billing::Billing::get_application_credit), a `GET` to `/admin/api/2020-10/application_credits.json`.
+    pub fn get_application_credit(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/application_credits.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::create_application_credits`](crate::billing::Billing::create_application_credits), a `POST` to `/admin/api/2020-10/application_credits.json`.
+    pub fn create_application_credits(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/application_credits.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::get_application_credits_param_credit`](crate::billing::Billing::get_application_credits_param_credit), a `GET` to `/admin/api/2020-10/application_credits/{application_credit_id}.json`.
+    pub fn get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/application_credits/{}.json",
+                crate::progenitor_support::encode_path(application_credit_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_recurring_application_charge`](crate::billing::Billing::get_recurring_application_charge), a `GET` to `/admin/api/2020-10/recurring_application_charges.json`.
+    pub fn get_recurring_application_charge(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/recurring_application_charges.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::create_recurring_application_charges`](crate::billing::Billing::create_recurring_application_charges), a `POST` to `/admin/api/2020-10/recurring_application_charges.json`.
+    pub fn create_recurring_application_charges(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/recurring_application_charges.json".to_string(),
+        )
+    }
+
+    /// Mock [`Billing::get_recurring_application_charges_param_charge`](crate::billing::Billing::get_recurring_application_charges_param_charge), a `GET` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json`.
+    pub fn get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::delete_recurring_application_charges_param_charge`](crate::billing::Billing::delete_recurring_application_charges_param_charge), a `DELETE` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json`.
+    pub fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::create_recurring_application_charges_param_charge_activate`](crate::billing::Billing::create_recurring_application_charges_param_charge_activate), a `POST` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/activate.json`.
+    pub fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/activate.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::update_recurring_application_charges_param_charge_customize`](crate::billing::Billing::update_recurring_application_charges_param_charge_customize), a `PUT` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/customize.json`.
+    pub fn update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/customize.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_recurring_application_charges_param_charge_usage`](crate::billing::Billing::get_recurring_application_charges_param_charge_usage), a `GET` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json`.
+    pub fn get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::create_recurring_application_charges_param_charge_usage`](crate::billing::Billing::create_recurring_application_charges_param_charge_usage), a `POST` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json`.
+    pub fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_recurring_application_charges_param_charge_usage_billing`](crate::billing::Billing::get_recurring_application_charges_param_charge_usage_billing), a `GET` to `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json`.
+    pub fn get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}.json",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id)
+            ),
+        )
+    }
+}
+
+pub struct Customers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Customers<'a> {
+    /// Mock [`Customers::get`](crate::customers::Customers::get), a `GET` to `/admin/api/2020-10/customers.json`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::resources::Customers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/customers.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::create`](crate::customers::Customers::create), a `POST` to `/admin/api/2020-10/customers.json`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::resources::CustomerBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/customers.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::get_search`](crate::customers::Customers::get_search), a `GET` to `/admin/api/2020-10/customers/search.json`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::resources::Customers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/customers/search.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::get_param`](crate::customers::Customers::get_param), a `GET` to `/admin/api/2020-10/customers/{customer_id}.json`.
+    pub fn get_param(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customers/{}.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update_param`](crate::customers::Customers::update_param), a `PUT` to `/admin/api/2020-10/customers/{customer_id}.json`.
+    pub fn update_param(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/customers/{}.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_param`](crate::customers::Customers::delete_param), a `DELETE` to `/admin/api/2020-10/customers/{customer_id}.json`.
+    pub fn delete_param(&self, customer_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/customers/{}.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::create_param_account_activation_url`](crate::customers::Customers::create_param_account_activation_url), a `POST` to `/admin/api/2020-10/customers/{customer_id}/account_activation_url.json`.
+    pub fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::AccountActivationUrl> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/customers/{}/account_activation_url.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::create_param_send_invite`](crate::customers::Customers::create_param_send_invite), a `POST` to `/admin/api/2020-10/customers/{customer_id}/send_invite.json`.
+    pub fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerInviteBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/customers/{}/send_invite.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_count`](crate::customers::Customers::get_count), a `GET` to `/admin/api/2020-10/customers/count.json`.
+    pub fn get_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/customers/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::get_param_order`](crate::customers::Customers::get_param_order), a `GET` to `/admin/api/2020-10/customers/{customer_id}/orders.json`.
+    pub fn get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Orders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customers/{}/orders.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_param_addresse`](crate::customers::Customers::get_param_addresse), a `GET` to `/admin/api/2020-10/customers/{customer_id}/addresses.json`.
+    pub fn get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerAddresses> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::create_param_addresses`](crate::customers::Customers::create_param_addresses), a `POST` to `/admin/api/2020-10/customers/{customer_id}/addresses.json`.
+    pub fn create_param_addresses(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerAddressBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_param_addresses_address`](crate::customers::Customers::get_param_addresses_address), a `GET` to `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json`.
+    pub fn get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerAddressBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update_param_addresses_address`](crate::customers::Customers::update_param_addresses_address), a `PUT` to `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json`.
+    pub fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerAddressBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_param_addresses_address`](crate::customers::Customers::delete_param_addresses_address), a `DELETE` to `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json`.
+    pub fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update_param_addresses_set`](crate::customers::Customers::update_param_addresses_set), a `PUT` to `/admin/api/2020-10/customers/{customer_id}/addresses/set.json`.
+    pub fn update_param_addresses_set(
+        &self,
+        customer_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses/set.json",
+                crate::progenitor_support::encode_path(customer_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update_param_addresses_address_default`](crate::customers::Customers::update_param_addresses_address_default), a `PUT` to `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}/default.json`.
+    pub fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerAddressBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/customers/{}/addresses/{}/default.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_saved_searche`](crate::customers::Customers::get_saved_searche), a `GET` to `/admin/api/2020-10/customer_saved_searches.json`.
+    pub fn get_saved_searche(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerSavedSearches> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/customer_saved_searches.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::create_saved_searches`](crate::customers::Customers::create_saved_searches), a `POST` to `/admin/api/2020-10/customer_saved_searches.json`.
+    pub fn create_saved_searches(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerSavedSearchBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/customer_saved_searches.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::get_saved_searches_count`](crate::customers::Customers::get_saved_searches_count), a `GET` to `/admin/api/2020-10/customer_saved_searches/count.json`.
+    pub fn get_saved_searches_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/customer_saved_searches/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Customers::get_saved_searches_param_search`](crate::customers::Customers::get_saved_searches_param_search), a `GET` to `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json`.
+    pub fn get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerSavedSearchBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customer_saved_searches/{}.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::update_saved_searches_param_search`](crate::customers::Customers::update_saved_searches_param_search), a `PUT` to `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json`.
+    pub fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomerSavedSearchBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/customer_saved_searches/{}.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_saved_searches_param_search`](crate::customers::Customers::delete_saved_searches_param_search), a `DELETE` to `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json`.
+    pub fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/customer_saved_searches/{}.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_saved_searches_param_search_customers`](crate::customers::Customers::get_saved_searches_param_search_customers), a `GET` to `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}/customers.json`.
+    pub fn get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Customers> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/customer_saved_searches/{}/customers.json",
+                crate::progenitor_support::encode_path(customer_saved_search_id)
+            ),
+        )
+    }
+}
+
+pub struct DeprecatedApiCalls<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DeprecatedApiCalls<'a> {
+    /// Mock [`DeprecatedApiCalls::get_call`](crate::deprecated_api_calls::DeprecatedApiCalls::get_call), a `GET` to `/admin/api/2021-01/deprecated_api_calls.json`.
+    pub fn get_call(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2021-01/deprecated_api_calls.json".to_string(),
+        )
+    }
+}
+
+pub struct Discounts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Discounts<'a> {
+    /// Mock [`Discounts::get_price_rules_param_rule_code`](crate::discounts::Discounts::get_price_rules_param_rule_code), a `GET` to `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes.json`.
+    pub fn get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/discount_codes.json",
+                crate::progenitor_support::encode_path(price_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::create_price_rules_param_rule_codes`](crate::discounts::Discounts::create_price_rules_param_rule_codes), a `POST` to `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes.json`.
+    pub fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/discount_codes.json",
+                crate::progenitor_support::encode_path(price_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::get_price_rules_param_rule_codes_code`](crate::discounts::Discounts::get_price_rules_param_rule_codes_code), a `GET` to `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json`.
+    pub fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/discount_codes/{}.json",
+                crate::progenitor_support::encode_path(price_rule_id),
+                crate::progenitor_support::encode_path(discount_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::update_price_rules_param_rule_codes_code`](crate::discounts::Discounts::update_price_rules_param_rule_codes_code), a `PUT` to `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json`.
+    pub fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/discount_codes/{}.json",
+                crate::progenitor_support::encode_path(price_rule_id),
+                crate::progenitor_support::encode_path(discount_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::delete_price_rules_param_rule_codes_code`](crate::discounts::Discounts::delete_price_rules_param_rule_codes_code), a `DELETE` to `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json`.
+    pub fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/discount_codes/{}.json",
+                crate::progenitor_support::encode_path(price_rule_id),
+                crate::progenitor_support::encode_path(discount_code_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::get_codes_lookup`](crate::discounts::Discounts::get_codes_lookup), a `GET` to `/admin/api/2020-10/discount_codes/lookup.json`.
+    pub fn get_codes_lookup(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/discount_codes/lookup.json".to_string(),
+        )
+    }
+
+    /// Mock [`Discounts::create_price_rules_param_rule_batch`](crate::discounts::Discounts::create_price_rules_param_rule_batch), a `POST` to `/admin/api/2020-10/price_rules/{price_rule_id}/batch.json`.
+    pub fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/batch.json",
+                crate::progenitor_support::encode_path(price_rule_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::get_price_rules_param_rule_batch`](crate::discounts::Discounts::get_price_rules_param_rule_batch), a `GET` to `/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}.json`.
+    pub fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/batch/{}.json",
+                crate::progenitor_support::encode_path(price_rule_id),
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+
+    /// Mock [`Discounts::get_price_rules_param_rule_batch_code`](crate::discounts::Discounts::get_price_rules_param_rule_batch_code), a `GET` to `/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json`.
+    pub fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/price_rules/{}/batch/{}/discount_codes.json",
+                crate::progenitor_support::encode_path(price_rule_id),
+                crate::progenitor_support::encode_path(batch_id)
+            ),
+        )
+    }
+}
+
+pub struct Events<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Events<'a> {
+    /// Mock [`Events::get`](crate::events::Events::get), a `GET` to `/admin/api/2020-10/events.json`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/events.json".to_string(),
+        )
+    }
+
+    /// Mock [`Events::get_param`](crate::events::Events::get_param), a `GET` to `/admin/api/2020-10/events/{event_id}.json`.
+    pub fn get_param(&self, event_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/events/{}.json",
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::get_count`](crate::events::Events::get_count), a `GET` to `/admin/api/2020-10/events/count.json`.
+    pub fn get_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/events/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Events::get_webhook`](crate::events::Events::get_webhook), a `GET` to `/admin/api/2020-10/webhooks.json`.
+    pub fn get_webhook(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/webhooks.json".to_string(),
+        )
+    }
+
+    /// Mock [`Events::create_webhooks`](crate::events::Events::create_webhooks), a `POST` to `/admin/api/2020-10/webhooks.json`.
+    pub fn create_webhooks(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/webhooks.json".to_string(),
+        )
+    }
+
+    /// Mock [`Events::get_webhooks_count`](crate::events::Events::get_webhooks_count), a `GET` to `/admin/api/2020-10/webhooks/count.json`.
+    pub fn get_webhooks_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/webhooks/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Events::get_webhooks_param_webhook`](crate::events::Events::get_webhooks_param_webhook), a `GET` to `/admin/api/2020-10/webhooks/{webhook_id}.json`.
+    pub fn get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/webhooks/{}.json",
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::update_webhooks_param_webhook`](crate::events::Events::update_webhooks_param_webhook), a `PUT` to `/admin/api/2020-10/webhooks/{webhook_id}.json`.
+    pub fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/webhooks/{}.json",
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+
+    /// Mock [`Events::delete_webhooks_param_webhook`](crate::events::Events::delete_webhooks_param_webhook), a `DELETE` to `/admin/api/2020-10/webhooks/{webhook_id}.json`.
+    pub fn delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/webhooks/{}.json",
+                crate::progenitor_support::encode_path(webhook_id)
+            ),
+        )
+    }
+}
+
+pub struct Inventory<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Inventory<'a> {
+    /// Mock [`Inventory::get_item`](crate::inventory::Inventory::get_item), a `GET` to `/admin/api/2020-10/inventory_items.json`.
+    pub fn get_item(&self) -> crate::mock::MockOperation<'a, crate::resources::InventoryItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/inventory_items.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::get_items_param_item`](crate::inventory::Inventory::get_items_param_item), a `GET` to `/admin/api/2020-10/inventory_items/{inventory_item_id}.json`.
+    pub fn get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryItemBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/inventory_items/{}.json",
+                crate::progenitor_support::encode_path(inventory_item_id)
+            ),
+        )
+    }
+
+    /// Mock [`Inventory::update_items_param_item`](crate::inventory::Inventory::update_items_param_item), a `PUT` to `/admin/api/2020-10/inventory_items/{inventory_item_id}.json`.
+    pub fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryItemBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/inventory_items/{}.json",
+                crate::progenitor_support::encode_path(inventory_item_id)
+            ),
+        )
+    }
+
+    /// Mock [`Inventory::get_level`](crate::inventory::Inventory::get_level), a `GET` to `/admin/api/2020-10/inventory_levels.json`.
+    pub fn get_level(&self) -> crate::mock::MockOperation<'a, crate::resources::InventoryLevels> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/inventory_levels.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::delete_levels`](crate::inventory::Inventory::delete_levels), a `DELETE` to `/admin/api/2020-10/inventory_levels.json`.
+    pub fn delete_levels(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            "/admin/api/2020-10/inventory_levels.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::create_levels_adjust`](crate::inventory::Inventory::create_levels_adjust), a `POST` to `/admin/api/2020-10/inventory_levels/adjust.json`.
+    pub fn create_levels_adjust(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryLevelBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/inventory_levels/adjust.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::create_levels_connect`](crate::inventory::Inventory::create_levels_connect), a `POST` to `/admin/api/2020-10/inventory_levels/connect.json`.
+    pub fn create_levels_connect(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryLevelBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/inventory_levels/connect.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::create_levels_set`](crate::inventory::Inventory::create_levels_set), a `POST` to `/admin/api/2020-10/inventory_levels/set.json`.
+    pub fn create_levels_set(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryLevelBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/inventory_levels/set.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::get_location`](crate::inventory::Inventory::get_location), a `GET` to `/admin/api/2020-10/locations.json`.
+    pub fn get_location(&self) -> crate::mock::MockOperation<'a, crate::resources::Locations> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/locations.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::get_locations_param_location`](crate::inventory::Inventory::get_locations_param_location), a `GET` to `/admin/api/2020-10/locations/{location_id}.json`.
+    pub fn get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::LocationBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/locations/{}.json",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`Inventory::get_locations_count`](crate::inventory::Inventory::get_locations_count), a `GET` to `/admin/api/2020-10/locations/count.json`.
+    pub fn get_locations_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/locations/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Inventory::get_locations_param_location_level`](crate::inventory::Inventory::get_locations_param_location_level), a `GET` to `/admin/api/2020-10/locations/{location_id}/inventory_levels.json`.
+    pub fn get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::InventoryLevels> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/locations/{}/inventory_levels.json",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+}
+
+pub struct Metafield<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Metafield<'a> {
+    /// Mock [`Metafield::get`](crate::metafield::Metafield::get), a `GET` to `/admin/api/2020-10/metafields.json`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/metafields.json".to_string(),
+        )
+    }
+
+    /// Mock [`Metafield::create`](crate::metafield::Metafield::create), a `POST` to `/admin/api/2020-10/metafields.json`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/metafields.json".to_string(),
+        )
+    }
+
+    /// Mock [`Metafield::get_count`](crate::metafield::Metafield::get_count), a `GET` to `/admin/api/2020-10/metafields/count.json`.
+    pub fn get_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/metafields/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Metafield::get_param`](crate::metafield::Metafield::get_param), a `GET` to `/admin/api/2020-10/metafields/{metafield_id}.json`.
+    pub fn get_param(&self, metafield_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/metafields/{}.json",
+                crate::progenitor_support::encode_path(metafield_id)
+            ),
+        )
+    }
+
+    /// Mock [`Metafield::update_param`](crate::metafield::Metafield::update_param), a `PUT` to `/admin/api/2020-10/metafields/{metafield_id}.json`.
+    pub fn update_param(&self, metafield_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/metafields/{}.json",
+                crate::progenitor_support::encode_path(metafield_id)
+            ),
+        )
+    }
+
+    /// Mock [`Metafield::delete_param`](crate::metafield::Metafield::delete_param), a `DELETE` to `/admin/api/2020-10/metafields/{metafield_id}.json`.
+    pub fn delete_param(&self, metafield_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/metafields/{}.json",
+                crate::progenitor_support::encode_path(metafield_id)
+            ),
+        )
+    }
+}
+
+pub struct OnlineStore<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> OnlineStore<'a> {
+    /// Mock [`OnlineStore::get_blogs_param_blog_article`](crate::online_store::OnlineStore::get_blogs_param_blog_article), a `GET` to `/admin/api/2020-10/blogs/{blog_id}/articles.json`.
+    pub fn get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_blogs_param_blog_articles`](crate::online_store::OnlineStore::create_blogs_param_blog_articles), a `POST` to `/admin/api/2020-10/blogs/{blog_id}/articles.json`.
+    pub fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_blogs_param_blog_articles_count`](crate::online_store::OnlineStore::get_blogs_param_blog_articles_count), a `GET` to `/admin/api/2020-10/blogs/{blog_id}/articles/count.json`.
+    pub fn get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles/count.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_blogs_param_blog_articles_article`](crate::online_store::OnlineStore::get_blogs_param_blog_articles_article), a `GET` to `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json`.
+    pub fn get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles/{}.json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_blogs_param_blog_articles_article`](crate::online_store::OnlineStore::update_blogs_param_blog_articles_article), a `PUT` to `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json`.
+    pub fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles/{}.json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_blogs_param_blog_articles_article`](crate::online_store::OnlineStore::delete_blogs_param_blog_articles_article), a `DELETE` to `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json`.
+    pub fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/blogs/{}/articles/{}.json",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_articles_author`](crate::online_store::OnlineStore::get_articles_author), a `GET` to `/admin/api/2020-10/articles/authors.json`.
+    pub fn get_articles_author(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/articles/authors.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_articles_tag`](crate::online_store::OnlineStore::get_articles_tag), a `GET` to `/admin/api/2020-10/articles/tags.json`.
+    pub fn get_articles_tag(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/articles/tags.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_themes_param_theme_asset`](crate::online_store::OnlineStore::get_themes_param_theme_asset), a `GET` to `/admin/api/2020-10/themes/{theme_id}/assets.json`.
+    pub fn get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_themes_param_theme_assets`](crate::online_store::OnlineStore::update_themes_param_theme_assets), a `PUT` to `/admin/api/2020-10/themes/{theme_id}/assets.json`.
+    pub fn update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_themes_param_theme_assets`](crate::online_store::OnlineStore::delete_themes_param_theme_assets), a `DELETE` to `/admin/api/2020-10/themes/{theme_id}/assets.json`.
+    pub fn delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_blog`](crate::online_store::OnlineStore::get_blog), a `GET` to `/admin/api/2020-01/blogs.json`.
+    pub fn get_blog(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-01/blogs.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_blogs`](crate::online_store::OnlineStore::create_blogs), a `POST` to `/admin/api/2020-01/blogs.json`.
+    pub fn create_blogs(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-01/blogs.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_blogs_count`](crate::online_store::OnlineStore::get_blogs_count), a `GET` to `/admin/api/2020-01/blogs/count.json`.
+    pub fn get_blogs_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-01/blogs/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_blogs_param_blog`](crate::online_store::OnlineStore::get_blogs_param_blog), a `GET` to `/admin/api/2020-01/blogs/{blog_id}.json`.
+    pub fn get_blogs_param_blog(&self, blog_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-01/blogs/{}.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_blogs_param_blog`](crate::online_store::OnlineStore::update_blogs_param_blog), a `PUT` to `/admin/api/2020-01/blogs/{blog_id}.json`.
+    pub fn update_blogs_param_blog(&self, blog_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-01/blogs/{}.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_blogs_param_blog`](crate::online_store::OnlineStore::delete_blogs_param_blog), a `DELETE` to `/admin/api/2020-01/blogs/{blog_id}.json`.
+    pub fn delete_blogs_param_blog(&self, blog_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-01/blogs/{}.json",
+                crate::progenitor_support::encode_path(blog_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_comment`](crate::online_store::OnlineStore::get_comment), a `GET` to `/admin/api/2020-01/comments.json`.
+    pub fn get_comment(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-01/comments.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments`](crate::online_store::OnlineStore::create_comments), a `POST` to `/admin/api/2020-01/comments.json`.
+    pub fn create_comments(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-01/comments.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_comments_count`](crate::online_store::OnlineStore::get_comments_count), a `GET` to `/admin/api/2020-01/comments/count.json`.
+    pub fn get_comments_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-01/comments/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_comments_param_comment`](crate::online_store::OnlineStore::get_comments_param_comment), a `GET` to `/admin/api/2020-01/comments/{comment_id}.json`.
+    pub fn get_comments_param_comment(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-01/comments/{}.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_comments_param_comment`](crate::online_store::OnlineStore::update_comments_param_comment), a `PUT` to `/admin/api/2020-01/comments/{comment_id}.json`.
+    pub fn update_comments_param_comment(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-01/comments/{}.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments_param_comment_spam`](crate::online_store::OnlineStore::create_comments_param_comment_spam), a `POST` to `/admin/api/2020-01/comments/{comment_id}/spam.json`.
+    pub fn create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-01/comments/{}/spam.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments_param_comment_not_spam`](crate::online_store::OnlineStore::create_comments_param_comment_not_spam), a `POST` to `/admin/api/2020-01/comments/{comment_id}/not_spam.json`.
+    pub fn create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-01/comments/{}/not_spam.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments_param_comment_approve`](crate::online_store::OnlineStore::create_comments_param_comment_approve), a `POST` to `/admin/api/2020-01/comments/{comment_id}/approve.json`.
+    pub fn create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-01/comments/{}/approve.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments_param_comment_remove`](crate::online_store::OnlineStore::create_comments_param_comment_remove), a `POST` to `/admin/api/2020-01/comments/{comment_id}/remove.json`.
+    pub fn create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-01/comments/{}/remove.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_comments_param_comment_restore`](crate::online_store::OnlineStore::create_comments_param_comment_restore), a `POST` to `/admin/api/2020-01/comments/{comment_id}/restore.json`.
+    pub fn create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-01/comments/{}/restore.json",
+                crate::progenitor_support::encode_path(comment_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_redirect`](crate::online_store::OnlineStore::get_redirect), a `GET` to `/admin/api/2020-10/redirects.json`.
+    pub fn get_redirect(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/redirects.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_redirects`](crate::online_store::OnlineStore::create_redirects), a `POST` to `/admin/api/2020-10/redirects.json`.
+    pub fn create_redirects(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/redirects.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_redirects_count`](crate::online_store::OnlineStore::get_redirects_count), a `GET` to `/admin/api/2020-10/redirects/count.json`.
+    pub fn get_redirects_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/redirects/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_redirects_param_redirect`](crate::online_store::OnlineStore::get_redirects_param_redirect), a `GET` to `/admin/api/2020-10/redirects/{redirect_id}.json`.
+    pub fn get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/redirects/{}.json",
+                crate::progenitor_support::encode_path(redirect_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_redirects_param_redirect`](crate::online_store::OnlineStore::update_redirects_param_redirect), a `PUT` to `/admin/api/2020-10/redirects/{redirect_id}.json`.
+    pub fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/redirects/{}.json",
+                crate::progenitor_support::encode_path(redirect_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_redirects_param_redirect`](crate::online_store::OnlineStore::delete_redirects_param_redirect), a `DELETE` to `/admin/api/2020-10/redirects/{redirect_id}.json`.
+    pub fn delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/redirects/{}.json",
+                crate::progenitor_support::encode_path(redirect_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_script_tag`](crate::online_store::OnlineStore::get_script_tag), a `GET` to `/admin/api/2020-10/script_tags.json`.
+    pub fn get_script_tag(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/script_tags.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_script_tags`](crate::online_store::OnlineStore::create_script_tags), a `POST` to `/admin/api/2020-10/script_tags.json`.
+    pub fn create_script_tags(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/script_tags.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_script_tags_count`](crate::online_store::OnlineStore::get_script_tags_count), a `GET` to `/admin/api/2020-10/script_tags/count.json`.
+    pub fn get_script_tags_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/script_tags/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_script_tags_param_tag`](crate::online_store::OnlineStore::get_script_tags_param_tag), a `GET` to `/admin/api/2020-10/script_tags/{script_tag_id}.json`.
+    pub fn get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/script_tags/{}.json",
+                crate::progenitor_support::encode_path(script_tag_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_script_tags_param_tag`](crate::online_store::OnlineStore::update_script_tags_param_tag), a `PUT` to `/admin/api/2020-10/script_tags/{script_tag_id}.json`.
+    pub fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/script_tags/{}.json",
+                crate::progenitor_support::encode_path(script_tag_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_script_tags_param_tag`](crate::online_store::OnlineStore::delete_script_tags_param_tag), a `DELETE` to `/admin/api/2020-10/script_tags/{script_tag_id}.json`.
+    pub fn delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/script_tags/{}.json",
+                crate::progenitor_support::encode_path(script_tag_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_theme`](crate::online_store::OnlineStore::get_theme), a `GET` to `/admin/api/2020-10/themes.json`.
+    pub fn get_theme(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/themes.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::create_themes`](crate::online_store::OnlineStore::create_themes), a `POST` to `/admin/api/2020-10/themes.json`.
+    pub fn create_themes(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/themes.json".to_string(),
+        )
+    }
+
+    /// Mock [`OnlineStore::get_themes_param_theme`](crate::online_store::OnlineStore::get_themes_param_theme), a `GET` to `/admin/api/2020-10/themes/{theme_id}.json`.
+    pub fn get_themes_param_theme(&self, theme_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/themes/{}.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::update_themes_param_theme`](crate::online_store::OnlineStore::update_themes_param_theme), a `PUT` to `/admin/api/2020-10/themes/{theme_id}.json`.
+    pub fn update_themes_param_theme(&self, theme_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/themes/{}.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+
+    /// Mock [`OnlineStore::delete_themes_param_theme`](crate::online_store::OnlineStore::delete_themes_param_theme), a `DELETE` to `/admin/api/2020-10/themes/{theme_id}.json`.
+    pub fn delete_themes_param_theme(&self, theme_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/themes/{}.json",
+                crate::progenitor_support::encode_path(theme_id)
+            ),
+        )
+    }
+}
+
+pub struct Orders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Orders<'a> {
+    /// Mock [`Orders::get_checkouts_count`](crate::orders::Orders::get_checkouts_count), a `GET` to `/admin/api/2020-10/checkouts/count.json`.
+    pub fn get_checkouts_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/checkouts/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Orders::create`](crate::orders::Orders::create), a `POST` to `/admin/api/2020-04/orders.json`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-04/orders.json".to_string(),
+        )
+    }
+
+    /// Mock [`Orders::get_param`](crate::orders::Orders::get_param), a `GET` to `/admin/api/2020-04/orders/{order_id}.json`.
+    pub fn get_param(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-04/orders/{}.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::update_param`](crate::orders::Orders::update_param), a `PUT` to `/admin/api/2020-04/orders/{order_id}.json`.
+    pub fn update_param(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-04/orders/{}.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::delete_param`](crate::orders::Orders::delete_param), a `DELETE` to `/admin/api/2020-04/orders/{order_id}.json`.
+    pub fn delete_param(&self, order_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-04/orders/{}.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_count`](crate::orders::Orders::get_count), a `GET` to `/admin/api/2020-04/orders/count.json`.
+    pub fn get_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-04/orders/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Orders::create_param_close`](crate::orders::Orders::create_param_close), a `POST` to `/admin/api/2020-04/orders/{order_id}/close.json`.
+    pub fn create_param_close(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-04/orders/{}/close.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::create_param_open`](crate::orders::Orders::create_param_open), a `POST` to `/admin/api/2020-04/orders/{order_id}/open.json`.
+    pub fn create_param_open(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-04/orders/{}/open.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::create_param_cancel`](crate::orders::Orders::create_param_cancel), a `POST` to `/admin/api/2020-04/orders/{order_id}/cancel.json`.
+    pub fn create_param_cancel(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-04/orders/{}/cancel.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get`](crate::orders::Orders::get), a `GET` to `/admin/api/2020-07/orders.json`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::resources::Orders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-07/orders.json".to_string(),
+        )
+    }
+
+    /// Mock [`Orders::get_param_risk`](crate::orders::Orders::get_param_risk), a `GET` to `/admin/api/2020-10/orders/{order_id}/risks.json`.
+    pub fn get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderRisks> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/risks.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::create_param_risks`](crate::orders::Orders::create_param_risks), a `POST` to `/admin/api/2020-10/orders/{order_id}/risks.json`.
+    pub fn create_param_risks(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderRiskBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/risks.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_param_risks_risk`](crate::orders::Orders::get_param_risks_risk), a `GET` to `/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json`.
+    pub fn get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderRiskBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/risks/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(risk_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::update_param_risks_risk`](crate::orders::Orders::update_param_risks_risk), a `PUT` to `/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json`.
+    pub fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::OrderRiskBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/orders/{}/risks/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(risk_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::delete_param_risks_risk`](crate::orders::Orders::delete_param_risks_risk), a `DELETE` to `/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json`.
+    pub fn delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/orders/{}/risks/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(risk_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_param_refund`](crate::orders::Orders::get_param_refund), a `GET` to `/admin/api/2020-10/orders/{order_id}/refunds.json`.
+    pub fn get_param_refund(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Refunds> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::create_param_refunds`](crate::orders::Orders::create_param_refunds), a `POST` to `/admin/api/2020-10/orders/{order_id}/refunds.json`.
+    pub fn create_param_refunds(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::RefundBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::get_param_refunds_refund`](crate::orders::Orders::get_param_refunds_refund), a `GET` to `/admin/api/2020-10/orders/{order_id}/refunds/{refund_id}.json`.
+    pub fn get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::RefundBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/refunds/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::create_param_refunds_calculate`](crate::orders::Orders::create_param_refunds_calculate), a `POST` to `/admin/api/2020-10/orders/{order_id}/refunds/calculate.json`.
+    pub fn create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::RefundBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/refunds/calculate.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+}
+
+pub struct Plus<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Plus<'a> {
+    /// Mock [`Plus::get_gift_card`](crate::plus::Plus::get_gift_card), a `GET` to `/admin/api/2020-10/gift_cards.json`.
+    pub fn get_gift_card(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/gift_cards.json".to_string(),
+        )
+    }
+
+    /// Mock [`Plus::create_gift_cards`](crate::plus::Plus::create_gift_cards), a `POST` to `/admin/api/2020-10/gift_cards.json`.
+    pub fn create_gift_cards(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/gift_cards.json".to_string(),
+        )
+    }
+
+    /// Mock [`Plus::get_gift_cards_param_card`](crate::plus::Plus::get_gift_cards_param_card), a `GET` to `/admin/api/2020-10/gift_cards/{gift_card_id}.json`.
+    pub fn get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/gift_cards/{}.json",
+                crate::progenitor_support::encode_path(gift_card_id)
+            ),
+        )
+    }
+
+    /// Mock [`Plus::update_gift_cards_param_card`](crate::plus::Plus::update_gift_cards_param_card), a `PUT` to `/admin/api/2020-10/gift_cards/{gift_card_id}.json`.
+    pub fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/gift_cards/{}.json",
+                crate::progenitor_support::encode_path(gift_card_id)
+            ),
+        )
+    }
+
+    /// Mock [`Plus::get_gift_cards_count`](crate::plus::Plus::get_gift_cards_count), a `GET` to `/admin/api/2020-10/gift_cards/count.json`.
+    pub fn get_gift_cards_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/gift_cards/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Plus::create_gift_cards_param_card_disable`](crate::plus::Plus::create_gift_cards_param_card_disable), a `POST` to `/admin/api/2020-10/gift_cards/{gift_card_id}/disable.json`.
+    pub fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/gift_cards/{}/disable.json",
+                crate::progenitor_support::encode_path(gift_card_id)
+            ),
+        )
+    }
+
+    /// Mock [`Plus::get_gift_cards_search`](crate::plus::Plus::get_gift_cards_search), a `GET` to `/admin/api/2020-10/gift_cards/search.json`.
+    pub fn get_gift_cards_search(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/gift_cards/search.json".to_string(),
+        )
+    }
+
+    /// Mock [`Plus::get_user`](crate::plus::Plus::get_user), a `GET` to `/admin/api/2020-10/users.json`.
+    pub fn get_user(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/users.json".to_string(),
+        )
+    }
+
+    /// Mock [`Plus::get_users_param_user`](crate::plus::Plus::get_users_param_user), a `GET` to `/admin/api/2020-10/users/{user_id}.json`.
+    pub fn get_users_param_user(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/users/{}.json",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Plus::get_users_current`](crate::plus::Plus::get_users_current), a `GET` to `/admin/api/2020-10/users/current.json`.
+    pub fn get_users_current(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/users/current.json".to_string(),
+        )
+    }
+}
+
+pub struct Products<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Products<'a> {
+    /// Mock [`Products::get_collect`](crate::products::Products::get_collect), a `GET` to `/admin/api/2020-10/collects.json`.
+    pub fn get_collect(&self) -> crate::mock::MockOperation<'a, crate::resources::Collects> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/collects.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::create_collects`](crate::products::Products::create_collects), a `POST` to `/admin/api/2020-10/collects.json`.
+    pub fn create_collects(&self) -> crate::mock::MockOperation<'a, crate::resources::CollectBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/collects.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_collects_param_collect`](crate::products::Products::get_collects_param_collect), a `GET` to `/admin/api/2020-10/collects/{collect_id}.json`.
+    pub fn get_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CollectBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/collects/{}.json",
+                crate::progenitor_support::encode_path(collect_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete_collects_param_collect`](crate::products::Products::delete_collects_param_collect), a `DELETE` to `/admin/api/2020-10/collects/{collect_id}.json`.
+    pub fn delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/collects/{}.json",
+                crate::progenitor_support::encode_path(collect_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_collects_count`](crate::products::Products::get_collects_count), a `GET` to `/admin/api/2020-10/collects/count.json`.
+    pub fn get_collects_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/collects/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_collections_param_collection`](crate::products::Products::get_collections_param_collection), a `GET` to `/admin/api/2020-10/collections/{collection_id}.json`.
+    pub fn get_collections_param_collection(
+        &self,
+        collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CollectionBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/collections/{}.json",
+                crate::progenitor_support::encode_path(collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_collections_param_collection_products`](crate::products::Products::get_collections_param_collection_products), a `GET` to `/admin/api/2020-10/collections/{collection_id}/products.json`.
+    pub fn get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Products> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_custom_collection`](crate::products::Products::get_custom_collection), a `GET` to `/admin/api/2020-10/custom_collections.json`.
+    pub fn get_custom_collection(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomCollections> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/custom_collections.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::create_custom_collections`](crate::products::Products::create_custom_collections), a `POST` to `/admin/api/2020-10/custom_collections.json`.
+    pub fn create_custom_collections(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/custom_collections.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_custom_collections_count`](crate::products::Products::get_custom_collections_count), a `GET` to `/admin/api/2020-10/custom_collections/count.json`.
+    pub fn get_custom_collections_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/custom_collections/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_custom_collections_param_collection`](crate::products::Products::get_custom_collections_param_collection), a `GET` to `/admin/api/2020-10/custom_collections/{custom_collection_id}.json`.
+    pub fn get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/custom_collections/{}.json",
+                crate::progenitor_support::encode_path(custom_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::update_custom_collections_param_collection`](crate::products::Products::update_custom_collections_param_collection), a `PUT` to `/admin/api/2020-10/custom_collections/{custom_collection_id}.json`.
+    pub fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CustomCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/custom_collections/{}.json",
+                crate::progenitor_support::encode_path(custom_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete_custom_collections_param_collection`](crate::products::Products::delete_custom_collections_param_collection), a `DELETE` to `/admin/api/2020-10/custom_collections/{custom_collection_id}.json`.
+    pub fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/custom_collections/{}.json",
+                crate::progenitor_support::encode_path(custom_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get`](crate::products::Products::get), a `GET` to `/admin/api/2020-10/products.json`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::resources::Products> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/products.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::create`](crate::products::Products::create), a `POST` to `/admin/api/2020-10/products.json`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::resources::ProductBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/products.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_count`](crate::products::Products::get_count), a `GET` to `/admin/api/2020-10/products/count.json`.
+    pub fn get_count(&self) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/products/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_param`](crate::products::Products::get_param), a `GET` to `/admin/api/2020-10/products/{product_id}.json`.
+    pub fn get_param(
+        &self,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/products/{}.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::update_param`](crate::products::Products::update_param), a `PUT` to `/admin/api/2020-10/products/{product_id}.json`.
+    pub fn update_param(
+        &self,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/products/{}.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete_param`](crate::products::Products::delete_param), a `DELETE` to `/admin/api/2020-10/products/{product_id}.json`.
+    pub fn delete_param(&self, product_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/products/{}.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_param_image`](crate::products::Products::get_param_image), a `GET` to `/admin/api/2020-10/products/{product_id}/images.json`.
+    pub fn get_param_image(
+        &self,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductImages> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::create_param_images`](crate::products::Products::create_param_images), a `POST` to `/admin/api/2020-10/products/{product_id}/images.json`.
+    pub fn create_param_images(
+        &self,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductImageBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_param_images_count`](crate::products::Products::get_param_images_count), a `GET` to `/admin/api/2020-10/products/{product_id}/images/count.json`.
+    pub fn get_param_images_count(
+        &self,
+        product_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/products/{}/images/count.json",
+                crate::progenitor_support::encode_path(product_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_param_images_image`](crate::products::Products::get_param_images_image), a `GET` to `/admin/api/2020-10/products/{product_id}/images/{image_id}.json`.
+    pub fn get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductImageBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/products/{}/images/{}.json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::update_param_images_image`](crate::products::Products::update_param_images_image), a `PUT` to `/admin/api/2020-10/products/{product_id}/images/{image_id}.json`.
+    pub fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::ProductImageBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/products/{}/images/{}.json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete_param_images_image`](crate::products::Products::delete_param_images_image), a `DELETE` to `/admin/api/2020-10/products/{product_id}/images/{image_id}.json`.
+    pub fn delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/products/{}/images/{}.json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::get_smart_collection`](crate::products::Products::get_smart_collection), a `GET` to `/admin/api/2020-10/smart_collections.json`.
+    pub fn get_smart_collection(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::SmartCollections> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/smart_collections.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::create_smart_collections`](crate::products::Products::create_smart_collections), a `POST` to `/admin/api/2020-10/smart_collections.json`.
+    pub fn create_smart_collections(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::SmartCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/smart_collections.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_smart_collections_count`](crate::products::Products::get_smart_collections_count), a `GET` to `/admin/api/2020-10/smart_collections/count.json`.
+    pub fn get_smart_collections_count(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/smart_collections/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`Products::get_smart_collections_param_collection`](crate::products::Products::get_smart_collections_param_collection), a `GET` to `/admin/api/2020-10/smart_collections/{smart_collection_id}.json`.
+    pub fn get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::SmartCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/smart_collections/{}.json",
+                crate::progenitor_support::encode_path(smart_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::update_smart_collections_param_collection`](crate::products::Products::update_smart_collections_param_collection), a `PUT` to `/admin/api/2020-10/smart_collections/{smart_collection_id}.json`.
+    pub fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::SmartCollectionBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/smart_collections/{}.json",
+                crate::progenitor_support::encode_path(smart_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete_smart_collections_param_collection`](crate::products::Products::delete_smart_collections_param_collection), a `DELETE` to `/admin/api/2020-10/smart_collections/{smart_collection_id}.json`.
+    pub fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/smart_collections/{}.json",
+                crate::progenitor_support::encode_path(smart_collection_id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::update_smart_collections_param_collection_order`](crate::products::Products::update_smart_collections_param_collection_order), a `PUT` to `/admin/api/2020-10/smart_collections/{smart_collection_id}/order.json`.
+    pub fn update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/smart_collections/{}/order.json",
+                crate::progenitor_support::encode_path(smart_collection_id)
+            ),
+        )
+    }
+}
+
+pub struct SalesChannels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SalesChannels<'a> {
+    /// Mock [`SalesChannels::create_checkouts`](crate::sales_channels::SalesChannels::create_checkouts), a `POST` to `/admin/api/2020-10/checkouts.json`.
+    pub fn create_checkouts(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/checkouts.json".to_string(),
+        )
+    }
+
+    /// Mock [`SalesChannels::create_checkouts_param_token_complete`](crate::sales_channels::SalesChannels::create_checkouts_param_token_complete), a `POST` to `/admin/api/2020-10/checkouts/{token}/complete.json`.
+    pub fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/complete.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_checkouts_param_token`](crate::sales_channels::SalesChannels::get_checkouts_param_token), a `GET` to `/admin/api/2020-10/checkouts/{token}.json`.
+    pub fn get_checkouts_param_token(&self, token: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/checkouts/{}.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::update_checkouts_param_token`](crate::sales_channels::SalesChannels::update_checkouts_param_token), a `PUT` to `/admin/api/2020-10/checkouts/{token}.json`.
+    pub fn update_checkouts_param_token(&self, token: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/checkouts/{}.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_checkouts_param_token_shipping_rate`](crate::sales_channels::SalesChannels::get_checkouts_param_token_shipping_rate), a `GET` to `/admin/api/2020-10/checkouts/{token}/shipping_rates.json`.
+    pub fn get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/shipping_rates.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_collection_listing`](crate::sales_channels::SalesChannels::get_collection_listing), a `GET` to `/admin/api/2020-10/collection_listings.json`.
+    pub fn get_collection_listing(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/collection_listings.json".to_string(),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_collection_listings_param_listing_product_id`](crate::sales_channels::SalesChannels::get_collection_listings_param_listing_product_id), a `GET` to `/admin/api/2020-10/collection_listings/{collection_listing_id}/product_ids.json`.
+    pub fn get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/collection_listings/{}/product_ids.json",
+                crate::progenitor_support::encode_path(collection_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_collection_listings_param_listing`](crate::sales_channels::SalesChannels::get_collection_listings_param_listing), a `GET` to `/admin/api/2020-10/collection_listings/{collection_listing_id}.json`.
+    pub fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::update_collection_listings_param_listing`](crate::sales_channels::SalesChannels::update_collection_listings_param_listing), a `PUT` to `/admin/api/2020-10/collection_listings/{collection_listing_id}.json`.
+    pub fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::delete_collection_listings_param_listing`](crate::sales_channels::SalesChannels::delete_collection_listings_param_listing), a `DELETE` to `/admin/api/2020-10/collection_listings/{collection_listing_id}.json`.
+    pub fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_checkouts_param_token_payment`](crate::sales_channels::SalesChannels::get_checkouts_param_token_payment), a `GET` to `/admin/api/2020-10/checkouts/{token}/payments.json`.
+    pub fn get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::create_checkouts_param_token_payments`](crate::sales_channels::SalesChannels::create_checkouts_param_token_payments), a `POST` to `/admin/api/2020-10/checkouts/{token}/payments.json`.
+    pub fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_checkouts_param_token_payments_payment`](crate::sales_channels::SalesChannels::get_checkouts_param_token_payments_payment), a `GET` to `/admin/api/2020-10/checkouts/{token}/payments/{payment_id}.json`.
+    pub fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/payments/{}.json",
+                crate::progenitor_support::encode_path(token),
+                crate::progenitor_support::encode_path(payment_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_checkouts_param_token_payments_count`](crate::sales_channels::SalesChannels::get_checkouts_param_token_payments_count), a `GET` to `/admin/api/2020-10/checkouts/{token}/payments/count.json`.
+    pub fn get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/checkouts/{}/payments/count.json",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_product_listing`](crate::sales_channels::SalesChannels::get_product_listing), a `GET` to `/admin/api/2020-07/product_listings.json`.
+    pub fn get_product_listing(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-07/product_listings.json".to_string(),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_product_listings_id`](crate::sales_channels::SalesChannels::get_product_listings_id), a `GET` to `/admin/api/2020-07/product_listings/product_ids.json`.
+    pub fn get_product_listings_id(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-07/product_listings/product_ids.json".to_string(),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_product_listings_count`](crate::sales_channels::SalesChannels::get_product_listings_count), a `GET` to `/admin/api/2020-07/product_listings/count.json`.
+    pub fn get_product_listings_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-07/product_listings/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`SalesChannels::get_product_listings_param_listing`](crate::sales_channels::SalesChannels::get_product_listings_param_listing), a `GET` to `/admin/api/2020-07/product_listings/{product_listing_id}.json`.
+    pub fn get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-07/product_listings/{}.json",
+                crate::progenitor_support::encode_path(product_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::update_product_listings_param_listing`](crate::sales_channels::SalesChannels::update_product_listings_param_listing), a `PUT` to `/admin/api/2020-07/product_listings/{product_listing_id}.json`.
+    pub fn update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-07/product_listings/{}.json",
+                crate::progenitor_support::encode_path(product_listing_id)
+            ),
+        )
+    }
+
+    /// Mock [`SalesChannels::delete_product_listings_param_listing`](crate::sales_channels::SalesChannels::delete_product_listings_param_listing), a `DELETE` to `/admin/api/2020-07/product_listings/{product_listing_id}.json`.
+    pub fn delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-07/product_listings/{}.json",
+                crate::progenitor_support::encode_path(product_listing_id)
+            ),
+        )
+    }
+}
+
+pub struct ShippingAndFulfillment<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ShippingAndFulfillment<'a> {
+    /// Mock [`ShippingAndFulfillment::get_assigned_fulfillment_order`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_assigned_fulfillment_order), a `GET` to `/admin/api/2020-10/assigned_fulfillment_orders.json`.
+    pub fn get_assigned_fulfillment_order(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/assigned_fulfillment_orders.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json`.
+    pub fn create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json`.
+    pub fn create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json`.
+    pub fn create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_carrier_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_carrier_service), a `GET` to `/admin/api/2020-10/carrier_services.json`.
+    pub fn get_carrier_service(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CarrierServices> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/carrier_services.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_carrier_services`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_carrier_services), a `POST` to `/admin/api/2020-10/carrier_services.json`.
+    pub fn create_carrier_services(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CarrierServiceBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/carrier_services.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_carrier_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_carrier_services_param_service), a `GET` to `/admin/api/2020-10/carrier_services/{carrier_service_id}.json`.
+    pub fn get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CarrierServiceBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::update_carrier_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::update_carrier_services_param_service), a `PUT` to `/admin/api/2020-10/carrier_services/{carrier_service_id}.json`.
+    pub fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::CarrierServiceBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::delete_carrier_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_carrier_services_param_service), a `DELETE` to `/admin/api/2020-10/carrier_services/{carrier_service_id}.json`.
+    pub fn delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillment), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillments.json`.
+    pub fn get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Fulfillments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_orders_param_order_fulfillments`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments), a `POST` to `/admin/api/2020-10/orders/{order_id}/fulfillments.json`.
+    pub fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment), a `GET` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillments.json`.
+    pub fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Fulfillments> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillments_count`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_count), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillments/count.json`.
+    pub fn get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::Count> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/count.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json`.
+    pub fn get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment), a `PUT` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json`.
+    pub fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillments`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments), a `POST` to `/admin/api/2020-10/fulfillments.json`.
+    pub fn create_fulfillments(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/fulfillments.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking), a `POST` to `/admin/api/2020-10/fulfillments/{fulfillment_id}/update_tracking.json`.
+    pub fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillments/{}/update_tracking.json",
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete), a `POST` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json`.
+    pub fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/complete.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open), a `POST` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/open.json`.
+    pub fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/open.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel), a `POST` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json`.
+    pub fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/cancel.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel), a `POST` to `/admin/api/2020-10/fulfillments/{fulfillment_id}/cancel.json`.
+    pub fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillments/{}/cancel.json",
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json`.
+    pub fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentEvents> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events), a `POST` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json`.
+    pub fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentEventBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json`.
+    pub fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentEventBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event`](crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event), a `DELETE` to `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json`.
+    pub fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                crate::progenitor_support::encode_path(event_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment), a `GET` to `/admin/api/2020-10/orders/{order_id}/fulfillment_orders.json`.
+    pub fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrders> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/orders/{}/fulfillment_orders.json",
+                crate::progenitor_support::encode_path(order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_fulfillment_orders_param_order`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order), a `GET` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}.json`.
+    pub fn get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancel.json`.
+    pub fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderChanges> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancel.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_close`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_close), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/close.json`.
+    pub fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_move`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_move), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/move.json`.
+    pub fn create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderChanges> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_open`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_open), a `POST` to `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/open.json`.
+    pub fn create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/open.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_reschedule`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_reschedule), a `POST` to `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/reschedule.json`.
+    pub fn create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/reschedule.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_release_hold`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_release_hold), a `POST` to `/admin/api/unstable/fulfillment_orders/release_hold.json`.
+    pub fn create_fulfillment_orders_release_hold(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/unstable/fulfillment_orders/release_hold.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json`.
+    pub fn create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderChanges> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json`.
+    pub fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject), a `POST` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json`.
+    pub fn create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentOrderBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number`](crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number), a `GET` to `/fetch_tracking_numbers`.
+    pub fn deprecated_unknown_version_get_fetch_tracking_number(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::TrackingNumbers> {
+        self.mock
+            .operation(reqwest::Method::GET, "/fetch_tracking_numbers".to_string())
+    }
+
+    /// Mock [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock`](crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock), a `GET` to `/fetch_stock`.
+    pub fn deprecated_unknown_version_get_fetch_stock(
+        &self,
+    ) -> crate::mock::MockOperation<'a, std::collections::BTreeMap<String, i64>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/fetch_stock".to_string())
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_fulfillment_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_service), a `GET` to `/admin/api/2020-10/fulfillment_services.json`.
+    pub fn get_fulfillment_service(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentServices> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/fulfillment_services.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::create_fulfillment_services`](crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_services), a `POST` to `/admin/api/2020-10/fulfillment_services.json`.
+    pub fn create_fulfillment_services(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentServiceBody> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/fulfillment_services.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_fulfillment_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_services_param_service), a `GET` to `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json`.
+    pub fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentServiceBody> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/fulfillment_services/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::update_fulfillment_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::update_fulfillment_services_param_service), a `PUT` to `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json`.
+    pub fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::FulfillmentServiceBody> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/fulfillment_services/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::delete_fulfillment_services_param_service`](crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_fulfillment_services_param_service), a `DELETE` to `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json`.
+    pub fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/fulfillment_services/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_service_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move`](crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move), a `GET` to `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json`.
+    pub fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::resources::LocationsForMove> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/locations_for_move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id)
+            ),
+        )
+    }
+}
+
+pub struct ShopifyPayments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ShopifyPayments<'a> {
+    /// Mock [`ShopifyPayments::get_balance`](crate::shopify_payments::ShopifyPayments::get_balance), a `GET` to `/admin/api/2020-10/shopify_payments/balance.json`.
+    pub fn get_balance(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/shopify_payments/balance.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShopifyPayments::get_disputes_param_dispute`](crate::shopify_payments::ShopifyPayments::get_disputes_param_dispute), a `GET` to `/admin/api/2020-01/shopify_payments/disputes/{dispute_id}.json`.
+    pub fn get_disputes_param_dispute(
+        &self,
+        dispute_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-01/shopify_payments/disputes/{}.json",
+                crate::progenitor_support::encode_path(dispute_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShopifyPayments::get_dispute`](crate::shopify_payments::ShopifyPayments::get_dispute), a `GET` to `/admin/api/2020-04/shopify_payments/disputes.json`.
+    pub fn get_dispute(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-04/shopify_payments/disputes.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShopifyPayments::get_payout`](crate::shopify_payments::ShopifyPayments::get_payout), a `GET` to `/admin/api/2020-10/shopify_payments/payouts.json`.
+    pub fn get_payout(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/shopify_payments/payouts.json".to_string(),
+        )
+    }
+
+    /// Mock [`ShopifyPayments::get_payouts_param_payout`](crate::shopify_payments::ShopifyPayments::get_payouts_param_payout), a `GET` to `/admin/api/2020-10/shopify_payments/payouts/{payout_id}.json`.
+    pub fn get_payouts_param_payout(&self, payout_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/shopify_payments/payouts/{}.json",
+                crate::progenitor_support::encode_path(payout_id)
+            ),
+        )
+    }
+
+    /// Mock [`ShopifyPayments::get_balance_transaction`](crate::shopify_payments::ShopifyPayments::get_balance_transaction), a `GET` to `/admin/api/2020-01/shopify_payments/balance/transactions.json`.
+    pub fn get_balance_transaction(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-01/shopify_payments/balance/transactions.json".to_string(),
+        )
+    }
+}
+
+pub struct StoreProperties<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> StoreProperties<'a> {
+    /// Mock [`StoreProperties::get_countrie`](crate::store_properties::StoreProperties::get_countrie), a `GET` to `/admin/api/2020-10/countries.json`.
+    pub fn get_countrie(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/countries.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::create_countries`](crate::store_properties::StoreProperties::create_countries), a `POST` to `/admin/api/2020-10/countries.json`.
+    pub fn create_countries(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin/api/2020-10/countries.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_countries_count`](crate::store_properties::StoreProperties::get_countries_count), a `GET` to `/admin/api/2020-10/countries/count.json`.
+    pub fn get_countries_count(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/countries/count.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_countries_param_country`](crate::store_properties::StoreProperties::get_countries_param_country), a `GET` to `/admin/api/2020-10/countries/{country_id}.json`.
+    pub fn get_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/countries/{}.json",
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::update_countries_param_country`](crate::store_properties::StoreProperties::update_countries_param_country), a `PUT` to `/admin/api/2020-10/countries/{country_id}.json`.
+    pub fn update_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/countries/{}.json",
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::delete_countries_param_country`](crate::store_properties::StoreProperties::delete_countries_param_country), a `DELETE` to `/admin/api/2020-10/countries/{country_id}.json`.
+    pub fn delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/admin/api/2020-10/countries/{}.json",
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_currencie`](crate::store_properties::StoreProperties::get_currencie), a `GET` to `/admin/api/2020-10/currencies.json`.
+    pub fn get_currencie(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/currencies.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_policie`](crate::store_properties::StoreProperties::get_policie), a `GET` to `/admin/api/2020-10/policies.json`.
+    pub fn get_policie(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/policies.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_countries_param_country_province`](crate::store_properties::StoreProperties::get_countries_param_country_province), a `GET` to `/admin/api/2020-10/countries/{country_id}/provinces.json`.
+    pub fn get_countries_param_country_province(
+        &self,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/countries/{}/provinces.json",
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_countries_param_country_provinces_count`](crate::store_properties::StoreProperties::get_countries_param_country_provinces_count), a `GET` to `/admin/api/2020-10/countries/{country_id}/provinces/count.json`.
+    pub fn get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/countries/{}/provinces/count.json",
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_countries_param_country_provinces_province`](crate::store_properties::StoreProperties::get_countries_param_country_provinces_province), a `GET` to `/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json`.
+    pub fn get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/admin/api/2020-10/countries/{}/provinces/{}.json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::update_countries_param_country_provinces_province`](crate::store_properties::StoreProperties::update_countries_param_country_provinces_province), a `PUT` to `/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json`.
+    pub fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/admin/api/2020-10/countries/{}/provinces/{}.json",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id)
+            ),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_shipping_zone`](crate::store_properties::StoreProperties::get_shipping_zone), a `GET` to `/admin/api/2020-10/shipping_zones.json`.
+    pub fn get_shipping_zone(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/shipping_zones.json".to_string(),
+        )
+    }
+
+    /// Mock [`StoreProperties::get_shop`](crate::store_properties::StoreProperties::get_shop), a `GET` to `/admin/api/2020-10/shop.json`.
+    pub fn get_shop(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/shop.json".to_string(),
+        )
+    }
+}
+
+pub struct Tendertransaction<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Tendertransaction<'a> {
+    /// Mock [`Tendertransaction::get_tender_transaction`](crate::tendertransaction::Tendertransaction::get_tender_transaction), a `GET` to `/admin/api/2020-10/tender_transactions.json`.
+    pub fn get_tender_transaction(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin/api/2020-10/tender_transactions.json".to_string(),
+        )
+    }
+}
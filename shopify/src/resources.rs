@@ -0,0 +1,1456 @@
+//! Typed bodies of the orders, products, customers, inventory and fulfillments
+//! of the Admin API.
+//!
+//! The spec of the Admin API leaves its bodies untyped, so these are written by
+//! hand. The API wraps each resource in an object named after it, like
+//! `{"order": {…}}` or `{"orders": […]}`, which is what the `…Body` and plural
+//! types are. Every field is optional: a body sent to the API only has the
+//! fields that are set, so an update leaves the other fields as they are.
+//!
+//! ```
+//! use shopify::resources::{Product, ProductBody};
+//!
+//! async fn rename(shopify: &shopify::Client, id: i64, title: &str) -> anyhow::Result<()> {
+//!     let body = ProductBody {
+//!         product: Product {
+//!             id: Some(id),
+//!             title: Some(title.to_string()),
+//!             ..Default::default()
+//!         },
+//!     };
+//!     let product = shopify
+//!         .products()
+//!         .update_param(&id.to_string(), &body)
+//!         .await?
+//!         .product;
+//!     println!("renamed {:?}", product.title);
+//!     Ok(())
+//! }
+//! ```
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The number of resources matching a `count` request.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Count {
+    #[serde(default)]
+    pub count: i64,
+}
+
+/// A billing or shipping address, or an address of a customer.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Address {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// The two-letter code of the country, like `CA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// Whether this is the default address of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+/// A customer of the shop.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Customer {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// `disabled`, `invited`, `enabled` or `declined`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Comma separated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified_email: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepts_marketing: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_exempt: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orders_count: Option<i64>,
+    /// The amount spent across all orders, like `199.65`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_spent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A product variant in an order or a fulfillment.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct LineItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant_title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+    /// The price of one item, like `199.00`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grams: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillable_quantity: Option<i64>,
+    /// `fulfilled`, `partial` or `null` when not fulfilled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<String>,
+}
+
+/// An order of a customer.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Order {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// The name the merchant and the customer see, like `#1001`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_number: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Comma separated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// `pending`, `authorized`, `paid`, `refunded`, `voided`, ...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financial_status: Option<String>,
+    /// `fulfilled`, `partial`, `restocked` or `null` when not fulfilled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_price: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtotal_price: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_discounts: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<LineItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fulfillments: Vec<Fulfillment>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A variant of a product, like a size or a color.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductVariant {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compare_at_price: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barcode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option3: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_quantity: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_management: Option<String>,
+    /// `deny` or `continue` selling when out of stock.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grams: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight_unit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// An option of a product, like `Size`, and its values.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductOption {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
+
+/// An image of a product.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variant_ids: Vec<i64>,
+}
+
+/// A product of the shop.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Product {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The description, in HTML.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    /// `active`, `archived` or `draft`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Comma separated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<ProductVariant>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ProductOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ProductImage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<ProductImage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The inventory of a product variant.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code_of_origin: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province_code_of_origin: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub harmonized_system_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The quantity of an inventory item available at a location.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryLevel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<i64>,
+    /// Unset when the item isn't tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The tracking of a fulfillment, set with `update_tracking`.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct TrackingInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+}
+
+/// The shipment of line items of an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Fulfillment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `pending`, `open`, `success`, `cancelled`, `error` or `failure`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipment_status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_company: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracking_numbers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracking_urls: Vec<String>,
+    /// Sent to `update_tracking` instead of the tracking fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_info: Option<TrackingInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_customer: Option<bool>,
+    /// The line items to fulfill, all of them when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<LineItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A risk of fraud the merchant or an app found in an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct OrderRisk {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkout_id: Option<i64>,
+    /// `External` for the risks of apps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Between `0.0` and `1.0`, like `1.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    /// `cancel`, `investigate` or `accept`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cause_cancel: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merchant_message: Option<String>,
+}
+
+/// A payment, capture or refund of an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Transaction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i64>,
+    /// `authorization`, `capture`, `sale`, `void` or `refund`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    /// `pending`, `failure`, `success` or `error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// What can still be refunded, in a calculated refund.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_refundable: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A line item of an order to refund, and whether to restock it.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct RefundLineItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+    /// `no_restock`, `cancel` or `return`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restock_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtotal: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_item: Option<LineItem>,
+}
+
+/// The shipping to refund.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct RefundShipping {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_refund: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// What can still be refunded, in a calculated refund.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_refundable: Option<String>,
+}
+
+/// A refund of an order, or one to calculate.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Refund {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+    /// Send a notification to the customer, when creating the refund.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<RefundShipping>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub refund_line_items: Vec<RefundLineItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<Transaction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A product in a custom collection.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Collect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The image of a collection.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CollectionImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    /// The image as base64, when uploading it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A condition on the products of a smart collection, like `tag` `equals` `sale`.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CollectionRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+/// A custom or smart collection of products.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Collection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The description, in HTML.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    /// `custom` or `smart`, for the collections read from `collections`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_type: Option<String>,
+    /// `alpha-asc`, `best-selling`, `manual`, ...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub products_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<CollectionImage>,
+    /// The rules of a smart collection.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<CollectionRule>,
+    /// Whether a product of a smart collection only needs to match one of its rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disjunctive: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// An email inviting a customer to create an account.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerInvite {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+/// A search of customers the merchant saved.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerSavedSearch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Like `Bob country:Canada`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A place where the shop stocks inventory, like a store or a warehouse.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Whether the location is a fulfillment service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A location an order can be moved to.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct LocationForMove {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub movable: Option<bool>,
+    /// Why the order can't be moved there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// An update to the delivery of a fulfillment, like `in_transit`.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    /// `confirmed`, `in_transit`, `out_for_delivery`, `delivered`, `failure`, ...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub happened_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_delivery_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The address a fulfillment order is shipped to.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderDestination {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+/// The location a fulfillment order is assigned to.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderLocation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+/// A line item of a fulfillment order, or the quantity of one to request fulfilling.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderLineItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillable_quantity: Option<i64>,
+}
+
+/// A request the merchant sent to the fulfillment service of a fulfillment order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct MerchantRequest {
+    /// `fulfillment_request` or `cancellation_request`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The line items to fulfill, all of them when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fulfillment_order_line_items: Vec<FulfillmentOrderLineItem>,
+}
+
+/// The line items of an order to fulfill from one location.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrder {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_location_id: Option<i64>,
+    /// `open`, `in_progress`, `scheduled`, `on_hold`, `cancelled`, `closed` or `incomplete`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// `unsubmitted`, `submitted`, `accepted`, `rejected`, ...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_status: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_actions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination: Option<FulfillmentOrderDestination>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_location: Option<FulfillmentOrderLocation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<FulfillmentOrderLineItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merchant_requests: Vec<MerchantRequest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfill_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A service that calculates shipping rates at checkout.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CarrierService {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Where Shopify asks for rates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carrier_service_type: Option<String>,
+    /// Whether the merchant can send dummy data to see the rates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_discovery: Option<bool>,
+    /// `json` or `xml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+/// A third party warehouse that fulfills orders.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentService {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<i64>,
+    /// Where Shopify fetches the tracking numbers and stock of the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// `json` or `xml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_management: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_support: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_shipping_method: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_orders_opt_in: Option<bool>,
+}
+
+/// A page of customers.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Customers {
+    #[serde(default)]
+    pub customers: Vec<Customer>,
+}
+
+/// A customer, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerBody {
+    pub customer: Customer,
+}
+
+/// A page of orders.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Orders {
+    #[serde(default)]
+    pub orders: Vec<Order>,
+}
+
+/// An order, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct OrderBody {
+    pub order: Order,
+}
+
+/// A page of products.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Products {
+    #[serde(default)]
+    pub products: Vec<Product>,
+}
+
+/// A product, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductBody {
+    pub product: Product,
+}
+
+/// A page of inventory items.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryItems {
+    #[serde(default)]
+    pub inventory_items: Vec<InventoryItem>,
+}
+
+/// An inventory item, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryItemBody {
+    pub inventory_item: InventoryItem,
+}
+
+/// A page of inventory levels.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryLevels {
+    #[serde(default)]
+    pub inventory_levels: Vec<InventoryLevel>,
+}
+
+/// An inventory level, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct InventoryLevelBody {
+    pub inventory_level: InventoryLevel,
+}
+
+/// A page of fulfillments.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Fulfillments {
+    #[serde(default)]
+    pub fulfillments: Vec<Fulfillment>,
+}
+
+/// A fulfillment, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentBody {
+    pub fulfillment: Fulfillment,
+}
+
+/// A page of risks of an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct OrderRisks {
+    #[serde(default)]
+    pub risks: Vec<OrderRisk>,
+}
+
+/// A risk of an order, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct OrderRiskBody {
+    pub risk: OrderRisk,
+}
+
+/// A page of refunds of an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Refunds {
+    #[serde(default)]
+    pub refunds: Vec<Refund>,
+}
+
+/// A refund, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct RefundBody {
+    pub refund: Refund,
+}
+
+/// A page of collects.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Collects {
+    #[serde(default)]
+    pub collects: Vec<Collect>,
+}
+
+/// A collect, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CollectBody {
+    pub collect: Collect,
+}
+
+/// A collection of either kind, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CollectionBody {
+    pub collection: Collection,
+}
+
+/// A page of custom collections.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomCollections {
+    #[serde(default)]
+    pub custom_collections: Vec<Collection>,
+}
+
+/// A custom collection, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomCollectionBody {
+    pub custom_collection: Collection,
+}
+
+/// A page of smart collections.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct SmartCollections {
+    #[serde(default)]
+    pub smart_collections: Vec<Collection>,
+}
+
+/// A smart collection, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct SmartCollectionBody {
+    pub smart_collection: Collection,
+}
+
+/// A page of images of a product.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductImages {
+    #[serde(default)]
+    pub images: Vec<ProductImage>,
+}
+
+/// An image of a product, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ProductImageBody {
+    pub image: ProductImage,
+}
+
+/// An address of a customer, as sent to the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct AddressBody {
+    pub address: Address,
+}
+
+/// An address of a customer, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerAddressBody {
+    pub customer_address: Address,
+}
+
+/// A page of addresses of a customer.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerAddresses {
+    #[serde(default)]
+    pub addresses: Vec<Address>,
+}
+
+/// An invite, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerInviteBody {
+    pub customer_invite: CustomerInvite,
+}
+
+/// A page of saved searches.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerSavedSearches {
+    #[serde(default)]
+    pub customer_saved_searches: Vec<CustomerSavedSearch>,
+}
+
+/// A saved search, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CustomerSavedSearchBody {
+    pub customer_saved_search: CustomerSavedSearch,
+}
+
+/// A page of locations.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct Locations {
+    #[serde(default)]
+    pub locations: Vec<Location>,
+}
+
+/// A location, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct LocationBody {
+    pub location: Location,
+}
+
+/// The locations a fulfillment order can be moved to.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct LocationsForMove {
+    #[serde(default)]
+    pub locations_for_move: Vec<LocationForMove>,
+}
+
+/// A page of events of a fulfillment.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentEvents {
+    #[serde(default)]
+    pub fulfillment_events: Vec<FulfillmentEvent>,
+}
+
+/// An event of a fulfillment, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentEventBody {
+    pub fulfillment_event: FulfillmentEvent,
+}
+
+/// An event of a fulfillment, as sent to the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentEventRequest {
+    pub event: FulfillmentEvent,
+}
+
+/// A page of fulfillment orders.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrders {
+    #[serde(default)]
+    pub fulfillment_orders: Vec<FulfillmentOrder>,
+}
+
+/// A fulfillment order, as returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderBody {
+    pub fulfillment_order: FulfillmentOrder,
+}
+
+/// A request to cancel a fulfillment order, as sent to the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CancellationRequestBody {
+    pub cancellation_request: MerchantRequest,
+}
+
+/// A request to fulfill a fulfillment order, as sent to the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentRequestBody {
+    pub fulfillment_request: MerchantRequest,
+}
+
+/// A page of carrier services.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CarrierServices {
+    #[serde(default)]
+    pub carrier_services: Vec<CarrierService>,
+}
+
+/// A carrier service, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CarrierServiceBody {
+    pub carrier_service: CarrierService,
+}
+
+/// A page of fulfillment services.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentServices {
+    #[serde(default)]
+    pub fulfillment_services: Vec<FulfillmentService>,
+}
+
+/// A fulfillment service, as sent and returned by the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentServiceBody {
+    pub fulfillment_service: FulfillmentService,
+}
+
+/// Adjusts the quantity available of an inventory item at a location.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct AdjustInventoryLevel {
+    pub location_id: i64,
+    pub inventory_item_id: i64,
+    /// Added to the quantity available, or removed from it when negative.
+    pub available_adjustment: i64,
+}
+
+/// Stocks an inventory item at a location.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct ConnectInventoryLevel {
+    pub location_id: i64,
+    pub inventory_item_id: i64,
+    /// Disconnect the item from the locations it can't be stocked at along
+    /// this one, like another fulfillment service.
+    #[serde(default)]
+    pub relocate_if_necessary: bool,
+}
+
+/// Sets the quantity available of an inventory item at a location.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct SetInventoryLevel {
+    pub location_id: i64,
+    pub inventory_item_id: i64,
+    pub available: i64,
+    /// Disconnect the item from the locations it can't be stocked at along
+    /// this one, like another fulfillment service.
+    #[serde(default)]
+    pub disconnect_if_necessary: bool,
+}
+
+/// The body of the actions that take no parameters, like closing an order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct NoParams {}
+
+/// Cancels an order, refunding it and restocking its items.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct CancelOrder {
+    /// `customer`, `fraud`, `inventory`, `declined` or `other`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Email the customer that the order was cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<bool>,
+    /// The refund to make, calculated with `create_param_refunds_calculate`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund: Option<Refund>,
+}
+
+/// The URL a customer activates their account at.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct AccountActivationUrl {
+    #[serde(default)]
+    pub account_activation_url: String,
+}
+
+/// Closes, moves or reschedules a fulfillment order.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderChange {
+    /// Why the fulfillment order is closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_location_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_fulfill_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A change of a fulfillment order, as sent to the API.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderChangeBody {
+    pub fulfillment_order: FulfillmentOrderChange,
+}
+
+/// The fulfillment orders an action on a fulfillment order split it into.
+/// Which are set depends on the action: a move returns the original, moved
+/// and remaining fulfillment orders, a cancellation the fulfillment order and
+/// its replacement.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct FulfillmentOrderChanges {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement_fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moved_fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submitted_fulfillment_order: Option<FulfillmentOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsubmitted_fulfillment_order: Option<FulfillmentOrder>,
+}
+
+/// The tracking numbers a fulfillment service returns to `fetch_tracking_numbers`.
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+pub struct TrackingNumbers {
+    /// The tracking number of each order, by the name of the order.
+    #[serde(default)]
+    pub tracking_numbers: std::collections::BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub success: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_orders() {
+        let orders: Orders = serde_json::from_value(serde_json::json!({
+            "orders": [{
+                "id": 450789469,
+                "name": "#1001",
+                "email": "bob.norman@hostmail.com",
+                "financial_status": "paid",
+                "fulfillment_status": null,
+                "total_price": "598.94",
+                "test": false,
+                "created_at": "2008-01-10T11:00:00-05:00",
+                "customer": {"id": 207119551, "email": "bob.norman@hostmail.com"},
+                "line_items": [{
+                    "id": 466157049,
+                    "variant_id": 39072856,
+                    "quantity": 1,
+                    "price": "199.00",
+                    "fulfillment_status": null
+                }],
+                "shipping_address": null,
+                "some_field_added_later": true
+            }]
+        }))
+        .unwrap();
+
+        let order = &orders.orders[0];
+        assert_eq!(order.id, Some(450789469));
+        assert_eq!(order.fulfillment_status, None);
+        assert_eq!(order.shipping_address, None);
+        assert_eq!(
+            order.created_at.unwrap().to_rfc3339(),
+            "2008-01-10T16:00:00+00:00"
+        );
+        assert_eq!(order.customer.as_ref().unwrap().id, Some(207119551));
+        assert_eq!(order.line_items[0].price.as_deref(), Some("199.00"));
+    }
+
+    #[test]
+    fn reads_moved_fulfillment_orders() {
+        let moved: FulfillmentOrderChanges = serde_json::from_value(serde_json::json!({
+            "original_fulfillment_order": {
+                "id": 1046000789,
+                "order_id": 450789469,
+                "assigned_location_id": 24826418,
+                "status": "closed",
+                "supported_actions": [],
+                "line_items": []
+            },
+            "moved_fulfillment_order": {
+                "id": 1046000790,
+                "assigned_location_id": 905684977,
+                "status": "open",
+                "supported_actions": ["create_fulfillment", "move"],
+                "destination": {"address1": "Chestnut Street 92", "email": "bob.norman@mail.example.com"},
+                "line_items": [{"id": 1058737493, "line_item_id": 466157049, "quantity": 1}]
+            },
+            "remaining_fulfillment_order": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            moved.original_fulfillment_order.unwrap().status.as_deref(),
+            Some("closed")
+        );
+        let fulfillment_order = moved.moved_fulfillment_order.unwrap();
+        assert_eq!(fulfillment_order.assigned_location_id, Some(905684977));
+        assert_eq!(fulfillment_order.line_items[0].quantity, Some(1));
+        assert_eq!(
+            fulfillment_order.destination.unwrap().email.as_deref(),
+            Some("bob.norman@mail.example.com")
+        );
+        assert_eq!(moved.remaining_fulfillment_order, None);
+    }
+
+    #[test]
+    fn sends_no_params_as_an_empty_object() {
+        assert_eq!(
+            serde_json::to_value(NoParams {}).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn only_sends_the_fields_set() {
+        let body = ProductBody {
+            product: Product {
+                title: Some("Burton Custom Freestyle 151".to_string()),
+                variants: vec![ProductVariant {
+                    price: Some("10.00".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            serde_json::json!({
+                "product": {
+                    "title": "Burton Custom Freestyle 151",
+                    "variants": [{"price": "10.00"}]
+                }
+            })
+        );
+    }
+}
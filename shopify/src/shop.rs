@@ -0,0 +1,223 @@
+//! Points the client at a shop.
+//!
+//! The Admin API and its OAuth endpoints live on the domain of each shop, like
+//! `my-shop.myshopify.com`, and the Admin API under a dated version, like
+//! `2026-10`. [`Client::new_for_shop`] sends the requests and runs the install
+//! flow against a shop:
+//!
+//! ```
+//! use shopify::Client;
+//!
+//! async fn install(code: &str, state: &str) -> anyhow::Result<()> {
+//!     let mut shopify = Client::new_for_shop(
+//!         "my-shop",
+//!         "client-id",
+//!         "client-secret",
+//!         "https://example.com/callback",
+//!         "",
+//!     )?;
+//!     shopify.with_api_version("2026-07");
+//!
+//!     // Send the merchant here, with a state to check in the callback.
+//!     let install_url = shopify.install_url(&["read_orders", "write_products"], "nonce");
+//!
+//!     // In the callback, check its query string and exchange the code.
+//!     shopify.get_access_token(code, state).await?;
+//!     Ok(())
+//! }
+//! ```
+use anyhow::Result;
+
+use crate::Client;
+
+/// The `myshopify.com` domain of a shop, from its name, like `my-shop`, its
+/// domain or its URL. Any other domain is an error, so the client secret and
+/// access token are only sent to Shopify.
+pub fn shop_domain(shop: &str) -> Result<String> {
+    let shop = shop
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .to_ascii_lowercase();
+    let domain = if shop.contains('.') {
+        shop
+    } else {
+        format!("{}.myshopify.com", shop)
+    };
+
+    let name = domain.strip_suffix(".myshopify.com").unwrap_or_default();
+    if !name.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        anyhow::bail!("{:?} is not the domain of a shop on myshopify.com", domain);
+    }
+    Ok(domain)
+}
+
+impl Client {
+    /// Create a client for the Admin API of a shop, named like `my-shop` or by
+    /// its `myshopify.com` domain. The token is the access token of the
+    /// install flow, or the Admin API access token of a custom app, and may be
+    /// empty until the shop installs the app.
+    pub fn new_for_shop<S, I, K, R, T>(
+        shop: S,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+    ) -> Result<Self>
+    where
+        S: ToString,
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+    {
+        // Shopify's access tokens don't expire, so there is nothing to refresh.
+        Client::new(client_id, client_secret, redirect_uri, token, "", shop)
+    }
+
+    /// Send the requests to this shop, and run the install flow against its
+    /// OAuth endpoints. The shop has its own call limit, shared by the clones
+    /// of the client made from then on.
+    pub fn with_shop<S>(&mut self, shop: S) -> Result<&mut Self>
+    where
+        S: ToString,
+    {
+        let domain = shop_domain(&shop.to_string())?;
+        self.host = format!("https://{}", domain);
+        self.token_endpoint = format!("https://{}{}", domain, crate::TOKEN_PATH);
+        self.call_limit = Default::default();
+        Ok(self)
+    }
+
+    /// Send the requests to this version of the Admin API, like `2026-07`,
+    /// instead of the one the crate was generated for.
+    pub fn with_api_version<V>(&mut self, version: V) -> &mut Self
+    where
+        V: ToString,
+    {
+        self.api_version = version.to_string();
+        self
+    }
+
+    /// The domain of the shop the requests are sent to.
+    pub fn shop(&self) -> &str {
+        self.host.trim_start_matches("https://")
+    }
+
+    /// The version of the Admin API the requests are sent to, whatever the
+    /// version in the path of the function.
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// The URL to send the merchant to, to install the app on the shop with
+    /// these access scopes. Shopify redirects to the redirect URI with a `code`
+    /// for [`Client::get_access_token`] and the `state`, which must be checked
+    /// to be the one given here.
+    pub fn install_url(&self, scopes: &[&str], state: &str) -> String {
+        let scope = scopes.join(",");
+        let query = serde_urlencoded::to_string([
+            ("client_id", self.client_id.as_str()),
+            ("scope", scope.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("state", state),
+        ])
+        .unwrap();
+
+        format!("{}{}?{}", self.host, crate::USER_CONSENT_PATH, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_a_shop() {
+        let mut client =
+            Client::new_for_shop("my-shop", "id", "hush", "https://example.com", "").unwrap();
+        assert_eq!(client.shop(), "my-shop.myshopify.com");
+        assert_eq!(client.api_version(), "2026-10");
+        assert_eq!(
+            client.url("/admin/api/2021-01/orders.json", None),
+            "https://my-shop.myshopify.com/admin/api/2026-10/orders.json"
+        );
+        assert_eq!(
+            client.token_endpoint,
+            "https://my-shop.myshopify.com/admin/oauth/access_token"
+        );
+
+        client
+            .with_api_version("2026-07")
+            .with_shop("https://other-shop.myshopify.com/")
+            .unwrap();
+        assert_eq!(client.api_version(), "2026-07");
+        assert_eq!(
+            client.url("/admin/api/2021-01/orders.json", None),
+            "https://other-shop.myshopify.com/admin/api/2026-07/orders.json"
+        );
+        assert_eq!(
+            client.url("/admin/oauth/access_scopes.json", None),
+            "https://other-shop.myshopify.com/admin/oauth/access_scopes.json"
+        );
+    }
+
+    #[test]
+    fn only_points_at_myshopify_domains() {
+        assert_eq!(shop_domain("My-Shop2").unwrap(), "my-shop2.myshopify.com");
+        assert_eq!(
+            shop_domain("http://my-shop.myshopify.com").unwrap(),
+            "my-shop.myshopify.com"
+        );
+        for shop in [
+            "",
+            "-my-shop",
+            "my_shop",
+            "evil.com",
+            "my-shop.myshopify.com.evil.com",
+            "evil.com/my-shop.myshopify.com",
+            "my-shop.evil.myshopify.com",
+            "my-shop.myshopify.com:8443",
+        ] {
+            assert!(shop_domain(shop).is_err(), "{}", shop);
+        }
+
+        let mut client = Client::new_for_shop("my-shop", "", "", "", "").unwrap();
+        assert!(client.with_shop("evil.com").is_err());
+        assert_eq!(client.shop(), "my-shop.myshopify.com");
+        assert!(Client::new_for_shop("evil.com", "", "", "", "").is_err());
+    }
+
+    #[test]
+    fn keeps_a_call_limit_per_shop() {
+        let mut client = Client::new_for_shop("my-shop", "", "", "", "").unwrap();
+        let clone = client.clone();
+        assert!(std::sync::Arc::ptr_eq(
+            &client.call_limit.0,
+            &clone.call_limit.0
+        ));
+
+        client.with_shop("other-shop").unwrap();
+        assert!(!std::sync::Arc::ptr_eq(
+            &client.call_limit.0,
+            &clone.call_limit.0
+        ));
+    }
+
+    #[test]
+    fn builds_install_urls() {
+        let client =
+            Client::new_for_shop("my-shop", "id", "hush", "https://example.com/cb", "").unwrap();
+        assert_eq!(
+            client.install_url(&["read_orders", "write_products"], "nonce"),
+            "https://my-shop.myshopify.com/admin/oauth/authorize?client_id=id\
+             &scope=read_orders%2Cwrite_products&redirect_uri=https%3A%2F%2Fexample.com%2Fcb\
+             &state=nonce"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+//! Verifies and parses the requests Shopify sends to an app: webhooks, the
+//! redirects of the install flow and app proxy requests.
+//!
+//! Shopify signs each of them with the client secret of the app. Webhooks carry
+//! the base64 HMAC of their raw body in the `X-Shopify-Hmac-Sha256` header, and
+//! their topic, like `orders/create`, in the `X-Shopify-Topic` header.
+//! [`WebhookRequest::from_request`] checks the signature before parsing the
+//! body:
+//!
+//! ```
+//! use shopify::webhooks::{Webhook, WebhookRequest};
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) -> http::StatusCode {
+//!     match WebhookRequest::from_request("client-secret", headers, body) {
+//!         Ok(request) => {
+//!             if let Webhook::OrdersCreate(order) = &request.webhook {
+//!                 println!("{} ordered {:?}", request.shop_domain, order.name);
+//!             }
+//!             http::StatusCode::OK
+//!         }
+//!         Err(_) => http::StatusCode::UNAUTHORIZED,
+//!     }
+//! }
+//! ```
+//!
+//! The query strings of the install flow and app proxy requests are checked
+//! with [`verify_query`] and [`verify_proxy_query`]. [`verify_query`] also
+//! rejects a query whose `timestamp` is older than the [`DEFAULT_TOLERANCE`], so
+//! the redirect of an install can't be replayed. Topics without a variant
+//! here, like `app/uninstalled`, keep their payload as JSON in
+//! [`Webhook::Unknown`].
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::resources;
+
+const X_SHOPIFY_API_VERSION: &str = "x-shopify-api-version";
+const X_SHOPIFY_HMAC_SHA256: &str = "x-shopify-hmac-sha256";
+const X_SHOPIFY_SHOP_DOMAIN: &str = "x-shopify-shop-domain";
+const X_SHOPIFY_TOPIC: &str = "x-shopify-topic";
+const X_SHOPIFY_WEBHOOK_ID: &str = "x-shopify-webhook-id";
+
+/// How old the `timestamp` of a signed query may be.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// A webhook not signed with the client secret, or not a payload of its topic.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The request is missing a header Shopify always sends.
+    #[error("missing the {0} header")]
+    MissingHeader(&'static str),
+    /// The body wasn't signed with the client secret of the app.
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The body isn't the payload of its topic.
+    #[error("invalid payload: {0}")]
+    Payload(#[from] serde_json::Error),
+}
+
+/// Check the `X-Shopify-Hmac-Sha256` header of a webhook, the base64
+/// HMAC-SHA256 of its raw body keyed with the client secret of the app.
+/// Whitespace around the header is ignored.
+pub fn verify_webhook(secret: &str, body: &[u8], hmac: &str) -> Result<(), WebhookError> {
+    let signature = base64::decode(hmac.trim()).map_err(|_| WebhookError::InvalidSignature)?;
+    let mut mac = new_mac(secret).ok_or(WebhookError::InvalidSignature)?;
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Whether the query string of a request Shopify sent the merchant to, like
+/// the redirect of the install flow, was signed with the client secret within
+/// the [`DEFAULT_TOLERANCE`]. Its `hmac` parameter is the hex HMAC of the other
+/// parameters, sorted and joined with `&`.
+pub fn verify_query(secret: &str, query: &str) -> bool {
+    verify_query_with_tolerance(secret, query, DEFAULT_TOLERANCE)
+}
+
+/// Like [`verify_query`], with another tolerance for the `timestamp` of the
+/// query.
+pub fn verify_query_with_tolerance(secret: &str, query: &str, tolerance: Duration) -> bool {
+    let mut params = parse_query(query);
+    let signature = match take(&mut params, "hmac") {
+        Some(signature) => signature,
+        None => return false,
+    };
+
+    params.retain(|(k, _)| k != "signature");
+    params.sort();
+    let message = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&");
+    if !verify_hex(secret, &message, &signature) {
+        return false;
+    }
+
+    // The timestamp is signed, so it is only trusted once the signature is.
+    match params
+        .iter()
+        .find(|(k, _)| k == "timestamp")
+        .and_then(|(_, v)| v.parse::<i64>().ok())
+    {
+        Some(timestamp) => (now() - timestamp).unsigned_abs() <= tolerance.as_secs(),
+        None => false,
+    }
+}
+
+/// Whether the query string of a request Shopify forwarded to an app proxy was
+/// signed with the client secret. Its `signature` parameter is the hex HMAC of
+/// the other parameters, sorted, with the values of a repeated parameter joined
+/// with `,`, and concatenated without a separator.
+pub fn verify_proxy_query(secret: &str, query: &str) -> bool {
+    let mut params = parse_query(query);
+    let signature = match take(&mut params, "signature") {
+        Some(signature) => signature,
+        None => return false,
+    };
+
+    // A stable sort keeps the values of a repeated parameter in order.
+    params.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut message = String::new();
+    let mut last: Option<&str> = None;
+    for (k, v) in &params {
+        if last == Some(k.as_str()) {
+            message.push(',');
+        } else {
+            message.push_str(k);
+            message.push('=');
+        }
+        message.push_str(v);
+        last = Some(k);
+    }
+
+    verify_hex(secret, &message, &signature)
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    serde_urlencoded::from_str(query.trim_start_matches('?')).unwrap_or_default()
+}
+
+fn take(params: &mut Vec<(String, String)>, key: &str) -> Option<String> {
+    let i = params.iter().position(|(k, _)| k == key)?;
+    Some(params.remove(i).1)
+}
+
+fn verify_hex(secret: &str, message: &str, signature: &str) -> bool {
+    let signature = match hex::decode(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    match new_mac(secret) {
+        Some(mut mac) => {
+            mac.update(message.as_bytes());
+            mac.verify_slice(&signature).is_ok()
+        }
+        None => false,
+    }
+}
+
+fn new_mac(secret: &str) -> Option<Hmac<Sha256>> {
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// The id of a deleted resource, the payload of the `*/delete` topics.
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
+pub struct DeletedResource {
+    pub id: i64,
+}
+
+/// The payload of a webhook, by its topic.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Webhook {
+    OrdersCreate(resources::Order),
+    OrdersUpdated(resources::Order),
+    OrdersPaid(resources::Order),
+    OrdersFulfilled(resources::Order),
+    OrdersPartiallyFulfilled(resources::Order),
+    OrdersCancelled(resources::Order),
+    OrdersDelete(DeletedResource),
+    ProductsCreate(resources::Product),
+    ProductsUpdate(resources::Product),
+    ProductsDelete(DeletedResource),
+    CustomersCreate(resources::Customer),
+    CustomersUpdate(resources::Customer),
+    CustomersEnable(resources::Customer),
+    CustomersDisable(resources::Customer),
+    CustomersDelete(DeletedResource),
+    InventoryItemsCreate(resources::InventoryItem),
+    InventoryItemsUpdate(resources::InventoryItem),
+    InventoryItemsDelete(DeletedResource),
+    InventoryLevelsConnect(resources::InventoryLevel),
+    InventoryLevelsUpdate(resources::InventoryLevel),
+    InventoryLevelsDisconnect(resources::InventoryLevel),
+    FulfillmentsCreate(resources::Fulfillment),
+    FulfillmentsUpdate(resources::Fulfillment),
+    /// The payload of a topic without a variant here, like `app/uninstalled`.
+    Unknown {
+        topic: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl Webhook {
+    /// Deserialize the payload of a webhook of the given topic, like
+    /// `orders/create`.
+    pub fn from_payload(topic: &str, body: &[u8]) -> Result<Self, WebhookError> {
+        Ok(match topic {
+            "orders/create" => Webhook::OrdersCreate(serde_json::from_slice(body)?),
+            "orders/updated" => Webhook::OrdersUpdated(serde_json::from_slice(body)?),
+            "orders/paid" => Webhook::OrdersPaid(serde_json::from_slice(body)?),
+            "orders/fulfilled" => Webhook::OrdersFulfilled(serde_json::from_slice(body)?),
+            "orders/partially_fulfilled" => {
+                Webhook::OrdersPartiallyFulfilled(serde_json::from_slice(body)?)
+            }
+            "orders/cancelled" => Webhook::OrdersCancelled(serde_json::from_slice(body)?),
+            "orders/delete" => Webhook::OrdersDelete(serde_json::from_slice(body)?),
+            "products/create" => Webhook::ProductsCreate(serde_json::from_slice(body)?),
+            "products/update" => Webhook::ProductsUpdate(serde_json::from_slice(body)?),
+            "products/delete" => Webhook::ProductsDelete(serde_json::from_slice(body)?),
+            "customers/create" => Webhook::CustomersCreate(serde_json::from_slice(body)?),
+            "customers/update" => Webhook::CustomersUpdate(serde_json::from_slice(body)?),
+            "customers/enable" => Webhook::CustomersEnable(serde_json::from_slice(body)?),
+            "customers/disable" => Webhook::CustomersDisable(serde_json::from_slice(body)?),
+            "customers/delete" => Webhook::CustomersDelete(serde_json::from_slice(body)?),
+            "inventory_items/create" => {
+                Webhook::InventoryItemsCreate(serde_json::from_slice(body)?)
+            }
+            "inventory_items/update" => {
+                Webhook::InventoryItemsUpdate(serde_json::from_slice(body)?)
+            }
+            "inventory_items/delete" => {
+                Webhook::InventoryItemsDelete(serde_json::from_slice(body)?)
+            }
+            "inventory_levels/connect" => {
+                Webhook::InventoryLevelsConnect(serde_json::from_slice(body)?)
+            }
+            "inventory_levels/update" => {
+                Webhook::InventoryLevelsUpdate(serde_json::from_slice(body)?)
+            }
+            "inventory_levels/disconnect" => {
+                Webhook::InventoryLevelsDisconnect(serde_json::from_slice(body)?)
+            }
+            "fulfillments/create" => Webhook::FulfillmentsCreate(serde_json::from_slice(body)?),
+            "fulfillments/update" => Webhook::FulfillmentsUpdate(serde_json::from_slice(body)?),
+            _ => Webhook::Unknown {
+                topic: topic.to_string(),
+                payload: serde_json::from_slice(body)?,
+            },
+        })
+    }
+}
+
+/// A webhook delivered to the app.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookRequest {
+    /// The topic of the webhook, like `orders/create`.
+    pub topic: String,
+    /// The domain of the shop the webhook is about, like
+    /// `my-shop.myshopify.com`.
+    pub shop_domain: String,
+    /// The version of the Admin API the payload is in.
+    pub api_version: Option<String>,
+    /// The id of the webhook, the same for each retry of a delivery.
+    pub webhook_id: Option<String>,
+    /// The payload of the webhook.
+    pub webhook: Webhook,
+}
+
+impl WebhookRequest {
+    /// Verify the `X-Shopify-Hmac-Sha256` header of a webhook with the client
+    /// secret of the app, then parse its body by its `X-Shopify-Topic` header.
+    pub fn from_request(
+        secret: &str,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self, WebhookError> {
+        // Only the HMAC, the topic and the shop are always sent.
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let hmac = header(X_SHOPIFY_HMAC_SHA256)
+            .ok_or(WebhookError::MissingHeader(X_SHOPIFY_HMAC_SHA256))?;
+        verify_webhook(secret, body, hmac)?;
+
+        let topic = header(X_SHOPIFY_TOPIC).ok_or(WebhookError::MissingHeader(X_SHOPIFY_TOPIC))?;
+        let shop_domain = header(X_SHOPIFY_SHOP_DOMAIN)
+            .ok_or(WebhookError::MissingHeader(X_SHOPIFY_SHOP_DOMAIN))?;
+        Ok(WebhookRequest {
+            topic: topic.to_string(),
+            shop_domain: shop_domain.to_string(),
+            api_version: header(X_SHOPIFY_API_VERSION).map(str::to_string),
+            webhook_id: header(X_SHOPIFY_WEBHOOK_ID).map(str::to_string),
+            webhook: Webhook::from_payload(topic, body)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "hush";
+    const BODY: &[u8] = br##"{"id":820982911946154508,"email":"jon@example.com","name":"#9999","line_items":[{"id":866550311766439020,"quantity":1,"price":"199.00"}]}"##;
+    const HMAC: &str = "xpNiZKiBbreIVTqJ6vZxi8lB7lm70wxkztQApxNbkqo=";
+
+    fn headers(topic: &str, hmac: &str) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(X_SHOPIFY_TOPIC, topic.parse().unwrap());
+        headers.insert(X_SHOPIFY_HMAC_SHA256, hmac.parse().unwrap());
+        headers.insert(
+            X_SHOPIFY_SHOP_DOMAIN,
+            "my-shop.myshopify.com".parse().unwrap(),
+        );
+        headers.insert(X_SHOPIFY_API_VERSION, "2023-10".parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn verifies_webhooks() {
+        verify_webhook(SECRET, BODY, HMAC).unwrap();
+        assert!(matches!(
+            verify_webhook("another-secret", BODY, HMAC),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_webhook(SECRET, b"{}", HMAC),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_webhook(SECRET, BODY, "not base64"),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn parses_webhooks_by_topic() {
+        let request =
+            WebhookRequest::from_request(SECRET, &headers("orders/create", HMAC), BODY).unwrap();
+        assert_eq!(request.shop_domain, "my-shop.myshopify.com");
+        assert_eq!(request.api_version.as_deref(), Some("2023-10"));
+        assert_eq!(request.webhook_id, None);
+        match request.webhook {
+            Webhook::OrdersCreate(order) => {
+                assert_eq!(order.name.as_deref(), Some("#9999"));
+                assert_eq!(order.line_items[0].quantity, Some(1));
+            }
+            other => panic!("expected an order, got {:?}", other),
+        }
+
+        let request =
+            WebhookRequest::from_request(SECRET, &headers("app/uninstalled", HMAC), BODY).unwrap();
+        assert!(matches!(
+            request.webhook,
+            Webhook::Unknown { ref topic, .. } if topic == "app/uninstalled"
+        ));
+
+        assert!(matches!(
+            WebhookRequest::from_request(SECRET, &http::HeaderMap::new(), BODY),
+            Err(WebhookError::MissingHeader(X_SHOPIFY_HMAC_SHA256))
+        ));
+    }
+
+    #[test]
+    fn verifies_install_queries() {
+        // The example of https://shopify.dev/docs/apps/auth/oauth/getting-started
+        let query = "code=0907a61c0c8d55e99db179b68161bc00\
+                     &hmac=700e2dadb827fcc8609e9d5ce208b2e9cdaab9df07390d2cbca10d7c328fc4bf\
+                     &shop=some-shop.myshopify.com&state=0.6784241404160823&timestamp=1337178173";
+        assert!(verify_query_with_tolerance(SECRET, query, Duration::MAX));
+        assert!(!verify_query_with_tolerance(
+            SECRET,
+            &query.replace("1337178173", "1337178174"),
+            Duration::MAX
+        ));
+        assert!(!verify_query_with_tolerance(
+            SECRET,
+            "code=0907a61c0c8d55e99db179b68161bc00",
+            Duration::MAX
+        ));
+    }
+
+    #[test]
+    fn rejects_replayed_install_queries() {
+        let sign = |timestamp: i64| {
+            let query = format!(
+                "code=0907a61c0c8d55e99db179b68161bc00&shop=some-shop.myshopify.com\
+                 &state=nonce&timestamp={}",
+                timestamp
+            );
+            let mut mac = new_mac(SECRET).unwrap();
+            mac.update(query.as_bytes());
+            format!(
+                "{}&hmac={}",
+                query,
+                hex::encode(mac.finalize().into_bytes())
+            )
+        };
+
+        assert!(verify_query(SECRET, &sign(now())));
+        let replayed = sign(now() - 600);
+        assert!(!verify_query(SECRET, &replayed));
+        assert!(verify_query_with_tolerance(
+            SECRET,
+            &replayed,
+            Duration::from_secs(900)
+        ));
+    }
+
+    #[test]
+    fn verifies_proxy_queries() {
+        let query = "extra=1&extra=2&shop=shop-name.myshopify.com&logged_in_customer_id=\
+                     &path_prefix=%2Fapps%2Fawesome_reviews&timestamp=1317327555\
+                     &signature=e072b6d7e6622d85912a5214b860d3100dc1e73d9bc29f43796ac8c9ff8093cb";
+        assert!(verify_proxy_query(SECRET, query));
+        assert!(!verify_proxy_query(
+            SECRET,
+            &query.replace("extra=2", "extra=3")
+        ));
+        assert!(!verify_proxy_query(SECRET, "shop=shop-name.myshopify.com"));
+    }
+}
@@ -1 +1,232 @@
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use shopify::{
+    resources::{Product, ProductBody},
+    Client, ClientError,
+};
+
+#[tokio::test]
+async fn test_sends_the_access_token_to_the_api_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2023-10/shopify_payments/balance.json"))
+        .and(header("x-shopify-access-token", "shpat_token"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut shopify = Client::new_for_shop(
+        "my-shop",
+        "client-id",
+        "client-secret",
+        "https://example.com/callback",
+        "shpat_token",
+    )
+    .unwrap();
+    shopify
+        .with_api_version("2023-10")
+        .with_host_override(server.uri());
+
+    shopify.shopify_payments().get_balance().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests[0]
+        .headers
+        .iter()
+        .all(|(name, _)| name.as_str() != "authorization"));
+}
+
+#[tokio::test]
+async fn test_takes_and_returns_typed_resources() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-10/products/632910392.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "product": {
+                "id": 632910392,
+                "title": "IPod Nano - 8GB",
+                "variants": [{"id": 808950810, "price": "199.00"}]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/admin/api/2026-10/products/632910392.json"))
+        .and(body_json(serde_json::json!({
+            "product": {"id": 632910392, "title": "IPod Nano - 16GB"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "product": {"id": 632910392, "title": "IPod Nano - 16GB"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut shopify = Client::new_for_shop("my-shop", "", "", "", "shpat_token").unwrap();
+    shopify.with_host_override(server.uri());
+
+    let product = shopify
+        .products()
+        .get_param("632910392", "")
+        .await
+        .unwrap()
+        .product;
+    assert_eq!(product.variants[0].price.as_deref(), Some("199.00"));
+
+    let body = ProductBody {
+        product: Product {
+            id: product.id,
+            title: Some("IPod Nano - 16GB".to_string()),
+            ..Default::default()
+        },
+    };
+    let updated = shopify
+        .products()
+        .update_param("632910392", &body)
+        .await
+        .unwrap();
+    assert_eq!(updated.product.title.as_deref(), Some("IPod Nano - 16GB"));
+}
+
+#[tokio::test]
+async fn test_waits_for_the_call_limit_across_clones() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-10/shopify_payments/balance.json"))
+        .respond_with(
+            ResponseTemplate::new(200).insert_header("x-shopify-shop-api-call-limit", "40/40"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut shopify = Client::new_for_shop("my-shop", "", "", "", "shpat_token").unwrap();
+    shopify.with_host_override(server.uri());
+    let clone = shopify.clone();
+
+    shopify.shopify_payments().get_balance().await.unwrap();
+
+    // The bucket is full, so the clone waits for a request to leak out of it.
+    let started = std::time::Instant::now();
+    clone.shopify_payments().get_balance().await.unwrap();
+    assert!(started.elapsed() >= std::time::Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_waits_out_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-10/shopify_payments/balance.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1.0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-10/shopify_payments/balance.json"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut shopify = Client::new_for_shop("my-shop", "", "", "", "shpat_token").unwrap();
+    shopify.with_host_override(server.uri());
+
+    let started = std::time::Instant::now();
+    shopify.shopify_payments().get_balance().await.unwrap();
+    assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_holds_retries_for_the_call_limit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-10/shopify_payments/balance.json"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("retry-after", "0")
+                .insert_header("x-shopify-shop-api-call-limit", "40/40"),
+        )
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let mut shopify = Client::new_for_shop("my-shop", "", "", "", "shpat_token").unwrap();
+    shopify.with_host_override(server.uri());
+
+    // The request is sent again 3 times, each time once a request leaked out of
+    // the full bucket.
+    let started = std::time::Instant::now();
+    let err = shopify.shopify_payments().get_balance().await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ClientError>(),
+        Some(ClientError::RateLimited { .. })
+    ));
+    assert!(started.elapsed() >= std::time::Duration::from_millis(1400));
+}
+
+#[test]
+fn test_parses_signed_product_webhooks() {
+    use hmac::Mac;
+    use shopify::webhooks::{Webhook, WebhookError, WebhookRequest};
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "id": 788032119674292922_i64,
+        "title": "Example T-Shirt",
+        "vendor": "Acme",
+        "status": "active",
+    }))
+    .unwrap();
+
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(b"client-secret").unwrap();
+    mac.update(&body);
+    let hmac = base64::encode(mac.finalize().into_bytes());
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-shopify-hmac-sha256", hmac.parse().unwrap());
+    headers.insert("x-shopify-topic", "products/update".parse().unwrap());
+    headers.insert(
+        "x-shopify-shop-domain",
+        "my-shop.myshopify.com".parse().unwrap(),
+    );
+    headers.insert(
+        "x-shopify-webhook-id",
+        "b54557e4-bdd9-4b37-8a5f-bf7d70bcd043".parse().unwrap(),
+    );
+
+    let request = WebhookRequest::from_request("client-secret", &headers, &body).unwrap();
+    assert_eq!(request.topic, "products/update");
+    assert_eq!(request.shop_domain, "my-shop.myshopify.com");
+    assert_eq!(request.api_version, None);
+    assert_eq!(
+        request.webhook_id.as_deref(),
+        Some("b54557e4-bdd9-4b37-8a5f-bf7d70bcd043")
+    );
+    match request.webhook {
+        Webhook::ProductsUpdate(product) => {
+            assert_eq!(product.id, Some(788032119674292922));
+            assert_eq!(product.title.as_deref(), Some("Example T-Shirt"));
+        }
+        other => panic!("expected a product, got {:?}", other),
+    }
+
+    let err = WebhookRequest::from_request("another-secret", &headers, &body).unwrap_err();
+    assert!(matches!(err, WebhookError::InvalidSignature));
+
+    headers.remove("x-shopify-shop-domain");
+    let err = WebhookRequest::from_request("client-secret", &headers, &body).unwrap_err();
+    assert!(matches!(
+        err,
+        WebhookError::MissingHeader("x-shopify-shop-domain")
+    ));
+}
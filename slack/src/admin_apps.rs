@@ -23,13 +23,16 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn approve(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.apps.approve", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn restrict(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.apps.restrict", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
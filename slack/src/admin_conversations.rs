@@ -23,13 +23,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,7 +49,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn convert_private(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.convertToPrivate", None);
@@ -54,7 +60,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -71,13 +77,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::AdminConversationsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> Result<crate::types::AdminConversationsCreateSchema> {
         let url = self.client.url("/admin.conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -94,13 +103,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -117,7 +129,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.disconnectShared", None);
@@ -125,7 +140,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -210,6 +225,62 @@ impl AdminConversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_team`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub fn get_all_teams_stream(
+        &self,
+        channel_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<String> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin.conversations.getTeams?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::AdminConversationsGetTeamsSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: resp.response_metadata.map(|m| m.next_cursor),
+                    items: resp.team_ids,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
      *
@@ -221,13 +292,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -244,13 +318,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -334,7 +411,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.setConversationPrefs", None);
@@ -342,7 +422,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -359,13 +439,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.setTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -382,13 +465,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -19,7 +19,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.addGroup", None);
@@ -27,7 +30,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -80,7 +83,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.removeGroup", None);
@@ -88,7 +94,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -19,13 +19,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -38,13 +41,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.addAlias", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -92,13 +98,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -111,13 +120,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
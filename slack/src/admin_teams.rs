@@ -23,13 +23,16 @@ impl AdminTeams {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
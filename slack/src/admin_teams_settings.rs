@@ -50,7 +50,10 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
      */
-    pub async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDefaultChannels", None);
@@ -58,7 +61,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -75,7 +78,10 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_description(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDescription", None);
@@ -83,7 +89,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -100,7 +106,10 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDiscoverability", None);
@@ -108,7 +117,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -121,13 +130,16 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.settings.setIcon", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -144,13 +156,16 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.settings.setName", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
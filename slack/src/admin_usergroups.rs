@@ -23,13 +23,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.addChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.addTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -116,13 +122,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn remove_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.removeChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -23,13 +23,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn assign(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.assign", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -113,13 +119,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -136,13 +145,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.setAdmin", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -159,13 +171,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_expiration(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.setExpiration", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -182,13 +197,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_owner(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.setOwner", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -205,13 +223,16 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_regular(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.setRegular", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -23,13 +23,16 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invalidate(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.session.invalidate", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn reset(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.users.session.reset", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
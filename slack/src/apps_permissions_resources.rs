@@ -52,4 +52,51 @@ impl AppsPermissionsResources {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Resources> {
+        let url = self.client.url("/apps.permissions.resources.list", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::AppsPermissionsResourcesListSuccessSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: resp.response_metadata.map(|m| m.next_cursor),
+                    items: resp.resources,
+                })
+            }
+        })
+    }
 }
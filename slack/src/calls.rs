@@ -23,13 +23,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn end(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.end", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -98,13 +104,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -23,13 +23,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::ChatDeleteSuccessSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> Result<crate::types::ChatDeleteSuccessSchema> {
         let url = self.client.url("/chat.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/chat.deleteScheduledMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -108,13 +114,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn me_message(&self) -> Result<crate::types::ChatMeMessageSchema> {
+    pub async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> Result<crate::types::ChatMeMessageSchema> {
         let url = self.client.url("/chat.meMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -131,13 +140,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_ephemeral(&self) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
+    pub async fn post_ephemeral(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
         let url = self.client.url("/chat.postEphemeral", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -154,13 +166,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_message(&self) -> Result<crate::types::ChatPostMessageSuccessSchema> {
+    pub async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema> {
         let url = self.client.url("/chat.postMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -177,13 +192,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn schedule_message(&self) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
+    pub async fn schedule_message(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
         let url = self.client.url("/chat.scheduleMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -200,13 +218,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/chat.unfurl", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -223,13 +244,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::ChatUpdateSuccessSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema> {
         let url = self.client.url("/chat.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -66,4 +66,68 @@ impl ChatScheduledMessages {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ScheduledMessages> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/chat.scheduledMessages.list?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ChatScheduledMessagesListSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.response_metadata.next_cursor),
+                    items: resp.scheduled_messages,
+                })
+            }
+        })
+    }
 }
@@ -23,13 +23,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn close(&self) -> Result<crate::types::ConversationsCloseSuccessSchema> {
+    pub async fn close(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsCloseSuccessSchema> {
         let url = self.client.url("/conversations.close", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -69,13 +75,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -198,13 +207,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -221,13 +233,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `channels:write`.
      */
-    pub async fn join(&self) -> Result<crate::types::ConversationsJoinSuccessSchema> {
+    pub async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsJoinSuccessSchema> {
         let url = self.client.url("/conversations.join", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -244,13 +259,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.kick", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -267,13 +285,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn leave(&self) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
+    pub async fn leave(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
         let url = self.client.url("/conversations.leave", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -328,6 +349,66 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<Vec<crate::types::ObjsConversationAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.list?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ConversationsListSuccessSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: resp.response_metadata.map(|m| m.next_cursor),
+                    items: resp.channels,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/conversations.mark` endpoint.
      *
@@ -339,13 +420,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.mark", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -395,6 +479,62 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `member`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub fn get_all_members_stream(
+        &self,
+        channel: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<String> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.members?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ConversationsMembersSuccessSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.response_metadata.next_cursor),
+                    items: resp.members,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/conversations.open` endpoint.
      *
@@ -406,13 +546,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn open(&self) -> Result<crate::types::ConversationsOpenSuccessSchema> {
+    pub async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> Result<crate::types::ConversationsOpenSuccessSchema> {
         let url = self.client.url("/conversations.open", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -429,13 +572,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -516,13 +662,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.setPurpose", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -539,13 +688,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.setTopic", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -562,13 +714,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -0,0 +1,721 @@
+//! Verifies and parses the requests Slack sends to an app: the Events API,
+//! interactivity and slash commands.
+//!
+//! Slack signs the raw body of each request with the signing secret of the
+//! app, in the `X-Slack-Signature` header, along with the time of the request
+//! in the `X-Slack-Request-Timestamp` header. The time is checked against a
+//! tolerance so a captured request can't be replayed later.
+//! [`SlackRequest::from_request`] checks both before parsing the body, and
+//! [`SlackRequest::ack`] is the response Slack expects within 3 seconds:
+//!
+//! ```
+//! use slack_chat_api::events::{Event, SlackRequest};
+//!
+//! fn handle(headers: &http::HeaderMap, body: &[u8]) -> http::Response<String> {
+//!     match SlackRequest::from_request("signing-secret", headers, body) {
+//!         Ok(request) => {
+//!             if let SlackRequest::EventCallback(callback) = &request {
+//!                 if let Event::AppMention(mention) = &callback.event {
+//!                     println!("<@{}> said {}", mention.user, mention.text);
+//!                 }
+//!             }
+//!             request.ack().into_response()
+//!         }
+//!         Err(e) => http::Response::builder()
+//!             .status(http::StatusCode::UNAUTHORIZED)
+//!             .body(e.to_string())
+//!             .unwrap(),
+//!     }
+//! }
+//! ```
+//!
+//! Events and interactions without a variant here, like `team_join` or
+//! `shortcut`, keep their JSON in an `Unknown` variant, so a new kind of event
+//! is still acknowledged.
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use sha2::Sha256;
+
+const X_SLACK_REQUEST_TIMESTAMP: &str = "x-slack-request-timestamp";
+const X_SLACK_SIGNATURE: &str = "x-slack-signature";
+
+/// How old a request may be, like the Slack SDKs.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// A request that didn't come from Slack, or isn't one Slack sends.
+#[derive(Debug, thiserror::Error)]
+pub enum RequestError {
+    /// The request is missing a header Slack always sends.
+    #[error("missing the {0} header")]
+    MissingHeader(&'static str),
+    /// The body wasn't signed with the signing secret of the app.
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The request is older than the tolerance, or from the future.
+    #[error("the timestamp of the request is outside the tolerance")]
+    TimestampOutsideTolerance,
+    /// The body isn't the JSON Slack sends.
+    #[error("invalid payload: {0}")]
+    Payload(#[from] serde_json::Error),
+    /// The body isn't the form Slack sends.
+    #[error("invalid form: {0}")]
+    Form(#[from] serde_urlencoded::de::Error),
+    /// The form has no `payload`, and its `command` doesn't start with `/`.
+    #[error("not a slash command: {0:?}")]
+    NotASlashCommand(String),
+}
+
+/// Check the `X-Slack-Signature` header of a request, like `v0=a2114d57…`,
+/// against its `X-Slack-Request-Timestamp` header and raw body. Slack signs
+/// `v0:<timestamp>:<body>`, so the timestamp is only held to the tolerance once
+/// the signature matches.
+pub fn verify_signature(
+    signing_secret: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+    tolerance: Duration,
+) -> Result<(), RequestError> {
+    let signature = signature
+        .strip_prefix("v0=")
+        .and_then(|s| hex::decode(s).ok())
+        .ok_or(RequestError::InvalidSignature)?;
+    sign(signing_secret, timestamp, body)?
+        .verify_slice(&signature)
+        .map_err(|_| RequestError::InvalidSignature)?;
+
+    let timestamp: i64 = timestamp
+        .parse()
+        .map_err(|_| RequestError::TimestampOutsideTolerance)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    if (now - timestamp).unsigned_abs() > tolerance.as_secs() {
+        return Err(RequestError::TimestampOutsideTolerance);
+    }
+
+    Ok(())
+}
+
+fn header<'a>(headers: &'a http::HeaderMap, name: &'static str) -> Result<&'a str, RequestError> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .ok_or(RequestError::MissingHeader(name))
+}
+
+fn sign(signing_secret: &str, timestamp: &str, body: &[u8]) -> Result<Hmac<Sha256>, RequestError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes())
+        .map_err(|_| RequestError::InvalidSignature)?;
+    mac.update(b"v0:");
+    mac.update(timestamp.as_bytes());
+    mac.update(b":");
+    mac.update(body);
+    Ok(mac)
+}
+
+/// A message posted in a conversation the app is in.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct MessageEvent {
+    /// Set for the messages that aren't plain user messages, like
+    /// `bot_message` or `message_changed`.
+    #[serde(default)]
+    pub subtype: Option<String>,
+    #[serde(default)]
+    pub channel: String,
+    /// `channel`, `group`, `im` or `mpim`.
+    #[serde(default)]
+    pub channel_type: String,
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// A message mentioning the app.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct AppMentionEvent {
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// The item a reaction was added to.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ReactionItem {
+    /// `message`, `file` or `file_comment`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
+/// A reaction added to an item.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ReactionAddedEvent {
+    #[serde(default)]
+    pub user: String,
+    /// The name of the emoji, without colons.
+    #[serde(default)]
+    pub reaction: String,
+    /// The author of the item.
+    #[serde(default)]
+    pub item_user: String,
+    pub item: ReactionItem,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// An event of the Events API, by its `type`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    Message(MessageEvent),
+    AppMention(AppMentionEvent),
+    ReactionAdded(ReactionAddedEvent),
+    /// An event without a variant here, like `team_join`.
+    Unknown {
+        type_: String,
+        event: serde_json::Value,
+    },
+}
+
+impl Event {
+    fn from_value(event: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let type_ = event["type"].as_str().unwrap_or_default().to_string();
+        Ok(match type_.as_str() {
+            "message" => Event::Message(serde_json::from_value(event)?),
+            "app_mention" => Event::AppMention(serde_json::from_value(event)?),
+            "reaction_added" => Event::ReactionAdded(serde_json::from_value(event)?),
+            _ => Event::Unknown { type_, event },
+        })
+    }
+
+    /// The `type` of the event.
+    pub fn type_(&self) -> &str {
+        match self {
+            Event::Message(_) => "message",
+            Event::AppMention(_) => "app_mention",
+            Event::ReactionAdded(_) => "reaction_added",
+            Event::Unknown { type_, .. } => type_,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Event::from_value(serde_json::Value::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// The envelope of an event of the Events API.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct EventCallback {
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub api_app_id: String,
+    pub event: Event,
+    /// Unique across workspaces, to tell a retried delivery of an event.
+    #[serde(default)]
+    pub event_id: String,
+    #[serde(default)]
+    pub event_time: i64,
+}
+
+/// The user of an interaction.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct InteractionUser {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub team_id: String,
+}
+
+/// The team of an interaction.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct InteractionTeam {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub domain: String,
+}
+
+/// The channel of an interaction.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct InteractionChannel {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// An interactive component of a block that was used.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct BlockAction {
+    #[serde(default)]
+    pub action_id: String,
+    #[serde(default)]
+    pub block_id: String,
+    /// `button`, `static_select`, `datepicker`…
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// The value of a button.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// The option picked in a select menu.
+    #[serde(default)]
+    pub selected_option: Option<serde_json::Value>,
+    #[serde(default)]
+    pub action_ts: String,
+}
+
+/// Buttons clicked or menus picked in a message, a modal or the App Home.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct BlockActionsPayload {
+    #[serde(default)]
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub channel: Option<InteractionChannel>,
+    #[serde(default)]
+    pub trigger_id: String,
+    /// Where to post messages in reply, for actions in messages.
+    #[serde(default)]
+    pub response_url: Option<String>,
+    #[serde(default)]
+    pub actions: Vec<BlockAction>,
+    /// The message of the blocks, for actions in messages.
+    #[serde(default)]
+    pub message: Option<serde_json::Value>,
+    /// The view of the blocks, for actions in modals and the App Home.
+    #[serde(default)]
+    pub view: Option<serde_json::Value>,
+}
+
+/// The state of the inputs of a submitted modal.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ViewState {
+    /// The values of the inputs, by `block_id` then `action_id`.
+    #[serde(default)]
+    pub values: BTreeMap<String, BTreeMap<String, serde_json::Value>>,
+}
+
+/// A submitted modal.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct SubmittedView {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub private_metadata: String,
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub state: ViewState,
+}
+
+/// A modal submitted by a user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ViewSubmissionPayload {
+    #[serde(default)]
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub trigger_id: String,
+    pub view: SubmittedView,
+}
+
+/// An interaction with the app, the `payload` of the form Slack posts, by its
+/// `type`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InteractionPayload {
+    BlockActions(BlockActionsPayload),
+    ViewSubmission(ViewSubmissionPayload),
+    /// An interaction without a variant here, like `shortcut` or
+    /// `view_closed`.
+    Unknown {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl InteractionPayload {
+    fn from_value(payload: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let type_ = payload["type"].as_str().unwrap_or_default().to_string();
+        Ok(match type_.as_str() {
+            "block_actions" => InteractionPayload::BlockActions(serde_json::from_value(payload)?),
+            "view_submission" => {
+                InteractionPayload::ViewSubmission(serde_json::from_value(payload)?)
+            }
+            _ => InteractionPayload::Unknown { type_, payload },
+        })
+    }
+}
+
+/// A slash command run by a user.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct SlashCommand {
+    /// The command, like `/weather`.
+    pub command: String,
+    /// What follows the command.
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub team_domain: String,
+    #[serde(default)]
+    pub channel_id: String,
+    #[serde(default)]
+    pub channel_name: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub user_name: String,
+    #[serde(default)]
+    pub api_app_id: String,
+    /// Where to post messages in reply, for 30 minutes.
+    #[serde(default)]
+    pub response_url: String,
+    #[serde(default)]
+    pub trigger_id: String,
+}
+
+#[derive(Deserialize)]
+struct InteractionForm {
+    payload: String,
+}
+
+/// A request Slack sends to an app.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlackRequest {
+    /// Sent when the request URL of the Events API is set, to be answered with
+    /// its challenge.
+    UrlVerification {
+        challenge: String,
+    },
+    EventCallback(EventCallback),
+    Interaction(InteractionPayload),
+    SlashCommand(SlashCommand),
+    /// An Events API request without a variant here, like
+    /// `app_rate_limited`.
+    Unknown {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl SlackRequest {
+    /// Parse the body of a request: the JSON of the Events API, or the form of
+    /// an interaction or a slash command. The signature isn't checked.
+    pub fn parse(body: &[u8]) -> Result<Self, RequestError> {
+        if body.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{') {
+            let payload: serde_json::Value = serde_json::from_slice(body)?;
+            let type_ = payload["type"].as_str().unwrap_or_default().to_string();
+            return Ok(match type_.as_str() {
+                "url_verification" => SlackRequest::UrlVerification {
+                    challenge: payload["challenge"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+                "event_callback" => SlackRequest::EventCallback(serde_json::from_value(payload)?),
+                _ => SlackRequest::Unknown { type_, payload },
+            });
+        }
+
+        match serde_urlencoded::from_bytes::<InteractionForm>(body) {
+            Ok(form) => Ok(SlackRequest::Interaction(InteractionPayload::from_value(
+                serde_json::from_str(&form.payload)?,
+            )?)),
+            Err(_) => {
+                let command: SlashCommand = serde_urlencoded::from_bytes(body)?;
+                if !command.command.starts_with('/') {
+                    return Err(RequestError::NotASlashCommand(command.command));
+                }
+                Ok(SlackRequest::SlashCommand(command))
+            }
+        }
+    }
+
+    /// Verify the signature of a request with the signing secret of the app,
+    /// within the [`DEFAULT_TOLERANCE`], then parse its body.
+    pub fn from_request(
+        signing_secret: &str,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self, RequestError> {
+        verify_signature(
+            signing_secret,
+            header(headers, X_SLACK_REQUEST_TIMESTAMP)?,
+            body,
+            header(headers, X_SLACK_SIGNATURE)?,
+            DEFAULT_TOLERANCE,
+        )?;
+        SlackRequest::parse(body)
+    }
+
+    /// The acknowledgement of the request: its challenge for a
+    /// `url_verification`, an empty `200 OK` otherwise.
+    pub fn ack(&self) -> Acknowledgement {
+        match self {
+            SlackRequest::UrlVerification { challenge } => {
+                Acknowledgement::Challenge(challenge.clone())
+            }
+            _ => Acknowledgement::Empty,
+        }
+    }
+}
+
+/// The response to a request, which Slack expects within 3 seconds. Anything
+/// slower goes through the Web API or the `response_url` afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Acknowledgement {
+    /// An empty `200 OK`, which closes a submitted modal.
+    Empty,
+    /// The challenge of a `url_verification`.
+    Challenge(String),
+    /// A message answering a slash command, seen by everyone in the channel or
+    /// only by the user who ran the command.
+    Message { text: String, in_channel: bool },
+    /// Errors shown under the inputs of a submitted modal, by `block_id`.
+    ViewErrors(BTreeMap<String, String>),
+    /// Replace a submitted modal with this view.
+    ViewUpdate(serde_json::Value),
+    /// Push this view on top of a submitted modal.
+    ViewPush(serde_json::Value),
+    /// Close all the modals of a submitted one.
+    ViewClear,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+enum ResponseAction<'a> {
+    Errors {
+        errors: &'a BTreeMap<String, String>,
+    },
+    Update {
+        view: &'a serde_json::Value,
+    },
+    Push {
+        view: &'a serde_json::Value,
+    },
+    Clear,
+}
+
+impl Acknowledgement {
+    /// The body of the response, JSON unless empty or a challenge.
+    pub fn body(&self) -> String {
+        let json = match self {
+            Acknowledgement::Empty => return String::new(),
+            Acknowledgement::Challenge(challenge) => return challenge.to_string(),
+            Acknowledgement::Message { text, in_channel } => serde_json::json!({
+                "response_type": if *in_channel { "in_channel" } else { "ephemeral" },
+                "text": text,
+            }),
+            Acknowledgement::ViewErrors(errors) => {
+                serde_json::json!(ResponseAction::Errors { errors })
+            }
+            Acknowledgement::ViewUpdate(view) => serde_json::json!(ResponseAction::Update { view }),
+            Acknowledgement::ViewPush(view) => serde_json::json!(ResponseAction::Push { view }),
+            Acknowledgement::ViewClear => serde_json::json!(ResponseAction::Clear),
+        };
+        json.to_string()
+    }
+
+    /// The `200 OK` response of the acknowledgement.
+    pub fn into_response(self) -> http::Response<String> {
+        let content_type = match self {
+            Acknowledgement::Empty | Acknowledgement::Challenge(_) => "text/plain",
+            _ => "application/json",
+        };
+        http::Response::builder()
+            .status(http::StatusCode::OK)
+            .header(http::header::CONTENT_TYPE, content_type)
+            .body(self.body())
+            // The status and the header are valid.
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of https://api.slack.com/authentication/verifying-requests-from-slack
+    const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &[u8] = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    #[test]
+    fn verifies_the_documented_slash_command() {
+        // The example was signed in 2018, so only the signature is checked.
+        let tolerance = Duration::from_secs(u64::MAX);
+        verify_signature(SIGNING_SECRET, TIMESTAMP, BODY, SIGNATURE, tolerance).unwrap();
+
+        assert!(matches!(
+            verify_signature(SIGNING_SECRET, "1531420619", BODY, SIGNATURE, tolerance),
+            Err(RequestError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_signature("another secret", TIMESTAMP, BODY, SIGNATURE, tolerance),
+            Err(RequestError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_signature(
+                SIGNING_SECRET,
+                TIMESTAMP,
+                BODY,
+                SIGNATURE,
+                DEFAULT_TOLERANCE
+            ),
+            Err(RequestError::TimestampOutsideTolerance)
+        ));
+    }
+
+    #[test]
+    fn parses_slash_commands() {
+        match SlackRequest::parse(BODY).unwrap() {
+            SlackRequest::SlashCommand(command) => {
+                assert_eq!(command.command, "/webhook-collect");
+                assert_eq!(command.user_name, "roadrunner");
+                assert_eq!(command.text, "");
+            }
+            other => panic!("expected a slash command, got {:?}", other),
+        }
+        assert!(matches!(
+            SlackRequest::parse(b"name=roadrunner&speed=fast"),
+            Err(RequestError::Form(_))
+        ));
+        assert!(matches!(
+            SlackRequest::parse(b"command=weather&text=tomorrow"),
+            Err(RequestError::NotASlashCommand(command)) if command == "weather"
+        ));
+
+        let ack = Acknowledgement::Message {
+            text: "Collected!".to_string(),
+            in_channel: false,
+        };
+        let body: serde_json::Value = serde_json::from_str(&ack.body()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({"response_type": "ephemeral", "text": "Collected!"})
+        );
+    }
+
+    #[test]
+    fn answers_url_verifications() {
+        let request = SlackRequest::parse(
+            br#"{"token": "Jhj5dZrVaK7ZwHHjRyZWjbDl", "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P", "type": "url_verification"}"#,
+        )
+        .unwrap();
+        let response = request.ack().into_response();
+        assert_eq!(
+            response.body(),
+            "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+        );
+    }
+
+    #[test]
+    fn parses_events() {
+        let request = SlackRequest::parse(
+            br#"{
+                "type": "event_callback",
+                "team_id": "T1",
+                "api_app_id": "A1",
+                "event_id": "Ev1",
+                "event_time": 1515449522,
+                "event": {
+                    "type": "reaction_added",
+                    "user": "U1",
+                    "reaction": "thumbsup",
+                    "item_user": "U2",
+                    "item": {"type": "message", "channel": "C1", "ts": "1360782400.498405"},
+                    "event_ts": "1360782804.083113"
+                }
+            }"#,
+        )
+        .unwrap();
+        match request {
+            SlackRequest::EventCallback(EventCallback {
+                event: Event::ReactionAdded(event),
+                ..
+            }) => {
+                assert_eq!(event.reaction, "thumbsup");
+                assert_eq!(event.item.channel, "C1");
+            }
+            other => panic!("expected a reaction, got {:?}", other),
+        }
+
+        let event = Event::from_value(serde_json::json!({"type": "team_join"})).unwrap();
+        assert_eq!(event.type_(), "team_join");
+    }
+
+    #[test]
+    fn parses_view_submissions() {
+        let payload = serde_json::json!({
+            "type": "view_submission",
+            "user": {"id": "U1", "username": "roadrunner"},
+            "view": {
+                "id": "V1",
+                "callback_id": "feedback",
+                "state": {"values": {"rating": {"stars": {"type": "plain_text_input", "value": "5"}}}}
+            }
+        });
+        let body = serde_urlencoded::to_string([("payload", payload.to_string())]).unwrap();
+
+        match SlackRequest::parse(body.as_bytes()).unwrap() {
+            SlackRequest::Interaction(InteractionPayload::ViewSubmission(submission)) => {
+                assert_eq!(submission.user.username, "roadrunner");
+                assert_eq!(submission.view.callback_id, "feedback");
+                assert_eq!(
+                    submission.view.state.values["rating"]["stars"]["value"],
+                    "5"
+                );
+            }
+            other => panic!("expected a view submission, got {:?}", other),
+        }
+
+        let errors = [("rating".to_string(), "Pick 1 to 5 stars".to_string())].into();
+        let body: serde_json::Value =
+            serde_json::from_str(&Acknowledgement::ViewErrors(errors).body()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "response_action": "errors",
+                "errors": {"rating": "Pick 1 to 5 stars"}
+            })
+        );
+    }
+}
@@ -2,6 +2,114 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`Files::upload`].
+#[derive(Default)]
+pub struct FilesUploadForm {
+    token: Option<String>,
+    file: Option<crate::utils::FilePart>,
+    content: Option<String>,
+    filetype: Option<String>,
+    filename: Option<String>,
+    title: Option<String>,
+    initial_comment: Option<String>,
+    channels: Option<String>,
+    thread_ts: Option<f64>,
+}
+
+impl FilesUploadForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Authentication token. Requires scope: `files:write:user`
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// File contents via `multipart/form-data`. If omitting this parameter, you must submit `content`.
+    pub fn file(mut self, value: crate::utils::FilePart) -> Self {
+        self.file = Some(value);
+        self
+    }
+
+    /// File contents via a POST variable. If omitting this parameter, you must provide a `file`.
+    pub fn content(mut self, value: impl Into<String>) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// A [file type](/types/file#file_types) identifier.
+    pub fn filetype(mut self, value: impl Into<String>) -> Self {
+        self.filetype = Some(value.into());
+        self
+    }
+
+    /// Filename of file.
+    pub fn filename(mut self, value: impl Into<String>) -> Self {
+        self.filename = Some(value.into());
+        self
+    }
+
+    /// Title of file.
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// The message text introducing the file in specified `channels`.
+    pub fn initial_comment(mut self, value: impl Into<String>) -> Self {
+        self.initial_comment = Some(value.into());
+        self
+    }
+
+    /// Comma-separated list of channel names or IDs where the file will be shared.
+    pub fn channels(mut self, value: impl Into<String>) -> Self {
+        self.channels = Some(value.into());
+        self
+    }
+
+    /// Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.
+    pub fn thread_ts(mut self, value: f64) -> Self {
+        self.thread_ts = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.token {
+            form = form.text("token", value);
+        }
+        if let Some(value) = self.file {
+            form = form.part("file", value.into_part().await?);
+        }
+        if let Some(value) = self.content {
+            form = form.text("content", value);
+        }
+        if let Some(value) = self.filetype {
+            form = form.text("filetype", value);
+        }
+        if let Some(value) = self.filename {
+            form = form.text("filename", value);
+        }
+        if let Some(value) = self.title {
+            form = form.text("title", value);
+        }
+        if let Some(value) = self.initial_comment {
+            form = form.text("initial_comment", value);
+        }
+        if let Some(value) = self.channels {
+            form = form.text("channels", value);
+        }
+        if let Some(value) = self.thread_ts {
+            form = form.text("thread_ts", value.to_string());
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct Files {
     pub client: Client,
 }
@@ -23,13 +131,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/files.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -168,13 +279,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.revokePublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -191,13 +305,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.sharedPublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -210,16 +327,10 @@ impl Files {
      *
      * FROM: <https://api.slack.com/methods/files.upload>
      */
-    pub async fn upload(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn upload(&self, body: FilesUploadForm) -> Result<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.upload", None);
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
 }
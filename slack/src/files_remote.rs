@@ -2,6 +2,192 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`FilesRemote::add`].
+#[derive(Default)]
+pub struct FilesRemoteAddForm {
+    token: Option<String>,
+    external_id: Option<String>,
+    title: Option<String>,
+    filetype: Option<String>,
+    external_url: Option<String>,
+    preview_image: Option<crate::utils::FilePart>,
+    indexable_file_contents: Option<String>,
+}
+
+impl FilesRemoteAddForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Authentication token. Requires scope: `remote_files:write`
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, value: impl Into<String>) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Title of the file being shared.
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// type of file
+    pub fn filetype(mut self, value: impl Into<String>) -> Self {
+        self.filetype = Some(value.into());
+        self
+    }
+
+    /// URL of the remote file.
+    pub fn external_url(mut self, value: impl Into<String>) -> Self {
+        self.external_url = Some(value.into());
+        self
+    }
+
+    /// Preview of the document via `multipart/form-data`.
+    pub fn preview_image(mut self, value: crate::utils::FilePart) -> Self {
+        self.preview_image = Some(value);
+        self
+    }
+
+    /// A text file (txt, pdf, doc, etc.) containing textual search terms that are used to improve discovery of the remote file.
+    pub fn indexable_file_contents(mut self, value: impl Into<String>) -> Self {
+        self.indexable_file_contents = Some(value.into());
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.token {
+            form = form.text("token", value);
+        }
+        if let Some(value) = self.external_id {
+            form = form.text("external_id", value);
+        }
+        if let Some(value) = self.title {
+            form = form.text("title", value);
+        }
+        if let Some(value) = self.filetype {
+            form = form.text("filetype", value);
+        }
+        if let Some(value) = self.external_url {
+            form = form.text("external_url", value);
+        }
+        if let Some(value) = self.preview_image {
+            form = form.part("preview_image", value.into_part().await?);
+        }
+        if let Some(value) = self.indexable_file_contents {
+            form = form.text("indexable_file_contents", value);
+        }
+
+        Ok(form)
+    }
+}
+
+/// The `multipart/form-data` body sent by [`FilesRemote::update`].
+#[derive(Default)]
+pub struct FilesRemoteUpdateForm {
+    token: Option<String>,
+    file: Option<String>,
+    external_id: Option<String>,
+    title: Option<String>,
+    filetype: Option<String>,
+    external_url: Option<String>,
+    preview_image: Option<crate::utils::FilePart>,
+    indexable_file_contents: Option<String>,
+}
+
+impl FilesRemoteUpdateForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Authentication token. Requires scope: `remote_files:write`
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, value: impl Into<String>) -> Self {
+        self.file = Some(value.into());
+        self
+    }
+
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, value: impl Into<String>) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Title of the file being shared.
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// type of file
+    pub fn filetype(mut self, value: impl Into<String>) -> Self {
+        self.filetype = Some(value.into());
+        self
+    }
+
+    /// URL of the remote file.
+    pub fn external_url(mut self, value: impl Into<String>) -> Self {
+        self.external_url = Some(value.into());
+        self
+    }
+
+    /// Preview of the document via `multipart/form-data`.
+    pub fn preview_image(mut self, value: crate::utils::FilePart) -> Self {
+        self.preview_image = Some(value);
+        self
+    }
+
+    /// File containing contents that can be used to improve searchability for the remote file.
+    pub fn indexable_file_contents(mut self, value: impl Into<String>) -> Self {
+        self.indexable_file_contents = Some(value.into());
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.token {
+            form = form.text("token", value);
+        }
+        if let Some(value) = self.file {
+            form = form.text("file", value);
+        }
+        if let Some(value) = self.external_id {
+            form = form.text("external_id", value);
+        }
+        if let Some(value) = self.title {
+            form = form.text("title", value);
+        }
+        if let Some(value) = self.filetype {
+            form = form.text("filetype", value);
+        }
+        if let Some(value) = self.external_url {
+            form = form.text("external_url", value);
+        }
+        if let Some(value) = self.preview_image {
+            form = form.part("preview_image", value.into_part().await?);
+        }
+        if let Some(value) = self.indexable_file_contents {
+            form = form.text("indexable_file_contents", value);
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct FilesRemote {
     pub client: Client,
 }
@@ -19,16 +205,10 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(&self, body: FilesRemoteAddForm) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.add", None);
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
     /**
@@ -127,13 +307,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -190,16 +373,10 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(&self, body: FilesRemoteUpdateForm) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.update", None);
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
 }
@@ -0,0 +1,1965 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`AdminApps`](crate::admin_apps::AdminApps).
+    pub fn admin_apps(&self) -> AdminApps<'_> {
+        AdminApps { mock: self }
+    }
+
+    /// Mock the functions of [`AdminAppsApproved`](crate::admin_apps_approved::AdminAppsApproved).
+    pub fn admin_apps_approved(&self) -> AdminAppsApproved<'_> {
+        AdminAppsApproved { mock: self }
+    }
+
+    /// Mock the functions of [`AdminAppsRequests`](crate::admin_apps_requests::AdminAppsRequests).
+    pub fn admin_apps_requests(&self) -> AdminAppsRequests<'_> {
+        AdminAppsRequests { mock: self }
+    }
+
+    /// Mock the functions of [`AdminAppsRestricted`](crate::admin_apps_restricted::AdminAppsRestricted).
+    pub fn admin_apps_restricted(&self) -> AdminAppsRestricted<'_> {
+        AdminAppsRestricted { mock: self }
+    }
+
+    /// Mock the functions of [`AdminConversations`](crate::admin_conversations::AdminConversations).
+    pub fn admin_conversations(&self) -> AdminConversations<'_> {
+        AdminConversations { mock: self }
+    }
+
+    /// Mock the functions of [`AdminConversationsEkm`](crate::admin_conversations_ekm::AdminConversationsEkm).
+    pub fn admin_conversations_ekm(&self) -> AdminConversationsEkm<'_> {
+        AdminConversationsEkm { mock: self }
+    }
+
+    /// Mock the functions of [`AdminConversationsRestrictAccess`](crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess).
+    pub fn admin_conversations_restrict_access(&self) -> AdminConversationsRestrictAccess<'_> {
+        AdminConversationsRestrictAccess { mock: self }
+    }
+
+    /// Mock the functions of [`AdminEmoji`](crate::admin_emoji::AdminEmoji).
+    pub fn admin_emoji(&self) -> AdminEmoji<'_> {
+        AdminEmoji { mock: self }
+    }
+
+    /// Mock the functions of [`AdminInviteRequests`](crate::admin_invite_requests::AdminInviteRequests).
+    pub fn admin_invite_requests(&self) -> AdminInviteRequests<'_> {
+        AdminInviteRequests { mock: self }
+    }
+
+    /// Mock the functions of [`AdminInviteRequestsApproved`](crate::admin_invite_requests_approved::AdminInviteRequestsApproved).
+    pub fn admin_invite_requests_approved(&self) -> AdminInviteRequestsApproved<'_> {
+        AdminInviteRequestsApproved { mock: self }
+    }
+
+    /// Mock the functions of [`AdminInviteRequestsDenied`](crate::admin_invite_requests_denied::AdminInviteRequestsDenied).
+    pub fn admin_invite_requests_denied(&self) -> AdminInviteRequestsDenied<'_> {
+        AdminInviteRequestsDenied { mock: self }
+    }
+
+    /// Mock the functions of [`AdminTeams`](crate::admin_teams::AdminTeams).
+    pub fn admin_teams(&self) -> AdminTeams<'_> {
+        AdminTeams { mock: self }
+    }
+
+    /// Mock the functions of [`AdminTeamsAdmins`](crate::admin_teams_admins::AdminTeamsAdmins).
+    pub fn admin_teams_admins(&self) -> AdminTeamsAdmins<'_> {
+        AdminTeamsAdmins { mock: self }
+    }
+
+    /// Mock the functions of [`AdminTeamsOwners`](crate::admin_teams_owners::AdminTeamsOwners).
+    pub fn admin_teams_owners(&self) -> AdminTeamsOwners<'_> {
+        AdminTeamsOwners { mock: self }
+    }
+
+    /// Mock the functions of [`AdminTeamsSettings`](crate::admin_teams_settings::AdminTeamsSettings).
+    pub fn admin_teams_settings(&self) -> AdminTeamsSettings<'_> {
+        AdminTeamsSettings { mock: self }
+    }
+
+    /// Mock the functions of [`AdminUsergroups`](crate::admin_usergroups::AdminUsergroups).
+    pub fn admin_usergroups(&self) -> AdminUsergroups<'_> {
+        AdminUsergroups { mock: self }
+    }
+
+    /// Mock the functions of [`AdminUsers`](crate::admin_users::AdminUsers).
+    pub fn admin_users(&self) -> AdminUsers<'_> {
+        AdminUsers { mock: self }
+    }
+
+    /// Mock the functions of [`AdminUsersSession`](crate::admin_users_session::AdminUsersSession).
+    pub fn admin_users_session(&self) -> AdminUsersSession<'_> {
+        AdminUsersSession { mock: self }
+    }
+
+    /// Mock the functions of [`Api`](crate::api::Api).
+    pub fn api(&self) -> Api<'_> {
+        Api { mock: self }
+    }
+
+    /// Mock the functions of [`Apps`](crate::apps::Apps).
+    pub fn apps(&self) -> Apps<'_> {
+        Apps { mock: self }
+    }
+
+    /// Mock the functions of [`AppsEventAuthorizations`](crate::apps_event_authorizations::AppsEventAuthorizations).
+    pub fn apps_event_authorizations(&self) -> AppsEventAuthorizations<'_> {
+        AppsEventAuthorizations { mock: self }
+    }
+
+    /// Mock the functions of [`AppsPermissions`](crate::apps_permissions::AppsPermissions).
+    pub fn apps_permissions(&self) -> AppsPermissions<'_> {
+        AppsPermissions { mock: self }
+    }
+
+    /// Mock the functions of [`AppsPermissionsResources`](crate::apps_permissions_resources::AppsPermissionsResources).
+    pub fn apps_permissions_resources(&self) -> AppsPermissionsResources<'_> {
+        AppsPermissionsResources { mock: self }
+    }
+
+    /// Mock the functions of [`AppsPermissionsScopes`](crate::apps_permissions_scopes::AppsPermissionsScopes).
+    pub fn apps_permissions_scopes(&self) -> AppsPermissionsScopes<'_> {
+        AppsPermissionsScopes { mock: self }
+    }
+
+    /// Mock the functions of [`AppsPermissionsUsers`](crate::apps_permissions_users::AppsPermissionsUsers).
+    pub fn apps_permissions_users(&self) -> AppsPermissionsUsers<'_> {
+        AppsPermissionsUsers { mock: self }
+    }
+
+    /// Mock the functions of [`Auth`](crate::auth::Auth).
+    pub fn auth(&self) -> Auth<'_> {
+        Auth { mock: self }
+    }
+
+    /// Mock the functions of [`Bots`](crate::bots::Bots).
+    pub fn bots(&self) -> Bots<'_> {
+        Bots { mock: self }
+    }
+
+    /// Mock the functions of [`Calls`](crate::calls::Calls).
+    pub fn calls(&self) -> Calls<'_> {
+        Calls { mock: self }
+    }
+
+    /// Mock the functions of [`CallsParticipants`](crate::calls_participants::CallsParticipants).
+    pub fn calls_participants(&self) -> CallsParticipants<'_> {
+        CallsParticipants { mock: self }
+    }
+
+    /// Mock the functions of [`Chat`](crate::chat::Chat).
+    pub fn chat(&self) -> Chat<'_> {
+        Chat { mock: self }
+    }
+
+    /// Mock the functions of [`ChatScheduledMessages`](crate::chat_scheduled_messages::ChatScheduledMessages).
+    pub fn chat_scheduled_messages(&self) -> ChatScheduledMessages<'_> {
+        ChatScheduledMessages { mock: self }
+    }
+
+    /// Mock the functions of [`Conversations`](crate::conversations::Conversations).
+    pub fn conversations(&self) -> Conversations<'_> {
+        Conversations { mock: self }
+    }
+
+    /// Mock the functions of [`Dialog`](crate::dialog::Dialog).
+    pub fn dialog(&self) -> Dialog<'_> {
+        Dialog { mock: self }
+    }
+
+    /// Mock the functions of [`Dnd`](crate::dnd::Dnd).
+    pub fn dnd(&self) -> Dnd<'_> {
+        Dnd { mock: self }
+    }
+
+    /// Mock the functions of [`Emoji`](crate::emoji::Emoji).
+    pub fn emoji(&self) -> Emoji<'_> {
+        Emoji { mock: self }
+    }
+
+    /// Mock the functions of [`Files`](crate::files::Files).
+    pub fn files(&self) -> Files<'_> {
+        Files { mock: self }
+    }
+
+    /// Mock the functions of [`FilesComments`](crate::files_comments::FilesComments).
+    pub fn files_comments(&self) -> FilesComments<'_> {
+        FilesComments { mock: self }
+    }
+
+    /// Mock the functions of [`FilesRemote`](crate::files_remote::FilesRemote).
+    pub fn files_remote(&self) -> FilesRemote<'_> {
+        FilesRemote { mock: self }
+    }
+
+    /// Mock the functions of [`Migration`](crate::migration::Migration).
+    pub fn migration(&self) -> Migration<'_> {
+        Migration { mock: self }
+    }
+
+    /// Mock the functions of [`Oauth`](crate::oauth::Oauth).
+    pub fn oauth(&self) -> Oauth<'_> {
+        Oauth { mock: self }
+    }
+
+    /// Mock the functions of [`OauthV2`](crate::oauth_v_2::OauthV2).
+    pub fn oauth_v_2(&self) -> OauthV2<'_> {
+        OauthV2 { mock: self }
+    }
+
+    /// Mock the functions of [`Pins`](crate::pins::Pins).
+    pub fn pins(&self) -> Pins<'_> {
+        Pins { mock: self }
+    }
+
+    /// Mock the functions of [`Reactions`](crate::reactions::Reactions).
+    pub fn reactions(&self) -> Reactions<'_> {
+        Reactions { mock: self }
+    }
+
+    /// Mock the functions of [`Reminders`](crate::reminders::Reminders).
+    pub fn reminders(&self) -> Reminders<'_> {
+        Reminders { mock: self }
+    }
+
+    /// Mock the functions of [`Rtm`](crate::rtm::Rtm).
+    pub fn rtm(&self) -> Rtm<'_> {
+        Rtm { mock: self }
+    }
+
+    /// Mock the functions of [`Search`](crate::search::Search).
+    pub fn search(&self) -> Search<'_> {
+        Search { mock: self }
+    }
+
+    /// Mock the functions of [`Stars`](crate::stars::Stars).
+    pub fn stars(&self) -> Stars<'_> {
+        Stars { mock: self }
+    }
+
+    /// Mock the functions of [`Team`](crate::team::Team).
+    pub fn team(&self) -> Team<'_> {
+        Team { mock: self }
+    }
+
+    /// Mock the functions of [`TeamProfile`](crate::team_profile::TeamProfile).
+    pub fn team_profile(&self) -> TeamProfile<'_> {
+        TeamProfile { mock: self }
+    }
+
+    /// Mock the functions of [`Usergroups`](crate::usergroups::Usergroups).
+    pub fn usergroups(&self) -> Usergroups<'_> {
+        Usergroups { mock: self }
+    }
+
+    /// Mock the functions of [`UsergroupsUsers`](crate::usergroups_users::UsergroupsUsers).
+    pub fn usergroups_users(&self) -> UsergroupsUsers<'_> {
+        UsergroupsUsers { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+
+    /// Mock the functions of [`UsersProfile`](crate::users_profile::UsersProfile).
+    pub fn users_profile(&self) -> UsersProfile<'_> {
+        UsersProfile { mock: self }
+    }
+
+    /// Mock the functions of [`Views`](crate::views::Views).
+    pub fn views(&self) -> Views<'_> {
+        Views { mock: self }
+    }
+
+    /// Mock the functions of [`Workflows`](crate::workflows::Workflows).
+    pub fn workflows(&self) -> Workflows<'_> {
+        Workflows { mock: self }
+    }
+}
+
+pub struct AdminApps<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminApps<'a> {
+    /// Mock [`AdminApps::approve`](crate::admin_apps::AdminApps::approve), a `POST` to `/admin.apps.approve`.
+    pub fn approve(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.apps.approve".to_string())
+    }
+
+    /// Mock [`AdminApps::restrict`](crate::admin_apps::AdminApps::restrict), a `POST` to `/admin.apps.restrict`.
+    pub fn restrict(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.apps.restrict".to_string())
+    }
+}
+
+pub struct AdminAppsApproved<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminAppsApproved<'a> {
+    /// Mock [`AdminAppsApproved::list`](crate::admin_apps_approved::AdminAppsApproved::list), a `GET` to `/admin.apps.approved.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.apps.approved.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminAppsRequests<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminAppsRequests<'a> {
+    /// Mock [`AdminAppsRequests::list`](crate::admin_apps_requests::AdminAppsRequests::list), a `GET` to `/admin.apps.requests.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.apps.requests.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminAppsRestricted<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminAppsRestricted<'a> {
+    /// Mock [`AdminAppsRestricted::list`](crate::admin_apps_restricted::AdminAppsRestricted::list), a `GET` to `/admin.apps.restricted.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.apps.restricted.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminConversations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminConversations<'a> {
+    /// Mock [`AdminConversations::archive`](crate::admin_conversations::AdminConversations::archive), a `POST` to `/admin.conversations.archive`.
+    pub fn archive(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.archive".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::convert_private`](crate::admin_conversations::AdminConversations::convert_private), a `POST` to `/admin.conversations.convertToPrivate`.
+    pub fn convert_private(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.convertToPrivate".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::create`](crate::admin_conversations::AdminConversations::create), a `POST` to `/admin.conversations.create`.
+    pub fn create(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AdminConversationsCreateSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.create".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::delete`](crate::admin_conversations::AdminConversations::delete), a `POST` to `/admin.conversations.delete`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.delete".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::disconnect_shared`](crate::admin_conversations::AdminConversations::disconnect_shared), a `POST` to `/admin.conversations.disconnectShared`.
+    pub fn disconnect_shared(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.disconnectShared".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::get_conversation_pref`](crate::admin_conversations::AdminConversations::get_conversation_pref), a `GET` to `/admin.conversations.getConversationPrefs`.
+    pub fn get_conversation_pref(
+        &self,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::AdminConversationsGetConversationPrefsSchemaData,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.conversations.getConversationPrefs".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::get_team`](crate::admin_conversations::AdminConversations::get_team), a `GET` to `/admin.conversations.getTeams`.
+    pub fn get_team(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AdminConversationsGetTeamsSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.conversations.getTeams".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::invite`](crate::admin_conversations::AdminConversations::invite), a `POST` to `/admin.conversations.invite`.
+    pub fn invite(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.invite".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::rename`](crate::admin_conversations::AdminConversations::rename), a `POST` to `/admin.conversations.rename`.
+    pub fn rename(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.rename".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::search`](crate::admin_conversations::AdminConversations::search), a `GET` to `/admin.conversations.search`.
+    pub fn search(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AdminConversationsSearchSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.conversations.search".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::set_conversation_prefs`](crate::admin_conversations::AdminConversations::set_conversation_prefs), a `POST` to `/admin.conversations.setConversationPrefs`.
+    pub fn set_conversation_prefs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.setConversationPrefs".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::set_teams`](crate::admin_conversations::AdminConversations::set_teams), a `POST` to `/admin.conversations.setTeams`.
+    pub fn set_teams(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.setTeams".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversations::unarchive`](crate::admin_conversations::AdminConversations::unarchive), a `POST` to `/admin.conversations.unarchive`.
+    pub fn unarchive(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.unarchive".to_string(),
+        )
+    }
+}
+
+pub struct AdminConversationsEkm<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminConversationsEkm<'a> {
+    /// Mock [`AdminConversationsEkm::list_original_connected_channel_info`](crate::admin_conversations_ekm::AdminConversationsEkm::list_original_connected_channel_info), a `GET` to `/admin.conversations.ekm.listOriginalConnectedChannelInfo`.
+    pub fn list_original_connected_channel_info(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.conversations.ekm.listOriginalConnectedChannelInfo".to_string(),
+        )
+    }
+}
+
+pub struct AdminConversationsRestrictAccess<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminConversationsRestrictAccess<'a> {
+    /// Mock [`AdminConversationsRestrictAccess::add_group`](crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::add_group), a `POST` to `/admin.conversations.restrictAccess.addGroup`.
+    pub fn add_group(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.restrictAccess.addGroup".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversationsRestrictAccess::list_group`](crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::list_group), a `GET` to `/admin.conversations.restrictAccess.listGroups`.
+    pub fn list_group(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.conversations.restrictAccess.listGroups".to_string(),
+        )
+    }
+
+    /// Mock [`AdminConversationsRestrictAccess::remove_group`](crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::remove_group), a `POST` to `/admin.conversations.restrictAccess.removeGroup`.
+    pub fn remove_group(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.conversations.restrictAccess.removeGroup".to_string(),
+        )
+    }
+}
+
+pub struct AdminEmoji<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminEmoji<'a> {
+    /// Mock [`AdminEmoji::add`](crate::admin_emoji::AdminEmoji::add), a `POST` to `/admin.emoji.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.emoji.add".to_string())
+    }
+
+    /// Mock [`AdminEmoji::add_alias`](crate::admin_emoji::AdminEmoji::add_alias), a `POST` to `/admin.emoji.addAlias`.
+    pub fn add_alias(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.emoji.addAlias".to_string())
+    }
+
+    /// Mock [`AdminEmoji::list`](crate::admin_emoji::AdminEmoji::list), a `GET` to `/admin.emoji.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/admin.emoji.list".to_string())
+    }
+
+    /// Mock [`AdminEmoji::remove`](crate::admin_emoji::AdminEmoji::remove), a `POST` to `/admin.emoji.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.emoji.remove".to_string())
+    }
+
+    /// Mock [`AdminEmoji::rename`](crate::admin_emoji::AdminEmoji::rename), a `POST` to `/admin.emoji.rename`.
+    pub fn rename(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.emoji.rename".to_string())
+    }
+}
+
+pub struct AdminInviteRequests<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminInviteRequests<'a> {
+    /// Mock [`AdminInviteRequests::approve`](crate::admin_invite_requests::AdminInviteRequests::approve), a `POST` to `/admin.inviteRequests.approve`.
+    pub fn approve(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.inviteRequests.approve".to_string(),
+        )
+    }
+
+    /// Mock [`AdminInviteRequests::deny`](crate::admin_invite_requests::AdminInviteRequests::deny), a `POST` to `/admin.inviteRequests.deny`.
+    pub fn deny(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.inviteRequests.deny".to_string(),
+        )
+    }
+
+    /// Mock [`AdminInviteRequests::list`](crate::admin_invite_requests::AdminInviteRequests::list), a `GET` to `/admin.inviteRequests.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.inviteRequests.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminInviteRequestsApproved<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminInviteRequestsApproved<'a> {
+    /// Mock [`AdminInviteRequestsApproved::list`](crate::admin_invite_requests_approved::AdminInviteRequestsApproved::list), a `GET` to `/admin.inviteRequests.approved.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.inviteRequests.approved.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminInviteRequestsDenied<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminInviteRequestsDenied<'a> {
+    /// Mock [`AdminInviteRequestsDenied::list`](crate::admin_invite_requests_denied::AdminInviteRequestsDenied::list), a `GET` to `/admin.inviteRequests.denied.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.inviteRequests.denied.list".to_string(),
+        )
+    }
+}
+
+pub struct AdminTeams<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminTeams<'a> {
+    /// Mock [`AdminTeams::create`](crate::admin_teams::AdminTeams::create), a `POST` to `/admin.teams.create`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.teams.create".to_string())
+    }
+
+    /// Mock [`AdminTeams::list`](crate::admin_teams::AdminTeams::list), a `GET` to `/admin.teams.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/admin.teams.list".to_string())
+    }
+}
+
+pub struct AdminTeamsAdmins<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminTeamsAdmins<'a> {
+    /// Mock [`AdminTeamsAdmins::list`](crate::admin_teams_admins::AdminTeamsAdmins::list), a `GET` to `/admin.teams.admins.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/admin.teams.admins.list".to_string())
+    }
+}
+
+pub struct AdminTeamsOwners<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminTeamsOwners<'a> {
+    /// Mock [`AdminTeamsOwners::list`](crate::admin_teams_owners::AdminTeamsOwners::list), a `GET` to `/admin.teams.owners.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/admin.teams.owners.list".to_string())
+    }
+}
+
+pub struct AdminTeamsSettings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminTeamsSettings<'a> {
+    /// Mock [`AdminTeamsSettings::info`](crate::admin_teams_settings::AdminTeamsSettings::info), a `GET` to `/admin.teams.settings.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.teams.settings.info".to_string(),
+        )
+    }
+
+    /// Mock [`AdminTeamsSettings::set_default_channels`](crate::admin_teams_settings::AdminTeamsSettings::set_default_channels), a `POST` to `/admin.teams.settings.setDefaultChannels`.
+    pub fn set_default_channels(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.teams.settings.setDefaultChannels".to_string(),
+        )
+    }
+
+    /// Mock [`AdminTeamsSettings::set_description`](crate::admin_teams_settings::AdminTeamsSettings::set_description), a `POST` to `/admin.teams.settings.setDescription`.
+    pub fn set_description(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.teams.settings.setDescription".to_string(),
+        )
+    }
+
+    /// Mock [`AdminTeamsSettings::set_discoverability`](crate::admin_teams_settings::AdminTeamsSettings::set_discoverability), a `POST` to `/admin.teams.settings.setDiscoverability`.
+    pub fn set_discoverability(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.teams.settings.setDiscoverability".to_string(),
+        )
+    }
+
+    /// Mock [`AdminTeamsSettings::set_icon`](crate::admin_teams_settings::AdminTeamsSettings::set_icon), a `POST` to `/admin.teams.settings.setIcon`.
+    pub fn set_icon(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.teams.settings.setIcon".to_string(),
+        )
+    }
+
+    /// Mock [`AdminTeamsSettings::set_name`](crate::admin_teams_settings::AdminTeamsSettings::set_name), a `POST` to `/admin.teams.settings.setName`.
+    pub fn set_name(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.teams.settings.setName".to_string(),
+        )
+    }
+}
+
+pub struct AdminUsergroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminUsergroups<'a> {
+    /// Mock [`AdminUsergroups::add_channels`](crate::admin_usergroups::AdminUsergroups::add_channels), a `POST` to `/admin.usergroups.addChannels`.
+    pub fn add_channels(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.usergroups.addChannels".to_string(),
+        )
+    }
+
+    /// Mock [`AdminUsergroups::add_teams`](crate::admin_usergroups::AdminUsergroups::add_teams), a `POST` to `/admin.usergroups.addTeams`.
+    pub fn add_teams(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.usergroups.addTeams".to_string(),
+        )
+    }
+
+    /// Mock [`AdminUsergroups::list_channel`](crate::admin_usergroups::AdminUsergroups::list_channel), a `GET` to `/admin.usergroups.listChannels`.
+    pub fn list_channel(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/admin.usergroups.listChannels".to_string(),
+        )
+    }
+
+    /// Mock [`AdminUsergroups::remove_channels`](crate::admin_usergroups::AdminUsergroups::remove_channels), a `POST` to `/admin.usergroups.removeChannels`.
+    pub fn remove_channels(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.usergroups.removeChannels".to_string(),
+        )
+    }
+}
+
+pub struct AdminUsers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminUsers<'a> {
+    /// Mock [`AdminUsers::assign`](crate::admin_users::AdminUsers::assign), a `POST` to `/admin.users.assign`.
+    pub fn assign(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.assign".to_string())
+    }
+
+    /// Mock [`AdminUsers::invite`](crate::admin_users::AdminUsers::invite), a `POST` to `/admin.users.invite`.
+    pub fn invite(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.invite".to_string())
+    }
+
+    /// Mock [`AdminUsers::list`](crate::admin_users::AdminUsers::list), a `GET` to `/admin.users.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/admin.users.list".to_string())
+    }
+
+    /// Mock [`AdminUsers::remove`](crate::admin_users::AdminUsers::remove), a `POST` to `/admin.users.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.remove".to_string())
+    }
+
+    /// Mock [`AdminUsers::set`](crate::admin_users::AdminUsers::set), a `POST` to `/admin.users.setAdmin`.
+    pub fn set(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.setAdmin".to_string())
+    }
+
+    /// Mock [`AdminUsers::set_expiration`](crate::admin_users::AdminUsers::set_expiration), a `POST` to `/admin.users.setExpiration`.
+    pub fn set_expiration(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.users.setExpiration".to_string(),
+        )
+    }
+
+    /// Mock [`AdminUsers::set_owner`](crate::admin_users::AdminUsers::set_owner), a `POST` to `/admin.users.setOwner`.
+    pub fn set_owner(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.setOwner".to_string())
+    }
+
+    /// Mock [`AdminUsers::set_regular`](crate::admin_users::AdminUsers::set_regular), a `POST` to `/admin.users.setRegular`.
+    pub fn set_regular(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/admin.users.setRegular".to_string())
+    }
+}
+
+pub struct AdminUsersSession<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AdminUsersSession<'a> {
+    /// Mock [`AdminUsersSession::invalidate`](crate::admin_users_session::AdminUsersSession::invalidate), a `POST` to `/admin.users.session.invalidate`.
+    pub fn invalidate(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.users.session.invalidate".to_string(),
+        )
+    }
+
+    /// Mock [`AdminUsersSession::reset`](crate::admin_users_session::AdminUsersSession::reset), a `POST` to `/admin.users.session.reset`.
+    pub fn reset(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/admin.users.session.reset".to_string(),
+        )
+    }
+}
+
+pub struct Api<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Api<'a> {
+    /// Mock [`Api::test`](crate::api::Api::test), a `GET` to `/api.test`.
+    pub fn test(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/api.test".to_string())
+    }
+}
+
+pub struct Apps<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Apps<'a> {
+    /// Mock [`Apps::uninstall`](crate::apps::Apps::uninstall), a `GET` to `/apps.uninstall`.
+    pub fn uninstall(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/apps.uninstall".to_string())
+    }
+}
+
+pub struct AppsEventAuthorizations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AppsEventAuthorizations<'a> {
+    /// Mock [`AppsEventAuthorizations::list`](crate::apps_event_authorizations::AppsEventAuthorizations::list), a `GET` to `/apps.event.authorizations.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.event.authorizations.list".to_string(),
+        )
+    }
+}
+
+pub struct AppsPermissions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AppsPermissions<'a> {
+    /// Mock [`AppsPermissions::info`](crate::apps_permissions::AppsPermissions::info), a `GET` to `/apps.permissions.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::AppsPermissionsInfoSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/apps.permissions.info".to_string())
+    }
+
+    /// Mock [`AppsPermissions::request`](crate::apps_permissions::AppsPermissions::request), a `GET` to `/apps.permissions.request`.
+    pub fn request(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.permissions.request".to_string(),
+        )
+    }
+}
+
+pub struct AppsPermissionsResources<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AppsPermissionsResources<'a> {
+    /// Mock [`AppsPermissionsResources::list`](crate::apps_permissions_resources::AppsPermissionsResources::list), a `GET` to `/apps.permissions.resources.list`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AppsPermissionsResourcesListSuccessSchema>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.permissions.resources.list".to_string(),
+        )
+    }
+}
+
+pub struct AppsPermissionsScopes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AppsPermissionsScopes<'a> {
+    /// Mock [`AppsPermissionsScopes::list`](crate::apps_permissions_scopes::AppsPermissionsScopes::list), a `GET` to `/apps.permissions.scopes.list`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApiPermissionsScopesListSuccessSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.permissions.scopes.list".to_string(),
+        )
+    }
+}
+
+pub struct AppsPermissionsUsers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AppsPermissionsUsers<'a> {
+    /// Mock [`AppsPermissionsUsers::list`](crate::apps_permissions_users::AppsPermissionsUsers::list), a `GET` to `/apps.permissions.users.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.permissions.users.list".to_string(),
+        )
+    }
+
+    /// Mock [`AppsPermissionsUsers::request`](crate::apps_permissions_users::AppsPermissionsUsers::request), a `GET` to `/apps.permissions.users.request`.
+    pub fn request(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/apps.permissions.users.request".to_string(),
+        )
+    }
+}
+
+pub struct Auth<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Auth<'a> {
+    /// Mock [`Auth::revoke`](crate::auth::Auth::revoke), a `GET` to `/auth.revoke`.
+    pub fn revoke(&self) -> crate::mock::MockOperation<'a, crate::types::AuthRevokeSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/auth.revoke".to_string())
+    }
+
+    /// Mock [`Auth::test`](crate::auth::Auth::test), a `GET` to `/auth.test`.
+    pub fn test(&self) -> crate::mock::MockOperation<'a, crate::types::AuthTestSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/auth.test".to_string())
+    }
+}
+
+pub struct Bots<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Bots<'a> {
+    /// Mock [`Bots::info`](crate::bots::Bots::info), a `GET` to `/bots.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::BotsInfoSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/bots.info".to_string())
+    }
+}
+
+pub struct Calls<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Calls<'a> {
+    /// Mock [`Calls::add`](crate::calls::Calls::add), a `POST` to `/calls.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/calls.add".to_string())
+    }
+
+    /// Mock [`Calls::end`](crate::calls::Calls::end), a `POST` to `/calls.end`.
+    pub fn end(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/calls.end".to_string())
+    }
+
+    /// Mock [`Calls::info`](crate::calls::Calls::info), a `GET` to `/calls.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/calls.info".to_string())
+    }
+
+    /// Mock [`Calls::update`](crate::calls::Calls::update), a `POST` to `/calls.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/calls.update".to_string())
+    }
+}
+
+pub struct CallsParticipants<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CallsParticipants<'a> {
+    /// Mock [`CallsParticipants::add`](crate::calls_participants::CallsParticipants::add), a `POST` to `/calls.participants.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/calls.participants.add".to_string())
+    }
+
+    /// Mock [`CallsParticipants::remove`](crate::calls_participants::CallsParticipants::remove), a `POST` to `/calls.participants.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/calls.participants.remove".to_string(),
+        )
+    }
+}
+
+pub struct Chat<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Chat<'a> {
+    /// Mock [`Chat::delete`](crate::chat::Chat::delete), a `POST` to `/chat.delete`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::ChatDeleteSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.delete".to_string())
+    }
+
+    /// Mock [`Chat::delete_scheduled_message`](crate::chat::Chat::delete_scheduled_message), a `POST` to `/chat.deleteScheduledMessage`.
+    pub fn delete_scheduled_message(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/chat.deleteScheduledMessage".to_string(),
+        )
+    }
+
+    /// Mock [`Chat::get_permalink`](crate::chat::Chat::get_permalink), a `GET` to `/chat.getPermalink`.
+    pub fn get_permalink(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChatGetPermalinkSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/chat.getPermalink".to_string())
+    }
+
+    /// Mock [`Chat::me_message`](crate::chat::Chat::me_message), a `POST` to `/chat.meMessage`.
+    pub fn me_message(&self) -> crate::mock::MockOperation<'a, crate::types::ChatMeMessageSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.meMessage".to_string())
+    }
+
+    /// Mock [`Chat::post_ephemeral`](crate::chat::Chat::post_ephemeral), a `POST` to `/chat.postEphemeral`.
+    pub fn post_ephemeral(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChatPostEphemeralSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.postEphemeral".to_string())
+    }
+
+    /// Mock [`Chat::post_message`](crate::chat::Chat::post_message), a `POST` to `/chat.postMessage`.
+    pub fn post_message(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChatPostMessageSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.postMessage".to_string())
+    }
+
+    /// Mock [`Chat::schedule_message`](crate::chat::Chat::schedule_message), a `POST` to `/chat.scheduleMessage`.
+    pub fn schedule_message(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChatScheduleMessageSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.scheduleMessage".to_string())
+    }
+
+    /// Mock [`Chat::unfurl`](crate::chat::Chat::unfurl), a `POST` to `/chat.unfurl`.
+    pub fn unfurl(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.unfurl".to_string())
+    }
+
+    /// Mock [`Chat::update`](crate::chat::Chat::update), a `POST` to `/chat.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::ChatUpdateSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/chat.update".to_string())
+    }
+}
+
+pub struct ChatScheduledMessages<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChatScheduledMessages<'a> {
+    /// Mock [`ChatScheduledMessages::list`](crate::chat_scheduled_messages::ChatScheduledMessages::list), a `GET` to `/chat.scheduledMessages.list`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ChatScheduledMessagesListSchema> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/chat.scheduledMessages.list".to_string(),
+        )
+    }
+}
+
+pub struct Conversations<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Conversations<'a> {
+    /// Mock [`Conversations::archive`](crate::conversations::Conversations::archive), a `POST` to `/conversations.archive`.
+    pub fn archive(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.archive".to_string())
+    }
+
+    /// Mock [`Conversations::close`](crate::conversations::Conversations::close), a `POST` to `/conversations.close`.
+    pub fn close(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsCloseSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.close".to_string())
+    }
+
+    /// Mock [`Conversations::create`](crate::conversations::Conversations::create), a `POST` to `/conversations.create`.
+    pub fn create(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.create".to_string())
+    }
+
+    /// Mock [`Conversations::history`](crate::conversations::Conversations::history), a `GET` to `/conversations.history`.
+    pub fn history(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsHistorySuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations.history".to_string())
+    }
+
+    /// Mock [`Conversations::info`](crate::conversations::Conversations::info), a `GET` to `/conversations.info`.
+    pub fn info(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations.info".to_string())
+    }
+
+    /// Mock [`Conversations::invite`](crate::conversations::Conversations::invite), a `POST` to `/conversations.invite`.
+    pub fn invite(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.invite".to_string())
+    }
+
+    /// Mock [`Conversations::join`](crate::conversations::Conversations::join), a `POST` to `/conversations.join`.
+    pub fn join(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsJoinSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.join".to_string())
+    }
+
+    /// Mock [`Conversations::kick`](crate::conversations::Conversations::kick), a `POST` to `/conversations.kick`.
+    pub fn kick(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.kick".to_string())
+    }
+
+    /// Mock [`Conversations::leave`](crate::conversations::Conversations::leave), a `POST` to `/conversations.leave`.
+    pub fn leave(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsLeaveSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.leave".to_string())
+    }
+
+    /// Mock [`Conversations::list`](crate::conversations::Conversations::list), a `GET` to `/conversations.list`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsListSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations.list".to_string())
+    }
+
+    /// Mock [`Conversations::mark`](crate::conversations::Conversations::mark), a `POST` to `/conversations.mark`.
+    pub fn mark(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.mark".to_string())
+    }
+
+    /// Mock [`Conversations::member`](crate::conversations::Conversations::member), a `GET` to `/conversations.members`.
+    pub fn member(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsMembersSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations.members".to_string())
+    }
+
+    /// Mock [`Conversations::open`](crate::conversations::Conversations::open), a `POST` to `/conversations.open`.
+    pub fn open(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsOpenSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.open".to_string())
+    }
+
+    /// Mock [`Conversations::rename`](crate::conversations::Conversations::rename), a `POST` to `/conversations.rename`.
+    pub fn rename(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.rename".to_string())
+    }
+
+    /// Mock [`Conversations::replie`](crate::conversations::Conversations::replie), a `GET` to `/conversations.replies`.
+    pub fn replie(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsRepliesSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/conversations.replies".to_string())
+    }
+
+    /// Mock [`Conversations::set_purpose`](crate::conversations::Conversations::set_purpose), a `POST` to `/conversations.setPurpose`.
+    pub fn set_purpose(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/conversations.setPurpose".to_string(),
+        )
+    }
+
+    /// Mock [`Conversations::set_topic`](crate::conversations::Conversations::set_topic), a `POST` to `/conversations.setTopic`.
+    pub fn set_topic(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ConversationsInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/conversations.setTopic".to_string())
+    }
+
+    /// Mock [`Conversations::unarchive`](crate::conversations::Conversations::unarchive), a `POST` to `/conversations.unarchive`.
+    pub fn unarchive(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/conversations.unarchive".to_string(),
+        )
+    }
+}
+
+pub struct Dialog<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Dialog<'a> {
+    /// Mock [`Dialog::open`](crate::dialog::Dialog::open), a `GET` to `/dialog.open`.
+    pub fn open(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/dialog.open".to_string())
+    }
+}
+
+pub struct Dnd<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Dnd<'a> {
+    /// Mock [`Dnd::end`](crate::dnd::Dnd::end), a `POST` to `/dnd.endDnd`.
+    pub fn end(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/dnd.endDnd".to_string())
+    }
+
+    /// Mock [`Dnd::end_snooze`](crate::dnd::Dnd::end_snooze), a `POST` to `/dnd.endSnooze`.
+    pub fn end_snooze(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSnoozeSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/dnd.endSnooze".to_string())
+    }
+
+    /// Mock [`Dnd::info`](crate::dnd::Dnd::info), a `GET` to `/dnd.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::DndInfoSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/dnd.info".to_string())
+    }
+
+    /// Mock [`Dnd::set_snooze`](crate::dnd::Dnd::set_snooze), a `POST` to `/dnd.setSnooze`.
+    pub fn set_snooze(&self) -> crate::mock::MockOperation<'a, crate::types::DndSetSnoozeSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/dnd.setSnooze".to_string())
+    }
+
+    /// Mock [`Dnd::team_info`](crate::dnd::Dnd::team_info), a `GET` to `/dnd.teamInfo`.
+    pub fn team_info(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/dnd.teamInfo".to_string())
+    }
+}
+
+pub struct Emoji<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Emoji<'a> {
+    /// Mock [`Emoji::list`](crate::emoji::Emoji::list), a `GET` to `/emoji.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/emoji.list".to_string())
+    }
+}
+
+pub struct Files<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Files<'a> {
+    /// Mock [`Files::delete`](crate::files::Files::delete), a `POST` to `/files.delete`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.delete".to_string())
+    }
+
+    /// Mock [`Files::info`](crate::files::Files::info), a `GET` to `/files.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::FilesInfoSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files.info".to_string())
+    }
+
+    /// Mock [`Files::list`](crate::files::Files::list), a `GET` to `/files.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::FilesListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files.list".to_string())
+    }
+
+    /// Mock [`Files::revoke_public_url`](crate::files::Files::revoke_public_url), a `POST` to `/files.revokePublicURL`.
+    pub fn revoke_public_url(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::FilesUploadSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.revokePublicURL".to_string())
+    }
+
+    /// Mock [`Files::shared_public_url`](crate::files::Files::shared_public_url), a `POST` to `/files.sharedPublicURL`.
+    pub fn shared_public_url(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::FilesUploadSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.sharedPublicURL".to_string())
+    }
+
+    /// Mock [`Files::upload`](crate::files::Files::upload), a `POST` to `/files.upload`.
+    pub fn upload(&self) -> crate::mock::MockOperation<'a, crate::types::FilesUploadSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.upload".to_string())
+    }
+}
+
+pub struct FilesComments<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FilesComments<'a> {
+    /// Mock [`FilesComments::delete`](crate::files_comments::FilesComments::delete), a `POST` to `/files.comments.delete`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.comments.delete".to_string())
+    }
+}
+
+pub struct FilesRemote<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FilesRemote<'a> {
+    /// Mock [`FilesRemote::add`](crate::files_remote::FilesRemote::add), a `POST` to `/files.remote.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.remote.add".to_string())
+    }
+
+    /// Mock [`FilesRemote::info`](crate::files_remote::FilesRemote::info), a `GET` to `/files.remote.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files.remote.info".to_string())
+    }
+
+    /// Mock [`FilesRemote::list`](crate::files_remote::FilesRemote::list), a `GET` to `/files.remote.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files.remote.list".to_string())
+    }
+
+    /// Mock [`FilesRemote::remove`](crate::files_remote::FilesRemote::remove), a `POST` to `/files.remote.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.remote.remove".to_string())
+    }
+
+    /// Mock [`FilesRemote::share`](crate::files_remote::FilesRemote::share), a `GET` to `/files.remote.share`.
+    pub fn share(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/files.remote.share".to_string())
+    }
+
+    /// Mock [`FilesRemote::update`](crate::files_remote::FilesRemote::update), a `POST` to `/files.remote.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/files.remote.update".to_string())
+    }
+}
+
+pub struct Migration<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Migration<'a> {
+    /// Mock [`Migration::exchange`](crate::migration::Migration::exchange), a `GET` to `/migration.exchange`.
+    pub fn exchange(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::MigrationExchangeSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/migration.exchange".to_string())
+    }
+}
+
+pub struct Oauth<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Oauth<'a> {
+    /// Mock [`Oauth::access`](crate::oauth::Oauth::access), a `GET` to `/oauth.access`.
+    pub fn access(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/oauth.access".to_string())
+    }
+
+    /// Mock [`Oauth::token`](crate::oauth::Oauth::token), a `GET` to `/oauth.token`.
+    pub fn token(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/oauth.token".to_string())
+    }
+}
+
+pub struct OauthV2<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> OauthV2<'a> {
+    /// Mock [`OauthV2::oauth_access`](crate::oauth_v_2::OauthV2::oauth_access), a `GET` to `/oauth.v2.access`.
+    pub fn oauth_access(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/oauth.v2.access".to_string())
+    }
+}
+
+pub struct Pins<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Pins<'a> {
+    /// Mock [`Pins::add`](crate::pins::Pins::add), a `POST` to `/pins.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/pins.add".to_string())
+    }
+
+    /// Mock [`Pins::list`](crate::pins::Pins::list), a `GET` to `/pins.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::PinsListResponseAnyOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/pins.list".to_string())
+    }
+
+    /// Mock [`Pins::remove`](crate::pins::Pins::remove), a `POST` to `/pins.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/pins.remove".to_string())
+    }
+}
+
+pub struct Reactions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reactions<'a> {
+    /// Mock [`Reactions::add`](crate::reactions::Reactions::add), a `POST` to `/reactions.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/reactions.add".to_string())
+    }
+
+    /// Mock [`Reactions::get`](crate::reactions::Reactions::get), a `GET` to `/reactions.get`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Fields> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reactions.get".to_string())
+    }
+
+    /// Mock [`Reactions::list`](crate::reactions::Reactions::list), a `GET` to `/reactions.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::ReactionsListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reactions.list".to_string())
+    }
+
+    /// Mock [`Reactions::remove`](crate::reactions::Reactions::remove), a `POST` to `/reactions.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/reactions.remove".to_string())
+    }
+}
+
+pub struct Reminders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reminders<'a> {
+    /// Mock [`Reminders::add`](crate::reminders::Reminders::add), a `POST` to `/reminders.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::RemindersAddSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/reminders.add".to_string())
+    }
+
+    /// Mock [`Reminders::complete`](crate::reminders::Reminders::complete), a `POST` to `/reminders.complete`.
+    pub fn complete(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/reminders.complete".to_string())
+    }
+
+    /// Mock [`Reminders::delete`](crate::reminders::Reminders::delete), a `POST` to `/reminders.delete`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/reminders.delete".to_string())
+    }
+
+    /// Mock [`Reminders::info`](crate::reminders::Reminders::info), a `GET` to `/reminders.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::RemindersAddSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reminders.info".to_string())
+    }
+
+    /// Mock [`Reminders::list`](crate::reminders::Reminders::list), a `GET` to `/reminders.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::RemindersListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/reminders.list".to_string())
+    }
+}
+
+pub struct Rtm<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Rtm<'a> {
+    /// Mock [`Rtm::connect`](crate::rtm::Rtm::connect), a `GET` to `/rtm.connect`.
+    pub fn connect(&self) -> crate::mock::MockOperation<'a, crate::types::RtmConnectSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rtm.connect".to_string())
+    }
+}
+
+pub struct Search<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Search<'a> {
+    /// Mock [`Search::message`](crate::search::Search::message), a `GET` to `/search.messages`.
+    pub fn message(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/search.messages".to_string())
+    }
+}
+
+pub struct Stars<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Stars<'a> {
+    /// Mock [`Stars::add`](crate::stars::Stars::add), a `POST` to `/stars.add`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/stars.add".to_string())
+    }
+
+    /// Mock [`Stars::list`](crate::stars::Stars::list), a `GET` to `/stars.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::StarsListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/stars.list".to_string())
+    }
+
+    /// Mock [`Stars::remove`](crate::stars::Stars::remove), a `POST` to `/stars.remove`.
+    pub fn remove(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/stars.remove".to_string())
+    }
+}
+
+pub struct Team<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Team<'a> {
+    /// Mock [`Team::access_log`](crate::team::Team::access_log), a `GET` to `/team.accessLogs`.
+    pub fn access_log(&self) -> crate::mock::MockOperation<'a, crate::types::TeamAccessLogsSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/team.accessLogs".to_string())
+    }
+
+    /// Mock [`Team::billable_info`](crate::team::Team::billable_info), a `GET` to `/team.billableInfo`.
+    pub fn billable_info(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/team.billableInfo".to_string())
+    }
+
+    /// Mock [`Team::info`](crate::team::Team::info), a `GET` to `/team.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::TeamInfoSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/team.info".to_string())
+    }
+
+    /// Mock [`Team::integration_log`](crate::team::Team::integration_log), a `GET` to `/team.integrationLogs`.
+    pub fn integration_log(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TeamIntegrationLogsSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/team.integrationLogs".to_string())
+    }
+}
+
+pub struct TeamProfile<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TeamProfile<'a> {
+    /// Mock [`TeamProfile::get`](crate::team_profile::TeamProfile::get), a `GET` to `/team.profile.get`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::TeamProfileGetSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/team.profile.get".to_string())
+    }
+}
+
+pub struct Usergroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Usergroups<'a> {
+    /// Mock [`Usergroups::create`](crate::usergroups::Usergroups::create), a `POST` to `/usergroups.create`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsCreateSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/usergroups.create".to_string())
+    }
+
+    /// Mock [`Usergroups::disable`](crate::usergroups::Usergroups::disable), a `POST` to `/usergroups.disable`.
+    pub fn disable(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsCreateSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/usergroups.disable".to_string())
+    }
+
+    /// Mock [`Usergroups::enable`](crate::usergroups::Usergroups::enable), a `POST` to `/usergroups.enable`.
+    pub fn enable(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsCreateSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/usergroups.enable".to_string())
+    }
+
+    /// Mock [`Usergroups::list`](crate::usergroups::Usergroups::list), a `GET` to `/usergroups.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/usergroups.list".to_string())
+    }
+
+    /// Mock [`Usergroups::update`](crate::usergroups::Usergroups::update), a `POST` to `/usergroups.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsCreateSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/usergroups.update".to_string())
+    }
+}
+
+pub struct UsergroupsUsers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UsergroupsUsers<'a> {
+    /// Mock [`UsergroupsUsers::list`](crate::usergroups_users::UsergroupsUsers::list), a `GET` to `/usergroups.users.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsUsersListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/usergroups.users.list".to_string())
+    }
+
+    /// Mock [`UsergroupsUsers::update`](crate::usergroups_users::UsergroupsUsers::update), a `POST` to `/usergroups.users.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::UsergroupsCreateSchema> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/usergroups.users.update".to_string(),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::conversation`](crate::users::Users::conversation), a `GET` to `/users.conversations`.
+    pub fn conversation(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersConversationsSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.conversations".to_string())
+    }
+
+    /// Mock [`Users::delete_photo`](crate::users::Users::delete_photo), a `POST` to `/users.deletePhoto`.
+    pub fn delete_photo(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users.deletePhoto".to_string())
+    }
+
+    /// Mock [`Users::get_presence`](crate::users::Users::get_presence), a `GET` to `/users.getPresence`.
+    pub fn get_presence(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApiMethodUsersGetPresence> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.getPresence".to_string())
+    }
+
+    /// Mock [`Users::identity`](crate::users::Users::identity), a `GET` to `/users.identity`.
+    pub fn identity(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.identity".to_string())
+    }
+
+    /// Mock [`Users::info`](crate::users::Users::info), a `GET` to `/users.info`.
+    pub fn info(&self) -> crate::mock::MockOperation<'a, crate::types::UsersInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.info".to_string())
+    }
+
+    /// Mock [`Users::list`](crate::users::Users::list), a `GET` to `/users.list`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::UsersListSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.list".to_string())
+    }
+
+    /// Mock [`Users::lookup_email`](crate::users::Users::lookup_email), a `GET` to `/users.lookupByEmail`.
+    pub fn lookup_email(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsersInfoSuccessSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.lookupByEmail".to_string())
+    }
+
+    /// Mock [`Users::set_active`](crate::users::Users::set_active), a `POST` to `/users.setActive`.
+    pub fn set_active(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users.setActive".to_string())
+    }
+
+    /// Mock [`Users::set_photo`](crate::users::Users::set_photo), a `POST` to `/users.setPhoto`.
+    pub fn set_photo(&self) -> crate::mock::MockOperation<'a, crate::types::UsersSetPhotoSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users.setPhoto".to_string())
+    }
+
+    /// Mock [`Users::set_presence`](crate::users::Users::set_presence), a `POST` to `/users.setPresence`.
+    pub fn set_presence(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users.setPresence".to_string())
+    }
+}
+
+pub struct UsersProfile<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> UsersProfile<'a> {
+    /// Mock [`UsersProfile::get`](crate::users_profile::UsersProfile::get), a `GET` to `/users.profile.get`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::UsersProfileGetSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users.profile.get".to_string())
+    }
+
+    /// Mock [`UsersProfile::set`](crate::users_profile::UsersProfile::set), a `POST` to `/users.profile.set`.
+    pub fn set(&self) -> crate::mock::MockOperation<'a, crate::types::UsersProfileSetSchema> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users.profile.set".to_string())
+    }
+}
+
+pub struct Views<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Views<'a> {
+    /// Mock [`Views::open`](crate::views::Views::open), a `GET` to `/views.open`.
+    pub fn open(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/views.open".to_string())
+    }
+
+    /// Mock [`Views::publish`](crate::views::Views::publish), a `GET` to `/views.publish`.
+    pub fn publish(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/views.publish".to_string())
+    }
+
+    /// Mock [`Views::push`](crate::views::Views::push), a `GET` to `/views.push`.
+    pub fn push(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/views.push".to_string())
+    }
+
+    /// Mock [`Views::update`](crate::views::Views::update), a `GET` to `/views.update`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/views.update".to_string())
+    }
+}
+
+pub struct Workflows<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Workflows<'a> {
+    /// Mock [`Workflows::step_completed`](crate::workflows::Workflows::step_completed), a `GET` to `/workflows.stepCompleted`.
+    pub fn step_completed(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/workflows.stepCompleted".to_string())
+    }
+
+    /// Mock [`Workflows::step_failed`](crate::workflows::Workflows::step_failed), a `GET` to `/workflows.stepFailed`.
+    pub fn step_failed(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/workflows.stepFailed".to_string())
+    }
+
+    /// Mock [`Workflows::update_step`](crate::workflows::Workflows::update_step), a `GET` to `/workflows.updateStep`.
+    pub fn update_step(&self) -> crate::mock::MockOperation<'a, crate::types::DndEndSchema> {
+        self.mock
+            .operation(reqwest::Method::GET, "/workflows.updateStep".to_string())
+    }
+}
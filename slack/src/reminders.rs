@@ -23,13 +23,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::RemindersAddSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> Result<crate::types::RemindersAddSchema> {
         let url = self.client.url("/reminders.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn complete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/reminders.complete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -69,13 +75,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/reminders.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -23,13 +23,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -46,13 +49,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn disable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.disable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -69,13 +75,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.enable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -136,13 +145,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
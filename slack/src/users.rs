@@ -2,6 +2,76 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`Users::set_photo`].
+#[derive(Default)]
+pub struct UsersSetPhotoForm {
+    token: Option<String>,
+    crop_w: Option<String>,
+    crop_x: Option<String>,
+    crop_y: Option<String>,
+    image: Option<crate::utils::FilePart>,
+}
+
+impl UsersSetPhotoForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Authentication token. Requires scope: `users.profile:write`
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Width/height of crop box (always square)
+    pub fn crop_w(mut self, value: impl Into<String>) -> Self {
+        self.crop_w = Some(value.into());
+        self
+    }
+
+    /// X coordinate of top-left corner of crop box
+    pub fn crop_x(mut self, value: impl Into<String>) -> Self {
+        self.crop_x = Some(value.into());
+        self
+    }
+
+    /// Y coordinate of top-left corner of crop box
+    pub fn crop_y(mut self, value: impl Into<String>) -> Self {
+        self.crop_y = Some(value.into());
+        self
+    }
+
+    /// File contents via `multipart/form-data`.
+    pub fn image(mut self, value: crate::utils::FilePart) -> Self {
+        self.image = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.token {
+            form = form.text("token", value);
+        } else {
+            anyhow::bail!("missing required multipart field `token`");
+        }
+        if let Some(value) = self.crop_w {
+            form = form.text("crop_w", value);
+        }
+        if let Some(value) = self.crop_x {
+            form = form.text("crop_x", value);
+        }
+        if let Some(value) = self.crop_y {
+            form = form.text("crop_y", value);
+        }
+        if let Some(value) = self.image {
+            form = form.part("image", value.into_part().await?);
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -66,6 +136,70 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversation`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub fn get_all_conversations_stream(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<Vec<crate::types::ObjsConversationAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users.conversations?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}cursor={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::UsersConversationsSuccessSchema = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: resp.response_metadata.map(|m| m.next_cursor),
+                    items: resp.channels,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/users.deletePhoto` endpoint.
      *
@@ -73,13 +207,16 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/users.deletePhoto", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -163,6 +300,50 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get a user's identity.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn get_all_identity_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UsersIdentityResponseAnyOf> {
+        let url = self.client.url("/users.identity", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The page token is the URL of the next page from the link header.
+                let (next, items) = match page {
+                    Some(page) => {
+                        client
+                            .get_pages_url::<crate::types::UsersIdentityResponseAnyOf>(
+                                &reqwest::Url::parse(&page)?,
+                            )
+                            .await?
+                    }
+                    None => {
+                        client
+                            .get_pages::<crate::types::UsersIdentityResponseAnyOf>(&url)
+                            .await?
+                    }
+                };
+
+                Ok(crate::utils::Page {
+                    items,
+                    next: next.map(|n| n.0),
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/users.info` endpoint.
      *
@@ -303,16 +484,13 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.setPhoto>
      */
-    pub async fn set_photo(&self) -> Result<crate::types::UsersSetPhotoSchema> {
+    pub async fn set_photo(
+        &self,
+        body: UsersSetPhotoForm,
+    ) -> Result<crate::types::UsersSetPhotoSchema> {
         let url = self.client.url("/users.setPhoto", None);
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
     /**
@@ -326,13 +504,16 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> Result<crate::types::DndEndSchema> {
         let url = self.client.url("/users.setPresence", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
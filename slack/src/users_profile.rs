@@ -62,13 +62,16 @@ impl UsersProfile {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users.profile:write`.
      */
-    pub async fn set(&self) -> Result<crate::types::UsersProfileSetSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> Result<crate::types::UsersProfileSetSchema> {
         let url = self.client.url("/users.profile.set", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
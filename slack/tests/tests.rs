@@ -1 +1,195 @@
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use slack_chat_api::{types::ChatPostMessageRequest, Client, ClientError};
+
+fn client(server: &MockServer) -> Client {
+    let mut client = Client::new("client-id", "client-secret", "", "xoxb-token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_ok_false_is_an_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": false,
+            "error": "channel_not_found",
+            "warning": "missing_charset",
+            "response_metadata": {
+                "messages": ["[WARN] A Content-Type HTTP header was presented but did not declare a charset"]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let body = ChatPostMessageRequest {
+        channel: "C0000000000".to_string(),
+        text: "Hello".to_string(),
+        ..Default::default()
+    };
+    let err = client(&server)
+        .chat()
+        .post_message(&body)
+        .await
+        .unwrap_err();
+    match err.downcast_ref::<ClientError>() {
+        Some(ClientError::NotOk {
+            status,
+            error,
+            warning,
+            messages,
+            ..
+        }) => {
+            assert_eq!(status.as_u16(), 200);
+            assert_eq!(error, "channel_not_found");
+            assert_eq!(warning.as_deref(), Some("missing_charset"));
+            assert_eq!(messages.len(), 1);
+        }
+        other => panic!("expected a NotOk error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_waits_out_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "channel": "C0000000000",
+            "ts": "1503435956.000247",
+            "message": {"text": "Hello", "ts": "1503435956.000247"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let body = ChatPostMessageRequest {
+        channel: "C0000000000".to_string(),
+        text: "Hello".to_string(),
+        ..Default::default()
+    };
+    let started = std::time::Instant::now();
+    let message = client(&server).chat().post_message(&body).await.unwrap();
+    assert_eq!(message.ts, "1503435956.000247");
+    assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_sends_rate_limited_requests_again_3_times() {
+    let server = MockServer::start().await;
+
+    // The retry middleware would send a GET again too, but the 429s are only
+    // sent again by the client: the first request and 3 more.
+    Mock::given(method("GET"))
+        .and(path("/auth.test"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let err = client(&server).auth().test().await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ClientError>(),
+        Some(ClientError::RateLimited { .. })
+    ));
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_rate_limited_after_long_retry_after() {
+    let server = MockServer::start().await;
+
+    // Waiting longer than a minute is left to the caller.
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "120"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .chat()
+        .post_message(&Default::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ClientError>(),
+        Some(ClientError::RateLimited { reset: Some(_), .. })
+    ));
+}
+
+#[test]
+fn test_acks_signed_app_mentions() {
+    use hmac::Mac;
+    use slack_chat_api::events::{Acknowledgement, Event, RequestError, SlackRequest};
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "type": "event_callback",
+        "team_id": "T061EG9R6",
+        "api_app_id": "A0PNCHHK2",
+        "event_id": "Ev0PV52K25",
+        "event_time": 1515449522,
+        "event": {
+            "type": "app_mention",
+            "user": "U061F7AUR",
+            "text": "<@U0LAN0Z89> is it everything a river should be?",
+            "ts": "1515449522.000016",
+            "channel": "C0LAN2Q65",
+            "event_ts": "1515449522000016"
+        }
+    }))
+    .unwrap();
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        .to_string();
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(b"signing-secret").unwrap();
+    mac.update(format!("v0:{}:", timestamp).as_bytes());
+    mac.update(&body);
+    let signature = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-slack-request-timestamp", timestamp.parse().unwrap());
+    headers.insert("x-slack-signature", signature.parse().unwrap());
+
+    let request = SlackRequest::from_request("signing-secret", &headers, &body).unwrap();
+    match &request {
+        SlackRequest::EventCallback(callback) => match &callback.event {
+            Event::AppMention(mention) => {
+                assert_eq!(mention.user, "U061F7AUR");
+                assert_eq!(mention.channel, "C0LAN2Q65");
+            }
+            other => panic!("expected an app mention, got {:?}", other),
+        },
+        other => panic!("expected an event callback, got {:?}", other),
+    }
+    assert_eq!(request.ack(), Acknowledgement::Empty);
+
+    let err = SlackRequest::from_request("another-secret", &headers, &body).unwrap_err();
+    assert!(matches!(err, RequestError::InvalidSignature));
+
+    headers.remove("x-slack-signature");
+    let err = SlackRequest::from_request("signing-secret", &headers, &body).unwrap_err();
+    assert!(matches!(
+        err,
+        RequestError::MissingHeader("x-slack-signature")
+    ));
+}
@@ -40,13 +40,16 @@ impl Account {
      *
      * <p>To update your own account, use the <a href="https://dashboard.stripe.com/account">Dashboard</a>. Refer to our <a href="/docs/connect/updating-accounts">Connect</a> documentation to learn more about updating accounts.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Account> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostAccountsAccountRequest,
+    ) -> Result<crate::types::Account> {
         let url = self.client.url("/v1/account", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -78,13 +81,16 @@ impl Account {
      *
      * <p>Create an external account for a given account.</p>
      */
-    pub async fn post_bank(&self) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank(
+        &self,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url("/v1/account/bank_accounts", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -129,7 +135,11 @@ impl Account {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_bank_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank_account(
+        &self,
+        id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequestData,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/account/bank_accounts/{}",
@@ -141,7 +151,7 @@ impl Account {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -239,7 +249,7 @@ impl Account {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -267,6 +277,58 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Capability> {
+        let url = self.client.url("/v1/account/capabilities", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListAccountCapability = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/account/capabilities/{capability}` endpoint.
      *
@@ -310,6 +372,7 @@ impl Account {
     pub async fn post_capabilities_capability(
         &self,
         capability: &str,
+        body: &crate::types::PostAccountsAccountCapabilitiesCapabilityRequest,
     ) -> Result<crate::types::Capability> {
         let url = self.client.url(
             &format!(
@@ -322,7 +385,7 @@ impl Account {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -414,7 +477,7 @@ impl Account {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -442,18 +505,81 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DataAnyOf> {
+        let url = self.client.url("/v1/account/external_accounts", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ExternalAccounts = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data
+                        .last()
+                        .and_then(|last| match serde_json::json!(last) {
+                            serde_json::Value::Object(o) => o
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string()),
+                            _ => None,
+                        })
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: *resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
      *
      * <p>Create an external account for a given account.</p>
      */
-    pub async fn post_external(&self) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external(
+        &self,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url("/v1/account/external_accounts", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -498,7 +624,11 @@ impl Account {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_external_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external_account(
+        &self,
+        id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequestData,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/account/external_accounts/{}",
@@ -510,7 +640,7 @@ impl Account {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -553,13 +683,16 @@ impl Account {
      *
      * <p><strong>You may only create login links for <a href="/docs/connect/express-accounts">Express accounts</a> connected to your platform</strong>.</p>
      */
-    pub async fn post_login_link(&self) -> Result<crate::types::LoginLink> {
+    pub async fn post_login_link(
+        &self,
+        body: &crate::types::PostAccountsAccountLoginLinksRequest,
+    ) -> Result<crate::types::LoginLink> {
         let url = self.client.url("/v1/account/login_links", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -653,7 +786,7 @@ impl Account {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -681,18 +814,74 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        _relationship: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Person> {
+        let url = self.client.url("/v1/account/people", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountPeopleResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/account/people` endpoint.
      *
      * <p>Creates a new person.</p>
      */
-    pub async fn post_people(&self) -> Result<crate::types::Person> {
+    pub async fn post_people(
+        &self,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url("/v1/account/people", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -735,7 +924,11 @@ impl Account {
      *
      * * `person: &str` -- The account's country.
      */
-    pub async fn post_people_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_people_person(
+        &self,
+        person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
                 "/v1/account/people/{}",
@@ -747,7 +940,7 @@ impl Account {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -868,7 +1061,7 @@ impl Account {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -896,18 +1089,74 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        _relationship: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Person> {
+        let url = self.client.url("/v1/account/persons", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountPeopleResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/account/persons` endpoint.
      *
      * <p>Creates a new person.</p>
      */
-    pub async fn post_person(&self) -> Result<crate::types::Person> {
+    pub async fn post_person(
+        &self,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url("/v1/account/persons", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -950,7 +1199,11 @@ impl Account {
      *
      * * `person: &str` -- The account's country.
      */
-    pub async fn post_persons_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_persons_person(
+        &self,
+        person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
                 "/v1/account/persons/{}",
@@ -962,7 +1215,7 @@ impl Account {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -17,13 +17,16 @@ impl AccountLinks {
      *
      * <p>Creates an AccountLink object that includes a single-use Stripe URL that the platform can redirect their user to in order to take them through the Connect Onboarding flow.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::AccountLink> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostAccountLinksRequest,
+    ) -> Result<crate::types::AccountLink> {
         let url = self.client.url("/v1/account_links", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -98,7 +98,7 @@ impl Accounts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -126,19 +126,75 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Account> {
+        let url = self.client.url("/v1/accounts", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts` endpoint.
      *
      * <p>With <a href="/docs/connect">Connect</a>, you can create Stripe accounts for your users.
      * To do this, you’ll first need to <a href="https://dashboard.stripe.com/account/applications/settings">register your platform</a>.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Account> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostAccountsRequest,
+    ) -> Result<crate::types::Account> {
         let url = self.client.url("/v1/accounts", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -183,7 +239,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_accounts(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_accounts(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRequest,
+    ) -> Result<crate::types::Account> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}",
@@ -195,7 +255,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -241,7 +301,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_bank(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/bank_accounts",
@@ -253,7 +317,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -305,6 +369,7 @@ impl Accounts {
         &self,
         account: &str,
         id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequestData,
     ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
@@ -318,7 +383,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -438,7 +503,7 @@ impl Accounts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -466,6 +531,65 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        account: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Capability> {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/capabilities",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListAccountCapability = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
      *
@@ -514,6 +638,7 @@ impl Accounts {
         &self,
         account: &str,
         capability: &str,
+        body: &crate::types::PostAccountsAccountCapabilitiesCapabilityRequest,
     ) -> Result<crate::types::Capability> {
         let url = self.client.url(
             &format!(
@@ -527,7 +652,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -632,7 +757,7 @@ impl Accounts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -660,6 +785,73 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+        account: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::DataAnyOf> {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/external_accounts",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ExternalAccounts = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data
+                        .last()
+                        .and_then(|last| match serde_json::json!(last) {
+                            serde_json::Value::Object(o) => o
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string()),
+                            _ => None,
+                        })
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: *resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts` endpoint.
      *
@@ -669,7 +861,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_external(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/external_accounts",
@@ -681,7 +877,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -737,6 +933,7 @@ impl Accounts {
         &self,
         account: &str,
         id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequestData,
     ) -> Result<crate::types::DataAnyOf> {
         let url = self.client.url(
             &format!(
@@ -750,7 +947,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -800,7 +997,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_login_link(&self, account: &str) -> Result<crate::types::LoginLink> {
+    pub async fn post_login_link(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountLoginLinksRequest,
+    ) -> Result<crate::types::LoginLink> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/login_links",
@@ -812,7 +1013,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -923,7 +1124,7 @@ impl Accounts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -951,6 +1152,66 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Person> {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/people",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountPeopleResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/people` endpoint.
      *
@@ -960,7 +1221,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_people(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_people(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/people",
@@ -972,7 +1237,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1026,6 +1291,7 @@ impl Accounts {
         &self,
         account: &str,
         person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
     ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
@@ -1039,7 +1305,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1183,7 +1449,7 @@ impl Accounts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1211,6 +1477,66 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Person> {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/persons",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountPeopleResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/persons` endpoint.
      *
@@ -1220,7 +1546,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_person(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_person(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/persons",
@@ -1232,7 +1562,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1286,6 +1616,7 @@ impl Accounts {
         &self,
         account: &str,
         person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
     ) -> Result<crate::types::Person> {
         let url = self.client.url(
             &format!(
@@ -1299,7 +1630,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1349,7 +1680,11 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      */
-    pub async fn post_reject(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_reject(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRejectRequest,
+    ) -> Result<crate::types::Account> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/reject",
@@ -1361,7 +1696,7 @@ impl Accounts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
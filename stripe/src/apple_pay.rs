@@ -113,7 +113,7 @@ impl ApplePay {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -141,18 +141,81 @@ impl ApplePay {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_all_domains`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List apple pay domains.</p>
+     */
+    pub fn get_all_domains_stream(
+        &self,
+        domain_name: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ApplePayDomain> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain_name.is_empty() {
+            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/apple_pay/domains?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ApplePayDomainList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
      *
      * <p>Create an apple pay domain.</p>
      */
-    pub async fn post_domain(&self) -> Result<crate::types::ApplePayDomain> {
+    pub async fn post_domain(
+        &self,
+        body: &crate::types::PostApplePayDomainsRequest,
+    ) -> Result<crate::types::ApplePayDomain> {
         let url = self.client.url("/v1/apple_pay/domains", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -116,7 +116,7 @@ impl ApplicationFees {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -144,6 +144,67 @@ impl ApplicationFees {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PlatformFee> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/application_fees?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetApplicationFeesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
      *
@@ -186,7 +247,12 @@ impl ApplicationFees {
      * * `fee: &str` -- The account's country.
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_fee_refund(&self, fee: &str, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_fee_refund(
+        &self,
+        fee: &str,
+        id: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> Result<crate::types::FeeRefund> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refunds/{}",
@@ -199,7 +265,7 @@ impl ApplicationFees {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -242,7 +308,11 @@ impl ApplicationFees {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_refund(&self, id: &str) -> Result<crate::types::PlatformFee> {
+    pub async fn post_refund(
+        &self,
+        id: &str,
+        body: &crate::types::PostApplicationFeesRefundsRequest,
+    ) -> Result<crate::types::PlatformFee> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refund",
@@ -254,7 +324,7 @@ impl ApplicationFees {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -359,7 +429,7 @@ impl ApplicationFees {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -387,6 +457,65 @@ impl ApplicationFees {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::FeeRefund> {
+        let url = self.client.url(
+            &format!(
+                "/v1/application_fees/{}/refunds",
+                crate::progenitor_support::encode_path(id),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Refunds = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/application_fees/{id}/refunds` endpoint.
      *
@@ -404,7 +533,11 @@ impl ApplicationFees {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_refund_application_fees(&self, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_refund_application_fees(
+        &self,
+        id: &str,
+        body: &crate::types::PostApplicationFeesRefundsRequest,
+    ) -> Result<crate::types::FeeRefund> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refunds",
@@ -416,7 +549,7 @@ impl ApplicationFees {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -169,7 +169,7 @@ impl Balance {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -197,6 +197,81 @@ impl Balance {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/balance/history` endpoint.
+     *
+     * As opposed to `get_all_history`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
+     *
+     * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     */
+    pub fn get_all_history_stream(
+        &self,
+        _created: &str,
+        currency: &str,
+        payout: &str,
+        source: &str,
+        type_: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BalanceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !currency.is_empty() {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        if !payout.is_empty() {
+            query_args.push(("payout".to_string(), payout.to_string()));
+        }
+        if !source.is_empty() {
+            query_args.push(("source".to_string(), source.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/balance/history?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::BalanceTransactionsList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/balance/history/{id}` endpoint.
      *
@@ -124,7 +124,7 @@ impl BillingPortal {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -152,18 +152,86 @@ impl BillingPortal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_all_configurations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     */
+    pub fn get_all_configurations_stream(
+        &self,
+        active: bool,
+        is_default: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PortalConfiguration> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if is_default {
+            query_args.push(("is_default".to_string(), is_default.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/billing_portal/configurations?{}", query_),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetBillingPortalConfigurationsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
      *
      * <p>Creates a configuration that describes the functionality and behavior of a PortalSession</p>
      */
-    pub async fn post_configuration(&self) -> Result<crate::types::PortalConfiguration> {
+    pub async fn post_configuration(
+        &self,
+        body: &crate::types::PostBillingPortalConfigurationsRequest,
+    ) -> Result<crate::types::PortalConfiguration> {
         let url = self.client.url("/v1/billing_portal/configurations", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -212,6 +280,7 @@ impl BillingPortal {
     pub async fn post_configurations_configuration(
         &self,
         configuration: &str,
+        body: &crate::types::PostBillingPortalConfigurationsConfigurationRequest,
     ) -> Result<crate::types::PortalConfiguration> {
         let url = self.client.url(
             &format!(
@@ -224,7 +293,7 @@ impl BillingPortal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -235,13 +304,16 @@ impl BillingPortal {
      *
      * <p>Creates a session of the customer portal.</p>
      */
-    pub async fn post_session(&self) -> Result<crate::types::PortalSession> {
+    pub async fn post_session(
+        &self,
+        body: &crate::types::PostBillingPortalSessionsRequest,
+    ) -> Result<crate::types::PortalSession> {
         let url = self.client.url("/v1/billing_portal/sessions", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -130,7 +130,7 @@ impl Charges {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -158,18 +158,88 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of charges you’ve previously created. The charges are returned in sorted order, with the most recent charges appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        payment_intent: &str,
+        transfer_group: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        if !transfer_group.is_empty() {
+            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/charges?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Charges = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/charges` endpoint.
      *
      * <p>To charge a credit card or other payment source, you create a <code>Charge</code> object. If your API key is in test mode, the supplied payment source (e.g., card) won’t actually be charged, although everything else will occur as if in live mode. (Stripe assumes that the charge would have completed successfully).</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Charge> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostChargesRequest,
+    ) -> Result<crate::types::Charge> {
         let url = self.client.url("/v1/charges", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -274,7 +344,7 @@ impl Charges {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -302,6 +372,69 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Search for charges you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        query: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/charges/search?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::SearchResult = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/charges/{charge}` endpoint.
      *
@@ -339,7 +472,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_charges(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_charges(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRequest,
+    ) -> Result<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}",
@@ -351,7 +488,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -368,7 +505,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_capture(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_capture(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeCaptureRequest,
+    ) -> Result<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/capture",
@@ -380,7 +521,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -423,7 +564,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_dispute(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute(
+        &self,
+        charge: &str,
+        body: &crate::types::PostDisputesDisputeRequest,
+    ) -> Result<crate::types::Dispute> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/dispute",
@@ -435,7 +580,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -450,7 +595,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_dispute_close(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute_close(
+        &self,
+        charge: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Dispute> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/dispute/close",
@@ -462,7 +611,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -487,7 +636,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_refund(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_refund(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRefundRequest,
+    ) -> Result<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refund",
@@ -499,7 +652,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -604,7 +757,7 @@ impl Charges {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -632,6 +785,65 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        charge: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Refund> {
+        let url = self.client.url(
+            &format!(
+                "/v1/charges/{}/refunds",
+                crate::progenitor_support::encode_path(charge),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::RefundList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/charges/{charge}/refunds` endpoint.
      *
@@ -641,7 +853,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_refund_charges(&self, charge: &str) -> Result<crate::types::Refund> {
+    pub async fn post_refund_charges(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRefundRequest,
+    ) -> Result<crate::types::Refund> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refunds",
@@ -653,7 +869,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -707,6 +923,7 @@ impl Charges {
         &self,
         charge: &str,
         refund: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> Result<crate::types::Refund> {
         let url = self.client.url(
             &format!(
@@ -720,7 +937,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -122,7 +122,7 @@ impl CreditNotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -150,6 +150,70 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of credit notes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        invoice: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CreditNote> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::CreditNotesList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/credit_notes` endpoint.
      *
@@ -168,13 +232,16 @@ impl CreditNotes {
      * <p>You may issue multiple credit notes for an invoice. Each credit note will increment the invoice’s <code>pre_payment_credit_notes_amount</code>
      * or <code>post_payment_credit_notes_amount</code> depending on its <code>status</code> at the time of credit note creation.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::CreditNote> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCreditNotesRequest,
+    ) -> Result<crate::types::CreditNote> {
         let url = self.client.url("/v1/credit_notes", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -430,7 +497,7 @@ impl CreditNotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -458,6 +525,99 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_all_preview_lines`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_preview_lines_stream(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CreditNoteLineItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if amount > 0 {
+            query_args.push(("amount".to_string(), amount.to_string()));
+        }
+        if credit_amount > 0 {
+            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if !memo.is_empty() {
+            query_args.push(("memo".to_string(), memo.to_string()));
+        }
+        if out_of_band_amount > 0 {
+            query_args.push((
+                "out_of_band_amount".to_string(),
+                out_of_band_amount.to_string(),
+            ));
+        }
+        if !reason.to_string().is_empty() {
+            query_args.push(("reason".to_string(), reason.to_string()));
+        }
+        if !refund.is_empty() {
+            query_args.push(("refund".to_string(), refund.to_string()));
+        }
+        if refund_amount > 0 {
+            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Lines = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
      *
@@ -560,7 +720,7 @@ impl CreditNotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -588,6 +748,65 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_all_note_lines`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_note_lines_stream(
+        &self,
+        credit_note: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CreditNoteLineItem> {
+        let url = self.client.url(
+            &format!(
+                "/v1/credit_notes/{}/lines",
+                crate::progenitor_support::encode_path(credit_note),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Lines = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{id}` endpoint.
      *
@@ -625,7 +844,11 @@ impl CreditNotes {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_credit_notes(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_credit_notes(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesRequestData,
+    ) -> Result<crate::types::CreditNote> {
         let url = self.client.url(
             &format!(
                 "/v1/credit_notes/{}",
@@ -637,7 +860,7 @@ impl CreditNotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -652,7 +875,11 @@ impl CreditNotes {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_void(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_void(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::CreditNote> {
         let url = self.client.url(
             &format!(
                 "/v1/credit_notes/{}/void",
@@ -664,7 +891,7 @@ impl CreditNotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
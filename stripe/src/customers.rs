@@ -121,7 +121,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,18 +149,84 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your customers. The customers are returned sorted by creation date, with the most recent customers appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        test_clock: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Customer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if !test_clock.is_empty() {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/customers?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetCustomersResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers` endpoint.
      *
      * <p>Creates a new customer object.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Customer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCustomersRequest,
+    ) -> Result<crate::types::Customer> {
         let url = self.client.url("/v1/customers", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -265,7 +331,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -293,6 +359,69 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Search for customers you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        query: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/customers/search?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::SearchResult = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}` endpoint.
      *
@@ -335,7 +464,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_customers(&self, customer: &str) -> Result<crate::types::Customer> {
+    pub async fn post_customers(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerRequest,
+    ) -> Result<crate::types::Customer> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}",
@@ -347,7 +480,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -482,7 +615,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -510,6 +643,65 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all_balance_transactions`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of transactions that updated the customer’s <a href="/docs/billing/customer/balance">balances</a>.</p>
+     */
+    pub fn get_all_balance_transactions_stream(
+        &self,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CustomerBalanceTransaction> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/balance_transactions",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::CustomerBalanceTransactionList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/balance_transactions` endpoint.
      *
@@ -522,6 +714,7 @@ impl Customers {
     pub async fn post_balance_transaction(
         &self,
         customer: &str,
+        body: &crate::types::PostCustomersCustomerBalanceTransactionsRequest,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
         let url = self.client.url(
             &format!(
@@ -534,7 +727,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -588,6 +781,7 @@ impl Customers {
         &self,
         customer: &str,
         transaction: &str,
+        body: &crate::types::PostTopupsTopupRequest,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
         let url = self.client.url(
             &format!(
@@ -601,7 +795,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -709,7 +903,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -737,6 +931,65 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_bank_accounts`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the bank accounts belonging to a Customer. Note that the 10 most recent sources are always available by default on the Customer. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional bank accounts.</p>
+     */
+    pub fn get_all_bank_accounts_stream(
+        &self,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BankAccount> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/bank_accounts",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::BankAccountList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts` endpoint.
      *
@@ -753,6 +1006,7 @@ impl Customers {
     pub async fn post_bank_account(
         &self,
         customer: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
     ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = self.client.url(
             &format!(
@@ -765,7 +1019,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -819,6 +1073,7 @@ impl Customers {
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequestData,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = self.client.url(
             &format!(
@@ -832,7 +1087,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -885,6 +1140,7 @@ impl Customers {
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesVerifyRequest,
     ) -> Result<crate::types::BankAccount> {
         let url = self.client.url(
             &format!(
@@ -898,7 +1154,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1007,7 +1263,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1035,6 +1291,67 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the cards belonging to a customer.
+     * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
+     * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Card> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/cards",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Cards = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/cards` endpoint.
      *
@@ -1048,7 +1365,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_card(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_card(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/cards",
@@ -1060,7 +1381,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1110,6 +1431,7 @@ impl Customers {
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequestData,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = self.client.url(
             &format!(
@@ -1123,7 +1445,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1331,7 +1653,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1359,6 +1681,72 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_all_payment_methods`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of PaymentMethods for a given Customer</p>
+     */
+    pub fn get_all_payment_methods_stream(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PaymentMethod> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/payment_methods?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PaymentFlowsMethodList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
      *
@@ -1473,7 +1861,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1501,6 +1889,80 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_all_sources`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List sources for a specified customer.</p>
+     */
+    pub fn get_all_sources_stream(
+        &self,
+        customer: &str,
+        object: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CustomerSourcesDataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !object.is_empty() {
+            query_args.push(("object".to_string(), object.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/sources?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Sources = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data
+                        .last()
+                        .and_then(|last| match serde_json::json!(last) {
+                            serde_json::Value::Object(o) => o
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string()),
+                            _ => None,
+                        })
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: *resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/sources` endpoint.
      *
@@ -1514,7 +1976,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_source(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_source(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/sources",
@@ -1526,7 +1992,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1580,6 +2046,7 @@ impl Customers {
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequestData,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = self.client.url(
             &format!(
@@ -1593,7 +2060,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1646,6 +2113,7 @@ impl Customers {
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesVerifyRequest,
     ) -> Result<crate::types::BankAccount> {
         let url = self.client.url(
             &format!(
@@ -1659,7 +2127,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1767,7 +2235,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1795,6 +2263,65 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_all_subscriptions`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the customer’s active subscriptions. Note that the 10 most recent active subscriptions are always available by default on the customer object. If you need more than those 10, you can use the limit and starting_after parameters to page through additional subscriptions.</p>
+     */
+    pub fn get_all_subscriptions_stream(
+        &self,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Subscription> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/subscriptions",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Subscriptions = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/subscriptions` endpoint.
      *
@@ -1804,7 +2331,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_subscription(&self, customer: &str) -> Result<crate::types::Subscription> {
+    pub async fn post_subscription(
+        &self,
+        customer: &str,
+        body: &crate::types::PostSubscriptionsRequest,
+    ) -> Result<crate::types::Subscription> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/subscriptions",
@@ -1816,7 +2347,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1870,6 +2401,7 @@ impl Customers {
         &self,
         customer: &str,
         subscription_exposed_id: &str,
+        body: &crate::types::PostSubscriptionsSubscriptionExposedIdRequest,
     ) -> Result<crate::types::Subscription> {
         let url = self.client.url(
             &format!(
@@ -1883,7 +2415,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -2092,7 +2624,7 @@ impl Customers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -2120,6 +2652,65 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_all_tax_ids`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of tax IDs for a customer.</p>
+     */
+    pub fn get_all_tax_ids_stream(
+        &self,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TaxId> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/tax_ids",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TaxIds = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/tax_ids` endpoint.
      *
@@ -2129,7 +2720,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_tax_id(&self, customer: &str) -> Result<crate::types::TaxId> {
+    pub async fn post_tax_id(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerTaxIdsRequest,
+    ) -> Result<crate::types::TaxId> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/tax_ids",
@@ -2141,7 +2736,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -121,7 +121,7 @@ impl Disputes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,6 +149,69 @@ impl Disputes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/disputes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your disputes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        payment_intent: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Dispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/disputes?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetDisputesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/disputes/{dispute}` endpoint.
      *
@@ -188,7 +251,11 @@ impl Disputes {
      *
      * * `dispute: &str` -- The account's country.
      */
-    pub async fn post(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostDisputesDisputeRequest,
+    ) -> Result<crate::types::Dispute> {
         let url = self.client.url(
             &format!(
                 "/v1/disputes/{}",
@@ -200,7 +267,7 @@ impl Disputes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -217,7 +284,11 @@ impl Disputes {
      *
      * * `dispute: &str` -- The account's country.
      */
-    pub async fn post_close(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_close(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Dispute> {
         let url = self.client.url(
             &format!(
                 "/v1/disputes/{}/close",
@@ -229,7 +300,7 @@ impl Disputes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
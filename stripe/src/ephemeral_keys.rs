@@ -17,13 +17,16 @@ impl EphemeralKeys {
      *
      * <p>Creates a short-lived API key for a given resource.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::EphemeralKey> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostEphemeralKeysRequest,
+    ) -> Result<crate::types::EphemeralKey> {
         let url = self.client.url("/v1/ephemeral_keys", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
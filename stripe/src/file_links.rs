@@ -121,7 +121,7 @@ impl FileLinks {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,18 +149,84 @@ impl FileLinks {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/file_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of file links.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expired: bool,
+        file: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::FileLink> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if expired {
+            query_args.push(("expired".to_string(), expired.to_string()));
+        }
+        if !file.is_empty() {
+            query_args.push(("file".to_string(), file.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/file_links?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Links = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/file_links` endpoint.
      *
      * <p>Creates a new file link object.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::FileLink> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostFileLinksRequest,
+    ) -> Result<crate::types::FileLink> {
         let url = self.client.url("/v1/file_links", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -203,7 +269,11 @@ impl FileLinks {
      *
      * * `link: &str` -- The account's country.
      */
-    pub async fn post_link(&self, link: &str) -> Result<crate::types::FileLink> {
+    pub async fn post_link(
+        &self,
+        link: &str,
+        body: &crate::types::PostFileLinksLinkRequest,
+    ) -> Result<crate::types::FileLink> {
         let url = self.client.url(
             &format!(
                 "/v1/file_links/{}",
@@ -215,7 +285,7 @@ impl FileLinks {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
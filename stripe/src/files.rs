@@ -2,6 +2,48 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`Files::post`].
+#[derive(Default)]
+pub struct PostFilesForm {
+    file: Option<crate::utils::FilePart>,
+    purpose: Option<crate::types::Purpose>,
+}
+
+impl PostFilesForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A file to upload. The file should follow the specifications of RFC 2388 (which defines file transfers for the `multipart/form-data` protocol).
+    pub fn file(mut self, value: crate::utils::FilePart) -> Self {
+        self.file = Some(value);
+        self
+    }
+
+    /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+    pub fn purpose(mut self, value: crate::types::Purpose) -> Self {
+        self.purpose = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.file {
+            form = form.part("file", value.into_part().await?);
+        } else {
+            anyhow::bail!("missing required multipart field `file`");
+        }
+        if let Some(value) = self.purpose {
+            form = form.text("purpose", value.to_string());
+        } else {
+            anyhow::bail!("missing required multipart field `purpose`");
+        }
+
+        Ok(form)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PostFilesDefaultServer {}
 
@@ -121,7 +163,7 @@ impl Files {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,6 +191,65 @@ impl Files {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/files` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of the files that your account has access to. The files are returned sorted by creation date, with the most recently created files appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        purpose: crate::types::Purpose,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::File> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !purpose.to_string().is_empty() {
+            query_args.push(("purpose".to_string(), purpose.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/files?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetFilesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/files` endpoint.
      *
@@ -156,19 +257,13 @@ impl Files {
      *
      * <p>All of Stripe’s officially supported Client libraries should have support for sending <code>multipart/form-data</code>.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::File> {
+    pub async fn post(&self, body: PostFilesForm) -> Result<crate::types::File> {
         let url = self.client.url(
             "/v1/files",
             Some(PostFilesDefaultServer::default().default_url()),
         );
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
     /**
@@ -133,7 +133,7 @@ impl Identity {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -161,6 +161,75 @@ impl Identity {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_all_verification_reports`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List all verification reports.</p>
+     */
+    pub fn get_all_verification_reports_stream(
+        &self,
+        _created: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GelatoVerificationReport> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if !verification_session.is_empty() {
+            query_args.push((
+                "verification_session".to_string(),
+                verification_session.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/identity/verification_reports?{}", query_),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIdentityVerificationReportsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/identity/verification_reports/{report}` endpoint.
      *
@@ -298,7 +367,7 @@ impl Identity {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -326,6 +395,68 @@ impl Identity {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_all_verification_sessions`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of VerificationSessions</p>
+     */
+    pub fn get_all_verification_sessions_stream(
+        &self,
+        _created: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GelatoVerificationSession> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/identity/verification_sessions?{}", query_),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIdentityVerificationSessionsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/identity/verification_sessions` endpoint.
      *
@@ -339,13 +470,14 @@ impl Identity {
      */
     pub async fn post_verification_session(
         &self,
+        body: &crate::types::PostIdentityVerificationSessionsRequest,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = self.client.url("/v1/identity/verification_sessions", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -400,6 +532,7 @@ impl Identity {
     pub async fn post_verification_sessions_session(
         &self,
         session: &str,
+        body: &crate::types::PostIdentityVerificationSessionsSessionRequest,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = self.client.url(
             &format!(
@@ -412,7 +545,7 @@ impl Identity {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -432,6 +565,7 @@ impl Identity {
     pub async fn post_verification_sessions_session_cancel(
         &self,
         session: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = self.client.url(
             &format!(
@@ -444,7 +578,7 @@ impl Identity {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -480,6 +614,7 @@ impl Identity {
     pub async fn post_verification_sessions_session_redact(
         &self,
         session: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = self.client.url(
             &format!(
@@ -492,7 +627,7 @@ impl Identity {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
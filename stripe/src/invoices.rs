@@ -148,7 +148,7 @@ impl Invoices {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -176,18 +176,96 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Invoice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_method.to_string().is_empty() {
+            query_args.push((
+                "collection_method".to_string(),
+                collection_method.to_string(),
+            ));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::InvoicesList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/invoices` endpoint.
      *
      * <p>This endpoint creates a draft invoice for a given customer. The draft invoice created pulls in all pending invoice items on that customer, including prorations. The invoice remains a draft until you <a href="#finalize_invoice">finalize</a> the invoice, which allows you to <a href="#pay_invoice">pay</a> or <a href="#send_invoice">send</a> the invoice to your customers.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Invoice> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostInvoicesRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url("/v1/invoices", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -292,7 +370,7 @@ impl Invoices {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -320,6 +398,69 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Search for invoices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        query: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/search?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::SearchResult = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
      *
@@ -690,7 +831,7 @@ impl Invoices {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -718,6 +859,129 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_all_upcoming_lines`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_upcoming_lines_stream(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::LineItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coupon.is_empty() {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !schedule.is_empty() {
+            query_args.push(("schedule".to_string(), schedule.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        if subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                subscription_cancel_at_period_end.to_string(),
+            ));
+        }
+        if subscription_cancel_now {
+            query_args.push((
+                "subscription_cancel_now".to_string(),
+                subscription_cancel_now.to_string(),
+            ));
+        }
+        if !subscription_proration_behavior.to_string().is_empty() {
+            query_args.push((
+                "subscription_proration_behavior".to_string(),
+                subscription_proration_behavior.to_string(),
+            ));
+        }
+        if subscription_proration_date > 0 {
+            query_args.push((
+                "subscription_proration_date".to_string(),
+                subscription_proration_date.to_string(),
+            ));
+        }
+        if subscription_start_date > 0 {
+            query_args.push((
+                "subscription_start_date".to_string(),
+                subscription_start_date.to_string(),
+            ));
+        }
+        if subscription_trial_from_plan {
+            query_args.push((
+                "subscription_trial_from_plan".to_string(),
+                subscription_trial_from_plan.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::InvoiceLinesList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}` endpoint.
      *
@@ -760,7 +1024,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_invoices(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_invoices(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}",
@@ -772,7 +1040,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -814,7 +1082,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_finalize(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_finalize(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceFinalizeRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/finalize",
@@ -826,7 +1098,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -931,7 +1203,7 @@ impl Invoices {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -959,6 +1231,65 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_all_lines`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_lines_stream(
+        &self,
+        invoice: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::LineItem> {
+        let url = self.client.url(
+            &format!(
+                "/v1/invoices/{}/lines",
+                crate::progenitor_support::encode_path(invoice),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::InvoiceLinesList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/invoices/{invoice}/mark_uncollectible` endpoint.
      *
@@ -968,7 +1299,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_mark_uncollectible(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_mark_uncollectible(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/mark_uncollectible",
@@ -980,7 +1315,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -995,7 +1330,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_pay(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_pay(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoicePayRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/pay",
@@ -1007,7 +1346,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1024,7 +1363,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_send(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_send(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/send",
@@ -1036,7 +1379,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1051,7 +1394,11 @@ impl Invoices {
      *
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn post_void(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_void(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Invoice> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/void",
@@ -1063,7 +1410,7 @@ impl Invoices {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
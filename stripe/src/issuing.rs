@@ -134,7 +134,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -162,6 +162,75 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_all_authorizations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Authorization</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_authorizations_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingAuthorization> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card.is_empty() {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/authorizations?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIssuingAuthorizationsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/authorizations/{authorization}` endpoint.
      *
@@ -205,6 +274,7 @@ impl Issuing {
     pub async fn post_authorizations_authorization(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = self.client.url(
             &format!(
@@ -217,7 +287,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -235,6 +305,7 @@ impl Issuing {
     pub async fn post_authorizations_authorization_approve(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingAuthorizationsAuthorizationApproveRequest,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = self.client.url(
             &format!(
@@ -247,7 +318,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -265,6 +336,7 @@ impl Issuing {
     pub async fn post_authorizations_authorization_decline(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = self.client.url(
             &format!(
@@ -277,7 +349,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -414,7 +486,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -442,18 +514,94 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_all_cardholders`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Cardholder</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_cardholders_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingCardholder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/cardholders?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIssuingCardholdersResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/cardholders` endpoint.
      *
      * <p>Creates a new Issuing <code>Cardholder</code> object that can be issued cards.</p>
      */
-    pub async fn post_cardholder(&self) -> Result<crate::types::IssuingCardholder> {
+    pub async fn post_cardholder(
+        &self,
+        body: &crate::types::PostIssuingCardholdersRequest,
+    ) -> Result<crate::types::IssuingCardholder> {
         let url = self.client.url("/v1/issuing/cardholders", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -502,6 +650,7 @@ impl Issuing {
     pub async fn post_cardholders_cardholder(
         &self,
         cardholder: &str,
+        body: &crate::types::PostIssuingCardholdersCardholderRequest,
     ) -> Result<crate::types::IssuingCardholder> {
         let url = self.client.url(
             &format!(
@@ -514,7 +663,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -669,7 +818,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -697,18 +846,102 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Card</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        exp_month: i64,
+        exp_year: i64,
+        last_4: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingCard> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if exp_month > 0 {
+            query_args.push(("exp_month".to_string(), exp_month.to_string()));
+        }
+        if exp_year > 0 {
+            query_args.push(("exp_year".to_string(), exp_year.to_string()));
+        }
+        if !last_4.is_empty() {
+            query_args.push(("last4".to_string(), last_4.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/cards?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIssuingCardsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/cards` endpoint.
      *
      * <p>Creates an Issuing <code>Card</code> object.</p>
      */
-    pub async fn post_card(&self) -> Result<crate::types::IssuingCard> {
+    pub async fn post_card(
+        &self,
+        body: &crate::types::PostIssuingCardsRequest,
+    ) -> Result<crate::types::IssuingCard> {
         let url = self.client.url("/v1/issuing/cards", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -751,7 +984,11 @@ impl Issuing {
      *
      * * `card: &str` -- The account's country.
      */
-    pub async fn post_cards_card(&self, card: &str) -> Result<crate::types::IssuingCard> {
+    pub async fn post_cards_card(
+        &self,
+        card: &str,
+        body: &crate::types::PostIssuingCardsCardRequest,
+    ) -> Result<crate::types::IssuingCard> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/cards/{}",
@@ -763,7 +1000,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -882,7 +1119,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -910,18 +1147,86 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_all_disputes`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Dispute</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_disputes_stream(
+        &self,
+        _created: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingDispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !transaction.is_empty() {
+            query_args.push(("transaction".to_string(), transaction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/disputes?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::IssuingDisputeList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/disputes` endpoint.
      *
      * <p>Creates an Issuing <code>Dispute</code> object. Individual pieces of evidence within the <code>evidence</code> object are optional at this point. Stripe only validates that required evidence is present during submission. Refer to <a href="/docs/issuing/purchases/disputes#dispute-reasons-and-evidence">Dispute reasons and evidence</a> for more details about evidence requirements.</p>
      */
-    pub async fn post_dispute(&self) -> Result<crate::types::IssuingDispute> {
+    pub async fn post_dispute(
+        &self,
+        body: &crate::types::PostIssuingDisputesRequest,
+    ) -> Result<crate::types::IssuingDispute> {
         let url = self.client.url("/v1/issuing/disputes", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -970,6 +1275,7 @@ impl Issuing {
     pub async fn post_disputes_dispute(
         &self,
         dispute: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> Result<crate::types::IssuingDispute> {
         let url = self.client.url(
             &format!(
@@ -982,7 +1288,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1000,6 +1306,7 @@ impl Issuing {
     pub async fn post_disputes_dispute_submit(
         &self,
         dispute: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> Result<crate::types::IssuingDispute> {
         let url = self.client.url(
             &format!(
@@ -1012,7 +1319,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1109,7 +1416,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1137,6 +1444,59 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_all_settlements`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Settlement</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_settlements_stream(
+        &self,
+        _created: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingSettlement> {
+        let url = self.client.url("/v1/issuing/settlements", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIssuingSettlementsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/settlements/{settlement}` endpoint.
      *
@@ -1180,6 +1540,7 @@ impl Issuing {
     pub async fn post_settlements_settlement(
         &self,
         settlement: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> Result<crate::types::IssuingSettlement> {
         let url = self.client.url(
             &format!(
@@ -1192,7 +1553,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1320,7 +1681,7 @@ impl Issuing {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -1348,6 +1709,75 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of Issuing <code>Transaction</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        type_: crate::types::IssuingTransactionType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::IssuingTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card.is_empty() {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/transactions?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetIssuingTransactionsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/transactions/{transaction}` endpoint.
      *
@@ -1391,6 +1821,7 @@ impl Issuing {
     pub async fn post_transactions_transaction(
         &self,
         transaction: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> Result<crate::types::IssuingTransaction> {
         let url = self.client.url(
             &format!(
@@ -1403,7 +1834,7 @@ impl Issuing {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -0,0 +1,5554 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Account`](crate::account::Account).
+    pub fn account(&self) -> Account<'_> {
+        Account { mock: self }
+    }
+
+    /// Mock the functions of [`AccountLinks`](crate::account_links::AccountLinks).
+    pub fn account_links(&self) -> AccountLinks<'_> {
+        AccountLinks { mock: self }
+    }
+
+    /// Mock the functions of [`Accounts`](crate::accounts::Accounts).
+    pub fn accounts(&self) -> Accounts<'_> {
+        Accounts { mock: self }
+    }
+
+    /// Mock the functions of [`ApplePay`](crate::apple_pay::ApplePay).
+    pub fn apple_pay(&self) -> ApplePay<'_> {
+        ApplePay { mock: self }
+    }
+
+    /// Mock the functions of [`ApplicationFees`](crate::application_fees::ApplicationFees).
+    pub fn application_fees(&self) -> ApplicationFees<'_> {
+        ApplicationFees { mock: self }
+    }
+
+    /// Mock the functions of [`Balance`](crate::balance::Balance).
+    pub fn balance(&self) -> Balance<'_> {
+        Balance { mock: self }
+    }
+
+    /// Mock the functions of [`BalanceTransactions`](crate::balance_transactions::BalanceTransactions).
+    pub fn balance_transactions(&self) -> BalanceTransactions<'_> {
+        BalanceTransactions { mock: self }
+    }
+
+    /// Mock the functions of [`BillingPortal`](crate::billing_portal::BillingPortal).
+    pub fn billing_portal(&self) -> BillingPortal<'_> {
+        BillingPortal { mock: self }
+    }
+
+    /// Mock the functions of [`Bitcoin`](crate::bitcoin::Bitcoin).
+    pub fn bitcoin(&self) -> Bitcoin<'_> {
+        Bitcoin { mock: self }
+    }
+
+    /// Mock the functions of [`Charges`](crate::charges::Charges).
+    pub fn charges(&self) -> Charges<'_> {
+        Charges { mock: self }
+    }
+
+    /// Mock the functions of [`Checkout`](crate::checkout::Checkout).
+    pub fn checkout(&self) -> Checkout<'_> {
+        Checkout { mock: self }
+    }
+
+    /// Mock the functions of [`CountrySpecs`](crate::country_specs::CountrySpecs).
+    pub fn country_specs(&self) -> CountrySpecs<'_> {
+        CountrySpecs { mock: self }
+    }
+
+    /// Mock the functions of [`Coupons`](crate::coupons::Coupons).
+    pub fn coupons(&self) -> Coupons<'_> {
+        Coupons { mock: self }
+    }
+
+    /// Mock the functions of [`CreditNotes`](crate::credit_notes::CreditNotes).
+    pub fn credit_notes(&self) -> CreditNotes<'_> {
+        CreditNotes { mock: self }
+    }
+
+    /// Mock the functions of [`Customers`](crate::customers::Customers).
+    pub fn customers(&self) -> Customers<'_> {
+        Customers { mock: self }
+    }
+
+    /// Mock the functions of [`Disputes`](crate::disputes::Disputes).
+    pub fn disputes(&self) -> Disputes<'_> {
+        Disputes { mock: self }
+    }
+
+    /// Mock the functions of [`EphemeralKeys`](crate::ephemeral_keys::EphemeralKeys).
+    pub fn ephemeral_keys(&self) -> EphemeralKeys<'_> {
+        EphemeralKeys { mock: self }
+    }
+
+    /// Mock the functions of [`Events`](crate::events::Events).
+    pub fn events(&self) -> Events<'_> {
+        Events { mock: self }
+    }
+
+    /// Mock the functions of [`ExchangeRates`](crate::exchange_rates::ExchangeRates).
+    pub fn exchange_rates(&self) -> ExchangeRates<'_> {
+        ExchangeRates { mock: self }
+    }
+
+    /// Mock the functions of [`FileLinks`](crate::file_links::FileLinks).
+    pub fn file_links(&self) -> FileLinks<'_> {
+        FileLinks { mock: self }
+    }
+
+    /// Mock the functions of [`Files`](crate::files::Files).
+    pub fn files(&self) -> Files<'_> {
+        Files { mock: self }
+    }
+
+    /// Mock the functions of [`Identity`](crate::identity::Identity).
+    pub fn identity(&self) -> Identity<'_> {
+        Identity { mock: self }
+    }
+
+    /// Mock the functions of [`Invoiceitems`](crate::invoiceitems::Invoiceitems).
+    pub fn invoiceitems(&self) -> Invoiceitems<'_> {
+        Invoiceitems { mock: self }
+    }
+
+    /// Mock the functions of [`Invoices`](crate::invoices::Invoices).
+    pub fn invoices(&self) -> Invoices<'_> {
+        Invoices { mock: self }
+    }
+
+    /// Mock the functions of [`IssuerFraudRecords`](crate::issuer_fraud_records::IssuerFraudRecords).
+    pub fn issuer_fraud_records(&self) -> IssuerFraudRecords<'_> {
+        IssuerFraudRecords { mock: self }
+    }
+
+    /// Mock the functions of [`Issuing`](crate::issuing::Issuing).
+    pub fn issuing(&self) -> Issuing<'_> {
+        Issuing { mock: self }
+    }
+
+    /// Mock the functions of [`Mandates`](crate::mandates::Mandates).
+    pub fn mandates(&self) -> Mandates<'_> {
+        Mandates { mock: self }
+    }
+
+    /// Mock the functions of [`OrderReturns`](crate::order_returns::OrderReturns).
+    pub fn order_returns(&self) -> OrderReturns<'_> {
+        OrderReturns { mock: self }
+    }
+
+    /// Mock the functions of [`Orders`](crate::orders::Orders).
+    pub fn orders(&self) -> Orders<'_> {
+        Orders { mock: self }
+    }
+
+    /// Mock the functions of [`PaymentIntents`](crate::payment_intents::PaymentIntents).
+    pub fn payment_intents(&self) -> PaymentIntents<'_> {
+        PaymentIntents { mock: self }
+    }
+
+    /// Mock the functions of [`PaymentLinks`](crate::payment_links::PaymentLinks).
+    pub fn payment_links(&self) -> PaymentLinks<'_> {
+        PaymentLinks { mock: self }
+    }
+
+    /// Mock the functions of [`PaymentMethods`](crate::payment_methods::PaymentMethods).
+    pub fn payment_methods(&self) -> PaymentMethods<'_> {
+        PaymentMethods { mock: self }
+    }
+
+    /// Mock the functions of [`Payouts`](crate::payouts::Payouts).
+    pub fn payouts(&self) -> Payouts<'_> {
+        Payouts { mock: self }
+    }
+
+    /// Mock the functions of [`Plans`](crate::plans::Plans).
+    pub fn plans(&self) -> Plans<'_> {
+        Plans { mock: self }
+    }
+
+    /// Mock the functions of [`Prices`](crate::prices::Prices).
+    pub fn prices(&self) -> Prices<'_> {
+        Prices { mock: self }
+    }
+
+    /// Mock the functions of [`Products`](crate::products::Products).
+    pub fn products(&self) -> Products<'_> {
+        Products { mock: self }
+    }
+
+    /// Mock the functions of [`PromotionCodes`](crate::promotion_codes::PromotionCodes).
+    pub fn promotion_codes(&self) -> PromotionCodes<'_> {
+        PromotionCodes { mock: self }
+    }
+
+    /// Mock the functions of [`Quotes`](crate::quotes::Quotes).
+    pub fn quotes(&self) -> Quotes<'_> {
+        Quotes { mock: self }
+    }
+
+    /// Mock the functions of [`Radar`](crate::radar::Radar).
+    pub fn radar(&self) -> Radar<'_> {
+        Radar { mock: self }
+    }
+
+    /// Mock the functions of [`Recipients`](crate::recipients::Recipients).
+    pub fn recipients(&self) -> Recipients<'_> {
+        Recipients { mock: self }
+    }
+
+    /// Mock the functions of [`Refunds`](crate::refunds::Refunds).
+    pub fn refunds(&self) -> Refunds<'_> {
+        Refunds { mock: self }
+    }
+
+    /// Mock the functions of [`Reporting`](crate::reporting::Reporting).
+    pub fn reporting(&self) -> Reporting<'_> {
+        Reporting { mock: self }
+    }
+
+    /// Mock the functions of [`Reviews`](crate::reviews::Reviews).
+    pub fn reviews(&self) -> Reviews<'_> {
+        Reviews { mock: self }
+    }
+
+    /// Mock the functions of [`SetupAttempts`](crate::setup_attempts::SetupAttempts).
+    pub fn setup_attempts(&self) -> SetupAttempts<'_> {
+        SetupAttempts { mock: self }
+    }
+
+    /// Mock the functions of [`SetupIntents`](crate::setup_intents::SetupIntents).
+    pub fn setup_intents(&self) -> SetupIntents<'_> {
+        SetupIntents { mock: self }
+    }
+
+    /// Mock the functions of [`ShippingRates`](crate::shipping_rates::ShippingRates).
+    pub fn shipping_rates(&self) -> ShippingRates<'_> {
+        ShippingRates { mock: self }
+    }
+
+    /// Mock the functions of [`Sigma`](crate::sigma::Sigma).
+    pub fn sigma(&self) -> Sigma<'_> {
+        Sigma { mock: self }
+    }
+
+    /// Mock the functions of [`Skus`](crate::skus::Skus).
+    pub fn skus(&self) -> Skus<'_> {
+        Skus { mock: self }
+    }
+
+    /// Mock the functions of [`Sources`](crate::sources::Sources).
+    pub fn sources(&self) -> Sources<'_> {
+        Sources { mock: self }
+    }
+
+    /// Mock the functions of [`SubscriptionItems`](crate::subscription_items::SubscriptionItems).
+    pub fn subscription_items(&self) -> SubscriptionItems<'_> {
+        SubscriptionItems { mock: self }
+    }
+
+    /// Mock the functions of [`SubscriptionSchedules`](crate::subscription_schedules::SubscriptionSchedules).
+    pub fn subscription_schedules(&self) -> SubscriptionSchedules<'_> {
+        SubscriptionSchedules { mock: self }
+    }
+
+    /// Mock the functions of [`Subscriptions`](crate::subscriptions::Subscriptions).
+    pub fn subscriptions(&self) -> Subscriptions<'_> {
+        Subscriptions { mock: self }
+    }
+
+    /// Mock the functions of [`TaxCodes`](crate::tax_codes::TaxCodes).
+    pub fn tax_codes(&self) -> TaxCodes<'_> {
+        TaxCodes { mock: self }
+    }
+
+    /// Mock the functions of [`TaxRates`](crate::tax_rates::TaxRates).
+    pub fn tax_rates(&self) -> TaxRates<'_> {
+        TaxRates { mock: self }
+    }
+
+    /// Mock the functions of [`Terminal`](crate::terminal::Terminal).
+    pub fn terminal(&self) -> Terminal<'_> {
+        Terminal { mock: self }
+    }
+
+    /// Mock the functions of [`TestHelpers`](crate::test_helpers::TestHelpers).
+    pub fn test_helpers(&self) -> TestHelpers<'_> {
+        TestHelpers { mock: self }
+    }
+
+    /// Mock the functions of [`ThreeDSecure`](crate::three_d_secure::ThreeDSecure).
+    pub fn three_d_secure(&self) -> ThreeDSecure<'_> {
+        ThreeDSecure { mock: self }
+    }
+
+    /// Mock the functions of [`Tokens`](crate::tokens::Tokens).
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens { mock: self }
+    }
+
+    /// Mock the functions of [`Topups`](crate::topups::Topups).
+    pub fn topups(&self) -> Topups<'_> {
+        Topups { mock: self }
+    }
+
+    /// Mock the functions of [`Transfers`](crate::transfers::Transfers).
+    pub fn transfers(&self) -> Transfers<'_> {
+        Transfers { mock: self }
+    }
+
+    /// Mock the functions of [`WebhookEndpoints`](crate::webhook_endpoints::WebhookEndpoints).
+    pub fn webhook_endpoints(&self) -> WebhookEndpoints<'_> {
+        WebhookEndpoints { mock: self }
+    }
+}
+
+pub struct Account<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Account<'a> {
+    /// Mock [`Account::get`](crate::account::Account::get), a `GET` to `/v1/account`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/account".to_string())
+    }
+
+    /// Mock [`Account::post`](crate::account::Account::post), a `POST` to `/v1/account`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/account".to_string())
+    }
+
+    /// Mock [`Account::delete`](crate::account::Account::delete), a `DELETE` to `/v1/account`.
+    pub fn delete(&self) -> crate::mock::MockOperation<'a, crate::types::DeletedAccount> {
+        self.mock
+            .operation(reqwest::Method::DELETE, "/v1/account".to_string())
+    }
+
+    /// Mock [`Account::post_bank`](crate::account::Account::post_bank), a `POST` to `/v1/account/bank_accounts`.
+    pub fn post_bank(&self) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/account/bank_accounts".to_string(),
+        )
+    }
+
+    /// Mock [`Account::get_bank`](crate::account::Account::get_bank), a `GET` to `/v1/account/bank_accounts/{id}`.
+    pub fn get_bank(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/account/bank_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_bank_account`](crate::account::Account::post_bank_account), a `POST` to `/v1/account/bank_accounts/{id}`.
+    pub fn post_bank_account(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/account/bank_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::delete_bank`](crate::account::Account::delete_bank), a `DELETE` to `/v1/account/bank_accounts/{id}`.
+    pub fn delete_bank(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedExternalAccountAnyOf> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/account/bank_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::get_capabilities`](crate::account::Account::get_capabilities), a `GET` to `/v1/account/capabilities`.
+    pub fn get_capabilities(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAccountCapability> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/account/capabilities".to_string())
+    }
+
+    /// Mock [`Account::get_capabilities_capability`](crate::account::Account::get_capabilities_capability), a `GET` to `/v1/account/capabilities/{capability}`.
+    pub fn get_capabilities_capability(
+        &self,
+        capability: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Capability> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/account/capabilities/{}",
+                crate::progenitor_support::encode_path(capability)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_capabilities_capability`](crate::account::Account::post_capabilities_capability), a `POST` to `/v1/account/capabilities/{capability}`.
+    pub fn post_capabilities_capability(
+        &self,
+        capability: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Capability> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/account/capabilities/{}",
+                crate::progenitor_support::encode_path(capability)
+            ),
+        )
+    }
+
+    /// Mock [`Account::get_external`](crate::account::Account::get_external), a `GET` to `/v1/account/external_accounts`.
+    pub fn get_external(&self) -> crate::mock::MockOperation<'a, crate::types::ExternalAccounts> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/account/external_accounts".to_string(),
+        )
+    }
+
+    /// Mock [`Account::post_external`](crate::account::Account::post_external), a `POST` to `/v1/account/external_accounts`.
+    pub fn post_external(&self) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/account/external_accounts".to_string(),
+        )
+    }
+
+    /// Mock [`Account::get_external_account`](crate::account::Account::get_external_account), a `GET` to `/v1/account/external_accounts/{id}`.
+    pub fn get_external_account(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/account/external_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_external_account`](crate::account::Account::post_external_account), a `POST` to `/v1/account/external_accounts/{id}`.
+    pub fn post_external_account(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/account/external_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::delete_external`](crate::account::Account::delete_external), a `DELETE` to `/v1/account/external_accounts/{id}`.
+    pub fn delete_external(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedExternalAccountAnyOf> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/account/external_accounts/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_login_link`](crate::account::Account::post_login_link), a `POST` to `/v1/account/login_links`.
+    pub fn post_login_link(&self) -> crate::mock::MockOperation<'a, crate::types::LoginLink> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/account/login_links".to_string())
+    }
+
+    /// Mock [`Account::get_people`](crate::account::Account::get_people), a `GET` to `/v1/account/people`.
+    pub fn get_people(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountPeopleResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/account/people".to_string())
+    }
+
+    /// Mock [`Account::post_people`](crate::account::Account::post_people), a `POST` to `/v1/account/people`.
+    pub fn post_people(&self) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/account/people".to_string())
+    }
+
+    /// Mock [`Account::get_people_person`](crate::account::Account::get_people_person), a `GET` to `/v1/account/people/{person}`.
+    pub fn get_people_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/account/people/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_people_person`](crate::account::Account::post_people_person), a `POST` to `/v1/account/people/{person}`.
+    pub fn post_people_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/account/people/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Account::delete_people_person`](crate::account::Account::delete_people_person), a `DELETE` to `/v1/account/people/{person}`.
+    pub fn delete_people_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedPerson> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/account/people/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Account::get_persons`](crate::account::Account::get_persons), a `GET` to `/v1/account/persons`.
+    pub fn get_persons(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountPeopleResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/account/persons".to_string())
+    }
+
+    /// Mock [`Account::post_person`](crate::account::Account::post_person), a `POST` to `/v1/account/persons`.
+    pub fn post_person(&self) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/account/persons".to_string())
+    }
+
+    /// Mock [`Account::get_persons_person`](crate::account::Account::get_persons_person), a `GET` to `/v1/account/persons/{person}`.
+    pub fn get_persons_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/account/persons/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Account::post_persons_person`](crate::account::Account::post_persons_person), a `POST` to `/v1/account/persons/{person}`.
+    pub fn post_persons_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/account/persons/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Account::delete_persons_person`](crate::account::Account::delete_persons_person), a `DELETE` to `/v1/account/persons/{person}`.
+    pub fn delete_persons_person(
+        &self,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedPerson> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/account/persons/{}",
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+}
+
+pub struct AccountLinks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> AccountLinks<'a> {
+    /// Mock [`AccountLinks::post`](crate::account_links::AccountLinks::post), a `POST` to `/v1/account_links`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::AccountLink> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/account_links".to_string())
+    }
+}
+
+pub struct Accounts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Accounts<'a> {
+    /// Mock [`Accounts::get_page`](crate::accounts::Accounts::get_page), a `GET` to `/v1/accounts`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetAccountsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/accounts".to_string())
+    }
+
+    /// Mock [`Accounts::post`](crate::accounts::Accounts::post), a `POST` to `/v1/accounts`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/accounts".to_string())
+    }
+
+    /// Mock [`Accounts::get`](crate::accounts::Accounts::get), a `GET` to `/v1/accounts/{account}`.
+    pub fn get(&self, account: &str) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_accounts`](crate::accounts::Accounts::post_accounts), a `POST` to `/v1/accounts/{account}`.
+    pub fn post_accounts(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete`](crate::accounts::Accounts::delete), a `DELETE` to `/v1/accounts/{account}`.
+    pub fn delete(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedAccount> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/accounts/{}",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_bank`](crate::accounts::Accounts::post_bank), a `POST` to `/v1/accounts/{account}/bank_accounts`.
+    pub fn post_bank(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/bank_accounts",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_bank`](crate::accounts::Accounts::get_bank), a `GET` to `/v1/accounts/{account}/bank_accounts/{id}`.
+    pub fn get_bank(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_bank_accounts`](crate::accounts::Accounts::post_bank_accounts), a `POST` to `/v1/accounts/{account}/bank_accounts/{id}`.
+    pub fn post_bank_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete_bank`](crate::accounts::Accounts::delete_bank), a `DELETE` to `/v1/accounts/{account}/bank_accounts/{id}`.
+    pub fn delete_bank(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedExternalAccountAnyOf> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/accounts/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_capabilities`](crate::accounts::Accounts::get_capabilities), a `GET` to `/v1/accounts/{account}/capabilities`.
+    pub fn get_capabilities(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAccountCapability> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/capabilities",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_capabilities_capability`](crate::accounts::Accounts::get_capabilities_capability), a `GET` to `/v1/accounts/{account}/capabilities/{capability}`.
+    pub fn get_capabilities_capability(
+        &self,
+        account: &str,
+        capability: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Capability> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/capabilities/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(capability)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_capabilities_capability`](crate::accounts::Accounts::post_capabilities_capability), a `POST` to `/v1/accounts/{account}/capabilities/{capability}`.
+    pub fn post_capabilities_capability(
+        &self,
+        account: &str,
+        capability: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Capability> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/capabilities/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(capability)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_external`](crate::accounts::Accounts::get_external), a `GET` to `/v1/accounts/{account}/external_accounts`.
+    pub fn get_external(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ExternalAccounts> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/external_accounts",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_external`](crate::accounts::Accounts::post_external), a `POST` to `/v1/accounts/{account}/external_accounts`.
+    pub fn post_external(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/external_accounts",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_external_accounts`](crate::accounts::Accounts::get_external_accounts), a `GET` to `/v1/accounts/{account}/external_accounts/{id}`.
+    pub fn get_external_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/external_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_external_accounts`](crate::accounts::Accounts::post_external_accounts), a `POST` to `/v1/accounts/{account}/external_accounts/{id}`.
+    pub fn post_external_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DataAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/external_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete_external`](crate::accounts::Accounts::delete_external), a `DELETE` to `/v1/accounts/{account}/external_accounts/{id}`.
+    pub fn delete_external(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedExternalAccountAnyOf> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/accounts/{}/external_accounts/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_login_link`](crate::accounts::Accounts::post_login_link), a `POST` to `/v1/accounts/{account}/login_links`.
+    pub fn post_login_link(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LoginLink> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/login_links",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_people`](crate::accounts::Accounts::get_people), a `GET` to `/v1/accounts/{account}/people`.
+    pub fn get_people(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountPeopleResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/people",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_people`](crate::accounts::Accounts::post_people), a `POST` to `/v1/accounts/{account}/people`.
+    pub fn post_people(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/people",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_people_person`](crate::accounts::Accounts::get_people_person), a `GET` to `/v1/accounts/{account}/people/{person}`.
+    pub fn get_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/people/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_people_person`](crate::accounts::Accounts::post_people_person), a `POST` to `/v1/accounts/{account}/people/{person}`.
+    pub fn post_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/people/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete_people_person`](crate::accounts::Accounts::delete_people_person), a `DELETE` to `/v1/accounts/{account}/people/{person}`.
+    pub fn delete_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedPerson> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/accounts/{}/people/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_persons`](crate::accounts::Accounts::get_persons), a `GET` to `/v1/accounts/{account}/persons`.
+    pub fn get_persons(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountPeopleResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/persons",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_person`](crate::accounts::Accounts::post_person), a `POST` to `/v1/accounts/{account}/persons`.
+    pub fn post_person(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/persons",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_persons_person`](crate::accounts::Accounts::get_persons_person), a `GET` to `/v1/accounts/{account}/persons/{person}`.
+    pub fn get_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/accounts/{}/persons/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_persons_person`](crate::accounts::Accounts::post_persons_person), a `POST` to `/v1/accounts/{account}/persons/{person}`.
+    pub fn post_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Person> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/persons/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::delete_persons_person`](crate::accounts::Accounts::delete_persons_person), a `DELETE` to `/v1/accounts/{account}/persons/{person}`.
+    pub fn delete_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedPerson> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/accounts/{}/persons/{}",
+                crate::progenitor_support::encode_path(account),
+                crate::progenitor_support::encode_path(person)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::post_reject`](crate::accounts::Accounts::post_reject), a `POST` to `/v1/accounts/{account}/reject`.
+    pub fn post_reject(
+        &self,
+        account: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Account> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/accounts/{}/reject",
+                crate::progenitor_support::encode_path(account)
+            ),
+        )
+    }
+}
+
+pub struct ApplePay<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ApplePay<'a> {
+    /// Mock [`ApplePay::get_domains`](crate::apple_pay::ApplePay::get_domains), a `GET` to `/v1/apple_pay/domains`.
+    pub fn get_domains(&self) -> crate::mock::MockOperation<'a, crate::types::ApplePayDomainList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/apple_pay/domains".to_string())
+    }
+
+    /// Mock [`ApplePay::post_domain`](crate::apple_pay::ApplePay::post_domain), a `POST` to `/v1/apple_pay/domains`.
+    pub fn post_domain(&self) -> crate::mock::MockOperation<'a, crate::types::ApplePayDomain> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/apple_pay/domains".to_string())
+    }
+
+    /// Mock [`ApplePay::get_domains_domain`](crate::apple_pay::ApplePay::get_domains_domain), a `GET` to `/v1/apple_pay/domains/{domain}`.
+    pub fn get_domains_domain(
+        &self,
+        domain: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApplePayDomain> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/apple_pay/domains/{}",
+                crate::progenitor_support::encode_path(domain)
+            ),
+        )
+    }
+
+    /// Mock [`ApplePay::delete_domains_domain`](crate::apple_pay::ApplePay::delete_domains_domain), a `DELETE` to `/v1/apple_pay/domains/{domain}`.
+    pub fn delete_domains_domain(
+        &self,
+        domain: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedApplePayDomain> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/apple_pay/domains/{}",
+                crate::progenitor_support::encode_path(domain)
+            ),
+        )
+    }
+}
+
+pub struct ApplicationFees<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ApplicationFees<'a> {
+    /// Mock [`ApplicationFees::get_page`](crate::application_fees::ApplicationFees::get_page), a `GET` to `/v1/application_fees`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetApplicationFeesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/application_fees".to_string())
+    }
+
+    /// Mock [`ApplicationFees::get_fee_refund`](crate::application_fees::ApplicationFees::get_fee_refund), a `GET` to `/v1/application_fees/{fee}/refunds/{id}`.
+    pub fn get_fee_refund(
+        &self,
+        fee: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FeeRefund> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/application_fees/{}/refunds/{}",
+                crate::progenitor_support::encode_path(fee),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplicationFees::post_fee_refund`](crate::application_fees::ApplicationFees::post_fee_refund), a `POST` to `/v1/application_fees/{fee}/refunds/{id}`.
+    pub fn post_fee_refund(
+        &self,
+        fee: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FeeRefund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/application_fees/{}/refunds/{}",
+                crate::progenitor_support::encode_path(fee),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplicationFees::get`](crate::application_fees::ApplicationFees::get), a `GET` to `/v1/application_fees/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::PlatformFee> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/application_fees/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplicationFees::post_refund`](crate::application_fees::ApplicationFees::post_refund), a `POST` to `/v1/application_fees/{id}/refund`.
+    pub fn post_refund(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PlatformFee> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/application_fees/{}/refund",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplicationFees::get_refunds`](crate::application_fees::ApplicationFees::get_refunds), a `GET` to `/v1/application_fees/{id}/refunds`.
+    pub fn get_refunds(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Refunds> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/application_fees/{}/refunds",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`ApplicationFees::post_refund_application_fees`](crate::application_fees::ApplicationFees::post_refund_application_fees), a `POST` to `/v1/application_fees/{id}/refunds`.
+    pub fn post_refund_application_fees(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::FeeRefund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/application_fees/{}/refunds",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Balance<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Balance<'a> {
+    /// Mock [`Balance::get`](crate::balance::Balance::get), a `GET` to `/v1/balance`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Balance> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/balance".to_string())
+    }
+
+    /// Mock [`Balance::get_history`](crate::balance::Balance::get_history), a `GET` to `/v1/balance/history`.
+    pub fn get_history(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::BalanceTransactionsList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/balance/history".to_string())
+    }
+
+    /// Mock [`Balance::get_history_balance`](crate::balance::Balance::get_history_balance), a `GET` to `/v1/balance/history/{id}`.
+    pub fn get_history_balance(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BalanceTransaction> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/balance/history/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct BalanceTransactions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BalanceTransactions<'a> {
+    /// Mock [`BalanceTransactions::get_page`](crate::balance_transactions::BalanceTransactions::get_page), a `GET` to `/v1/balance_transactions`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::BalanceTransactionsList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/balance_transactions".to_string())
+    }
+
+    /// Mock [`BalanceTransactions::get`](crate::balance_transactions::BalanceTransactions::get), a `GET` to `/v1/balance_transactions/{id}`.
+    pub fn get(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BalanceTransaction> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/balance_transactions/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct BillingPortal<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> BillingPortal<'a> {
+    /// Mock [`BillingPortal::get_configurations`](crate::billing_portal::BillingPortal::get_configurations), a `GET` to `/v1/billing_portal/configurations`.
+    pub fn get_configurations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetBillingPortalConfigurationsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/billing_portal/configurations".to_string(),
+        )
+    }
+
+    /// Mock [`BillingPortal::post_configuration`](crate::billing_portal::BillingPortal::post_configuration), a `POST` to `/v1/billing_portal/configurations`.
+    pub fn post_configuration(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PortalConfiguration> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/billing_portal/configurations".to_string(),
+        )
+    }
+
+    /// Mock [`BillingPortal::get_configurations_configuration`](crate::billing_portal::BillingPortal::get_configurations_configuration), a `GET` to `/v1/billing_portal/configurations/{configuration}`.
+    pub fn get_configurations_configuration(
+        &self,
+        configuration: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PortalConfiguration> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/billing_portal/configurations/{}",
+                crate::progenitor_support::encode_path(configuration)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPortal::post_configurations_configuration`](crate::billing_portal::BillingPortal::post_configurations_configuration), a `POST` to `/v1/billing_portal/configurations/{configuration}`.
+    pub fn post_configurations_configuration(
+        &self,
+        configuration: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PortalConfiguration> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/billing_portal/configurations/{}",
+                crate::progenitor_support::encode_path(configuration)
+            ),
+        )
+    }
+
+    /// Mock [`BillingPortal::post_session`](crate::billing_portal::BillingPortal::post_session), a `POST` to `/v1/billing_portal/sessions`.
+    pub fn post_session(&self) -> crate::mock::MockOperation<'a, crate::types::PortalSession> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/billing_portal/sessions".to_string(),
+        )
+    }
+}
+
+pub struct Bitcoin<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Bitcoin<'a> {
+    /// Mock [`Bitcoin::get_receivers`](crate::bitcoin::Bitcoin::get_receivers), a `GET` to `/v1/bitcoin/receivers`.
+    pub fn get_receivers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetBitcoinReceiversResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/bitcoin/receivers".to_string())
+    }
+
+    /// Mock [`Bitcoin::get_receiver`](crate::bitcoin::Bitcoin::get_receiver), a `GET` to `/v1/bitcoin/receivers/{id}`.
+    pub fn get_receiver(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BitcoinReceiver> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/bitcoin/receivers/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Bitcoin::get_receivers_receiver_transactions`](crate::bitcoin::Bitcoin::get_receivers_receiver_transactions), a `GET` to `/v1/bitcoin/receivers/{receiver}/transactions`.
+    pub fn get_receivers_receiver_transactions(
+        &self,
+        receiver: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Transactions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/bitcoin/receivers/{}/transactions",
+                crate::progenitor_support::encode_path(receiver)
+            ),
+        )
+    }
+
+    /// Mock [`Bitcoin::get_transactions`](crate::bitcoin::Bitcoin::get_transactions), a `GET` to `/v1/bitcoin/transactions`.
+    pub fn get_transactions(&self) -> crate::mock::MockOperation<'a, crate::types::Transactions> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/bitcoin/transactions".to_string())
+    }
+}
+
+pub struct Charges<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Charges<'a> {
+    /// Mock [`Charges::get_page`](crate::charges::Charges::get_page), a `GET` to `/v1/charges`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::Charges> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/charges".to_string())
+    }
+
+    /// Mock [`Charges::post`](crate::charges::Charges::post), a `POST` to `/v1/charges`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Charge> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/charges".to_string())
+    }
+
+    /// Mock [`Charges::get_search`](crate::charges::Charges::get_search), a `GET` to `/v1/charges/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/charges/search".to_string())
+    }
+
+    /// Mock [`Charges::get`](crate::charges::Charges::get), a `GET` to `/v1/charges/{charge}`.
+    pub fn get(&self, charge: &str) -> crate::mock::MockOperation<'a, crate::types::Charge> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/charges/{}",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_charges`](crate::charges::Charges::post_charges), a `POST` to `/v1/charges/{charge}`.
+    pub fn post_charges(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Charge> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_capture`](crate::charges::Charges::post_capture), a `POST` to `/v1/charges/{charge}/capture`.
+    pub fn post_capture(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Charge> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/capture",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::get_dispute`](crate::charges::Charges::get_dispute), a `GET` to `/v1/charges/{charge}/dispute`.
+    pub fn get_dispute(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/charges/{}/dispute",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_dispute`](crate::charges::Charges::post_dispute), a `POST` to `/v1/charges/{charge}/dispute`.
+    pub fn post_dispute(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/dispute",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_dispute_close`](crate::charges::Charges::post_dispute_close), a `POST` to `/v1/charges/{charge}/dispute/close`.
+    pub fn post_dispute_close(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/dispute/close",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_refund`](crate::charges::Charges::post_refund), a `POST` to `/v1/charges/{charge}/refund`.
+    pub fn post_refund(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Charge> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/refund",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::get_refunds`](crate::charges::Charges::get_refunds), a `GET` to `/v1/charges/{charge}/refunds`.
+    pub fn get_refunds(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RefundList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/charges/{}/refunds",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_refund_charges`](crate::charges::Charges::post_refund_charges), a `POST` to `/v1/charges/{charge}/refunds`.
+    pub fn post_refund_charges(
+        &self,
+        charge: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/refunds",
+                crate::progenitor_support::encode_path(charge)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::get_refunds_refund`](crate::charges::Charges::get_refunds_refund), a `GET` to `/v1/charges/{charge}/refunds/{refund}`.
+    pub fn get_refunds_refund(
+        &self,
+        charge: &str,
+        refund: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/charges/{}/refunds/{}",
+                crate::progenitor_support::encode_path(charge),
+                crate::progenitor_support::encode_path(refund)
+            ),
+        )
+    }
+
+    /// Mock [`Charges::post_refunds_refund`](crate::charges::Charges::post_refunds_refund), a `POST` to `/v1/charges/{charge}/refunds/{refund}`.
+    pub fn post_refunds_refund(
+        &self,
+        charge: &str,
+        refund: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/charges/{}/refunds/{}",
+                crate::progenitor_support::encode_path(charge),
+                crate::progenitor_support::encode_path(refund)
+            ),
+        )
+    }
+}
+
+pub struct Checkout<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Checkout<'a> {
+    /// Mock [`Checkout::get_sessions`](crate::checkout::Checkout::get_sessions), a `GET` to `/v1/checkout/sessions`.
+    pub fn get_sessions(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentPagesCheckoutSessionList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/checkout/sessions".to_string())
+    }
+
+    /// Mock [`Checkout::post_session`](crate::checkout::Checkout::post_session), a `POST` to `/v1/checkout/sessions`.
+    pub fn post_session(&self) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/checkout/sessions".to_string())
+    }
+
+    /// Mock [`Checkout::get_sessions_session`](crate::checkout::Checkout::get_sessions_session), a `GET` to `/v1/checkout/sessions/{session}`.
+    pub fn get_sessions_session(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/checkout/sessions/{}",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+
+    /// Mock [`Checkout::post_sessions_session_expire`](crate::checkout::Checkout::post_sessions_session_expire), a `POST` to `/v1/checkout/sessions/{session}/expire`.
+    pub fn post_sessions_session_expire(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Session> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/checkout/sessions/{}/expire",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+
+    /// Mock [`Checkout::get_sessions_session_line_items`](crate::checkout::Checkout::get_sessions_session_line_items), a `GET` to `/v1/checkout/sessions/{session}/line_items`.
+    pub fn get_sessions_session_line_items(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LineItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/checkout/sessions/{}/line_items",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+}
+
+pub struct CountrySpecs<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CountrySpecs<'a> {
+    /// Mock [`CountrySpecs::get_page`](crate::country_specs::CountrySpecs::get_page), a `GET` to `/v1/country_specs`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCountrySpecsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/country_specs".to_string())
+    }
+
+    /// Mock [`CountrySpecs::get`](crate::country_specs::CountrySpecs::get), a `GET` to `/v1/country_specs/{country}`.
+    pub fn get(&self, country: &str) -> crate::mock::MockOperation<'a, crate::types::CountrySpec> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/country_specs/{}",
+                crate::progenitor_support::encode_path(country)
+            ),
+        )
+    }
+}
+
+pub struct Coupons<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Coupons<'a> {
+    /// Mock [`Coupons::get_page`](crate::coupons::Coupons::get_page), a `GET` to `/v1/coupons`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetCouponsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/coupons".to_string())
+    }
+
+    /// Mock [`Coupons::post`](crate::coupons::Coupons::post), a `POST` to `/v1/coupons`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Coupon> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/coupons".to_string())
+    }
+
+    /// Mock [`Coupons::get`](crate::coupons::Coupons::get), a `GET` to `/v1/coupons/{coupon}`.
+    pub fn get(&self, coupon: &str) -> crate::mock::MockOperation<'a, crate::types::Coupon> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/coupons/{}",
+                crate::progenitor_support::encode_path(coupon)
+            ),
+        )
+    }
+
+    /// Mock [`Coupons::post_coupons`](crate::coupons::Coupons::post_coupons), a `POST` to `/v1/coupons/{coupon}`.
+    pub fn post_coupons(
+        &self,
+        coupon: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Coupon> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/coupons/{}",
+                crate::progenitor_support::encode_path(coupon)
+            ),
+        )
+    }
+
+    /// Mock [`Coupons::delete`](crate::coupons::Coupons::delete), a `DELETE` to `/v1/coupons/{coupon}`.
+    pub fn delete(
+        &self,
+        coupon: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedCoupon> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/coupons/{}",
+                crate::progenitor_support::encode_path(coupon)
+            ),
+        )
+    }
+}
+
+pub struct CreditNotes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CreditNotes<'a> {
+    /// Mock [`CreditNotes::get_page`](crate::credit_notes::CreditNotes::get_page), a `GET` to `/v1/credit_notes`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::CreditNotesList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/credit_notes".to_string())
+    }
+
+    /// Mock [`CreditNotes::post`](crate::credit_notes::CreditNotes::post), a `POST` to `/v1/credit_notes`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::CreditNote> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/credit_notes".to_string())
+    }
+
+    /// Mock [`CreditNotes::get_preview`](crate::credit_notes::CreditNotes::get_preview), a `GET` to `/v1/credit_notes/preview`.
+    pub fn get_preview(&self) -> crate::mock::MockOperation<'a, crate::types::CreditNote> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/credit_notes/preview".to_string())
+    }
+
+    /// Mock [`CreditNotes::get_preview_lines`](crate::credit_notes::CreditNotes::get_preview_lines), a `GET` to `/v1/credit_notes/preview/lines`.
+    pub fn get_preview_lines(&self) -> crate::mock::MockOperation<'a, crate::types::Lines> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/credit_notes/preview/lines".to_string(),
+        )
+    }
+
+    /// Mock [`CreditNotes::get_note_lines`](crate::credit_notes::CreditNotes::get_note_lines), a `GET` to `/v1/credit_notes/{credit_note}/lines`.
+    pub fn get_note_lines(
+        &self,
+        credit_note: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Lines> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/credit_notes/{}/lines",
+                crate::progenitor_support::encode_path(credit_note)
+            ),
+        )
+    }
+
+    /// Mock [`CreditNotes::get`](crate::credit_notes::CreditNotes::get), a `GET` to `/v1/credit_notes/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::CreditNote> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/credit_notes/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`CreditNotes::post_credit_notes`](crate::credit_notes::CreditNotes::post_credit_notes), a `POST` to `/v1/credit_notes/{id}`.
+    pub fn post_credit_notes(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CreditNote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/credit_notes/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`CreditNotes::post_void`](crate::credit_notes::CreditNotes::post_void), a `POST` to `/v1/credit_notes/{id}/void`.
+    pub fn post_void(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::CreditNote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/credit_notes/{}/void",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Customers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Customers<'a> {
+    /// Mock [`Customers::get_page`](crate::customers::Customers::get_page), a `GET` to `/v1/customers`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetCustomersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/customers".to_string())
+    }
+
+    /// Mock [`Customers::post`](crate::customers::Customers::post), a `POST` to `/v1/customers`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/customers".to_string())
+    }
+
+    /// Mock [`Customers::get_search`](crate::customers::Customers::get_search), a `GET` to `/v1/customers/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/customers/search".to_string())
+    }
+
+    /// Mock [`Customers::get`](crate::customers::Customers::get), a `GET` to `/v1/customers/{customer}`.
+    pub fn get(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCustomersCustomerResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_customers`](crate::customers::Customers::post_customers), a `POST` to `/v1/customers/{customer}`.
+    pub fn post_customers(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Customer> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete`](crate::customers::Customers::delete), a `DELETE` to `/v1/customers/{customer}`.
+    pub fn delete(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedCustomer> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_balance_transactions`](crate::customers::Customers::get_balance_transactions), a `GET` to `/v1/customers/{customer}/balance_transactions`.
+    pub fn get_balance_transactions(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomerBalanceTransactionList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/balance_transactions",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_balance_transaction`](crate::customers::Customers::post_balance_transaction), a `POST` to `/v1/customers/{customer}/balance_transactions`.
+    pub fn post_balance_transaction(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomerBalanceTransaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/balance_transactions",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_balance_transactions_transaction`](crate::customers::Customers::get_balance_transactions_transaction), a `GET` to `/v1/customers/{customer}/balance_transactions/{transaction}`.
+    pub fn get_balance_transactions_transaction(
+        &self,
+        customer: &str,
+        transaction: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomerBalanceTransaction> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/balance_transactions/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(transaction)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_balance_transactions_transaction`](crate::customers::Customers::post_balance_transactions_transaction), a `POST` to `/v1/customers/{customer}/balance_transactions/{transaction}`.
+    pub fn post_balance_transactions_transaction(
+        &self,
+        customer: &str,
+        transaction: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CustomerBalanceTransaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/balance_transactions/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(transaction)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_bank_accounts`](crate::customers::Customers::get_bank_accounts), a `GET` to `/v1/customers/{customer}/bank_accounts`.
+    pub fn get_bank_accounts(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BankAccountList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/bank_accounts",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_bank_account`](crate::customers::Customers::post_bank_account), a `POST` to `/v1/customers/{customer}/bank_accounts`.
+    pub fn post_bank_account(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentSourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/bank_accounts",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_bank_account`](crate::customers::Customers::get_bank_account), a `GET` to `/v1/customers/{customer}/bank_accounts/{id}`.
+    pub fn get_bank_account(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BankAccount> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_bank_account_customers`](crate::customers::Customers::post_bank_account_customers), a `POST` to `/v1/customers/{customer}/bank_accounts/{id}`.
+    pub fn post_bank_account_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_bank_accounts`](crate::customers::Customers::delete_bank_accounts), a `DELETE` to `/v1/customers/{customer}/bank_accounts/{id}`.
+    pub fn delete_bank_accounts(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteCustomersCustomerCardsResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/bank_accounts/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_bank_accounts_verify`](crate::customers::Customers::post_bank_accounts_verify), a `POST` to `/v1/customers/{customer}/bank_accounts/{id}/verify`.
+    pub fn post_bank_accounts_verify(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BankAccount> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/bank_accounts/{}/verify",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_cards`](crate::customers::Customers::get_cards), a `GET` to `/v1/customers/{customer}/cards`.
+    pub fn get_cards(&self, customer: &str) -> crate::mock::MockOperation<'a, crate::types::Cards> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/cards",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_card`](crate::customers::Customers::post_card), a `POST` to `/v1/customers/{customer}/cards`.
+    pub fn post_card(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentSourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/cards",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_card`](crate::customers::Customers::get_card), a `GET` to `/v1/customers/{customer}/cards/{id}`.
+    pub fn get_card(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Card> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/cards/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_card_customers`](crate::customers::Customers::post_card_customers), a `POST` to `/v1/customers/{customer}/cards/{id}`.
+    pub fn post_card_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/cards/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_cards`](crate::customers::Customers::delete_cards), a `DELETE` to `/v1/customers/{customer}/cards/{id}`.
+    pub fn delete_cards(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteCustomersCustomerCardsResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/cards/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_discount`](crate::customers::Customers::get_discount), a `GET` to `/v1/customers/{customer}/discount`.
+    pub fn get_discount(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DiscountData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/discount",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_discount`](crate::customers::Customers::delete_discount), a `DELETE` to `/v1/customers/{customer}/discount`.
+    pub fn delete_discount(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedDiscount> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/discount",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_payment_methods`](crate::customers::Customers::get_payment_methods), a `GET` to `/v1/customers/{customer}/payment_methods`.
+    pub fn get_payment_methods(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentFlowsMethodList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/payment_methods",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_sources`](crate::customers::Customers::get_sources), a `GET` to `/v1/customers/{customer}/sources`.
+    pub fn get_sources(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Sources> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/sources",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_source`](crate::customers::Customers::post_source), a `POST` to `/v1/customers/{customer}/sources`.
+    pub fn post_source(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentSourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/sources",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_source`](crate::customers::Customers::get_source), a `GET` to `/v1/customers/{customer}/sources/{id}`.
+    pub fn get_source(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentSourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/sources/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_source_customers`](crate::customers::Customers::post_source_customers), a `POST` to `/v1/customers/{customer}/sources/{id}`.
+    pub fn post_source_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/sources/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_sources`](crate::customers::Customers::delete_sources), a `DELETE` to `/v1/customers/{customer}/sources/{id}`.
+    pub fn delete_sources(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteCustomersCustomerCardsResponseAnyOf>
+    {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/sources/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_sources_verify`](crate::customers::Customers::post_sources_verify), a `POST` to `/v1/customers/{customer}/sources/{id}/verify`.
+    pub fn post_sources_verify(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BankAccount> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/sources/{}/verify",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_subscriptions`](crate::customers::Customers::get_subscriptions), a `GET` to `/v1/customers/{customer}/subscriptions`.
+    pub fn get_subscriptions(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscriptions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/subscriptions",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_subscription`](crate::customers::Customers::post_subscription), a `POST` to `/v1/customers/{customer}/subscriptions`.
+    pub fn post_subscription(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/subscriptions",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_subscriptions_subscription_exposed`](crate::customers::Customers::get_subscriptions_subscription_exposed), a `GET` to `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}`.
+    pub fn get_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/subscriptions/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_subscriptions_subscription_exposed`](crate::customers::Customers::post_subscriptions_subscription_exposed), a `POST` to `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}`.
+    pub fn post_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/subscriptions/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_subscriptions_subscription_exposed`](crate::customers::Customers::delete_subscriptions_subscription_exposed), a `DELETE` to `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}`.
+    pub fn delete_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/subscriptions/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_subscriptions_subscription_exposed_discount`](crate::customers::Customers::get_subscriptions_subscription_exposed_discount), a `GET` to `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}/discount`.
+    pub fn get_subscriptions_subscription_exposed_discount(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DiscountData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/subscriptions/{}/discount",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_subscriptions_subscription_exposed_discount`](crate::customers::Customers::delete_subscriptions_subscription_exposed_discount), a `DELETE` to `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}/discount`.
+    pub fn delete_subscriptions_subscription_exposed_discount(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedDiscount> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/subscriptions/{}/discount",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_tax_ids`](crate::customers::Customers::get_tax_ids), a `GET` to `/v1/customers/{customer}/tax_ids`.
+    pub fn get_tax_ids(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxIds> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/tax_ids",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::post_tax_id`](crate::customers::Customers::post_tax_id), a `POST` to `/v1/customers/{customer}/tax_ids`.
+    pub fn post_tax_id(
+        &self,
+        customer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxId> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/customers/{}/tax_ids",
+                crate::progenitor_support::encode_path(customer)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::get_tax_id`](crate::customers::Customers::get_tax_id), a `GET` to `/v1/customers/{customer}/tax_ids/{id}`.
+    pub fn get_tax_id(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxId> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/customers/{}/tax_ids/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Customers::delete_tax_ids`](crate::customers::Customers::delete_tax_ids), a `DELETE` to `/v1/customers/{customer}/tax_ids/{id}`.
+    pub fn delete_tax_ids(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedTaxId> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/customers/{}/tax_ids/{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Disputes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Disputes<'a> {
+    /// Mock [`Disputes::get_page`](crate::disputes::Disputes::get_page), a `GET` to `/v1/disputes`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetDisputesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/disputes".to_string())
+    }
+
+    /// Mock [`Disputes::get`](crate::disputes::Disputes::get), a `GET` to `/v1/disputes/{dispute}`.
+    pub fn get(&self, dispute: &str) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/disputes/{}",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+
+    /// Mock [`Disputes::post`](crate::disputes::Disputes::post), a `POST` to `/v1/disputes/{dispute}`.
+    pub fn post(&self, dispute: &str) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/disputes/{}",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+
+    /// Mock [`Disputes::post_close`](crate::disputes::Disputes::post_close), a `POST` to `/v1/disputes/{dispute}/close`.
+    pub fn post_close(
+        &self,
+        dispute: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Dispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/disputes/{}/close",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+}
+
+pub struct EphemeralKeys<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> EphemeralKeys<'a> {
+    /// Mock [`EphemeralKeys::post`](crate::ephemeral_keys::EphemeralKeys::post), a `POST` to `/v1/ephemeral_keys`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::EphemeralKey> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/ephemeral_keys".to_string())
+    }
+
+    /// Mock [`EphemeralKeys::delete_key`](crate::ephemeral_keys::EphemeralKeys::delete_key), a `DELETE` to `/v1/ephemeral_keys/{key}`.
+    pub fn delete_key(
+        &self,
+        key: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EphemeralKey> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/ephemeral_keys/{}",
+                crate::progenitor_support::encode_path(key)
+            ),
+        )
+    }
+}
+
+pub struct Events<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Events<'a> {
+    /// Mock [`Events::get_page`](crate::events::Events::get_page), a `GET` to `/v1/events`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::NotificationEventList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/events".to_string())
+    }
+
+    /// Mock [`Events::get`](crate::events::Events::get), a `GET` to `/v1/events/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Event> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/events/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+}
+
+pub struct ExchangeRates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ExchangeRates<'a> {
+    /// Mock [`ExchangeRates::get_page`](crate::exchange_rates::ExchangeRates::get_page), a `GET` to `/v1/exchange_rates`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetExchangeRatesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/exchange_rates".to_string())
+    }
+
+    /// Mock [`ExchangeRates::get_rate`](crate::exchange_rates::ExchangeRates::get_rate), a `GET` to `/v1/exchange_rates/{rate_id}`.
+    pub fn get_rate(
+        &self,
+        rate_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ExchangeRate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/exchange_rates/{}",
+                crate::progenitor_support::encode_path(rate_id)
+            ),
+        )
+    }
+}
+
+pub struct FileLinks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> FileLinks<'a> {
+    /// Mock [`FileLinks::get_page`](crate::file_links::FileLinks::get_page), a `GET` to `/v1/file_links`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::Links> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/file_links".to_string())
+    }
+
+    /// Mock [`FileLinks::post`](crate::file_links::FileLinks::post), a `POST` to `/v1/file_links`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::FileLink> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/file_links".to_string())
+    }
+
+    /// Mock [`FileLinks::get_link`](crate::file_links::FileLinks::get_link), a `GET` to `/v1/file_links/{link}`.
+    pub fn get_link(&self, link: &str) -> crate::mock::MockOperation<'a, crate::types::FileLink> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/file_links/{}",
+                crate::progenitor_support::encode_path(link)
+            ),
+        )
+    }
+
+    /// Mock [`FileLinks::post_link`](crate::file_links::FileLinks::post_link), a `POST` to `/v1/file_links/{link}`.
+    pub fn post_link(&self, link: &str) -> crate::mock::MockOperation<'a, crate::types::FileLink> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/file_links/{}",
+                crate::progenitor_support::encode_path(link)
+            ),
+        )
+    }
+}
+
+pub struct Files<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Files<'a> {
+    /// Mock [`Files::get_page`](crate::files::Files::get_page), a `GET` to `/v1/files`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetFilesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/files".to_string())
+    }
+
+    /// Mock [`Files::post`](crate::files::Files::post), a `POST` to `/v1/files`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/files".to_string())
+    }
+
+    /// Mock [`Files::get`](crate::files::Files::get), a `GET` to `/v1/files/{file}`.
+    pub fn get(&self, file: &str) -> crate::mock::MockOperation<'a, crate::types::File> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/files/{}", crate::progenitor_support::encode_path(file)),
+        )
+    }
+}
+
+pub struct Identity<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Identity<'a> {
+    /// Mock [`Identity::get_verification_reports`](crate::identity::Identity::get_verification_reports), a `GET` to `/v1/identity/verification_reports`.
+    pub fn get_verification_reports(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIdentityVerificationReportsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/identity/verification_reports".to_string(),
+        )
+    }
+
+    /// Mock [`Identity::get_verification_reports_report`](crate::identity::Identity::get_verification_reports_report), a `GET` to `/v1/identity/verification_reports/{report}`.
+    pub fn get_verification_reports_report(
+        &self,
+        report: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationReport> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/identity/verification_reports/{}",
+                crate::progenitor_support::encode_path(report)
+            ),
+        )
+    }
+
+    /// Mock [`Identity::get_verification_sessions`](crate::identity::Identity::get_verification_sessions), a `GET` to `/v1/identity/verification_sessions`.
+    pub fn get_verification_sessions(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIdentityVerificationSessionsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/identity/verification_sessions".to_string(),
+        )
+    }
+
+    /// Mock [`Identity::post_verification_session`](crate::identity::Identity::post_verification_session), a `POST` to `/v1/identity/verification_sessions`.
+    pub fn post_verification_session(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationSession> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/identity/verification_sessions".to_string(),
+        )
+    }
+
+    /// Mock [`Identity::get_verification_sessions_session`](crate::identity::Identity::get_verification_sessions_session), a `GET` to `/v1/identity/verification_sessions/{session}`.
+    pub fn get_verification_sessions_session(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationSession> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/identity/verification_sessions/{}",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+
+    /// Mock [`Identity::post_verification_sessions_session`](crate::identity::Identity::post_verification_sessions_session), a `POST` to `/v1/identity/verification_sessions/{session}`.
+    pub fn post_verification_sessions_session(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationSession> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/identity/verification_sessions/{}",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+
+    /// Mock [`Identity::post_verification_sessions_session_cancel`](crate::identity::Identity::post_verification_sessions_session_cancel), a `POST` to `/v1/identity/verification_sessions/{session}/cancel`.
+    pub fn post_verification_sessions_session_cancel(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationSession> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/identity/verification_sessions/{}/cancel",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+
+    /// Mock [`Identity::post_verification_sessions_session_redact`](crate::identity::Identity::post_verification_sessions_session_redact), a `POST` to `/v1/identity/verification_sessions/{session}/redact`.
+    pub fn post_verification_sessions_session_redact(
+        &self,
+        session: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GelatoVerificationSession> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/identity/verification_sessions/{}/redact",
+                crate::progenitor_support::encode_path(session)
+            ),
+        )
+    }
+}
+
+pub struct Invoiceitems<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Invoiceitems<'a> {
+    /// Mock [`Invoiceitems::get_page`](crate::invoiceitems::Invoiceitems::get_page), a `GET` to `/v1/invoiceitems`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetInvoiceitemsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/invoiceitems".to_string())
+    }
+
+    /// Mock [`Invoiceitems::post`](crate::invoiceitems::Invoiceitems::post), a `POST` to `/v1/invoiceitems`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::InvoiceItem> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/invoiceitems".to_string())
+    }
+
+    /// Mock [`Invoiceitems::get`](crate::invoiceitems::Invoiceitems::get), a `GET` to `/v1/invoiceitems/{invoiceitem}`.
+    pub fn get(
+        &self,
+        invoiceitem: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InvoiceItem> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/invoiceitems/{}",
+                crate::progenitor_support::encode_path(invoiceitem)
+            ),
+        )
+    }
+
+    /// Mock [`Invoiceitems::post_invoiceitems`](crate::invoiceitems::Invoiceitems::post_invoiceitems), a `POST` to `/v1/invoiceitems/{invoiceitem}`.
+    pub fn post_invoiceitems(
+        &self,
+        invoiceitem: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InvoiceItem> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoiceitems/{}",
+                crate::progenitor_support::encode_path(invoiceitem)
+            ),
+        )
+    }
+
+    /// Mock [`Invoiceitems::delete`](crate::invoiceitems::Invoiceitems::delete), a `DELETE` to `/v1/invoiceitems/{invoiceitem}`.
+    pub fn delete(
+        &self,
+        invoiceitem: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedInvoiceItem> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/invoiceitems/{}",
+                crate::progenitor_support::encode_path(invoiceitem)
+            ),
+        )
+    }
+}
+
+pub struct Invoices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Invoices<'a> {
+    /// Mock [`Invoices::get_page`](crate::invoices::Invoices::get_page), a `GET` to `/v1/invoices`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::InvoicesList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/invoices".to_string())
+    }
+
+    /// Mock [`Invoices::post`](crate::invoices::Invoices::post), a `POST` to `/v1/invoices`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/invoices".to_string())
+    }
+
+    /// Mock [`Invoices::get_search`](crate::invoices::Invoices::get_search), a `GET` to `/v1/invoices/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/invoices/search".to_string())
+    }
+
+    /// Mock [`Invoices::get_upcoming`](crate::invoices::Invoices::get_upcoming), a `GET` to `/v1/invoices/upcoming`.
+    pub fn get_upcoming(&self) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/invoices/upcoming".to_string())
+    }
+
+    /// Mock [`Invoices::get_upcoming_lines`](crate::invoices::Invoices::get_upcoming_lines), a `GET` to `/v1/invoices/upcoming/lines`.
+    pub fn get_upcoming_lines(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::InvoiceLinesList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/invoices/upcoming/lines".to_string(),
+        )
+    }
+
+    /// Mock [`Invoices::get`](crate::invoices::Invoices::get), a `GET` to `/v1/invoices/{invoice}`.
+    pub fn get(&self, invoice: &str) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/invoices/{}",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_invoices`](crate::invoices::Invoices::post_invoices), a `POST` to `/v1/invoices/{invoice}`.
+    pub fn post_invoices(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::delete`](crate::invoices::Invoices::delete), a `DELETE` to `/v1/invoices/{invoice}`.
+    pub fn delete(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedInvoice> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/invoices/{}",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_finalize`](crate::invoices::Invoices::post_finalize), a `POST` to `/v1/invoices/{invoice}/finalize`.
+    pub fn post_finalize(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}/finalize",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::get_lines`](crate::invoices::Invoices::get_lines), a `GET` to `/v1/invoices/{invoice}/lines`.
+    pub fn get_lines(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InvoiceLinesList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/invoices/{}/lines",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_mark_uncollectible`](crate::invoices::Invoices::post_mark_uncollectible), a `POST` to `/v1/invoices/{invoice}/mark_uncollectible`.
+    pub fn post_mark_uncollectible(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}/mark_uncollectible",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_pay`](crate::invoices::Invoices::post_pay), a `POST` to `/v1/invoices/{invoice}/pay`.
+    pub fn post_pay(&self, invoice: &str) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}/pay",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_send`](crate::invoices::Invoices::post_send), a `POST` to `/v1/invoices/{invoice}/send`.
+    pub fn post_send(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}/send",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+
+    /// Mock [`Invoices::post_void`](crate::invoices::Invoices::post_void), a `POST` to `/v1/invoices/{invoice}/void`.
+    pub fn post_void(
+        &self,
+        invoice: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Invoice> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/invoices/{}/void",
+                crate::progenitor_support::encode_path(invoice)
+            ),
+        )
+    }
+}
+
+pub struct IssuerFraudRecords<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> IssuerFraudRecords<'a> {
+    /// Mock [`IssuerFraudRecords::get_page`](crate::issuer_fraud_records::IssuerFraudRecords::get_page), a `GET` to `/v1/issuer_fraud_records`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarIssuerFraudRecordList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuer_fraud_records".to_string())
+    }
+
+    /// Mock [`IssuerFraudRecords::get_record`](crate::issuer_fraud_records::IssuerFraudRecords::get_record), a `GET` to `/v1/issuer_fraud_records/{issuer_fraud_record}`.
+    pub fn get_record(
+        &self,
+        issuer_fraud_record: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuerFraudRecord> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuer_fraud_records/{}",
+                crate::progenitor_support::encode_path(issuer_fraud_record)
+            ),
+        )
+    }
+}
+
+pub struct Issuing<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Issuing<'a> {
+    /// Mock [`Issuing::get_authorizations`](crate::issuing::Issuing::get_authorizations), a `GET` to `/v1/issuing/authorizations`.
+    pub fn get_authorizations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIssuingAuthorizationsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/issuing/authorizations".to_string(),
+        )
+    }
+
+    /// Mock [`Issuing::get_authorizations_authorization`](crate::issuing::Issuing::get_authorizations_authorization), a `GET` to `/v1/issuing/authorizations/{authorization}`.
+    pub fn get_authorizations_authorization(
+        &self,
+        authorization: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingAuthorization> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/authorizations/{}",
+                crate::progenitor_support::encode_path(authorization)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_authorizations_authorization`](crate::issuing::Issuing::post_authorizations_authorization), a `POST` to `/v1/issuing/authorizations/{authorization}`.
+    pub fn post_authorizations_authorization(
+        &self,
+        authorization: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingAuthorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/authorizations/{}",
+                crate::progenitor_support::encode_path(authorization)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_authorizations_authorization_approve`](crate::issuing::Issuing::post_authorizations_authorization_approve), a `POST` to `/v1/issuing/authorizations/{authorization}/approve`.
+    pub fn post_authorizations_authorization_approve(
+        &self,
+        authorization: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingAuthorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/authorizations/{}/approve",
+                crate::progenitor_support::encode_path(authorization)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_authorizations_authorization_decline`](crate::issuing::Issuing::post_authorizations_authorization_decline), a `POST` to `/v1/issuing/authorizations/{authorization}/decline`.
+    pub fn post_authorizations_authorization_decline(
+        &self,
+        authorization: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingAuthorization> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/authorizations/{}/decline",
+                crate::progenitor_support::encode_path(authorization)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::get_cardholders`](crate::issuing::Issuing::get_cardholders), a `GET` to `/v1/issuing/cardholders`.
+    pub fn get_cardholders(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIssuingCardholdersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuing/cardholders".to_string())
+    }
+
+    /// Mock [`Issuing::post_cardholder`](crate::issuing::Issuing::post_cardholder), a `POST` to `/v1/issuing/cardholders`.
+    pub fn post_cardholder(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingCardholder> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/issuing/cardholders".to_string())
+    }
+
+    /// Mock [`Issuing::get_cardholders_cardholder`](crate::issuing::Issuing::get_cardholders_cardholder), a `GET` to `/v1/issuing/cardholders/{cardholder}`.
+    pub fn get_cardholders_cardholder(
+        &self,
+        cardholder: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingCardholder> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/cardholders/{}",
+                crate::progenitor_support::encode_path(cardholder)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_cardholders_cardholder`](crate::issuing::Issuing::post_cardholders_cardholder), a `POST` to `/v1/issuing/cardholders/{cardholder}`.
+    pub fn post_cardholders_cardholder(
+        &self,
+        cardholder: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingCardholder> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/cardholders/{}",
+                crate::progenitor_support::encode_path(cardholder)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::get_cards`](crate::issuing::Issuing::get_cards), a `GET` to `/v1/issuing/cards`.
+    pub fn get_cards(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIssuingCardsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuing/cards".to_string())
+    }
+
+    /// Mock [`Issuing::post_card`](crate::issuing::Issuing::post_card), a `POST` to `/v1/issuing/cards`.
+    pub fn post_card(&self) -> crate::mock::MockOperation<'a, crate::types::IssuingCard> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/issuing/cards".to_string())
+    }
+
+    /// Mock [`Issuing::get_cards_card`](crate::issuing::Issuing::get_cards_card), a `GET` to `/v1/issuing/cards/{card}`.
+    pub fn get_cards_card(
+        &self,
+        card: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingCard> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/cards/{}",
+                crate::progenitor_support::encode_path(card)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_cards_card`](crate::issuing::Issuing::post_cards_card), a `POST` to `/v1/issuing/cards/{card}`.
+    pub fn post_cards_card(
+        &self,
+        card: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingCard> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/cards/{}",
+                crate::progenitor_support::encode_path(card)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::get_disputes`](crate::issuing::Issuing::get_disputes), a `GET` to `/v1/issuing/disputes`.
+    pub fn get_disputes(&self) -> crate::mock::MockOperation<'a, crate::types::IssuingDisputeList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuing/disputes".to_string())
+    }
+
+    /// Mock [`Issuing::post_dispute`](crate::issuing::Issuing::post_dispute), a `POST` to `/v1/issuing/disputes`.
+    pub fn post_dispute(&self) -> crate::mock::MockOperation<'a, crate::types::IssuingDispute> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/issuing/disputes".to_string())
+    }
+
+    /// Mock [`Issuing::get_disputes_dispute`](crate::issuing::Issuing::get_disputes_dispute), a `GET` to `/v1/issuing/disputes/{dispute}`.
+    pub fn get_disputes_dispute(
+        &self,
+        dispute: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingDispute> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/disputes/{}",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_disputes_dispute`](crate::issuing::Issuing::post_disputes_dispute), a `POST` to `/v1/issuing/disputes/{dispute}`.
+    pub fn post_disputes_dispute(
+        &self,
+        dispute: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingDispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/disputes/{}",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_disputes_dispute_submit`](crate::issuing::Issuing::post_disputes_dispute_submit), a `POST` to `/v1/issuing/disputes/{dispute}/submit`.
+    pub fn post_disputes_dispute_submit(
+        &self,
+        dispute: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingDispute> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/disputes/{}/submit",
+                crate::progenitor_support::encode_path(dispute)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::get_settlements`](crate::issuing::Issuing::get_settlements), a `GET` to `/v1/issuing/settlements`.
+    pub fn get_settlements(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIssuingSettlementsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuing/settlements".to_string())
+    }
+
+    /// Mock [`Issuing::get_settlements_settlement`](crate::issuing::Issuing::get_settlements_settlement), a `GET` to `/v1/issuing/settlements/{settlement}`.
+    pub fn get_settlements_settlement(
+        &self,
+        settlement: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingSettlement> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/settlements/{}",
+                crate::progenitor_support::encode_path(settlement)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_settlements_settlement`](crate::issuing::Issuing::post_settlements_settlement), a `POST` to `/v1/issuing/settlements/{settlement}`.
+    pub fn post_settlements_settlement(
+        &self,
+        settlement: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingSettlement> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/settlements/{}",
+                crate::progenitor_support::encode_path(settlement)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::get_transactions`](crate::issuing::Issuing::get_transactions), a `GET` to `/v1/issuing/transactions`.
+    pub fn get_transactions(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetIssuingTransactionsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/issuing/transactions".to_string())
+    }
+
+    /// Mock [`Issuing::get_transactions_transaction`](crate::issuing::Issuing::get_transactions_transaction), a `GET` to `/v1/issuing/transactions/{transaction}`.
+    pub fn get_transactions_transaction(
+        &self,
+        transaction: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingTransaction> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/issuing/transactions/{}",
+                crate::progenitor_support::encode_path(transaction)
+            ),
+        )
+    }
+
+    /// Mock [`Issuing::post_transactions_transaction`](crate::issuing::Issuing::post_transactions_transaction), a `POST` to `/v1/issuing/transactions/{transaction}`.
+    pub fn post_transactions_transaction(
+        &self,
+        transaction: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::IssuingTransaction> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/issuing/transactions/{}",
+                crate::progenitor_support::encode_path(transaction)
+            ),
+        )
+    }
+}
+
+pub struct Mandates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Mandates<'a> {
+    /// Mock [`Mandates::get`](crate::mandates::Mandates::get), a `GET` to `/v1/mandates/{mandate}`.
+    pub fn get(&self, mandate: &str) -> crate::mock::MockOperation<'a, crate::types::Mandate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/mandates/{}",
+                crate::progenitor_support::encode_path(mandate)
+            ),
+        )
+    }
+}
+
+pub struct OrderReturns<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> OrderReturns<'a> {
+    /// Mock [`OrderReturns::get_page`](crate::order_returns::OrderReturns::get_page), a `GET` to `/v1/order_returns`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::Returns> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/order_returns".to_string())
+    }
+
+    /// Mock [`OrderReturns::get`](crate::order_returns::OrderReturns::get), a `GET` to `/v1/order_returns/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::OrderReturn> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/order_returns/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Orders<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Orders<'a> {
+    /// Mock [`Orders::get_page`](crate::orders::Orders::get_page), a `GET` to `/v1/orders`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrdersLegacyResourceOrderList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/orders".to_string())
+    }
+
+    /// Mock [`Orders::post`](crate::orders::Orders::post), a `POST` to `/v1/orders`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/orders".to_string())
+    }
+
+    /// Mock [`Orders::get`](crate::orders::Orders::get), a `GET` to `/v1/orders/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/orders/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Orders::post_orders`](crate::orders::Orders::post_orders), a `POST` to `/v1/orders/{id}`.
+    pub fn post_orders(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/v1/orders/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Orders::post_pay`](crate::orders::Orders::post_pay), a `POST` to `/v1/orders/{id}/pay`.
+    pub fn post_pay(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Order> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/orders/{}/pay",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Orders::post_return`](crate::orders::Orders::post_return), a `POST` to `/v1/orders/{id}/returns`.
+    pub fn post_return(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::OrderReturn> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/orders/{}/returns",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct PaymentIntents<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PaymentIntents<'a> {
+    /// Mock [`PaymentIntents::get_page`](crate::payment_intents::PaymentIntents::get_page), a `GET` to `/v1/payment_intents`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::PaymentFlowsIntentList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/payment_intents".to_string())
+    }
+
+    /// Mock [`PaymentIntents::post`](crate::payment_intents::PaymentIntents::post), a `POST` to `/v1/payment_intents`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/payment_intents".to_string())
+    }
+
+    /// Mock [`PaymentIntents::get_search`](crate::payment_intents::PaymentIntents::get_search), a `GET` to `/v1/payment_intents/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/payment_intents/search".to_string(),
+        )
+    }
+
+    /// Mock [`PaymentIntents::get_intent`](crate::payment_intents::PaymentIntents::get_intent), a `GET` to `/v1/payment_intents/{intent}`.
+    pub fn get_intent(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/payment_intents/{}",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentIntents::post_intent`](crate::payment_intents::PaymentIntents::post_intent), a `POST` to `/v1/payment_intents/{intent}`.
+    pub fn post_intent(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_intents/{}",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentIntents::post_intent_cancel`](crate::payment_intents::PaymentIntents::post_intent_cancel), a `POST` to `/v1/payment_intents/{intent}/cancel`.
+    pub fn post_intent_cancel(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_intents/{}/cancel",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentIntents::post_intent_capture`](crate::payment_intents::PaymentIntents::post_intent_capture), a `POST` to `/v1/payment_intents/{intent}/capture`.
+    pub fn post_intent_capture(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_intents/{}/capture",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentIntents::post_intent_confirm`](crate::payment_intents::PaymentIntents::post_intent_confirm), a `POST` to `/v1/payment_intents/{intent}/confirm`.
+    pub fn post_intent_confirm(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_intents/{}/confirm",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentIntents::post_intent_verify_microdeposit`](crate::payment_intents::PaymentIntents::post_intent_verify_microdeposit), a `POST` to `/v1/payment_intents/{intent}/verify_microdeposits`.
+    pub fn post_intent_verify_microdeposit(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_intents/{}/verify_microdeposits",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+}
+
+pub struct PaymentLinks<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PaymentLinks<'a> {
+    /// Mock [`PaymentLinks::get_page`](crate::payment_links::PaymentLinks::get_page), a `GET` to `/v1/payment_links`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPaymentLinksResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/payment_links".to_string())
+    }
+
+    /// Mock [`PaymentLinks::post`](crate::payment_links::PaymentLinks::post), a `POST` to `/v1/payment_links`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PaymentLink> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/payment_links".to_string())
+    }
+
+    /// Mock [`PaymentLinks::get_link`](crate::payment_links::PaymentLinks::get_link), a `GET` to `/v1/payment_links/{payment_link}`.
+    pub fn get_link(
+        &self,
+        payment_link: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentLink> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/payment_links/{}",
+                crate::progenitor_support::encode_path(payment_link)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentLinks::post_link`](crate::payment_links::PaymentLinks::post_link), a `POST` to `/v1/payment_links/{payment_link}`.
+    pub fn post_link(
+        &self,
+        payment_link: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentLink> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_links/{}",
+                crate::progenitor_support::encode_path(payment_link)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentLinks::get_link_line_items`](crate::payment_links::PaymentLinks::get_link_line_items), a `GET` to `/v1/payment_links/{payment_link}/line_items`.
+    pub fn get_link_line_items(
+        &self,
+        payment_link: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LineItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/payment_links/{}/line_items",
+                crate::progenitor_support::encode_path(payment_link)
+            ),
+        )
+    }
+}
+
+pub struct PaymentMethods<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PaymentMethods<'a> {
+    /// Mock [`PaymentMethods::get_page`](crate::payment_methods::PaymentMethods::get_page), a `GET` to `/v1/payment_methods`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::PaymentFlowsMethodList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/payment_methods".to_string())
+    }
+
+    /// Mock [`PaymentMethods::post`](crate::payment_methods::PaymentMethods::post), a `POST` to `/v1/payment_methods`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PaymentMethod> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/payment_methods".to_string())
+    }
+
+    /// Mock [`PaymentMethods::get_method`](crate::payment_methods::PaymentMethods::get_method), a `GET` to `/v1/payment_methods/{payment_method}`.
+    pub fn get_method(
+        &self,
+        payment_method: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentMethod> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/payment_methods/{}",
+                crate::progenitor_support::encode_path(payment_method)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentMethods::post_method`](crate::payment_methods::PaymentMethods::post_method), a `POST` to `/v1/payment_methods/{payment_method}`.
+    pub fn post_method(
+        &self,
+        payment_method: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentMethod> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_methods/{}",
+                crate::progenitor_support::encode_path(payment_method)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentMethods::post_method_attach`](crate::payment_methods::PaymentMethods::post_method_attach), a `POST` to `/v1/payment_methods/{payment_method}/attach`.
+    pub fn post_method_attach(
+        &self,
+        payment_method: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentMethod> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_methods/{}/attach",
+                crate::progenitor_support::encode_path(payment_method)
+            ),
+        )
+    }
+
+    /// Mock [`PaymentMethods::post_method_detach`](crate::payment_methods::PaymentMethods::post_method_detach), a `POST` to `/v1/payment_methods/{payment_method}/detach`.
+    pub fn post_method_detach(
+        &self,
+        payment_method: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentMethod> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payment_methods/{}/detach",
+                crate::progenitor_support::encode_path(payment_method)
+            ),
+        )
+    }
+}
+
+pub struct Payouts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Payouts<'a> {
+    /// Mock [`Payouts::get_page`](crate::payouts::Payouts::get_page), a `GET` to `/v1/payouts`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::PayoutList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/payouts".to_string())
+    }
+
+    /// Mock [`Payouts::post`](crate::payouts::Payouts::post), a `POST` to `/v1/payouts`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Payout> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/payouts".to_string())
+    }
+
+    /// Mock [`Payouts::get`](crate::payouts::Payouts::get), a `GET` to `/v1/payouts/{payout}`.
+    pub fn get(&self, payout: &str) -> crate::mock::MockOperation<'a, crate::types::Payout> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/payouts/{}",
+                crate::progenitor_support::encode_path(payout)
+            ),
+        )
+    }
+
+    /// Mock [`Payouts::post_payouts`](crate::payouts::Payouts::post_payouts), a `POST` to `/v1/payouts/{payout}`.
+    pub fn post_payouts(
+        &self,
+        payout: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Payout> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payouts/{}",
+                crate::progenitor_support::encode_path(payout)
+            ),
+        )
+    }
+
+    /// Mock [`Payouts::post_cancel`](crate::payouts::Payouts::post_cancel), a `POST` to `/v1/payouts/{payout}/cancel`.
+    pub fn post_cancel(
+        &self,
+        payout: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Payout> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payouts/{}/cancel",
+                crate::progenitor_support::encode_path(payout)
+            ),
+        )
+    }
+
+    /// Mock [`Payouts::post_reverse`](crate::payouts::Payouts::post_reverse), a `POST` to `/v1/payouts/{payout}/reverse`.
+    pub fn post_reverse(
+        &self,
+        payout: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Payout> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/payouts/{}/reverse",
+                crate::progenitor_support::encode_path(payout)
+            ),
+        )
+    }
+}
+
+pub struct Plans<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Plans<'a> {
+    /// Mock [`Plans::get_page`](crate::plans::Plans::get_page), a `GET` to `/v1/plans`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::PlanList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/plans".to_string())
+    }
+
+    /// Mock [`Plans::post`](crate::plans::Plans::post), a `POST` to `/v1/plans`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PlanData> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/plans".to_string())
+    }
+
+    /// Mock [`Plans::get`](crate::plans::Plans::get), a `GET` to `/v1/plans/{plan}`.
+    pub fn get(&self, plan: &str) -> crate::mock::MockOperation<'a, crate::types::PlanData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan)),
+        )
+    }
+
+    /// Mock [`Plans::post_plans`](crate::plans::Plans::post_plans), a `POST` to `/v1/plans/{plan}`.
+    pub fn post_plans(&self, plan: &str) -> crate::mock::MockOperation<'a, crate::types::PlanData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan)),
+        )
+    }
+
+    /// Mock [`Plans::delete`](crate::plans::Plans::delete), a `DELETE` to `/v1/plans/{plan}`.
+    pub fn delete(&self, plan: &str) -> crate::mock::MockOperation<'a, crate::types::DeletedPlan> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan)),
+        )
+    }
+}
+
+pub struct Prices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Prices<'a> {
+    /// Mock [`Prices::get_page`](crate::prices::Prices::get_page), a `GET` to `/v1/prices`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::PriceList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/prices".to_string())
+    }
+
+    /// Mock [`Prices::post`](crate::prices::Prices::post), a `POST` to `/v1/prices`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PriceData> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/prices".to_string())
+    }
+
+    /// Mock [`Prices::get_search`](crate::prices::Prices::get_search), a `GET` to `/v1/prices/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/prices/search".to_string())
+    }
+
+    /// Mock [`Prices::get`](crate::prices::Prices::get), a `GET` to `/v1/prices/{price}`.
+    pub fn get(&self, price: &str) -> crate::mock::MockOperation<'a, crate::types::PriceData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/prices/{}",
+                crate::progenitor_support::encode_path(price)
+            ),
+        )
+    }
+
+    /// Mock [`Prices::post_prices`](crate::prices::Prices::post_prices), a `POST` to `/v1/prices/{price}`.
+    pub fn post_prices(
+        &self,
+        price: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PriceData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/prices/{}",
+                crate::progenitor_support::encode_path(price)
+            ),
+        )
+    }
+}
+
+pub struct Products<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Products<'a> {
+    /// Mock [`Products::get_page`](crate::products::Products::get_page), a `GET` to `/v1/products`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::ProductList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/products".to_string())
+    }
+
+    /// Mock [`Products::post`](crate::products::Products::post), a `POST` to `/v1/products`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Product> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/products".to_string())
+    }
+
+    /// Mock [`Products::get_search`](crate::products::Products::get_search), a `GET` to `/v1/products/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/products/search".to_string())
+    }
+
+    /// Mock [`Products::get`](crate::products::Products::get), a `GET` to `/v1/products/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Product> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/products/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::post_products`](crate::products::Products::post_products), a `POST` to `/v1/products/{id}`.
+    pub fn post_products(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Product> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/products/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Products::delete`](crate::products::Products::delete), a `DELETE` to `/v1/products/{id}`.
+    pub fn delete(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::DeletedProduct> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/products/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct PromotionCodes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PromotionCodes<'a> {
+    /// Mock [`PromotionCodes::get_page`](crate::promotion_codes::PromotionCodes::get_page), a `GET` to `/v1/promotion_codes`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPromotionCodesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/promotion_codes".to_string())
+    }
+
+    /// Mock [`PromotionCodes::post`](crate::promotion_codes::PromotionCodes::post), a `POST` to `/v1/promotion_codes`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::PromotionCode> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/promotion_codes".to_string())
+    }
+
+    /// Mock [`PromotionCodes::get_code`](crate::promotion_codes::PromotionCodes::get_code), a `GET` to `/v1/promotion_codes/{promotion_code}`.
+    pub fn get_code(
+        &self,
+        promotion_code: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromotionCode> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/promotion_codes/{}",
+                crate::progenitor_support::encode_path(promotion_code)
+            ),
+        )
+    }
+
+    /// Mock [`PromotionCodes::post_code`](crate::promotion_codes::PromotionCodes::post_code), a `POST` to `/v1/promotion_codes/{promotion_code}`.
+    pub fn post_code(
+        &self,
+        promotion_code: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PromotionCode> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/promotion_codes/{}",
+                crate::progenitor_support::encode_path(promotion_code)
+            ),
+        )
+    }
+}
+
+pub struct Quotes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Quotes<'a> {
+    /// Mock [`Quotes::get_page`](crate::quotes::Quotes::get_page), a `GET` to `/v1/quotes`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetQuotesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/quotes".to_string())
+    }
+
+    /// Mock [`Quotes::post`](crate::quotes::Quotes::post), a `POST` to `/v1/quotes`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/quotes".to_string())
+    }
+
+    /// Mock [`Quotes::get`](crate::quotes::Quotes::get), a `GET` to `/v1/quotes/{quote}`.
+    pub fn get(&self, quote: &str) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/quotes/{}",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::post_quotes`](crate::quotes::Quotes::post_quotes), a `POST` to `/v1/quotes/{quote}`.
+    pub fn post_quotes(&self, quote: &str) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/quotes/{}",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::post_accept`](crate::quotes::Quotes::post_accept), a `POST` to `/v1/quotes/{quote}/accept`.
+    pub fn post_accept(&self, quote: &str) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/quotes/{}/accept",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::post_cancel`](crate::quotes::Quotes::post_cancel), a `POST` to `/v1/quotes/{quote}/cancel`.
+    pub fn post_cancel(&self, quote: &str) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/quotes/{}/cancel",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::get_computed_upfront_line_items`](crate::quotes::Quotes::get_computed_upfront_line_items), a `GET` to `/v1/quotes/{quote}/computed_upfront_line_items`.
+    pub fn get_computed_upfront_line_items(
+        &self,
+        quote: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LineItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/quotes/{}/computed_upfront_line_items",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::post_finalize`](crate::quotes::Quotes::post_finalize), a `POST` to `/v1/quotes/{quote}/finalize`.
+    pub fn post_finalize(
+        &self,
+        quote: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Quote> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/quotes/{}/finalize",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::get_line_items`](crate::quotes::Quotes::get_line_items), a `GET` to `/v1/quotes/{quote}/line_items`.
+    pub fn get_line_items(
+        &self,
+        quote: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::LineItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/quotes/{}/line_items",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+
+    /// Mock [`Quotes::get_pdf`](crate::quotes::Quotes::get_pdf), a `GET` to `/v1/quotes/{quote}/pdf`.
+    pub fn get_pdf(&self, quote: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/quotes/{}/pdf",
+                crate::progenitor_support::encode_path(quote)
+            ),
+        )
+    }
+}
+
+pub struct Radar<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Radar<'a> {
+    /// Mock [`Radar::get_early_fraud_warnings`](crate::radar::Radar::get_early_fraud_warnings), a `GET` to `/v1/radar/early_fraud_warnings`.
+    pub fn get_early_fraud_warnings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarEarlyFraudWarningList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/radar/early_fraud_warnings".to_string(),
+        )
+    }
+
+    /// Mock [`Radar::get_early_fraud_warnings_warning`](crate::radar::Radar::get_early_fraud_warnings_warning), a `GET` to `/v1/radar/early_fraud_warnings/{early_fraud_warning}`.
+    pub fn get_early_fraud_warnings_warning(
+        &self,
+        early_fraud_warning: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarEarlyFraudWarning> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/radar/early_fraud_warnings/{}",
+                crate::progenitor_support::encode_path(early_fraud_warning)
+            ),
+        )
+    }
+
+    /// Mock [`Radar::get_value_list_items`](crate::radar::Radar::get_value_list_items), a `GET` to `/v1/radar/value_list_items`.
+    pub fn get_value_list_items(&self) -> crate::mock::MockOperation<'a, crate::types::ListItems> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/radar/value_list_items".to_string(),
+        )
+    }
+
+    /// Mock [`Radar::post_value_list_item`](crate::radar::Radar::post_value_list_item), a `POST` to `/v1/radar/value_list_items`.
+    pub fn post_value_list_item(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarListItem> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/radar/value_list_items".to_string(),
+        )
+    }
+
+    /// Mock [`Radar::get_value_list_items_item`](crate::radar::Radar::get_value_list_items_item), a `GET` to `/v1/radar/value_list_items/{item}`.
+    pub fn get_value_list_items_item(
+        &self,
+        item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarListItem> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/radar/value_list_items/{}",
+                crate::progenitor_support::encode_path(item)
+            ),
+        )
+    }
+
+    /// Mock [`Radar::delete_value_list_items_item`](crate::radar::Radar::delete_value_list_items_item), a `DELETE` to `/v1/radar/value_list_items/{item}`.
+    pub fn delete_value_list_items_item(
+        &self,
+        item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarListDeletedItem> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/radar/value_list_items/{}",
+                crate::progenitor_support::encode_path(item)
+            ),
+        )
+    }
+
+    /// Mock [`Radar::get_value_lists`](crate::radar::Radar::get_value_lists), a `GET` to `/v1/radar/value_lists`.
+    pub fn get_value_lists(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetRadarValueListsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/radar/value_lists".to_string())
+    }
+
+    /// Mock [`Radar::post_value_list`](crate::radar::Radar::post_value_list), a `POST` to `/v1/radar/value_lists`.
+    pub fn post_value_list(&self) -> crate::mock::MockOperation<'a, crate::types::RadarList> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/radar/value_lists".to_string())
+    }
+
+    /// Mock [`Radar::get_value_lists_list`](crate::radar::Radar::get_value_lists_list), a `GET` to `/v1/radar/value_lists/{value_list}`.
+    pub fn get_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/radar/value_lists/{}",
+                crate::progenitor_support::encode_path(value_list)
+            ),
+        )
+    }
+
+    /// Mock [`Radar::post_value_lists_list`](crate::radar::Radar::post_value_lists_list), a `POST` to `/v1/radar/value_lists/{value_list}`.
+    pub fn post_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarList> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/radar/value_lists/{}",
+                crate::progenitor_support::encode_path(value_list)
+            ),
+        )
+    }
+
+    /// Mock [`Radar::delete_value_lists_list`](crate::radar::Radar::delete_value_lists_list), a `DELETE` to `/v1/radar/value_lists/{value_list}`.
+    pub fn delete_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RadarListDeleted> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/radar/value_lists/{}",
+                crate::progenitor_support::encode_path(value_list)
+            ),
+        )
+    }
+}
+
+pub struct Recipients<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Recipients<'a> {
+    /// Mock [`Recipients::get_page`](crate::recipients::Recipients::get_page), a `GET` to `/v1/recipients`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetRecipientsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/recipients".to_string())
+    }
+
+    /// Mock [`Recipients::post`](crate::recipients::Recipients::post), a `POST` to `/v1/recipients`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Recipient> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/recipients".to_string())
+    }
+
+    /// Mock [`Recipients::get`](crate::recipients::Recipients::get), a `GET` to `/v1/recipients/{id}`.
+    pub fn get(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetRecipientsResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/recipients/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Recipients::post_recipients`](crate::recipients::Recipients::post_recipients), a `POST` to `/v1/recipients/{id}`.
+    pub fn post_recipients(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Recipient> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/recipients/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Recipients::delete`](crate::recipients::Recipients::delete), a `DELETE` to `/v1/recipients/{id}`.
+    pub fn delete(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedRecipient> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/recipients/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct Refunds<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Refunds<'a> {
+    /// Mock [`Refunds::get_page`](crate::refunds::Refunds::get_page), a `GET` to `/v1/refunds`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::RefundList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/refunds".to_string())
+    }
+
+    /// Mock [`Refunds::post`](crate::refunds::Refunds::post), a `POST` to `/v1/refunds`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/refunds".to_string())
+    }
+
+    /// Mock [`Refunds::get`](crate::refunds::Refunds::get), a `GET` to `/v1/refunds/{refund}`.
+    pub fn get(&self, refund: &str) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/refunds/{}",
+                crate::progenitor_support::encode_path(refund)
+            ),
+        )
+    }
+
+    /// Mock [`Refunds::post_refunds`](crate::refunds::Refunds::post_refunds), a `POST` to `/v1/refunds/{refund}`.
+    pub fn post_refunds(
+        &self,
+        refund: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/refunds/{}",
+                crate::progenitor_support::encode_path(refund)
+            ),
+        )
+    }
+
+    /// Mock [`Refunds::post_cancel`](crate::refunds::Refunds::post_cancel), a `POST` to `/v1/refunds/{refund}/cancel`.
+    pub fn post_cancel(
+        &self,
+        refund: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Refund> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/refunds/{}/cancel",
+                crate::progenitor_support::encode_path(refund)
+            ),
+        )
+    }
+}
+
+pub struct Reporting<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reporting<'a> {
+    /// Mock [`Reporting::get_report_runs`](crate::reporting::Reporting::get_report_runs), a `GET` to `/v1/reporting/report_runs`.
+    pub fn get_report_runs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetReportingReportRunsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/reporting/report_runs".to_string(),
+        )
+    }
+
+    /// Mock [`Reporting::post_report_run`](crate::reporting::Reporting::post_report_run), a `POST` to `/v1/reporting/report_runs`.
+    pub fn post_report_run(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportingReportRun> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/reporting/report_runs".to_string(),
+        )
+    }
+
+    /// Mock [`Reporting::get_report_runs_run`](crate::reporting::Reporting::get_report_runs_run), a `GET` to `/v1/reporting/report_runs/{report_run}`.
+    pub fn get_report_runs_run(
+        &self,
+        report_run: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportingReportRun> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/reporting/report_runs/{}",
+                crate::progenitor_support::encode_path(report_run)
+            ),
+        )
+    }
+
+    /// Mock [`Reporting::get_report_types`](crate::reporting::Reporting::get_report_types), a `GET` to `/v1/reporting/report_types`.
+    pub fn get_report_types(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::FinancialReportingFinanceReportTypeList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/reporting/report_types".to_string(),
+        )
+    }
+
+    /// Mock [`Reporting::get_report_types_type`](crate::reporting::Reporting::get_report_types_type), a `GET` to `/v1/reporting/report_types/{report_type}`.
+    pub fn get_report_types_type(
+        &self,
+        report_type: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportingReportType> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/reporting/report_types/{}",
+                crate::progenitor_support::encode_path(report_type)
+            ),
+        )
+    }
+}
+
+pub struct Reviews<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reviews<'a> {
+    /// Mock [`Reviews::get_page`](crate::reviews::Reviews::get_page), a `GET` to `/v1/reviews`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetReviewsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/reviews".to_string())
+    }
+
+    /// Mock [`Reviews::get`](crate::reviews::Reviews::get), a `GET` to `/v1/reviews/{review}`.
+    pub fn get(&self, review: &str) -> crate::mock::MockOperation<'a, crate::types::Review> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/reviews/{}",
+                crate::progenitor_support::encode_path(review)
+            ),
+        )
+    }
+
+    /// Mock [`Reviews::post_approve`](crate::reviews::Reviews::post_approve), a `POST` to `/v1/reviews/{review}/approve`.
+    pub fn post_approve(
+        &self,
+        review: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Review> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/reviews/{}/approve",
+                crate::progenitor_support::encode_path(review)
+            ),
+        )
+    }
+}
+
+pub struct SetupAttempts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SetupAttempts<'a> {
+    /// Mock [`SetupAttempts::get_page`](crate::setup_attempts::SetupAttempts::get_page), a `GET` to `/v1/setup_attempts`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentFlowsSetupIntentAttemptList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/setup_attempts".to_string())
+    }
+}
+
+pub struct SetupIntents<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SetupIntents<'a> {
+    /// Mock [`SetupIntents::get_page`](crate::setup_intents::SetupIntents::get_page), a `GET` to `/v1/setup_intents`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::PaymentFlowsSetupIntentList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/setup_intents".to_string())
+    }
+
+    /// Mock [`SetupIntents::post`](crate::setup_intents::SetupIntents::post), a `POST` to `/v1/setup_intents`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/setup_intents".to_string())
+    }
+
+    /// Mock [`SetupIntents::get_intent`](crate::setup_intents::SetupIntents::get_intent), a `GET` to `/v1/setup_intents/{intent}`.
+    pub fn get_intent(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/setup_intents/{}",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`SetupIntents::post_intent`](crate::setup_intents::SetupIntents::post_intent), a `POST` to `/v1/setup_intents/{intent}`.
+    pub fn post_intent(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/setup_intents/{}",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`SetupIntents::post_intent_cancel`](crate::setup_intents::SetupIntents::post_intent_cancel), a `POST` to `/v1/setup_intents/{intent}/cancel`.
+    pub fn post_intent_cancel(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/setup_intents/{}/cancel",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`SetupIntents::post_intent_confirm`](crate::setup_intents::SetupIntents::post_intent_confirm), a `POST` to `/v1/setup_intents/{intent}/confirm`.
+    pub fn post_intent_confirm(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/setup_intents/{}/confirm",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+
+    /// Mock [`SetupIntents::post_intent_verify_microdeposit`](crate::setup_intents::SetupIntents::post_intent_verify_microdeposit), a `POST` to `/v1/setup_intents/{intent}/verify_microdeposits`.
+    pub fn post_intent_verify_microdeposit(
+        &self,
+        intent: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SetupIntent> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/setup_intents/{}/verify_microdeposits",
+                crate::progenitor_support::encode_path(intent)
+            ),
+        )
+    }
+}
+
+pub struct ShippingRates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ShippingRates<'a> {
+    /// Mock [`ShippingRates::get_page`](crate::shipping_rates::ShippingRates::get_page), a `GET` to `/v1/shipping_rates`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ShippingResourcesRateList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/shipping_rates".to_string())
+    }
+
+    /// Mock [`ShippingRates::post`](crate::shipping_rates::ShippingRates::post), a `POST` to `/v1/shipping_rates`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::ShippingRate> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/shipping_rates".to_string())
+    }
+
+    /// Mock [`ShippingRates::get_rate_token`](crate::shipping_rates::ShippingRates::get_rate_token), a `GET` to `/v1/shipping_rates/{shipping_rate_token}`.
+    pub fn get_rate_token(
+        &self,
+        shipping_rate_token: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ShippingRate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/shipping_rates/{}",
+                crate::progenitor_support::encode_path(shipping_rate_token)
+            ),
+        )
+    }
+
+    /// Mock [`ShippingRates::post_rate_token`](crate::shipping_rates::ShippingRates::post_rate_token), a `POST` to `/v1/shipping_rates/{shipping_rate_token}`.
+    pub fn post_rate_token(
+        &self,
+        shipping_rate_token: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ShippingRate> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/shipping_rates/{}",
+                crate::progenitor_support::encode_path(shipping_rate_token)
+            ),
+        )
+    }
+}
+
+pub struct Sigma<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Sigma<'a> {
+    /// Mock [`Sigma::get_scheduled_query_runs`](crate::sigma::Sigma::get_scheduled_query_runs), a `GET` to `/v1/sigma/scheduled_query_runs`.
+    pub fn get_scheduled_query_runs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSigmaScheduledQueryRunsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/sigma/scheduled_query_runs".to_string(),
+        )
+    }
+
+    /// Mock [`Sigma::get_scheduled_query_runs_run`](crate::sigma::Sigma::get_scheduled_query_runs_run), a `GET` to `/v1/sigma/scheduled_query_runs/{scheduled_query_run}`.
+    pub fn get_scheduled_query_runs_run(
+        &self,
+        scheduled_query_run: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ScheduledQueryRun> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/sigma/scheduled_query_runs/{}",
+                crate::progenitor_support::encode_path(scheduled_query_run)
+            ),
+        )
+    }
+}
+
+pub struct Skus<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Skus<'a> {
+    /// Mock [`Skus::get_page`](crate::skus::Skus::get_page), a `GET` to `/v1/skus`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetSkusResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/skus".to_string())
+    }
+
+    /// Mock [`Skus::post`](crate::skus::Skus::post), a `POST` to `/v1/skus`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Sku> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/skus".to_string())
+    }
+
+    /// Mock [`Skus::get`](crate::skus::Skus::get), a `GET` to `/v1/skus/{id}`.
+    pub fn get(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSkusResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/v1/skus/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Skus::post_skus`](crate::skus::Skus::post_skus), a `POST` to `/v1/skus/{id}`.
+    pub fn post_skus(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::Sku> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!("/v1/skus/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+
+    /// Mock [`Skus::delete`](crate::skus::Skus::delete), a `DELETE` to `/v1/skus/{id}`.
+    pub fn delete(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::DeletedSku> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/v1/skus/{}", crate::progenitor_support::encode_path(id)),
+        )
+    }
+}
+
+pub struct Sources<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Sources<'a> {
+    /// Mock [`Sources::post`](crate::sources::Sources::post), a `POST` to `/v1/sources`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::SourceData> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/sources".to_string())
+    }
+
+    /// Mock [`Sources::get`](crate::sources::Sources::get), a `GET` to `/v1/sources/{source}`.
+    pub fn get(&self, source: &str) -> crate::mock::MockOperation<'a, crate::types::SourceData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/sources/{}",
+                crate::progenitor_support::encode_path(source)
+            ),
+        )
+    }
+
+    /// Mock [`Sources::post_sources`](crate::sources::Sources::post_sources), a `POST` to `/v1/sources/{source}`.
+    pub fn post_sources(
+        &self,
+        source: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/sources/{}",
+                crate::progenitor_support::encode_path(source)
+            ),
+        )
+    }
+
+    /// Mock [`Sources::get_mandate_notifications_notification`](crate::sources::Sources::get_mandate_notifications_notification), a `GET` to `/v1/sources/{source}/mandate_notifications/{mandate_notification}`.
+    pub fn get_mandate_notifications_notification(
+        &self,
+        mandate_notification: &str,
+        source: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceMandateNotification> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/sources/{}/mandate_notifications/{}",
+                crate::progenitor_support::encode_path(source),
+                crate::progenitor_support::encode_path(mandate_notification)
+            ),
+        )
+    }
+
+    /// Mock [`Sources::get_transactions`](crate::sources::Sources::get_transactions), a `GET` to `/v1/sources/{source}/source_transactions`.
+    pub fn get_transactions(
+        &self,
+        source: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ApmsSourcesSourceTransactionList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/sources/{}/source_transactions",
+                crate::progenitor_support::encode_path(source)
+            ),
+        )
+    }
+
+    /// Mock [`Sources::get_transactions_transaction`](crate::sources::Sources::get_transactions_transaction), a `GET` to `/v1/sources/{source}/source_transactions/{source_transaction}`.
+    pub fn get_transactions_transaction(
+        &self,
+        source: &str,
+        source_transaction: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceTransaction> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/sources/{}/source_transactions/{}",
+                crate::progenitor_support::encode_path(source),
+                crate::progenitor_support::encode_path(source_transaction)
+            ),
+        )
+    }
+
+    /// Mock [`Sources::post_verify`](crate::sources::Sources::post_verify), a `POST` to `/v1/sources/{source}/verify`.
+    pub fn post_verify(
+        &self,
+        source: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SourceData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/sources/{}/verify",
+                crate::progenitor_support::encode_path(source)
+            ),
+        )
+    }
+}
+
+pub struct SubscriptionItems<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SubscriptionItems<'a> {
+    /// Mock [`SubscriptionItems::get_page`](crate::subscription_items::SubscriptionItems::get_page), a `GET` to `/v1/subscription_items`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::Items> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/subscription_items".to_string())
+    }
+
+    /// Mock [`SubscriptionItems::post`](crate::subscription_items::SubscriptionItems::post), a `POST` to `/v1/subscription_items`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::SubscriptionItem> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/subscription_items".to_string())
+    }
+
+    /// Mock [`SubscriptionItems::get_item`](crate::subscription_items::SubscriptionItems::get_item), a `GET` to `/v1/subscription_items/{item}`.
+    pub fn get_item(
+        &self,
+        item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionItem> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/subscription_items/{}",
+                crate::progenitor_support::encode_path(item)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionItems::post_item`](crate::subscription_items::SubscriptionItems::post_item), a `POST` to `/v1/subscription_items/{item}`.
+    pub fn post_item(
+        &self,
+        item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionItem> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscription_items/{}",
+                crate::progenitor_support::encode_path(item)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionItems::delete_item`](crate::subscription_items::SubscriptionItems::delete_item), a `DELETE` to `/v1/subscription_items/{item}`.
+    pub fn delete_item(
+        &self,
+        item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedSubscriptionItem> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/subscription_items/{}",
+                crate::progenitor_support::encode_path(item)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionItems::get_item_usage_record_summaries`](crate::subscription_items::SubscriptionItems::get_item_usage_record_summaries), a `GET` to `/v1/subscription_items/{subscription_item}/usage_record_summaries`.
+    pub fn get_item_usage_record_summaries(
+        &self,
+        subscription_item: &str,
+    ) -> crate::mock::MockOperation<
+        'a,
+        crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse,
+    > {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/subscription_items/{}/usage_record_summaries",
+                crate::progenitor_support::encode_path(subscription_item)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionItems::post_item_usage_record`](crate::subscription_items::SubscriptionItems::post_item_usage_record), a `POST` to `/v1/subscription_items/{subscription_item}/usage_records`.
+    pub fn post_item_usage_record(
+        &self,
+        subscription_item: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UsageRecord> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscription_items/{}/usage_records",
+                crate::progenitor_support::encode_path(subscription_item)
+            ),
+        )
+    }
+}
+
+pub struct SubscriptionSchedules<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SubscriptionSchedules<'a> {
+    /// Mock [`SubscriptionSchedules::get_page`](crate::subscription_schedules::SubscriptionSchedules::get_page), a `GET` to `/v1/subscription_schedules`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSubscriptionSchedulesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/subscription_schedules".to_string(),
+        )
+    }
+
+    /// Mock [`SubscriptionSchedules::post`](crate::subscription_schedules::SubscriptionSchedules::post), a `POST` to `/v1/subscription_schedules`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::SubscriptionSchedule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/subscription_schedules".to_string(),
+        )
+    }
+
+    /// Mock [`SubscriptionSchedules::get_schedule`](crate::subscription_schedules::SubscriptionSchedules::get_schedule), a `GET` to `/v1/subscription_schedules/{schedule}`.
+    pub fn get_schedule(
+        &self,
+        schedule: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionSchedule> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/subscription_schedules/{}",
+                crate::progenitor_support::encode_path(schedule)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionSchedules::post_schedule`](crate::subscription_schedules::SubscriptionSchedules::post_schedule), a `POST` to `/v1/subscription_schedules/{schedule}`.
+    pub fn post_schedule(
+        &self,
+        schedule: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionSchedule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscription_schedules/{}",
+                crate::progenitor_support::encode_path(schedule)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionSchedules::post_schedule_cancel`](crate::subscription_schedules::SubscriptionSchedules::post_schedule_cancel), a `POST` to `/v1/subscription_schedules/{schedule}/cancel`.
+    pub fn post_schedule_cancel(
+        &self,
+        schedule: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionSchedule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscription_schedules/{}/cancel",
+                crate::progenitor_support::encode_path(schedule)
+            ),
+        )
+    }
+
+    /// Mock [`SubscriptionSchedules::post_schedule_release`](crate::subscription_schedules::SubscriptionSchedules::post_schedule_release), a `POST` to `/v1/subscription_schedules/{schedule}/release`.
+    pub fn post_schedule_release(
+        &self,
+        schedule: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::SubscriptionSchedule> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscription_schedules/{}/release",
+                crate::progenitor_support::encode_path(schedule)
+            ),
+        )
+    }
+}
+
+pub struct Subscriptions<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Subscriptions<'a> {
+    /// Mock [`Subscriptions::get_page`](crate::subscriptions::Subscriptions::get_page), a `GET` to `/v1/subscriptions`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::Subscriptions> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/subscriptions".to_string())
+    }
+
+    /// Mock [`Subscriptions::post`](crate::subscriptions::Subscriptions::post), a `POST` to `/v1/subscriptions`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/subscriptions".to_string())
+    }
+
+    /// Mock [`Subscriptions::get_search`](crate::subscriptions::Subscriptions::get_search), a `GET` to `/v1/subscriptions/search`.
+    pub fn get_search(&self) -> crate::mock::MockOperation<'a, crate::types::SearchResult> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/subscriptions/search".to_string())
+    }
+
+    /// Mock [`Subscriptions::get_exposed`](crate::subscriptions::Subscriptions::get_exposed), a `GET` to `/v1/subscriptions/{subscription_exposed_id}`.
+    pub fn get_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/subscriptions/{}",
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Subscriptions::post_exposed`](crate::subscriptions::Subscriptions::post_exposed), a `POST` to `/v1/subscriptions/{subscription_exposed_id}`.
+    pub fn post_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/subscriptions/{}",
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Subscriptions::delete_exposed`](crate::subscriptions::Subscriptions::delete_exposed), a `DELETE` to `/v1/subscriptions/{subscription_exposed_id}`.
+    pub fn delete_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Subscription> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/subscriptions/{}",
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+
+    /// Mock [`Subscriptions::delete_exposed_discount`](crate::subscriptions::Subscriptions::delete_exposed_discount), a `DELETE` to `/v1/subscriptions/{subscription_exposed_id}/discount`.
+    pub fn delete_exposed_discount(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedDiscount> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/subscriptions/{}/discount",
+                crate::progenitor_support::encode_path(subscription_exposed_id)
+            ),
+        )
+    }
+}
+
+pub struct TaxCodes<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TaxCodes<'a> {
+    /// Mock [`TaxCodes::get_page`](crate::tax_codes::TaxCodes::get_page), a `GET` to `/v1/tax_codes`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxProductResourceCodeList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/tax_codes".to_string())
+    }
+
+    /// Mock [`TaxCodes::get`](crate::tax_codes::TaxCodes::get), a `GET` to `/v1/tax_codes/{id}`.
+    pub fn get(&self, id: &str) -> crate::mock::MockOperation<'a, crate::types::TaxCode> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/tax_codes/{}",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct TaxRates<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TaxRates<'a> {
+    /// Mock [`TaxRates::get_page`](crate::tax_rates::TaxRates::get_page), a `GET` to `/v1/tax_rates`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::GetTaxRatesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/tax_rates".to_string())
+    }
+
+    /// Mock [`TaxRates::post`](crate::tax_rates::TaxRates::post), a `POST` to `/v1/tax_rates`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::TaxRate> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/tax_rates".to_string())
+    }
+
+    /// Mock [`TaxRates::get_rate`](crate::tax_rates::TaxRates::get_rate), a `GET` to `/v1/tax_rates/{tax_rate}`.
+    pub fn get_rate(
+        &self,
+        tax_rate: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxRate> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/tax_rates/{}",
+                crate::progenitor_support::encode_path(tax_rate)
+            ),
+        )
+    }
+
+    /// Mock [`TaxRates::post_rate`](crate::tax_rates::TaxRates::post_rate), a `POST` to `/v1/tax_rates/{tax_rate}`.
+    pub fn post_rate(
+        &self,
+        tax_rate: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TaxRate> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/tax_rates/{}",
+                crate::progenitor_support::encode_path(tax_rate)
+            ),
+        )
+    }
+}
+
+pub struct Terminal<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Terminal<'a> {
+    /// Mock [`Terminal::post_connection_token`](crate::terminal::Terminal::post_connection_token), a `POST` to `/v1/terminal/connection_tokens`.
+    pub fn post_connection_token(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalConnectionToken> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/terminal/connection_tokens".to_string(),
+        )
+    }
+
+    /// Mock [`Terminal::get_locations`](crate::terminal::Terminal::get_locations), a `GET` to `/v1/terminal/locations`.
+    pub fn get_locations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalLocationList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/terminal/locations".to_string())
+    }
+
+    /// Mock [`Terminal::post_location`](crate::terminal::Terminal::post_location), a `POST` to `/v1/terminal/locations`.
+    pub fn post_location(&self) -> crate::mock::MockOperation<'a, crate::types::TerminalLocation> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/terminal/locations".to_string())
+    }
+
+    /// Mock [`Terminal::get_locations_location`](crate::terminal::Terminal::get_locations_location), a `GET` to `/v1/terminal/locations/{location}`.
+    pub fn get_locations_location(
+        &self,
+        location: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTerminalLocationResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/terminal/locations/{}",
+                crate::progenitor_support::encode_path(location)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_locations_location`](crate::terminal::Terminal::post_locations_location), a `POST` to `/v1/terminal/locations/{location}`.
+    pub fn post_locations_location(
+        &self,
+        location: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTerminalLocationResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/locations/{}",
+                crate::progenitor_support::encode_path(location)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::delete_locations_location`](crate::terminal::Terminal::delete_locations_location), a `DELETE` to `/v1/terminal/locations/{location}`.
+    pub fn delete_locations_location(
+        &self,
+        location: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedTerminalLocation> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/terminal/locations/{}",
+                crate::progenitor_support::encode_path(location)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::get_readers`](crate::terminal::Terminal::get_readers), a `GET` to `/v1/terminal/readers`.
+    pub fn get_readers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReaderRetrieve> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/terminal/readers".to_string())
+    }
+
+    /// Mock [`Terminal::post_reader`](crate::terminal::Terminal::post_reader), a `POST` to `/v1/terminal/readers`.
+    pub fn post_reader(&self) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/terminal/readers".to_string())
+    }
+
+    /// Mock [`Terminal::get_readers_reader`](crate::terminal::Terminal::get_readers_reader), a `GET` to `/v1/terminal/readers/{reader}`.
+    pub fn get_readers_reader(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/terminal/readers/{}",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_readers_reader`](crate::terminal::Terminal::post_readers_reader), a `POST` to `/v1/terminal/readers/{reader}`.
+    pub fn post_readers_reader(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/readers/{}",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::delete_readers_reader`](crate::terminal::Terminal::delete_readers_reader), a `DELETE` to `/v1/terminal/readers/{reader}`.
+    pub fn delete_readers_reader(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedTerminalReader> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/terminal/readers/{}",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_readers_reader_cancel_action`](crate::terminal::Terminal::post_readers_reader_cancel_action), a `POST` to `/v1/terminal/readers/{reader}/cancel_action`.
+    pub fn post_readers_reader_cancel_action(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/readers/{}/cancel_action",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_readers_reader_process_payment_intent`](crate::terminal::Terminal::post_readers_reader_process_payment_intent), a `POST` to `/v1/terminal/readers/{reader}/process_payment_intent`.
+    pub fn post_readers_reader_process_payment_intent(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/readers/{}/process_payment_intent",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_readers_reader_process_setup_intent`](crate::terminal::Terminal::post_readers_reader_process_setup_intent), a `POST` to `/v1/terminal/readers/{reader}/process_setup_intent`.
+    pub fn post_readers_reader_process_setup_intent(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/readers/{}/process_setup_intent",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`Terminal::post_readers_reader_set_display`](crate::terminal::Terminal::post_readers_reader_set_display), a `POST` to `/v1/terminal/readers/{reader}/set_reader_display`.
+    pub fn post_readers_reader_set_display(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/terminal/readers/{}/set_reader_display",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+}
+
+pub struct TestHelpers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TestHelpers<'a> {
+    /// Mock [`TestHelpers::post_terminal_readers_reader_present_payment_method`](crate::test_helpers::TestHelpers::post_terminal_readers_reader_present_payment_method), a `POST` to `/v1/test_helpers/terminal/readers/{reader}/present_payment_method`.
+    pub fn post_terminal_readers_reader_present_payment_method(
+        &self,
+        reader: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TerminalReader> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/test_helpers/terminal/readers/{}/present_payment_method",
+                crate::progenitor_support::encode_path(reader)
+            ),
+        )
+    }
+
+    /// Mock [`TestHelpers::get_clocks`](crate::test_helpers::TestHelpers::get_clocks), a `GET` to `/v1/test_helpers/test_clocks`.
+    pub fn get_clocks(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTestHelpersClocksResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/v1/test_helpers/test_clocks".to_string(),
+        )
+    }
+
+    /// Mock [`TestHelpers::post_clock`](crate::test_helpers::TestHelpers::post_clock), a `POST` to `/v1/test_helpers/test_clocks`.
+    pub fn post_clock(&self) -> crate::mock::MockOperation<'a, crate::types::TestClock> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/v1/test_helpers/test_clocks".to_string(),
+        )
+    }
+
+    /// Mock [`TestHelpers::get_clocks_clock`](crate::test_helpers::TestHelpers::get_clocks_clock), a `GET` to `/v1/test_helpers/test_clocks/{test_clock}`.
+    pub fn get_clocks_clock(
+        &self,
+        test_clock: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TestClock> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/test_helpers/test_clocks/{}",
+                crate::progenitor_support::encode_path(test_clock)
+            ),
+        )
+    }
+
+    /// Mock [`TestHelpers::delete_clocks_clock`](crate::test_helpers::TestHelpers::delete_clocks_clock), a `DELETE` to `/v1/test_helpers/test_clocks/{test_clock}`.
+    pub fn delete_clocks_clock(
+        &self,
+        test_clock: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedTestClock> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/test_helpers/test_clocks/{}",
+                crate::progenitor_support::encode_path(test_clock)
+            ),
+        )
+    }
+
+    /// Mock [`TestHelpers::post_clocks_clock_advance`](crate::test_helpers::TestHelpers::post_clocks_clock_advance), a `POST` to `/v1/test_helpers/test_clocks/{test_clock}/advance`.
+    pub fn post_clocks_clock_advance(
+        &self,
+        test_clock: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TestClock> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/test_helpers/test_clocks/{}/advance",
+                crate::progenitor_support::encode_path(test_clock)
+            ),
+        )
+    }
+}
+
+pub struct ThreeDSecure<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ThreeDSecure<'a> {
+    /// Mock [`ThreeDSecure::post_3d_secure`](crate::three_d_secure::ThreeDSecure::post_3d_secure), a `POST` to `/v1/3d_secure`.
+    pub fn post_3d_secure(&self) -> crate::mock::MockOperation<'a, crate::types::ThreeDSecure> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/3d_secure".to_string())
+    }
+
+    /// Mock [`ThreeDSecure::get_3d_secure_three_d`](crate::three_d_secure::ThreeDSecure::get_3d_secure_three_d), a `GET` to `/v1/3d_secure/{three_d_secure}`.
+    pub fn get_3d_secure_three_d(
+        &self,
+        three_d_secure: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ThreeDSecure> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/3d_secure/{}",
+                crate::progenitor_support::encode_path(three_d_secure)
+            ),
+        )
+    }
+}
+
+pub struct Tokens<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Tokens<'a> {
+    /// Mock [`Tokens::post`](crate::tokens::Tokens::post), a `POST` to `/v1/tokens`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Token> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/tokens".to_string())
+    }
+
+    /// Mock [`Tokens::get`](crate::tokens::Tokens::get), a `GET` to `/v1/tokens/{token}`.
+    pub fn get(&self, token: &str) -> crate::mock::MockOperation<'a, crate::types::Token> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/tokens/{}",
+                crate::progenitor_support::encode_path(token)
+            ),
+        )
+    }
+}
+
+pub struct Topups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Topups<'a> {
+    /// Mock [`Topups::get_page`](crate::topups::Topups::get_page), a `GET` to `/v1/topups`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::TopupList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/topups".to_string())
+    }
+
+    /// Mock [`Topups::post`](crate::topups::Topups::post), a `POST` to `/v1/topups`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Topup> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/topups".to_string())
+    }
+
+    /// Mock [`Topups::get`](crate::topups::Topups::get), a `GET` to `/v1/topups/{topup}`.
+    pub fn get(&self, topup: &str) -> crate::mock::MockOperation<'a, crate::types::Topup> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/topups/{}",
+                crate::progenitor_support::encode_path(topup)
+            ),
+        )
+    }
+
+    /// Mock [`Topups::post_topups`](crate::topups::Topups::post_topups), a `POST` to `/v1/topups/{topup}`.
+    pub fn post_topups(&self, topup: &str) -> crate::mock::MockOperation<'a, crate::types::Topup> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/topups/{}",
+                crate::progenitor_support::encode_path(topup)
+            ),
+        )
+    }
+
+    /// Mock [`Topups::post_cancel`](crate::topups::Topups::post_cancel), a `POST` to `/v1/topups/{topup}/cancel`.
+    pub fn post_cancel(&self, topup: &str) -> crate::mock::MockOperation<'a, crate::types::Topup> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/topups/{}/cancel",
+                crate::progenitor_support::encode_path(topup)
+            ),
+        )
+    }
+}
+
+pub struct Transfers<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Transfers<'a> {
+    /// Mock [`Transfers::get_page`](crate::transfers::Transfers::get_page), a `GET` to `/v1/transfers`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::TransferList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/transfers".to_string())
+    }
+
+    /// Mock [`Transfers::post`](crate::transfers::Transfers::post), a `POST` to `/v1/transfers`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::Transfer> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/transfers".to_string())
+    }
+
+    /// Mock [`Transfers::get_reversals`](crate::transfers::Transfers::get_reversals), a `GET` to `/v1/transfers/{id}/reversals`.
+    pub fn get_reversals(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Reversals> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/transfers/{}/reversals",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Transfers::post_reversal`](crate::transfers::Transfers::post_reversal), a `POST` to `/v1/transfers/{id}/reversals`.
+    pub fn post_reversal(
+        &self,
+        id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransferReversal> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/transfers/{}/reversals",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Transfers::get`](crate::transfers::Transfers::get), a `GET` to `/v1/transfers/{transfer}`.
+    pub fn get(&self, transfer: &str) -> crate::mock::MockOperation<'a, crate::types::Transfer> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/transfers/{}",
+                crate::progenitor_support::encode_path(transfer)
+            ),
+        )
+    }
+
+    /// Mock [`Transfers::post_transfers`](crate::transfers::Transfers::post_transfers), a `POST` to `/v1/transfers/{transfer}`.
+    pub fn post_transfers(
+        &self,
+        transfer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Transfer> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/transfers/{}",
+                crate::progenitor_support::encode_path(transfer)
+            ),
+        )
+    }
+
+    /// Mock [`Transfers::get_reversal`](crate::transfers::Transfers::get_reversal), a `GET` to `/v1/transfers/{transfer}/reversals/{id}`.
+    pub fn get_reversal(
+        &self,
+        id: &str,
+        transfer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransferReversal> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/transfers/{}/reversals/{}",
+                crate::progenitor_support::encode_path(transfer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Transfers::post_reversal_transfers`](crate::transfers::Transfers::post_reversal_transfers), a `POST` to `/v1/transfers/{transfer}/reversals/{id}`.
+    pub fn post_reversal_transfers(
+        &self,
+        id: &str,
+        transfer: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TransferReversal> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/transfers/{}/reversals/{}",
+                crate::progenitor_support::encode_path(transfer),
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+}
+
+pub struct WebhookEndpoints<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> WebhookEndpoints<'a> {
+    /// Mock [`WebhookEndpoints::get_page`](crate::webhook_endpoints::WebhookEndpoints::get_page), a `GET` to `/v1/webhook_endpoints`.
+    pub fn get_page(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetWebhookEndpointsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/v1/webhook_endpoints".to_string())
+    }
+
+    /// Mock [`WebhookEndpoints::post`](crate::webhook_endpoints::WebhookEndpoints::post), a `POST` to `/v1/webhook_endpoints`.
+    pub fn post(&self) -> crate::mock::MockOperation<'a, crate::types::WebhookEndpoint> {
+        self.mock
+            .operation(reqwest::Method::POST, "/v1/webhook_endpoints".to_string())
+    }
+
+    /// Mock [`WebhookEndpoints::get_endpoint`](crate::webhook_endpoints::WebhookEndpoints::get_endpoint), a `GET` to `/v1/webhook_endpoints/{webhook_endpoint}`.
+    pub fn get_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookEndpoint> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/v1/webhook_endpoints/{}",
+                crate::progenitor_support::encode_path(webhook_endpoint)
+            ),
+        )
+    }
+
+    /// Mock [`WebhookEndpoints::post_endpoint`](crate::webhook_endpoints::WebhookEndpoints::post_endpoint), a `POST` to `/v1/webhook_endpoints/{webhook_endpoint}`.
+    pub fn post_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebhookEndpoint> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/v1/webhook_endpoints/{}",
+                crate::progenitor_support::encode_path(webhook_endpoint)
+            ),
+        )
+    }
+
+    /// Mock [`WebhookEndpoints::delete_endpoint`](crate::webhook_endpoints::WebhookEndpoints::delete_endpoint), a `DELETE` to `/v1/webhook_endpoints/{webhook_endpoint}`.
+    pub fn delete_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeletedWebhookEndpoint> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/v1/webhook_endpoints/{}",
+                crate::progenitor_support::encode_path(webhook_endpoint)
+            ),
+        )
+    }
+}
@@ -130,7 +130,7 @@ impl Orders {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -158,18 +158,87 @@ impl Orders {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your orders. The orders are returned sorted by creation date, with the most recently created orders appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        _ids: &[String],
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Order> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/orders?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::OrdersLegacyResourceOrderList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/orders` endpoint.
      *
      * <p>Creates a new order object.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Order> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostOrdersRequest,
+    ) -> Result<crate::types::Order> {
         let url = self.client.url("/v1/orders", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -209,7 +278,11 @@ impl Orders {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_orders(&self, id: &str) -> Result<crate::types::Order> {
+    pub async fn post_orders(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersRequestData,
+    ) -> Result<crate::types::Order> {
         let url = self.client.url(
             &format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),),
             None,
@@ -218,7 +291,7 @@ impl Orders {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -233,7 +306,11 @@ impl Orders {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_pay(&self, id: &str) -> Result<crate::types::Order> {
+    pub async fn post_pay(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersPayRequest,
+    ) -> Result<crate::types::Order> {
         let url = self.client.url(
             &format!(
                 "/v1/orders/{}/pay",
@@ -245,7 +322,7 @@ impl Orders {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -260,7 +337,11 @@ impl Orders {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_return(&self, id: &str) -> Result<crate::types::OrderReturn> {
+    pub async fn post_return(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersReturnsRequest,
+    ) -> Result<crate::types::OrderReturn> {
         let url = self.client.url(
             &format!(
                 "/v1/orders/{}/returns",
@@ -272,7 +353,7 @@ impl Orders {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
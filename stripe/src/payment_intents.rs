@@ -116,7 +116,7 @@ impl PaymentIntents {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -144,6 +144,67 @@ impl PaymentIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of PaymentIntents.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PaymentIntent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_intents?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PaymentFlowsIntentList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/payment_intents` endpoint.
      *
@@ -158,13 +219,16 @@ impl PaymentIntents {
      * available in the <a href="/docs/api/payment_intents/confirm">confirm API</a> when <code>confirm=true</code>
      * is supplied.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::PaymentIntent> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPaymentIntentsRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url("/v1/payment_intents", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -269,7 +333,7 @@ impl PaymentIntents {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -297,6 +361,69 @@ impl PaymentIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Search for PaymentIntents you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        query: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_intents/search?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::SearchResult = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/payment_intents/{intent}` endpoint.
      *
@@ -355,7 +482,11 @@ impl PaymentIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}",
@@ -367,7 +498,7 @@ impl PaymentIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -386,7 +517,11 @@ impl PaymentIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_cancel(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCancelRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/cancel",
@@ -398,7 +533,7 @@ impl PaymentIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -417,7 +552,11 @@ impl PaymentIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent_capture(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_capture(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCaptureRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/capture",
@@ -429,7 +568,7 @@ impl PaymentIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -468,7 +607,11 @@ impl PaymentIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_confirm(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentConfirmRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/confirm",
@@ -480,7 +623,7 @@ impl PaymentIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -498,6 +641,7 @@ impl PaymentIntents {
     pub async fn post_intent_verify_microdeposit(
         &self,
         intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentVerifyMicrodepositsRequest,
     ) -> Result<crate::types::PaymentIntent> {
         let url = self.client.url(
             &format!(
@@ -510,7 +654,7 @@ impl PaymentIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -122,7 +122,7 @@ impl PaymentMethods {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -150,6 +150,70 @@ impl PaymentMethods {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of PaymentMethods. For listing a customer’s payment methods, you should use <a href="/docs/api/payment_methods/customer_list">List a Customer’s PaymentMethods</a></p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PaymentMethod> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_methods?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PaymentFlowsMethodList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/payment_methods` endpoint.
      *
@@ -157,13 +221,16 @@ impl PaymentMethods {
      *
      * <p>Instead of creating a PaymentMethod directly, we recommend using the <a href="/docs/payments/accept-a-payment">PaymentIntents</a> API to accept a payment immediately or the <a href="/docs/payments/save-and-reuse">SetupIntent</a> API to collect payment method details ahead of a future payment.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::PaymentMethod> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPaymentMethodsRequest,
+    ) -> Result<crate::types::PaymentMethod> {
         let url = self.client.url("/v1/payment_methods", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -206,7 +273,11 @@ impl PaymentMethods {
      *
      * * `payment_method: &str` -- The account's country.
      */
-    pub async fn post_method(&self, payment_method: &str) -> Result<crate::types::PaymentMethod> {
+    pub async fn post_method(
+        &self,
+        payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodRequest,
+    ) -> Result<crate::types::PaymentMethod> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_methods/{}",
@@ -218,7 +289,7 @@ impl PaymentMethods {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -246,6 +317,7 @@ impl PaymentMethods {
     pub async fn post_method_attach(
         &self,
         payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodAttachRequest,
     ) -> Result<crate::types::PaymentMethod> {
         let url = self.client.url(
             &format!(
@@ -258,7 +330,7 @@ impl PaymentMethods {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -276,6 +348,7 @@ impl PaymentMethods {
     pub async fn post_method_detach(
         &self,
         payment_method: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> Result<crate::types::PaymentMethod> {
         let url = self.client.url(
             &format!(
@@ -288,7 +361,7 @@ impl PaymentMethods {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -124,7 +124,7 @@ impl Payouts {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -152,6 +152,70 @@ impl Payouts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of existing payouts sent to third-party bank accounts or that Stripe has sent you. The payouts are returned in sorted order, with the most recently created payouts appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        status: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Payout> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !destination.is_empty() {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/payouts?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PayoutList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/payouts` endpoint.
      *
@@ -161,13 +225,16 @@ impl Payouts {
      *
      * <p>If you are creating a manual payout on a Stripe account that uses multiple payment source types, you’ll need to specify the source type balance that the payout should draw from. The <a href="#balance_object">balance object</a> details available and pending amounts by source type.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Payout> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPayoutsRequest,
+    ) -> Result<crate::types::Payout> {
         let url = self.client.url("/v1/payouts", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -210,7 +277,11 @@ impl Payouts {
      *
      * * `payout: &str` -- The account's country.
      */
-    pub async fn post_payouts(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_payouts(
+        &self,
+        payout: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> Result<crate::types::Payout> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}",
@@ -222,7 +293,7 @@ impl Payouts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -237,7 +308,11 @@ impl Payouts {
      *
      * * `payout: &str` -- The account's country.
      */
-    pub async fn post_cancel(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_cancel(
+        &self,
+        payout: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Payout> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}/cancel",
@@ -249,7 +324,7 @@ impl Payouts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -266,7 +341,11 @@ impl Payouts {
      *
      * * `payout: &str` -- The account's country.
      */
-    pub async fn post_reverse(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_reverse(
+        &self,
+        payout: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> Result<crate::types::Payout> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}/reverse",
@@ -278,7 +357,7 @@ impl Payouts {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -143,7 +143,7 @@ impl PromotionCodes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -171,18 +171,94 @@ impl PromotionCodes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your promotion codes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::PromotionCode> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if !code.is_empty() {
+            query_args.push(("code".to_string(), code.to_string()));
+        }
+        if !coupon.is_empty() {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/promotion_codes?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetPromotionCodesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/promotion_codes` endpoint.
      *
      * <p>A promotion code points to a coupon. You can optionally restrict the code to a specific customer, redemption limit, and expiration date.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::PromotionCode> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPromotionCodesRequest,
+    ) -> Result<crate::types::PromotionCode> {
         let url = self.client.url("/v1/promotion_codes", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -225,7 +301,11 @@ impl PromotionCodes {
      *
      * * `promotion_code: &str` -- The account's country.
      */
-    pub async fn post_code(&self, promotion_code: &str) -> Result<crate::types::PromotionCode> {
+    pub async fn post_code(
+        &self,
+        promotion_code: &str,
+        body: &crate::types::PostPromotionCodesPromotionCodeRequest,
+    ) -> Result<crate::types::PromotionCode> {
         let url = self.client.url(
             &format!(
                 "/v1/promotion_codes/{}",
@@ -237,7 +317,7 @@ impl PromotionCodes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
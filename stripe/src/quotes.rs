@@ -127,7 +127,7 @@ impl Quotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -155,18 +155,87 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your quotes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Quote> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !test_clock.is_empty() {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/quotes?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetQuotesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/quotes` endpoint.
      *
      * <p>A quote models prices and services for a customer. Default options for <code>header</code>, <code>description</code>, <code>footer</code>, and <code>expires_at</code> can be set in the dashboard via the <a href="https://dashboard.stripe.com/settings/billing/quote">quote template</a>.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Quote> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostQuotesRequest,
+    ) -> Result<crate::types::Quote> {
         let url = self.client.url("/v1/quotes", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -209,7 +278,11 @@ impl Quotes {
      *
      * * `quote: &str` -- The account's country.
      */
-    pub async fn post_quotes(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_quotes(
+        &self,
+        quote: &str,
+        body: &crate::types::PostQuotesQuoteRequest,
+    ) -> Result<crate::types::Quote> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}",
@@ -221,7 +294,7 @@ impl Quotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -236,7 +309,11 @@ impl Quotes {
      *
      * * `quote: &str` -- The account's country.
      */
-    pub async fn post_accept(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_accept(
+        &self,
+        quote: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Quote> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/accept",
@@ -248,7 +325,7 @@ impl Quotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -263,7 +340,11 @@ impl Quotes {
      *
      * * `quote: &str` -- The account's country.
      */
-    pub async fn post_cancel(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_cancel(
+        &self,
+        quote: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Quote> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/cancel",
@@ -275,7 +356,7 @@ impl Quotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -383,7 +464,7 @@ impl Quotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -411,6 +492,65 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
+     *
+     * As opposed to `get_all_computed_upfront_line_items`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving a quote, there is an includable <a href="https://stripe.com/docs/api/quotes/object#quote_object-computed-upfront-line_items"><strong>computed.upfront.line_items</strong></a> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of upfront line items.</p>
+     */
+    pub fn get_all_computed_upfront_line_items_stream(
+        &self,
+        quote: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Item> {
+        let url = self.client.url(
+            &format!(
+                "/v1/quotes/{}/computed_upfront_line_items",
+                crate::progenitor_support::encode_path(quote),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::LineItems = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/quotes/{quote}/finalize` endpoint.
      *
@@ -420,7 +560,11 @@ impl Quotes {
      *
      * * `quote: &str` -- The account's country.
      */
-    pub async fn post_finalize(&self, quote: &str) -> Result<crate::types::Quote> {
+    pub async fn post_finalize(
+        &self,
+        quote: &str,
+        body: &crate::types::PostQuotesQuoteFinalizeRequest,
+    ) -> Result<crate::types::Quote> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/finalize",
@@ -432,7 +576,7 @@ impl Quotes {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -537,7 +681,7 @@ impl Quotes {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -565,6 +709,65 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
+     *
+     * As opposed to `get_all_line_items`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>When retrieving a quote, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_line_items_stream(
+        &self,
+        quote: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Item> {
+        let url = self.client.url(
+            &format!(
+                "/v1/quotes/{}/line_items",
+                crate::progenitor_support::encode_path(quote),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::LineItems = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/quotes/{quote}/pdf` endpoint.
      *
@@ -121,7 +121,7 @@ impl Recipients {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,19 +149,85 @@ impl Recipients {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/recipients` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your recipients. The recipients are returned sorted by creation date, with the most recently created recipients appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Recipient> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if verified {
+            query_args.push(("verified".to_string(), verified.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/recipients?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetRecipientsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/recipients` endpoint.
      *
      * <p>Creates a new <code>Recipient</code> object and verifies the recipient’s identity.
      * Also verifies the recipient’s bank account information or debit card, if either is provided.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Recipient> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostRecipientsRequest,
+    ) -> Result<crate::types::Recipient> {
         let url = self.client.url("/v1/recipients", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -208,7 +274,11 @@ impl Recipients {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_recipients(&self, id: &str) -> Result<crate::types::Recipient> {
+    pub async fn post_recipients(
+        &self,
+        id: &str,
+        body: &crate::types::PostRecipientsRequestData,
+    ) -> Result<crate::types::Recipient> {
         let url = self.client.url(
             &format!(
                 "/v1/recipients/{}",
@@ -220,7 +290,7 @@ impl Recipients {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
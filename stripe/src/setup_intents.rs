@@ -125,7 +125,7 @@ impl SetupIntents {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -153,6 +153,71 @@ impl SetupIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of SetupIntents.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        payment_method: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SetupIntent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !payment_method.is_empty() {
+            query_args.push(("payment_method".to_string(), payment_method.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/setup_intents?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PaymentFlowsSetupIntentList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/setup_intents` endpoint.
      *
@@ -161,13 +226,16 @@ impl SetupIntents {
      * <p>After the SetupIntent is created, attach a payment method and <a href="/docs/api/setup_intents/confirm">confirm</a>
      * to collect any required permissions to charge the payment method later.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::SetupIntent> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSetupIntentsRequest,
+    ) -> Result<crate::types::SetupIntent> {
         let url = self.client.url("/v1/setup_intents", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -225,7 +293,11 @@ impl SetupIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentRequest,
+    ) -> Result<crate::types::SetupIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}",
@@ -237,7 +309,7 @@ impl SetupIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -254,7 +326,11 @@ impl SetupIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_cancel(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentCancelRequest,
+    ) -> Result<crate::types::SetupIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}/cancel",
@@ -266,7 +342,7 @@ impl SetupIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -293,7 +369,11 @@ impl SetupIntents {
      *
      * * `intent: &str` -- The account's country.
      */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_confirm(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentConfirmRequest,
+    ) -> Result<crate::types::SetupIntent> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}/confirm",
@@ -305,7 +385,7 @@ impl SetupIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -323,6 +403,7 @@ impl SetupIntents {
     pub async fn post_intent_verify_microdeposit(
         &self,
         intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentVerifyMicrodepositsRequest,
     ) -> Result<crate::types::SetupIntent> {
         let url = self.client.url(
             &format!(
@@ -335,7 +416,7 @@ impl SetupIntents {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
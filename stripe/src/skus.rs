@@ -133,7 +133,7 @@ impl Skus {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -161,18 +161,86 @@ impl Skus {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your SKUs. The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _attributes: &str,
+        _ids: &[String],
+        in_stock: bool,
+        product: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Sku> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if in_stock {
+            query_args.push(("in_stock".to_string(), in_stock.to_string()));
+        }
+        if !product.is_empty() {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/skus?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetSkusResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/skus` endpoint.
      *
      * <p>Creates a new SKU associated with a product.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Sku> {
+    pub async fn post(&self, body: &crate::types::PostSkusRequest) -> Result<crate::types::Sku> {
         let url = self.client.url("/v1/skus", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -214,7 +282,11 @@ impl Skus {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_skus(&self, id: &str) -> Result<crate::types::Sku> {
+    pub async fn post_skus(
+        &self,
+        id: &str,
+        body: &crate::types::PostSkusRequestData,
+    ) -> Result<crate::types::Sku> {
         let url = self.client.url(
             &format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),),
             None,
@@ -223,7 +295,7 @@ impl Skus {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
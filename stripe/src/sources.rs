@@ -17,13 +17,16 @@ impl Sources {
      *
      * <p>Creates a new source object.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::SourceData> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSourcesRequest,
+    ) -> Result<crate::types::SourceData> {
         let url = self.client.url("/v1/sources", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -75,7 +78,11 @@ impl Sources {
      *
      * * `source: &str` -- The account's country.
      */
-    pub async fn post_sources(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_sources(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceRequest,
+    ) -> Result<crate::types::SourceData> {
         let url = self.client.url(
             &format!(
                 "/v1/sources/{}",
@@ -87,7 +94,7 @@ impl Sources {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -229,7 +236,7 @@ impl Sources {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -257,6 +264,65 @@ impl Sources {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>List source transactions for a given source.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        source: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SourceTransaction> {
+        let url = self.client.url(
+            &format!(
+                "/v1/sources/{}/source_transactions",
+                crate::progenitor_support::encode_path(source),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ApmsSourcesSourceTransactionList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions/{source_transaction}` endpoint.
      *
@@ -300,7 +366,11 @@ impl Sources {
      *
      * * `source: &str` -- The account's country.
      */
-    pub async fn post_verify(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_verify(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceVerifyRequest,
+    ) -> Result<crate::types::SourceData> {
         let url = self.client.url(
             &format!(
                 "/v1/sources/{}/verify",
@@ -312,7 +382,7 @@ impl Sources {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
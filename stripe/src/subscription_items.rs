@@ -110,7 +110,7 @@ impl SubscriptionItems {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -138,18 +138,81 @@ impl SubscriptionItems {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your subscription items for a given subscription.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        subscription: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SubscriptionItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscription_items?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Items = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_items` endpoint.
      *
      * <p>Adds a new item to an existing subscription. No existing items will be changed or replaced.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::SubscriptionItem> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionItemsRequest,
+    ) -> Result<crate::types::SubscriptionItem> {
         let url = self.client.url("/v1/subscription_items", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -192,7 +255,11 @@ impl SubscriptionItems {
      *
      * * `item: &str` -- The account's country.
      */
-    pub async fn post_item(&self, item: &str) -> Result<crate::types::SubscriptionItem> {
+    pub async fn post_item(
+        &self,
+        item: &str,
+        body: &crate::types::PostSubscriptionItemsItemRequest,
+    ) -> Result<crate::types::SubscriptionItem> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_items/{}",
@@ -204,7 +271,7 @@ impl SubscriptionItems {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -343,7 +410,7 @@ impl SubscriptionItems {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -371,6 +438,68 @@ impl SubscriptionItems {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_all_item_usage_record_summaries`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>For the specified subscription item, returns a list of summary objects. Each object in the list provides usage information that’s been summarized from multiple usage records and over a subscription billing period (e.g., 15 usage records in the month of September).</p>
+     *
+     * <p>The list is sorted in reverse-chronological order (newest first). The first list item represents the most current usage period that hasn’t ended yet. Since new usage records can still be added, the returned summary information for the subscription item’s ID should be seen as unstable until the subscription billing period ends.</p>
+     */
+    pub fn get_all_item_usage_record_summaries_stream(
+        &self,
+        subscription_item: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UsageRecordSummary> {
+        let url = self.client.url(
+            &format!(
+                "/v1/subscription_items/{}/usage_record_summaries",
+                crate::progenitor_support::encode_path(subscription_item),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse =
+                    client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_items/{subscription_item}/usage_records` endpoint.
      *
@@ -389,6 +518,7 @@ impl SubscriptionItems {
     pub async fn post_item_usage_record(
         &self,
         subscription_item: &str,
+        body: &crate::types::PostSubscriptionItemsItemUsageRecordsRequest,
     ) -> Result<crate::types::UsageRecord> {
         let url = self.client.url(
             &format!(
@@ -401,7 +531,7 @@ impl SubscriptionItems {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
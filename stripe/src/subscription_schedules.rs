@@ -134,7 +134,7 @@ impl SubscriptionSchedules {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -162,18 +162,89 @@ impl SubscriptionSchedules {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Retrieves the list of your subscription schedules.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        _released_at: &str,
+        scheduled: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SubscriptionSchedule> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if scheduled {
+            query_args.push(("scheduled".to_string(), scheduled.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscription_schedules?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetSubscriptionSchedulesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_schedules` endpoint.
      *
      * <p>Creates a new subscription schedule object. Each customer can have up to 500 active or scheduled subscriptions.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::SubscriptionSchedule> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionSchedulesRequest,
+    ) -> Result<crate::types::SubscriptionSchedule> {
         let url = self.client.url("/v1/subscription_schedules", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -219,6 +290,7 @@ impl SubscriptionSchedules {
     pub async fn post_schedule(
         &self,
         schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleRequest,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = self.client.url(
             &format!(
@@ -231,7 +303,7 @@ impl SubscriptionSchedules {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -249,6 +321,7 @@ impl SubscriptionSchedules {
     pub async fn post_schedule_cancel(
         &self,
         schedule: &str,
+        body: &crate::types::DeleteSubscriptionsSubscriptionExposedRequest,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = self.client.url(
             &format!(
@@ -261,7 +334,7 @@ impl SubscriptionSchedules {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -279,6 +352,7 @@ impl SubscriptionSchedules {
     pub async fn post_schedule_release(
         &self,
         schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleReleaseRequest,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = self.client.url(
             &format!(
@@ -291,7 +365,7 @@ impl SubscriptionSchedules {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
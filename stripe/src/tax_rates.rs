@@ -121,7 +121,7 @@ impl TaxRates {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,18 +149,84 @@ impl TaxRates {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your tax rates. Tax rates are returned sorted by creation date, with the most recently created tax rates appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        inclusive: bool,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TaxRate> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/tax_rates?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetTaxRatesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/tax_rates` endpoint.
      *
      * <p>Creates a new tax rate.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::TaxRate> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTaxRatesRequest,
+    ) -> Result<crate::types::TaxRate> {
         let url = self.client.url("/v1/tax_rates", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -203,7 +269,11 @@ impl TaxRates {
      *
      * * `tax_rate: &str` -- The account's country.
      */
-    pub async fn post_rate(&self, tax_rate: &str) -> Result<crate::types::TaxRate> {
+    pub async fn post_rate(
+        &self,
+        tax_rate: &str,
+        body: &crate::types::PostTaxRatesRateRequest,
+    ) -> Result<crate::types::TaxRate> {
         let url = self.client.url(
             &format!(
                 "/v1/tax_rates/{}",
@@ -215,7 +285,7 @@ impl TaxRates {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -17,13 +17,16 @@ impl Terminal {
      *
      * <p>To connect to a reader the Stripe Terminal SDK needs to retrieve a short-lived connection token from Stripe, proxied through your server. On your backend, add an endpoint that creates and returns a connection token.</p>
      */
-    pub async fn post_connection_token(&self) -> Result<crate::types::TerminalConnectionToken> {
+    pub async fn post_connection_token(
+        &self,
+        body: &crate::types::PostTerminalConnectionTokensRequest,
+    ) -> Result<crate::types::TerminalConnectionToken> {
         let url = self.client.url("/v1/terminal/connection_tokens", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -115,7 +118,7 @@ impl Terminal {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -143,19 +146,74 @@ impl Terminal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_all_locations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of <code>Location</code> objects.</p>
+     */
+    pub fn get_all_locations_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TerminalLocation> {
+        let url = self.client.url("/v1/terminal/locations", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TerminalLocationList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/terminal/locations` endpoint.
      *
      * <p>Creates a new <code>Location</code> object.
      * For further details, including which address fields are required in each country, see the <a href="/docs/terminal/fleet/locations">Manage locations</a> guide.</p>
      */
-    pub async fn post_location(&self) -> Result<crate::types::TerminalLocation> {
+    pub async fn post_location(
+        &self,
+        body: &crate::types::PostTerminalLocationsRequest,
+    ) -> Result<crate::types::TerminalLocation> {
         let url = self.client.url("/v1/terminal/locations", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -204,6 +262,7 @@ impl Terminal {
     pub async fn post_locations_location(
         &self,
         location: &str,
+        body: &crate::types::PostTerminalLocationRequest,
     ) -> Result<crate::types::GetTerminalLocationResponseAnyOf> {
         let url = self.client.url(
             &format!(
@@ -216,7 +275,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -371,7 +430,7 @@ impl Terminal {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -399,18 +458,89 @@ impl Terminal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_all_readers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of <code>Reader</code> objects.</p>
+     */
+    pub fn get_all_readers_stream(
+        &self,
+        device_type: crate::types::DeviceType,
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TerminalReader> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !device_type.to_string().is_empty() {
+            query_args.push(("device_type".to_string(), device_type.to_string()));
+        }
+        if !location.is_empty() {
+            query_args.push(("location".to_string(), location.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/terminal/readers?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TerminalReaderRetrieve = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/terminal/readers` endpoint.
      *
      * <p>Creates a new <code>Reader</code> object.</p>
      */
-    pub async fn post_reader(&self) -> Result<crate::types::TerminalReader> {
+    pub async fn post_reader(
+        &self,
+        body: &crate::types::PostTerminalReadersRequest,
+    ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url("/v1/terminal/readers", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -459,6 +589,7 @@ impl Terminal {
     pub async fn post_readers_reader(
         &self,
         reader: &str,
+        body: &crate::types::PostTerminalReadersReaderRequest,
     ) -> Result<crate::types::GetTerminalReadersReaderResponseAnyOf> {
         let url = self.client.url(
             &format!(
@@ -471,7 +602,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -519,6 +650,7 @@ impl Terminal {
     pub async fn post_readers_reader_cancel_action(
         &self,
         reader: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url(
             &format!(
@@ -531,7 +663,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -549,6 +681,7 @@ impl Terminal {
     pub async fn post_readers_reader_process_payment_intent(
         &self,
         reader: &str,
+        body: &crate::types::PostTerminalReadersReaderProcessPaymentIntentRequest,
     ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url(
             &format!(
@@ -561,7 +694,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -579,6 +712,7 @@ impl Terminal {
     pub async fn post_readers_reader_process_setup_intent(
         &self,
         reader: &str,
+        body: &crate::types::PostTerminalReadersReaderProcessSetupIntentRequest,
     ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url(
             &format!(
@@ -591,7 +725,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -609,6 +743,7 @@ impl Terminal {
     pub async fn post_readers_reader_set_display(
         &self,
         reader: &str,
+        body: &crate::types::PostTerminalReadersReaderSetReaderDisplayRequest,
     ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url(
             &format!(
@@ -621,7 +756,7 @@ impl Terminal {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
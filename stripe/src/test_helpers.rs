@@ -24,6 +24,7 @@ impl TestHelpers {
     pub async fn post_terminal_readers_reader_present_payment_method(
         &self,
         reader: &str,
+        body: &crate::types::PostTestHelpersTerminalReadersReaderPresentPaymentMethodRequest,
     ) -> Result<crate::types::TerminalReader> {
         let url = self.client.url(
             &format!(
@@ -36,7 +37,7 @@ impl TestHelpers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -128,7 +129,7 @@ impl TestHelpers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -156,18 +157,73 @@ impl TestHelpers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/test_helpers/test_clocks` endpoint.
+     *
+     * As opposed to `get_all_clocks`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of your test clocks.</p>
+     */
+    pub fn get_all_clocks_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TestClock> {
+        let url = self.client.url("/v1/test_helpers/test_clocks", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetTestHelpersClocksResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/test_helpers/test_clocks` endpoint.
      *
      * <p>Creates a new test clock that can be attached to new customers and quotes.</p>
      */
-    pub async fn post_clock(&self) -> Result<crate::types::TestClock> {
+    pub async fn post_clock(
+        &self,
+        body: &crate::types::PostTestHelpersTestClocksRequest,
+    ) -> Result<crate::types::TestClock> {
         let url = self.client.url("/v1/test_helpers/test_clocks", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,6 +299,7 @@ impl TestHelpers {
     pub async fn post_clocks_clock_advance(
         &self,
         test_clock: &str,
+        body: &crate::types::PostTestHelpersTestClocksTestClockAdvanceRequest,
     ) -> Result<crate::types::TestClock> {
         let url = self.client.url(
             &format!(
@@ -255,7 +312,7 @@ impl TestHelpers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
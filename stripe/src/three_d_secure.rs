@@ -17,13 +17,16 @@ impl ThreeDSecure {
      *
      * <p>Initiate 3D Secure authentication.</p>
      */
-    pub async fn post_3d_secure(&self) -> Result<crate::types::ThreeDSecure> {
+    pub async fn post_3d_secure(
+        &self,
+        body: &crate::types::Post3DSecureRequest,
+    ) -> Result<crate::types::ThreeDSecure> {
         let url = self.client.url("/v1/3d_secure", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,13 +18,16 @@ impl Tokens {
      * <p>Creates a single-use token that represents a bank account’s details.
      * This token can be used with any API method in place of a bank account dictionary. This token can be used only once, by attaching it to a <a href="#accounts">Custom account</a>.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Token> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTokensRequest,
+    ) -> Result<crate::types::Token> {
         let url = self.client.url("/v1/tokens", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
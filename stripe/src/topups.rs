@@ -115,7 +115,7 @@ impl Topups {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -143,18 +143,81 @@ impl Topups {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of top-ups.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _amount: &str,
+        _created: &str,
+        status: crate::types::GetTopupsStatus,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Topup> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/topups?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TopupList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/topups` endpoint.
      *
      * <p>Top up the balance of an account</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Topup> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTopupsRequest,
+    ) -> Result<crate::types::Topup> {
         let url = self.client.url("/v1/topups", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -197,7 +260,11 @@ impl Topups {
      *
      * * `topup: &str` -- The account's country.
      */
-    pub async fn post_topups(&self, topup: &str) -> Result<crate::types::Topup> {
+    pub async fn post_topups(
+        &self,
+        topup: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> Result<crate::types::Topup> {
         let url = self.client.url(
             &format!(
                 "/v1/topups/{}",
@@ -209,7 +276,7 @@ impl Topups {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -224,7 +291,11 @@ impl Topups {
      *
      * * `topup: &str` -- The account's country.
      */
-    pub async fn post_cancel(&self, topup: &str) -> Result<crate::types::Topup> {
+    pub async fn post_cancel(
+        &self,
+        topup: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> Result<crate::types::Topup> {
         let url = self.client.url(
             &format!(
                 "/v1/topups/{}/cancel",
@@ -236,7 +307,7 @@ impl Topups {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -121,7 +121,7 @@ impl Transfers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -149,18 +149,84 @@ impl Transfers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/transfers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>Returns a list of existing transfers sent to connected accounts. The transfers are returned in sorted order, with the most recently created transfers appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        destination: &str,
+        transfer_group: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Transfer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !destination.is_empty() {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        if !transfer_group.is_empty() {
+            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/transfers?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::TransferList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/transfers` endpoint.
      *
      * <p>To send funds from your Stripe account to a connected account, you create a new transfer object. Your <a href="#balance">Stripe balance</a> must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Transfer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTransfersRequest,
+    ) -> Result<crate::types::Transfer> {
         let url = self.client.url("/v1/transfers", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -265,7 +331,7 @@ impl Transfers {
                 resp = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &format!("{}?starting_after={}", url, page),
                         crate::Message {
                             body: None,
                             content_type: None,
@@ -293,6 +359,65 @@ impl Transfers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{id}/reversals` endpoint.
+     *
+     * As opposed to `get_all_reversals`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * <p>You can see a list of the reversals belonging to a specific transfer. Note that the 10 most recent reversals are always available by default on the transfer object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional reversals.</p>
+     */
+    pub fn get_all_reversals_stream(
+        &self,
+        id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::TransferReversal> {
+        let url = self.client.url(
+            &format!(
+                "/v1/transfers/{}/reversals",
+                crate::progenitor_support::encode_path(id),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}starting_after={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::Reversals = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                // The next page starts after the last item of this one.
+                let next = if resp.has_more {
+                    resp.data.last().map(|last| last.id.clone())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/v1/transfers/{id}/reversals` endpoint.
      *
@@ -306,7 +431,11 @@ impl Transfers {
      *
      * * `id: &str` -- The account's country.
      */
-    pub async fn post_reversal(&self, id: &str) -> Result<crate::types::TransferReversal> {
+    pub async fn post_reversal(
+        &self,
+        id: &str,
+        body: &crate::types::PostTransfersReversalsRequest,
+    ) -> Result<crate::types::TransferReversal> {
         let url = self.client.url(
             &format!(
                 "/v1/transfers/{}/reversals",
@@ -318,7 +447,7 @@ impl Transfers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -363,7 +492,11 @@ impl Transfers {
      *
      * * `transfer: &str` -- The account's country.
      */
-    pub async fn post_transfers(&self, transfer: &str) -> Result<crate::types::Transfer> {
+    pub async fn post_transfers(
+        &self,
+        transfer: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> Result<crate::types::Transfer> {
         let url = self.client.url(
             &format!(
                 "/v1/transfers/{}",
@@ -375,7 +508,7 @@ impl Transfers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -431,6 +564,7 @@ impl Transfers {
         &self,
         id: &str,
         transfer: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> Result<crate::types::TransferReversal> {
         let url = self.client.url(
             &format!(
@@ -444,7 +578,7 @@ impl Transfers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::form_urlencode(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -0,0 +1,337 @@
+//! Verifies and parses the events Stripe delivers to webhook endpoints.
+//!
+//! Stripe signs the raw body of each delivery with the signing secret of the
+//! endpoint, `whsec_…`, in the `Stripe-Signature` header. The header also
+//! carries the time of the signature, which is checked against a tolerance so a
+//! captured delivery can't be replayed later. [`WebhookEvent::construct`]
+//! checks both before parsing the body:
+//!
+//! ```
+//! use dolladollabills::webhooks::{EventData, WebhookEvent};
+//!
+//! fn handle(signature: &str, body: &[u8]) {
+//!     match WebhookEvent::construct("whsec_…", body, signature) {
+//!         Ok(WebhookEvent {
+//!             data: EventData::InvoicePaid(invoice),
+//!             ..
+//!         }) => println!("{} was paid", invoice.id),
+//!         Ok(event) => println!("ignoring {} {}", event.event.type_, event.event.id),
+//!         Err(e) => println!("rejecting the delivery: {}", e),
+//!     }
+//! }
+//! ```
+//!
+//! Only the invoice and subscription events have a typed object. The others,
+//! like `charge.succeeded`, keep their `data.object` as JSON in
+//! [`EventData::Unknown`].
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::types;
+
+/// How old a signature may be, like the official Stripe libraries.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// What is wrong with the `Stripe-Signature` header or the body of a delivery.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The `Stripe-Signature` header has no timestamp or no `v1` signature.
+    #[error("invalid Stripe-Signature header")]
+    InvalidHeader,
+    /// The body wasn't signed with the signing secret of the endpoint.
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The signature is older than the tolerance, or from the future.
+    #[error("the timestamp of the signature is outside the tolerance")]
+    TimestampOutsideTolerance,
+    /// The body isn't an event.
+    #[error("invalid payload: {0}")]
+    Payload(#[from] serde_json::Error),
+}
+
+/// Check the `Stripe-Signature` header of a delivery, like
+/// `t=1492774577,v1=5257a869…`, against its raw body. Any of the `v1`
+/// signatures may match, which happens while a secret is being rolled, and the
+/// `v0` signatures of test events are ignored. The timestamp is signed along
+/// with the body, so its tolerance is only checked once a signature matches.
+pub fn verify_signature(
+    secret: &str,
+    body: &[u8],
+    header: &str,
+    tolerance: Duration,
+) -> Result<(), WebhookError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for (key, value) in header
+        .split(',')
+        .filter_map(|item| item.trim().split_once('='))
+    {
+        match key {
+            "t" => timestamp = value.parse::<i64>().ok(),
+            "v1" => signatures.push(value),
+            _ => {}
+        }
+    }
+    let timestamp = timestamp.ok_or(WebhookError::InvalidHeader)?;
+    if signatures.is_empty() {
+        return Err(WebhookError::InvalidHeader);
+    }
+
+    let mac = signed_payload(secret, body, timestamp)?;
+    if !signatures
+        .iter()
+        .any(|signature| match hex::decode(signature) {
+            Ok(signature) => mac.clone().verify_slice(&signature).is_ok(),
+            Err(_) => false,
+        })
+    {
+        return Err(WebhookError::InvalidSignature);
+    }
+
+    let age = now() - timestamp;
+    if age.unsigned_abs() > tolerance.as_secs() {
+        return Err(WebhookError::TimestampOutsideTolerance);
+    }
+
+    Ok(())
+}
+
+/// Sign a body the way Stripe does at the given Unix time, and return the
+/// `Stripe-Signature` header to deliver it with. Meant for tests.
+pub fn generate_test_header(secret: &str, body: &[u8], timestamp: i64) -> String {
+    // The secret only errors when its length is invalid, which HMAC doesn't have.
+    let mac = signed_payload(secret, body, timestamp).unwrap();
+    format!(
+        "t={},v1={}",
+        timestamp,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+fn signed_payload(secret: &str, body: &[u8], timestamp: i64) -> Result<Hmac<Sha256>, WebhookError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|_| WebhookError::InvalidSignature)?;
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    Ok(mac)
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// The object of an event, by the `type` of the event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventData {
+    InvoiceCreated(types::Invoice),
+    InvoiceFinalized(types::Invoice),
+    InvoicePaid(types::Invoice),
+    InvoicePaymentFailed(types::Invoice),
+    InvoicePaymentSucceeded(types::Invoice),
+    InvoiceUpdated(types::Invoice),
+    InvoiceVoided(types::Invoice),
+    CustomerSubscriptionCreated(types::Subscription),
+    CustomerSubscriptionDeleted(types::Subscription),
+    CustomerSubscriptionTrialWillEnd(types::Subscription),
+    CustomerSubscriptionUpdated(types::Subscription),
+    /// The `data.object` of an event type without a variant here.
+    Unknown {
+        type_: String,
+        object: serde_json::Value,
+    },
+}
+
+impl EventData {
+    /// Deserialize the `data.object` of an event of the given `type`.
+    pub fn from_object(type_: &str, object: serde_json::Value) -> Result<Self, WebhookError> {
+        Ok(match type_ {
+            "invoice.created" => EventData::InvoiceCreated(serde_json::from_value(object)?),
+            "invoice.finalized" => EventData::InvoiceFinalized(serde_json::from_value(object)?),
+            "invoice.paid" => EventData::InvoicePaid(serde_json::from_value(object)?),
+            "invoice.payment_failed" => {
+                EventData::InvoicePaymentFailed(serde_json::from_value(object)?)
+            }
+            "invoice.payment_succeeded" => {
+                EventData::InvoicePaymentSucceeded(serde_json::from_value(object)?)
+            }
+            "invoice.updated" => EventData::InvoiceUpdated(serde_json::from_value(object)?),
+            "invoice.voided" => EventData::InvoiceVoided(serde_json::from_value(object)?),
+            "customer.subscription.created" => {
+                EventData::CustomerSubscriptionCreated(serde_json::from_value(object)?)
+            }
+            "customer.subscription.deleted" => {
+                EventData::CustomerSubscriptionDeleted(serde_json::from_value(object)?)
+            }
+            "customer.subscription.trial_will_end" => {
+                EventData::CustomerSubscriptionTrialWillEnd(serde_json::from_value(object)?)
+            }
+            "customer.subscription.updated" => {
+                EventData::CustomerSubscriptionUpdated(serde_json::from_value(object)?)
+            }
+            _ => EventData::Unknown {
+                type_: type_.to_string(),
+                object,
+            },
+        })
+    }
+
+    /// The invoice of an `invoice.*` event.
+    pub fn invoice(&self) -> Option<&types::Invoice> {
+        match self {
+            EventData::InvoiceCreated(i)
+            | EventData::InvoiceFinalized(i)
+            | EventData::InvoicePaid(i)
+            | EventData::InvoicePaymentFailed(i)
+            | EventData::InvoicePaymentSucceeded(i)
+            | EventData::InvoiceUpdated(i)
+            | EventData::InvoiceVoided(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// The subscription of a `customer.subscription.*` event.
+    pub fn subscription(&self) -> Option<&types::Subscription> {
+        match self {
+            EventData::CustomerSubscriptionCreated(s)
+            | EventData::CustomerSubscriptionDeleted(s)
+            | EventData::CustomerSubscriptionTrialWillEnd(s)
+            | EventData::CustomerSubscriptionUpdated(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// An event delivered to a webhook endpoint.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookEvent {
+    /// The id, type, time, account and request of the event. Its `data` is
+    /// empty: the object is in `data` below.
+    pub event: types::Event,
+    /// The object of the event.
+    pub data: EventData,
+    /// The previous values of the updated attributes, for `*.updated` events.
+    pub previous_attributes: Option<serde_json::Value>,
+}
+
+impl WebhookEvent {
+    /// Parse the body of a delivery. The signature isn't checked.
+    pub fn parse(body: &[u8]) -> Result<Self, WebhookError> {
+        let mut value: serde_json::Value = serde_json::from_slice(body)?;
+
+        let mut object = serde_json::Value::Null;
+        let mut previous_attributes = None;
+        if let Some(data) = value.get_mut("data") {
+            if let Some(o) = data.get_mut("object") {
+                object = std::mem::replace(o, serde_json::json!({}));
+            }
+            previous_attributes = data
+                .get("previous_attributes")
+                .filter(|p| !p.is_null())
+                .cloned();
+        }
+
+        let event: types::Event = serde_json::from_value(value)?;
+        let data = EventData::from_object(&event.type_, object)?;
+
+        Ok(WebhookEvent {
+            event,
+            data,
+            previous_attributes,
+        })
+    }
+
+    /// Verify the `Stripe-Signature` header of a delivery with the signing
+    /// secret of the endpoint, within the [`DEFAULT_TOLERANCE`], then parse its
+    /// body.
+    pub fn construct(secret: &str, body: &[u8], signature: &str) -> Result<Self, WebhookError> {
+        WebhookEvent::construct_with_tolerance(secret, body, signature, DEFAULT_TOLERANCE)
+    }
+
+    /// Like [`WebhookEvent::construct`], with another tolerance for the time of
+    /// the signature.
+    pub fn construct_with_tolerance(
+        secret: &str,
+        body: &[u8],
+        signature: &str,
+        tolerance: Duration,
+    ) -> Result<Self, WebhookError> {
+        verify_signature(secret, body, signature, tolerance)?;
+        WebhookEvent::parse(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test_secret";
+    const BODY: &[u8] = br#"{"id": "evt_test_webhook", "object": "event"}"#;
+
+    #[test]
+    fn accepts_any_v1_signature_while_rolling_secrets() {
+        let header = generate_test_header(SECRET, BODY, now());
+        verify_signature(SECRET, BODY, &header, DEFAULT_TOLERANCE).unwrap();
+
+        // While a secret is rolled, the header has a signature for each.
+        let rolled = header.replacen(",v1=", &format!(",v1={},v0=ab,v1=", "0".repeat(64)), 1);
+        verify_signature(SECRET, BODY, &rolled, DEFAULT_TOLERANCE).unwrap();
+    }
+
+    #[test]
+    fn rejects_malformed_headers_and_other_secrets() {
+        let header = generate_test_header(SECRET, BODY, now());
+        assert!(matches!(
+            verify_signature("whsec_another_secret", BODY, &header, DEFAULT_TOLERANCE),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_signature(SECRET, b"{}", &header, DEFAULT_TOLERANCE),
+            Err(WebhookError::InvalidSignature)
+        ));
+
+        for header in ["", "v1=abcd", "t=12345", "t=now,v1=abcd"] {
+            assert!(matches!(
+                verify_signature(SECRET, BODY, header, DEFAULT_TOLERANCE),
+                Err(WebhookError::InvalidHeader)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_replayed_signatures() {
+        let header = generate_test_header(SECRET, BODY, now() - 600);
+        assert!(matches!(
+            verify_signature(SECRET, BODY, &header, DEFAULT_TOLERANCE),
+            Err(WebhookError::TimestampOutsideTolerance)
+        ));
+        verify_signature(SECRET, BODY, &header, Duration::from_secs(900)).unwrap();
+    }
+
+    #[test]
+    fn keeps_the_object_of_other_event_types() {
+        let event = WebhookEvent::parse(
+            br#"{
+                "id": "evt_1",
+                "object": "event",
+                "type": "charge.succeeded",
+                "data": {"object": {"id": "ch_1", "object": "charge"}}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(event.event.id, "evt_1");
+        assert_eq!(event.previous_attributes, None);
+        match event.data {
+            EventData::Unknown { type_, object } => {
+                assert_eq!(type_, "charge.succeeded");
+                assert_eq!(object["id"], "ch_1");
+            }
+            other => panic!("expected an unknown event, got {:?}", other),
+        }
+    }
+}
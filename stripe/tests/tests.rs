@@ -1 +1,201 @@
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use dolladollabills::{Client, ClientError, Credentials};
+
+#[tokio::test]
+async fn test_default_error_response_is_decoded() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/cus_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": {
+                "code": "resource_missing",
+                "message": "No such customer: 'cus_missing'",
+                "param": "id",
+                "type": "invalid_request_error"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("sk_test");
+    client.with_host_override(server.uri());
+
+    let err = client.customers().get("cus_missing").await.unwrap_err();
+    match err.downcast_ref::<ClientError>() {
+        Some(ClientError::Error { status, error, .. }) => {
+            assert_eq!(status.as_u16(), 404);
+            assert_eq!(error.error.code, "resource_missing");
+            assert_eq!(error.error.param, "id");
+        }
+        other => panic!("expected a decoded Error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_basic_auth_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/cus_missing"))
+        // base64 of "sk_test:".
+        .and(header("authorization", "Basic c2tfdGVzdDo="))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": {
+                "code": "resource_missing",
+                "message": "No such customer: 'cus_missing'",
+                "param": "id",
+                "type": "invalid_request_error"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("unused");
+    client
+        .with_host_override(server.uri())
+        .with_credentials(Credentials::BasicAuth {
+            username: "sk_test".to_string(),
+            password: String::new(),
+        });
+
+    let err = client.customers().get("cus_missing").await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ClientError>(),
+        Some(ClientError::Error { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_retries_posts_with_the_same_idempotency_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customers"))
+        .and(header("idempotency-key", "order-1234"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // Retry once, without waiting long.
+    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder()
+        .retry_bounds(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(10),
+        )
+        .build_with_max_retries(1);
+    let http = dolladollabills::ClientBuilder::new()
+        .retry_policy(retry_policy)
+        .build()
+        .unwrap();
+
+    let mut client = Client::new("sk_test");
+    client
+        .with_host_override(server.uri())
+        .with_http_client(http);
+
+    let result = client
+        .with_idempotency_key("order-1234")
+        .customers()
+        .post(&Default::default())
+        .await;
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_constructs_signed_subscription_updates() {
+    use dolladollabills::{
+        types::SubscriptionStatus,
+        webhooks::{generate_test_header, EventData, WebhookError, WebhookEvent},
+    };
+
+    let body = serde_json::to_vec(&serde_json::json!({
+        "id": "evt_1",
+        "object": "event",
+        "api_version": "2020-08-27",
+        "created": 1680000000,
+        "livemode": false,
+        "pending_webhooks": 1,
+        "type": "customer.subscription.updated",
+        "data": {
+            "object": {
+                "id": "sub_1",
+                "object": "subscription",
+                "automatic_tax": {"enabled": false},
+                "customer": "cus_1",
+                "items": {"object": "list", "data": [], "has_more": false, "url": "/v1/subscription_items"},
+                "status": "active"
+            },
+            "previous_attributes": {"status": "trialing"}
+        }
+    }))
+    .unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let signature = generate_test_header("whsec_test", &body, now);
+
+    let event = WebhookEvent::construct("whsec_test", &body, &signature).unwrap();
+    assert_eq!(event.event.id, "evt_1");
+    assert_eq!(event.event.type_, "customer.subscription.updated");
+    assert_eq!(
+        event.previous_attributes,
+        Some(serde_json::json!({"status": "trialing"}))
+    );
+    match &event.data {
+        EventData::CustomerSubscriptionUpdated(subscription) => {
+            assert_eq!(subscription.id, "sub_1");
+            assert_eq!(subscription.status, SubscriptionStatus::Active);
+        }
+        other => panic!("expected a subscription update, got {:?}", other),
+    }
+
+    // A delivery signed long ago is a replay.
+    let replayed = generate_test_header("whsec_test", &body, now - 3600);
+    assert!(matches!(
+        WebhookEvent::construct("whsec_test", &body, &replayed),
+        Err(WebhookError::TimestampOutsideTolerance)
+    ));
+}
+
+#[tokio::test]
+async fn test_generated_mocks() {
+    let mock = dolladollabills::mock::Mock::start().await;
+
+    let mut client = Client::new("sk_test");
+    mock.point(&mut client);
+
+    mock.tax_codes()
+        .get_page()
+        .with_query("limit", "1")
+        .expect(1)
+        .responds_with(
+            serde_json::from_value(serde_json::json!({
+                "object": "list",
+                "data": [{"id": "txcd_99999999", "object": "tax_code", "name": "General - Tangible Goods"}],
+                "has_more": true,
+                "url": "/v1/tax_codes"
+            }))
+            .unwrap(),
+        )
+        .await;
+    mock.customers()
+        .get("cus_missing")
+        .responds_with_status(404)
+        .await;
+
+    let tax_codes = client.tax_codes().get_page("", 1, "").await.unwrap();
+    assert_eq!(tax_codes.len(), 1);
+    assert_eq!(tax_codes[0].id, "txcd_99999999");
+    assert_eq!(tax_codes[0].name, "General - Tangible Goods");
+
+    assert!(client.customers().get("cus_missing").await.is_err());
+}
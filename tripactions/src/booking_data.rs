@@ -181,4 +181,82 @@ impl BookingData {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Return booking rows filtered by the parameters you select.
+     */
+    pub fn get_all_booking_report_stream(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::BookingReport> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !booking_status.to_string().is_empty() {
+            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+        }
+        if !booking_type.to_string().is_empty() {
+            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+        }
+        if !created_from.is_empty() {
+            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+        }
+        if !created_to.is_empty() {
+            query_args.push(("createdTo".to_string(), created_to.to_string()));
+        }
+        if !start_date_from.is_empty() {
+            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+        }
+        if !start_date_to.is_empty() {
+            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
+
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let page = page.unwrap_or_else(|| "0".to_string());
+                let url = format!(
+                    "{}{}page={}&size=100",
+                    url,
+                    if url.contains('?') { '&' } else { '?' },
+                    page
+                );
+                let resp: crate::types::BookingReportResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = if resp.page.current_page + 1 < resp.page.total_pages {
+                    Some((resp.page.current_page + 1).to_string())
+                } else {
+                    None
+                };
+
+                Ok(crate::utils::Page {
+                    items: resp.data,
+                    next,
+                })
+            }
+        })
+    }
 }
@@ -2,7 +2,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TravelerType {
     #[serde(rename = "GUEST")]
     Guest,
@@ -10,8 +10,8 @@ pub enum TravelerType {
     Passenger,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TravelerType {
@@ -20,12 +20,36 @@ impl std::fmt::Display for TravelerType {
             TravelerType::Guest => "GUEST",
             TravelerType::Passenger => "PASSENGER",
             TravelerType::Noop => "",
-            TravelerType::FallthroughString => "*",
+            TravelerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TravelerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TravelerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "GUEST" => TravelerType::Guest,
+            "PASSENGER" => TravelerType::Passenger,
+            "" => TravelerType::Noop,
+            _ => TravelerType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TravelerType {
     fn default() -> TravelerType {
         TravelerType::Noop
@@ -35,9 +59,14 @@ impl TravelerType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TravelerType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TravelerType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BookingStatus {
     #[serde(rename = "ACCEPTED")]
     Accepted,
@@ -51,8 +80,8 @@ pub enum BookingStatus {
     Voided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingStatus {
@@ -64,12 +93,39 @@ impl std::fmt::Display for BookingStatus {
             BookingStatus::Ticketed => "TICKETED",
             BookingStatus::Voided => "VOIDED",
             BookingStatus::Noop => "",
-            BookingStatus::FallthroughString => "*",
+            BookingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BookingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BookingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ACCEPTED" => BookingStatus::Accepted,
+            "CANCELED" => BookingStatus::Canceled,
+            "CONFIRMED" => BookingStatus::Confirmed,
+            "TICKETED" => BookingStatus::Ticketed,
+            "VOIDED" => BookingStatus::Voided,
+            "" => BookingStatus::Noop,
+            _ => BookingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for BookingStatus {
     fn default() -> BookingStatus {
         BookingStatus::Noop
@@ -79,9 +135,14 @@ impl BookingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, BookingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BookingStatus::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum BookingType {
     #[serde(rename = "BLACK_CAR")]
     BlackCar,
@@ -97,8 +158,8 @@ pub enum BookingType {
     Transportation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingType {
@@ -111,12 +172,40 @@ impl std::fmt::Display for BookingType {
             BookingType::Rail => "RAIL",
             BookingType::Transportation => "TRANSPORTATION",
             BookingType::Noop => "",
-            BookingType::FallthroughString => "*",
+            BookingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for BookingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BookingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "BLACK_CAR" => BookingType::BlackCar,
+            "CAR" => BookingType::Car,
+            "FLIGHT" => BookingType::Flight,
+            "HOTEL" => BookingType::Hotel,
+            "RAIL" => BookingType::Rail,
+            "TRANSPORTATION" => BookingType::Transportation,
+            "" => BookingType::Noop,
+            _ => BookingType::Unknown(s),
+        })
+    }
+}
+
 impl Default for BookingType {
     fn default() -> BookingType {
         BookingType::Noop
@@ -126,9 +215,14 @@ impl BookingType {
     pub fn is_noop(&self) -> bool {
         matches!(self, BookingType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, BookingType::Unknown(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PaymentSchedule {
     #[serde(rename = "LATER")]
     Later,
@@ -140,8 +234,8 @@ pub enum PaymentSchedule {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -152,12 +246,38 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Partial => "PARTIAL",
             PaymentSchedule::Unknown => "UNKNOWN",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PaymentSchedule {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentSchedule {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "LATER" => PaymentSchedule::Later,
+            "NOW" => PaymentSchedule::Now,
+            "PARTIAL" => PaymentSchedule::Partial,
+            "UNKNOWN" => PaymentSchedule::Unknown,
+            "" => PaymentSchedule::Noop,
+            _ => PaymentSchedule::UnknownValue(s),
+        })
+    }
+}
+
 impl Default for PaymentSchedule {
     fn default() -> PaymentSchedule {
         PaymentSchedule::Noop
@@ -167,9 +287,14 @@ impl PaymentSchedule {
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentSchedule::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PaymentSchedule::UnknownValue(_))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PassengerStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -177,8 +302,8 @@ pub enum PassengerStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PassengerStatus {
@@ -187,12 +312,36 @@ impl std::fmt::Display for PassengerStatus {
             PassengerStatus::Disabled => "DISABLED",
             PassengerStatus::Enabled => "ENABLED",
             PassengerStatus::Noop => "",
-            PassengerStatus::FallthroughString => "*",
+            PassengerStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PassengerStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PassengerStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DISABLED" => PassengerStatus::Disabled,
+            "ENABLED" => PassengerStatus::Enabled,
+            "" => PassengerStatus::Noop,
+            _ => PassengerStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PassengerStatus {
     fn default() -> PassengerStatus {
         PassengerStatus::Noop
@@ -202,6 +351,11 @@ impl PassengerStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PassengerStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PassengerStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -161,6 +161,74 @@ impl Archiving {
         // Return our response data.
         Ok(meetings)
     }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_all_archived_files`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Zoom’s [archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) allows account administrators to set up an automated mechanism to record, collect and archive meeting data to a 3rd party platform of their choice and hence, satisfy FINRA and/ or other compliance requirements.<br><br>
+     * Use this API to retrieve archived meeting or webinar files of an account.
+     *
+     * **Scope:** `recording:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br><br>
+     * **Prerequisites:** <br>
+     * * Enable cloud recording.
+     * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     */
+    pub fn list_all_archived_files_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListArchivedFilesResponseMeetings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/archive_files?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListArchivedFilesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.meetings,
+                })
+            }
+        })
+    }
     /**
      * Get meeting archived files.
      *
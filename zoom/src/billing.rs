@@ -500,7 +500,7 @@ impl Billing {
      * * You can make **one** request to this API every **30 minutes** until the daily limit is reached.
      * * This API has a daily limit of **100 requests per account**.
      */
-    pub async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<()> {
+    pub async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<crate::utils::Download> {
         let url = self.client.url(
             &format!(
                 "/api/download/billing/invoices/{}",
@@ -509,13 +509,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+            .download(reqwest::Method::GET, &url, "*/*")
             .await
     }
 }
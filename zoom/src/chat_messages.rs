@@ -201,6 +201,91 @@ impl ChatMessages {
         // Return our response data.
         Ok(messages)
     }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list the current user's chat messages between the user and an individual contact or a chat channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide one of the following:
+     *
+     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
+     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Messages> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message".to_string(),
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetChatMessagesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.messages,
+                })
+            }
+        })
+    }
     /**
      * Send a chat message.
      *
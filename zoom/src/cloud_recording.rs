@@ -810,4 +810,80 @@ impl CloudRecording {
         // Return our response data.
         Ok(meetings)
     }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_account`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List [Cloud Recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) available on an Account.
+     *
+     * > To access a password protected cloud recording, add an "access_token" parameter to the download URL and provide [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the value of the "access_token".
+     * <br>
+     * **Prerequisites**:<br>
+     * * A Pro or a higher paid plan with Cloud Recording option enabled.<br>
+     * **Scopes**: `recording:read:admin` or `account:read:admin`
+     *
+     * If the scope `recording:read:admin` is used, the Account ID of the Account must be provided in the `accountId` path parameter to list recordings that belong to the Account. This scope only works for sub accounts.
+     *
+     * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn get_all_account_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetAccountCloudRecordingResponseMeetings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/recordings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountCloudRecordingResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.meetings,
+                })
+            }
+        })
+    }
 }
@@ -1433,6 +1433,82 @@ impl Dashboards {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_all_call_logs_metrics`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Call logs provide a record of all incoming and outgoing calls over Zoom Phone in an account.
+     *
+     * Use this API to list monthly call logs metrics. You can use query parameters to filter the response by date, site and MOS(Mean Opinion Score) of the call.
+     *
+     * **Prerequisites:**
+     * * Business, or Education account
+     * * Zoom Phone license <br><br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
+     * **Rate Limit Label:** `Heavy`
+     */
+    pub fn list_all_call_logs_metrics_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListCallLogsMetricsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/metrics/call_logs?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListCallLogsMetricsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.call_logs,
+                })
+            }
+        })
+    }
     /**
      * Get call details from call log.
      *
@@ -1664,6 +1740,75 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_feedback`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * When a meeting ends, each attendee will be prompted to share their meeting experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific meeting. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     * <br> **Scope:** `dashboard_meetings:read:admiin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ParticipantFeedbackResponseParticipants> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ParticipantFeedbackResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.participants,
+                })
+            }
+        })
+    }
     /**
      * Get post webinar feedback.
      *
@@ -1820,4 +1965,74 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_webinar_feedback`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     *
+     * <br> **Scope:** `dashboard_webinars:read:admin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_webinar_feedback_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ParticipantFeedbackResponseParticipants> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ParticipantFeedbackResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.participants,
+                })
+            }
+        })
+    }
 }
@@ -312,6 +312,65 @@ impl Groups {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List the members of a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) under your account.
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_members_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UserCreateResponse> {
+        let url = self.client.url(
+            &format!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GroupMembersResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.members,
+                })
+            }
+        })
+    }
     /**
      * Add group members.
      *
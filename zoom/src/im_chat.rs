@@ -295,6 +295,80 @@ impl ImChat {
         // Return our response data.
         Ok(messages)
     }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_all_im_messages`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Get IM Chat messages for a specified period of time. This API only supports Oauth2.<br>
+     * **Scopes:** `imchat:read`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     */
+    pub fn list_all_im_messages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListimmessagesResponseMessages> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+        }
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/im/users/{}/chat/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListimmessagesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.messages,
+                })
+            }
+        })
+    }
     /**
      * Send IM messages.
      *
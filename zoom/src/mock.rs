@@ -0,0 +1,5597 @@
+//! Mocks of the API, served by a local [`wiremock::MockServer`].
+//!
+//! Every function of the client has a mock of the same name, taking the path
+//! parameters of the function. The request is mocked once it is given a
+//! response.
+//!
+//! ```ignore
+//! let mock = Mock::start().await;
+//! mock.point(&mut client);
+//!
+//! mock.pulls()
+//!     .list("owner", "repo")
+//!     .responds_with(vec![pull_request])
+//!     .await;
+//! ```
+use std::marker::PhantomData;
+
+use wiremock::{matchers, ResponseTemplate};
+
+/// A local server mocking the API.
+pub struct Mock {
+    server: wiremock::MockServer,
+}
+
+impl Mock {
+    /// Start the server, on a random local port.
+    pub async fn start() -> Self {
+        Mock {
+            server: wiremock::MockServer::start().await,
+        }
+    }
+
+    /// The URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The wiremock server, to mount other mocks on or look at the requests
+    /// it received.
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    /// Send the requests of the client to the server.
+    pub fn point<'c>(&self, client: &'c mut crate::Client) -> &'c mut crate::Client {
+        client.with_host_override(self.uri())
+    }
+
+    fn operation<T>(&self, method: reqwest::Method, path: String) -> MockOperation<'_, T> {
+        MockOperation {
+            server: &self.server,
+            method,
+            path,
+            query: Vec::new(),
+            expect: None,
+            response: PhantomData,
+        }
+    }
+}
+
+/// The response of a function that doesn't return JSON, like a file download.
+/// Its mock responds with [`MockOperation::responds_with_body`].
+pub struct Raw;
+
+/// A mocked request, mounted on the server once it is given a response.
+#[must_use = "the request is only mocked once it is given a response"]
+pub struct MockOperation<'a, T> {
+    server: &'a wiremock::MockServer,
+    method: reqwest::Method,
+    path: String,
+    query: Vec<(String, String)>,
+    expect: Option<u64>,
+    response: PhantomData<T>,
+}
+
+impl<T> MockOperation<'_, T> {
+    /// Only match requests with this query parameter.
+    pub fn with_query<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expect the request to be made this many times. This is checked when
+    /// the server is dropped.
+    pub fn expect(mut self, times: u64) -> Self {
+        self.expect = Some(times);
+        self
+    }
+
+    /// Respond with an empty body and this status.
+    pub async fn responds_with_status(self, status: u16) {
+        self.responds_with_template(ResponseTemplate::new(status))
+            .await
+    }
+
+    /// Respond with a body that isn't JSON.
+    pub async fn responds_with_body<B>(self, body: B, content_type: &str)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_raw(body, content_type))
+            .await
+    }
+
+    /// Respond with any response, like an error.
+    pub async fn responds_with_template(self, response: ResponseTemplate) {
+        let mut mock = wiremock::Mock::given(matchers::method(self.method.as_str()))
+            .and(matchers::path(self.path));
+        for (key, value) in self.query {
+            mock = mock.and(matchers::query_param(key, value));
+        }
+
+        let mut mock = mock.respond_with(response);
+        if let Some(times) = self.expect {
+            mock = mock.expect(times);
+        }
+        mock.mount(self.server).await;
+    }
+}
+
+impl<T> MockOperation<'_, T>
+where
+    T: serde::Serialize,
+{
+    /// Respond with this body, as JSON.
+    pub async fn responds_with(self, body: T) {
+        self.responds_with_template(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+}
+
+impl Mock {
+    /// Mock the functions of [`Accounts`](crate::accounts::Accounts).
+    pub fn accounts(&self) -> Accounts<'_> {
+        Accounts { mock: self }
+    }
+
+    /// Mock the functions of [`Archiving`](crate::archiving::Archiving).
+    pub fn archiving(&self) -> Archiving<'_> {
+        Archiving { mock: self }
+    }
+
+    /// Mock the functions of [`Billing`](crate::billing::Billing).
+    pub fn billing(&self) -> Billing<'_> {
+        Billing { mock: self }
+    }
+
+    /// Mock the functions of [`ChatChannels`](crate::chat_channels::ChatChannels).
+    pub fn chat_channels(&self) -> ChatChannels<'_> {
+        ChatChannels { mock: self }
+    }
+
+    /// Mock the functions of [`ChatChannelsAccountLevel`](crate::chat_channels_account_level::ChatChannelsAccountLevel).
+    pub fn chat_channels_account_level(&self) -> ChatChannelsAccountLevel<'_> {
+        ChatChannelsAccountLevel { mock: self }
+    }
+
+    /// Mock the functions of [`ChatMessages`](crate::chat_messages::ChatMessages).
+    pub fn chat_messages(&self) -> ChatMessages<'_> {
+        ChatMessages { mock: self }
+    }
+
+    /// Mock the functions of [`ChatbotMessages`](crate::chatbot_messages::ChatbotMessages).
+    pub fn chatbot_messages(&self) -> ChatbotMessages<'_> {
+        ChatbotMessages { mock: self }
+    }
+
+    /// Mock the functions of [`CloudRecording`](crate::cloud_recording::CloudRecording).
+    pub fn cloud_recording(&self) -> CloudRecording<'_> {
+        CloudRecording { mock: self }
+    }
+
+    /// Mock the functions of [`CommonAreaPhones`](crate::common_area_phones::CommonAreaPhones).
+    pub fn common_area_phones(&self) -> CommonAreaPhones<'_> {
+        CommonAreaPhones { mock: self }
+    }
+
+    /// Mock the functions of [`Contacts`](crate::contacts::Contacts).
+    pub fn contacts(&self) -> Contacts<'_> {
+        Contacts { mock: self }
+    }
+
+    /// Mock the functions of [`Dashboards`](crate::dashboards::Dashboards).
+    pub fn dashboards(&self) -> Dashboards<'_> {
+        Dashboards { mock: self }
+    }
+
+    /// Mock the functions of [`DeprecatedApiEndpoints`](crate::deprecated_api_endpoints::DeprecatedApiEndpoints).
+    pub fn deprecated_api_endpoints(&self) -> DeprecatedApiEndpoints<'_> {
+        DeprecatedApiEndpoints { mock: self }
+    }
+
+    /// Mock the functions of [`Devices`](crate::devices::Devices).
+    pub fn devices(&self) -> Devices<'_> {
+        Devices { mock: self }
+    }
+
+    /// Mock the functions of [`Groups`](crate::groups::Groups).
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { mock: self }
+    }
+
+    /// Mock the functions of [`ImChat`](crate::im_chat::ImChat).
+    pub fn im_chat(&self) -> ImChat<'_> {
+        ImChat { mock: self }
+    }
+
+    /// Mock the functions of [`ImGroups`](crate::im_groups::ImGroups).
+    pub fn im_groups(&self) -> ImGroups<'_> {
+        ImGroups { mock: self }
+    }
+
+    /// Mock the functions of [`Meetings`](crate::meetings::Meetings).
+    pub fn meetings(&self) -> Meetings<'_> {
+        Meetings { mock: self }
+    }
+
+    /// Mock the functions of [`Pac`](crate::pac::Pac).
+    pub fn pac(&self) -> Pac<'_> {
+        Pac { mock: self }
+    }
+
+    /// Mock the functions of [`Phone`](crate::phone::Phone).
+    pub fn phone(&self) -> Phone<'_> {
+        Phone { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneAutoReceptionists`](crate::phone_auto_receptionists::PhoneAutoReceptionists).
+    pub fn phone_auto_receptionists(&self) -> PhoneAutoReceptionists<'_> {
+        PhoneAutoReceptionists { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneBlockedList`](crate::phone_blocked_list::PhoneBlockedList).
+    pub fn phone_blocked_list(&self) -> PhoneBlockedList<'_> {
+        PhoneBlockedList { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneCallQueues`](crate::phone_call_queues::PhoneCallQueues).
+    pub fn phone_call_queues(&self) -> PhoneCallQueues<'_> {
+        PhoneCallQueues { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneDevices`](crate::phone_devices::PhoneDevices).
+    pub fn phone_devices(&self) -> PhoneDevices<'_> {
+        PhoneDevices { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneReports`](crate::phone_reports::PhoneReports).
+    pub fn phone_reports(&self) -> PhoneReports<'_> {
+        PhoneReports { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneSharedLineGroups`](crate::phone_shared_line_groups::PhoneSharedLineGroups).
+    pub fn phone_shared_line_groups(&self) -> PhoneSharedLineGroups<'_> {
+        PhoneSharedLineGroups { mock: self }
+    }
+
+    /// Mock the functions of [`PhoneSite`](crate::phone_site::PhoneSite).
+    pub fn phone_site(&self) -> PhoneSite<'_> {
+        PhoneSite { mock: self }
+    }
+
+    /// Mock the functions of [`Reports`](crate::reports::Reports).
+    pub fn reports(&self) -> Reports<'_> {
+        Reports { mock: self }
+    }
+
+    /// Mock the functions of [`Roles`](crate::roles::Roles).
+    pub fn roles(&self) -> Roles<'_> {
+        Roles { mock: self }
+    }
+
+    /// Mock the functions of [`Rooms`](crate::rooms::Rooms).
+    pub fn rooms(&self) -> Rooms<'_> {
+        Rooms { mock: self }
+    }
+
+    /// Mock the functions of [`RoomsAccount`](crate::rooms_account::RoomsAccount).
+    pub fn rooms_account(&self) -> RoomsAccount<'_> {
+        RoomsAccount { mock: self }
+    }
+
+    /// Mock the functions of [`RoomsDevices`](crate::rooms_devices::RoomsDevices).
+    pub fn rooms_devices(&self) -> RoomsDevices<'_> {
+        RoomsDevices { mock: self }
+    }
+
+    /// Mock the functions of [`RoomsLocation`](crate::rooms_location::RoomsLocation).
+    pub fn rooms_location(&self) -> RoomsLocation<'_> {
+        RoomsLocation { mock: self }
+    }
+
+    /// Mock the functions of [`SipConnectedAudio`](crate::sip_connected_audio::SipConnectedAudio).
+    pub fn sip_connected_audio(&self) -> SipConnectedAudio<'_> {
+        SipConnectedAudio { mock: self }
+    }
+
+    /// Mock the functions of [`SipPhone`](crate::sip_phone::SipPhone).
+    pub fn sip_phone(&self) -> SipPhone<'_> {
+        SipPhone { mock: self }
+    }
+
+    /// Mock the functions of [`TrackingField`](crate::tracking_field::TrackingField).
+    pub fn tracking_field(&self) -> TrackingField<'_> {
+        TrackingField { mock: self }
+    }
+
+    /// Mock the functions of [`Tsp`](crate::tsp::Tsp).
+    pub fn tsp(&self) -> Tsp<'_> {
+        Tsp { mock: self }
+    }
+
+    /// Mock the functions of [`Users`](crate::users::Users).
+    pub fn users(&self) -> Users<'_> {
+        Users { mock: self }
+    }
+
+    /// Mock the functions of [`Webinars`](crate::webinars::Webinars).
+    pub fn webinars(&self) -> Webinars<'_> {
+        Webinars { mock: self }
+    }
+}
+
+pub struct Accounts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Accounts<'a> {
+    /// Mock [`Accounts::get`](crate::accounts::Accounts::get), a `GET` to `/accounts`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/accounts".to_string())
+    }
+
+    /// Mock [`Accounts::create`](crate::accounts::Accounts::create), a `POST` to `/accounts`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::AccountCreateResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/accounts".to_string())
+    }
+
+    /// Mock [`Accounts::account`](crate::accounts::Accounts::account), a `GET` to `/accounts/{accountId}`.
+    pub fn account(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::disassociate`](crate::accounts::Accounts::disassociate), a `DELETE` to `/accounts/{accountId}`.
+    pub fn disassociate(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::options_update`](crate::accounts::Accounts::options_update), a `PATCH` to `/accounts/{accountId}/options`.
+    pub fn options_update(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/options",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_domains`](crate::accounts::Accounts::settings_domains), a `GET` to `/accounts/{accountId}/settings`.
+    pub fn settings_domains(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_security`](crate::accounts::Accounts::settings_security), a `GET` to `/accounts/{accountId}/settings`.
+    pub fn settings_security(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Security> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_account`](crate::accounts::Accounts::settings_account), a `GET` to `/accounts/{accountId}/settings`.
+    pub fn settings_account(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_meeting_security`](crate::accounts::Accounts::settings_meeting_security), a `GET` to `/accounts/{accountId}/settings`.
+    pub fn settings_meeting_security(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingSecuritySettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::setting`](crate::accounts::Accounts::setting), a `GET` to `/accounts/{accountId}/settings`.
+    pub fn setting(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountSettingsResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::settings_update`](crate::accounts::Accounts::settings_update), a `PATCH` to `/accounts/{accountId}/settings`.
+    pub fn settings_update(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::managed_domain`](crate::accounts::Accounts::managed_domain), a `GET` to `/accounts/{accountId}/managed_domains`.
+    pub fn managed_domain(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DomainsList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/managed_domains",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::trusted_domain`](crate::accounts::Accounts::trusted_domain), a `GET` to `/accounts/{accountId}/trusted_domains`.
+    pub fn trusted_domain(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountTrustedDomainResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/trusted_domains",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::get_lock_setting`](crate::accounts::Accounts::get_lock_setting), a `GET` to `/accounts/{accountId}/lock_settings`.
+    pub fn get_lock_setting(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/lock_settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::update_lock_settings`](crate::accounts::Accounts::update_lock_settings), a `PATCH` to `/accounts/{accountId}/lock_settings`.
+    pub fn update_lock_settings(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/lock_settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::update_owner`](crate::accounts::Accounts::update_owner), a `PUT` to `/accounts/{accountId}/owner`.
+    pub fn update_owner(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/accounts/{}/owner",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::upload_vb`](crate::accounts::Accounts::upload_vb), a `POST` to `/accounts/{accountId}/settings/virtual_backgrounds`.
+    pub fn upload_vb(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Accounts::del_vb`](crate::accounts::Accounts::del_vb), a `DELETE` to `/accounts/{accountId}/settings/virtual_backgrounds`.
+    pub fn del_vb(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct Archiving<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Archiving<'a> {
+    /// Mock [`Archiving::list_archived_files`](crate::archiving::Archiving::list_archived_files), a `GET` to `/archive_files`.
+    pub fn list_archived_files(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListArchivedFilesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/archive_files".to_string())
+    }
+
+    /// Mock [`Archiving::testget_record_archived_file`](crate::archiving::Archiving::testget_record_archived_file), a `GET` to `/past_meetings/{meetingUUID}/archive_files`.
+    pub fn testget_record_archived_file(
+        &self,
+        meeting_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CloudArchivedFiles> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}/archive_files",
+                crate::progenitor_support::encode_path(meeting_uuid)
+            ),
+        )
+    }
+}
+
+pub struct Billing<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Billing<'a> {
+    /// Mock [`Billing::account`](crate::billing::Billing::account), a `GET` to `/accounts/{accountId}/billing`.
+    pub fn account(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Contact> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/billing",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_update`](crate::billing::Billing::account_update), a `PATCH` to `/accounts/{accountId}/billing`.
+    pub fn account_update(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/billing",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plans`](crate::billing::Billing::account_plans), a `GET` to `/accounts/{accountId}/plans`.
+    pub fn account_plans(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountPlansResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/plans",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_create`](crate::billing::Billing::account_plan_create), a `POST` to `/accounts/{accountId}/plans`.
+    pub fn account_plan_create(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/plans",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_base_update`](crate::billing::Billing::account_plan_base_update), a `PUT` to `/accounts/{accountId}/plans/base`.
+    pub fn account_plan_base_update(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/accounts/{}/plans/base",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_addon_update`](crate::billing::Billing::account_plan_addon_update), a `PUT` to `/accounts/{accountId}/plans/addons`.
+    pub fn account_plan_addon_update(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/accounts/{}/plans/addons",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_addon_create`](crate::billing::Billing::account_plan_addon_create), a `POST` to `/accounts/{accountId}/plans/addons`.
+    pub fn account_plan_addon_create(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountPlans> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/plans/addons",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_base_delete`](crate::billing::Billing::account_plan_base_delete), a `PATCH` to `/accounts/{accountId}/plans/base/status`.
+    pub fn account_plan_base_delete(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/plans/base/status",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_plan_addon_cancel`](crate::billing::Billing::account_plan_addon_cancel), a `PATCH` to `/accounts/{accountId}/plans/addons/status`.
+    pub fn account_plan_addon_cancel(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/plans/addons/status",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_plan_usage`](crate::billing::Billing::get_plan_usage), a `GET` to `/accounts/{accountId}/plans/usage`.
+    pub fn get_plan_usage(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPlanUsageResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/plans/usage",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::account_invoice`](crate::billing::Billing::account_invoice), a `GET` to `/accounts/{accountId}/billing/invoices`.
+    pub fn account_invoice(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountBillingInvoicesResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/billing/invoices",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::get_account_invoice`](crate::billing::Billing::get_account_invoice), a `GET` to `/accounts/{accountId}/billing/invoices/{invoiceId}`.
+    pub fn get_account_invoice(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountBillingInvoiceResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/billing/invoices/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(invoice_id)
+            ),
+        )
+    }
+
+    /// Mock [`Billing::download_invoice_pdf`](crate::billing::Billing::download_invoice_pdf), a `GET` to `/api/download/billing/invoices/{invoiceId}`.
+    pub fn download_invoice_pdf(
+        &self,
+        invoice_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::mock::Raw> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/api/download/billing/invoices/{}",
+                crate::progenitor_support::encode_path(invoice_id)
+            ),
+        )
+    }
+}
+
+pub struct ChatChannels<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChatChannels<'a> {
+    /// Mock [`ChatChannels::get_channels`](crate::chat_channels::ChatChannels::get_channels), a `GET` to `/chat/users/{userId}/channels`.
+    pub fn get_channels(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetChannelsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/{}/channels",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::create_channel`](crate::chat_channels::ChatChannels::create_channel), a `POST` to `/chat/users/{userId}/channels`.
+    pub fn create_channel(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CreateChannelResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/chat/users/{}/channels",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::get_user_level_channel`](crate::chat_channels::ChatChannels::get_user_level_channel), a `GET` to `/chat/channels/{channelId}`.
+    pub fn get_user_level_channel(
+        &self,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/channels/{}",
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::delete_user_level_channel`](crate::chat_channels::ChatChannels::delete_user_level_channel), a `DELETE` to `/chat/channels/{channelId}`.
+    pub fn delete_user_level_channel(
+        &self,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/channels/{}",
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::update_user_level_channel`](crate::chat_channels::ChatChannels::update_user_level_channel), a `PATCH` to `/chat/channels/{channelId}`.
+    pub fn update_user_level_channel(
+        &self,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/chat/channels/{}",
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::remove_user_level_channel_member`](crate::chat_channels::ChatChannels::remove_user_level_channel_member), a `DELETE` to `/chat/channels/{channelId}/members/{memberId}`.
+    pub fn remove_user_level_channel_member(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/channels/{}/members/{}",
+                crate::progenitor_support::encode_path(channel_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::join_channel`](crate::chat_channels::ChatChannels::join_channel), a `POST` to `/chat/channels/{channelId}/members/me`.
+    pub fn join_channel(
+        &self,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::JoinChannelResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/chat/channels/{}/members/me",
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannels::leave_channel`](crate::chat_channels::ChatChannels::leave_channel), a `DELETE` to `/chat/channels/{channelId}/members/me`.
+    pub fn leave_channel(&self, channel_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/channels/{}/members/me",
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+}
+
+pub struct ChatChannelsAccountLevel<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChatChannelsAccountLevel<'a> {
+    /// Mock [`ChatChannelsAccountLevel::get_channel`](crate::chat_channels_account_level::ChatChannelsAccountLevel::get_channel), a `GET` to `/chat/users/{userId}/channels/{channelId}`.
+    pub fn get_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Channel> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/{}/channels/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannelsAccountLevel::delete_channel`](crate::chat_channels_account_level::ChatChannelsAccountLevel::delete_channel), a `DELETE` to `/chat/users/{userId}/channels/{channelId}`.
+    pub fn delete_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/users/{}/channels/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannelsAccountLevel::update_channel`](crate::chat_channels_account_level::ChatChannelsAccountLevel::update_channel), a `PATCH` to `/chat/users/{userId}/channels/{channelId}`.
+    pub fn update_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/chat/users/{}/channels/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannelsAccountLevel::list_channel_members`](crate::chat_channels_account_level::ChatChannelsAccountLevel::list_channel_members), a `GET` to `/chat/users/{userId}/channels/{channelId}/members`.
+    pub fn list_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListChannelMembersResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/{}/channels/{}/members",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannelsAccountLevel::invite_channel_members`](crate::chat_channels_account_level::ChatChannelsAccountLevel::invite_channel_members), a `POST` to `/chat/users/{userId}/channels/{channelId}/members`.
+    pub fn invite_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::InviteChannelMembersResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/chat/users/{}/channels/{}/members",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatChannelsAccountLevel::remove_channel_member`](crate::chat_channels_account_level::ChatChannelsAccountLevel::remove_channel_member), a `DELETE` to `/chat/users/{userId}/channels/{channelId}/members/{memberId}`.
+    pub fn remove_channel_member(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/users/{}/channels/{}/members/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+}
+
+pub struct ChatMessages<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChatMessages<'a> {
+    /// Mock [`ChatMessages::get_page`](crate::chat_messages::ChatMessages::get_page), a `GET` to `/chat/users/{userId}/messages`.
+    pub fn get_page(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetChatMessagesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/{}/messages",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::senda`](crate::chat_messages::ChatMessages::senda), a `POST` to `/chat/users/{userId}/messages`.
+    pub fn senda(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::Groups> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/chat/users/{}/messages",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::mark_message`](crate::chat_messages::ChatMessages::mark_message), a `PATCH` to `/chat/users/{userId}/messages/{messageId}/status`.
+    pub fn mark_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/chat/users/{}/messages/{}/status",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::react_message`](crate::chat_messages::ChatMessages::react_message), a `PATCH` to `/chat/users/{userId}/messages/{messageId}/emoji_reactions`.
+    pub fn react_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/chat/users/{}/messages/{}/emoji_reactions",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::get`](crate::chat_messages::ChatMessages::get), a `GET` to `/chat/users/{userId}/messages/{messageId}`.
+    pub fn get(
+        &self,
+        user_id: &str,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetChatMessageResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/{}/messages/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::edit_message`](crate::chat_messages::ChatMessages::edit_message), a `PUT` to `/chat/users/{userId}/messages/{messageId}`.
+    pub fn edit_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/chat/users/{}/messages/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatMessages::delete`](crate::chat_messages::ChatMessages::delete), a `DELETE` to `/chat/users/{userId}/messages/{messageId}`.
+    pub fn delete(&self, user_id: &str, message_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/chat/users/{}/messages/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+}
+
+pub struct ChatbotMessages<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ChatbotMessages<'a> {
+    /// Mock [`ChatbotMessages::sendchatbot`](crate::chatbot_messages::ChatbotMessages::sendchatbot), a `POST` to `/im/chat/messages`.
+    pub fn sendchatbot(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/im/chat/messages".to_string())
+    }
+
+    /// Mock [`ChatbotMessages::edit`](crate::chatbot_messages::ChatbotMessages::edit), a `PUT` to `/im/chat/messages/{message_id}`.
+    pub fn edit(
+        &self,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::EditChatbotMessageResponse> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/im/chat/messages/{}",
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+
+    /// Mock [`ChatbotMessages::delete`](crate::chatbot_messages::ChatbotMessages::delete), a `DELETE` to `/im/chat/messages/{message_id}`.
+    pub fn delete(
+        &self,
+        message_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DeleteChatbotMessageResponse> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/im/chat/messages/{}",
+                crate::progenitor_support::encode_path(message_id)
+            ),
+        )
+    }
+}
+
+pub struct CloudRecording<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CloudRecording<'a> {
+    /// Mock [`CloudRecording::recordings_list`](crate::cloud_recording::CloudRecording::recordings_list), a `GET` to `/users/{userId}/recordings`.
+    pub fn recordings_list(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_get`](crate::cloud_recording::CloudRecording::recording_get), a `GET` to `/meetings/{meetingId}/recordings`.
+    pub fn recording_get(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecordingGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/recordings",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_delete`](crate::cloud_recording::CloudRecording::recording_delete), a `DELETE` to `/meetings/{meetingId}/recordings`.
+    pub fn recording_delete(&self, meeting_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/meetings/{}/recordings",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_delete_one`](crate::cloud_recording::CloudRecording::recording_delete_one), a `DELETE` to `/meetings/{meetingId}/recordings/{recordingId}`.
+    pub fn recording_delete_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/meetings/{}/recordings/{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(recording_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_status_update`](crate::cloud_recording::CloudRecording::recording_status_update), a `PUT` to `/meetings/{meetingId}/recordings/status`.
+    pub fn recording_status_update(&self, meeting_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/recordings/status",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_status_update_one`](crate::cloud_recording::CloudRecording::recording_status_update_one), a `PUT` to `/meetings/{meetingId}/recordings/{recordingId}/status`.
+    pub fn recording_status_update_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/recordings/{}/status",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(recording_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_setting_update`](crate::cloud_recording::CloudRecording::recording_setting_update), a `GET` to `/meetings/{meetingId}/recordings/settings`.
+    pub fn recording_setting_update(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecordingSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/recordings/settings",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_settings_update`](crate::cloud_recording::CloudRecording::recording_settings_update), a `PATCH` to `/meetings/{meetingId}/recordings/settings`.
+    pub fn recording_settings_update(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}/recordings/settings",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::meeting_recording_registrant`](crate::cloud_recording::CloudRecording::meeting_recording_registrant), a `GET` to `/meetings/{meetingId}/recordings/registrants`.
+    pub fn meeting_recording_registrant(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/recordings/registrants",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::meeting_recording_registrant_create`](crate::cloud_recording::CloudRecording::meeting_recording_registrant_create), a `POST` to `/meetings/{meetingId}/recordings/registrants`.
+    pub fn meeting_recording_registrant_create(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingRecordingRegistrantCreateResponse>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/recordings/registrants",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::meeting_recording_registrant_status`](crate::cloud_recording::CloudRecording::meeting_recording_registrant_status), a `PUT` to `/meetings/{meetingId}/recordings/registrants/status`.
+    pub fn meeting_recording_registrant_status(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/recordings/registrants/status",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_registrants_questions_get`](crate::cloud_recording::CloudRecording::recording_registrants_questions_get), a `GET` to `/meetings/{meetingId}/recordings/registrants/questions`.
+    pub fn recording_registrants_questions_get(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RecordingRegistrantQuestionsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/recordings/registrants/questions",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::recording_registrant_question_update`](crate::cloud_recording::CloudRecording::recording_registrant_question_update), a `PATCH` to `/meetings/{meetingId}/recordings/registrants/questions`.
+    pub fn recording_registrant_question_update(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}/recordings/registrants/questions",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`CloudRecording::get_account`](crate::cloud_recording::CloudRecording::get_account), a `GET` to `/accounts/{accountId}/recordings`.
+    pub fn get_account(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetAccountCloudRecordingResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/recordings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+}
+
+pub struct CommonAreaPhones<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> CommonAreaPhones<'a> {
+    /// Mock [`CommonAreaPhones::list`](crate::common_area_phones::CommonAreaPhones::list), a `GET` to `/phone/common_area_phones`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListCommonAreaPhonesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/phone/common_area_phones".to_string(),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::add`](crate::common_area_phones::CommonAreaPhones::add), a `POST` to `/phone/common_area_phones`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::AddCommonAreaPhoneResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/phone/common_area_phones".to_string(),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::get`](crate::common_area_phones::CommonAreaPhones::get), a `GET` to `/phone/common_area_phones/{commonAreaPhoneId}`.
+    pub fn get(
+        &self,
+        common_area_phone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCommonAreaPhoneResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/common_area_phones/{}",
+                crate::progenitor_support::encode_path(common_area_phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::delete`](crate::common_area_phones::CommonAreaPhones::delete), a `DELETE` to `/phone/common_area_phones/{commonAreaPhoneId}`.
+    pub fn delete(&self, common_area_phone_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/common_area_phones/{}",
+                crate::progenitor_support::encode_path(common_area_phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::update`](crate::common_area_phones::CommonAreaPhones::update), a `PATCH` to `/phone/common_area_phones/{commonAreaPhoneId}`.
+    pub fn update(&self, common_area_phone_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/common_area_phones/{}",
+                crate::progenitor_support::encode_path(common_area_phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::assign_phone_numbers_common_area`](crate::common_area_phones::CommonAreaPhones::assign_phone_numbers_common_area), a `POST` to `/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers`.
+    pub fn assign_phone_numbers_common_area(
+        &self,
+        common_area_phone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AssignPhoneNumbersCommonAreaResponseData>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/common_area_phones/{}/phone_numbers",
+                crate::progenitor_support::encode_path(common_area_phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::unassign_phone_numbers_from_common_area`](crate::common_area_phones::CommonAreaPhones::unassign_phone_numbers_from_common_area), a `DELETE` to `/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}`.
+    pub fn unassign_phone_numbers_from_common_area(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/common_area_phones/{}/phone_numbers/{}",
+                crate::progenitor_support::encode_path(common_area_phone_id),
+                crate::progenitor_support::encode_path(phone_number_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::assign_calling_plans`](crate::common_area_phones::CommonAreaPhones::assign_calling_plans), a `POST` to `/phone/common_area_phones/{commonAreaPhoneId}/calling_plans`.
+    pub fn assign_calling_plans(
+        &self,
+        common_area_phone_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AssignCallingPlansCommonAreaPhoneResponseData>
+    {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/common_area_phones/{}/calling_plans",
+                crate::progenitor_support::encode_path(common_area_phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`CommonAreaPhones::unassign_calling_plans_from`](crate::common_area_phones::CommonAreaPhones::unassign_calling_plans_from), a `DELETE` to `/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}`.
+    pub fn unassign_calling_plans_from(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/common_area_phones/{}/calling_plans/{}",
+                crate::progenitor_support::encode_path(common_area_phone_id),
+                crate::progenitor_support::encode_path(type_)
+            ),
+        )
+    }
+}
+
+pub struct Contacts<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Contacts<'a> {
+    /// Mock [`Contacts::search_company`](crate::contacts::Contacts::search_company), a `GET` to `/contacts`.
+    pub fn search_company(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::SearchCompanyContactsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/contacts".to_string())
+    }
+
+    /// Mock [`Contacts::get_user`](crate::contacts::Contacts::get_user), a `GET` to `/chat/users/me/contacts`.
+    pub fn get_user(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserContactsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/chat/users/me/contacts".to_string())
+    }
+
+    /// Mock [`Contacts::get_user_contacts`](crate::contacts::Contacts::get_user_contacts), a `GET` to `/chat/users/me/contacts/{contactId}`.
+    pub fn get_user_contacts(
+        &self,
+        contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetUserContactResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/chat/users/me/contacts/{}",
+                crate::progenitor_support::encode_path(contact_id)
+            ),
+        )
+    }
+}
+
+pub struct Dashboards<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Dashboards<'a> {
+    /// Mock [`Dashboards::meeting`](crate::dashboards::Dashboards::meeting), a `GET` to `/metrics/meetings`.
+    pub fn meeting(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardMeetingsResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/meetings".to_string())
+    }
+
+    /// Mock [`Dashboards::meeting_detail`](crate::dashboards::Dashboards::meeting_detail), a `GET` to `/metrics/meetings/{meetingId}`.
+    pub fn meeting_detail(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingMetric> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::meeting_participant`](crate::dashboards::Dashboards::meeting_participant), a `GET` to `/metrics/meetings/{meetingId}/participants`.
+    pub fn meeting_participant(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardMeetingParticipantsResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::meeting_participant_qo`](crate::dashboards::Dashboards::meeting_participant_qo), a `GET` to `/metrics/meetings/{meetingId}/participants/{participantId}/qos`.
+    pub fn meeting_participant_qo(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParticipantQos> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}/participants/{}/qos",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(participant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::meeting_participants_qo`](crate::dashboards::Dashboards::meeting_participants_qo), a `GET` to `/metrics/meetings/{meetingId}/participants/qos`.
+    pub fn meeting_participants_qo(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}/participants/qos",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::meeting_participant_share`](crate::dashboards::Dashboards::meeting_participant_share), a `GET` to `/metrics/meetings/{meetingId}/participants/sharing`.
+    pub fn meeting_participant_share(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardMeetingParticipantShareResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}/participants/sharing",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::webinar`](crate::dashboards::Dashboards::webinar), a `GET` to `/metrics/webinars`.
+    pub fn webinar(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardWebinarsResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/webinars".to_string())
+    }
+
+    /// Mock [`Dashboards::webinar_detail`](crate::dashboards::Dashboards::webinar_detail), a `GET` to `/metrics/webinars/{webinarId}`.
+    pub fn webinar_detail(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Webinars> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::webinar_participant`](crate::dashboards::Dashboards::webinar_participant), a `GET` to `/metrics/webinars/{webinarId}/participants`.
+    pub fn webinar_participant(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardWebinarParticipantsResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::webinar_participant_qo`](crate::dashboards::Dashboards::webinar_participant_qo), a `GET` to `/metrics/webinars/{webinarId}/participants/{participantId}/qos`.
+    pub fn webinar_participant_qo(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParticipantQos> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}/participants/{}/qos",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(participant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::webinar_participants_qo`](crate::dashboards::Dashboards::webinar_participants_qo), a `GET` to `/metrics/webinars/{webinarId}/participants/qos`.
+    pub fn webinar_participants_qo(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}/participants/qos",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::webinar_participant_share`](crate::dashboards::Dashboards::webinar_participant_share), a `GET` to `/metrics/webinars/{webinarId}/participants/sharing`.
+    pub fn webinar_participant_share(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardMeetingParticipantShareResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}/participants/sharing",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::zoom_room`](crate::dashboards::Dashboards::zoom_room), a `GET` to `/metrics/zoomrooms`.
+    pub fn zoom_room(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/zoomrooms".to_string())
+    }
+
+    /// Mock [`Dashboards::zoom_room_dashboards`](crate::dashboards::Dashboards::zoom_room_dashboards), a `GET` to `/metrics/zoomrooms/{zoomroomId}`.
+    pub fn zoom_room_dashboards(
+        &self,
+        zoomroom_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/zoomrooms/{}",
+                crate::progenitor_support::encode_path(zoomroom_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::crc`](crate::dashboards::Dashboards::crc), a `GET` to `/metrics/crc`.
+    pub fn crc(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/crc".to_string())
+    }
+
+    /// Mock [`Dashboards::im`](crate::dashboards::Dashboards::im), a `GET` to `/metrics/im`.
+    pub fn im(&self) -> crate::mock::MockOperation<'a, crate::types::DashboardImResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/im".to_string())
+    }
+
+    /// Mock [`Dashboards::chat`](crate::dashboards::Dashboards::chat), a `GET` to `/metrics/chat`.
+    pub fn chat(&self) -> crate::mock::MockOperation<'a, crate::types::DashboardChatResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/chat".to_string())
+    }
+
+    /// Mock [`Dashboards::client_feedback`](crate::dashboards::Dashboards::client_feedback), a `GET` to `/metrics/client/feedback`.
+    pub fn client_feedback(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardClientFeedbackResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/client/feedback".to_string())
+    }
+
+    /// Mock [`Dashboards::zoom_room_issue`](crate::dashboards::Dashboards::zoom_room_issue), a `GET` to `/metrics/zoomrooms/issues`.
+    pub fn zoom_room_issue(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/metrics/zoomrooms/issues".to_string(),
+        )
+    }
+
+    /// Mock [`Dashboards::issue_zoom_room`](crate::dashboards::Dashboards::issue_zoom_room), a `GET` to `/metrics/issues/zoomrooms`.
+    pub fn issue_zoom_room(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardIssueZoomRoomResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/metrics/issues/zoomrooms".to_string(),
+        )
+    }
+
+    /// Mock [`Dashboards::issue_detail_zoom_room`](crate::dashboards::Dashboards::issue_detail_zoom_room), a `GET` to `/metrics/issues/zoomrooms/{zoomroomId}`.
+    pub fn issue_detail_zoom_room(
+        &self,
+        zoomroom_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardIssueDetailZoomRoomResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/issues/zoomrooms/{}",
+                crate::progenitor_support::encode_path(zoomroom_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::client_feedback_detail`](crate::dashboards::Dashboards::client_feedback_detail), a `GET` to `/metrics/client/feedback/{feedbackId}`.
+    pub fn client_feedback_detail(
+        &self,
+        feedback_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::DashboardClientFeedbackDetailResponseAllOf>
+    {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/client/feedback/{}",
+                crate::progenitor_support::encode_path(feedback_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::list_meeting_satisfaction`](crate::dashboards::Dashboards::list_meeting_satisfaction), a `GET` to `/metrics/client/satisfaction`.
+    pub fn list_meeting_satisfaction(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMeetingSatisfactionResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/metrics/client/satisfaction".to_string(),
+        )
+    }
+
+    /// Mock [`Dashboards::list_call_logs_metrics`](crate::dashboards::Dashboards::list_call_logs_metrics), a `GET` to `/phone/metrics/call_logs`.
+    pub fn list_call_logs_metrics(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListCallLogsMetricsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/metrics/call_logs".to_string())
+    }
+
+    /// Mock [`Dashboards::get_call_log_metrics_details`](crate::dashboards::Dashboards::get_call_log_metrics_details), a `GET` to `/phone/metrics/call_logs/{call_id}`.
+    pub fn get_call_log_metrics_details(
+        &self,
+        call_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListCallLogsMetricsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/metrics/call_logs/{}",
+                crate::progenitor_support::encode_path(call_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::get_call_qo`](crate::dashboards::Dashboards::get_call_qo), a `GET` to `/phone/metrics/call_logs/{callId}/qos`.
+    pub fn get_call_qo(
+        &self,
+        call_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCallQoSResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/metrics/call_logs/{}/qos",
+                crate::progenitor_support::encode_path(call_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::participant_feedback`](crate::dashboards::Dashboards::participant_feedback), a `GET` to `/metrics/meetings/{meetingId}/participants/satisfaction`.
+    pub fn participant_feedback(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParticipantFeedbackResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Dashboards::participant_webinar_feedback`](crate::dashboards::Dashboards::participant_webinar_feedback), a `GET` to `/metrics/webinars/{webinarId}/participants/satisfaction`.
+    pub fn participant_webinar_feedback(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ParticipantFeedbackResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+}
+
+pub struct DeprecatedApiEndpoints<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> DeprecatedApiEndpoints<'a> {
+    /// Mock [`DeprecatedApiEndpoints::list_past_meeting_files`](crate::deprecated_api_endpoints::DeprecatedApiEndpoints::list_past_meeting_files), a `GET` to `/past_meetings/{meetingId}/files`.
+    pub fn list_past_meeting_files(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPastMeetingFilesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}/files",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`DeprecatedApiEndpoints::list_past_webinar_files`](crate::deprecated_api_endpoints::DeprecatedApiEndpoints::list_past_webinar_files), a `GET` to `/past_webinars/{webinarId}/files`.
+    pub fn list_past_webinar_files(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPastMeetingFilesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/files",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+}
+
+pub struct Devices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Devices<'a> {
+    /// Mock [`Devices::list`](crate::devices::Devices::list), a `GET` to `/h323/devices`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/h323/devices".to_string())
+    }
+
+    /// Mock [`Devices::create`](crate::devices::Devices::create), a `POST` to `/h323/devices`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/h323/devices".to_string())
+    }
+
+    /// Mock [`Devices::delete`](crate::devices::Devices::delete), a `DELETE` to `/h323/devices/{deviceId}`.
+    pub fn delete(&self, device_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/h323/devices/{}",
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`Devices::update`](crate::devices::Devices::update), a `PATCH` to `/h323/devices/{deviceId}`.
+    pub fn update(&self, device_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/h323/devices/{}",
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+}
+
+pub struct Groups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Groups<'a> {
+    /// Mock [`Groups::get`](crate::groups::Groups::get), a `GET` to `/groups`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::GroupList> {
+        self.mock
+            .operation(reqwest::Method::GET, "/groups".to_string())
+    }
+
+    /// Mock [`Groups::create`](crate::groups::Groups::create), a `POST` to `/groups`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/groups".to_string())
+    }
+
+    /// Mock [`Groups::group`](crate::groups::Groups::group), a `GET` to `/groups/{groupId}`.
+    pub fn group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::delete`](crate::groups::Groups::delete), a `DELETE` to `/groups/{groupId}`.
+    pub fn delete(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update`](crate::groups::Groups::update), a `PATCH` to `/groups/{groupId}`.
+    pub fn update(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::members`](crate::groups::Groups::members), a `GET` to `/groups/{groupId}/members`.
+    pub fn members(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GroupMembersResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::members_create`](crate::groups::Groups::members_create), a `POST` to `/groups/{groupId}/members`.
+    pub fn members_create(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::members_delete`](crate::groups::Groups::members_delete), a `DELETE` to `/groups/{groupId}/members/{memberId}`.
+    pub fn members_delete(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update_member`](crate::groups::Groups::update_member), a `PATCH` to `/groups/{groupId}/members/{memberId}`.
+    pub fn update_member(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_settings_domains`](crate::groups::Groups::get_settings_domains), a `GET` to `/groups/{groupId}/settings`.
+    pub fn get_settings_domains(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_settings_meeting_security`](crate::groups::Groups::get_settings_meeting_security), a `GET` to `/groups/{groupId}/settings`.
+    pub fn get_settings_meeting_security(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingSecuritySettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_settings_group_response`](crate::groups::Groups::get_settings_group_response), a `GET` to `/groups/{groupId}/settings`.
+    pub fn get_settings_group_response(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetGroupSettingsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_setting`](crate::groups::Groups::get_setting), a `GET` to `/groups/{groupId}/settings`.
+    pub fn get_setting(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetGroupSettingsResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::update_settings`](crate::groups::Groups::update_settings), a `PATCH` to `/groups/{groupId}/settings`.
+    pub fn update_settings(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/groups/{}/settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_lock_settings_meeting_security`](crate::groups::Groups::get_lock_settings_meeting_security), a `GET` to `/groups/{groupId}/lock_settings`.
+    pub fn get_lock_settings_meeting_security(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingSecuritySettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_lock_settings_group_response`](crate::groups::Groups::get_lock_settings_group_response), a `GET` to `/groups/{groupId}/lock_settings`.
+    pub fn get_lock_settings_group_response(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetGroupLockSettingsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::get_lock_setting`](crate::groups::Groups::get_lock_setting), a `GET` to `/groups/{groupId}/lock_settings`.
+    pub fn get_lock_setting(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetGroupLockSettingsResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::locked_settings`](crate::groups::Groups::locked_settings), a `PATCH` to `/groups/{groupId}/lock_settings`.
+    pub fn locked_settings(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/groups/{}/lock_settings",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::upload_vb`](crate::groups::Groups::upload_vb), a `POST` to `/groups/{groupId}/settings/virtual_backgrounds`.
+    pub fn upload_vb(&self, group_id: &str) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/groups/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`Groups::del_vb`](crate::groups::Groups::del_vb), a `DELETE` to `/groups/{groupId}/settings/virtual_backgrounds`.
+    pub fn del_vb(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/groups/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+}
+
+pub struct ImChat<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ImChat<'a> {
+    /// Mock [`ImChat::session`](crate::im_chat::ImChat::session), a `GET` to `/im/chat/sessions`.
+    pub fn session(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ImChatSessionsResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/im/chat/sessions".to_string())
+    }
+
+    /// Mock [`ImChat::message`](crate::im_chat::ImChat::message), a `GET` to `/im/chat/sessions/{sessionId}`.
+    pub fn message(
+        &self,
+        session_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ImChatMessagesResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/im/chat/sessions/{}",
+                crate::progenitor_support::encode_path(session_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImChat::list_im_messages`](crate::im_chat::ImChat::list_im_messages), a `GET` to `/im/users/{userId}/chat/messages`.
+    pub fn list_im_messages(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListimmessagesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/im/users/{}/chat/messages",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImChat::send_im_messages`](crate::im_chat::ImChat::send_im_messages), a `POST` to `/im/users/me/chat/messages`.
+    pub fn send_im_messages(&self) -> crate::mock::MockOperation<'a, crate::types::Groups> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/im/users/me/chat/messages".to_string(),
+        )
+    }
+}
+
+pub struct ImGroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> ImGroups<'a> {
+    /// Mock [`ImGroups::get`](crate::im_groups::ImGroups::get), a `GET` to `/im/groups`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/im/groups".to_string())
+    }
+
+    /// Mock [`ImGroups::create`](crate::im_groups::ImGroups::create), a `POST` to `/im/groups`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/im/groups".to_string())
+    }
+
+    /// Mock [`ImGroups::im_group`](crate::im_groups::ImGroups::im_group), a `GET` to `/im/groups/{groupId}`.
+    pub fn im_group(
+        &self,
+        group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ImGroupResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/im/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImGroups::delete`](crate::im_groups::ImGroups::delete), a `DELETE` to `/im/groups/{groupId}`.
+    pub fn delete(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/im/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImGroups::update`](crate::im_groups::ImGroups::update), a `PATCH` to `/im/groups/{groupId}`.
+    pub fn update(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/im/groups/{}",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImGroups::member`](crate::im_groups::ImGroups::member), a `GET` to `/im/groups/{groupId}/members`.
+    pub fn member(&self, group_id: &str) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/im/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImGroups::members_create`](crate::im_groups::ImGroups::members_create), a `POST` to `/im/groups/{groupId}/members`.
+    pub fn members_create(&self, group_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/im/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id)
+            ),
+        )
+    }
+
+    /// Mock [`ImGroups::members_delete`](crate::im_groups::ImGroups::members_delete), a `DELETE` to `/im/groups/{groupId}/members/{memberId}`.
+    pub fn members_delete(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/im/groups/{}/members/{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+}
+
+pub struct Meetings<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Meetings<'a> {
+    /// Mock [`Meetings::get`](crate::meetings::Meetings::get), a `GET` to `/users/{userId}/meetings`.
+    pub fn get(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/meetings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::create`](crate::meetings::Meetings::create), a `POST` to `/users/{userId}/meetings`.
+    pub fn create(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingCreateResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/meetings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::meeting`](crate::meetings::Meetings::meeting), a `GET` to `/meetings/{meetingId}`.
+    pub fn meeting(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::delete`](crate::meetings::Meetings::delete), a `DELETE` to `/meetings/{meetingId}`.
+    pub fn delete(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::update`](crate::meetings::Meetings::update), a `PATCH` to `/meetings/{meetingId}`.
+    pub fn update(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::status`](crate::meetings::Meetings::status), a `PUT` to `/meetings/{meetingId}/status`.
+    pub fn status(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/status",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::registrant`](crate::meetings::Meetings::registrant), a `GET` to `/meetings/{meetingId}/registrants`.
+    pub fn registrant(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/registrants",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::registrant_create`](crate::meetings::Meetings::registrant_create), a `POST` to `/meetings/{meetingId}/registrants`.
+    pub fn registrant_create(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingRegistrantCreateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/registrants",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::meetingregistrantdelete`](crate::meetings::Meetings::meetingregistrantdelete), a `DELETE` to `/meetings/{meetingId}/registrants/{registrantId}`.
+    pub fn meetingregistrantdelete(
+        &self,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/meetings/{}/registrants/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                crate::progenitor_support::encode_path(registrant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::registrant_status`](crate::meetings::Meetings::registrant_status), a `PUT` to `/meetings/{meetingId}/registrants/status`.
+    pub fn registrant_status(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/registrants/status",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::past_details`](crate::meetings::Meetings::past_details), a `GET` to `/past_meetings/{meetingUUID}`.
+    pub fn past_details(
+        &self,
+        meeting_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PastMeetingDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}",
+                crate::progenitor_support::encode_path(meeting_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::past_participant`](crate::meetings::Meetings::past_participant), a `GET` to `/past_meetings/{meetingUUID}/participants`.
+    pub fn past_participant(
+        &self,
+        meeting_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PastMeetingParticipantsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::past`](crate::meetings::Meetings::past), a `GET` to `/past_meetings/{meetingId}/instances`.
+    pub fn past(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}/instances",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::poll`](crate::meetings::Meetings::poll), a `GET` to `/meetings/{meetingId}/polls`.
+    pub fn poll(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/polls",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::poll_create`](crate::meetings::Meetings::poll_create), a `POST` to `/meetings/{meetingId}/polls`.
+    pub fn poll_create(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingPollGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/polls",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::poll_get`](crate::meetings::Meetings::poll_get), a `GET` to `/meetings/{meetingId}/polls/{pollId}`.
+    pub fn poll_get(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingPollGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/polls/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::poll_update`](crate::meetings::Meetings::poll_update), a `PUT` to `/meetings/{meetingId}/polls/{pollId}`.
+    pub fn poll_update(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/meetings/{}/polls/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::poll_delete`](crate::meetings::Meetings::poll_delete), a `DELETE` to `/meetings/{meetingId}/polls/{pollId}`.
+    pub fn poll_delete(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/meetings/{}/polls/{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::registrants_questions_get`](crate::meetings::Meetings::registrants_questions_get), a `GET` to `/meetings/{meetingId}/registrants/questions`.
+    pub fn registrants_questions_get(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingRegistrantQuestionsData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/registrants/questions",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::registrant_question_update`](crate::meetings::Meetings::registrant_question_update), a `PATCH` to `/meetings/{meetingId}/registrants/questions`.
+    pub fn registrant_question_update(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}/registrants/questions",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::invitation`](crate::meetings::Meetings::invitation), a `GET` to `/meetings/{meetingId}/invitation`.
+    pub fn invitation(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingInvitation> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/invitation",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::get_live_stream_details`](crate::meetings::Meetings::get_live_stream_details), a `GET` to `/meetings/{meetingId}/livestream`.
+    pub fn get_live_stream_details(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetLiveStreamDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/meetings/{}/livestream",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::live_stream_update`](crate::meetings::Meetings::live_stream_update), a `PATCH` to `/meetings/{meetingId}/livestream`.
+    pub fn live_stream_update(&self, meeting_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}/livestream",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::live_stream_status_update`](crate::meetings::Meetings::live_stream_status_update), a `PATCH` to `/meetings/{meetingId}/livestream/status`.
+    pub fn live_stream_status_update(&self, meeting_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/meetings/{}/livestream/status",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::list_past_polls`](crate::meetings::Meetings::list_past_polls), a `GET` to `/past_meetings/{meetingId}/polls`.
+    pub fn list_past_polls(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingPollsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_meetings/{}/polls",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::add_batch_registrants`](crate::meetings::Meetings::add_batch_registrants), a `POST` to `/meetings/{meetingId}/batch_registrants`.
+    pub fn add_batch_registrants(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddBatchRegistrantsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/batch_registrants",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::recording_control`](crate::meetings::Meetings::recording_control), a `PATCH` to `/live_meetings/{meetingId}/events`.
+    pub fn recording_control(&self, meeting_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/live_meetings/{}/events",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::quality_score`](crate::meetings::Meetings::quality_score), a `GET` to `/metrics/quality`.
+    pub fn quality_score(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/metrics/quality".to_string())
+    }
+
+    /// Mock [`Meetings::create_batch_polls`](crate::meetings::Meetings::create_batch_polls), a `POST` to `/meetings/{meetingId}/batch_polls`.
+    pub fn create_batch_polls(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::CreateBatchPollsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/batch_polls",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::list_template`](crate::meetings::Meetings::list_template), a `GET` to `/users/{userId}/meeting_templates`.
+    pub fn list_template(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListMeetingTemplatesResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/meeting_templates",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Meetings::invite_links_create`](crate::meetings::Meetings::invite_links_create), a `POST` to `/meetings/{meetingId}/invite_links`.
+    pub fn invite_links_create(
+        &self,
+        meeting_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::InviteLinks> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/meetings/{}/invite_links",
+                crate::progenitor_support::encode_path(&meeting_id.to_string())
+            ),
+        )
+    }
+}
+
+pub struct Pac<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Pac<'a> {
+    /// Mock [`Pac::user_pa_cs`](crate::pac::Pac::user_pa_cs), a `GET` to `/users/{userId}/pac`.
+    pub fn user_pa_cs(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPaCsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/pac",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct Phone<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Phone<'a> {
+    /// Mock [`Phone::set_up_account`](crate::phone::Phone::set_up_account), a `POST` to `/accounts/{accountId}/phone/setup`.
+    pub fn set_up_account(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/phone/setup",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::list_account_numbers`](crate::phone::Phone::list_account_numbers), a `GET` to `/phone/numbers`.
+    pub fn list_account_numbers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListAccountPhoneNumbersResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/numbers".to_string())
+    }
+
+    /// Mock [`Phone::user`](crate::phone::Phone::user), a `GET` to `/phone/users/{userId}`.
+    pub fn user(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PhoneUserResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_user_profile`](crate::phone::Phone::update_user_profile), a `PATCH` to `/phone/users/{userId}`.
+    pub fn update_user_profile(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/users/{}",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::setting`](crate::phone::Phone::setting), a `GET` to `/phone/settings`.
+    pub fn setting(&self) -> crate::mock::MockOperation<'a, crate::types::PhoneSettingResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/settings".to_string())
+    }
+
+    /// Mock [`Phone::update_settings`](crate::phone::Phone::update_settings), a `PATCH` to `/phone/settings`.
+    pub fn update_settings(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/phone/settings".to_string())
+    }
+
+    /// Mock [`Phone::user_settings`](crate::phone::Phone::user_settings), a `GET` to `/phone/users/{userId}/settings`.
+    pub fn user_settings(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PhoneUserSettingsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::list_setting_templates`](crate::phone::Phone::list_setting_templates), a `GET` to `/phone/setting_templates`.
+    pub fn list_setting_templates(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSettingTemplatesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/setting_templates".to_string())
+    }
+
+    /// Mock [`Phone::add_setting_template`](crate::phone::Phone::add_setting_template), a `POST` to `/phone/setting_templates`.
+    pub fn add_setting_template(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddSettingTemplateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/phone/setting_templates".to_string(),
+        )
+    }
+
+    /// Mock [`Phone::batch_add_locations`](crate::phone::Phone::batch_add_locations), a `POST` to `/phone/batch_locations`.
+    pub fn batch_add_locations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, Vec<crate::types::BatchAddLocationsResponse>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/batch_locations".to_string())
+    }
+
+    /// Mock [`Phone::list_locations`](crate::phone::Phone::list_locations), a `GET` to `/phone/locations`.
+    pub fn list_locations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListLocationsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/locations".to_string())
+    }
+
+    /// Mock [`Phone::add_location`](crate::phone::Phone::add_location), a `POST` to `/phone/locations`.
+    pub fn add_location(&self) -> crate::mock::MockOperation<'a, Vec<crate::types::Site>> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/locations".to_string())
+    }
+
+    /// Mock [`Phone::get_location`](crate::phone::Phone::get_location), a `GET` to `/phone/locations/{locationId}`.
+    pub fn get_location(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetLocationResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::delete_location`](crate::phone::Phone::delete_location), a `DELETE` to `/phone/locations/{locationId}`.
+    pub fn delete_location(&self, location_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_location`](crate::phone::Phone::update_location), a `PATCH` to `/phone/locations/{locationId}`.
+    pub fn update_location(&self, location_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::list_sip_groups`](crate::phone::Phone::list_sip_groups), a `GET` to `/phone/sip_groups`.
+    pub fn list_sip_groups(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSipGroupsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/sip_groups".to_string())
+    }
+
+    /// Mock [`Phone::get_setting_template`](crate::phone::Phone::get_setting_template), a `GET` to `/phone/setting_templates/{templateId}`.
+    pub fn get_setting_template(
+        &self,
+        template_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSettingTemplateResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/setting_templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_setting_template`](crate::phone::Phone::update_setting_template), a `PATCH` to `/phone/setting_templates/{templateId}`.
+    pub fn update_setting_template(&self, template_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/setting_templates/{}",
+                crate::progenitor_support::encode_path(template_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::user_call_logs`](crate::phone::Phone::user_call_logs), a `GET` to `/phone/users/{userId}/call_logs`.
+    pub fn user_call_logs(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PhoneUserCallLogsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/users/{}/call_logs",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::user_recordings`](crate::phone::Phone::user_recordings), a `GET` to `/phone/users/{userId}/recordings`.
+    pub fn user_recordings(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PhoneUserRecordingsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/users/{}/recordings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::user_voice_mails`](crate::phone::Phone::user_voice_mails), a `GET` to `/phone/users/{userId}/voice_mails`.
+    pub fn user_voice_mails(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PhoneUserVoiceMailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/users/{}/voice_mails",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::add_user_setting`](crate::phone::Phone::add_user_setting), a `POST` to `/phone/users/{userId}/settings/{settingType}`.
+    pub fn add_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddUserSettingResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/users/{}/settings/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(setting_type)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::delete_user_setting`](crate::phone::Phone::delete_user_setting), a `DELETE` to `/phone/users/{userId}/settings/{settingType}`.
+    pub fn delete_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/users/{}/settings/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(setting_type)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_user_setting`](crate::phone::Phone::update_user_setting), a `PATCH` to `/phone/users/{userId}/settings/{settingType}`.
+    pub fn update_user_setting(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/users/{}/settings/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(setting_type)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::account_call_logs`](crate::phone::Phone::account_call_logs), a `GET` to `/phone/call_logs`.
+    pub fn account_call_logs(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AccountCallLogsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/call_logs".to_string())
+    }
+
+    /// Mock [`Phone::assign_number`](crate::phone::Phone::assign_number), a `POST` to `/phone/users/{userId}/phone_numbers`.
+    pub fn assign_number(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddByocNumberResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/users/{}/phone_numbers",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::unassign_number`](crate::phone::Phone::unassign_number), a `DELETE` to `/phone/users/{userId}/phone_numbers/{phoneNumberId}`.
+    pub fn unassign_number(
+        &self,
+        user_id: &str,
+        phone_number_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/users/{}/phone_numbers/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(phone_number_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::assign_calling_plan`](crate::phone::Phone::assign_calling_plan), a `POST` to `/phone/users/{userId}/calling_plans`.
+    pub fn assign_calling_plan(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/users/{}/calling_plans",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::unassign_calling_plan`](crate::phone::Phone::unassign_calling_plan), a `DELETE` to `/phone/users/{userId}/calling_plans/{type}`.
+    pub fn unassign_calling_plan(
+        &self,
+        user_id: &str,
+        type_: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/users/{}/calling_plans/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(type_)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::get_recordings`](crate::phone::Phone::get_recordings), a `GET` to `/phone/recordings`.
+    pub fn get_recordings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPhoneRecordingsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/recordings".to_string())
+    }
+
+    /// Mock [`Phone::list_byocsip_trunk`](crate::phone::Phone::list_byocsip_trunk), a `GET` to `/phone/sip_trunk/trunks`.
+    pub fn list_byocsip_trunk(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListByocsipTrunkResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/sip_trunk/trunks".to_string())
+    }
+
+    /// Mock [`Phone::post_sip_trunk`](crate::phone::Phone::post_sip_trunk), a `POST` to `/accounts/{accountId}/phone/sip_trunk/trunks`.
+    pub fn post_sip_trunk(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::PostPhoneSipTrunkRequest> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/phone/sip_trunk/trunks",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_sip_trunk`](crate::phone::Phone::update_sip_trunk), a `PATCH` to `/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}`.
+    pub fn update_sip_trunk(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/phone/sip_trunk/trunks/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(sip_trunk_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::list_external_contacts`](crate::phone::Phone::list_external_contacts), a `GET` to `/phone/external_contacts`.
+    pub fn list_external_contacts(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListExternalContactsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/external_contacts".to_string())
+    }
+
+    /// Mock [`Phone::add_external_contact`](crate::phone::Phone::add_external_contact), a `POST` to `/phone/external_contacts`.
+    pub fn add_external_contact(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/phone/external_contacts".to_string(),
+        )
+    }
+
+    /// Mock [`Phone::get_external_contact`](crate::phone::Phone::get_external_contact), a `GET` to `/phone/external_contacts/{externalContactId}`.
+    pub fn get_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ExternalContacts> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/external_contacts/{}",
+                crate::progenitor_support::encode_path(external_contact_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::delete_external_contact`](crate::phone::Phone::delete_external_contact), a `DELETE` to `/phone/external_contacts/{externalContactId}`.
+    pub fn delete_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/external_contacts/{}",
+                crate::progenitor_support::encode_path(external_contact_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_external_contact`](crate::phone::Phone::update_external_contact), a `PATCH` to `/phone/external_contacts/{externalContactId}`.
+    pub fn update_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/external_contacts/{}",
+                crate::progenitor_support::encode_path(external_contact_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::get_number_details`](crate::phone::Phone::get_number_details), a `GET` to `/phone/numbers/{numberId}`.
+    pub fn get_number_details(
+        &self,
+        number_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetPhoneNumberDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/numbers/{}",
+                crate::progenitor_support::encode_path(number_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::update_number_details`](crate::phone::Phone::update_number_details), a `PATCH` to `/phone/numbers/{numberId}`.
+    pub fn update_number_details(&self, number_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/numbers/{}",
+                crate::progenitor_support::encode_path(number_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::change_main_company_number`](crate::phone::Phone::change_main_company_number), a `PUT` to `/phone/company_number`.
+    pub fn change_main_company_number(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::PUT, "/phone/company_number".to_string())
+    }
+
+    /// Mock [`Phone::list_calling_plan`](crate::phone::Phone::list_calling_plan), a `GET` to `/phone/calling_plans`.
+    pub fn list_calling_plan(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListCallingPlansResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/calling_plans".to_string())
+    }
+
+    /// Mock [`Phone::list_users`](crate::phone::Phone::list_users), a `GET` to `/phone/users`.
+    pub fn list_users(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPhoneUsersResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/users".to_string())
+    }
+
+    /// Mock [`Phone::get_call_log_details`](crate::phone::Phone::get_call_log_details), a `GET` to `/phone/call_logs/{callLogId}`.
+    pub fn get_call_log_details(
+        &self,
+        call_log_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCallLogDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/call_logs/{}",
+                crate::progenitor_support::encode_path(call_log_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::delete_call_log`](crate::phone::Phone::delete_call_log), a `DELETE` to `/phone/users/{userId}/call_logs/{callLogId}`.
+    pub fn delete_call_log(
+        &self,
+        user_id: &str,
+        call_log_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/users/{}/call_logs/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(call_log_id)
+            ),
+        )
+    }
+
+    /// Mock [`Phone::add_byoc_number`](crate::phone::Phone::add_byoc_number), a `POST` to `/phone/byoc_numbers`.
+    pub fn add_byoc_number(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddByocNumberResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/byoc_numbers".to_string())
+    }
+
+    /// Mock [`Phone::delete_voicemail`](crate::phone::Phone::delete_voicemail), a `DELETE` to `/phone/voice_mails/{voicemailId}`.
+    pub fn delete_voicemail(&self, voicemail_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/voice_mails/{}",
+                crate::progenitor_support::encode_path(voicemail_id)
+            ),
+        )
+    }
+}
+
+pub struct PhoneAutoReceptionists<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneAutoReceptionists<'a> {
+    /// Mock [`PhoneAutoReceptionists::update_auto_receptionist`](crate::phone_auto_receptionists::PhoneAutoReceptionists::update_auto_receptionist), a `PATCH` to `/phone/auto_receptionists/{autoReceptionistId}`.
+    pub fn update_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/auto_receptionists/{}",
+                crate::progenitor_support::encode_path(auto_receptionist_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneAutoReceptionists::assign_phone_numbers_auto_receptionist`](crate::phone_auto_receptionists::PhoneAutoReceptionists::assign_phone_numbers_auto_receptionist), a `POST` to `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers`.
+    pub fn assign_phone_numbers_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/auto_receptionists/{}/phone_numbers",
+                crate::progenitor_support::encode_path(auto_receptionist_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneAutoReceptionists::unassign_all_phone_nums_auto_receptionist`](crate::phone_auto_receptionists::PhoneAutoReceptionists::unassign_all_phone_nums_auto_receptionist), a `DELETE` to `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers`.
+    pub fn unassign_all_phone_nums_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/auto_receptionists/{}/phone_numbers",
+                crate::progenitor_support::encode_path(auto_receptionist_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneAutoReceptionists::unassign_phone_num_auto_receptionist`](crate::phone_auto_receptionists::PhoneAutoReceptionists::unassign_phone_num_auto_receptionist), a `DELETE` to `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}`.
+    pub fn unassign_phone_num_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/auto_receptionists/{}/phone_numbers/{}",
+                crate::progenitor_support::encode_path(auto_receptionist_id),
+                crate::progenitor_support::encode_path(phone_number_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneAutoReceptionists::add_auto_receptionist`](crate::phone_auto_receptionists::PhoneAutoReceptionists::add_auto_receptionist), a `POST` to `/phone/auto_receptionists`.
+    pub fn add_auto_receptionist(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddAutoReceptionistResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/phone/auto_receptionists".to_string(),
+        )
+    }
+}
+
+pub struct PhoneBlockedList<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneBlockedList<'a> {
+    /// Mock [`PhoneBlockedList::list_blocked`](crate::phone_blocked_list::PhoneBlockedList::list_blocked), a `GET` to `/phone/blocked_list`.
+    pub fn list_blocked(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListBlockedResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/blocked_list".to_string())
+    }
+
+    /// Mock [`PhoneBlockedList::add_anumber_blocked_list`](crate::phone_blocked_list::PhoneBlockedList::add_anumber_blocked_list), a `POST` to `/phone/blocked_list`.
+    pub fn add_anumber_blocked_list(&self) -> crate::mock::MockOperation<'a, crate::types::Groups> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/blocked_list".to_string())
+    }
+
+    /// Mock [`PhoneBlockedList::get_blocked_list`](crate::phone_blocked_list::PhoneBlockedList::get_blocked_list), a `GET` to `/phone/blocked_list/{blockedListId}`.
+    pub fn get_blocked_list(
+        &self,
+        blocked_list_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::BlockedList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/blocked_list/{}",
+                crate::progenitor_support::encode_path(blocked_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneBlockedList::delete_blocked_list`](crate::phone_blocked_list::PhoneBlockedList::delete_blocked_list), a `DELETE` to `/phone/blocked_list/{blockedListId}`.
+    pub fn delete_blocked_list(&self, blocked_list_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/blocked_list/{}",
+                crate::progenitor_support::encode_path(blocked_list_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneBlockedList::update_blocked_list`](crate::phone_blocked_list::PhoneBlockedList::update_blocked_list), a `PATCH` to `/phone/blocked_list/{blockedListId}`.
+    pub fn update_blocked_list(&self, blocked_list_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/blocked_list/{}",
+                crate::progenitor_support::encode_path(blocked_list_id)
+            ),
+        )
+    }
+}
+
+pub struct PhoneCallQueues<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneCallQueues<'a> {
+    /// Mock [`PhoneCallQueues::list_call_queues`](crate::phone_call_queues::PhoneCallQueues::list_call_queues), a `GET` to `/phone/call_queues`.
+    pub fn list_call_queues(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListCallQueuesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/call_queues".to_string())
+    }
+
+    /// Mock [`PhoneCallQueues::create_call_queue`](crate::phone_call_queues::PhoneCallQueues::create_call_queue), a `POST` to `/phone/call_queues`.
+    pub fn create_call_queue(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::CreateCallQueueResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/call_queues".to_string())
+    }
+
+    /// Mock [`PhoneCallQueues::get_call_queue`](crate::phone_call_queues::PhoneCallQueues::get_call_queue), a `GET` to `/phone/call_queues/{callQueueId}`.
+    pub fn get_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCallQueueResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/call_queues/{}",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::delete_call_queue`](crate::phone_call_queues::PhoneCallQueues::delete_call_queue), a `DELETE` to `/phone/call_queues/{callQueueId}`.
+    pub fn delete_call_queue(&self, call_queue_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/call_queues/{}",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::update_call_queue`](crate::phone_call_queues::PhoneCallQueues::update_call_queue), a `PATCH` to `/phone/call_queues/{callQueueId}`.
+    pub fn update_call_queue(&self, call_queue_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/call_queues/{}",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::assign`](crate::phone_call_queues::PhoneCallQueues::assign), a `POST` to `/phone/call_queues/{callQueueId}/phone_numbers`.
+    pub fn assign(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/call_queues/{}/phone_numbers",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::unassign_phone_num_call_queue`](crate::phone_call_queues::PhoneCallQueues::unassign_phone_num_call_queue), a `DELETE` to `/phone/call_queues/{callQueueId}/phone_numbers`.
+    pub fn unassign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/call_queues/{}/phone_numbers",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::un_assign_phone_num_call_queue`](crate::phone_call_queues::PhoneCallQueues::un_assign_phone_num_call_queue), a `DELETE` to `/phone/call_queues/{callQueueId}/phone_numbers/{phoneNumberId}`.
+    pub fn un_assign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+        phone_number_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/call_queues/{}/phone_numbers/{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                crate::progenitor_support::encode_path(phone_number_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::add_members_call_queue`](crate::phone_call_queues::PhoneCallQueues::add_members_call_queue), a `POST` to `/phone/call_queues/{callQueueId}/members`.
+    pub fn add_members_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/call_queues/{}/members",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::unassign_all_members`](crate::phone_call_queues::PhoneCallQueues::unassign_all_members), a `DELETE` to `/phone/call_queues/{callQueueId}/members`.
+    pub fn unassign_all_members(&self, call_queue_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/call_queues/{}/members",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::unassign_member_from_call_queue`](crate::phone_call_queues::PhoneCallQueues::unassign_member_from_call_queue), a `DELETE` to `/phone/call_queues/{callQueueId}/members/{memberId}`.
+    pub fn unassign_member_from_call_queue(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/call_queues/{}/members/{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::change_call_queue_manager`](crate::phone_call_queues::PhoneCallQueues::change_call_queue_manager), a `PUT` to `/phone/call_queues/{callQueueId}/manager`.
+    pub fn change_call_queue_manager(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/phone/call_queues/{}/manager",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneCallQueues::get_call_queue_recordings`](crate::phone_call_queues::PhoneCallQueues::get_call_queue_recordings), a `GET` to `/phone/call_queues/{callQueueId}/recordings`.
+    pub fn get_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetCallQueueRecordingsResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/call_queues/{}/recordings",
+                crate::progenitor_support::encode_path(call_queue_id)
+            ),
+        )
+    }
+}
+
+pub struct PhoneDevices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneDevices<'a> {
+    /// Mock [`PhoneDevices::list`](crate::phone_devices::PhoneDevices::list), a `GET` to `/phone/devices`.
+    pub fn list(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListPhoneDevicesResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/devices".to_string())
+    }
+
+    /// Mock [`PhoneDevices::add`](crate::phone_devices::PhoneDevices::add), a `POST` to `/phone/devices`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/devices".to_string())
+    }
+
+    /// Mock [`PhoneDevices::get_device`](crate::phone_devices::PhoneDevices::get_device), a `GET` to `/phone/devices/{deviceId}`.
+    pub fn get_device(
+        &self,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetDeviceResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/devices/{}",
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneDevices::delete_device`](crate::phone_devices::PhoneDevices::delete_device), a `DELETE` to `/phone/devices/{deviceId}`.
+    pub fn delete_device(&self, device_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/devices/{}",
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneDevices::update_device`](crate::phone_devices::PhoneDevices::update_device), a `PATCH` to `/phone/devices/{deviceId}`.
+    pub fn update_device(&self, device_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/devices/{}",
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+}
+
+pub struct PhoneReports<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneReports<'a> {
+    /// Mock [`PhoneReports::get_ps_operation_log`](crate::phone_reports::PhoneReports::get_ps_operation_log), a `GET` to `/phone/reports/operationlogs`.
+    pub fn get_ps_operation_log(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportOperationLogsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/phone/reports/operationlogs".to_string(),
+        )
+    }
+}
+
+pub struct PhoneSharedLineGroups<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneSharedLineGroups<'a> {
+    /// Mock [`PhoneSharedLineGroups::list_shared_line_groups`](crate::phone_shared_line_groups::PhoneSharedLineGroups::list_shared_line_groups), a `GET` to `/phone/shared_line_groups`.
+    pub fn list_shared_line_groups(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSharedLineGroupsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/phone/shared_line_groups".to_string(),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::create_shared_line_group`](crate::phone_shared_line_groups::PhoneSharedLineGroups::create_shared_line_group), a `POST` to `/phone/shared_line_groups`.
+    pub fn create_shared_line_group(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            "/phone/shared_line_groups".to_string(),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::get_shared_line_group`](crate::phone_shared_line_groups::PhoneSharedLineGroups::get_shared_line_group), a `GET` to `/phone/shared_line_groups/{sharedLineGroupId}`.
+    pub fn get_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSharedLineGroupResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/shared_line_groups/{}",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::delete_shared_line_group`](crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_shared_line_group), a `DELETE` to `/phone/shared_line_groups/{sharedLineGroupId}`.
+    pub fn delete_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/shared_line_groups/{}",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::update_shared_line_group`](crate::phone_shared_line_groups::PhoneSharedLineGroups::update_shared_line_group), a `PATCH` to `/phone/shared_line_groups/{sharedLineGroupId}`.
+    pub fn update_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/shared_line_groups/{}",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::add_members_shared_line_group`](crate::phone_shared_line_groups::PhoneSharedLineGroups::add_members_shared_line_group), a `POST` to `/phone/shared_line_groups/{sharedLineGroupId}/members`.
+    pub fn add_members_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/shared_line_groups/{}/members",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::delete_members_of_slg`](crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_members_of_slg), a `DELETE` to `/phone/shared_line_groups/{sharedLineGroupId}/members`.
+    pub fn delete_members_of_slg(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/shared_line_groups/{}/members",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::delete_member_slg`](crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_member_slg), a `DELETE` to `/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}`.
+    pub fn delete_member_slg(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/shared_line_groups/{}/members/{}",
+                crate::progenitor_support::encode_path(shared_line_group_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::assign_phone_numbers_slg`](crate::phone_shared_line_groups::PhoneSharedLineGroups::assign_phone_numbers_slg), a `POST` to `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers`.
+    pub fn assign_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/phone/shared_line_groups/{}/phone_numbers",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::delete_phone_numbers_slg`](crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_phone_numbers_slg), a `DELETE` to `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers`.
+    pub fn delete_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/shared_line_groups/{}/phone_numbers",
+                crate::progenitor_support::encode_path(shared_line_group_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSharedLineGroups::delete_phone_number_slg`](crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_phone_number_slg), a `DELETE` to `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}`.
+    pub fn delete_phone_number_slg(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/shared_line_groups/{}/phone_numbers/{}",
+                crate::progenitor_support::encode_path(shared_line_group_id),
+                crate::progenitor_support::encode_path(phone_number_id)
+            ),
+        )
+    }
+}
+
+pub struct PhoneSite<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> PhoneSite<'a> {
+    /// Mock [`PhoneSite::list`](crate::phone_site::PhoneSite::list), a `GET` to `/phone/sites`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::ListPhoneSitesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/phone/sites".to_string())
+    }
+
+    /// Mock [`PhoneSite::create`](crate::phone_site::PhoneSite::create), a `POST` to `/phone/sites`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::Site> {
+        self.mock
+            .operation(reqwest::Method::POST, "/phone/sites".to_string())
+    }
+
+    /// Mock [`PhoneSite::get_site`](crate::phone_site::PhoneSite::get_site), a `GET` to `/phone/sites/{siteId}`.
+    pub fn get_site(
+        &self,
+        site_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetSiteResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/phone/sites/{}",
+                crate::progenitor_support::encode_path(site_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSite::delete`](crate::phone_site::PhoneSite::delete), a `DELETE` to `/phone/sites/{siteId}`.
+    pub fn delete(&self, site_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/phone/sites/{}",
+                crate::progenitor_support::encode_path(site_id)
+            ),
+        )
+    }
+
+    /// Mock [`PhoneSite::update_site_details`](crate::phone_site::PhoneSite::update_site_details), a `PATCH` to `/phone/sites/{siteId}`.
+    pub fn update_site_details(&self, site_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/phone/sites/{}",
+                crate::progenitor_support::encode_path(site_id)
+            ),
+        )
+    }
+}
+
+pub struct Reports<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Reports<'a> {
+    /// Mock [`Reports::daily`](crate::reports::Reports::daily), a `GET` to `/report/daily`.
+    pub fn daily(&self) -> crate::mock::MockOperation<'a, crate::types::ReportDailyResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/daily".to_string())
+    }
+
+    /// Mock [`Reports::user`](crate::reports::Reports::user), a `GET` to `/report/users`.
+    pub fn user(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/users".to_string())
+    }
+
+    /// Mock [`Reports::meeting`](crate::reports::Reports::meeting), a `GET` to `/report/users/{userId}/meetings`.
+    pub fn meeting(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/users/{}/meetings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::meeting_details`](crate::reports::Reports::meeting_details), a `GET` to `/report/meetings/{meetingId}`.
+    pub fn meeting_details(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/meetings/{}",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::meeting_participant`](crate::reports::Reports::meeting_participant), a `GET` to `/report/meetings/{meetingId}/participants`.
+    pub fn meeting_participant(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingParticipantsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/meetings/{}/participants",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::meeting_polls`](crate::reports::Reports::meeting_polls), a `GET` to `/report/meetings/{meetingId}/polls`.
+    pub fn meeting_polls(
+        &self,
+        meeting_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingPollsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/meetings/{}/polls",
+                crate::progenitor_support::encode_path(meeting_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::webinar_details`](crate::reports::Reports::webinar_details), a `GET` to `/report/webinars/{webinarId}`.
+    pub fn webinar_details(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/webinars/{}",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::webinar_participant`](crate::reports::Reports::webinar_participant), a `GET` to `/report/webinars/{webinarId}/participants`.
+    pub fn webinar_participant(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportWebinarParticipantsResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::webinar_polls`](crate::reports::Reports::webinar_polls), a `GET` to `/report/webinars/{webinarId}/polls`.
+    pub fn webinar_polls(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingPollsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/webinars/{}/polls",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::webinar_qa`](crate::reports::Reports::webinar_qa), a `GET` to `/report/webinars/{webinarId}/qa`.
+    pub fn webinar_qa(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportWebinarQaResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/report/webinars/{}/qa",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Reports::telephone`](crate::reports::Reports::telephone), a `GET` to `/report/telephone`.
+    pub fn telephone(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/telephone".to_string())
+    }
+
+    /// Mock [`Reports::cloud_recording`](crate::reports::Reports::cloud_recording), a `GET` to `/report/cloud_recording`.
+    pub fn cloud_recording(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportCloudRecordingResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/cloud_recording".to_string())
+    }
+
+    /// Mock [`Reports::operation_log`](crate::reports::Reports::operation_log), a `GET` to `/report/operationlogs`.
+    pub fn operation_log(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportOperationLogsResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/operationlogs".to_string())
+    }
+
+    /// Mock [`Reports::sign_out_activities`](crate::reports::Reports::sign_out_activities), a `GET` to `/report/activities`.
+    pub fn sign_out_activities(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportSignInOutActivitiesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/activities".to_string())
+    }
+
+    /// Mock [`Reports::get_billing`](crate::reports::Reports::get_billing), a `GET` to `/report/billing`.
+    pub fn get_billing(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetBillingReportResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/billing".to_string())
+    }
+
+    /// Mock [`Reports::get_billing_invoices`](crate::reports::Reports::get_billing_invoices), a `GET` to `/report/billing/invoices`.
+    pub fn get_billing_invoices(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetBillingInvoicesReportsResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/report/billing/invoices".to_string())
+    }
+}
+
+pub struct Roles<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Roles<'a> {
+    /// Mock [`Roles::get`](crate::roles::Roles::get), a `GET` to `/roles`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/roles".to_string())
+    }
+
+    /// Mock [`Roles::create`](crate::roles::Roles::create), a `POST` to `/roles`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/roles".to_string())
+    }
+
+    /// Mock [`Roles::members`](crate::roles::Roles::members), a `GET` to `/roles/{roleId}/members`.
+    pub fn members(
+        &self,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::RoleMembersList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/roles/{}/members",
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::add_members`](crate::roles::Roles::add_members), a `POST` to `/roles/{roleId}/members`.
+    pub fn add_members(
+        &self,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddRoleMembersResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/roles/{}/members",
+                crate::progenitor_support::encode_path(role_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::member_delete`](crate::roles::Roles::member_delete), a `DELETE` to `/roles/{roleId}/members/{memberId}`.
+    pub fn member_delete(
+        &self,
+        role_id: &str,
+        member_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/roles/{}/members/{}",
+                crate::progenitor_support::encode_path(role_id),
+                crate::progenitor_support::encode_path(member_id)
+            ),
+        )
+    }
+
+    /// Mock [`Roles::get_information`](crate::roles::Roles::get_information), a `GET` to `/roles/{roleId}`.
+    pub fn get_information(
+        &self,
+        role_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetRoleInformationResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/roles/{}", crate::progenitor_support::encode_path(role_id)),
+        )
+    }
+
+    /// Mock [`Roles::delete`](crate::roles::Roles::delete), a `DELETE` to `/roles/{roleId}`.
+    pub fn delete(&self, role_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/roles/{}", crate::progenitor_support::encode_path(role_id)),
+        )
+    }
+
+    /// Mock [`Roles::update`](crate::roles::Roles::update), a `PATCH` to `/roles/{roleId}`.
+    pub fn update(&self, role_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/roles/{}", crate::progenitor_support::encode_path(role_id)),
+        )
+    }
+}
+
+pub struct Rooms<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Rooms<'a> {
+    /// Mock [`Rooms::list_zoom`](crate::rooms::Rooms::list_zoom), a `GET` to `/rooms`.
+    pub fn list_zoom(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListZoomRoomsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rooms".to_string())
+    }
+
+    /// Mock [`Rooms::add`](crate::rooms::Rooms::add), a `POST` to `/rooms`.
+    pub fn add(&self) -> crate::mock::MockOperation<'a, crate::types::AddRoomResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/rooms".to_string())
+    }
+
+    /// Mock [`Rooms::get_zr_profile`](crate::rooms::Rooms::get_zr_profile), a `GET` to `/rooms/{roomId}`.
+    pub fn get_zr_profile(
+        &self,
+        room_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetZrProfileResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/rooms/{}", crate::progenitor_support::encode_path(room_id)),
+        )
+    }
+
+    /// Mock [`Rooms::delete_zoom`](crate::rooms::Rooms::delete_zoom), a `DELETE` to `/rooms/{roomId}`.
+    pub fn delete_zoom(
+        &self,
+        room_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/rooms/{}", crate::progenitor_support::encode_path(room_id)),
+        )
+    }
+
+    /// Mock [`Rooms::update_profile`](crate::rooms::Rooms::update_profile), a `PATCH` to `/rooms/{roomId}`.
+    pub fn update_profile(
+        &self,
+        room_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/rooms/{}", crate::progenitor_support::encode_path(room_id)),
+        )
+    }
+
+    /// Mock [`Rooms::get_zr_setting`](crate::rooms::Rooms::get_zr_setting), a `GET` to `/rooms/{roomId}/settings`.
+    pub fn get_zr_setting(
+        &self,
+        room_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/rooms/{}/settings",
+                crate::progenitor_support::encode_path(room_id)
+            ),
+        )
+    }
+
+    /// Mock [`Rooms::update_zr_settings`](crate::rooms::Rooms::update_zr_settings), a `PATCH` to `/rooms/{roomId}/settings`.
+    pub fn update_zr_settings(&self, room_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/rooms/{}/settings",
+                crate::progenitor_support::encode_path(room_id)
+            ),
+        )
+    }
+
+    /// Mock [`Rooms::list_zr_devices`](crate::rooms::Rooms::list_zr_devices), a `GET` to `/rooms/{roomId}/devices`.
+    pub fn list_zr_devices(
+        &self,
+        room_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListZrDevicesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/rooms/{}/devices",
+                crate::progenitor_support::encode_path(room_id)
+            ),
+        )
+    }
+
+    /// Mock [`Rooms::change_zr_location`](crate::rooms::Rooms::change_zr_location), a `PUT` to `/rooms/{roomId}/location`.
+    pub fn change_zr_location(&self, room_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/rooms/{}/location",
+                crate::progenitor_support::encode_path(room_id)
+            ),
+        )
+    }
+
+    /// Mock [`Rooms::check`](crate::rooms::Rooms::check), a `PATCH` to `/rooms/{id}/events`.
+    pub fn check(&self, id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/rooms/{}/events",
+                crate::progenitor_support::encode_path(id)
+            ),
+        )
+    }
+
+    /// Mock [`Rooms::list_digital_signage_content`](crate::rooms::Rooms::list_digital_signage_content), a `GET` to `/rooms/digital_signage`.
+    pub fn list_digital_signage_content(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListDigitalSignageContentResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rooms/digital_signage".to_string())
+    }
+
+    /// Mock [`Rooms::manage_e_91_1signage`](crate::rooms::Rooms::manage_e_91_1signage), a `PATCH` to `/rooms/events`.
+    pub fn manage_e_91_1signage(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ManageE911SignageResponse> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/rooms/events".to_string())
+    }
+}
+
+pub struct RoomsAccount<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RoomsAccount<'a> {
+    /// Mock [`RoomsAccount::get_zr_account_profile`](crate::rooms_account::RoomsAccount::get_zr_account_profile), a `GET` to `/rooms/account_profile`.
+    pub fn get_zr_account_profile(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UpdateZrAccProfileRequest> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rooms/account_profile".to_string())
+    }
+
+    /// Mock [`RoomsAccount::update_zr_acc_profile`](crate::rooms_account::RoomsAccount::update_zr_acc_profile), a `PATCH` to `/rooms/account_profile`.
+    pub fn update_zr_acc_profile(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/rooms/account_profile".to_string())
+    }
+
+    /// Mock [`RoomsAccount::get_zr_account_setting`](crate::rooms_account::RoomsAccount::get_zr_account_setting), a `GET` to `/rooms/account_settings`.
+    pub fn get_zr_account_setting(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rooms/account_settings".to_string())
+    }
+
+    /// Mock [`RoomsAccount::update_zoom_room_acc_settings`](crate::rooms_account::RoomsAccount::update_zoom_room_acc_settings), a `PATCH` to `/rooms/account_settings`.
+    pub fn update_zoom_room_acc_settings(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/rooms/account_settings".to_string(),
+        )
+    }
+}
+
+pub struct RoomsDevices<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RoomsDevices<'a> {
+    /// Mock [`RoomsDevices::change_zoom_rooms_app_version`](crate::rooms_devices::RoomsDevices::change_zoom_rooms_app_version), a `PUT` to `/rooms/{roomId}/devices/{deviceId}/app_version`.
+    pub fn change_zoom_rooms_app_version(
+        &self,
+        room_id: &str,
+        device_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/rooms/{}/devices/{}/app_version",
+                crate::progenitor_support::encode_path(room_id),
+                crate::progenitor_support::encode_path(device_id)
+            ),
+        )
+    }
+}
+
+pub struct RoomsLocation<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> RoomsLocation<'a> {
+    /// Mock [`RoomsLocation::list_zr_locations`](crate::rooms_location::RoomsLocation::list_zr_locations), a `GET` to `/rooms/locations`.
+    pub fn list_zr_locations(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListZrLocationsResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/rooms/locations".to_string())
+    }
+
+    /// Mock [`RoomsLocation::add_azr_location`](crate::rooms_location::RoomsLocation::add_azr_location), a `POST` to `/rooms/locations`.
+    pub fn add_azr_location(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddAzrLocationResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/rooms/locations".to_string())
+    }
+
+    /// Mock [`RoomsLocation::get_zr_location_profile`](crate::rooms_location::RoomsLocation::get_zr_location_profile), a `GET` to `/rooms/locations/{locationId}`.
+    pub fn get_zr_location_profile(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetZrLocationProfileResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/rooms/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`RoomsLocation::update_zr_location_profile`](crate::rooms_location::RoomsLocation::update_zr_location_profile), a `PATCH` to `/rooms/locations/{locationId}`.
+    pub fn update_zr_location_profile(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/rooms/locations/{}",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`RoomsLocation::get_zr_location_setting`](crate::rooms_location::RoomsLocation::get_zr_location_setting), a `GET` to `/rooms/locations/{locationId}/settings`.
+    pub fn get_zr_location_setting(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/rooms/locations/{}/settings",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`RoomsLocation::update_zr_location_settings`](crate::rooms_location::RoomsLocation::update_zr_location_settings), a `PATCH` to `/rooms/locations/{locationId}/settings`.
+    pub fn update_zr_location_settings(
+        &self,
+        location_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/rooms/locations/{}/settings",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+
+    /// Mock [`RoomsLocation::get_zr_location_structure`](crate::rooms_location::RoomsLocation::get_zr_location_structure), a `GET` to `/rooms/locations/structure`.
+    pub fn get_zr_location_structure(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetZrLocationStructureResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            "/rooms/locations/structure".to_string(),
+        )
+    }
+
+    /// Mock [`RoomsLocation::update_zoom_structure`](crate::rooms_location::RoomsLocation::update_zoom_structure), a `PATCH` to `/rooms/locations/structure`.
+    pub fn update_zoom_structure(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            "/rooms/locations/structure".to_string(),
+        )
+    }
+
+    /// Mock [`RoomsLocation::change_parent_location`](crate::rooms_location::RoomsLocation::change_parent_location), a `PUT` to `/rooms/locations/{locationId}/location`.
+    pub fn change_parent_location(&self, location_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/rooms/locations/{}/location",
+                crate::progenitor_support::encode_path(location_id)
+            ),
+        )
+    }
+}
+
+pub struct SipConnectedAudio<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SipConnectedAudio<'a> {
+    /// Mock [`SipConnectedAudio::list_sip_trunk_numbers`](crate::sip_connected_audio::SipConnectedAudio::list_sip_trunk_numbers), a `GET` to `/sip_trunk/numbers`.
+    pub fn list_sip_trunk_numbers(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSipTrunkNumbersResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/sip_trunk/numbers".to_string())
+    }
+
+    /// Mock [`SipConnectedAudio::assign_sip_config`](crate::sip_connected_audio::SipConnectedAudio::assign_sip_config), a `PATCH` to `/accounts/{accountId}/sip_trunk/settings`.
+    pub fn assign_sip_config(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/accounts/{}/sip_trunk/settings",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::assign_sip_trunk_numbers`](crate::sip_connected_audio::SipConnectedAudio::assign_sip_trunk_numbers), a `POST` to `/accounts/{accountId}/sip_trunk/numbers`.
+    pub fn assign_sip_trunk_numbers(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/sip_trunk/numbers",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::delete_all_sip_numbers`](crate::sip_connected_audio::SipConnectedAudio::delete_all_sip_numbers), a `DELETE` to `/accounts/{accountId}/sip_trunk/numbers`.
+    pub fn delete_all_sip_numbers(&self, account_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}/sip_trunk/numbers",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::list_sip_trunk`](crate::sip_connected_audio::SipConnectedAudio::list_sip_trunk), a `GET` to `/accounts/{accountId}/sip_trunk/trunks`.
+    pub fn list_sip_trunk(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListSipTrunksResponseData> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/sip_trunk/trunks",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::assign_sip_trunks`](crate::sip_connected_audio::SipConnectedAudio::assign_sip_trunks), a `POST` to `/accounts/{accountId}/sip_trunk/trunks`.
+    pub fn assign_sip_trunks(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AssignSipTrunksResponseData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/sip_trunk/trunks",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::delete_sip_trunk`](crate::sip_connected_audio::SipConnectedAudio::delete_sip_trunk), a `DELETE` to `/accounts/{accountId}/sip_trunk/trunks/{trunkId}`.
+    pub fn delete_sip_trunk(
+        &self,
+        account_id: &str,
+        trunk_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}/sip_trunk/trunks/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(trunk_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::list_internal_callout_countries`](crate::sip_connected_audio::SipConnectedAudio::list_internal_callout_countries), a `GET` to `/accounts/{accountId}/sip_trunk/callout_countries`.
+    pub fn list_internal_callout_countries(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListInternalCalloutCountriesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/sip_trunk/callout_countries",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::add_callout_countries`](crate::sip_connected_audio::SipConnectedAudio::add_callout_countries), a `POST` to `/accounts/{accountId}/sip_trunk/callout_countries`.
+    pub fn add_callout_countries(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddCalloutCountriesResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/sip_trunk/callout_countries",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::delete_internal_call_out_country`](crate::sip_connected_audio::SipConnectedAudio::delete_internal_call_out_country), a `DELETE` to `/accounts/{accountId}/sip_trunk/callout_countries/{countryId}`.
+    pub fn delete_internal_call_out_country(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}/sip_trunk/callout_countries/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(country_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::list_internal_numbers`](crate::sip_connected_audio::SipConnectedAudio::list_internal_numbers), a `GET` to `/accounts/{accountId}/sip_trunk/internal_numbers`.
+    pub fn list_internal_numbers(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListInternalNumbersResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::add_internal_numbers`](crate::sip_connected_audio::SipConnectedAudio::add_internal_numbers), a `POST` to `/accounts/{accountId}/sip_trunk/internal_numbers`.
+    pub fn add_internal_numbers(
+        &self,
+        account_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddInternalNumbersResponseData> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipConnectedAudio::delete_internal_number`](crate::sip_connected_audio::SipConnectedAudio::delete_internal_number), a `DELETE` to `/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}`.
+    pub fn delete_internal_number(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/accounts/{}/sip_trunk/internal_numbers/{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(number_id)
+            ),
+        )
+    }
+}
+
+pub struct SipPhone<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> SipPhone<'a> {
+    /// Mock [`SipPhone::list`](crate::sip_phone::SipPhone::list), a `GET` to `/sip_phones`.
+    pub fn list(&self) -> crate::mock::MockOperation<'a, crate::types::ListSipPhonesResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/sip_phones".to_string())
+    }
+
+    /// Mock [`SipPhone::create`](crate::sip_phone::SipPhone::create), a `POST` to `/sip_phones`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::POST, "/sip_phones".to_string())
+    }
+
+    /// Mock [`SipPhone::delete`](crate::sip_phone::SipPhone::delete), a `DELETE` to `/sip_phones/{phoneId}`.
+    pub fn delete(&self, phone_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/sip_phones/{}",
+                crate::progenitor_support::encode_path(phone_id)
+            ),
+        )
+    }
+
+    /// Mock [`SipPhone::update`](crate::sip_phone::SipPhone::update), a `PATCH` to `/sip_phones/{phoneId}`.
+    pub fn update(&self, phone_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/sip_phones/{}",
+                crate::progenitor_support::encode_path(phone_id)
+            ),
+        )
+    }
+}
+
+pub struct TrackingField<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> TrackingField<'a> {
+    /// Mock [`TrackingField::trackingfield_list`](crate::tracking_field::TrackingField::trackingfield_list), a `GET` to `/tracking_fields`.
+    pub fn trackingfield_list(&self) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock
+            .operation(reqwest::Method::GET, "/tracking_fields".to_string())
+    }
+
+    /// Mock [`TrackingField::trackingfield_create`](crate::tracking_field::TrackingField::trackingfield_create), a `POST` to `/tracking_fields`.
+    pub fn trackingfield_create(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrackingfieldGetResponseAllOf> {
+        self.mock
+            .operation(reqwest::Method::POST, "/tracking_fields".to_string())
+    }
+
+    /// Mock [`TrackingField::trackingfield_get`](crate::tracking_field::TrackingField::trackingfield_get), a `GET` to `/tracking_fields/{fieldId}`.
+    pub fn trackingfield_get(
+        &self,
+        field_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TrackingfieldGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/tracking_fields/{}",
+                crate::progenitor_support::encode_path(field_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrackingField::trackingfield_delete`](crate::tracking_field::TrackingField::trackingfield_delete), a `DELETE` to `/tracking_fields/{fieldId}`.
+    pub fn trackingfield_delete(&self, field_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/tracking_fields/{}",
+                crate::progenitor_support::encode_path(field_id)
+            ),
+        )
+    }
+
+    /// Mock [`TrackingField::trackingfield_update`](crate::tracking_field::TrackingField::trackingfield_update), a `PATCH` to `/tracking_fields/{fieldId}`.
+    pub fn trackingfield_update(&self, field_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/tracking_fields/{}",
+                crate::progenitor_support::encode_path(field_id)
+            ),
+        )
+    }
+}
+
+pub struct Tsp<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Tsp<'a> {
+    /// Mock [`Tsp::get`](crate::tsp::Tsp::get), a `GET` to `/tsp`.
+    pub fn get(&self) -> crate::mock::MockOperation<'a, crate::types::TspResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/tsp".to_string())
+    }
+
+    /// Mock [`Tsp::update`](crate::tsp::Tsp::update), a `PATCH` to `/tsp`.
+    pub fn update(&self) -> crate::mock::MockOperation<'a, ()> {
+        self.mock
+            .operation(reqwest::Method::PATCH, "/tsp".to_string())
+    }
+
+    /// Mock [`Tsp::user_ts_ps`](crate::tsp::Tsp::user_ts_ps), a `GET` to `/users/{userId}/tsp`.
+    pub fn user_ts_ps(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserTsPsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/tsp",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tsp::user_create`](crate::tsp::Tsp::user_create), a `POST` to `/users/{userId}/tsp`.
+    pub fn user_create(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TspAccountsList> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/tsp",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tsp::user`](crate::tsp::Tsp::user), a `GET` to `/users/{userId}/tsp/{tspId}`.
+    pub fn user(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::TspAccount> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/tsp/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(tsp_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tsp::user_delete`](crate::tsp::Tsp::user_delete), a `DELETE` to `/users/{userId}/tsp/{tspId}`.
+    pub fn user_delete(&self, user_id: &str, tsp_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/tsp/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(tsp_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tsp::user_update`](crate::tsp::Tsp::user_update), a `PATCH` to `/users/{userId}/tsp/{tspId}`.
+    pub fn user_update(&self, user_id: &str, tsp_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/users/{}/tsp/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(tsp_id)
+            ),
+        )
+    }
+
+    /// Mock [`Tsp::url_update`](crate::tsp::Tsp::url_update), a `PATCH` to `/users/{userId}/tsp/settings`.
+    pub fn url_update(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/users/{}/tsp/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct Users<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Users<'a> {
+    /// Mock [`Users::get_page`](crate::users::Users::get_page), a `GET` to `/users`.
+    pub fn get_page(&self) -> crate::mock::MockOperation<'a, crate::types::UsersResponseData> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users".to_string())
+    }
+
+    /// Mock [`Users::create`](crate::users::Users::create), a `POST` to `/users`.
+    pub fn create(&self) -> crate::mock::MockOperation<'a, crate::types::UserCreateResponse> {
+        self.mock
+            .operation(reqwest::Method::POST, "/users".to_string())
+    }
+
+    /// Mock [`Users::user`](crate::users::Users::user), a `GET` to `/users/{userId}`.
+    pub fn user(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!("/users/{}", crate::progenitor_support::encode_path(user_id)),
+        )
+    }
+
+    /// Mock [`Users::delete`](crate::users::Users::delete), a `DELETE` to `/users/{userId}`.
+    pub fn delete(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!("/users/{}", crate::progenitor_support::encode_path(user_id)),
+        )
+    }
+
+    /// Mock [`Users::update`](crate::users::Users::update), a `PATCH` to `/users/{userId}`.
+    pub fn update(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!("/users/{}", crate::progenitor_support::encode_path(user_id)),
+        )
+    }
+
+    /// Mock [`Users::zak`](crate::users::Users::zak), a `GET` to `/users/me/zak`.
+    pub fn zak(&self) -> crate::mock::MockOperation<'a, crate::types::UserZakResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users/me/zak".to_string())
+    }
+
+    /// Mock [`Users::assistant`](crate::users::Users::assistant), a `GET` to `/users/{userId}/assistants`.
+    pub fn assistant(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserAssistantsList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/assistants",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::assistant_create`](crate::users::Users::assistant_create), a `POST` to `/users/{userId}/assistants`.
+    pub fn assistant_create(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddRoleMembersResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/assistants",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::assistants_delete`](crate::users::Users::assistants_delete), a `DELETE` to `/users/{userId}/assistants`.
+    pub fn assistants_delete(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/assistants",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::assistant_delete`](crate::users::Users::assistant_delete), a `DELETE` to `/users/{userId}/assistants/{assistantId}`.
+    pub fn assistant_delete(
+        &self,
+        user_id: &str,
+        assistant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/assistants/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(assistant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::scheduler`](crate::users::Users::scheduler), a `GET` to `/users/{userId}/schedulers`.
+    pub fn scheduler(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSchedulersList> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/schedulers",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::schedulers_delete`](crate::users::Users::schedulers_delete), a `DELETE` to `/users/{userId}/schedulers`.
+    pub fn schedulers_delete(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/schedulers",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::scheduler_delete`](crate::users::Users::scheduler_delete), a `DELETE` to `/users/{userId}/schedulers/{schedulerId}`.
+    pub fn scheduler_delete(
+        &self,
+        user_id: &str,
+        scheduler_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/schedulers/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(scheduler_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::picture`](crate::users::Users::picture), a `POST` to `/users/{userId}/picture`.
+    pub fn picture(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/picture",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_domains`](crate::users::Users::settings_domains), a `GET` to `/users/{userId}/settings`.
+    pub fn settings_domains(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_user`](crate::users::Users::settings_user), a `GET` to `/users/{userId}/settings`.
+    pub fn settings_user(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_meeting_security`](crate::users::Users::settings_meeting_security), a `GET` to `/users/{userId}/settings`.
+    pub fn settings_meeting_security(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingSecuritySettings> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::setting`](crate::users::Users::setting), a `GET` to `/users/{userId}/settings`.
+    pub fn setting(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserSettingsResponseOneOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::settings_update`](crate::users::Users::settings_update), a `PATCH` to `/users/{userId}/settings`.
+    pub fn settings_update(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/users/{}/settings",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::status`](crate::users::Users::status), a `PUT` to `/users/{userId}/status`.
+    pub fn status(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/users/{}/status",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::password`](crate::users::Users::password), a `PUT` to `/users/{userId}/password`.
+    pub fn password(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/users/{}/password",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::permission`](crate::users::Users::permission), a `GET` to `/users/{userId}/permissions`.
+    pub fn permission(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserPermissions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/permissions",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::token`](crate::users::Users::token), a `GET` to `/users/{userId}/token`.
+    pub fn token(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserZakResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/token",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::sso_token_delete`](crate::users::Users::sso_token_delete), a `DELETE` to `/users/{userId}/token`.
+    pub fn sso_token_delete(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/token",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::email`](crate::users::Users::email), a `GET` to `/users/email`.
+    pub fn email(&self) -> crate::mock::MockOperation<'a, crate::types::UserEmailResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users/email".to_string())
+    }
+
+    /// Mock [`Users::email_update`](crate::users::Users::email_update), a `PUT` to `/users/{userId}/email`.
+    pub fn email_update(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/users/{}/email",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::vanity_name`](crate::users::Users::vanity_name), a `GET` to `/users/vanity_name`.
+    pub fn vanity_name(
+        &self,
+    ) -> crate::mock::MockOperation<'a, crate::types::UserVanityNameResponse> {
+        self.mock
+            .operation(reqwest::Method::GET, "/users/vanity_name".to_string())
+    }
+
+    /// Mock [`Users::switch_account`](crate::users::Users::switch_account), a `PUT` to `/accounts/{accountId}/users/{userId}/account`.
+    pub fn switch_account(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/accounts/{}/users/{}/account",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::update_presence_status`](crate::users::Users::update_presence_status), a `PUT` to `/users/{userId}/presence_status`.
+    pub fn update_presence_status(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/users/{}/presence_status",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::upload_v_buser`](crate::users::Users::upload_v_buser), a `POST` to `/users/{userId}/settings/virtual_backgrounds`.
+    pub fn upload_v_buser(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Files> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Users::del_vb`](crate::users::Users::del_vb), a `DELETE` to `/users/{userId}/settings/virtual_backgrounds`.
+    pub fn del_vb(&self, user_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/users/{}/settings/virtual_backgrounds",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+}
+
+pub struct Webinars<'a> {
+    mock: &'a Mock,
+}
+
+impl<'a> Webinars<'a> {
+    /// Mock [`Webinars::get`](crate::webinars::Webinars::get), a `GET` to `/users/{userId}/webinars`.
+    pub fn get(&self, user_id: &str) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/webinars",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::create`](crate::webinars::Webinars::create), a `POST` to `/users/{userId}/webinars`.
+    pub fn create(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebinarCreateResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/users/{}/webinars",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::webinar`](crate::webinars::Webinars::webinar), a `GET` to `/webinars/{webinarId}`.
+    pub fn webinar(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebinarResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::delete`](crate::webinars::Webinars::delete), a `DELETE` to `/webinars/{webinarId}`.
+    pub fn delete(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::update`](crate::webinars::Webinars::update), a `PATCH` to `/webinars/{webinarId}`.
+    pub fn update(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/webinars/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::list_participants`](crate::webinars::Webinars::list_participants), a `GET` to `/past_webinars/{webinarId}/participants`.
+    pub fn list_participants(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebinarParticipantsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::status`](crate::webinars::Webinars::status), a `PUT` to `/webinars/{webinarId}/status`.
+    pub fn status(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/webinars/{}/status",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::panelist`](crate::webinars::Webinars::panelist), a `GET` to `/webinars/{webinarId}/panelists`.
+    pub fn panelist(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/panelists",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::panelist_create`](crate::webinars::Webinars::panelist_create), a `POST` to `/webinars/{webinarId}/panelists`.
+    pub fn panelist_create(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/webinars/{}/panelists",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::panelists_delete`](crate::webinars::Webinars::panelists_delete), a `DELETE` to `/webinars/{webinarId}/panelists`.
+    pub fn panelists_delete(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webinars/{}/panelists",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::panelist_delete`](crate::webinars::Webinars::panelist_delete), a `DELETE` to `/webinars/{webinarId}/panelists/{panelistId}`.
+    pub fn panelist_delete(
+        &self,
+        webinar_id: i64,
+        panelist_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webinars/{}/panelists/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                crate::progenitor_support::encode_path(&panelist_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrant`](crate::webinars::Webinars::registrant), a `GET` to `/webinars/{webinarId}/registrants`.
+    pub fn registrant(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/registrants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrant_create`](crate::webinars::Webinars::registrant_create), a `POST` to `/webinars/{webinarId}/registrants`.
+    pub fn registrant_create(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebinarRegistrantCreateResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/webinars/{}/registrants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::add_batch_registrants`](crate::webinars::Webinars::add_batch_registrants), a `POST` to `/webinars/{webinarId}/batch_registrants`.
+    pub fn add_batch_registrants(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::AddBatchRegistrantsResponse> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/webinars/{}/batch_registrants",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrant_status`](crate::webinars::Webinars::registrant_status), a `PUT` to `/webinars/{webinarId}/registrants/status`.
+    pub fn registrant_status(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/webinars/{}/registrants/status",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::past`](crate::webinars::Webinars::past), a `GET` to `/past_webinars/{webinarId}/instances`.
+    pub fn past(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/instances",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::poll`](crate::webinars::Webinars::poll), a `GET` to `/webinars/{webinarId}/polls`.
+    pub fn poll(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/polls",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::poll_create`](crate::webinars::Webinars::poll_create), a `POST` to `/webinars/{webinarId}/polls`.
+    pub fn poll_create(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingPollGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/webinars/{}/polls",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::poll_get`](crate::webinars::Webinars::poll_get), a `GET` to `/webinars/{webinarId}/polls/{pollId}`.
+    pub fn poll_get(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::MeetingPollGetResponseAllOf> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/polls/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::poll_update`](crate::webinars::Webinars::poll_update), a `PUT` to `/webinars/{webinarId}/polls/{pollId}`.
+    pub fn poll_update(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PUT,
+            format!(
+                "/webinars/{}/polls/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::poll_delete`](crate::webinars::Webinars::poll_delete), a `DELETE` to `/webinars/{webinarId}/polls/{pollId}`.
+    pub fn poll_delete(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webinars/{}/polls/{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                crate::progenitor_support::encode_path(poll_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrants_questions_get`](crate::webinars::Webinars::registrants_questions_get), a `GET` to `/webinars/{webinarId}/registrants/questions`.
+    pub fn registrants_questions_get(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::WebinarRegistrantQuestions> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/registrants/questions",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrant_question_update`](crate::webinars::Webinars::registrant_question_update), a `PATCH` to `/webinars/{webinarId}/registrants/questions`.
+    pub fn registrant_question_update(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/webinars/{}/registrants/questions",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::registrant_get`](crate::webinars::Webinars::registrant_get), a `GET` to `/webinars/{webinarId}/registrants/{registrantId}`.
+    pub fn registrant_get(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/registrants/{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::delete_registrant`](crate::webinars::Webinars::delete_registrant), a `DELETE` to `/webinars/{webinarId}/registrants/{registrantId}`.
+    pub fn delete_registrant(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+    ) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::DELETE,
+            format!(
+                "/webinars/{}/registrants/{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::absentee`](crate::webinars::Webinars::absentee), a `GET` to `/past_webinars/{WebinarUUID}/absentees`.
+    pub fn absentee(
+        &self,
+        webinar_uuid: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::Domains> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/absentees",
+                crate::progenitor_support::encode_path(webinar_uuid)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::get_tracking_sources`](crate::webinars::Webinars::get_tracking_sources), a `GET` to `/webinars/{webinarId}/tracking_sources`.
+    pub fn get_tracking_sources(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetTrackingSourcesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/tracking_sources",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::list_past_poll_results`](crate::webinars::Webinars::list_past_poll_results), a `GET` to `/past_webinars/{webinarId}/polls`.
+    pub fn list_past_poll_results(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportMeetingPollsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/polls",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::list_past_qa`](crate::webinars::Webinars::list_past_qa), a `GET` to `/past_webinars/{webinarId}/qa`.
+    pub fn list_past_qa(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ReportWebinarQaResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/past_webinars/{}/qa",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::list_templates`](crate::webinars::Webinars::list_templates), a `GET` to `/users/{userId}/webinar_templates`.
+    pub fn list_templates(
+        &self,
+        user_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::ListWebinarTemplatesResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/users/{}/webinar_templates",
+                crate::progenitor_support::encode_path(user_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::get_live_stream_details`](crate::webinars::Webinars::get_live_stream_details), a `GET` to `/webinars/{webinarId}/livestream`.
+    pub fn get_live_stream_details(
+        &self,
+        webinar_id: &str,
+    ) -> crate::mock::MockOperation<'a, crate::types::GetLiveStreamDetailsResponse> {
+        self.mock.operation(
+            reqwest::Method::GET,
+            format!(
+                "/webinars/{}/livestream",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::live_stream_update`](crate::webinars::Webinars::live_stream_update), a `PATCH` to `/webinars/{webinarId}/livestream`.
+    pub fn live_stream_update(&self, webinar_id: &str) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/webinars/{}/livestream",
+                crate::progenitor_support::encode_path(webinar_id)
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::live_stream_status_update`](crate::webinars::Webinars::live_stream_status_update), a `PATCH` to `/webinars/{webinarId}/livestream/status`.
+    pub fn live_stream_status_update(&self, webinar_id: i64) -> crate::mock::MockOperation<'a, ()> {
+        self.mock.operation(
+            reqwest::Method::PATCH,
+            format!(
+                "/webinars/{}/livestream/status",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+
+    /// Mock [`Webinars::invite_links_create`](crate::webinars::Webinars::invite_links_create), a `POST` to `/webinars/{webinarId}/invite_links`.
+    pub fn invite_links_create(
+        &self,
+        webinar_id: i64,
+    ) -> crate::mock::MockOperation<'a, crate::types::InviteLinks> {
+        self.mock.operation(
+            reqwest::Method::POST,
+            format!(
+                "/webinars/{}/invite_links",
+                crate::progenitor_support::encode_path(&webinar_id.to_string())
+            ),
+        )
+    }
+}
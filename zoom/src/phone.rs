@@ -223,6 +223,81 @@ impl Phone {
         // Return our response data.
         Ok(phone_numbers)
     }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_all_account_numbers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list all Zoom Phone numbers in a Zoom account.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_all_account_numbers_stream(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListAccountPhoneNumbersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !extension_type.to_string().is_empty() {
+            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        }
+        if !number_type.to_string().is_empty() {
+            query_args.push(("number_type".to_string(), number_type.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListAccountPhoneNumbersResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.phone_numbers,
+                })
+            }
+        })
+    }
     /**
      * Get user's profile.
      *
@@ -521,6 +596,67 @@ impl Phone {
         // Return our response data.
         Ok(templates)
     }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_all_setting_templates`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to get a list of all the created phone template settings.
+     *
+     * **Scopes:** `phone:read:admin` or `phone:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn list_all_setting_templates_stream(
+        &self,
+        site_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Templates> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/setting_templates?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListSettingTemplatesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.templates,
+                })
+            }
+        })
+    }
     /**
      * Add a setting template.
      *
@@ -689,6 +825,59 @@ impl Phone {
         // Return our response data.
         Ok(locations)
     }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_all_locations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list emergency service locations.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_locations_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListLocationsResponse> {
+        let url = self.client.url("/phone/locations", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListLocationsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.locations,
+                })
+            }
+        })
+    }
     /**
      * Add emergency service location.
      *
@@ -943,6 +1132,59 @@ impl Phone {
         // Return our response data.
         Ok(sip_groups)
     }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_all_sip_groups`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_sip_groups_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::SipGroups> {
+        let url = self.client.url("/phone/sip_groups", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListSipGroupsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.sip_groups,
+                })
+            }
+        })
+    }
     /**
      * Get setting template details.
      *
@@ -1213,6 +1455,89 @@ impl Phone {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `get_all_user_call_logs`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_call_logs_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CallLogs> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/call_logs?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserCallLogsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.call_logs,
+                })
+            }
+        })
+    }
     /**
      * Get user's recordings.
      *
@@ -1368,6 +1693,77 @@ impl Phone {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_user_recordings`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_recordings_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Recordings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/recordings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserRecordingsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.recordings,
+                })
+            }
+        })
+    }
     /**
      * Get user's voicemails.
      *
@@ -1532,6 +1928,81 @@ impl Phone {
         // Return our response data.
         Ok(voice_mails)
     }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `get_all_user_voice_mails`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_voice_mails_stream(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::VoiceMails> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/voice_mails?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserVoiceMailsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.voice_mails,
+                })
+            }
+        })
+    }
     /**
      * Set up shared access.
      *
@@ -1856,6 +2327,88 @@ impl Phone {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `get_all_account_call_logs`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
+     *
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     */
+    pub fn get_all_account_call_logs_stream(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AccountCallLogsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/call_logs?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::AccountCallLogsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.call_logs,
+                })
+            }
+        })
+    }
     /**
      * Assign phone number to user.
      *
@@ -2188,6 +2741,88 @@ impl Phone {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_all_recordings`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     *
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
+     */
+    pub fn get_all_recordings_stream(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetPhoneRecordingsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !owner_type.is_empty() {
+            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !recording_type.is_empty() {
+            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/recordings?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetPhoneRecordingsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.recordings,
+                })
+            }
+        })
+    }
     /**
      * List BYOC SIP trunks.
      *
@@ -2304,6 +2939,58 @@ impl Phone {
         // Return our response data.
         Ok(byoc_sip_trunk)
     }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_all_byocsip_trunk`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn list_all_byocsip_trunk_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ByocSipTrunk> {
+        let url = self.client.url("/phone/sip_trunk/trunks", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListByocsipTrunkResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.byoc_sip_trunk,
+                })
+            }
+        })
+    }
     /**
      * Assign SIP trunks.
      *
@@ -2501,6 +3188,59 @@ impl Phone {
         // Return our response data.
         Ok(external_contacts)
     }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_all_external_contacts`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_external_contacts_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ExternalContacts> {
+        let url = self.client.url("/phone/external_contacts", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListExternalContactsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.external_contacts,
+                })
+            }
+        })
+    }
     /**
      * Add an external contact.
      *
@@ -2901,6 +3641,65 @@ impl Phone {
         // Return our response data.
         Ok(users)
     }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_all_users_stream(
+        &self,
+        site_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListPhoneUsersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/users?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListPhoneUsersResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.users,
+                })
+            }
+        })
+    }
     /**
      * Get call log details.
      *
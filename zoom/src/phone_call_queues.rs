@@ -136,6 +136,62 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(call_queues)
     }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_all_call_queues`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Call queues allow you to route incoming calls to a group of users. For instance, you can use call queues to route calls to various departments in your organization such as sales, engineering, billing, customer service etc.<br> Use this API to list Call queues.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro, Business, or Education account
+     * * Account owner or admin permissions
+     * * Zoom Phone license<br>
+     * **Scopes:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn list_all_call_queues_stream(
+        &self,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::CallQueues> {
+        let url = self.client.url("/phone/call_queues", None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListCallQueuesResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.call_queues,
+                })
+            }
+        })
+    }
     /**
      * Create a call queue.
      *
@@ -726,4 +782,76 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_call_queue_recordings`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to view [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091#h_cbc9f2a3-e06c-4daa-83d4-ddbceef9c77b) from the call queue.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or higher account with Zoom Phone license.
+     * * [Automatic call recordings](https://support.zoom.us/hc/en-us/articles/360033511872#h_fcb297bb-14e8-4094-91ca-dc61e1a18734) must be enabled in the Policy Settings for call queues. <br> **Scope:** `phone:read:admin`<br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     *
+     *
+     *
+     */
+    pub fn get_all_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::GetCallQueueRecordingsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/call_queues/{}/recordings?{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::GetCallQueueRecordingsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.recordings,
+                })
+            }
+        })
+    }
 }
@@ -218,6 +218,72 @@ impl Roles {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * User [roles](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) can have a set of permissions that allows access only to the pages a user needs to view or edit. Use this API to list all the members that are assigned a specific role.
+     *
+     * **Scope:** `role:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
+     * * A Pro or a higher plan.
+     */
+    pub fn get_all_members_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Domains> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_count.is_empty() {
+            query_args.push(("page_count".to_string(), page_count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/roles/{}/members?{}",
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::RoleMembersList = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.members,
+                })
+            }
+        })
+    }
     /**
      * Assign a role.
      *
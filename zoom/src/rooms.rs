@@ -164,6 +164,75 @@ impl Rooms {
         // Return our response data.
         Ok(rooms)
     }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_all_zoom`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Zoom Rooms is a software-based room system that provides an integrated experience for audio conferencing, wireless screen sharing and video conferencing. Use this API to list all the existing [Zoom Rooms](https://support.zoom.us/hc/en-us/articles/207483343-Getting-Started-with-Zoom-Rooms) in a Zoom account.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
+     * **Scopes**: `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zoom_stream(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::ListZoomRoomsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/rooms?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListZoomRoomsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.rooms,
+                })
+            }
+        })
+    }
     /**
      * Add a Zoom Room.
      *
@@ -637,6 +706,72 @@ impl Rooms {
         // Return our response data.
         Ok(contents)
     }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_all_digital_signage_content`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * List information about existing [Zoom Rooms digital signage](https://support.zoom.us/hc/en-us/articles/360000030683-Zoom-Rooms-digital-signage) content in a Zoom account.<br> You can also access this information by logging into your Zoom account in the Zoom web portal and visiting the [Digital Signage Content](https://zoom.us/digitalsignage#/) page listed under **Room Management**.
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Rooms.
+     * * Existing content files or folder in [Digital Signage Content](https://zoom.us/digitalsignage#/) page.
+     *
+     *
+     *
+     */
+    pub fn list_all_digital_signage_content_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Site> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/digital_signage?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListDigitalSignageContentResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.contents,
+                })
+            }
+        })
+    }
     /**
      * Update E911 digital signage.
      *
@@ -161,6 +161,74 @@ impl RoomsLocation {
         // Return our response data.
         Ok(locations)
     }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_all_zr_locations`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * A Zoom account owner or a Zoom Room administrator can establish a [location hierarchy](https://support.zoom.us/hc/en-us/articles/115000342983-Zoom-Rooms-Location-Hierarchy) to help manage Zoom Rooms that are spread among a variety of locations. Use this API to list the different location types used for Zoom Rooms in an account.<br><br>
+     * **Prerequisites:**
+     * * Account owner or admin permissions.
+     * * Zoom Rooms Version 4.0 or higher<br><br>
+     * **Scopes:** `room:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zr_locations_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::AddAzrLocationResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent_location_id.is_empty() {
+            query_args.push((
+                "parent_location_id".to_string(),
+                parent_location_id.to_string(),
+            ));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/locations?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListZrLocationsResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.locations,
+                })
+            }
+        })
+    }
     /**
      * Add a location.
      *
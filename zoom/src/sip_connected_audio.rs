@@ -504,6 +504,66 @@ impl SipConnectedAudio {
         // Return our response data.
         Ok(internal_numbers)
     }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_all_internal_numbers`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * This API allows a master account with SIP Connected Audio plan to list internal phone numbers (i.e., numbers that are not provided by Zoom but are owned by the organization consuming the API) assigned to a master account or a sub account.<br><br>To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
+     * <br><b>Prerequisites:</b><br>
+     * * The account making this API request must be a [master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) with SIP Connected Audio Plan.<br><br>
+     * **Scope:** `sip_trunk:master`
+     *
+     *
+     *
+     */
+    pub fn list_all_internal_numbers_stream(
+        &self,
+        account_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::InternalNumbers> {
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListInternalNumbersResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.internal_numbers,
+                })
+            }
+        })
+    }
     /**
      * Add internal numbers.
      *
@@ -5,14 +5,14 @@ use serde::{Deserialize, Serialize};
 /**
 * Payee:<br>`master` - master account holder pays.<br>`sub` - Sub account holder pays.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PayMode {
     #[serde(rename = "master")]
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayMode {
@@ -20,17 +20,46 @@ impl std::fmt::Display for PayMode {
         match self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PayMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PayMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "master" => PayMode::Master,
+            "sub" => PayMode::Sub,
+            _ => PayMode::Unknown(s),
+        })
+    }
+}
+
 impl Default for PayMode {
     fn default() -> PayMode {
         PayMode::Master
     }
 }
+impl PayMode {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PayMode::Unknown(_))
+    }
+}
 
 /// Account options object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -475,7 +504,7 @@ pub struct AccountPlans {
 /**
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Telephony and VoIP.<br>`telephony` - Audio PSTN telephony only.<br>`voip` - VoIP only.<br>`thirdParty` - 3rd party audio conference.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AudioType {
     #[serde(rename = "both")]
     Both,
@@ -485,8 +514,8 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioType {
@@ -496,23 +525,54 @@ impl std::fmt::Display for AudioType {
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AudioType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "both" => AudioType::Both,
+            "telephony" => AudioType::Telephony,
+            "thirdParty" => AudioType::ThirdParty,
+            "voip" => AudioType::Voip,
+            _ => AudioType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AudioType {
     fn default() -> AudioType {
         AudioType::Both
     }
 }
+impl AudioType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AudioType::Unknown(_))
+    }
+}
 
 /**
 * Require a password for a meeting held using Personal Meeting ID (PMI) This setting is always enabled for free accounts and Pro accounts with a single host and cannot be modified for these accounts.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "all")]
     All,
@@ -522,8 +582,8 @@ pub enum RequirePasswordPmiMeetings {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequirePasswordPmiMeetings {
@@ -533,12 +593,37 @@ impl std::fmt::Display for RequirePasswordPmiMeetings {
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
             RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RequirePasswordPmiMeetings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RequirePasswordPmiMeetings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => RequirePasswordPmiMeetings::All,
+            "jbh_only" => RequirePasswordPmiMeetings::JbhOnly,
+            "none" => RequirePasswordPmiMeetings::None,
+            "" => RequirePasswordPmiMeetings::Noop,
+            _ => RequirePasswordPmiMeetings::Unknown(s),
+        })
+    }
+}
+
 impl Default for RequirePasswordPmiMeetings {
     fn default() -> RequirePasswordPmiMeetings {
         RequirePasswordPmiMeetings::Noop
@@ -548,6 +633,11 @@ impl RequirePasswordPmiMeetings {
     pub fn is_noop(&self) -> bool {
         matches!(self, RequirePasswordPmiMeetings::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RequirePasswordPmiMeetings::Unknown(_))
+    }
 }
 
 /// Account wide meeting/webinar [password requirements](https://support.zoom.us/hc/en-us/articles/360033559832-Meeting-and-webinar-passwords#h_a427384b-e383-4f80-864d-794bf0a37604).
@@ -766,7 +856,7 @@ pub struct ScheduleMeeting {
 /**
 * Play sound when participants join or leave.<br>`host` - Heard by host only.<br>`all` - Heard by host and all attendees.<br>`none` - Disable.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EntryExitChime {
     #[serde(rename = "all")]
     All,
@@ -776,8 +866,8 @@ pub enum EntryExitChime {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntryExitChime {
@@ -787,12 +877,37 @@ impl std::fmt::Display for EntryExitChime {
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
             EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EntryExitChime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EntryExitChime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => EntryExitChime::All,
+            "host" => EntryExitChime::Host,
+            "none" => EntryExitChime::None,
+            "" => EntryExitChime::Noop,
+            _ => EntryExitChime::Unknown(s),
+        })
+    }
+}
+
 impl Default for EntryExitChime {
     fn default() -> EntryExitChime {
         EntryExitChime::Noop
@@ -802,6 +917,11 @@ impl EntryExitChime {
     pub fn is_noop(&self) -> bool {
         matches!(self, EntryExitChime::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EntryExitChime::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -897,7 +1017,7 @@ pub struct VirtualBackgroundSettings {
 *   `all`: Both hosts and attendees can share their screen during meetings. For Webinar, the hosts and panelists can start screen sharing, but not the attendees.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WhoCanShareScreen {
     #[serde(rename = "all")]
     All,
@@ -905,8 +1025,8 @@ pub enum WhoCanShareScreen {
     Host,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WhoCanShareScreen {
@@ -915,12 +1035,36 @@ impl std::fmt::Display for WhoCanShareScreen {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
             WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WhoCanShareScreen {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WhoCanShareScreen {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => WhoCanShareScreen::All,
+            "host" => WhoCanShareScreen::Host,
+            "" => WhoCanShareScreen::Noop,
+            _ => WhoCanShareScreen::Unknown(s),
+        })
+    }
+}
+
 impl Default for WhoCanShareScreen {
     fn default() -> WhoCanShareScreen {
         WhoCanShareScreen::Noop
@@ -930,6 +1074,11 @@ impl WhoCanShareScreen {
     pub fn is_noop(&self) -> bool {
         matches!(self, WhoCanShareScreen::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WhoCanShareScreen::Unknown(_))
+    }
 }
 
 /// Account Settings: In Meeting.
@@ -1543,7 +1692,7 @@ pub struct PasswordRequirement {
 *   `sign_in_with_two_factor_auth_roles` field.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "all")]
     All,
@@ -1555,8 +1704,8 @@ pub enum SignInWithTwoFactorAuth {
     Role,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignInWithTwoFactorAuth {
@@ -1567,12 +1716,38 @@ impl std::fmt::Display for SignInWithTwoFactorAuth {
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
             SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SignInWithTwoFactorAuth {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignInWithTwoFactorAuth {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => SignInWithTwoFactorAuth::All,
+            "group" => SignInWithTwoFactorAuth::Group,
+            "none" => SignInWithTwoFactorAuth::None,
+            "role" => SignInWithTwoFactorAuth::Role,
+            "" => SignInWithTwoFactorAuth::Noop,
+            _ => SignInWithTwoFactorAuth::Unknown(s),
+        })
+    }
+}
+
 impl Default for SignInWithTwoFactorAuth {
     fn default() -> SignInWithTwoFactorAuth {
         SignInWithTwoFactorAuth::Noop
@@ -1582,6 +1757,11 @@ impl SignInWithTwoFactorAuth {
     pub fn is_noop(&self) -> bool {
         matches!(self, SignInWithTwoFactorAuth::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SignInWithTwoFactorAuth::Unknown(_))
+    }
 }
 
 /// [Security settings](https://support.zoom.us/hc/en-us/articles/360034675592-Advanced-security-settings#h_bf8a25f6-9a66-447a-befd-f02ed3404f89) of an Account.
@@ -1662,7 +1842,7 @@ pub struct Security {
 /**
 * Automatic recording:<br>`local` - Record on local.<br>`cloud` -  Record on cloud.<br>`none` - Disabled.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AutoRecording {
     #[serde(rename = "cloud")]
     Cloud,
@@ -1672,8 +1852,8 @@ pub enum AutoRecording {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecording {
@@ -1683,12 +1863,37 @@ impl std::fmt::Display for AutoRecording {
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
             AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AutoRecording {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoRecording {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cloud" => AutoRecording::Cloud,
+            "local" => AutoRecording::Local,
+            "none" => AutoRecording::None,
+            "" => AutoRecording::Noop,
+            _ => AutoRecording::Unknown(s),
+        })
+    }
+}
+
 impl Default for AutoRecording {
     fn default() -> AutoRecording {
         AutoRecording::Noop
@@ -1698,6 +1903,11 @@ impl AutoRecording {
     pub fn is_noop(&self) -> bool {
         matches!(self, AutoRecording::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AutoRecording::Unknown(_))
+    }
 }
 
 /// This object represents the minimum password requirements set for recordings via Account Recording Settings.
@@ -2563,7 +2773,7 @@ pub struct DateTime {
 /**
 * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Protocol {
     #[serde(rename = "H.323")]
     H323,
@@ -2571,8 +2781,8 @@ pub enum Protocol {
     Sip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Protocol {
@@ -2581,12 +2791,36 @@ impl std::fmt::Display for Protocol {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
             Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "H.323" => Protocol::H323,
+            "SIP" => Protocol::Sip,
+            "" => Protocol::Noop,
+            _ => Protocol::Unknown(s),
+        })
+    }
+}
+
 impl Default for Protocol {
     fn default() -> Protocol {
         Protocol::Noop
@@ -2596,12 +2830,17 @@ impl Protocol {
     pub fn is_noop(&self) -> bool {
         matches!(self, Protocol::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Protocol::Unknown(_))
+    }
 }
 
 /**
 * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Encryption {
     #[serde(rename = "auto")]
     Auto,
@@ -2611,8 +2850,8 @@ pub enum Encryption {
     Yes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Encryption {
@@ -2622,12 +2861,37 @@ impl std::fmt::Display for Encryption {
             Encryption::No => "no",
             Encryption::Yes => "yes",
             Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Encryption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Encryption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auto" => Encryption::Auto,
+            "no" => Encryption::No,
+            "yes" => Encryption::Yes,
+            "" => Encryption::Noop,
+            _ => Encryption::Unknown(s),
+        })
+    }
+}
+
 impl Default for Encryption {
     fn default() -> Encryption {
         Encryption::Noop
@@ -2637,6 +2901,11 @@ impl Encryption {
     pub fn is_noop(&self) -> bool {
         matches!(self, Encryption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Encryption::Unknown(_))
+    }
 }
 
 /// The H.323/SIP device object.
@@ -2872,7 +3141,7 @@ pub struct Occurrence {
 /**
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Both Telephony and VoIP.<br>`telephony` - Telephony only.<br>`voip` - VoIP only.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Audio {
     #[serde(rename = "both")]
     Both,
@@ -2880,8 +3149,8 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Audio {
@@ -2890,22 +3159,52 @@ impl std::fmt::Display for Audio {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Audio {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Audio {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "both" => Audio::Both,
+            "telephony" => Audio::Telephony,
+            "voip" => Audio::Voip,
+            _ => Audio::Unknown(s),
+        })
+    }
+}
+
 impl Default for Audio {
     fn default() -> Audio {
         Audio::Both
     }
 }
+impl Audio {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Audio::Unknown(_))
+    }
+}
 
 /**
 * Type of number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Type {
     #[serde(rename = "toll")]
     Toll,
@@ -2913,8 +3212,8 @@ pub enum Type {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2923,12 +3222,36 @@ impl std::fmt::Display for Type {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "toll" => Type::Toll,
+            "tollfree" => Type::Tollfree,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        })
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -2938,6 +3261,11 @@ impl Type {
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2991,7 +3319,7 @@ pub struct GlobalDialInNumbers {
 *   
 *   `e2ee`: [End-to-end encryption](https://support.zoom.us/hc/en-us/articles/360048660871). The encryption key is stored in your local device and can not be obtained by anyone else. Enabling this setting also **disables** the following features: join before host, cloud recording, streaming, live transcription, breakout rooms, polling, 1:1 private chat, and meeting reactions.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum EncryptionType {
     #[serde(rename = "e2ee")]
     E2Ee,
@@ -2999,8 +3327,8 @@ pub enum EncryptionType {
     EnhancedEncryption,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EncryptionType {
@@ -3009,12 +3337,36 @@ impl std::fmt::Display for EncryptionType {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
             EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "e2ee" => EncryptionType::E2Ee,
+            "enhanced_encryption" => EncryptionType::EnhancedEncryption,
+            "" => EncryptionType::Noop,
+            _ => EncryptionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for EncryptionType {
     fn default() -> EncryptionType {
         EncryptionType::Noop
@@ -3024,6 +3376,11 @@ impl EncryptionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, EncryptionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, EncryptionType::Unknown(_))
+    }
 }
 
 /**
@@ -3031,7 +3388,7 @@ impl EncryptionType {
 *   `approve`: Allow users from specific regions/countries to join this meeting. If this setting is selected, the approved regions/countries must be included in the `approved_list`.<br><br>
 *   `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Method {
     #[serde(rename = "approve")]
     Approve,
@@ -3039,8 +3396,8 @@ pub enum Method {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -3049,12 +3406,36 @@ impl std::fmt::Display for Method {
             Method::Approve => "approve",
             Method::Deny => "deny",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approve" => Method::Approve,
+            "deny" => Method::Deny,
+            "" => Method::Noop,
+            _ => Method::Unknown(s),
+        })
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -3064,6 +3445,11 @@ impl Method {
     pub fn is_noop(&self) -> bool {
         matches!(self, Method::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Method::Unknown(_))
+    }
 }
 
 /// Approve or block users from specific regions/countries from joining this meeting.
@@ -3543,7 +3929,7 @@ pub struct MeetingSettings {
 *   
 *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WeeklyDays {
     #[serde(rename = "1")]
     One,
@@ -3559,8 +3945,8 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WeeklyDays {
@@ -3573,17 +3959,51 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WeeklyDays {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WeeklyDays {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "1" => WeeklyDays::One,
+            "2" => WeeklyDays::Two,
+            "3" => WeeklyDays::Three,
+            "4" => WeeklyDays::Four,
+            "5" => WeeklyDays::Five,
+            "6" => WeeklyDays::Six,
+            "7" => WeeklyDays::Seven,
+            _ => WeeklyDays::Unknown(s),
+        })
+    }
+}
+
 impl Default for WeeklyDays {
     fn default() -> WeeklyDays {
         WeeklyDays::One
     }
 }
+impl WeeklyDays {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WeeklyDays::Unknown(_))
+    }
+}
 
 /// Recurrence object. Use this object only for a meeting with type `8` i.e., a recurring meeting with fixed time.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3813,7 +4233,7 @@ pub struct MeetingInfo {
 /**
 * Meeting status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Status {
     #[serde(rename = "started")]
     Started,
@@ -3821,8 +4241,8 @@ pub enum Status {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -3831,12 +4251,36 @@ impl std::fmt::Display for Status {
             Status::Started => "started",
             Status::Waiting => "waiting",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "started" => Status::Started,
+            "waiting" => Status::Waiting,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        })
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -3846,6 +4290,11 @@ impl Status {
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Status::Unknown(_))
+    }
 }
 
 /// Meeting object.
@@ -4244,7 +4693,7 @@ pub struct MeetingLiveStream {
 *   `start`: Start a live stream. <br>
 *   `stop`: Stop an ongoing live stream.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Action {
     #[serde(rename = "start")]
     Start,
@@ -4252,8 +4701,8 @@ pub enum Action {
     Stop,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -4262,12 +4711,36 @@ impl std::fmt::Display for Action {
             Action::Start => "start",
             Action::Stop => "stop",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "start" => Action::Start,
+            "stop" => Action::Stop,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        })
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -4277,6 +4750,11 @@ impl Action {
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Action::Unknown(_))
+    }
 }
 
 /// Update the settings of a live streaming session. The settings can only be updated for a live stream that has been stopped. You can not update the settings of an ongoing live stream.
@@ -4598,14 +5076,14 @@ pub struct QosAudio {
 /**
 * The recording status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingStatus {
     #[serde(rename = "completed")]
     Completed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatus {
@@ -4613,12 +5091,35 @@ impl std::fmt::Display for RecordingStatus {
         match self {
             RecordingStatus::Completed => "completed",
             RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "completed" => RecordingStatus::Completed,
+            "" => RecordingStatus::Noop,
+            _ => RecordingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingStatus {
     fn default() -> RecordingStatus {
         RecordingStatus::Noop
@@ -4628,6 +5129,11 @@ impl RecordingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingStatus::Unknown(_))
+    }
 }
 
 /// Recording file object.
@@ -4815,7 +5321,7 @@ pub struct RecordingSeparateAudio {
 /**
 * Determine how the meeting recording is shared.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ShareRecording {
     #[serde(rename = "internally")]
     Internally,
@@ -4825,8 +5331,8 @@ pub enum ShareRecording {
     Publicly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShareRecording {
@@ -4836,12 +5342,37 @@ impl std::fmt::Display for ShareRecording {
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
             ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ShareRecording {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ShareRecording {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "internally" => ShareRecording::Internally,
+            "none" => ShareRecording::None,
+            "publicly" => ShareRecording::Publicly,
+            "" => ShareRecording::Noop,
+            _ => ShareRecording::Unknown(s),
+        })
+    }
+}
+
 impl Default for ShareRecording {
     fn default() -> ShareRecording {
         ShareRecording::Noop
@@ -4851,6 +5382,11 @@ impl ShareRecording {
     pub fn is_noop(&self) -> bool {
         matches!(self, ShareRecording::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ShareRecording::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5096,7 +5632,7 @@ pub struct Registrant {
 /**
 * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RegistrantStatusAction {
     #[serde(rename = "approve")]
     Approve,
@@ -5106,8 +5642,8 @@ pub enum RegistrantStatusAction {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RegistrantStatusAction {
@@ -5117,12 +5653,37 @@ impl std::fmt::Display for RegistrantStatusAction {
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
             RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RegistrantStatusAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RegistrantStatusAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approve" => RegistrantStatusAction::Approve,
+            "cancel" => RegistrantStatusAction::Cancel,
+            "deny" => RegistrantStatusAction::Deny,
+            "" => RegistrantStatusAction::Noop,
+            _ => RegistrantStatusAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for RegistrantStatusAction {
     fn default() -> RegistrantStatusAction {
         RegistrantStatusAction::Noop
@@ -5132,6 +5693,11 @@ impl RegistrantStatusAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, RegistrantStatusAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RegistrantStatusAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5575,7 +6141,7 @@ pub struct RecurrenceWebinar {
 *   
 *   *  `all`: Attendees are able to view all questions submitted in the Q&A.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AnswerQuestions {
     #[serde(rename = "all")]
     All,
@@ -5583,8 +6149,8 @@ pub enum AnswerQuestions {
     Only,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnswerQuestions {
@@ -5593,12 +6159,36 @@ impl std::fmt::Display for AnswerQuestions {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
             AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AnswerQuestions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AnswerQuestions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => AnswerQuestions::All,
+            "only" => AnswerQuestions::Only,
+            "" => AnswerQuestions::Noop,
+            _ => AnswerQuestions::Unknown(s),
+        })
+    }
+}
+
 impl Default for AnswerQuestions {
     fn default() -> AnswerQuestions {
         AnswerQuestions::Noop
@@ -5608,6 +6198,11 @@ impl AnswerQuestions {
     pub fn is_noop(&self) -> bool {
         matches!(self, AnswerQuestions::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AnswerQuestions::Unknown(_))
+    }
 }
 
 /// [Q&A](https://support.zoom.us/hc/en-us/articles/203686015-Using-Q-A-as-the-webinar-host#:~:text=Overview,and%20upvote%20each%20other's%20questions.) for webinar.
@@ -6190,7 +6785,7 @@ pub struct SessionWebinarUpdate {
 * Dial-in number types:<br>`toll` - Toll number.<br>`tollfree` -Toll free number.<br>
 *   `media_link` - Media link.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "media_link")]
     MediaLink,
@@ -6200,8 +6795,8 @@ pub enum TspAccountsListDialInNumbersType {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspAccountsListDialInNumbersType {
@@ -6211,12 +6806,37 @@ impl std::fmt::Display for TspAccountsListDialInNumbersType {
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
             TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TspAccountsListDialInNumbersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TspAccountsListDialInNumbersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "media_link" => TspAccountsListDialInNumbersType::MediaLink,
+            "toll" => TspAccountsListDialInNumbersType::Toll,
+            "tollfree" => TspAccountsListDialInNumbersType::Tollfree,
+            "" => TspAccountsListDialInNumbersType::Noop,
+            _ => TspAccountsListDialInNumbersType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TspAccountsListDialInNumbersType {
     fn default() -> TspAccountsListDialInNumbersType {
         TspAccountsListDialInNumbersType::Noop
@@ -6226,6 +6846,11 @@ impl TspAccountsListDialInNumbersType {
     pub fn is_noop(&self) -> bool {
         matches!(self, TspAccountsListDialInNumbersType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TspAccountsListDialInNumbersType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6268,7 +6893,7 @@ pub struct DialInNumbers {
 /**
 * Telephony bridge
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TspBridge {
     #[serde(rename = "EU_TSP_TB")]
     EuTspTb,
@@ -6276,8 +6901,8 @@ pub enum TspBridge {
     UsTspTb,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspBridge {
@@ -6286,12 +6911,36 @@ impl std::fmt::Display for TspBridge {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
             TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TspBridge {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TspBridge {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "EU_TSP_TB" => TspBridge::EuTspTb,
+            "US_TSP_TB" => TspBridge::UsTspTb,
+            "" => TspBridge::Noop,
+            _ => TspBridge::Unknown(s),
+        })
+    }
+}
+
 impl Default for TspBridge {
     fn default() -> TspBridge {
         TspBridge::Noop
@@ -6301,6 +6950,11 @@ impl TspBridge {
     pub fn is_noop(&self) -> bool {
         matches!(self, TspBridge::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TspBridge::Unknown(_))
+    }
 }
 
 /// List of TSP accounts.
@@ -6565,7 +7219,7 @@ pub struct CustomAttributes {
 *   * `Home`
 *   * `Fax`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Label {
     #[serde(rename = "Fax")]
     Fax,
@@ -6577,8 +7231,8 @@ pub enum Label {
     Office,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Label {
@@ -6589,12 +7243,38 @@ impl std::fmt::Display for Label {
             Label::Mobile => "Mobile",
             Label::Office => "Office",
             Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Fax" => Label::Fax,
+            "Home" => Label::Home,
+            "Mobile" => Label::Mobile,
+            "Office" => Label::Office,
+            "" => Label::Noop,
+            _ => Label::Unknown(s),
+        })
+    }
+}
+
 impl Default for Label {
     fn default() -> Label {
         Label::Noop
@@ -6604,6 +7284,11 @@ impl Label {
     pub fn is_noop(&self) -> bool {
         matches!(self, Label::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Label::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8335,7 +9020,7 @@ pub struct ZoomRoom {
 /**
 * Poll Question & Answer type:<br>`single` - Single choice<br>`mutliple` - Multiple choice
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PollQuestionsType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8343,8 +9028,8 @@ pub enum PollQuestionsType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollQuestionsType {
@@ -8353,12 +9038,36 @@ impl std::fmt::Display for PollQuestionsType {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
             PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PollQuestionsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PollQuestionsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "multiple" => PollQuestionsType::Multiple,
+            "single" => PollQuestionsType::Single,
+            "" => PollQuestionsType::Noop,
+            _ => PollQuestionsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PollQuestionsType {
     fn default() -> PollQuestionsType {
         PollQuestionsType::Noop
@@ -8368,6 +9077,11 @@ impl PollQuestionsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PollQuestionsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PollQuestionsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8426,7 +9140,7 @@ pub struct Poll {
 /**
 * Field name of the question.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FieldName {
     #[serde(rename = "address")]
     Address,
@@ -8456,8 +9170,8 @@ pub enum FieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldName {
@@ -8477,12 +9191,47 @@ impl std::fmt::Display for FieldName {
             FieldName::State => "state",
             FieldName::Zip => "zip",
             FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FieldName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "address" => FieldName::Address,
+            "city" => FieldName::City,
+            "comments" => FieldName::Comments,
+            "country" => FieldName::Country,
+            "industry" => FieldName::Industry,
+            "job_title" => FieldName::JobTitle,
+            "no_of_employees" => FieldName::NoOfEmployees,
+            "org" => FieldName::Org,
+            "phone" => FieldName::Phone,
+            "purchasing_time_frame" => FieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => FieldName::RoleInPurchaseProcess,
+            "state" => FieldName::State,
+            "zip" => FieldName::Zip,
+            "" => FieldName::Noop,
+            _ => FieldName::Unknown(s),
+        })
+    }
+}
+
 impl Default for FieldName {
     fn default() -> FieldName {
         FieldName::Noop
@@ -8492,6 +9241,11 @@ impl FieldName {
     pub fn is_noop(&self) -> bool {
         matches!(self, FieldName::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FieldName::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8514,7 +9268,7 @@ pub struct MeetingRegistrantQuestions {
 /**
 * Type of the question being asked.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "short")]
     Short,
@@ -8522,8 +9276,8 @@ pub enum MeetingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
@@ -8532,12 +9286,36 @@ impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
             MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MeetingRegistrantQuestionsCustomType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingRegistrantQuestionsCustomType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "short" => MeetingRegistrantQuestionsCustomType::Short,
+            "single" => MeetingRegistrantQuestionsCustomType::Single,
+            "" => MeetingRegistrantQuestionsCustomType::Noop,
+            _ => MeetingRegistrantQuestionsCustomType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MeetingRegistrantQuestionsCustomType {
     fn default() -> MeetingRegistrantQuestionsCustomType {
         MeetingRegistrantQuestionsCustomType::Noop
@@ -8547,6 +9325,11 @@ impl MeetingRegistrantQuestionsCustomType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingRegistrantQuestionsCustomType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MeetingRegistrantQuestionsCustomType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8636,7 +9419,7 @@ pub struct WebinarRegistrantQuestions {
 /**
 * Field name.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "address")]
     Address,
@@ -8668,8 +9451,8 @@ pub enum RecordingRegistrantQuestionsFieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
@@ -8692,12 +9475,50 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
             RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingRegistrantQuestionsFieldName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingRegistrantQuestionsFieldName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "address" => RecordingRegistrantQuestionsFieldName::Address,
+            "city" => RecordingRegistrantQuestionsFieldName::City,
+            "comments" => RecordingRegistrantQuestionsFieldName::Comments,
+            "country" => RecordingRegistrantQuestionsFieldName::Country,
+            "industry" => RecordingRegistrantQuestionsFieldName::Industry,
+            "job_title" => RecordingRegistrantQuestionsFieldName::JobTitle,
+            "last_name" => RecordingRegistrantQuestionsFieldName::LastName,
+            "no_of_employees" => RecordingRegistrantQuestionsFieldName::NoOfEmployees,
+            "org" => RecordingRegistrantQuestionsFieldName::Org,
+            "phone" => RecordingRegistrantQuestionsFieldName::Phone,
+            "purchasing_time_frame" => RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => {
+                RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess
+            }
+            "state" => RecordingRegistrantQuestionsFieldName::State,
+            "zip" => RecordingRegistrantQuestionsFieldName::Zip,
+            "" => RecordingRegistrantQuestionsFieldName::Noop,
+            _ => RecordingRegistrantQuestionsFieldName::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsFieldName {
     fn default() -> RecordingRegistrantQuestionsFieldName {
         RecordingRegistrantQuestionsFieldName::Noop
@@ -8707,6 +9528,11 @@ impl RecordingRegistrantQuestionsFieldName {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingRegistrantQuestionsFieldName::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingRegistrantQuestionsFieldName::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8729,7 +9555,7 @@ pub struct RecordingRegistrantQuestions {
 /**
 * The type of registration question and answers.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8739,8 +9565,8 @@ pub enum RecordingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
@@ -8750,12 +9576,37 @@ impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
             RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingRegistrantQuestionsCustomType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingRegistrantQuestionsCustomType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "multiple" => RecordingRegistrantQuestionsCustomType::Multiple,
+            "short" => RecordingRegistrantQuestionsCustomType::Short,
+            "single" => RecordingRegistrantQuestionsCustomType::Single,
+            "" => RecordingRegistrantQuestionsCustomType::Noop,
+            _ => RecordingRegistrantQuestionsCustomType::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsCustomType {
     fn default() -> RecordingRegistrantQuestionsCustomType {
         RecordingRegistrantQuestionsCustomType::Noop
@@ -8765,6 +9616,11 @@ impl RecordingRegistrantQuestionsCustomType {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingRegistrantQuestionsCustomType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingRegistrantQuestionsCustomType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10247,7 +11103,7 @@ pub struct ParticipantQos {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "enforce_login")]
     EnforceLogin,
@@ -10257,8 +11113,8 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     Internally,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
@@ -10270,17 +11126,54 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionTyp
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
             AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "enforce_login" => AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin,
+            "enforce_login_with_domains" => {
+                AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains
+            }
+            "internally" => AccountSettingsRecordingAuthenticationUpdateOptionType::Internally,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionType::Noop,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionType {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionType {
         AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
     }
 }
+impl AccountSettingsRecordingAuthenticationUpdateOptionType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(_)
+        )
+    }
+}
+
 impl AccountSettingsRecordingAuthenticationUpdateOptionType {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -10290,7 +11183,7 @@ impl AccountSettingsRecordingAuthenticationUpdateOptionType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "add")]
     Add,
@@ -10300,8 +11193,8 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
@@ -10311,17 +11204,52 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAct
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "add" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Add,
+            "delete" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete,
+            "update" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Update,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionAction {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionAction {
         AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
     }
 }
+impl AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(_)
+        )
+    }
+}
+
 impl AccountSettingsRecordingAuthenticationUpdateOptionAction {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -10883,7 +11811,7 @@ pub struct Metrics {
 *   
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "CC")]
     Cc,
@@ -10899,8 +11827,8 @@ pub enum FileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -10913,12 +11841,40 @@ impl std::fmt::Display for FileType {
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CC" => FileType::Cc,
+            "CHAT" => FileType::Chat,
+            "CSV" => FileType::Csv,
+            "M4A" => FileType::M4A,
+            "MP4" => FileType::Mp4,
+            "TRANSCRIPT" => FileType::Transcript,
+            "" => FileType::Noop,
+            _ => FileType::Unknown(s),
+        })
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -10928,6 +11884,11 @@ impl FileType {
     pub fn is_noop(&self) -> bool {
         matches!(self, FileType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, FileType::Unknown(_))
+    }
 }
 
 /**
@@ -10946,7 +11907,7 @@ impl FileType {
 *   
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -10972,8 +11933,8 @@ pub enum RecordingType {
     SpeakerView,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingType {
@@ -10991,12 +11952,45 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
             RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active_speaker" => RecordingType::ActiveSpeaker,
+            "audio_only" => RecordingType::AudioOnly,
+            "audio_transcript" => RecordingType::AudioTranscript,
+            "chat_file" => RecordingType::ChatFile,
+            "gallery_view" => RecordingType::GalleryView,
+            "host_video" => RecordingType::HostVideo,
+            "shared_screen" => RecordingType::SharedScreen,
+            "shared_screen_with_gallery_view" => RecordingType::SharedScreenWithGalleryView,
+            "shared_screen_with_speaker_view" => RecordingType::SharedScreenWithSpeakerView,
+            "shared_screen_with_speaker_view(CC)" => RecordingType::SharedScreenWithSpeakerViewCc,
+            "speaker_view" => RecordingType::SpeakerView,
+            "" => RecordingType::Noop,
+            _ => RecordingType::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingType {
     fn default() -> RecordingType {
         RecordingType::Noop
@@ -11006,6 +12000,11 @@ impl RecordingType {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11264,14 +12263,14 @@ pub struct InviteLinks {
     pub attendees: Vec<InviteLinksAttendees>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "recover")]
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
@@ -11279,12 +12278,35 @@ impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
         match self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
             RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingStatusUpdateBodyRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingStatusUpdateBodyRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "recover" => RecordingStatusUpdateBodyRequestAction::Recover,
+            "" => RecordingStatusUpdateBodyRequestAction::Noop,
+            _ => RecordingStatusUpdateBodyRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingStatusUpdateBodyRequestAction {
     fn default() -> RecordingStatusUpdateBodyRequestAction {
         RecordingStatusUpdateBodyRequestAction::Noop
@@ -11294,6 +12316,11 @@ impl RecordingStatusUpdateBodyRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingStatusUpdateBodyRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingStatusUpdateBodyRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11401,7 +12428,7 @@ pub struct SetUpAccountRequest {
 *   `all`: Include both assigned and unassigned numbers in the response.<br>
 *   `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "all")]
     All,
@@ -11411,8 +12438,8 @@ pub enum ListAccountPhoneNumbersType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersType {
@@ -11422,12 +12449,37 @@ impl std::fmt::Display for ListAccountPhoneNumbersType {
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
             ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListAccountPhoneNumbersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListAccountPhoneNumbersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => ListAccountPhoneNumbersType::All,
+            "assigned" => ListAccountPhoneNumbersType::Assigned,
+            "unassigned" => ListAccountPhoneNumbersType::Unassigned,
+            "" => ListAccountPhoneNumbersType::Noop,
+            _ => ListAccountPhoneNumbersType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListAccountPhoneNumbersType {
     fn default() -> ListAccountPhoneNumbersType {
         ListAccountPhoneNumbersType::Noop
@@ -11437,6 +12489,11 @@ impl ListAccountPhoneNumbersType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListAccountPhoneNumbersType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListAccountPhoneNumbersType::Unknown(_))
+    }
 }
 
 /**
@@ -11444,7 +12501,7 @@ impl ListAccountPhoneNumbersType {
 *   `user`<br> `callQueue`<br> `autoReceptionist`<br>
 *   `commonAreaPhone`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -11456,8 +12513,8 @@ pub enum ExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExtensionType {
@@ -11468,12 +12525,38 @@ impl std::fmt::Display for ExtensionType {
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
             ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ExtensionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtensionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoReceptionist" => ExtensionType::AutoReceptionist,
+            "callQueue" => ExtensionType::CallQueue,
+            "commonAreaPhone" => ExtensionType::CommonAreaPhone,
+            "user" => ExtensionType::User,
+            "" => ExtensionType::Noop,
+            _ => ExtensionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ExtensionType {
     fn default() -> ExtensionType {
         ExtensionType::Noop
@@ -11483,12 +12566,17 @@ impl ExtensionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ExtensionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ExtensionType::Unknown(_))
+    }
 }
 
 /**
 * Source of phone number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Source {
     #[serde(rename = "external")]
     External,
@@ -11496,8 +12584,8 @@ pub enum Source {
     Internal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11506,12 +12594,36 @@ impl std::fmt::Display for Source {
             Source::External => "external",
             Source::Internal => "internal",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "external" => Source::External,
+            "internal" => Source::Internal,
+            "" => Source::Noop,
+            _ => Source::Unknown(s),
+        })
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -11521,12 +12633,17 @@ impl Source {
     pub fn is_noop(&self) -> bool {
         matches!(self, Source::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Source::Unknown(_))
+    }
 }
 
 /**
 * Status of the number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "available")]
     Available,
@@ -11534,8 +12651,8 @@ pub enum ListAccountPhoneNumbersResponseStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
@@ -11544,12 +12661,36 @@ impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
             ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListAccountPhoneNumbersResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListAccountPhoneNumbersResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "available" => ListAccountPhoneNumbersResponseStatus::Available,
+            "pending" => ListAccountPhoneNumbersResponseStatus::Pending,
+            "" => ListAccountPhoneNumbersResponseStatus::Noop,
+            _ => ListAccountPhoneNumbersResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListAccountPhoneNumbersResponseStatus {
     fn default() -> ListAccountPhoneNumbersResponseStatus {
         ListAccountPhoneNumbersResponseStatus::Noop
@@ -11559,6 +12700,11 @@ impl ListAccountPhoneNumbersResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListAccountPhoneNumbersResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListAccountPhoneNumbersResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11788,7 +12934,7 @@ pub struct ListAccountPhoneNumbersResponseData {
 /**
 * Protocols supported by the SIP provider.<br> The value must be either `UDP`, `TCP`, `TLS`, `AUTO`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TransportProtocol {
     #[serde(rename = "AUTO")]
     Auto,
@@ -11800,8 +12946,8 @@ pub enum TransportProtocol {
     Udp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransportProtocol {
@@ -11812,12 +12958,38 @@ impl std::fmt::Display for TransportProtocol {
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
             TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TransportProtocol {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TransportProtocol {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "AUTO" => TransportProtocol::Auto,
+            "TCP" => TransportProtocol::Tcp,
+            "TLS" => TransportProtocol::Tls,
+            "UDP" => TransportProtocol::Udp,
+            "" => TransportProtocol::Noop,
+            _ => TransportProtocol::Unknown(s),
+        })
+    }
+}
+
 impl Default for TransportProtocol {
     fn default() -> TransportProtocol {
         TransportProtocol::Noop
@@ -11827,6 +12999,11 @@ impl TransportProtocol {
     pub fn is_noop(&self) -> bool {
         matches!(self, TransportProtocol::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TransportProtocol::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12334,7 +13511,7 @@ pub struct UpdateSipPhoneRequest {
 /**
 * The status of the Zoom Room.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListZoomRoomsStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12346,8 +13523,8 @@ pub enum ListZoomRoomsStatus {
     UnderConstruction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsStatus {
@@ -12358,12 +13535,38 @@ impl std::fmt::Display for ListZoomRoomsStatus {
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
             ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListZoomRoomsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZoomRoomsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Available" => ListZoomRoomsStatus::Available,
+            "InMeeting" => ListZoomRoomsStatus::InMeeting,
+            "Offline" => ListZoomRoomsStatus::Offline,
+            "UnderConstruction" => ListZoomRoomsStatus::UnderConstruction,
+            "" => ListZoomRoomsStatus::Noop,
+            _ => ListZoomRoomsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListZoomRoomsStatus {
     fn default() -> ListZoomRoomsStatus {
         ListZoomRoomsStatus::Noop
@@ -12373,12 +13576,17 @@ impl ListZoomRoomsStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZoomRoomsStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListZoomRoomsStatus::Unknown(_))
+    }
 }
 
 /**
 * Type of the Zoom Rooms.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListZoomRoomsType {
     #[serde(rename = "DigitalSignageOnly")]
     DigitalSignageOnly,
@@ -12388,8 +13596,8 @@ pub enum ListZoomRoomsType {
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsType {
@@ -12399,12 +13607,37 @@ impl std::fmt::Display for ListZoomRoomsType {
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
             ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListZoomRoomsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZoomRoomsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "DigitalSignageOnly" => ListZoomRoomsType::DigitalSignageOnly,
+            "SchedulingDisplayOnly" => ListZoomRoomsType::SchedulingDisplayOnly,
+            "ZoomRoom" => ListZoomRoomsType::ZoomRoom,
+            "" => ListZoomRoomsType::Noop,
+            _ => ListZoomRoomsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListZoomRoomsType {
     fn default() -> ListZoomRoomsType {
         ListZoomRoomsType::Noop
@@ -12414,6 +13647,11 @@ impl ListZoomRoomsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZoomRoomsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListZoomRoomsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12947,7 +14185,7 @@ pub struct UpdateSiteDetailsRequest {
 * Presence status of the contact in Zoom Client. The value of this field can be one of the following:
 *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PresenceStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12959,8 +14197,8 @@ pub enum PresenceStatus {
     Offline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PresenceStatus {
@@ -12971,12 +14209,38 @@ impl std::fmt::Display for PresenceStatus {
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
             PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PresenceStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PresenceStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Available" => PresenceStatus::Available,
+            "Away" => PresenceStatus::Away,
+            "Do_Not_Disturb" => PresenceStatus::DoNotDisturb,
+            "Offline" => PresenceStatus::Offline,
+            "" => PresenceStatus::Noop,
+            _ => PresenceStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PresenceStatus {
     fn default() -> PresenceStatus {
         PresenceStatus::Noop
@@ -12986,6 +14250,11 @@ impl PresenceStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PresenceStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PresenceStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13151,7 +14420,7 @@ pub struct SearchCompanyContactsResponse {
 *   
 *   This field is only returned if you set the value of `for include_deleted_and_edited_message` query parameter to `true`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "Deleted")]
     Deleted,
@@ -13161,8 +14430,8 @@ pub enum GetChatMessagesResponseStatus {
     Normal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetChatMessagesResponseStatus {
@@ -13172,12 +14441,37 @@ impl std::fmt::Display for GetChatMessagesResponseStatus {
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
             GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetChatMessagesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetChatMessagesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Deleted" => GetChatMessagesResponseStatus::Deleted,
+            "Edited" => GetChatMessagesResponseStatus::Edited,
+            "Normal" => GetChatMessagesResponseStatus::Normal,
+            "" => GetChatMessagesResponseStatus::Noop,
+            _ => GetChatMessagesResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetChatMessagesResponseStatus {
     fn default() -> GetChatMessagesResponseStatus {
         GetChatMessagesResponseStatus::Noop
@@ -13187,6 +14481,11 @@ impl GetChatMessagesResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetChatMessagesResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetChatMessagesResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13467,7 +14766,7 @@ pub struct MarkMessageRequest {
 *   * `add` — Add an emoji reaction.
 *   * `remove` — Remove an emoji reaction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReactMessageRequestAction {
     #[serde(rename = "add")]
     Add,
@@ -13475,8 +14774,8 @@ pub enum ReactMessageRequestAction {
     Remove,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactMessageRequestAction {
@@ -13485,12 +14784,36 @@ impl std::fmt::Display for ReactMessageRequestAction {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
             ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReactMessageRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactMessageRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "add" => ReactMessageRequestAction::Add,
+            "remove" => ReactMessageRequestAction::Remove,
+            "" => ReactMessageRequestAction::Noop,
+            _ => ReactMessageRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReactMessageRequestAction {
     fn default() -> ReactMessageRequestAction {
         ReactMessageRequestAction::Noop
@@ -13500,6 +14823,11 @@ impl ReactMessageRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReactMessageRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReactMessageRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14021,7 +15349,7 @@ pub struct GetUserContactResponse {
 /**
 * The role of the member. The value can be one of these: `owner`, `admin`, `member`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -14031,8 +15359,8 @@ pub enum Role {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -14042,12 +15370,37 @@ impl std::fmt::Display for Role {
             Role::Member => "member",
             Role::Owner => "owner",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "admin" => Role::Admin,
+            "member" => Role::Member,
+            "owner" => Role::Owner,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        })
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -14057,6 +15410,11 @@ impl Role {
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Role::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14369,7 +15727,7 @@ pub struct AccountResponse {
 *   * `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
 *   * `meeting_security` — View the account's meeting security settings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum OptionData {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14377,8 +15735,8 @@ pub enum OptionData {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OptionData {
@@ -14387,12 +15745,36 @@ impl std::fmt::Display for OptionData {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
             OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for OptionData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "meeting_authentication" => OptionData::MeetingAuthentication,
+            "recording_authentication" => OptionData::RecordingAuthentication,
+            "" => OptionData::Noop,
+            _ => OptionData::Unknown(s),
+        })
+    }
+}
+
 impl Default for OptionData {
     fn default() -> OptionData {
         OptionData::Noop
@@ -14402,6 +15784,11 @@ impl OptionData {
     pub fn is_noop(&self) -> bool {
         matches!(self, OptionData::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, OptionData::Unknown(_))
+    }
 }
 
 /// All of the following types:
@@ -14494,7 +15881,7 @@ impl AccountSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14506,8 +15893,8 @@ pub enum AccountSettingsUpdateOption {
     Security,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsUpdateOption {
@@ -14518,12 +15905,38 @@ impl std::fmt::Display for AccountSettingsUpdateOption {
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
             AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountSettingsUpdateOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsUpdateOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "meeting_authentication" => AccountSettingsUpdateOption::MeetingAuthentication,
+            "meeting_security" => AccountSettingsUpdateOption::MeetingSecurity,
+            "recording_authentication" => AccountSettingsUpdateOption::RecordingAuthentication,
+            "security" => AccountSettingsUpdateOption::Security,
+            "" => AccountSettingsUpdateOption::Noop,
+            _ => AccountSettingsUpdateOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountSettingsUpdateOption {
     fn default() -> AccountSettingsUpdateOption {
         AccountSettingsUpdateOption::Noop
@@ -14533,6 +15946,11 @@ impl AccountSettingsUpdateOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountSettingsUpdateOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AccountSettingsUpdateOption::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14554,7 +15972,7 @@ pub struct AccountTrustedDomainResponse {
 /**
 * Status of the plan.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "active")]
     Active,
@@ -14564,8 +15982,8 @@ pub enum AccountPlansResponsePlanBaseStatus {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
@@ -14575,12 +15993,37 @@ impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
             AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountPlansResponsePlanBaseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountPlansResponsePlanBaseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => AccountPlansResponsePlanBaseStatus::Active,
+            "cancel" => AccountPlansResponsePlanBaseStatus::Cancel,
+            "expired" => AccountPlansResponsePlanBaseStatus::Expired,
+            "" => AccountPlansResponsePlanBaseStatus::Noop,
+            _ => AccountPlansResponsePlanBaseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountPlansResponsePlanBaseStatus {
     fn default() -> AccountPlansResponsePlanBaseStatus {
         AccountPlansResponsePlanBaseStatus::Noop
@@ -14590,6 +16033,11 @@ impl AccountPlansResponsePlanBaseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountPlansResponsePlanBaseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AccountPlansResponsePlanBaseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14688,7 +16136,7 @@ pub struct PlanWebinar {
 /**
 * Status of additional Cloud Recording plan.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PlanRecordingStatus {
     #[serde(rename = "Active")]
     Active,
@@ -14696,8 +16144,8 @@ pub enum PlanRecordingStatus {
     Cancelled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanRecordingStatus {
@@ -14706,12 +16154,36 @@ impl std::fmt::Display for PlanRecordingStatus {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
             PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PlanRecordingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlanRecordingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Active" => PlanRecordingStatus::Active,
+            "Cancelled" => PlanRecordingStatus::Cancelled,
+            "" => PlanRecordingStatus::Noop,
+            _ => PlanRecordingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PlanRecordingStatus {
     fn default() -> PlanRecordingStatus {
         PlanRecordingStatus::Noop
@@ -14721,6 +16193,11 @@ impl PlanRecordingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PlanRecordingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PlanRecordingStatus::Unknown(_))
+    }
 }
 
 /// Additional audio conferencing <a href="https://marketplace.zoom.us/docs/api-reference/other-references/plans#audio-conferencing-plans">plan type.</a>
@@ -15206,7 +16683,7 @@ pub struct GroupMembersCreateResponse {
 *   * `move` — Remove the group member from one group and move them to a different group.
 *   * `set_primary` — Set the user's primary group.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "move")]
     Move,
@@ -15214,8 +16691,8 @@ pub enum UpdateGroupMemberRequestAction {
     SetPrimary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupMemberRequestAction {
@@ -15224,12 +16701,36 @@ impl std::fmt::Display for UpdateGroupMemberRequestAction {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
             UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UpdateGroupMemberRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateGroupMemberRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "move" => UpdateGroupMemberRequestAction::Move,
+            "set_primary" => UpdateGroupMemberRequestAction::SetPrimary,
+            "" => UpdateGroupMemberRequestAction::Noop,
+            _ => UpdateGroupMemberRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for UpdateGroupMemberRequestAction {
     fn default() -> UpdateGroupMemberRequestAction {
         UpdateGroupMemberRequestAction::Noop
@@ -15239,6 +16740,11 @@ impl UpdateGroupMemberRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdateGroupMemberRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UpdateGroupMemberRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15299,7 +16805,7 @@ pub struct TrackingfieldGetResponseAllOf {
 /**
 * IM Group types:<br>`normal` - Only members can see automatically see the other members of this group. Other people can search for members within this group. <br>`shared` - Everyone under an account can see the group members automatically.<br>`restricted` - Nobody can see the group or search for members except the members in the group.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImGroupCreateRequestType {
     #[serde(rename = "normal")]
     Normal,
@@ -15307,8 +16813,8 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImGroupCreateRequestType {
@@ -15317,17 +16823,47 @@ impl std::fmt::Display for ImGroupCreateRequestType {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImGroupCreateRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImGroupCreateRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "normal" => ImGroupCreateRequestType::Normal,
+            "restricted" => ImGroupCreateRequestType::Restricted,
+            "shared" => ImGroupCreateRequestType::Shared,
+            _ => ImGroupCreateRequestType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ImGroupCreateRequestType {
     fn default() -> ImGroupCreateRequestType {
         ImGroupCreateRequestType::Normal
     }
 }
+impl ImGroupCreateRequestType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImGroupCreateRequestType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ImGroupCreateRequest {
@@ -15455,7 +16991,7 @@ pub struct DashboardImResponse {
 /**
 * IM chat session type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ImChatSessionsResponseType {
     #[serde(rename = "1:1")]
     One,
@@ -15463,8 +16999,8 @@ pub enum ImChatSessionsResponseType {
     Group,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImChatSessionsResponseType {
@@ -15473,12 +17009,36 @@ impl std::fmt::Display for ImChatSessionsResponseType {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
             ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ImChatSessionsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImChatSessionsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "1:1" => ImChatSessionsResponseType::One,
+            "Group" => ImChatSessionsResponseType::Group,
+            "" => ImChatSessionsResponseType::Noop,
+            _ => ImChatSessionsResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ImChatSessionsResponseType {
     fn default() -> ImChatSessionsResponseType {
         ImChatSessionsResponseType::Noop
@@ -15488,6 +17048,11 @@ impl ImChatSessionsResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ImChatSessionsResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ImChatSessionsResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15673,7 +17238,7 @@ pub struct ImChatMessagesResponseAllOf {
 /**
 * The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -15681,8 +17246,8 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingsType {
@@ -15691,17 +17256,47 @@ impl std::fmt::Display for MeetingsType {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "live" => MeetingsType::Live,
+            "scheduled" => MeetingsType::Scheduled,
+            "upcoming" => MeetingsType::Upcoming,
+            _ => MeetingsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MeetingsType {
     fn default() -> MeetingsType {
         MeetingsType::Live
     }
 }
+impl MeetingsType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MeetingsType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingCreateResponse {
@@ -15855,7 +17450,7 @@ pub struct MeetingUpdateRequestAllOf {
 *   `recover` - [Recover](https://support.zoom.us/hc/en-us/articles/360038297111-Recover-a-deleted-meeting) a deleted meeting.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MeetingStatusRequestAction {
     #[serde(rename = "end")]
     End,
@@ -15863,8 +17458,8 @@ pub enum MeetingStatusRequestAction {
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingStatusRequestAction {
@@ -15873,12 +17468,36 @@ impl std::fmt::Display for MeetingStatusRequestAction {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
             MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MeetingStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "end" => MeetingStatusRequestAction::End,
+            "recover" => MeetingStatusRequestAction::Recover,
+            "" => MeetingStatusRequestAction::Noop,
+            _ => MeetingStatusRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for MeetingStatusRequestAction {
     fn default() -> MeetingStatusRequestAction {
         MeetingStatusRequestAction::Noop
@@ -15888,6 +17507,11 @@ impl MeetingStatusRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingStatusRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MeetingStatusRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15904,7 +17528,7 @@ pub struct MeetingStatusRequest {
 /**
 * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MeetingRegistrantsStatus {
     #[serde(rename = "approved")]
     Approved,
@@ -15912,8 +17536,8 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantsStatus {
@@ -15922,17 +17546,47 @@ impl std::fmt::Display for MeetingRegistrantsStatus {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MeetingRegistrantsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingRegistrantsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "approved" => MeetingRegistrantsStatus::Approved,
+            "denied" => MeetingRegistrantsStatus::Denied,
+            "pending" => MeetingRegistrantsStatus::Pending,
+            _ => MeetingRegistrantsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for MeetingRegistrantsStatus {
     fn default() -> MeetingRegistrantsStatus {
         MeetingRegistrantsStatus::Approved
     }
 }
+impl MeetingRegistrantsStatus {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MeetingRegistrantsStatus::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingRegistrantCreateResponse {
@@ -16159,7 +17813,7 @@ pub struct PastMeetingParticipantsResponseAllOf {
 /**
 * Status of the Meeting Poll:<br>`notstart` - Poll not started<br>`started` - Poll started<br>`ended` - Poll ended<br>`sharing` - Sharing poll results
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "ended")]
     Ended,
@@ -16171,8 +17825,8 @@ pub enum MeetingPollCreateResponseStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingPollCreateResponseStatus {
@@ -16183,12 +17837,38 @@ impl std::fmt::Display for MeetingPollCreateResponseStatus {
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
             MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MeetingPollCreateResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingPollCreateResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "ended" => MeetingPollCreateResponseStatus::Ended,
+            "notstart" => MeetingPollCreateResponseStatus::Notstart,
+            "sharing" => MeetingPollCreateResponseStatus::Sharing,
+            "started" => MeetingPollCreateResponseStatus::Started,
+            "" => MeetingPollCreateResponseStatus::Noop,
+            _ => MeetingPollCreateResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for MeetingPollCreateResponseStatus {
     fn default() -> MeetingPollCreateResponseStatus {
         MeetingPollCreateResponseStatus::Noop
@@ -16198,6 +17878,11 @@ impl MeetingPollCreateResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingPollCreateResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MeetingPollCreateResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16272,14 +17957,14 @@ pub struct RecordingGetResponseAllOf {
 /**
 * The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "trash")]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingDeleteAction {
@@ -16287,17 +17972,46 @@ impl std::fmt::Display for RecordingDeleteAction {
         match self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingDeleteAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingDeleteAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "delete" => RecordingDeleteAction::Delete,
+            "trash" => RecordingDeleteAction::Trash,
+            _ => RecordingDeleteAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingDeleteAction {
     fn default() -> RecordingDeleteAction {
         RecordingDeleteAction::Trash
     }
 }
+impl RecordingDeleteAction {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingDeleteAction::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingRecordingRegistrantCreateResponse {
@@ -16344,7 +18058,7 @@ pub struct MeetingRecordingRegistrantCreateResponse {
 *   
 *   If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DashboardMeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -16352,8 +18066,8 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingsType {
@@ -16362,29 +18076,59 @@ impl std::fmt::Display for DashboardMeetingsType {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DashboardMeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "live" => DashboardMeetingsType::Live,
+            "past" => DashboardMeetingsType::Past,
+            "pastOne" => DashboardMeetingsType::PastOne,
+            _ => DashboardMeetingsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DashboardMeetingsType {
     fn default() -> DashboardMeetingsType {
         DashboardMeetingsType::Live
     }
 }
+impl DashboardMeetingsType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DashboardMeetingsType::Unknown(_))
+    }
+}
 
 /**
 * Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum IncludeFields {
     #[serde(rename = "tracking_fields")]
     TrackingFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IncludeFields {
@@ -16392,12 +18136,35 @@ impl std::fmt::Display for IncludeFields {
         match self {
             IncludeFields::TrackingFields => "tracking_fields",
             IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for IncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "tracking_fields" => IncludeFields::TrackingFields,
+            "" => IncludeFields::Noop,
+            _ => IncludeFields::Unknown(s),
+        })
+    }
+}
+
 impl Default for IncludeFields {
     fn default() -> IncludeFields {
         IncludeFields::Noop
@@ -16407,6 +18174,11 @@ impl IncludeFields {
     pub fn is_noop(&self) -> bool {
         matches!(self, IncludeFields::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, IncludeFields::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16444,14 +18216,14 @@ pub struct DashboardMeetingsResponseAllOf {
 /**
 * Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "registrant_id")]
     RegistrantId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
@@ -16459,12 +18231,35 @@ impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
         match self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
             DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DashboardMeetingParticipantsIncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsIncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "registrant_id" => DashboardMeetingParticipantsIncludeFields::RegistrantId,
+            "" => DashboardMeetingParticipantsIncludeFields::Noop,
+            _ => DashboardMeetingParticipantsIncludeFields::Unknown(s),
+        })
+    }
+}
+
 impl Default for DashboardMeetingParticipantsIncludeFields {
     fn default() -> DashboardMeetingParticipantsIncludeFields {
         DashboardMeetingParticipantsIncludeFields::Noop
@@ -16474,6 +18269,11 @@ impl DashboardMeetingParticipantsIncludeFields {
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsIncludeFields::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DashboardMeetingParticipantsIncludeFields::Unknown(_))
+    }
 }
 
 /**
@@ -16487,7 +18287,7 @@ impl DashboardMeetingParticipantsIncludeFields {
 *   
 *   **Note:** This response returns an empty string (`““`) value for any users who are **not** a part of the host’s account (external users).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "Android")]
     Android,
@@ -16503,8 +18303,8 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     IOs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
@@ -16517,12 +18317,40 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
             DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DashboardMeetingParticipantsResponseDevice {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsResponseDevice {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Android" => DashboardMeetingParticipantsResponseDevice::Android,
+            "H.323/SIP" => DashboardMeetingParticipantsResponseDevice::H323Sip,
+            "Mac" => DashboardMeetingParticipantsResponseDevice::Mac,
+            "Phone" => DashboardMeetingParticipantsResponseDevice::Phone,
+            "Windows" => DashboardMeetingParticipantsResponseDevice::Windows,
+            "iOS" => DashboardMeetingParticipantsResponseDevice::IOs,
+            "" => DashboardMeetingParticipantsResponseDevice::Noop,
+            _ => DashboardMeetingParticipantsResponseDevice::Unknown(s),
+        })
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseDevice {
     fn default() -> DashboardMeetingParticipantsResponseDevice {
         DashboardMeetingParticipantsResponseDevice::Noop
@@ -16532,6 +18360,11 @@ impl DashboardMeetingParticipantsResponseDevice {
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsResponseDevice::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DashboardMeetingParticipantsResponseDevice::Unknown(_))
+    }
 }
 
 /**
@@ -16543,7 +18376,7 @@ impl DashboardMeetingParticipantsResponseDevice {
 *   * `Cellular` — 3G, 4G, and 5G cellular.
 *   * `Others` — An unknown device.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum NetworkType {
     #[serde(rename = "Cellular")]
     Cellular,
@@ -16557,8 +18390,8 @@ pub enum NetworkType {
     Wired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkType {
@@ -16570,12 +18403,39 @@ impl std::fmt::Display for NetworkType {
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
             NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for NetworkType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Cellular" => NetworkType::Cellular,
+            "Others" => NetworkType::Others,
+            "PPP" => NetworkType::Ppp,
+            "Wifi" => NetworkType::Wifi,
+            "Wired" => NetworkType::Wired,
+            "" => NetworkType::Noop,
+            _ => NetworkType::Unknown(s),
+        })
+    }
+}
+
 impl Default for NetworkType {
     fn default() -> NetworkType {
         NetworkType::Noop
@@ -16585,12 +18445,17 @@ impl NetworkType {
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, NetworkType::Unknown(_))
+    }
 }
 
 /**
 * Audio quality of the participant.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AudioQuality {
     #[serde(rename = "bad")]
     Bad,
@@ -16602,8 +18467,8 @@ pub enum AudioQuality {
     Poor,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioQuality {
@@ -16614,12 +18479,38 @@ impl std::fmt::Display for AudioQuality {
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
             AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AudioQuality {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioQuality {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "bad" => AudioQuality::Bad,
+            "fair" => AudioQuality::Fair,
+            "good" => AudioQuality::Good,
+            "poor" => AudioQuality::Poor,
+            "" => AudioQuality::Noop,
+            _ => AudioQuality::Unknown(s),
+        })
+    }
+}
+
 impl Default for AudioQuality {
     fn default() -> AudioQuality {
         AudioQuality::Noop
@@ -16629,6 +18520,11 @@ impl AudioQuality {
     pub fn is_noop(&self) -> bool {
         matches!(self, AudioQuality::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AudioQuality::Unknown(_))
+    }
 }
 
 /**
@@ -16636,7 +18532,7 @@ impl AudioQuality {
 *   
 *   The value of this field can be `in_meeting` or `in_waiting_room`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "in_meeting")]
     InMeeting,
@@ -16644,8 +18540,8 @@ pub enum DashboardMeetingParticipantsResponseStatus {
     InWaitingRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
@@ -16654,12 +18550,36 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
             DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DashboardMeetingParticipantsResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "in_meeting" => DashboardMeetingParticipantsResponseStatus::InMeeting,
+            "in_waiting_room" => DashboardMeetingParticipantsResponseStatus::InWaitingRoom,
+            "" => DashboardMeetingParticipantsResponseStatus::Noop,
+            _ => DashboardMeetingParticipantsResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseStatus {
     fn default() -> DashboardMeetingParticipantsResponseStatus {
         DashboardMeetingParticipantsResponseStatus::Noop
@@ -16669,6 +18589,11 @@ impl DashboardMeetingParticipantsResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DashboardMeetingParticipantsResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17085,14 +19010,14 @@ pub struct DashboardMeetingParticipantShareResponseAllOf {
 /**
 * The webinar type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DashboardWebinarsType {
     #[serde(rename = "live")]
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardWebinarsType {
@@ -17100,17 +19025,46 @@ impl std::fmt::Display for DashboardWebinarsType {
         match self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DashboardWebinarsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardWebinarsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "live" => DashboardWebinarsType::Live,
+            "past" => DashboardWebinarsType::Past,
+            _ => DashboardWebinarsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DashboardWebinarsType {
     fn default() -> DashboardWebinarsType {
         DashboardWebinarsType::Live
     }
 }
+impl DashboardWebinarsType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DashboardWebinarsType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DashboardWebinarsResponse {
@@ -18079,7 +20033,7 @@ pub struct ReportDailyResponse {
 /**
 * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReportUsersType {
     #[serde(rename = "active")]
     Active,
@@ -18087,8 +20041,8 @@ pub enum ReportUsersType {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportUsersType {
@@ -18097,12 +20051,36 @@ impl std::fmt::Display for ReportUsersType {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
             ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ReportUsersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportUsersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => ReportUsersType::Active,
+            "inactive" => ReportUsersType::Inactive,
+            "" => ReportUsersType::Noop,
+            _ => ReportUsersType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ReportUsersType {
     fn default() -> ReportUsersType {
         ReportUsersType::Noop
@@ -18112,19 +20090,24 @@ impl ReportUsersType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ReportUsersType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReportUsersType::Unknown(_))
+    }
 }
 
 /**
 * The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ReportMeetingsType {
     #[serde(rename = "past")]
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportMeetingsType {
@@ -18132,198 +20115,49 @@ impl std::fmt::Display for ReportMeetingsType {
         match self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for ReportMeetingsType {
-    fn default() -> ReportMeetingsType {
-        ReportMeetingsType::Past
+impl Serialize for ReportMeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Meetings {
-    /**
-     * Custom keys and values assigned to the meeting.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_keys: Vec<CustomKeys>,
-    /**
-     * Account seats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub duration: i64,
-    /**
-     * Account subscription start date and time.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Account seats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Account seats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub participants_count: i64,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source: String,
-    /**
-     * Account subscription start date and time.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub topic: String,
-    /**
-     * Account seats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_minutes: i64,
-    /**
-     * Account seats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "type"
-    )]
-    pub type_: i64,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_email: String,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_name: String,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
+impl<'de> Deserialize<'de> for ReportMeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "past" => ReportMeetingsType::Past,
+            "pastOne" => ReportMeetingsType::PastOne,
+            _ => ReportMeetingsType::Unknown(s),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReportMeetingsResponse {
-    /**
-     * Start Date.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub from: Option<chrono::NaiveDate>,
-    /**
-     * Array of meeting objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub meetings: Vec<Meetings>,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_page_token: String,
-    /**
-     * Start Date.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub to: Option<chrono::NaiveDate>,
+impl Default for ReportMeetingsType {
+    fn default() -> ReportMeetingsType {
+        ReportMeetingsType::Past
+    }
 }
-
-/// All of the following types are flattened into one object:
-///
-/// - `Pagination`
-/// - `ReportMeetingsResponse`
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReportMeetingsResponseAllOf {
-    /**
-     * Pagination Object.
-     */
-    #[serde(flatten)]
-    pub pagination: Pagination,
-    #[serde(flatten)]
-    pub report_meetings_response: ReportMeetingsResponse,
+impl ReportMeetingsType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ReportMeetingsType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReportMeetingDetailsResponse {
+pub struct Meetings {
     /**
      * Custom keys and values assigned to the meeting.
      */
@@ -18333,15 +20167,193 @@ pub struct ReportMeetingDetailsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_keys: Vec<CustomKeys>,
-    /**
-     * User's first name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub dept: String,
+    /**
+     * Account seats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub duration: i64,
+    /**
+     * Account subscription start date and time.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Account seats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Account seats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub participants_count: i64,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    /**
+     * Account subscription start date and time.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub topic: String,
+    /**
+     * Account seats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_minutes: i64,
+    /**
+     * Account seats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "type"
+    )]
+    pub type_: i64,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_email: String,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_name: String,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReportMeetingsResponse {
+    /**
+     * Start Date.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub from: Option<chrono::NaiveDate>,
+    /**
+     * Array of meeting objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub meetings: Vec<Meetings>,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page_token: String,
+    /**
+     * Start Date.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub to: Option<chrono::NaiveDate>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Pagination`
+/// - `ReportMeetingsResponse`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReportMeetingsResponseAllOf {
+    /**
+     * Pagination Object.
+     */
+    #[serde(flatten)]
+    pub pagination: Pagination,
+    #[serde(flatten)]
+    pub report_meetings_response: ReportMeetingsResponse,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReportMeetingDetailsResponse {
+    /**
+     * Custom keys and values assigned to the meeting.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub custom_keys: Vec<CustomKeys>,
+    /**
+     * User's first name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dept: String,
     /**
      * Account seats.
      */
@@ -18899,14 +20911,14 @@ pub struct ReportWebinarQaResponse {
 /**
 * Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum QueryDateType {
     #[serde(rename = "end_time")]
     EndTime,
     #[serde(rename = "start_time")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QueryDateType {
@@ -18914,17 +20926,46 @@ impl std::fmt::Display for QueryDateType {
         match self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for QueryDateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryDateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "end_time" => QueryDateType::EndTime,
+            "start_time" => QueryDateType::StartTime,
+            _ => QueryDateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for QueryDateType {
     fn default() -> QueryDateType {
         QueryDateType::StartTime
     }
 }
+impl QueryDateType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, QueryDateType::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportCloudRecordingResponse {
@@ -18960,7 +21001,7 @@ pub struct ReportCloudRecordingResponseAllOf {
 *   Filter your response by a category type to see reports for a specific category.
 *   The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CategoryType {
     #[serde(rename = "account")]
     Account,
@@ -18988,8 +21029,8 @@ pub enum CategoryType {
     ZoomRooms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CategoryType {
@@ -19008,12 +21049,46 @@ impl std::fmt::Display for CategoryType {
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
             CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CategoryType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CategoryType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "account" => CategoryType::Account,
+            "all" => CategoryType::All,
+            "billing" => CategoryType::Billing,
+            "im" => CategoryType::Im,
+            "phone_contacts" => CategoryType::PhoneContacts,
+            "recording" => CategoryType::Recording,
+            "role" => CategoryType::Role,
+            "sub_account" => CategoryType::SubAccount,
+            "user" => CategoryType::User,
+            "user_settings" => CategoryType::UserSettings,
+            "webinar" => CategoryType::Webinar,
+            "zoom_rooms" => CategoryType::ZoomRooms,
+            "" => CategoryType::Noop,
+            _ => CategoryType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CategoryType {
     fn default() -> CategoryType {
         CategoryType::Noop
@@ -19023,6 +21098,11 @@ impl CategoryType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CategoryType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CategoryType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19358,7 +21438,7 @@ pub struct TspUpdateRequest {
 *   
 *   This value defaults to `active`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UsersStatus {
     #[serde(rename = "active")]
     Active,
@@ -19366,8 +21446,8 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersStatus {
@@ -19376,24 +21456,54 @@ impl std::fmt::Display for UsersStatus {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UsersStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active" => UsersStatus::Active,
+            "inactive" => UsersStatus::Inactive,
+            "pending" => UsersStatus::Pending,
+            _ => UsersStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for UsersStatus {
     fn default() -> UsersStatus {
         UsersStatus::Active
     }
 }
+impl UsersStatus {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UsersStatus::Unknown(_))
+    }
+}
 
 /**
 * Use this parameter to display one of the following attributes in the API call's response:
 *   * `custom_attributes` — Return the user's custom attributes.
 *   * `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UsersIncludeFields {
     #[serde(rename = "custom_attributes")]
     CustomAttributes,
@@ -19401,8 +21511,8 @@ pub enum UsersIncludeFields {
     HostKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersIncludeFields {
@@ -19411,12 +21521,36 @@ impl std::fmt::Display for UsersIncludeFields {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
             UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UsersIncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersIncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "custom_attributes" => UsersIncludeFields::CustomAttributes,
+            "host_key" => UsersIncludeFields::HostKey,
+            "" => UsersIncludeFields::Noop,
+            _ => UsersIncludeFields::Unknown(s),
+        })
+    }
+}
+
 impl Default for UsersIncludeFields {
     fn default() -> UsersIncludeFields {
         UsersIncludeFields::Noop
@@ -19426,6 +21560,11 @@ impl UsersIncludeFields {
     pub fn is_noop(&self) -> bool {
         matches!(self, UsersIncludeFields::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UsersIncludeFields::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19705,7 +21844,7 @@ pub struct UsersResponseData {
 /**
 * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserCreateRequestAction {
     #[serde(rename = "autoCreate")]
     AutoCreate,
@@ -19717,8 +21856,8 @@ pub enum UserCreateRequestAction {
     SsoCreate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserCreateRequestAction {
@@ -19729,12 +21868,38 @@ impl std::fmt::Display for UserCreateRequestAction {
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
             UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserCreateRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserCreateRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoCreate" => UserCreateRequestAction::AutoCreate,
+            "create" => UserCreateRequestAction::Create,
+            "custCreate" => UserCreateRequestAction::CustCreate,
+            "ssoCreate" => UserCreateRequestAction::SsoCreate,
+            "" => UserCreateRequestAction::Noop,
+            _ => UserCreateRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserCreateRequestAction {
     fn default() -> UserCreateRequestAction {
         UserCreateRequestAction::Noop
@@ -19744,6 +21909,11 @@ impl UserCreateRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserCreateRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserCreateRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19816,7 +21986,7 @@ pub struct UserCreateRequest {
 *   
 *   `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum LoginType {
     #[serde(rename = "0")]
     Zero,
@@ -19844,8 +22014,8 @@ pub enum LoginType {
     NinetyNine,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginType {
@@ -19864,12 +22034,46 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
             LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for LoginType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LoginType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "0" => LoginType::Zero,
+            "1" => LoginType::One,
+            "100" => LoginType::OneHundred,
+            "101" => LoginType::OneHundredAndOne,
+            "11" => LoginType::Eleven,
+            "21" => LoginType::TwentyOne,
+            "23" => LoginType::TwentyThree,
+            "24" => LoginType::TwentyFour,
+            "27" => LoginType::TwentySeven,
+            "97" => LoginType::NinetySeven,
+            "98" => LoginType::NinetyEight,
+            "99" => LoginType::NinetyNine,
+            "" => LoginType::Noop,
+            _ => LoginType::Unknown(s),
+        })
+    }
+}
+
 impl Default for LoginType {
     fn default() -> LoginType {
         LoginType::Noop
@@ -19879,6 +22083,11 @@ impl LoginType {
     pub fn is_noop(&self) -> bool {
         matches!(self, LoginType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, LoginType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20208,14 +22417,14 @@ pub struct UserResponseAllOf {
 /**
 * Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "disassociate")]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserDeleteAction {
@@ -20223,17 +22432,46 @@ impl std::fmt::Display for UserDeleteAction {
         match self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserDeleteAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserDeleteAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "delete" => UserDeleteAction::Delete,
+            "disassociate" => UserDeleteAction::Disassociate,
+            _ => UserDeleteAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserDeleteAction {
     fn default() -> UserDeleteAction {
         UserDeleteAction::Disassociate
     }
 }
+impl UserDeleteAction {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserDeleteAction::Unknown(_))
+    }
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserZakResponse {
@@ -20326,7 +22564,7 @@ impl UserSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -20336,8 +22574,8 @@ pub enum UserSettingsUpdateOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSettingsUpdateOption {
@@ -20347,12 +22585,37 @@ impl std::fmt::Display for UserSettingsUpdateOption {
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserSettingsUpdateOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSettingsUpdateOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "meeting_authentication" => UserSettingsUpdateOption::MeetingAuthentication,
+            "meeting_secuirty" => UserSettingsUpdateOption::MeetingSecuirty,
+            "recording_authentication" => UserSettingsUpdateOption::RecordingAuthentication,
+            "" => UserSettingsUpdateOption::Noop,
+            _ => UserSettingsUpdateOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserSettingsUpdateOption {
     fn default() -> UserSettingsUpdateOption {
         UserSettingsUpdateOption::Noop
@@ -20362,12 +22625,17 @@ impl UserSettingsUpdateOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSettingsUpdateOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserSettingsUpdateOption::Unknown(_))
+    }
 }
 
 /**
 * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserStatusRequestAction {
     #[serde(rename = "activate")]
     Activate,
@@ -20375,8 +22643,8 @@ pub enum UserStatusRequestAction {
     Deactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatusRequestAction {
@@ -20385,12 +22653,36 @@ impl std::fmt::Display for UserStatusRequestAction {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
             UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "activate" => UserStatusRequestAction::Activate,
+            "deactivate" => UserStatusRequestAction::Deactivate,
+            "" => UserStatusRequestAction::Noop,
+            _ => UserStatusRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserStatusRequestAction {
     fn default() -> UserStatusRequestAction {
         UserStatusRequestAction::Noop
@@ -20400,6 +22692,11 @@ impl UserStatusRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserStatusRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserStatusRequestAction::Unknown(_))
+    }
 }
 
 /// The action.
@@ -20626,7 +22923,7 @@ pub struct TspAccountData {
 /**
 * User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UserTokenType {
     #[serde(rename = "token")]
     Token,
@@ -20634,8 +22931,8 @@ pub enum UserTokenType {
     Zak,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserTokenType {
@@ -20644,12 +22941,36 @@ impl std::fmt::Display for UserTokenType {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
             UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UserTokenType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserTokenType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "token" => UserTokenType::Token,
+            "zak" => UserTokenType::Zak,
+            "" => UserTokenType::Noop,
+            _ => UserTokenType::Unknown(s),
+        })
+    }
+}
+
 impl Default for UserTokenType {
     fn default() -> UserTokenType {
         UserTokenType::Noop
@@ -20659,6 +22980,11 @@ impl UserTokenType {
     pub fn is_noop(&self) -> bool {
         matches!(self, UserTokenType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UserTokenType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20863,14 +23189,14 @@ pub struct ListWebinarParticipantsResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum WebinarStatusRequestAction {
     #[serde(rename = "end")]
     End,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebinarStatusRequestAction {
@@ -20878,12 +23204,35 @@ impl std::fmt::Display for WebinarStatusRequestAction {
         match self {
             WebinarStatusRequestAction::End => "end",
             WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for WebinarStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebinarStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "end" => WebinarStatusRequestAction::End,
+            "" => WebinarStatusRequestAction::Noop,
+            _ => WebinarStatusRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for WebinarStatusRequestAction {
     fn default() -> WebinarStatusRequestAction {
         WebinarStatusRequestAction::Noop
@@ -20893,6 +23242,11 @@ impl WebinarStatusRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, WebinarStatusRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, WebinarStatusRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22001,7 +24355,7 @@ pub struct PhoneUserSettingsResponse {
 *   * `zr`<br>
 *   * `interop`<br>
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "autReceptionist")]
     AutReceptionist,
@@ -22017,8 +24371,8 @@ pub enum ListSettingTemplatesResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListSettingTemplatesResponseType {
@@ -22031,12 +24385,40 @@ impl std::fmt::Display for ListSettingTemplatesResponseType {
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
             ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListSettingTemplatesResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListSettingTemplatesResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autReceptionist" => ListSettingTemplatesResponseType::AutReceptionist,
+            "commonArea" => ListSettingTemplatesResponseType::CommonArea,
+            "group" => ListSettingTemplatesResponseType::Group,
+            "interop" => ListSettingTemplatesResponseType::Interop,
+            "user" => ListSettingTemplatesResponseType::User,
+            "zr" => ListSettingTemplatesResponseType::Zr,
+            "" => ListSettingTemplatesResponseType::Noop,
+            _ => ListSettingTemplatesResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListSettingTemplatesResponseType {
     fn default() -> ListSettingTemplatesResponseType {
         ListSettingTemplatesResponseType::Noop
@@ -22046,6 +24428,11 @@ impl ListSettingTemplatesResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListSettingTemplatesResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListSettingTemplatesResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22131,7 +24518,7 @@ pub struct ListSettingTemplatesResponse {
 /**
 * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AddSettingTemplateRequestType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -22145,8 +24532,8 @@ pub enum AddSettingTemplateRequestType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddSettingTemplateRequestType {
@@ -22158,12 +24545,39 @@ impl std::fmt::Display for AddSettingTemplateRequestType {
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
             AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AddSettingTemplateRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AddSettingTemplateRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoReceptionist" => AddSettingTemplateRequestType::AutoReceptionist,
+            "commonarea" => AddSettingTemplateRequestType::Commonarea,
+            "group" => AddSettingTemplateRequestType::Group,
+            "interop" => AddSettingTemplateRequestType::Interop,
+            "user" => AddSettingTemplateRequestType::User,
+            "" => AddSettingTemplateRequestType::Noop,
+            _ => AddSettingTemplateRequestType::Unknown(s),
+        })
+    }
+}
+
 impl Default for AddSettingTemplateRequestType {
     fn default() -> AddSettingTemplateRequestType {
         AddSettingTemplateRequestType::Noop
@@ -22173,6 +24587,11 @@ impl AddSettingTemplateRequestType {
     pub fn is_noop(&self) -> bool {
         matches!(self, AddSettingTemplateRequestType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AddSettingTemplateRequestType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23169,7 +25588,7 @@ pub struct ListSipGroupsResponse {
 /**
 * The type of template being queried. Values: `user`, `group`, `auto receptionist` `common area`,`zr`, `interop`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetSettingTemplateResponseType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23185,8 +25604,8 @@ pub enum GetSettingTemplateResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSettingTemplateResponseType {
@@ -23199,12 +25618,40 @@ impl std::fmt::Display for GetSettingTemplateResponseType {
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
             GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetSettingTemplateResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetSettingTemplateResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoReceptionist" => GetSettingTemplateResponseType::AutoReceptionist,
+            "commonArea" => GetSettingTemplateResponseType::CommonArea,
+            "group" => GetSettingTemplateResponseType::Group,
+            "interop" => GetSettingTemplateResponseType::Interop,
+            "user" => GetSettingTemplateResponseType::User,
+            "zr" => GetSettingTemplateResponseType::Zr,
+            "" => GetSettingTemplateResponseType::Noop,
+            _ => GetSettingTemplateResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetSettingTemplateResponseType {
     fn default() -> GetSettingTemplateResponseType {
         GetSettingTemplateResponseType::Noop
@@ -23214,6 +25661,11 @@ impl GetSettingTemplateResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetSettingTemplateResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetSettingTemplateResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23399,7 +25851,7 @@ pub struct CustomHours {
 *   5-zoomRoom, <br>
 *   7-sharedLineGroup
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23415,8 +25867,8 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display
@@ -23431,12 +25883,44 @@ GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorTy
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s.as_str(),
 }
 .fmt(f)
     }
 }
 
+impl Serialize
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de>
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoReceptionist" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist,
+            "callQueue" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue,
+            "commonAreaPhone" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone,
+            "sharedLineGroup" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup,
+            "user" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User,
+            "zoomRoom" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom,
+            "" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop,
+            _ => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s),
+        })
+    }
+}
+
 impl Default
     for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
 {
@@ -23449,6 +25933,11 @@ impl GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOpera
     pub fn is_noop(&self) -> bool {
         matches!(self, GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(_))
+    }
 }
 
 /// Allow callers to press Zero to reach an operator or press One to leave a message, or allow neither of these options.
@@ -23577,7 +26066,7 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
 *   
 *   * `disable`: This means that the hold music is disabled.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum HoldMusic {
     #[serde(rename = "default")]
     Default,
@@ -23585,8 +26074,8 @@ pub enum HoldMusic {
     Disable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HoldMusic {
@@ -23595,12 +26084,36 @@ impl std::fmt::Display for HoldMusic {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
             HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for HoldMusic {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HoldMusic {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "default" => HoldMusic::Default,
+            "disable" => HoldMusic::Disable,
+            "" => HoldMusic::Noop,
+            _ => HoldMusic::Unknown(s),
+        })
+    }
+}
+
 impl Default for HoldMusic {
     fn default() -> HoldMusic {
         HoldMusic::Noop
@@ -23610,6 +26123,11 @@ impl HoldMusic {
     pub fn is_noop(&self) -> bool {
         matches!(self, HoldMusic::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HoldMusic::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23691,7 +26209,7 @@ pub struct GetSettingTemplateResponse {
 /**
 * Values: inbound, outbound, both.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum RecordingCalls {
     #[serde(rename = "both")]
     Both,
@@ -23701,8 +26219,8 @@ pub enum RecordingCalls {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingCalls {
@@ -23712,12 +26230,37 @@ impl std::fmt::Display for RecordingCalls {
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
             RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for RecordingCalls {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingCalls {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "both" => RecordingCalls::Both,
+            "inbound" => RecordingCalls::Inbound,
+            "outbound" => RecordingCalls::Outbound,
+            "" => RecordingCalls::Noop,
+            _ => RecordingCalls::Unknown(s),
+        })
+    }
+}
+
 impl Default for RecordingCalls {
     fn default() -> RecordingCalls {
         RecordingCalls::Noop
@@ -23727,6 +26270,11 @@ impl RecordingCalls {
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingCalls::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RecordingCalls::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23802,7 +26350,7 @@ pub struct UpdateSettingTemplateRequest {
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
     All,
@@ -23810,8 +26358,8 @@ pub enum PhoneUserCallLogsType {
     Missed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsType {
@@ -23820,12 +26368,36 @@ impl std::fmt::Display for PhoneUserCallLogsType {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
             PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PhoneUserCallLogsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserCallLogsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => PhoneUserCallLogsType::All,
+            "missed" => PhoneUserCallLogsType::Missed,
+            "" => PhoneUserCallLogsType::Noop,
+            _ => PhoneUserCallLogsType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PhoneUserCallLogsType {
     fn default() -> PhoneUserCallLogsType {
         PhoneUserCallLogsType::Noop
@@ -23835,19 +26407,24 @@ impl PhoneUserCallLogsType {
     pub fn is_noop(&self) -> bool {
         matches!(self, PhoneUserCallLogsType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PhoneUserCallLogsType::Unknown(_))
+    }
 }
 
 /**
 * Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum TimeType {
     #[serde(rename = "endTime")]
     EndTime,
     #[serde(rename = "startTime")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TimeType {
@@ -23855,17 +26432,46 @@ impl std::fmt::Display for TimeType {
         match self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for TimeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "endTime" => TimeType::EndTime,
+            "startTime" => TimeType::StartTime,
+            _ => TimeType::Unknown(s),
+        })
+    }
+}
+
 impl Default for TimeType {
     fn default() -> TimeType {
         TimeType::StartTime
     }
 }
+impl TimeType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, TimeType::Unknown(_))
+    }
+}
 
 /// Indicates who the call was forwarded to.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23917,7 +26523,7 @@ pub struct ForwardedTo {
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23931,8 +26537,8 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
@@ -23946,17 +26552,56 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
             PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "autoReceptionist" => {
+                PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist
+            }
+            "callQueue" => PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue,
+            "commonAreaPhone" => PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone,
+            "sharedLineGroup" => PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup,
+            "user" => PhoneUserCallLogsResponseForwardedByExtensionType::User,
+            "" => PhoneUserCallLogsResponseForwardedByExtensionType::Noop,
+            _ => PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for PhoneUserCallLogsResponseForwardedByExtensionType {
     fn default() -> PhoneUserCallLogsResponseForwardedByExtensionType {
         PhoneUserCallLogsResponseForwardedByExtensionType::Noop
     }
 }
+impl PhoneUserCallLogsResponseForwardedByExtensionType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(_)
+        )
+    }
+}
+
 impl PhoneUserCallLogsResponseForwardedByExtensionType {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -24605,7 +27250,7 @@ pub struct PhoneUserRecordingsResponse {
 /**
 * Status of the voice mail
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PhoneUserVoiceMailsStatus {
     #[serde(rename = "all")]
     All,
@@ -24613,8 +27258,8 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
@@ -24623,22 +27268,52 @@ impl std::fmt::Display for PhoneUserVoiceMailsStatus {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PhoneUserVoiceMailsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserVoiceMailsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "all" => PhoneUserVoiceMailsStatus::All,
+            "read" => PhoneUserVoiceMailsStatus::Read,
+            "unread" => PhoneUserVoiceMailsStatus::Unread,
+            _ => PhoneUserVoiceMailsStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PhoneUserVoiceMailsStatus {
     fn default() -> PhoneUserVoiceMailsStatus {
         PhoneUserVoiceMailsStatus::All
     }
 }
+impl PhoneUserVoiceMailsStatus {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PhoneUserVoiceMailsStatus::Unknown(_))
+    }
+}
 
 /**
 * Status of the voice mail. Can be either 'read' or 'unread'
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "read")]
     Read,
@@ -24646,8 +27321,8 @@ pub enum PhoneUserVoiceMailsResponseStatus {
     Unread,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
@@ -24656,12 +27331,36 @@ impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
             PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for PhoneUserVoiceMailsResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserVoiceMailsResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "read" => PhoneUserVoiceMailsResponseStatus::Read,
+            "unread" => PhoneUserVoiceMailsResponseStatus::Unread,
+            "" => PhoneUserVoiceMailsResponseStatus::Noop,
+            _ => PhoneUserVoiceMailsResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for PhoneUserVoiceMailsResponseStatus {
     fn default() -> PhoneUserVoiceMailsResponseStatus {
         PhoneUserVoiceMailsResponseStatus::Noop
@@ -24671,6 +27370,11 @@ impl PhoneUserVoiceMailsResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, PhoneUserVoiceMailsResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, PhoneUserVoiceMailsResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24979,7 +27683,7 @@ pub struct UpdateUserSettingRequest {
 *   *`international`
 *   *`contactCenter`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum CallType {
     #[serde(rename = "contactCenter")]
     ContactCenter,
@@ -24993,8 +27697,8 @@ pub enum CallType {
     Voip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CallType {
@@ -25006,12 +27710,39 @@ impl std::fmt::Display for CallType {
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
             CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for CallType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CallType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "contactCenter" => CallType::ContactCenter,
+            "international" => CallType::International,
+            "pstn" => CallType::Pstn,
+            "tollfree" => CallType::Tollfree,
+            "voip" => CallType::Voip,
+            "" => CallType::Noop,
+            _ => CallType::Unknown(s),
+        })
+    }
+}
+
 impl Default for CallType {
     fn default() -> CallType {
         CallType::Noop
@@ -25021,6 +27752,11 @@ impl CallType {
     pub fn is_noop(&self) -> bool {
         matches!(self, CallType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, CallType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25380,7 +28116,7 @@ pub struct AssignCallingPlanRequest {
 /**
 * The owner type: `user` or `call queue`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "call queue")]
     CallQueue,
@@ -25388,8 +28124,8 @@ pub enum GetPhoneRecordingsResponseOwnerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
@@ -25398,12 +28134,36 @@ impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
             GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetPhoneRecordingsResponseOwnerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetPhoneRecordingsResponseOwnerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "call queue" => GetPhoneRecordingsResponseOwnerType::CallQueue,
+            "user" => GetPhoneRecordingsResponseOwnerType::User,
+            "" => GetPhoneRecordingsResponseOwnerType::Noop,
+            _ => GetPhoneRecordingsResponseOwnerType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetPhoneRecordingsResponseOwnerType {
     fn default() -> GetPhoneRecordingsResponseOwnerType {
         GetPhoneRecordingsResponseOwnerType::Noop
@@ -25413,6 +28173,11 @@ impl GetPhoneRecordingsResponseOwnerType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetPhoneRecordingsResponseOwnerType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetPhoneRecordingsResponseOwnerType::Unknown(_))
+    }
 }
 
 /// The owner of the recording.
@@ -25455,7 +28220,7 @@ pub struct GetPhoneRecordingsResponseOwner {
 /**
 * The direction of the call. Values: `inbound` or `outbound`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Direction {
     #[serde(rename = "inbound")]
     Inbound,
@@ -25463,8 +28228,8 @@ pub enum Direction {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -25473,12 +28238,36 @@ impl std::fmt::Display for Direction {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "inbound" => Direction::Inbound,
+            "outbound" => Direction::Outbound,
+            "" => Direction::Noop,
+            _ => Direction::Unknown(s),
+        })
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -25488,6 +28277,11 @@ impl Direction {
     pub fn is_noop(&self) -> bool {
         matches!(self, Direction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Direction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25849,14 +28643,14 @@ pub struct AccountPlanBaseDeleteRequest {
 /**
 * The action that needs to be taken for this sub account. Value must be set to "cancel".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
@@ -25864,12 +28658,35 @@ impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
         match self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
             AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for AccountPlanAddonCancelRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountPlanAddonCancelRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cancel" => AccountPlanAddonCancelRequestAction::Cancel,
+            "" => AccountPlanAddonCancelRequestAction::Noop,
+            _ => AccountPlanAddonCancelRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for AccountPlanAddonCancelRequestAction {
     fn default() -> AccountPlanAddonCancelRequestAction {
         AccountPlanAddonCancelRequestAction::Noop
@@ -25879,6 +28696,11 @@ impl AccountPlanAddonCancelRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountPlanAddonCancelRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AccountPlanAddonCancelRequestAction::Unknown(_))
+    }
 }
 
 /// Account plan object
@@ -27352,7 +30174,7 @@ impl UpdateGroupSettingsRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UpdateGroupSettingsOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -27362,8 +30184,8 @@ pub enum UpdateGroupSettingsOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupSettingsOption {
@@ -27373,12 +30195,37 @@ impl std::fmt::Display for UpdateGroupSettingsOption {
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
             UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UpdateGroupSettingsOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateGroupSettingsOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "meeting_authentication" => UpdateGroupSettingsOption::MeetingAuthentication,
+            "meeting_security" => UpdateGroupSettingsOption::MeetingSecurity,
+            "recording_authentication" => UpdateGroupSettingsOption::RecordingAuthentication,
+            "" => UpdateGroupSettingsOption::Noop,
+            _ => UpdateGroupSettingsOption::Unknown(s),
+        })
+    }
+}
+
 impl Default for UpdateGroupSettingsOption {
     fn default() -> UpdateGroupSettingsOption {
         UpdateGroupSettingsOption::Noop
@@ -27388,6 +30235,11 @@ impl UpdateGroupSettingsOption {
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdateGroupSettingsOption::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UpdateGroupSettingsOption::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28519,7 +31371,7 @@ pub struct SwitchUserAccountRequest {
 *   A recording file object with file type of either `CC` or `TB` **does not have** the following properties:<br>
 *   	`id`, `status`, `file_size`, `recording_type`, and `play_url`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "CC")]
     Cc,
@@ -28537,8 +31389,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
@@ -28552,17 +31404,56 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "CC" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc,
+            "CHAT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat,
+            "CSV" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv,
+            "M4A" => GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A,
+            "MP4" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4,
+            "TB" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb,
+            "TRANSCRIPT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesFileType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesFileType {
         GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
     }
 }
+impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(_)
+        )
+    }
+}
+
 impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -28575,7 +31466,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
 /**
 * The status of the recording, either `completed` or `processing`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -28583,8 +31474,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
@@ -28593,17 +31484,51 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "completed" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed,
+            "processing" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesStatus {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesStatus {
         GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
     }
 }
+impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(_)
+        )
+    }
+}
+
 impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -28631,7 +31556,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
 *   * `thumbnail`
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -28663,8 +31588,8 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     Timeline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
@@ -28693,17 +31618,71 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
             GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "active_speaker" => GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker,
+            "audio_only" => GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly,
+            "audio_transcript" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript
+            }
+            "chat_file" => GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile,
+            "closed_caption" => GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption,
+            "gallery_view" => GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView,
+            "host_video" => GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo,
+            "poll" => GetAccountCloudRecordingResponseMeetingsFilesType::Poll,
+            "shared_screen" => GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen,
+            "shared_screen_with_gallery_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView
+            }
+            "shared_screen_with_speaker_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView
+            }
+            "shared_screen_with_speaker_view(CC)" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc
+            }
+            "thumbnail" => GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail,
+            "timeline" => GetAccountCloudRecordingResponseMeetingsFilesType::Timeline,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesType {
         GetAccountCloudRecordingResponseMeetingsFilesType::Noop
     }
 }
+impl GetAccountCloudRecordingResponseMeetingsFilesType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(_)
+        )
+    }
+}
+
 impl GetAccountCloudRecordingResponseMeetingsFilesType {
     pub fn is_noop(&self) -> bool {
         matches!(
@@ -29084,14 +32063,14 @@ pub struct UpdateZrAccProfileRequest {
 *   `meeting`: Meeting settings of the Zoom Rooms Account. <br>
 *   `signage`: View digital signage settings of the Zoom Rooms Account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum SettingType {
     #[serde(rename = "alert")]
     Alert,
     #[serde(rename = "meeting")]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SettingType {
@@ -29099,23 +32078,52 @@ impl std::fmt::Display for SettingType {
         match self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for SettingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SettingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "alert" => SettingType::Alert,
+            "meeting" => SettingType::Meeting,
+            _ => SettingType::Unknown(s),
+        })
+    }
+}
+
 impl Default for SettingType {
     fn default() -> SettingType {
         SettingType::Meeting
     }
 }
+impl SettingType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SettingType::Unknown(_))
+    }
+}
 
 /**
 * The type of location. The value can be one of the following:
 *   `country`, `states`, `city`, `campus`, `building`, `floor`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListZrLocationsResponseType {
     #[serde(rename = "building")]
     Building,
@@ -29131,8 +32139,8 @@ pub enum ListZrLocationsResponseType {
     States,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrLocationsResponseType {
@@ -29145,12 +32153,40 @@ impl std::fmt::Display for ListZrLocationsResponseType {
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
             ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListZrLocationsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZrLocationsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "building" => ListZrLocationsResponseType::Building,
+            "campus" => ListZrLocationsResponseType::Campus,
+            "city" => ListZrLocationsResponseType::City,
+            "country" => ListZrLocationsResponseType::Country,
+            "floor" => ListZrLocationsResponseType::Floor,
+            "states" => ListZrLocationsResponseType::States,
+            "" => ListZrLocationsResponseType::Noop,
+            _ => ListZrLocationsResponseType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListZrLocationsResponseType {
     fn default() -> ListZrLocationsResponseType {
         ListZrLocationsResponseType::Noop
@@ -29160,6 +32196,11 @@ impl ListZrLocationsResponseType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZrLocationsResponseType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListZrLocationsResponseType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29483,7 +32524,7 @@ pub struct UpdateRoomProfileRequest {
 /**
 * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum DeviceType {
     #[serde(rename = "Companion Whiteboard")]
     CompanionWhiteboard,
@@ -29497,8 +32538,8 @@ pub enum DeviceType {
     ZoomRoomsControlSystem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -29510,12 +32551,39 @@ impl std::fmt::Display for DeviceType {
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for DeviceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Companion Whiteboard" => DeviceType::CompanionWhiteboard,
+            "Controller" => DeviceType::Controller,
+            "Scheduling Display" => DeviceType::SchedulingDisplay,
+            "Zoom Rooms Computer" => DeviceType::ZoomRoomsComputer,
+            "Zoom Rooms Control System" => DeviceType::ZoomRoomsControlSystem,
+            "" => DeviceType::Noop,
+            _ => DeviceType::Unknown(s),
+        })
+    }
+}
+
 impl Default for DeviceType {
     fn default() -> DeviceType {
         DeviceType::Noop
@@ -29525,12 +32593,17 @@ impl DeviceType {
     pub fn is_noop(&self) -> bool {
         matches!(self, DeviceType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DeviceType::Unknown(_))
+    }
 }
 
 /**
 * Status of the device. The value can be either `Online` or `Offline`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "Offline")]
     Offline,
@@ -29538,8 +32611,8 @@ pub enum ListZrDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrDevicesResponseStatus {
@@ -29548,12 +32621,36 @@ impl std::fmt::Display for ListZrDevicesResponseStatus {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
             ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListZrDevicesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZrDevicesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Offline" => ListZrDevicesResponseStatus::Offline,
+            "Online" => ListZrDevicesResponseStatus::Online,
+            "" => ListZrDevicesResponseStatus::Noop,
+            _ => ListZrDevicesResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListZrDevicesResponseStatus {
     fn default() -> ListZrDevicesResponseStatus {
         ListZrDevicesResponseStatus::Noop
@@ -29563,6 +32660,11 @@ impl ListZrDevicesResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZrDevicesResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListZrDevicesResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29834,7 +32936,7 @@ pub struct ListMeetingSatisfactionResponse {
 /**
 * State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListPhoneDevicesType {
     #[serde(rename = "assigned")]
     Assigned,
@@ -29842,8 +32944,8 @@ pub enum ListPhoneDevicesType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesType {
@@ -29852,12 +32954,36 @@ impl std::fmt::Display for ListPhoneDevicesType {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
             ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListPhoneDevicesType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListPhoneDevicesType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "assigned" => ListPhoneDevicesType::Assigned,
+            "unassigned" => ListPhoneDevicesType::Unassigned,
+            "" => ListPhoneDevicesType::Noop,
+            _ => ListPhoneDevicesType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListPhoneDevicesType {
     fn default() -> ListPhoneDevicesType {
         ListPhoneDevicesType::Noop
@@ -29867,6 +32993,11 @@ impl ListPhoneDevicesType {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListPhoneDevicesType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListPhoneDevicesType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29903,7 +33034,7 @@ pub struct AddAutoReceptionistResponse {
 /**
 * Status of the device. The value is either `online` or `offline`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "offline")]
     Offline,
@@ -29911,8 +33042,8 @@ pub enum ListPhoneDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesResponseStatus {
@@ -29921,12 +33052,36 @@ impl std::fmt::Display for ListPhoneDevicesResponseStatus {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
             ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListPhoneDevicesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListPhoneDevicesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "offline" => ListPhoneDevicesResponseStatus::Offline,
+            "online" => ListPhoneDevicesResponseStatus::Online,
+            "" => ListPhoneDevicesResponseStatus::Noop,
+            _ => ListPhoneDevicesResponseStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListPhoneDevicesResponseStatus {
     fn default() -> ListPhoneDevicesResponseStatus {
         ListPhoneDevicesResponseStatus::Noop
@@ -29936,6 +33091,11 @@ impl ListPhoneDevicesResponseStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, ListPhoneDevicesResponseStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListPhoneDevicesResponseStatus::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30115,7 +33275,7 @@ pub struct GetDeviceResponseAssignee {
 *   
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "assisted")]
     Assisted,
@@ -30125,8 +33285,8 @@ pub enum GetDeviceResponseProvisionType {
     Ztp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetDeviceResponseProvisionType {
@@ -30136,12 +33296,37 @@ impl std::fmt::Display for GetDeviceResponseProvisionType {
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
             GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetDeviceResponseProvisionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetDeviceResponseProvisionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "assisted" => GetDeviceResponseProvisionType::Assisted,
+            "manual" => GetDeviceResponseProvisionType::Manual,
+            "ztp" => GetDeviceResponseProvisionType::Ztp,
+            "" => GetDeviceResponseProvisionType::Noop,
+            _ => GetDeviceResponseProvisionType::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetDeviceResponseProvisionType {
     fn default() -> GetDeviceResponseProvisionType {
         GetDeviceResponseProvisionType::Noop
@@ -30151,6 +33336,11 @@ impl GetDeviceResponseProvisionType {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetDeviceResponseProvisionType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GetDeviceResponseProvisionType::Unknown(_))
+    }
 }
 
 /// Line subscription.
@@ -30883,7 +34073,7 @@ pub struct CreateCallQueueResponse {
 *   `manager`: A call queue manager has the privilege to change call queue settings, policy settings and manage recordings and voicemail inbox. There can only be one manager for each call queue.<br><br>
 *   `user`: Regular user without the privileges of a manager.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Level {
     #[serde(rename = "manager")]
     Manager,
@@ -30891,8 +34081,8 @@ pub enum Level {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Level {
@@ -30901,12 +34091,36 @@ impl std::fmt::Display for Level {
             Level::Manager => "manager",
             Level::User => "user",
             Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Level {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Level {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "manager" => Level::Manager,
+            "user" => Level::User,
+            "" => Level::Noop,
+            _ => Level::Unknown(s),
+        })
+    }
+}
+
 impl Default for Level {
     fn default() -> Level {
         Level::Noop
@@ -30916,6 +34130,11 @@ impl Level {
     pub fn is_noop(&self) -> bool {
         matches!(self, Level::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Level::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31850,7 +35069,7 @@ pub struct InternationalCall {
 /**
 * Allow Hot Desking feature to current device.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "off")]
     Off,
@@ -31860,8 +35079,8 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     Unsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
@@ -31871,12 +35090,37 @@ impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "off" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off,
+            "on" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On,
+            "unsupported" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported,
+            "" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop,
+            _ => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s),
+        })
+    }
+}
+
 impl Default for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     fn default() -> GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
         GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop
@@ -31886,6 +35130,14 @@ impl GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(
+            self,
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(_)
+        )
+    }
 }
 
 ///
@@ -32157,7 +35409,7 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
 *   `phoneNumber`: Indicates that only a specific phone number that is shown in the `phone_number` field is blocked.<br><br>
 *   `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum MatchType {
     #[serde(rename = "phoneNumber")]
     PhoneNumber,
@@ -32165,8 +35417,8 @@ pub enum MatchType {
     Prefix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -32175,12 +35427,36 @@ impl std::fmt::Display for MatchType {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for MatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "phoneNumber" => MatchType::PhoneNumber,
+            "prefix" => MatchType::Prefix,
+            "" => MatchType::Noop,
+            _ => MatchType::Unknown(s),
+        })
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -32190,6 +35466,11 @@ impl MatchType {
     pub fn is_noop(&self) -> bool {
         matches!(self, MatchType::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MatchType::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33360,7 +36641,7 @@ pub struct AddCalloutCountriesResponse {
 /**
 * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Languages {
     #[serde(rename = "de-DE")]
     De,
@@ -33370,8 +36651,8 @@ pub enum Languages {
     EnUs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Languages {
@@ -33381,12 +36662,37 @@ impl std::fmt::Display for Languages {
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
             Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Languages {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Languages {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "de-DE" => Languages::De,
+            "en-GB" => Languages::EnGb,
+            "en-US" => Languages::EnUs,
+            "" => Languages::Noop,
+            _ => Languages::Unknown(s),
+        })
+    }
+}
+
 impl Default for Languages {
     fn default() -> Languages {
         Languages::Noop
@@ -33396,6 +36702,11 @@ impl Languages {
     pub fn is_noop(&self) -> bool {
         matches!(self, Languages::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Languages::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33629,7 +36940,7 @@ pub struct AddInternalNumbersResponseData {
 *   `downgrade`: Downgrade the Zoom Rooms App Version.<br>
 *   `cancel`: Cancel an ongoing upgrade or downgrade process.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -33639,8 +36950,8 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     Upgrade,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
@@ -33650,12 +36961,37 @@ impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
             ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ChangeZoomRoomsAppVersionRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChangeZoomRoomsAppVersionRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "cancel" => ChangeZoomRoomsAppVersionRequestAction::Cancel,
+            "downgrade" => ChangeZoomRoomsAppVersionRequestAction::Downgrade,
+            "upgrade" => ChangeZoomRoomsAppVersionRequestAction::Upgrade,
+            "" => ChangeZoomRoomsAppVersionRequestAction::Noop,
+            _ => ChangeZoomRoomsAppVersionRequestAction::Unknown(s),
+        })
+    }
+}
+
 impl Default for ChangeZoomRoomsAppVersionRequestAction {
     fn default() -> ChangeZoomRoomsAppVersionRequestAction {
         ChangeZoomRoomsAppVersionRequestAction::Noop
@@ -33665,6 +37001,11 @@ impl ChangeZoomRoomsAppVersionRequestAction {
     pub fn is_noop(&self) -> bool {
         matches!(self, ChangeZoomRoomsAppVersionRequestAction::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ChangeZoomRoomsAppVersionRequestAction::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33747,7 +37088,7 @@ pub struct ListWebinarTemplatesResponse {
 *   * `Available` to `Away`
 *   * `Away` to `Available`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "Available")]
     Available,
@@ -33765,8 +37106,8 @@ pub enum UpdatePresenceStatusRequest {
     Presenting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdatePresenceStatusRequest {
@@ -33780,12 +37121,41 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
             UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for UpdatePresenceStatusRequest {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdatePresenceStatusRequest {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "Available" => UpdatePresenceStatusRequest::Available,
+            "Away" => UpdatePresenceStatusRequest::Away,
+            "Do_No_Disturb" => UpdatePresenceStatusRequest::DoNoDisturb,
+            "In_A_Zoom_Meeting" => UpdatePresenceStatusRequest::InAZoomMeeting,
+            "In_Calendar_Event" => UpdatePresenceStatusRequest::InCalendarEvent,
+            "On_A_Call" => UpdatePresenceStatusRequest::OnACall,
+            "Presenting" => UpdatePresenceStatusRequest::Presenting,
+            "" => UpdatePresenceStatusRequest::Noop,
+            _ => UpdatePresenceStatusRequest::Unknown(s),
+        })
+    }
+}
+
 impl Default for UpdatePresenceStatusRequest {
     fn default() -> UpdatePresenceStatusRequest {
         UpdatePresenceStatusRequest::Noop
@@ -33795,6 +37165,11 @@ impl UpdatePresenceStatusRequest {
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdatePresenceStatusRequest::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, UpdatePresenceStatusRequest::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34680,7 +38055,7 @@ pub struct GetAccountBillingInvoiceResponse {
 *   * `GOOD`: Thumbs up.
 *   * `NOT GOOD`: Thumbs down.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Quality {
     #[serde(rename = "GOOD")]
     Good,
@@ -34688,8 +38063,8 @@ pub enum Quality {
     NotGood,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Quality {
@@ -34698,12 +38073,36 @@ impl std::fmt::Display for Quality {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
             Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Quality {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Quality {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "GOOD" => Quality::Good,
+            "NOT GOOD" => Quality::NotGood,
+            "" => Quality::Noop,
+            _ => Quality::Unknown(s),
+        })
+    }
+}
+
 impl Default for Quality {
     fn default() -> Quality {
         Quality::Noop
@@ -34713,6 +38112,11 @@ impl Quality {
     pub fn is_noop(&self) -> bool {
         matches!(self, Quality::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Quality::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34785,7 +38189,7 @@ pub struct ParticipantFeedbackResponse {
 /**
 * Result of the call: `call_connected` | `recorded` | `no_answer`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum Result {
     #[serde(rename = "call_connected")]
     CallConnected,
@@ -34795,8 +38199,8 @@ pub enum Result {
     Recorded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -34806,12 +38210,37 @@ impl std::fmt::Display for Result {
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for Result {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Result {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "call_connected" => Result::CallConnected,
+            "no_answer" => Result::NoAnswer,
+            "recorded" => Result::Recorded,
+            "" => Result::Noop,
+            _ => Result::Unknown(s),
+        })
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -34821,6 +38250,11 @@ impl Result {
     pub fn is_noop(&self) -> bool {
         matches!(self, Result::Noop)
     }
+
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Result::Unknown(_))
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -35191,14 +38625,14 @@ pub struct ListMeetingTemplatesResponseData {
 /**
 * The query date type for the `from` and `to` parameters.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
 pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "archive_complete_time")]
     ArchiveCompleteTime,
     #[serde(rename = "meeting_start_time")]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListArchivedFilesQueryDateType {
@@ -35206,17 +38640,46 @@ impl std::fmt::Display for ListArchivedFilesQueryDateType {
         match self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl Serialize for ListArchivedFilesQueryDateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ListArchivedFilesQueryDateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "archive_complete_time" => ListArchivedFilesQueryDateType::ArchiveCompleteTime,
+            "meeting_start_time" => ListArchivedFilesQueryDateType::MeetingStartTime,
+            _ => ListArchivedFilesQueryDateType::Unknown(s),
+        })
+    }
+}
+
 impl Default for ListArchivedFilesQueryDateType {
     fn default() -> ListArchivedFilesQueryDateType {
         ListArchivedFilesQueryDateType::MeetingStartTime
     }
 }
+impl ListArchivedFilesQueryDateType {
+    /// Returns true if the value isn't one the spec defines.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ListArchivedFilesQueryDateType::Unknown(_))
+    }
+}
 
 /// Information about the archived files.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
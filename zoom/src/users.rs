@@ -2,6 +2,36 @@ use anyhow::Result;
 
 use crate::Client;
 
+/// The `multipart/form-data` body sent by [`Users::picture`].
+#[derive(Default)]
+pub struct UserPictureForm {
+    pic_file: Option<crate::utils::FilePart>,
+}
+
+impl UserPictureForm {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The file's path.
+    pub fn pic_file(mut self, value: crate::utils::FilePart) -> Self {
+        self.pic_file = Some(value);
+        self
+    }
+
+    /// Build the multipart form, opening any files that are uploaded from disk.
+    pub async fn into_form(self) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(value) = self.pic_file {
+            form = form.part("pic_file", value.into_part().await?);
+        } else {
+            anyhow::bail!("missing required multipart field `pic_file`");
+        }
+
+        Ok(form)
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -163,6 +193,69 @@ impl Users {
         // Return our response data.
         Ok(users)
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list your account's users.
+     *
+     * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::UsersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_fields.to_string().is_empty() {
+            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id".to_string(), role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::UsersResponseData = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.users,
+                })
+            }
+        })
+    }
     /**
      * Create users.
      *
@@ -666,7 +759,7 @@ impl Users {
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
-    pub async fn picture(&self, user_id: &str) -> Result<()> {
+    pub async fn picture(&self, user_id: &str, body: UserPictureForm) -> Result<()> {
         let url = self.client.url(
             &format!(
                 "/users/{}/picture",
@@ -675,13 +768,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form().await?)
             .await
     }
     /**
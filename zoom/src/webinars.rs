@@ -395,6 +395,70 @@ impl Webinars {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_all_participants`, this function returns a stream of the items that only fetches
+     * the next page once the items of the previous one have been consumed.
+     * Pass the `cursor()` of an earlier stream as `page_cursor` to resume where it left off.
+     *
+     * Use this API to list all the participants who attended a webinar hosted in the past. <br>
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     */
+    pub fn list_all_participants_stream(
+        &self,
+        webinar_id: &str,
+        page_cursor: Option<crate::utils::PageCursor>,
+    ) -> crate::utils::Pages<crate::types::Participants> {
+        let url = self.client.url(
+            &format!(
+                "/past_webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            None,
+        );
+        let client = self.client.clone();
+        crate::utils::Pages::new(page_cursor, move |page| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => format!(
+                        "{}{}next_page_token={}",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    ),
+                    None => url,
+                };
+                let resp: crate::types::ListWebinarParticipantsResponse = client
+                    .get(
+                        &url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                Ok(crate::utils::Page {
+                    next: Some(resp.next_page_token),
+                    items: resp.participants,
+                })
+            }
+        })
+    }
     /**
      * Update webinar status.
      *
@@ -83,3 +83,33 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+#[tokio::test]
+async fn test_generated_mocks() {
+    let mock = zoom_api::mock::Mock::start().await;
+
+    let mut client = zoom_api::Client::new("", "", "", "token", "");
+    mock.point(&mut client);
+
+    mock.meetings()
+        .meeting(85746065)
+        .with_query("occurrence_id", "1648194360000")
+        .expect(1)
+        .responds_with(
+            serde_json::from_value(serde_json::json!({
+                "id": 85746065,
+                "topic": "Standup",
+                "duration": 15
+            }))
+            .unwrap(),
+        )
+        .await;
+
+    let meeting = client
+        .meetings()
+        .meeting(85746065, "1648194360000", false)
+        .await
+        .unwrap();
+    assert_eq!(meeting.meeting_info_get.topic, "Standup");
+    assert_eq!(meeting.meeting_info_get.duration, 15);
+}